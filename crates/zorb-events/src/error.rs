@@ -0,0 +1,41 @@
+//! Decode errors.
+
+use core::fmt;
+use pinocchio::pubkey::Pubkey;
+
+/// Error returned when bytes or logs cannot be decoded into events.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// Program id is not a Zorb program on the compiled network
+    UnknownProgram(Pubkey),
+    /// Shorter than an event discriminator
+    TooShort,
+    /// Discriminator is not an event of the emitting program
+    UnknownDiscriminator(u64),
+    /// Length matches neither framing of the event type
+    InvalidLength,
+    /// Reserved frame bytes are not zero
+    InvalidFrame,
+    /// Schema version this decoder does not understand
+    UnsupportedVersion(u8),
+    /// `Program data:` entry is not valid base64
+    InvalidBase64,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::UnknownProgram(id) => {
+                write!(f, "not a Zorb program: {}", bs58::encode(id).into_string())
+            }
+            DecodeError::TooShort => f.write_str("event shorter than its discriminator"),
+            DecodeError::UnknownDiscriminator(d) => write!(f, "unknown event discriminator {d}"),
+            DecodeError::InvalidLength => f.write_str("event length matches no framing"),
+            DecodeError::InvalidFrame => f.write_str("reserved event frame bytes are not zero"),
+            DecodeError::UnsupportedVersion(v) => write!(f, "unsupported event schema version {v}"),
+            DecodeError::InvalidBase64 => f.write_str("program data is not valid base64"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
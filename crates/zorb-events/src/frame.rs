@@ -0,0 +1,162 @@
+//! Event frame and `Log` instruction unwrapping shared by all programs.
+
+use crate::DecodeError;
+use bytemuck::Pod;
+use panchor::events::LogFraming;
+use shielded_pool::events::{EVENT_FRAME_HEADER_SIZE, EventFrameHeader, LEGACY_SCHEMA_VERSION};
+
+/// Size of the legacy frame header: discriminator (8).
+const LEGACY_FRAME_HEADER_SIZE: usize = 8;
+
+/// Size of the `Log` instruction header: discriminator (1) + length (4).
+const LOG_HEADER_SIZE: usize = 5;
+
+/// Schema version of the first versioned layout of an event type.
+///
+/// Token-pool and unified-sol-pool events are still emitted in the legacy
+/// frame; when they move to the versioned frame, their current layouts become
+/// this version.
+pub const FIRST_SCHEMA_VERSION: u8 = 1;
+
+/// Strip the `Log` instruction header if `data` is a self-CPI `Log` call.
+///
+/// Anything else is returned unchanged as event bytes.
+pub(crate) fn unwrap_log_instruction(
+    data: &[u8],
+    log_discriminator: u8,
+    framing: LogFraming,
+) -> &[u8] {
+    match data.split_first() {
+        Some((&discriminator, rest)) if discriminator == log_discriminator => {
+            match framing.payload(rest) {
+                // Programs emit exactly the payload, whatever the framing allows
+                Ok(payload) if LOG_HEADER_SIZE + payload.len() == data.len() => payload,
+                _ => data,
+            }
+        }
+        _ => data,
+    }
+}
+
+/// Read the event discriminator (the first 8 bytes in both framings).
+pub(crate) fn discriminator(data: &[u8]) -> Result<u64, DecodeError> {
+    data.get(..LEGACY_FRAME_HEADER_SIZE)
+        .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+        .ok_or(DecodeError::TooShort)
+}
+
+/// Split versioned event bytes into `(schema_version, payload)`.
+///
+/// Versions from [`FIRST_SCHEMA_VERSION`] up to `max_version` are accepted.
+pub(crate) fn split_versioned(data: &[u8], max_version: u8) -> Result<(u8, &[u8]), DecodeError> {
+    if data.len() < EVENT_FRAME_HEADER_SIZE {
+        return Err(DecodeError::InvalidLength);
+    }
+    let header: EventFrameHeader = bytemuck::pod_read_unaligned(&data[..EVENT_FRAME_HEADER_SIZE]);
+    if header._reserved != [0u8; 7] {
+        return Err(DecodeError::InvalidFrame);
+    }
+    if header.schema_version == LEGACY_SCHEMA_VERSION || header.schema_version > max_version {
+        return Err(DecodeError::UnsupportedVersion(header.schema_version));
+    }
+    Ok((header.schema_version, &data[EVENT_FRAME_HEADER_SIZE..]))
+}
+
+/// Decode a fixed-size event of type `T` from either framing.
+///
+/// Legacy bytes are exactly `8 + size_of::<T>()` long and versioned bytes
+/// `16 + size_of::<T>()`; versioned events newer than `max_version` are
+/// rejected.
+pub(crate) fn decode_fixed<T: Pod>(data: &[u8], max_version: u8) -> Result<T, DecodeError> {
+    let payload_len = core::mem::size_of::<T>();
+    if data.len() == LEGACY_FRAME_HEADER_SIZE + payload_len {
+        return Ok(bytemuck::pod_read_unaligned(
+            &data[LEGACY_FRAME_HEADER_SIZE..],
+        ));
+    }
+    if data.len() != EVENT_FRAME_HEADER_SIZE + payload_len {
+        return Err(DecodeError::InvalidLength);
+    }
+    let (_, payload) = split_versioned(data, max_version)?;
+    Ok(bytemuck::pod_read_unaligned(payload))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Build a value of type `T` with every byte set to a distinct pattern.
+    pub(crate) fn patterned<T: Pod>() -> T {
+        let bytes: Vec<u8> = (0..core::mem::size_of::<T>())
+            .map(|i| (i * 7 + 1) as u8)
+            .collect();
+        bytemuck::pod_read_unaligned(&bytes)
+    }
+
+    /// Versioned frame around `payload`.
+    pub(crate) fn versioned(discriminator: u64, schema_version: u8, payload: &[u8]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        EventFrameHeader::new(discriminator, schema_version).write_to(&mut bytes);
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+
+    #[test]
+    fn test_unwrap_log_instruction() {
+        let event = versioned(33, 1, &[5u8; 24]);
+        let mut data = vec![33];
+        data.extend_from_slice(&(event.len() as u32).to_le_bytes());
+        data.extend_from_slice(&event);
+        for framing in [LogFraming::BorshVec, LogFraming::LengthPrefixed] {
+            assert_eq!(unwrap_log_instruction(&data, 33, framing), &event[..]);
+            // Event bytes whose discriminator equals the Log discriminator
+            assert_eq!(unwrap_log_instruction(&event, 33, framing), &event[..]);
+            assert_eq!(unwrap_log_instruction(&data, 34, framing), &data[..]);
+        }
+
+        // Trailing bytes are never emitted, so the data is not a Log call
+        data.push(0);
+        assert_eq!(
+            unwrap_log_instruction(&data, 33, LogFraming::LengthPrefixed),
+            &data[..]
+        );
+    }
+
+    #[test]
+    fn test_decode_fixed_framings() {
+        let value: [u64; 3] = patterned();
+        let payload = bytemuck::bytes_of(&value);
+
+        let mut legacy = 7u64.to_le_bytes().to_vec();
+        legacy.extend_from_slice(payload);
+        assert_eq!(decode_fixed::<[u64; 3]>(&legacy, 1), Ok(value));
+        assert_eq!(
+            decode_fixed::<[u64; 3]>(&versioned(7, 1, payload), 1),
+            Ok(value)
+        );
+        assert_eq!(
+            decode_fixed::<[u64; 3]>(&versioned(7, 2, payload), 2),
+            Ok(value)
+        );
+
+        assert_eq!(
+            decode_fixed::<[u64; 3]>(&versioned(7, 2, payload), 1),
+            Err(DecodeError::UnsupportedVersion(2))
+        );
+        assert_eq!(
+            decode_fixed::<[u64; 3]>(&versioned(7, 0, payload), 1),
+            Err(DecodeError::UnsupportedVersion(0))
+        );
+        assert_eq!(
+            decode_fixed::<[u64; 3]>(&legacy[..legacy.len() - 1], 1),
+            Err(DecodeError::InvalidLength)
+        );
+
+        let mut reserved = versioned(7, 1, payload);
+        reserved[12] = 1;
+        assert_eq!(
+            decode_fixed::<[u64; 3]>(&reserved, 1),
+            Err(DecodeError::InvalidFrame)
+        );
+    }
+}
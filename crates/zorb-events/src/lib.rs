@@ -0,0 +1,119 @@
+//! Off-chain decoder for the events of every Zorb program.
+//!
+//! Indexers, relayers and tests decode shielded-pool, token-pool and
+//! unified-sol-pool events through [`decode_event`] instead of parsing byte
+//! offsets themselves. Event types come from the program crates, so a layout
+//! change there is picked up here at compile time.
+//!
+//! # Framings
+//!
+//! [`decode_event`] accepts event bytes as they appear anywhere in a
+//! transaction:
+//!
+//! - **Self-CPI**: the data of an inner instruction to the program's `Log`
+//!   instruction, `[Log discriminator][len: u32 LE][event bytes]`.
+//! - **Direct log**: the event bytes of a base64 `Program data:` entry.
+//!
+//! The event bytes themselves are either versioned,
+//! `[discriminator: 8][schema_version: 1][reserved: 7][payload]` (every
+//! shielded-pool event), or legacy, `[discriminator: 8][payload]` (token-pool
+//! and unified-sol-pool events until they adopt the versioned frame). Fixed-size
+//! events are told apart by length, as in `shielded_pool::events::decode`.
+//!
+//! [`from_transaction_logs`] decodes every event in a transaction's log
+//! messages.
+//!
+//! # Feature Flags
+//!
+//! Program IDs follow the network feature (`mainnet`, `devnet`, `testnet`,
+//! `localnet`; mainnet when none is set), as in the program crates.
+
+#[macro_use]
+mod macros;
+
+mod error;
+mod frame;
+mod logs;
+mod shielded;
+mod token;
+mod unified_sol;
+
+pub use error::DecodeError;
+pub use frame::FIRST_SCHEMA_VERSION;
+pub use logs::from_transaction_logs;
+pub use shielded::{NewCommitment, NewCommitmentChunk, NewReceipt, ShieldedPoolEvent};
+pub use token::TokenPoolEvent;
+pub use unified_sol::UnifiedSolPoolEvent;
+
+use pinocchio::pubkey::Pubkey;
+use serde::{Deserialize, Serialize};
+
+/// An event emitted by one of the Zorb programs.
+#[derive(Clone, Serialize, Deserialize)]
+pub enum ZorbEvent {
+    /// Event emitted by the shielded pool (hub)
+    ShieldedPool(ShieldedPoolEvent),
+    /// Event emitted by the token pool
+    TokenPool(TokenPoolEvent),
+    /// Event emitted by the unified SOL pool
+    UnifiedSolPool(UnifiedSolPoolEvent),
+}
+
+impl ZorbEvent {
+    /// Event type name, as in the emitting program's `EventType`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ZorbEvent::ShieldedPool(event) => event.name(),
+            ZorbEvent::TokenPool(event) => event.name(),
+            ZorbEvent::UnifiedSolPool(event) => event.name(),
+        }
+    }
+}
+
+/// Decode an event emitted by `program_id`.
+///
+/// `bytes` is either the event bytes of a `Program data:` entry or the data of
+/// a self-CPI `Log` instruction (see [Framings](crate#framings)). The two
+/// cannot be confused: an event discriminator is below 256, so event bytes
+/// starting with the `Log` discriminator would have a zero length prefix,
+/// while every event is longer than the prefix.
+///
+/// # Errors
+///
+/// Returns [`DecodeError::UnknownProgram`] if `program_id` is not a Zorb
+/// program on the compiled network, or the error from decoding the event.
+pub fn decode_event(program_id: &Pubkey, bytes: &[u8]) -> Result<ZorbEvent, DecodeError> {
+    if *program_id == shielded_pool::ID {
+        shielded::decode(bytes).map(ZorbEvent::ShieldedPool)
+    } else if *program_id == token_pool::ID {
+        token::decode(bytes).map(ZorbEvent::TokenPool)
+    } else if *program_id == unified_sol_pool::ID {
+        unified_sol::decode(bytes).map(ZorbEvent::UnifiedSolPool)
+    } else {
+        Err(DecodeError::UnknownProgram(*program_id))
+    }
+}
+
+/// Whether `program_id` is one of the Zorb programs [`decode_event`] accepts.
+pub fn is_zorb_program(program_id: &Pubkey) -> bool {
+    [shielded_pool::ID, token_pool::ID, unified_sol_pool::ID].contains(program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use token_pool::events::PoolClosedEvent;
+
+    #[test]
+    fn test_unknown_program_rejected() {
+        let event = <PoolClosedEvent as bytemuck::Zeroable>::zeroed();
+        let bytes = panchor::prelude::EventBytes::to_event_bytes(&event);
+        assert!(decode_event(&token_pool::ID, &bytes).is_ok());
+        assert_eq!(
+            decode_event(&[9u8; 32], &bytes).err(),
+            Some(DecodeError::UnknownProgram([9u8; 32]))
+        );
+        assert!(!is_zorb_program(&[9u8; 32]));
+        assert!(is_zorb_program(&shielded_pool::ID));
+    }
+}
@@ -0,0 +1,203 @@
+//! Decoding events from transaction log messages.
+
+use crate::{DecodeError, ZorbEvent, decode_event, is_zorb_program};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use pinocchio::pubkey::Pubkey;
+
+const PROGRAM_PREFIX: &str = "Program ";
+const DATA_PREFIX: &str = "Program data: ";
+
+/// A `Program data:` entry logged by a Zorb program.
+struct DataEntry {
+    program_id: Pubkey,
+    depth: usize,
+    /// Logged by the program's own `Log` instruction (self-CPI)
+    self_cpi: bool,
+    bytes: Vec<u8>,
+}
+
+/// Decode every Zorb event in a transaction's log messages.
+///
+/// `Program data:` entries are attributed to the innermost invoked program;
+/// entries logged by other programs are skipped. An event a program logs
+/// directly and then again through its self-CPI `Log` instruction (the
+/// shielded pool's `SelfCpi` emission mode) is returned once.
+///
+/// # Errors
+///
+/// Returns [`DecodeError::InvalidBase64`] for a malformed `Program data:`
+/// entry, or the error from [`decode_event`] for an entry logged by a Zorb
+/// program that does not decode.
+pub fn from_transaction_logs(logs: &[String]) -> Result<Vec<ZorbEvent>, DecodeError> {
+    let mut stack: Vec<Option<Pubkey>> = Vec::new();
+    let mut entries: Vec<DataEntry> = Vec::new();
+
+    for line in logs {
+        if let Some(data) = line.strip_prefix(DATA_PREFIX) {
+            let Some(Some(program_id)) = stack.last() else {
+                continue;
+            };
+            if !is_zorb_program(program_id) {
+                continue;
+            }
+            let mut bytes = Vec::new();
+            for field in data.split(' ') {
+                bytes.extend(
+                    STANDARD
+                        .decode(field)
+                        .map_err(|_| DecodeError::InvalidBase64)?,
+                );
+            }
+            let self_cpi = stack.len() >= 2 && stack[stack.len() - 2] == Some(*program_id);
+            entries.push(DataEntry {
+                program_id: *program_id,
+                depth: stack.len(),
+                self_cpi,
+                bytes,
+            });
+        } else if let Some(rest) = line.strip_prefix(PROGRAM_PREFIX) {
+            let mut words = rest.split(' ');
+            let (Some(id), Some(status)) = (words.next(), words.next()) else {
+                continue;
+            };
+            if status == "invoke" {
+                stack.push(parse_pubkey(id));
+            } else if status == "success" || status == "failed:" {
+                stack.pop();
+            }
+        }
+    }
+
+    let mut events = Vec::with_capacity(entries.len());
+    for (i, entry) in entries.iter().enumerate() {
+        if !entry.self_cpi
+            && entries
+                .get(i + 1)
+                .is_some_and(|next| is_self_cpi_copy(entry, next))
+        {
+            continue;
+        }
+        events.push(decode_event(&entry.program_id, &entry.bytes)?);
+    }
+    Ok(events)
+}
+
+/// Whether `next` is the self-CPI `Log` re-emission of `entry`.
+fn is_self_cpi_copy(entry: &DataEntry, next: &DataEntry) -> bool {
+    next.self_cpi
+        && next.program_id == entry.program_id
+        && next.depth == entry.depth + 1
+        && next.bytes == entry.bytes
+}
+
+fn parse_pubkey(s: &str) -> Option<Pubkey> {
+    bs58::decode(s.as_bytes()).into_array_const::<32>().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use panchor::prelude::EventBytes;
+
+    const FOREIGN: Pubkey = [7u8; 32];
+
+    fn invoke(id: &Pubkey, depth: usize) -> String {
+        format!(
+            "Program {} invoke [{depth}]",
+            bs58::encode(id).into_string()
+        )
+    }
+
+    fn success(id: &Pubkey) -> String {
+        format!("Program {} success", bs58::encode(id).into_string())
+    }
+
+    fn data(bytes: &[u8]) -> String {
+        format!("{DATA_PREFIX}{}", STANDARD.encode(bytes))
+    }
+
+    fn token_event(pool: u8) -> Vec<u8> {
+        let mut event = <token_pool::events::PoolClosedEvent as bytemuck::Zeroable>::zeroed();
+        event.pool_config = [pool; 32];
+        event.to_event_bytes()
+    }
+
+    fn pool_configs(events: &[ZorbEvent]) -> Vec<u8> {
+        events
+            .iter()
+            .map(|event| match event {
+                ZorbEvent::TokenPool(crate::TokenPoolEvent::PoolClosed(e)) => e.pool_config[0],
+                _ => panic!("unexpected {}", event.name()),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_direct_and_self_cpi_events() {
+        let logs = vec![
+            invoke(&token_pool::ID, 1),
+            "Program log: Instruction: ClosePool".to_string(),
+            data(&token_event(1)),
+            invoke(&token_pool::ID, 2),
+            data(&token_event(2)),
+            success(&token_pool::ID),
+            success(&token_pool::ID),
+        ];
+        let events = from_transaction_logs(&logs).unwrap();
+        assert_eq!(pool_configs(&events), [1, 2]);
+    }
+
+    #[test]
+    fn test_self_cpi_copy_deduplicated() {
+        let logs = vec![
+            invoke(&shielded_pool::ID, 1),
+            invoke(&token_pool::ID, 2),
+            data(&token_event(1)),
+            invoke(&token_pool::ID, 3),
+            data(&token_event(1)),
+            success(&token_pool::ID),
+            // Same event logged again directly is kept
+            data(&token_event(1)),
+            success(&token_pool::ID),
+            success(&shielded_pool::ID),
+        ];
+        let events = from_transaction_logs(&logs).unwrap();
+        assert_eq!(pool_configs(&events), [1, 1]);
+    }
+
+    #[test]
+    fn test_foreign_program_data_skipped() {
+        let logs = vec![
+            invoke(&FOREIGN, 1),
+            data(b"not an event"),
+            invoke(&token_pool::ID, 2),
+            data(&token_event(3)),
+            format!(
+                "Program {} failed: custom program error: 0x1",
+                bs58::encode(token_pool::ID).into_string()
+            ),
+            data(b"still not an event"),
+            success(&FOREIGN),
+            // Outside any invocation
+            data(b"orphan"),
+        ];
+        let events = from_transaction_logs(&logs).unwrap();
+        assert_eq!(pool_configs(&events), [3]);
+    }
+
+    #[test]
+    fn test_malformed_entries_rejected() {
+        let bad_base64 = vec![invoke(&token_pool::ID, 1), format!("{DATA_PREFIX}!!")];
+        assert_eq!(
+            from_transaction_logs(&bad_base64).err(),
+            Some(DecodeError::InvalidBase64)
+        );
+
+        let bad_event = vec![invoke(&token_pool::ID, 1), data(&[0u8; 4])];
+        assert_eq!(
+            from_transaction_logs(&bad_event).err(),
+            Some(DecodeError::TooShort)
+        );
+    }
+}
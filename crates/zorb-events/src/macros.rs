@@ -0,0 +1,107 @@
+//! Per-program event enum generation.
+
+/// Define a program's event enum.
+///
+/// `fixed` lists the program's fixed-size (Pod) events, decoded with
+/// [`crate::frame::decode_fixed`] accepting schema versions up to
+/// `max_version::<Event>()`. `variable` lists variants the program module
+/// decodes itself. Variant names must match the program's `EventType`.
+///
+/// In tests, `emitted_bytes(&event)` serializes a fixed-size event the way
+/// the program emits it.
+macro_rules! program_events {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            max_version = $max_version:ident;
+            emitted_bytes = $emitted_bytes:ident;
+            fixed {
+                $( $variant:ident($ty:ty), )*
+            }
+            variable {
+                $( $var_variant:ident($var_ty:ty), )*
+            }
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, serde::Serialize, serde::Deserialize)]
+        pub enum $name {
+            $(
+                #[doc = concat!("[`", stringify!($ty), "`]")]
+                $variant($ty),
+            )*
+            $(
+                #[doc = concat!("[`", stringify!($var_ty), "`]")]
+                $var_variant($var_ty),
+            )*
+        }
+
+        impl $name {
+            /// Event type name, as in the program's `EventType`.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $( Self::$variant(_) => stringify!($variant), )*
+                    $( Self::$var_variant(_) => stringify!($var_variant), )*
+                }
+            }
+
+            /// Decode a fixed-size event, or `None` if `discriminator` is not
+            /// one.
+            fn decode_fixed(
+                discriminator: u64,
+                data: &[u8],
+            ) -> Option<Result<Self, $crate::DecodeError>> {
+                $(
+                    if discriminator == <$ty as panchor::Discriminator>::DISCRIMINATOR {
+                        return Some(
+                            $crate::frame::decode_fixed::<$ty>(data, $max_version::<$ty>())
+                                .map(Self::$variant),
+                        );
+                    }
+                )*
+                None
+            }
+
+            /// One patterned instance of every fixed-size event.
+            #[cfg(test)]
+            fn fixed_samples() -> Vec<Self> {
+                vec![$( Self::$variant($crate::frame::tests::patterned::<$ty>()), )*]
+            }
+
+            /// Discriminator and payload of a fixed-size event.
+            #[cfg(test)]
+            fn fixed_parts(&self) -> Option<(u64, &[u8])> {
+                match self {
+                    $(
+                        Self::$variant(event) => Some((
+                            <$ty as panchor::Discriminator>::DISCRIMINATOR,
+                            bytemuck::bytes_of(event),
+                        )),
+                    )*
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            }
+
+            /// `Event::name()` of a fixed-size event's type.
+            #[cfg(test)]
+            fn fixed_type_name(&self) -> Option<&'static str> {
+                match self {
+                    $( Self::$variant(_) => Some(<$ty as panchor::Event>::name()), )*
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            }
+
+            /// Bytes of a fixed-size event as the program emits it.
+            #[cfg(test)]
+            fn fixed_event_bytes(&self) -> Option<Vec<u8>> {
+                match self {
+                    $( Self::$variant(event) => Some($emitted_bytes(event)), )*
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            }
+        }
+    };
+}
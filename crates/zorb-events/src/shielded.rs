@@ -0,0 +1,333 @@
+//! Shielded pool (hub) events.
+
+use crate::DecodeError;
+use crate::frame;
+use panchor::Discriminator;
+use panchor::events::LogFraming;
+use serde::{Deserialize, Serialize};
+use shielded_pool::ShieldedPoolInstruction;
+use shielded_pool::events::*;
+
+/// Size of the schema version 1 receipt event header: receipt_index (8) +
+/// receipt_hash (32). Version 2 appended the relayer and fee breakdown.
+const NEW_RECEIPT_EVENT_HEADER_V1_SIZE: usize = 40;
+
+/// Shielded pool events are versioned per event type.
+const fn max_version<T: EventSchema>() -> u8 {
+    T::CURRENT_VERSION
+}
+
+/// Shielded pool events are emitted in the versioned frame.
+#[cfg(test)]
+fn emitted_bytes<T: VersionedEventBytes>(event: &T) -> Vec<u8> {
+    event.to_versioned_event_bytes()
+}
+
+/// A decoded [`NewCommitmentEvent`].
+#[derive(Clone, Serialize, Deserialize)]
+pub struct NewCommitment {
+    /// Leaf index, commitment, new root and encrypted output size
+    pub header: NewCommitmentHeader,
+    /// Inline encrypted output (empty when carried by chunk events)
+    pub encrypted_output: Vec<u8>,
+}
+
+impl NewCommitment {
+    fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        frame::split_versioned(data, <NewCommitmentHeader as EventSchema>::CURRENT_VERSION)?;
+        let (header, encrypted_output) =
+            parse_new_commitment_event(data).map_err(|()| DecodeError::InvalidLength)?;
+        Ok(Self {
+            header,
+            encrypted_output: encrypted_output.to_vec(),
+        })
+    }
+}
+
+/// A decoded [`NewCommitmentChunkEvent`].
+#[derive(Clone, Serialize, Deserialize)]
+pub struct NewCommitmentChunk {
+    /// Leaf index and chunk position
+    pub header: NewCommitmentChunkHeader,
+    /// Chunk of the encrypted output
+    pub chunk: Vec<u8>,
+}
+
+impl NewCommitmentChunk {
+    fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        frame::split_versioned(
+            data,
+            <NewCommitmentChunkHeader as EventSchema>::CURRENT_VERSION,
+        )?;
+        let (header, chunk) =
+            parse_new_commitment_chunk_event(data).map_err(|()| DecodeError::InvalidLength)?;
+        Ok(Self {
+            header,
+            chunk: chunk.to_vec(),
+        })
+    }
+}
+
+/// A decoded [`NewReceiptEvent`].
+#[derive(Clone, Serialize, Deserialize)]
+pub struct NewReceipt {
+    /// Schema version the event was emitted with
+    pub schema_version: u8,
+    /// Receipt index, hash, relayer and fee breakdown. Version 1 events carry
+    /// only the index and hash; the other fields are zero.
+    pub header: NewReceiptEventHeader,
+    /// Borsh-serialized `Receipt`, the preimage of `header.receipt_hash`
+    pub receipt_data: Vec<u8>,
+}
+
+impl NewReceipt {
+    fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        let (schema_version, payload) =
+            frame::split_versioned(data, <NewReceiptEvent as EventSchema>::CURRENT_VERSION)?;
+        let header_size = if schema_version == 1 {
+            NEW_RECEIPT_EVENT_HEADER_V1_SIZE
+        } else {
+            NEW_RECEIPT_EVENT_HEADER_SIZE
+        };
+        if payload.len() < header_size {
+            return Err(DecodeError::InvalidLength);
+        }
+
+        let mut header = [0u8; NEW_RECEIPT_EVENT_HEADER_SIZE];
+        header[..header_size].copy_from_slice(&payload[..header_size]);
+        Ok(Self {
+            schema_version,
+            header: bytemuck::pod_read_unaligned(&header),
+            receipt_data: payload[header_size..].to_vec(),
+        })
+    }
+}
+
+program_events! {
+    /// An event emitted by the shielded pool.
+    pub enum ShieldedPoolEvent {
+        max_version = max_version;
+        emitted_bytes = emitted_bytes;
+        fixed {
+            NewNullifier(NewNullifierEvent),
+            NullifierBatchInserted(NullifierBatchInsertedEvent),
+            NullifierEpochAdvanced(NullifierEpochAdvancedEvent),
+            NullifierEarliestEpochAdvanced(NullifierEarliestEpochAdvancedEvent),
+            NullifierLeafInserted(NullifierLeafInsertedEvent),
+            NullifierPdaClosed(NullifierPdaClosedEvent),
+            NullifierEpochRootClosed(NullifierEpochRootClosedEvent),
+            DepositEscrowCreated(DepositEscrowCreatedEvent),
+            DepositEscrowClosed(DepositEscrowClosedEvent),
+            DepositEscrowRelayerUpdated(DepositEscrowRelayerUpdatedEvent),
+            TransactSessionCreated(TransactSessionCreatedEvent),
+            TransactChunkUploaded(TransactChunkUploadedEvent),
+            TransactSessionClosed(TransactSessionClosedEvent),
+            PoolRegistered(PoolRegisteredEvent),
+            AuthorityTransferInitiated(AuthorityTransferInitiatedEvent),
+            AuthorityTransferCompleted(AuthorityTransferCompletedEvent),
+            PoolPauseChanged(PoolPauseChangedEvent),
+            PoolConfigActiveChanged(PoolConfigActiveChangedEvent),
+            PoolInitialized(PoolInitializedEvent),
+            AuthorityTransferCancelled(AuthorityTransferCancelledEvent),
+            AuthorityTransferDelayChanged(AuthorityTransferDelayChangedEvent),
+            RoleChanged(RoleChangedEvent),
+            PoolDeregistered(PoolDeregisteredEvent),
+            PoolConfigClosed(PoolConfigClosedEvent),
+            GlobalParamChanged(GlobalParamChangedEvent),
+            GlobalConfigMigrated(GlobalConfigMigratedEvent),
+            PoolConfigModeChanged(PoolConfigModeChangedEvent),
+            WithdrawalsOnlyChanged(WithdrawalsOnlyChangedEvent),
+            EventEmissionModeChanged(EventEmissionModeChangedEvent),
+            StaleAccumulatorPolicyChanged(StaleAccumulatorPolicyChangedEvent),
+        }
+        variable {
+            NewCommitment(NewCommitment),
+            NewReceipt(NewReceipt),
+            NewCommitmentChunk(NewCommitmentChunk),
+        }
+    }
+}
+
+/// Decode a shielded pool event from event bytes or `Log` instruction data.
+pub(crate) fn decode(data: &[u8]) -> Result<ShieldedPoolEvent, DecodeError> {
+    let data = frame::unwrap_log_instruction(
+        data,
+        ShieldedPoolInstruction::Log as u8,
+        LogFraming::BorshVec,
+    );
+    let discriminator = frame::discriminator(data)?;
+    if discriminator == NewCommitmentHeader::DISCRIMINATOR {
+        NewCommitment::decode(data).map(ShieldedPoolEvent::NewCommitment)
+    } else if discriminator == NewCommitmentChunkHeader::DISCRIMINATOR {
+        NewCommitmentChunk::decode(data).map(ShieldedPoolEvent::NewCommitmentChunk)
+    } else if discriminator == NewReceiptEvent::DISCRIMINATOR {
+        NewReceipt::decode(data).map(ShieldedPoolEvent::NewReceipt)
+    } else {
+        ShieldedPoolEvent::decode_fixed(discriminator, data)
+            .unwrap_or(Err(DecodeError::UnknownDiscriminator(discriminator)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::tests::{patterned, versioned};
+    use crate::{ZorbEvent, decode_event};
+    use shielded_pool::instructions::types::{N_INS, N_OUTS, N_PUBLIC_LINES};
+
+    fn decoded(bytes: &[u8]) -> ShieldedPoolEvent {
+        match decode_event(&shielded_pool::ID, bytes) {
+            Ok(ZorbEvent::ShieldedPool(event)) => event,
+            Ok(other) => panic!("decoded as {}", other.name()),
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    fn receipt() -> Receipt {
+        Receipt {
+            version: RECEIPT_VERSION,
+            slot: 7,
+            epoch: 2,
+            commitment_root: [1u8; 32],
+            last_commitment_index: 11,
+            commitments: [[2u8; 32]; N_OUTS],
+            nullifiers: [[3u8; 32]; N_INS],
+            transact_params_hash: [4u8; 32],
+            public_asset_ids: [[5u8; 32]; N_PUBLIC_LINES],
+            public_amounts: [[6u8; 32]; N_PUBLIC_LINES],
+        }
+    }
+
+    #[test]
+    fn test_round_trip_every_fixed_event() {
+        let samples = ShieldedPoolEvent::fixed_samples();
+        assert_eq!(samples.len(), 30);
+        for sample in samples {
+            let (discriminator, payload) = sample.fixed_parts().unwrap();
+            assert_eq!(Some(sample.name()), sample.fixed_type_name());
+
+            // Emitted frame, its self-CPI Log wrapping, and the legacy frame
+            let emitted = sample.fixed_event_bytes().unwrap();
+            let log_ix = build_log_instruction_data(&emitted);
+            let mut legacy = discriminator.to_le_bytes().to_vec();
+            legacy.extend_from_slice(payload);
+            for bytes in [&emitted, &log_ix, &legacy] {
+                let event = decoded(bytes);
+                assert_eq!(event.name(), sample.name());
+                assert_eq!(event.fixed_parts(), Some((discriminator, payload)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_rejects_newer_schema_versions() {
+        let event: PoolPauseChangedEvent = patterned();
+        let mut bytes = event.to_versioned_event_bytes();
+        bytes[8] = PoolPauseChangedEvent::CURRENT_VERSION + 1;
+        assert_eq!(
+            decode(&bytes).err(),
+            Some(DecodeError::UnsupportedVersion(bytes[8]))
+        );
+    }
+
+    #[test]
+    fn test_new_commitment_inline_and_chunked() {
+        let inline_output = [9u8; 100];
+        let inline = NewCommitmentEvent::new(3, [1u8; 32], [2u8; 32], &inline_output);
+        let ShieldedPoolEvent::NewCommitment(event) =
+            decoded(&build_log_instruction_data(&inline.to_event_bytes()))
+        else {
+            panic!("not a commitment");
+        };
+        assert_eq!(event.header.leaf_index, 3);
+        assert_eq!(event.header.commitment, [1u8; 32]);
+        assert_eq!(event.header.new_root, [2u8; 32]);
+        assert_eq!(event.encrypted_output, inline_output);
+
+        let chunked_output: Vec<u8> = (0..1300).map(|i| i as u8).collect();
+        let chunked = NewCommitmentEvent::new(4, [1u8; 32], [2u8; 32], &chunked_output);
+        let ShieldedPoolEvent::NewCommitment(event) = decoded(&chunked.to_event_bytes()) else {
+            panic!("not a commitment");
+        };
+        assert_eq!(event.header.total_chunks, 3);
+        assert!(event.encrypted_output.is_empty());
+
+        let mut reassembled = Vec::new();
+        for chunk in chunked.chunks() {
+            let ShieldedPoolEvent::NewCommitmentChunk(event) = decoded(&chunk.to_event_bytes())
+            else {
+                panic!("not a chunk");
+            };
+            assert_eq!(event.header.leaf_index, 4);
+            assert_eq!(
+                event.header.chunk_index as usize * COMMITMENT_CHUNK_SIZE,
+                reassembled.len()
+            );
+            reassembled.extend_from_slice(&event.chunk);
+        }
+        assert_eq!(reassembled, chunked_output);
+    }
+
+    #[test]
+    fn test_new_receipt_versions() {
+        let receipt = receipt();
+        let header: NewReceiptEventHeader = patterned();
+        let bytes = build_new_receipt_event_bytes(&header, &receipt).unwrap();
+        let ShieldedPoolEvent::NewReceipt(event) = decoded(&bytes) else {
+            panic!("not a receipt");
+        };
+        assert_eq!(event.schema_version, 2);
+        assert_eq!(
+            bytemuck::bytes_of(&event.header),
+            bytemuck::bytes_of(&header)
+        );
+        assert_eq!(event.receipt_data, receipt.to_bytes().unwrap());
+
+        // Version 1: index and hash only
+        let mut v1_payload =
+            bytemuck::bytes_of(&header)[..NEW_RECEIPT_EVENT_HEADER_V1_SIZE].to_vec();
+        v1_payload.extend_from_slice(&event.receipt_data);
+        let ShieldedPoolEvent::NewReceipt(v1) =
+            decoded(&versioned(NewReceiptEvent::DISCRIMINATOR, 1, &v1_payload))
+        else {
+            panic!("not a receipt");
+        };
+        assert_eq!(v1.schema_version, 1);
+        assert_eq!(v1.header.receipt_index, header.receipt_index);
+        assert_eq!(v1.header.receipt_hash, header.receipt_hash);
+        assert_eq!(v1.header.relayer, [0u8; 32]);
+        assert_eq!(v1.header.fees, [0u64; N_PUBLIC_LINES]);
+        assert_eq!(v1.receipt_data, event.receipt_data);
+
+        assert_eq!(
+            decode(&versioned(NewReceiptEvent::DISCRIMINATOR, 3, &v1_payload)).err(),
+            Some(DecodeError::UnsupportedVersion(3))
+        );
+        assert_eq!(
+            decode(&versioned(
+                NewReceiptEvent::DISCRIMINATOR,
+                2,
+                &v1_payload[..10]
+            ))
+            .err(),
+            Some(DecodeError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let header: NewReceiptEventHeader = patterned();
+        let receipt_bytes = build_new_receipt_event_bytes(&header, &receipt()).unwrap();
+        let events = [
+            decoded(&patterned::<PoolPauseChangedEvent>().to_versioned_event_bytes()),
+            decoded(&receipt_bytes),
+        ];
+        for event in events {
+            let event = ZorbEvent::ShieldedPool(event);
+            let json = serde_json::to_value(&event).unwrap();
+            assert!(json["ShieldedPool"][event.name()].is_object());
+            let back: ZorbEvent = serde_json::from_value(json.clone()).unwrap();
+            assert_eq!(serde_json::to_value(&back).unwrap(), json);
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! Unified SOL pool events.
+
+use crate::DecodeError;
+use crate::frame::{self, FIRST_SCHEMA_VERSION};
+use panchor::events::LogFraming;
+use unified_sol_pool::events::*;
+use unified_sol_pool::instructions::UnifiedSolPoolInstruction;
+
+/// Unified SOL pool events are not versioned yet. Generic to match the shielded
+/// pool's per-event version.
+#[allow(clippy::extra_unused_type_parameters)]
+const fn max_version<T>() -> u8 {
+    FIRST_SCHEMA_VERSION
+}
+
+/// Unified SOL pool events are emitted in the legacy frame.
+#[cfg(test)]
+fn emitted_bytes<T: panchor::prelude::EventBytes>(event: &T) -> Vec<u8> {
+    event.to_event_bytes()
+}
+
+program_events! {
+    /// An event emitted by the unified SOL pool.
+    pub enum UnifiedSolPoolEvent {
+        max_version = max_version;
+        emitted_bytes = emitted_bytes;
+        fixed {
+            UnifiedSolDeposit(UnifiedSolDepositEvent),
+            UnifiedSolWithdrawal(UnifiedSolWithdrawalEvent),
+            UnifiedSolRewardsFinalized(UnifiedSolRewardsFinalizedEvent),
+            AppreciationHarvested(AppreciationHarvestedEvent),
+            ExchangeRateUpdated(ExchangeRateUpdatedEvent),
+            ManualRateProposed(ManualRateProposedEvent),
+            ManualRateApplied(ManualRateAppliedEvent),
+            ManualRateCancelled(ManualRateCancelledEvent),
+            LstFinalized(LstFinalizedEvent),
+            StakePoolProgramAdded(StakePoolProgramAddedEvent),
+            StakePoolProgramRemoved(StakePoolProgramRemovedEvent),
+            LstConfigRetired(LstConfigRetiredEvent),
+            LstConfigClosed(LstConfigClosedEvent),
+            LstRateBoundsUpdated(LstRateBoundsUpdatedEvent),
+            LstRateJumpApproved(LstRateJumpApprovedEvent),
+            LstVirtualSolCapUpdated(LstVirtualSolCapUpdatedEvent),
+            UnifiedSolVirtualSolCapUpdated(UnifiedSolVirtualSolCapUpdatedEvent),
+            WithdrawPriorityUpdated(WithdrawPriorityUpdatedEvent),
+            AppreciationCommissionUpdated(AppreciationCommissionUpdatedEvent),
+            CommissionWithdrawn(CommissionWithdrawnEvent),
+            LstConfigMigrated(LstConfigMigratedEvent),
+            EpochModeUpdated(EpochModeUpdatedEvent),
+            MinHarvestLamportsUpdated(MinHarvestLamportsUpdatedEvent),
+            ManualRateDelayUpdated(ManualRateDelayUpdatedEvent),
+            KeeperAdded(KeeperAddedEvent),
+            KeeperRemoved(KeeperRemovedEvent),
+            KeeperAllowlistEnabledUpdated(KeeperAllowlistEnabledUpdatedEvent),
+        }
+        variable {}
+    }
+}
+
+/// Decode a unified SOL pool event from event bytes or `Log` instruction data.
+pub(crate) fn decode(data: &[u8]) -> Result<UnifiedSolPoolEvent, DecodeError> {
+    let data = frame::unwrap_log_instruction(
+        data,
+        UnifiedSolPoolInstruction::Log as u8,
+        LogFraming::LengthPrefixed,
+    );
+    let discriminator = frame::discriminator(data)?;
+    UnifiedSolPoolEvent::decode_fixed(discriminator, data)
+        .unwrap_or(Err(DecodeError::UnknownDiscriminator(discriminator)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::tests::versioned;
+    use crate::{ZorbEvent, decode_event};
+
+    fn decoded(bytes: &[u8]) -> UnifiedSolPoolEvent {
+        match decode_event(&unified_sol_pool::ID, bytes) {
+            Ok(ZorbEvent::UnifiedSolPool(event)) => event,
+            Ok(other) => panic!("decoded as {}", other.name()),
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    #[test]
+    fn test_round_trip_every_event() {
+        let samples = UnifiedSolPoolEvent::fixed_samples();
+        assert_eq!(samples.len(), 27);
+        for sample in samples {
+            let (discriminator, payload) = sample.fixed_parts().unwrap();
+            assert_eq!(Some(sample.name()), sample.fixed_type_name());
+
+            let legacy = sample.fixed_event_bytes().unwrap();
+            let log_ix = unified_sol_pool::events::build_log_instruction_data(&legacy);
+            let upcoming = versioned(discriminator, FIRST_SCHEMA_VERSION, payload);
+            for bytes in [&legacy, &log_ix, &upcoming] {
+                let event = decoded(bytes);
+                assert_eq!(event.name(), sample.name());
+                assert_eq!(event.fixed_parts(), Some((discriminator, payload)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_rejects_malformed_events() {
+        let legacy = UnifiedSolPoolEvent::fixed_samples()[0]
+            .fixed_event_bytes()
+            .unwrap();
+        assert_eq!(
+            decode(&legacy[..legacy.len() - 1]).err(),
+            Some(DecodeError::InvalidLength)
+        );
+        assert_eq!(decode(&legacy[..7]).err(), Some(DecodeError::TooShort));
+
+        let mut unknown = legacy.clone();
+        unknown[..8].copy_from_slice(&99u64.to_le_bytes());
+        assert_eq!(
+            decode(&unknown).err(),
+            Some(DecodeError::UnknownDiscriminator(99))
+        );
+
+        let (discriminator, payload) = UnifiedSolPoolEvent::fixed_samples()[0]
+            .fixed_parts()
+            .map(|(d, p)| (d, p.to_vec()))
+            .unwrap();
+        assert_eq!(
+            decode(&versioned(discriminator, 2, &payload)).err(),
+            Some(DecodeError::UnsupportedVersion(2))
+        );
+    }
+}
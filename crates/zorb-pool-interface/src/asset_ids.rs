@@ -16,6 +16,16 @@
 //!
 //! The probability of a Poseidon hash colliding with the reserved range is
 //! approximately 2^64 / 2^254 ≈ 0, making collisions computationally infeasible.
+//!
+//! # Canonical derivation
+//!
+//! The ZK circuit binds asset IDs, so every program must derive them the same
+//! way. [`compute_token_asset_id`] and [`compute_unified_sol_asset_id`] are the
+//! single source of truth; the golden vectors in this module's tests pin their
+//! output.
+
+use pinocchio::pubkey::Pubkey;
+use solana_poseidon::{Endianness, Parameters, hashv};
 
 /// Unified SOL pool asset ID.
 ///
@@ -51,6 +61,50 @@ pub const UNIFIED_ETH: [u8; 32] = {
     id
 };
 
+/// Returns the unified SOL pool asset ID ([`UNIFIED_SOL`]).
+#[inline]
+pub const fn compute_unified_sol_asset_id() -> [u8; 32] {
+    UNIFIED_SOL
+}
+
+/// Computes a token pool's asset ID from its SPL mint.
+///
+/// The mint (32 bytes, little-endian) is split into two 128-bit limbs, matching
+/// the circuit's representation of a 256-bit value as two field elements:
+///
+/// - Low limb:  `mint[0..16]`  (LE) -> reversed to BE, zero-padded to 32 bytes
+/// - High limb: `mint[16..32]` (LE) -> reversed to BE, zero-padded to 32 bytes
+///
+/// The result is `Poseidon(low_limb, high_limb)` over BN254 with X5 parameters
+/// and big-endian encoding.
+///
+/// The token program is deliberately not an input. Clients and the on-chain
+/// asset binding (`publicAssetId == Poseidon(mint)`, see
+/// `docs/CIRCUIT_ROUTING.md`) hash the mint alone, and a mint address already
+/// belongs to exactly one token program. Hashing the program too would change
+/// the ID of every registered pool and strand its notes.
+///
+/// Returns `None` if the Poseidon syscall fails, so on-chain callers can map
+/// it to an error instead of panicking.
+pub fn compute_token_asset_id(mint: &Pubkey) -> Option<[u8; 32]> {
+    let mut low_limb = [0u8; 32];
+    let mut high_limb = [0u8; 32];
+
+    // Reverse each 16-byte LE chunk into the low 16 bytes of a BE field element
+    for i in 0..16 {
+        low_limb[31 - i] = mint[i];
+        high_limb[31 - i] = mint[16 + i];
+    }
+
+    hashv(
+        Parameters::Bn254X5,
+        Endianness::BigEndian,
+        &[&low_limb, &high_limb],
+    )
+    .ok()
+    .map(|hash| hash.to_bytes())
+}
+
 /// Check if an asset ID is in the reserved range.
 ///
 /// Reserved asset IDs have the first 24 bytes as zero.
@@ -136,6 +190,333 @@ mod tests {
         assert_eq!(UNIFIED_SOL, expected);
     }
 
+    #[test]
+    fn test_compute_unified_sol_asset_id() {
+        assert_eq!(compute_unified_sol_asset_id(), UNIFIED_SOL);
+    }
+
+    /// The token asset-id derivation as it lived privately in shielded-pool
+    /// and token-pool before moving here.
+    fn legacy_token_asset_id(mint_bytes: &[u8; 32]) -> [u8; 32] {
+        let mut low_limb = [0u8; 32];
+        let mut high_limb = [0u8; 32];
+        for i in 0..16 {
+            low_limb[31 - i] = mint_bytes[i];
+            high_limb[31 - i] = mint_bytes[16 + i];
+        }
+        hashv(
+            Parameters::Bn254X5,
+            Endianness::BigEndian,
+            &[&low_limb, &high_limb],
+        )
+        .expect("Poseidon hash should succeed")
+        .to_bytes()
+    }
+
+    /// Golden vectors: mint -> expected asset ID.
+    ///
+    /// A change to any of these breaks every existing token pool's notes.
+    const GOLDEN_VECTORS: [([u8; 32], [u8; 32]); 4] = [
+        // Sequential bytes (vector shared with client implementations)
+        (
+            [
+                0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+                0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c,
+                0x1d, 0x1e, 0x1f, 0x20,
+            ],
+            [
+                0x2a, 0xd3, 0x69, 0x9b, 0xf2, 0xd1, 0xbe, 0x62, 0x80, 0x90, 0x82, 0x18, 0x0a, 0x33,
+                0xac, 0x9b, 0x9d, 0x4f, 0x05, 0x75, 0xa3, 0xfd, 0x45, 0x44, 0x1f, 0x7b, 0xaf, 0x04,
+                0x2f, 0x97, 0x77, 0x09,
+            ],
+        ),
+        // WSOL: So11111111111111111111111111111111111111112
+        (
+            five8_const::decode_32_const("So11111111111111111111111111111111111111112"),
+            [
+                0x05, 0xeb, 0x7c, 0xce, 0x0a, 0xc5, 0x3d, 0x52, 0x9a, 0xb2, 0x03, 0x74, 0xbe, 0x8e,
+                0x30, 0x2e, 0xa0, 0xbb, 0xf1, 0x36, 0xfb, 0xfa, 0x52, 0xe3, 0xd7, 0xc4, 0x3a, 0xa2,
+                0x74, 0xdf, 0x8a, 0x30,
+            ],
+        ),
+        // USDC: EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v
+        (
+            five8_const::decode_32_const("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"),
+            [
+                0x0b, 0x5c, 0x04, 0x86, 0x67, 0x0d, 0x62, 0xfc, 0xef, 0xcd, 0xf2, 0x07, 0x6c, 0xc2,
+                0xfe, 0xa0, 0xe9, 0x0b, 0x08, 0x39, 0x09, 0x52, 0xf3, 0xf4, 0x19, 0xbf, 0x2e, 0x27,
+                0xdc, 0x01, 0xde, 0xde,
+            ],
+        ),
+        // All-zero mint: Poseidon(0, 0)
+        (
+            [0u8; 32],
+            [
+                0x20, 0x98, 0xf5, 0xfb, 0x9e, 0x23, 0x9e, 0xab, 0x3c, 0xea, 0xc3, 0xf2, 0x7b, 0x81,
+                0xe4, 0x81, 0xdc, 0x31, 0x24, 0xd5, 0x5f, 0xfe, 0xd5, 0x23, 0xa8, 0x39, 0xee, 0x84,
+                0x46, 0xb6, 0x48, 0x64,
+            ],
+        ),
+    ];
+
+    #[test]
+    fn test_token_asset_id_golden_vectors() {
+        for (mint, expected) in GOLDEN_VECTORS {
+            let asset_id = compute_token_asset_id(&mint).unwrap();
+            assert_eq!(asset_id, expected, "mint {:02x?}", mint);
+            assert!(!is_reserved(&asset_id));
+        }
+    }
+
+    #[test]
+    fn test_token_asset_id_matches_legacy_implementation() {
+        let single_byte_mints = (0..32).map(|i| {
+            let mut mint = [0u8; 32];
+            mint[i] = (i as u8).wrapping_mul(37) | 1;
+            mint
+        });
+        let mints = GOLDEN_VECTORS
+            .iter()
+            .map(|(mint, _)| *mint)
+            .chain([[0xFF; 32]])
+            .chain(single_byte_mints);
+        for mint in mints {
+            assert_eq!(
+                compute_token_asset_id(&mint).unwrap(),
+                legacy_token_asset_id(&mint),
+                "mint {:02x?}",
+                mint
+            );
+        }
+    }
+
+    #[test]
+    fn test_asset_id_deterministic() {
+        // Use a fixed mint address for reproducibility
+        let mint_bytes: [u8; 32] = [
+            0x06, 0xa7, 0xd5, 0x17, 0x18, 0x7b, 0xd1, 0x63, 0x35, 0xb7, 0xd6, 0xb8, 0x7a, 0x67,
+            0x6c, 0x55, 0x26, 0x3a, 0x6c, 0x86, 0x40, 0xf4, 0xd1, 0x6e, 0x86, 0xf8, 0x24, 0x85,
+            0x05, 0x9d, 0x65, 0x38,
+        ];
+
+        // Compute asset ID multiple times - should always be the same
+        let asset_id_1 = compute_token_asset_id(&mint_bytes).unwrap();
+        let asset_id_2 = compute_token_asset_id(&mint_bytes).unwrap();
+        let asset_id_3 = compute_token_asset_id(&mint_bytes).unwrap();
+
+        assert_eq!(asset_id_1, asset_id_2, "Asset ID should be deterministic");
+        assert_eq!(asset_id_2, asset_id_3, "Asset ID should be deterministic");
+    }
+
+    #[test]
+    fn test_asset_id_different_mints_produce_different_ids() {
+        let mint1_bytes: [u8; 32] = [1u8; 32];
+        let mint2_bytes: [u8; 32] = [2u8; 32];
+
+        let asset_id_1 = compute_token_asset_id(&mint1_bytes).unwrap();
+        let asset_id_2 = compute_token_asset_id(&mint2_bytes).unwrap();
+
+        assert_ne!(
+            asset_id_1, asset_id_2,
+            "Different mints should produce different asset IDs"
+        );
+    }
+
+    #[test]
+    fn test_asset_id_zero_mint() {
+        // Edge case: zero mint address
+        let zero_mint_bytes: [u8; 32] = [0u8; 32];
+
+        let asset_id = compute_token_asset_id(&zero_mint_bytes).unwrap();
+
+        // Poseidon hash of two zero limbs produces a specific non-zero value
+        assert_ne!(
+            asset_id, [0u8; 32],
+            "Asset ID of zero mint should not be zero"
+        );
+
+        // Should be deterministic
+        let asset_id_2 = compute_token_asset_id(&zero_mint_bytes).unwrap();
+        assert_eq!(
+            asset_id, asset_id_2,
+            "Asset ID should be deterministic for zero mint"
+        );
+    }
+
+    #[test]
+    fn test_asset_id_small_values() {
+        // Test with small mint values
+        let small_mint_1: [u8; 32] = {
+            let mut arr = [0u8; 32];
+            arr[31] = 1;
+            arr
+        };
+        let small_mint_2: [u8; 32] = {
+            let mut arr = [0u8; 32];
+            arr[31] = 2;
+            arr
+        };
+
+        let asset_id_1 = compute_token_asset_id(&small_mint_1).unwrap();
+        let asset_id_2 = compute_token_asset_id(&small_mint_2).unwrap();
+
+        // Different inputs should produce different outputs
+        assert_ne!(asset_id_1, asset_id_2);
+
+        // Each should be deterministic
+        assert_eq!(asset_id_1, compute_token_asset_id(&small_mint_1).unwrap());
+        assert_eq!(asset_id_2, compute_token_asset_id(&small_mint_2).unwrap());
+    }
+
+    #[test]
+    fn test_asset_id_collision_resistance() {
+        // Test that similar inputs produce very different outputs (avalanche effect)
+        let mint1: [u8; 32] = [0x01; 32];
+        let mut mint2 = mint1;
+        mint2[0] = 0x02; // Only change first byte
+
+        let asset_id_1 = compute_token_asset_id(&mint1).unwrap();
+        let asset_id_2 = compute_token_asset_id(&mint2).unwrap();
+
+        // Count differing bytes - should be significant (avalanche effect)
+        let differing_bytes: usize = asset_id_1
+            .iter()
+            .zip(asset_id_2.iter())
+            .filter(|(a, b)| a != b)
+            .count();
+
+        // With good hash function, roughly half the bytes should differ
+        assert!(
+            differing_bytes > 10,
+            "Poseidon should exhibit avalanche effect, but only {} bytes differ",
+            differing_bytes
+        );
+    }
+
+    #[test]
+    fn test_asset_id_limb_split() {
+        // Test that the 128-bit limb split works correctly
+        // Mint with distinct high and low halves
+        let mint_bytes: [u8; 32] = [
+            // High 128 bits (bytes 0-15)
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+            0x0f, 0x10, // Low 128 bits (bytes 16-31)
+            0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e,
+            0x1f, 0x20,
+        ];
+
+        let asset_id = compute_token_asset_id(&mint_bytes).unwrap();
+
+        // Swapping high and low should produce different result
+        let mut swapped_bytes = [0u8; 32];
+        swapped_bytes[0..16].copy_from_slice(&mint_bytes[16..32]);
+        swapped_bytes[16..32].copy_from_slice(&mint_bytes[0..16]);
+
+        let swapped_asset_id = compute_token_asset_id(&swapped_bytes).unwrap();
+
+        assert_ne!(
+            asset_id, swapped_asset_id,
+            "Swapping limbs should produce different asset ID"
+        );
+    }
+
+    #[test]
+    fn test_asset_id_max_values() {
+        // Test with max value (0xFF..FF) - should work without field reduction
+        // since each 128-bit limb is well within the ~254-bit BN254 field
+        let max_value: [u8; 32] = [0xFF; 32];
+        let asset_id = compute_token_asset_id(&max_value).unwrap();
+
+        // Should produce a valid non-zero hash
+        assert_ne!(
+            asset_id, [0u8; 32],
+            "Max value should produce non-zero asset ID"
+        );
+
+        // Should be deterministic
+        let asset_id_2 = compute_token_asset_id(&max_value).unwrap();
+        assert_eq!(
+            asset_id, asset_id_2,
+            "Asset ID should be deterministic for max value"
+        );
+    }
+
+    #[test]
+    fn test_asset_id_little_endian_interpretation() {
+        // Test that mint bytes are interpreted as little-endian
+        // A small value (1) in little-endian has the 0x01 byte at position 0
+        let mut mint_le: [u8; 32] = [0u8; 32];
+        mint_le[0] = 0x01; // Value = 1 in little-endian (low byte first)
+
+        // Same value in "big-endian position" would be at byte 31
+        let mut mint_be_style: [u8; 32] = [0u8; 32];
+        mint_be_style[31] = 0x01;
+
+        let asset_id_le = compute_token_asset_id(&mint_le).unwrap();
+        let asset_id_be = compute_token_asset_id(&mint_be_style).unwrap();
+
+        // These should produce different results since we interpret as little-endian
+        assert_ne!(
+            asset_id_le, asset_id_be,
+            "LE and BE-style positioning should produce different asset IDs"
+        );
+    }
+
+    #[test]
+    fn test_asset_id_low_limb_only() {
+        // Value that only affects the low limb (bytes 0-15)
+        let mut mint_low: [u8; 32] = [0u8; 32];
+        mint_low[0] = 0x42;
+        mint_low[15] = 0x43;
+
+        // Value that only affects the high limb (bytes 16-31)
+        let mut mint_high: [u8; 32] = [0u8; 32];
+        mint_high[16] = 0x42;
+        mint_high[31] = 0x43;
+
+        let asset_id_low = compute_token_asset_id(&mint_low).unwrap();
+        let asset_id_high = compute_token_asset_id(&mint_high).unwrap();
+
+        // Low-only and high-only should produce different results
+        assert_ne!(
+            asset_id_low, asset_id_high,
+            "Low-limb-only and high-limb-only should produce different asset IDs"
+        );
+    }
+
+    #[test]
+    fn test_asset_id_byte_reversal() {
+        // Test that bytes are correctly reversed within each limb
+        // In little-endian: byte[0] is LSB, byte[15] is MSB of low limb
+
+        // Create a mint where low limb has ascending bytes
+        let mint_bytes: [u8; 32] = [
+            // Low limb: 0x01 at LSB, 0x10 at MSB (little-endian)
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+            0x0f, 0x10, // High limb: all zeros
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00,
+        ];
+
+        // Create a mint where low limb has descending bytes (reversed)
+        let mint_bytes_reversed: [u8; 32] = [
+            // Low limb: 0x10 at LSB, 0x01 at MSB (reversed)
+            0x10, 0x0f, 0x0e, 0x0d, 0x0c, 0x0b, 0x0a, 0x09, 0x08, 0x07, 0x06, 0x05, 0x04, 0x03,
+            0x02, 0x01, // High limb: all zeros
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00,
+        ];
+
+        let asset_id = compute_token_asset_id(&mint_bytes).unwrap();
+        let asset_id_reversed = compute_token_asset_id(&mint_bytes_reversed).unwrap();
+
+        // These represent different values, so should produce different hashes
+        assert_ne!(
+            asset_id, asset_id_reversed,
+            "Original and reversed bytes should produce different asset IDs"
+        );
+    }
+
     #[test]
     fn test_unified_usd_value() {
         // UNIFIED_USD should be [0, 0, ..., 0, 2]
@@ -5,8 +5,25 @@
 //! - Hub handles all token transfers
 //! - Deposits: Hub transfers first, then CPIs to pool for validation/accounting
 //! - Withdrawals: Hub CPIs to pool for approval, then executes transfers
+//!
+//! Admin and maintenance instructions of each pool are built by
+//! [`token_pool_ix`] and [`unified_sol_pool_ix`] (`cpi` or `client` feature).
+
+#[cfg(any(feature = "cpi", feature = "client"))]
+mod pool_ix;
+#[cfg(any(feature = "cpi", feature = "client"))]
+pub mod token_pool_ix;
+#[cfg(any(feature = "cpi", feature = "client"))]
+pub mod unified_sol_pool_ix;
+
+#[cfg(any(feature = "cpi", feature = "client"))]
+pub use pool_ix::{PoolIx, PoolIxAccount};
 
-use crate::{DepositParams, PoolInstruction, PoolType, WithdrawParams};
+use crate::{
+    DepositParams, DepositParamsV2, PoolInstruction, PoolOpResult, PoolType, UnifiedSolOpResult,
+    WithdrawParams, WithdrawParamsV2,
+};
+use pinocchio::pubkey::Pubkey;
 
 // ============================================================================
 // Instruction Data Builders
@@ -14,21 +31,31 @@ use crate::{DepositParams, PoolInstruction, PoolType, WithdrawParams};
 
 /// Build instruction data for a deposit CPI.
 ///
-/// Layout: [discriminator: u8, params: DepositParams (16 bytes)]
-pub fn build_deposit_instruction_data(params: &DepositParams) -> [u8; 17] {
-    let mut data = [0u8; 17];
+/// Always emits the newest layout: [discriminator: u8, params: DepositParamsV2 (64 bytes)]
+///
+/// Read the pool's result right after the invoke returns (e.g. with
+/// [`DepositResult::from_return_data`](crate::DepositResult::from_return_data)):
+/// the next CPI the caller makes replaces or clears the return data.
+pub fn build_deposit_instruction_data(params: &DepositParams) -> [u8; 1 + DepositParamsV2::SIZE] {
+    let mut data = [0u8; 1 + DepositParamsV2::SIZE];
     data[0] = PoolInstruction::Deposit.to_u8();
-    data[1..17].copy_from_slice(&params.to_bytes());
+    data[1..].copy_from_slice(&DepositParamsV2::new(*params).to_bytes());
     data
 }
 
 /// Build instruction data for a withdrawal CPI.
 ///
-/// Layout: [discriminator: u8, params: WithdrawParams (16 bytes)]
-pub fn build_withdraw_instruction_data(params: &WithdrawParams) -> [u8; 17] {
-    let mut data = [0u8; 17];
+/// Always emits the newest layout: [discriminator: u8, params: WithdrawParamsV2 (72 bytes)]
+///
+/// Read the pool's result right after the invoke returns (e.g. with
+/// [`WithdrawResult::from_return_data`](crate::WithdrawResult::from_return_data)):
+/// the next CPI the caller makes replaces or clears the return data.
+pub fn build_withdraw_instruction_data(
+    params: &WithdrawParams,
+) -> [u8; 1 + WithdrawParamsV2::SIZE] {
+    let mut data = [0u8; 1 + WithdrawParamsV2::SIZE];
     data[0] = PoolInstruction::Withdraw.to_u8();
-    data[1..17].copy_from_slice(&params.to_bytes());
+    data[1..].copy_from_slice(&WithdrawParamsV2::new(*params).to_bytes());
     data
 }
 
@@ -45,20 +72,41 @@ pub fn parse_instruction_discriminator(data: &[u8]) -> Option<PoolInstruction> {
     }
 }
 
-/// Parse deposit params from instruction data (after discriminator).
+/// Parse deposit params, in any supported layout, from instruction data
+/// (discriminator included).
 pub fn parse_deposit_params(data: &[u8]) -> Option<DepositParams> {
-    if data.len() < 17 {
+    DepositParams::parse_any(data.get(1..)?)
+}
+
+/// Parse withdraw params, in any supported layout, from instruction data
+/// (discriminator included).
+pub fn parse_withdraw_params(data: &[u8]) -> Option<WithdrawParams> {
+    WithdrawParams::parse_any(data.get(1..)?)
+}
+
+/// Read the [`PoolOpResult`] a pool set as return data during the last CPI.
+///
+/// Returns `None` if there is no return data, it was set by a program other
+/// than `pool_program`, or it is not exactly [`PoolOpResult::SIZE`] bytes.
+pub fn read_pool_op_result(pool_program: &Pubkey) -> Option<PoolOpResult> {
+    let return_data = pinocchio::cpi::get_return_data()?;
+    if return_data.program_id() != pool_program {
         return None;
     }
-    DepositParams::from_bytes(&data[1..])
+    PoolOpResult::from_bytes(return_data.as_slice())
 }
 
-/// Parse withdraw params from instruction data (after discriminator).
-pub fn parse_withdraw_params(data: &[u8]) -> Option<WithdrawParams> {
-    if data.len() < 17 {
+/// Read the [`UnifiedSolOpResult`] a unified SOL pool set as return data
+/// during the last CPI.
+///
+/// Returns `None` if there is no return data, it was set by a program other
+/// than `pool_program`, or it is not exactly [`UnifiedSolOpResult::SIZE`] bytes.
+pub fn read_unified_sol_op_result(pool_program: &Pubkey) -> Option<UnifiedSolOpResult> {
+    let return_data = pinocchio::cpi::get_return_data()?;
+    if return_data.program_id() != pool_program {
         return None;
     }
-    WithdrawParams::from_bytes(&data[1..])
+    UnifiedSolOpResult::from_bytes(return_data.as_slice())
 }
 
 // ============================================================================
@@ -85,6 +133,19 @@ pub mod pool_layout {
     /// Number of user token accounts per public asset slot.
     /// Layout: (depositor_token, recipient_token, relayer_token)
     pub const USER_TOKEN_ACCOUNTS: usize = 3;
+
+    /// Number of accounts every hub public slot carries after the pool accounts.
+    /// Layout: (escrow, escrow_vault_authority, escrow_token, recipient_token,
+    /// relayer_token, pool_program)
+    pub const SLOT_SHARED_ACCOUNTS: usize = 6;
+
+    /// Offset of `deposit_fee_rate` in TokenPoolConfig account data
+    /// (`withdrawal_fee_rate` follows it).
+    pub const TOKEN_POOL_FEE_RATES_OFFSET: usize = 408;
+
+    /// Offset of `deposit_fee_rate` in UnifiedSolPoolConfig account data
+    /// (`withdrawal_fee_rate` follows it).
+    pub const UNIFIED_SOL_POOL_FEE_RATES_OFFSET: usize = 280;
 }
 
 /// Account indices for deposit CPI.
@@ -156,6 +217,43 @@ pub mod withdraw_accounts {
     pub const UNIFIED_COUNT: usize = 7;
 }
 
+/// Account indices for native SOL unwrap.
+///
+/// Appended after a unified SOL withdrawal's accounts when
+/// `WithdrawParams::unwrap` is set; hub_authority must then sign the CPI.
+/// The pool creates the temporary WSOL account, moves the recipient's share
+/// into it and closes it, then pays the recipient in lamports and refunds the
+/// payer's rent.
+pub mod unwrap_accounts {
+    /// Recipient system account (writable, receives the unwrapped lamports)
+    pub const RECIPIENT: usize = 0;
+    /// Temporary WSOL account PDA (writable, created and closed by the pool)
+    pub const UNWRAP_ACCOUNT: usize = 1;
+    /// WSOL (native) mint
+    pub const NATIVE_MINT: usize = 2;
+    /// Rent payer for the temporary account (writable, signer)
+    pub const PAYER: usize = 3;
+    /// System program
+    pub const SYSTEM_PROGRAM: usize = 4;
+    /// Total unwrap accounts
+    pub const COUNT: usize = 5;
+}
+
+/// Account indices for one extra vault of a routed withdrawal.
+///
+/// Appended after a unified SOL withdrawal's accounts (in priority order, at
+/// most [`MAX_WITHDRAW_ROUTE_VAULTS`](crate::MAX_WITHDRAW_ROUTE_VAULTS) of
+/// them) when `WithdrawParams::route_automatically` is set, or in the
+/// caller's order (`WithdrawParams::fallback_count` of them) for fallback LSTs.
+pub mod route_accounts {
+    /// LST config account of the extra vault (writable, PDA signer)
+    pub const LST_CONFIG: usize = 0;
+    /// LST vault token account (writable, delegated to hub_authority)
+    pub const VAULT: usize = 1;
+    /// Accounts per extra vault
+    pub const COUNT: usize = 2;
+}
+
 // ============================================================================
 // Pool Operations Trait
 // ============================================================================
@@ -171,10 +269,10 @@ pub trait PoolOperations {
     /// Check if the pool is active
     fn is_active(&self) -> bool;
 
-    /// Get deposit fee rate in basis points
+    /// Get deposit fee rate in basis points (after any per-asset override)
     fn deposit_fee_rate(&self) -> u16;
 
-    /// Get withdrawal fee rate in basis points
+    /// Get withdrawal fee rate in basis points (after any per-asset override)
     fn withdrawal_fee_rate(&self) -> u16;
 
     /// Get exchange rate numerator
@@ -203,10 +301,14 @@ mod tests {
         let params = DepositParams {
             amount: 1000,
             expected_output: 995,
+            depositor: [7u8; 32],
+            expect_fee_exempt: 1,
+            ..Default::default()
         };
         let data = build_deposit_instruction_data(&params);
         assert_eq!(data[0], PoolInstruction::Deposit.to_u8());
-        assert_eq!(data.len(), 17);
+        assert_eq!(data.len(), 65);
+        assert_eq!(data[1], crate::CPI_PARAMS_VERSION);
 
         // Verify we can parse it back
         let discriminator = parse_instruction_discriminator(&data);
@@ -215,6 +317,19 @@ mod tests {
         let parsed = parse_deposit_params(&data).unwrap();
         assert_eq!(parsed.amount, 1000);
         assert_eq!(parsed.expected_output, 995);
+        assert_eq!(parsed.depositor, [7u8; 32]);
+        assert!(parsed.expects_fee_exempt());
+
+        // Pre-exemption 16-byte params are rejected
+        assert!(parse_deposit_params(&data[..17]).is_none());
+
+        // Legacy unversioned params still parse
+        let mut legacy = [0u8; 1 + DepositParams::SIZE];
+        legacy[0] = PoolInstruction::Deposit.to_u8();
+        legacy[1..].copy_from_slice(&params.to_bytes());
+        let parsed = parse_deposit_params(&legacy).unwrap();
+        assert_eq!(parsed.amount, 1000);
+        assert!(parsed.expects_fee_exempt());
     }
 
     #[test]
@@ -222,10 +337,13 @@ mod tests {
         let params = WithdrawParams {
             amount: 1000,
             expected_output: 995,
+            recipient: [9u8; 32],
+            ..Default::default()
         };
         let data = build_withdraw_instruction_data(&params);
         assert_eq!(data[0], PoolInstruction::Withdraw.to_u8());
-        assert_eq!(data.len(), 17);
+        assert_eq!(data.len(), 73);
+        assert_eq!(data[1], crate::CPI_PARAMS_VERSION);
 
         // Verify we can parse it back
         let discriminator = parse_instruction_discriminator(&data);
@@ -234,5 +352,39 @@ mod tests {
         let parsed = parse_withdraw_params(&data).unwrap();
         assert_eq!(parsed.amount, 1000);
         assert_eq!(parsed.expected_output, 995);
+        assert_eq!(parsed.recipient, [9u8; 32]);
+        assert!(!parsed.expects_fee_exempt());
+        assert!(!parsed.unwraps());
+        assert_eq!(parsed.unwrap_amount, 0);
+
+        // Pre-unwrap 56-byte params are rejected
+        assert!(parse_withdraw_params(&data[..57]).is_none());
+
+        // Legacy unversioned params still parse
+        let mut legacy = [0u8; 1 + WithdrawParams::SIZE];
+        legacy[0] = PoolInstruction::Withdraw.to_u8();
+        legacy[1..].copy_from_slice(&params.to_bytes());
+        let parsed = parse_withdraw_params(&legacy).unwrap();
+        assert_eq!(parsed.amount, 1000);
+        assert_eq!(parsed.recipient, [9u8; 32]);
+    }
+
+    #[test]
+    fn test_withdraw_instruction_data_unwrap() {
+        let params = WithdrawParams {
+            amount: 1000,
+            expected_output: 995,
+            recipient: [9u8; 32],
+            unwrap: 1,
+            unwrap_amount: 990,
+            ..Default::default()
+        };
+        let data = build_withdraw_instruction_data(&params);
+        assert_eq!(data.len(), 73);
+
+        let parsed = parse_withdraw_params(&data).unwrap();
+        assert!(parsed.unwraps());
+        assert_eq!(parsed.unwrap_amount, 990);
+        assert_eq!(parsed.expected_output, 995);
     }
 }
@@ -0,0 +1,167 @@
+//! Pool instruction type shared by the admin and maintenance builders.
+
+use alloc::vec::Vec;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::slice_invoke_signed,
+    pubkey::Pubkey,
+};
+
+/// System program ID.
+pub(crate) const SYSTEM_PROGRAM_ID: Pubkey = [0u8; 32];
+
+/// Associated token account program ID.
+pub(crate) const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    five8_const::decode_32_const("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+/// An account of a [`PoolIx`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PoolIxAccount {
+    /// Account address
+    pub pubkey: Pubkey,
+    /// Whether the instruction writes the account
+    pub is_writable: bool,
+    /// Whether the account must sign
+    pub is_signer: bool,
+}
+
+impl PoolIxAccount {
+    /// A read-only, non-signer account.
+    pub const fn readonly(pubkey: &Pubkey) -> Self {
+        Self {
+            pubkey: *pubkey,
+            is_writable: false,
+            is_signer: false,
+        }
+    }
+
+    /// A writable, non-signer account.
+    pub const fn writable(pubkey: &Pubkey) -> Self {
+        Self {
+            pubkey: *pubkey,
+            is_writable: true,
+            is_signer: false,
+        }
+    }
+
+    /// A read-only signer.
+    pub const fn readonly_signer(pubkey: &Pubkey) -> Self {
+        Self {
+            pubkey: *pubkey,
+            is_writable: false,
+            is_signer: true,
+        }
+    }
+
+    /// A writable signer.
+    pub const fn writable_signer(pubkey: &Pubkey) -> Self {
+        Self {
+            pubkey: *pubkey,
+            is_writable: true,
+            is_signer: true,
+        }
+    }
+}
+
+/// A pool program instruction built by [`token_pool_ix`](crate::token_pool_ix)
+/// or [`unified_sol_pool_ix`](crate::unified_sol_pool_ix).
+///
+/// `accounts` are in the program's order, trailing accounts included; `data`
+/// is the one-byte discriminator followed by the instruction's data struct.
+/// Invoke it via CPI with [`PoolIx::invoke_signed`], or convert it to a
+/// `solana_program` instruction (`client` feature).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoolIx {
+    /// Pool program invoked
+    pub program_id: Pubkey,
+    /// Accounts in the program's order
+    pub accounts: Vec<PoolIxAccount>,
+    /// Discriminator, then instruction data
+    pub data: Vec<u8>,
+}
+
+impl PoolIx {
+    /// An instruction whose data is `discriminator` followed by `fields`.
+    pub(crate) fn new(
+        program_id: Pubkey,
+        discriminator: u8,
+        accounts: Vec<PoolIxAccount>,
+        fields: &[&[u8]],
+    ) -> Self {
+        let mut data = Vec::with_capacity(1 + fields.iter().map(|f| f.len()).sum::<usize>());
+        data.push(discriminator);
+        for field in fields {
+            data.extend_from_slice(field);
+        }
+        Self {
+            program_id,
+            accounts,
+            data,
+        }
+    }
+
+    /// Append trailing accounts (e.g. a Token-2022 mint).
+    pub fn with_remaining_accounts(
+        mut self,
+        accounts: impl IntoIterator<Item = PoolIxAccount>,
+    ) -> Self {
+        self.accounts.extend(accounts);
+        self
+    }
+
+    /// Account metas for a pinocchio [`Instruction`], in order.
+    pub fn account_metas(&self) -> Vec<AccountMeta<'_>> {
+        self.accounts
+            .iter()
+            .map(|account| {
+                AccountMeta::new(&account.pubkey, account.is_writable, account.is_signer)
+            })
+            .collect()
+    }
+
+    /// Invoke the instruction via CPI.
+    ///
+    /// `account_infos` must be the accounts of [`PoolIx::accounts`], in the same order.
+    pub fn invoke_signed(
+        &self,
+        account_infos: &[&AccountInfo],
+        signers: &[Signer],
+    ) -> ProgramResult {
+        let metas = self.account_metas();
+        let instruction = Instruction {
+            program_id: &self.program_id,
+            accounts: &metas,
+            data: &self.data,
+        };
+        slice_invoke_signed(&instruction, account_infos, signers)
+    }
+}
+
+#[cfg(feature = "client")]
+impl From<PoolIx> for solana_program::instruction::Instruction {
+    fn from(ix: PoolIx) -> Self {
+        use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+
+        let accounts = ix
+            .accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: Pubkey::new_from_array(account.pubkey),
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            })
+            .collect();
+        Self {
+            program_id: Pubkey::new_from_array(ix.program_id),
+            accounts,
+            data: ix.data,
+        }
+    }
+}
+
+/// Encode a bool as the programs' `u8` flag.
+pub(crate) const fn flag(value: bool) -> [u8; 1] {
+    [value as u8]
+}
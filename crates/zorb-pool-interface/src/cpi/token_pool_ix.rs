@@ -0,0 +1,554 @@
+//! Builders for token-pool admin and permissionless instructions.
+//!
+//! Each builder lays out its accounts in the order of the program's
+//! `*Accounts` struct and encodes its `*Data` struct after the discriminator;
+//! the program's own parsers check both in its tests. Program accounts the
+//! program pins to a fixed address (its own ID, the system and associated
+//! token programs) are filled in.
+//!
+//! Instructions moving vault tokens take an optional `mint`: Token-2022 pools
+//! need it as a trailing account.
+
+use alloc::vec;
+use pinocchio::pubkey::Pubkey;
+
+use super::pool_ix::{ASSOCIATED_TOKEN_PROGRAM_ID, PoolIx, PoolIxAccount, SYSTEM_PROGRAM_ID, flag};
+use crate::TOKEN_POOL_PROGRAM_ID;
+
+const INIT_POOL: u8 = 64;
+const SET_POOL_ACTIVE: u8 = 65;
+const SET_FEE_RATES: u8 = 66;
+const FINALIZE_REWARDS: u8 = 67;
+const FUND_REWARDS: u8 = 68;
+const SWEEP_EXCESS: u8 = 70;
+const RECONCILE_VAULT: u8 = 71;
+const FINALIZE_REWARDS_BATCH: u8 = 72;
+const TRANSFER_AUTHORITY: u8 = 192;
+const ACCEPT_AUTHORITY: u8 = 193;
+const SET_PROTOCOL_FEE_CONFIG: u8 = 194;
+const WITHDRAW_PROTOCOL_FEES: u8 = 195;
+const SET_POOL_PAUSED: u8 = 196;
+const CLOSE_POOL: u8 = 197;
+const SET_POOL_LIMITS: u8 = 198;
+const SET_FINALIZE_INTERVAL: u8 = 199;
+const SET_SWEEP_CONFIG: u8 = 200;
+const RETURN_EXCESS: u8 = 201;
+const ADD_FEE_EXEMPT: u8 = 202;
+const REMOVE_FEE_EXEMPT: u8 = 203;
+const CLEAR_VAULT_DEFICIT: u8 = 204;
+const INITIATE_EMERGENCY_WITHDRAW: u8 = 205;
+const CANCEL_EMERGENCY_WITHDRAW: u8 = 206;
+const EXECUTE_EMERGENCY_WITHDRAW: u8 = 207;
+const SET_EMERGENCY_WITHDRAW_DELAY: u8 = 208;
+const INITIATE_VAULT_MIGRATION: u8 = 209;
+const MIGRATE_VAULT: u8 = 210;
+const SET_WITHDRAWAL_POLICY: u8 = 211;
+
+/// An instruction whose accounts are the pool config and its authority.
+fn authority_ix(
+    discriminator: u8,
+    pool_config: &Pubkey,
+    authority: &Pubkey,
+    fields: &[&[u8]],
+) -> PoolIx {
+    PoolIx::new(
+        TOKEN_POOL_PROGRAM_ID,
+        discriminator,
+        vec![
+            PoolIxAccount::writable(pool_config),
+            PoolIxAccount::readonly_signer(authority),
+        ],
+        fields,
+    )
+}
+
+/// An authority instruction that also passes the program for event self-CPI.
+fn authority_event_ix(
+    discriminator: u8,
+    pool_config: &Pubkey,
+    authority: &Pubkey,
+    fields: &[&[u8]],
+) -> PoolIx {
+    authority_ix(discriminator, pool_config, authority, fields)
+        .with_remaining_accounts([PoolIxAccount::readonly(&TOKEN_POOL_PROGRAM_ID)])
+}
+
+/// Append the Token-2022 `mint`, if given.
+fn with_mint(ix: PoolIx, mint: Option<&Pubkey>) -> PoolIx {
+    ix.with_remaining_accounts(mint.map(PoolIxAccount::readonly))
+}
+
+// ============================================================================
+// Admin Instructions
+// ============================================================================
+
+/// `InitPool`: create the pool config and its vault (the config PDA's ATA).
+#[allow(clippy::too_many_arguments)]
+pub fn init_pool(
+    mint: &Pubkey,
+    pool_config: &Pubkey,
+    vault: &Pubkey,
+    authority: &Pubkey,
+    token_program: &Pubkey,
+    max_deposit_amount: u64,
+    deposit_fee_rate: u16,
+    withdrawal_fee_rate: u16,
+) -> PoolIx {
+    PoolIx::new(
+        TOKEN_POOL_PROGRAM_ID,
+        INIT_POOL,
+        vec![
+            PoolIxAccount::readonly(mint),
+            PoolIxAccount::writable(pool_config),
+            PoolIxAccount::writable(vault),
+            PoolIxAccount::writable_signer(authority),
+            PoolIxAccount::readonly(token_program),
+            PoolIxAccount::readonly(&SYSTEM_PROGRAM_ID),
+            PoolIxAccount::readonly(&ASSOCIATED_TOKEN_PROGRAM_ID),
+        ],
+        &[
+            &max_deposit_amount.to_le_bytes(),
+            &deposit_fee_rate.to_le_bytes(),
+            &withdrawal_fee_rate.to_le_bytes(),
+            &[0; 4],
+        ],
+    )
+}
+
+/// `SetPoolActive`: set the pool's active flag.
+pub fn set_pool_active(pool_config: &Pubkey, authority: &Pubkey, is_active: bool) -> PoolIx {
+    authority_ix(
+        SET_POOL_ACTIVE,
+        pool_config,
+        authority,
+        &[&flag(is_active), &[0; 7]],
+    )
+}
+
+/// `SetFeeRates`: set the deposit and withdrawal fee rates (basis points).
+pub fn set_fee_rates(
+    pool_config: &Pubkey,
+    authority: &Pubkey,
+    deposit_fee_rate: u16,
+    withdrawal_fee_rate: u16,
+) -> PoolIx {
+    authority_ix(
+        SET_FEE_RATES,
+        pool_config,
+        authority,
+        &[
+            &deposit_fee_rate.to_le_bytes(),
+            &withdrawal_fee_rate.to_le_bytes(),
+            &[0; 4],
+        ],
+    )
+}
+
+/// `TransferAuthority`: propose `new_authority` (two-step transfer).
+pub fn transfer_authority(
+    pool_config: &Pubkey,
+    authority: &Pubkey,
+    new_authority: &Pubkey,
+) -> PoolIx {
+    authority_ix(TRANSFER_AUTHORITY, pool_config, authority, &[])
+        .with_remaining_accounts([PoolIxAccount::readonly(new_authority)])
+}
+
+/// `AcceptAuthority`: the pending authority accepts the transfer.
+pub fn accept_authority(pool_config: &Pubkey, pending_authority: &Pubkey) -> PoolIx {
+    authority_ix(ACCEPT_AUTHORITY, pool_config, pending_authority, &[])
+}
+
+/// `SetProtocolFeeConfig`: set the protocol fee share and treasury wallet.
+pub fn set_protocol_fee_config(
+    pool_config: &Pubkey,
+    authority: &Pubkey,
+    treasury: &Pubkey,
+    protocol_fee_share_bps: u16,
+) -> PoolIx {
+    authority_ix(
+        SET_PROTOCOL_FEE_CONFIG,
+        pool_config,
+        authority,
+        &[treasury, &protocol_fee_share_bps.to_le_bytes(), &[0; 6]],
+    )
+}
+
+/// `WithdrawProtocolFees`: move accrued protocol fees to the treasury ATA.
+#[allow(clippy::too_many_arguments)]
+pub fn withdraw_protocol_fees(
+    pool_config: &Pubkey,
+    vault: &Pubkey,
+    treasury_token: &Pubkey,
+    authority: &Pubkey,
+    token_program: &Pubkey,
+    mint: Option<&Pubkey>,
+    amount: u64,
+) -> PoolIx {
+    let ix = PoolIx::new(
+        TOKEN_POOL_PROGRAM_ID,
+        WITHDRAW_PROTOCOL_FEES,
+        vec![
+            PoolIxAccount::writable(pool_config),
+            PoolIxAccount::writable(vault),
+            PoolIxAccount::writable(treasury_token),
+            PoolIxAccount::readonly_signer(authority),
+            PoolIxAccount::readonly(token_program),
+            PoolIxAccount::readonly(&TOKEN_POOL_PROGRAM_ID),
+        ],
+        &[&amount.to_le_bytes()],
+    );
+    with_mint(ix, mint)
+}
+
+/// `SetPoolPaused`: pause or unpause the pool's own deposit/withdraw handling.
+pub fn set_pool_paused(
+    pool_config: &Pubkey,
+    authority: &Pubkey,
+    paused: bool,
+    allow_withdrawals_when_paused: bool,
+) -> PoolIx {
+    authority_event_ix(
+        SET_POOL_PAUSED,
+        pool_config,
+        authority,
+        &[&flag(paused), &flag(allow_withdrawals_when_paused), &[0; 6]],
+    )
+}
+
+/// `ClosePool`: close a retired pool's vault and config, refunding `authority`.
+pub fn close_pool(
+    pool_config: &Pubkey,
+    vault: &Pubkey,
+    hub_pool_config: &Pubkey,
+    authority: &Pubkey,
+    token_program: &Pubkey,
+) -> PoolIx {
+    PoolIx::new(
+        TOKEN_POOL_PROGRAM_ID,
+        CLOSE_POOL,
+        vec![
+            PoolIxAccount::writable(pool_config),
+            PoolIxAccount::writable(vault),
+            PoolIxAccount::readonly(hub_pool_config),
+            PoolIxAccount::writable_signer(authority),
+            PoolIxAccount::readonly(token_program),
+            PoolIxAccount::readonly(&TOKEN_POOL_PROGRAM_ID),
+        ],
+        &[],
+    )
+}
+
+/// `SetPoolLimits`: set the total pool cap, single-deposit cap and deposit minimum.
+pub fn set_pool_limits(
+    pool_config: &Pubkey,
+    authority: &Pubkey,
+    max_total_pool: u128,
+    max_single_deposit: u64,
+    min_deposit: u64,
+) -> PoolIx {
+    authority_ix(
+        SET_POOL_LIMITS,
+        pool_config,
+        authority,
+        &[
+            &max_total_pool.to_le_bytes(),
+            &max_single_deposit.to_le_bytes(),
+            &min_deposit.to_le_bytes(),
+        ],
+    )
+}
+
+/// `SetFinalizeInterval`: set the minimum slots between reward finalizations.
+pub fn set_finalize_interval(
+    pool_config: &Pubkey,
+    authority: &Pubkey,
+    finalize_interval_slots: u64,
+) -> PoolIx {
+    authority_ix(
+        SET_FINALIZE_INTERVAL,
+        pool_config,
+        authority,
+        &[&finalize_interval_slots.to_le_bytes()],
+    )
+}
+
+/// `SetSweepConfig`: set the per-call cap and delay applied by `SweepExcess`.
+pub fn set_sweep_config(
+    pool_config: &Pubkey,
+    authority: &Pubkey,
+    max_sweep_per_call: u64,
+    sweep_delay_slots: u64,
+) -> PoolIx {
+    authority_ix(
+        SET_SWEEP_CONFIG,
+        pool_config,
+        authority,
+        &[
+            &max_sweep_per_call.to_le_bytes(),
+            &sweep_delay_slots.to_le_bytes(),
+        ],
+    )
+}
+
+/// `ReturnExcess`: send untracked vault excess to `destination`.
+#[allow(clippy::too_many_arguments)]
+pub fn return_excess(
+    pool_config: &Pubkey,
+    vault: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    token_program: &Pubkey,
+    mint: Option<&Pubkey>,
+    amount: u64,
+) -> PoolIx {
+    let ix = PoolIx::new(
+        TOKEN_POOL_PROGRAM_ID,
+        RETURN_EXCESS,
+        vec![
+            PoolIxAccount::writable(pool_config),
+            PoolIxAccount::writable(vault),
+            PoolIxAccount::writable(destination),
+            PoolIxAccount::readonly_signer(authority),
+            PoolIxAccount::readonly(token_program),
+            PoolIxAccount::readonly(&TOKEN_POOL_PROGRAM_ID),
+        ],
+        &[&amount.to_le_bytes()],
+    );
+    with_mint(ix, mint)
+}
+
+/// `AddFeeExempt`: exempt `exempt_authority` from pool fees.
+pub fn add_fee_exempt(
+    pool_config: &Pubkey,
+    authority: &Pubkey,
+    exempt_authority: &Pubkey,
+) -> PoolIx {
+    authority_ix(ADD_FEE_EXEMPT, pool_config, authority, &[exempt_authority])
+}
+
+/// `RemoveFeeExempt`: remove `exempt_authority` from the fee-exempt list.
+pub fn remove_fee_exempt(
+    pool_config: &Pubkey,
+    authority: &Pubkey,
+    exempt_authority: &Pubkey,
+) -> PoolIx {
+    authority_ix(
+        REMOVE_FEE_EXEMPT,
+        pool_config,
+        authority,
+        &[exempt_authority],
+    )
+}
+
+/// `ClearVaultDeficit`: clear a deficit flagged by `ReconcileVault`.
+pub fn clear_vault_deficit(pool_config: &Pubkey, authority: &Pubkey) -> PoolIx {
+    authority_ix(CLEAR_VAULT_DEFICIT, pool_config, authority, &[])
+}
+
+/// `InitiateEmergencyWithdraw`: queue an emergency withdrawal to `destination`.
+pub fn initiate_emergency_withdraw(
+    pool_config: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+    destination: &Pubkey,
+) -> PoolIx {
+    authority_event_ix(
+        INITIATE_EMERGENCY_WITHDRAW,
+        pool_config,
+        authority,
+        &[&amount.to_le_bytes(), destination],
+    )
+}
+
+/// `CancelEmergencyWithdraw`: drop the pending emergency withdrawal.
+pub fn cancel_emergency_withdraw(pool_config: &Pubkey, authority: &Pubkey) -> PoolIx {
+    authority_event_ix(CANCEL_EMERGENCY_WITHDRAW, pool_config, authority, &[])
+}
+
+/// `ExecuteEmergencyWithdraw`: transfer the pending emergency withdrawal.
+pub fn execute_emergency_withdraw(
+    pool_config: &Pubkey,
+    vault: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    token_program: &Pubkey,
+    mint: Option<&Pubkey>,
+) -> PoolIx {
+    let ix = PoolIx::new(
+        TOKEN_POOL_PROGRAM_ID,
+        EXECUTE_EMERGENCY_WITHDRAW,
+        vec![
+            PoolIxAccount::writable(pool_config),
+            PoolIxAccount::writable(vault),
+            PoolIxAccount::writable(destination),
+            PoolIxAccount::readonly_signer(authority),
+            PoolIxAccount::readonly(token_program),
+            PoolIxAccount::readonly(&TOKEN_POOL_PROGRAM_ID),
+        ],
+        &[],
+    );
+    with_mint(ix, mint)
+}
+
+/// `SetEmergencyWithdrawDelay`: set the emergency withdrawal delay.
+pub fn set_emergency_withdraw_delay(
+    pool_config: &Pubkey,
+    authority: &Pubkey,
+    delay_slots: u64,
+) -> PoolIx {
+    authority_ix(
+        SET_EMERGENCY_WITHDRAW_DELAY,
+        pool_config,
+        authority,
+        &[&delay_slots.to_le_bytes()],
+    )
+}
+
+/// `InitiateVaultMigration`: queue a move of the pool's funds to `new_vault`.
+pub fn initiate_vault_migration(
+    pool_config: &Pubkey,
+    new_vault: &Pubkey,
+    authority: &Pubkey,
+) -> PoolIx {
+    PoolIx::new(
+        TOKEN_POOL_PROGRAM_ID,
+        INITIATE_VAULT_MIGRATION,
+        vec![
+            PoolIxAccount::writable(pool_config),
+            PoolIxAccount::readonly(new_vault),
+            PoolIxAccount::readonly_signer(authority),
+            PoolIxAccount::readonly(&TOKEN_POOL_PROGRAM_ID),
+        ],
+        &[],
+    )
+}
+
+/// `MigrateVault`: create the queued vault and move the full balance into it.
+pub fn migrate_vault(
+    pool_config: &Pubkey,
+    old_vault: &Pubkey,
+    new_vault: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    token_program: &Pubkey,
+) -> PoolIx {
+    PoolIx::new(
+        TOKEN_POOL_PROGRAM_ID,
+        MIGRATE_VAULT,
+        vec![
+            PoolIxAccount::writable(pool_config),
+            PoolIxAccount::writable(old_vault),
+            PoolIxAccount::writable(new_vault),
+            PoolIxAccount::readonly(mint),
+            PoolIxAccount::writable_signer(authority),
+            PoolIxAccount::readonly(token_program),
+            PoolIxAccount::readonly(&SYSTEM_PROGRAM_ID),
+            PoolIxAccount::readonly(&TOKEN_POOL_PROGRAM_ID),
+        ],
+        &[],
+    )
+}
+
+/// `SetWithdrawalPolicy`: set the minimum withdrawal and fee rounding direction.
+pub fn set_withdrawal_policy(
+    pool_config: &Pubkey,
+    authority: &Pubkey,
+    min_withdrawal: u64,
+    round_fees_up: bool,
+) -> PoolIx {
+    authority_ix(
+        SET_WITHDRAWAL_POLICY,
+        pool_config,
+        authority,
+        &[&min_withdrawal.to_le_bytes(), &flag(round_fees_up), &[0; 7]],
+    )
+}
+
+// ============================================================================
+// Permissionless Instructions
+// ============================================================================
+
+/// `FinalizeRewards`: update the reward accumulator once the interval has passed.
+pub fn finalize_rewards(pool_config: &Pubkey) -> PoolIx {
+    PoolIx::new(
+        TOKEN_POOL_PROGRAM_ID,
+        FINALIZE_REWARDS,
+        vec![
+            PoolIxAccount::writable(pool_config),
+            PoolIxAccount::readonly(&TOKEN_POOL_PROGRAM_ID),
+        ],
+        &[],
+    )
+}
+
+/// `FinalizeRewardsBatch`: finalize each `(pool_config, vault)` pool that is due.
+pub fn finalize_rewards_batch(pools: &[(Pubkey, Pubkey)]) -> PoolIx {
+    PoolIx::new(
+        TOKEN_POOL_PROGRAM_ID,
+        FINALIZE_REWARDS_BATCH,
+        vec![PoolIxAccount::readonly(&TOKEN_POOL_PROGRAM_ID)],
+        &[],
+    )
+    .with_remaining_accounts(pools.iter().flat_map(|(pool_config, vault)| {
+        [
+            PoolIxAccount::writable(pool_config),
+            PoolIxAccount::readonly(vault),
+        ]
+    }))
+}
+
+/// `FundRewards`: fund rewards from `funder_token`, vesting over `vest_duration_slots`.
+#[allow(clippy::too_many_arguments)]
+pub fn fund_rewards(
+    pool_config: &Pubkey,
+    vault: &Pubkey,
+    funder_token: &Pubkey,
+    funder: &Pubkey,
+    token_program: &Pubkey,
+    mint: Option<&Pubkey>,
+    amount: u64,
+    vest_duration_slots: u64,
+) -> PoolIx {
+    let ix = PoolIx::new(
+        TOKEN_POOL_PROGRAM_ID,
+        FUND_REWARDS,
+        vec![
+            PoolIxAccount::writable(pool_config),
+            PoolIxAccount::writable(vault),
+            PoolIxAccount::writable(funder_token),
+            PoolIxAccount::readonly_signer(funder),
+            PoolIxAccount::readonly(token_program),
+        ],
+        &[&amount.to_le_bytes(), &vest_duration_slots.to_le_bytes()],
+    );
+    with_mint(ix, mint)
+}
+
+/// `SweepExcess`: move untracked vault excess into pending rewards.
+pub fn sweep_excess(pool_config: &Pubkey, vault: &Pubkey) -> PoolIx {
+    PoolIx::new(
+        TOKEN_POOL_PROGRAM_ID,
+        SWEEP_EXCESS,
+        vec![
+            PoolIxAccount::writable(pool_config),
+            PoolIxAccount::readonly(vault),
+            PoolIxAccount::readonly(&TOKEN_POOL_PROGRAM_ID),
+        ],
+        &[],
+    )
+}
+
+/// `ReconcileVault`: check the vault balance against the accounted balance.
+pub fn reconcile_vault(pool_config: &Pubkey, vault: &Pubkey) -> PoolIx {
+    PoolIx::new(
+        TOKEN_POOL_PROGRAM_ID,
+        RECONCILE_VAULT,
+        vec![
+            PoolIxAccount::writable(pool_config),
+            PoolIxAccount::readonly(vault),
+            PoolIxAccount::readonly(&TOKEN_POOL_PROGRAM_ID),
+        ],
+        &[],
+    )
+}
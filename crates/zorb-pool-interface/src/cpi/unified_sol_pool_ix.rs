@@ -0,0 +1,707 @@
+//! Builders for unified-sol-pool admin and permissionless instructions.
+//!
+//! Each builder lays out its accounts in the order of the program's
+//! `*Accounts` struct and encodes its `*Data` struct after the discriminator;
+//! the program's own parsers check both in its tests. Program accounts the
+//! program pins to a fixed address (its own ID, the system program) are
+//! filled in.
+//!
+//! `unified_sol_pool_config` is the pool-wide config PDA; `lst_config` the
+//! per-LST config PDA.
+
+use alloc::vec;
+use pinocchio::pubkey::Pubkey;
+
+use super::pool_ix::{PoolIx, PoolIxAccount, SYSTEM_PROGRAM_ID, flag};
+use crate::{MAX_WITHDRAW_ROUTE_VAULTS, UNIFIED_SOL_POOL_PROGRAM_ID};
+
+const INIT_UNIFIED_SOL_POOL_CONFIG: u8 = 64;
+const INIT_LST_CONFIG: u8 = 65;
+const SET_UNIFIED_SOL_POOL_CONFIG_ACTIVE: u8 = 66;
+const SET_LST_CONFIG_ACTIVE: u8 = 67;
+const SET_UNIFIED_SOL_POOL_CONFIG_FEE_RATES: u8 = 68;
+const FINALIZE_UNIFIED_REWARDS: u8 = 69;
+const HARVEST_LST_APPRECIATION: u8 = 70;
+const HARVEST_ALL_LST_APPRECIATION: u8 = 72;
+const TRANSFER_AUTHORITY: u8 = 192;
+const ACCEPT_AUTHORITY: u8 = 193;
+const ADD_STAKE_POOL_PROGRAM: u8 = 194;
+const REMOVE_STAKE_POOL_PROGRAM: u8 = 195;
+const SET_LST_CONFIG_RETIRED: u8 = 196;
+const CLOSE_LST_CONFIG: u8 = 197;
+const SET_LST_CONFIG_RATE_BOUNDS: u8 = 198;
+const APPROVE_LST_RATE_JUMP: u8 = 199;
+const SET_LST_CONFIG_FEE_OVERRIDES: u8 = 200;
+const SET_LST_CONFIG_VIRTUAL_SOL_CAP: u8 = 201;
+const SET_UNIFIED_SOL_POOL_CONFIG_VIRTUAL_SOL_CAP: u8 = 202;
+const SET_WITHDRAW_PRIORITY: u8 = 203;
+const SET_APPRECIATION_COMMISSION: u8 = 204;
+const WITHDRAW_COMMISSION: u8 = 205;
+const MIGRATE_LST_CONFIG: u8 = 206;
+const SET_EPOCH_MODE: u8 = 207;
+const SET_LST_CONFIG_MODE: u8 = 208;
+const SET_MIN_HARVEST_LAMPORTS: u8 = 209;
+const PROPOSE_MANUAL_RATE: u8 = 210;
+const APPLY_MANUAL_RATE: u8 = 211;
+const CANCEL_MANUAL_RATE: u8 = 212;
+const SET_MANUAL_RATE_DELAY: u8 = 213;
+const ADD_KEEPER: u8 = 214;
+const REMOVE_KEEPER: u8 = 215;
+const SET_KEEPER_ALLOWLIST_ENABLED: u8 = 216;
+
+/// An instruction whose accounts are the pool config and its authority.
+fn pool_authority_ix(
+    discriminator: u8,
+    unified_sol_pool_config: &Pubkey,
+    authority: &Pubkey,
+    fields: &[&[u8]],
+) -> PoolIx {
+    PoolIx::new(
+        UNIFIED_SOL_POOL_PROGRAM_ID,
+        discriminator,
+        vec![
+            PoolIxAccount::writable(unified_sol_pool_config),
+            PoolIxAccount::readonly_signer(authority),
+        ],
+        fields,
+    )
+}
+
+/// A pool authority instruction that also passes the program for event
+/// self-CPI.
+fn pool_authority_event_ix(
+    discriminator: u8,
+    unified_sol_pool_config: &Pubkey,
+    authority: &Pubkey,
+    fields: &[&[u8]],
+) -> PoolIx {
+    pool_authority_ix(discriminator, unified_sol_pool_config, authority, fields)
+        .with_remaining_accounts([PoolIxAccount::readonly(&UNIFIED_SOL_POOL_PROGRAM_ID)])
+}
+
+/// An instruction updating `lst_config`, authorized by the pool authority.
+fn lst_authority_ix(
+    discriminator: u8,
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    authority: &Pubkey,
+    fields: &[&[u8]],
+) -> PoolIx {
+    PoolIx::new(
+        UNIFIED_SOL_POOL_PROGRAM_ID,
+        discriminator,
+        vec![
+            PoolIxAccount::readonly(unified_sol_pool_config),
+            PoolIxAccount::writable(lst_config),
+            PoolIxAccount::readonly_signer(authority),
+        ],
+        fields,
+    )
+}
+
+/// An LST authority instruction that also passes the program for event
+/// self-CPI.
+fn lst_authority_event_ix(
+    discriminator: u8,
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    authority: &Pubkey,
+    fields: &[&[u8]],
+) -> PoolIx {
+    lst_authority_ix(
+        discriminator,
+        unified_sol_pool_config,
+        lst_config,
+        authority,
+        fields,
+    )
+    .with_remaining_accounts([PoolIxAccount::readonly(&UNIFIED_SOL_POOL_PROGRAM_ID)])
+}
+
+// ============================================================================
+// Admin Instructions
+// ============================================================================
+
+/// `InitUnifiedSolPoolConfig`: create the pool config PDA.
+#[allow(clippy::too_many_arguments)]
+pub fn init_unified_sol_pool_config(
+    unified_sol_pool_config: &Pubkey,
+    authority: &Pubkey,
+    max_deposit_amount: u64,
+    deposit_fee_rate: u16,
+    withdrawal_fee_rate: u16,
+    min_buffer_bps: u16,
+    min_buffer_amount: u64,
+) -> PoolIx {
+    PoolIx::new(
+        UNIFIED_SOL_POOL_PROGRAM_ID,
+        INIT_UNIFIED_SOL_POOL_CONFIG,
+        vec![
+            PoolIxAccount::writable(unified_sol_pool_config),
+            PoolIxAccount::writable_signer(authority),
+            PoolIxAccount::readonly(&SYSTEM_PROGRAM_ID),
+        ],
+        &[
+            &max_deposit_amount.to_le_bytes(),
+            &deposit_fee_rate.to_le_bytes(),
+            &withdrawal_fee_rate.to_le_bytes(),
+            &min_buffer_bps.to_le_bytes(),
+            &[0; 2],
+            &min_buffer_amount.to_le_bytes(),
+        ],
+    )
+}
+
+/// `InitLstConfig`: register an LST of `pool_type` (see `PoolType`) with its vault.
+#[allow(clippy::too_many_arguments)]
+pub fn init_lst_config(
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    lst_mint: &Pubkey,
+    lst_vault: &Pubkey,
+    stake_pool: &Pubkey,
+    stake_pool_program: &Pubkey,
+    authority: &Pubkey,
+    token_program: &Pubkey,
+    pool_type: u8,
+) -> PoolIx {
+    PoolIx::new(
+        UNIFIED_SOL_POOL_PROGRAM_ID,
+        INIT_LST_CONFIG,
+        vec![
+            PoolIxAccount::writable(unified_sol_pool_config),
+            PoolIxAccount::writable(lst_config),
+            PoolIxAccount::readonly(lst_mint),
+            PoolIxAccount::writable(lst_vault),
+            PoolIxAccount::readonly(stake_pool),
+            PoolIxAccount::readonly(stake_pool_program),
+            PoolIxAccount::writable_signer(authority),
+            PoolIxAccount::readonly(token_program),
+            PoolIxAccount::readonly(&SYSTEM_PROGRAM_ID),
+        ],
+        &[&[pool_type], &[0; 7]],
+    )
+}
+
+/// `SetUnifiedSolPoolConfigActive`: set the pool's active flag.
+pub fn set_unified_sol_pool_config_active(
+    unified_sol_pool_config: &Pubkey,
+    authority: &Pubkey,
+    is_active: bool,
+) -> PoolIx {
+    pool_authority_ix(
+        SET_UNIFIED_SOL_POOL_CONFIG_ACTIVE,
+        unified_sol_pool_config,
+        authority,
+        &[&flag(is_active), &[0; 7]],
+    )
+}
+
+/// `SetLstConfigActive`: set an LST's active flag.
+pub fn set_lst_config_active(
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    authority: &Pubkey,
+    is_active: bool,
+) -> PoolIx {
+    lst_authority_ix(
+        SET_LST_CONFIG_ACTIVE,
+        unified_sol_pool_config,
+        lst_config,
+        authority,
+        &[&flag(is_active), &[0; 7]],
+    )
+}
+
+/// `SetUnifiedSolPoolConfigFeeRates`: set the pool-wide fee rates (basis points).
+pub fn set_unified_sol_pool_config_fee_rates(
+    unified_sol_pool_config: &Pubkey,
+    authority: &Pubkey,
+    deposit_fee_rate: u16,
+    withdrawal_fee_rate: u16,
+) -> PoolIx {
+    pool_authority_ix(
+        SET_UNIFIED_SOL_POOL_CONFIG_FEE_RATES,
+        unified_sol_pool_config,
+        authority,
+        &[
+            &deposit_fee_rate.to_le_bytes(),
+            &withdrawal_fee_rate.to_le_bytes(),
+            &[0; 4],
+        ],
+    )
+}
+
+/// `TransferAuthority`: propose `new_authority` (two-step transfer).
+pub fn transfer_authority(
+    unified_sol_pool_config: &Pubkey,
+    authority: &Pubkey,
+    new_authority: &Pubkey,
+) -> PoolIx {
+    pool_authority_ix(TRANSFER_AUTHORITY, unified_sol_pool_config, authority, &[])
+        .with_remaining_accounts([PoolIxAccount::readonly(new_authority)])
+}
+
+/// `AcceptAuthority`: the pending authority accepts the transfer.
+pub fn accept_authority(unified_sol_pool_config: &Pubkey, pending_authority: &Pubkey) -> PoolIx {
+    pool_authority_ix(
+        ACCEPT_AUTHORITY,
+        unified_sol_pool_config,
+        pending_authority,
+        &[],
+    )
+}
+
+/// `AddStakePoolProgram`: allowlist a stake pool program.
+pub fn add_stake_pool_program(
+    unified_sol_pool_config: &Pubkey,
+    authority: &Pubkey,
+    program: &Pubkey,
+) -> PoolIx {
+    pool_authority_event_ix(
+        ADD_STAKE_POOL_PROGRAM,
+        unified_sol_pool_config,
+        authority,
+        &[program],
+    )
+}
+
+/// `RemoveStakePoolProgram`: remove a stake pool program from the allowlist.
+pub fn remove_stake_pool_program(
+    unified_sol_pool_config: &Pubkey,
+    authority: &Pubkey,
+    program: &Pubkey,
+) -> PoolIx {
+    pool_authority_event_ix(
+        REMOVE_STAKE_POOL_PROGRAM,
+        unified_sol_pool_config,
+        authority,
+        &[program],
+    )
+}
+
+/// `SetLstConfigRetired`: retire (or un-retire) an LST, closing deposits.
+pub fn set_lst_config_retired(
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    authority: &Pubkey,
+    is_retired: bool,
+) -> PoolIx {
+    lst_authority_event_ix(
+        SET_LST_CONFIG_RETIRED,
+        unified_sol_pool_config,
+        lst_config,
+        authority,
+        &[&flag(is_retired), &[0; 7]],
+    )
+}
+
+/// `CloseLstConfig`: close a retired, empty LST's config and vault.
+pub fn close_lst_config(
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    lst_vault: &Pubkey,
+    authority: &Pubkey,
+    token_program: &Pubkey,
+) -> PoolIx {
+    PoolIx::new(
+        UNIFIED_SOL_POOL_PROGRAM_ID,
+        CLOSE_LST_CONFIG,
+        vec![
+            PoolIxAccount::writable(unified_sol_pool_config),
+            PoolIxAccount::writable(lst_config),
+            PoolIxAccount::writable(lst_vault),
+            PoolIxAccount::writable_signer(authority),
+            PoolIxAccount::readonly(token_program),
+            PoolIxAccount::readonly(&UNIFIED_SOL_POOL_PROGRAM_ID),
+        ],
+        &[],
+    )
+}
+
+/// `SetLstConfigRateBounds`: set an LST's per-epoch rate band and staleness limit.
+#[allow(clippy::too_many_arguments)]
+pub fn set_lst_config_rate_bounds(
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    authority: &Pubkey,
+    max_rate_increase_bps_per_epoch: u16,
+    max_rate_decrease_bps_per_epoch: u16,
+    max_rate_staleness_slots: u64,
+) -> PoolIx {
+    lst_authority_event_ix(
+        SET_LST_CONFIG_RATE_BOUNDS,
+        unified_sol_pool_config,
+        lst_config,
+        authority,
+        &[
+            &max_rate_increase_bps_per_epoch.to_le_bytes(),
+            &max_rate_decrease_bps_per_epoch.to_le_bytes(),
+            &[0; 4],
+            &max_rate_staleness_slots.to_le_bytes(),
+        ],
+    )
+}
+
+/// `ApproveLstRateJump`: approve a rate outside the LST's per-epoch band.
+pub fn approve_lst_rate_jump(
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    authority: &Pubkey,
+    approved_rate: u64,
+) -> PoolIx {
+    lst_authority_event_ix(
+        APPROVE_LST_RATE_JUMP,
+        unified_sol_pool_config,
+        lst_config,
+        authority,
+        &[&approved_rate.to_le_bytes()],
+    )
+}
+
+/// `SetLstConfigFeeOverrides`: set an LST's fee rate overrides.
+pub fn set_lst_config_fee_overrides(
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    authority: &Pubkey,
+    deposit_fee_bps_override: u16,
+    withdraw_fee_bps_override: u16,
+) -> PoolIx {
+    lst_authority_ix(
+        SET_LST_CONFIG_FEE_OVERRIDES,
+        unified_sol_pool_config,
+        lst_config,
+        authority,
+        &[
+            &deposit_fee_bps_override.to_le_bytes(),
+            &withdraw_fee_bps_override.to_le_bytes(),
+            &[0; 4],
+        ],
+    )
+}
+
+/// `SetLstConfigVirtualSolCap`: cap an LST's virtual SOL.
+pub fn set_lst_config_virtual_sol_cap(
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    authority: &Pubkey,
+    max_virtual_sol: u64,
+) -> PoolIx {
+    lst_authority_event_ix(
+        SET_LST_CONFIG_VIRTUAL_SOL_CAP,
+        unified_sol_pool_config,
+        lst_config,
+        authority,
+        &[&max_virtual_sol.to_le_bytes()],
+    )
+}
+
+/// `SetUnifiedSolPoolConfigVirtualSolCap`: cap the pool's total virtual SOL.
+pub fn set_unified_sol_pool_config_virtual_sol_cap(
+    unified_sol_pool_config: &Pubkey,
+    authority: &Pubkey,
+    max_total_virtual_sol: u64,
+) -> PoolIx {
+    pool_authority_event_ix(
+        SET_UNIFIED_SOL_POOL_CONFIG_VIRTUAL_SOL_CAP,
+        unified_sol_pool_config,
+        authority,
+        &[&max_total_virtual_sol.to_le_bytes()],
+    )
+}
+
+/// `SetWithdrawPriority`: set the LST mints routed withdrawals draw from, in
+/// order (zero = empty slot).
+pub fn set_withdraw_priority(
+    unified_sol_pool_config: &Pubkey,
+    authority: &Pubkey,
+    withdraw_priority: &[Pubkey; MAX_WITHDRAW_ROUTE_VAULTS],
+) -> PoolIx {
+    pool_authority_event_ix(
+        SET_WITHDRAW_PRIORITY,
+        unified_sol_pool_config,
+        authority,
+        &[withdraw_priority.as_flattened()],
+    )
+}
+
+/// `SetAppreciationCommission`: set the commission on harvested appreciation
+/// and its treasury.
+pub fn set_appreciation_commission(
+    unified_sol_pool_config: &Pubkey,
+    authority: &Pubkey,
+    commission_treasury: &Pubkey,
+    appreciation_commission_bps: u16,
+) -> PoolIx {
+    pool_authority_event_ix(
+        SET_APPRECIATION_COMMISSION,
+        unified_sol_pool_config,
+        authority,
+        &[
+            commission_treasury,
+            &appreciation_commission_bps.to_le_bytes(),
+            &[0; 6],
+        ],
+    )
+}
+
+/// `WithdrawCommission`: withdraw accrued commission from an LST vault.
+#[allow(clippy::too_many_arguments)]
+pub fn withdraw_commission(
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    lst_vault: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    token_program: &Pubkey,
+    amount: u64,
+) -> PoolIx {
+    PoolIx::new(
+        UNIFIED_SOL_POOL_PROGRAM_ID,
+        WITHDRAW_COMMISSION,
+        vec![
+            PoolIxAccount::writable(unified_sol_pool_config),
+            PoolIxAccount::writable(lst_config),
+            PoolIxAccount::writable(lst_vault),
+            PoolIxAccount::writable(destination),
+            PoolIxAccount::readonly_signer(authority),
+            PoolIxAccount::readonly(token_program),
+            PoolIxAccount::readonly(&UNIFIED_SOL_POOL_PROGRAM_ID),
+        ],
+        &[&amount.to_le_bytes()],
+    )
+}
+
+/// `MigrateLstConfig`: grow an LstConfig to the current layout, `payer`
+/// funding the extra rent.
+pub fn migrate_lst_config(
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+) -> PoolIx {
+    PoolIx::new(
+        UNIFIED_SOL_POOL_PROGRAM_ID,
+        MIGRATE_LST_CONFIG,
+        vec![
+            PoolIxAccount::readonly(unified_sol_pool_config),
+            PoolIxAccount::writable(lst_config),
+            PoolIxAccount::readonly_signer(authority),
+            PoolIxAccount::writable_signer(payer),
+            PoolIxAccount::readonly(&SYSTEM_PROGRAM_ID),
+            PoolIxAccount::readonly(&UNIFIED_SOL_POOL_PROGRAM_ID),
+        ],
+        &[],
+    )
+}
+
+/// `SetEpochMode`: set how reward epochs advance (see `EpochMode`).
+pub fn set_epoch_mode(
+    unified_sol_pool_config: &Pubkey,
+    authority: &Pubkey,
+    epoch_mode: u8,
+) -> PoolIx {
+    pool_authority_event_ix(
+        SET_EPOCH_MODE,
+        unified_sol_pool_config,
+        authority,
+        &[&[epoch_mode], &[0; 7]],
+    )
+}
+
+/// `SetLstConfigMode`: set an LST's mode bits (deposits/withdrawals disabled).
+pub fn set_lst_config_mode(
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    authority: &Pubkey,
+    mode: u8,
+) -> PoolIx {
+    lst_authority_ix(
+        SET_LST_CONFIG_MODE,
+        unified_sol_pool_config,
+        lst_config,
+        authority,
+        &[&[mode], &[0; 7]],
+    )
+}
+
+/// `SetMinHarvestLamports`: set the smallest appreciation a harvest books.
+pub fn set_min_harvest_lamports(
+    unified_sol_pool_config: &Pubkey,
+    authority: &Pubkey,
+    min_harvest_lamports: u64,
+) -> PoolIx {
+    pool_authority_event_ix(
+        SET_MIN_HARVEST_LAMPORTS,
+        unified_sol_pool_config,
+        authority,
+        &[&min_harvest_lamports.to_le_bytes()],
+    )
+}
+
+/// `ProposeManualRate`: propose a manual exchange rate for an LST whose rate
+/// source is unreadable.
+pub fn propose_manual_rate(
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    authority: &Pubkey,
+    rate: u64,
+) -> PoolIx {
+    lst_authority_event_ix(
+        PROPOSE_MANUAL_RATE,
+        unified_sol_pool_config,
+        lst_config,
+        authority,
+        &[&rate.to_le_bytes()],
+    )
+}
+
+/// `CancelManualRate`: drop the pending manual rate proposal.
+pub fn cancel_manual_rate(
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    authority: &Pubkey,
+) -> PoolIx {
+    lst_authority_event_ix(
+        CANCEL_MANUAL_RATE,
+        unified_sol_pool_config,
+        lst_config,
+        authority,
+        &[],
+    )
+}
+
+/// `SetManualRateDelay`: set the delay before a proposed manual rate applies.
+pub fn set_manual_rate_delay(
+    unified_sol_pool_config: &Pubkey,
+    authority: &Pubkey,
+    delay_slots: u64,
+) -> PoolIx {
+    pool_authority_event_ix(
+        SET_MANUAL_RATE_DELAY,
+        unified_sol_pool_config,
+        authority,
+        &[&delay_slots.to_le_bytes()],
+    )
+}
+
+/// `AddKeeper`: add a keeper to the allowlist.
+pub fn add_keeper(unified_sol_pool_config: &Pubkey, authority: &Pubkey, keeper: &Pubkey) -> PoolIx {
+    pool_authority_event_ix(ADD_KEEPER, unified_sol_pool_config, authority, &[keeper])
+}
+
+/// `RemoveKeeper`: remove a keeper from the allowlist.
+pub fn remove_keeper(
+    unified_sol_pool_config: &Pubkey,
+    authority: &Pubkey,
+    keeper: &Pubkey,
+) -> PoolIx {
+    pool_authority_event_ix(REMOVE_KEEPER, unified_sol_pool_config, authority, &[keeper])
+}
+
+/// `SetKeeperAllowlistEnabled`: enforce (or stop enforcing) the keeper allowlist.
+pub fn set_keeper_allowlist_enabled(
+    unified_sol_pool_config: &Pubkey,
+    authority: &Pubkey,
+    enabled: bool,
+) -> PoolIx {
+    pool_authority_event_ix(
+        SET_KEEPER_ALLOWLIST_ENABLED,
+        unified_sol_pool_config,
+        authority,
+        &[&flag(enabled), &[0; 7]],
+    )
+}
+
+// ============================================================================
+// Permissionless Instructions
+// ============================================================================
+
+/// `FinalizeUnifiedRewards`: finalize rewards across every registered
+/// `lst_configs`.
+pub fn finalize_unified_rewards(
+    unified_sol_pool_config: &Pubkey,
+    caller: &Pubkey,
+    lst_configs: &[Pubkey],
+) -> PoolIx {
+    pool_authority_event_ix(
+        FINALIZE_UNIFIED_REWARDS,
+        unified_sol_pool_config,
+        caller,
+        &[],
+    )
+    .with_remaining_accounts(lst_configs.iter().map(PoolIxAccount::writable))
+}
+
+/// `HarvestLstAppreciation`: harvest one LST's appreciation.
+///
+/// With `expected_rate`, the harvest fails unless the derived rate is within
+/// the program's tolerance of it.
+pub fn harvest_lst_appreciation(
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    rate_data_account: &Pubkey,
+    caller: &Pubkey,
+    expected_rate: Option<u64>,
+) -> PoolIx {
+    let expected_rate = expected_rate.map(u64::to_le_bytes);
+    PoolIx::new(
+        UNIFIED_SOL_POOL_PROGRAM_ID,
+        HARVEST_LST_APPRECIATION,
+        vec![
+            PoolIxAccount::writable(unified_sol_pool_config),
+            PoolIxAccount::writable(lst_config),
+            PoolIxAccount::readonly(rate_data_account),
+            PoolIxAccount::readonly_signer(caller),
+            PoolIxAccount::readonly(&UNIFIED_SOL_POOL_PROGRAM_ID),
+        ],
+        &[expected_rate.as_ref().map_or(&[][..], |rate| rate)],
+    )
+}
+
+/// `HarvestAllLstAppreciation`: harvest every registered LST, given as
+/// `(lst_config, rate_data_account, lst_vault)` triples.
+pub fn harvest_all_lst_appreciation(
+    unified_sol_pool_config: &Pubkey,
+    caller: &Pubkey,
+    lsts: &[(Pubkey, Pubkey, Pubkey)],
+) -> PoolIx {
+    pool_authority_event_ix(
+        HARVEST_ALL_LST_APPRECIATION,
+        unified_sol_pool_config,
+        caller,
+        &[],
+    )
+    .with_remaining_accounts(lsts.iter().flat_map(
+        |(lst_config, rate_data_account, lst_vault)| {
+            [
+                PoolIxAccount::writable(lst_config),
+                PoolIxAccount::readonly(rate_data_account),
+                PoolIxAccount::readonly(lst_vault),
+            ]
+        },
+    ))
+}
+
+/// `ApplyManualRate`: apply a proposed manual rate once its delay has passed.
+pub fn apply_manual_rate(
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    rate_data_account: &Pubkey,
+    lst_vault: &Pubkey,
+    caller: &Pubkey,
+) -> PoolIx {
+    PoolIx::new(
+        UNIFIED_SOL_POOL_PROGRAM_ID,
+        APPLY_MANUAL_RATE,
+        vec![
+            PoolIxAccount::writable(unified_sol_pool_config),
+            PoolIxAccount::writable(lst_config),
+            PoolIxAccount::readonly(rate_data_account),
+            PoolIxAccount::readonly(lst_vault),
+            PoolIxAccount::readonly_signer(caller),
+            PoolIxAccount::readonly(&UNIFIED_SOL_POOL_PROGRAM_ID),
+        ],
+        &[],
+    )
+}
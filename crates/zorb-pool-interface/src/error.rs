@@ -1,5 +1,9 @@
 //! Pool error types.
 
+use pinocchio::pubkey::Pubkey;
+
+use crate::{PoolType, TOKEN_POOL_PROGRAM_ID, UNIFIED_SOL_POOL_PROGRAM_ID};
+
 /// Pool error codes shared across all pool implementations.
 ///
 /// These error codes are used by pool programs and can be matched by the hub
@@ -78,6 +82,190 @@ impl PoolError {
     }
 }
 
+// ============================================================================
+// Pool Program Errors
+// ============================================================================
+
+/// A pool program error, decoded from the `ProgramError::Custom` code a pool
+/// CPI failed with.
+///
+/// Each pool numbers its own errors from 0, so the same code means different
+/// things in different pools; decode with [`decode_pool_error`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PoolErrorKind {
+    /// A `TokenPoolError` variant, by name
+    Token(&'static str),
+    /// A `UnifiedSolPoolError` variant, by name
+    UnifiedSol(&'static str),
+}
+
+impl PoolErrorKind {
+    /// The pool that raised the error.
+    pub const fn pool_type(self) -> PoolType {
+        match self {
+            Self::Token(_) => PoolType::Token,
+            Self::UnifiedSol(_) => PoolType::UnifiedSol,
+        }
+    }
+
+    /// The error variant's name.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Token(name) | Self::UnifiedSol(name) => name,
+        }
+    }
+}
+
+/// `TokenPoolError` variant names, indexed by error code.
+pub const TOKEN_POOL_ERROR_NAMES: [&str; 50] = [
+    "PoolPaused",
+    "InsufficientBalance",
+    "DepositLimitExceeded",
+    "InvalidInstructionData",
+    "InvalidPoolConfig",
+    "InvalidVault",
+    "InvalidTokenProgram",
+    "InvalidHubCaller",
+    "ArithmeticOverflow",
+    "InvalidAccountOwner",
+    "RelayerFeeExceedsAmount",
+    "InvalidHubAuthority",
+    "Unauthorized",
+    "RewardsNotReady",
+    "InvalidAmount",
+    "InvalidMint",
+    "InvalidSystemProgram",
+    "AlreadyInitialized",
+    "ExpectedOutputMismatch",
+    "InvalidFeeRate",
+    "InvalidVaultPda",
+    "InvalidPoolConfigPda",
+    "InsufficientProtocolFees",
+    "InvalidTreasuryAccount",
+    "PoolNotEmpty",
+    "HubPoolStillRegistered",
+    "UnsupportedMintExtension",
+    "MissingMintAccount",
+    "TransferFeeExceedsPoolFee",
+    "DepositBelowMinimum",
+    "SingleDepositCapExceeded",
+    "PoolCapExceeded",
+    "InvalidFinalizeInterval",
+    "InsufficientExcess",
+    "FeeExemptListFull",
+    "FeeExemptionMismatch",
+    "FeeExemptNotListed",
+    "VaultDeficitDetected",
+    "EmergencyWithdrawPending",
+    "NoEmergencyWithdrawPending",
+    "EmergencyWithdrawLocked",
+    "InvalidEmergencyWithdrawDelay",
+    "InvalidEmergencyWithdrawDestination",
+    "VaultMigrationPending",
+    "NoVaultMigrationPending",
+    "VaultMigrationLocked",
+    "VaultMigrationShortfall",
+    "WithdrawalBelowMinimum",
+    "InvalidFinalizeBatch",
+    "AssetIdComputationFailed",
+];
+
+/// `UnifiedSolPoolError` variant names, indexed by error code.
+pub const UNIFIED_SOL_POOL_ERROR_NAMES: [&str; 71] = [
+    "PoolPaused",
+    "InsufficientBalance",
+    "DepositLimitExceeded",
+    "InvalidInstructionData",
+    "InvalidUnifiedSolPoolConfig",
+    "InvalidLstConfig",
+    "InvalidVault",
+    "InvalidTokenProgram",
+    "InvalidHubCaller",
+    "ArithmeticOverflow",
+    "InvalidAccountOwner",
+    "RelayerFeeExceedsAmount",
+    "StaleExchangeRate",
+    "LstNotHarvested",
+    "InvalidExchangeRate",
+    "InsufficientLiquidity",
+    "LstNotActive",
+    "InvalidHubAuthority",
+    "Unauthorized",
+    "RewardsNotReady",
+    "InvalidSystemProgram",
+    "AlreadyInitialized",
+    "LstAlreadyRegistered",
+    "InvalidStakePool",
+    "InvalidPoolType",
+    "InvalidFeeRate",
+    "MissingLstConfigs",
+    "DuplicateLstConfig",
+    "ExpectedOutputMismatch",
+    "MaxLstConfigsReached",
+    "InvalidLstVaultPda",
+    "InvalidStakePoolProgram",
+    "InvalidHubAuthorityPda",
+    "InvalidUnifiedConfigPda",
+    "StakePoolMintMismatch",
+    "StaleStakePoolRate",
+    "InsufficientBuffer",
+    "VaultBalanceMismatch",
+    "ExchangeRateMismatch",
+    "StakePoolProgramAllowlistFull",
+    "StakePoolProgramNotListed",
+    "LstRetired",
+    "LstNotRetired",
+    "LstConfigNotEmpty",
+    "ExchangeRateOutOfBounds",
+    "ExchangeRateStale",
+    "InvalidRateBounds",
+    "UnwrapNotSupported",
+    "InvalidUnwrapAccounts",
+    "LstVirtualSolCapExceeded",
+    "PoolVirtualSolCapExceeded",
+    "InvalidWithdrawPriority",
+    "InvalidRouteAccounts",
+    "InsufficientRouteLiquidity",
+    "InsufficientCommission",
+    "InvalidCommissionDestination",
+    "LstConfigUpToDate",
+    "InvalidEpochMode",
+    "LstDepositsDisabled",
+    "LstWithdrawalsDisabled",
+    "InvalidLstMode",
+    "ManualRateNotProposed",
+    "ManualRateTimelocked",
+    "RateSourceHealthy",
+    "InvalidManualRateDelay",
+    "LstConfigNeedsMigration",
+    "KeeperNotAllowed",
+    "InvalidKeeper",
+    "KeeperAllowlistFull",
+    "KeeperNotListed",
+    "KeeperAllowlistEmpty",
+];
+
+/// Decode a pool program's `Custom` error code.
+///
+/// Returns `None` for programs other than the pools, and for codes the pool
+/// does not define.
+pub fn decode_pool_error(program_id: &Pubkey, code: u32) -> Option<PoolErrorKind> {
+    let code = code as usize;
+    if *program_id == TOKEN_POOL_PROGRAM_ID {
+        TOKEN_POOL_ERROR_NAMES
+            .get(code)
+            .copied()
+            .map(PoolErrorKind::Token)
+    } else if *program_id == UNIFIED_SOL_POOL_PROGRAM_ID {
+        UNIFIED_SOL_POOL_ERROR_NAMES
+            .get(code)
+            .copied()
+            .map(PoolErrorKind::UnifiedSol)
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,4 +276,43 @@ mod tests {
         let code = error.to_u32();
         assert_eq!(PoolError::from_u32(code), Some(error));
     }
+
+    #[test]
+    fn test_decode_pool_error() {
+        // The same code names a different error in each pool
+        assert_eq!(
+            decode_pool_error(&TOKEN_POOL_PROGRAM_ID, 7),
+            Some(PoolErrorKind::Token("InvalidHubCaller"))
+        );
+        assert_eq!(
+            decode_pool_error(&UNIFIED_SOL_POOL_PROGRAM_ID, 7),
+            Some(PoolErrorKind::UnifiedSol("InvalidTokenProgram"))
+        );
+
+        let kind = decode_pool_error(&UNIFIED_SOL_POOL_PROGRAM_ID, 70).unwrap();
+        assert_eq!(kind.pool_type(), PoolType::UnifiedSol);
+        assert_eq!(kind.name(), "KeeperAllowlistEmpty");
+
+        // Undefined codes and other programs don't decode
+        let token_count = TOKEN_POOL_ERROR_NAMES.len() as u32;
+        let unified_count = UNIFIED_SOL_POOL_ERROR_NAMES.len() as u32;
+        assert_eq!(decode_pool_error(&TOKEN_POOL_PROGRAM_ID, token_count), None);
+        assert_eq!(
+            decode_pool_error(&UNIFIED_SOL_POOL_PROGRAM_ID, unified_count),
+            None
+        );
+        assert_eq!(decode_pool_error(&[0u8; 32], 0), None);
+    }
+
+    #[test]
+    fn test_pool_error_names_unique() {
+        for names in [
+            &TOKEN_POOL_ERROR_NAMES[..],
+            &UNIFIED_SOL_POOL_ERROR_NAMES[..],
+        ] {
+            for (i, name) in names.iter().enumerate() {
+                assert!(!names[..i].contains(name), "duplicate error name {name}");
+            }
+        }
+    }
 }
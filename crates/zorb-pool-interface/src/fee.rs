@@ -0,0 +1,188 @@
+//! Protocol fee math shared by the hub and the pools.
+//!
+//! The hub prices a transaction's fee before invoking a pool, and the pool
+//! recomputes it and reports what it charged; the hub rejects any difference.
+//! Both sides compute fees only through [`compute_fee`] and [`net_of_fee`], so
+//! their results agree exactly for every principal, rate and rounding.
+//!
+//! ```text
+//! fee = principal × rate_bps / BASIS_POINTS   (rounded per `Rounding`)
+//! net = principal - fee
+//! ```
+//!
+//! # Invariants
+//!
+//! For `rate_bps <= BASIS_POINTS`:
+//! - `fee <= principal`, so [`net_of_fee`] never fails
+//! - `fee` is non-decreasing in `principal` and in `rate_bps`
+//! - `Rounding::Up` charges at most one base unit more than `Rounding::Down`,
+//!   and only when the division leaves a remainder
+//!
+//! Rates above `BASIS_POINTS` are never accepted by pool configs; for them the
+//! fee can exceed the principal and the functions return `None` rather than
+//! wrap.
+
+use crate::BASIS_POINTS;
+
+/// Which way a fee's division by `BASIS_POINTS` rounds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Rounding {
+    /// Truncate: fractional base units are not charged.
+    #[default]
+    Down,
+    /// Any remainder costs one more base unit.
+    Up,
+}
+
+impl Rounding {
+    /// Rounding selected by a config's `round_fees_up` flag.
+    #[inline]
+    pub const fn from_round_up(round_up: bool) -> Self {
+        if round_up { Self::Up } else { Self::Down }
+    }
+}
+
+/// Fee of `rate_bps` basis points on `principal`.
+///
+/// The product is taken in u128, so it cannot overflow; `None` means the fee
+/// does not fit in a u64, which requires `rate_bps > BASIS_POINTS`.
+///
+/// # Example
+/// ```
+/// use zorb_pool_interface::fee::{Rounding, compute_fee};
+///
+/// // 0.01% of 10_001 is 1.0001 base units
+/// assert_eq!(compute_fee(10_001, 1, Rounding::Down), Some(1));
+/// assert_eq!(compute_fee(10_001, 1, Rounding::Up), Some(2));
+/// ```
+#[inline]
+pub const fn compute_fee(principal: u64, rate_bps: u16, rounding: Rounding) -> Option<u64> {
+    let scaled = principal as u128 * rate_bps as u128;
+    let fee = match rounding {
+        Rounding::Down => scaled / BASIS_POINTS as u128,
+        Rounding::Up => scaled.div_ceil(BASIS_POINTS as u128),
+    };
+    if fee > u64::MAX as u128 {
+        return None;
+    }
+    Some(fee as u64)
+}
+
+/// `principal` less its fee of `rate_bps` basis points.
+///
+/// Returns `None` if the fee exceeds the principal (`rate_bps > BASIS_POINTS`).
+///
+/// # Example
+/// ```
+/// use zorb_pool_interface::fee::{Rounding, net_of_fee};
+///
+/// assert_eq!(net_of_fee(1_000, 100, Rounding::Down), Some(990));
+/// assert_eq!(net_of_fee(1_000, 10_010, Rounding::Down), None);
+/// ```
+#[inline]
+pub const fn net_of_fee(principal: u64, rate_bps: u16, rounding: Rounding) -> Option<u64> {
+    match compute_fee(principal, rate_bps, rounding) {
+        Some(fee) => principal.checked_sub(fee),
+        None => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRINCIPALS: [u64; 12] = [
+        0,
+        1,
+        9_999,
+        10_000,
+        10_001,
+        123_456_789,
+        1_000_000_000,
+        u32::MAX as u64,
+        u64::MAX / 10_000,
+        u64::MAX / 2,
+        u64::MAX - 1,
+        u64::MAX,
+    ];
+    const RATES: [u16; 8] = [0, 1, 30, 100, 2_500, 5_000, 9_999, FULL_RATE];
+    const FULL_RATE: u16 = BASIS_POINTS as u16;
+    const ROUNDINGS: [Rounding; 2] = [Rounding::Down, Rounding::Up];
+
+    #[test]
+    fn test_rounding_boundaries() {
+        // 1 bps: exact at multiples of 10_000, one unit apart otherwise
+        assert_eq!(compute_fee(9_999, 1, Rounding::Down), Some(0));
+        assert_eq!(compute_fee(9_999, 1, Rounding::Up), Some(1));
+        assert_eq!(compute_fee(10_000, 1, Rounding::Down), Some(1));
+        assert_eq!(compute_fee(10_000, 1, Rounding::Up), Some(1));
+        assert_eq!(compute_fee(10_001, 1, Rounding::Down), Some(1));
+        assert_eq!(compute_fee(10_001, 1, Rounding::Up), Some(2));
+
+        // Zero principal or rate never rounds up to a fee
+        assert_eq!(compute_fee(0, 100, Rounding::Up), Some(0));
+        assert_eq!(compute_fee(1, 0, Rounding::Up), Some(0));
+
+        // 100% takes exactly the principal
+        assert_eq!(
+            compute_fee(u64::MAX, FULL_RATE, Rounding::Up),
+            Some(u64::MAX)
+        );
+        assert_eq!(net_of_fee(u64::MAX, FULL_RATE, Rounding::Up), Some(0));
+    }
+
+    #[test]
+    fn test_rate_above_basis_points() {
+        assert_eq!(compute_fee(10_000, 10_001, Rounding::Down), Some(10_001));
+        assert_eq!(net_of_fee(10_000, 10_001, Rounding::Down), None);
+        assert_eq!(compute_fee(u64::MAX, u16::MAX, Rounding::Down), None);
+        assert_eq!(net_of_fee(u64::MAX, u16::MAX, Rounding::Up), None);
+    }
+
+    #[test]
+    fn test_fee_bounded_by_principal() {
+        for principal in PRINCIPALS {
+            for rate in RATES {
+                for rounding in ROUNDINGS {
+                    let fee = compute_fee(principal, rate, rounding).unwrap();
+                    assert!(fee <= principal, "{principal} @ {rate} {rounding:?}");
+                    assert_eq!(net_of_fee(principal, rate, rounding), Some(principal - fee));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fee_monotonic() {
+        for rounding in ROUNDINGS {
+            for rate in RATES {
+                let mut prev = 0;
+                for principal in PRINCIPALS {
+                    let fee = compute_fee(principal, rate, rounding).unwrap();
+                    assert!(fee >= prev, "{principal} @ {rate} {rounding:?}");
+                    prev = fee;
+                }
+            }
+            for principal in PRINCIPALS {
+                let mut prev = 0;
+                for rate in RATES {
+                    let fee = compute_fee(principal, rate, rounding).unwrap();
+                    assert!(fee >= prev, "{principal} @ {rate} {rounding:?}");
+                    prev = fee;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_up_at_most_one_unit_more() {
+        for principal in PRINCIPALS {
+            for rate in RATES {
+                let down = compute_fee(principal, rate, Rounding::Down).unwrap();
+                let up = compute_fee(principal, rate, Rounding::Up).unwrap();
+                let exact = (principal as u128 * rate as u128).is_multiple_of(BASIS_POINTS as u128);
+                assert_eq!(up - down, !exact as u64, "{principal} @ {rate}");
+            }
+        }
+    }
+}
@@ -29,27 +29,44 @@
 //! fee = principal × rate / BASIS_POINTS
 //! ```
 //!
+//! Pools recompute the fee they charge with the same [`fee`] functions, so the
+//! hub's expected fee and the pool's charged fee agree exactly.
+//!
 //! Pools expose their fee rates via their config accounts. The hub reads these
 //! rates, computes expected fees, validates user-provided fees, and passes
 //! pre-computed amounts to pools via CPI.
 //!
 //! # Modules
 //!
+//! - [`fee`]: Fee math shared by the hub and the pools
 //! - [`types`]: Core types (PoolType, PoolInfo, DepositParams, WithdrawParams)
 //! - [`cpi`]: CPI instruction builders for invoking pool programs
-//! - [`error`]: Pool error types
+//! - [`token_pool_ix`], [`unified_sol_pool_ix`]: Pool admin and maintenance
+//!   instruction builders (`cpi` or `client` feature)
+//! - [`pool_cpi`]: Pool-type agnostic CPI dispatch (`cpi` feature)
+//! - [`error`]: Pool error types and pool error decoding
 //! - [`program_ids`]: Pool program ID constants
 
 #![no_std]
 
+// IdlType derives allocate IDL definitions; pool instruction builders own
+// their account lists and data
+#[cfg(any(feature = "idl-build", feature = "cpi", feature = "client"))]
+extern crate alloc;
+
 pub mod asset_ids;
 pub mod authority;
 mod cpi;
 mod error;
+pub mod fee;
+#[cfg(feature = "cpi")]
+mod pool_cpi;
 mod program_ids;
 mod types;
 
 pub use cpi::*;
 pub use error::*;
+#[cfg(feature = "cpi")]
+pub use pool_cpi::*;
 pub use program_ids::*;
 pub use types::*;
@@ -0,0 +1,867 @@
+//! Pool-type agnostic CPI dispatch.
+//!
+//! [`PoolCpi`] lets the hub invoke any pool's deposit and withdraw without
+//! matching on the pool type: each pool lists its CPI accounts in its own
+//! order, and [`PoolType`] dispatches statically to the pool's implementation.
+//!
+//! ```text
+//! Hub: DepositCpiAccounts / WithdrawCpiAccounts (pool-agnostic roles)
+//!   → PoolCpi::deposit_accounts / withdraw_accounts (pool's order and access)
+//!   → invoke pool program with build_*_instruction_data
+//! ```
+//!
+//! Account lists are generic over [`CpiKey`], so the exact metas a pool CPI
+//! sends can be checked off-chain with bare pubkeys. The accounts each pool's
+//! `*Accounts` struct declares are built from per-pool access arrays (e.g.
+//! [`TOKEN_POOL_DEPOSIT_ACCESS`]), which the hub checks against the structs'
+//! derived `ACCOUNT_FLAGS` at compile time.
+
+use crate::{
+    DepositParams, MAX_WITHDRAW_ROUTE_VAULTS, PoolType, TOKEN_POOL_PROGRAM_ID,
+    UNIFIED_SOL_POOL_PROGRAM_ID, WithdrawParams, build_deposit_instruction_data,
+    build_withdraw_instruction_data, pool_layout, route_accounts, unwrap_accounts,
+};
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::slice_invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Most accounts in any pool CPI: a unified SOL withdrawal (6 accounts)
+/// routed across the most vaults.
+pub const MAX_POOL_CPI_ACCOUNTS: usize = 6 + MAX_WITHDRAW_ROUTE_VAULTS * route_accounts::COUNT;
+
+const _: () = assert!(6 + unwrap_accounts::COUNT <= MAX_POOL_CPI_ACCOUNTS);
+
+// ============================================================================
+// Accounts
+// ============================================================================
+
+/// An account that can appear in a pool CPI.
+pub trait CpiKey {
+    /// The account's address.
+    fn cpi_key(&self) -> &Pubkey;
+}
+
+impl CpiKey for AccountInfo {
+    #[inline]
+    fn cpi_key(&self) -> &Pubkey {
+        self.key()
+    }
+}
+
+impl CpiKey for Pubkey {
+    #[inline]
+    fn cpi_key(&self) -> &Pubkey {
+        self
+    }
+}
+
+/// How a pool takes an account in a CPI.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CpiAccess {
+    /// `[]`
+    Readonly,
+    /// `[writable]`
+    Writable,
+    /// `[signer]`
+    ReadonlySigner,
+    /// `[writable, signer]`
+    WritableSigner,
+}
+
+impl CpiAccess {
+    /// The account meta for `key` with this access.
+    #[inline]
+    pub const fn meta(self, key: &Pubkey) -> AccountMeta<'_> {
+        match self {
+            CpiAccess::Readonly => AccountMeta::readonly(key),
+            CpiAccess::Writable => AccountMeta::writable(key),
+            CpiAccess::ReadonlySigner => AccountMeta::readonly_signer(key),
+            CpiAccess::WritableSigner => AccountMeta::writable_signer(key),
+        }
+    }
+
+    /// Whether the pool writes the account.
+    #[inline]
+    pub const fn is_writable(self) -> bool {
+        matches!(self, CpiAccess::Writable | CpiAccess::WritableSigner)
+    }
+
+    /// Whether the account must sign.
+    #[inline]
+    pub const fn is_signer(self) -> bool {
+        matches!(self, CpiAccess::ReadonlySigner | CpiAccess::WritableSigner)
+    }
+}
+
+/// Access of each `token_pool::DepositAccounts` field, in field order.
+pub const TOKEN_POOL_DEPOSIT_ACCESS: [CpiAccess; 6] = [
+    CpiAccess::Writable,
+    CpiAccess::Writable,
+    CpiAccess::Writable,
+    CpiAccess::ReadonlySigner,
+    CpiAccess::Readonly,
+    CpiAccess::Readonly,
+];
+
+/// Access of each `token_pool::WithdrawAccounts` field, in field order.
+pub const TOKEN_POOL_WITHDRAW_ACCESS: [CpiAccess; 5] = [
+    CpiAccess::Writable,
+    CpiAccess::Writable,
+    CpiAccess::Readonly,
+    CpiAccess::Readonly,
+    CpiAccess::Readonly,
+];
+
+/// Access of each `unified_sol_pool::DepositAccounts` field, in field order.
+pub const UNIFIED_SOL_POOL_DEPOSIT_ACCESS: [CpiAccess; 7] = [
+    CpiAccess::Writable,
+    CpiAccess::Writable,
+    CpiAccess::Writable,
+    CpiAccess::Writable,
+    CpiAccess::ReadonlySigner,
+    CpiAccess::Readonly,
+    CpiAccess::Readonly,
+];
+
+/// Access of each `unified_sol_pool::WithdrawAccounts` field, in field order.
+///
+/// The withdraw CPI follows them with the token program and any unwrap or
+/// route accounts, which the pool reads as remaining accounts.
+pub const UNIFIED_SOL_POOL_WITHDRAW_ACCESS: [CpiAccess; 5] = [
+    CpiAccess::Writable,
+    CpiAccess::Writable,
+    CpiAccess::Writable,
+    CpiAccess::Readonly,
+    CpiAccess::Readonly,
+];
+
+/// The metas of `keys` with the matching `access` (e.g.
+/// [`TOKEN_POOL_DEPOSIT_ACCESS`]); a key list of the wrong length does not
+/// compile.
+#[inline]
+pub fn account_metas<'a, const N: usize>(
+    keys: [&'a Pubkey; N],
+    access: &[CpiAccess; N],
+) -> [AccountMeta<'a>; N] {
+    core::array::from_fn(|i| access[i].meta(keys[i]))
+}
+
+/// A pool CPI's accounts in the pool's order (at most
+/// [`MAX_POOL_CPI_ACCOUNTS`]).
+pub struct CpiAccountList<'a, A = AccountInfo> {
+    accounts: [(&'a A, CpiAccess); MAX_POOL_CPI_ACCOUNTS],
+    len: usize,
+}
+
+impl<'a, A> CpiAccountList<'a, A> {
+    fn new(first: &'a A, access: CpiAccess) -> Self {
+        Self {
+            accounts: [(first, access); MAX_POOL_CPI_ACCOUNTS],
+            len: 1,
+        }
+    }
+
+    /// A list of `accounts` with the matching `access`.
+    fn from_fixed<const N: usize>(
+        accounts: [&'a A; N],
+        access: &[CpiAccess; N],
+    ) -> Result<Self, ProgramError> {
+        let mut list = Self::new(accounts[0], access[0]);
+        for (account, access) in accounts.into_iter().zip(access).skip(1) {
+            list.push(account, *access)?;
+        }
+        Ok(list)
+    }
+
+    fn push(&mut self, account: &'a A, access: CpiAccess) -> Result<(), ProgramError> {
+        let slot = self
+            .accounts
+            .get_mut(self.len)
+            .ok_or(ProgramError::InvalidArgument)?;
+        *slot = (account, access);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// The accounts and their access, in CPI order.
+    #[inline]
+    pub fn as_slice(&self) -> &[(&'a A, CpiAccess)] {
+        &self.accounts[..self.len]
+    }
+}
+
+/// Accounts for a pool deposit CPI, by role.
+pub struct DepositCpiAccounts<'a, A = AccountInfo> {
+    /// Pool-wide state: the UnifiedSolPoolConfig (unified SOL only)
+    pub pool_state: Option<&'a A>,
+    /// Asset config: the TokenPoolConfig, or the LstConfig for unified SOL
+    pub pool_config: &'a A,
+    /// Vault token account
+    pub vault: &'a A,
+    /// Depositor's token account (source)
+    pub depositor_token: &'a A,
+    /// Depositor authority (signer)
+    pub depositor: &'a A,
+    /// SPL Token program
+    pub token_program: &'a A,
+    /// Pool program (for self-CPI events)
+    pub pool_program: &'a A,
+}
+
+/// Accounts for a pool withdraw CPI, by role.
+pub struct WithdrawCpiAccounts<'a, A = AccountInfo> {
+    /// Pool-wide state: the UnifiedSolPoolConfig (unified SOL only)
+    pub pool_state: Option<&'a A>,
+    /// Asset config (PDA signer): the TokenPoolConfig, or the LstConfig for
+    /// unified SOL
+    pub pool_config: &'a A,
+    /// Vault token account
+    pub vault: &'a A,
+    /// Hub authority PDA (delegate for vault transfers)
+    pub hub_authority: &'a A,
+    /// SPL Token program
+    pub token_program: &'a A,
+    /// Pool program (for self-CPI events)
+    pub pool_program: &'a A,
+    /// Native SOL unwrap accounts in [`unwrap_accounts`] order (unified SOL
+    /// only); hub_authority must then sign
+    pub unwrap: Option<[&'a A; unwrap_accounts::COUNT]>,
+    /// Extra vaults of a routed or fallback withdrawal in
+    /// [`route_accounts`] order (unified SOL only)
+    pub route: &'a [[&'a A; route_accounts::COUNT]],
+}
+
+/// Fee rates a pool charges, in basis points.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeeRates {
+    /// Deposit fee rate
+    pub deposit: u16,
+    /// Withdrawal fee rate
+    pub withdrawal: u16,
+}
+
+impl FeeRates {
+    /// Read the two consecutive little-endian rates at `offset`.
+    fn read(config_data: &[u8], offset: usize) -> Option<Self> {
+        let bytes = config_data.get(offset..offset + 4)?;
+        Some(Self {
+            deposit: u16::from_le_bytes([bytes[0], bytes[1]]),
+            withdrawal: u16::from_le_bytes([bytes[2], bytes[3]]),
+        })
+    }
+}
+
+// ============================================================================
+// PoolCpi Trait
+// ============================================================================
+
+/// A pool program the hub can deposit to and withdraw from via CPI.
+///
+/// Implemented by [`TokenPoolCpi`] and [`UnifiedSolPoolCpi`], and by
+/// [`PoolType`], which dispatches to them.
+pub trait PoolCpi {
+    /// The pool program invoked.
+    fn program_id(&self) -> &'static Pubkey;
+
+    /// Accounts a hub public slot carries for this pool (before any unwrap or
+    /// route accounts): the pool accounts of [`pool_layout`], then
+    /// [`pool_layout::SLOT_SHARED_ACCOUNTS`].
+    fn expected_account_count(&self) -> usize;
+
+    /// Pool-wide fee rates read from the pool's config account data
+    /// (TokenPoolConfig or UnifiedSolPoolConfig), or `None` if it is too short.
+    fn fee_rates(&self, config_data: &[u8]) -> Option<FeeRates>;
+
+    /// The deposit CPI's accounts in the pool's order.
+    fn deposit_accounts<'a, A: CpiKey>(
+        &self,
+        accounts: &DepositCpiAccounts<'a, A>,
+    ) -> Result<CpiAccountList<'a, A>, ProgramError>;
+
+    /// The withdraw CPI's accounts in the pool's order.
+    fn withdraw_accounts<'a, A: CpiKey>(
+        &self,
+        accounts: &WithdrawCpiAccounts<'a, A>,
+    ) -> Result<CpiAccountList<'a, A>, ProgramError>;
+
+    /// Invoke the pool's deposit with `signers` (e.g. the depositor PDA).
+    ///
+    /// Read the pool's result from return data right after this returns.
+    fn deposit(
+        &self,
+        params: &DepositParams,
+        accounts: &DepositCpiAccounts<'_>,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        let accounts = self.deposit_accounts(accounts)?;
+        let data = build_deposit_instruction_data(params);
+        invoke_pool(self.program_id(), &accounts, &data, signers)
+    }
+
+    /// Invoke the pool's withdraw with `signers` (hub_authority for unwraps).
+    ///
+    /// Read the pool's result from return data right after this returns.
+    fn withdraw(
+        &self,
+        params: &WithdrawParams,
+        accounts: &WithdrawCpiAccounts<'_>,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        let accounts = self.withdraw_accounts(accounts)?;
+        let data = build_withdraw_instruction_data(params);
+        invoke_pool(self.program_id(), &accounts, &data, signers)
+    }
+}
+
+/// Invoke `program_id` with `accounts` in list order.
+fn invoke_pool(
+    program_id: &Pubkey,
+    accounts: &CpiAccountList<'_>,
+    data: &[u8],
+    signers: &[Signer],
+) -> ProgramResult {
+    let list = accounts.as_slice();
+    let metas: [AccountMeta; MAX_POOL_CPI_ACCOUNTS] = core::array::from_fn(|i| {
+        let (account, access) = list.get(i).copied().unwrap_or(list[0]);
+        access.meta(account.key())
+    });
+    let infos: [&AccountInfo; MAX_POOL_CPI_ACCOUNTS] =
+        core::array::from_fn(|i| list.get(i).map_or(list[0].0, |(account, _)| *account));
+
+    let instruction = Instruction {
+        program_id,
+        accounts: &metas[..list.len()],
+        data,
+    };
+    slice_invoke_signed(&instruction, &infos[..list.len()], signers)
+}
+
+// ============================================================================
+// Implementations
+// ============================================================================
+
+/// The token pool program.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokenPoolCpi;
+
+impl PoolCpi for TokenPoolCpi {
+    fn program_id(&self) -> &'static Pubkey {
+        &TOKEN_POOL_PROGRAM_ID
+    }
+
+    fn expected_account_count(&self) -> usize {
+        pool_layout::TOKEN_POOL_ACCOUNTS + pool_layout::SLOT_SHARED_ACCOUNTS
+    }
+
+    fn fee_rates(&self, config_data: &[u8]) -> Option<FeeRates> {
+        FeeRates::read(config_data, pool_layout::TOKEN_POOL_FEE_RATES_OFFSET)
+    }
+
+    /// 0. `[writable]` Pool config
+    /// 1. `[writable]` Vault
+    /// 2. `[writable]` Depositor token account
+    /// 3. `[signer]` Depositor
+    /// 4. `[]` Token program
+    /// 5. `[]` Pool program
+    fn deposit_accounts<'a, A: CpiKey>(
+        &self,
+        accounts: &DepositCpiAccounts<'a, A>,
+    ) -> Result<CpiAccountList<'a, A>, ProgramError> {
+        if accounts.pool_state.is_some() {
+            return Err(ProgramError::InvalidArgument);
+        }
+        CpiAccountList::from_fixed(
+            [
+                accounts.pool_config,
+                accounts.vault,
+                accounts.depositor_token,
+                accounts.depositor,
+                accounts.token_program,
+                accounts.pool_program,
+            ],
+            &TOKEN_POOL_DEPOSIT_ACCESS,
+        )
+    }
+
+    /// 0. `[writable]` Pool config (PDA signer)
+    /// 1. `[writable]` Vault
+    /// 2. `[]` Hub authority
+    /// 3. `[]` Pool program
+    /// 4. `[]` Token program
+    fn withdraw_accounts<'a, A: CpiKey>(
+        &self,
+        accounts: &WithdrawCpiAccounts<'a, A>,
+    ) -> Result<CpiAccountList<'a, A>, ProgramError> {
+        // Token pools neither unwrap nor route
+        if accounts.pool_state.is_some() || accounts.unwrap.is_some() || !accounts.route.is_empty()
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+        CpiAccountList::from_fixed(
+            [
+                accounts.pool_config,
+                accounts.vault,
+                accounts.hub_authority,
+                accounts.pool_program,
+                accounts.token_program,
+            ],
+            &TOKEN_POOL_WITHDRAW_ACCESS,
+        )
+    }
+}
+
+/// The unified SOL pool program.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UnifiedSolPoolCpi;
+
+impl PoolCpi for UnifiedSolPoolCpi {
+    fn program_id(&self) -> &'static Pubkey {
+        &UNIFIED_SOL_POOL_PROGRAM_ID
+    }
+
+    fn expected_account_count(&self) -> usize {
+        pool_layout::UNIFIED_SOL_POOL_ACCOUNTS + pool_layout::SLOT_SHARED_ACCOUNTS
+    }
+
+    /// Pool-wide rates; an LstConfig override still applies on top.
+    fn fee_rates(&self, config_data: &[u8]) -> Option<FeeRates> {
+        FeeRates::read(config_data, pool_layout::UNIFIED_SOL_POOL_FEE_RATES_OFFSET)
+    }
+
+    /// 0. `[writable]` Unified SOL config
+    /// 1. `[writable]` LST config
+    /// 2. `[writable]` Vault
+    /// 3. `[writable]` Depositor token account
+    /// 4. `[signer]` Depositor
+    /// 5. `[]` Pool program
+    /// 6. `[]` Token program
+    fn deposit_accounts<'a, A: CpiKey>(
+        &self,
+        accounts: &DepositCpiAccounts<'a, A>,
+    ) -> Result<CpiAccountList<'a, A>, ProgramError> {
+        let pool_state = accounts
+            .pool_state
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        CpiAccountList::from_fixed(
+            [
+                pool_state,
+                accounts.pool_config,
+                accounts.vault,
+                accounts.depositor_token,
+                accounts.depositor,
+                accounts.pool_program,
+                accounts.token_program,
+            ],
+            &UNIFIED_SOL_POOL_DEPOSIT_ACCESS,
+        )
+    }
+
+    /// 0. `[writable]` Unified SOL config
+    /// 1. `[writable]` LST config (PDA signer)
+    /// 2. `[writable]` Vault
+    /// 3. `[]` Hub authority (`[signer]` with unwrap)
+    /// 4. `[]` Pool program
+    /// 5. `[]` Token program
+    ///
+    /// Then the [`unwrap_accounts`], or every [`route_accounts`] pair
+    /// (all writable).
+    fn withdraw_accounts<'a, A: CpiKey>(
+        &self,
+        accounts: &WithdrawCpiAccounts<'a, A>,
+    ) -> Result<CpiAccountList<'a, A>, ProgramError> {
+        let pool_state = accounts
+            .pool_state
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        if accounts.unwrap.is_some() && !accounts.route.is_empty() {
+            return Err(ProgramError::InvalidArgument);
+        }
+        let mut access = UNIFIED_SOL_POOL_WITHDRAW_ACCESS;
+        if accounts.unwrap.is_some() {
+            // hub_authority signs for the unwrap
+            access[3] = CpiAccess::ReadonlySigner;
+        }
+
+        let mut list = CpiAccountList::from_fixed(
+            [
+                pool_state,
+                accounts.pool_config,
+                accounts.vault,
+                accounts.hub_authority,
+                accounts.pool_program,
+            ],
+            &access,
+        )?;
+        list.push(accounts.token_program, CpiAccess::Readonly)?;
+        if let Some(unwrap) = accounts.unwrap {
+            list.push(unwrap[unwrap_accounts::RECIPIENT], CpiAccess::Writable)?;
+            list.push(unwrap[unwrap_accounts::UNWRAP_ACCOUNT], CpiAccess::Writable)?;
+            list.push(unwrap[unwrap_accounts::NATIVE_MINT], CpiAccess::Readonly)?;
+            list.push(unwrap[unwrap_accounts::PAYER], CpiAccess::WritableSigner)?;
+            list.push(unwrap[unwrap_accounts::SYSTEM_PROGRAM], CpiAccess::Readonly)?;
+        }
+        for pair in accounts.route {
+            for account in pair {
+                list.push(account, CpiAccess::Writable)?;
+            }
+        }
+        Ok(list)
+    }
+}
+
+impl PoolCpi for PoolType {
+    fn program_id(&self) -> &'static Pubkey {
+        match self {
+            PoolType::Token => TokenPoolCpi.program_id(),
+            PoolType::UnifiedSol => UnifiedSolPoolCpi.program_id(),
+        }
+    }
+
+    fn expected_account_count(&self) -> usize {
+        match self {
+            PoolType::Token => TokenPoolCpi.expected_account_count(),
+            PoolType::UnifiedSol => UnifiedSolPoolCpi.expected_account_count(),
+        }
+    }
+
+    fn fee_rates(&self, config_data: &[u8]) -> Option<FeeRates> {
+        match self {
+            PoolType::Token => TokenPoolCpi.fee_rates(config_data),
+            PoolType::UnifiedSol => UnifiedSolPoolCpi.fee_rates(config_data),
+        }
+    }
+
+    fn deposit_accounts<'a, A: CpiKey>(
+        &self,
+        accounts: &DepositCpiAccounts<'a, A>,
+    ) -> Result<CpiAccountList<'a, A>, ProgramError> {
+        match self {
+            PoolType::Token => TokenPoolCpi.deposit_accounts(accounts),
+            PoolType::UnifiedSol => UnifiedSolPoolCpi.deposit_accounts(accounts),
+        }
+    }
+
+    fn withdraw_accounts<'a, A: CpiKey>(
+        &self,
+        accounts: &WithdrawCpiAccounts<'a, A>,
+    ) -> Result<CpiAccountList<'a, A>, ProgramError> {
+        match self {
+            PoolType::Token => TokenPoolCpi.withdraw_accounts(accounts),
+            PoolType::UnifiedSol => UnifiedSolPoolCpi.withdraw_accounts(accounts),
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Distinct stand-in keys, one per account role.
+    const KEYS: [Pubkey; 16] = {
+        let mut keys = [[0u8; 32]; 16];
+        let mut i = 0;
+        while i < 16 {
+            keys[i][0] = i as u8 + 1;
+            i += 1;
+        }
+        keys
+    };
+    const POOL_STATE: usize = 0;
+    const POOL_CONFIG: usize = 1;
+    const VAULT: usize = 2;
+    const DEPOSITOR_TOKEN: usize = 3;
+    const DEPOSITOR: usize = 4;
+    const HUB_AUTHORITY: usize = 5;
+    const TOKEN_PROGRAM: usize = 6;
+    const POOL_PROGRAM: usize = 7;
+    const UNWRAP: [usize; unwrap_accounts::COUNT] = [8, 9, 10, 11, 12];
+    const ROUTE: [usize; 4] = [12, 13, 14, 15];
+
+    type Meta = (Pubkey, bool, bool);
+
+    fn w(i: usize) -> Meta {
+        (KEYS[i], true, false)
+    }
+    fn r(i: usize) -> Meta {
+        (KEYS[i], false, false)
+    }
+    fn rs(i: usize) -> Meta {
+        (KEYS[i], false, true)
+    }
+    fn ws(i: usize) -> Meta {
+        (KEYS[i], true, true)
+    }
+
+    fn assert_metas(list: &CpiAccountList<'_, Pubkey>, expected: &[Meta]) {
+        assert_eq!(list.as_slice().len(), expected.len());
+        for ((key, access), expected) in list.as_slice().iter().zip(expected) {
+            let meta = access.meta(key.cpi_key());
+            assert_eq!((*meta.pubkey, meta.is_writable, meta.is_signer), *expected);
+        }
+    }
+
+    fn deposit_accounts(pool_state: Option<&Pubkey>) -> DepositCpiAccounts<'_, Pubkey> {
+        DepositCpiAccounts {
+            pool_state,
+            pool_config: &KEYS[POOL_CONFIG],
+            vault: &KEYS[VAULT],
+            depositor_token: &KEYS[DEPOSITOR_TOKEN],
+            depositor: &KEYS[DEPOSITOR],
+            token_program: &KEYS[TOKEN_PROGRAM],
+            pool_program: &KEYS[POOL_PROGRAM],
+        }
+    }
+
+    fn withdraw_accounts(pool_state: Option<&Pubkey>) -> WithdrawCpiAccounts<'_, Pubkey> {
+        WithdrawCpiAccounts {
+            pool_state,
+            pool_config: &KEYS[POOL_CONFIG],
+            vault: &KEYS[VAULT],
+            hub_authority: &KEYS[HUB_AUTHORITY],
+            token_program: &KEYS[TOKEN_PROGRAM],
+            pool_program: &KEYS[POOL_PROGRAM],
+            unwrap: None,
+            route: &[],
+        }
+    }
+
+    // Expected metas below are the hub's hand-built CPI account lists from
+    // before `PoolCpi`, so these pin byte-identical instructions.
+
+    #[test]
+    fn test_token_deposit_accounts_unchanged() {
+        let list = PoolType::Token
+            .deposit_accounts(&deposit_accounts(None))
+            .unwrap();
+        assert_metas(
+            &list,
+            &[
+                w(POOL_CONFIG),
+                w(VAULT),
+                w(DEPOSITOR_TOKEN),
+                rs(DEPOSITOR),
+                r(TOKEN_PROGRAM),
+                r(POOL_PROGRAM),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_token_withdraw_accounts_unchanged() {
+        let list = PoolType::Token
+            .withdraw_accounts(&withdraw_accounts(None))
+            .unwrap();
+        assert_metas(
+            &list,
+            &[
+                w(POOL_CONFIG),
+                w(VAULT),
+                r(HUB_AUTHORITY),
+                r(POOL_PROGRAM),
+                r(TOKEN_PROGRAM),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_unified_sol_deposit_accounts_unchanged() {
+        let list = PoolType::UnifiedSol
+            .deposit_accounts(&deposit_accounts(Some(&KEYS[POOL_STATE])))
+            .unwrap();
+        assert_metas(
+            &list,
+            &[
+                w(POOL_STATE),
+                w(POOL_CONFIG),
+                w(VAULT),
+                w(DEPOSITOR_TOKEN),
+                rs(DEPOSITOR),
+                r(POOL_PROGRAM),
+                r(TOKEN_PROGRAM),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_unified_sol_withdraw_accounts_unchanged() {
+        let base = [
+            w(POOL_STATE),
+            w(POOL_CONFIG),
+            w(VAULT),
+            r(HUB_AUTHORITY),
+            r(POOL_PROGRAM),
+            r(TOKEN_PROGRAM),
+        ];
+        let accounts = withdraw_accounts(Some(&KEYS[POOL_STATE]));
+        let list = PoolType::UnifiedSol.withdraw_accounts(&accounts).unwrap();
+        assert_metas(&list, &base);
+
+        // Unwrap: hub_authority signs, then the unwrap accounts
+        let unwrap = UNWRAP.map(|i| &KEYS[i]);
+        let list = PoolType::UnifiedSol
+            .withdraw_accounts(&WithdrawCpiAccounts {
+                unwrap: Some(unwrap),
+                ..withdraw_accounts(Some(&KEYS[POOL_STATE]))
+            })
+            .unwrap();
+        assert_metas(
+            &list,
+            &[
+                w(POOL_STATE),
+                w(POOL_CONFIG),
+                w(VAULT),
+                rs(HUB_AUTHORITY),
+                r(POOL_PROGRAM),
+                r(TOKEN_PROGRAM),
+                w(UNWRAP[0]),
+                w(UNWRAP[1]),
+                r(UNWRAP[2]),
+                ws(UNWRAP[3]),
+                r(UNWRAP[4]),
+            ],
+        );
+
+        // Routed: every route account writable, in order
+        let route = [
+            [&KEYS[ROUTE[0]], &KEYS[ROUTE[1]]],
+            [&KEYS[ROUTE[2]], &KEYS[ROUTE[3]]],
+        ];
+        let list = PoolType::UnifiedSol
+            .withdraw_accounts(&WithdrawCpiAccounts {
+                route: &route,
+                ..withdraw_accounts(Some(&KEYS[POOL_STATE]))
+            })
+            .unwrap();
+        assert_metas(
+            &list,
+            &[
+                w(POOL_STATE),
+                w(POOL_CONFIG),
+                w(VAULT),
+                r(HUB_AUTHORITY),
+                r(POOL_PROGRAM),
+                r(TOKEN_PROGRAM),
+                w(ROUTE[0]),
+                w(ROUTE[1]),
+                w(ROUTE[2]),
+                w(ROUTE[3]),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_pool_cpi_rejects_mismatched_accounts() {
+        let pool_state = &KEYS[POOL_STATE];
+        let unwrap = UNWRAP.map(|i| &KEYS[i]);
+        let route = [[&KEYS[ROUTE[0]], &KEYS[ROUTE[1]]]];
+
+        // Unified SOL needs its pool state; token pools take none
+        assert!(
+            PoolType::UnifiedSol
+                .deposit_accounts(&deposit_accounts(None))
+                .is_err()
+        );
+        assert!(
+            PoolType::UnifiedSol
+                .withdraw_accounts(&withdraw_accounts(None))
+                .is_err()
+        );
+        assert!(
+            PoolType::Token
+                .deposit_accounts(&deposit_accounts(Some(pool_state)))
+                .is_err()
+        );
+
+        // Token pools neither unwrap nor route
+        for accounts in [
+            WithdrawCpiAccounts {
+                unwrap: Some(unwrap),
+                ..withdraw_accounts(None)
+            },
+            WithdrawCpiAccounts {
+                route: &route,
+                ..withdraw_accounts(None)
+            },
+        ] {
+            assert!(PoolType::Token.withdraw_accounts(&accounts).is_err());
+        }
+
+        // An unwrap is never routed
+        let accounts = WithdrawCpiAccounts {
+            unwrap: Some(unwrap),
+            route: &route,
+            ..withdraw_accounts(Some(pool_state))
+        };
+        assert!(PoolType::UnifiedSol.withdraw_accounts(&accounts).is_err());
+
+        // More route vaults than the pool accepts
+        let route = [[&KEYS[ROUTE[0]], &KEYS[ROUTE[1]]]; MAX_WITHDRAW_ROUTE_VAULTS + 1];
+        let accounts = WithdrawCpiAccounts {
+            route: &route,
+            ..withdraw_accounts(Some(pool_state))
+        };
+        assert!(PoolType::UnifiedSol.withdraw_accounts(&accounts).is_err());
+    }
+
+    #[test]
+    fn test_account_metas() {
+        let keys: [&Pubkey; 5] = core::array::from_fn(|i| &KEYS[i]);
+        let metas = account_metas(keys, &TOKEN_POOL_WITHDRAW_ACCESS);
+        for ((meta, key), access) in metas.iter().zip(keys).zip(TOKEN_POOL_WITHDRAW_ACCESS) {
+            assert_eq!(
+                (meta.pubkey, meta.is_writable, meta.is_signer),
+                (key, access.is_writable(), access.is_signer())
+            );
+        }
+        assert!(CpiAccess::WritableSigner.is_writable() && CpiAccess::WritableSigner.is_signer());
+        assert!(!CpiAccess::Readonly.is_writable() && !CpiAccess::Readonly.is_signer());
+    }
+
+    #[test]
+    fn test_pool_cpi_dispatch() {
+        assert_eq!(PoolType::Token.program_id(), &TOKEN_POOL_PROGRAM_ID);
+        assert_eq!(
+            PoolType::UnifiedSol.program_id(),
+            &UNIFIED_SOL_POOL_PROGRAM_ID
+        );
+        // Hub slot layouts: 9 token accounts, 10 unified SOL accounts
+        assert_eq!(PoolType::Token.expected_account_count(), 9);
+        assert_eq!(PoolType::UnifiedSol.expected_account_count(), 10);
+    }
+
+    #[test]
+    fn test_fee_rates() {
+        let mut data = [0u8; 512];
+        let offset = pool_layout::TOKEN_POOL_FEE_RATES_OFFSET;
+        data[offset..offset + 2].copy_from_slice(&30u16.to_le_bytes());
+        data[offset + 2..offset + 4].copy_from_slice(&45u16.to_le_bytes());
+        assert_eq!(
+            PoolType::Token.fee_rates(&data),
+            Some(FeeRates {
+                deposit: 30,
+                withdrawal: 45
+            })
+        );
+        assert_eq!(PoolType::Token.fee_rates(&data[..offset + 3]), None);
+
+        let offset = pool_layout::UNIFIED_SOL_POOL_FEE_RATES_OFFSET;
+        data[offset..offset + 2].copy_from_slice(&10u16.to_le_bytes());
+        data[offset + 2..offset + 4].copy_from_slice(&20u16.to_le_bytes());
+        assert_eq!(
+            PoolType::UnifiedSol.fee_rates(&data),
+            Some(FeeRates {
+                deposit: 10,
+                withdrawal: 20
+            })
+        );
+        assert_eq!(PoolType::UnifiedSol.fee_rates(&[]), None);
+    }
+}
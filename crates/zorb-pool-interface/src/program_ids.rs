@@ -1,7 +1,8 @@
 //! Pool program ID constants.
 //!
-//! These program IDs are imported from the central `zorb-program-ids` crate,
-//! which is the single source of truth for all program addresses.
+//! These program IDs are re-exported from the central `zorb-program-ids` crate,
+//! which is the single source of truth for all program addresses and decodes
+//! their byte forms at compile time.
 //!
 //! The correct addresses are selected at compile-time based on the network feature.
 
@@ -16,20 +17,17 @@ use crate::PoolType;
 /// Token Pool program ID.
 ///
 /// Handles SPL token deposits and withdrawals with 1:1 exchange rate.
-pub const TOKEN_POOL_PROGRAM_ID: Pubkey =
-    five8_const::decode_32_const(zorb_program_ids::TOKEN_POOL_ID);
+pub const TOKEN_POOL_PROGRAM_ID: Pubkey = zorb_program_ids::TOKEN_POOL_PUBKEY;
 
 /// Unified SOL Pool program ID.
 ///
 /// Handles LST deposits and withdrawals with exchange rate conversion.
-pub const UNIFIED_SOL_POOL_PROGRAM_ID: Pubkey =
-    five8_const::decode_32_const(zorb_program_ids::UNIFIED_SOL_POOL_ID);
+pub const UNIFIED_SOL_POOL_PROGRAM_ID: Pubkey = zorb_program_ids::UNIFIED_SOL_POOL_PUBKEY;
 
 /// Hub program ID (shielded-pool).
 ///
 /// Used by pools to validate CPI callers.
-pub const HUB_PROGRAM_ID: Pubkey =
-    five8_const::decode_32_const(zorb_program_ids::SHIELDED_POOL_ID);
+pub const HUB_PROGRAM_ID: Pubkey = zorb_program_ids::SHIELDED_POOL_PUBKEY;
 
 // =============================================================================
 // Helper Functions
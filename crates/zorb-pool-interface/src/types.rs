@@ -1,6 +1,12 @@
 //! Core types for pool interface.
 
+use core::mem::{offset_of, size_of};
+use core::ops::RangeInclusive;
+
 use bytemuck::{Pod, Zeroable};
+use pinocchio::pubkey::Pubkey;
+
+use crate::fee::{Rounding, compute_fee};
 
 /// Basis points precision (10000 = 100%)
 pub const BASIS_POINTS: u64 = 10_000;
@@ -83,6 +89,31 @@ pub fn virtual_sol_to_tokens(virtual_sol: u64, exchange_rate: u64) -> Option<u64
 // Unified Fee Calculation Functions
 // ============================================================================
 
+/// Per-asset fee override value meaning "unset": the pool-wide rate applies.
+pub const FEE_RATE_UNSET: u16 = u16::MAX;
+
+/// Resolve the fee rate for one asset of a pool.
+///
+/// Returns `override_rate` unless it is [`FEE_RATE_UNSET`], else `pool_rate`.
+/// Pools with per-asset overrides (LSTs in the unified SOL pool) and the hub
+/// both resolve rates here, so their fee computations cannot drift apart.
+///
+/// # Example
+/// ```
+/// use zorb_pool_interface::{FEE_RATE_UNSET, effective_fee_rate};
+///
+/// assert_eq!(effective_fee_rate(30, FEE_RATE_UNSET), 30);
+/// assert_eq!(effective_fee_rate(30, 0), 0);
+/// ```
+#[inline]
+pub const fn effective_fee_rate(pool_rate: u16, override_rate: u16) -> u16 {
+    if override_rate == FEE_RATE_UNSET {
+        pool_rate
+    } else {
+        override_rate
+    }
+}
+
 /// Calculate deposit output for both pool types.
 ///
 /// This function handles the fee calculation for deposits:
@@ -124,9 +155,7 @@ pub fn calculate_deposit_output(
     };
 
     // Calculate fee: working_units × rate / BASIS_POINTS
-    let fee = (working_units as u128)
-        .checked_mul(fee_rate_bps as u128)?
-        .checked_div(BASIS_POINTS as u128)? as u64;
+    let fee = compute_fee(working_units, fee_rate_bps, Rounding::Down)?;
 
     // Principal = working_units - fee
     let principal = working_units.checked_sub(fee)?;
@@ -168,9 +197,7 @@ pub fn calculate_withdrawal_output(
     exchange_rate: Option<u64>,
 ) -> Option<(u64, u64)> {
     // Calculate fee: amount × rate / BASIS_POINTS (fee is in pool units)
-    let fee = (amount as u128)
-        .checked_mul(fee_rate_bps as u128)?
-        .checked_div(BASIS_POINTS as u128)? as u64;
+    let fee = compute_fee(amount, fee_rate_bps, Rounding::Down)?;
 
     // Net amount after fee
     let net_amount = amount.checked_sub(fee)?;
@@ -217,6 +244,52 @@ impl PoolType {
     }
 }
 
+/// Registry key of a pool plugin, stored in the hub's `PoolConfig.pool_kind`.
+///
+/// Unlike [`PoolType`], kinds are open-ended: the hub looks a kind up in its
+/// pool registry and rejects any it doesn't know, so adding a plugin means
+/// adding a registry entry rather than a variant. Values are split into
+/// reserved ranges:
+///
+/// | Range | Use |
+/// |-------|-----|
+/// | `0` | [`UNSET`](Self::UNSET): config predates `pool_kind` |
+/// | [`CORE`](Self::CORE) | Pool programs maintained with the hub |
+/// | [`EXTERNAL`](Self::EXTERNAL) | Third-party pool programs |
+/// | [`EXPERIMENTAL`](Self::EXPERIMENTAL) | Test and devnet pools, never registered on mainnet |
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Pod, Zeroable)]
+pub struct PoolKind(pub u16);
+
+impl PoolKind {
+    /// No kind recorded; resolve from the config's [`PoolType`] instead
+    pub const UNSET: Self = Self(0);
+    /// Token pool ([`PoolType::Token`])
+    pub const TOKEN: Self = Self(1);
+    /// Unified SOL pool ([`PoolType::UnifiedSol`])
+    pub const UNIFIED_SOL: Self = Self(2);
+
+    /// Kinds of pool programs maintained with the hub
+    pub const CORE: RangeInclusive<u16> = 0x0001..=0x00ff;
+    /// Kinds assigned to third-party pool programs
+    pub const EXTERNAL: RangeInclusive<u16> = 0x0100..=0x7fff;
+    /// Kinds for test and devnet pools
+    pub const EXPERIMENTAL: RangeInclusive<u16> = 0x8000..=0xffff;
+
+    /// The kind of a [`PoolType`] pool.
+    pub const fn from_pool_type(pool_type: PoolType) -> Self {
+        match pool_type {
+            PoolType::Token => Self::TOKEN,
+            PoolType::UnifiedSol => Self::UNIFIED_SOL,
+        }
+    }
+
+    /// Whether this kind is in the [`CORE`](Self::CORE) range
+    pub fn is_core(&self) -> bool {
+        Self::CORE.contains(&self.0)
+    }
+}
+
 // ============================================================================
 // CPI Instruction Discriminators
 // ============================================================================
@@ -258,7 +331,7 @@ impl PoolInstruction {
 /// # Token Flow (Deposit)
 /// ```text
 /// Hub validates proof, calculates amounts
-/// Hub CPIs to pool: { amount, expected_output }
+/// Hub CPIs to pool: { amount, expected_output, depositor, expect_fee_exempt }
 /// Pool: depositor ──(amount)──► vault
 /// Pool: validates expected_output = amount - fee (with exchange rate if applicable)
 /// Pool: updates state, returns { fee }
@@ -268,8 +341,16 @@ impl PoolInstruction {
 /// # Responsibilities
 /// - Pool: Transfer amount from depositor to vault
 /// - Pool: Validate expected_output matches (amount - protocol_fee) with exchange rate
+/// - Pool: Validate expect_fee_exempt against its fee-exempt list
 /// - Pool: Update accounting, return protocol_fee
 /// - Hub: Handle relayer_fee transfer (not included in pool CPI)
+///
+/// # Fee Exemption
+///
+/// The hub sets `expect_fee_exempt` when `depositor` is on the pool's
+/// fee-exempt list and computes `expected_output` with no fee. The pool
+/// rejects the deposit if the flag disagrees with its list, so both sides
+/// always apply the same fee.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, Pod, Zeroable)]
 pub struct DepositParams {
@@ -279,17 +360,30 @@ pub struct DepositParams {
     /// For token pools: expected_output = amount - protocol_fee
     /// For unified SOL: expected_output = (amount - protocol_fee) * exchange_rate
     pub expected_output: u64,
+    /// Depositor authority (the signer the pool transfers from)
+    pub depositor: [u8; 32],
+    /// Whether the hub computed no fee because `depositor` is fee-exempt (1 = yes)
+    pub expect_fee_exempt: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 7],
 }
 
 impl DepositParams {
     /// Size in bytes
-    pub const SIZE: usize = 16;
+    pub const SIZE: usize = 56;
+
+    /// Whether the hub expects the pool to waive the fee
+    pub fn expects_fee_exempt(&self) -> bool {
+        self.expect_fee_exempt != 0
+    }
 
     /// Serialize to bytes for CPI instruction data
     pub fn to_bytes(&self) -> [u8; Self::SIZE] {
         let mut bytes = [0u8; Self::SIZE];
         bytes[0..8].copy_from_slice(&self.amount.to_le_bytes());
         bytes[8..16].copy_from_slice(&self.expected_output.to_le_bytes());
+        bytes[16..48].copy_from_slice(&self.depositor);
+        bytes[48] = self.expect_fee_exempt;
         bytes
     }
 
@@ -301,10 +395,94 @@ impl DepositParams {
         Some(Self {
             amount: u64::from_le_bytes(bytes[0..8].try_into().ok()?),
             expected_output: u64::from_le_bytes(bytes[8..16].try_into().ok()?),
+            depositor: bytes[16..48].try_into().ok()?,
+            expect_fee_exempt: bytes[48],
+            _padding: [0u8; 7],
         })
     }
+
+    /// Parse deposit params in any supported wire layout.
+    ///
+    /// Accepts exactly [`Self::SIZE`] bytes of the legacy unversioned layout
+    /// or a [`DepositParamsV2`]; anything else (truncated, trailing bytes, or
+    /// an unknown version) is rejected. Pools parse with this so callers
+    /// built against either layout keep working.
+    pub fn parse_any(bytes: &[u8]) -> Option<Self> {
+        match bytes.len() {
+            Self::SIZE => Self::from_bytes(bytes),
+            DepositParamsV2::SIZE => DepositParamsV2::from_bytes(bytes).map(|v2| v2.params),
+            _ => None,
+        }
+    }
 }
 
+// Pins the Pod layout to the offsets `to_bytes` / `from_bytes` write and read
+const _: () = {
+    assert!(size_of::<DepositParams>() == DepositParams::SIZE);
+    assert!(offset_of!(DepositParams, amount) == 0);
+    assert!(offset_of!(DepositParams, expected_output) == 8);
+    assert!(offset_of!(DepositParams, depositor) == 16);
+    assert!(offset_of!(DepositParams, expect_fee_exempt) == 48);
+    assert!(offset_of!(DepositParams, _padding) == 49);
+};
+
+/// Version byte of the current versioned CPI params layouts.
+pub const CPI_PARAMS_VERSION: u8 = 2;
+
+/// Versioned wire layout of [`DepositParams`].
+///
+/// A leading `version` byte (padded to 8) followed by the params, so a field
+/// can be added in a later version without the pools misreading older
+/// callers' bytes. The legacy layout is the bare, unversioned
+/// [`DepositParams`]; the two are told apart by length.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Pod, Zeroable)]
+pub struct DepositParamsV2 {
+    /// Layout version ([`CPI_PARAMS_VERSION`])
+    pub version: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 7],
+    /// The deposit params
+    pub params: DepositParams,
+}
+
+impl DepositParamsV2 {
+    /// Size in bytes
+    pub const SIZE: usize = core::mem::size_of::<Self>();
+
+    /// Wrap `params` in the current layout version.
+    pub const fn new(params: DepositParams) -> Self {
+        Self {
+            version: CPI_PARAMS_VERSION,
+            _padding: [0u8; 7],
+            params,
+        }
+    }
+
+    /// Serialize to bytes for CPI instruction data
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+        bytes[0] = self.version;
+        bytes[8..].copy_from_slice(&self.params.to_bytes());
+        bytes
+    }
+
+    /// Deserialize from exactly [`Self::SIZE`] bytes of this version
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::SIZE || bytes[0] != CPI_PARAMS_VERSION {
+            return None;
+        }
+        Some(Self::new(DepositParams::from_bytes(&bytes[8..])?))
+    }
+}
+
+// version + padding: 8 + params: 56
+const _: () = {
+    assert!(DepositParamsV2::SIZE == 64);
+    assert!(offset_of!(DepositParamsV2, version) == 0);
+    assert!(offset_of!(DepositParamsV2, params) == 8);
+};
+
 /// Parameters for a withdrawal CPI call from hub to pool.
 ///
 /// Pool validates amounts and approves hub to transfer from vault.
@@ -313,7 +491,7 @@ impl DepositParams {
 /// # Token Flow (Withdrawal)
 /// ```text
 /// Hub validates proof, calculates amounts
-/// Hub CPIs to pool: { amount, expected_output }
+/// Hub CPIs to pool: { amount, expected_output, recipient, expect_fee_exempt }
 /// Pool: validates expected_output = amount - protocol_fee
 /// Pool: approves hub_authority for expected_output (total tokens to distribute)
 /// Pool: updates state, returns { fee }
@@ -323,10 +501,44 @@ impl DepositParams {
 ///
 /// # Responsibilities
 /// - Pool: Validate expected_output matches (amount - protocol_fee) with exchange rate
+/// - Pool: Validate expect_fee_exempt against its fee-exempt list
 /// - Pool: Approve hub_authority as delegate for expected_output
 /// - Pool: Update accounting, return protocol_fee
 /// - Hub: Transfer (expected_output - relayer_fee) from vault to recipient
 /// - Hub: Transfer relayer_fee from vault to relayer
+///
+/// # Fee Exemption
+///
+/// As for [`DepositParams`], with `recipient` checked against the pool's
+/// fee-exempt list.
+///
+/// # Native SOL Unwrap
+///
+/// When `unwrap` is set (unified SOL pool, WSOL only), `recipient` is a
+/// system account rather than a token account owner. The pool moves
+/// `unwrap_amount` tokens into a temporary WSOL account, closes it so the
+/// recipient receives lamports, and approves hub_authority only for the
+/// remainder (`expected_output - unwrap_amount`, the relayer fee).
+///
+/// # Automatic Routing
+///
+/// When `route_automatically` is set (unified SOL pool only), the named LST's
+/// vault is drawn first and any shortfall is filled from the vaults passed
+/// as [`route_accounts`](crate::route_accounts), which must follow the pool's
+/// withdrawal priority list. `expected_output` is still priced at the named
+/// LST's rate; the pool approves hub_authority on every vault it draws from
+/// and reports the per-vault amounts as [`RoutedWithdrawReturnData`].
+///
+/// # Fallback LSTs
+///
+/// When `fallback_count` is non-zero (unified SOL pool only, at most
+/// [`MAX_WITHDRAW_FALLBACK_VAULTS`]), the caller names the vaults itself:
+/// the named LST's vault is drawn first and any shortfall is filled from the
+/// `fallback_count` [`route_accounts`](crate::route_accounts) pairs in the
+/// order given, each at its own frozen rate. They need not be on the priority
+/// list, so this cannot be combined with `route_automatically`. The fee is
+/// still charged once on `amount`, and the result is reported as
+/// [`RoutedWithdrawReturnData`].
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, Pod, Zeroable)]
 pub struct WithdrawParams {
@@ -337,17 +549,58 @@ pub struct WithdrawParams {
     /// For unified SOL: expected_output = (amount - protocol_fee) / exchange_rate
     /// Hub will split this between recipient (expected_output - relayer_fee) and relayer (relayer_fee)
     pub expected_output: u64,
+    /// Recipient authority (owner of the token account receiving the output),
+    /// or the recipient system account when `unwrap` is set
+    pub recipient: [u8; 32],
+    /// Whether the hub computed no fee because `recipient` is fee-exempt (1 = yes)
+    pub expect_fee_exempt: u8,
+    /// Whether the recipient's share is paid out as native SOL (1 = yes)
+    pub unwrap: u8,
+    /// Whether a shortfall in the named vault is filled from other vaults (1 = yes)
+    pub route_automatically: u8,
+    /// Fallback vaults passed after the named one (0 = none)
+    pub fallback_count: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 4],
+    /// Tokens to unwrap to `recipient` (0 unless `unwrap` is set)
+    pub unwrap_amount: u64,
 }
 
 impl WithdrawParams {
     /// Size in bytes
-    pub const SIZE: usize = 16;
+    pub const SIZE: usize = 64;
+
+    /// Whether the hub expects the pool to waive the fee
+    pub fn expects_fee_exempt(&self) -> bool {
+        self.expect_fee_exempt != 0
+    }
+
+    /// Whether the hub expects the pool to unwrap the recipient's share
+    pub fn unwraps(&self) -> bool {
+        self.unwrap != 0
+    }
+
+    /// Whether the hub asks the pool to route across vaults
+    pub fn routes_automatically(&self) -> bool {
+        self.route_automatically != 0
+    }
+
+    /// Whether the caller passed fallback vaults
+    pub fn has_fallbacks(&self) -> bool {
+        self.fallback_count != 0
+    }
 
     /// Serialize to bytes for CPI instruction data
     pub fn to_bytes(&self) -> [u8; Self::SIZE] {
         let mut bytes = [0u8; Self::SIZE];
         bytes[0..8].copy_from_slice(&self.amount.to_le_bytes());
         bytes[8..16].copy_from_slice(&self.expected_output.to_le_bytes());
+        bytes[16..48].copy_from_slice(&self.recipient);
+        bytes[48] = self.expect_fee_exempt;
+        bytes[49] = self.unwrap;
+        bytes[50] = self.route_automatically;
+        bytes[51] = self.fallback_count;
+        bytes[56..64].copy_from_slice(&self.unwrap_amount.to_le_bytes());
         bytes
     }
 
@@ -359,10 +612,94 @@ impl WithdrawParams {
         Some(Self {
             amount: u64::from_le_bytes(bytes[0..8].try_into().ok()?),
             expected_output: u64::from_le_bytes(bytes[8..16].try_into().ok()?),
+            recipient: bytes[16..48].try_into().ok()?,
+            expect_fee_exempt: bytes[48],
+            unwrap: bytes[49],
+            route_automatically: bytes[50],
+            fallback_count: bytes[51],
+            _padding: [0u8; 4],
+            unwrap_amount: u64::from_le_bytes(bytes[56..64].try_into().ok()?),
         })
     }
+
+    /// Parse withdrawal params in any supported wire layout.
+    ///
+    /// As [`DepositParams::parse_any`], for the legacy unversioned layout or
+    /// a [`WithdrawParamsV2`].
+    pub fn parse_any(bytes: &[u8]) -> Option<Self> {
+        match bytes.len() {
+            Self::SIZE => Self::from_bytes(bytes),
+            WithdrawParamsV2::SIZE => WithdrawParamsV2::from_bytes(bytes).map(|v2| v2.params),
+            _ => None,
+        }
+    }
+}
+
+// Pins the Pod layout to the offsets `to_bytes` / `from_bytes` write and read
+const _: () = {
+    assert!(size_of::<WithdrawParams>() == WithdrawParams::SIZE);
+    assert!(offset_of!(WithdrawParams, amount) == 0);
+    assert!(offset_of!(WithdrawParams, expected_output) == 8);
+    assert!(offset_of!(WithdrawParams, recipient) == 16);
+    assert!(offset_of!(WithdrawParams, expect_fee_exempt) == 48);
+    assert!(offset_of!(WithdrawParams, unwrap) == 49);
+    assert!(offset_of!(WithdrawParams, route_automatically) == 50);
+    assert!(offset_of!(WithdrawParams, fallback_count) == 51);
+    assert!(offset_of!(WithdrawParams, _padding) == 52);
+    assert!(offset_of!(WithdrawParams, unwrap_amount) == 56);
+};
+
+/// Versioned wire layout of [`WithdrawParams`].
+///
+/// Same scheme as [`DepositParamsV2`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Pod, Zeroable)]
+pub struct WithdrawParamsV2 {
+    /// Layout version ([`CPI_PARAMS_VERSION`])
+    pub version: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 7],
+    /// The withdrawal params
+    pub params: WithdrawParams,
+}
+
+impl WithdrawParamsV2 {
+    /// Size in bytes
+    pub const SIZE: usize = core::mem::size_of::<Self>();
+
+    /// Wrap `params` in the current layout version.
+    pub const fn new(params: WithdrawParams) -> Self {
+        Self {
+            version: CPI_PARAMS_VERSION,
+            _padding: [0u8; 7],
+            params,
+        }
+    }
+
+    /// Serialize to bytes for CPI instruction data
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+        bytes[0] = self.version;
+        bytes[8..].copy_from_slice(&self.params.to_bytes());
+        bytes
+    }
+
+    /// Deserialize from exactly [`Self::SIZE`] bytes of this version
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::SIZE || bytes[0] != CPI_PARAMS_VERSION {
+            return None;
+        }
+        Some(Self::new(WithdrawParams::from_bytes(&bytes[8..])?))
+    }
 }
 
+// version + padding: 8 + params: 64
+const _: () = {
+    assert!(WithdrawParamsV2::SIZE == 72);
+    assert!(offset_of!(WithdrawParamsV2, version) == 0);
+    assert!(offset_of!(WithdrawParamsV2, params) == 8);
+};
+
 /// Return data from pool operations.
 ///
 /// Pool returns protocol fee collected via `set_return_data`.
@@ -393,6 +730,334 @@ impl PoolReturnData {
     }
 }
 
+const _: () = {
+    assert!(size_of::<PoolReturnData>() == PoolReturnData::SIZE);
+    assert!(offset_of!(PoolReturnData, fee) == 0);
+};
+
+/// Maximum vaults an automatically routed withdrawal may draw from besides
+/// the named LST's vault (also the length of the pool's priority list).
+pub const MAX_WITHDRAW_ROUTE_VAULTS: usize = 4;
+
+/// Maximum fallback vaults a withdrawal may name besides the requested LST's.
+pub const MAX_WITHDRAW_FALLBACK_VAULTS: usize = 2;
+
+/// Return data from a routed (or fallback) unified SOL withdrawal.
+///
+/// `outputs[0]` is the amount approved on the named LST's vault and
+/// `outputs[i]` the amount approved on the vault of the `i`-th
+/// [`route_accounts`](crate::route_accounts) entry (0 = not drawn from).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Pod, Zeroable)]
+pub struct RoutedWithdrawReturnData {
+    /// Protocol fee collected by the pool
+    pub fee: u64,
+    /// Tokens approved for hub_authority on each vault, in route order
+    pub outputs: [u64; MAX_WITHDRAW_ROUTE_VAULTS + 1],
+}
+
+impl RoutedWithdrawReturnData {
+    /// Size in bytes
+    pub const SIZE: usize = core::mem::size_of::<Self>();
+
+    /// Deserialize from exactly [`Self::SIZE`] bytes
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        bytemuck::try_pod_read_unaligned(bytes).ok()
+    }
+}
+
+// fee: 8 + outputs: 5 * 8
+const _: () = {
+    assert!(RoutedWithdrawReturnData::SIZE == 48);
+    assert!(offset_of!(RoutedWithdrawReturnData, fee) == 0);
+    assert!(offset_of!(RoutedWithdrawReturnData, outputs) == 8);
+};
+
+/// Structured result of a pool `Deposit` or `Withdraw`.
+///
+/// Token pools set this as return data so the hub can cross-check its own
+/// fee math without re-reading pool accounts; read it with
+/// [`read_pool_op_result`](crate::read_pool_op_result).
+///
+/// `gross == fee + net` always holds. On deposit, `fee` is the full fee the
+/// hub priced in; a Token-2022 transfer fee is paid out of it.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "idl-build", derive(panchor::IdlType))]
+pub struct PoolOpResult {
+    /// Gross amount in pool units (`DepositParams.amount` / `WithdrawParams.amount`)
+    pub gross: u64,
+    /// Fee charged on `gross`
+    pub fee: u64,
+    /// Net amount credited (deposit) or approved for distribution (withdraw)
+    pub net: u64,
+    /// Reward epoch the operation landed in: the pool's `accumulator_epoch`
+    /// counter, incremented once per finalization. This is an epoch number,
+    /// not a slot.
+    pub accumulator_epoch: u64,
+    /// Pool's accounted balance after the operation
+    pub new_accounted_total: u128,
+}
+
+impl PoolOpResult {
+    /// Size in bytes
+    pub const SIZE: usize = core::mem::size_of::<Self>();
+
+    /// Deserialize from exactly [`Self::SIZE`] bytes
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        bytemuck::try_pod_read_unaligned(bytes).ok()
+    }
+
+    /// Check the pool computed the same amounts as the caller.
+    pub fn matches(&self, gross: u64, fee: u64, net: u64) -> bool {
+        self.gross == gross && self.fee == fee && self.net == net
+    }
+}
+
+// gross, fee, net, accumulator_epoch: 4 * 8 + new_accounted_total: 16
+const _: () = {
+    assert!(PoolOpResult::SIZE == 48);
+    assert!(offset_of!(PoolOpResult, gross) == 0);
+    assert!(offset_of!(PoolOpResult, fee) == 8);
+    assert!(offset_of!(PoolOpResult, net) == 16);
+    assert!(offset_of!(PoolOpResult, accumulator_epoch) == 24);
+    assert!(offset_of!(PoolOpResult, new_accounted_total) == 32);
+};
+
+/// Structured result of a unified SOL pool `Deposit` or `Withdraw`.
+///
+/// Set as return data so the hub can check the pool priced the operation at
+/// the same exchange rate it read; read it with
+/// [`read_unified_sol_op_result`](crate::read_unified_sol_op_result).
+/// Routed withdrawals return [`RoutedWithdrawReturnData`] instead.
+///
+/// `sol_value` is gross (fee included): `φ(lst_amount)` on deposit, and
+/// `WithdrawParams.amount` on withdrawal, where `lst_amount` is `φ⁻¹(sol_value - fee)`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "idl-build", derive(panchor::IdlType))]
+pub struct UnifiedSolOpResult {
+    /// LST tokens moved (deposited, or approved for distribution)
+    pub lst_amount: u64,
+    /// Virtual SOL value of the operation, fee included
+    pub sol_value: u64,
+    /// Protocol fee in virtual SOL
+    pub fee: u64,
+    /// Frozen exchange rate the pool priced at (`harvested_exchange_rate`)
+    pub exchange_rate: u64,
+    /// Reward epoch the operation landed in
+    pub accumulator_epoch: u64,
+}
+
+impl UnifiedSolOpResult {
+    /// Size in bytes
+    pub const SIZE: usize = core::mem::size_of::<Self>();
+
+    /// Deserialize from exactly [`Self::SIZE`] bytes
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        bytemuck::try_pod_read_unaligned(bytes).ok()
+    }
+
+    /// Check the pool computed the same amounts, at the same rate, as the caller.
+    pub fn matches(&self, lst_amount: u64, sol_value: u64, fee: u64, exchange_rate: u64) -> bool {
+        self.lst_amount == lst_amount
+            && self.sol_value == sol_value
+            && self.fee == fee
+            && self.exchange_rate == exchange_rate
+    }
+}
+
+// lst_amount, sol_value, fee, exchange_rate, accumulator_epoch: 5 * 8
+const _: () = {
+    assert!(UnifiedSolOpResult::SIZE == 40);
+    assert!(offset_of!(UnifiedSolOpResult, lst_amount) == 0);
+    assert!(offset_of!(UnifiedSolOpResult, sol_value) == 8);
+    assert!(offset_of!(UnifiedSolOpResult, fee) == 16);
+    assert!(offset_of!(UnifiedSolOpResult, exchange_rate) == 24);
+    assert!(offset_of!(UnifiedSolOpResult, accumulator_epoch) == 32);
+};
+
+/// Versioned result of a pool `Deposit`, shared by both pool types.
+///
+/// The versioned successor to [`PoolOpResult`] and [`UnifiedSolOpResult`]:
+/// one layout for every pool, led by a version byte so it can grow without
+/// the hub misreading an older pool's bytes. Pools set it with
+/// [`to_bytes`](Self::to_bytes) and the hub reads it with
+/// [`from_return_data`](Self::from_return_data).
+///
+/// `amount == fee + credited` in pool units once `amount` is converted at
+/// `exchange_rate` (1:1 for token pools).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "idl-build", derive(panchor::IdlType))]
+pub struct DepositResult {
+    /// Layout version ([`Self::VERSION`])
+    pub version: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 7],
+    /// Tokens deposited (`DepositParams.amount`)
+    pub amount: u64,
+    /// Protocol fee in pool units
+    pub fee: u64,
+    /// Pool units credited to the depositor (`DepositParams.expected_output`)
+    pub credited: u64,
+    /// Reward epoch the deposit landed in (token pools: `accumulator_epoch`)
+    pub accumulator_epoch: u64,
+    /// Exchange rate the pool priced at (`RATE_PRECISION` for token pools)
+    pub exchange_rate: u64,
+}
+
+impl DepositResult {
+    /// Current layout version
+    pub const VERSION: u8 = 1;
+
+    /// Size in bytes
+    pub const SIZE: usize = core::mem::size_of::<Self>();
+
+    /// Build a result in the current layout version.
+    pub const fn new(
+        amount: u64,
+        fee: u64,
+        credited: u64,
+        accumulator_epoch: u64,
+        exchange_rate: u64,
+    ) -> Self {
+        Self {
+            version: Self::VERSION,
+            _padding: [0u8; 7],
+            amount,
+            fee,
+            credited,
+            accumulator_epoch,
+            exchange_rate,
+        }
+    }
+
+    /// Serialize to bytes (for `set_return_data`)
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        bytemuck::cast(*self)
+    }
+
+    /// Deserialize from exactly [`Self::SIZE`] bytes of the current version
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        bytemuck::try_pod_read_unaligned::<Self>(bytes)
+            .ok()
+            .filter(|result| result.version == Self::VERSION)
+    }
+
+    /// Read the result `pool_program` set as return data during the last CPI.
+    ///
+    /// Returns `None` if there is no return data, another program set it, or
+    /// it is not a current-version `DepositResult`.
+    pub fn from_return_data(pool_program: &Pubkey) -> Option<Self> {
+        let return_data = pinocchio::cpi::get_return_data()?;
+        if return_data.program_id() != pool_program {
+            return None;
+        }
+        Self::from_bytes(return_data.as_slice())
+    }
+}
+
+// version + padding: 8 + amount, fee, credited, accumulator_epoch, exchange_rate: 5 * 8
+const _: () = {
+    assert!(DepositResult::SIZE == 48);
+    assert!(offset_of!(DepositResult, version) == 0);
+    assert!(offset_of!(DepositResult, amount) == 8);
+    assert!(offset_of!(DepositResult, fee) == 16);
+    assert!(offset_of!(DepositResult, credited) == 24);
+    assert!(offset_of!(DepositResult, accumulator_epoch) == 32);
+    assert!(offset_of!(DepositResult, exchange_rate) == 40);
+};
+
+/// Versioned result of a pool `Withdraw`, shared by both pool types.
+///
+/// The withdrawal counterpart of [`DepositResult`]. Routed and fallback
+/// withdrawals still return [`RoutedWithdrawReturnData`].
+///
+/// `output` is `amount - fee` converted to tokens at `exchange_rate` (1:1 for
+/// token pools).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "idl-build", derive(panchor::IdlType))]
+pub struct WithdrawResult {
+    /// Layout version ([`Self::VERSION`])
+    pub version: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 7],
+    /// Pool units withdrawn, fee included (`WithdrawParams.amount`)
+    pub amount: u64,
+    /// Protocol fee in pool units
+    pub fee: u64,
+    /// Tokens approved for distribution (`WithdrawParams.expected_output`)
+    pub output: u64,
+    /// Reward epoch the withdrawal landed in (token pools: `accumulator_epoch`)
+    pub accumulator_epoch: u64,
+    /// Exchange rate the pool priced at (`RATE_PRECISION` for token pools)
+    pub exchange_rate: u64,
+}
+
+impl WithdrawResult {
+    /// Current layout version
+    pub const VERSION: u8 = 1;
+
+    /// Size in bytes
+    pub const SIZE: usize = core::mem::size_of::<Self>();
+
+    /// Build a result in the current layout version.
+    pub const fn new(
+        amount: u64,
+        fee: u64,
+        output: u64,
+        accumulator_epoch: u64,
+        exchange_rate: u64,
+    ) -> Self {
+        Self {
+            version: Self::VERSION,
+            _padding: [0u8; 7],
+            amount,
+            fee,
+            output,
+            accumulator_epoch,
+            exchange_rate,
+        }
+    }
+
+    /// Serialize to bytes (for `set_return_data`)
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        bytemuck::cast(*self)
+    }
+
+    /// Deserialize from exactly [`Self::SIZE`] bytes of the current version
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        bytemuck::try_pod_read_unaligned::<Self>(bytes)
+            .ok()
+            .filter(|result| result.version == Self::VERSION)
+    }
+
+    /// Read the result `pool_program` set as return data during the last CPI.
+    ///
+    /// Returns `None` if there is no return data, another program set it, or
+    /// it is not a current-version `WithdrawResult`.
+    pub fn from_return_data(pool_program: &Pubkey) -> Option<Self> {
+        let return_data = pinocchio::cpi::get_return_data()?;
+        if return_data.program_id() != pool_program {
+            return None;
+        }
+        Self::from_bytes(return_data.as_slice())
+    }
+}
+
+// Same layout as DepositResult
+const _: () = {
+    assert!(WithdrawResult::SIZE == 48);
+    assert!(offset_of!(WithdrawResult, version) == 0);
+    assert!(offset_of!(WithdrawResult, amount) == 8);
+    assert!(offset_of!(WithdrawResult, fee) == 16);
+    assert!(offset_of!(WithdrawResult, output) == 24);
+    assert!(offset_of!(WithdrawResult, accumulator_epoch) == 32);
+    assert!(offset_of!(WithdrawResult, exchange_rate) == 40);
+};
+
 // ============================================================================
 // Pool Info (for Hub Fee Calculation)
 // ============================================================================
@@ -407,6 +1072,11 @@ impl PoolReturnData {
 /// For Token pools: `exchange_rate_num = exchange_rate_denom = RATE_PRECISION` (1:1)
 /// For Unified SOL: `exchange_rate = exchange_rate_num / exchange_rate_denom`
 ///
+/// # Fee Rates
+///
+/// Fee rates are the effective rates for the asset being moved. For Unified
+/// SOL that is the LST's override where set (see [`PoolInfo::with_fee_overrides`]).
+///
 /// The exchange rate converts between tokens and pool-native units:
 /// - Deposit: `pool_units = tokens × exchange_rate_num / exchange_rate_denom`
 /// - Withdraw: `tokens = pool_units × exchange_rate_denom / exchange_rate_num`
@@ -465,6 +1135,18 @@ impl PoolInfo {
         }
     }
 
+    /// Apply per-asset fee overrides ([`FEE_RATE_UNSET`] keeps the pool-wide rate)
+    pub const fn with_fee_overrides(
+        mut self,
+        deposit_fee_override: u16,
+        withdrawal_fee_override: u16,
+    ) -> Self {
+        self.deposit_fee_rate = effective_fee_rate(self.deposit_fee_rate, deposit_fee_override);
+        self.withdrawal_fee_rate =
+            effective_fee_rate(self.withdrawal_fee_rate, withdrawal_fee_override);
+        self
+    }
+
     /// Returns true if the pool is paused
     pub const fn is_paused(&self) -> bool {
         self.is_paused != 0
@@ -499,14 +1181,15 @@ impl PoolInfo {
 
     /// Calculate the expected fee for a given principal amount
     ///
-    /// Uses the universal formula: `fee = principal × rate / BASIS_POINTS`
+    /// Uses the universal formula: `fee = principal × rate / BASIS_POINTS`,
+    /// rounded down; saturates at `u64::MAX` for out-of-range rates.
     pub fn calculate_fee(&self, principal: u64, is_deposit: bool) -> u64 {
         let rate = if is_deposit {
             self.deposit_fee_rate
         } else {
             self.withdrawal_fee_rate
         };
-        (principal as u128 * rate as u128 / BASIS_POINTS as u128) as u64
+        compute_fee(principal, rate, Rounding::Down).unwrap_or(u64::MAX)
     }
 }
 
@@ -531,6 +1214,10 @@ pub struct ComputedPoolParams {
     pub protocol_fee: u64,
     /// Relayer fee in tokens (hub handles transfer separately)
     pub relayer_fee_tokens: u64,
+    /// Depositor (deposit) or recipient (withdraw) authority
+    pub authority: [u8; 32],
+    /// Whether `authority` is on the pool's fee-exempt list
+    pub fee_exempt: bool,
 }
 
 impl ComputedPoolParams {
@@ -539,6 +1226,9 @@ impl ComputedPoolParams {
         DepositParams {
             amount: self.amount,
             expected_output: self.expected_output,
+            depositor: self.authority,
+            expect_fee_exempt: self.fee_exempt as u8,
+            _padding: [0u8; 7],
         }
     }
 
@@ -547,6 +1237,13 @@ impl ComputedPoolParams {
         WithdrawParams {
             amount: self.amount,
             expected_output: self.expected_output,
+            recipient: self.authority,
+            expect_fee_exempt: self.fee_exempt as u8,
+            unwrap: 0,
+            route_automatically: 0,
+            fallback_count: 0,
+            _padding: [0u8; 4],
+            unwrap_amount: 0,
         }
     }
 }
@@ -566,6 +1263,28 @@ mod tests {
         assert_eq!(PoolType::from_u8(2), None);
     }
 
+    #[test]
+    fn test_pool_kind_ranges() {
+        assert_eq!(PoolKind::from_pool_type(PoolType::Token), PoolKind::TOKEN);
+        assert_eq!(
+            PoolKind::from_pool_type(PoolType::UnifiedSol),
+            PoolKind::UNIFIED_SOL
+        );
+        assert!(PoolKind::TOKEN.is_core());
+        assert!(PoolKind::UNIFIED_SOL.is_core());
+        assert!(!PoolKind::UNSET.is_core());
+        assert!(!PoolKind(0x0100).is_core());
+
+        // Ranges tile 1..=u16::MAX without overlap
+        assert_eq!(*PoolKind::CORE.start(), 1);
+        assert_eq!(*PoolKind::EXTERNAL.start(), PoolKind::CORE.end() + 1);
+        assert_eq!(
+            *PoolKind::EXPERIMENTAL.start(),
+            PoolKind::EXTERNAL.end() + 1
+        );
+        assert_eq!(*PoolKind::EXPERIMENTAL.end(), u16::MAX);
+    }
+
     #[test]
     fn test_pool_info_token_pool() {
         let info = PoolInfo::new_token_pool(100, 50);
@@ -591,6 +1310,20 @@ mod tests {
         assert_eq!(info.pool_units_to_tokens(1100), 1000);
     }
 
+    #[test]
+    fn test_pool_info_fee_overrides() {
+        let info = PoolInfo::new_unified_sol_pool(30, 50, RATE_PRECISION, RATE_PRECISION);
+
+        let unset = info.with_fee_overrides(FEE_RATE_UNSET, FEE_RATE_UNSET);
+        assert_eq!(unset.deposit_fee_rate, 30);
+        assert_eq!(unset.withdrawal_fee_rate, 50);
+
+        // A zero override is a real rate (fee-free), not "unset"
+        let overridden = info.with_fee_overrides(0, 75);
+        assert_eq!(overridden.calculate_fee(10000, true), 0);
+        assert_eq!(overridden.calculate_fee(10000, false), 75);
+    }
+
     #[test]
     fn test_fee_calculation() {
         let info = PoolInfo::new_token_pool(100, 50); // 1% deposit, 0.5% withdraw
@@ -603,14 +1336,17 @@ mod tests {
 
     #[test]
     fn test_deposit_params_size() {
-        // amount: 8 + expected_output: 8 = 16
-        assert_eq!(core::mem::size_of::<DepositParams>(), 16);
+        // amount: 8 + expected_output: 8 + depositor: 32 + expect_fee_exempt: 1 + padding: 7 = 56
+        assert_eq!(core::mem::size_of::<DepositParams>(), 56);
+        assert_eq!(DepositParams::SIZE, 56);
     }
 
     #[test]
     fn test_withdraw_params_size() {
-        // amount: 8 + expected_output: 8 = 16
-        assert_eq!(core::mem::size_of::<WithdrawParams>(), 16);
+        // amount: 8 + expected_output: 8 + recipient: 32 + expect_fee_exempt: 1 + unwrap: 1
+        // + route_automatically: 1 + padding: 5 + unwrap_amount: 8 = 64
+        assert_eq!(core::mem::size_of::<WithdrawParams>(), 64);
+        assert_eq!(WithdrawParams::SIZE, 64);
     }
 
     #[test]
@@ -619,6 +1355,13 @@ mod tests {
         assert_eq!(core::mem::size_of::<PoolReturnData>(), 8);
     }
 
+    #[test]
+    fn test_pool_op_result_size() {
+        // gross: 8 + fee: 8 + net: 8 + accumulator_epoch: 8 + new_accounted_total: 16 = 48
+        assert_eq!(core::mem::size_of::<PoolOpResult>(), 48);
+        assert_eq!(PoolOpResult::SIZE, 48);
+    }
+
     #[test]
     fn test_pool_info_size() {
         // 2 + 2 + 12 (padding) + 16 + 16 + 1 + 1 + 14 (padding) = 64
@@ -631,11 +1374,17 @@ mod tests {
         let params = DepositParams {
             amount: 1000,
             expected_output: 995,
+            depositor: [3u8; 32],
+            expect_fee_exempt: 1,
+            _padding: [0u8; 7],
         };
         let bytes = params.to_bytes();
         let restored = DepositParams::from_bytes(&bytes).unwrap();
         assert_eq!(params.amount, restored.amount);
         assert_eq!(params.expected_output, restored.expected_output);
+        assert_eq!(params.depositor, restored.depositor);
+        assert!(restored.expects_fee_exempt());
+        assert_eq!(bytes.as_slice(), bytemuck::bytes_of(&params));
     }
 
     #[test]
@@ -643,11 +1392,172 @@ mod tests {
         let params = WithdrawParams {
             amount: 1000,
             expected_output: 995,
+            recipient: [4u8; 32],
+            expect_fee_exempt: 0,
+            unwrap: 1,
+            route_automatically: 1,
+            fallback_count: 2,
+            _padding: [0u8; 4],
+            unwrap_amount: 990,
         };
         let bytes = params.to_bytes();
         let restored = WithdrawParams::from_bytes(&bytes).unwrap();
         assert_eq!(params.amount, restored.amount);
         assert_eq!(params.expected_output, restored.expected_output);
+        assert_eq!(params.recipient, restored.recipient);
+        assert!(!restored.expects_fee_exempt());
+        assert!(restored.unwraps());
+        assert!(restored.routes_automatically());
+        assert_eq!(params.fallback_count, restored.fallback_count);
+        assert_eq!(params.unwrap_amount, restored.unwrap_amount);
+        assert_eq!(bytes.as_slice(), bytemuck::bytes_of(&params));
+    }
+
+    #[test]
+    fn test_versioned_params_size() {
+        // version: 1 + padding: 7 + params
+        assert_eq!(DepositParamsV2::SIZE, 8 + DepositParams::SIZE);
+        assert_eq!(WithdrawParamsV2::SIZE, 8 + WithdrawParams::SIZE);
+    }
+
+    #[test]
+    fn test_params_encoders_match_pod_layout() {
+        // Non-zero, distinct bytes in every field so a shifted offset shows up
+        let deposit = DepositParams {
+            amount: 0x0102_0304_0506_0708,
+            expected_output: 0x1112_1314_1516_1718,
+            depositor: [0x21; 32],
+            expect_fee_exempt: 1,
+            _padding: [0; 7],
+        };
+        let withdraw = WithdrawParams {
+            amount: 0x0102_0304_0506_0708,
+            expected_output: 0x1112_1314_1516_1718,
+            recipient: [0x21; 32],
+            expect_fee_exempt: 1,
+            unwrap: 1,
+            route_automatically: 1,
+            fallback_count: 2,
+            _padding: [0; 4],
+            unwrap_amount: 0x3132_3334_3536_3738,
+        };
+
+        assert_eq!(deposit.to_bytes(), bytemuck::bytes_of(&deposit));
+        assert_eq!(withdraw.to_bytes(), bytemuck::bytes_of(&withdraw));
+        let deposit_v2 = DepositParamsV2::new(deposit);
+        let withdraw_v2 = WithdrawParamsV2::new(withdraw);
+        assert_eq!(deposit_v2.to_bytes(), bytemuck::bytes_of(&deposit_v2));
+        assert_eq!(withdraw_v2.to_bytes(), bytemuck::bytes_of(&withdraw_v2));
+    }
+
+    #[test]
+    fn test_deposit_params_parse_any() {
+        let params = DepositParams {
+            amount: 1000,
+            expected_output: 995,
+            depositor: [3u8; 32],
+            expect_fee_exempt: 1,
+            _padding: [0u8; 7],
+        };
+        let legacy = params.to_bytes();
+        let v2 = DepositParamsV2::new(params).to_bytes();
+        assert_eq!(v2[0], CPI_PARAMS_VERSION);
+        assert_eq!(&v2[8..], legacy.as_slice());
+
+        // Legacy and v2 bytes parse to the same params
+        for bytes in [legacy.as_slice(), v2.as_slice()] {
+            let parsed = DepositParams::parse_any(bytes).unwrap();
+            assert_eq!(parsed.amount, 1000);
+            assert_eq!(parsed.expected_output, 995);
+            assert_eq!(parsed.depositor, [3u8; 32]);
+            assert!(parsed.expects_fee_exempt());
+        }
+
+        // Truncated or padded bytes of either layout
+        for len in [0, 1, 16, DepositParams::SIZE - 1, DepositParamsV2::SIZE - 1] {
+            assert!(
+                DepositParams::parse_any(&v2[..len]).is_none(),
+                "len {}",
+                len
+            );
+        }
+        let mut long = [0u8; DepositParamsV2::SIZE + 1];
+        long[..DepositParamsV2::SIZE].copy_from_slice(&v2);
+        assert!(DepositParams::parse_any(&long).is_none());
+        assert!(DepositParams::parse_any(&long[..DepositParams::SIZE + 1]).is_none());
+
+        // Unknown versions, past and future
+        for version in [0, 1, CPI_PARAMS_VERSION + 1, u8::MAX] {
+            let mut bytes = v2;
+            bytes[0] = version;
+            assert!(
+                DepositParams::parse_any(&bytes).is_none(),
+                "version {}",
+                version
+            );
+            assert!(DepositParamsV2::from_bytes(&bytes).is_none());
+        }
+    }
+
+    #[test]
+    fn test_withdraw_params_parse_any() {
+        let params = WithdrawParams {
+            amount: 1000,
+            expected_output: 995,
+            recipient: [4u8; 32],
+            expect_fee_exempt: 0,
+            unwrap: 1,
+            route_automatically: 0,
+            fallback_count: 2,
+            _padding: [0u8; 4],
+            unwrap_amount: 990,
+        };
+        let legacy = params.to_bytes();
+        let v2 = WithdrawParamsV2::new(params).to_bytes();
+        assert_eq!(v2[0], CPI_PARAMS_VERSION);
+        assert_eq!(&v2[8..], legacy.as_slice());
+
+        // Legacy and v2 bytes parse to the same params
+        for bytes in [legacy.as_slice(), v2.as_slice()] {
+            let parsed = WithdrawParams::parse_any(bytes).unwrap();
+            assert_eq!(parsed.amount, 1000);
+            assert_eq!(parsed.expected_output, 995);
+            assert_eq!(parsed.recipient, [4u8; 32]);
+            assert!(parsed.unwraps());
+            assert_eq!(parsed.fallback_count, 2);
+            assert_eq!(parsed.unwrap_amount, 990);
+        }
+
+        // Truncated or padded bytes of either layout
+        for len in [
+            0,
+            1,
+            56,
+            WithdrawParams::SIZE - 1,
+            WithdrawParamsV2::SIZE - 1,
+        ] {
+            assert!(
+                WithdrawParams::parse_any(&v2[..len]).is_none(),
+                "len {}",
+                len
+            );
+        }
+        let mut long = [0u8; WithdrawParamsV2::SIZE + 1];
+        long[..WithdrawParamsV2::SIZE].copy_from_slice(&v2);
+        assert!(WithdrawParams::parse_any(&long).is_none());
+        assert!(WithdrawParams::parse_any(&long[..WithdrawParams::SIZE + 1]).is_none());
+
+        // Unknown versions, past and future
+        for version in [0, 1, CPI_PARAMS_VERSION + 1, u8::MAX] {
+            let mut bytes = v2;
+            bytes[0] = version;
+            assert!(
+                WithdrawParams::parse_any(&bytes).is_none(),
+                "version {}",
+                version
+            );
+            assert!(WithdrawParamsV2::from_bytes(&bytes).is_none());
+        }
     }
 
     #[test]
@@ -658,6 +1568,146 @@ mod tests {
         assert_eq!(data.fee, restored.fee);
     }
 
+    #[test]
+    fn test_routed_withdraw_return_data_roundtrip() {
+        // fee: 8 + outputs: 8 * 5 = 48
+        assert_eq!(RoutedWithdrawReturnData::SIZE, 48);
+
+        let data = RoutedWithdrawReturnData {
+            fee: 7,
+            outputs: [100, 0, 250, 0, 0],
+        };
+        let restored = RoutedWithdrawReturnData::from_bytes(bytemuck::bytes_of(&data)).unwrap();
+        assert_eq!(restored, data);
+
+        // Plain PoolReturnData is not mistaken for a routed result
+        assert!(RoutedWithdrawReturnData::from_bytes(&data.fee.to_le_bytes()).is_none());
+    }
+
+    #[test]
+    fn test_pool_op_result_roundtrip() {
+        let result = PoolOpResult {
+            gross: 1_000,
+            fee: 10,
+            net: 990,
+            accumulator_epoch: 42,
+            new_accounted_total: u128::MAX,
+        };
+        let restored = PoolOpResult::from_bytes(bytemuck::bytes_of(&result)).unwrap();
+        assert_eq!(restored, result);
+
+        // Exact size only
+        assert!(PoolOpResult::from_bytes(&[0u8; PoolOpResult::SIZE - 1]).is_none());
+        assert!(PoolOpResult::from_bytes(&[0u8; PoolOpResult::SIZE + 1]).is_none());
+    }
+
+    #[test]
+    fn test_pool_op_result_matches() {
+        let result = PoolOpResult {
+            gross: 1_000,
+            fee: 10,
+            net: 990,
+            ..Default::default()
+        };
+        assert!(result.matches(1_000, 10, 990));
+        assert!(!result.matches(1_001, 10, 990));
+        assert!(!result.matches(1_000, 11, 990));
+        assert!(!result.matches(1_000, 10, 989));
+    }
+
+    #[test]
+    fn test_unified_sol_op_result_roundtrip() {
+        // lst_amount + sol_value + fee + exchange_rate + accumulator_epoch: 5 * 8 = 40
+        assert_eq!(UnifiedSolOpResult::SIZE, 40);
+
+        let result = UnifiedSolOpResult {
+            lst_amount: 1_000,
+            sol_value: 1_050,
+            fee: 10,
+            exchange_rate: 1_050_000_000,
+            accumulator_epoch: 42,
+        };
+        let restored = UnifiedSolOpResult::from_bytes(bytemuck::bytes_of(&result)).unwrap();
+        assert_eq!(restored, result);
+
+        // Neither the plain fee nor a routed result is mistaken for one
+        assert!(UnifiedSolOpResult::from_bytes(&result.fee.to_le_bytes()).is_none());
+        assert!(UnifiedSolOpResult::from_bytes(&[0u8; RoutedWithdrawReturnData::SIZE]).is_none());
+    }
+
+    #[test]
+    fn test_unified_sol_op_result_matches() {
+        let result = UnifiedSolOpResult {
+            lst_amount: 1_000,
+            sol_value: 1_050,
+            fee: 10,
+            exchange_rate: 1_050_000_000,
+            accumulator_epoch: 42,
+        };
+        assert!(result.matches(1_000, 1_050, 10, 1_050_000_000));
+        assert!(!result.matches(999, 1_050, 10, 1_050_000_000));
+        assert!(!result.matches(1_000, 1_049, 10, 1_050_000_000));
+        assert!(!result.matches(1_000, 1_050, 11, 1_050_000_000));
+        // Same amounts priced at a different rate
+        assert!(!result.matches(1_000, 1_050, 10, 1_050_000_001));
+    }
+
+    #[test]
+    fn test_deposit_result_roundtrip() {
+        let result = DepositResult::new(1_000, 10, 1_040, 42, 1_050_000_000);
+        assert_eq!(result.version, DepositResult::VERSION);
+
+        let bytes = result.to_bytes();
+        assert_eq!(bytes[0], DepositResult::VERSION);
+        assert_eq!(bytes.as_slice(), bytemuck::bytes_of(&result));
+        assert_eq!(DepositResult::from_bytes(&bytes), Some(result));
+    }
+
+    #[test]
+    fn test_withdraw_result_roundtrip() {
+        let result = WithdrawResult::new(1_050, 5, 995, 42, 1_050_000_000);
+        assert_eq!(result.version, WithdrawResult::VERSION);
+
+        let bytes = result.to_bytes();
+        assert_eq!(bytes[0], WithdrawResult::VERSION);
+        assert_eq!(bytes.as_slice(), bytemuck::bytes_of(&result));
+        assert_eq!(WithdrawResult::from_bytes(&bytes), Some(result));
+    }
+
+    #[test]
+    fn test_versioned_results_reject_bad_buffers() {
+        let deposit = DepositResult::new(1_000, 10, 990, 42, RATE_PRECISION as u64).to_bytes();
+        let withdraw = WithdrawResult::new(1_000, 5, 995, 42, RATE_PRECISION as u64).to_bytes();
+
+        // Exact size only
+        assert!(DepositResult::from_bytes(&deposit[..DepositResult::SIZE - 1]).is_none());
+        assert!(DepositResult::from_bytes(&[0u8; DepositResult::SIZE + 1]).is_none());
+        assert!(WithdrawResult::from_bytes(&withdraw[..WithdrawResult::SIZE - 1]).is_none());
+        assert!(WithdrawResult::from_bytes(&[]).is_none());
+
+        // Other versions, including an all-zero buffer
+        for version in [0, DepositResult::VERSION + 1] {
+            let mut bytes = deposit;
+            bytes[0] = version;
+            assert!(DepositResult::from_bytes(&bytes).is_none());
+        }
+        for version in [0, WithdrawResult::VERSION + 1] {
+            let mut bytes = withdraw;
+            bytes[0] = version;
+            assert!(WithdrawResult::from_bytes(&bytes).is_none());
+        }
+
+        // Unversioned results of the same size are not mistaken for one
+        let op_result = PoolOpResult {
+            gross: 1_000,
+            fee: 10,
+            net: 990,
+            accumulator_epoch: 42,
+            new_accounted_total: 0,
+        };
+        assert!(DepositResult::from_bytes(bytemuck::bytes_of(&op_result)).is_none());
+    }
+
     // ========================================================================
     // Exchange Rate Conversion Function Tests
     // ========================================================================
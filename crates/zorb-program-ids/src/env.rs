@@ -0,0 +1,235 @@
+//! Runtime environment selection for off-chain tooling.
+//!
+//! The crate-level constants are fixed by the network feature at compile time,
+//! which is what on-chain programs need. Tools that deal with several
+//! deployments at once (the relayer, deploy scripts, key syncing) select the
+//! environment at runtime here instead.
+//!
+//! ```
+//! use zorb_program_ids::env::{Env, ProgramKind, program_id};
+//!
+//! let env = Env::from_name("devnet").unwrap();
+//! assert_eq!(
+//!     program_id(env, ProgramKind::TokenPool),
+//!     "GomZwW2f2AyqTMfqRhNxbuN8RyEbArD4r93wY8zaRQxw"
+//! );
+//! ```
+
+/// A deployment environment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Env {
+    /// Mainnet-beta
+    Mainnet,
+    /// Devnet
+    Devnet,
+    /// Testnet; placeholder program IDs until deployed
+    Testnet,
+    /// Local validator; uses the mainnet program IDs
+    Localnet,
+}
+
+impl Env {
+    /// Every environment, in table order.
+    pub const ALL: [Env; 4] = [Env::Mainnet, Env::Devnet, Env::Testnet, Env::Localnet];
+
+    /// The environment the crate-level constants were compiled for.
+    #[cfg(feature = "devnet")]
+    pub const CURRENT: Env = Env::Devnet;
+    /// The environment the crate-level constants were compiled for.
+    #[cfg(all(feature = "testnet", not(feature = "devnet")))]
+    pub const CURRENT: Env = Env::Testnet;
+    /// The environment the crate-level constants were compiled for.
+    #[cfg(all(
+        feature = "localnet",
+        not(any(feature = "devnet", feature = "testnet"))
+    ))]
+    pub const CURRENT: Env = Env::Localnet;
+    /// The environment the crate-level constants were compiled for (the
+    /// default when no network feature is enabled).
+    #[cfg(not(any(feature = "devnet", feature = "testnet", feature = "localnet")))]
+    pub const CURRENT: Env = Env::Mainnet;
+
+    /// Lowercase name, matching the feature flag.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Env::Mainnet => "mainnet",
+            Env::Devnet => "devnet",
+            Env::Testnet => "testnet",
+            Env::Localnet => "localnet",
+        }
+    }
+
+    /// Parse a lowercase environment name.
+    pub fn from_name(name: &str) -> Option<Env> {
+        Env::ALL.into_iter().find(|env| env.name() == name)
+    }
+}
+
+/// A Zorb protocol program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ProgramKind {
+    /// Hub program (shielded-pool)
+    ShieldedPool,
+    /// Token Pool
+    TokenPool,
+    /// Unified SOL Pool
+    UnifiedSolPool,
+}
+
+impl ProgramKind {
+    /// Every program, in table order.
+    pub const ALL: [ProgramKind; 3] = [
+        ProgramKind::ShieldedPool,
+        ProgramKind::TokenPool,
+        ProgramKind::UnifiedSolPool,
+    ];
+}
+
+/// Every program ID in every environment.
+pub const ALL_PROGRAMS: &[(Env, ProgramKind, &str)] = &[
+    (
+        Env::Mainnet,
+        ProgramKind::ShieldedPool,
+        "zrbus1K97oD9wzzygehPBZMh5EVXPturZNgbfoTig5Z",
+    ),
+    (
+        Env::Mainnet,
+        ProgramKind::TokenPool,
+        "tokucUdUVP8k9xMS98cnVFmy4Yg3zkKMjfmGuYma8ah",
+    ),
+    (
+        Env::Mainnet,
+        ProgramKind::UnifiedSolPool,
+        "unixG6MuVwukHrmCbn4oE8LAPYKDfDMyNtNuMSEYJmi",
+    ),
+    (
+        Env::Devnet,
+        ProgramKind::ShieldedPool,
+        "Ar4QfyyGcZENwwHcYA8d45XcnjtjcaWBSHzEzvyAP5dT",
+    ),
+    (
+        Env::Devnet,
+        ProgramKind::TokenPool,
+        "GomZwW2f2AyqTMfqRhNxbuN8RyEbArD4r93wY8zaRQxw",
+    ),
+    (
+        Env::Devnet,
+        ProgramKind::UnifiedSolPool,
+        "5RvgA1AKJSp9dgWMStgU7ud7WJvDEVx1ybU3du9BUCya",
+    ),
+    (
+        Env::Testnet,
+        ProgramKind::ShieldedPool,
+        "9hYPp3XN2st877Bj76wx6nqSf1AaN4pTKQ8DUJX6grta",
+    ),
+    (
+        Env::Testnet,
+        ProgramKind::TokenPool,
+        "3AYXjoFS2pFVL7jyycsT63tAXFE8kqPEkjXmunL5fwiG",
+    ),
+    (
+        Env::Testnet,
+        ProgramKind::UnifiedSolPool,
+        "7GfkzEPJc6RtDWRCL7wUKeju8sDw9wGSqBfGzkvQCs3J",
+    ),
+    (
+        Env::Localnet,
+        ProgramKind::ShieldedPool,
+        "zrbus1K97oD9wzzygehPBZMh5EVXPturZNgbfoTig5Z",
+    ),
+    (
+        Env::Localnet,
+        ProgramKind::TokenPool,
+        "tokucUdUVP8k9xMS98cnVFmy4Yg3zkKMjfmGuYma8ah",
+    ),
+    (
+        Env::Localnet,
+        ProgramKind::UnifiedSolPool,
+        "unixG6MuVwukHrmCbn4oE8LAPYKDfDMyNtNuMSEYJmi",
+    ),
+];
+
+/// Program ID of `kind` in `env`.
+pub const fn program_id(env: Env, kind: ProgramKind) -> &'static str {
+    // ALL_PROGRAMS is laid out env-major in Env::ALL / ProgramKind::ALL order
+    ALL_PROGRAMS[env as usize * ProgramKind::ALL.len() + kind as usize].2
+}
+
+/// Shielded Pool (hub) program ID in `env`.
+pub const fn shielded_pool_id(env: Env) -> &'static str {
+    program_id(env, ProgramKind::ShieldedPool)
+}
+
+/// Token Pool program ID in `env`.
+pub const fn token_pool_id(env: Env) -> &'static str {
+    program_id(env, ProgramKind::TokenPool)
+}
+
+/// Unified SOL Pool program ID in `env`.
+pub const fn unified_sol_pool_id(env: Env) -> &'static str {
+    program_id(env, ProgramKind::UnifiedSolPool)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SHIELDED_POOL_ID, TOKEN_POOL_ID, UNIFIED_SOL_POOL_ID};
+
+    fn assert_matches_cfg_constants(env: Env) {
+        assert_eq!(shielded_pool_id(env), SHIELDED_POOL_ID);
+        assert_eq!(token_pool_id(env), TOKEN_POOL_ID);
+        assert_eq!(unified_sol_pool_id(env), UNIFIED_SOL_POOL_ID);
+    }
+
+    #[test]
+    fn test_table_layout() {
+        assert_eq!(ALL_PROGRAMS.len(), Env::ALL.len() * ProgramKind::ALL.len());
+        for env in Env::ALL {
+            for kind in ProgramKind::ALL {
+                let (_, _, id) = ALL_PROGRAMS
+                    .iter()
+                    .find(|(e, k, _)| *e == env && *k == kind)
+                    .unwrap();
+                assert_eq!(program_id(env, kind), *id);
+            }
+        }
+    }
+
+    #[test]
+    fn test_env_names_round_trip() {
+        for env in Env::ALL {
+            assert_eq!(Env::from_name(env.name()), Some(env));
+        }
+        assert_eq!(Env::from_name("mainnet-beta"), None);
+    }
+
+    #[test]
+    fn test_current_env_matches_cfg_constants() {
+        assert_matches_cfg_constants(Env::CURRENT);
+    }
+
+    #[cfg(feature = "devnet")]
+    #[test]
+    fn test_devnet_matches_cfg_constants() {
+        assert_matches_cfg_constants(Env::Devnet);
+    }
+
+    #[cfg(feature = "testnet")]
+    #[test]
+    fn test_testnet_matches_cfg_constants() {
+        assert_matches_cfg_constants(Env::Testnet);
+    }
+
+    #[cfg(not(any(feature = "devnet", feature = "testnet")))]
+    #[test]
+    fn test_mainnet_and_localnet_match_cfg_constants() {
+        assert_matches_cfg_constants(Env::Mainnet);
+        assert_matches_cfg_constants(Env::Localnet);
+    }
+
+    #[cfg(not(any(feature = "devnet", feature = "testnet", feature = "localnet")))]
+    #[test]
+    fn test_mainnet_is_default() {
+        assert_eq!(Env::CURRENT, Env::Mainnet);
+    }
+}
@@ -1,14 +1,22 @@
 //! Single source of truth for all Zorb protocol program IDs.
 //!
 //! This crate defines program IDs as `&'static str` constants that can be used
-//! at compile time by the panchor `program!` macro and other crates.
+//! at compile time by the panchor `program!` macro and other crates, and the
+//! same IDs as 32-byte `*_PUBKEY` arrays decoded from those strings at compile
+//! time, for CPI target and owner checks.
 //!
 //! # Feature Flags
 //!
+//! At most one network feature may be enabled; enabling two is a compile error.
+//!
+//! - `mainnet` - Use mainnet program IDs (the default when no feature is set)
 //! - `devnet` - Use devnet program IDs
-//! - `mainnet` - Use mainnet program IDs (default)
+//! - `testnet` - Use testnet program IDs (placeholders until deployed)
 //! - `localnet` - Use localnet program IDs (same as mainnet)
 //!
+//! Off-chain tools that need every environment's IDs at once select them at
+//! runtime through [`env`] instead.
+//!
 //! # Usage
 //!
 //! ```rust,ignore
@@ -17,10 +25,28 @@
 //!     id = zorb_program_ids::SHIELDED_POOL_ID,
 //!     instructions = MyInstruction,
 //! }
+//!
+//! // In a CPI target check
+//! accounts[8].assert_key(&zorb_program_ids::TOKEN_POOL_PUBKEY)?;
 //! ```
 
 #![no_std]
 
+#[cfg(all(feature = "mainnet", feature = "devnet"))]
+compile_error!("features `mainnet` and `devnet` are mutually exclusive");
+#[cfg(all(feature = "mainnet", feature = "testnet"))]
+compile_error!("features `mainnet` and `testnet` are mutually exclusive");
+#[cfg(all(feature = "mainnet", feature = "localnet"))]
+compile_error!("features `mainnet` and `localnet` are mutually exclusive");
+#[cfg(all(feature = "devnet", feature = "testnet"))]
+compile_error!("features `devnet` and `testnet` are mutually exclusive");
+#[cfg(all(feature = "devnet", feature = "localnet"))]
+compile_error!("features `devnet` and `localnet` are mutually exclusive");
+#[cfg(all(feature = "testnet", feature = "localnet"))]
+compile_error!("features `testnet` and `localnet` are mutually exclusive");
+
+pub mod env;
+
 // =============================================================================
 // Shielded Pool (Hub) Program ID
 // =============================================================================
@@ -31,12 +57,21 @@
 #[cfg(feature = "devnet")]
 pub const SHIELDED_POOL_ID: &str = "Ar4QfyyGcZENwwHcYA8d45XcnjtjcaWBSHzEzvyAP5dT";
 
+/// Shielded Pool program ID (testnet placeholder).
+///
+/// Hub program for ZK shielded transactions with Groth16 verification.
+#[cfg(feature = "testnet")]
+pub const SHIELDED_POOL_ID: &str = "9hYPp3XN2st877Bj76wx6nqSf1AaN4pTKQ8DUJX6grta";
+
 /// Shielded Pool program ID (mainnet/localnet).
 ///
 /// Hub program for ZK shielded transactions with Groth16 verification.
-#[cfg(not(feature = "devnet"))]
+#[cfg(not(any(feature = "devnet", feature = "testnet")))]
 pub const SHIELDED_POOL_ID: &str = "zrbus1K97oD9wzzygehPBZMh5EVXPturZNgbfoTig5Z";
 
+/// Shielded Pool program ID bytes ([`SHIELDED_POOL_ID`] decoded at compile time).
+pub const SHIELDED_POOL_PUBKEY: [u8; 32] = five8_const::decode_32_const(SHIELDED_POOL_ID);
+
 // =============================================================================
 // Token Pool Program ID
 // =============================================================================
@@ -47,12 +82,21 @@ pub const SHIELDED_POOL_ID: &str = "zrbus1K97oD9wzzygehPBZMh5EVXPturZNgbfoTig5Z"
 #[cfg(feature = "devnet")]
 pub const TOKEN_POOL_ID: &str = "GomZwW2f2AyqTMfqRhNxbuN8RyEbArD4r93wY8zaRQxw";
 
+/// Token Pool program ID (testnet placeholder).
+///
+/// Handles SPL token deposits and withdrawals with 1:1 exchange rate.
+#[cfg(feature = "testnet")]
+pub const TOKEN_POOL_ID: &str = "3AYXjoFS2pFVL7jyycsT63tAXFE8kqPEkjXmunL5fwiG";
+
 /// Token Pool program ID (mainnet/localnet).
 ///
 /// Handles SPL token deposits and withdrawals with 1:1 exchange rate.
-#[cfg(not(feature = "devnet"))]
+#[cfg(not(any(feature = "devnet", feature = "testnet")))]
 pub const TOKEN_POOL_ID: &str = "tokucUdUVP8k9xMS98cnVFmy4Yg3zkKMjfmGuYma8ah";
 
+/// Token Pool program ID bytes ([`TOKEN_POOL_ID`] decoded at compile time).
+pub const TOKEN_POOL_PUBKEY: [u8; 32] = five8_const::decode_32_const(TOKEN_POOL_ID);
+
 // =============================================================================
 // Unified SOL Pool Program ID
 // =============================================================================
@@ -63,15 +107,56 @@ pub const TOKEN_POOL_ID: &str = "tokucUdUVP8k9xMS98cnVFmy4Yg3zkKMjfmGuYma8ah";
 #[cfg(feature = "devnet")]
 pub const UNIFIED_SOL_POOL_ID: &str = "5RvgA1AKJSp9dgWMStgU7ud7WJvDEVx1ybU3du9BUCya";
 
+/// Unified SOL Pool program ID (testnet placeholder).
+///
+/// Handles LST deposits and withdrawals with exchange rate conversion.
+#[cfg(feature = "testnet")]
+pub const UNIFIED_SOL_POOL_ID: &str = "7GfkzEPJc6RtDWRCL7wUKeju8sDw9wGSqBfGzkvQCs3J";
+
 /// Unified SOL Pool program ID (mainnet/localnet).
 ///
 /// Handles LST deposits and withdrawals with exchange rate conversion.
-#[cfg(not(feature = "devnet"))]
+#[cfg(not(any(feature = "devnet", feature = "testnet")))]
 pub const UNIFIED_SOL_POOL_ID: &str = "unixG6MuVwukHrmCbn4oE8LAPYKDfDMyNtNuMSEYJmi";
 
+/// Unified SOL Pool program ID bytes ([`UNIFIED_SOL_POOL_ID`] decoded at compile time).
+pub const UNIFIED_SOL_POOL_PUBKEY: [u8; 32] = five8_const::decode_32_const(UNIFIED_SOL_POOL_ID);
+
 // =============================================================================
 // Convenience re-exports with alternative names
 // =============================================================================
 
 /// Hub program ID (alias for SHIELDED_POOL_ID).
 pub const HUB_PROGRAM_ID: &str = SHIELDED_POOL_ID;
+
+/// Hub program ID bytes (alias for SHIELDED_POOL_PUBKEY).
+pub const HUB_PROGRAM_PUBKEY: [u8; 32] = SHIELDED_POOL_PUBKEY;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_encodes_to(bytes: &[u8; 32], id: &str) {
+        assert_eq!(bs58::encode(bytes).into_string(), id);
+    }
+
+    #[test]
+    fn test_shielded_pool_pubkey() {
+        assert_encodes_to(&SHIELDED_POOL_PUBKEY, SHIELDED_POOL_ID);
+    }
+
+    #[test]
+    fn test_token_pool_pubkey() {
+        assert_encodes_to(&TOKEN_POOL_PUBKEY, TOKEN_POOL_ID);
+    }
+
+    #[test]
+    fn test_unified_sol_pool_pubkey() {
+        assert_encodes_to(&UNIFIED_SOL_POOL_PUBKEY, UNIFIED_SOL_POOL_ID);
+    }
+
+    #[test]
+    fn test_hub_program_pubkey() {
+        assert_encodes_to(&HUB_PROGRAM_PUBKEY, HUB_PROGRAM_ID);
+    }
+}
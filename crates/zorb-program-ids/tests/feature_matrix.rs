@@ -0,0 +1,59 @@
+//! Network feature matrix: each network feature builds on its own, and any
+//! two together fail with the crate's `compile_error!`.
+//!
+//! Each case runs `cargo check` on this crate in a separate target directory.
+
+// Builds the crate through cargo instead of linking it
+#![allow(unused_crate_dependencies)]
+
+use std::process::{Command, Output};
+
+const NETWORKS: [&str; 4] = ["mainnet", "devnet", "testnet", "localnet"];
+
+fn cargo_check(features: &[&str]) -> Output {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".into());
+    Command::new(cargo)
+        .args([
+            "check",
+            "--quiet",
+            "--offline",
+            "--lib",
+            "--no-default-features",
+        ])
+        .arg("--manifest-path")
+        .arg(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"))
+        .arg("--features")
+        .arg(features.join(","))
+        .env("CARGO_TARGET_DIR", env!("CARGO_TARGET_TMPDIR"))
+        .output()
+        .expect("failed to run cargo")
+}
+
+#[test]
+fn test_single_network_builds() {
+    // No feature is the mainnet default
+    let cases = std::iter::once(&[][..]).chain(NETWORKS.iter().map(core::slice::from_ref));
+    for features in cases {
+        let output = cargo_check(features);
+        assert!(
+            output.status.success(),
+            "{features:?}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
+
+#[test]
+fn test_conflicting_networks_rejected() {
+    for (i, a) in NETWORKS.iter().enumerate() {
+        for b in &NETWORKS[i + 1..] {
+            let output = cargo_check(&[a, b]);
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            assert!(!output.status.success(), "{a} + {b} built");
+            assert!(
+                stderr.contains(&format!("features `{a}` and `{b}` are mutually exclusive")),
+                "{a} + {b}: {stderr}"
+            );
+        }
+    }
+}
@@ -0,0 +1,35 @@
+#!/usr/bin/env bash
+# Build the program with cargo build-sbf and fail if the SBF linker reports a
+# stack frame overflow ("Stack offset of N exceeded max offset ...") for
+# process_execute_transact. Other functions' stack warnings are printed but
+# do not fail the check.
+#
+# Usage: scripts/check-stack-frames.sh [extra cargo build-sbf args]
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+
+CHECKED_FN="process_execute_transact"
+
+# Stack warnings are only printed when the crate is recompiled
+touch src/lib.rs
+
+if ! output=$(cargo build-sbf --manifest-path Cargo.toml "$@" 2>&1); then
+    echo "$output"
+    echo "cargo build-sbf failed" >&2
+    exit 1
+fi
+
+warnings=$(grep -E "Stack offset of [0-9]+ exceeded" <<<"$output" || true)
+
+if [[ -n "$warnings" ]]; then
+    echo "SBF stack warnings:"
+    echo "$warnings"
+fi
+
+if grep -q "$CHECKED_FN" <<<"$warnings"; then
+    echo "error: $CHECKED_FN exceeds the SBF stack frame limit" >&2
+    exit 1
+fi
+
+echo "ok: no stack frame warnings for $CHECKED_FN"
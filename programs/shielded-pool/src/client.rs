@@ -0,0 +1,147 @@
+//! Client-side hashing helpers.
+//!
+//! Relayers and clients must reproduce the hashes the program checks. These
+//! wrap the functions the on-chain code calls, so off-chain Rust never carries
+//! a second copy of the byte layout or field reduction.
+//!
+//! `tests/vectors/client_hashes.json` holds known-answer vectors for all three
+//! helpers; the tests below check them, and other client implementations
+//! should test against the same file.
+
+use crate::events::Receipt;
+use crate::instructions::TransactParams;
+use crate::utils;
+use ark_ff::{BigInteger, PrimeField};
+
+/// SHA256 of `params`' Pod bytes, as computed in ExecuteTransact (P8).
+pub fn transact_params_hash(params: &TransactParams) -> [u8; 32] {
+    utils::calculate_transact_params_hash(params)
+}
+
+/// SHA256 of the Borsh-serialized `receipt`, the receipt tree leaf.
+pub fn receipt_hash(receipt: &Receipt) -> [u8; 32] {
+    receipt
+        .to_leaf_hash()
+        .expect("Borsh serialization into a Vec cannot fail")
+}
+
+/// Reduce a little-endian digest into the BN254 scalar field, big-endian.
+///
+/// `reduce_to_fr_be(&transact_params_hash(params))` is the proof's
+/// `transact_params_hash` public input.
+pub fn reduce_to_fr_be(bytes: &[u8; 32]) -> [u8; 32] {
+    let be = utils::transact_params_hash_to_fr(bytes)
+        .into_bigint()
+        .to_bytes_be();
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&be);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::types::{N_INS, N_OUTS, N_PUBLIC_LINES};
+    use serde_json::Value;
+
+    const VECTORS: &str = include_str!("../tests/vectors/client_hashes.json");
+
+    fn vectors(section: &str) -> Vec<Value> {
+        let all: Value = serde_json::from_str(VECTORS).unwrap();
+        all[section].as_array().unwrap().clone()
+    }
+
+    fn bytes32(v: &Value) -> [u8; 32] {
+        hex::decode(v.as_str().unwrap())
+            .unwrap()
+            .try_into()
+            .unwrap()
+    }
+
+    fn bytes32_array<const N: usize>(v: &Value) -> [[u8; 32]; N] {
+        let items: Vec<_> = v.as_array().unwrap().iter().map(bytes32).collect();
+        items.try_into().unwrap()
+    }
+
+    fn u64_array<const N: usize>(v: &Value) -> [u64; N] {
+        let items: Vec<u64> = v
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|x| x.as_str().unwrap().parse().unwrap())
+            .collect();
+        items.try_into().unwrap()
+    }
+
+    fn i64_array<const N: usize>(v: &Value) -> [i64; N] {
+        let items: Vec<i64> = v
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|x| x.as_str().unwrap().parse().unwrap())
+            .collect();
+        items.try_into().unwrap()
+    }
+
+    fn params(v: &Value) -> TransactParams {
+        TransactParams {
+            asset_ids: bytes32_array::<N_PUBLIC_LINES>(&v["asset_ids"]),
+            mints: bytes32_array::<N_PUBLIC_LINES>(&v["mints"]),
+            ext_amounts: i64_array::<N_PUBLIC_LINES>(&v["ext_amounts"]),
+            fees: u64_array::<N_PUBLIC_LINES>(&v["fees"]),
+            recipients: bytes32_array::<N_PUBLIC_LINES>(&v["recipients"]),
+            relayer_fees: u64_array::<N_PUBLIC_LINES>(&v["relayer_fees"]),
+            relayer: bytes32(&v["relayer"]),
+            slot_expiry: v["slot_expiry"].as_str().unwrap().parse().unwrap(),
+            encrypted_output_hashes: bytes32_array::<N_OUTS>(&v["encrypted_output_hashes"]),
+        }
+    }
+
+    fn receipt(v: &Value) -> Receipt {
+        let int = |key: &str| v[key].as_str().unwrap().parse::<u64>().unwrap();
+        Receipt {
+            version: v["version"].as_u64().unwrap() as u8,
+            slot: int("slot"),
+            epoch: int("epoch"),
+            commitment_root: bytes32(&v["commitment_root"]),
+            last_commitment_index: int("last_commitment_index"),
+            commitments: bytes32_array::<N_OUTS>(&v["commitments"]),
+            nullifiers: bytes32_array::<N_INS>(&v["nullifiers"]),
+            transact_params_hash: bytes32(&v["transact_params_hash"]),
+            public_asset_ids: bytes32_array::<N_PUBLIC_LINES>(&v["public_asset_ids"]),
+            public_amounts: bytes32_array::<N_PUBLIC_LINES>(&v["public_amounts"]),
+        }
+    }
+
+    #[test]
+    fn test_transact_params_hash_vectors() {
+        for case in vectors("transact_params") {
+            let name = case["name"].as_str().unwrap();
+            let hash = transact_params_hash(&params(&case["params"]));
+            assert_eq!(hash, bytes32(&case["hash"]), "{name}");
+            assert_eq!(
+                reduce_to_fr_be(&hash),
+                bytes32(&case["hash_fr_be"]),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_receipt_hash_vectors() {
+        for case in vectors("receipts") {
+            let name = case["name"].as_str().unwrap();
+            let hash = receipt_hash(&receipt(&case["receipt"]));
+            assert_eq!(hash, bytes32(&case["hash"]), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_reduce_to_fr_be_vectors() {
+        for case in vectors("reduce_to_fr_be") {
+            let name = case["name"].as_str().unwrap();
+            let reduced = reduce_to_fr_be(&bytes32(&case["input"]));
+            assert_eq!(reduced, bytes32(&case["fr_be"]), "{name}");
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Compute-unit probes for the compute budget harness.
+//!
+//! With the `cu-trace` feature, [`probe`] logs `cu-trace: <phase>` followed by
+//! the runtime's `Program consumption: <n> units remaining` line, which
+//! `tests/compute_budget.rs` turns into a per-phase breakdown. Without the
+//! feature probes compile to nothing, so they can stay in release builds.
+//!
+//! Each probe costs a few hundred CU, so budgets are measured on a build
+//! without the feature.
+
+/// Mark the start of `phase` in the compute-unit trace.
+#[inline(always)]
+pub fn probe(phase: &str) {
+    #[cfg(feature = "cu-trace")]
+    {
+        pinocchio_log::log!("cu-trace: {}", phase);
+        pinocchio::log::sol_log_compute_units();
+    }
+    #[cfg(not(feature = "cu-trace"))]
+    let _ = phase;
+}
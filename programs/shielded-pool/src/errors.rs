@@ -9,7 +9,10 @@
 //! | 40-47 | Unified SOL | LST pool and epoch management |
 //! | 48-67 | Nullifier Tree | Indexed tree insertion and verification |
 //! | 68-78 | Pool Config | Pool routing and validation |
-//! | 100-108 | Groth16 | ZK proof verification failures |
+//! | 95-99 | Input Validation | Public input encoding |
+//! | 100-109 | Groth16 | ZK proof verification failures |
+//! | 110-119 | Admin | Authority and governance controls |
+//! | 120-139 | Pool Registry | Pool deregistration, config closing, and pool kinds |
 //!
 //! # Error Code Reference
 //!
@@ -119,7 +122,11 @@
 //! - 93: UnsupportedBatchSize
 //! - 94: EpochAdvanceTooSoon
 //!
-//! ## Groth16 ZK Proof Errors (100-108)
+//! ## Input Validation Errors (95-99)
+//! - 95: NonCanonicalFieldElement
+//! - 96: SessionDataCorrupted
+//!
+//! ## Groth16 ZK Proof Errors (100-109)
 //! - 100: InvalidG1Length
 //! - 101: InvalidG2Length
 //! - 102: InvalidPublicInputsLength
@@ -129,12 +136,38 @@
 //! - 106: ProofVerificationFailed
 //! - 107: InvalidG1
 //! - 108: InvalidG2
+//! - 109: InvalidG2Subgroup
+//!
+//! ## Admin Errors (110-119)
+//! - 110: AuthorityTransferTimelocked
+//! - 111: AuthorityTransferDelayTooShort
+//! - 112: AuthorityTransferPending
+//! - 113: InvalidAdminRole
+//! - 114: UnknownGlobalParam
+//! - 115: GlobalParamOutOfBounds
+//! - 116: DuplicateGlobalParam
+//! - 117: RelayerFeeCapExceeded
+//! - 118: GlobalConfigUpToDate
+//! - 119: DepositsDisabled
+//!
+//! ## Pool Registry Errors (120-139)
+//! - 120: PoolDeregistered
+//! - 121: PoolHasBalance
+//! - 122: PoolNotDeregistered
+//! - 123: PoolConfigGracePeriodActive
+//! - 124: PoolDepositsDisabled
+//! - 125: PoolWithdrawalsDisabled
+//! - 126: InvalidPoolMode
+//! - 127: PoolResultMismatch
+//! - 128: LstDepositsDisabled
+//! - 129: LstWithdrawalsDisabled
+//! - 130: UnknownPoolKind
 
 use pinocchio::program_error::ProgramError;
 
 /// Groth16 ZK proof verification errors.
 ///
-/// These errors use codes 100-108 and indicate failures during ZK proof
+/// These errors use codes 100-109 and indicate failures during ZK proof
 /// verification. They are useful for debugging proof generation issues.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Groth16Error {
@@ -149,6 +182,8 @@ pub enum Groth16Error {
     InvalidG1,
     /// G2 point decompression failed
     InvalidG2,
+    /// Uncompressed G2 point is on the curve but not in the prime-order subgroup
+    InvalidG2Subgroup,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -248,7 +283,8 @@ pub enum ShieldedPoolError {
     EpochTooOld,
     /// Cannot close epoch root - epoch is still provable (>= earliest_provable_epoch)
     EpochStillProvable,
-    /// Invalid earliest_provable_epoch value (must be >= current and <= current_epoch - MIN_PROVABLE_EPOCHS)
+    /// Invalid earliest_provable_epoch value (must be >= current and <= current_epoch - MIN_PROVABLE_EPOCHS).
+    /// No longer returned: AdvanceEarliestProvableEpoch clamps its target instead.
     InvalidEarliestEpoch,
     /// Invalid nullifier epoch root PDA (doesn't match expected derivation or discriminator)
     InvalidNullifierEpochRootPda,
@@ -274,7 +310,7 @@ pub enum ShieldedPoolError {
     InvalidReceiptTreePda,
     /// Relayer fee exceeds pool's maximum allowed fee (fee_rate × amount)
     RelayerFeeExceedsPoolFee,
-    /// Epoch advance attempted before MIN_SLOTS_PER_NULLIFIER_EPOCH slots have passed
+    /// Epoch advance attempted before GlobalConfig.nullifier_epoch_min_slots slots have passed
     EpochAdvanceTooSoon,
     /// Invalid recipient address (zero or system program)
     InvalidRecipient,
@@ -307,11 +343,59 @@ pub enum ShieldedPoolError {
     AssetIdComputationFailed,
     /// Batch size not yet supported (verification key pending trusted setup)
     UnsupportedBatchSize,
+    /// Public input is not a canonical BN254 scalar field element (>= r)
+    NonCanonicalFieldElement,
+    /// Uploaded session body does not hash to the body_hash declared at init
+    SessionDataCorrupted,
+    // Admin errors
+    /// Authority transfer cannot be accepted before its unlock slot
+    AuthorityTransferTimelocked,
+    /// Authority transfer delay is below MIN_AUTHORITY_TRANSFER_DELAY_SLOTS
+    AuthorityTransferDelayTooShort,
+    /// Operation not allowed while an authority transfer is pending
+    AuthorityTransferPending,
+    /// Unknown admin role id
+    InvalidAdminRole,
+    /// Unknown SetGlobalParams param_id
+    UnknownGlobalParam,
+    /// SetGlobalParams value outside the parameter's bounds
+    GlobalParamOutOfBounds,
+    /// Same param_id appears more than once in a SetGlobalParams batch
+    DuplicateGlobalParam,
+    /// Relayer fee exceeds max_relayer_fee_bps of |ext_amount|
+    RelayerFeeCapExceeded,
+    /// GlobalConfig is already at the current layout version
+    GlobalConfigUpToDate,
+    /// Deposits are disabled while the hub is in withdrawal-only mode
+    DepositsDisabled,
+    // Pool registry errors
+    /// Asset's pool has been deregistered from the hub
+    PoolDeregistered,
+    /// Pool still has accounted balances and cannot be deregistered
+    PoolHasBalance,
+    /// Pool config must be deregistered before it can be closed
+    PoolNotDeregistered,
+    /// Pool config close grace period has not elapsed
+    PoolConfigGracePeriodActive,
+    /// Deposits are disabled by the asset's pool config mode
+    PoolDepositsDisabled,
+    /// Withdrawals are disabled by the asset's pool config mode
+    PoolWithdrawalsDisabled,
+    /// Pool config mode contains unknown bits
+    InvalidPoolMode,
+    /// Pool's returned PoolOpResult / UnifiedSolOpResult disagrees with the hub's math
+    PoolResultMismatch,
+    /// Deposits of this LST are disabled by its LstConfig mode
+    LstDepositsDisabled,
+    /// Withdrawals of this LST are disabled by its LstConfig mode
+    LstWithdrawalsDisabled,
+    /// Pool config's pool_kind is not in the hub's pool registry
+    UnknownPoolKind,
 }
 
 impl From<Groth16Error> for ProgramError {
     fn from(error: Groth16Error) -> Self {
-        // Groth16 errors use codes 100-108 for debugging ZK proof failures
+        // Groth16 errors use codes 100-109 for debugging ZK proof failures
         match error {
             Groth16Error::InvalidG1Length => ProgramError::Custom(100),
             Groth16Error::InvalidG2Length => ProgramError::Custom(101),
@@ -322,6 +406,7 @@ impl From<Groth16Error> for ProgramError {
             Groth16Error::ProofVerificationFailed => ProgramError::Custom(106),
             Groth16Error::InvalidG1 => ProgramError::Custom(107),
             Groth16Error::InvalidG2 => ProgramError::Custom(108),
+            Groth16Error::InvalidG2Subgroup => ProgramError::Custom(109),
         }
     }
 }
@@ -429,6 +514,29 @@ impl From<ShieldedPoolError> for ProgramError {
             ShieldedPoolError::AssetIdComputationFailed => ProgramError::Custom(92),
             ShieldedPoolError::UnsupportedBatchSize => ProgramError::Custom(93),
             ShieldedPoolError::EpochAdvanceTooSoon => ProgramError::Custom(94),
+            ShieldedPoolError::NonCanonicalFieldElement => ProgramError::Custom(95),
+            ShieldedPoolError::SessionDataCorrupted => ProgramError::Custom(96),
+            ShieldedPoolError::AuthorityTransferTimelocked => ProgramError::Custom(110),
+            ShieldedPoolError::AuthorityTransferDelayTooShort => ProgramError::Custom(111),
+            ShieldedPoolError::AuthorityTransferPending => ProgramError::Custom(112),
+            ShieldedPoolError::InvalidAdminRole => ProgramError::Custom(113),
+            ShieldedPoolError::UnknownGlobalParam => ProgramError::Custom(114),
+            ShieldedPoolError::GlobalParamOutOfBounds => ProgramError::Custom(115),
+            ShieldedPoolError::DuplicateGlobalParam => ProgramError::Custom(116),
+            ShieldedPoolError::RelayerFeeCapExceeded => ProgramError::Custom(117),
+            ShieldedPoolError::GlobalConfigUpToDate => ProgramError::Custom(118),
+            ShieldedPoolError::DepositsDisabled => ProgramError::Custom(119),
+            ShieldedPoolError::PoolDeregistered => ProgramError::Custom(120),
+            ShieldedPoolError::PoolHasBalance => ProgramError::Custom(121),
+            ShieldedPoolError::PoolNotDeregistered => ProgramError::Custom(122),
+            ShieldedPoolError::PoolConfigGracePeriodActive => ProgramError::Custom(123),
+            ShieldedPoolError::PoolDepositsDisabled => ProgramError::Custom(124),
+            ShieldedPoolError::PoolWithdrawalsDisabled => ProgramError::Custom(125),
+            ShieldedPoolError::InvalidPoolMode => ProgramError::Custom(126),
+            ShieldedPoolError::PoolResultMismatch => ProgramError::Custom(127),
+            ShieldedPoolError::LstDepositsDisabled => ProgramError::Custom(128),
+            ShieldedPoolError::LstWithdrawalsDisabled => ProgramError::Custom(129),
+            ShieldedPoolError::UnknownPoolKind => ProgramError::Custom(130),
         }
     }
 }
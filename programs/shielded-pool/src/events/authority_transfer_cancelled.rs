@@ -0,0 +1,36 @@
+//! Authority transfer cancelled event definition.
+
+use super::{EventSchema, EventType};
+use panchor::prelude::*;
+use pinocchio::pubkey::Pubkey;
+
+/// Event emitted when a pending authority transfer is cancelled.
+///
+/// This event is emitted after a successful `cancel_authority_transfer`
+/// instruction, which clears `pending_authority` before it could be accepted.
+///
+/// # Security Considerations
+///
+/// A cancellation during the timelock window is the expected response to an
+/// unwanted `AuthorityTransferInitiatedEvent`. Monitor both together.
+///
+/// # Usage by Indexers
+///
+/// 1. Clear pending transfer state
+/// 2. Audit trail for governance changes
+#[event(EventType::AuthorityTransferCancelled)]
+#[repr(C)]
+pub struct AuthorityTransferCancelledEvent {
+    /// Authority who cancelled the transfer.
+    pub authority: Pubkey,
+    /// Pending authority whose transfer was cancelled.
+    pub cancelled_pending_authority: Pubkey,
+    /// Unlock slot the cancelled transfer would have reached.
+    pub unlock_slot: u64,
+    /// Slot when transfer was cancelled.
+    pub slot: u64,
+}
+
+impl EventSchema for AuthorityTransferCancelledEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
@@ -1,6 +1,6 @@
 //! Authority transfer completed event definition.
 
-use super::EventType;
+use super::{EventSchema, EventType};
 use panchor::prelude::*;
 use pinocchio::pubkey::Pubkey;
 
@@ -33,3 +33,7 @@ pub struct AuthorityTransferCompletedEvent {
     /// Slot when transfer was completed.
     pub slot: u64,
 }
+
+impl EventSchema for AuthorityTransferCompletedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
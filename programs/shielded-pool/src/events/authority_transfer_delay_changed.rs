@@ -0,0 +1,31 @@
+//! Authority transfer delay changed event definition.
+
+use super::{EventSchema, EventType};
+use panchor::prelude::*;
+use pinocchio::pubkey::Pubkey;
+
+/// Event emitted when the authority transfer timelock is changed.
+///
+/// This event is emitted after a successful `set_authority_transfer_delay`
+/// instruction. The delay applies to transfers initiated afterwards.
+///
+/// # Security Considerations
+///
+/// Lowering the delay shortens the window for cancelling a malicious transfer;
+/// alert on decreases.
+#[event(EventType::AuthorityTransferDelayChanged)]
+#[repr(C)]
+pub struct AuthorityTransferDelayChangedEvent {
+    /// Authority who changed the delay.
+    pub authority: Pubkey,
+    /// Previous delay in slots.
+    pub old_delay_slots: u64,
+    /// New delay in slots.
+    pub new_delay_slots: u64,
+    /// Slot when delay changed.
+    pub slot: u64,
+}
+
+impl EventSchema for AuthorityTransferDelayChangedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
@@ -1,6 +1,6 @@
 //! Authority transfer initiated event definition.
 
-use super::EventType;
+use super::{EventSchema, EventType};
 use panchor::prelude::*;
 use pinocchio::pubkey::Pubkey;
 
@@ -8,7 +8,8 @@ use pinocchio::pubkey::Pubkey;
 ///
 /// This event is emitted after a successful `transfer_authority` instruction,
 /// which sets the `pending_authority` field on the global config. The transfer
-/// is not complete until `accept_authority` is called by the pending authority.
+/// is not complete until `accept_authority` is called by the pending authority
+/// at or after `unlock_slot`.
 ///
 /// # Security Considerations
 ///
@@ -21,7 +22,8 @@ use pinocchio::pubkey::Pubkey;
 ///
 /// 1. Track pending authority transfers
 /// 2. Alert on authority transfer initiation
-/// 3. Monitor for acceptance timeout (if applicable)
+/// 3. Surface the timelock window (`slot..unlock_slot`) during which the
+///    current authority can cancel
 /// 4. Audit trail for governance changes
 #[event(EventType::AuthorityTransferInitiated)]
 #[repr(C)]
@@ -32,4 +34,10 @@ pub struct AuthorityTransferInitiatedEvent {
     pub pending_authority: Pubkey,
     /// Slot when transfer was initiated.
     pub slot: u64,
+    /// First slot at which the pending authority may accept.
+    pub unlock_slot: u64,
+}
+
+impl EventSchema for AuthorityTransferInitiatedEvent {
+    const CURRENT_VERSION: u8 = 1;
 }
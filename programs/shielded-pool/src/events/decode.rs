@@ -0,0 +1,310 @@
+//! Off-chain event decoding for clients and indexers.
+//!
+//! Decodes event bytes in both the versioned framing written today and the
+//! pre-version framing (`[discriminator][payload]`) used by older deployments.
+//! See [`super::framing`] for the layouts.
+//!
+//! Fixed-size events are decoded with [`decode_event`], which tells the two
+//! framings apart by length. Variable-length events (commitments, commitment
+//! chunks, receipts) are only emitted in the versioned framing and are split
+//! with [`decode_versioned_frame`] or their dedicated `parse_*` helpers.
+
+use super::{
+    EVENT_FRAME_HEADER_SIZE, EventFrameHeader, LEGACY_SCHEMA_VERSION, VersionedEventBytes,
+};
+
+/// Size of the legacy frame header: discriminator (8).
+const LEGACY_FRAME_HEADER_SIZE: usize = 8;
+
+/// Framing an event was decoded from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventFraming {
+    /// `[discriminator][payload]`, emitted before schema versioning
+    Legacy,
+    /// `[discriminator][schema_version][reserved][payload]`
+    Versioned,
+}
+
+/// Error returned when event bytes cannot be decoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventDecodeError {
+    /// Shorter than a frame header
+    TooShort,
+    /// Discriminator does not match the requested event type
+    DiscriminatorMismatch,
+    /// Length matches neither framing of the requested event type
+    InvalidLength,
+    /// Reserved frame bytes are not zero
+    InvalidFrame,
+    /// Schema version this decoder does not understand
+    UnsupportedVersion(u8),
+}
+
+/// A fixed-size event decoded from either framing.
+#[derive(Clone, Copy, Debug)]
+pub struct DecodedEvent<T> {
+    /// Framing the bytes were written in
+    pub framing: EventFraming,
+    /// Payload schema version ([`LEGACY_SCHEMA_VERSION`] for legacy framing)
+    pub schema_version: u8,
+    /// The decoded event
+    pub event: T,
+}
+
+/// A versioned event split into frame fields and payload.
+#[derive(Clone, Copy, Debug)]
+pub struct EventFrame<'a> {
+    /// Event type discriminator
+    pub discriminator: u64,
+    /// Payload schema version
+    pub schema_version: u8,
+    /// Payload bytes following the frame header
+    pub payload: &'a [u8],
+}
+
+/// Read the event discriminator (the first 8 bytes in both framings).
+pub fn event_discriminator(data: &[u8]) -> Option<u64> {
+    data.get(..8)
+        .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+/// Split versioned event bytes into frame fields and payload.
+pub fn decode_versioned_frame(data: &[u8]) -> Result<EventFrame<'_>, EventDecodeError> {
+    if data.len() < EVENT_FRAME_HEADER_SIZE {
+        return Err(EventDecodeError::TooShort);
+    }
+    let header: EventFrameHeader = bytemuck::pod_read_unaligned(&data[..EVENT_FRAME_HEADER_SIZE]);
+    if header._reserved != [0u8; 7] {
+        return Err(EventDecodeError::InvalidFrame);
+    }
+    if header.schema_version == LEGACY_SCHEMA_VERSION {
+        return Err(EventDecodeError::UnsupportedVersion(header.schema_version));
+    }
+
+    Ok(EventFrame {
+        discriminator: header.discriminator,
+        schema_version: header.schema_version,
+        payload: &data[EVENT_FRAME_HEADER_SIZE..],
+    })
+}
+
+/// Decode a fixed-size event of type `T` from either framing.
+///
+/// Legacy bytes are exactly `8 + size_of::<T>()` long and versioned bytes
+/// `16 + size_of::<T>()`, so the framing is determined by length. Versioned
+/// events newer than `T::CURRENT_VERSION` are rejected.
+pub fn decode_event<T: VersionedEventBytes>(
+    data: &[u8],
+) -> Result<DecodedEvent<T>, EventDecodeError> {
+    let discriminator = event_discriminator(data).ok_or(EventDecodeError::TooShort)?;
+    if discriminator != T::DISCRIMINATOR {
+        return Err(EventDecodeError::DiscriminatorMismatch);
+    }
+
+    let payload_len = core::mem::size_of::<T>();
+    if data.len() == LEGACY_FRAME_HEADER_SIZE + payload_len {
+        return Ok(DecodedEvent {
+            framing: EventFraming::Legacy,
+            schema_version: LEGACY_SCHEMA_VERSION,
+            event: bytemuck::pod_read_unaligned(&data[LEGACY_FRAME_HEADER_SIZE..]),
+        });
+    }
+    if data.len() != EVENT_FRAME_HEADER_SIZE + payload_len {
+        return Err(EventDecodeError::InvalidLength);
+    }
+
+    let frame = decode_versioned_frame(data)?;
+    if frame.schema_version > T::CURRENT_VERSION {
+        return Err(EventDecodeError::UnsupportedVersion(frame.schema_version));
+    }
+
+    Ok(DecodedEvent {
+        framing: EventFraming::Versioned,
+        schema_version: frame.schema_version,
+        event: bytemuck::pod_read_unaligned(frame.payload),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::*;
+    use alloc::vec::Vec;
+    use panchor::prelude::EventBytes;
+
+    /// Build an event of type `T` with every byte set to a distinct pattern.
+    fn patterned<T: bytemuck::Pod>() -> T {
+        let bytes: Vec<u8> = (0..core::mem::size_of::<T>())
+            .map(|i| (i * 7 + 1) as u8)
+            .collect();
+        bytemuck::pod_read_unaligned(&bytes)
+    }
+
+    /// Round-trip `T` through the versioned and legacy framings.
+    fn assert_round_trips<T: VersionedEventBytes>() {
+        let event: T = patterned();
+
+        let versioned = event.to_versioned_event_bytes();
+        let decoded = decode_event::<T>(&versioned).unwrap();
+        assert_eq!(decoded.framing, EventFraming::Versioned);
+        assert_eq!(decoded.schema_version, T::CURRENT_VERSION);
+        assert_eq!(
+            bytemuck::bytes_of(&decoded.event),
+            bytemuck::bytes_of(&event)
+        );
+
+        let legacy = event.to_event_bytes();
+        let decoded = decode_event::<T>(&legacy).unwrap();
+        assert_eq!(decoded.framing, EventFraming::Legacy);
+        assert_eq!(decoded.schema_version, LEGACY_SCHEMA_VERSION);
+        assert_eq!(
+            bytemuck::bytes_of(&decoded.event),
+            bytemuck::bytes_of(&event)
+        );
+    }
+
+    macro_rules! assert_all_round_trip {
+        ($($event:ty),* $(,)?) => {
+            $(assert_round_trips::<$event>();)*
+        };
+    }
+
+    #[test]
+    fn test_every_event_round_trips() {
+        assert_all_round_trip!(
+            AuthorityTransferCancelledEvent,
+            AuthorityTransferCompletedEvent,
+            AuthorityTransferDelayChangedEvent,
+            AuthorityTransferInitiatedEvent,
+            DepositEscrowClosedEvent,
+            DepositEscrowCreatedEvent,
+            DepositEscrowRelayerUpdatedEvent,
+            EventEmissionModeChangedEvent,
+            GlobalConfigMigratedEvent,
+            GlobalParamChangedEvent,
+            NewNullifierEvent,
+            NullifierBatchInsertedEvent,
+            NullifierEarliestEpochAdvancedEvent,
+            NullifierEpochAdvancedEvent,
+            NullifierEpochRootClosedEvent,
+            NullifierLeafInsertedEvent,
+            NullifierPdaClosedEvent,
+            PoolConfigActiveChangedEvent,
+            PoolConfigClosedEvent,
+            PoolConfigModeChangedEvent,
+            PoolDeregisteredEvent,
+            PoolInitializedEvent,
+            PoolPauseChangedEvent,
+            PoolRegisteredEvent,
+            RoleChangedEvent,
+            StaleAccumulatorPolicyChangedEvent,
+            TransactChunkUploadedEvent,
+            TransactSessionClosedEvent,
+            TransactSessionCreatedEvent,
+            WithdrawalsOnlyChangedEvent,
+        );
+    }
+
+    #[test]
+    fn test_variable_length_events_use_versioned_frame() {
+        let output = [5u8; 40];
+        let commitment = NewCommitmentEvent::new(3, [1u8; 32], [2u8; 32], &output);
+        let bytes = commitment.to_event_bytes();
+        let frame = decode_versioned_frame(&bytes).unwrap();
+        assert_eq!(frame.discriminator, EventType::NewCommitment as u64);
+        assert_eq!(frame.schema_version, NewCommitmentHeader::CURRENT_VERSION);
+        assert_eq!(
+            frame.payload.len(),
+            NEW_COMMITMENT_HEADER_SIZE + output.len()
+        );
+
+        let receipt = Receipt {
+            version: RECEIPT_VERSION,
+            slot: 1,
+            epoch: 2,
+            commitment_root: [3u8; 32],
+            last_commitment_index: 4,
+            commitments: [[5u8; 32]; crate::instructions::N_OUTS],
+            nullifiers: [[6u8; 32]; crate::instructions::N_INS],
+            transact_params_hash: [7u8; 32],
+            public_asset_ids: [[8u8; 32]; crate::instructions::N_PUBLIC_LINES],
+            public_amounts: [[9u8; 32]; crate::instructions::N_PUBLIC_LINES],
+        };
+        let header = NewReceiptEventHeader {
+            receipt_index: 11,
+            receipt_hash: [12u8; 32],
+            ..patterned()
+        };
+        let bytes = build_new_receipt_event_bytes(&header, &receipt).unwrap();
+        let frame = decode_versioned_frame(&bytes).unwrap();
+        assert_eq!(frame.discriminator, EventType::NewReceipt as u64);
+        assert_eq!(frame.schema_version, NewReceiptEvent::CURRENT_VERSION);
+        assert_eq!(frame.payload[..8], 11u64.to_le_bytes());
+        assert_eq!(frame.payload[8..40], [12u8; 32]);
+        assert_eq!(
+            frame.payload[..NEW_RECEIPT_EVENT_HEADER_SIZE],
+            *bytemuck::bytes_of(&header)
+        );
+        assert_eq!(
+            frame.payload[NEW_RECEIPT_EVENT_HEADER_SIZE..],
+            receipt.to_bytes().unwrap()[..]
+        );
+    }
+
+    #[test]
+    fn test_decode_legacy_pool_pause_changed_blob() {
+        // PoolPauseChanged as logged before versioning: discriminator (51),
+        // authority, is_paused = 1, padding, slot = 123_456_789
+        let mut blob = Vec::new();
+        blob.extend_from_slice(&[0x33, 0, 0, 0, 0, 0, 0, 0]);
+        blob.extend_from_slice(&[0xab; 32]);
+        blob.extend_from_slice(&[1, 0, 0, 0, 0, 0, 0, 0]);
+        blob.extend_from_slice(&[0x15, 0xcd, 0x5b, 0x07, 0, 0, 0, 0]);
+
+        let decoded = decode_event::<PoolPauseChangedEvent>(&blob).unwrap();
+        assert_eq!(decoded.framing, EventFraming::Legacy);
+        assert_eq!(decoded.schema_version, LEGACY_SCHEMA_VERSION);
+        assert_eq!(decoded.event.authority, [0xab; 32]);
+        assert_eq!(decoded.event.is_paused, 1);
+        assert_eq!(decoded.event.slot, 123_456_789);
+
+        // The legacy blob is not a valid versioned frame of any other event
+        assert_eq!(
+            decode_event::<WithdrawalsOnlyChangedEvent>(&blob).err(),
+            Some(EventDecodeError::DiscriminatorMismatch)
+        );
+    }
+
+    #[test]
+    fn test_decode_rejects_bad_frames() {
+        let event: PoolPauseChangedEvent = patterned();
+        let mut bytes = event.to_versioned_event_bytes();
+
+        // Truncated
+        assert_eq!(
+            decode_event::<PoolPauseChangedEvent>(&bytes[..bytes.len() - 1]).err(),
+            Some(EventDecodeError::InvalidLength)
+        );
+        assert_eq!(
+            decode_event::<PoolPauseChangedEvent>(&bytes[..4]).err(),
+            Some(EventDecodeError::TooShort)
+        );
+
+        // Newer schema than this decoder knows
+        bytes[8] = PoolPauseChangedEvent::CURRENT_VERSION + 1;
+        assert_eq!(
+            decode_event::<PoolPauseChangedEvent>(&bytes).err(),
+            Some(EventDecodeError::UnsupportedVersion(
+                PoolPauseChangedEvent::CURRENT_VERSION + 1
+            ))
+        );
+
+        // Non-zero reserved bytes
+        bytes[8] = PoolPauseChangedEvent::CURRENT_VERSION;
+        bytes[9] = 1;
+        assert_eq!(
+            decode_event::<PoolPauseChangedEvent>(&bytes).err(),
+            Some(EventDecodeError::InvalidFrame)
+        );
+    }
+}
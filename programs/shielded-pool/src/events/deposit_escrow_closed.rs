@@ -1,6 +1,6 @@
 //! Deposit escrow closed event definition.
 
-use super::EventType;
+use super::{EventSchema, EventType};
 use panchor::prelude::*;
 use pinocchio::pubkey::Pubkey;
 
@@ -28,12 +28,21 @@ pub struct DepositEscrowClosedEvent {
     pub depositor: Pubkey,
     /// Escrow PDA address.
     pub escrow: Pubkey,
-    /// Token mint.
+    /// Token mint (WSOL for native SOL escrows).
     pub mint: Pubkey,
-    /// Amount of tokens returned to depositor.
+    /// Amount of tokens (or lamports for native SOL escrows) returned to depositor.
     pub amount_returned: u64,
     /// Escrow nonce (for correlation with creation event).
     pub nonce: u64,
-    /// Rent lamports reclaimed.
+    /// Rent lamports reclaimed from the escrow PDA.
     pub lamports_reclaimed: u64,
+    /// Escrowed asset (`DepositEscrowAssetKind`: 0 = token, 1 = native SOL).
+    pub asset_kind: u8,
+    /// Padding for alignment.
+    pub _padding: [u8; 7],
+}
+
+impl EventSchema for DepositEscrowClosedEvent {
+    /// Version 2 added `asset_kind`.
+    const CURRENT_VERSION: u8 = 2;
 }
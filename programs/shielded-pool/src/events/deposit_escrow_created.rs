@@ -1,6 +1,6 @@
 //! Deposit escrow created event definition.
 
-use super::EventType;
+use super::{EventSchema, EventType};
 use panchor::prelude::*;
 use pinocchio::pubkey::Pubkey;
 
@@ -23,7 +23,7 @@ pub struct DepositEscrowCreatedEvent {
     pub depositor: Pubkey,
     /// Escrow PDA address.
     pub escrow: Pubkey,
-    /// Token mint of escrowed tokens.
+    /// Token mint of escrowed tokens (WSOL for native SOL escrows).
     pub mint: Pubkey,
     /// SHA256 of transact session (binds escrow to proof).
     pub proof_hash: [u8; 32],
@@ -33,6 +33,15 @@ pub struct DepositEscrowCreatedEvent {
     pub expiry_slot: u64,
     /// Escrow nonce (for multiple concurrent escrows).
     pub nonce: u64,
-    /// Amount of tokens escrowed.
+    /// Amount of tokens (or lamports for native SOL escrows) escrowed.
     pub amount: u64,
+    /// Escrowed asset (`DepositEscrowAssetKind`: 0 = token, 1 = native SOL).
+    pub asset_kind: u8,
+    /// Padding for alignment.
+    pub _padding: [u8; 7],
+}
+
+impl EventSchema for DepositEscrowCreatedEvent {
+    /// Version 2 added `asset_kind`.
+    const CURRENT_VERSION: u8 = 2;
 }
@@ -0,0 +1,38 @@
+//! Deposit escrow relayer updated event definition.
+
+use super::{EventSchema, EventType};
+use panchor::prelude::*;
+use pinocchio::pubkey::Pubkey;
+
+/// Event emitted when a depositor changes an escrow's authorized relayer.
+///
+/// This event is emitted after a successful `update_deposit_escrow_relayer`
+/// instruction. From then on only `new_relayer` (or, if it is zero, any
+/// relayer) can consume the escrow.
+///
+/// # Usage by Indexers
+///
+/// 1. Update the escrow's relayer binding
+/// 2. Notify the old relayer to drop any queued execution
+#[event(EventType::DepositEscrowRelayerUpdated)]
+#[repr(C)]
+pub struct DepositEscrowRelayerUpdatedEvent {
+    /// Depositor who changed the relayer.
+    pub depositor: Pubkey,
+    /// Escrow PDA address.
+    pub escrow: Pubkey,
+    /// Previously authorized relayer ([0;32] = any relayer).
+    pub old_relayer: Pubkey,
+    /// Newly authorized relayer ([0;32] = any relayer).
+    pub new_relayer: Pubkey,
+    /// Escrow nonce (for correlation with creation event).
+    pub nonce: u64,
+    /// Escrow `relayer_revision` after the change.
+    pub relayer_revision: u8,
+    /// Padding for alignment.
+    pub _padding: [u8; 7],
+}
+
+impl EventSchema for DepositEscrowRelayerUpdatedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
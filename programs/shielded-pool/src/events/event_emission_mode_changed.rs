@@ -0,0 +1,29 @@
+//! Event emission mode changed event definition.
+
+use super::{EventSchema, EventType};
+use panchor::prelude::*;
+use pinocchio::pubkey::Pubkey;
+
+/// Event emitted when GlobalConfig's event emission mode is changed.
+///
+/// This event is emitted after a successful `set_event_emission_mode`
+/// instruction. It is written in the *previous* mode, so an indexer following
+/// the old encoding always sees the switch; every later event uses `new_mode`.
+#[event(EventType::EventEmissionModeChanged)]
+#[repr(C)]
+pub struct EventEmissionModeChangedEvent {
+    /// Authority who changed the mode.
+    pub authority: Pubkey,
+    /// Mode before the change (0 = self-CPI, 1 = log data).
+    pub old_mode: u8,
+    /// Mode after the change (0 = self-CPI, 1 = log data).
+    pub new_mode: u8,
+    /// Padding for 8-byte alignment.
+    pub _padding: [u8; 6],
+    /// Slot when the mode changed.
+    pub slot: u64,
+}
+
+impl EventSchema for EventEmissionModeChangedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
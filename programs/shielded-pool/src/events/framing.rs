@@ -0,0 +1,90 @@
+//! Versioned event framing.
+//!
+//! Every event emitted by the shielded pool is framed as:
+//!
+//! ```text
+//! [discriminator: 8 bytes (u64 LE)][schema_version: 1 byte][reserved: 7 bytes][payload...]
+//! ```
+//!
+//! The `schema_version` is the payload layout version of that event type
+//! ([`EventSchema::CURRENT_VERSION`]). Indexers branch on it when a layout
+//! changes. The reserved bytes are zero and keep the payload 8-byte aligned.
+//!
+//! Events emitted before versioning used `[discriminator][payload]` with no
+//! version byte. They are decoded as [`LEGACY_SCHEMA_VERSION`].
+
+use alloc::vec::Vec;
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::EventBytes;
+
+/// Size of the event frame header: discriminator (8) + schema_version (1) + reserved (7).
+pub const EVENT_FRAME_HEADER_SIZE: usize = 16;
+
+/// Schema version reported for events in the pre-version framing.
+pub const LEGACY_SCHEMA_VERSION: u8 = 0;
+
+/// Frame header written before every event payload.
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+pub struct EventFrameHeader {
+    /// Event type discriminator ([`super::EventType`])
+    pub discriminator: u64,
+    /// Payload layout version of this event type
+    pub schema_version: u8,
+    /// Reserved for future framing flags (zero)
+    pub _reserved: [u8; 7],
+}
+
+impl EventFrameHeader {
+    /// Create a frame header for the given discriminator and version.
+    pub const fn new(discriminator: u64, schema_version: u8) -> Self {
+        Self {
+            discriminator,
+            schema_version,
+            _reserved: [0u8; 7],
+        }
+    }
+
+    /// Append the frame header to `bytes`.
+    pub fn write_to(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(bytemuck::bytes_of(self));
+    }
+}
+
+/// Payload layout version of an event type.
+///
+/// Bump `CURRENT_VERSION` whenever the event's payload layout changes.
+pub trait EventSchema {
+    /// Version written into the frame of every emitted event of this type.
+    const CURRENT_VERSION: u8;
+}
+
+/// Versioned serialization for fixed-size (Pod) events.
+pub trait VersionedEventBytes: EventBytes + EventSchema {
+    /// Serialize the event with the versioned frame header.
+    ///
+    /// Returns: `[discriminator: 8][schema_version: 1][reserved: 7][payload]`
+    fn to_versioned_event_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(EVENT_FRAME_HEADER_SIZE + core::mem::size_of::<Self>());
+        EventFrameHeader::new(Self::DISCRIMINATOR, Self::CURRENT_VERSION).write_to(&mut bytes);
+        bytes.extend_from_slice(bytemuck::bytes_of(self));
+        bytes
+    }
+}
+
+impl<T: EventBytes + EventSchema> VersionedEventBytes for T {}
+
+/// Split versioned event bytes into `(schema_version, payload)`.
+///
+/// Returns `None` if `data` is shorter than the frame header or its
+/// discriminator is not `discriminator`.
+pub fn split_event_frame(data: &[u8], discriminator: u64) -> Option<(u8, &[u8])> {
+    if data.len() < EVENT_FRAME_HEADER_SIZE {
+        return None;
+    }
+    let header: EventFrameHeader = bytemuck::pod_read_unaligned(&data[..EVENT_FRAME_HEADER_SIZE]);
+    if header.discriminator != discriminator {
+        return None;
+    }
+    Some((header.schema_version, &data[EVENT_FRAME_HEADER_SIZE..]))
+}
@@ -0,0 +1,30 @@
+//! Global config migrated event definition.
+
+use super::{EventSchema, EventType};
+use panchor::prelude::*;
+use pinocchio::pubkey::Pubkey;
+
+/// Event emitted when GlobalConfig is migrated to a newer layout version.
+///
+/// This event is emitted after a successful `migrate_global_config`
+/// instruction. Fields added by the migration hold their defaults.
+#[event(EventType::GlobalConfigMigrated)]
+#[repr(C)]
+pub struct GlobalConfigMigratedEvent {
+    /// Authority who ran the migration.
+    pub authority: Pubkey,
+    /// Layout version before the migration.
+    pub old_version: u16,
+    /// Layout version after the migration.
+    pub new_version: u16,
+    /// Padding for 8-byte alignment.
+    pub _padding: [u8; 4],
+    /// Account size in bytes after the migration.
+    pub new_size: u64,
+    /// Slot when the migration ran.
+    pub slot: u64,
+}
+
+impl EventSchema for GlobalConfigMigratedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
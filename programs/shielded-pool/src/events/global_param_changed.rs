@@ -0,0 +1,34 @@
+//! Global parameter changed event definition.
+
+use super::{EventSchema, EventType};
+use panchor::prelude::*;
+use pinocchio::pubkey::Pubkey;
+
+/// Event emitted for each tunable parameter changed by `set_global_params`.
+///
+/// Parameters whose value is unchanged by the batch do not emit an event.
+///
+/// # Security Considerations
+///
+/// Raising `MaxRelayerFeeBps` or lowering `MaxRootAge` changes what users'
+/// pending proofs will be accepted with; alert on changes.
+#[event(EventType::GlobalParamChanged)]
+#[repr(C)]
+pub struct GlobalParamChangedEvent {
+    /// Signer who changed the parameter (authority or fee_admin).
+    pub admin: Pubkey,
+    /// Parameter id (see `GlobalParam`).
+    pub param_id: u16,
+    /// Padding for 8-byte alignment.
+    pub _padding: [u8; 6],
+    /// Previous value.
+    pub old_value: u64,
+    /// New value.
+    pub new_value: u64,
+    /// Slot when the parameter changed.
+    pub slot: u64,
+}
+
+impl EventSchema for GlobalParamChangedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
@@ -1,14 +1,16 @@
 //! Event definitions for the shielded pool program.
 //!
 //! Events are emitted via self-invocation of the Log instruction,
-//! which allows event data to be recorded in transaction logs without truncation.
+//! which allows event data to be recorded in transaction logs without truncation,
+//! or logged directly with `sol_log_data` (see [Encodings](#encodings)).
 //!
 //! # Event Types
 //!
 //! ## Core Events (1-15)
 //! - [`NewCommitmentEvent`] - Emitted when a new commitment is added to the tree
+//! - [`NewCommitmentChunkEvent`] - Carries a chunk of a large commitment encrypted output
 //! - [`NewNullifierEvent`] - Emitted when a nullifier is created (spent note)
-//! - [`NewReceiptEvent`] - Emitted when a transaction receipt is recorded
+//! - [`NewReceiptEvent`] - Emitted when a transaction receipt is recorded (with relayer and fee breakdown)
 //! - [`NullifierBatchInsertedEvent`] - Emitted when nullifiers are batch inserted into indexed tree
 //! - [`NullifierLeafInsertedEvent`] - Emitted per-nullifier with full leaf data during batch insert
 //! - [`NullifierPdaClosedEvent`] - Emitted when a nullifier PDA is closed (GC)
@@ -19,17 +21,33 @@
 //! ## Transfer/Escrow Events (16-31)
 //! - [`DepositEscrowCreatedEvent`] - Emitted when a deposit escrow is created
 //! - [`DepositEscrowClosedEvent`] - Emitted when a deposit escrow is closed
+//! - [`DepositEscrowRelayerUpdatedEvent`] - Emitted when a depositor changes an escrow's relayer
 //!
 //! ## State Change Events (32-47)
-//! - Reserved for future use (e.g., TransactSession events)
+//! - [`TransactSessionCreatedEvent`] - Emitted when a transact session is created
+//! - [`TransactChunkUploadedEvent`] - Emitted per chunk uploaded to a transact session
+//! - [`TransactSessionClosedEvent`] - Emitted when a transact session is closed
 //!
 //! ## Admin Events (48-63)
 //! - [`PoolRegisteredEvent`] - Emitted when a pool is registered with the hub
 //! - [`AuthorityTransferInitiatedEvent`] - Emitted when authority transfer begins
 //! - [`AuthorityTransferCompletedEvent`] - Emitted when authority transfer completes
+//! - [`AuthorityTransferCancelledEvent`] - Emitted when a pending authority transfer is cancelled
+//! - [`AuthorityTransferDelayChangedEvent`] - Emitted when the authority transfer delay changes
 //! - [`PoolPauseChangedEvent`] - Emitted when pool paused state changes
 //! - [`PoolConfigActiveChangedEvent`] - Emitted when pool config active state changes
+//! - [`PoolConfigModeChangedEvent`] - Emitted when pool config deposit/withdrawal mode changes
+//! - [`WithdrawalsOnlyChangedEvent`] - Emitted when hub-wide withdrawal-only mode changes
+//! - [`EventEmissionModeChangedEvent`] - Emitted when the event encoding is switched
 //! - [`PoolInitializedEvent`] - Emitted when pool is initialized
+//! - [`RoleChangedEvent`] - Emitted when a delegated admin role is assigned or cleared
+//! - [`PoolDeregisteredEvent`] - Emitted when a pool is deregistered from the hub
+//! - [`PoolConfigClosedEvent`] - Emitted when a deregistered pool config is closed
+//! - [`GlobalParamChangedEvent`] - Emitted per tunable parameter changed by SetGlobalParams
+//! - [`GlobalConfigMigratedEvent`] - Emitted when GlobalConfig is migrated to a new layout version
+//!
+//! ## Admin Events, continued (64-79)
+//! - [`StaleAccumulatorPolicyChangedEvent`] - Emitted when the one-epoch-stale accumulator policy changes
 //!
 //! # Event Pattern
 //!
@@ -38,11 +56,40 @@
 //! - Implements `Event` trait for event metadata
 //! - Derives `Pod` and `Zeroable` for zero-copy serialization
 //!
-//! Events are emitted via CPI to the Log instruction with a PDA as signer to
-//! ensure only valid program invocations can emit events.
+//! Each event type also implements [`EventSchema`], whose `CURRENT_VERSION` is
+//! written into the versioned frame (see [`framing`]).
+//!
+//! # Encodings
+//!
+//! `GlobalConfig.event_emission_mode` selects how events reach the logs. The
+//! event bytes (`[discriminator: u64 LE][schema_version: u8][reserved: 7][payload]`)
+//! are identical in both modes:
+//!
+//! - **Self-CPI** (`EventEmissionMode::SelfCpi`, default): the program invokes
+//!   its own `Log` instruction with a program PDA as signer. Indexers read the
+//!   inner instruction data `[Log disc (33)][len: u32 LE][event bytes]` of an
+//!   inner instruction whose program id is this program. The `Log` handler
+//!   also writes the event bytes as a `Program data:` entry one level deeper.
+//! - **Log data** (`EventEmissionMode::LogData`): the executing instruction
+//!   writes the event bytes directly with `sol_log_data`, saving the CPI.
+//!   Indexers read the base64 `Program data:` entry and must check that it
+//!   was logged inside an invocation frame of this program.
+//!
+//! Events signed by PDAs other than `global_config` (deposit escrow events)
+//! always use self-CPI. TransactSession events (32-34) are always logged
+//! directly via [`log_event`]: the session instructions carry no GlobalConfig
+//! or program account, and chunk uploads are frequent enough that the CPI cost
+//! matters. The mode change event itself is emitted in the old mode.
+//! The `Log` instruction remains available in both modes.
+
+// Framing and decoding
+pub mod framing;
+#[cfg(any(test, feature = "solana-sdk"))]
+pub mod decode;
 
 // Core events
 mod new_commitment;
+mod new_commitment_chunk;
 mod new_nullifier;
 mod new_receipt;
 mod nullifier_batch_inserted;
@@ -55,20 +102,46 @@ mod nullifier_leaf_inserted;
 // Transfer/Escrow events
 mod deposit_escrow_closed;
 mod deposit_escrow_created;
+mod deposit_escrow_relayer_updated;
+
+// State change events
+mod transact_chunk_uploaded;
+mod transact_session_closed;
+mod transact_session_created;
 
 // Admin events
+mod authority_transfer_cancelled;
 mod authority_transfer_completed;
+mod authority_transfer_delay_changed;
 mod authority_transfer_initiated;
+mod event_emission_mode_changed;
+mod global_config_migrated;
+mod global_param_changed;
 mod pool_config_active_changed;
+mod pool_config_closed;
+mod pool_config_mode_changed;
+mod pool_deregistered;
 mod pool_initialized;
 mod pool_paused;
 mod pool_registered;
+mod role_changed;
+mod stale_accumulator_policy_changed;
+mod withdrawals_only_changed;
+
+pub use framing::*;
 
+pub use authority_transfer_cancelled::*;
 pub use authority_transfer_completed::*;
+pub use authority_transfer_delay_changed::*;
 pub use authority_transfer_initiated::*;
+pub use event_emission_mode_changed::*;
+pub use global_config_migrated::*;
+pub use global_param_changed::*;
 pub use deposit_escrow_closed::*;
 pub use deposit_escrow_created::*;
+pub use deposit_escrow_relayer_updated::*;
 pub use new_commitment::*;
+pub use new_commitment_chunk::*;
 pub use new_nullifier::*;
 pub use new_receipt::*;
 pub use nullifier_batch_inserted::*;
@@ -78,9 +151,18 @@ pub use nullifier_pda_closed::*;
 pub use nullifier_epoch_root_closed::*;
 pub use nullifier_leaf_inserted::*;
 pub use pool_config_active_changed::*;
+pub use pool_config_closed::*;
+pub use pool_config_mode_changed::*;
+pub use pool_deregistered::*;
 pub use pool_initialized::*;
 pub use pool_paused::*;
 pub use pool_registered::*;
+pub use role_changed::*;
+pub use stale_accumulator_policy_changed::*;
+pub use transact_chunk_uploaded::*;
+pub use transact_session_closed::*;
+pub use transact_session_created::*;
+pub use withdrawals_only_changed::*;
 
 use alloc::vec::Vec;
 use panchor::prelude::*;
@@ -93,6 +175,7 @@ use pinocchio::{
 
 use crate::ID;
 use crate::instructions::ShieldedPoolInstruction;
+use crate::state::{EventEmissionMode, GlobalConfigHeader};
 
 /// Event type discriminators for identifying event types in logs.
 ///
@@ -104,6 +187,7 @@ use crate::instructions::ShieldedPoolInstruction;
 /// - **16-31**: Transfer events (escrow operations)
 /// - **32-47**: State change events (session management)
 /// - **48-63**: Admin events (protocol administration)
+/// - **64-79**: Admin events, continued
 #[repr(u64)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, strum::IntoStaticStr)]
 pub enum EventType {
@@ -129,7 +213,9 @@ pub enum EventType {
     NullifierPdaClosed = 9,
     /// Nullifier epoch root PDA closed and rent reclaimed (GC)
     NullifierEpochRootClosed = 10,
-    // Reserved: 11-15
+    /// Chunk of a commitment encrypted output too large to inline
+    NewCommitmentChunk = 11,
+    // Reserved: 12-15
 
     // =========================================================================
     // Transfer Events (16-31) - Escrow operations
@@ -138,12 +224,20 @@ pub enum EventType {
     DepositEscrowCreated = 16,
     /// Deposit escrow closed and tokens returned
     DepositEscrowClosed = 17,
-    // Reserved: 18-31
+    /// Deposit escrow's authorized relayer changed by the depositor
+    DepositEscrowRelayerUpdated = 18,
+    // Reserved: 19-31
 
     // =========================================================================
     // State Change Events (32-47) - Session management
     // =========================================================================
-    // Reserved: 32-47 (TransactSession events if needed)
+    /// Transact session created for chunked proof upload
+    TransactSessionCreated = 32,
+    /// Chunk uploaded to a transact session
+    TransactChunkUploaded = 33,
+    /// Transact session closed and rent refunded
+    TransactSessionClosed = 34,
+    // Reserved: 35-47
 
     // =========================================================================
     // Admin Events (48-63) - Protocol administration events
@@ -160,45 +254,109 @@ pub enum EventType {
     PoolConfigActiveChanged = 52,
     /// Pool initialized (genesis event)
     PoolInitialized = 53,
-    // Reserved: 54-63
+    /// Pending authority transfer cancelled by the current authority
+    AuthorityTransferCancelled = 54,
+    /// Authority transfer timelock changed
+    AuthorityTransferDelayChanged = 55,
+    /// Delegated admin role assigned or cleared
+    RoleChanged = 56,
+    /// Pool deregistered from the hub
+    PoolDeregistered = 57,
+    /// Deregistered pool config closed and rent reclaimed
+    PoolConfigClosed = 58,
+    /// Tunable global parameter changed
+    GlobalParamChanged = 59,
+    /// GlobalConfig migrated to a new layout version
+    GlobalConfigMigrated = 60,
+    /// Pool config deposit/withdrawal mode changed for an asset
+    PoolConfigModeChanged = 61,
+    /// Hub-wide withdrawal-only mode changed
+    WithdrawalsOnlyChanged = 62,
+    /// Event emission mode changed (emitted in the previous mode)
+    EventEmissionModeChanged = 63,
+
+    // =========================================================================
+    // Admin Events, continued (64-79)
+    // =========================================================================
+    /// One-epoch-stale token accumulator policy changed
+    StaleAccumulatorPolicyChanged = 64,
+    // Reserved: 65-79
 }
 
-/// Emit a panchor event via self-invocation of the Log instruction.
+/// Build the `Log` instruction data carrying `event_data`.
+///
+/// Format: `[Log discriminator (33)][data length: u32 LE][data...]`, i.e. the
+/// Borsh encoding of the Log instruction's `Vec<u8>` argument.
+pub fn build_log_instruction_data(event_data: &[u8]) -> Vec<u8> {
+    let mut instruction_data = Vec::with_capacity(1 + 4 + event_data.len());
+    instruction_data.push(ShieldedPoolInstruction::Log as u8);
+    instruction_data.extend_from_slice(&(event_data.len() as u32).to_le_bytes());
+    instruction_data.extend_from_slice(event_data);
+    instruction_data
+}
+
+/// Event emission mode configured on `global_config`.
+///
+/// Falls back to self-CPI when the account is not a readable GlobalConfig
+/// (e.g. a deposit escrow used as the event signer).
+#[inline]
+pub fn emission_mode_of(global_config: &AccountInfo) -> EventEmissionMode {
+    GlobalConfigHeader::load(global_config)
+        .map(|header| header.emission_mode())
+        .unwrap_or(EventEmissionMode::SelfCpi)
+}
+
+/// Emit a panchor event using the mode configured on `global_config`.
 ///
 /// This function:
-/// 1. Serializes the event using `EventBytes::to_event_bytes()` (Pod + discriminator)
-/// 2. Builds an instruction to invoke the Log handler
-/// 3. Invokes the Log instruction with the global config PDA as signer
+/// 1. Serializes the event with `VersionedEventBytes::to_versioned_event_bytes()`
+/// 2. Reads `event_emission_mode` from the global config header
+/// 3. Self-invokes the Log instruction, or logs the bytes directly
 ///
 /// # Arguments
 /// * `global_config` - The global config PDA account (used as signer)
 /// * `shielded_pool_program` - The shielded pool program account (required for self-CPI)
 /// * `signer` - Signer seeds for the global config PDA
-/// * `event` - The event to emit (must implement EventBytes)
+/// * `event` - The event to emit (must implement EventBytes and EventSchema)
 ///
 /// # Note
 /// The global config PDA signs the log instruction to ensure only valid program
 /// invocations can emit events.
-pub fn emit_event<T: EventBytes>(
+pub fn emit_event<T: VersionedEventBytes>(
+    global_config: &AccountInfo,
+    shielded_pool_program: &AccountInfo,
+    signer: Signer,
+    event: &T,
+) -> ProgramResult {
+    emit_event_with_mode(
+        emission_mode_of(global_config),
+        global_config,
+        shielded_pool_program,
+        signer,
+        event,
+    )
+}
+
+/// Emit a panchor event in an explicit [`EventEmissionMode`].
+///
+/// See [`emit_event`]. Used where the mode is already known, or where the
+/// event must use a different mode than the one currently stored.
+pub fn emit_event_with_mode<T: VersionedEventBytes>(
+    mode: EventEmissionMode,
     global_config: &AccountInfo,
     shielded_pool_program: &AccountInfo,
     signer: Signer,
     event: &T,
 ) -> ProgramResult {
-    use borsh::BorshSerialize;
+    // Serialize the event using Pod serialization with the versioned frame
+    let event_data = event.to_versioned_event_bytes();
 
-    // Serialize the event using Pod serialization with discriminator
-    let event_data = event.to_event_bytes();
+    if mode == EventEmissionMode::LogData {
+        pinocchio::log::sol_log_data(&[&event_data]);
+        return Ok(());
+    }
 
-    // Build instruction data: [Log discriminator (33), Borsh-serialized data length + data]
-    // Format: [discriminator, data_length (u32 le), data...]
-    let log_discriminator = ShieldedPoolInstruction::Log as u8;
-    let mut instruction_data = Vec::with_capacity(1 + 4 + event_data.len());
-    instruction_data.push(log_discriminator);
-    // Borsh-serialize the Vec<u8> data (length-prefixed)
-    event_data
-        .serialize(&mut instruction_data)
-        .map_err(|_| pinocchio::program_error::ProgramError::InvalidInstructionData)?;
+    let instruction_data = build_log_instruction_data(&event_data);
 
     // Build instruction for self-CPI to Log
     let instruction = Instruction {
@@ -213,3 +371,121 @@ pub fn emit_event<T: EventBytes>(
 
     Ok(())
 }
+
+/// Log a panchor event directly with `sol_log_data`, ignoring the configured mode.
+///
+/// Used by the TransactSession instructions, which have no GlobalConfig to
+/// sign a self-CPI or read the mode from.
+pub fn log_event<T: VersionedEventBytes>(event: &T) {
+    pinocchio::log::sol_log_data(&[&event.to_versioned_event_bytes()]);
+}
+
+/// Emit pre-serialized event bytes in the given [`EventEmissionMode`].
+///
+/// Used by `ExecuteTransact` for variable-length events (commitments, receipts)
+/// where the mode is read once from GlobalConfig.
+pub fn emit_event_data(
+    mode: EventEmissionMode,
+    authority: &AccountInfo,
+    shielded_pool_program: &AccountInfo,
+    signers: &[Signer],
+    event_data: Vec<u8>,
+) -> ProgramResult {
+    match mode {
+        EventEmissionMode::SelfCpi => {
+            crate::utils::emit_cpi_log(&ID, authority, shielded_pool_program, signers, event_data)
+        }
+        EventEmissionMode::LogData => {
+            pinocchio::log::sol_log_data(&[&event_data]);
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_instruction_data_wraps_event_bytes() {
+        let event = PoolPauseChangedEvent {
+            authority: [3u8; 32],
+            is_paused: 1,
+            _padding: [0u8; 7],
+            slot: 42,
+        };
+        let event_data = event.to_versioned_event_bytes();
+        let instruction_data = build_log_instruction_data(&event_data);
+
+        // Self-CPI payload == direct log payload after the Log header
+        assert_eq!(instruction_data[0], ShieldedPoolInstruction::Log as u8);
+        assert_eq!(
+            instruction_data[1..5],
+            (event_data.len() as u32).to_le_bytes()
+        );
+        assert_eq!(instruction_data[5..], event_data[..]);
+        assert_eq!(
+            event_data[..8],
+            (EventType::PoolPauseChanged as u64).to_le_bytes()
+        );
+    }
+
+    #[test]
+    fn test_versioned_frame_layout() {
+        let event = PoolPauseChangedEvent {
+            authority: [3u8; 32],
+            is_paused: 1,
+            _padding: [0u8; 7],
+            slot: 42,
+        };
+        let event_data = event.to_versioned_event_bytes();
+
+        // Version sits between the discriminator and the payload
+        assert_eq!(event_data[..8], event.to_event_bytes()[..8]);
+        assert_eq!(event_data[8], PoolPauseChangedEvent::CURRENT_VERSION);
+        assert_eq!(event_data[9..EVENT_FRAME_HEADER_SIZE], [0u8; 7]);
+        assert_eq!(
+            event_data[EVENT_FRAME_HEADER_SIZE..],
+            event.to_event_bytes()[8..]
+        );
+        assert_eq!(
+            core::mem::size_of::<EventFrameHeader>(),
+            EVENT_FRAME_HEADER_SIZE
+        );
+    }
+
+    #[test]
+    fn test_log_instruction_data_decodes_to_event() {
+        use panchor::events::LogFraming;
+
+        let event = PoolPauseChangedEvent {
+            authority: [3u8; 32],
+            is_paused: 1,
+            _padding: [0u8; 7],
+            slot: 42,
+        };
+        let instruction_data = build_log_instruction_data(&event.to_versioned_event_bytes());
+
+        // What the Log handler passes to sol_log_data
+        let payload = LogFraming::BorshVec
+            .payload(&instruction_data[1..])
+            .unwrap();
+        let decoded = decode::decode_event::<PoolPauseChangedEvent>(payload).unwrap();
+        assert_eq!(decoded.framing, decode::EventFraming::Versioned);
+        assert_eq!(
+            bytemuck::bytes_of(&decoded.event),
+            bytemuck::bytes_of(&event)
+        );
+    }
+
+    #[test]
+    fn test_log_instruction_data_matches_borsh() {
+        use borsh::BorshSerialize;
+
+        // Must stay decodable by the Log handler's Borsh Vec<u8> argument
+        let event_data: Vec<u8> = (0u8..100).collect();
+        let mut expected = alloc::vec![ShieldedPoolInstruction::Log as u8];
+        event_data.serialize(&mut expected).unwrap();
+        assert_eq!(build_log_instruction_data(&event_data), expected);
+    }
+}
@@ -6,19 +6,29 @@
 //! - Fixed-size header (Pod-compatible, zero-copy accessible)
 //! - Variable-length body (encrypted output data)
 //!
-//! Wire format: `[discriminator: 8 bytes][header: 48 bytes][encrypted_output: variable]`
+//! Wire format: `[frame: 16 bytes][header: 80 bytes][encrypted_output: variable]`,
+//! where the frame is the versioned event frame (see [`super::framing`]).
 //!
 //! This approach avoids wasting space on fixed-size buffers while maintaining
 //! zero-copy access to the header fields for efficient indexing.
+//!
+//! Encrypted outputs longer than [`MAX_INLINE_ENCRYPTED_OUTPUT_LEN`] are not
+//! inlined. The header's `total_chunks` is then non-zero, the body is empty,
+//! and the output follows as that many [`NewCommitmentChunkEvent`]s.
 
-use super::EventType;
+use super::{
+    COMMITMENT_CHUNK_SIZE, EVENT_FRAME_HEADER_SIZE, EventFrameHeader, EventSchema, EventType,
+    MAX_INLINE_ENCRYPTED_OUTPUT_LEN, NewCommitmentChunkEvent, NewCommitmentChunkHeader,
+    split_event_frame,
+};
 use alloc::vec::Vec;
 use borsh::{BorshDeserialize, BorshSerialize};
 use bytemuck::{Pod, Zeroable};
 
 /// Size of the fixed header portion of NewCommitmentEvent.
-/// Layout: index (8) + commitment (32) + encrypted_output_len (4) + _padding (4) = 48 bytes
-pub const NEW_COMMITMENT_HEADER_SIZE: usize = 48;
+/// Layout: leaf_index (8) + commitment (32) + new_root (32) + encrypted_output_len (4)
+/// + total_chunks (2) + _padding (2) = 80 bytes
+pub const NEW_COMMITMENT_HEADER_SIZE: usize = 80;
 
 /// Data for a commitment in the merkle tree.
 ///
@@ -41,21 +51,26 @@ pub struct CommitmentData {
 ///
 /// # Wire Format
 /// ```text
-/// [discriminator: 8 bytes][header: 48 bytes][encrypted_output: variable]
+/// [frame: 16 bytes][header: 80 bytes][encrypted_output: variable]
 ///                         ^^^^^^^^^^^^^^^^
 ///                         This struct
 /// ```
 #[derive(Clone, Copy, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct NewCommitmentHeader {
-    /// Index of this commitment in the tree
-    pub index: u64,
+    /// Leaf index of this commitment in the tree
+    pub leaf_index: u64,
     /// The commitment hash (32 bytes, big-endian)
     pub commitment: [u8; 32],
-    /// Length of the encrypted output data that follows
+    /// Commitment tree root after this commitment was appended
+    pub new_root: [u8; 32],
+    /// Length of the full encrypted output (inline or chunked)
     pub encrypted_output_len: u32,
+    /// Number of chunk events carrying the output (0 = inline body)
+    pub total_chunks: u16,
     /// Padding for 8-byte alignment
-    pub _padding: [u8; 4],
+    pub _padding: [u8; 2],
 }
 
 impl NewCommitmentHeader {
@@ -77,6 +92,10 @@ impl panchor::Event for NewCommitmentHeader {
     }
 }
 
+impl EventSchema for NewCommitmentHeader {
+    const CURRENT_VERSION: u8 = 1;
+}
+
 /// Event emitted when a new commitment is added to the commitment merkle tree.
 ///
 /// Commitments represent shielded notes in the pool. Each commitment is a
@@ -87,17 +106,23 @@ impl panchor::Event for NewCommitmentHeader {
 /// Uses a fixed-size header + variable-length body to efficiently handle
 /// encrypted outputs of any size without wasting space on fixed buffers.
 ///
-/// Wire format: `[discriminator: 8 bytes][header: 48 bytes][encrypted_output: variable]`
+/// Wire format: `[frame: 16 bytes][header: 80 bytes][encrypted_output: variable]`
+///
+/// The leaf index and new root let indexers rebuild the commitment tree
+/// without correlating receipt events.
 ///
 /// # Parsing
 ///
 /// ```ignore
 /// let discriminator = u64::from_le_bytes(data[0..8]);
-/// let header: &NewCommitmentHeader = bytemuck::from_bytes(&data[8..56]);
-/// let encrypted_output = &data[56..56 + header.encrypted_output_len as usize];
+/// let schema_version = data[8];
+/// let header: &NewCommitmentHeader = bytemuck::from_bytes(&data[16..96]);
+/// if header.total_chunks == 0 {
+///     let encrypted_output = &data[96..96 + header.encrypted_output_len as usize];
+/// }
 /// ```
 pub struct NewCommitmentEvent<'a> {
-    /// Fixed header containing index, commitment, and length
+    /// Fixed header containing leaf index, commitment, root, and length
     pub header: NewCommitmentHeader,
     /// Variable-length encrypted output data (borrowed)
     pub encrypted_output: &'a [u8],
@@ -105,13 +130,29 @@ pub struct NewCommitmentEvent<'a> {
 
 impl<'a> NewCommitmentEvent<'a> {
     /// Create a new commitment event.
-    pub fn new(index: u64, commitment: [u8; 32], encrypted_output: &'a [u8]) -> Self {
+    ///
+    /// Outputs longer than [`MAX_INLINE_ENCRYPTED_OUTPUT_LEN`] are marked for
+    /// chunking; emit [`Self::chunks`] after the event itself.
+    pub fn new(
+        leaf_index: u64,
+        commitment: [u8; 32],
+        new_root: [u8; 32],
+        encrypted_output: &'a [u8],
+    ) -> Self {
+        let total_chunks = if encrypted_output.len() > MAX_INLINE_ENCRYPTED_OUTPUT_LEN {
+            encrypted_output.len().div_ceil(COMMITMENT_CHUNK_SIZE) as u16
+        } else {
+            0
+        };
+
         Self {
             header: NewCommitmentHeader {
-                index,
+                leaf_index,
                 commitment,
+                new_root,
                 encrypted_output_len: encrypted_output.len() as u32,
-                _padding: [0u8; 4],
+                total_chunks,
+                _padding: [0u8; 2],
             },
             encrypted_output,
         }
@@ -119,26 +160,77 @@ impl<'a> NewCommitmentEvent<'a> {
 
     /// Serialize the event to bytes with discriminator prepended.
     ///
-    /// Returns: `[discriminator: 8 bytes][header: 48 bytes][encrypted_output: variable]`
+    /// Returns: `[frame: 16 bytes][header: 80 bytes][encrypted_output: variable]`.
+    /// The body is empty when the output is chunked.
     pub fn to_event_bytes(&self) -> Vec<u8> {
-        let total_size = 8 + NEW_COMMITMENT_HEADER_SIZE + self.encrypted_output.len();
+        let body = self.inline_output();
+        let total_size = EVENT_FRAME_HEADER_SIZE + NEW_COMMITMENT_HEADER_SIZE + body.len();
         let mut bytes = Vec::with_capacity(total_size);
 
-        // Discriminator (8 bytes, little-endian)
-        bytes.extend_from_slice(&NewCommitmentHeader::DISCRIMINATOR.to_le_bytes());
+        // Frame: discriminator + schema version (16 bytes)
+        EventFrameHeader::new(
+            NewCommitmentHeader::DISCRIMINATOR,
+            NewCommitmentHeader::CURRENT_VERSION,
+        )
+        .write_to(&mut bytes);
 
-        // Header (48 bytes, Pod serialization)
+        // Header (80 bytes, Pod serialization)
         bytes.extend_from_slice(bytemuck::bytes_of(&self.header));
 
         // Variable-length body
-        bytes.extend_from_slice(self.encrypted_output);
+        bytes.extend_from_slice(body);
 
         bytes
     }
 
-    /// Get the commitment index.
-    pub fn index(&self) -> u64 {
-        self.header.index
+    /// Encrypted output bytes carried inline (empty when chunked).
+    fn inline_output(&self) -> &'a [u8] {
+        if self.is_chunked() {
+            &[]
+        } else {
+            self.encrypted_output
+        }
+    }
+
+    /// Whether the encrypted output is emitted as chunk events.
+    pub fn is_chunked(&self) -> bool {
+        self.header.total_chunks > 0
+    }
+
+    /// Chunk events carrying the encrypted output, in order.
+    ///
+    /// Empty when the output is inlined.
+    pub fn chunks(&self) -> impl Iterator<Item = NewCommitmentChunkEvent<'a>> {
+        let leaf_index = self.header.leaf_index;
+        let total_chunks = self.header.total_chunks;
+        let output = if self.is_chunked() {
+            self.encrypted_output
+        } else {
+            &[]
+        };
+
+        output
+            .chunks(COMMITMENT_CHUNK_SIZE)
+            .enumerate()
+            .map(move |(i, chunk)| NewCommitmentChunkEvent {
+                header: NewCommitmentChunkHeader {
+                    leaf_index,
+                    chunk_index: i as u16,
+                    total_chunks,
+                    chunk_len: chunk.len() as u32,
+                },
+                chunk,
+            })
+    }
+
+    /// Get the commitment leaf index.
+    pub fn leaf_index(&self) -> u64 {
+        self.header.leaf_index
+    }
+
+    /// Get the commitment tree root after the append.
+    pub fn new_root(&self) -> [u8; 32] {
+        self.header.new_root
     }
 
     /// Get the commitment hash.
@@ -150,34 +242,125 @@ impl<'a> NewCommitmentEvent<'a> {
 /// Parse a NewCommitmentEvent from raw event bytes.
 ///
 /// # Arguments
-/// * `data` - Raw event bytes including discriminator
+/// * `data` - Raw event bytes including the versioned frame
 ///
 /// # Returns
-/// * `Ok((header, encrypted_output))` - Parsed header and encrypted output slice
-/// * `Err(())` - If data is too short or discriminator doesn't match
+/// * `Ok((header, encrypted_output))` - Parsed header and inline encrypted output
+///   slice (empty when `header.total_chunks > 0`)
+/// * `Err(())` - If data is too short, the discriminator doesn't match, or the
+///   schema version is not [`NewCommitmentHeader::CURRENT_VERSION`]
 pub fn parse_new_commitment_event(data: &[u8]) -> Result<(NewCommitmentHeader, &[u8]), ()> {
-    const MIN_SIZE: usize = 8 + NEW_COMMITMENT_HEADER_SIZE;
-
-    if data.len() < MIN_SIZE {
-        return Err(());
-    }
+    const MIN_SIZE: usize = NEW_COMMITMENT_HEADER_SIZE;
 
-    // Check discriminator
-    let discriminator = u64::from_le_bytes(data[0..8].try_into().unwrap());
-    if discriminator != NewCommitmentHeader::DISCRIMINATOR {
+    // Check frame (discriminator and schema version)
+    let (schema_version, data) =
+        split_event_frame(data, NewCommitmentHeader::DISCRIMINATOR).ok_or(())?;
+    if schema_version != NewCommitmentHeader::CURRENT_VERSION || data.len() < MIN_SIZE {
         return Err(());
     }
 
-    // Parse header (zero-copy)
-    let header: &NewCommitmentHeader =
-        bytemuck::from_bytes(&data[8..8 + NEW_COMMITMENT_HEADER_SIZE]);
+    // Parse header
+    let header: NewCommitmentHeader = bytemuck::pod_read_unaligned(&data[..MIN_SIZE]);
 
-    // Extract encrypted output
-    let encrypted_output_end = MIN_SIZE + header.encrypted_output_len as usize;
+    // Extract encrypted output (only inlined when not chunked)
+    let inline_len = if header.total_chunks == 0 {
+        header.encrypted_output_len as usize
+    } else {
+        0
+    };
+    let encrypted_output_end = MIN_SIZE + inline_len;
     if data.len() < encrypted_output_end {
         return Err(());
     }
     let encrypted_output = &data[MIN_SIZE..encrypted_output_end];
 
-    Ok((*header, encrypted_output))
+    Ok((header, encrypted_output))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{NEW_COMMITMENT_CHUNK_HEADER_SIZE, parse_new_commitment_chunk_event};
+    use crate::state::MAX_SESSION_DATA_LEN;
+
+    #[test]
+    fn test_header_size() {
+        assert_eq!(
+            core::mem::size_of::<NewCommitmentHeader>(),
+            NEW_COMMITMENT_HEADER_SIZE
+        );
+    }
+
+    #[test]
+    fn test_small_output_inlined() {
+        let output = [7u8; MAX_INLINE_ENCRYPTED_OUTPUT_LEN];
+        let event = NewCommitmentEvent::new(5, [1u8; 32], [2u8; 32], &output);
+        assert!(!event.is_chunked());
+        assert_eq!(event.chunks().count(), 0);
+
+        let bytes = event.to_event_bytes();
+        let (header, body) = parse_new_commitment_event(&bytes).unwrap();
+        assert_eq!(header.leaf_index, 5);
+        assert_eq!(header.commitment, [1u8; 32]);
+        assert_eq!(header.new_root, [2u8; 32]);
+        assert_eq!(header.total_chunks, 0);
+        assert_eq!(body, &output[..]);
+    }
+
+    #[test]
+    fn test_max_size_output_round_trips_through_chunks() {
+        // A session can carry at most MAX_SESSION_DATA_LEN bytes of outputs
+        let output: Vec<u8> = (0..MAX_SESSION_DATA_LEN as usize)
+            .map(|i| (i % 251) as u8)
+            .collect();
+        let event = NewCommitmentEvent::new(42, [1u8; 32], [2u8; 32], &output);
+        assert!(event.is_chunked());
+
+        let bytes = event.to_event_bytes();
+        assert_eq!(
+            bytes.len(),
+            EVENT_FRAME_HEADER_SIZE + NEW_COMMITMENT_HEADER_SIZE
+        );
+        let (header, body) = parse_new_commitment_event(&bytes).unwrap();
+        assert!(body.is_empty());
+        assert_eq!(header.encrypted_output_len as usize, output.len());
+
+        // Reassemble from the serialized chunk events
+        let mut reassembled = Vec::new();
+        for (i, chunk_event) in event.chunks().enumerate() {
+            let chunk_bytes = chunk_event.to_event_bytes();
+            assert!(
+                chunk_bytes.len()
+                    <= EVENT_FRAME_HEADER_SIZE
+                        + NEW_COMMITMENT_CHUNK_HEADER_SIZE
+                        + COMMITMENT_CHUNK_SIZE
+            );
+
+            let (chunk_header, chunk) = parse_new_commitment_chunk_event(&chunk_bytes).unwrap();
+            assert_eq!(chunk_header.leaf_index, header.leaf_index);
+            assert_eq!(chunk_header.chunk_index as usize, i);
+            assert_eq!(chunk_header.total_chunks, header.total_chunks);
+            reassembled.extend_from_slice(chunk);
+        }
+        assert_eq!(event.chunks().count(), header.total_chunks as usize);
+        assert_eq!(reassembled, output);
+    }
+
+    #[test]
+    fn test_chunk_boundary() {
+        // One byte over the inline limit needs two chunks
+        let output = [9u8; MAX_INLINE_ENCRYPTED_OUTPUT_LEN + 1];
+        let event = NewCommitmentEvent::new(0, [0u8; 32], [0u8; 32], &output);
+        assert_eq!(event.header.total_chunks, 2);
+
+        let chunks: Vec<_> = event.chunks().collect();
+        assert_eq!(chunks[0].chunk.len(), COMMITMENT_CHUNK_SIZE);
+        assert_eq!(chunks[1].chunk.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_chunk_rejects_commitment_event() {
+        let event = NewCommitmentEvent::new(0, [0u8; 32], [0u8; 32], &[1, 2, 3]);
+        assert!(parse_new_commitment_chunk_event(&event.to_event_bytes()).is_err());
+    }
 }
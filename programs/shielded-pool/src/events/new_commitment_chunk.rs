@@ -0,0 +1,138 @@
+//! New commitment chunk event definition.
+//!
+//! # Event Format
+//!
+//! Carries one slice of an encrypted output that was too large to inline in
+//! its [`NewCommitmentEvent`](super::NewCommitmentEvent).
+//!
+//! Wire format: `[frame: 16 bytes][header: 16 bytes][chunk: variable]`, where the
+//! frame is the versioned event frame (see [`super::framing`]).
+//!
+//! Chunks are emitted in order right after the commitment event. Indexers
+//! concatenate `total_chunks` chunks for the same `leaf_index` by
+//! `chunk_index` to recover the full encrypted output.
+
+use super::{EVENT_FRAME_HEADER_SIZE, EventFrameHeader, EventSchema, EventType, split_event_frame};
+use alloc::vec::Vec;
+use bytemuck::{Pod, Zeroable};
+
+/// Size of the fixed header portion of NewCommitmentChunkEvent.
+/// Layout: leaf_index (8) + chunk_index (2) + total_chunks (2) + chunk_len (4) = 16 bytes
+pub const NEW_COMMITMENT_CHUNK_HEADER_SIZE: usize = 16;
+
+/// Largest encrypted output emitted inline in a NewCommitmentEvent.
+///
+/// Larger outputs are split into [`COMMITMENT_CHUNK_SIZE`] byte chunks so a
+/// single event never carries more than this many body bytes.
+pub const MAX_INLINE_ENCRYPTED_OUTPUT_LEN: usize = 512;
+
+/// Maximum encrypted output bytes carried by one chunk event.
+pub const COMMITMENT_CHUNK_SIZE: usize = 512;
+
+/// Fixed-size header for NewCommitmentChunkEvent.
+///
+/// # Wire Format
+/// ```text
+/// [frame: 16 bytes][header: 16 bytes][chunk: variable]
+///                         ^^^^^^^^^^^^^^^^
+///                         This struct
+/// ```
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct NewCommitmentChunkHeader {
+    /// Leaf index of the commitment this chunk belongs to
+    pub leaf_index: u64,
+    /// Position of this chunk (0-based)
+    pub chunk_index: u16,
+    /// Number of chunks for this commitment
+    pub total_chunks: u16,
+    /// Length of the chunk data that follows
+    pub chunk_len: u32,
+}
+
+impl NewCommitmentChunkHeader {
+    /// Event discriminator (EventType::NewCommitmentChunk = 11)
+    pub const DISCRIMINATOR: u64 = EventType::NewCommitmentChunk as u64;
+}
+
+impl panchor::Discriminator for NewCommitmentChunkHeader {
+    const DISCRIMINATOR: u64 = EventType::NewCommitmentChunk as u64;
+}
+
+impl panchor::Event for NewCommitmentChunkHeader {
+    fn name() -> &'static str {
+        "NewCommitmentChunk"
+    }
+}
+
+impl EventSchema for NewCommitmentChunkHeader {
+    const CURRENT_VERSION: u8 = 1;
+}
+
+/// Event carrying one chunk of a large encrypted output.
+///
+/// Wire format: `[frame: 16 bytes][header: 16 bytes][chunk: variable]`
+///
+/// # Parsing
+///
+/// ```ignore
+/// let (header, chunk) = parse_new_commitment_chunk_event(data)?;
+/// output[header.chunk_index as usize * COMMITMENT_CHUNK_SIZE..][..chunk.len()]
+///     .copy_from_slice(chunk);
+/// ```
+pub struct NewCommitmentChunkEvent<'a> {
+    /// Fixed header containing leaf index, chunk position, and length
+    pub header: NewCommitmentChunkHeader,
+    /// Chunk data (borrowed)
+    pub chunk: &'a [u8],
+}
+
+impl<'a> NewCommitmentChunkEvent<'a> {
+    /// Serialize the event to bytes with discriminator prepended.
+    ///
+    /// Returns: `[frame: 16 bytes][header: 16 bytes][chunk: variable]`
+    pub fn to_event_bytes(&self) -> Vec<u8> {
+        let total_size =
+            EVENT_FRAME_HEADER_SIZE + NEW_COMMITMENT_CHUNK_HEADER_SIZE + self.chunk.len();
+        let mut bytes = Vec::with_capacity(total_size);
+
+        EventFrameHeader::new(
+            NewCommitmentChunkHeader::DISCRIMINATOR,
+            NewCommitmentChunkHeader::CURRENT_VERSION,
+        )
+        .write_to(&mut bytes);
+        bytes.extend_from_slice(bytemuck::bytes_of(&self.header));
+        bytes.extend_from_slice(self.chunk);
+
+        bytes
+    }
+}
+
+/// Parse a NewCommitmentChunkEvent from raw event bytes.
+///
+/// # Returns
+/// * `Ok((header, chunk))` - Parsed header and chunk slice
+/// * `Err(())` - If data is too short, the discriminator doesn't match, or the
+///   schema version is not [`NewCommitmentChunkHeader::CURRENT_VERSION`]
+#[allow(clippy::result_unit_err)]
+pub fn parse_new_commitment_chunk_event(
+    data: &[u8],
+) -> Result<(NewCommitmentChunkHeader, &[u8]), ()> {
+    const MIN_SIZE: usize = NEW_COMMITMENT_CHUNK_HEADER_SIZE;
+
+    let (schema_version, data) =
+        split_event_frame(data, NewCommitmentChunkHeader::DISCRIMINATOR).ok_or(())?;
+    if schema_version != NewCommitmentChunkHeader::CURRENT_VERSION || data.len() < MIN_SIZE {
+        return Err(());
+    }
+
+    let header: NewCommitmentChunkHeader = bytemuck::pod_read_unaligned(&data[..MIN_SIZE]);
+
+    let chunk_end = MIN_SIZE + header.chunk_len as usize;
+    if data.len() < chunk_end {
+        return Err(());
+    }
+
+    Ok((header, &data[MIN_SIZE..chunk_end]))
+}
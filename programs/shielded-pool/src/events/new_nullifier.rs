@@ -1,6 +1,6 @@
 //! New nullifier event definition.
 
-use super::EventType;
+use super::{EventSchema, EventType};
 use panchor::prelude::*;
 
 /// Event emitted when a new nullifier is created (input note spent).
@@ -25,3 +25,7 @@ pub struct NewNullifierEvent {
     /// local state for proof generation.
     pub pending_index: u64,
 }
+
+impl EventSchema for NewNullifierEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
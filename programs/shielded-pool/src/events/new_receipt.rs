@@ -1,10 +1,11 @@
 //! New receipt event definition.
 
-use super::EventType;
-use crate::instructions::types::{N_INS, N_OUTS, N_PUBLIC_LINES};
+use super::{EVENT_FRAME_HEADER_SIZE, EventFrameHeader, EventSchema, EventType};
+use crate::instructions::types::{N_INS, N_OUTS, N_PUBLIC_LINES, TransactParams};
 use alloc::vec::Vec;
 use borsh::BorshSerialize;
 use panchor::prelude::*;
+use pinocchio::pubkey::Pubkey;
 
 /// Current receipt format version.
 /// Increment this when the Receipt struct layout changes.
@@ -66,25 +67,61 @@ impl Receipt {
     }
 }
 
+/// Size of [`NewReceiptEventHeader`] in bytes.
+pub const NEW_RECEIPT_EVENT_HEADER_SIZE: usize = core::mem::size_of::<NewReceiptEventHeader>();
+
 /// Event header for NewReceiptEvent.
 ///
 /// This is the fixed-size header that precedes the serialized Receipt data.
-/// The full event format is:
+/// The full event format (schema version 2) is:
 /// ```text
 /// [discriminator: 8 bytes (u64 LE)]
+/// [schema_version: 1 byte][reserved: 7 bytes]
 /// [receipt_index: 8 bytes (u64 LE)]
 /// [receipt_hash: 32 bytes]
+/// [relayer: 32 bytes]
+/// [fees: N_PUBLIC_LINES x u64 LE]
+/// [relayer_fees: N_PUBLIC_LINES x u64 LE]
+/// [ext_amounts_abs: N_PUBLIC_LINES x u64 LE]
 /// [receipt_data: variable (Borsh-serialized Receipt)]
 /// ```
 ///
-/// Indexers can verify data integrity: `receipt_hash == sha256(receipt_data)`
+/// Schema version 1 events end the header after `receipt_hash`.
+///
+/// Indexers can verify data integrity: `receipt_hash == sha256(receipt_data)`.
+/// The relayer and fee fields are copied from the transaction's `TransactParams`
+/// (as validated against the proof and pool fee rates) for analytics; they are
+/// not part of the receipt hash preimage.
 #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct NewReceiptEventHeader {
     /// Index of this receipt in the receipt merkle tree
     pub receipt_index: u64,
     /// The computed receipt leaf hash (SHA256 of Borsh-serialized Receipt)
     pub receipt_hash: [u8; 32],
+    /// Relayer wallet that submitted the transaction (zero if none)
+    pub relayer: Pubkey,
+    /// Protocol fee per public line in token base units
+    pub fees: [u64; N_PUBLIC_LINES],
+    /// Relayer fee per public line in token base units
+    pub relayer_fees: [u64; N_PUBLIC_LINES],
+    /// `|ext_amount|` per public line (volume crossing the pool boundary)
+    pub ext_amounts_abs: [u64; N_PUBLIC_LINES],
+}
+
+impl NewReceiptEventHeader {
+    /// Build the header for a receipt from the transaction's params.
+    pub fn new(receipt_index: u64, receipt_hash: [u8; 32], params: &TransactParams) -> Self {
+        Self {
+            receipt_index,
+            receipt_hash,
+            relayer: params.relayer,
+            fees: params.fees,
+            relayer_fees: params.relayer_fees,
+            ext_amounts_abs: params.ext_amounts.map(i64::unsigned_abs),
+        }
+    }
 }
 
 /// Marker struct for NewReceiptEvent discriminator and event name.
@@ -102,31 +139,39 @@ impl panchor::Event for NewReceiptEvent {
     }
 }
 
+impl EventSchema for NewReceiptEvent {
+    /// Version 2 added the relayer and fee breakdown to the header.
+    const CURRENT_VERSION: u8 = 2;
+}
+
 /// Build the complete NewReceiptEvent bytes for emission.
 ///
 /// Format:
 /// - discriminator (8 bytes): EventType::NewReceipt as u64 LE
-/// - receipt_index (8 bytes): u64 LE
-/// - receipt_hash (32 bytes): SHA256 of receipt_data
+/// - schema_version (1 byte) + reserved (7 bytes): versioned event frame
+/// - header ([`NEW_RECEIPT_EVENT_HEADER_SIZE`] bytes): [`NewReceiptEventHeader`]
 /// - receipt_data (variable): Borsh-serialized Receipt
 ///
 /// Indexers verify: `receipt_hash == sha256(receipt_data)`
 pub fn build_new_receipt_event_bytes(
-    receipt_index: u64,
-    receipt_hash: [u8; 32],
+    header: &NewReceiptEventHeader,
     receipt: &Receipt,
 ) -> Result<Vec<u8>, pinocchio::program_error::ProgramError> {
     let receipt_data = receipt.to_bytes()?;
 
-    // Pre-allocate: discriminator(8) + receipt_index(8) + receipt_hash(32) + receipt_data
-    let mut bytes = Vec::with_capacity(8 + 8 + 32 + receipt_data.len());
+    // Pre-allocate: frame(16) + header + receipt_data
+    let mut bytes = Vec::with_capacity(
+        EVENT_FRAME_HEADER_SIZE + NEW_RECEIPT_EVENT_HEADER_SIZE + receipt_data.len(),
+    );
 
-    // Discriminator
-    bytes.extend_from_slice(&NewReceiptEvent::DISCRIMINATOR.to_le_bytes());
-    // Receipt index
-    bytes.extend_from_slice(&receipt_index.to_le_bytes());
-    // Receipt hash
-    bytes.extend_from_slice(&receipt_hash);
+    // Frame: discriminator + schema version
+    EventFrameHeader::new(
+        NewReceiptEvent::DISCRIMINATOR,
+        NewReceiptEvent::CURRENT_VERSION,
+    )
+    .write_to(&mut bytes);
+    // Receipt index, hash, relayer and fee breakdown
+    bytes.extend_from_slice(bytemuck::bytes_of(header));
     // Serialized receipt data
     bytes.extend_from_slice(&receipt_data);
 
@@ -1,6 +1,6 @@
 //! Nullifier batch inserted event definition.
 
-use super::EventType;
+use super::{EventSchema, EventType};
 use panchor::prelude::*;
 
 /// Event emitted when nullifiers are batch inserted into the indexed merkle tree.
@@ -30,3 +30,7 @@ pub struct NullifierBatchInsertedEvent {
     /// Padding for 8-byte alignment
     pub _padding: [u8; 7],
 }
+
+impl EventSchema for NullifierBatchInsertedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
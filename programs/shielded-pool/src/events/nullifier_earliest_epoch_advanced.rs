@@ -1,6 +1,6 @@
 //! Nullifier earliest epoch advanced event definition.
 
-use super::EventType;
+use super::{EventSchema, EventType};
 use panchor::prelude::*;
 
 /// Event emitted when the nullifier tree's earliest provable epoch is advanced.
@@ -22,3 +22,7 @@ pub struct NullifierEarliestEpochAdvancedEvent {
     /// New earliest provable epoch
     pub new_epoch: u64,
 }
+
+impl EventSchema for NullifierEarliestEpochAdvancedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
@@ -1,6 +1,6 @@
 //! Nullifier epoch advanced event definition.
 
-use super::EventType;
+use super::{EventSchema, EventType};
 use panchor::prelude::*;
 
 /// Event emitted when the nullifier epoch is advanced.
@@ -25,3 +25,7 @@ pub struct NullifierEpochAdvancedEvent {
     /// Last tree index included in this nullifier epoch
     pub finalized_index: u64,
 }
+
+impl EventSchema for NullifierEpochAdvancedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
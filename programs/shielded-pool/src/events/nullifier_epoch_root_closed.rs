@@ -1,6 +1,6 @@
 //! Nullifier epoch root closed event definition.
 
-use super::EventType;
+use super::{EventSchema, EventType};
 use panchor::prelude::*;
 
 /// Event emitted when a nullifier epoch root PDA is closed and rent reclaimed.
@@ -26,3 +26,7 @@ pub struct NullifierEpochRootClosedEvent {
     /// Lamports reclaimed from the closed account
     pub reclaimed_lamports: u64,
 }
+
+impl EventSchema for NullifierEpochRootClosedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
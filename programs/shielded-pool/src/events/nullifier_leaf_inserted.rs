@@ -1,6 +1,6 @@
 //! Nullifier leaf inserted event definition.
 
-use super::EventType;
+use super::{EventSchema, EventType};
 use panchor::prelude::*;
 
 /// Event emitted for each nullifier inserted during ZK batch insertion.
@@ -26,3 +26,7 @@ pub struct NullifierLeafInsertedEvent {
     /// Epoch when this nullifier was inserted into the indexed tree
     pub inserted_epoch: u64,
 }
+
+impl EventSchema for NullifierLeafInsertedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
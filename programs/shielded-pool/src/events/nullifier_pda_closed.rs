@@ -1,6 +1,6 @@
 //! Nullifier closed event definition.
 
-use super::EventType;
+use super::{EventSchema, EventType};
 use panchor::prelude::*;
 use pinocchio::pubkey::Pubkey;
 
@@ -35,3 +35,7 @@ pub struct NullifierPdaClosedEvent {
     /// Lamports reclaimed from the closed account
     pub reclaimed_lamports: u64,
 }
+
+impl EventSchema for NullifierPdaClosedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
@@ -1,6 +1,6 @@
 //! Pool config active changed event definition.
 
-use super::EventType;
+use super::{EventSchema, EventType};
 use panchor::prelude::*;
 use pinocchio::pubkey::Pubkey;
 
@@ -27,3 +27,7 @@ pub struct PoolConfigActiveChangedEvent {
     /// Padding for 8-byte alignment.
     pub _padding: [u8; 7],
 }
+
+impl EventSchema for PoolConfigActiveChangedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
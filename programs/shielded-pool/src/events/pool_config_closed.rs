@@ -0,0 +1,26 @@
+//! Pool config closed event definition.
+
+use super::{EventSchema, EventType};
+use panchor::prelude::*;
+use pinocchio::pubkey::Pubkey;
+
+/// Event emitted when a deregistered pool's `PoolConfig` is closed.
+///
+/// This event is emitted after a successful `close_pool_config` instruction.
+/// The PDA no longer exists, so the asset can be registered again.
+#[event(EventType::PoolConfigClosed)]
+#[repr(C)]
+pub struct PoolConfigClosedEvent {
+    /// The PoolConfig PDA that was closed.
+    pub pool_config: Pubkey,
+    /// Asset ID of the closed config.
+    pub asset_id: [u8; 32],
+    /// Lamports reclaimed to the authority.
+    pub reclaimed_lamports: u64,
+    /// Slot when the config was closed.
+    pub slot: u64,
+}
+
+impl EventSchema for PoolConfigClosedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
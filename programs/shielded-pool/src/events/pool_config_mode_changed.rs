@@ -0,0 +1,33 @@
+//! Pool config mode changed event definition.
+
+use super::{EventSchema, EventType};
+use panchor::prelude::*;
+use pinocchio::pubkey::Pubkey;
+
+/// Event emitted when a pool config's direction mode is changed.
+///
+/// This event is emitted after a successful `set_pool_config_mode` instruction,
+/// which enables or disables deposits and withdrawals for a specific asset.
+///
+/// # Usage by Indexers
+///
+/// 1. Track per-direction asset availability
+/// 2. Alert on deposit-only / withdrawal-only incident modes
+#[event(EventType::PoolConfigModeChanged)]
+#[repr(C)]
+pub struct PoolConfigModeChangedEvent {
+    /// Authority who changed the mode.
+    pub authority: Pubkey,
+    /// Asset ID affected.
+    pub asset_id: [u8; 32],
+    /// Mode before the change (`POOL_MODE_*` bits).
+    pub old_mode: u8,
+    /// Mode after the change (`POOL_MODE_*` bits).
+    pub new_mode: u8,
+    /// Padding for 8-byte alignment.
+    pub _padding: [u8; 6],
+}
+
+impl EventSchema for PoolConfigModeChangedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
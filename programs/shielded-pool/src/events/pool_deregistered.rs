@@ -0,0 +1,33 @@
+//! Pool deregistered event definition.
+
+use super::{EventSchema, EventType};
+use panchor::prelude::*;
+use pinocchio::pubkey::Pubkey;
+
+/// Event emitted when a pool is deregistered from the hub.
+///
+/// This event is emitted after a successful `deregister_pool` instruction.
+/// From this point the asset can no longer be routed by `execute_transact`;
+/// the `PoolConfig` account stays in place until `closable_at_slot`, after
+/// which `close_pool_config` may remove it.
+///
+/// # Usage by Indexers
+///
+/// 1. Remove the asset from the list of supported assets
+/// 2. Schedule the PoolConfig for removal at `closable_at_slot`
+#[event(EventType::PoolDeregistered)]
+#[repr(C)]
+pub struct PoolDeregisteredEvent {
+    /// Signer who deregistered the pool (authority or registrar).
+    pub authority: Pubkey,
+    /// Asset ID that was deregistered.
+    pub asset_id: [u8; 32],
+    /// First slot at which the PoolConfig may be closed.
+    pub closable_at_slot: u64,
+    /// Slot when the pool was deregistered.
+    pub slot: u64,
+}
+
+impl EventSchema for PoolDeregisteredEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
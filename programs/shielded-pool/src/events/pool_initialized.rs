@@ -1,6 +1,6 @@
 //! Pool initialized event definition.
 
-use super::EventType;
+use super::{EventSchema, EventType};
 use panchor::prelude::*;
 use pinocchio::pubkey::Pubkey;
 
@@ -39,3 +39,7 @@ pub struct PoolInitializedEvent {
     /// Genesis slot.
     pub slot: u64,
 }
+
+impl EventSchema for PoolInitializedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
@@ -1,6 +1,6 @@
 //! Pool pause changed event definition.
 
-use super::EventType;
+use super::{EventSchema, EventType};
 use panchor::prelude::*;
 use pinocchio::pubkey::Pubkey;
 
@@ -35,3 +35,7 @@ pub struct PoolPauseChangedEvent {
     /// Slot when state changed.
     pub slot: u64,
 }
+
+impl EventSchema for PoolPauseChangedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
@@ -1,6 +1,6 @@
 //! Pool registered event definition.
 
-use super::EventType;
+use super::{EventSchema, EventType};
 use panchor::prelude::*;
 use pinocchio::pubkey::Pubkey;
 
@@ -27,3 +27,7 @@ pub struct PoolRegisteredEvent {
     /// The pool program ID
     pub pool_program: Pubkey,
 }
+
+impl EventSchema for PoolRegisteredEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
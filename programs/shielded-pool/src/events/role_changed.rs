@@ -0,0 +1,36 @@
+//! Admin role changed event definition.
+
+use super::{EventSchema, EventType};
+use panchor::prelude::*;
+use pinocchio::pubkey::Pubkey;
+
+/// Event emitted when a delegated admin role is assigned or cleared.
+///
+/// This event is emitted after a successful `set_role` instruction. A zero
+/// `new_holder` means the role was cleared and its capability is back to
+/// authority-only.
+///
+/// # Security Considerations
+///
+/// Role holders can pause the pool, change fees, or register pools without
+/// the authority key. Alert on any assignment.
+#[event(EventType::RoleChanged)]
+#[repr(C)]
+pub struct RoleChangedEvent {
+    /// Authority who changed the role.
+    pub authority: Pubkey,
+    /// Previous role holder (zero if unset).
+    pub old_holder: Pubkey,
+    /// New role holder (zero if cleared).
+    pub new_holder: Pubkey,
+    /// Role id (see `AdminRole`).
+    pub role: u8,
+    /// Padding for 8-byte alignment.
+    pub _padding: [u8; 7],
+    /// Slot when role changed.
+    pub slot: u64,
+}
+
+impl EventSchema for RoleChangedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
@@ -0,0 +1,27 @@
+//! Stale accumulator policy changed event definition.
+
+use super::{EventSchema, EventType};
+use panchor::prelude::*;
+use pinocchio::pubkey::Pubkey;
+
+/// Event emitted when GlobalConfig's one-epoch-stale accumulator policy changes.
+///
+/// This event is emitted after a successful `set_allow_one_epoch_stale`
+/// instruction. While enabled, `ExecuteTransact` also accepts a token pool's
+/// previous reward accumulator shortly after that pool finalizes.
+#[event(EventType::StaleAccumulatorPolicyChanged)]
+#[repr(C)]
+pub struct StaleAccumulatorPolicyChangedEvent {
+    /// Authority who changed the policy.
+    pub authority: Pubkey,
+    /// New state: 1 = previous accumulator accepted, 0 = current only.
+    pub allow_one_epoch_stale: u8,
+    /// Padding for 8-byte alignment.
+    pub _padding: [u8; 7],
+    /// Slot when the policy changed.
+    pub slot: u64,
+}
+
+impl EventSchema for StaleAccumulatorPolicyChangedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
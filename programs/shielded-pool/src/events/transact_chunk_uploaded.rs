@@ -0,0 +1,34 @@
+//! Transact chunk uploaded event definition.
+
+use super::{EventSchema, EventType};
+use panchor::prelude::*;
+use pinocchio::pubkey::Pubkey;
+
+/// Event emitted for every chunk written by `upload_transact_chunk`.
+///
+/// Uploads are frequent, so this event is always logged directly with
+/// `sol_log_data` and carries no slot.
+///
+/// # Usage by Indexers
+///
+/// Compare `bytes_received_total` against `data_len` to track upload
+/// progress. Overlapping writes are allowed, so the total counts every
+/// uploaded byte and may exceed `data_len`.
+#[event(EventType::TransactChunkUploaded)]
+#[repr(C)]
+pub struct TransactChunkUploadedEvent {
+    /// Transact session PDA address.
+    pub session: Pubkey,
+    /// Byte offset of the chunk within the session body.
+    pub offset: u32,
+    /// Chunk length in bytes.
+    pub len: u32,
+    /// Bytes uploaded to the session so far, including this chunk.
+    pub bytes_received_total: u32,
+    /// Expected body length in bytes.
+    pub data_len: u32,
+}
+
+impl EventSchema for TransactChunkUploadedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
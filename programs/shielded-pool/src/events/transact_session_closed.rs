@@ -0,0 +1,66 @@
+//! Transact session closed event definition.
+
+use super::{EventSchema, EventType};
+use panchor::prelude::*;
+use pinocchio::pubkey::Pubkey;
+
+/// Why a transact session was closed.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransactSessionCloseReason {
+    /// The session was consumed by a successful `execute_transact`
+    Executed = 0,
+    /// The session was closed after `SESSION_EXPIRY_SLOTS` without executing
+    Expired = 1,
+    /// The authority closed the session before expiry without executing
+    Cancelled = 2,
+}
+
+impl TransactSessionCloseReason {
+    /// Convert from u8, returning None for invalid values.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(TransactSessionCloseReason::Executed),
+            1 => Some(TransactSessionCloseReason::Expired),
+            2 => Some(TransactSessionCloseReason::Cancelled),
+            _ => None,
+        }
+    }
+}
+
+/// Event emitted when a transact session is closed.
+///
+/// Emitted by `close_transact_session` after the session lamports have been
+/// moved to the closer. Logged directly with `sol_log_data` (see the module docs).
+///
+/// # Usage by Indexers
+///
+/// 1. Stop tracking the session
+/// 2. Track abandoned uploads (`Expired` / `Cancelled`)
+#[event(EventType::TransactSessionClosed)]
+#[repr(C)]
+pub struct TransactSessionClosedEvent {
+    /// Transact session PDA address.
+    pub session: Pubkey,
+    /// Account the rent was refunded to (the closer).
+    pub rent_refunded_to: Pubkey,
+    /// Rent lamports refunded.
+    pub lamports_refunded: u64,
+    /// Close reason (see [`TransactSessionCloseReason`]).
+    pub reason: u8,
+    /// Padding for alignment.
+    pub _padding: [u8; 7],
+    /// Slot when the session was closed.
+    pub slot: u64,
+}
+
+impl TransactSessionClosedEvent {
+    /// Decoded close reason, or None for unknown values.
+    pub fn close_reason(&self) -> Option<TransactSessionCloseReason> {
+        TransactSessionCloseReason::from_u8(self.reason)
+    }
+}
+
+impl EventSchema for TransactSessionClosedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
@@ -0,0 +1,39 @@
+//! Transact session created event definition.
+
+use super::{EventSchema, EventType};
+use panchor::prelude::*;
+use pinocchio::pubkey::Pubkey;
+
+/// Event emitted when a transact session is created.
+///
+/// Emitted by `init_transact_session` after the session PDA has been
+/// allocated. Logged directly with `sol_log_data` (see the module docs).
+///
+/// # Usage by Indexers
+///
+/// 1. Start tracking upload progress for the session
+/// 2. Schedule cleanup of sessions left open past `expiry_slot`
+#[event(EventType::TransactSessionCreated)]
+#[repr(C)]
+pub struct TransactSessionCreatedEvent {
+    /// Transact session PDA address.
+    pub session: Pubkey,
+    /// Session authority, which also paid the rent.
+    pub payer: Pubkey,
+    /// Session nonce (PDA seed).
+    pub nonce: u64,
+    /// Slot from which anyone may close the session.
+    pub expiry_slot: u64,
+    /// Expected body length in bytes.
+    pub data_len: u32,
+    /// Proof point encoding of the body (see `ProofFormat`).
+    pub proof_format: u8,
+    /// Padding for alignment.
+    pub _padding: [u8; 3],
+    /// Slot when the session was created.
+    pub slot: u64,
+}
+
+impl EventSchema for TransactSessionCreatedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
@@ -0,0 +1,32 @@
+//! Withdrawals-only mode changed event definition.
+
+use super::{EventSchema, EventType};
+use panchor::prelude::*;
+use pinocchio::pubkey::Pubkey;
+
+/// Event emitted when the hub-wide withdrawal-only mode is changed.
+///
+/// This event is emitted after a successful `set_withdrawals_only` instruction.
+/// While enabled, deposits and deposit escrow creation are rejected for every
+/// asset; withdrawals and private transfers continue.
+///
+/// # Usage by Indexers
+///
+/// 1. Track whether the hub accepts deposits
+/// 2. Alert on emergency withdrawal-only activations
+#[event(EventType::WithdrawalsOnlyChanged)]
+#[repr(C)]
+pub struct WithdrawalsOnlyChangedEvent {
+    /// Authority who changed the state.
+    pub authority: Pubkey,
+    /// New state: 1 = withdrawals only, 0 = deposits allowed.
+    pub withdrawals_only: u8,
+    /// Padding for 8-byte alignment.
+    pub _padding: [u8; 7],
+    /// Slot when state changed.
+    pub slot: u64,
+}
+
+impl EventSchema for WithdrawalsOnlyChangedEvent {
+    const CURRENT_VERSION: u8 = 1;
+}
@@ -1,5 +1,7 @@
 use crate::errors::Groth16Error;
+use ark_bn254::{Fq, Fq2, G1Affine, G2Affine};
 use ark_ff::PrimeField;
+use ark_serialize::CanonicalDeserialize;
 use num_bigint::BigUint;
 use solana_bn254::prelude::{
     alt_bn128_g1_addition_be, alt_bn128_g1_multiplication_be, alt_bn128_pairing_be,
@@ -27,6 +29,34 @@ pub struct CompressedGroth16Proof<'a> {
     pub proof_c: &'a [u8; 32],
 }
 
+/// Raw uncompressed Groth16 proof elements.
+///
+/// Some prover stacks (e.g. rapidsnark deployments) emit uncompressed points.
+/// Accepting them directly avoids client-side recompression, which is easy to
+/// get wrong (the y-coordinate sign bit). Unlike the compressed path, these
+/// bytes are untrusted curve coordinates and are validated before use.
+#[derive(Debug, Clone, Copy)]
+pub struct UncompressedGroth16Proof<'a> {
+    /// G1 point, uncompressed (64 bytes, big-endian `x || y`)
+    pub proof_a: &'a [u8; 64],
+    /// G2 point, uncompressed (128 bytes, big-endian `x.c1 || x.c0 || y.c1 || y.c0`)
+    pub proof_b: &'a [u8; 128],
+    /// G1 point, uncompressed (64 bytes, big-endian `x || y`)
+    pub proof_c: &'a [u8; 64],
+}
+
+/// Groth16 proof elements in either supported encoding.
+#[derive(Debug, Clone, Copy)]
+pub enum Groth16ProofPoints<'a> {
+    /// Compressed points (32-byte G1, 64-byte G2)
+    Compressed(CompressedGroth16Proof<'a>),
+    /// Uncompressed points (64-byte G1, 128-byte G2)
+    Uncompressed(UncompressedGroth16Proof<'a>),
+}
+
+/// Uncompressed, verifier-ready proof points: (-proof_a, proof_b, proof_c).
+pub type PreparedProofPoints = ([u8; 64], [u8; 128], [u8; 64]);
+
 #[derive(PartialEq, Eq, Debug)]
 pub struct Groth16Verifyingkey<'a> {
     pub nr_pubinputs: usize,
@@ -169,7 +199,7 @@ fn negate_y(y: &[u8; 32]) -> [u8; 32] {
 #[inline(never)]
 pub fn decompress_and_negate_proof(
     compressed: &CompressedGroth16Proof,
-) -> Result<([u8; 64], [u8; 128], [u8; 64]), Groth16Error> {
+) -> Result<PreparedProofPoints, Groth16Error> {
     // Decompress proof_a (G1 point, 32 bytes compressed -> 64 bytes uncompressed)
     let proof_a_decompressed =
         alt_bn128_g1_decompress(compressed.proof_a).map_err(|_| Groth16Error::InvalidG1)?;
@@ -197,6 +227,85 @@ pub fn decompress_and_negate_proof(
     Ok((proof_a_neg, proof_b, proof_c))
 }
 
+/// Reverse each 32-byte limb of a big-endian buffer into little-endian order.
+fn be_limbs_to_le<const N: usize>(bytes: &[u8; N]) -> [u8; N] {
+    let mut out = *bytes;
+    for start in (0..N).step_by(32) {
+        out[start..start + 32].reverse();
+    }
+    out
+}
+
+/// Check a big-endian uncompressed G1 point is canonical and on the curve.
+///
+/// Coordinates must be canonical base field elements (< p). The all-zero
+/// encoding (point at infinity) is rejected: it never appears in an honestly
+/// generated proof.
+fn validate_g1_be(bytes: &[u8; 64]) -> Result<(), Groth16Error> {
+    if *bytes == [0u8; 64] {
+        return Err(Groth16Error::InvalidG1);
+    }
+    let le = be_limbs_to_le(bytes);
+    let x = Fq::deserialize_uncompressed(&le[..32]).map_err(|_| Groth16Error::InvalidG1)?;
+    let y = Fq::deserialize_uncompressed(&le[32..]).map_err(|_| Groth16Error::InvalidG1)?;
+
+    let point = G1Affine::new_unchecked(x, y);
+    // G1 has cofactor 1, so on-curve implies correct subgroup
+    if !point.is_on_curve() {
+        return Err(Groth16Error::InvalidG1);
+    }
+    Ok(())
+}
+
+/// Check a big-endian uncompressed G2 point is canonical, on the curve, and in the subgroup.
+///
+/// The BN254 twist has a non-trivial cofactor, so an on-curve point is not
+/// necessarily in the prime-order subgroup; both checks are required.
+fn validate_g2_be(bytes: &[u8; 128]) -> Result<(), Groth16Error> {
+    if *bytes == [0u8; 128] {
+        return Err(Groth16Error::InvalidG2);
+    }
+    // Big-endian layout is c1 || c0 per coordinate; ark expects c0 then c1
+    let le = be_limbs_to_le(bytes);
+    let fq = |limb: &[u8]| Fq::deserialize_uncompressed(limb).map_err(|_| Groth16Error::InvalidG2);
+    let x = Fq2::new(fq(&le[32..64])?, fq(&le[..32])?);
+    let y = Fq2::new(fq(&le[96..128])?, fq(&le[64..96])?);
+
+    let point = G2Affine::new_unchecked(x, y);
+    if !point.is_on_curve() {
+        return Err(Groth16Error::InvalidG2);
+    }
+    if !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(Groth16Error::InvalidG2Subgroup);
+    }
+    Ok(())
+}
+
+/// Validates an uncompressed Groth16 proof and negates proof_a for verification.
+///
+/// Counterpart of [`decompress_and_negate_proof`] for uncompressed input:
+/// 1. Checks proof_a and proof_c are canonical, on-curve G1 points
+/// 2. Checks proof_b is a canonical, on-curve G2 point in the r-torsion subgroup
+/// 3. Negates proof_a: (x, y) → (x, -y)
+///
+/// Returns (proof_a_neg, proof_b, proof_c) in the same format as the compressed path.
+#[inline(never)]
+pub fn validate_and_negate_uncompressed_proof(
+    uncompressed: &UncompressedGroth16Proof,
+) -> Result<PreparedProofPoints, Groth16Error> {
+    validate_g1_be(uncompressed.proof_a)?;
+    validate_g2_be(uncompressed.proof_b)?;
+    validate_g1_be(uncompressed.proof_c)?;
+
+    let mut proof_a_neg = *uncompressed.proof_a;
+    let y: [u8; 32] = uncompressed.proof_a[32..64]
+        .try_into()
+        .map_err(|_| Groth16Error::InvalidG1)?;
+    proof_a_neg[32..64].copy_from_slice(&negate_y(&y));
+
+    Ok((proof_a_neg, *uncompressed.proof_b, *uncompressed.proof_c))
+}
+
 /// Verifies a Groth16 proof with the given public inputs and verifying key.
 ///
 /// This is the shared verification function used by all proof types.
@@ -226,6 +335,44 @@ pub fn verify_groth16<const N: usize>(
     verifier.verify()
 }
 
+/// Verifies an uncompressed Groth16 proof with the given public inputs and verifying key.
+///
+/// Same as [`verify_groth16`], but takes uncompressed points which are checked
+/// for curve and subgroup membership before being passed to the pairing.
+///
+/// # Returns
+/// * `Ok(true)` if the proof is valid
+/// * `Err(Groth16Error)` if the points are malformed or verification fails
+#[inline(never)]
+pub fn verify_groth16_uncompressed<const N: usize>(
+    uncompressed: &UncompressedGroth16Proof,
+    public_inputs: &[[u8; 32]; N],
+    vk: &Groth16Verifyingkey,
+) -> Result<bool, Groth16Error> {
+    let (proof_a, proof_b, proof_c) = validate_and_negate_uncompressed_proof(uncompressed)?;
+
+    let mut verifier = Groth16Verifier::new(&proof_a, &proof_b, &proof_c, public_inputs, vk)?;
+
+    verifier.verify()
+}
+
+/// Verifies a Groth16 proof in either encoding.
+///
+/// Dispatches to [`verify_groth16`] or [`verify_groth16_uncompressed`].
+#[inline(never)]
+pub fn verify_groth16_points<const N: usize>(
+    points: &Groth16ProofPoints,
+    public_inputs: &[[u8; 32]; N],
+    vk: &Groth16Verifyingkey,
+) -> Result<bool, Groth16Error> {
+    match points {
+        Groth16ProofPoints::Compressed(compressed) => verify_groth16(compressed, public_inputs, vk),
+        Groth16ProofPoints::Uncompressed(uncompressed) => {
+            verify_groth16_uncompressed(uncompressed, public_inputs, vk)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -307,4 +454,175 @@ mod tests {
             "Fr modulus + 1 should NOT be < Fr modulus"
         );
     }
+
+    // ------------------------------------------------------------------------
+    // Uncompressed proof validation
+    // ------------------------------------------------------------------------
+
+    use ark_bn254::g2::{G2_GENERATOR_X, G2_GENERATOR_Y};
+    use ark_ff::Field;
+    use ark_serialize::CanonicalSerialize;
+    use solana_bn254::compression::prelude::{alt_bn128_g1_compress, alt_bn128_g2_compress};
+
+    fn fq_to_be(f: &Fq) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        f.serialize_uncompressed(&mut out[..]).unwrap();
+        out.reverse();
+        out
+    }
+
+    fn g1_to_be(x: &Fq, y: &Fq) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        out[..32].copy_from_slice(&fq_to_be(x));
+        out[32..].copy_from_slice(&fq_to_be(y));
+        out
+    }
+
+    fn g2_to_be(x: &Fq2, y: &Fq2) -> [u8; 128] {
+        let mut out = [0u8; 128];
+        out[..32].copy_from_slice(&fq_to_be(&x.c1));
+        out[32..64].copy_from_slice(&fq_to_be(&x.c0));
+        out[64..96].copy_from_slice(&fq_to_be(&y.c1));
+        out[96..].copy_from_slice(&fq_to_be(&y.c0));
+        out
+    }
+
+    fn g1_generator_be() -> [u8; 64] {
+        g1_to_be(&Fq::from(1u64), &Fq::from(2u64))
+    }
+
+    fn g2_generator_be() -> [u8; 128] {
+        g2_to_be(&G2_GENERATOR_X, &G2_GENERATOR_Y)
+    }
+
+    /// Find a point on the BN254 twist that lies outside the r-torsion subgroup.
+    fn g2_point_outside_subgroup() -> [u8; 128] {
+        // b' = y_gen^2 - x_gen^3 (avoids depending on the curve config trait)
+        let b = G2_GENERATOR_Y.square() - G2_GENERATOR_X.square() * G2_GENERATOR_X;
+        for i in 1u64..1000 {
+            let x = Fq2::new(Fq::from(i), Fq::from(0u64));
+            let rhs = x.square() * x + b;
+            if let Some(y) = rhs.sqrt() {
+                let point = G2Affine::new_unchecked(x, y);
+                assert!(point.is_on_curve());
+                if !point.is_in_correct_subgroup_assuming_on_curve() {
+                    return g2_to_be(&x, &y);
+                }
+            }
+        }
+        panic!("no non-subgroup twist point found");
+    }
+
+    #[test]
+    fn test_uncompressed_matches_compressed_path() {
+        let a = g1_generator_be();
+        let b = g2_generator_be();
+        let c = g1_generator_be();
+
+        let a_compressed = alt_bn128_g1_compress(&a).unwrap();
+        let b_compressed = alt_bn128_g2_compress(&b).unwrap();
+        let c_compressed = alt_bn128_g1_compress(&c).unwrap();
+
+        let from_compressed = decompress_and_negate_proof(&CompressedGroth16Proof {
+            proof_a: &a_compressed,
+            proof_b: &b_compressed,
+            proof_c: &c_compressed,
+        })
+        .unwrap();
+        let from_uncompressed = validate_and_negate_uncompressed_proof(&UncompressedGroth16Proof {
+            proof_a: &a,
+            proof_b: &b,
+            proof_c: &c,
+        })
+        .unwrap();
+
+        assert_eq!(from_compressed, from_uncompressed);
+    }
+
+    #[test]
+    fn test_uncompressed_rejects_off_curve_g1() {
+        // (1, 3) is not on y^2 = x^3 + 3
+        let off_curve = g1_to_be(&Fq::from(1u64), &Fq::from(3u64));
+        let b = g2_generator_be();
+        let c = g1_generator_be();
+
+        let result = validate_and_negate_uncompressed_proof(&UncompressedGroth16Proof {
+            proof_a: &off_curve,
+            proof_b: &b,
+            proof_c: &c,
+        });
+        assert_eq!(result.unwrap_err(), Groth16Error::InvalidG1);
+
+        let a = g1_generator_be();
+        let result = validate_and_negate_uncompressed_proof(&UncompressedGroth16Proof {
+            proof_a: &a,
+            proof_b: &b,
+            proof_c: &off_curve,
+        });
+        assert_eq!(result.unwrap_err(), Groth16Error::InvalidG1);
+    }
+
+    #[test]
+    fn test_uncompressed_rejects_off_curve_g2() {
+        let off_curve = g2_to_be(&G2_GENERATOR_X, &(G2_GENERATOR_Y + Fq2::ONE));
+        let a = g1_generator_be();
+
+        let result = validate_and_negate_uncompressed_proof(&UncompressedGroth16Proof {
+            proof_a: &a,
+            proof_b: &off_curve,
+            proof_c: &a,
+        });
+        assert_eq!(result.unwrap_err(), Groth16Error::InvalidG2);
+    }
+
+    #[test]
+    fn test_uncompressed_rejects_wrong_subgroup_g2() {
+        let wrong_subgroup = g2_point_outside_subgroup();
+        let a = g1_generator_be();
+
+        let result = validate_and_negate_uncompressed_proof(&UncompressedGroth16Proof {
+            proof_a: &a,
+            proof_b: &wrong_subgroup,
+            proof_c: &a,
+        });
+        assert_eq!(result.unwrap_err(), Groth16Error::InvalidG2Subgroup);
+    }
+
+    #[test]
+    fn test_uncompressed_rejects_non_canonical_coordinate() {
+        // x = p aliases x = 0 but is not a canonical field element
+        let mut a = g1_generator_be();
+        a[..32].copy_from_slice(&BN254_FIELD_MODULUS);
+        let b = g2_generator_be();
+        let c = g1_generator_be();
+
+        let result = validate_and_negate_uncompressed_proof(&UncompressedGroth16Proof {
+            proof_a: &a,
+            proof_b: &b,
+            proof_c: &c,
+        });
+        assert_eq!(result.unwrap_err(), Groth16Error::InvalidG1);
+    }
+
+    #[test]
+    fn test_uncompressed_rejects_identity() {
+        let zero_g1 = [0u8; 64];
+        let zero_g2 = [0u8; 128];
+        let a = g1_generator_be();
+        let b = g2_generator_be();
+
+        let result = validate_and_negate_uncompressed_proof(&UncompressedGroth16Proof {
+            proof_a: &zero_g1,
+            proof_b: &b,
+            proof_c: &a,
+        });
+        assert_eq!(result.unwrap_err(), Groth16Error::InvalidG1);
+
+        let result = validate_and_negate_uncompressed_proof(&UncompressedGroth16Proof {
+            proof_a: &a,
+            proof_b: &zero_g2,
+            proof_c: &a,
+        });
+        assert_eq!(result.unwrap_err(), Groth16Error::InvalidG2);
+    }
 }
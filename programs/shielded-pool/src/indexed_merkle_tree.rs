@@ -15,8 +15,8 @@
 //! - `zero_hashes[i] = Poseidon(zero_hashes[i-1], zero_hashes[i-1])`
 
 use crate::errors::ShieldedPoolError;
+use crate::poseidon::PoseidonBackend;
 use crate::state::{IndexedLeaf, NULLIFIER_TREE_HEIGHT, NullifierIndexedTree};
-use light_hasher::Hasher;
 use pinocchio::program_error::ProgramError;
 
 /// Pre-computed zero hashes for the indexed merkle tree (BIG-ENDIAN format).
@@ -25,7 +25,7 @@ use pinocchio::program_error::ProgramError;
 /// - `INDEXED_ZERO_HASHES[0]` = genesis leaf hash
 /// - `INDEXED_ZERO_HASHES[i]` = `Poseidon(INDEXED_ZERO_HASHES[i-1], INDEXED_ZERO_HASHES[i-1])`
 ///
-/// IMPORTANT: light_hasher::Poseidon uses big-endian byte order for inputs and outputs.
+/// IMPORTANT: Both Poseidon backends use big-endian byte order for inputs and outputs.
 /// These values must be in big-endian format to match the on-chain hash computation.
 pub const INDEXED_ZERO_HASHES: [[u8; 32]; NULLIFIER_TREE_HEIGHT as usize] = [
     // Level 0: 0x0bc188d27dcceadc1dcfb6af0a7af08fe2864eecec96c5ae7cee6db31ba599aa
@@ -193,7 +193,9 @@ impl IndexedMerkleTree {
     /// Compute the hash of an indexed leaf.
     ///
     /// Hash = Poseidon(value, next_index, next_value)
-    pub fn compute_leaf_hash<H: Hasher>(leaf: &IndexedLeaf) -> Result<[u8; 32], ProgramError> {
+    pub fn compute_leaf_hash<H: PoseidonBackend>(
+        leaf: &IndexedLeaf,
+    ) -> Result<[u8; 32], ProgramError> {
         // Convert next_index to 32 bytes (little-endian padded)
         let mut next_index_bytes = [0u8; 32];
         next_index_bytes[..8].copy_from_slice(&leaf.next_index.to_le_bytes());
@@ -222,7 +224,9 @@ impl IndexedMerkleTree {
     /// capacity of `2^height - 1` nullifiers.
     ///
     /// @see https://docs.aztec.network/developers/docs/foundational-topics/advanced/storage/indexed_merkle_tree
-    pub fn initialize<H: Hasher>(tree: &mut NullifierIndexedTree) -> Result<(), ProgramError> {
+    pub fn initialize<H: PoseidonBackend>(
+        tree: &mut NullifierIndexedTree,
+    ) -> Result<(), ProgramError> {
         let height = tree.height as usize;
 
         // Create genesis leaf and compute its hash
@@ -258,7 +262,7 @@ impl IndexedMerkleTree {
     /// Verify a merkle proof for a leaf at a given index.
     ///
     /// Returns the computed root if the proof is valid.
-    pub fn compute_root_from_proof<H: Hasher>(
+    pub fn compute_root_from_proof<H: PoseidonBackend>(
         leaf_hash: [u8; 32],
         index: u64,
         proof: &[[u8; 32]],
@@ -345,7 +349,7 @@ impl IndexedMerkleTree {
     /// 3. Updates the tree root (but NOT root_history)
     ///
     /// Returns the new root.
-    pub fn insert<H: Hasher>(
+    pub fn insert<H: PoseidonBackend>(
         tree: &mut NullifierIndexedTree,
         nullifier: &[u8; 32],
         low_nullifier_index: u64,
@@ -424,7 +428,7 @@ impl IndexedMerkleTree {
     /// Append a new leaf to the tree using subtree caching.
     ///
     /// This is similar to the regular merkle tree append but doesn't update root_history.
-    fn append_leaf<H: Hasher>(
+    fn append_leaf<H: PoseidonBackend>(
         tree: &mut NullifierIndexedTree,
         leaf_hash: [u8; 32],
         index: u64,
@@ -477,9 +481,20 @@ mod tests {
         }
     }
 
-    // Note: Poseidon hash tests require the "poseidon" feature in light-hasher
-    // which is only available for native builds. The on-chain program uses
-    // the Solana syscall instead. Run LiteSVM tests to validate the implementation.
+    #[test]
+    fn test_genesis_leaf_hash_matches_zero_hash_for_both_backends() {
+        use crate::poseidon::{LightPoseidon, SyscallPoseidon};
+
+        let genesis = IndexedLeaf::genesis();
+        assert_eq!(
+            IndexedMerkleTree::compute_leaf_hash::<LightPoseidon>(&genesis).unwrap(),
+            INDEXED_ZERO_HASHES[0]
+        );
+        assert_eq!(
+            IndexedMerkleTree::compute_leaf_hash::<SyscallPoseidon>(&genesis).unwrap(),
+            INDEXED_ZERO_HASHES[0]
+        );
+    }
 
     #[test]
     fn test_ordering_valid() {
@@ -1,7 +1,8 @@
 //! `AcceptAuthority` instruction handler.
 //!
 //! Completes the two-step authority transfer by accepting the pending authority role.
-//! Must be called by the `pending_authority` address.
+//! Must be called by the `pending_authority` address once the transfer timelock
+//! has elapsed.
 
 use panchor::prelude::*;
 use pinocchio::{
@@ -14,6 +15,7 @@ use pinocchio::{
 };
 
 use crate::{
+    errors::ShieldedPoolError,
     events::{AuthorityTransferCompletedEvent, emit_event},
     pda::gen_global_config_seeds,
     state::GlobalConfig,
@@ -35,7 +37,8 @@ pub struct AcceptAuthorityAccounts<'info> {
 /// Process accept authority instruction.
 ///
 /// Completes the two-step authority transfer. The signer must match the
-/// `pending_authority` field on the global config.
+/// `pending_authority` field on the global config, and the current slot must
+/// be at or after `transfer_initiated_slot + authority_transfer_delay_slots`.
 ///
 /// # Accounts
 /// 0. `[writable]` - Global config PDA
@@ -65,12 +68,18 @@ pub fn process_accept_authority(ctx: Context<AcceptAuthorityAccounts>) -> Progra
             return Err(ProgramError::UninitializedAccount);
         }
 
+        // Enforce the transfer timelock
+        if clock.slot < config.authority_transfer_unlock_slot() {
+            return Err(ShieldedPoolError::AuthorityTransferTimelocked.into());
+        }
+
         let previous = config.authority;
         let new = config.pending_authority;
 
         // Transfer authority role
         config.authority = config.pending_authority;
         config.pending_authority = Pubkey::default();
+        config.transfer_initiated_slot = 0;
 
         Ok((previous, new, config.bump))
     })?;
@@ -0,0 +1,100 @@
+//! `CancelAuthorityTransfer` instruction handler.
+//!
+//! Clears a pending authority transfer during its timelock window.
+//! Must be called by the current authority.
+
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{Sysvar, clock::Clock},
+};
+
+use crate::{
+    errors::ShieldedPoolError,
+    events::{AuthorityTransferCancelledEvent, emit_event},
+    pda::gen_global_config_seeds,
+    state::GlobalConfig,
+};
+
+/// Accounts for the `CancelAuthorityTransfer` instruction.
+#[derive(Accounts)]
+pub struct CancelAuthorityTransferAccounts<'info> {
+    /// Global config PDA ["global_config"]
+    #[account(mut, owner = crate::ID)]
+    pub global_config: AccountLoader<'info, GlobalConfig>,
+    /// Current authority (must be signer, must match global_config.authority)
+    pub authority: Signer<'info>,
+    /// Shielded pool program (for event emission via self-CPI)
+    #[account(address = crate::ID)]
+    pub shielded_pool_program: &'info AccountInfo,
+}
+
+/// Process cancel authority transfer instruction.
+///
+/// Clears `pending_authority` and `transfer_initiated_slot`. Only transfers
+/// that have not yet been accepted can be cancelled, so this is only useful
+/// while the pending authority is still waiting on the timelock (or has not
+/// accepted yet).
+///
+/// # Accounts
+/// 0. `[writable]` - Global config PDA
+/// 1. `[signer]` - Current authority
+/// 2. `[]` - Shielded pool program (for event emission)
+pub fn process_cancel_authority_transfer(
+    ctx: Context<CancelAuthorityTransferAccounts>,
+) -> ProgramResult {
+    let CancelAuthorityTransferAccounts {
+        global_config,
+        authority,
+        shielded_pool_program,
+    } = ctx.accounts;
+
+    // Get current slot for event
+    let clock = Clock::get()?;
+
+    let (cancelled_pending_authority, unlock_slot, bump) = global_config.try_map_mut(|config| {
+        if config.authority != *authority.key() {
+            msg!("cancel_authority_transfer: unauthorized");
+            return Err(ShieldedPoolError::Unauthorized.into());
+        }
+
+        if !config.has_pending_authority() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        let cancelled = config.pending_authority;
+        let unlock_slot = config.authority_transfer_unlock_slot();
+
+        config.pending_authority = Pubkey::default();
+        config.transfer_initiated_slot = 0;
+
+        msg!("cancel_authority_transfer: pending authority cleared");
+        Ok((cancelled, unlock_slot, config.bump))
+    })?;
+
+    // Emit event
+    let bump_bytes = [bump];
+    let seeds = gen_global_config_seeds(&bump_bytes);
+    let signer = PinocchioSigner::from(&seeds);
+
+    let event = AuthorityTransferCancelledEvent {
+        authority: *authority.key(),
+        cancelled_pending_authority,
+        unlock_slot,
+        slot: clock.slot,
+    };
+
+    emit_event(
+        global_config.account_info(),
+        shielded_pool_program,
+        signer,
+        &event,
+    )?;
+
+    Ok(())
+}
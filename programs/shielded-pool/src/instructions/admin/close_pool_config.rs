@@ -0,0 +1,128 @@
+//! Close a deregistered pool config.
+//!
+//! Removes the PoolConfig PDA of a deregistered pool once its grace period has
+//! elapsed and returns the rent to the authority. The asset can then be
+//! registered again.
+
+use crate::{
+    errors::ShieldedPoolError,
+    events::{PoolConfigClosedEvent, emit_event},
+    pda::gen_global_config_seeds,
+    state::{GlobalConfig, PoolConfig},
+};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    msg,
+    sysvars::{Sysvar, clock::Clock},
+};
+
+/// Accounts for the ClosePoolConfig instruction.
+#[derive(Accounts)]
+pub struct ClosePoolConfigAccounts<'info> {
+    /// Global config PDA ["global_config"]
+    #[account(owner = crate::ID)]
+    pub global_config: AccountLoader<'info, GlobalConfig>,
+
+    /// Pool config PDA to close ["pool_config", asset_id]
+    #[account(mut, owner = crate::ID)]
+    pub pool_config: AccountLoader<'info, PoolConfig>,
+
+    /// Must match global_config.authority (receives reclaimed rent)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Shielded pool program (for event emission via self-CPI)
+    #[account(address = crate::ID)]
+    pub shielded_pool_program: &'info AccountInfo,
+}
+
+/// Close a deregistered pool config and reclaim rent to the authority.
+///
+/// The PoolConfig must have been deregistered and the current slot must be
+/// at or after its `closable_at_slot`.
+///
+/// # Authority
+///
+/// Must be GlobalConfig.authority.
+pub fn process_close_pool_config(ctx: Context<ClosePoolConfigAccounts>) -> ProgramResult {
+    let ClosePoolConfigAccounts {
+        global_config,
+        pool_config,
+        authority,
+        shielded_pool_program,
+    } = ctx.accounts;
+
+    // Validate authority against GlobalConfig and get bump for event emission
+    let bump = global_config.try_map(|config| {
+        if config.authority != *authority.key() {
+            msg!("close_pool_config: unauthorized");
+            return Err(ShieldedPoolError::Unauthorized.into());
+        }
+        Ok(config.bump)
+    })?;
+
+    let clock = Clock::get()?;
+
+    let asset_id = pool_config.try_map(|config| {
+        if !config.deregistered() {
+            msg!("close_pool_config: pool not deregistered");
+            return Err(ShieldedPoolError::PoolNotDeregistered.into());
+        }
+        if clock.slot < config.closable_at_slot {
+            msg!("close_pool_config: grace period active");
+            return Err(ShieldedPoolError::PoolConfigGracePeriodActive.into());
+        }
+        Ok(config.asset_id)
+    })?;
+
+    // Capture PDA key before closing
+    let pool_config_key = *pool_config.key();
+
+    // Transfer lamports to authority and close account
+    let lamports = pool_config.lamports();
+
+    unsafe {
+        *pool_config.borrow_mut_lamports_unchecked() = 0;
+    }
+
+    unsafe {
+        *authority.borrow_mut_lamports_unchecked() = authority
+            .lamports()
+            .checked_add(lamports)
+            .ok_or(ShieldedPoolError::ArithmeticOverflow)?;
+    }
+
+    // Zero out the account data
+    let mut data = pool_config.try_borrow_mut_data()?;
+    data.fill(0);
+
+    // Resize to 0 bytes
+    drop(data);
+    pool_config.resize(0)?;
+
+    msg!("close_pool_config: success");
+
+    // Emit event
+    let bump_bytes = [bump];
+    let seeds = gen_global_config_seeds(&bump_bytes);
+    let signer = PinocchioSigner::from(&seeds);
+
+    let event = PoolConfigClosedEvent {
+        pool_config: pool_config_key,
+        asset_id,
+        reclaimed_lamports: lamports,
+        slot: clock.slot,
+    };
+
+    emit_event(
+        global_config.account_info(),
+        shielded_pool_program,
+        signer,
+        &event,
+    )?;
+
+    Ok(())
+}
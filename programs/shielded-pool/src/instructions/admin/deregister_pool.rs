@@ -0,0 +1,167 @@
+//! Deregister a pool from the hub.
+//!
+//! Marks a PoolConfig as deregistered so the asset can no longer be routed.
+//! Only pools with zero accounted balances can be deregistered. The PDA itself
+//! is removed later by `close_pool_config`, after a grace period.
+
+use crate::{
+    errors::ShieldedPoolError,
+    events::{PoolDeregisteredEvent, emit_event},
+    pda::gen_global_config_seeds,
+    pool_registry::pool_kind_entry,
+    state::{
+        AdminRole, GlobalConfig, POOL_CONFIG_CLOSE_GRACE_SLOTS, PoolConfig, TokenPoolConfig,
+        UnifiedSolPoolConfig,
+    },
+};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    msg,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_contrib::AccountAssertions;
+use zorb_pool_interface::PoolType;
+
+/// Accounts for the DeregisterPool instruction.
+#[derive(Accounts)]
+pub struct DeregisterPoolAccounts<'info> {
+    /// Global config PDA ["global_config"]
+    #[account(owner = crate::ID)]
+    pub global_config: AccountLoader<'info, GlobalConfig>,
+
+    /// Pool config PDA ["pool_config", asset_id]
+    #[account(mut, owner = crate::ID)]
+    pub pool_config: AccountLoader<'info, PoolConfig>,
+
+    /// Pool program's config account (TokenPoolConfig or UnifiedSolPoolConfig).
+    /// Must be owned by pool_config.pool_program and carry the same asset_id.
+    pub pool_program_config: &'info AccountInfo,
+
+    /// Must match global_config.authority or global_config.registrar
+    pub authority: Signer<'info>,
+
+    /// Shielded pool program (for event emission via self-CPI)
+    #[account(address = crate::ID)]
+    pub shielded_pool_program: &'info AccountInfo,
+}
+
+/// Deregister a pool from the hub.
+///
+/// The pool program's config is loaded the same way reward configs are in
+/// `execute_transact` (hub PoolConfig determines the pool type, the pool
+/// program's config supplies balances). All accounted balances
+/// (`finalized_balance`, `pending_deposits`, `pending_withdrawals`) must be
+/// zero. On success the PoolConfig is set inactive and deregistered, and
+/// becomes closable after [`POOL_CONFIG_CLOSE_GRACE_SLOTS`].
+///
+/// # Authority
+///
+/// GlobalConfig.authority or GlobalConfig.registrar (if set).
+pub fn process_deregister_pool(ctx: Context<DeregisterPoolAccounts>) -> ProgramResult {
+    let DeregisterPoolAccounts {
+        global_config,
+        pool_config,
+        pool_program_config,
+        authority,
+        shielded_pool_program,
+    } = ctx.accounts;
+
+    // Validate authority against GlobalConfig and get bump for event emission
+    let bump = global_config.try_map(|config| {
+        if !config.has_role(AdminRole::Registrar, authority.key()) {
+            msg!("deregister_pool: unauthorized");
+            return Err(ShieldedPoolError::Unauthorized.into());
+        }
+        Ok(config.bump)
+    })?;
+
+    let (asset_id, pool_type, pool_program) = pool_config.try_map(|config| {
+        config.require_registered()?;
+        let pool_type = pool_kind_entry(config.pool_kind())?.pool;
+        Ok((config.asset_id, pool_type, config.pool_program))
+    })?;
+
+    // Load the pool program's config and read accounted balances
+    pool_program_config
+        .assert_owner(&pool_program)
+        .map_err(|_| ShieldedPoolError::InvalidAccountOwner)?;
+
+    let (config_asset_id, balances) = match pool_type {
+        PoolType::Token => {
+            let config = AccountLoader::<TokenPoolConfig>::new(pool_program_config)
+                .map_err(|_| ShieldedPoolError::InvalidTokenConfig)?
+                .load()
+                .map_err(|_| ShieldedPoolError::InvalidTokenConfig)?;
+            (
+                config.asset_id,
+                [
+                    config.finalized_balance,
+                    config.pending_deposits,
+                    config.pending_withdrawals,
+                ],
+            )
+        }
+        PoolType::UnifiedSol => {
+            let config = AccountLoader::<UnifiedSolPoolConfig>::new(pool_program_config)
+                .map_err(|_| ShieldedPoolError::InvalidPoolConfig)?
+                .load()
+                .map_err(|_| ShieldedPoolError::InvalidPoolConfig)?;
+            (
+                config.asset_id,
+                [
+                    config.finalized_balance,
+                    config.pending_deposits,
+                    config.pending_withdrawals,
+                ],
+            )
+        }
+    };
+
+    if config_asset_id != asset_id {
+        msg!("deregister_pool: pool program config asset_id mismatch");
+        return Err(ShieldedPoolError::InvalidAssetId.into());
+    }
+
+    if balances.iter().any(|&balance| balance != 0) {
+        msg!("deregister_pool: pool has non-zero balance");
+        return Err(ShieldedPoolError::PoolHasBalance.into());
+    }
+
+    let clock = Clock::get()?;
+    let closable_at_slot = clock
+        .slot
+        .checked_add(POOL_CONFIG_CLOSE_GRACE_SLOTS)
+        .ok_or(ShieldedPoolError::ArithmeticOverflow)?;
+
+    pool_config.map_mut(|config| {
+        config.mode = 0;
+        config.is_deregistered = 1;
+        config.closable_at_slot = closable_at_slot;
+    })?;
+
+    msg!("deregister_pool: success");
+
+    // Emit event
+    let bump_bytes = [bump];
+    let seeds = gen_global_config_seeds(&bump_bytes);
+    let signer = PinocchioSigner::from(&seeds);
+
+    let event = PoolDeregisteredEvent {
+        authority: *authority.key(),
+        asset_id,
+        closable_at_slot,
+        slot: clock.slot,
+    };
+
+    emit_event(
+        global_config.account_info(),
+        shielded_pool_program,
+        signer,
+        &event,
+    )?;
+
+    Ok(())
+}
@@ -5,12 +5,15 @@ use crate::{
     indexed_merkle_tree::IndexedMerkleTree,
     merkle_tree::MerkleTree,
     pda::gen_global_config_seeds,
+    poseidon::Poseidon,
     state::{
-        COMMITMENT_TREE_HEIGHT, CommitmentMerkleTree, GlobalConfig, NULLIFIER_TREE_HEIGHT,
-        NullifierIndexedTree, RECEIPT_TREE_HEIGHT, ReceiptMerkleTree, ROOT_HISTORY_SIZE,
+        COMMITMENT_TREE_HEIGHT, CommitmentMerkleTree, DEFAULT_AUTHORITY_TRANSFER_DELAY_SLOTS,
+        EventEmissionMode,
+        GLOBAL_CONFIG_VERSION, GlobalConfig, NULLIFIER_TREE_HEIGHT, NullifierIndexedTree, RECEIPT_TREE_HEIGHT,
+        ReceiptMerkleTree, ROOT_HISTORY_SIZE,
     },
 };
-use light_hasher::{Poseidon, Sha256};
+use light_hasher::Sha256;
 use panchor::prelude::*;
 use pinocchio::{
     ProgramResult,
@@ -107,6 +110,11 @@ pub fn process_initialize(ctx: Context<InitializeAccounts>) -> ProgramResult {
         config.authority = *authority.key();
         config.is_paused = 0;
         config.bump = bumps.global_config;
+        config.version = GLOBAL_CONFIG_VERSION;
+        config.withdrawals_only = 0;
+        config.event_emission_mode = EventEmissionMode::SelfCpi as u8;
+        config.authority_transfer_delay_slots = DEFAULT_AUTHORITY_TRANSFER_DELAY_SLOTS;
+        config.set_default_params();
     }
 
     // Initialize receipt merkle tree
@@ -0,0 +1,134 @@
+//! Migrate GlobalConfig to the current layout version.
+
+use crate::{
+    errors::ShieldedPoolError,
+    events::{GlobalConfigMigratedEvent, emit_event},
+    pda::gen_global_config_seeds,
+    state::{GLOBAL_CONFIG_VERSION, GlobalConfig, GlobalConfigHeader},
+};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    msg,
+    program_error::ProgramError,
+    sysvars::{Sysvar, clock::Clock, rent::Rent},
+};
+use pinocchio_system::instructions::Transfer;
+
+/// Accounts for the MigrateGlobalConfig instruction.
+#[derive(Accounts)]
+pub struct MigrateGlobalConfigAccounts<'info> {
+    /// Global config PDA ["global_config"] (any layout version).
+    /// Raw AccountInfo since older layouts are smaller than `GlobalConfig`.
+    #[account(mut)]
+    pub global_config: &'info AccountInfo,
+
+    /// Must match global_config.authority
+    pub authority: Signer<'info>,
+
+    /// Tops up rent for the grown account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for the rent transfer
+    pub system_program: Program<'info, System>,
+
+    /// Shielded pool program (for event emission via self-CPI)
+    #[account(address = crate::ID)]
+    pub shielded_pool_program: &'info AccountInfo,
+}
+
+/// Upgrade the account contents from `version` to `version + 1`.
+///
+/// Runs after the account has been grown and zero-extended, so new fields
+/// start at zero and only need non-zero defaults written here.
+fn upgrade_from(config: &mut GlobalConfig, version: u16) -> ProgramResult {
+    match version {
+        // v2 added the tunable parameters; zero is out of bounds for some
+        1 => config.set_default_params(),
+        // v3 added the protocol stats; counting starts from zero
+        2 => {}
+        _ => return Err(ProgramError::InvalidAccountData),
+    }
+    Ok(())
+}
+
+/// Migrate GlobalConfig in place to [`GLOBAL_CONFIG_VERSION`].
+///
+/// Reallocs the account to the current `GlobalConfig` size (the payer covers
+/// the additional rent), runs the upgrade step for every intermediate
+/// version, and records the new version. Rejected once the account is current.
+pub fn process_migrate_global_config(ctx: Context<MigrateGlobalConfigAccounts>) -> ProgramResult {
+    let MigrateGlobalConfigAccounts {
+        global_config,
+        authority,
+        payer,
+        system_program,
+        shielded_pool_program,
+    } = ctx.accounts;
+
+    if *system_program.key() != pinocchio_contrib::constants::SYSTEM_PROGRAM_ID {
+        msg!("migrate_global_config: invalid system program");
+        return Err(ShieldedPoolError::InvalidSystemProgram.into());
+    }
+
+    // The header is readable on every layout version
+    let header = GlobalConfigHeader::load(global_config)?;
+    if header.authority != *authority.key() {
+        msg!("migrate_global_config: unauthorized");
+        return Err(ShieldedPoolError::Unauthorized.into());
+    }
+
+    let old_version = header.layout_version();
+    if old_version >= GLOBAL_CONFIG_VERSION {
+        msg!("migrate_global_config: already current");
+        return Err(ShieldedPoolError::GlobalConfigUpToDate.into());
+    }
+
+    // Grow the account and top up rent for the new size
+    let new_size = GlobalConfig::INIT_SPACE.max(global_config.data_len());
+    let required_lamports = Rent::get()?.minimum_balance(new_size);
+    let shortfall = required_lamports.saturating_sub(global_config.lamports());
+    if shortfall > 0 {
+        Transfer {
+            from: payer,
+            to: global_config,
+            lamports: shortfall,
+        }
+        .invoke()?;
+    }
+    // Zero-extends the new region
+    global_config.resize(new_size)?;
+
+    // Run each upgrade step and stamp the new version
+    AccountLoader::<GlobalConfig>::new(global_config)?.try_inspect_mut(|config| {
+        for version in old_version..GLOBAL_CONFIG_VERSION {
+            upgrade_from(config, version)?;
+        }
+        config.version = GLOBAL_CONFIG_VERSION;
+        Ok(())
+    })?;
+
+    msg!("migrate_global_config: success");
+
+    // Emit event
+    let clock = Clock::get()?;
+    let bump_bytes = [header.bump];
+    let seeds = gen_global_config_seeds(&bump_bytes);
+    let signer = PinocchioSigner::from(&seeds);
+
+    let event = GlobalConfigMigratedEvent {
+        authority: *authority.key(),
+        old_version,
+        new_version: GLOBAL_CONFIG_VERSION,
+        _padding: [0u8; 4],
+        new_size: new_size as u64,
+        slot: clock.slot,
+    };
+
+    emit_event(global_config, shielded_pool_program, signer, &event)?;
+
+    Ok(())
+}
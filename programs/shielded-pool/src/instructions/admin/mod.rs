@@ -3,27 +3,100 @@
 //! This module contains instructions for pool initialization and configuration.
 
 mod accept_authority;
+mod cancel_authority_transfer;
+mod close_pool_config;
+mod deregister_pool;
 mod initialize;
+mod migrate_global_config;
 mod register_token_pool;
 mod register_unified_sol_pool;
+mod set_allow_one_epoch_stale;
+mod set_authority_transfer_delay;
+mod set_event_emission_mode;
+mod set_global_params;
 mod set_pool_config_active;
+mod set_pool_config_mode;
 mod set_pool_paused;
+mod set_role;
+mod set_withdrawals_only;
 mod transfer_authority;
 
 // Re-export Accounts structs
 pub use accept_authority::AcceptAuthorityAccounts;
+pub use cancel_authority_transfer::CancelAuthorityTransferAccounts;
+pub use close_pool_config::ClosePoolConfigAccounts;
+pub use deregister_pool::DeregisterPoolAccounts;
 pub use initialize::InitializeAccounts;
+pub use migrate_global_config::MigrateGlobalConfigAccounts;
 pub use register_token_pool::RegisterTokenPoolAccounts;
 pub use register_unified_sol_pool::RegisterUnifiedSolPoolAccounts;
+pub use set_allow_one_epoch_stale::{SetAllowOneEpochStaleAccounts, SetAllowOneEpochStaleData};
+pub use set_authority_transfer_delay::{
+    SetAuthorityTransferDelayAccounts, SetAuthorityTransferDelayData,
+};
+pub use set_event_emission_mode::{SetEventEmissionModeAccounts, SetEventEmissionModeData};
+pub use set_global_params::SetGlobalParamsAccounts;
 pub use set_pool_config_active::{SetPoolConfigActiveAccounts, SetPoolConfigActiveData};
+pub use set_pool_config_mode::{SetPoolConfigModeAccounts, SetPoolConfigModeData};
 pub use set_pool_paused::{SetPoolPausedAccounts, SetPoolPausedData};
+pub use set_role::{SetRoleAccounts, SetRoleData};
+pub use set_withdrawals_only::{SetWithdrawalsOnlyAccounts, SetWithdrawalsOnlyData};
 pub use transfer_authority::TransferAuthorityAccounts;
 
+// Re-export client Input structs (generated by #[derive(Accounts)])
+#[cfg(feature = "solana-sdk")]
+pub use accept_authority::AcceptAuthorityInput;
+#[cfg(feature = "solana-sdk")]
+pub use cancel_authority_transfer::CancelAuthorityTransferInput;
+#[cfg(feature = "solana-sdk")]
+pub use close_pool_config::ClosePoolConfigInput;
+#[cfg(feature = "solana-sdk")]
+pub use deregister_pool::DeregisterPoolInput;
+#[cfg(feature = "solana-sdk")]
+pub use initialize::InitializeInput;
+#[cfg(feature = "solana-sdk")]
+pub use migrate_global_config::MigrateGlobalConfigInput;
+#[cfg(feature = "solana-sdk")]
+pub use register_token_pool::RegisterTokenPoolInput;
+#[cfg(feature = "solana-sdk")]
+pub use register_unified_sol_pool::RegisterUnifiedSolPoolInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_allow_one_epoch_stale::SetAllowOneEpochStaleInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_authority_transfer_delay::SetAuthorityTransferDelayInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_event_emission_mode::SetEventEmissionModeInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_global_params::SetGlobalParamsInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_pool_config_active::SetPoolConfigActiveInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_pool_config_mode::SetPoolConfigModeInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_pool_paused::SetPoolPausedInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_role::SetRoleInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_withdrawals_only::SetWithdrawalsOnlyInput;
+#[cfg(feature = "solana-sdk")]
+pub use transfer_authority::TransferAuthorityInput;
+
 // Re-export handlers (called by #[instructions] macro generated dispatch)
 pub use accept_authority::process_accept_authority;
+pub use cancel_authority_transfer::process_cancel_authority_transfer;
+pub use close_pool_config::process_close_pool_config;
+pub use deregister_pool::process_deregister_pool;
 pub use initialize::process_initialize;
+pub use migrate_global_config::process_migrate_global_config;
 pub use register_token_pool::process_register_token_pool;
 pub use register_unified_sol_pool::process_register_unified_sol_pool;
+pub use set_allow_one_epoch_stale::process_set_allow_one_epoch_stale;
+pub use set_authority_transfer_delay::process_set_authority_transfer_delay;
+pub use set_event_emission_mode::process_set_event_emission_mode;
+pub use set_global_params::process_set_global_params;
 pub use set_pool_config_active::process_set_pool_config_active;
+pub use set_pool_config_mode::process_set_pool_config_mode;
 pub use set_pool_paused::process_set_pool_paused;
+pub use set_role::process_set_role;
+pub use set_withdrawals_only::process_set_withdrawals_only;
 pub use transfer_authority::process_transfer_authority;
@@ -7,7 +7,7 @@ use crate::{
     errors::ShieldedPoolError,
     events::{PoolRegisteredEvent, emit_event},
     pda::{POOL_CONFIG_SEED, find_pool_config_pda, gen_global_config_seeds},
-    state::{GlobalConfig, HubPoolType, PoolConfig, TokenPoolConfig},
+    state::{AdminRole, GlobalConfig, HubPoolType, POOL_MODE_ALL, PoolConfig, TokenPoolConfig},
 };
 use panchor::{SetDiscriminator, prelude::*};
 use pinocchio::{
@@ -17,7 +17,7 @@ use pinocchio::{
 };
 use pinocchio_log::log;
 use pinocchio_system::instructions::CreateAccount;
-use zorb_pool_interface::{TOKEN_POOL_PROGRAM_ID, asset_ids};
+use zorb_pool_interface::{PoolKind, TOKEN_POOL_PROGRAM_ID, asset_ids};
 
 /// Accounts for the RegisterTokenPool instruction.
 #[derive(Accounts)]
@@ -36,7 +36,7 @@ pub struct RegisterTokenPoolAccounts<'info> {
     #[account(owner = TOKEN_POOL_PROGRAM_ID)]
     pub token_pool_config: AccountLoader<'info, TokenPoolConfig>,
 
-    /// Must match global_config.authority (signer and payer)
+    /// Must match global_config.authority or global_config.registrar (signer and payer)
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -55,7 +55,7 @@ pub struct RegisterTokenPoolAccounts<'info> {
 ///
 /// # Authority
 ///
-/// Must be GlobalConfig.authority.
+/// GlobalConfig.authority or GlobalConfig.registrar (if set).
 ///
 /// # Prerequisites
 ///
@@ -78,7 +78,7 @@ pub fn process_register_token_pool(ctx: Context<RegisterTokenPoolAccounts>) -> P
 
     // Validate authority against GlobalConfig and get bump for event emission
     let global_config_bump = global_config.try_map(|config| {
-        if config.authority != *authority.key() {
+        if !config.has_role(AdminRole::Registrar, authority.key()) {
             log!("register_token_pool: unauthorized");
             return Err(ShieldedPoolError::Unauthorized.into());
         }
@@ -138,9 +138,13 @@ pub fn process_register_token_pool(ctx: Context<RegisterTokenPoolAccounts>) -> P
             config.asset_id = asset_id;
             // Metadata (8 bytes)
             config.pool_type = HubPoolType::Token as u8;
-            config.is_active = 1;
+            config.mode = POOL_MODE_ALL;
             config.bump = bump;
-            config._padding = [0u8; 5];
+            config.is_deregistered = 0;
+            config.pool_kind = PoolKind::TOKEN.0;
+            config._padding = [0u8; 2];
+            // Deregistration (8 bytes)
+            config.closable_at_slot = 0;
         })?;
 
     // Emit pool registered event
@@ -7,7 +7,9 @@ use crate::{
     errors::ShieldedPoolError,
     events::{PoolRegisteredEvent, emit_event},
     pda::{POOL_CONFIG_SEED, find_pool_config_pda, gen_global_config_seeds},
-    state::{GlobalConfig, HubPoolType, PoolConfig, UnifiedSolPoolConfig},
+    state::{
+        AdminRole, GlobalConfig, HubPoolType, POOL_MODE_ALL, PoolConfig, UnifiedSolPoolConfig,
+    },
 };
 use panchor::{SetDiscriminator, prelude::*};
 use pinocchio::{
@@ -17,7 +19,7 @@ use pinocchio::{
 };
 use pinocchio_log::log;
 use pinocchio_system::instructions::CreateAccount;
-use zorb_pool_interface::{UNIFIED_SOL_POOL_PROGRAM_ID, asset_ids};
+use zorb_pool_interface::{PoolKind, UNIFIED_SOL_POOL_PROGRAM_ID, asset_ids};
 
 /// Accounts for the RegisterUnifiedSolPool instruction.
 #[derive(Accounts)]
@@ -36,7 +38,7 @@ pub struct RegisterUnifiedSolPoolAccounts<'info> {
     #[account(owner = UNIFIED_SOL_POOL_PROGRAM_ID)]
     pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
 
-    /// Must match global_config.authority (signer and payer)
+    /// Must match global_config.authority or global_config.registrar (signer and payer)
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -55,7 +57,7 @@ pub struct RegisterUnifiedSolPoolAccounts<'info> {
 ///
 /// # Authority
 ///
-/// Must be GlobalConfig.authority.
+/// GlobalConfig.authority or GlobalConfig.registrar (if set).
 ///
 /// # Prerequisites
 ///
@@ -80,7 +82,7 @@ pub fn process_register_unified_sol_pool(
 
     // Validate authority against GlobalConfig and get bump for event emission
     let global_config_bump = global_config.try_map(|config| {
-        if config.authority != *authority.key() {
+        if !config.has_role(AdminRole::Registrar, authority.key()) {
             log!("register_unified_sol_pool: unauthorized");
             return Err(ShieldedPoolError::Unauthorized.into());
         }
@@ -140,9 +142,13 @@ pub fn process_register_unified_sol_pool(
             config.asset_id = asset_id;
             // Metadata (8 bytes)
             config.pool_type = HubPoolType::UnifiedSol as u8;
-            config.is_active = 1;
+            config.mode = POOL_MODE_ALL;
             config.bump = bump;
-            config._padding = [0u8; 5];
+            config.is_deregistered = 0;
+            config.pool_kind = PoolKind::UNIFIED_SOL.0;
+            config._padding = [0u8; 2];
+            // Deregistration (8 bytes)
+            config.closable_at_slot = 0;
         })?;
 
     // Emit pool registered event
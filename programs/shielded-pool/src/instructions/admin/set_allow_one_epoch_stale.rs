@@ -0,0 +1,107 @@
+//! Set whether token pool accumulators may be one finalization stale.
+
+use crate::{
+    errors::ShieldedPoolError,
+    events::{StaleAccumulatorPolicyChangedEvent, emit_event},
+    pda::gen_global_config_seeds,
+    state::GlobalConfig,
+};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    msg,
+    sysvars::{Sysvar, clock::Clock},
+};
+
+/// Instruction data for SetAllowOneEpochStale.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetAllowOneEpochStaleData {
+    /// New policy (1 = accept the previous accumulator, 0 = current only)
+    pub allow_one_epoch_stale: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 7],
+}
+
+/// Accounts for the SetAllowOneEpochStale instruction.
+#[derive(Accounts)]
+pub struct SetAllowOneEpochStaleAccounts<'info> {
+    /// Global config PDA ["global_config"]
+    #[account(mut, owner = crate::ID)]
+    pub global_config: AccountLoader<'info, GlobalConfig>,
+
+    /// Must match global_config.authority
+    pub authority: Signer<'info>,
+
+    /// Shielded pool program (for event emission via self-CPI)
+    #[account(address = crate::ID)]
+    pub shielded_pool_program: &'info AccountInfo,
+}
+
+/// Set the one-epoch-stale token accumulator policy.
+///
+/// While enabled, `ExecuteTransact` accepts a token pool reward line that
+/// matches the pool's `previous_reward_accumulator`, as long as the pool
+/// finalized at most `MAX_STALE_ACCUMULATOR_SLOTS` ago. This covers proofs
+/// built just before a `FinalizeRewards` that lands first. Unified SOL
+/// accumulators are unaffected.
+///
+/// # Authority
+///
+/// GlobalConfig.authority only, since it relaxes proof validation.
+///
+/// # Arguments
+///
+/// * `allow_one_epoch_stale` - New policy (1 = accept previous, 0 = current only)
+pub fn process_set_allow_one_epoch_stale(
+    ctx: Context<SetAllowOneEpochStaleAccounts>,
+    data: SetAllowOneEpochStaleData,
+) -> ProgramResult {
+    let SetAllowOneEpochStaleAccounts {
+        global_config,
+        authority,
+        shielded_pool_program,
+    } = ctx.accounts;
+
+    // Normalize flag to 0/1
+    let allow_one_epoch_stale = (data.allow_one_epoch_stale != 0) as u8;
+
+    // Get current slot for event
+    let clock = Clock::get()?;
+
+    // Validate authority and update policy, get bump for event emission
+    let bump = global_config.try_map_mut(|global_config_data| {
+        if global_config_data.authority != *authority.key() {
+            msg!("set_allow_one_epoch_stale: unauthorized");
+            return Err(ShieldedPoolError::Unauthorized.into());
+        }
+
+        global_config_data.allow_one_epoch_stale = allow_one_epoch_stale;
+
+        msg!("set_allow_one_epoch_stale: success");
+        Ok(global_config_data.bump)
+    })?;
+
+    // Emit event
+    let bump_bytes = [bump];
+    let seeds = gen_global_config_seeds(&bump_bytes);
+    let signer = PinocchioSigner::from(&seeds);
+
+    let event = StaleAccumulatorPolicyChangedEvent {
+        authority: *authority.key(),
+        allow_one_epoch_stale,
+        _padding: [0u8; 7],
+        slot: clock.slot,
+    };
+
+    emit_event(
+        global_config.account_info(),
+        shielded_pool_program,
+        signer,
+        &event,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+//! Set the authority transfer timelock.
+
+use crate::{
+    errors::ShieldedPoolError,
+    events::{AuthorityTransferDelayChangedEvent, emit_event},
+    pda::gen_global_config_seeds,
+    state::{GlobalConfig, MIN_AUTHORITY_TRANSFER_DELAY_SLOTS},
+};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    msg,
+    sysvars::{Sysvar, clock::Clock},
+};
+
+/// Instruction data for SetAuthorityTransferDelay.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetAuthorityTransferDelayData {
+    /// New delay in slots (>= MIN_AUTHORITY_TRANSFER_DELAY_SLOTS)
+    pub delay_slots: u64,
+}
+
+/// Accounts for the SetAuthorityTransferDelay instruction.
+#[derive(Accounts)]
+pub struct SetAuthorityTransferDelayAccounts<'info> {
+    /// Global config PDA ["global_config"]
+    #[account(mut, owner = crate::ID)]
+    pub global_config: AccountLoader<'info, GlobalConfig>,
+
+    /// Must match global_config.authority
+    pub authority: Signer<'info>,
+
+    /// Shielded pool program (for event emission via self-CPI)
+    #[account(address = crate::ID)]
+    pub shielded_pool_program: &'info AccountInfo,
+}
+
+/// Set the number of slots a pending authority must wait before accepting.
+///
+/// Rejected while a transfer is pending so that the unlock slot announced in
+/// `AuthorityTransferInitiatedEvent` cannot move.
+///
+/// # Arguments
+///
+/// * `delay_slots` - New delay, at least `MIN_AUTHORITY_TRANSFER_DELAY_SLOTS`
+pub fn process_set_authority_transfer_delay(
+    ctx: Context<SetAuthorityTransferDelayAccounts>,
+    data: SetAuthorityTransferDelayData,
+) -> ProgramResult {
+    let SetAuthorityTransferDelayAccounts {
+        global_config,
+        authority,
+        shielded_pool_program,
+    } = ctx.accounts;
+
+    if data.delay_slots < MIN_AUTHORITY_TRANSFER_DELAY_SLOTS {
+        msg!("set_authority_transfer_delay: delay below minimum");
+        return Err(ShieldedPoolError::AuthorityTransferDelayTooShort.into());
+    }
+
+    // Get current slot for event
+    let clock = Clock::get()?;
+
+    let (old_delay_slots, bump) = global_config.try_map_mut(|config| {
+        if config.authority != *authority.key() {
+            msg!("set_authority_transfer_delay: unauthorized");
+            return Err(ShieldedPoolError::Unauthorized.into());
+        }
+
+        if config.has_pending_authority() {
+            msg!("set_authority_transfer_delay: transfer pending");
+            return Err(ShieldedPoolError::AuthorityTransferPending.into());
+        }
+
+        let old = config.authority_transfer_delay_slots;
+        config.authority_transfer_delay_slots = data.delay_slots;
+
+        msg!("set_authority_transfer_delay: success");
+        Ok((old, config.bump))
+    })?;
+
+    // Emit event
+    let bump_bytes = [bump];
+    let seeds = gen_global_config_seeds(&bump_bytes);
+    let signer = PinocchioSigner::from(&seeds);
+
+    let event = AuthorityTransferDelayChangedEvent {
+        authority: *authority.key(),
+        old_delay_slots,
+        new_delay_slots: data.delay_slots,
+        slot: clock.slot,
+    };
+
+    emit_event(
+        global_config.account_info(),
+        shielded_pool_program,
+        signer,
+        &event,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+//! Set how events are written to the transaction logs.
+
+use crate::{
+    errors::ShieldedPoolError,
+    events::{EventEmissionModeChangedEvent, emit_event_with_mode},
+    pda::gen_global_config_seeds,
+    state::{EventEmissionMode, GlobalConfig},
+};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    msg,
+    program_error::ProgramError,
+    sysvars::{Sysvar, clock::Clock},
+};
+
+/// Instruction data for SetEventEmissionMode.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetEventEmissionModeData {
+    /// New mode (0 = self-CPI, 1 = log data)
+    pub mode: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 7],
+}
+
+/// Accounts for the SetEventEmissionMode instruction.
+#[derive(Accounts)]
+pub struct SetEventEmissionModeAccounts<'info> {
+    /// Global config PDA ["global_config"]
+    #[account(mut, owner = crate::ID)]
+    pub global_config: AccountLoader<'info, GlobalConfig>,
+
+    /// Must match global_config.authority
+    pub authority: Signer<'info>,
+
+    /// Shielded pool program (for event emission via self-CPI)
+    #[account(address = crate::ID)]
+    pub shielded_pool_program: &'info AccountInfo,
+}
+
+/// Set the event emission mode.
+///
+/// Switches every event signed by `global_config` between self-CPI to the
+/// `Log` instruction and direct `sol_log_data`. Indexers must handle both
+/// encodings (see the `events` module). The change event is emitted in the
+/// previous mode.
+///
+/// # Authority
+///
+/// GlobalConfig.authority only, since the mode changes what indexers parse.
+///
+/// # Arguments
+///
+/// * `mode` - New mode (0 = self-CPI, 1 = log data)
+pub fn process_set_event_emission_mode(
+    ctx: Context<SetEventEmissionModeAccounts>,
+    data: SetEventEmissionModeData,
+) -> ProgramResult {
+    let SetEventEmissionModeAccounts {
+        global_config,
+        authority,
+        shielded_pool_program,
+    } = ctx.accounts;
+
+    let new_mode = EventEmissionMode::from_u8(data.mode).ok_or_else(|| {
+        msg!("set_event_emission_mode: invalid mode");
+        ProgramError::InvalidInstructionData
+    })?;
+
+    // Get current slot for event
+    let clock = Clock::get()?;
+
+    // Validate authority and update mode, get old mode and bump for event emission
+    let (old_mode, bump) = global_config.try_map_mut(|global_config_data| {
+        if global_config_data.authority != *authority.key() {
+            msg!("set_event_emission_mode: unauthorized");
+            return Err(ShieldedPoolError::Unauthorized.into());
+        }
+
+        let old_mode = global_config_data.emission_mode();
+        global_config_data.event_emission_mode = new_mode as u8;
+
+        msg!("set_event_emission_mode: success");
+        Ok((old_mode, global_config_data.bump))
+    })?;
+
+    // Emit event in the previous mode
+    let bump_bytes = [bump];
+    let seeds = gen_global_config_seeds(&bump_bytes);
+    let signer = PinocchioSigner::from(&seeds);
+
+    let event = EventEmissionModeChangedEvent {
+        authority: *authority.key(),
+        old_mode: old_mode as u8,
+        new_mode: new_mode as u8,
+        _padding: [0u8; 6],
+        slot: clock.slot,
+    };
+
+    emit_event_with_mode(
+        old_mode,
+        global_config.account_info(),
+        shielded_pool_program,
+        signer,
+        &event,
+    )?;
+
+    Ok(())
+}
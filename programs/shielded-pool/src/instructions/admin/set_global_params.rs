@@ -0,0 +1,169 @@
+//! Set tunable global parameters in a single atomic batch.
+
+use crate::{
+    errors::ShieldedPoolError,
+    events::{GlobalParamChangedEvent, emit_event},
+    pda::gen_global_config_seeds,
+    state::{AdminRole, GlobalConfig, GlobalParam},
+};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    msg,
+    program_error::ProgramError,
+    sysvars::{Sysvar, clock::Clock},
+};
+
+/// Size of an entry header: param_id (u16) + len (u8).
+const ENTRY_HEADER_SIZE: usize = 3;
+
+/// Byte length of every current parameter value (u64).
+const VALUE_SIZE: usize = 8;
+
+/// Accounts for the SetGlobalParams instruction.
+#[derive(Accounts)]
+pub struct SetGlobalParamsAccounts<'info> {
+    /// Global config PDA ["global_config"]
+    #[account(mut, owner = crate::ID)]
+    pub global_config: AccountLoader<'info, GlobalConfig>,
+
+    /// Must match global_config.authority or global_config.fee_admin
+    pub authority: Signer<'info>,
+
+    /// Shielded pool program (for event emission via self-CPI)
+    #[account(address = crate::ID)]
+    pub shielded_pool_program: &'info AccountInfo,
+}
+
+/// Parsed SetGlobalParams batch (at most one entry per parameter).
+struct GlobalParamsBatch {
+    entries: [(GlobalParam, u64); GlobalParam::ALL.len()],
+    len: usize,
+}
+
+/// Parse and validate every entry before anything is applied.
+fn parse_global_params(mut data: &[u8]) -> Result<GlobalParamsBatch, ProgramError> {
+    let mut batch = GlobalParamsBatch {
+        entries: [(GlobalParam::MaxRootAge, 0); GlobalParam::ALL.len()],
+        len: 0,
+    };
+
+    if data.is_empty() {
+        msg!("set_global_params: empty batch");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    while !data.is_empty() {
+        if data.len() < ENTRY_HEADER_SIZE {
+            msg!("set_global_params: truncated entry");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let param_id = u16::from_le_bytes([data[0], data[1]]);
+        let value_len = data[2] as usize;
+        if value_len != VALUE_SIZE || data.len() < ENTRY_HEADER_SIZE + value_len {
+            msg!("set_global_params: invalid value length");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let value = u64::from_le_bytes(
+            data[ENTRY_HEADER_SIZE..ENTRY_HEADER_SIZE + VALUE_SIZE]
+                .try_into()
+                .unwrap(),
+        );
+        data = &data[ENTRY_HEADER_SIZE + value_len..];
+
+        let param = GlobalParam::from_u16(param_id).ok_or_else(|| {
+            msg!("set_global_params: unknown param");
+            ShieldedPoolError::UnknownGlobalParam
+        })?;
+        if !param.is_valid(value) {
+            msg!("set_global_params: value out of bounds");
+            return Err(ShieldedPoolError::GlobalParamOutOfBounds.into());
+        }
+        if batch.entries[..batch.len].iter().any(|(p, _)| *p == param) {
+            msg!("set_global_params: duplicate param");
+            return Err(ShieldedPoolError::DuplicateGlobalParam.into());
+        }
+
+        batch.entries[batch.len] = (param, value);
+        batch.len += 1;
+    }
+
+    Ok(batch)
+}
+
+/// Set one or more tunable protocol parameters.
+///
+/// The batch is validated in full before any parameter is written, so a single
+/// unknown id or out-of-bounds value rejects the whole instruction. A
+/// `GlobalParamChangedEvent` is emitted for each parameter whose value changed.
+/// See [`GlobalParam`] for the parameter table and bounds.
+///
+/// # Raw Data Format
+///
+/// A list of entries, each:
+///
+/// * `[0..2]` - param_id: u16 (little-endian)
+/// * `[2]` - len: u8 - value length in bytes (must be 8)
+/// * `[3..3+len]` - value: u64 (little-endian)
+pub fn process_set_global_params(
+    ctx: Context<SetGlobalParamsAccounts>,
+    data: &[u8],
+) -> ProgramResult {
+    let SetGlobalParamsAccounts {
+        global_config,
+        authority,
+        shielded_pool_program,
+    } = ctx.accounts;
+
+    let batch = parse_global_params(data)?;
+
+    // Get current slot for events
+    let clock = Clock::get()?;
+
+    let mut old_values = [0u64; GlobalParam::ALL.len()];
+    let bump = global_config.try_map_mut(|config| {
+        if !config.has_role(AdminRole::FeeAdmin, authority.key()) {
+            msg!("set_global_params: unauthorized");
+            return Err(ShieldedPoolError::Unauthorized.into());
+        }
+
+        for (i, (param, value)) in batch.entries[..batch.len].iter().enumerate() {
+            let slot = config.param_mut(*param);
+            old_values[i] = *slot;
+            *slot = *value;
+        }
+
+        msg!("set_global_params: success");
+        Ok(config.bump)
+    })?;
+
+    // Emit one event per changed parameter
+    let bump_bytes = [bump];
+    let seeds = gen_global_config_seeds(&bump_bytes);
+
+    for (i, (param, value)) in batch.entries[..batch.len].iter().enumerate() {
+        if old_values[i] == *value {
+            continue;
+        }
+
+        let event = GlobalParamChangedEvent {
+            admin: *authority.key(),
+            param_id: *param as u16,
+            _padding: [0u8; 6],
+            old_value: old_values[i],
+            new_value: *value,
+            slot: clock.slot,
+        };
+
+        emit_event(
+            global_config.account_info(),
+            shielded_pool_program,
+            PinocchioSigner::from(&seeds),
+            &event,
+        )?;
+    }
+
+    Ok(())
+}
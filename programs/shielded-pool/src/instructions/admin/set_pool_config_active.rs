@@ -1,12 +1,14 @@
 //! Set the active state for a pool config.
 //!
-//! Enables or disables pool routing for an asset in the hub.
+//! Enables or disables pool routing for an asset in the hub. Compatibility
+//! wrapper over the `PoolConfig.mode` bitfield; see `SetPoolConfigMode` for
+//! per-direction control.
 
 use crate::{
     errors::ShieldedPoolError,
     events::{PoolConfigActiveChangedEvent, emit_event},
     pda::gen_global_config_seeds,
-    state::{GlobalConfig, PoolConfig},
+    state::{AdminRole, GlobalConfig, POOL_MODE_ALL, PoolConfig},
 };
 use panchor::prelude::*;
 use pinocchio::{
@@ -36,7 +38,7 @@ pub struct SetPoolConfigActiveAccounts<'info> {
     #[account(mut)]
     pub pool_config: AccountLoader<'info, PoolConfig>,
 
-    /// Must match global_config.authority
+    /// Must match global_config.authority or global_config.registrar
     pub authority: Signer<'info>,
 
     /// Shielded pool program (for event emission via self-CPI)
@@ -46,7 +48,8 @@ pub struct SetPoolConfigActiveAccounts<'info> {
 
 /// Set the active state for a pool config.
 ///
-/// Enables or disables pool routing for this asset in the hub.
+/// Enables or disables pool routing for this asset in the hub by setting or
+/// clearing both direction bits of `PoolConfig.mode`.
 /// When inactive, deposits/withdrawals for this asset will fail.
 ///
 /// # Arguments
@@ -55,7 +58,7 @@ pub struct SetPoolConfigActiveAccounts<'info> {
 ///
 /// # Authority
 ///
-/// Must be GlobalConfig.authority.
+/// GlobalConfig.authority or GlobalConfig.registrar (if set).
 pub fn process_set_pool_config_active(
     ctx: Context<SetPoolConfigActiveAccounts>,
     data: SetPoolConfigActiveData,
@@ -69,7 +72,7 @@ pub fn process_set_pool_config_active(
 
     // Validate authority against GlobalConfig and get bump for event emission
     let bump = global_config.try_map(|global_config_data| {
-        if global_config_data.authority != *authority.key() {
+        if !global_config_data.has_role(AdminRole::Registrar, authority.key()) {
             msg!("set_pool_config_active: unauthorized");
             return Err(ShieldedPoolError::Unauthorized.into());
         }
@@ -77,9 +80,15 @@ pub fn process_set_pool_config_active(
     })?;
 
     // Update pool_config active state and get asset_id for event
-    let asset_id = pool_config.map_mut(|pool_config_account| {
-        pool_config_account.is_active = data.is_active;
-        pool_config_account.asset_id
+    let asset_id = pool_config.try_map_mut(|pool_config_account| {
+        // Deregistered pools can only be closed, never reactivated
+        pool_config_account.require_registered()?;
+        pool_config_account.mode = if data.is_active != 0 {
+            POOL_MODE_ALL
+        } else {
+            0
+        };
+        Ok(pool_config_account.asset_id)
     })?;
 
     msg!("set_pool_config_active: success");
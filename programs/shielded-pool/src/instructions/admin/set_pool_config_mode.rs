@@ -0,0 +1,117 @@
+//! Set the deposit/withdrawal mode for a pool config.
+//!
+//! Enables or disables each direction of pool routing for an asset in the hub,
+//! e.g. blocking new deposits during an incident while users can still exit.
+
+use crate::{
+    errors::ShieldedPoolError,
+    events::{PoolConfigModeChangedEvent, emit_event},
+    pda::gen_global_config_seeds,
+    state::{AdminRole, GlobalConfig, PoolConfig},
+};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    msg,
+};
+
+/// Instruction data for SetPoolConfigMode.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetPoolConfigModeData {
+    /// New mode (`POOL_MODE_DEPOSITS_ENABLED | POOL_MODE_WITHDRAWALS_ENABLED`)
+    pub mode: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 7],
+}
+
+/// Accounts for the SetPoolConfigMode instruction.
+#[derive(Accounts)]
+pub struct SetPoolConfigModeAccounts<'info> {
+    /// Global config PDA ["global_config"]
+    pub global_config: AccountLoader<'info, GlobalConfig>,
+
+    /// Pool config PDA ["pool_config", asset_id]
+    #[account(mut)]
+    pub pool_config: AccountLoader<'info, PoolConfig>,
+
+    /// Must match global_config.authority or global_config.registrar
+    pub authority: Signer<'info>,
+
+    /// Shielded pool program (for event emission via self-CPI)
+    #[account(address = crate::ID)]
+    pub shielded_pool_program: &'info AccountInfo,
+}
+
+/// Set the deposit/withdrawal mode for a pool config.
+///
+/// `ExecuteTransact` checks the bit matching the sign of each slot's
+/// `ext_amount`. Reward accumulator reads for the asset are unaffected.
+///
+/// # Arguments
+///
+/// * `mode` - New mode bits; unknown bits are rejected
+///
+/// # Authority
+///
+/// GlobalConfig.authority or GlobalConfig.registrar (if set).
+pub fn process_set_pool_config_mode(
+    ctx: Context<SetPoolConfigModeAccounts>,
+    data: SetPoolConfigModeData,
+) -> ProgramResult {
+    let SetPoolConfigModeAccounts {
+        global_config,
+        pool_config,
+        authority,
+        shielded_pool_program,
+    } = ctx.accounts;
+
+    if !PoolConfig::is_valid_mode(data.mode) {
+        msg!("set_pool_config_mode: invalid mode");
+        return Err(ShieldedPoolError::InvalidPoolMode.into());
+    }
+
+    // Validate authority against GlobalConfig and get bump for event emission
+    let bump = global_config.try_map(|global_config_data| {
+        if !global_config_data.has_role(AdminRole::Registrar, authority.key()) {
+            msg!("set_pool_config_mode: unauthorized");
+            return Err(ShieldedPoolError::Unauthorized.into());
+        }
+        Ok(global_config_data.bump)
+    })?;
+
+    // Update pool_config mode and get asset_id for event
+    let (asset_id, old_mode) = pool_config.try_map_mut(|pool_config_account| {
+        // Deregistered pools can only be closed, never reactivated
+        pool_config_account.require_registered()?;
+        let old_mode = pool_config_account.mode;
+        pool_config_account.mode = data.mode;
+        Ok((pool_config_account.asset_id, old_mode))
+    })?;
+
+    msg!("set_pool_config_mode: success");
+
+    // Emit event
+    let bump_bytes = [bump];
+    let seeds = gen_global_config_seeds(&bump_bytes);
+    let signer = PinocchioSigner::from(&seeds);
+
+    let event = PoolConfigModeChangedEvent {
+        authority: *authority.key(),
+        asset_id,
+        old_mode,
+        new_mode: data.mode,
+        _padding: [0u8; 6],
+    };
+
+    emit_event(
+        global_config.account_info(),
+        shielded_pool_program,
+        signer,
+        &event,
+    )?;
+
+    Ok(())
+}
@@ -4,7 +4,7 @@ use crate::{
     errors::ShieldedPoolError,
     events::{PoolPauseChangedEvent, emit_event},
     pda::gen_global_config_seeds,
-    state::GlobalConfig,
+    state::{AdminRole, GlobalConfig},
 };
 use panchor::prelude::*;
 use pinocchio::{
@@ -32,7 +32,7 @@ pub struct SetPoolPausedAccounts<'info> {
     #[account(mut, owner = crate::ID)]
     pub global_config: AccountLoader<'info, GlobalConfig>,
 
-    /// Must match global_config.authority
+    /// Must match global_config.authority or global_config.pauser
     pub authority: Signer<'info>,
 
     /// Shielded pool program (for event emission via self-CPI)
@@ -44,6 +44,10 @@ pub struct SetPoolPausedAccounts<'info> {
 ///
 /// Enables or disables the pool for all operations.
 ///
+/// # Authority
+///
+/// GlobalConfig.authority or GlobalConfig.pauser (if set).
+///
 /// # Arguments
 ///
 /// * `is_paused` - New paused state (1 = paused, 0 = active)
@@ -62,7 +66,7 @@ pub fn process_set_pool_paused(
 
     // Validate authority and update paused state, get bump for event emission
     let bump = global_config.try_map_mut(|global_config_data| {
-        if global_config_data.authority != *authority.key() {
+        if !global_config_data.has_role(AdminRole::Pauser, authority.key()) {
             msg!("set_pool_paused: unauthorized");
             return Err(ShieldedPoolError::Unauthorized.into());
         }
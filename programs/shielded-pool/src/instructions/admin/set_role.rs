@@ -0,0 +1,105 @@
+//! Assign or clear a delegated admin role.
+
+use crate::{
+    errors::ShieldedPoolError,
+    events::{RoleChangedEvent, emit_event},
+    pda::gen_global_config_seeds,
+    state::{AdminRole, GlobalConfig},
+};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    msg,
+    pubkey::Pubkey,
+    sysvars::{Sysvar, clock::Clock},
+};
+
+/// Instruction data for SetRole.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetRoleData {
+    /// New role holder, or [0;32] to clear the role
+    pub holder: Pubkey,
+    /// Role id (0 = pauser, 1 = fee_admin, 2 = registrar)
+    pub role: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 7],
+}
+
+/// Accounts for the SetRole instruction.
+#[derive(Accounts)]
+pub struct SetRoleAccounts<'info> {
+    /// Global config PDA ["global_config"]
+    #[account(mut, owner = crate::ID)]
+    pub global_config: AccountLoader<'info, GlobalConfig>,
+
+    /// Must match global_config.authority
+    pub authority: Signer<'info>,
+
+    /// Shielded pool program (for event emission via self-CPI)
+    #[account(address = crate::ID)]
+    pub shielded_pool_program: &'info AccountInfo,
+}
+
+/// Assign or clear a delegated admin role.
+///
+/// Roles are only ever granted by the authority; role holders cannot
+/// reassign their own or any other role.
+///
+/// # Arguments
+///
+/// * `holder` - New role holder, or [0;32] to fall back to authority-only
+/// * `role` - Role id (see [`AdminRole`])
+pub fn process_set_role(ctx: Context<SetRoleAccounts>, data: SetRoleData) -> ProgramResult {
+    let SetRoleAccounts {
+        global_config,
+        authority,
+        shielded_pool_program,
+    } = ctx.accounts;
+
+    let role = AdminRole::from_u8(data.role).ok_or_else(|| {
+        msg!("set_role: invalid role");
+        ShieldedPoolError::InvalidAdminRole
+    })?;
+
+    // Get current slot for event
+    let clock = Clock::get()?;
+
+    let (old_holder, bump) = global_config.try_map_mut(|config| {
+        if config.authority != *authority.key() {
+            msg!("set_role: unauthorized");
+            return Err(ShieldedPoolError::Unauthorized.into());
+        }
+
+        let old = *config.role(role);
+        *config.role_mut(role) = data.holder;
+
+        msg!("set_role: success");
+        Ok((old, config.bump))
+    })?;
+
+    // Emit event
+    let bump_bytes = [bump];
+    let seeds = gen_global_config_seeds(&bump_bytes);
+    let signer = PinocchioSigner::from(&seeds);
+
+    let event = RoleChangedEvent {
+        authority: *authority.key(),
+        old_holder,
+        new_holder: data.holder,
+        role: data.role,
+        _padding: [0u8; 7],
+        slot: clock.slot,
+    };
+
+    emit_event(
+        global_config.account_info(),
+        shielded_pool_program,
+        signer,
+        &event,
+    )?;
+
+    Ok(())
+}
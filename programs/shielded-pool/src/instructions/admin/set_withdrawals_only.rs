@@ -0,0 +1,103 @@
+//! Set the hub-wide withdrawal-only mode.
+
+use crate::{
+    errors::ShieldedPoolError,
+    events::{WithdrawalsOnlyChangedEvent, emit_event},
+    pda::gen_global_config_seeds,
+    state::{AdminRole, GlobalConfig},
+};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    msg,
+    sysvars::{Sysvar, clock::Clock},
+};
+
+/// Instruction data for SetWithdrawalsOnly.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetWithdrawalsOnlyData {
+    /// New mode (1 = withdrawals only, 0 = deposits allowed)
+    pub withdrawals_only: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 7],
+}
+
+/// Accounts for the SetWithdrawalsOnly instruction.
+#[derive(Accounts)]
+pub struct SetWithdrawalsOnlyAccounts<'info> {
+    /// Global config PDA ["global_config"]
+    #[account(mut, owner = crate::ID)]
+    pub global_config: AccountLoader<'info, GlobalConfig>,
+
+    /// Must match global_config.authority or global_config.pauser
+    pub authority: Signer<'info>,
+
+    /// Shielded pool program (for event emission via self-CPI)
+    #[account(address = crate::ID)]
+    pub shielded_pool_program: &'info AccountInfo,
+}
+
+/// Set the hub-wide withdrawal-only mode.
+///
+/// While enabled, every public slot with a positive `ext_amount` and every
+/// `InitDepositEscrow` fails with `DepositsDisabled`. Withdrawals and private
+/// transfers are unaffected. Independent of `SetPoolPaused`, which stops all
+/// operations, and of per-asset `SetPoolConfigMode`.
+///
+/// # Authority
+///
+/// GlobalConfig.authority or GlobalConfig.pauser (if set).
+///
+/// # Arguments
+///
+/// * `withdrawals_only` - New mode (1 = withdrawals only, 0 = deposits allowed)
+pub fn process_set_withdrawals_only(
+    ctx: Context<SetWithdrawalsOnlyAccounts>,
+    data: SetWithdrawalsOnlyData,
+) -> ProgramResult {
+    let SetWithdrawalsOnlyAccounts {
+        global_config,
+        authority,
+        shielded_pool_program,
+    } = ctx.accounts;
+
+    // Get current slot for event
+    let clock = Clock::get()?;
+
+    // Validate authority and update mode, get bump for event emission
+    let bump = global_config.try_map_mut(|global_config_data| {
+        if !global_config_data.has_role(AdminRole::Pauser, authority.key()) {
+            msg!("set_withdrawals_only: unauthorized");
+            return Err(ShieldedPoolError::Unauthorized.into());
+        }
+
+        global_config_data.withdrawals_only = data.withdrawals_only;
+
+        msg!("set_withdrawals_only: success");
+        Ok(global_config_data.bump)
+    })?;
+
+    // Emit event
+    let bump_bytes = [bump];
+    let seeds = gen_global_config_seeds(&bump_bytes);
+    let signer = PinocchioSigner::from(&seeds);
+
+    let event = WithdrawalsOnlyChangedEvent {
+        authority: *authority.key(),
+        withdrawals_only: data.withdrawals_only,
+        _padding: [0u8; 7],
+        slot: clock.slot,
+    };
+
+    emit_event(
+        global_config.account_info(),
+        shielded_pool_program,
+        signer,
+        &event,
+    )?;
+
+    Ok(())
+}
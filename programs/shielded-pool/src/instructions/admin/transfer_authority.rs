@@ -1,7 +1,8 @@
 //! `TransferAuthority` instruction handler.
 //!
 //! Initiates a two-step authority transfer by setting `pending_authority`.
-//! The new authority must call `accept_authority` to complete the transfer.
+//! The new authority must call `accept_authority` to complete the transfer
+//! once `authority_transfer_delay_slots` have elapsed.
 
 use panchor::prelude::*;
 use pinocchio::{
@@ -36,8 +37,11 @@ pub struct TransferAuthorityAccounts<'info> {
 
 /// Process transfer authority instruction.
 ///
-/// Sets the `pending_authority` field on the global config. The new authority
-/// must call `accept_authority` to complete the transfer.
+/// Sets the `pending_authority` field on the global config and records the
+/// current slot as `transfer_initiated_slot`. The new authority must call
+/// `accept_authority` at or after the unlock slot to complete the transfer.
+/// Calling this again while a transfer is pending replaces the pending
+/// authority and restarts the timelock.
 ///
 /// # Accounts
 /// 0. `[writable]` - Global config PDA
@@ -56,7 +60,7 @@ pub fn process_transfer_authority(ctx: Context<TransferAuthorityAccounts>) -> Pr
     let clock = Clock::get()?;
 
     // Update config and get bump for event emission
-    let (current_authority, bump) = global_config.try_map_mut(|config| {
+    let (current_authority, unlock_slot, bump) = global_config.try_map_mut(|config| {
         // Verify signer is current authority
         if config.authority != *authority.key() {
             msg!("transfer_authority: unauthorized");
@@ -65,11 +69,14 @@ pub fn process_transfer_authority(ctx: Context<TransferAuthorityAccounts>) -> Pr
 
         let current = config.authority;
 
-        // Set pending authority
+        // Set pending authority and (re)start the timelock
         config.pending_authority = *new_authority.key();
+        config.transfer_initiated_slot = clock.slot;
+
+        let unlock_slot = config.authority_transfer_unlock_slot();
 
         msg!("transfer_authority: pending authority set");
-        Ok((current, config.bump))
+        Ok((current, unlock_slot, config.bump))
     })?;
 
     // Emit event
@@ -81,6 +88,7 @@ pub fn process_transfer_authority(ctx: Context<TransferAuthorityAccounts>) -> Pr
         current_authority,
         pending_authority: *new_authority.key(),
         slot: clock.slot,
+        unlock_slot,
     };
 
     emit_event(
@@ -3,6 +3,9 @@
 //! This instruction allows the depositor to reclaim their tokens after the escrow
 //! has expired. It transfers all tokens from the escrow vault back to the depositor,
 //! closes the vault ATA, and closes the escrow account (returning rent lamports).
+//!
+//! Native SOL escrows refund the escrowed lamports held on the escrow PDA along
+//! with its rent; closing the (empty) WSOL vault returns its rent as well.
 
 use crate::{
     errors::ShieldedPoolError,
@@ -11,14 +14,14 @@ use crate::{
         find_deposit_escrow_pda, find_escrow_vault_authority_pda,
         gen_deposit_escrow_seeds, gen_escrow_vault_authority_seeds,
     },
-    state::DepositEscrow,
+    state::{DepositEscrow, DepositEscrowAssetKind},
 };
 use panchor::prelude::*;
 use pinocchio::{
     ProgramResult,
     account_info::AccountInfo,
     instruction::Signer as PinocchioSigner,
-    sysvars::{Sysvar, clock::Clock},
+    sysvars::{Sysvar, clock::Clock, rent::Rent},
 };
 use pinocchio_log::log;
 use pinocchio_token::instructions::{CloseAccount, Transfer};
@@ -38,7 +41,7 @@ pub struct CloseDepositEscrowData {
 /// 1. `[mut]` escrow - Escrow PDA to close
 /// 2. `[]` escrow_vault_authority - PDA that owns the vault
 /// 3. `[mut]` escrow_vault - ATA to close and transfer tokens from
-/// 4. `[mut]` depositor_token_account - Destination for reclaimed tokens
+/// 4. `[mut]` depositor_token_account - Destination for reclaimed tokens (unused for native SOL)
 /// 5. `[]` token_program - SPL Token program
 /// 6. `[]` shielded_pool_program - This program (for event emission)
 #[derive(Accounts)]
@@ -61,6 +64,7 @@ pub struct CloseDepositEscrowAccounts<'info> {
     pub escrow_vault: &'info AccountInfo,
 
     /// Depositor's token account (receives reclaimed tokens).
+    /// Ignored for native SOL escrows.
     #[account(mut)]
     pub depositor_token_account: &'info AccountInfo,
 
@@ -147,6 +151,10 @@ pub fn process_close_deposit_escrow(
     // Save data for event emission (before we close the escrow)
     let mint = escrow_state.mint;
     let escrow_bump = escrow_state.bump;
+    let asset_kind = escrow_state.asset_kind().ok_or_else(|| {
+        log!("close_deposit_escrow: invalid asset kind");
+        ShieldedPoolError::InvalidEscrowAccount
+    })?;
 
     // Drop borrow before we modify escrow
     drop(escrow_data);
@@ -169,7 +177,8 @@ pub fn process_close_deposit_escrow(
     // Get vault balance
     let vault_balance = crate::token::get_token_account_balance(escrow_vault)?;
 
-    if vault_balance > 0 {
+    // A native vault's balance is returned as lamports when it is closed below
+    if asset_kind == DepositEscrowAssetKind::Token && vault_balance > 0 {
         // Generate signer seeds for escrow_vault_authority
         let escrow_key = escrow.key();
         let bump_slice = [vault_auth_bump];
@@ -216,6 +225,15 @@ pub fn process_close_deposit_escrow(
     // Get escrow lamports before closing
     let escrow_lamports = escrow.lamports();
 
+    // Native SOL escrows hold the escrowed amount on top of rent
+    let escrowed_lamports = match asset_kind {
+        DepositEscrowAssetKind::Token => 0,
+        DepositEscrowAssetKind::NativeSol => DepositEscrow::escrowed_lamports(
+            escrow_lamports,
+            Rent::get()?.minimum_balance(DepositEscrow::ACCOUNT_SIZE),
+        ),
+    };
+
     // Emit event using escrow PDA as signer
     let nonce_bytes = data.nonce.to_le_bytes();
     let escrow_bump_slice = [escrow_bump];
@@ -226,9 +244,11 @@ pub fn process_close_deposit_escrow(
         depositor: *depositor.key(),
         escrow: *escrow.key(),
         mint,
-        amount_returned: vault_balance,
+        amount_returned: vault_balance.saturating_add(escrowed_lamports),
         nonce: data.nonce,
-        lamports_reclaimed: escrow_lamports,
+        lamports_reclaimed: escrow_lamports - escrowed_lamports,
+        asset_kind: asset_kind as u8,
+        _padding: [0u8; 7],
     };
 
     emit_event(escrow, shielded_pool_program, escrow_signer, &event)?;
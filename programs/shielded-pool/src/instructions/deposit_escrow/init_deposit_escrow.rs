@@ -3,12 +3,17 @@
 //! This instruction creates an escrow account and vault, then transfers tokens
 //! from the depositor to the vault. The escrow is bound to a specific proof hash,
 //! which the relayer must match when executing the transact.
+//!
+//! Native SOL escrows instead fund the escrow PDA itself with `amount` lamports
+//! above rent. The WSOL vault is still created (empty) and is only funded when
+//! `execute_transact` consumes the escrow.
 
 use crate::{
     errors::ShieldedPoolError,
     events::{DepositEscrowCreatedEvent, emit_event},
     pda::{find_deposit_escrow_pda, find_escrow_vault_authority_pda, gen_deposit_escrow_seeds},
-    state::DepositEscrow,
+    state::{DepositEscrow, DepositEscrowAssetKind, GlobalConfigHeader},
+    token::WSOL_MINT,
 };
 use panchor::prelude::*;
 use pinocchio::{
@@ -18,7 +23,6 @@ use pinocchio::{
     pubkey::Pubkey,
     sysvars::{Sysvar, clock::Clock, rent::Rent},
 };
-use pinocchio_associated_token_account::instructions::CreateIdempotent;
 use pinocchio_log::log;
 use pinocchio_system::instructions::CreateAccount;
 use pinocchio_token::instructions::Transfer;
@@ -28,9 +32,10 @@ use pinocchio_token::instructions::Transfer;
 /// # Fields
 /// - `proof_hash`: SHA256(session_body) that this escrow is bound to
 /// - `nonce`: Unique nonce for this escrow (allows multiple concurrent escrows)
-/// - `amount`: Amount of tokens to transfer to escrow vault
+/// - `amount`: Amount of tokens (or lamports for native SOL) to escrow
 /// - `authorized_relayer`: [0;32] allows any relayer, otherwise specific relayer only
 /// - `expiry_slots`: Number of slots after creation when escrow expires
+/// - `asset_kind`: `DepositEscrowAssetKind` (0 = token, 1 = native SOL)
 #[repr(C)]
 #[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
 pub struct InitDepositEscrowData {
@@ -39,12 +44,17 @@ pub struct InitDepositEscrowData {
     /// Unique nonce for this escrow.
     pub nonce: u64,
     /// Amount of tokens to transfer to escrow vault.
+    /// For native SOL escrows, lamports held on the escrow PDA above rent.
     pub amount: u64,
     /// Authorized relayer pubkey, or [0;32] to allow any relayer.
     pub authorized_relayer: Pubkey,
     /// Number of slots after creation when escrow expires.
     /// User can reclaim tokens after expiry.
     pub expiry_slots: u64,
+    /// Escrowed asset (`DepositEscrowAssetKind`).
+    pub asset_kind: u8,
+    /// Padding for alignment.
+    pub _padding: [u8; 7],
 }
 
 /// Accounts for InitDepositEscrow instruction.
@@ -54,12 +64,13 @@ pub struct InitDepositEscrowData {
 /// 1. `[mut]` escrow - PDA to create ["deposit_escrow", depositor, nonce]
 /// 2. `[]` escrow_vault_authority - PDA that owns the vault ["escrow_vault_authority", escrow]
 /// 3. `[mut]` escrow_vault - ATA of escrow_vault_authority for the mint
-/// 4. `[mut]` depositor_token_account - Source token account
-/// 5. `[]` mint - SPL token mint
+/// 4. `[mut]` depositor_token_account - Source token account (unused for native SOL)
+/// 5. `[]` mint - SPL token mint (WSOL for native SOL)
 /// 6. `[]` token_program - SPL Token program
 /// 7. `[]` associated_token_program - Associated Token program
 /// 8. `[]` system_program - System program
 /// 9. `[]` shielded_pool_program - This program (for event emission)
+/// 10. `[]` global_config - Global config PDA (withdrawal-only check)
 #[derive(Accounts)]
 pub struct InitDepositEscrowAccounts<'info> {
     /// Depositor (payer) for the escrow.
@@ -82,6 +93,7 @@ pub struct InitDepositEscrowAccounts<'info> {
     pub escrow_vault: &'info AccountInfo,
 
     /// Depositor's token account (source of tokens).
+    /// Ignored for native SOL escrows.
     #[account(mut)]
     pub depositor_token_account: &'info AccountInfo,
 
@@ -100,12 +112,21 @@ pub struct InitDepositEscrowAccounts<'info> {
     /// Shielded pool program (for event emission via self-CPI)
     #[account(address = crate::ID)]
     pub shielded_pool_program: &'info AccountInfo,
+
+    /// Global config PDA ["global_config"] (any layout version).
+    /// Escrows are refused in withdrawal-only mode.
+    pub global_config: &'info AccountInfo,
 }
 
 /// Initialize a deposit escrow for relayer-assisted deposits.
 ///
 /// Creates the escrow PDA, creates the escrow vault ATA, transfers tokens
 /// from the depositor to the vault, and initializes the escrow state.
+/// Native SOL escrows (mint must be WSOL) fund the escrow PDA with
+/// `amount` lamports on top of rent instead of transferring tokens.
+///
+/// Rejected with `DepositsDisabled` while the hub is in withdrawal-only mode,
+/// since the escrowed funds could not be deposited.
 pub fn process_init_deposit_escrow(
     ctx: Context<InitDepositEscrowAccounts>,
     data: InitDepositEscrowData,
@@ -121,10 +142,28 @@ pub fn process_init_deposit_escrow(
         associated_token_program: _,
         system_program,
         shielded_pool_program,
+        global_config,
     } = ctx.accounts;
 
     let program_id = &crate::ID;
 
+    // Don't lock funds that could not be deposited
+    if GlobalConfigHeader::load(global_config)?.deposits_disabled() {
+        log!("init_deposit_escrow: deposits disabled");
+        return Err(ShieldedPoolError::DepositsDisabled.into());
+    }
+
+    let asset_kind = DepositEscrowAssetKind::from_u8(data.asset_kind).ok_or_else(|| {
+        log!("init_deposit_escrow: invalid asset kind");
+        ShieldedPoolError::InvalidEscrowAccount
+    })?;
+
+    // Native SOL is wrapped into a WSOL vault at deposit time
+    if asset_kind == DepositEscrowAssetKind::NativeSol && *mint.key() != WSOL_MINT {
+        log!("init_deposit_escrow: native SOL escrow requires WSOL mint");
+        return Err(ShieldedPoolError::EscrowMintMismatch.into());
+    }
+
     // ========================================================================
     // 1. VALIDATE AND CREATE ESCROW PDA
     // ========================================================================
@@ -136,9 +175,16 @@ pub fn process_init_deposit_escrow(
         return Err(pinocchio::program_error::ProgramError::InvalidSeeds);
     }
 
-    // Create the escrow account
+    // Create the escrow account (native SOL escrows hold the amount on top of rent)
     let space = DepositEscrow::ACCOUNT_SIZE;
     let rent = Rent::get()?;
+    let lamports = match asset_kind {
+        DepositEscrowAssetKind::Token => rent.minimum_balance(space),
+        DepositEscrowAssetKind::NativeSol => rent
+            .minimum_balance(space)
+            .checked_add(data.amount)
+            .ok_or(ShieldedPoolError::ArithmeticOverflow)?,
+    };
 
     let nonce_bytes = data.nonce.to_le_bytes();
     let bump_slice = [escrow_bump];
@@ -148,7 +194,7 @@ pub fn process_init_deposit_escrow(
     CreateAccount {
         from: depositor,
         to: escrow,
-        lamports: rent.minimum_balance(space),
+        lamports,
         space: space as u64,
         owner: program_id,
     }
@@ -170,21 +216,21 @@ pub fn process_init_deposit_escrow(
     // ========================================================================
 
     // Create the escrow vault ATA owned by escrow_vault_authority
-    CreateIdempotent {
-        funding_account: depositor,
-        account: escrow_vault,
-        wallet: escrow_vault_authority,
+    panchor::programs::associated_token::create_idempotent(
+        depositor,
+        escrow_vault,
+        escrow_vault_authority,
         mint,
-        system_program,
         token_program,
-    }
-    .invoke()?;
+        system_program,
+    )?;
 
     // ========================================================================
     // 4. TRANSFER TOKENS TO ESCROW VAULT
     // ========================================================================
 
-    if data.amount > 0 {
+    // Native SOL was already moved onto the escrow PDA at creation
+    if asset_kind == DepositEscrowAssetKind::Token && data.amount > 0 {
         Transfer {
             from: depositor_token_account,
             to: escrow_vault,
@@ -222,7 +268,9 @@ pub fn process_init_deposit_escrow(
         escrow_state.nonce = data.nonce;
         escrow_state.consumed = 0;
         escrow_state.bump = escrow_bump;
-        escrow_state._padding = [0u8; 6];
+        escrow_state.asset_kind = asset_kind as u8;
+        escrow_state.relayer_revision = 0;
+        escrow_state._padding = [0u8; 4];
     }
 
     log!("init_deposit_escrow: escrow created successfully");
@@ -247,6 +295,8 @@ pub fn process_init_deposit_escrow(
         expiry_slot,
         nonce: data.nonce,
         amount: data.amount,
+        asset_kind: asset_kind as u8,
+        _padding: [0u8; 7],
     };
 
     emit_event(escrow, shielded_pool_program, escrow_signer, &event)?;
@@ -6,6 +6,8 @@
 
 mod init_deposit_escrow;
 mod close_deposit_escrow;
+mod update_deposit_escrow_relayer;
 
 pub use init_deposit_escrow::*;
 pub use close_deposit_escrow::*;
+pub use update_deposit_escrow_relayer::*;
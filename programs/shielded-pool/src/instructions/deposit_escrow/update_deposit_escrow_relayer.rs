@@ -0,0 +1,160 @@
+//! Change the authorized relayer of an unconsumed deposit escrow.
+//!
+//! A depositor whose escrow is bound to a relayer that went offline can rebind
+//! it to another relayer, or clear the binding to relay it themselves, instead
+//! of waiting for expiry and paying rent and fees for a new escrow.
+//!
+//! Executions built for the old relayer then fail the escrow's relayer check in
+//! `execute_transact`. Clearing the binding opens the escrow to any relayer,
+//! the old one included; the proof it executes is still fixed by `proof_hash`.
+
+use crate::{
+    errors::ShieldedPoolError,
+    events::{DepositEscrowRelayerUpdatedEvent, emit_event},
+    pda::{find_deposit_escrow_pda, gen_deposit_escrow_seeds},
+    state::DepositEscrow,
+};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    pubkey::Pubkey,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+
+/// Instruction data for UpdateDepositEscrowRelayer.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct UpdateDepositEscrowRelayerData {
+    /// New authorized relayer pubkey, or [0;32] to allow any relayer.
+    pub new_relayer: Pubkey,
+    /// Escrow nonce (used to derive the PDA).
+    pub nonce: u64,
+}
+
+/// Accounts for UpdateDepositEscrowRelayer instruction.
+///
+/// # Account Layout
+/// 0. `[signer]` depositor - Original escrow creator
+/// 1. `[mut]` escrow - Escrow PDA ["deposit_escrow", depositor, nonce]
+/// 2. `[]` shielded_pool_program - This program (for event emission)
+#[derive(Accounts)]
+pub struct UpdateDepositEscrowRelayerAccounts<'info> {
+    /// Depositor (original escrow creator).
+    pub depositor: Signer<'info>,
+
+    /// Escrow PDA to update ["deposit_escrow", depositor, nonce]
+    #[account(mut)]
+    pub escrow: &'info AccountInfo,
+
+    /// Shielded pool program (for event emission via self-CPI)
+    #[account(address = crate::ID)]
+    pub shielded_pool_program: &'info AccountInfo,
+}
+
+/// Change the authorized relayer of a deposit escrow.
+///
+/// # Requirements
+/// - Depositor must be the original creator (verified via PDA)
+/// - Escrow must exist, not be consumed, and not be expired
+pub fn process_update_deposit_escrow_relayer(
+    ctx: Context<UpdateDepositEscrowRelayerAccounts>,
+    data: UpdateDepositEscrowRelayerData,
+) -> ProgramResult {
+    let UpdateDepositEscrowRelayerAccounts {
+        depositor,
+        escrow,
+        shielded_pool_program,
+    } = ctx.accounts;
+
+    // ========================================================================
+    // 1. VALIDATE ESCROW PDA
+    // ========================================================================
+
+    // Verify escrow PDA derivation (this also proves depositor is the creator)
+    let (expected_escrow_pda, _escrow_bump) = find_deposit_escrow_pda(depositor.key(), data.nonce);
+    if escrow.key() != &expected_escrow_pda {
+        log!("update_deposit_escrow_relayer: invalid escrow PDA");
+        return Err(ShieldedPoolError::InvalidEscrowAccount.into());
+    }
+
+    // Verify escrow is owned by this program
+    if escrow.owner() != &crate::ID {
+        log!("update_deposit_escrow_relayer: escrow not owned by program");
+        return Err(ShieldedPoolError::InvalidEscrowAccount.into());
+    }
+
+    // ========================================================================
+    // 2. LOAD AND VALIDATE ESCROW STATE
+    // ========================================================================
+
+    let mut escrow_data = escrow.try_borrow_mut_data()?;
+    if escrow_data.len() < DepositEscrow::ACCOUNT_SIZE {
+        log!("update_deposit_escrow_relayer: escrow data too small");
+        return Err(ShieldedPoolError::InvalidEscrowAccount.into());
+    }
+
+    // Verify discriminator
+    let discriminator = u64::from_le_bytes(escrow_data[..8].try_into().unwrap());
+    if discriminator != DepositEscrow::DISCRIMINATOR {
+        log!("update_deposit_escrow_relayer: invalid discriminator");
+        return Err(ShieldedPoolError::InvalidEscrowAccount.into());
+    }
+
+    let escrow_state =
+        bytemuck::from_bytes_mut::<DepositEscrow>(&mut escrow_data[8..DepositEscrow::ACCOUNT_SIZE]);
+
+    if escrow_state.is_consumed() {
+        log!("update_deposit_escrow_relayer: escrow already consumed");
+        return Err(ShieldedPoolError::EscrowAlreadyConsumed.into());
+    }
+
+    // An expired escrow can only be closed
+    if escrow_state.is_expired(Clock::get()?.slot) {
+        log!("update_deposit_escrow_relayer: escrow expired");
+        return Err(ShieldedPoolError::EscrowExpired.into());
+    }
+
+    // ========================================================================
+    // 3. UPDATE RELAYER
+    // ========================================================================
+
+    let old_relayer = escrow_state
+        .set_authorized_relayer(data.new_relayer)
+        .ok_or(ShieldedPoolError::ArithmeticOverflow)?;
+    let relayer_revision = escrow_state.relayer_revision;
+    let escrow_bump = escrow_state.bump;
+
+    // Drop borrow before the escrow PDA signs the event CPI
+    drop(escrow_data);
+
+    // ========================================================================
+    // 4. EMIT EVENT
+    // ========================================================================
+
+    let nonce_bytes = data.nonce.to_le_bytes();
+    let escrow_bump_slice = [escrow_bump];
+    let escrow_seeds = gen_deposit_escrow_seeds(depositor.key(), &nonce_bytes, &escrow_bump_slice);
+    let escrow_signer = PinocchioSigner::from(&escrow_seeds);
+
+    let event = DepositEscrowRelayerUpdatedEvent {
+        depositor: *depositor.key(),
+        escrow: *escrow.key(),
+        old_relayer,
+        new_relayer: data.new_relayer,
+        nonce: data.nonce,
+        relayer_revision,
+        _padding: [0u8; 7],
+    };
+
+    emit_event(escrow, shielded_pool_program, escrow_signer, &event)?;
+
+    log!(
+        "update_deposit_escrow_relayer: relayer updated (revision {})",
+        relayer_revision
+    );
+
+    Ok(())
+}
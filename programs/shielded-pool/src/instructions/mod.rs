@@ -6,7 +6,7 @@
 //!
 //! Instructions are organized by domain with discriminator ranges:
 //! - **0-31**: Transact instructions (private transfers)
-//! - **32-63**: Utility instructions (hashing, logging)
+//! - **32-63**: Utility instructions (hashing, logging, read-only queries)
 //! - **64-127**: Nullifier tree instructions
 //! - **192-255**: Admin instructions
 
@@ -30,13 +30,18 @@ pub use types::{
 
 // Re-export accounts and data structs
 pub use admin::{
-    AcceptAuthorityAccounts, InitializeAccounts, RegisterTokenPoolAccounts,
-    RegisterUnifiedSolPoolAccounts, SetPoolConfigActiveAccounts, SetPoolConfigActiveData,
-    SetPoolPausedAccounts, SetPoolPausedData, TransferAuthorityAccounts,
+    AcceptAuthorityAccounts, CancelAuthorityTransferAccounts, ClosePoolConfigAccounts,
+    DeregisterPoolAccounts, InitializeAccounts, MigrateGlobalConfigAccounts,
+    RegisterTokenPoolAccounts, RegisterUnifiedSolPoolAccounts, SetAllowOneEpochStaleAccounts,
+    SetAllowOneEpochStaleData, SetAuthorityTransferDelayAccounts, SetAuthorityTransferDelayData,
+    SetEventEmissionModeAccounts, SetEventEmissionModeData, SetGlobalParamsAccounts,
+    SetPoolConfigActiveAccounts, SetPoolConfigActiveData, SetPoolConfigModeAccounts,
+    SetPoolConfigModeData, SetPoolPausedAccounts, SetPoolPausedData, SetRoleAccounts, SetRoleData,
+    SetWithdrawalsOnlyAccounts, SetWithdrawalsOnlyData, TransferAuthorityAccounts,
 };
 pub use deposit_escrow::{
     CloseDepositEscrowAccounts, CloseDepositEscrowData, InitDepositEscrowAccounts,
-    InitDepositEscrowData,
+    InitDepositEscrowData, UpdateDepositEscrowRelayerAccounts, UpdateDepositEscrowRelayerData,
 };
 pub use nullifier_tree::{
     AdvanceEarliestProvableEpochAccounts, AdvanceEarliestProvableEpochData,
@@ -49,17 +54,26 @@ pub use transact::{
     UploadTransactChunkAccounts,
 };
 pub use util::{
-    LogAccounts, PoseidonHashAccounts, PoseidonHashData, TestGroth16Accounts, TestGroth16Data,
+    GetPoolStateAccounts, GetProtocolStatsAccounts, LogAccounts, PoolStateView,
+    PoseidonBenchmarkReturnData, PoseidonHashAccounts, PoseidonHashData, TestGroth16Accounts,
+    TestGroth16Data,
 };
 
 // Re-export handler functions for #[instructions] macro
 // The macro expects process_* functions to be in scope
 pub use admin::{
-    process_accept_authority, process_initialize, process_register_token_pool,
-    process_register_unified_sol_pool, process_set_pool_config_active, process_set_pool_paused,
-    process_transfer_authority,
+    process_accept_authority, process_cancel_authority_transfer, process_close_pool_config,
+    process_deregister_pool, process_initialize, process_migrate_global_config,
+    process_register_token_pool, process_register_unified_sol_pool,
+    process_set_allow_one_epoch_stale, process_set_authority_transfer_delay,
+    process_set_event_emission_mode, process_set_global_params, process_set_pool_config_active,
+    process_set_pool_config_mode, process_set_pool_paused, process_set_role,
+    process_set_withdrawals_only, process_transfer_authority,
+};
+pub use deposit_escrow::{
+    process_close_deposit_escrow, process_init_deposit_escrow,
+    process_update_deposit_escrow_relayer,
 };
-pub use deposit_escrow::{process_close_deposit_escrow, process_init_deposit_escrow};
 pub use nullifier_tree::{
     process_advance_earliest_provable_epoch, process_advance_nullifier_epoch,
     process_close_inserted_nullifier, process_close_nullifier_epoch_root,
@@ -69,7 +83,38 @@ pub use transact::{
     process_close_transact_session, process_execute_transact, process_init_transact_session,
     process_upload_transact_chunk,
 };
-pub use util::{process_log, process_poseidon_hash, process_test_groth16};
+pub use util::{
+    process_get_pool_state, process_get_protocol_stats, process_log, process_poseidon_hash,
+    process_test_groth16,
+};
+
+// Re-export client Input structs for the generated `client` module
+#[cfg(feature = "solana-sdk")]
+pub use admin::{
+    AcceptAuthorityInput, CancelAuthorityTransferInput, ClosePoolConfigInput, DeregisterPoolInput,
+    InitializeInput, MigrateGlobalConfigInput, RegisterTokenPoolInput, RegisterUnifiedSolPoolInput,
+    SetAuthorityTransferDelayInput, SetEventEmissionModeInput, SetGlobalParamsInput,
+    SetPoolConfigActiveInput, SetPoolConfigModeInput, SetPoolPausedInput, SetRoleInput,
+    SetWithdrawalsOnlyInput, TransferAuthorityInput,
+};
+#[cfg(feature = "solana-sdk")]
+pub use deposit_escrow::{
+    CloseDepositEscrowInput, InitDepositEscrowInput, UpdateDepositEscrowRelayerInput,
+};
+#[cfg(feature = "solana-sdk")]
+pub use nullifier_tree::{
+    AdvanceEarliestProvableEpochInput, AdvanceNullifierEpochInput, CloseInsertedNullifierInput,
+    CloseNullifierEpochRootInput, NullifierBatchInsertInput,
+};
+#[cfg(feature = "solana-sdk")]
+pub use transact::{
+    CloseTransactSessionInput, ExecuteTransactInput, InitTransactSessionInput,
+    UploadTransactChunkInput,
+};
+#[cfg(feature = "solana-sdk")]
+pub use util::{
+    GetPoolStateInput, GetProtocolStatsInput, LogInput, PoseidonHashInput, TestGroth16Input,
+};
 
 /// Shielded pool instruction set.
 ///
@@ -106,7 +151,8 @@ pub enum ShieldedPoolInstruction {
     // =========================================================================
     // Utility Instructions (32-63)
     // =========================================================================
-    /// Compute Poseidon hash (utility instruction for testing/verification).
+    /// Benchmark the compiled-in Poseidon backend.
+    /// Returns the hash and remaining CU before/after via return data.
     #[handler(data, accounts = PoseidonHashAccounts)]
     PoseidonHash = 32,
 
@@ -121,6 +167,16 @@ pub enum ShieldedPoolInstruction {
     #[handler(data, accounts = TestGroth16Accounts)]
     TestGroth16 = 34,
 
+    /// Read the protocol statistics counters from GlobalConfig.
+    /// Returns `ProtocolStats` via return data; intended for simulation.
+    #[handler(accounts = GetProtocolStatsAccounts, returns = crate::state::ProtocolStats)]
+    GetProtocolStats = 35,
+
+    /// Read the tree roots, indices and epochs needed to build a proof.
+    /// Returns `PoolStateView` via return data; intended for simulation.
+    #[handler(accounts = GetPoolStateAccounts, returns = PoolStateView)]
+    GetPoolState = 36,
+
     // =========================================================================
     // Nullifier Tree Instructions (64-127)
     // Discriminators 64 and 67 were removed (InitNullifierTree, SingleInsertNullifier)
@@ -160,6 +216,11 @@ pub enum ShieldedPoolInstruction {
     #[handler(data, accounts = CloseDepositEscrowAccounts)]
     CloseDepositEscrow = 129,
 
+    /// Change or clear the authorized relayer of an unconsumed, unexpired escrow.
+    /// Callable only by the original depositor.
+    #[handler(data, accounts = UpdateDepositEscrowRelayerAccounts)]
+    UpdateDepositEscrowRelayer = 130,
+
     // =========================================================================
     // Admin Instructions (192-255) - Pool initialization and configuration
     // =========================================================================
@@ -168,6 +229,7 @@ pub enum ShieldedPoolInstruction {
     Initialize = 192,
 
     /// Set the paused state for the pool.
+    /// Callable by the authority or the pauser role.
     #[handler(data, accounts = SetPoolPausedAccounts)]
     SetPoolPaused = 193,
 
@@ -195,4 +257,56 @@ pub enum ShieldedPoolInstruction {
     /// Must be called by the pending_authority address.
     #[handler(accounts = AcceptAuthorityAccounts)]
     AcceptAuthority = 198,
+
+    /// Cancel a pending authority transfer before it is accepted.
+    /// Must be called by the current authority.
+    #[handler(accounts = CancelAuthorityTransferAccounts)]
+    CancelAuthorityTransfer = 199,
+
+    /// Set the authority transfer timelock (in slots, with a minimum).
+    #[handler(data, accounts = SetAuthorityTransferDelayAccounts)]
+    SetAuthorityTransferDelay = 200,
+
+    /// Assign or clear a delegated admin role (pauser, fee_admin, registrar).
+    #[handler(data, accounts = SetRoleAccounts)]
+    SetRole = 201,
+
+    /// Deregister a pool with zero balances from the hub.
+    /// The asset stops routing; the PoolConfig becomes closable after a grace period.
+    #[handler(accounts = DeregisterPoolAccounts)]
+    DeregisterPool = 202,
+
+    /// Close a deregistered PoolConfig after its grace period and reclaim rent.
+    #[handler(accounts = ClosePoolConfigAccounts)]
+    ClosePoolConfig = 203,
+
+    /// Set tunable protocol parameters from a list of (param_id, value) entries.
+    /// Callable by the authority or the fee_admin role.
+    #[handler(raw_data, accounts = SetGlobalParamsAccounts)]
+    SetGlobalParams = 204,
+
+    /// Realloc GlobalConfig to the current layout and run version upgrades.
+    /// Must be called by the authority; the payer covers additional rent.
+    #[handler(accounts = MigrateGlobalConfigAccounts)]
+    MigrateGlobalConfig = 205,
+
+    /// Set the deposit/withdrawal mode bits for a pool config.
+    /// Allows blocking one direction (e.g. deposits) while keeping the other.
+    #[handler(data, accounts = SetPoolConfigModeAccounts)]
+    SetPoolConfigMode = 206,
+
+    /// Enable or disable hub-wide withdrawal-only mode (deposits blocked).
+    /// Callable by the authority or the pauser role.
+    #[handler(data, accounts = SetWithdrawalsOnlyAccounts)]
+    SetWithdrawalsOnly = 207,
+
+    /// Switch event emission between self-CPI and direct sol_log_data.
+    /// Must be called by the authority.
+    #[handler(data, accounts = SetEventEmissionModeAccounts)]
+    SetEventEmissionMode = 208,
+
+    /// Accept token pool accumulators one finalization stale, shortly after finalizing.
+    /// Must be called by the authority.
+    #[handler(data, accounts = SetAllowOneEpochStaleAccounts)]
+    SetAllowOneEpochStale = 209,
 }
@@ -4,7 +4,7 @@ use crate::{
     errors::ShieldedPoolError,
     events::{NullifierEarliestEpochAdvancedEvent, emit_event},
     pda::gen_global_config_seeds,
-    state::{GlobalConfig, MIN_PROVABLE_NULLIFIER_EPOCHS, NullifierIndexedTree},
+    state::{GlobalConfigHeader, NullifierIndexedTree},
 };
 use bytemuck::{Pod, Zeroable};
 use panchor::prelude::*;
@@ -21,8 +21,8 @@ pub struct AdvanceEarliestProvableEpochAccounts<'info> {
     #[account(mut)]
     pub nullifier_tree: AccountLoader<'info, NullifierIndexedTree>,
 
-    /// Global config PDA for authority verification and event signing
-    pub global_config: AccountLoader<'info, GlobalConfig>,
+    /// Global config PDA for authority verification and event signing (any layout version)
+    pub global_config: &'info AccountInfo,
 
     /// Must be global config authority
     pub authority: Signer<'info>,
@@ -40,8 +40,9 @@ pub struct AdvanceEarliestProvableEpochAccounts<'info> {
 #[repr(C)]
 #[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
 pub struct AdvanceEarliestProvableEpochData {
-    /// The new earliest provable epoch value
-    pub new_earliest_epoch: u64,
+    /// Epochs to advance by, capped at `MAX_EARLIEST_EPOCH_ADVANCE` and at
+    /// what the provable window allows
+    pub max_epochs: u64,
 }
 
 // ============================================================================
@@ -51,9 +52,13 @@ pub struct AdvanceEarliestProvableEpochData {
 /// Advance the earliest provable epoch for the nullifier tree.
 ///
 /// This allows old EpochRootAccount PDAs to be closed and their rent reclaimed.
-/// The new value must be:
-/// - >= current earliest_provable_epoch (no going back)
-/// - <= current_epoch - MIN_PROVABLE_NULLIFIER_EPOCHS (maintain coverage invariant)
+/// The target is computed from on-chain state (see
+/// `NullifierIndexedTree::earliest_provable_epoch_target`): at most
+/// `max_epochs` (and `MAX_EARLIEST_EPOCH_ADVANCE`) forward, and never past
+/// `current_epoch - MIN_PROVABLE_NULLIFIER_EPOCHS`. A tree that is already
+/// caught up is left unchanged and no event is emitted.
+///
+/// Emits one `NullifierEarliestEpochAdvancedEvent` per epoch advanced.
 pub fn process_advance_earliest_provable_epoch(
     ctx: Context<AdvanceEarliestProvableEpochAccounts>,
     data: AdvanceEarliestProvableEpochData,
@@ -61,58 +66,37 @@ pub fn process_advance_earliest_provable_epoch(
     let accounts = ctx.accounts;
 
     // Get global config bump and verify authority
-    let global_config_bump = accounts.global_config.map(|config| config.bump)?;
-    accounts.global_config.try_inspect(|config| {
-        if *accounts.authority.key() != config.authority {
-            return Err(ShieldedPoolError::Unauthorized.into());
-        }
-        Ok(())
-    })?;
-
-    // Capture old_epoch and update tree
-    let old_epoch = accounts.nullifier_tree.try_map_mut(|tree| {
-        // Capture old value before update
+    let config = GlobalConfigHeader::load(accounts.global_config)?;
+    if *accounts.authority.key() != config.authority {
+        return Err(ShieldedPoolError::Unauthorized.into());
+    }
+    let global_config_bump = config.bump;
+
+    // Clamp the target and update tree
+    let (old_epoch, new_epoch) = accounts.nullifier_tree.try_map_mut(|tree| {
         let old_epoch = tree.earliest_provable_epoch;
-
-        // Verify new value is >= current (no going back)
-        if data.new_earliest_epoch < old_epoch {
-            return Err(ShieldedPoolError::InvalidEarliestEpoch.into());
-        }
-
-        // Verify current_epoch >= MIN_PROVABLE_NULLIFIER_EPOCHS to prevent underflow
-        if tree.current_epoch < MIN_PROVABLE_NULLIFIER_EPOCHS {
-            return Err(ShieldedPoolError::InvalidEarliestEpoch.into());
-        }
-
-        // Verify new value maintains coverage invariant
-        // new_earliest <= current_epoch - MIN_PROVABLE_NULLIFIER_EPOCHS
-        let max_allowed = tree.current_epoch - MIN_PROVABLE_NULLIFIER_EPOCHS;
-        if data.new_earliest_epoch > max_allowed {
-            return Err(ShieldedPoolError::InvalidEarliestEpoch.into());
-        }
-
-        // Update earliest_provable_epoch
-        tree.earliest_provable_epoch = data.new_earliest_epoch;
-
-        Ok(old_epoch)
+        let new_epoch = tree.earliest_provable_epoch_target(data.max_epochs);
+        tree.earliest_provable_epoch = new_epoch;
+        Ok((old_epoch, new_epoch))
     })?;
 
-    // Emit event
+    // Emit one event per epoch advanced
     let bump_bytes = [global_config_bump];
     let signer_seeds = gen_global_config_seeds(&bump_bytes);
-    let signer = CpiSigner::from(&signer_seeds);
-
-    let event = NullifierEarliestEpochAdvancedEvent {
-        old_epoch,
-        new_epoch: data.new_earliest_epoch,
-    };
-
-    emit_event(
-        accounts.global_config.account_info(),
-        accounts.shielded_pool_program,
-        signer,
-        &event,
-    )?;
+
+    for epoch in old_epoch..new_epoch {
+        let event = NullifierEarliestEpochAdvancedEvent {
+            old_epoch: epoch,
+            new_epoch: epoch + 1,
+        };
+
+        emit_event(
+            accounts.global_config,
+            accounts.shielded_pool_program,
+            CpiSigner::from(&signer_seeds),
+            &event,
+        )?;
+    }
 
     Ok(())
 }
@@ -4,7 +4,7 @@ use crate::{
     errors::ShieldedPoolError,
     events::{NullifierEpochAdvancedEvent, emit_event},
     pda::{find_nullifier_epoch_root_pda, gen_nullifier_epoch_root_seeds, gen_global_config_seeds},
-    state::{NullifierEpochRoot, GlobalConfig, NullifierIndexedTree, MIN_SLOTS_PER_NULLIFIER_EPOCH},
+    state::{NullifierEpochRoot, GlobalConfig, NullifierIndexedTree},
 };
 use panchor::{SetDiscriminator, prelude::*};
 use pinocchio::{
@@ -61,7 +61,7 @@ pub struct AdvanceNullifierEpochAccounts<'info> {
 /// verify against the finalized nullifier epoch root.
 ///
 /// **Requirements**:
-/// - At least `MIN_SLOTS_PER_NULLIFIER_EPOCH` slots have passed since the last epoch advance
+/// - At least `GlobalConfig.nullifier_epoch_min_slots` slots have passed since the last epoch advance
 ///
 /// Anyone can call this instruction (permissionless).
 pub fn process_advance_nullifier_epoch(
@@ -76,8 +76,9 @@ pub fn process_advance_nullifier_epoch(
         shielded_pool_program,
     } = ctx.accounts;
 
-    // Get global config bump for event signing
-    let global_config_bump = global_config.map(|config| config.bump)?;
+    // Get global config bump for event signing and the epoch interval
+    let (global_config_bump, epoch_min_slots) =
+        global_config.map(|config| (config.bump, config.nullifier_epoch_min_slots))?;
 
     // Get current slot for time-based epoch advancement check
     let current_slot = Clock::get()?.slot;
@@ -87,7 +88,7 @@ pub fn process_advance_nullifier_epoch(
         nullifier_tree.try_map_mut(|tree| {
             // Check if enough slots have passed since the last epoch advance.
             // This ensures epochs advance at a regular time-based interval.
-            if current_slot < tree.last_epoch_slot.saturating_add(MIN_SLOTS_PER_NULLIFIER_EPOCH) {
+            if current_slot < tree.last_epoch_slot.saturating_add(epoch_min_slots) {
                 return Err(ShieldedPoolError::EpochAdvanceTooSoon.into());
             }
 
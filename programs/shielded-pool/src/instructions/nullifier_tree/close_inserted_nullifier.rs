@@ -14,7 +14,9 @@ use crate::{
     errors::ShieldedPoolError,
     events::{NullifierPdaClosedEvent, emit_event},
     pda::gen_global_config_seeds,
-    state::{CLEANUP_GRACE_EPOCHS, GlobalConfig, Nullifier, NullifierIndexedTree},
+    state::{
+        CLEANUP_GRACE_EPOCHS, GlobalConfigHeader, Nullifier, NullifierIndexedTree, ProtocolStats,
+    },
 };
 use panchor::prelude::*;
 use pinocchio::{ProgramResult, account_info::AccountInfo, instruction::Signer as CpiSigner};
@@ -47,8 +49,9 @@ pub struct CloseInsertedNullifierAccounts<'info> {
     /// After grace period: can be anyone (permissionless GC)
     pub authority: Signer<'info>,
 
-    /// Global config PDA for event signing
-    pub global_config: AccountLoader<'info, GlobalConfig>,
+    /// Global config PDA for event signing and protocol stats (any layout version)
+    #[account(mut)]
+    pub global_config: &'info AccountInfo,
 
     /// Shielded pool program (for event emission via self-CPI)
     #[account(address = crate::ID)]
@@ -86,7 +89,7 @@ pub fn process_close_inserted_nullifier(
         nullifier_tree.map(|tree| (tree.current_epoch, tree.earliest_provable_epoch))?;
 
     // Get global config bump for event signing
-    let global_config_bump = global_config.map(|config| config.bump)?;
+    let global_config_bump = GlobalConfigHeader::load(global_config)?.bump;
 
     // Verify nullifier account state and authorization, capture inserted_epoch for event
     let inserted_epoch = nullifier_pda.try_map(|nullifier_account| {
@@ -145,6 +148,8 @@ pub fn process_close_inserted_nullifier(
     drop(data);
     nullifier_pda.resize(0)?;
 
+    ProtocolStats::update(global_config, |stats| stats.record_rent_reclaimed(lamports))?;
+
     // Emit NullifierPdaClosedEvent
     let bump_bytes = [global_config_bump];
     let signer_seeds = gen_global_config_seeds(&bump_bytes);
@@ -156,7 +161,7 @@ pub fn process_close_inserted_nullifier(
     };
 
     emit_event(
-        global_config.account_info(),
+        global_config,
         shielded_pool_program,
         CpiSigner::from(&signer_seeds),
         &event,
@@ -26,7 +26,7 @@ use crate::{
     errors::ShieldedPoolError,
     events::{NullifierEpochRootClosedEvent, emit_event},
     pda::{find_nullifier_epoch_root_pda, gen_global_config_seeds},
-    state::{NullifierEpochRoot, GlobalConfig, NullifierIndexedTree},
+    state::{GlobalConfigHeader, NullifierEpochRoot, NullifierIndexedTree, ProtocolStats},
 };
 use panchor::prelude::*;
 use pinocchio::{ProgramResult, account_info::AccountInfo, instruction::Signer as CpiSigner};
@@ -49,8 +49,10 @@ pub struct CloseNullifierEpochRootAccounts<'info> {
     #[account(mut)]
     pub destination: &'info AccountInfo,
 
-    /// Global config PDA for authority verification and event signing
-    pub global_config: AccountLoader<'info, GlobalConfig>,
+    /// Global config PDA for authority verification, event signing and protocol
+    /// stats (any layout version)
+    #[account(mut)]
+    pub global_config: &'info AccountInfo,
 
     /// Must be global config authority
     pub authority: Signer<'info>,
@@ -79,12 +81,11 @@ pub fn process_close_nullifier_epoch_root(ctx: Context<CloseNullifierEpochRootAc
     } = ctx.accounts;
 
     // Verify authority matches global config and get bump for event signing
-    let global_config_bump = global_config.try_map(|config| {
-        if *authority.key() != config.authority {
-            return Err(ShieldedPoolError::Unauthorized.into());
-        }
-        Ok(config.bump)
-    })?;
+    let config = GlobalConfigHeader::load(global_config)?;
+    if *authority.key() != config.authority {
+        return Err(ShieldedPoolError::Unauthorized.into());
+    }
+    let global_config_bump = config.bump;
 
     // Load nullifier tree to check earliest_provable_epoch
     let earliest_provable_epoch = nullifier_tree.map(|tree| tree.earliest_provable_epoch)?;
@@ -129,6 +130,8 @@ pub fn process_close_nullifier_epoch_root(ctx: Context<CloseNullifierEpochRootAc
     drop(data);
     nullifier_epoch_root_pda.resize(0)?;
 
+    ProtocolStats::update(global_config, |stats| stats.record_rent_reclaimed(lamports))?;
+
     // Emit NullifierEpochRootClosedEvent
     let bump_bytes = [global_config_bump];
     let signer_seeds = gen_global_config_seeds(&bump_bytes);
@@ -139,7 +142,7 @@ pub fn process_close_nullifier_epoch_root(ctx: Context<CloseNullifierEpochRootAc
     };
 
     emit_event(
-        global_config.account_info(),
+        global_config,
         shielded_pool_program,
         CpiSigner::from(&signer_seeds),
         &event,
@@ -37,5 +37,17 @@ pub use close_nullifier_epoch_root::process_close_nullifier_epoch_root;
 pub use nullifier_batch_insert::NullifierBatchInsertAccounts;
 pub use nullifier_batch_insert::process_nullifier_batch_insert;
 
+// Re-export client Input structs (generated by #[derive(Accounts)])
+#[cfg(feature = "solana-sdk")]
+pub use advance_earliest_provable_epoch::AdvanceEarliestProvableEpochInput;
+#[cfg(feature = "solana-sdk")]
+pub use advance_nullifier_epoch::AdvanceNullifierEpochInput;
+#[cfg(feature = "solana-sdk")]
+pub use close_inserted_nullifier::CloseInsertedNullifierInput;
+#[cfg(feature = "solana-sdk")]
+pub use close_nullifier_epoch_root::CloseNullifierEpochRootInput;
+#[cfg(feature = "solana-sdk")]
+pub use nullifier_batch_insert::NullifierBatchInsertInput;
+
 // Re-export constants
 pub use nullifier_batch_insert::MAX_NULLIFIER_BATCH_SIZE;
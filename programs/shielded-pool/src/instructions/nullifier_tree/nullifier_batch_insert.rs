@@ -27,15 +27,18 @@
 //! - Subtrees are updated directly from the proof data
 
 use crate::{
+    cu_trace,
     errors::ShieldedPoolError,
     events::{NullifierBatchInsertedEvent, NullifierLeafInsertedEvent, emit_event},
     groth16::{CompressedGroth16Proof, Groth16Verifyingkey, verify_groth16},
     instructions::types::{NullifierBatchInsertData, NullifierBatchInsertProof},
     pda::gen_global_config_seeds,
-    state::{GlobalConfig, NULLIFIER_TREE_HEIGHT, Nullifier, NullifierIndexedTree},
+    state::{
+        GlobalConfigHeader, NULLIFIER_TREE_HEIGHT, Nullifier, NullifierIndexedTree, ProtocolStats,
+    },
     verifying_keys::{
-        N_PUBLIC_INPUTS_BATCH_4, N_PUBLIC_INPUTS_BATCH_16, N_PUBLIC_INPUTS_BATCH_64,
-        NULLIFIER_BATCH_VK_4, NULLIFIER_BATCH_VK_16, NULLIFIER_BATCH_VK_64,
+        CircuitId, N_PUBLIC_INPUTS_BATCH_4, N_PUBLIC_INPUTS_BATCH_16, N_PUBLIC_INPUTS_BATCH_64,
+        vk_for,
     },
 };
 use panchor::prelude::*;
@@ -58,8 +61,9 @@ pub struct NullifierBatchInsertAccounts<'info> {
     #[account(mut)]
     pub nullifier_tree: AccountLoader<'info, NullifierIndexedTree>,
 
-    /// Global config PDA for event signing
-    pub global_config: AccountLoader<'info, GlobalConfig>,
+    /// Global config PDA for event signing and protocol stats (any layout version)
+    #[account(mut)]
+    pub global_config: &'info AccountInfo,
 
     /// Shielded pool program (for event emission via self-CPI)
     #[account(address = crate::ID)]
@@ -92,9 +96,9 @@ fn verify_nullifier_batch_proof(
 ) -> Result<bool, ProgramError> {
     // Select verification key based on batch size
     let vk: &Groth16Verifyingkey = match batch_size {
-        1..=4 => &NULLIFIER_BATCH_VK_4,
-        5..=16 => &NULLIFIER_BATCH_VK_16,
-        17..=64 => &NULLIFIER_BATCH_VK_64,
+        1..=4 => vk_for(CircuitId::NullifierBatch4),
+        5..=16 => vk_for(CircuitId::NullifierBatch16),
+        17..=64 => vk_for(CircuitId::NullifierBatch64),
         _ => return Err(ShieldedPoolError::InvalidBatchSize.into()),
     };
 
@@ -209,7 +213,7 @@ pub fn process_nullifier_batch_insert(
     } = ctx.accounts;
 
     // Get global config bump for event signing
-    let global_config_bump = global_config.map(|config| config.bump)?;
+    let global_config_bump = GlobalConfigHeader::load(global_config)?.bump;
 
     // Get nullifier PDAs from remaining accounts
     let nullifier_pdas = ctx.remaining_accounts;
@@ -222,6 +226,7 @@ pub fn process_nullifier_batch_insert(
     let (starting_index, current_root, current_epoch) =
         nullifier_tree.map(|tree| (tree.next_index, tree.root, tree.current_epoch))?;
 
+    cu_trace::probe("validate");
     // Verify old_root matches current tree root
     if current_root != proof.old_root {
         log!("Old root mismatch: proof.old_root does not match tree.root");
@@ -276,6 +281,7 @@ pub fn process_nullifier_batch_insert(
         })?;
     }
 
+    cu_trace::probe("proof");
     // Verify Groth16 proof
     // AUDIT NOTE: starting_index is captured from tree.next_index BEFORE nullifier PDAs are
     // presented. This ensures the ZK proof commits to the correct insertion position regardless
@@ -285,6 +291,7 @@ pub fn process_nullifier_batch_insert(
         return Err(ShieldedPoolError::InvalidProof.into());
     }
 
+    cu_trace::probe("insert");
     // Mark all nullifiers as inserted with current epoch (idempotent)
     // If already set to current_epoch, skip (allows retry after partial failure)
     for nullifier_pda in nullifier_pdas.iter() {
@@ -315,8 +322,14 @@ pub fn process_nullifier_batch_insert(
         Ok(())
     })?;
 
+    // Unmigrated configs have no stats and are left as-is
+    ProtocolStats::update(global_config, |stats| {
+        stats.record_nullifiers(batch_size as u64)
+    })?;
+
     log!("ZK batch insert: {} nullifiers inserted", batch_size);
 
+    cu_trace::probe("events");
     // Emit events
     let bump_bytes = [global_config_bump];
     let signer_seeds = gen_global_config_seeds(&bump_bytes);
@@ -331,7 +344,7 @@ pub fn process_nullifier_batch_insert(
         };
 
         emit_event(
-            global_config.account_info(),
+            global_config,
             shielded_pool_program,
             CpiSigner::from(&signer_seeds),
             &leaf_event,
@@ -349,11 +362,12 @@ pub fn process_nullifier_batch_insert(
     };
 
     emit_event(
-        global_config.account_info(),
+        global_config,
         shielded_pool_program,
         CpiSigner::from(&signer_seeds),
         &batch_event,
     )?;
 
+    cu_trace::probe("end");
     Ok(())
 }
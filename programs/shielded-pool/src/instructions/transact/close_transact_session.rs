@@ -2,6 +2,7 @@
 
 use crate::{
     errors::ShieldedPoolError,
+    events::{TransactSessionCloseReason, TransactSessionClosedEvent, log_event},
     state::{SESSION_EXPIRY_SLOTS, TransactSession},
 };
 use panchor::prelude::*;
@@ -29,6 +30,10 @@ pub struct CloseTransactSessionAccounts<'info> {
 /// Can be called by:
 /// - The session authority at any time
 /// - Anyone after SESSION_EXPIRY_SLOTS (~24 hours) have passed since creation
+///
+/// Logs a `TransactSessionClosedEvent` whose reason is `Executed` if
+/// `execute_transact` consumed the session, otherwise `Expired` once past
+/// expiry, otherwise `Cancelled`.
 pub fn process_close_transact_session(ctx: Context<CloseTransactSessionAccounts>) -> ProgramResult {
     let CloseTransactSessionAccounts {
         transact_session: transact_session_account,
@@ -38,9 +43,13 @@ pub fn process_close_transact_session(ctx: Context<CloseTransactSessionAccounts>
     let program_id = &crate::ID;
 
     // Load header to validate and check authorization, extract values, then drop borrow
-    let (session_authority, session_created_slot) = {
+    let (session_authority, session_created_slot, session_executed) = {
         let session = TransactSession::load_header(transact_session_account, program_id)?;
-        (session.authority, session.created_slot)
+        (
+            session.authority,
+            session.created_slot,
+            session.is_executed(),
+        )
     };
 
     // Check authorization: either authority OR session has expired
@@ -76,5 +85,22 @@ pub fn process_close_transact_session(ctx: Context<CloseTransactSessionAccounts>
 
     log!("close_transact_session: session closed successfully");
 
+    let reason = if session_executed {
+        TransactSessionCloseReason::Executed
+    } else if is_expired {
+        TransactSessionCloseReason::Expired
+    } else {
+        TransactSessionCloseReason::Cancelled
+    };
+
+    log_event(&TransactSessionClosedEvent {
+        session: *transact_session_account.key(),
+        rent_refunded_to: *closer.key(),
+        lamports_refunded: lamports,
+        reason: reason as u8,
+        _padding: [0u8; 7],
+        slot: clock.slot,
+    });
+
     Ok(())
 }
@@ -38,7 +38,7 @@
 //! | 2 | receipt_tree | W | - | Receipt merkle tree |
 //! | 3 | nullifier_indexed_tree | W | - | Nullifier indexed merkle tree |
 //! | 4 | epoch_root_pda | - | - | Epoch root PDA (optional) |
-//! | 5 | global_config | - | - | Global pool config |
+//! | 5 | global_config | W | - | Global pool config (protocol stats) |
 //! | 6-9 | nullifiers[N_INS] | W | - | Nullifier PDAs (4 slots) |
 //! | 10 | depositor | - | S | Deposit authorizer (conditional) |
 //! | 11 | relayer | - | S | Relayer (conditional signer) |
@@ -47,7 +47,7 @@
 //! | 14 | payer | W | S | Rent payer |
 //!
 //! ## Dynamic Accounts (remaining_accounts, based on unique_asset_count)
-//! Pool accounts loaded based on pool_type from PoolConfig:
+//! Pool accounts loaded based on the registry entry for PoolConfig's pool_kind:
 //! - Token pool: 3 accounts (pool_config, token_pool_config, vault)
 //! - UnifiedSol pool: 4 accounts (pool_config, unified_sol_pool_config, lst_config, vault)
 //!
@@ -55,11 +55,23 @@
 
 use crate::{
     errors::ShieldedPoolError,
-    state::{HubPoolType, PoolConfig},
+    instructions::types::N_PUBLIC_LINES,
+    pda::HUB_AUTHORITY_ADDRESS,
+    pool_registry::pool_kind_entry,
+    state::{
+        LstConfig, PoolConfig, TokenPoolConfig, UnifiedSolPoolConfig, find_lst_config_pda,
+        find_token_pool_config_pda, find_unified_sol_pool_config_pda,
+    },
+    validation::require_valid_token_account,
 };
 use alloc::collections::BTreeMap;
-use panchor::AccountLoader;
+use panchor::{AccountLoader, account_loaders::load_pairs};
 use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use pinocchio_contrib::AccountAssertions;
+use zorb_pool_interface::{
+    DepositCpiAccounts, MAX_WITHDRAW_FALLBACK_VAULTS, MAX_WITHDRAW_ROUTE_VAULTS, PoolCpi, PoolType,
+    TOKEN_POOL_PROGRAM_ID, UNIFIED_SOL_POOL_PROGRAM_ID, WithdrawCpiAccounts,
+};
 
 // ============================================================================
 // Reward Config and Slot Accounts
@@ -83,6 +95,12 @@ use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::
 /// - `None (0)`: Inactive slot, 0 accounts
 /// - `Token (1)`: 8 accounts (3 pool + 3 escrow + 2 user tokens)
 /// - `UnifiedSol (2)`: 9 accounts (4 pool + 3 escrow + 2 user tokens)
+/// - `UnifiedSolUnwrap (3)`: UnifiedSol + 2 unwrap accounts (WSOL withdrawals
+///   paid to the recipient as native SOL)
+/// - `UnifiedSolRouted (4)`: UnifiedSol + 3 accounts per route leg (withdrawals
+///   the pool fills from several LST vaults)
+/// - `UnifiedSolFallback (5)`: UnifiedSol + 3 accounts per fallback LST
+///   (withdrawals the pool tops up from caller-named LST vaults)
 ///
 /// # Account Layout Changes (v2 - Per-Slot Escrow)
 ///
@@ -109,6 +127,18 @@ pub enum SlotPoolType {
     Token = 1,
     /// Unified SOL pool: 9 accounts (4 pool + 3 escrow + 2 user tokens).
     UnifiedSol = 2,
+    /// Unified SOL pool WSOL withdrawal unwrapped to native SOL: UnifiedSol
+    /// accounts + unwrap_account + native_mint. The recipient is a system
+    /// account rather than a token account.
+    UnifiedSolUnwrap = 3,
+    /// Unified SOL pool withdrawal routed across LST vaults: UnifiedSol
+    /// accounts + [`RouteLegAccounts`] for each leg in
+    /// `ExecuteTransactData.route_leg_counts`.
+    UnifiedSolRouted = 4,
+    /// Unified SOL pool withdrawal with caller-named fallback LSTs: UnifiedSol
+    /// accounts + [`RouteLegAccounts`] for each fallback in
+    /// `ExecuteTransactData.route_leg_counts` (1 to `MAX_WITHDRAW_FALLBACK_VAULTS`).
+    UnifiedSolFallback = 5,
 }
 
 impl SlotPoolType {
@@ -118,18 +148,46 @@ impl SlotPoolType {
             0 => Some(SlotPoolType::None),
             1 => Some(SlotPoolType::Token),
             2 => Some(SlotPoolType::UnifiedSol),
+            3 => Some(SlotPoolType::UnifiedSolUnwrap),
+            4 => Some(SlotPoolType::UnifiedSolRouted),
+            5 => Some(SlotPoolType::UnifiedSolFallback),
             _ => None,
         }
     }
 
-    /// Number of accounts this pool type consumes.
+    /// The pool program this slot type invokes (`None` for inactive slots).
+    pub fn pool_type(&self) -> Option<PoolType> {
+        match self {
+            SlotPoolType::None => None,
+            SlotPoolType::Token => Some(PoolType::Token),
+            SlotPoolType::UnifiedSol
+            | SlotPoolType::UnifiedSolUnwrap
+            | SlotPoolType::UnifiedSolRouted
+            | SlotPoolType::UnifiedSolFallback => Some(PoolType::UnifiedSol),
+        }
+    }
+
+    /// Number of accounts this pool type consumes (before route legs).
     pub fn account_count(&self) -> usize {
+        // Token: pool_config, token_pool_config, vault + escrow, escrow_vault_authority, escrow_token + recipient_token, relayer_token + pool_program
+        // UnifiedSol: pool_config, unified_sol_pool_config, lst_config, vault + escrow, escrow_vault_authority, escrow_token + recipient_token, relayer_token + pool_program
+        let pool_accounts = self
+            .pool_type()
+            .map_or(0, |pool| pool.expected_account_count());
+        match self {
+            // UnifiedSol + unwrap_account, native_mint
+            SlotPoolType::UnifiedSolUnwrap => pool_accounts + UNWRAP_SLOT_ACCOUNT_COUNT,
+            // UnifiedSol, then ROUTE_LEG_ACCOUNT_COUNT per route leg
+            _ => pool_accounts,
+        }
+    }
+
+    /// Whether this pool type takes `legs` route legs.
+    pub fn accepts_route_legs(&self, legs: usize) -> bool {
         match self {
-            SlotPoolType::None => 0,
-            // pool_config, token_pool_config, vault + escrow, escrow_vault_authority, escrow_token + recipient_token, relayer_token + pool_program
-            SlotPoolType::Token => 9,
-            // pool_config, unified_sol_pool_config, lst_config, vault + escrow, escrow_vault_authority, escrow_token + recipient_token, relayer_token + pool_program
-            SlotPoolType::UnifiedSol => 10,
+            SlotPoolType::UnifiedSolRouted => legs <= MAX_WITHDRAW_ROUTE_VAULTS,
+            SlotPoolType::UnifiedSolFallback => (1..=MAX_WITHDRAW_FALLBACK_VAULTS).contains(&legs),
+            _ => legs == 0,
         }
     }
 }
@@ -221,6 +279,39 @@ pub struct TokenSlotAccounts<'a> {
     pub pool_program: &'a AccountInfo,
 }
 
+impl<'a> TokenSlotAccounts<'a> {
+    /// Accounts for the token pool deposit CPI (escrow vault as depositor).
+    pub fn deposit_cpi_accounts(&self, token_program: &'a AccountInfo) -> DepositCpiAccounts<'a> {
+        DepositCpiAccounts {
+            pool_state: None,
+            pool_config: self.token_pool_config,
+            vault: self.vault,
+            depositor_token: self.escrow_token,
+            depositor: self.escrow_vault_authority,
+            token_program,
+            pool_program: self.pool_program,
+        }
+    }
+
+    /// Accounts for the token pool withdraw CPI.
+    pub fn withdraw_cpi_accounts(
+        &self,
+        hub_authority: &'a AccountInfo,
+        token_program: &'a AccountInfo,
+    ) -> WithdrawCpiAccounts<'a> {
+        WithdrawCpiAccounts {
+            pool_state: None,
+            pool_config: self.token_pool_config,
+            vault: self.vault,
+            hub_authority,
+            token_program,
+            pool_program: self.pool_program,
+            unwrap: None,
+            route: &[],
+        }
+    }
+}
+
 /// Unified SOL slot accounts (10 accounts).
 /// Pool accounts + escrow accounts + user token accounts + pool program.
 ///
@@ -238,6 +329,11 @@ pub struct TokenSlotAccounts<'a> {
 /// [8]     relayer_token           SPL Token          Relayer fee destination
 /// [9]     pool_program            (Executable)       Unified SOL pool program for CPI
 /// ```
+///
+/// `UnifiedSolUnwrap` slots append [`UnwrapSlotAccounts`] at [10] and [11],
+/// and [7] is the recipient's system account instead of a token account.
+/// `UnifiedSolRouted` and `UnifiedSolFallback` slots append
+/// [`RouteLegAccounts`] from [10].
 #[derive(Clone, Copy)]
 pub struct UnifiedSolSlotAccounts<'a> {
     // Pool accounts (4)
@@ -267,6 +363,106 @@ pub struct UnifiedSolSlotAccounts<'a> {
     // Program account (1)
     /// Unified SOL pool program for CPI.
     pub pool_program: &'a AccountInfo,
+    // Unwrap accounts (UnifiedSolUnwrap slots only)
+    /// Native SOL unwrap accounts; recipient_token is then a system account.
+    pub unwrap: Option<UnwrapSlotAccounts<'a>>,
+    // Route legs (UnifiedSolRouted and UnifiedSolFallback slots only)
+    /// Route leg accounts, `ROUTE_LEG_ACCOUNT_COUNT` per leg.
+    pub route: Option<&'a [AccountInfo]>,
+    /// Whether the route legs are caller-named fallback LSTs rather than the
+    /// pool's priority list.
+    pub fallback: bool,
+}
+
+impl<'a> UnifiedSolSlotAccounts<'a> {
+    /// Accounts for the unified SOL pool deposit CPI (escrow vault as depositor).
+    pub fn deposit_cpi_accounts(&self, token_program: &'a AccountInfo) -> DepositCpiAccounts<'a> {
+        DepositCpiAccounts {
+            pool_state: Some(self.unified_sol_pool_config),
+            pool_config: self.lst_config,
+            vault: self.vault,
+            depositor_token: self.escrow_token,
+            depositor: self.escrow_vault_authority,
+            token_program,
+            pool_program: self.pool_program,
+        }
+    }
+
+    /// Accounts for the unified SOL pool withdraw CPI, without unwrap or
+    /// route accounts.
+    pub fn withdraw_cpi_accounts(
+        &self,
+        hub_authority: &'a AccountInfo,
+        token_program: &'a AccountInfo,
+    ) -> WithdrawCpiAccounts<'a> {
+        WithdrawCpiAccounts {
+            pool_state: Some(self.unified_sol_pool_config),
+            pool_config: self.lst_config,
+            vault: self.vault,
+            hub_authority,
+            token_program,
+            pool_program: self.pool_program,
+            unwrap: None,
+            route: &[],
+        }
+    }
+
+    /// Route legs in the pool's priority order, or the caller's order for
+    /// fallback LSTs (empty unless routed).
+    pub fn route_legs(&self) -> impl Iterator<Item = RouteLegAccounts<'a>> {
+        self.route
+            .unwrap_or_default()
+            .as_chunks::<ROUTE_LEG_ACCOUNT_COUNT>()
+            .0
+            .iter()
+            .map(|[lst_config, vault, recipient_token]| RouteLegAccounts {
+                lst_config,
+                vault,
+                recipient_token,
+            })
+    }
+}
+
+/// Native SOL unwrap accounts (2 accounts, `UnifiedSolUnwrap` slots only).
+///
+/// # Account Layout
+/// ```text
+/// INDEX   ACCOUNT                 OWNER              PURPOSE
+/// [10]    unwrap_account          (PDA)              Temporary WSOL account, created and closed by the pool
+/// [11]    native_mint             SPL Token          WSOL mint
+/// ```
+#[derive(Clone, Copy)]
+pub struct UnwrapSlotAccounts<'a> {
+    /// Unified SOL pool's temporary WSOL account PDA: ["unwrap", lst_config].
+    pub unwrap_account: &'a AccountInfo,
+    /// WSOL (native) mint.
+    pub native_mint: &'a AccountInfo,
+}
+
+/// Accounts a `UnifiedSolUnwrap` slot appends ([`UnwrapSlotAccounts`]).
+pub const UNWRAP_SLOT_ACCOUNT_COUNT: usize = 2;
+
+/// Accounts per route leg in a `UnifiedSolRouted` or `UnifiedSolFallback` slot.
+pub const ROUTE_LEG_ACCOUNT_COUNT: usize = 3;
+
+/// One route leg of a `UnifiedSolRouted` or `UnifiedSolFallback` slot (3
+/// accounts per leg).
+///
+/// # Account Layout
+/// ```text
+/// INDEX      ACCOUNT                 OWNER              PURPOSE
+/// [10+3k]    lst_config              unified-sol-pool   Routed LST (priority or fallback order)
+/// [11+3k]    vault                   SPL Token          Routed LST vault
+/// [12+3k]    recipient_token         SPL Token          Recipient's account for the routed LST
+/// ```
+#[derive(Clone, Copy)]
+pub struct RouteLegAccounts<'a> {
+    /// Routed LST's LstConfig.
+    pub lst_config: &'a AccountInfo,
+    /// Routed LST's vault.
+    pub vault: &'a AccountInfo,
+    /// Recipient's token account for the routed LST (same owner as [7]).
+    pub recipient_token: &'a AccountInfo,
 }
 
 /// Enum for slot accounts based on pool type.
@@ -278,6 +474,15 @@ pub enum SlotAccounts<'a> {
 }
 
 impl<'a> SlotAccounts<'a> {
+    /// The pool this slot's accounts are laid out for.
+    #[inline]
+    pub fn pool_type(&self) -> PoolType {
+        match self {
+            SlotAccounts::Token(_) => PoolType::Token,
+            SlotAccounts::UnifiedSol(_) => PoolType::UnifiedSol,
+        }
+    }
+
     /// Get the hub pool config account.
     #[inline]
     pub fn pool_config(&self) -> &'a AccountInfo {
@@ -340,6 +545,12 @@ impl<'a> SlotAccounts<'a> {
             SlotAccounts::UnifiedSol(u) => u.relayer_token,
         }
     }
+
+    /// Whether withdrawals from this slot are paid out as native SOL.
+    #[inline]
+    pub fn unwraps(&self) -> bool {
+        matches!(self, SlotAccounts::UnifiedSol(u) if u.unwrap.is_some())
+    }
 }
 
 // ============================================================================
@@ -372,7 +583,8 @@ impl<'a> SlotAccounts<'a> {
 /// |-------|-------|-------------|
 /// | PoolConfig owner | HERE | Must be shielded-pool program |
 /// | PoolConfig discriminator | HERE | Valid PoolConfig account |
-/// | pool_type valid | HERE | Must be Token(0) or UnifiedSol(1) |
+/// | pool_kind registered | HERE | In the pool registry, else `UnknownPoolKind` |
+/// | not deregistered | HERE | `PoolDeregistered` |
 /// | TokenPoolConfig owner | DEFERRED | validate_token_accumulator_v2 |
 /// | TokenPoolConfig PDA | DEFERRED | validate_token_accumulator_v2 |
 /// | TokenPoolConfig.asset_id | DEFERRED | Must match proof's rewardAssetId |
@@ -396,49 +608,53 @@ pub fn build_reward_config_map<'a>(
     unique_reward_config_count: usize,
 ) -> Result<BuildRewardConfigResult<'a>, ProgramError> {
     let mut reward_config_map: RewardConfigMap<'a> = BTreeMap::new();
-    let mut idx = 0;
 
-    for _i in 0..unique_reward_config_count {
-        // ====================================================================
-        // ACCOUNT COUNT CHECK
-        // Each reward config group requires exactly 2 accounts
-        // ====================================================================
-        if idx + 2 > remaining.len() {
-            return Err(ShieldedPoolError::MissingAccounts.into());
-        }
+    // ========================================================================
+    // ACCOUNT COUNT CHECK
+    // Each reward config group requires exactly 2 accounts
+    // ========================================================================
+    let pairs = load_pairs::<AccountLoader<'a, PoolConfig>, &'a AccountInfo>(
+        remaining,
+        unique_reward_config_count,
+    )
+    .map_err(|e| e.log_with(ShieldedPoolError::MissingAccounts))?;
 
+    for pair in pairs {
         // ====================================================================
         // ACCOUNT [0]: pool_config (Hub's PoolConfig)
         // ====================================================================
         // Type:    PoolConfig (defined in shielded-pool/src/state/pool_config.rs)
         // Owner:   shielded-pool program (crate::ID)
         // PDA:     ["pool_config", asset_id] - verified implicitly by owner check
-        // Purpose: Routes to correct pool program, stores asset_id and pool_type
+        // Purpose: Routes to correct pool program, stores asset_id and pool_kind
         //
         // VALIDATION HERE:
-        //   ✓ Owner is shielded-pool program (AccountLoader::new checks this)
+        //   ✓ Owner is shielded-pool program (load_pairs loads it as AccountLoader)
         //   ✓ Data deserializes as valid PoolConfig (discriminator check)
-        //   ✓ pool_type is valid enum variant (Token=0 or UnifiedSol=1)
+        //   ✓ pool_kind is in the pool registry (UnknownPoolKind)
+        //   ✓ Pool has not been deregistered (PoolDeregistered)
+        //   - PoolConfig.mode is NOT checked: reward accumulators stay
+        //     readable whichever directions are disabled
         //
         // SECURITY: Attacker cannot provide fake PoolConfig because:
         //   - They cannot create accounts owned by shielded-pool program
         //   - If owner check passes, data integrity is guaranteed
+        //
+        // A failure logs the pair index before returning InvalidPoolConfig.
         // ====================================================================
-        let pool_config_account = &remaining[idx];
-
-        let loader = AccountLoader::<PoolConfig>::new(pool_config_account)
-            .map_err(|_| ShieldedPoolError::InvalidPoolConfig)?;
+        let (loader, pool_specific_config) =
+            pair.map_err(|e| e.log_with(ShieldedPoolError::InvalidPoolConfig))?;
         let config = loader.load()
             .map_err(|_| ShieldedPoolError::InvalidPoolConfig)?;
+        config.require_registered()?;
 
         let asset_id = config.asset_id;
-        let pool_type = HubPoolType::from_u8(config.pool_type)
-            .ok_or(ShieldedPoolError::InvalidPoolConfig)?;
+        let pool_type = pool_kind_entry(config.pool_kind())?.pool;
 
         // ====================================================================
         // ACCOUNT [1]: pool_specific_config (TokenPoolConfig or UnifiedSolPoolConfig)
         // ====================================================================
-        // Type depends on pool_type from PoolConfig above.
+        // Type depends on the registry entry for pool_kind above.
         //
         // FOR TOKEN POOL:
         //   Type:    TokenPoolConfig (defined in token-pool program)
@@ -461,21 +677,20 @@ pub fn build_reward_config_map<'a>(
         //
         // WHY DEFERRED: We don't have the proof data here. Validation functions
         // receive both the config and the proof values to cross-check.
+        // pool_specific_config is loaded as a plain AccountInfo for that reason.
         // ====================================================================
-        let pool_specific_config = &remaining[idx + 1];
-        idx += 2;
 
         // Build the reward config enum based on pool type
         // Note: pool_config_account is used above for routing but not stored
         let reward_config = match pool_type {
-            HubPoolType::Token => {
+            PoolType::Token => {
                 // Token pool: pool_specific_config is TokenPoolConfig
                 // Owner/PDA validation deferred to validate_token_accumulator_v2
                 RewardConfig::Token(TokenRewardConfig {
                     token_pool_config: pool_specific_config,
                 })
             }
-            HubPoolType::UnifiedSol => {
+            PoolType::UnifiedSol => {
                 // Unified SOL pool: pool_specific_config is UnifiedSolPoolConfig
                 // Owner/PDA validation deferred to validate_unified_sol_accumulator_v2
                 //
@@ -509,7 +724,7 @@ pub fn build_reward_config_map<'a>(
 
     Ok(BuildRewardConfigResult {
         reward_config_map,
-        accounts_consumed: idx,
+        accounts_consumed: unique_reward_config_count * 2,
     })
 }
 
@@ -524,3 +739,806 @@ pub fn require_reward_config<'a, 'b>(
         .get(asset_id)
         .ok_or_else(|| ShieldedPoolError::InvalidAssetId.into())
 }
+
+// ============================================================================
+// Slot Account Loading
+// ============================================================================
+
+/// Pool config PDA derivations checked by slot loading.
+///
+/// [`ProgramPdas`] derives them with the pool crates' `find_*_pda` functions.
+/// pinocchio has no off-chain PDA derivation, so unit tests substitute fixed
+/// addresses to reach the PDA checks and the loaded slot.
+pub trait SlotPdas {
+    /// TokenPoolConfig PDA for `mint`
+    fn token_pool_config(mint: &Pubkey) -> Pubkey;
+    /// UnifiedSolPoolConfig PDA
+    fn unified_sol_pool_config() -> Pubkey;
+    /// LstConfig PDA for `lst_mint`
+    fn lst_config(lst_mint: &Pubkey) -> Pubkey;
+}
+
+/// On-chain [`SlotPdas`].
+pub struct ProgramPdas;
+
+impl SlotPdas for ProgramPdas {
+    fn token_pool_config(mint: &Pubkey) -> Pubkey {
+        find_token_pool_config_pda(mint).0
+    }
+
+    fn unified_sol_pool_config() -> Pubkey {
+        find_unified_sol_pool_config_pda().0
+    }
+
+    fn lst_config(lst_mint: &Pubkey) -> Pubkey {
+        find_lst_config_pda(lst_mint).0
+    }
+}
+
+/// Load every public slot's accounts and the hub authority.
+///
+/// `remaining` starts at the first slot account (after the reward configs).
+/// Slots are laid out in order, each taking as many accounts as its
+/// `slot_pool_type` and route leg count require, followed by the hub
+/// authority.
+///
+/// Each slot is loaded by its own `#[inline(never)]` call so the config
+/// loaders and PDA derivations stay out of `process_execute_transact`'s frame.
+///
+/// # Arguments
+/// * `remaining` - remaining_accounts after the reward config section
+/// * `slot_pool_types` - [`SlotPoolType`] discriminant per slot
+/// * `route_leg_counts` - route legs per slot (routed and fallback slots only)
+#[inline(never)]
+pub fn load_public_slots<'a>(
+    remaining: &'a [AccountInfo],
+    slot_pool_types: &[u8; N_PUBLIC_LINES],
+    route_leg_counts: &[u8; N_PUBLIC_LINES],
+) -> Result<([Option<SlotAccounts<'a>>; N_PUBLIC_LINES], &'a AccountInfo), ProgramError> {
+    let mut slot_accounts: [Option<SlotAccounts>; N_PUBLIC_LINES] = [None; N_PUBLIC_LINES];
+    let mut remaining_idx = 0;
+
+    for i in 0..N_PUBLIC_LINES {
+        let (slot, consumed) = load_slot_accounts::<ProgramPdas>(
+            slot_pool_types[i],
+            route_leg_counts[i] as usize,
+            &remaining[remaining_idx..],
+        )?;
+        slot_accounts[i] = slot;
+        remaining_idx += consumed;
+    }
+
+    // Hub authority is always last
+    let hub_authority = remaining
+        .get(remaining_idx)
+        .ok_or(ShieldedPoolError::MissingAccounts)?;
+
+    if hub_authority.key() != &HUB_AUTHORITY_ADDRESS {
+        return Err(ShieldedPoolError::InvalidHubAuthority.into());
+    }
+
+    Ok((slot_accounts, hub_authority))
+}
+
+/// Load one public slot's accounts from the front of `r`.
+///
+/// Both slots go through this function, so their validation cannot drift
+/// apart. Checks run in a fixed order (route legs, account count, config
+/// owner, config data, config PDA, vault, pool program) and map to the same
+/// error codes for every slot.
+///
+/// Account counts per slot type:
+///   - None (0): 0 accounts
+///   - Token (1): 9 accounts  [pool_config, token_pool_config, vault, escrow, escrow_vault_auth, escrow_token, recipient_token, relayer_token, pool_program]
+///   - UnifiedSol (2): 10 accounts [pool_config, unified_sol_pool_config, lst_config, vault, escrow, escrow_vault_auth, escrow_token, recipient_token, relayer_token, pool_program]
+///   - UnifiedSolUnwrap (3): 12 accounts [UnifiedSol..., unwrap_account, native_mint]
+///   - UnifiedSolRouted (4): 10 + 3 per leg [UnifiedSol..., (lst_config, vault, recipient_token)...]
+///   - UnifiedSolFallback (5): as UnifiedSolRouted, one leg per fallback LST
+///
+/// Config PDAs are derived through `P` ([`ProgramPdas`] on-chain).
+///
+/// # Returns
+/// The slot's accounts (`None` for an inactive slot) and the number of
+/// accounts consumed.
+#[inline(never)]
+pub fn load_slot_accounts<'a, P: SlotPdas>(
+    slot_pool_type: u8,
+    route_legs: usize,
+    r: &'a [AccountInfo],
+) -> Result<(Option<SlotAccounts<'a>>, usize), ProgramError> {
+    let route_legs_valid = SlotPoolType::from_u8(slot_pool_type)
+        .map_or(route_legs == 0, |ty| ty.accepts_route_legs(route_legs));
+    if !route_legs_valid {
+        return Err(ShieldedPoolError::InvalidSlotConfiguration.into());
+    }
+
+    match SlotPoolType::from_u8(slot_pool_type) {
+        Some(SlotPoolType::Token) => {
+            // Token: 9 accounts
+            let count = SlotPoolType::Token.account_count();
+            if r.len() < count {
+                return Err(ShieldedPoolError::MissingAccounts.into());
+            }
+
+            // [1] token_pool_config: owner + PDA validation
+            r[1].assert_owner(&TOKEN_POOL_PROGRAM_ID)
+                .map_err(|_| ShieldedPoolError::InvalidAccountOwner)?;
+            let token_config = AccountLoader::<TokenPoolConfig>::new(&r[1])
+                .map_err(|_| ShieldedPoolError::InvalidTokenConfig)?
+                .load()
+                .map_err(|_| ShieldedPoolError::InvalidTokenConfig)?;
+            r[1].assert_key(&P::token_pool_config(&token_config.mint))
+                .map_err(|_| ShieldedPoolError::InvalidPoolConfigPda)?;
+
+            // [2] vault: valid token account
+            require_valid_token_account(&r[2])?;
+
+            // [8] pool_program: key validation
+            r[8].assert_key(&TOKEN_POOL_PROGRAM_ID)
+                .map_err(|_| ShieldedPoolError::InvalidProgramAccount)?;
+
+            let slot = SlotAccounts::Token(TokenSlotAccounts {
+                pool_config: &r[0],
+                token_pool_config: &r[1],
+                vault: &r[2],
+                escrow: &r[3],
+                escrow_vault_authority: &r[4],
+                escrow_token: &r[5],
+                recipient_token: &r[6],
+                relayer_token: &r[7],
+                pool_program: &r[8],
+            });
+            Ok((Some(slot), count))
+        }
+        Some(
+            slot_type @ (SlotPoolType::UnifiedSol
+            | SlotPoolType::UnifiedSolUnwrap
+            | SlotPoolType::UnifiedSolRouted
+            | SlotPoolType::UnifiedSolFallback),
+        ) => {
+            // UnifiedSol: 10 accounts (UnifiedSolUnwrap: 12, UnifiedSolRouted and
+            // UnifiedSolFallback: 10 + 3 per leg)
+            let count = slot_type.account_count() + route_legs * ROUTE_LEG_ACCOUNT_COUNT;
+            if r.len() < count {
+                return Err(ShieldedPoolError::MissingAccounts.into());
+            }
+
+            // [1] unified_sol_pool_config: owner + PDA validation
+            r[1].assert_owner(&UNIFIED_SOL_POOL_PROGRAM_ID)
+                .map_err(|_| ShieldedPoolError::InvalidAccountOwner)?;
+            let _unified_config = AccountLoader::<UnifiedSolPoolConfig>::new(&r[1])
+                .map_err(|_| ShieldedPoolError::InvalidPoolConfig)?
+                .load()
+                .map_err(|_| ShieldedPoolError::InvalidPoolConfig)?;
+            r[1].assert_key(&P::unified_sol_pool_config())
+                .map_err(|_| ShieldedPoolError::InvalidPoolConfigPda)?;
+
+            // [2] lst_config: owner + PDA validation (CRITICAL for exchange rate)
+            r[2].assert_owner(&UNIFIED_SOL_POOL_PROGRAM_ID)
+                .map_err(|_| ShieldedPoolError::InvalidAccountOwner)?;
+            let lst_config = AccountLoader::<LstConfig>::new(&r[2])
+                .map_err(|_| ShieldedPoolError::InvalidLstConfig)?
+                .load()
+                .map_err(|_| ShieldedPoolError::InvalidLstConfig)?;
+            r[2].assert_key(&P::lst_config(&lst_config.lst_mint))
+                .map_err(|_| ShieldedPoolError::InvalidPoolConfigPda)?;
+
+            // [3] vault: valid token account
+            require_valid_token_account(&r[3])?;
+
+            // [9] pool_program: key validation
+            r[9].assert_key(&UNIFIED_SOL_POOL_PROGRAM_ID)
+                .map_err(|_| ShieldedPoolError::InvalidProgramAccount)?;
+
+            let slot = SlotAccounts::UnifiedSol(UnifiedSolSlotAccounts {
+                pool_config: &r[0],
+                unified_sol_pool_config: &r[1],
+                lst_config: &r[2],
+                vault: &r[3],
+                escrow: &r[4],
+                escrow_vault_authority: &r[5],
+                escrow_token: &r[6],
+                recipient_token: &r[7],
+                relayer_token: &r[8],
+                pool_program: &r[9],
+                // [10] unwrap_account, [11] native_mint: validated by the
+                // unified SOL pool (PDA, WSOL mint) during the withdraw CPI
+                unwrap: (slot_type == SlotPoolType::UnifiedSolUnwrap).then(|| UnwrapSlotAccounts {
+                    unwrap_account: &r[10],
+                    native_mint: &r[11],
+                }),
+                // [10..] route legs: lst_config validated here, priority
+                // order and vault PDAs by the pool during the withdraw CPI
+                // (fallback LSTs also in slot validation)
+                route: match slot_type {
+                    SlotPoolType::UnifiedSolRouted | SlotPoolType::UnifiedSolFallback => {
+                        Some(validate_route_legs::<P>(&r[10..count])?)
+                    }
+                    _ => None,
+                },
+                fallback: slot_type == SlotPoolType::UnifiedSolFallback,
+            });
+            Ok((Some(slot), count))
+        }
+        Some(SlotPoolType::None) => {
+            // Inactive slot - no accounts to load
+            Ok((None, 0))
+        }
+        None => Err(ShieldedPoolError::InvalidPoolConfig.into()),
+    }
+}
+
+// ============================================================================
+// Route Leg Validation
+// ============================================================================
+
+/// Validate a `UnifiedSolRouted` or `UnifiedSolFallback` slot's route legs.
+///
+/// Each leg's lst_config must be a unified SOL pool LstConfig at its PDA and
+/// its vault a valid token account. The pool checks the legs against its
+/// priority list (or for duplicates, for fallback LSTs) and the vault PDAs;
+/// recipient tokens and fallback LSTs' state are checked in slot validation.
+fn validate_route_legs<P: SlotPdas>(legs: &[AccountInfo]) -> Result<&[AccountInfo], ProgramError> {
+    for leg in legs.as_chunks::<ROUTE_LEG_ACCOUNT_COUNT>().0 {
+        leg[0]
+            .assert_owner(&UNIFIED_SOL_POOL_PROGRAM_ID)
+            .map_err(|_| ShieldedPoolError::InvalidAccountOwner)?;
+        let lst_config = AccountLoader::<LstConfig>::new(&leg[0])
+            .map_err(|_| ShieldedPoolError::InvalidLstConfig)?
+            .load()
+            .map_err(|_| ShieldedPoolError::InvalidLstConfig)?;
+        leg[0]
+            .assert_key(&P::lst_config(&lst_config.lst_mint))
+            .map_err(|_| ShieldedPoolError::InvalidPoolConfigPda)?;
+
+        require_valid_token_account(&leg[1])?;
+    }
+    Ok(legs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token::SPL_TOKEN_PROGRAM_ID;
+    use pinocchio_test_utils::{AccountInfoBuilder, TestAccount};
+    use pinocchio_token::state::TokenAccount;
+
+    const TOKEN_MINT: Pubkey = [3u8; 32];
+    const LST_MINT: Pubkey = [4u8; 32];
+    const UNIFIED_SOL_POOL_CONFIG: Pubkey = [5u8; 32];
+
+    /// Stand-in config PDAs: each mint's PDA is the mint with a tagged first byte.
+    struct TestPdas;
+
+    impl SlotPdas for TestPdas {
+        fn token_pool_config(mint: &Pubkey) -> Pubkey {
+            test_pda(0xa0, mint)
+        }
+
+        fn unified_sol_pool_config() -> Pubkey {
+            UNIFIED_SOL_POOL_CONFIG
+        }
+
+        fn lst_config(lst_mint: &Pubkey) -> Pubkey {
+            test_pda(0xa1, lst_mint)
+        }
+    }
+
+    fn test_pda(tag: u8, mint: &Pubkey) -> Pubkey {
+        let mut pda = *mint;
+        pda[0] = tag;
+        pda
+    }
+
+    /// `count` default accounts, with `owners[i]` overriding account `i`'s owner.
+    fn slot_accounts(count: usize, owners: &[(usize, &Pubkey)]) -> Vec<TestAccount> {
+        (0..count)
+            .map(|i| {
+                let builder = AccountInfoBuilder::new();
+                match owners.iter().find(|(idx, _)| *idx == i) {
+                    Some((_, owner)) => builder.owner(owner).build(),
+                    None => builder.build(),
+                }
+            })
+            .collect()
+    }
+
+    fn infos(accounts: &[TestAccount]) -> Vec<AccountInfo> {
+        accounts.iter().map(TestAccount::info).collect()
+    }
+
+    fn load_err(slot_pool_type: SlotPoolType, route_legs: usize, r: &[AccountInfo]) -> ProgramError {
+        match load_slot_accounts::<TestPdas>(slot_pool_type as u8, route_legs, r) {
+            Err(err) => err,
+            Ok(_) => panic!("slot type {} loaded", slot_pool_type as u8),
+        }
+    }
+
+    #[test]
+    fn test_none_slot_consumes_nothing() {
+        let accounts = slot_accounts(3, &[]);
+        let r = infos(&accounts);
+        let (slot, consumed) =
+            load_slot_accounts::<TestPdas>(SlotPoolType::None as u8, 0, &r).unwrap();
+        assert!(slot.is_none());
+        assert_eq!(consumed, 0);
+    }
+
+    #[test]
+    fn test_unknown_slot_type_rejected() {
+        assert_eq!(
+            load_slot_accounts::<TestPdas>(6, 0, &[]).err(),
+            Some(ShieldedPoolError::InvalidPoolConfig.into())
+        );
+        // Route legs are checked first
+        assert_eq!(
+            load_slot_accounts::<TestPdas>(6, 1, &[]).err(),
+            Some(ShieldedPoolError::InvalidSlotConfiguration.into())
+        );
+    }
+
+    #[test]
+    fn test_route_legs_only_on_routed_slots() {
+        for slot_type in [
+            SlotPoolType::None,
+            SlotPoolType::Token,
+            SlotPoolType::UnifiedSol,
+            SlotPoolType::UnifiedSolUnwrap,
+        ] {
+            assert_eq!(
+                load_err(slot_type, 1, &[]),
+                ShieldedPoolError::InvalidSlotConfiguration.into()
+            );
+        }
+        assert_eq!(
+            load_err(SlotPoolType::UnifiedSolFallback, 0, &[]),
+            ShieldedPoolError::InvalidSlotConfiguration.into()
+        );
+    }
+
+    #[test]
+    fn test_short_slice_rejected() {
+        let cases = [
+            (SlotPoolType::Token, 0),
+            (SlotPoolType::UnifiedSol, 0),
+            (SlotPoolType::UnifiedSolUnwrap, 0),
+            (SlotPoolType::UnifiedSolRouted, 2),
+            (SlotPoolType::UnifiedSolFallback, 1),
+        ];
+        for (slot_type, route_legs) in cases {
+            let count = slot_type.account_count() + route_legs * ROUTE_LEG_ACCOUNT_COUNT;
+            let accounts = slot_accounts(count - 1, &[]);
+            assert_eq!(
+                load_err(slot_type, route_legs, &infos(&accounts)),
+                ShieldedPoolError::MissingAccounts.into(),
+                "slot type {}",
+                slot_type as u8
+            );
+        }
+    }
+
+    #[test]
+    fn test_token_config_wrong_owner() {
+        let accounts = slot_accounts(9, &[(1, &UNIFIED_SOL_POOL_PROGRAM_ID)]);
+        assert_eq!(
+            load_err(SlotPoolType::Token, 0, &infos(&accounts)),
+            ShieldedPoolError::InvalidAccountOwner.into()
+        );
+    }
+
+    #[test]
+    fn test_token_config_invalid_data() {
+        let accounts = slot_accounts(9, &[(1, &TOKEN_POOL_PROGRAM_ID)]);
+        assert_eq!(
+            load_err(SlotPoolType::Token, 0, &infos(&accounts)),
+            ShieldedPoolError::InvalidTokenConfig.into()
+        );
+    }
+
+    #[test]
+    fn test_unified_sol_config_wrong_owner() {
+        let accounts = slot_accounts(10, &[(1, &TOKEN_POOL_PROGRAM_ID)]);
+        assert_eq!(
+            load_err(SlotPoolType::UnifiedSol, 0, &infos(&accounts)),
+            ShieldedPoolError::InvalidAccountOwner.into()
+        );
+    }
+
+    #[test]
+    fn test_unified_sol_config_invalid_data() {
+        let accounts = slot_accounts(10, &[(1, &UNIFIED_SOL_POOL_PROGRAM_ID)]);
+        assert_eq!(
+            load_err(SlotPoolType::UnifiedSol, 0, &infos(&accounts)),
+            ShieldedPoolError::InvalidPoolConfig.into()
+        );
+    }
+
+    #[test]
+    fn test_public_slots_hub_authority() {
+        let types = [SlotPoolType::None as u8; N_PUBLIC_LINES];
+        let legs = [0; N_PUBLIC_LINES];
+
+        let hub_authority = AccountInfoBuilder::new().key(&HUB_AUTHORITY_ADDRESS).build();
+        let remaining = [hub_authority.info()];
+        let (slots, authority) = load_public_slots(&remaining, &types, &legs).unwrap();
+        assert!(slots.iter().all(Option::is_none));
+        assert_eq!(authority.key(), &HUB_AUTHORITY_ADDRESS);
+
+        assert_eq!(
+            load_public_slots(&[], &types, &legs).err(),
+            Some(ShieldedPoolError::MissingAccounts.into())
+        );
+
+        let impostor = AccountInfoBuilder::new().build();
+        assert_eq!(
+            load_public_slots(&[impostor.info()], &types, &legs).err(),
+            Some(ShieldedPoolError::InvalidHubAuthority.into())
+        );
+    }
+
+    #[test]
+    fn test_public_slots_checks_every_slot() {
+        // Slot 1 goes through the same checks as slot 0
+        let mut types = [SlotPoolType::None as u8; N_PUBLIC_LINES];
+        types[N_PUBLIC_LINES - 1] = SlotPoolType::Token as u8;
+        let accounts = slot_accounts(9, &[(1, &UNIFIED_SOL_POOL_PROGRAM_ID)]);
+        assert_eq!(
+            load_public_slots(&infos(&accounts), &types, &[0; N_PUBLIC_LINES]).err(),
+            Some(ShieldedPoolError::InvalidAccountOwner.into())
+        );
+    }
+
+    /// Account data (discriminator + body) for a zeroed `T` after `edit`.
+    fn account_data<T: bytemuck::Pod + panchor::Discriminator>(
+        edit: impl FnOnce(&mut T),
+    ) -> Vec<u8> {
+        let mut account = T::zeroed();
+        edit(&mut account);
+        let mut data = T::DISCRIMINATOR.to_le_bytes().to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&account));
+        data
+    }
+
+    /// A config account at `key`, owned by `owner`, holding `data`.
+    fn config_account(key: &Pubkey, owner: &Pubkey, data: &[u8]) -> TestAccount {
+        AccountInfoBuilder::new()
+            .key(key)
+            .owner(owner)
+            .data(data)
+            .build()
+    }
+
+    fn token_pool_config(mint: &Pubkey) -> TestAccount {
+        let data = account_data::<TokenPoolConfig>(|config| config.mint = *mint);
+        config_account(
+            &TestPdas::token_pool_config(mint),
+            &TOKEN_POOL_PROGRAM_ID,
+            &data,
+        )
+    }
+
+    fn unified_sol_pool_config() -> TestAccount {
+        let data = account_data::<UnifiedSolPoolConfig>(|_| {});
+        config_account(
+            &UNIFIED_SOL_POOL_CONFIG,
+            &UNIFIED_SOL_POOL_PROGRAM_ID,
+            &data,
+        )
+    }
+
+    fn lst_config(lst_mint: &Pubkey) -> TestAccount {
+        let data = account_data::<LstConfig>(|config| config.lst_mint = *lst_mint);
+        config_account(
+            &TestPdas::lst_config(lst_mint),
+            &UNIFIED_SOL_POOL_PROGRAM_ID,
+            &data,
+        )
+    }
+
+    /// An SPL token account of the right size (the vault check).
+    fn token_account() -> TestAccount {
+        AccountInfoBuilder::new()
+            .owner(&SPL_TOKEN_PROGRAM_ID)
+            .data(&[0; TokenAccount::LEN])
+            .build()
+    }
+
+    fn program(id: &Pubkey) -> TestAccount {
+        AccountInfoBuilder::new().key(id).executable(true).build()
+    }
+
+    /// Accounts for a slot that passes every check; route leg `i` uses the
+    /// LstConfig for mint `[0x40 + i; 32]`.
+    fn valid_slot(slot_type: SlotPoolType, route_legs: usize) -> Vec<TestAccount> {
+        let mut accounts = slot_accounts(slot_type.account_count(), &[]);
+        match slot_type {
+            SlotPoolType::Token => {
+                accounts[1] = token_pool_config(&TOKEN_MINT);
+                accounts[2] = token_account();
+                accounts[8] = program(&TOKEN_POOL_PROGRAM_ID);
+            }
+            _ => {
+                accounts[1] = unified_sol_pool_config();
+                accounts[2] = lst_config(&LST_MINT);
+                accounts[3] = token_account();
+                accounts[9] = program(&UNIFIED_SOL_POOL_PROGRAM_ID);
+            }
+        }
+        for leg in 0..route_legs {
+            accounts.push(lst_config(&[0x40 + leg as u8; 32]));
+            accounts.push(token_account());
+            accounts.push(AccountInfoBuilder::new().build());
+        }
+        accounts
+    }
+
+    #[test]
+    fn test_token_slot_loads() {
+        let accounts = valid_slot(SlotPoolType::Token, 0);
+        let r = infos(&accounts);
+        let (slot, consumed) =
+            load_slot_accounts::<TestPdas>(SlotPoolType::Token as u8, 0, &r).unwrap();
+        assert_eq!(consumed, 9);
+        let Some(SlotAccounts::Token(slot)) = slot else {
+            panic!("expected a token slot");
+        };
+        assert_eq!(
+            slot.token_pool_config.key(),
+            &TestPdas::token_pool_config(&TOKEN_MINT)
+        );
+        assert_eq!(slot.vault.key(), r[2].key());
+        assert_eq!(slot.pool_program.key(), &TOKEN_POOL_PROGRAM_ID);
+    }
+
+    #[test]
+    fn test_token_slot_checks() {
+        let other_mint = [6u8; 32];
+        let other_mint_config = account_data::<TokenPoolConfig>(|config| config.mint = other_mint);
+        let cases = [
+            // Config for one mint at another mint's PDA
+            (
+                1,
+                config_account(
+                    &TestPdas::token_pool_config(&TOKEN_MINT),
+                    &TOKEN_POOL_PROGRAM_ID,
+                    &other_mint_config,
+                ),
+                ShieldedPoolError::InvalidPoolConfigPda.into(),
+            ),
+            (
+                2,
+                AccountInfoBuilder::new().build(),
+                ProgramError::InvalidAccountData,
+            ),
+            (
+                8,
+                program(&UNIFIED_SOL_POOL_PROGRAM_ID),
+                ShieldedPoolError::InvalidProgramAccount.into(),
+            ),
+        ];
+        for (index, account, expected) in cases {
+            let mut accounts = valid_slot(SlotPoolType::Token, 0);
+            accounts[index] = account;
+            assert_eq!(
+                load_err(SlotPoolType::Token, 0, &infos(&accounts)),
+                expected,
+                "account {index}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_unified_sol_slots_load() {
+        let cases = [
+            (SlotPoolType::UnifiedSol, 0),
+            (SlotPoolType::UnifiedSolUnwrap, 0),
+            (SlotPoolType::UnifiedSolRouted, 2),
+            (SlotPoolType::UnifiedSolFallback, 1),
+        ];
+        for (slot_type, route_legs) in cases {
+            let accounts = valid_slot(slot_type, route_legs);
+            let r = infos(&accounts);
+            let (slot, consumed) =
+                load_slot_accounts::<TestPdas>(slot_type as u8, route_legs, &r).unwrap();
+            assert_eq!(consumed, r.len(), "slot type {}", slot_type as u8);
+            let Some(SlotAccounts::UnifiedSol(slot)) = slot else {
+                panic!("slot type {} is not a unified SOL slot", slot_type as u8);
+            };
+            assert_eq!(slot.unified_sol_pool_config.key(), &UNIFIED_SOL_POOL_CONFIG);
+            assert_eq!(slot.lst_config.key(), &TestPdas::lst_config(&LST_MINT));
+            assert_eq!(slot.pool_program.key(), &UNIFIED_SOL_POOL_PROGRAM_ID);
+            assert_eq!(
+                slot.unwrap.is_some(),
+                slot_type == SlotPoolType::UnifiedSolUnwrap
+            );
+            assert_eq!(
+                slot.route.map_or(0, <[AccountInfo]>::len),
+                route_legs * ROUTE_LEG_ACCOUNT_COUNT
+            );
+            assert_eq!(slot.fallback, slot_type == SlotPoolType::UnifiedSolFallback);
+        }
+    }
+
+    #[test]
+    fn test_unified_sol_slot_checks() {
+        let unified_config = account_data::<UnifiedSolPoolConfig>(|_| {});
+        let other_mint_lst = account_data::<LstConfig>(|config| config.lst_mint = [6u8; 32]);
+        let cases = [
+            // UnifiedSolPoolConfig away from its PDA
+            (
+                1,
+                config_account(&[7u8; 32], &UNIFIED_SOL_POOL_PROGRAM_ID, &unified_config),
+                ShieldedPoolError::InvalidPoolConfigPda.into(),
+            ),
+            (
+                2,
+                slot_accounts(1, &[(0, &TOKEN_POOL_PROGRAM_ID)]).remove(0),
+                ShieldedPoolError::InvalidAccountOwner.into(),
+            ),
+            (
+                2,
+                slot_accounts(1, &[(0, &UNIFIED_SOL_POOL_PROGRAM_ID)]).remove(0),
+                ShieldedPoolError::InvalidLstConfig.into(),
+            ),
+            // LstConfig for one mint at another mint's PDA
+            (
+                2,
+                config_account(
+                    &TestPdas::lst_config(&LST_MINT),
+                    &UNIFIED_SOL_POOL_PROGRAM_ID,
+                    &other_mint_lst,
+                ),
+                ShieldedPoolError::InvalidPoolConfigPda.into(),
+            ),
+            (
+                3,
+                AccountInfoBuilder::new().build(),
+                ProgramError::InvalidAccountData,
+            ),
+            (
+                9,
+                program(&TOKEN_POOL_PROGRAM_ID),
+                ShieldedPoolError::InvalidProgramAccount.into(),
+            ),
+        ];
+        for (index, account, expected) in cases {
+            let mut accounts = valid_slot(SlotPoolType::UnifiedSol, 0);
+            accounts[index] = account;
+            assert_eq!(
+                load_err(SlotPoolType::UnifiedSol, 0, &infos(&accounts)),
+                expected,
+                "account {index}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_route_leg_checks() {
+        let other_mint_lst = account_data::<LstConfig>(|config| config.lst_mint = [6u8; 32]);
+        for slot_type in [
+            SlotPoolType::UnifiedSolRouted,
+            SlotPoolType::UnifiedSolFallback,
+        ] {
+            // Second leg: [13] lst_config, [14] vault
+            let cases = [
+                (
+                    13,
+                    slot_accounts(1, &[(0, &TOKEN_POOL_PROGRAM_ID)]).remove(0),
+                    ShieldedPoolError::InvalidAccountOwner.into(),
+                ),
+                (
+                    13,
+                    slot_accounts(1, &[(0, &UNIFIED_SOL_POOL_PROGRAM_ID)]).remove(0),
+                    ShieldedPoolError::InvalidLstConfig.into(),
+                ),
+                (
+                    13,
+                    config_account(
+                        &TestPdas::lst_config(&[0x41; 32]),
+                        &UNIFIED_SOL_POOL_PROGRAM_ID,
+                        &other_mint_lst,
+                    ),
+                    ShieldedPoolError::InvalidPoolConfigPda.into(),
+                ),
+                (
+                    14,
+                    AccountInfoBuilder::new().build(),
+                    ProgramError::InvalidAccountData,
+                ),
+            ];
+            for (index, account, expected) in cases {
+                let mut accounts = valid_slot(slot_type, 2);
+                accounts[index] = account;
+                assert_eq!(
+                    load_err(slot_type, 2, &infos(&accounts)),
+                    expected,
+                    "slot type {}, account {index}",
+                    slot_type as u8
+                );
+            }
+        }
+    }
+
+    /// PoolConfig account data (discriminator + body) for a token pool.
+    fn pool_config_data(asset_id: u8, deregistered: bool) -> Vec<u8> {
+        let mut config = <PoolConfig as bytemuck::Zeroable>::zeroed();
+        config.asset_id = [asset_id; 32];
+        config.pool_kind = zorb_pool_interface::PoolKind::TOKEN.0;
+        config.is_deregistered = deregistered as u8;
+        let mut data = <PoolConfig as panchor::Discriminator>::DISCRIMINATOR
+            .to_le_bytes()
+            .to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&config));
+        data
+    }
+
+    /// Reward config pairs with the given PoolConfig data, in order.
+    fn reward_config_accounts(pool_configs: &[Vec<u8>]) -> Vec<TestAccount> {
+        pool_configs
+            .iter()
+            .flat_map(|data| {
+                [
+                    AccountInfoBuilder::new()
+                        .owner(&crate::ID)
+                        .data(data)
+                        .build(),
+                    AccountInfoBuilder::new()
+                        .owner(&TOKEN_POOL_PROGRAM_ID)
+                        .build(),
+                ]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_reward_config_map_keyed_by_asset_id() {
+        let configs: Vec<_> = (0..4).map(|i| pool_config_data(i, false)).collect();
+        let accounts = reward_config_accounts(&configs);
+        let r = infos(&accounts);
+
+        let result = build_reward_config_map(&crate::ID, &r, 4).unwrap();
+        assert_eq!(result.accounts_consumed, 8);
+        assert_eq!(result.reward_config_map.len(), 4);
+        for i in 0..4 {
+            assert!(matches!(
+                require_reward_config(&result.reward_config_map, &[i; 32]),
+                Ok(RewardConfig::Token(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_reward_config_corrupt_pair_reports_index() {
+        let mut configs: Vec<_> = (0..5).map(|i| pool_config_data(i, false)).collect();
+        configs[3][0] ^= 1;
+        let accounts = reward_config_accounts(&configs);
+        let r = infos(&accounts);
+
+        let err = load_pairs::<AccountLoader<PoolConfig>, &AccountInfo>(&r, 5)
+            .unwrap()
+            .find_map(Result::err)
+            .unwrap();
+        assert_eq!(err.index, 3);
+        assert_eq!(err.position, panchor::account_loaders::PairPosition::First);
+        assert_eq!(
+            build_reward_config_map(&crate::ID, &r, 5).err(),
+            Some(ShieldedPoolError::InvalidPoolConfig.into())
+        );
+    }
+
+    #[test]
+    fn test_reward_config_errors() {
+        let configs = [pool_config_data(0, false), pool_config_data(1, true)];
+        let accounts = reward_config_accounts(&configs);
+        let r = infos(&accounts);
+
+        assert_eq!(
+            build_reward_config_map(&crate::ID, &r, 2).err(),
+            Some(ShieldedPoolError::PoolDeregistered.into())
+        );
+        assert_eq!(
+            build_reward_config_map(&crate::ID, &r[..3], 2).err(),
+            Some(ShieldedPoolError::MissingAccounts.into())
+        );
+    }
+}
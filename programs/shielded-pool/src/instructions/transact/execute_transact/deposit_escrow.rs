@@ -3,11 +3,24 @@
 //! This module provides security-critical escrow validation and state management
 //! for the execute_transact instruction. These helpers ensure that deposits are
 //! properly authorized and prevent replay attacks.
-
-use crate::{errors::ShieldedPoolError, state::{DepositEscrow, TransactSession}};
-use light_hasher::Sha256;
+//!
+//! Native SOL escrows are wrapped into their WSOL vault here, immediately
+//! before the pool deposit CPI, so the vault holds no balance until then.
+
+use crate::{
+    errors::ShieldedPoolError,
+    pda::find_escrow_vault_authority_pda,
+    state::{DepositEscrow, DepositEscrowAssetKind},
+    token::{SPL_TOKEN_PROGRAM_ID, WSOL_MINT, sync_native},
+    validation::require_associated_token_account,
+};
 use panchor::Discriminator;
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, sysvars::Sysvar};
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{Sysvar, rent::Rent},
+};
 
 // ============================================================================
 // Escrow Helper Functions
@@ -18,10 +31,13 @@ use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::
 /// # Security Checks
 /// 1. Escrow is owned by this program
 /// 2. Escrow has valid discriminator
-/// 3. proof_hash matches SHA256(session_body)
+/// 3. proof_hash matches the session body hash
 /// 4. Relayer is authorized on the escrow
 /// 5. Escrow is not already consumed
 /// 6. Escrow has not expired
+/// 7. Asset kind is known
+///
+/// Returns the escrow's asset kind so the caller can wrap native SOL.
 ///
 /// Note: PDA derivation check removed - security is provided by:
 /// - Program ownership check (only this program creates escrows)
@@ -31,16 +47,15 @@ use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::
 /// # Arguments
 /// * `program_id` - This program's ID
 /// * `escrow` - The escrow account
-/// * `session_data` - The raw session data (for computing proof hash)
+/// * `body_hash` - SHA256 of the session body, already verified against the
+///   uploaded bytes by `parse_session_data`
 /// * `relayer` - The relayer's pubkey
 pub fn verify_escrow_for_deposit(
     program_id: &Pubkey,
     escrow: &AccountInfo,
-    session_data: &[u8],
+    body_hash: &[u8; 32],
     relayer: &Pubkey,
-) -> Result<(), ProgramError> {
-    use light_hasher::Hasher;
-
+) -> Result<DepositEscrowAssetKind, ProgramError> {
     // 1. Verify escrow is owned by this program
     if escrow.owner() != program_id {
         return Err(ShieldedPoolError::InvalidEscrowAccount.into());
@@ -62,14 +77,7 @@ pub fn verify_escrow_for_deposit(
         bytemuck::from_bytes::<DepositEscrow>(&escrow_data[8..DepositEscrow::ACCOUNT_SIZE]);
 
     // 3. Verify proof_hash matches SHA256(session_body)
-    // session_body starts after the 8-byte header (authority, nonce, data_len, bump, created_slot)
-    // Actually, looking at TransactSession, the body is the full proof + params + encrypted outputs
-    // The plan says proof_hash = SHA256(session_body), and session_body is the data part after header
-    let session_body = &session_data[TransactSession::BASE_SIZE..];
-    let computed_hash = Sha256::hash(session_body)
-        .map_err(|_| ShieldedPoolError::ArithmeticOverflow)?;
-
-    if escrow_state.proof_hash != computed_hash {
+    if escrow_state.proof_hash != *body_hash {
         return Err(ShieldedPoolError::EscrowProofHashMismatch.into());
     }
 
@@ -89,6 +97,48 @@ pub fn verify_escrow_for_deposit(
         return Err(ShieldedPoolError::EscrowExpired.into());
     }
 
+    // 7. Verify asset kind
+    escrow_state
+        .asset_kind()
+        .ok_or_else(|| ShieldedPoolError::InvalidEscrowAccount.into())
+}
+
+/// Wrap a native SOL escrow's lamports into its WSOL vault.
+///
+/// Moves every lamport above the escrow PDA's rent-exempt minimum into
+/// `escrow_token` and syncs it, so the pool deposit CPI can spend it like a
+/// token escrow. `escrow_token` must be the WSOL ATA of the escrow's vault
+/// authority, which `init_deposit_escrow` created.
+pub fn wrap_native_escrow(
+    escrow: &AccountInfo,
+    escrow_token: &AccountInfo,
+    token_program: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let (vault_authority, _) = find_escrow_vault_authority_pda(escrow.key());
+    require_associated_token_account(
+        escrow_token,
+        &vault_authority,
+        &WSOL_MINT,
+        &SPL_TOKEN_PROGRAM_ID,
+    )
+    .map_err(|_| ShieldedPoolError::InvalidEscrowAccount)?;
+
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(DepositEscrow::ACCOUNT_SIZE);
+    let escrowed = DepositEscrow::escrowed_lamports(escrow.lamports(), rent_exempt_minimum);
+    if escrowed == 0 {
+        return Err(ShieldedPoolError::EscrowInsufficientBalance.into());
+    }
+
+    // The escrow PDA is program-owned, so its lamports can be debited directly
+    *escrow.try_borrow_mut_lamports()? -= escrowed;
+    let mut vault_lamports = escrow_token.try_borrow_mut_lamports()?;
+    *vault_lamports = vault_lamports
+        .checked_add(escrowed)
+        .ok_or(ShieldedPoolError::ArithmeticOverflow)?;
+    drop(vault_lamports);
+
+    sync_native(escrow_token, token_program)?;
+
     Ok(())
 }
 
@@ -113,3 +163,40 @@ pub fn mark_escrow_consumed(escrow: &AccountInfo) -> Result<(), ProgramError> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytemuck::Zeroable;
+    use pinocchio_test_utils::AccountInfoBuilder;
+
+    const BODY_HASH: [u8; 32] = [7u8; 32];
+
+    fn escrow_account_data(escrow: &DepositEscrow) -> Vec<u8> {
+        let mut data = DepositEscrow::DISCRIMINATOR.to_le_bytes().to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(escrow));
+        data
+    }
+
+    /// An execution built for the relayer an escrow was rebound away from
+    /// fails the relayer check before any state is touched.
+    #[test]
+    fn test_stale_relayer_rejected() {
+        let old_relayer: Pubkey = [1u8; 32];
+        let new_relayer: Pubkey = [2u8; 32];
+        let mut escrow = DepositEscrow::zeroed();
+        escrow.proof_hash = BODY_HASH;
+        escrow.authorized_relayer = old_relayer;
+        escrow.set_authorized_relayer(new_relayer).unwrap();
+
+        let data = escrow_account_data(&escrow);
+        let account = AccountInfoBuilder::new()
+            .owner(&crate::ID)
+            .data(&data)
+            .build();
+        assert_eq!(
+            verify_escrow_for_deposit(&crate::ID, &account.info(), &BODY_HASH, &old_relayer),
+            Err(ShieldedPoolError::EscrowUnauthorizedRelayer.into())
+        );
+    }
+}
@@ -7,33 +7,121 @@
 //! - All arithmetic uses checked operations to prevent overflow
 //! - Fee rate is in basis points (1/10000), max 10000 (100%)
 //! - Results are validated to fit in u64
+//! - Fees come from [`zorb_pool_interface::fee`], the same math the pools
+//!   charge with, so the pool's reported fee matches the hub's exactly
 
 use crate::errors::ShieldedPoolError;
 use pinocchio::program_error::ProgramError;
+use zorb_pool_interface::{
+    BASIS_POINTS,
+    fee::{Rounding, compute_fee},
+};
 
 // ============================================================================
 // Fee Calculation Helpers
 // ============================================================================
 
-/// Calculate fee amount: (amount * rate) / 10_000
+/// Calculate fee amount: (amount * rate) / 10_000, rounded per `rounding`
 ///
 /// # Security
 /// - Uses u128 intermediate to prevent overflow on multiplication
-/// - Checked division prevents panic
 /// - Result validated to fit in u64
 ///
 /// # Arguments
 /// * `amount` - The base amount to calculate fee on
 /// * `rate` - Fee rate in basis points (e.g., 100 = 1%)
+/// * `rounding` - The pool's fee rounding
 ///
 /// # Returns
 /// The fee amount, or ArithmeticOverflow if calculation fails.
 #[inline]
-pub fn calculate_fee(amount: u64, rate: u16) -> Result<u64, ProgramError> {
-    use crate::utils::BASIS_POINTS_DENOMINATOR;
-    (amount as u128)
-        .checked_mul(rate as u128)
-        .and_then(|v| v.checked_div(BASIS_POINTS_DENOMINATOR))
-        .and_then(|v| u64::try_from(v).ok())
+pub fn calculate_fee(amount: u64, rate: u16, rounding: Rounding) -> Result<u64, ProgramError> {
+    compute_fee(amount, rate, rounding).ok_or_else(|| ShieldedPoolError::ArithmeticOverflow.into())
+}
+
+/// Recover a gross amount from the net a withdrawal must deliver:
+/// gross = net * 10_000 / (10_000 - rate)
+///
+/// The pool charges its fee on the gross, so `gross - fee(gross)` may differ
+/// from `net` by rounding; callers recompute the output with [`calculate_fee`].
+///
+/// # Returns
+/// The gross amount, or ArithmeticOverflow if `rate` is 100% or more or the
+/// result overflows.
+#[inline]
+pub fn gross_from_net(net: u64, rate: u16) -> Result<u64, ProgramError> {
+    let denominator = BASIS_POINTS
+        .checked_sub(rate as u64)
+        .filter(|&d| d != 0)
+        .ok_or(ShieldedPoolError::ArithmeticOverflow)?;
+
+    net.checked_mul(BASIS_POINTS)
+        .and_then(|v| v.checked_div(denominator))
         .ok_or_else(|| ShieldedPoolError::ArithmeticOverflow.into())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use token_pool::TokenPoolConfig;
+    use unified_sol_pool::state::protocol_fee;
+
+    const PRINCIPALS: [u64; 10] = [
+        0,
+        1,
+        9_999,
+        10_000,
+        10_001,
+        333_333_333,
+        1_000_000_007,
+        u64::MAX / 10_000,
+        u64::MAX / 3,
+        u64::MAX,
+    ];
+    const RATES: [u16; 8] = [0, 1, 7, 30, 100, 3_333, 9_999, 10_000];
+
+    #[test]
+    fn test_hub_fee_matches_token_pool_fee() {
+        let mut config: TokenPoolConfig = bytemuck::Zeroable::zeroed();
+        for round_fees_up in [0, 1] {
+            config.round_fees_up = round_fees_up;
+            for principal in PRINCIPALS {
+                for rate in RATES {
+                    let expected = calculate_fee(principal, rate, config.fee_rounding());
+                    let charged = config.calculate_fee(principal, rate).map_err(Into::into);
+                    assert_eq!(
+                        expected, charged,
+                        "{principal} @ {rate}, up={round_fees_up}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_hub_fee_matches_unified_sol_pool_fee() {
+        for principal in PRINCIPALS {
+            for rate in RATES {
+                let expected = calculate_fee(principal, rate, Rounding::Down);
+                let charged = protocol_fee(principal, rate).map_err(Into::into);
+                assert_eq!(expected, charged, "{principal} @ {rate}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_gross_from_net_round_trips() {
+        for net in PRINCIPALS
+            .into_iter()
+            .filter(|&net| net <= u64::MAX / 10_000)
+        {
+            for rate in RATES.into_iter().filter(|&rate| rate < 10_000) {
+                let gross = gross_from_net(net, rate).unwrap();
+                let fee = calculate_fee(gross, rate, Rounding::Down).unwrap();
+                assert_eq!(gross - fee, net, "{net} @ {rate}");
+            }
+        }
+        assert!(gross_from_net(1, 10_000).is_err());
+        assert!(gross_from_net(u64::MAX, 1).is_err());
+    }
+}
@@ -103,6 +103,8 @@
 //! │                REQUIRE asset_map.contains(rewardAssetId[i])
 //! │                REQUIRE pool.is_active
 //! │                REQUIRE rewardAcc[i] == pool.reward_accumulator
+//! │                     OR (allow_one_epoch_stale AND finalized ≤ 150 slots ago
+//! │                         AND rewardAcc[i] == pool.previous_reward_accumulator)
 //! │
 //! ├──► 3. validate_transact_proof
 //! │        REQUIRE commitment_root ∈ tree.root_history
@@ -110,8 +112,10 @@
 //! │        REQUIRE Groth16.verify(proof, TRANSACT_VK, public_inputs)
 //! │
 //! ├──► 4. validate_public_slots (i IN 0..2)
+//! │        IF global_config.withdrawals_only: REQUIRE no ext_amount[i] > 0
 //! │        IF publicAssetId[i] != 0 AND ext_amount[i] != 0:
 //! │            REQUIRE params.asset_ids[i] == proof.publicAssetId[i]
+//! │            REQUIRE hub_pool_config.mode allows sign(ext_amount[i])
 //! │            REQUIRE publicAmount[i] == computed:
 //! │                Token:      ext_amount - fee
 //! │                UnifiedSol: φ(ext_amount) - fee, φ(e) = e * rate / 1e9
@@ -142,7 +146,8 @@
 //! ├──► 8. append_commitments
 //! │        FOR i IN 0..4:
 //! │            commitment_tree.append(commitments[i])
-//! │            EMIT NewCommitmentEvent { index, commitment, encrypted_output }
+//! │            EMIT NewCommitmentEvent { leaf_index, commitment, new_root, encrypted_output }
+//! │            EMIT NewCommitmentChunkEvent × total_chunks (large outputs only)
 //! │
 //! └──► 9. append_receipt
 //!          receipt_hash = SHA256(tx_type, slot, epoch, commitments, nullifiers, ...)
@@ -167,25 +172,21 @@ mod validators;
 pub use accounts::SlotPoolType;
 
 use crate::{
-    CommitmentMerkleTree,
+    CommitmentMerkleTree, cu_trace,
     errors::ShieldedPoolError,
     instructions::types::{N_INS, N_OUTS, N_PUBLIC_LINES, N_REWARD_LINES},
     merkle_tree::MerkleTree,
-    pda::{HUB_AUTHORITY_ADDRESS, find_nullifier_pda},
+    pda::find_nullifier_pda,
     state::{
-        GlobalConfig, LstConfig, MAX_SESSION_DATA_LEN, NullifierIndexedTree, ReceiptMerkleTree,
-        TokenPoolConfig, TransactSession, UnifiedSolPoolConfig,
-        find_lst_config_pda, find_token_pool_config_pda, find_unified_sol_pool_config_pda,
+        GlobalConfig, MAX_SESSION_DATA_LEN, NullifierIndexedTree, ReceiptMerkleTree,
+        TransactSession,
     },
-    utils::{self, compute_unified_sol_asset_id, verify_proof},
-    validation::{require_token_account_owner, require_valid_token_account},
-    verifying_keys::TRANSACT_VK,
+    utils::verify_proof,
+    validation::require_token_account_owner,
+    verifying_keys::{CircuitId, vk_for},
 };
-use zorb_pool_interface::{TOKEN_POOL_PROGRAM_ID, UNIFIED_SOL_POOL_PROGRAM_ID};
+use zorb_pool_interface::asset_ids::compute_unified_sol_asset_id;
 
-use ark_bn254::Fr;
-use ark_ff::PrimeField;
-use light_hasher::Sha256;
 use panchor::prelude::*;
 use pinocchio::{
     ProgramResult,
@@ -199,15 +200,14 @@ use pinocchio_contrib::AccountAssertions;
 use super::session_data::parse_session_data;
 
 // Local submodule imports
-use accounts::{
-    SlotAccounts, TokenSlotAccounts, UnifiedSolSlotAccounts,
-    build_reward_config_map, require_reward_config,
-};
+use accounts::{build_reward_config_map, load_public_slots, require_reward_config};
 use public_slots::execute_public_slots;
 use nullifier::{verify_and_create_nullifier, verify_nullifier_non_membership_proof};
 use slot_validation::validate_public_slots;
-use tree_updates::{append_commitment, compute_receipt_and_hash, emit_receipt_event};
-use validators::{validate_token_accumulator, validate_unified_sol_accumulator};
+use tree_updates::{append_commitment, append_receipt};
+use validators::{
+    validate_token_accumulator, validate_transact_params_hash, validate_unified_sol_accumulator,
+};
 
 // ============================================================================
 // Panchor Accounts Wrapper and Handler
@@ -255,7 +255,8 @@ pub struct ExecuteTransactAccounts<'info> {
     /// in the handler when the account is actually used.
     pub epoch_root_pda: &'info AccountInfo,
 
-    /// Global pool configuration
+    /// Global pool configuration (protocol stats are updated)
+    #[account(mut)]
     pub global_config: AccountLoader<'info, GlobalConfig>,
 
     /// Nullifier PDA 0 (initialized during execution)
@@ -320,6 +321,9 @@ pub struct ExecuteTransactAccounts<'info> {
 /// - `0` = None (inactive slot, 0 accounts)
 /// - `1` = Token (8 accounts: pool_config, token_pool_config, vault + 3 escrow + 2 user tokens)
 /// - `2` = UnifiedSol (9 accounts: pool_config, unified_sol_pool_config, lst_config, vault + 3 escrow + 2 user tokens)
+/// - `3` = UnifiedSolUnwrap (UnifiedSol + unwrap_account, native_mint; WSOL withdrawal paid as native SOL)
+/// - `4` = UnifiedSolRouted (UnifiedSol + lst_config, vault, recipient_token per route leg)
+/// - `5` = UnifiedSolFallback (UnifiedSol + lst_config, vault, recipient_token per fallback LST)
 ///
 /// Stored as `[u8; 2]` for bytemuck Pod compatibility. Clients should use
 /// the `SlotPoolType` enum for type-safe construction.
@@ -342,13 +346,29 @@ pub struct ExecuteTransactData {
     /// Each loads 2 accounts: pool_config + pool-specific config.
     pub unique_reward_config_count: u8,
     /// Pool type for each public slot (N_PUBLIC_LINES = 2).
-    /// Uses [`SlotPoolType`] discriminant values (0=None, 1=Token, 2=UnifiedSol).
-    /// Determines account count per slot: None=0, Token=9, UnifiedSol=10.
+    /// Uses [`SlotPoolType`] discriminant values (0=None, 1=Token, 2=UnifiedSol,
+    /// 3=UnifiedSolUnwrap, 4=UnifiedSolRouted, 5=UnifiedSolFallback).
+    /// Determines account count per slot: None=0, Token=9, UnifiedSol=10, UnifiedSolUnwrap=12,
+    /// UnifiedSolRouted and UnifiedSolFallback=10 + 3 per route leg.
     pub slot_pool_type: [u8; N_PUBLIC_LINES],
+    /// Route legs for each `UnifiedSolRouted` slot (max `MAX_WITHDRAW_ROUTE_VAULTS`)
+    /// or `UnifiedSolFallback` slot (1 to `MAX_WITHDRAW_FALLBACK_VAULTS`);
+    /// 0 for every other slot type.
+    pub route_leg_counts: [u8; N_PUBLIC_LINES],
     /// Padding for 8-byte alignment.
-    pub _padding: [u8; 5],
+    pub _padding: [u8; 3],
 }
 
+// Pins the wire layout clients write (count, slot types, route leg counts)
+const _: () = {
+    use core::mem::{offset_of, size_of};
+    assert!(size_of::<ExecuteTransactData>() == 8);
+    assert!(offset_of!(ExecuteTransactData, unique_reward_config_count) == 0);
+    assert!(offset_of!(ExecuteTransactData, slot_pool_type) == 1);
+    assert!(offset_of!(ExecuteTransactData, route_leg_counts) == 1 + N_PUBLIC_LINES);
+    assert!(offset_of!(ExecuteTransactData, _padding) == 1 + 2 * N_PUBLIC_LINES);
+};
+
 /// Handler for ExecuteTransact instruction.
 ///
 /// This is a shielded transaction that:
@@ -366,7 +386,7 @@ pub struct ExecuteTransactData {
 /// ├─ P1: Extract fixed account references from panchor
 /// │
 /// FAIL-FAST PHASE (cheap checks before expensive work)
-/// ├─ P2: Parse session data (needed for all subsequent checks)
+/// ├─ P2: Verify session body hash, parse session data (needed for all subsequent checks)
 /// ├─ P3: Validate program account, data length, expiry, pause state
 /// │
 /// ACCOUNT LOADING PHASE (expensive, only after passing cheap checks)
@@ -411,6 +431,7 @@ pub fn process_execute_transact(
     // Zero-cost binding of panchor-validated accounts to local variables.
     // Panchor has already validated ownership and deserialization.
 
+    cu_trace::probe("P1");
     let transact_session = accounts.transact_session.account_info();
     let commitment_tree = accounts.commitment_tree.account_info();
     let epoch_root_pda = accounts.epoch_root_pda;
@@ -432,7 +453,9 @@ pub fn process_execute_transact(
     // ========================================================================
     // Session parsing is required for ALL subsequent validation, so do it early.
     // The borrow must be kept alive for zero-copy references in session.
+    // Parsing first checks the body against the body_hash declared at init.
 
+    cu_trace::probe("P2");
     let session_data_ref = transact_session.try_borrow_data()?;
     let session = parse_session_data(&session_data_ref)?;
 
@@ -442,6 +465,7 @@ pub fn process_execute_transact(
     let nullifier_nm_proof = session.nullifier_nm_proof;
     let encrypted_outputs = &session.encrypted_outputs;
     let session_data_len = session.header.data_len;
+    let body_hash = session.header.body_hash;
     let has_relayer = session.has_relayer();
 
     // ========================================================================
@@ -450,6 +474,7 @@ pub fn process_execute_transact(
     // These checks are O(1) and should reject invalid transactions early,
     // before we spend compute on account loading or proof verification.
 
+    cu_trace::probe("P3");
     // P3.1: Validate session data length is within bounds
     if session_data_len > MAX_SESSION_DATA_LEN {
         return Err(ShieldedPoolError::ProofPayloadOverflow.into());
@@ -467,12 +492,34 @@ pub fn process_execute_transact(
         return Err(ShieldedPoolError::Unauthorized.into());
     }
 
-    // P3.5: Load global config and check pause state
-    let global_config_data = accounts.global_config.load()?;
-    if global_config_data.paused() {
-        return Err(ShieldedPoolError::PoolPaused.into());
-    }
-    let global_config_bump = global_config_data.bump;
+    // P3.5: Load global config, check pause state and record protocol stats.
+    // Stats are counted on this single load_mut rather than after E4; any later
+    // failure reverts them along with the rest of the transaction. The borrow
+    // is released here because E-phase event CPIs sign with global_config.
+    let (
+        global_config_bump,
+        max_root_age,
+        max_relayer_fee_bps,
+        withdrawals_only,
+        event_mode,
+        allow_one_epoch_stale,
+    ) = {
+        let mut global_config_data = accounts.global_config.load_mut()?;
+        if global_config_data.paused() {
+            return Err(ShieldedPoolError::PoolPaused.into());
+        }
+        global_config_data
+            .stats
+            .record_transact(&transact_params.ext_amounts);
+        (
+            global_config_data.bump,
+            global_config_data.max_root_age,
+            global_config_data.max_relayer_fee_bps,
+            global_config_data.deposits_disabled(),
+            global_config_data.emission_mode(),
+            global_config_data.allows_one_epoch_stale(),
+        )
+    };
 
     // ========================================================================
     // P4: ACCOUNT LOADING - Parse remaining_accounts (expensive)
@@ -480,6 +527,7 @@ pub fn process_execute_transact(
     // Only performed after passing all cheap fail-fast checks.
     // Layout: [Reward Configs] [Slot Accounts] [Hub Authority]
 
+    cu_trace::probe("P4");
     // Account layout in remaining_accounts (deterministic order):
     //   [0..R]     = Reward config accounts (R = unique_reward_config_count * 2)
     //   [R..S0]    = Slot 0 accounts (if slot_pool_type[0] != None)
@@ -497,228 +545,12 @@ pub fn process_execute_transact(
         let reward_config_map = reward_result.reward_config_map;
         remaining_idx += reward_result.accounts_consumed;
 
-        // Section 2: Load slot accounts (inlined per-slot for auditability)
-        //
-        // Account counts per slot type:
-        //   - None (0): 0 accounts
-        //   - Token (1): 9 accounts  [pool_config, token_pool_config, vault, escrow, escrow_vault_auth, escrow_token, recipient_token, relayer_token, pool_program]
-        //   - UnifiedSol (2): 10 accounts [pool_config, unified_sol_pool_config, lst_config, vault, escrow, escrow_vault_auth, escrow_token, recipient_token, relayer_token, pool_program]
-        let mut slot_accounts: [Option<SlotAccounts>; N_PUBLIC_LINES] = [None; N_PUBLIC_LINES];
-        let remaining = &panchor_ctx.remaining_accounts[remaining_idx..];
-        // Reset remaining_idx to 0 since 'remaining' is already sliced from the reward config offset.
-        // All subsequent indexing into 'remaining' should start from 0.
-        remaining_idx = 0;
-
-        // ════════════════════════════════════════════════════════════════════
-        // SLOT 0
-        // ════════════════════════════════════════════════════════════════════
-        match SlotPoolType::from_u8(data.slot_pool_type[0]) {
-            Some(SlotPoolType::Token) => {
-                // Token: 9 accounts
-                if remaining.len() < remaining_idx + 9 {
-                    return Err(ShieldedPoolError::MissingAccounts.into());
-                }
-                let r = &remaining[remaining_idx..];
-
-                // [1] token_pool_config: owner + PDA validation
-                r[1].assert_owner(&TOKEN_POOL_PROGRAM_ID)
-                    .map_err(|_| ShieldedPoolError::InvalidAccountOwner)?;
-                let token_config = AccountLoader::<TokenPoolConfig>::new(&r[1])
-                    .map_err(|_| ShieldedPoolError::InvalidTokenConfig)?
-                    .load()
-                    .map_err(|_| ShieldedPoolError::InvalidTokenConfig)?;
-                let (expected_pda, _) = find_token_pool_config_pda(&token_config.mint);
-                r[1].assert_key(&expected_pda)
-                    .map_err(|_| ShieldedPoolError::InvalidPoolConfigPda)?;
-
-                // [2] vault: valid token account
-                require_valid_token_account(&r[2])?;
-
-                // [8] pool_program: key validation
-                r[8].assert_key(&TOKEN_POOL_PROGRAM_ID)
-                    .map_err(|_| ShieldedPoolError::InvalidProgramAccount)?;
-
-                slot_accounts[0] = Some(SlotAccounts::Token(TokenSlotAccounts {
-                    pool_config: &r[0],
-                    token_pool_config: &r[1],
-                    vault: &r[2],
-                    escrow: &r[3],
-                    escrow_vault_authority: &r[4],
-                    escrow_token: &r[5],
-                    recipient_token: &r[6],
-                    relayer_token: &r[7],
-                    pool_program: &r[8],
-                }));
-                remaining_idx += 9;
-            }
-            Some(SlotPoolType::UnifiedSol) => {
-                // UnifiedSol: 10 accounts
-                if remaining.len() < remaining_idx + 10 {
-                    return Err(ShieldedPoolError::MissingAccounts.into());
-                }
-                let r = &remaining[remaining_idx..];
-
-                // [1] unified_sol_pool_config: owner + PDA validation
-                r[1].assert_owner(&UNIFIED_SOL_POOL_PROGRAM_ID)
-                    .map_err(|_| {
-                        ShieldedPoolError::InvalidAccountOwner
-                    })?;
-                let _unified_config = AccountLoader::<UnifiedSolPoolConfig>::new(&r[1])
-                    .map_err(|_| ShieldedPoolError::InvalidPoolConfig)?
-                    .load()
-                    .map_err(|_| ShieldedPoolError::InvalidPoolConfig)?;
-                let (expected_unified_pda, _) = find_unified_sol_pool_config_pda();
-                r[1].assert_key(&expected_unified_pda)
-                    .map_err(|_| ShieldedPoolError::InvalidPoolConfigPda)?;
-
-                // [2] lst_config: owner + PDA validation (CRITICAL for exchange rate)
-                r[2].assert_owner(&UNIFIED_SOL_POOL_PROGRAM_ID)
-                    .map_err(|_| ShieldedPoolError::InvalidAccountOwner)?;
-                let lst_config = AccountLoader::<LstConfig>::new(&r[2])
-                    .map_err(|_| ShieldedPoolError::InvalidLstConfig)?
-                    .load()
-                    .map_err(|_| ShieldedPoolError::InvalidLstConfig)?;
-                let (expected_lst_pda, _) = find_lst_config_pda(&lst_config.lst_mint);
-                r[2].assert_key(&expected_lst_pda)
-                    .map_err(|_| ShieldedPoolError::InvalidPoolConfigPda)?;
-
-                // [3] vault: valid token account
-                require_valid_token_account(&r[3])?;
-
-                // [9] pool_program: key validation
-                r[9].assert_key(&UNIFIED_SOL_POOL_PROGRAM_ID)
-                    .map_err(|_| {
-                        ShieldedPoolError::InvalidProgramAccount
-                    })?;
-
-                slot_accounts[0] = Some(SlotAccounts::UnifiedSol(UnifiedSolSlotAccounts {
-                    pool_config: &r[0],
-                    unified_sol_pool_config: &r[1],
-                    lst_config: &r[2],
-                    vault: &r[3],
-                    escrow: &r[4],
-                    escrow_vault_authority: &r[5],
-                    escrow_token: &r[6],
-                    recipient_token: &r[7],
-                    relayer_token: &r[8],
-                    pool_program: &r[9],
-                }));
-                remaining_idx += 10;
-            }
-            Some(SlotPoolType::None) => {
-                // Inactive slot - no accounts to load
-            }
-            None => return Err(ShieldedPoolError::InvalidPoolConfig.into()),
-        }
-
-        // ════════════════════════════════════════════════════════════════════
-        // SLOT 1
-        // ════════════════════════════════════════════════════════════════════
-        match SlotPoolType::from_u8(data.slot_pool_type[1]) {
-            Some(SlotPoolType::Token) => {
-                // Token: 9 accounts
-                if remaining.len() < remaining_idx + 9 {
-                    return Err(ShieldedPoolError::MissingAccounts.into());
-                }
-                let r = &remaining[remaining_idx..];
-
-                // [1] token_pool_config: owner + PDA validation
-                r[1].assert_owner(&TOKEN_POOL_PROGRAM_ID)
-                    .map_err(|_| ShieldedPoolError::InvalidAccountOwner)?;
-                let token_config = AccountLoader::<TokenPoolConfig>::new(&r[1])
-                    .map_err(|_| ShieldedPoolError::InvalidTokenConfig)?
-                    .load()
-                    .map_err(|_| ShieldedPoolError::InvalidTokenConfig)?;
-                let (expected_pda, _) = find_token_pool_config_pda(&token_config.mint);
-                r[1].assert_key(&expected_pda)
-                    .map_err(|_| ShieldedPoolError::InvalidPoolConfigPda)?;
-
-                // [2] vault: valid token account
-                require_valid_token_account(&r[2])?;
-
-                // [8] pool_program: key validation
-                r[8].assert_key(&TOKEN_POOL_PROGRAM_ID)
-                    .map_err(|_| ShieldedPoolError::InvalidProgramAccount)?;
-
-                slot_accounts[1] = Some(SlotAccounts::Token(TokenSlotAccounts {
-                    pool_config: &r[0],
-                    token_pool_config: &r[1],
-                    vault: &r[2],
-                    escrow: &r[3],
-                    escrow_vault_authority: &r[4],
-                    escrow_token: &r[5],
-                    recipient_token: &r[6],
-                    relayer_token: &r[7],
-                    pool_program: &r[8],
-                }));
-                remaining_idx += 9;
-            }
-            Some(SlotPoolType::UnifiedSol) => {
-                // UnifiedSol: 10 accounts
-                if remaining.len() < remaining_idx + 10 {
-                    return Err(ShieldedPoolError::MissingAccounts.into());
-                }
-                let r = &remaining[remaining_idx..];
-
-                // [1] unified_sol_pool_config: owner + PDA validation
-                r[1].assert_owner(&UNIFIED_SOL_POOL_PROGRAM_ID)
-                    .map_err(|_| ShieldedPoolError::InvalidAccountOwner)?;
-                let _unified_config = AccountLoader::<UnifiedSolPoolConfig>::new(&r[1])
-                    .map_err(|_| ShieldedPoolError::InvalidPoolConfig)?
-                    .load()
-                    .map_err(|_| ShieldedPoolError::InvalidPoolConfig)?;
-                let (expected_unified_pda, _) = find_unified_sol_pool_config_pda();
-                r[1].assert_key(&expected_unified_pda)
-                    .map_err(|_| ShieldedPoolError::InvalidPoolConfigPda)?;
-
-                // [2] lst_config: owner + PDA validation (CRITICAL for exchange rate)
-                r[2].assert_owner(&UNIFIED_SOL_POOL_PROGRAM_ID)
-                    .map_err(|_| ShieldedPoolError::InvalidAccountOwner)?;
-                let lst_config = AccountLoader::<LstConfig>::new(&r[2])
-                    .map_err(|_| ShieldedPoolError::InvalidLstConfig)?
-                    .load()
-                    .map_err(|_| ShieldedPoolError::InvalidLstConfig)?;
-                let (expected_lst_pda, _) = find_lst_config_pda(&lst_config.lst_mint);
-                r[2].assert_key(&expected_lst_pda)
-                    .map_err(|_| ShieldedPoolError::InvalidPoolConfigPda)?;
-
-                // [3] vault: valid token account
-                require_valid_token_account(&r[3])?;
-
-                // [9] pool_program: key validation
-                r[9].assert_key(&UNIFIED_SOL_POOL_PROGRAM_ID)
-                    .map_err(|_| ShieldedPoolError::InvalidProgramAccount)?;
-
-                slot_accounts[1] = Some(SlotAccounts::UnifiedSol(UnifiedSolSlotAccounts {
-                    pool_config: &r[0],
-                    unified_sol_pool_config: &r[1],
-                    lst_config: &r[2],
-                    vault: &r[3],
-                    escrow: &r[4],
-                    escrow_vault_authority: &r[5],
-                    escrow_token: &r[6],
-                    recipient_token: &r[7],
-                    relayer_token: &r[8],
-                    pool_program: &r[9],
-                }));
-                remaining_idx += 10;
-            }
-            Some(SlotPoolType::None) => {
-                // Inactive slot - no accounts to load
-            }
-            None => return Err(ShieldedPoolError::InvalidPoolConfig.into()),
-        }
-
-        // Section 3: Extract and validate hub_authority (always last)
-        // Note: remaining_idx is now relative to 'remaining' (after slot accounts),
-        // so we access remaining.get() not remaining_accounts.get()
-        let hub_authority = remaining
-            .get(remaining_idx)
-            .ok_or(ShieldedPoolError::MissingAccounts)?;
-
-        if hub_authority.key() != &HUB_AUTHORITY_ADDRESS {
-            return Err(ShieldedPoolError::InvalidHubAuthority.into());
-        }
+        // Section 2: Load slot accounts, Section 3: hub_authority (always last)
+        let (slot_accounts, hub_authority) = load_public_slots(
+            &panchor_ctx.remaining_accounts[remaining_idx..],
+            &data.slot_pool_type,
+            &data.route_leg_counts,
+        )?;
 
         (reward_config_map, slot_accounts, hub_authority)
     };
@@ -729,6 +561,7 @@ pub fn process_execute_transact(
     // Validates relayer signer and token accounts only if relayer fees are charged.
     // R10: relayer authorized, R11: token accounts correct
 
+    cu_trace::probe("P5");
     if has_relayer {
         // R10: Relayer must be a signer if there are non-zero relayer fees
         relayer.assert_signer()?;
@@ -745,19 +578,20 @@ pub fn process_execute_transact(
             {
                 let relayer_token = slot.relayer_token();
 
-                // Verify relayer_token is owned by SPL Token program
-                crate::validation::require_token_program_owner(relayer_token)?;
+                // Verify relayer_token is owned by a token program
+                let token_program =
+                    crate::validation::require_any_token_program_owner(relayer_token)?;
 
                 // Verify token account owner is the relayer
                 require_token_account_owner(relayer_token, relayer.key())?;
 
                 // Verify relayer_token is the canonical ATA for the relayer
                 let mint = crate::token::get_token_account_mint(relayer_token)?;
-                crate::token::require_associated_token_account(
+                crate::validation::require_associated_token_account(
                     relayer_token,
                     relayer.key(),
                     &mint,
-                    relayer_token.owner(),
+                    token_program.id(),
                 )?;
             }
         }
@@ -772,6 +606,7 @@ pub fn process_execute_transact(
     // The 8 reward registry lines provide privacy over which assets are transacted.
     // ALL non-zero lines must be validated (not just the privately-selected ones).
 
+    cu_trace::probe("P6");
     let unified_sol_asset_id = compute_unified_sol_asset_id();
 
     for i in 0..N_REWARD_LINES {
@@ -787,18 +622,26 @@ pub fn process_execute_transact(
         if *in_asset_id == unified_sol_asset_id {
             validate_unified_sol_accumulator(reward_config, in_asset_id, in_accumulator)?;
         } else {
-            validate_token_accumulator(reward_config, in_asset_id, in_accumulator)?;
+            validate_token_accumulator(
+                reward_config,
+                in_asset_id,
+                in_accumulator,
+                allow_one_epoch_stale,
+                clock.slot,
+            )?;
         }
     }
 
     // ========================================================================
     // P7: COMMITMENT ROOT VALIDATION (Spec §5.5)
     // ========================================================================
-    // R3: commitment root must be in the tree's root history
+    // R3: commitment root must be among the last `max_root_age` roots
 
+    cu_trace::probe("P7");
     {
         let commitment_tree_data = accounts.commitment_tree.load()?;
-        if !MerkleTree::is_known_root(&commitment_tree_data, proof.commitment_root) {
+        if !MerkleTree::is_recent_root(&commitment_tree_data, proof.commitment_root, max_root_age)
+        {
             return Err(ShieldedPoolError::UnknownRoot.into());
         }
     }
@@ -808,19 +651,15 @@ pub fn process_execute_transact(
     // ========================================================================
     // R2: SHA256(params) mod Fr must match the ZK public input
 
-    let transact_params_hash = utils::calculate_transact_params_hash(transact_params);
-
-    if Fr::from_le_bytes_mod_order(&transact_params_hash)
-        != Fr::from_be_bytes_mod_order(&proof.transact_params_hash)
-    {
-        return Err(ShieldedPoolError::TransactParamsHashMismatch.into());
-    }
+    cu_trace::probe("P8");
+    let transact_params_hash = validate_transact_params_hash(transact_params, proof)?;
 
     // ========================================================================
     // P9: NULLIFIER PDA KEY VALIDATION (fail-fast before expensive proof)
     // ========================================================================
     // Check nullifier account keys match expected PDAs (~200K CU savings if wrong)
 
+    cu_trace::probe("P9");
     for i in 0..N_INS {
         let (expected_pda, _) = find_nullifier_pda(&proof.nullifiers[i]);
         nullifiers[i].assert_key(&expected_pda)?;
@@ -831,7 +670,8 @@ pub fn process_execute_transact(
     // ========================================================================
     // R1: Verify the main transact proof
 
-    if !verify_proof(proof, TRANSACT_VK) {
+    cu_trace::probe("P10");
+    if !verify_proof(proof, &session.proof_points(), vk_for(CircuitId::Transact)) {
         return Err(ShieldedPoolError::InvalidProof.into());
     }
 
@@ -842,11 +682,14 @@ pub fn process_execute_transact(
     // R11: token accounts correct, R12: pools operational
     // Validates all configs and token accounts BEFORE state changes.
 
+    cu_trace::probe("P11");
     let validation_result = validate_public_slots(
         proof,
         transact_params,
         &slot_accounts,
-        unified_sol_asset_id,
+        &unified_sol_asset_id,
+        max_relayer_fee_bps,
+        withdrawals_only,
     )?;
     let _accumulator_epoch = validation_result.accumulator_epoch;
 
@@ -856,6 +699,7 @@ pub fn process_execute_transact(
     // R4: nullifier root valid
     // ZK proof verifies non-membership in indexed merkle tree (finalized epochs)
 
+    cu_trace::probe("P12");
     {
         let tree = accounts.nullifier_indexed_tree.load()?;
         let has_epoch_root = epoch_root_pda.key() != &pinocchio_system::ID;
@@ -888,6 +732,7 @@ pub fn process_execute_transact(
     // R5: nullifier PDAs must be uninitialized (double-spend prevention)
     // Creating PDA fails if already exists = double-spend attempt
 
+    cu_trace::probe("E1");
     let starting_pending_index = {
         let mut tree = accounts.nullifier_indexed_tree.load_mut()?;
         let start_idx = tree.next_pending_index;
@@ -908,6 +753,7 @@ pub fn process_execute_transact(
             global_config,
             shielded_pool_program,
             global_config_bump,
+            event_mode,
             starting_pending_index + i as u64,
         )?;
     }
@@ -918,13 +764,16 @@ pub fn process_execute_transact(
     // Executes deposits/withdrawals via CPI to pool programs.
     // Escrow verification and consumption is done per-slot for deposits.
 
+    cu_trace::probe("E2");
     execute_public_slots(
         program_id,
         &slot_accounts,
         token_program,
         hub_authority,
+        payer,
+        system_program,
         transact_params,
-        &*session_data_ref,
+        &body_hash,
         relayer.key(),
     )?;
 
@@ -933,17 +782,18 @@ pub fn process_execute_transact(
     // ========================================================================
     // Appends new commitments and emits NewCommitmentEvent for each
 
+    cu_trace::probe("E3");
     let (new_root, last_commitment_index) = {
         let mut commitment_tree_data = accounts.commitment_tree.load_mut()?;
         for i in 0..N_OUTS {
             append_commitment(
-                program_id,
                 &mut commitment_tree_data,
                 proof.commitments[i],
                 encrypted_outputs[i],
                 global_config,
                 shielded_pool_program,
                 global_config_bump,
+                event_mode,
             )?;
         }
         let new_root = commitment_tree_data.root;
@@ -956,37 +806,68 @@ pub fn process_execute_transact(
     // ========================================================================
     // Computes receipt hash, appends to tree, and emits event
 
-    let slot = clock.slot;
-    let epoch = clock.epoch;
-
-    let (receipt, receipt_hash) = compute_receipt_and_hash(
-        slot,
-        epoch,
+    cu_trace::probe("E4");
+    append_receipt(
+        &accounts.receipt_tree,
+        &clock,
         new_root,
         last_commitment_index,
         proof,
-        transact_params_hash,
-    )?;
-
-    let receipt_index = {
-        let mut receipt_tree_data = accounts.receipt_tree.load_mut()?;
-        let receipt_index = receipt_tree_data.next_index;
-        receipt_tree_data.append::<Sha256>(receipt_hash)?;
-        receipt_index
-    };
-
-    emit_receipt_event(
-        program_id,
-        receipt_index,
-        &receipt,
-        receipt_hash,
+        transact_params,
+        &transact_params_hash,
         global_config,
         shielded_pool_program,
         global_config_bump,
+        event_mode,
     )?;
 
+    // Mark the session consumed so CloseTransactSession reports `Executed`
+    drop(session_data_ref);
+    TransactSession::header_mut(transact_session)?.executed = 1;
+
+    cu_trace::probe("end");
     Ok(())
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Compare the listed fields, naming the first that differs.
+    macro_rules! assert_fields_eq {
+        ($actual:expr, $expected:expr, [$($field:ident),+ $(,)?]) => {
+            $(
+                assert_eq!(
+                    $actual.$field,
+                    $expected.$field,
+                    concat!("field `", stringify!($field), "`")
+                );
+            )+
+        };
+    }
 
-
+    /// Client-encoded data, as the generated `to_ix` and hand-laid bytes,
+    /// comes out of the handler's parser field for field.
+    #[test]
+    fn test_execute_transact_data_parse() {
+        let token = SlotPoolType::Token as u8;
+        let routed = SlotPoolType::UnifiedSolRouted as u8;
+        let data = ExecuteTransactData {
+            unique_reward_config_count: 3,
+            slot_pool_type: [token, routed],
+            route_leg_counts: [0, 2],
+            _padding: [0; 3],
+        };
+        // count, slot types, route leg counts, padding
+        let hand_laid = [3, token, routed, 0, 2, 0, 0, 0];
+
+        for bytes in [bytemuck::bytes_of(&data), &hand_laid[..]] {
+            let parsed = ExecuteTransactData::try_from(bytes).unwrap();
+            assert_fields_eq!(
+                parsed,
+                data,
+                [unique_reward_config_count, slot_pool_type, route_leg_counts]
+            );
+        }
+    }
+}
@@ -14,12 +14,12 @@
 
 use crate::{
     errors::ShieldedPoolError,
-    events::NewNullifierEvent,
+    events::{NewNullifierEvent, VersionedEventBytes, emit_event_data},
     groth16::{CompressedGroth16Proof, verify_groth16},
     instructions::types::{N_INS, NullifierNonMembershipProofData},
     pda::{NULLIFIER_SEED, find_nullifier_epoch_root_pda, find_nullifier_pda, gen_global_config_seeds},
-    state::{Nullifier, NullifierEpochRoot, NullifierIndexedTree},
-    verifying_keys::NULLIFIER_NON_MEMBERSHIP_VK,
+    state::{EventEmissionMode, Nullifier, NullifierEpochRoot, NullifierIndexedTree},
+    verifying_keys::{CircuitId, vk_for},
 };
 use panchor::{SetDiscriminator, prelude::*};
 use pinocchio::{
@@ -121,8 +121,12 @@ pub fn verify_nullifier_non_membership_proof(
         proof_c: &proof_data.proof_c,
     };
 
-    let verified = verify_groth16(&compressed, &public_inputs, &NULLIFIER_NON_MEMBERSHIP_VK)
-        .map_err(|_| ShieldedPoolError::InvalidNullifierNonMembershipProof)?;
+    let verified = verify_groth16(
+        &compressed,
+        &public_inputs,
+        vk_for(CircuitId::NullifierNonMembership),
+    )
+    .map_err(|_| ShieldedPoolError::InvalidNullifierNonMembershipProof)?;
 
     if !verified {
         return Err(ShieldedPoolError::InvalidNullifierNonMembershipProof.into());
@@ -153,6 +157,7 @@ pub fn verify_and_create_nullifier<'a>(
     global_config_account: &'a AccountInfo,
     shielded_pool_program: &'a AccountInfo,
     global_config_bump: u8,
+    event_mode: EventEmissionMode,
     pending_index: u64,
 ) -> Result<(), ProgramError> {
     let (expected_pda, bump) = find_nullifier_pda(nullifier_hash);
@@ -177,21 +182,20 @@ pub fn verify_and_create_nullifier<'a>(
             data.pending_index = pending_index;
         })?;
 
-    // Emit nullifier event using panchor EventBytes
-    use panchor::prelude::EventBytes;
+    // Emit nullifier event with the versioned event frame
     let nullifier_event = NewNullifierEvent {
         nullifier: *nullifier_hash,
         pending_index,
     };
-    let event_data = nullifier_event.to_event_bytes();
+    let event_data = nullifier_event.to_versioned_event_bytes();
 
-    // Emit via CPI using global_config as signer
+    // Emit via CPI (global_config as signer) or directly, per event_mode
     let bump_bytes = [global_config_bump];
     let signer_seeds = gen_global_config_seeds(&bump_bytes);
     let signer = CpiSigner::from(&signer_seeds);
 
-    crate::utils::emit_cpi_log(
-        &crate::ID,
+    emit_event_data(
+        event_mode,
         global_config_account,
         shielded_pool_program,
         &[signer],
@@ -40,25 +40,63 @@ impl<'a> PoolConfig<'a> {
                 unified_config,
                 lst_config,
                 ..
-            } => unified_config.is_active != 0 && lst_config.is_active != 0,
+            } => unified_config.is_active != 0 && lst_config.is_active(),
+        }
+    }
+
+    /// Reject a slot whose direction is disabled by the LST's mode.
+    ///
+    /// Token pools have no per-asset mode here (the hub's `PoolConfig.mode`
+    /// covers them); unified SOL deposits need the LST's deposit bit and
+    /// withdrawals its withdrawal bit.
+    #[inline]
+    pub fn require_direction_enabled(&self, ext_amount: i64) -> Result<(), ShieldedPoolError> {
+        match self {
+            PoolConfig::Token { .. } => Ok(()),
+            PoolConfig::UnifiedSol { lst_config, .. } => {
+                require_lst_direction_enabled(lst_config, ext_amount)
+            }
         }
     }
 
     /// Get deposit fee rate in basis points.
+    ///
+    /// For unified SOL pools this is the LST's override when set, resolved
+    /// exactly as the pool resolves it in its deposit handler.
     #[inline]
     pub fn deposit_fee_rate(&self) -> u16 {
         match self {
             PoolConfig::Token { config, .. } => config.deposit_fee_rate,
-            PoolConfig::UnifiedSol { unified_config, .. } => unified_config.deposit_fee_rate,
+            PoolConfig::UnifiedSol {
+                unified_config,
+                lst_config,
+            } => lst_config.effective_deposit_fee_rate(unified_config.deposit_fee_rate),
         }
     }
 
     /// Get withdrawal fee rate in basis points.
+    ///
+    /// For unified SOL pools this is the LST's override when set.
     #[inline]
     pub fn withdrawal_fee_rate(&self) -> u16 {
         match self {
             PoolConfig::Token { config, .. } => config.withdrawal_fee_rate,
-            PoolConfig::UnifiedSol { unified_config, .. } => unified_config.withdrawal_fee_rate,
+            PoolConfig::UnifiedSol {
+                unified_config,
+                lst_config,
+            } => lst_config.effective_withdrawal_fee_rate(unified_config.withdrawal_fee_rate),
+        }
+    }
+
+    /// Whether fees round up rather than down.
+    ///
+    /// Token pools choose this with `TokenPoolConfig.round_fees_up` and compute
+    /// their own fee the same way; unified SOL pools always round down.
+    #[inline]
+    pub fn rounds_fees_up(&self) -> bool {
+        match self {
+            PoolConfig::Token { config, .. } => config.round_fees_up != 0,
+            PoolConfig::UnifiedSol { .. } => false,
         }
     }
 
@@ -149,3 +187,47 @@ impl<'a> PoolConfig<'a> {
         }
     }
 }
+
+/// Reject a unified SOL slot whose direction is disabled by `LstConfig.mode`.
+#[inline]
+fn require_lst_direction_enabled(
+    lst_config: &LstConfig,
+    ext_amount: i64,
+) -> Result<(), ShieldedPoolError> {
+    if ext_amount > 0 && !lst_config.deposits_enabled() {
+        return Err(ShieldedPoolError::LstDepositsDisabled);
+    }
+    if ext_amount < 0 && !lst_config.withdrawals_enabled() {
+        return Err(ShieldedPoolError::LstWithdrawalsDisabled);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytemuck::Zeroable;
+    use unified_sol_pool::{LST_MODE_ALL, LST_MODE_DEPOSITS_ENABLED, LST_MODE_WITHDRAWALS_ENABLED};
+
+    #[test]
+    fn test_lst_direction_all_modes() {
+        let deposits_disabled = Err(ShieldedPoolError::LstDepositsDisabled);
+        let withdrawals_disabled = Err(ShieldedPoolError::LstWithdrawalsDisabled);
+        // (mode, deposit result, withdrawal result)
+        let cases = [
+            (0, deposits_disabled.clone(), withdrawals_disabled.clone()),
+            (LST_MODE_DEPOSITS_ENABLED, Ok(()), withdrawals_disabled),
+            (LST_MODE_WITHDRAWALS_ENABLED, deposits_disabled, Ok(())),
+            (LST_MODE_ALL, Ok(()), Ok(())),
+        ];
+
+        let mut lst_config = LstConfig::zeroed();
+        for (mode, deposit, withdrawal) in cases {
+            lst_config.mode = mode;
+            assert_eq!(require_lst_direction_enabled(&lst_config, 1), deposit);
+            assert_eq!(require_lst_direction_enabled(&lst_config, -1), withdrawal);
+            // Slots that move no tokens are never blocked
+            assert_eq!(require_lst_direction_enabled(&lst_config, 0), Ok(()));
+        }
+    }
+}
@@ -1,10 +1,11 @@
 //! Public slot execution orchestration for execute_transact.
 //!
 //! This module orchestrates all operations for each active public slot:
-//! 1. Escrow verification (deposits only)
+//! 1. Escrow verification (deposits only), wrapping native SOL escrows
 //! 2. Pool CPI (deposit or withdrawal)
 //! 3. Escrow consumption (deposits only)
-//! 4. Recipient distribution (withdrawals only)
+//! 4. Recipient distribution (withdrawals only; unwrap slots are paid by the pool,
+//!    routed slots are also paid from each routed vault)
 //! 5. Relayer fee transfer
 //!
 //! # Relayer Fee Source
@@ -14,28 +15,33 @@
 use crate::{
     errors::ShieldedPoolError,
     instructions::types::{N_PUBLIC_LINES, TransactParams},
-    pda::find_escrow_vault_authority_pda,
+    pda::{
+        HUB_AUTHORITY_BUMP, find_escrow_vault_authority_pda, gen_escrow_vault_authority_seeds,
+        gen_hub_authority_seeds,
+    },
     pool_cpi::{
-        execute_signed_vault_transfer, execute_token_deposit_from_escrow_cpi,
-        execute_token_withdrawal_cpi, execute_unified_sol_deposit_from_escrow_cpi,
-        execute_unified_sol_withdrawal_cpi,
+        execute_signed_vault_transfer, read_routed_withdraw_return_data, read_token_pool_op_result,
+        read_unified_sol_pool_op_result, require_pool_op_result, require_unified_sol_op_result,
+        trace_pool_error,
     },
-    state::{LstConfig, TokenPoolConfig, UnifiedSolPoolConfig},
+    state::{DepositEscrowAssetKind, LstConfig, TokenPoolConfig, UnifiedSolPoolConfig},
 };
 
 use super::accounts::{SlotAccounts, TokenSlotAccounts, UnifiedSolSlotAccounts};
-use super::deposit_escrow::{mark_escrow_consumed, verify_escrow_for_deposit};
+use super::deposit_escrow::{mark_escrow_consumed, verify_escrow_for_deposit, wrap_native_escrow};
+use alloc::vec::Vec;
 use panchor::prelude::AccountLoader;
 use pinocchio::{
-    account_info::AccountInfo,
-    instruction::Signer as PinocchioSigner,
-    program_error::ProgramError,
-    pubkey::Pubkey,
+    ProgramResult, account_info::AccountInfo, instruction::Signer as PinocchioSigner,
+    program_error::ProgramError, pubkey::Pubkey,
 };
 use pinocchio_token::instructions::Transfer;
-use zorb_pool_interface::{tokens_to_virtual_sol, virtual_sol_to_tokens};
+use zorb_pool_interface::{
+    DepositCpiAccounts, DepositParams, PoolCpi, PoolType, WithdrawParams, fee::Rounding,
+    route_accounts, tokens_to_virtual_sol, virtual_sol_to_tokens,
+};
 
-use super::fee::calculate_fee;
+use super::fee::{calculate_fee, gross_from_net};
 
 // ============================================================================
 // Public Slot Execution
@@ -44,19 +50,25 @@ use super::fee::calculate_fee;
 /// Execute all operations for active public slots.
 ///
 /// For each non-zero ext_amount, orchestrates:
-/// 1. Escrow verification (deposits)
+/// 1. Escrow verification (deposits), wrapping native SOL escrows
 /// 2. Pool CPI (deposit/withdrawal)
 /// 3. Escrow consumption (deposits)
-/// 4. Recipient distribution (withdrawals)
+/// 4. Recipient distribution (withdrawals, except native SOL unwrap slots)
 /// 5. Relayer fee transfer
+///
+/// `payer` and `system_program` fund the pool's temporary WSOL account in
+/// unwrap slots.
+#[allow(clippy::too_many_arguments)]
 #[inline(never)]
 pub fn execute_public_slots<'a>(
     program_id: &Pubkey,
     slot_accounts: &[Option<SlotAccounts<'a>>; N_PUBLIC_LINES],
     token_program: &'a AccountInfo,
     hub_authority: &'a AccountInfo,
+    payer: &'a AccountInfo,
+    system_program: &'a AccountInfo,
     transact_params: &TransactParams,
-    session_data: &[u8],
+    body_hash: &[u8; 32],
     relayer_key: &Pubkey,
 ) -> Result<(), ProgramError> {
     for i in 0..N_PUBLIC_LINES {
@@ -73,9 +85,13 @@ pub fn execute_public_slots<'a>(
 
         let relayer_fee = transact_params.relayer_fees[i];
 
-        // 1. Verify escrow (deposits only)
+        // 1. Verify escrow (deposits only); native SOL is wrapped just in time
         if ext_amount > 0 {
-            verify_escrow_for_deposit(program_id, slot.escrow(), session_data, relayer_key)?;
+            let asset_kind =
+                verify_escrow_for_deposit(program_id, slot.escrow(), body_hash, relayer_key)?;
+            if asset_kind == DepositEscrowAssetKind::NativeSol {
+                wrap_native_escrow(slot.escrow(), slot.escrow_token(), token_program)?;
+            }
         }
 
         // 2. Pool CPI — returns expected_output for withdrawals (0 for deposits)
@@ -84,8 +100,11 @@ pub fn execute_public_slots<'a>(
                 execute_unified_sol_slot_cpi(
                     token_program,
                     hub_authority,
+                    payer,
+                    system_program,
                     unified,
                     ext_amount,
+                    relayer_fee,
                 )?
             }
             SlotAccounts::Token(token) => {
@@ -103,8 +122,9 @@ pub fn execute_public_slots<'a>(
             mark_escrow_consumed(slot.escrow())?;
         }
 
-        // 4. Distribute withdrawal output to recipient
-        if ext_amount < 0 {
+        // 4. Distribute withdrawal output to recipient (the pool already paid
+        //    unwrap recipients in native SOL)
+        if ext_amount < 0 && !slot.unwraps() {
             let recipient_amount = expected_output
                 .checked_sub(relayer_fee)
                 .ok_or(ShieldedPoolError::ArithmeticOverflow)?;
@@ -138,16 +158,31 @@ pub fn execute_public_slots<'a>(
 ///
 /// - Deposit: `s = φ(e)`, fee calculated on s, `p = s - f`
 /// - Withdrawal: `|e| = φ⁻¹(s - f)`, reverse-engineer s from |e|, `p = -s`
+///
+/// In unwrap slots the pool pays the recipient's share (`expected_output -
+/// relayer_fee`) as native SOL and approves only the relayer fee.
+///
+/// In routed slots the pool may fill the withdrawal from several vaults; each
+/// routed vault's share is paid to its leg's recipient_token here, and only
+/// the named vault's share is returned for recipient and relayer distribution.
+///
+/// Otherwise the pool's `UnifiedSolOpResult` must match the hub's token
+/// amount, virtual SOL value, fee, and exchange rate, so a rate that moved
+/// between the hub's read and the pool's execution aborts the transaction.
+#[allow(clippy::too_many_arguments)]
 #[inline(never)]
 fn execute_unified_sol_slot_cpi<'a>(
     token_program: &'a AccountInfo,
     hub_authority: &'a AccountInfo,
+    payer: &'a AccountInfo,
+    system_program: &'a AccountInfo,
     slot: &UnifiedSolSlotAccounts<'a>,
     ext_amount: i64,
+    relayer_fee: u64,
 ) -> Result<u64, ProgramError> {
     // Load exchange rate from LstConfig
-    let exchange_rate = AccountLoader::<LstConfig>::new(slot.lst_config)?
-        .map(|config| config.harvested_exchange_rate)?;
+    let lst_config = AccountLoader::<LstConfig>::new(slot.lst_config)?;
+    let exchange_rate = lst_config.map(|config| config.harvested_exchange_rate)?;
 
     if ext_amount > 0 {
         // Deposit: ext_amount is GROSS tokens (domain E)
@@ -158,32 +193,35 @@ fn execute_unified_sol_slot_cpi<'a>(
         let virtual_sol = tokens_to_virtual_sol(amount_tokens, exchange_rate)
             .ok_or(ShieldedPoolError::ArithmeticOverflow)? as u64;
 
-        let deposit_fee_rate =
+        // Same effective rate the pool charges (LST override if set)
+        let pool_deposit_fee_rate =
             AccountLoader::<UnifiedSolPoolConfig>::new(slot.unified_sol_pool_config)?
                 .map(|config| config.deposit_fee_rate)?;
+        let deposit_fee_rate =
+            lst_config.map(|config| config.effective_deposit_fee_rate(pool_deposit_fee_rate))?;
 
-        // Fee calculated in domain S (virtual SOL)
-        let fee = calculate_fee(virtual_sol, deposit_fee_rate)?;
+        // Fee calculated in domain S (virtual SOL); unified SOL fees round down
+        let fee = calculate_fee(virtual_sol, deposit_fee_rate, Rounding::Down)?;
 
         let expected_output = virtual_sol
             .checked_sub(fee)
             .ok_or(ShieldedPoolError::ArithmeticOverflow)?;
 
-        let (_, vault_authority_bump) = find_escrow_vault_authority_pda(slot.escrow.key());
-
-        execute_unified_sol_deposit_from_escrow_cpi(
-            slot.unified_sol_pool_config,
-            slot.lst_config,
-            slot.vault,
-            slot.escrow_token,
-            slot.escrow_vault_authority,
-            slot.escrow,
-            slot.pool_program,
-            token_program,
-            vault_authority_bump,
-            amount_tokens,
+        // Unified SOL pools have no fee-exempt list
+        let params = DepositParams {
+            amount: amount_tokens,
             expected_output,
+            depositor: *slot.escrow_vault_authority.key(),
+            ..Default::default()
+        };
+        deposit_from_escrow(
+            PoolType::UnifiedSol,
+            &slot.deposit_cpi_accounts(token_program),
+            slot.escrow,
+            &params,
         )?;
+        let result = read_unified_sol_pool_op_result()?;
+        require_unified_sol_op_result(&result, amount_tokens, virtual_sol, fee, exchange_rate)?;
 
         Ok(0) // No expected_output to distribute for deposits
     } else {
@@ -196,31 +234,21 @@ fn execute_unified_sol_slot_cpi<'a>(
         let net_virtual_sol = tokens_to_virtual_sol(net_tokens, exchange_rate)
             .ok_or(ShieldedPoolError::ArithmeticOverflow)? as u64;
 
-        let withdrawal_fee_rate =
+        // Same effective rate the pool charges (LST override if set)
+        let pool_withdrawal_fee_rate =
             AccountLoader::<UnifiedSolPoolConfig>::new(slot.unified_sol_pool_config)?
                 .map(|config| config.withdrawal_fee_rate)?;
+        let withdrawal_fee_rate = lst_config
+            .map(|config| config.effective_withdrawal_fee_rate(pool_withdrawal_fee_rate))?;
 
         // Reverse-engineer gross virtual SOL from net:
         // Given: net = gross - fee = gross - (gross × rate / B) = gross × (B - rate) / B
         // Solve: gross = net × B / (B - rate)
-        let rate = withdrawal_fee_rate as u64;
-        let denominator = BASIS_POINTS
-            .checked_sub(rate)
-            .ok_or(ShieldedPoolError::ArithmeticOverflow)?;
-
-        if denominator == 0 {
-            return Err(ShieldedPoolError::ArithmeticOverflow.into());
-        }
-
-        let gross_virtual_sol = net_virtual_sol
-            .checked_mul(BASIS_POINTS)
-            .ok_or(ShieldedPoolError::ArithmeticOverflow)?
-            .checked_div(denominator)
-            .ok_or(ShieldedPoolError::ArithmeticOverflow)?;
+        let gross_virtual_sol = gross_from_net(net_virtual_sol, withdrawal_fee_rate)?;
 
         // Pool calculates: fee = gross × rate / B, net = gross - fee
         // Convert net to tokens for actual transfer: tokens = φ⁻¹(net)
-        let fee = calculate_fee(gross_virtual_sol, withdrawal_fee_rate)?;
+        let fee = calculate_fee(gross_virtual_sol, withdrawal_fee_rate, Rounding::Down)?;
         let actual_net_virtual_sol = gross_virtual_sol
             .checked_sub(fee)
             .ok_or(ShieldedPoolError::ArithmeticOverflow)?;
@@ -228,24 +256,81 @@ fn execute_unified_sol_slot_cpi<'a>(
         let expected_output_tokens = virtual_sol_to_tokens(actual_net_virtual_sol, exchange_rate)
             .ok_or(ShieldedPoolError::ArithmeticOverflow)?;
 
-        execute_unified_sol_withdrawal_cpi(
-            slot.unified_sol_pool_config,
-            slot.lst_config,
-            slot.vault,
-            hub_authority,
-            slot.pool_program,
-            token_program,
-            gross_virtual_sol,
+        // Unified SOL pools have no fee-exempt list
+        let mut params = WithdrawParams {
+            amount: gross_virtual_sol,
+            expected_output: expected_output_tokens,
+            ..Default::default()
+        };
+        let mut accounts = slot.withdraw_cpi_accounts(hub_authority, token_program);
+
+        if slot.route.is_some() {
+            let route: Vec<[&AccountInfo; route_accounts::COUNT]> = slot
+                .route_legs()
+                .map(|leg| [leg.lst_config, leg.vault])
+                .collect();
+            params.recipient = token_account_owner(slot.recipient_token);
+            params.route_automatically = (!slot.fallback) as u8;
+            params.fallback_count = if slot.fallback { route.len() as u8 } else { 0 };
+            accounts.route = &route;
+
+            PoolType::UnifiedSol
+                .withdraw(&params, &accounts, &[])
+                .map_err(|e| trace_pool_error(PoolType::UnifiedSol, e))?;
+            let return_data = read_routed_withdraw_return_data()?;
+
+            // Routed vaults pay their leg's recipient_token directly
+            for (leg, &output) in slot.route_legs().zip(&return_data.outputs[1..]) {
+                distribute_to_recipient(leg.vault, leg.recipient_token, hub_authority, output)?;
+            }
+
+            return Ok(return_data.outputs[0]);
+        }
+
+        match slot.unwrap {
+            // Unwrap slots: recipient_token is the recipient's system account,
+            // which the pool pays directly, so it requires the hub's signature
+            Some(unwrap) => {
+                params.recipient = *slot.recipient_token.key();
+                params.unwrap = 1;
+                params.unwrap_amount = expected_output_tokens
+                    .checked_sub(relayer_fee)
+                    .ok_or(ShieldedPoolError::ArithmeticOverflow)?;
+                accounts.unwrap = Some([
+                    slot.recipient_token,
+                    unwrap.unwrap_account,
+                    unwrap.native_mint,
+                    payer,
+                    system_program,
+                ]);
+
+                let bump_bytes = [HUB_AUTHORITY_BUMP];
+                let seeds = gen_hub_authority_seeds(&bump_bytes);
+                let signer = [PinocchioSigner::from(&seeds)];
+                PoolType::UnifiedSol
+                    .withdraw(&params, &accounts, &signer)
+                    .map_err(|e| trace_pool_error(PoolType::UnifiedSol, e))?;
+            }
+            None => {
+                params.recipient = token_account_owner(slot.recipient_token);
+                PoolType::UnifiedSol
+                    .withdraw(&params, &accounts, &[])
+                    .map_err(|e| trace_pool_error(PoolType::UnifiedSol, e))?;
+            }
+        }
+        let result = read_unified_sol_pool_op_result()?;
+        require_unified_sol_op_result(
+            &result,
             expected_output_tokens,
+            gross_virtual_sol,
+            fee,
+            exchange_rate,
         )?;
 
         Ok(expected_output_tokens)
     }
 }
 
-/// Basis points constant (100% = 10000).
-const BASIS_POINTS: u64 = 10_000;
-
 /// Execute token pool CPI for a slot.
 ///
 /// Computes deposit/withdrawal amounts with fee calculation,
@@ -269,29 +354,40 @@ fn execute_token_slot_cpi<'a>(
         // Deposit: ext_amount is GROSS (what enters the vault)
         let amount = ext_amount as u64;
 
-        let deposit_fee_rate = AccountLoader::<TokenPoolConfig>::new(slot.token_pool_config)?
-            .map(|config| config.deposit_fee_rate)?;
-
-        let fee = calculate_fee(amount, deposit_fee_rate)?;
+        // Exempt depositors pay no fee; the pool re-checks its list against this
+        let depositor = *slot.escrow_vault_authority.key();
+        let (deposit_fee_rate, fee_exempt, rounding, accumulator_epoch) =
+            AccountLoader::<TokenPoolConfig>::new(slot.token_pool_config)?.map(|config| {
+                (
+                    config.deposit_fee_rate,
+                    config.is_fee_exempt(&depositor),
+                    config.fee_rounding(),
+                    config.accumulator_epoch,
+                )
+            })?;
+        let deposit_fee_rate = if fee_exempt { 0 } else { deposit_fee_rate };
+
+        let fee = calculate_fee(amount, deposit_fee_rate, rounding)?;
 
         let expected_output = amount
             .checked_sub(fee)
             .ok_or(ShieldedPoolError::ArithmeticOverflow)?;
 
-        let (_, vault_authority_bump) = find_escrow_vault_authority_pda(slot.escrow.key());
-
-        execute_token_deposit_from_escrow_cpi(
-            slot.token_pool_config,
-            slot.vault,
-            slot.escrow_token,
-            slot.escrow_vault_authority,
-            token_program,
-            slot.escrow,
-            slot.pool_program,
-            vault_authority_bump,
+        let params = DepositParams {
             amount,
             expected_output,
+            depositor,
+            expect_fee_exempt: fee_exempt as u8,
+            ..Default::default()
+        };
+        deposit_from_escrow(
+            PoolType::Token,
+            &slot.deposit_cpi_accounts(token_program),
+            slot.escrow,
+            &params,
         )?;
+        let result = read_token_pool_op_result()?;
+        require_pool_op_result(&result, amount, fee, expected_output, accumulator_epoch)?;
 
         Ok(0) // No expected_output to distribute for deposits
     } else {
@@ -299,47 +395,93 @@ fn execute_token_slot_cpi<'a>(
         // Formula from spec: ext_amount = -(gross - fee), so |ext_amount| = gross - fee = NET
         let net_output = (-ext_amount) as u64;
 
-        let withdrawal_fee_rate = AccountLoader::<TokenPoolConfig>::new(slot.token_pool_config)?
-            .map(|config| config.withdrawal_fee_rate)?;
+        // Exempt recipients pay no fee; the pool re-checks its list against this
+        let recipient = token_account_owner(slot.recipient_token);
+        let (withdrawal_fee_rate, fee_exempt, rounding, accumulator_epoch) =
+            AccountLoader::<TokenPoolConfig>::new(slot.token_pool_config)?.map(|config| {
+                (
+                    config.withdrawal_fee_rate,
+                    config.is_fee_exempt(&recipient),
+                    config.fee_rounding(),
+                    config.accumulator_epoch,
+                )
+            })?;
+        let withdrawal_fee_rate = if fee_exempt { 0 } else { withdrawal_fee_rate };
 
         // Reverse-engineer gross amount: gross = net * 10000 / (10000 - rate)
         // This ensures pool's calculation: gross - fee(gross) == net
-        let rate = withdrawal_fee_rate as u64;
-        let denominator = BASIS_POINTS
-            .checked_sub(rate)
-            .ok_or(ShieldedPoolError::ArithmeticOverflow)?;
-
-        if denominator == 0 {
-            return Err(ShieldedPoolError::ArithmeticOverflow.into());
-        }
+        let gross_amount = gross_from_net(net_output, withdrawal_fee_rate)?;
 
-        let gross_amount = net_output
-            .checked_mul(BASIS_POINTS)
-            .ok_or(ShieldedPoolError::ArithmeticOverflow)?
-            .checked_div(denominator)
-            .ok_or(ShieldedPoolError::ArithmeticOverflow)?;
-
-        // Compute actual expected_output (what pool will calculate and approve)
+        // Compute actual expected_output (what pool will calculate and approve),
+        // rounded the way the pool rounds its fees
         // May differ slightly from net_output due to integer division rounding
-        let fee = calculate_fee(gross_amount, withdrawal_fee_rate)?;
+        let fee = calculate_fee(gross_amount, withdrawal_fee_rate, rounding)?;
         let expected_output = gross_amount
             .checked_sub(fee)
             .ok_or(ShieldedPoolError::ArithmeticOverflow)?;
 
-        execute_token_withdrawal_cpi(
-            slot.token_pool_config,
-            slot.vault,
-            hub_authority,
-            slot.pool_program,
-            token_program,
+        let params = WithdrawParams {
+            amount: gross_amount,
+            expected_output,
+            recipient,
+            expect_fee_exempt: fee_exempt as u8,
+            ..Default::default()
+        };
+        PoolType::Token
+            .withdraw(
+                &params,
+                &slot.withdraw_cpi_accounts(hub_authority, token_program),
+                &[],
+            )
+            .map_err(|e| trace_pool_error(PoolType::Token, e))?;
+        let result = read_token_pool_op_result()?;
+        require_pool_op_result(
+            &result,
             gross_amount,
+            fee,
             expected_output,
+            accumulator_epoch,
         )?;
 
         Ok(expected_output)
     }
 }
 
+/// Invoke `pool`'s deposit with the slot's escrow vault as the depositor.
+///
+/// The pool executes the escrow_vault→vault transfer, so the
+/// escrow_vault_authority PDA signs the CPI.
+fn deposit_from_escrow(
+    pool: PoolType,
+    accounts: &DepositCpiAccounts<'_>,
+    escrow: &AccountInfo,
+    params: &DepositParams,
+) -> ProgramResult {
+    let (_, vault_authority_bump) = find_escrow_vault_authority_pda(escrow.key());
+    let bump_slice = [vault_authority_bump];
+    let seeds = gen_escrow_vault_authority_seeds(escrow.key(), &bump_slice);
+    let signer = [PinocchioSigner::from(&seeds)];
+    pool.deposit(params, accounts, &signer)
+        .map_err(|e| trace_pool_error(pool, e))
+}
+
+/// Offset of `Account.owner` in an SPL Token account.
+const TOKEN_ACCOUNT_OWNER_OFFSET: usize = 32;
+
+/// Read the owner of a token account, or the zero key if it has no readable owner.
+///
+/// The zero key is never fee-exempt, so an unreadable recipient pays fees.
+fn token_account_owner(token_account: &AccountInfo) -> Pubkey {
+    token_account
+        .try_borrow_data()
+        .ok()
+        .and_then(|data| {
+            data.get(TOKEN_ACCOUNT_OWNER_OFFSET..TOKEN_ACCOUNT_OWNER_OFFSET + 32)
+                .and_then(|bytes| bytes.try_into().ok())
+        })
+        .unwrap_or_default()
+}
+
 // ============================================================================
 // Distribution Helpers
 // ============================================================================
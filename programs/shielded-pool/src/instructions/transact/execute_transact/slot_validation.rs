@@ -14,16 +14,22 @@
 use crate::{
     errors::ShieldedPoolError,
     instructions::types::{N_PUBLIC_LINES, TransactParams, TransactProofData},
+    pool_registry::pool_kind_entry,
     state::{
-        HubPoolType, LstConfig, PoolConfig as HubPoolConfig, TokenPoolConfig,
+        BPS_DENOMINATOR, LstConfig, PoolConfig as HubPoolConfig, TokenPoolConfig,
         UnifiedSolPoolConfig,
     },
-    validation::require_token_account_mint,
+    token::WSOL_MINT,
+    validation::{
+        require_any_token_program_owner, require_token_account_mint, require_token_account_owner,
+    },
 };
 use panchor::prelude::AccountLoader;
 use pinocchio::{ProgramResult, program_error::ProgramError, pubkey::Pubkey};
 
-use super::accounts::SlotAccounts;
+use pinocchio_token::state::TokenAccount;
+
+use super::accounts::{SlotAccounts, UnifiedSolSlotAccounts};
 use super::pool_config::PoolConfig;
 use super::validators::{
     validate_token_public_amount, validate_unified_sol_public_amount,
@@ -45,7 +51,7 @@ pub struct SlotValidationResult {
 /// - R7: public amounts match ZK proof
 /// - R8: fees are sufficient
 /// - R11: token accounts are correct
-/// - R12: pools are operational
+/// - R12: pools are operational (and the slot's direction is enabled)
 ///
 /// # Security
 /// - ALL validation must pass BEFORE nullifier creation
@@ -58,6 +64,8 @@ pub struct SlotValidationResult {
 /// * `transact_params` - The transaction parameters
 /// * `slot_accounts` - Loaded slot accounts for each public slot
 /// * `unified_sol_asset_id` - The computed unified SOL asset ID
+/// * `max_relayer_fee_bps` - GlobalConfig relayer fee cap
+/// * `withdrawals_only` - GlobalConfig withdrawal-only mode (rejects deposit slots)
 ///
 /// # Returns
 /// * `Ok(SlotValidationResult)` - Validation passed, returns data needed for execution
@@ -67,10 +75,15 @@ pub fn validate_public_slots<'a>(
     proof: &TransactProofData,
     transact_params: &TransactParams,
     slot_accounts: &[Option<SlotAccounts<'a>>; N_PUBLIC_LINES],
-    unified_sol_asset_id: [u8; 32],
+    unified_sol_asset_id: &[u8; 32],
+    max_relayer_fee_bps: u64,
+    withdrawals_only: bool,
 ) -> Result<SlotValidationResult, ProgramError> {
     let mut accumulator_epoch: u64 = 0;
 
+    // Withdrawal-only mode: any deposit slot rejects the whole transaction
+    validate_withdrawals_only(&transact_params.ext_amounts, withdrawals_only)?;
+
     for i in 0..N_PUBLIC_LINES {
         let public_asset_id = proof.public_asset_ids[i];
         let ext_amount = transact_params.ext_amounts[i];
@@ -101,16 +114,18 @@ pub fn validate_public_slots<'a>(
 
         let fee = transact_params.fees[i];
         let recipient = transact_params.recipients[i];
-        let is_unified = public_asset_id == unified_sol_asset_id;
+        let is_unified = public_asset_id == *unified_sol_asset_id;
 
-        // V8.0: Defense-in-depth - Validate hub pool_config
-        validate_hub_pool_config(slot)?;
+        // V8.0: Defense-in-depth - Validate hub pool_config and direction mode
+        validate_hub_pool_config(slot, ext_amount)?;
 
         // Load config and construct PoolConfig based on slot type
         let pool = load_and_validate_pool_config(slot, is_unified, &mut accumulator_epoch)?;
 
-        // Relayer fee used directly - if too high, transfer will fail naturally
+        // Relayer fee is capped by GlobalConfig.max_relayer_fee_bps; amounts
+        // beyond |ext_amount| fail naturally in the transfer
         let relayer_fee = transact_params.relayer_fees[i];
+        validate_relayer_fee_cap(relayer_fee, ext_amount, max_relayer_fee_bps)?;
 
         // V8.3/V8.4: R7 (public amounts), R8 (fees), R12 (is_active)
         let public_amount = proof.public_amounts[i];
@@ -134,6 +149,13 @@ pub fn validate_public_slots<'a>(
         // Defense-in-depth: Validate vault mint matches expected
         require_token_account_mint(slot.vault(), &pool.vault_mint())?;
 
+        // Fallback LSTs get the same pool, direction and vault checks
+        if let SlotAccounts::UnifiedSol(unified) = slot
+            && unified.fallback
+        {
+            validate_fallback_legs(unified, ext_amount)?;
+        }
+
         // V8.3.5/V8.4.5: R12 - Pool-specific deposit validation
         if ext_amount > 0 {
             pool.validate_deposit(ext_amount as u64, accumulator_epoch)?;
@@ -156,6 +178,30 @@ pub fn validate_public_slots<'a>(
 // Helper Functions
 // ============================================================================
 
+/// Validate that `relayer_fee` is at most `max_bps` basis points of |ext_amount|.
+#[inline]
+pub fn validate_relayer_fee_cap(relayer_fee: u64, ext_amount: i64, max_bps: u64) -> ProgramResult {
+    let cap = ext_amount.unsigned_abs() as u128 * max_bps as u128 / BPS_DENOMINATOR as u128;
+    if relayer_fee as u128 > cap {
+        return Err(ShieldedPoolError::RelayerFeeCapExceeded.into());
+    }
+    Ok(())
+}
+
+/// Reject deposits (positive `ext_amount`) while the hub is in withdrawal-only mode.
+///
+/// Withdrawals and inactive slots (pure private transfers) are unaffected.
+#[inline]
+pub fn validate_withdrawals_only(
+    ext_amounts: &[i64; N_PUBLIC_LINES],
+    withdrawals_only: bool,
+) -> ProgramResult {
+    if withdrawals_only && ext_amounts.iter().any(|&ext_amount| ext_amount > 0) {
+        return Err(ShieldedPoolError::DepositsDisabled.into());
+    }
+    Ok(())
+}
+
 /// Validate that an inactive slot has zero values in proof and params.
 ///
 /// # Security
@@ -187,25 +233,26 @@ fn validate_inactive_slot(
     Ok(())
 }
 
-/// Validate hub pool_config owner and pool_type match slot type.
+/// Validate hub pool_config owner and pool_kind match slot type.
 ///
 /// # Security
-/// - Verifies hub's PoolConfig matches the expected pool type based on SlotAccounts
+/// - Looks up the PoolConfig's pool_kind in the pool registry (`UnknownPoolKind`
+///   if unregistered) and requires its strategy to match the SlotAccounts variant
+/// - Requires the PoolConfig's pool_program to be the registered program
+/// - Rejects assets whose pool has been deregistered
+/// - Rejects deposits/withdrawals whose direction is disabled in `PoolConfig.mode`
 #[inline(never)]
-fn validate_hub_pool_config(slot: &SlotAccounts) -> Result<(), ProgramError> {
+fn validate_hub_pool_config(slot: &SlotAccounts, ext_amount: i64) -> Result<(), ProgramError> {
     let hub_config = AccountLoader::<HubPoolConfig>::new(slot.pool_config())
         .map_err(|_| ShieldedPoolError::InvalidPoolConfig)?
         .load()
         .map_err(|_| ShieldedPoolError::InvalidPoolConfig)?;
+    hub_config.require_registered()?;
+    hub_config.require_direction_enabled(ext_amount)?;
 
-    // Verify pool_type matches the SlotAccounts variant
-    let expected_pool_type = match slot {
-        SlotAccounts::Token(_) => HubPoolType::Token,
-        SlotAccounts::UnifiedSol(_) => HubPoolType::UnifiedSol,
-    };
-    let actual_pool_type =
-        HubPoolType::from_u8(hub_config.pool_type).ok_or(ShieldedPoolError::InvalidPoolConfig)?;
-    if actual_pool_type != expected_pool_type {
+    // Verify the registered strategy matches the SlotAccounts variant
+    let entry = pool_kind_entry(hub_config.pool_kind())?;
+    if entry.pool != slot.pool_type() || hub_config.pool_program() != entry.program_id() {
         return Err(ShieldedPoolError::InvalidPoolConfig.into());
     }
 
@@ -237,7 +284,7 @@ fn load_and_validate_pool_config<'a>(
             // Capture reward_epoch for harvest validation
             *accumulator_epoch = unified_config.reward_epoch;
 
-            // PDA validation already done in load_slot_accounts (accounts.rs L980-993, L1020-1033)
+            // PDA validation already done in load_slot_accounts (accounts.rs)
 
             let lst_config = AccountLoader::<LstConfig>::new(unified.lst_config)?.load()?;
 
@@ -255,7 +302,7 @@ fn load_and_validate_pool_config<'a>(
             let token_config =
                 AccountLoader::<TokenPoolConfig>::new(token.token_pool_config)?.load()?;
 
-            // PDA validation already done in load_slot_accounts (accounts.rs L742-755)
+            // PDA validation already done in load_slot_accounts (accounts.rs)
 
             // Validate is_unified flag matches asset type
             if is_unified {
@@ -274,6 +321,10 @@ fn load_and_validate_pool_config<'a>(
 /// # Security
 /// - Recipient token address must match transact_params (ZK-bound)
 /// - For withdrawals: recipient token must be valid SPL token with correct mint
+/// - For native SOL unwrap slots: recipient must be a system account (see
+///   [`validate_unwrap_recipient`])
+/// - For routed and fallback slots: every route leg pays the same recipient
+///   (see [`validate_route_recipients`])
 /// - Relayer token mint is validated (owner validated in V2)
 ///
 /// # Escrow Flow
@@ -292,8 +343,17 @@ pub fn validate_slot_token_accounts(
         return Err(ShieldedPoolError::RecipientMismatch.into());
     }
 
-    // Additional recipient validation for withdrawals
-    if ext_amount < 0 {
+    // Native SOL unwrap: recipient_token holds the recipient's system account
+    if slot.unwraps() {
+        validate_unwrap_recipient(
+            mint,
+            recipient,
+            slot.recipient_token().owner(),
+            relayer_fee,
+            ext_amount,
+        )?;
+    } else if ext_amount < 0 {
+        // Additional recipient validation for withdrawals
         let withdrawal_amount = (-ext_amount) as u64;
         let recipient_amount = withdrawal_amount
             .checked_sub(relayer_fee)
@@ -306,14 +366,21 @@ pub fn validate_slot_token_accounts(
                 return Err(ShieldedPoolError::InvalidRecipient.into());
             }
 
-            // Validate recipient_token is owned by SPL Token program
-            crate::validation::require_token_program_owner(slot.recipient_token())?;
+            // Validate recipient_token is owned by a token program
+            require_any_token_program_owner(slot.recipient_token())?;
 
             // Validate recipient_token mint matches expected
             require_token_account_mint(slot.recipient_token(), mint)?;
         }
     }
 
+    // Routed withdrawal: each route leg pays the recipient in its own LST
+    if let SlotAccounts::UnifiedSol(unified) = slot
+        && unified.route.is_some()
+    {
+        validate_route_recipients(unified, ext_amount)?;
+    }
+
     // Relayer token mint validation (owner validated in V2)
     if relayer_fee > 0 {
         require_token_account_mint(slot.relayer_token(), mint)?;
@@ -321,3 +388,246 @@ pub fn validate_slot_token_accounts(
 
     Ok(())
 }
+
+/// Validate a native SOL unwrap slot's recipient.
+///
+/// # Security
+/// - Only WSOL withdrawals can be unwrapped (the pool re-checks the LST)
+/// - The recipient must receive a non-zero amount, so the pool's temporary
+///   WSOL account is never created for nothing
+/// - The recipient must be a non-zero, system-owned account; lamports
+///   credited to a token or program account could be stranded
+#[inline]
+pub fn validate_unwrap_recipient(
+    mint: &Pubkey,
+    recipient: &Pubkey,
+    recipient_owner: &Pubkey,
+    relayer_fee: u64,
+    ext_amount: i64,
+) -> ProgramResult {
+    if ext_amount >= 0 {
+        return Err(ShieldedPoolError::InvalidSlotConfiguration.into());
+    }
+    if *mint != WSOL_MINT {
+        return Err(ShieldedPoolError::InvalidMint.into());
+    }
+
+    let recipient_amount = ext_amount
+        .unsigned_abs()
+        .checked_sub(relayer_fee)
+        .ok_or(ShieldedPoolError::ArithmeticOverflow)?;
+    if recipient_amount == 0 {
+        return Err(ShieldedPoolError::InvalidSlotConfiguration.into());
+    }
+
+    if *recipient == Pubkey::default() || *recipient_owner != pinocchio_system::ID {
+        return Err(ShieldedPoolError::InvalidRecipient.into());
+    }
+    Ok(())
+}
+
+/// Validate a routed slot's per-leg recipient token accounts.
+///
+/// # Security
+/// - Only withdrawals can be routed
+/// - Each leg's recipient_token must be an SPL token account for that leg's
+///   LST, owned by the same wallet as the ZK-bound recipient_token, so routed
+///   output cannot be redirected
+#[inline(never)]
+pub fn validate_route_recipients(slot: &UnifiedSolSlotAccounts, ext_amount: i64) -> ProgramResult {
+    if ext_amount >= 0 {
+        return Err(ShieldedPoolError::InvalidSlotConfiguration.into());
+    }
+
+    require_any_token_program_owner(slot.recipient_token)?;
+    let recipient_owner = *TokenAccount::from_account_info(slot.recipient_token)?.owner();
+
+    for leg in slot.route_legs() {
+        let lst_mint = AccountLoader::<LstConfig>::new(leg.lst_config)?.map(|c| c.lst_mint)?;
+        require_any_token_program_owner(leg.recipient_token)?;
+        require_token_account_mint(leg.recipient_token, &lst_mint)?;
+        require_token_account_owner(leg.recipient_token, &recipient_owner)
+            .map_err(|_| ShieldedPoolError::InvalidRecipient)?;
+    }
+    Ok(())
+}
+
+/// Validate a fallback slot's LSTs as the slot's own LST is validated.
+///
+/// # Security
+/// - Fallback LSTs are named by the caller rather than taken from the pool's
+///   priority list, so each must be active with withdrawals enabled and its
+///   vault must be its LstConfig's vault, holding its mint
+/// - No LST may appear twice (the slot's own included), so no vault is
+///   approved twice
+#[inline(never)]
+pub fn validate_fallback_legs(slot: &UnifiedSolSlotAccounts, ext_amount: i64) -> ProgramResult {
+    for (i, leg) in slot.route_legs().enumerate() {
+        if leg.lst_config.key() == slot.lst_config.key()
+            || slot
+                .route_legs()
+                .take(i)
+                .any(|prev| prev.lst_config.key() == leg.lst_config.key())
+        {
+            return Err(ShieldedPoolError::InvalidSlotConfiguration.into());
+        }
+
+        let pool = PoolConfig::UnifiedSol {
+            unified_config: AccountLoader::<UnifiedSolPoolConfig>::new(
+                slot.unified_sol_pool_config,
+            )?
+            .load()?,
+            lst_config: AccountLoader::<LstConfig>::new(leg.lst_config)?.load()?,
+        };
+        if !pool.is_active() {
+            return Err(ShieldedPoolError::PoolPaused.into());
+        }
+        pool.require_direction_enabled(ext_amount)?;
+        if pool.expected_vault_address() != *leg.vault.key() {
+            return Err(ShieldedPoolError::InvalidVault.into());
+        }
+        require_token_account_mint(leg.vault, &pool.vault_mint())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relayer_fee_cap() {
+        // 1% of 10_000 = 100
+        assert!(validate_relayer_fee_cap(100, -10_000, 100).is_ok());
+        assert!(validate_relayer_fee_cap(101, -10_000, 100).is_err());
+        assert!(validate_relayer_fee_cap(100, 10_000, 100).is_ok());
+        // Default (100%) only bounds the fee by |ext_amount|
+        assert!(validate_relayer_fee_cap(u64::MAX, i64::MIN, BPS_DENOMINATOR).is_err());
+        assert!(validate_relayer_fee_cap(1 << 63, i64::MIN, BPS_DENOMINATOR).is_ok());
+        // Zero cap forbids any relayer fee
+        assert!(validate_relayer_fee_cap(0, -10_000, 0).is_ok());
+        assert!(validate_relayer_fee_cap(1, -10_000, 0).is_err());
+    }
+
+    #[test]
+    fn test_withdrawals_only() {
+        let deposit_err = Err(ShieldedPoolError::DepositsDisabled.into());
+
+        // Mixed deposit + withdrawal is rejected
+        assert_eq!(validate_withdrawals_only(&[100, -50], true), deposit_err);
+        assert_eq!(validate_withdrawals_only(&[-50, 100], true), deposit_err);
+        // Single deposit is rejected
+        assert_eq!(validate_withdrawals_only(&[100, 0], true), deposit_err);
+        // Pure withdrawals and private transfers pass
+        assert_eq!(validate_withdrawals_only(&[-50, 0], true), Ok(()));
+        assert_eq!(validate_withdrawals_only(&[-50, -25], true), Ok(()));
+        assert_eq!(validate_withdrawals_only(&[0, 0], true), Ok(()));
+        // Mode off allows deposits
+        assert_eq!(validate_withdrawals_only(&[100, -50], false), Ok(()));
+    }
+
+    #[test]
+    fn test_receipt_event_fee_breakdown_matches_validated_params() {
+        use crate::events::{
+            NEW_RECEIPT_EVENT_HEADER_SIZE, NewReceiptEventHeader, RECEIPT_VERSION, Receipt,
+            build_new_receipt_event_bytes, split_event_frame,
+        };
+        use crate::utils::{check_public_amount, validate_fee};
+
+        // Deposit of 10_000 and withdrawal of 20_000 at a 10 bps pool fee
+        let mut params: TransactParams = bytemuck::Zeroable::zeroed();
+        params.relayer = [9u8; 32];
+        params.ext_amounts = [10_000, -20_000];
+        params.fees = [10, 20];
+        params.relayer_fees = [0, 150];
+
+        // The per-slot fee checks validate_public_slots runs on token slots
+        let public_amounts = [amount_bytes(9_990), amount_bytes(-20_020)];
+        for i in 0..N_PUBLIC_LINES {
+            let ext_amount = params.ext_amounts[i];
+            assert!(check_public_amount(ext_amount, params.fees[i], public_amounts[i]));
+            validate_fee(
+                ext_amount,
+                params.fees[i],
+                params.relayer_fees[i],
+                10,
+                10,
+                false,
+            )
+            .unwrap();
+            validate_relayer_fee_cap(params.relayer_fees[i], ext_amount, 100).unwrap();
+        }
+
+        let receipt = Receipt {
+            version: RECEIPT_VERSION,
+            slot: 1,
+            epoch: 2,
+            commitment_root: [3u8; 32],
+            last_commitment_index: 4,
+            commitments: [[5u8; 32]; crate::instructions::N_OUTS],
+            nullifiers: [[6u8; 32]; crate::instructions::N_INS],
+            transact_params_hash: [7u8; 32],
+            public_asset_ids: [[8u8; 32]; N_PUBLIC_LINES],
+            public_amounts,
+        };
+        let header = NewReceiptEventHeader::new(5, [1u8; 32], &params);
+        let bytes = build_new_receipt_event_bytes(&header, &receipt).unwrap();
+
+        // Indexer view of the emitted header
+        let (_, payload) =
+            split_event_frame(&bytes, crate::events::EventType::NewReceipt as u64).unwrap();
+        let emitted: NewReceiptEventHeader =
+            bytemuck::pod_read_unaligned(&payload[..NEW_RECEIPT_EVENT_HEADER_SIZE]);
+        assert_eq!(emitted.receipt_index, 5);
+        assert_eq!(emitted.relayer, params.relayer);
+        assert_eq!(emitted.fees, params.fees);
+        assert_eq!(emitted.relayer_fees, params.relayer_fees);
+        assert_eq!(emitted.ext_amounts_abs, [10_000, 20_000]);
+    }
+
+    #[test]
+    fn test_validate_unwrap_recipient() {
+        let recipient = [7u8; 32];
+        let system = pinocchio_system::ID;
+
+        // WSOL withdrawal to a system account passes
+        assert_eq!(
+            validate_unwrap_recipient(&WSOL_MINT, &recipient, &system, 10, -1_000),
+            Ok(())
+        );
+
+        // Deposits and other mints cannot be unwrapped
+        assert_eq!(
+            validate_unwrap_recipient(&WSOL_MINT, &recipient, &system, 0, 1_000),
+            Err(ShieldedPoolError::InvalidSlotConfiguration.into())
+        );
+        assert_eq!(
+            validate_unwrap_recipient(&[1u8; 32], &recipient, &system, 0, -1_000),
+            Err(ShieldedPoolError::InvalidMint.into())
+        );
+
+        // The relayer fee cannot consume the whole withdrawal
+        assert_eq!(
+            validate_unwrap_recipient(&WSOL_MINT, &recipient, &system, 1_000, -1_000),
+            Err(ShieldedPoolError::InvalidSlotConfiguration.into())
+        );
+
+        // Token accounts and the zero key are not valid unwrap recipients
+        assert_eq!(
+            validate_unwrap_recipient(&WSOL_MINT, &recipient, &pinocchio_token::ID, 0, -1_000),
+            Err(ShieldedPoolError::InvalidRecipient.into())
+        );
+        assert_eq!(
+            validate_unwrap_recipient(&WSOL_MINT, &Pubkey::default(), &system, 0, -1_000),
+            Err(ShieldedPoolError::InvalidRecipient.into())
+        );
+    }
+
+    /// Signed amount as a big-endian BN254 field element
+    fn amount_bytes(amount: i64) -> [u8; 32] {
+        use ark_ff::{BigInteger, PrimeField};
+        let value = ark_bn254::Fr::from(amount.unsigned_abs());
+        let value = if amount < 0 { -value } else { value };
+        value.into_bigint().to_bytes_be().try_into().unwrap()
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! This module handles Merkle tree state transitions:
 //! - Appending commitments to the commitment tree
-//! - Computing and appending receipt hashes to the receipt tree
+//! - Computing and appending receipts to the receipt tree
 //! - Emitting events for off-chain indexers
 //!
 //! # Security Considerations
@@ -12,17 +12,23 @@
 
 use crate::{
     CommitmentMerkleTree,
-    events::{NewCommitmentEvent, Receipt, RECEIPT_VERSION, build_new_receipt_event_bytes},
-    instructions::types::TransactProofData,
+    events::{
+        NewCommitmentEvent, NewReceiptEventHeader, Receipt, RECEIPT_VERSION,
+        build_new_receipt_event_bytes, emit_event_data,
+    },
+    instructions::types::{TransactParams, TransactProofData},
     merkle_tree::MerkleTree,
     pda::gen_global_config_seeds,
+    poseidon::Poseidon,
+    state::{EventEmissionMode, ReceiptMerkleTree},
 };
-use light_hasher::Poseidon;
+use light_hasher::Sha256;
+use panchor::prelude::AccountLoader;
 use pinocchio::{
     account_info::AccountInfo,
     instruction::Signer as CpiSigner,
     program_error::ProgramError,
-    pubkey::Pubkey,
+    sysvars::clock::Clock,
 };
 
 // ============================================================================
@@ -34,46 +40,59 @@ use pinocchio::{
 ///
 /// # Security
 /// - Commitment is appended to Merkle tree using Poseidon hasher
-/// - Event contains encrypted output for wallet synchronization
+/// - Event contains the leaf index and new root for tree reconstruction
+/// - Event contains encrypted output for wallet synchronization; large outputs
+///   follow as `NewCommitmentChunkEvent`s so nothing is truncated
 /// - Returns the index of the appended commitment
 ///
 /// # Arguments
-/// * `program_id` - This program's ID
 /// * `commitment_tree` - Mutable reference to the commitment Merkle tree
 /// * `commitment` - The 32-byte commitment hash to append
 /// * `encrypted_output` - Encrypted note data for wallet sync
 /// * `global_config_account` - Global config PDA for CPI signing
 /// * `shielded_pool_program` - The shielded pool program account (required for self-CPI)
 /// * `global_config_bump` - Bump seed for global config PDA
+/// * `event_mode` - GlobalConfig event emission mode
 #[inline(never)]
 pub fn append_commitment<'a>(
-    program_id: &Pubkey,
     commitment_tree: &mut CommitmentMerkleTree,
     commitment: [u8; 32],
     encrypted_output: &[u8],
     global_config_account: &'a AccountInfo,
     shielded_pool_program: &'a AccountInfo,
     global_config_bump: u8,
+    event_mode: EventEmissionMode,
 ) -> Result<u64, ProgramError> {
     let index = commitment_tree.next_index;
     MerkleTree::append::<Poseidon>(commitment, commitment_tree)?;
+    let new_root = commitment_tree.root;
 
     // Emit commitment event (hybrid header + variable body encoding)
-    let event = NewCommitmentEvent::new(index, commitment, encrypted_output);
-    let event_data = event.to_event_bytes();
+    let event = NewCommitmentEvent::new(index, commitment, new_root, encrypted_output);
 
-    // Emit via CPI using global_config as signer
+    // Emit via CPI (global_config as signer) or directly, per event_mode
     let bump_bytes = [global_config_bump];
     let signer_seeds = gen_global_config_seeds(&bump_bytes);
-    let signer = CpiSigner::from(&signer_seeds);
-    crate::utils::emit_cpi_log(
-        program_id,
+    let signers = [CpiSigner::from(&signer_seeds)];
+    emit_event_data(
+        event_mode,
         global_config_account,
         shielded_pool_program,
-        &[signer],
-        event_data,
+        &signers,
+        event.to_event_bytes(),
     )?;
 
+    // Large encrypted outputs follow as ordered chunk events
+    for chunk in event.chunks() {
+        emit_event_data(
+            event_mode,
+            global_config_account,
+            shielded_pool_program,
+            &signers,
+            chunk.to_event_bytes(),
+        )?;
+    }
+
     Ok(index)
 }
 
@@ -81,6 +100,68 @@ pub fn append_commitment<'a>(
 // Receipt Computation and Emission
 // ============================================================================
 
+/// Compute, append and emit the transaction's receipt (E4).
+///
+/// The Receipt and event header are built in this frame rather than the
+/// handler's, which only passes references in.
+///
+/// # Security
+/// - Receipt hash is appended to the receipt tree before the event is emitted
+/// - The receipt tree borrow is released before the event CPI
+///
+/// # Arguments
+/// * `receipt_tree` - Receipt tree account
+/// * `clock` - Clock sysvar loaded by the handler (slot and epoch)
+/// * `commitment_root` - Commitment tree root after E3
+/// * `last_commitment_index` - Index of the last commitment appended in E3
+/// * `proof` - The transact proof data (commitments, nullifiers, public lines)
+/// * `transact_params` - The transaction parameters (fee breakdown for the header)
+/// * `transact_params_hash` - SHA256 of `transact_params`, validated in P8
+/// * `global_config_account` - Global config PDA for CPI signing
+/// * `shielded_pool_program` - The shielded pool program account (required for self-CPI)
+/// * `global_config_bump` - Bump seed for global config PDA
+/// * `event_mode` - GlobalConfig event emission mode
+#[inline(never)]
+#[allow(clippy::too_many_arguments)]
+pub fn append_receipt<'a>(
+    receipt_tree: &AccountLoader<'a, ReceiptMerkleTree>,
+    clock: &Clock,
+    commitment_root: [u8; 32],
+    last_commitment_index: u64,
+    proof: &TransactProofData,
+    transact_params: &TransactParams,
+    transact_params_hash: &[u8; 32],
+    global_config_account: &'a AccountInfo,
+    shielded_pool_program: &'a AccountInfo,
+    global_config_bump: u8,
+    event_mode: EventEmissionMode,
+) -> Result<(), ProgramError> {
+    let (receipt, receipt_hash) = compute_receipt_and_hash(
+        clock.slot,
+        clock.epoch,
+        commitment_root,
+        last_commitment_index,
+        proof,
+        *transact_params_hash,
+    )?;
+
+    let receipt_index = {
+        let mut receipt_tree_data = receipt_tree.load_mut()?;
+        let receipt_index = receipt_tree_data.next_index;
+        receipt_tree_data.append::<Sha256>(receipt_hash)?;
+        receipt_index
+    };
+
+    emit_receipt_event(
+        &NewReceiptEventHeader::new(receipt_index, receipt_hash, transact_params),
+        &receipt,
+        global_config_account,
+        shielded_pool_program,
+        global_config_bump,
+        event_mode,
+    )
+}
+
 /// Compute receipt and its hash from components. Separating this reduces stack pressure in main function.
 ///
 /// Returns both the Receipt struct and its hash. The Receipt is needed for event emission
@@ -119,32 +200,32 @@ pub fn compute_receipt_and_hash(
 /// Emit the receipt event. Separating this reduces stack pressure in main function.
 ///
 /// Emits the NewReceiptEvent containing:
-/// - receipt_index: position in the receipt merkle tree
-/// - receipt_hash: SHA256 of the Borsh-serialized Receipt
+/// - header: receipt index and hash, plus the relayer and per-slot fee breakdown
+///   (see [`NewReceiptEventHeader`])
 /// - receipt_data: the full Borsh-serialized Receipt for indexer verification
 ///
 /// # Security
 /// - Receipt data is fully serialized for indexer verification
-/// - Event emission uses CPI with global_config as signer
+/// - Event emission uses CPI with global_config as signer, or a direct log
+///   entry when `event_mode` is `LogData`
 #[inline(never)]
 pub fn emit_receipt_event<'a>(
-    program_id: &Pubkey,
-    receipt_index: u64,
+    header: &NewReceiptEventHeader,
     receipt: &Receipt,
-    receipt_hash: [u8; 32],
     global_config_account: &'a AccountInfo,
     shielded_pool_program: &'a AccountInfo,
     global_config_bump: u8,
+    event_mode: EventEmissionMode,
 ) -> Result<(), ProgramError> {
-    // Build receipt event bytes: discriminator + receipt_index + receipt_hash + receipt_data
-    let receipt_event_data = build_new_receipt_event_bytes(receipt_index, receipt_hash, receipt)?;
+    // Build receipt event bytes: frame + header + receipt_data
+    let receipt_event_data = build_new_receipt_event_bytes(header, receipt)?;
 
-    // Emit via CPI using global_config as signer
+    // Emit via CPI (global_config as signer) or directly, per event_mode
     let bump_bytes = [global_config_bump];
     let signer_seeds = gen_global_config_seeds(&bump_bytes);
     let signer = CpiSigner::from(&signer_seeds);
-    crate::utils::emit_cpi_log(
-        program_id,
+    emit_event_data(
+        event_mode,
         global_config_account,
         shielded_pool_program,
         &[signer],
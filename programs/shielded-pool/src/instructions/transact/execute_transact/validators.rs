@@ -8,12 +8,16 @@
 //! - Public amount validation ensures ZK proof binds to correct ext_amount
 //! - Accumulator validation ensures reward calculations use fresh on-chain state
 //! - Exchange rate validation (unified SOL) prevents rate manipulation attacks
+//! - Params hash validation binds the session's TransactParams to the proof
 
 use crate::{
     errors::ShieldedPoolError,
+    instructions::types::{TransactParams, TransactProofData},
     state::{LstConfig, TokenPoolConfig, UnifiedSolPoolConfig, find_token_pool_config_pda},
     utils::{self, check_public_amount_unified, validate_fee, validate_fee_unified},
 };
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
 use panchor::prelude::AccountLoader;
 use pinocchio::program_error::ProgramError;
 use pinocchio_contrib::AccountAssertions;
@@ -36,6 +40,7 @@ use super::pool_config::PoolConfig;
 ///
 /// # Security
 /// - Validates pool is active before accepting transactions
+/// - Rejects deposits/withdrawals whose direction is disabled in `LstConfig.mode`
 /// - Verifies exchange rate conversion matches ZK-bound public_amount
 /// - Validates fees are within pool's configured bounds
 #[inline(never)]
@@ -50,6 +55,7 @@ pub fn validate_unified_sol_public_amount(
     if !pool.is_active() {
         return Err(ShieldedPoolError::PoolPaused.into());
     }
+    pool.require_direction_enabled(ext_amount)?;
 
     let exchange_rate = pool.deposit_exchange_rate();
 
@@ -83,7 +89,7 @@ pub fn validate_unified_sol_public_amount(
 ///
 /// For token pools:
 /// - Public amount is 1:1 with external amount: p = ext_amount - fee
-/// - Fee is calculated on external amount
+/// - Fee is calculated on external amount, rounded as the pool rounds it
 ///
 /// # Security
 /// - Validates pool is active before accepting transactions
@@ -119,6 +125,7 @@ pub fn validate_token_public_amount(
         relayer_fee,
         pool.deposit_fee_rate(),
         pool.withdrawal_fee_rate(),
+        pool.rounds_fees_up(),
     )?;
 
     Ok(())
@@ -141,6 +148,37 @@ fn accumulator_to_bytes(accumulator: u128) -> [u8; 32] {
 // Accumulator Validators
 // ============================================================================
 
+/// Slots after a token pool finalization during which its previous
+/// accumulator is still accepted, when `GlobalConfig.allow_one_epoch_stale`
+/// is set.
+///
+/// Matches the ~150 slot lifetime of a recent blockhash: a transaction built
+/// before the finalization cannot land much later than this anyway.
+pub const MAX_STALE_ACCUMULATOR_SLOTS: u64 = 150;
+
+/// Returns true if `in_accumulator` is acceptable for `token_config`.
+///
+/// The current accumulator is always accepted. With `allow_one_epoch_stale`,
+/// the accumulator replaced by the latest finalization is also accepted for
+/// `MAX_STALE_ACCUMULATOR_SLOTS` after it, so a proof built just before a
+/// `FinalizeRewards` that lands first still verifies. Older values never are.
+pub fn token_accumulator_accepted(
+    token_config: &TokenPoolConfig,
+    in_accumulator: &[u8; 32],
+    allow_one_epoch_stale: bool,
+    current_slot: u64,
+) -> bool {
+    if accumulator_to_bytes(token_config.reward_accumulator) == *in_accumulator {
+        return true;
+    }
+
+    allow_one_epoch_stale
+        && token_config.accumulator_epoch > 0
+        && current_slot.saturating_sub(token_config.last_finalized_slot)
+            <= MAX_STALE_ACCUMULATOR_SLOTS
+        && accumulator_to_bytes(token_config.previous_reward_accumulator) == *in_accumulator
+}
+
 /// Validate token pool reward accumulator using RewardConfig (v2).
 /// Uses the new separated reward config structure.
 ///
@@ -149,12 +187,15 @@ fn accumulator_to_bytes(accumulator: u128) -> [u8; 32] {
 /// - Validates PDA derivation to prevent spoofed configs
 /// - Ensures asset_id matches expected value
 /// - Verifies pool is active
-/// - Confirms accumulator matches on-chain state (prevents stale reward attacks)
+/// - Confirms accumulator matches on-chain state (prevents stale reward attacks);
+///   see [`token_accumulator_accepted`] for the one-epoch-stale window
 #[inline(never)]
 pub fn validate_token_accumulator(
     reward_config: &RewardConfig,
     in_asset_id: &[u8; 32],
     in_accumulator: &[u8; 32],
+    allow_one_epoch_stale: bool,
+    current_slot: u64,
 ) -> Result<(), ProgramError> {
     let config_account = match reward_config {
         RewardConfig::Token(t) => t.token_pool_config,
@@ -182,7 +223,12 @@ pub fn validate_token_accumulator(
         }
 
         // Verify accumulator matches on-chain state
-        if accumulator_to_bytes(token_config.reward_accumulator) != *in_accumulator {
+        if !token_accumulator_accepted(
+            token_config,
+            in_accumulator,
+            allow_one_epoch_stale,
+            current_slot,
+        ) {
             return Err(ShieldedPoolError::InvalidAssetId.into());
         }
         Ok(())
@@ -231,3 +277,146 @@ pub fn validate_unified_sol_accumulator(
         Ok(())
     })
 }
+
+// ============================================================================
+// Transact Params Hash Validator
+// ============================================================================
+
+/// Validate that the session's TransactParams hash to the proof's public input.
+///
+/// Returns the SHA256 hash of the params, which is also recorded in the receipt.
+///
+/// # Security
+/// - R2: SHA256(params) mod Fr must equal `transactParamsHash`, binding every
+///   param (recipients, fees, expiry, relayer) to the ZK proof
+#[inline(never)]
+pub fn validate_transact_params_hash(
+    transact_params: &TransactParams,
+    proof: &TransactProofData,
+) -> Result<[u8; 32], ProgramError> {
+    let transact_params_hash = utils::calculate_transact_params_hash(transact_params);
+
+    if utils::transact_params_hash_to_fr(&transact_params_hash)
+        != Fr::from_be_bytes_mod_order(&proof.transact_params_hash)
+    {
+        return Err(ShieldedPoolError::TransactParamsHashMismatch.into());
+    }
+
+    Ok(transact_params_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytemuck::Zeroable;
+
+    const FINALIZE_SLOT: u64 = 1_000_000;
+
+    /// A pool that has just finalized rewards at `FINALIZE_SLOT`, moving its
+    /// accumulator past the one a proof built earlier would carry.
+    fn finalized_pool() -> (TokenPoolConfig, [u8; 32]) {
+        let mut config = TokenPoolConfig::zeroed();
+        config.finalized_balance = 1_000_000_000_000;
+        config.reward_accumulator = 1_000_000_000_000_000_000;
+        config.pending_deposit_fees = 1_000_000_000;
+        let before = accumulator_to_bytes(config.reward_accumulator);
+
+        config.finalize_rewards(FINALIZE_SLOT).unwrap();
+        assert_ne!(accumulator_to_bytes(config.reward_accumulator), before);
+        (config, before)
+    }
+
+    #[test]
+    fn test_current_accumulator_always_accepted() {
+        let (config, _) = finalized_pool();
+        let current = accumulator_to_bytes(config.reward_accumulator);
+
+        assert!(token_accumulator_accepted(
+            &config,
+            &current,
+            false,
+            FINALIZE_SLOT
+        ));
+        assert!(token_accumulator_accepted(
+            &config,
+            &current,
+            true,
+            u64::MAX
+        ));
+    }
+
+    #[test]
+    fn test_same_slot_finalize_race() {
+        let (config, stale) = finalized_pool();
+
+        // Proof built before a finalize that landed first in the same slot
+        assert!(!token_accumulator_accepted(
+            &config,
+            &stale,
+            false,
+            FINALIZE_SLOT
+        ));
+        assert!(token_accumulator_accepted(
+            &config,
+            &stale,
+            true,
+            FINALIZE_SLOT
+        ));
+    }
+
+    #[test]
+    fn test_stale_accumulator_window() {
+        let (config, stale) = finalized_pool();
+        let last = FINALIZE_SLOT + MAX_STALE_ACCUMULATOR_SLOTS;
+
+        assert!(token_accumulator_accepted(&config, &stale, true, last));
+        assert!(!token_accumulator_accepted(&config, &stale, true, last + 1));
+    }
+
+    #[test]
+    fn test_stale_accumulator_rejected_before_first_finalize() {
+        // previous_reward_accumulator is zero until the first finalization
+        let mut config = TokenPoolConfig::zeroed();
+        config.reward_accumulator = 1;
+
+        assert!(!token_accumulator_accepted(&config, &[0u8; 32], true, 0));
+    }
+
+    #[test]
+    fn test_older_accumulator_rejected() {
+        let (mut config, two_back) = finalized_pool();
+        config.pending_deposit_fees = 1_000_000_000;
+        let next_slot = FINALIZE_SLOT + TokenPoolConfig::UPDATE_SLOT_INTERVAL;
+        config.finalize_rewards(next_slot).unwrap();
+
+        let one_back = accumulator_to_bytes(config.previous_reward_accumulator);
+        assert!(token_accumulator_accepted(
+            &config, &one_back, true, next_slot
+        ));
+        assert!(!token_accumulator_accepted(
+            &config, &two_back, true, next_slot
+        ));
+    }
+
+    #[test]
+    fn test_client_reduced_hash_passes_params_hash_check() {
+        use crate::client::{reduce_to_fr_be, transact_params_hash};
+
+        let mut params = TransactParams::zeroed();
+        params.ext_amounts[0] = 1_000_000_000;
+        params.relayer = [7; 32];
+        let mut proof = TransactProofData::zeroed();
+        proof.transact_params_hash = reduce_to_fr_be(&transact_params_hash(&params));
+        assert_eq!(
+            validate_transact_params_hash(&params, &proof),
+            Ok(transact_params_hash(&params))
+        );
+
+        // The unreduced little-endian digest is the mismatch clients have hit
+        proof.transact_params_hash = transact_params_hash(&params);
+        assert_eq!(
+            validate_transact_params_hash(&params, &proof),
+            Err(ShieldedPoolError::TransactParamsHashMismatch.into())
+        );
+    }
+}
@@ -2,8 +2,9 @@
 
 use crate::{
     errors::ShieldedPoolError,
+    events::{TransactSessionCreatedEvent, log_event},
     pda::{find_transact_session_pda, gen_transact_session_seeds},
-    state::{TransactSession, MAX_SESSION_DATA_LEN},
+    state::{MAX_SESSION_DATA_LEN, ProofFormat, SESSION_EXPIRY_SLOTS, TransactSession},
 };
 use panchor::prelude::*;
 use pinocchio::{
@@ -23,8 +24,12 @@ pub struct InitTransactSessionData {
     pub nonce: u64,
     /// Total size of transaction data to be uploaded
     pub data_len: u32,
+    /// Proof point encoding (0 = compressed, 1 = uncompressed)
+    pub proof_format: u8,
     /// Padding for 8-byte alignment
-    pub _padding: [u8; 4],
+    pub _padding: [u8; 3],
+    /// SHA256 of the complete body, checked by execute_transact before parsing
+    pub body_hash: [u8; 32],
 }
 
 /// Accounts for InitTransactSession instruction.
@@ -47,7 +52,9 @@ pub struct InitTransactSessionAccounts<'info> {
 ///
 /// This instruction creates a PDA account that can store transaction data uploaded
 /// across multiple transactions. Once complete, the execute_transact instruction
-/// reads from this account.
+/// reads from this account, provided the uploaded body hashes to `body_hash`.
+///
+/// Logs a `TransactSessionCreatedEvent`.
 pub fn process_init_transact_session(
     ctx: Context<InitTransactSessionAccounts>,
     data: InitTransactSessionData,
@@ -62,6 +69,11 @@ pub fn process_init_transact_session(
     let nonce = data.nonce;
     let data_len = data.data_len;
 
+    let proof_format = ProofFormat::from_u8(data.proof_format).ok_or_else(|| {
+        log!("init_transact_session: invalid proof_format");
+        ShieldedPoolError::InvalidSessionState
+    })?;
+
     // M-1 audit fix: Validate data_len bounds early to fail fast
     // This check is also performed in execute_transact, but checking here
     // saves compute and lamports by rejecting invalid sessions at creation time
@@ -106,9 +118,22 @@ pub fn process_init_transact_session(
         data_len,
         bump,
         clock.slot,
+        proof_format,
+        data.body_hash,
     )?;
 
     log!("init_transact_session: session created successfully");
 
+    log_event(&TransactSessionCreatedEvent {
+        session: *transact_session.key(),
+        payer: *authority.key(),
+        nonce,
+        expiry_slot: clock.slot.saturating_add(SESSION_EXPIRY_SLOTS),
+        data_len,
+        proof_format: proof_format as u8,
+        _padding: [0u8; 3],
+        slot: clock.slot,
+    });
+
     Ok(())
 }
@@ -63,6 +63,8 @@ mod execute_transact;
 
 // Re-export all public items from instruction modules
 pub use close_transact_session::*;
+#[cfg(feature = "solana-sdk")]
+pub use execute_transact::ExecuteTransactInput;
 pub use execute_transact::{
     ExecuteTransactAccounts, ExecuteTransactData, process_execute_transact,
 };
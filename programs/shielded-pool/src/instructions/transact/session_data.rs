@@ -4,15 +4,35 @@
 //! into its component parts: proof, params, nullifier proof, and encrypted outputs.
 //!
 //! Parsing is zero-copy for large data structures (proof ~800 bytes, params ~700 bytes).
-//! Only the small session header (56 bytes) is copied.
+//! Only the small session header (96 bytes) is copied.
+//!
+//! # Body Layout
+//!
+//! ```text
+//! Compressed:   [TransactProofData][TransactParams][NullifierNMProof][encrypted_outputs]
+//! Uncompressed: [UncompressedProofPoints][TransactProofData][TransactParams][NullifierNMProof][encrypted_outputs]
+//! ```
+//!
+//! For uncompressed sessions the compressed point fields inside `TransactProofData`
+//! are unused and must be zero, so a body has exactly one proof encoding.
+//!
+//! # Integrity
+//!
+//! The body must hash to the `body_hash` the client declared at init before any
+//! of it is parsed, so a chunk corrupted in transit fails fast with
+//! `SessionDataCorrupted` rather than as a proof failure after the pairing check.
+//! The verified hash is also the escrow `proof_hash`, binding deposits to exactly
+//! the uploaded bytes.
 
 use crate::{
     errors::ShieldedPoolError,
+    groth16::{CompressedGroth16Proof, Groth16ProofPoints, UncompressedGroth16Proof},
     instructions::types::{
         N_OUTS, NULLIFIER_NM_PROOF_SIZE, NullifierNonMembershipProofData, PROOF_SIZE,
-        TRANSACT_PARAMS_SIZE, TransactParams, TransactProofData,
+        TRANSACT_PARAMS_SIZE, TransactParams, TransactProofData, UNCOMPRESSED_PROOF_POINTS_SIZE,
+        UncompressedProofPoints,
     },
-    state::{TRANSACT_SESSION_HEADER_SIZE, TransactSession},
+    state::{ProofFormat, TRANSACT_SESSION_HEADER_SIZE, TransactSession},
     utils,
 };
 use panchor::prelude::*; // For HasDiscriminator trait
@@ -23,10 +43,19 @@ use pinocchio::program_error::ProgramError;
 pub const MIN_SESSION_DATA_SIZE: usize =
     PROOF_SIZE + TRANSACT_PARAMS_SIZE + NULLIFIER_NM_PROOF_SIZE;
 
+/// Size of the body prefix preceding `TransactProofData` for the given format.
+#[inline]
+pub const fn proof_points_prefix_size(format: ProofFormat) -> usize {
+    match format {
+        ProofFormat::Compressed => 0,
+        ProofFormat::Uncompressed => UNCOMPRESSED_PROOF_POINTS_SIZE,
+    }
+}
+
 /// Parsed session data with zero-copy references to the underlying account data.
 ///
 /// This struct provides convenient access to all components stored in a transact session:
-/// - The session header (authority, nonce, created_slot, etc.) - copied, 56 bytes
+/// - The session header (authority, nonce, created_slot, etc.) - copied, 96 bytes
 /// - The ZK proof for the transaction - zero-copy reference
 /// - Transaction parameters (amounts, recipients, fees, etc.) - zero-copy reference
 /// - Nullifier non-membership proof - zero-copy reference
@@ -34,10 +63,12 @@ pub const MIN_SESSION_DATA_SIZE: usize =
 ///
 /// The caller must keep the account data borrow alive for the references to remain valid.
 pub struct SessionData<'a> {
-    /// Session header (authority, nonce, created_slot, etc.) - small copy (56 bytes)
+    /// Session header (authority, nonce, created_slot, etc.) - small copy (96 bytes)
     pub header: TransactSession,
     /// Groth16 proof and public inputs - zero-copy reference (~800 bytes)
     pub proof: &'a TransactProofData,
+    /// Uncompressed proof points, present only for `ProofFormat::Uncompressed` sessions
+    pub uncompressed_points: Option<&'a UncompressedProofPoints>,
     /// Transaction parameters bound by the proof - zero-copy reference (~700 bytes)
     pub params: &'a TransactParams,
     /// Nullifier non-membership proof for the indexed tree - zero-copy reference
@@ -47,6 +78,23 @@ pub struct SessionData<'a> {
 }
 
 impl<'a> SessionData<'a> {
+    /// Groth16 proof points in the encoding the session was uploaded with.
+    #[inline]
+    pub fn proof_points(&self) -> Groth16ProofPoints<'a> {
+        match self.uncompressed_points {
+            Some(points) => Groth16ProofPoints::Uncompressed(UncompressedGroth16Proof {
+                proof_a: &points.proof_a,
+                proof_b: &points.proof_b,
+                proof_c: &points.proof_c,
+            }),
+            None => Groth16ProofPoints::Compressed(CompressedGroth16Proof {
+                proof_a: &self.proof.proof_a,
+                proof_b: &self.proof.proof_b,
+                proof_c: &self.proof.proof_c,
+            }),
+        }
+    }
+
     /// Check if this transaction has a relayer (not system program).
     #[inline]
     pub fn has_relayer(&self) -> bool {
@@ -70,10 +118,13 @@ impl<'a> SessionData<'a> {
 ///
 /// This function performs mostly zero-copy parsing:
 /// 1. Validates discriminator
-/// 2. Copies the small header (56 bytes)
-/// 3. Returns zero-copy references to proof, params, nullifier proof (~1.5KB total)
-/// 4. Parses encrypted outputs as zero-copy slices
-/// 5. Validates encrypted output hashes match params
+/// 2. Copies the small header (96 bytes)
+/// 3. Verifies the body hashes to the header's `body_hash`
+/// 4. Resolves the proof format and, for uncompressed sessions, the points prefix
+/// 5. Validates every public input is a canonical field element
+/// 6. Returns zero-copy references to proof, params, nullifier proof (~1.5KB total)
+/// 7. Parses encrypted outputs as zero-copy slices
+/// 8. Validates encrypted output hashes match params
 ///
 /// # Arguments
 /// * `data` - Raw account data bytes (discriminator + header + body)
@@ -83,7 +134,11 @@ impl<'a> SessionData<'a> {
 ///
 /// # Errors
 /// * `InvalidDiscriminator` - Wrong account type
+/// * `SessionDataCorrupted` - Body does not match the declared `body_hash`
+/// * `InvalidSessionState` - Unknown proof format
 /// * `InvalidAccountData` - Data too small or malformed
+/// * `InvalidProof` - Uncompressed session with non-zero compressed point fields
+/// * `NonCanonicalFieldElement` - A public input is `>= r`
 /// * `InvalidEncryptedOutputHash` - Hash mismatch in encrypted outputs
 pub fn parse_session_data(data: &[u8]) -> Result<SessionData<'_>, ProgramError> {
     if data.len() < TRANSACT_SESSION_HEADER_SIZE {
@@ -96,20 +151,45 @@ pub fn parse_session_data(data: &[u8]) -> Result<SessionData<'_>, ProgramError>
         return Err(ShieldedPoolError::InvalidDiscriminator.into());
     }
 
-    // Copy header (56 bytes) - necessary for borrow checker, negligible cost
+    // Copy header (96 bytes) - necessary for borrow checker, negligible cost
     let header: TransactSession = *bytemuck::from_bytes(&data[8..TRANSACT_SESSION_HEADER_SIZE]);
 
+    // Reject corrupted uploads before interpreting any of the body
+    if utils::sha256(&data[TRANSACT_SESSION_HEADER_SIZE..]) != header.body_hash {
+        return Err(ShieldedPoolError::SessionDataCorrupted.into());
+    }
+
+    let proof_format =
+        ProofFormat::from_u8(header.proof_format).ok_or(ShieldedPoolError::InvalidSessionState)?;
+    let prefix_size = proof_points_prefix_size(proof_format);
+
     // Body starts after header
     let body = &data[TRANSACT_SESSION_HEADER_SIZE..];
 
-    // Verify minimum body size
-    if body.len() < MIN_SESSION_DATA_SIZE {
+    // Verify minimum body size (including the uncompressed points prefix, if any)
+    if body.len() < prefix_size + MIN_SESSION_DATA_SIZE {
         return Err(ProgramError::InvalidAccountData);
     }
 
+    let uncompressed_points: Option<&UncompressedProofPoints> = match proof_format {
+        ProofFormat::Compressed => None,
+        ProofFormat::Uncompressed => Some(bytemuck::from_bytes(&body[..prefix_size])),
+    };
+    let body = &body[prefix_size..];
+
     // Zero-copy parse proof (~800 bytes - no stack allocation)
     let proof: &TransactProofData = bytemuck::from_bytes(&body[..PROOF_SIZE]);
 
+    // Uncompressed sessions must not also carry compressed points
+    if uncompressed_points.is_some()
+        && (proof.proof_a != [0u8; 32] || proof.proof_b != [0u8; 64] || proof.proof_c != [0u8; 32])
+    {
+        return Err(ShieldedPoolError::InvalidProof.into());
+    }
+
+    // Reject non-canonical public inputs before any of them are used
+    utils::validate_public_inputs_canonical(proof)?;
+
     // Zero-copy parse params (~700 bytes)
     let params: &TransactParams =
         bytemuck::from_bytes(&body[PROOF_SIZE..PROOF_SIZE + TRANSACT_PARAMS_SIZE]);
@@ -131,6 +211,7 @@ pub fn parse_session_data(data: &[u8]) -> Result<SessionData<'_>, ProgramError>
     Ok(SessionData {
         header,
         proof,
+        uncompressed_points,
         params,
         nullifier_nm_proof,
         encrypted_outputs,
@@ -193,4 +274,66 @@ mod tests {
             PROOF_SIZE + TRANSACT_PARAMS_SIZE + NULLIFIER_NM_PROOF_SIZE
         );
     }
+
+    #[test]
+    fn test_proof_points_prefix_size() {
+        assert_eq!(proof_points_prefix_size(ProofFormat::Compressed), 0);
+        assert_eq!(
+            proof_points_prefix_size(ProofFormat::Uncompressed),
+            64 + 128 + 64
+        );
+    }
+
+    /// Offset of `TransactSession::body_hash` in account data.
+    const BODY_HASH_OFFSET: usize = TRANSACT_SESSION_HEADER_SIZE - 32;
+
+    /// Build a compressed-format session with the given proof and a zeroed remainder.
+    fn session_bytes(proof: &TransactProofData) -> std::vec::Vec<u8> {
+        let mut data = std::vec![0u8; TRANSACT_SESSION_HEADER_SIZE];
+        data[..8].copy_from_slice(&TransactSession::DISCRIMINATOR.to_le_bytes());
+        data.extend_from_slice(bytemuck::bytes_of(proof));
+        data.resize(TRANSACT_SESSION_HEADER_SIZE + MIN_SESSION_DATA_SIZE, 0);
+        let body_hash = utils::sha256(&data[TRANSACT_SESSION_HEADER_SIZE..]);
+        data[BODY_HASH_OFFSET..TRANSACT_SESSION_HEADER_SIZE].copy_from_slice(&body_hash);
+        data
+    }
+
+    #[test]
+    fn test_parse_rejects_corrupted_body() {
+        use bytemuck::Zeroable;
+
+        let mut data = session_bytes(&TransactProofData::zeroed());
+        assert_ne!(
+            parse_session_data(&data).err(),
+            Some(ShieldedPoolError::SessionDataCorrupted.into())
+        );
+
+        // A single flipped bit anywhere in the body is caught before parsing
+        data[TRANSACT_SESSION_HEADER_SIZE + PROOF_SIZE + 3] ^= 0x10;
+        assert_eq!(
+            parse_session_data(&data).err(),
+            Some(ShieldedPoolError::SessionDataCorrupted.into())
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_non_canonical_public_input() {
+        use bytemuck::Zeroable;
+
+        let mut proof = TransactProofData::zeroed();
+        proof.nullifiers[2] = utils::FR_MODULUS_BE;
+        let data = session_bytes(&proof);
+        assert_eq!(
+            parse_session_data(&data).err(),
+            Some(ShieldedPoolError::NonCanonicalFieldElement.into())
+        );
+
+        // Canonical inputs get past the check (and fail later on missing outputs)
+        let proof = TransactProofData::zeroed();
+        let data = session_bytes(&proof);
+        assert_ne!(
+            parse_session_data(&data).err(),
+            Some(ShieldedPoolError::NonCanonicalFieldElement.into())
+        );
+    }
 }
@@ -1,6 +1,7 @@
 //! Upload a chunk of transaction data to a tra
 use crate::{
     errors::ShieldedPoolError,
+    events::{TransactChunkUploadedEvent, log_event},
     state::{TRANSACT_SESSION_HEADER_SIZE, TransactSession},
 };
 use panchor::prelude::*;
@@ -28,6 +29,9 @@ pub struct UploadTransactChunkAccounts<'info> {
 ///
 /// * `[0..4]` - offset: u32 (little-endian) - Byte offset where this chunk should be written
 /// * `[4..]` - data: bytes - The chunk data to write
+///
+/// Adds the chunk length to the session's `bytes_received` and logs a
+/// `TransactChunkUploadedEvent`.
 pub fn process_upload_transact_chunk(
     ctx: Context<UploadTransactChunkAccounts>,
     data: &[u8],
@@ -76,13 +80,33 @@ pub fn process_upload_transact_chunk(
     // Write the chunk to data section (after header)
     // Note: Overlapping writes are intentionally allowed - the authority owns the session
     // and may overwrite their own data if needed (e.g., to correct upload errors).
-    let mut account_data = transact_session.try_borrow_mut_data()?;
-    let body = &mut account_data[TRANSACT_SESSION_HEADER_SIZE..];
-    let start = offset as usize;
-    let end = end_offset as usize;
-    body[start..end].copy_from_slice(chunk_data);
+    {
+        let mut account_data = transact_session.try_borrow_mut_data()?;
+        let body = &mut account_data[TRANSACT_SESSION_HEADER_SIZE..];
+        let start = offset as usize;
+        let end = end_offset as usize;
+        body[start..end].copy_from_slice(chunk_data);
+    }
+
+    // Track upload progress (bounded by the transaction size, so cannot overflow u32
+    // in practice; saturate rather than fail an otherwise valid upload)
+    let bytes_received_total = {
+        let mut header = TransactSession::header_mut(transact_session)?;
+        header.bytes_received = header
+            .bytes_received
+            .saturating_add(chunk_data.len() as u32);
+        header.bytes_received
+    };
 
     log!("upload_transact_chunk: chunk written successfully");
 
+    log_event(&TransactChunkUploadedEvent {
+        session: *transact_session.key(),
+        offset,
+        len: chunk_data.len() as u32,
+        bytes_received_total,
+        data_len: header_data_len,
+    });
+
     Ok(())
 }
@@ -26,6 +26,27 @@ pub const N_INS: usize = 4;
 /// Number of output notes in a transaction
 pub const N_OUTS: usize = 4;
 
+/// Size of the UncompressedProofPoints struct in bytes (for zero-copy access)
+pub const UNCOMPRESSED_PROOF_POINTS_SIZE: usize = core::mem::size_of::<UncompressedProofPoints>();
+
+/// Uncompressed Groth16 proof points.
+///
+/// Prefixed to the session body when the session was created with
+/// `ProofFormat::Uncompressed`. Points are validated on-curve and in the correct
+/// subgroup before verification.
+///
+/// All fields are big-endian.
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, IdlType)]
+pub struct UncompressedProofPoints {
+    /// Groth16 proof element A (G1 point, `x || y`)
+    pub proof_a: [u8; 64],
+    /// Groth16 proof element B (G2 point, `x.c1 || x.c0 || y.c1 || y.c0`)
+    pub proof_b: [u8; 128],
+    /// Groth16 proof element C (G1 point, `x || y`)
+    pub proof_c: [u8; 64],
+}
+
 /// Size of the Proof struct in bytes (for zero-copy access)
 pub const PROOF_SIZE: usize = core::mem::size_of::<TransactProofData>();
 
@@ -1,17 +1,24 @@
 //! Utility instructions.
 //!
-//! This module contains helper instructions for logging, hashing, and testing.
+//! This module contains helper instructions for logging, hashing, testing, and
+//! read-only queries that return account state via return data.
 
-use alloc::vec::Vec;
 use bytemuck::{Pod, Zeroable};
+use panchor::events::LogFraming;
 use panchor::prelude::*;
-use pinocchio::{ProgramResult, account_info::AccountInfo, program_error::ProgramError};
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, program::set_return_data, program_error::ProgramError,
+};
 use pinocchio_log::log;
-use solana_poseidon::{Endianness, Parameters, PoseidonHash as SolanaPoseidonHash, hashv};
 
 use crate::errors::ShieldedPoolError;
 use crate::groth16::{CompressedGroth16Proof, verify_groth16};
-use crate::verifying_keys::{N_PUBLIC_INPUTS_BATCH_4, NULLIFIER_BATCH_VK_4};
+use crate::poseidon::{MAX_POSEIDON_INPUTS, Poseidon, PoseidonBackend};
+use crate::state::{
+    CommitmentMerkleTree, GlobalConfig, GlobalConfigHeader, NullifierIndexedTree,
+    ReceiptMerkleTree,
+};
+use crate::verifying_keys::{CircuitId, N_PUBLIC_INPUTS_BATCH_4, vk_for};
 
 // ============================================================================
 // Data Structs
@@ -21,8 +28,58 @@ use crate::verifying_keys::{N_PUBLIC_INPUTS_BATCH_4, NULLIFIER_BATCH_VK_4};
 #[repr(C)]
 #[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
 pub struct PoseidonHashData {
-    /// 32-byte input to hash
+    /// 32-byte big-endian field element, repeated `num_inputs` times
     pub input: [u8; 32],
+    /// Number of inputs to hash (1..=12)
+    pub num_inputs: u8,
+    /// Padding for alignment
+    pub _padding: [u8; 7],
+}
+
+/// Return data for PoseidonHash instruction.
+///
+/// `cu_before - cu_after` is the compute cost of one hash with `backend`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Pod, Zeroable)]
+pub struct PoseidonBenchmarkReturnData {
+    /// Resulting hash (big-endian)
+    pub hash: [u8; 32],
+    /// Remaining compute units before hashing
+    pub cu_before: u64,
+    /// Remaining compute units after hashing
+    pub cu_after: u64,
+    /// Backend ID (`PoseidonBackend::ID`)
+    pub backend: u8,
+    /// Number of inputs hashed
+    pub num_inputs: u8,
+    /// Padding for alignment
+    pub _padding: [u8; 6],
+}
+
+/// Return data for GetPoolState instruction.
+///
+/// Everything a client needs from the pool accounts to build a transact proof.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Pod, Zeroable, IdlType)]
+pub struct PoolStateView {
+    /// Current commitment tree root
+    pub commitment_root: [u8; 32],
+    /// Index the next commitment will be appended at
+    pub commitment_next_index: u64,
+    /// Index the next receipt will be appended at
+    pub receipt_next_index: u64,
+    /// Current nullifier indexed tree root
+    pub nullifier_root: [u8; 32],
+    /// Current nullifier epoch
+    pub nullifier_epoch: u64,
+    /// Oldest nullifier epoch a proof may reference
+    pub earliest_provable_epoch: u64,
+    /// Whether the pool is paused (0 = active, 1 = paused)
+    pub is_paused: u8,
+    /// Hub-wide withdrawal-only mode (0 = off, 1 = deposits disabled)
+    pub withdrawals_only: u8,
+    /// Padding for alignment
+    pub _padding: [u8; 6],
 }
 
 /// Size of the TestGroth16Data struct in bytes
@@ -91,139 +148,92 @@ pub struct LogAccounts<'info> {
     pub authority: &'info AccountInfo,
 }
 
+/// Accounts for GetProtocolStats instruction.
+#[derive(Accounts)]
+pub struct GetProtocolStatsAccounts<'info> {
+    /// Global config PDA ["global_config"] (must be migrated to the current version)
+    pub global_config: AccountLoader<'info, GlobalConfig>,
+}
+
+/// Accounts for GetPoolState instruction.
+#[derive(Accounts)]
+pub struct GetPoolStateAccounts<'info> {
+    /// Commitment merkle tree PDA
+    pub commitment_tree: AccountLoader<'info, CommitmentMerkleTree>,
+
+    /// Receipt merkle tree PDA
+    pub receipt_tree: AccountLoader<'info, ReceiptMerkleTree>,
+
+    /// Nullifier indexed merkle tree PDA
+    pub nullifier_tree: AccountLoader<'info, NullifierIndexedTree>,
+
+    /// Global config PDA ["global_config"] (any layout version)
+    pub global_config: &'info AccountInfo,
+}
+
 // ============================================================================
 // Handlers
 // ============================================================================
 
-/// Compute a Poseidon hash (utility instruction).
-///
-/// This is a utility instruction for computing Poseidon hashes on-chain.
-/// Useful for testing and verifying hash computations client-side.
+/// Benchmark the selected Poseidon backend (utility instruction).
 ///
-/// Uses BN254 curve parameters with big-endian encoding.
-/// Tests 1, 2, and 3 input variants using both solana_poseidon and light_hasher.
+/// Hashes `num_inputs` copies of `input` with [`crate::poseidon::Poseidon`] and
+/// reports the remaining compute units immediately before and after the hash
+/// via return data ([`PoseidonBenchmarkReturnData`]). The difference is the
+/// cost of a single hash with the compiled-in backend.
 pub fn process_poseidon_hash(
     _ctx: Context<PoseidonHashAccounts>,
     data: PoseidonHashData,
 ) -> ProgramResult {
-    use light_hasher::{Hasher, Poseidon as LightPoseidon};
-
-    log!("Poseidon hash instruction called");
-
-    // Test using solana_poseidon (official crate)
-    let zero = [0u8; 32];
-
-    // Test 1-input solana_poseidon
-    let result1: SolanaPoseidonHash =
-        hashv(Parameters::Bn254X5, Endianness::BigEndian, &[&data.input]).map_err(|_e| {
-            log!("solana_poseidon 1-input failed");
-            ProgramError::InvalidArgument
-        })?;
-    log!("solana_poseidon 1-input: OK");
-
-    // Test 2-input solana_poseidon
-    let _result2: SolanaPoseidonHash = hashv(
-        Parameters::Bn254X5,
-        Endianness::BigEndian,
-        &[&data.input, &zero],
-    )
-    .map_err(|_e| {
-        log!("solana_poseidon 2-input failed");
-        ProgramError::InvalidArgument
-    })?;
-    log!("solana_poseidon 2-input: OK");
-
-    // Test 3-input solana_poseidon
-    let _result3: SolanaPoseidonHash = hashv(
-        Parameters::Bn254X5,
-        Endianness::BigEndian,
-        &[&data.input, &zero, &zero],
-    )
-    .map_err(|_e| {
-        log!("solana_poseidon 3-input failed");
-        ProgramError::InvalidArgument
-    })?;
-    log!("solana_poseidon 3-input: OK");
-
-    // Now test using light_hasher (used by indexed merkle tree)
-    // Test 1-input light_hasher
-    let _lh1 = LightPoseidon::hashv(&[&data.input]).map_err(|_e| {
-        log!("light_hasher 1-input failed");
-        ProgramError::InvalidArgument
-    })?;
-    log!("light_hasher 1-input: OK");
-
-    // Test 2-input light_hasher
-    let _lh2 = LightPoseidon::hashv(&[&data.input, &zero]).map_err(|_e| {
-        log!("light_hasher 2-input failed");
-        ProgramError::InvalidArgument
-    })?;
-    log!("light_hasher 2-input: OK");
-
-    // Test 3-input light_hasher
-    let _lh3 = LightPoseidon::hashv(&[&data.input, &zero, &zero]).map_err(|_e| {
-        log!("light_hasher 3-input failed");
-        ProgramError::InvalidArgument
-    })?;
-    log!("light_hasher 3-input: OK");
-
-    // Test the exact code path from IndexedMerkleTree::compute_leaf_hash
-    // This mimics what happens during initialize
-    use crate::state::IndexedLeaf;
-    let genesis_leaf = IndexedLeaf::genesis();
-
-    // Convert next_index to 32 bytes (little-endian padded) - exact copy from indexed_merkle_tree.rs
-    let mut next_index_bytes = [0u8; 32];
-    next_index_bytes[..8].copy_from_slice(&genesis_leaf.next_index.to_le_bytes());
-
-    log!("Testing genesis leaf hash computation...");
-    log!(
-        "value[0..4]: {} {} {} {}",
-        genesis_leaf.value[0],
-        genesis_leaf.value[1],
-        genesis_leaf.value[2],
-        genesis_leaf.value[3]
-    );
-    log!("next_index: {}", genesis_leaf.next_index);
-    log!(
-        "next_value[0..4]: {} {} {} {}",
-        genesis_leaf.next_value[0],
-        genesis_leaf.next_value[1],
-        genesis_leaf.next_value[2],
-        genesis_leaf.next_value[3]
-    );
+    let num_inputs = data.num_inputs as usize;
+    if num_inputs == 0 || num_inputs > MAX_POSEIDON_INPUTS {
+        log!(
+            "PoseidonHash: num_inputs must be 1..={}",
+            MAX_POSEIDON_INPUTS
+        );
+        return Err(ProgramError::InvalidInstructionData);
+    }
 
-    // 3-input Poseidon: (value, next_index, next_value) - exact copy from indexed_merkle_tree.rs
-    let genesis_hash = LightPoseidon::hashv(&[
-        &genesis_leaf.value,
-        &next_index_bytes,
-        &genesis_leaf.next_value,
-    ])
-    .map_err(|_e| {
-        log!("genesis leaf hash FAILED");
-        ProgramError::InvalidArgument
-    })?;
-    log!("genesis leaf hash: OK");
-    log!(
-        "hash[0..4]: {} {} {} {}",
-        genesis_hash[0],
-        genesis_hash[1],
-        genesis_hash[2],
-        genesis_hash[3]
-    );
+    let inputs: [&[u8]; MAX_POSEIDON_INPUTS] = [&data.input; MAX_POSEIDON_INPUTS];
+
+    let cu_before = remaining_compute_units();
+    let hash = Poseidon::hashv(&inputs[..num_inputs])?;
+    let cu_after = remaining_compute_units();
 
-    let output = result1.to_bytes();
     log!(
-        "Poseidon output[0..4]: {} {} {} {}",
-        output[0],
-        output[1],
-        output[2],
-        output[3]
+        "PoseidonHash: backend={} inputs={} cu={}",
+        Poseidon::ID,
+        num_inputs,
+        cu_before.saturating_sub(cu_after)
     );
 
+    let return_data = PoseidonBenchmarkReturnData {
+        hash,
+        cu_before,
+        cu_after,
+        backend: Poseidon::ID,
+        num_inputs: data.num_inputs,
+        _padding: [0u8; 6],
+    };
+    set_return_data(bytemuck::bytes_of(&return_data));
+
     Ok(())
 }
 
+/// Remaining compute units in the current transaction (0 off-chain).
+#[inline(always)]
+fn remaining_compute_units() -> u64 {
+    #[cfg(target_os = "solana")]
+    {
+        // SAFETY: syscall takes no arguments and only reads the meter.
+        unsafe { pinocchio::syscalls::sol_remaining_compute_units() }
+    }
+    #[cfg(not(target_os = "solana"))]
+    {
+        0
+    }
+}
+
 /// Test Groth16 proof verification (utility instruction).
 ///
 /// This instruction verifies a Groth16 proof against the nullifier batch insertion
@@ -251,7 +261,7 @@ pub fn process_test_groth16(
     let result = verify_groth16::<N_PUBLIC_INPUTS_BATCH_4>(
         &compressed,
         &data.public_inputs,
-        &NULLIFIER_BATCH_VK_4,
+        vk_for(CircuitId::NullifierBatch4),
     );
 
     match result {
@@ -272,30 +282,59 @@ pub fn process_test_groth16(
 
 /// Log event data via CPI self-invocation.
 ///
-/// This instruction calls sol_log_data with the provided data.
-/// Access is restricted to accounts owned by this program.
+/// The data is a Borsh `Vec<u8>` (see [`crate::events::build_log_instruction_data`]).
+/// Access is restricted to PDAs owned by this program that signed the CPI.
 pub fn process_log(ctx: Context<LogAccounts>, data: &[u8]) -> ProgramResult {
-    use borsh::BorshDeserialize;
-
-    #[derive(BorshDeserialize)]
-    struct LogData {
-        data: Vec<u8>,
-    }
-
-    let args = LogData::try_from_slice(data).map_err(|_| ProgramError::InvalidInstructionData)?;
-
-    let LogAccounts { authority } = ctx.accounts;
+    panchor::events::process_log(
+        ctx.accounts.authority,
+        &crate::ID,
+        data,
+        LogFraming::BorshVec,
+    )
+}
 
-    // Authority must be a signer (PDA signed via invoke_signed)
-    if !authority.is_signer() {
-        return Err(ProgramError::MissingRequiredSignature);
-    }
+/// Return the protocol statistics (read-only utility instruction).
+///
+/// Writes [`crate::state::ProtocolStats`] from GlobalConfig as return data, so
+/// clients can read the counters by simulating this instruction.
+pub fn process_get_protocol_stats(ctx: Context<GetProtocolStatsAccounts>) -> ProgramResult {
+    let stats = ctx.accounts.global_config.map(|config| config.stats)?;
+    set_return_data(bytemuck::bytes_of(&stats));
+    Ok(())
+}
 
-    // Authority must be owned by this program
-    if authority.owner() != &crate::ID {
-        return Err(ProgramError::IllegalOwner);
-    }
+/// Return the current tree roots and indices (read-only utility instruction).
+///
+/// Packs the commitment, receipt and nullifier tree state with the pool flags
+/// into a [`PoolStateView`] and writes it as return data, so clients can fetch
+/// it with a single simulation instead of parsing each account.
+pub fn process_get_pool_state(ctx: Context<GetPoolStateAccounts>) -> ProgramResult {
+    let GetPoolStateAccounts {
+        commitment_tree,
+        receipt_tree,
+        nullifier_tree,
+        global_config,
+    } = ctx.accounts;
+
+    let header = GlobalConfigHeader::load(global_config)?;
+    let (commitment_root, commitment_next_index) =
+        commitment_tree.map(|tree| (tree.root, tree.next_index))?;
+    let receipt_next_index = receipt_tree.map(|tree| tree.next_index)?;
+    let (nullifier_root, nullifier_epoch, earliest_provable_epoch) = nullifier_tree
+        .map(|tree| (tree.root, tree.current_epoch, tree.earliest_provable_epoch))?;
+
+    let view = PoolStateView {
+        commitment_root,
+        commitment_next_index,
+        receipt_next_index,
+        nullifier_root,
+        nullifier_epoch,
+        earliest_provable_epoch,
+        is_paused: header.is_paused,
+        withdrawals_only: header.withdrawals_only,
+        _padding: [0u8; 6],
+    };
+    set_return_data(bytemuck::bytes_of(&view));
 
-    pinocchio::log::sol_log_data(&[&args.data]);
     Ok(())
 }
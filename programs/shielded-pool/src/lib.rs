@@ -3,6 +3,9 @@
 extern crate alloc;
 
 pub mod account_loaders;
+#[cfg(any(test, feature = "solana-sdk", feature = "idl-build"))]
+pub mod client;
+pub mod cu_trace;
 pub mod errors;
 pub mod events;
 pub mod groth16;
@@ -11,6 +14,10 @@ pub mod instructions;
 pub mod merkle_tree;
 pub mod pda;
 pub mod pool_cpi;
+pub mod pool_registry;
+pub mod poseidon;
+#[cfg(any(test, feature = "solana-sdk"))]
+pub mod proofs;
 pub mod state;
 pub mod token;
 pub mod utils;
@@ -1,6 +1,5 @@
-use crate::{errors::ShieldedPoolError, state::CommitmentMerkleTree};
+use crate::{errors::ShieldedPoolError, poseidon::PoseidonBackend, state::CommitmentMerkleTree};
 use alloc::vec;
-use light_hasher::Hasher;
 use pinocchio::program_error::ProgramError;
 use pinocchio_log::log;
 
@@ -18,7 +17,7 @@ impl MerkleTree {
     /// - `next_index = 0` (first insertion goes to index 0)
     /// - `root` = zero hash at tree height
     /// - `root_history[0]` = initial root
-    pub fn initialize<H: Hasher>(
+    pub fn initialize<H: PoseidonBackend>(
         merkle_tree_account: &mut CommitmentMerkleTree,
     ) -> Result<(), ProgramError> {
         let height = merkle_tree_account.height as usize;
@@ -35,7 +34,7 @@ impl MerkleTree {
         Ok(())
     }
 
-    pub fn append<H: Hasher>(
+    pub fn append<H: PoseidonBackend>(
         leaf: [u8; 32],
         merkle_tree_account: &mut CommitmentMerkleTree,
     ) -> Result<alloc::vec::Vec<[u8; 32]>, ProgramError> {
@@ -90,16 +89,45 @@ impl MerkleTree {
         Ok(proof)
     }
 
+    /// Returns true if `siblings` proves `leaf` at `leaf_index` against a root
+    /// still in the history buffer.
+    pub fn is_known_path<H: PoseidonBackend>(
+        merkle_tree_account: &CommitmentMerkleTree,
+        leaf: [u8; 32],
+        leaf_index: u64,
+        siblings: &[[u8; 32]],
+    ) -> bool {
+        if siblings.len() != merkle_tree_account.height as usize {
+            return false;
+        }
+        path_root(leaf, leaf_index, siblings, |left, right| {
+            H::hashv(&[left.as_slice(), right.as_slice()])
+        })
+        .is_ok_and(|root| Self::is_known_root(merkle_tree_account, root))
+    }
+
     pub fn is_known_root(merkle_tree_account: &CommitmentMerkleTree, root: [u8; 32]) -> bool {
+        let root_history_size = merkle_tree_account.root_history_size as u64;
+        Self::is_recent_root(merkle_tree_account, root, root_history_size)
+    }
+
+    /// Returns true if `root` is one of the `max_age` most recent roots
+    /// (the current root has age 1). `max_age` is clamped to the history size.
+    pub fn is_recent_root(
+        merkle_tree_account: &CommitmentMerkleTree,
+        root: [u8; 32],
+        max_age: u64,
+    ) -> bool {
         if root == [0u8; 32] {
             return false;
         }
 
         let root_history_size = merkle_tree_account.root_history_size as usize;
         let current_root_index = merkle_tree_account.root_index as usize;
+        let max_age = (max_age as usize).min(root_history_size);
 
         // Search backwards through the circular root history buffer
-        for offset in 0..root_history_size {
+        for offset in 0..max_age {
             let i = (current_root_index + root_history_size - offset) % root_history_size;
             if root == merkle_tree_account.root_history[i] {
                 return true;
@@ -110,11 +138,40 @@ impl MerkleTree {
     }
 }
 
+/// Fold a merkle path from `leaf` up to the root it implies.
+///
+/// `siblings[i]` is the sibling at level `i` (leaf level first); bit `i` of
+/// `leaf_index` selects whether the running hash is the left or right input.
+/// Shared by [`MerkleTree::is_known_path`] and the off-chain verifier in
+/// `crate::proofs`, so both sides agree on the path layout.
+pub fn path_root(
+    leaf: [u8; 32],
+    leaf_index: u64,
+    siblings: &[[u8; 32]],
+    hash: impl Fn(&[u8; 32], &[u8; 32]) -> Result<[u8; 32], ProgramError>,
+) -> Result<[u8; 32], ProgramError> {
+    let mut current = leaf;
+    let mut index = leaf_index;
+    for sibling in siblings {
+        current = if index.is_multiple_of(2) {
+            hash(&current, sibling)?
+        } else {
+            hash(sibling, &current)?
+        };
+        index /= 2;
+    }
+    if index != 0 {
+        // leaf_index does not fit in a tree of this height
+        return Err(ProgramError::InvalidArgument);
+    }
+    Ok(current)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::poseidon::Poseidon;
     use crate::state::{COMMITMENT_TREE_HEIGHT, ROOT_HISTORY_SIZE};
-    use light_hasher::Poseidon;
     use std::{format, println, string::String};
 
     fn create_test_tree() -> CommitmentMerkleTree {
@@ -179,6 +236,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_recent_root_respects_max_age() {
+        let mut tree = create_test_tree();
+        MerkleTree::initialize::<Poseidon>(&mut tree).unwrap();
+        let initial_root = tree.root;
+
+        MerkleTree::append::<Poseidon>([1u8; 32], &mut tree).unwrap();
+        MerkleTree::append::<Poseidon>([2u8; 32], &mut tree).unwrap();
+
+        // Initial root is now the third most recent
+        assert!(MerkleTree::is_known_root(&tree, initial_root));
+        assert!(MerkleTree::is_recent_root(&tree, initial_root, 3));
+        assert!(!MerkleTree::is_recent_root(&tree, initial_root, 2));
+        assert!(MerkleTree::is_recent_root(&tree, tree.root, 1));
+    }
+
     #[test]
     fn test_zero_root_is_not_known() {
         let mut tree = create_test_tree();
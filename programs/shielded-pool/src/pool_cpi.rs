@@ -2,35 +2,99 @@
 //!
 //! # Architecture
 //!
-//! Pool CPI functions are pure CPI calls. They invoke the pool program and return
-//! minimal data (expected_output or protocol_fee). All token distribution (recipient,
-//! relayer) is handled by the orchestration layer in public_slots.rs.
+//! Pool CPIs are pure calls through [`PoolCpi`](zorb_pool_interface::PoolCpi),
+//! which builds each pool's account list; the pools return minimal data (their
+//! `PoolOpResult` / `UnifiedSolOpResult`, or routed outputs), read back here.
+//! All token distribution (recipient, relayer) is handled by the orchestration
+//! layer in public_slots.rs, which cross-checks pool results against its own
+//! fee math via [`require_pool_op_result`] and [`require_unified_sol_op_result`].
 //!
 //! ```text
 //! DEPOSIT:
-//! Hub: CPI → Pool { amount, expected_output }
+//! Hub: CPI → Pool { amount, expected_output, depositor, expect_fee_exempt }
 //! Pool: Transfer depositor→vault (amount)
-//! Pool: Validate expected_output, update state, return { fee }
+//! Pool: Validate expected_output, update state, return { gross, fee, net, .. }
 //!
 //! WITHDRAW:
-//! Hub: CPI → Pool { amount, expected_output }
+//! Hub: CPI → Pool { amount, expected_output, recipient, expect_fee_exempt }
 //! Pool: Approve hub_authority for expected_output (total tokens to distribute)
-//! Pool: Update state, return { fee }
+//! Pool: Update state, return { gross, fee, net, .. }
+//!
+//! WITHDRAW (unified SOL, WSOL, unwrap):
+//! Hub: CPI → Pool { .., recipient (system account), unwrap, unwrap_amount }
+//! Pool: vault→temporary WSOL account (unwrap_amount), CloseAccount, credit recipient
+//! Pool: Approve hub_authority for the rest (relayer fee)
+//!
+//! WITHDRAW (unified SOL, routed):
+//! Hub: CPI → Pool { .., route_automatically } + (lst_config, vault) per routed vault
+//!      (or { .., fallback_count } + (lst_config, vault) per fallback LST)
+//! Pool: Approve hub_authority on each vault drawn from
+//! Pool: Update state, return { fee, outputs }
 //! ```
+//!
+//! Token pools return `PoolOpResult`; the unified SOL pool returns `UnifiedSolOpResult`
+//! (`RoutedWithdrawReturnData` for routed withdrawals).
+//!
+//! `expect_fee_exempt` tells the pool which fee the hub priced in; the pool
+//! rejects the CPI if its own fee-exempt list disagrees.
 
+use panchor::AccountFlags;
 use pinocchio::{
-    ProgramResult,
-    account_info::AccountInfo,
-    instruction::{AccountMeta, Instruction, Signer as PinocchioSigner},
+    ProgramResult, account_info::AccountInfo, instruction::Signer as PinocchioSigner,
     program_error::ProgramError,
 };
 use zorb_pool_interface::{
-    DepositParams, PoolReturnData, TOKEN_POOL_PROGRAM_ID, UNIFIED_SOL_POOL_PROGRAM_ID,
-    WithdrawParams, build_deposit_instruction_data, build_withdraw_instruction_data,
+    CpiAccess, PoolOpResult, PoolType, RoutedWithdrawReturnData, TOKEN_POOL_DEPOSIT_ACCESS,
+    TOKEN_POOL_PROGRAM_ID, TOKEN_POOL_WITHDRAW_ACCESS, UNIFIED_SOL_POOL_DEPOSIT_ACCESS,
+    UNIFIED_SOL_POOL_PROGRAM_ID, UNIFIED_SOL_POOL_WITHDRAW_ACCESS, UnifiedSolOpResult,
+    read_pool_op_result, read_unified_sol_op_result,
 };
 
+use crate::errors::ShieldedPoolError;
 use crate::pda::{HUB_AUTHORITY_BUMP, gen_hub_authority_seeds};
 
+// ============================================================================
+// Pool Account Checks
+// ============================================================================
+
+/// Whether a pool accounts struct's derived flags match the access the
+/// interface builds its CPI with.
+const fn access_matches<const N: usize>(
+    flags: &[AccountFlags; N],
+    access: &[CpiAccess; N],
+) -> bool {
+    let mut i = 0;
+    while i < N {
+        if flags[i].writable != access[i].is_writable() || flags[i].signer != access[i].is_signer()
+        {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+// A pool adding, removing or re-flagging a Deposit/Withdraw account fails to
+// compile here (array length or assertion) rather than at CPI time.
+const _: () = {
+    assert!(access_matches(
+        &token_pool::instructions::DepositAccounts::ACCOUNT_FLAGS,
+        &TOKEN_POOL_DEPOSIT_ACCESS,
+    ));
+    assert!(access_matches(
+        &token_pool::instructions::WithdrawAccounts::ACCOUNT_FLAGS,
+        &TOKEN_POOL_WITHDRAW_ACCESS,
+    ));
+    assert!(access_matches(
+        &unified_sol_pool::instructions::DepositAccounts::ACCOUNT_FLAGS,
+        &UNIFIED_SOL_POOL_DEPOSIT_ACCESS,
+    ));
+    assert!(access_matches(
+        &unified_sol_pool::instructions::WithdrawAccounts::ACCOUNT_FLAGS,
+        &UNIFIED_SOL_POOL_WITHDRAW_ACCESS,
+    ));
+};
+
 // ============================================================================
 // Hub-Side Transfer Helper
 // ============================================================================
@@ -70,273 +134,186 @@ pub fn execute_signed_vault_transfer<'a>(
 // CPI Return Data Helpers
 // ============================================================================
 
-/// Read pool return data after CPI.
+/// Pass through a failed pool CPI's error, logging the pool's error name
+/// under the `trace` feature.
 ///
-/// Pool sets return data with PoolReturnData { fee }.
-fn read_pool_return_data() -> Result<PoolReturnData, ProgramError> {
-    let return_data =
-        pinocchio::program::get_return_data().ok_or(ProgramError::InvalidAccountData)?;
-
-    PoolReturnData::from_bytes(return_data.as_slice()).ok_or(ProgramError::InvalidAccountData)
+/// Pools number their errors independently, so a bare `Custom(n)` doesn't say
+/// which error failed the CPI; see
+/// [`decode_pool_error`](zorb_pool_interface::decode_pool_error).
+pub fn trace_pool_error(pool: PoolType, error: ProgramError) -> ProgramError {
+    #[cfg(feature = "trace")]
+    {
+        use zorb_pool_interface::{PoolCpi, decode_pool_error};
+
+        if let ProgramError::Custom(code) = error
+            && let Some(kind) = decode_pool_error(pool.program_id(), code)
+        {
+            pinocchio_log::log!("pool CPI failed: {}", kind.name());
+        }
+    }
+    #[cfg(not(feature = "trace"))]
+    let _ = pool;
+    error
 }
 
-// ============================================================================
-// Token Pool CPI
-// ============================================================================
-
-/// Invoke token-pool withdraw via CPI (pure CPI call).
-///
-/// Pool validates amounts, approves hub_authority for output tokens,
-/// updates state, and returns protocol fee.
-///
-/// Returns `expected_output` for the orchestration layer to distribute.
-///
-/// # Account Layout (matches token-pool WithdrawAccounts struct)
-/// 0. `[writable]` Pool config account (PDA signer)
-/// 1. `[writable]` Vault token account
-/// 2. `[]` Hub authority PDA (delegate for vault transfers)
-/// 3. `[]` Token pool program (for self-CPI events)
-/// 4. `[]` SPL Token program (for Approve CPI)
-pub fn execute_token_withdrawal_cpi<'a>(
-    pool_config: &'a AccountInfo,
-    vault: &'a AccountInfo,
-    hub_authority: &'a AccountInfo,
-    pool_program: &'a AccountInfo,
-    token_program: &'a AccountInfo,
-    amount: u64,
-    expected_output: u64,
-) -> Result<u64, ProgramError> {
-    let params = WithdrawParams {
-        amount,
-        expected_output,
-    };
-
-    let instruction_data = build_withdraw_instruction_data(&params);
-
-    let account_metas = [
-        AccountMeta::writable(pool_config.key()),
-        AccountMeta::writable(vault.key()),
-        AccountMeta::readonly(hub_authority.key()),
-        AccountMeta::readonly(pool_program.key()),
-        AccountMeta::readonly(token_program.key()),
-    ];
-
-    let instruction = Instruction {
-        program_id: &TOKEN_POOL_PROGRAM_ID,
-        accounts: &account_metas,
-        data: &instruction_data,
-    };
-
-    // Note: pool_program and token_program must be included for Solana runtime
-    pinocchio::program::invoke(
-        &instruction,
-        &[pool_config, vault, hub_authority, pool_program, token_program],
-    )?;
-
-    // Read return data (validates pool acknowledged the CPI)
-    let _return_data = read_pool_return_data()?;
-
-    Ok(expected_output)
+/// Read the unified SOL pool's `UnifiedSolOpResult` after CPI.
+pub fn read_unified_sol_pool_op_result() -> Result<UnifiedSolOpResult, ProgramError> {
+    read_unified_sol_op_result(&UNIFIED_SOL_POOL_PROGRAM_ID).ok_or(ProgramError::InvalidAccountData)
 }
 
-// ============================================================================
-// Unified SOL Pool CPI
-// ============================================================================
-
-/// Invoke unified-sol-pool withdraw via CPI (pure CPI call).
-///
-/// Pool validates amounts, approves hub_authority for output tokens,
-/// updates state, and returns protocol fee.
-///
-/// Returns `expected_output` for the orchestration layer to distribute.
-///
-/// # Account Layout (matches unified-sol-pool WithdrawAccounts struct)
-/// 0. `[writable]` Unified SOL config account
-/// 1. `[writable]` LST config account (PDA signer)
-/// 2. `[writable]` Vault token account
-/// 3. `[]` Hub authority PDA (delegate for vault transfers)
-/// 4. `[]` Pool program (UNIFIED_SOL_POOL_PROGRAM_ID - for self-CPI events)
-/// 5. `[]` SPL Token program (for Approve CPI)
-pub fn execute_unified_sol_withdrawal_cpi<'a>(
-    unified_config: &'a AccountInfo,
-    lst_config: &'a AccountInfo,
-    vault: &'a AccountInfo,
-    hub_authority: &'a AccountInfo,
-    pool_program: &'a AccountInfo,
-    token_program: &'a AccountInfo,
-    amount: u64,
-    expected_output: u64,
-) -> Result<u64, ProgramError> {
-    let params = WithdrawParams {
-        amount,
-        expected_output,
-    };
-
-    let instruction_data = build_withdraw_instruction_data(&params);
-
-    let account_metas = [
-        AccountMeta::writable(unified_config.key()),
-        AccountMeta::writable(lst_config.key()),
-        AccountMeta::writable(vault.key()),
-        AccountMeta::readonly(hub_authority.key()),
-        AccountMeta::readonly(pool_program.key()),
-        AccountMeta::readonly(token_program.key()),
-    ];
-
-    let instruction = Instruction {
-        program_id: &UNIFIED_SOL_POOL_PROGRAM_ID,
-        accounts: &account_metas,
-        data: &instruction_data,
-    };
-
-    // Note: pool_program and token_program must be included for Solana runtime
-    pinocchio::program::invoke(
-        &instruction,
-        &[unified_config, lst_config, vault, hub_authority, pool_program, token_program],
-    )?;
-
-    // Read return data (validates pool acknowledged the CPI)
-    let _return_data = read_pool_return_data()?;
-
-    Ok(expected_output)
+/// Read the token pool's `PoolOpResult` after CPI.
+pub fn read_token_pool_op_result() -> Result<PoolOpResult, ProgramError> {
+    read_pool_op_result(&TOKEN_POOL_PROGRAM_ID).ok_or(ProgramError::InvalidAccountData)
 }
 
-// ============================================================================
-// Escrow Deposit CPI Functions
-// ============================================================================
+/// Read the unified SOL pool's `RoutedWithdrawReturnData` after a routed
+/// withdrawal CPI: the tokens approved on the named vault, then on each route
+/// vault.
+pub fn read_routed_withdraw_return_data() -> Result<RoutedWithdrawReturnData, ProgramError> {
+    let return_data =
+        pinocchio::program::get_return_data().ok_or(ProgramError::InvalidAccountData)?;
+    RoutedWithdrawReturnData::from_bytes(return_data.as_slice())
+        .ok_or(ProgramError::InvalidAccountData)
+}
 
-/// Execute a token pool deposit from escrow vault (pure CPI call).
+/// Check a pool's `PoolOpResult` against the hub's own amounts.
 ///
-/// CPI to pool with escrow_vault_authority as signed depositor.
-/// Pool executes escrow_vault→vault transfer and updates accounting.
-///
-/// Returns `protocol_fee`.
-#[allow(clippy::too_many_arguments)]
-pub fn execute_token_deposit_from_escrow_cpi<'a>(
-    pool_config: &'a AccountInfo,
-    vault: &'a AccountInfo,
-    escrow_vault: &'a AccountInfo,
-    escrow_vault_authority: &'a AccountInfo,
-    token_program: &'a AccountInfo,
-    escrow: &'a AccountInfo,
-    pool_program: &'a AccountInfo,
-    vault_authority_bump: u8,
-    amount: u64,
-    expected_output: u64,
-) -> Result<u64, ProgramError> {
-    use crate::pda::gen_escrow_vault_authority_seeds;
-
-    let params = DepositParams {
-        amount,
-        expected_output,
-    };
-
-    // Build signer seeds for escrow_vault_authority PDA
-    let bump_slice = [vault_authority_bump];
-    let seeds = gen_escrow_vault_authority_seeds(escrow.key(), &bump_slice);
-    let signer = [PinocchioSigner::from(&seeds)];
-
-    // CPI to pool with escrow_vault_authority as the signed depositor
-    let instruction_data = build_deposit_instruction_data(&params);
-
-    let account_metas = [
-        AccountMeta::writable(pool_config.key()),
-        AccountMeta::writable(vault.key()),
-        AccountMeta::writable(escrow_vault.key()),
-        AccountMeta::readonly_signer(escrow_vault_authority.key()),
-        AccountMeta::readonly(token_program.key()),
-        AccountMeta::readonly(pool_program.key()), // token_pool_program for self-CPI events
-    ];
-
-    let instruction = Instruction {
-        program_id: &TOKEN_POOL_PROGRAM_ID,
-        accounts: &account_metas,
-        data: &instruction_data,
-    };
-
-    // Note: pool_program and token_program must be included for Solana runtime to find the program executables
-    pinocchio::program::invoke_signed(
-        &instruction,
-        &[pool_config, vault, escrow_vault, escrow_vault_authority, token_program, pool_program],
-        &signer,
-    )?;
-
-    // Read return data from pool
-    let return_data = read_pool_return_data()?;
-
-    Ok(return_data.fee)
+/// Fails with `PoolResultMismatch` if the pool computed a different gross,
+/// fee, or net than the hub priced into the transaction, or reports a
+/// different `accumulator_epoch` than the one the hub read from the
+/// `TokenPoolConfig`.
+pub fn require_pool_op_result(
+    result: &PoolOpResult,
+    gross: u64,
+    fee: u64,
+    net: u64,
+    accumulator_epoch: u64,
+) -> Result<(), ProgramError> {
+    if !result.matches(gross, fee, net) || result.accumulator_epoch != accumulator_epoch {
+        return Err(ShieldedPoolError::PoolResultMismatch.into());
+    }
+    Ok(())
 }
 
-/// Execute a unified SOL pool deposit from escrow vault (pure CPI call).
-///
-/// CPI to pool with escrow_vault_authority as signed depositor.
-/// Pool executes escrow_vault→vault transfer and updates accounting.
+/// Check a unified SOL pool's `UnifiedSolOpResult` against the hub's own pricing.
 ///
-/// Returns `protocol_fee`.
-///
-/// # Account Layout (matches unified-sol-pool DepositAccounts struct)
-/// 0. `[writable]` Unified SOL config account
-/// 1. `[writable]` LST config account
-/// 2. `[writable]` Vault token account
-/// 3. `[writable]` Escrow vault (depositor's token source)
-/// 4. `[signer]` Escrow vault authority (signed via PDA)
-/// 5. `[]` Pool program (UNIFIED_SOL_POOL_PROGRAM_ID - for self-CPI events)
-/// 6. `[]` SPL Token program (required for Transfer CPI within pool)
-#[allow(clippy::too_many_arguments)]
-pub fn execute_unified_sol_deposit_from_escrow_cpi<'a>(
-    unified_config: &'a AccountInfo,
-    lst_config: &'a AccountInfo,
-    vault: &'a AccountInfo,
-    escrow_vault: &'a AccountInfo,
-    escrow_vault_authority: &'a AccountInfo,
-    escrow: &'a AccountInfo,
-    pool_program: &'a AccountInfo,
-    token_program: &'a AccountInfo,
-    vault_authority_bump: u8,
-    amount: u64,
-    expected_output: u64,
-) -> Result<u64, ProgramError> {
-    use crate::pda::gen_escrow_vault_authority_seeds;
-
-    let params = DepositParams {
-        amount,
-        expected_output,
-    };
-
-    // Build signer seeds for escrow_vault_authority PDA
-    let bump_slice = [vault_authority_bump];
-    let seeds = gen_escrow_vault_authority_seeds(escrow.key(), &bump_slice);
-    let signer = [PinocchioSigner::from(&seeds)];
-
-    // CPI to pool with escrow_vault_authority as the signed depositor
-    let instruction_data = build_deposit_instruction_data(&params);
-
-    let account_metas = [
-        AccountMeta::writable(unified_config.key()),
-        AccountMeta::writable(lst_config.key()),
-        AccountMeta::writable(vault.key()),
-        AccountMeta::writable(escrow_vault.key()),
-        AccountMeta::readonly_signer(escrow_vault_authority.key()),
-        AccountMeta::readonly(pool_program.key()),
-        AccountMeta::readonly(token_program.key()),
-    ];
+/// Fails with `PoolResultMismatch` if the pool moved a different token
+/// amount, valued it differently, charged a different fee, or priced it at a
+/// different exchange rate than the one the hub read from the LstConfig.
+pub fn require_unified_sol_op_result(
+    result: &UnifiedSolOpResult,
+    lst_amount: u64,
+    sol_value: u64,
+    fee: u64,
+    exchange_rate: u64,
+) -> Result<(), ProgramError> {
+    if !result.matches(lst_amount, sol_value, fee, exchange_rate) {
+        return Err(ShieldedPoolError::PoolResultMismatch.into());
+    }
+    Ok(())
+}
 
-    let instruction = Instruction {
-        program_id: &UNIFIED_SOL_POOL_PROGRAM_ID,
-        accounts: &account_metas,
-        data: &instruction_data,
-    };
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_require_pool_op_result() {
+        let result = PoolOpResult {
+            gross: 1_000_000,
+            fee: 10_000,
+            net: 990_000,
+            accumulator_epoch: 7,
+            new_accounted_total: 5_000_000,
+        };
+        assert!(require_pool_op_result(&result, 1_000_000, 10_000, 990_000, 7).is_ok());
+    }
 
-    // Note: pool_program and token_program must be included for Solana runtime to find the program executables
-    // token_program is needed because the pool's Deposit handler does Transfer::invoke() to SPL Token
-    pinocchio::program::invoke_signed(
-        &instruction,
-        &[unified_config, lst_config, vault, escrow_vault, escrow_vault_authority, pool_program, token_program],
-        &signer,
-    )?;
+    #[test]
+    fn test_require_pool_op_result_mismatch() {
+        let result = PoolOpResult {
+            gross: 1_000_000,
+            fee: 10_000,
+            net: 990_000,
+            accumulator_epoch: 7,
+            ..Default::default()
+        };
+        let mismatch = Err(ShieldedPoolError::PoolResultMismatch.into());
+
+        // Pool charged a different fee (e.g. its rate changed under the hub)
+        assert_eq!(
+            require_pool_op_result(&result, 1_000_000, 5_000, 995_000, 7),
+            mismatch
+        );
+        // Pool reports a different gross or net
+        assert_eq!(
+            require_pool_op_result(&result, 999_999, 10_000, 990_000, 7),
+            mismatch
+        );
+        assert_eq!(
+            require_pool_op_result(&result, 1_000_000, 10_000, 989_999, 7),
+            mismatch
+        );
+        // Pool reports a different epoch (e.g. a slot instead of the counter)
+        assert_eq!(
+            require_pool_op_result(&result, 1_000_000, 10_000, 990_000, 8),
+            mismatch
+        );
+    }
 
-    // Read return data from pool
-    let return_data = read_pool_return_data()?;
+    #[test]
+    fn test_require_unified_sol_op_result() {
+        let result = UnifiedSolOpResult {
+            lst_amount: 1_000_000,
+            sol_value: 1_050_000,
+            fee: 10_500,
+            exchange_rate: 1_050_000_000,
+            accumulator_epoch: 7,
+        };
+        assert!(
+            require_unified_sol_op_result(&result, 1_000_000, 1_050_000, 10_500, 1_050_000_000)
+                .is_ok()
+        );
+    }
 
-    Ok(return_data.fee)
+    #[test]
+    fn test_require_unified_sol_op_result_mismatch() {
+        let result = UnifiedSolOpResult {
+            lst_amount: 1_000_000,
+            sol_value: 1_050_000,
+            fee: 10_500,
+            exchange_rate: 1_050_000_000,
+            accumulator_epoch: 7,
+        };
+        let mismatch = Err(ShieldedPoolError::PoolResultMismatch.into());
+
+        // Injected drift: the pool priced at a rate other than the one the hub read
+        let drifted = UnifiedSolOpResult {
+            sol_value: 1_051_000,
+            exchange_rate: 1_051_000_000,
+            ..result
+        };
+        assert_eq!(
+            require_unified_sol_op_result(&drifted, 1_000_000, 1_050_000, 10_500, 1_050_000_000),
+            mismatch
+        );
+        // A rate mismatch alone is rejected
+        assert_eq!(
+            require_unified_sol_op_result(&result, 1_000_000, 1_050_000, 10_500, 1_049_999_999),
+            mismatch
+        );
+        // Pool reports a different token amount, value, or fee
+        assert_eq!(
+            require_unified_sol_op_result(&result, 999_999, 1_050_000, 10_500, 1_050_000_000),
+            mismatch
+        );
+        assert_eq!(
+            require_unified_sol_op_result(&result, 1_000_000, 1_049_999, 10_500, 1_050_000_000),
+            mismatch
+        );
+        assert_eq!(
+            require_unified_sol_op_result(&result, 1_000_000, 1_050_000, 10_499, 1_050_000_000),
+            mismatch
+        );
+    }
 }
@@ -0,0 +1,109 @@
+//! Registry of the pool plugins the hub routes to.
+//!
+//! Every hub `PoolConfig` names its plugin by `pool_kind`. Slot loading and
+//! validation look the kind up here to pick the pool's account layout,
+//! validation and CPI strategy instead of matching on a closed enum, so a new
+//! plugin is one more [`POOL_REGISTRY`] entry. Kinds missing from the table
+//! fail with `UnknownPoolKind`.
+
+use pinocchio::pubkey::Pubkey;
+use zorb_pool_interface::{PoolCpi, PoolKind, PoolType};
+
+use crate::errors::ShieldedPoolError;
+
+/// How the hub routes to one kind of pool.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PoolKindEntry {
+    /// Registry key (`PoolConfig.pool_kind`)
+    pub kind: PoolKind,
+    /// CPI strategy: builds the pool's account lists and invokes it
+    pub pool: PoolType,
+}
+
+impl PoolKindEntry {
+    /// The pool program invoked (and owner of its config accounts).
+    #[inline]
+    pub fn program_id(&self) -> &'static Pubkey {
+        self.pool.program_id()
+    }
+
+    /// Accounts a public slot carries for this pool, before unwrap or route
+    /// accounts.
+    #[inline]
+    pub fn slot_account_count(&self) -> usize {
+        self.pool.expected_account_count()
+    }
+}
+
+/// Pool kinds the hub can route to.
+pub const POOL_REGISTRY: [PoolKindEntry; 2] = [
+    PoolKindEntry {
+        kind: PoolKind::TOKEN,
+        pool: PoolType::Token,
+    },
+    PoolKindEntry {
+        kind: PoolKind::UNIFIED_SOL,
+        pool: PoolType::UnifiedSol,
+    },
+];
+
+/// Look up the registry entry for `kind`.
+///
+/// Fails with `UnknownPoolKind` if no entry is registered for it.
+#[inline]
+pub fn pool_kind_entry(kind: PoolKind) -> Result<&'static PoolKindEntry, ShieldedPoolError> {
+    POOL_REGISTRY
+        .iter()
+        .find(|entry| entry.kind == kind)
+        .ok_or(ShieldedPoolError::UnknownPoolKind)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zorb_pool_interface::{TOKEN_POOL_PROGRAM_ID, UNIFIED_SOL_POOL_PROGRAM_ID};
+
+    #[test]
+    fn test_token_kind_routing() {
+        let entry = pool_kind_entry(PoolKind::TOKEN).unwrap();
+        assert_eq!(entry.pool, PoolType::Token);
+        assert_eq!(entry.program_id(), &TOKEN_POOL_PROGRAM_ID);
+        assert_eq!(entry.slot_account_count(), 9);
+    }
+
+    #[test]
+    fn test_unified_sol_kind_routing() {
+        let entry = pool_kind_entry(PoolKind::UNIFIED_SOL).unwrap();
+        assert_eq!(entry.pool, PoolType::UnifiedSol);
+        assert_eq!(entry.program_id(), &UNIFIED_SOL_POOL_PROGRAM_ID);
+        assert_eq!(entry.slot_account_count(), 10);
+    }
+
+    #[test]
+    fn test_unknown_kind_rejected() {
+        for kind in [
+            PoolKind::UNSET,
+            PoolKind(3),
+            PoolKind(0x0100),
+            PoolKind(0x8000),
+        ] {
+            assert_eq!(
+                pool_kind_entry(kind),
+                Err(ShieldedPoolError::UnknownPoolKind)
+            );
+        }
+    }
+
+    #[test]
+    fn test_registry_kinds_unique_and_core() {
+        for (i, entry) in POOL_REGISTRY.iter().enumerate() {
+            assert!(entry.kind.is_core());
+            assert_eq!(PoolKind::from_pool_type(entry.pool), entry.kind);
+            assert!(
+                POOL_REGISTRY[..i]
+                    .iter()
+                    .all(|other| other.kind != entry.kind)
+            );
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Poseidon backend selection.
+//!
+//! The commitment tree and the indexed nullifier tree hash with circom-compatible
+//! Poseidon over BN254 (x^5 S-box, big-endian inputs and outputs). Two
+//! implementations are available and selected at compile time:
+//!
+//! | Feature            | Backend            | Implementation               |
+//! |--------------------|--------------------|------------------------------|
+//! | `poseidon-light`   | [`LightPoseidon`]  | `light_hasher::Poseidon`     |
+//! | `poseidon-syscall` | [`SyscallPoseidon`]| `solana_poseidon::hashv`     |
+//!
+//! `poseidon-light` is used when neither feature is enabled. Enabling both is a
+//! compile error.
+//!
+//! On-chain both backends end in the `sol_poseidon` syscall; they differ in the
+//! wrapper work done around it. Use the `PoseidonHash` benchmark instruction to
+//! measure the selected backend. Both backends produce identical hashes (see the
+//! golden-vector tests below), so switching does not change any tree root.
+//!
+//! The receipt tree is a SHA-256 tree and does not go through this module.
+
+use light_hasher::{Hasher, zero_bytes::ZeroBytes};
+use pinocchio::program_error::ProgramError;
+use solana_poseidon::{Endianness, Parameters, hashv};
+
+#[cfg(all(feature = "poseidon-light", feature = "poseidon-syscall"))]
+compile_error!("features `poseidon-light` and `poseidon-syscall` are mutually exclusive");
+
+/// Maximum number of inputs supported by the BN254 x^5 Poseidon parameters.
+pub const MAX_POSEIDON_INPUTS: usize = 12;
+
+/// A Poseidon hash implementation usable by the merkle trees.
+pub trait PoseidonBackend {
+    /// Backend identifier reported by the benchmark instruction.
+    const ID: u8;
+
+    /// Hash up to [`MAX_POSEIDON_INPUTS`] 32-byte big-endian field elements.
+    fn hashv(inputs: &[&[u8]]) -> Result<[u8; 32], ProgramError>;
+
+    /// Zero-subtree hashes for a standard (non-indexed) merkle tree.
+    fn zero_bytes() -> ZeroBytes {
+        light_hasher::zero_bytes::poseidon::ZERO_BYTES
+    }
+}
+
+/// Poseidon via `light_hasher`.
+pub struct LightPoseidon;
+
+impl PoseidonBackend for LightPoseidon {
+    const ID: u8 = 0;
+
+    #[inline]
+    fn hashv(inputs: &[&[u8]]) -> Result<[u8; 32], ProgramError> {
+        light_hasher::Poseidon::hashv(inputs).map_err(|_| ProgramError::InvalidArgument)
+    }
+}
+
+/// Poseidon via `solana_poseidon`.
+pub struct SyscallPoseidon;
+
+impl PoseidonBackend for SyscallPoseidon {
+    const ID: u8 = 1;
+
+    #[inline]
+    fn hashv(inputs: &[&[u8]]) -> Result<[u8; 32], ProgramError> {
+        hashv(Parameters::Bn254X5, Endianness::BigEndian, inputs)
+            .map(|hash| hash.to_bytes())
+            .map_err(|_| ProgramError::InvalidArgument)
+    }
+}
+
+/// The backend selected by the `poseidon-*` features.
+#[cfg(feature = "poseidon-syscall")]
+pub type Poseidon = SyscallPoseidon;
+
+/// The backend selected by the `poseidon-*` features.
+#[cfg(not(feature = "poseidon-syscall"))]
+pub type Poseidon = LightPoseidon;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(value: u8) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes[31] = value;
+        bytes
+    }
+
+    fn hex32(s: &str) -> [u8; 32] {
+        hex::decode(s).unwrap().try_into().unwrap()
+    }
+
+    /// Circom Poseidon reference outputs for inputs `[1]`, `[1, 2]` and `[1, 2, 3]`.
+    const GOLDEN: [&str; 3] = [
+        "29176100eaa962bdc1fe6c654d6a3c130e96a4d1168b33848b897dc502820133",
+        "115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a",
+        "0e7732d89e6939c0ff03d5e58dab6302f3230e269dc5b968f725df34ab36d732",
+    ];
+
+    #[test]
+    fn test_backends_match_golden_vectors() {
+        let inputs = [field(1), field(2), field(3)];
+        for (n, expected) in GOLDEN.iter().enumerate() {
+            let refs: std::vec::Vec<&[u8]> = inputs[..=n].iter().map(|i| i.as_slice()).collect();
+            let expected = hex32(expected);
+            assert_eq!(
+                LightPoseidon::hashv(&refs).unwrap(),
+                expected,
+                "light, {} inputs",
+                n + 1
+            );
+            assert_eq!(
+                SyscallPoseidon::hashv(&refs).unwrap(),
+                expected,
+                "syscall, {} inputs",
+                n + 1
+            );
+        }
+    }
+
+    #[test]
+    fn test_backends_match_on_zero_inputs() {
+        let zero = [0u8; 32];
+        for n in 1..=3 {
+            let refs = [&zero[..]; 3];
+            assert_eq!(
+                LightPoseidon::hashv(&refs[..n]).unwrap(),
+                SyscallPoseidon::hashv(&refs[..n]).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_zero_bytes_level_one_is_hash_of_zeros() {
+        let zero_bytes = Poseidon::zero_bytes();
+        let expected = Poseidon::hashv(&[&zero_bytes[0], &zero_bytes[0]]).unwrap();
+        assert_eq!(zero_bytes[1], expected);
+    }
+
+    #[test]
+    fn test_backends_reject_non_canonical_input() {
+        let over_modulus = [0xffu8; 32];
+        assert!(LightPoseidon::hashv(&[&over_modulus]).is_err());
+        assert!(SyscallPoseidon::hashv(&[&over_modulus]).is_err());
+    }
+}
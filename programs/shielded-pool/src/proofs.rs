@@ -0,0 +1,485 @@
+//! Off-chain merkle proof construction.
+//!
+//! Clients need merkle paths for their commitments (to spend them) and for
+//! receipts (to prove transaction history). [`OffchainMerkleTree`] rebuilds
+//! either tree from the event stream using the same hashers, heights and path
+//! layout as the on-chain append, so a root computed here can be compared
+//! byte-for-byte with the account or the `new_root` carried by each
+//! [`NewCommitmentHeader`].
+//!
+//! [`verify_path`] folds a path with [`crate::merkle_tree::path_root`], the
+//! same routine behind [`MerkleTree::is_known_path`](crate::merkle_tree::MerkleTree::is_known_path).
+
+use crate::events::{NewCommitmentHeader, NewReceiptEventHeader};
+use crate::merkle_tree::path_root;
+use crate::poseidon::{LightPoseidon, Poseidon, PoseidonBackend, SyscallPoseidon};
+use crate::state::{COMMITMENT_TREE_HEIGHT, RECEIPT_TREE_HEIGHT};
+use alloc::vec::Vec;
+use light_hasher::{Hasher, Sha256, zero_bytes::ZeroBytes};
+use pinocchio::program_error::ProgramError;
+
+/// Node hash and zero-subtree table for one of the program's merkle trees.
+pub trait TreeHasher {
+    /// Hash two child nodes into their parent.
+    fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> Result<[u8; 32], ProgramError>;
+
+    /// Zero-subtree hashes, indexed by level.
+    fn zero_bytes() -> ZeroBytes;
+}
+
+impl TreeHasher for LightPoseidon {
+    fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> Result<[u8; 32], ProgramError> {
+        <Self as PoseidonBackend>::hashv(&[left.as_slice(), right.as_slice()])
+    }
+
+    fn zero_bytes() -> ZeroBytes {
+        <Self as PoseidonBackend>::zero_bytes()
+    }
+}
+
+impl TreeHasher for SyscallPoseidon {
+    fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> Result<[u8; 32], ProgramError> {
+        <Self as PoseidonBackend>::hashv(&[left.as_slice(), right.as_slice()])
+    }
+
+    fn zero_bytes() -> ZeroBytes {
+        <Self as PoseidonBackend>::zero_bytes()
+    }
+}
+
+impl TreeHasher for Sha256 {
+    fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> Result<[u8; 32], ProgramError> {
+        <Self as Hasher>::hashv(&[left.as_slice(), right.as_slice()])
+            .map_err(|_| ProgramError::InvalidArgument)
+    }
+
+    fn zero_bytes() -> ZeroBytes {
+        <Self as Hasher>::zero_bytes()
+    }
+}
+
+/// Off-chain mirror of the commitment tree (Poseidon, selected backend).
+pub type OffchainCommitmentTree = OffchainMerkleTree<Poseidon>;
+
+/// Off-chain mirror of the receipt tree (SHA-256).
+pub type OffchainReceiptTree = OffchainMerkleTree<Sha256>;
+
+/// Error returned while rebuilding a tree or building a path.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProofError {
+    /// Event leaf index is not the next index of the tree
+    LeafIndexMismatch {
+        /// Index the tree expected next
+        expected: u64,
+        /// Index carried by the event
+        actual: u64,
+    },
+    /// Root after appending differs from the root carried by the event
+    RootMismatch {
+        /// Leaf index whose append diverged
+        leaf_index: u64,
+    },
+    /// Tree holds `2^height` leaves already
+    TreeFull,
+    /// Requested leaf has not been appended
+    LeafNotFound(u64),
+    /// Node hash failed
+    HashFailed,
+}
+
+/// Sibling path for one leaf.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerklePath {
+    /// Index of the proven leaf
+    pub leaf_index: u64,
+    /// Sibling hashes from the leaf level up (`height` entries)
+    pub siblings: Vec<[u8; 32]>,
+}
+
+/// Incremental append-only merkle tree rebuilt from program events.
+///
+/// Keeps every non-zero node, so paths can be produced for any leaf without
+/// replaying the stream. Memory is about `2 * leaves * 32` bytes.
+pub struct OffchainMerkleTree<H: TreeHasher> {
+    height: usize,
+    /// `levels[i]` holds the level-`i` nodes covering the appended leaves;
+    /// `levels[height]` holds the root once a leaf exists.
+    levels: Vec<Vec<[u8; 32]>>,
+    zero_bytes: ZeroBytes,
+    _hasher: core::marker::PhantomData<H>,
+}
+
+impl OffchainCommitmentTree {
+    /// Empty tree at [`COMMITMENT_TREE_HEIGHT`].
+    pub fn commitment_tree() -> Self {
+        Self::new(COMMITMENT_TREE_HEIGHT)
+    }
+
+    /// Append the commitment carried by a [`NewCommitmentHeader`].
+    ///
+    /// # Errors
+    /// [`ProofError::LeafIndexMismatch`] if events were skipped or replayed,
+    /// [`ProofError::RootMismatch`] if the rebuilt root differs from the
+    /// event's `new_root`.
+    pub fn append_commitment_event(
+        &mut self,
+        header: &NewCommitmentHeader,
+    ) -> Result<(), ProofError> {
+        self.check_next_index(header.leaf_index)?;
+        let root = self.append(header.commitment)?;
+        if root != header.new_root {
+            return Err(ProofError::RootMismatch {
+                leaf_index: header.leaf_index,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl OffchainReceiptTree {
+    /// Empty tree at [`RECEIPT_TREE_HEIGHT`].
+    pub fn receipt_tree() -> Self {
+        Self::new(RECEIPT_TREE_HEIGHT)
+    }
+
+    /// Append the receipt hash carried by a [`NewReceiptEventHeader`].
+    ///
+    /// Receipt events carry no root; compare [`Self::root`] with the
+    /// `ReceiptMerkleTree` account to cross-check.
+    ///
+    /// # Errors
+    /// [`ProofError::LeafIndexMismatch`] if events were skipped or replayed.
+    pub fn append_receipt_event(
+        &mut self,
+        header: &NewReceiptEventHeader,
+    ) -> Result<(), ProofError> {
+        self.check_next_index(header.receipt_index)?;
+        self.append(header.receipt_hash)?;
+        Ok(())
+    }
+}
+
+impl<H: TreeHasher> OffchainMerkleTree<H> {
+    /// Empty tree of the given height.
+    pub fn new(height: u8) -> Self {
+        let height = height as usize;
+        Self {
+            height,
+            levels: (0..=height).map(|_| Vec::new()).collect(),
+            zero_bytes: H::zero_bytes(),
+            _hasher: core::marker::PhantomData,
+        }
+    }
+
+    /// Tree height.
+    pub fn height(&self) -> u8 {
+        self.height as u8
+    }
+
+    /// Number of appended leaves (the on-chain `next_index`).
+    pub fn len(&self) -> u64 {
+        self.levels[0].len() as u64
+    }
+
+    /// Returns true if no leaf has been appended.
+    pub fn is_empty(&self) -> bool {
+        self.levels[0].is_empty()
+    }
+
+    /// Current root (the zero root at `height` when empty).
+    pub fn root(&self) -> [u8; 32] {
+        self.levels[self.height]
+            .first()
+            .copied()
+            .unwrap_or(self.zero_bytes[self.height])
+    }
+
+    /// Leaf at `leaf_index`, if appended.
+    pub fn leaf(&self, leaf_index: u64) -> Option<[u8; 32]> {
+        self.levels[0]
+            .get(usize::try_from(leaf_index).ok()?)
+            .copied()
+    }
+
+    /// Append a leaf and return the new root.
+    ///
+    /// # Errors
+    /// [`ProofError::TreeFull`] at capacity, [`ProofError::HashFailed`] if a
+    /// node hash fails.
+    pub fn append(&mut self, leaf: [u8; 32]) -> Result<[u8; 32], ProofError> {
+        if self.len() >= 1u64 << self.height {
+            return Err(ProofError::TreeFull);
+        }
+
+        let mut index = self.levels[0].len();
+        self.levels[0].push(leaf);
+        for level in 0..self.height {
+            let left_index = index & !1;
+            let left = self.node(level, left_index);
+            let right = self.node(level, left_index + 1);
+            let parent = H::hash_pair(&left, &right).map_err(|_| ProofError::HashFailed)?;
+
+            index /= 2;
+            let parents = &mut self.levels[level + 1];
+            if index < parents.len() {
+                parents[index] = parent;
+            } else {
+                parents.push(parent);
+            }
+        }
+
+        Ok(self.root())
+    }
+
+    /// Sibling path for `leaf_index` against the current root.
+    ///
+    /// # Errors
+    /// [`ProofError::LeafNotFound`] if the leaf has not been appended.
+    pub fn prove(&self, leaf_index: u64) -> Result<MerklePath, ProofError> {
+        if leaf_index >= self.len() {
+            return Err(ProofError::LeafNotFound(leaf_index));
+        }
+
+        let mut index = leaf_index as usize;
+        let siblings = (0..self.height)
+            .map(|level| {
+                let sibling = self.node(level, index ^ 1);
+                index /= 2;
+                sibling
+            })
+            .collect();
+
+        Ok(MerklePath {
+            leaf_index,
+            siblings,
+        })
+    }
+
+    fn node(&self, level: usize, index: usize) -> [u8; 32] {
+        self.levels[level]
+            .get(index)
+            .copied()
+            .unwrap_or(self.zero_bytes[level])
+    }
+
+    fn check_next_index(&self, actual: u64) -> Result<(), ProofError> {
+        let expected = self.len();
+        if actual != expected {
+            return Err(ProofError::LeafIndexMismatch { expected, actual });
+        }
+        Ok(())
+    }
+}
+
+/// Returns true if `path` proves `leaf` against `root` under hasher `H`.
+pub fn verify_path<H: TreeHasher>(root: [u8; 32], leaf: [u8; 32], path: &MerklePath) -> bool {
+    path_root(leaf, path.leaf_index, &path.siblings, H::hash_pair).is_ok_and(|r| r == root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkle_tree::MerkleTree;
+    use crate::state::{CommitmentMerkleTree, ROOT_HISTORY_SIZE, ReceiptMerkleTree};
+
+    /// Deterministic xorshift64 leaf generator; no rand dependency needed.
+    struct Leaves(u64);
+
+    impl Leaves {
+        fn next_leaf(&mut self) -> [u8; 32] {
+            let mut leaf = [0u8; 32];
+            for chunk in leaf.chunks_mut(8) {
+                self.0 ^= self.0 << 13;
+                self.0 ^= self.0 >> 7;
+                self.0 ^= self.0 << 17;
+                chunk.copy_from_slice(&self.0.to_le_bytes());
+            }
+            // Keep Poseidon inputs below the BN254 modulus
+            leaf[0] = 0;
+            leaf
+        }
+    }
+
+    fn onchain_commitment_tree() -> CommitmentMerkleTree {
+        let mut tree = CommitmentMerkleTree {
+            authority: [0u8; 32],
+            next_index: 0,
+            root_index: 0,
+            height: COMMITMENT_TREE_HEIGHT,
+            bump: 0,
+            root_history_size: ROOT_HISTORY_SIZE as u16,
+            _padding: [0u8; 4],
+            root: [0u8; 32],
+            subtrees: [[0u8; 32]; COMMITMENT_TREE_HEIGHT as usize],
+            root_history: [[0u8; 32]; ROOT_HISTORY_SIZE],
+        };
+        MerkleTree::initialize::<Poseidon>(&mut tree).unwrap();
+        tree
+    }
+
+    fn onchain_receipt_tree() -> ReceiptMerkleTree {
+        let mut tree = ReceiptMerkleTree {
+            authority: [0u8; 32],
+            next_index: 0,
+            root: [0u8; 32],
+            height: RECEIPT_TREE_HEIGHT,
+            bump: 0,
+            _padding: [0u8; 6],
+            subtrees: [[0u8; 32]; RECEIPT_TREE_HEIGHT as usize],
+        };
+        tree.initialize::<Sha256>().unwrap();
+        tree
+    }
+
+    fn commitment_header(
+        leaf_index: u64,
+        commitment: [u8; 32],
+        new_root: [u8; 32],
+    ) -> NewCommitmentHeader {
+        NewCommitmentHeader {
+            leaf_index,
+            commitment,
+            new_root,
+            encrypted_output_len: 0,
+            total_chunks: 0,
+            _padding: [0u8; 2],
+        }
+    }
+
+    #[test]
+    fn test_empty_roots_match_onchain_initialize() {
+        assert_eq!(
+            OffchainCommitmentTree::commitment_tree().root(),
+            onchain_commitment_tree().root
+        );
+        assert_eq!(
+            OffchainReceiptTree::receipt_tree().root(),
+            onchain_receipt_tree().root
+        );
+    }
+
+    #[test]
+    fn test_commitment_root_matches_onchain_after_random_appends() {
+        for seed in [1u64, 0x5eed, 0xdead_beef] {
+            let mut leaves = Leaves(seed);
+            let appends = 1 + (seed % 47) as usize;
+            let mut onchain = onchain_commitment_tree();
+            let mut offchain = OffchainCommitmentTree::commitment_tree();
+
+            for _ in 0..appends {
+                let leaf = leaves.next_leaf();
+                let leaf_index = onchain.next_index;
+                let insert_proof = MerkleTree::append::<Poseidon>(leaf, &mut onchain).unwrap();
+
+                offchain
+                    .append_commitment_event(&commitment_header(leaf_index, leaf, onchain.root))
+                    .unwrap();
+                assert_eq!(
+                    offchain.root(),
+                    onchain.root,
+                    "seed {seed} leaf {leaf_index}"
+                );
+                // The newest leaf's path equals the proof returned by the on-chain append
+                assert_eq!(offchain.prove(leaf_index).unwrap().siblings, insert_proof);
+            }
+
+            for leaf_index in 0..offchain.len() {
+                let leaf = offchain.leaf(leaf_index).unwrap();
+                let path = offchain.prove(leaf_index).unwrap();
+                assert!(verify_path::<Poseidon>(offchain.root(), leaf, &path));
+                assert!(MerkleTree::is_known_path::<Poseidon>(
+                    &onchain,
+                    leaf,
+                    leaf_index,
+                    &path.siblings
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn test_receipt_root_matches_onchain_after_random_appends() {
+        for seed in [7u64, 0xc0ffee, 0x1234_5678_9abc] {
+            let mut leaves = Leaves(seed);
+            let appends = 1 + (seed % 61) as usize;
+            let mut onchain = onchain_receipt_tree();
+            let mut offchain = OffchainReceiptTree::receipt_tree();
+
+            for _ in 0..appends {
+                let receipt_hash = leaves.next_leaf();
+                let receipt_index = onchain.next_index;
+                onchain.append::<Sha256>(receipt_hash).unwrap();
+
+                let header = NewReceiptEventHeader {
+                    receipt_index,
+                    receipt_hash,
+                    ..bytemuck::Zeroable::zeroed()
+                };
+                offchain.append_receipt_event(&header).unwrap();
+                assert_eq!(
+                    offchain.root(),
+                    onchain.root,
+                    "seed {seed} leaf {receipt_index}"
+                );
+            }
+
+            for leaf_index in 0..offchain.len() {
+                let path = offchain.prove(leaf_index).unwrap();
+                let leaf = offchain.leaf(leaf_index).unwrap();
+                assert!(verify_path::<Sha256>(onchain.root, leaf, &path));
+            }
+        }
+    }
+
+    #[test]
+    fn test_commitment_event_stream_checks() {
+        let mut tree = OffchainCommitmentTree::commitment_tree();
+        let leaf = Leaves(3).next_leaf();
+
+        assert_eq!(
+            tree.append_commitment_event(&commitment_header(1, leaf, [0u8; 32])),
+            Err(ProofError::LeafIndexMismatch {
+                expected: 0,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            tree.append_commitment_event(&commitment_header(0, leaf, [9u8; 32])),
+            Err(ProofError::RootMismatch { leaf_index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_verify_path_rejects_wrong_inputs() {
+        let mut leaves = Leaves(11);
+        let mut tree = OffchainCommitmentTree::commitment_tree();
+        for _ in 0..5 {
+            tree.append(leaves.next_leaf()).unwrap();
+        }
+        let root = tree.root();
+        let leaf = tree.leaf(2).unwrap();
+        let path = tree.prove(2).unwrap();
+
+        assert!(verify_path::<Poseidon>(root, leaf, &path));
+        assert!(!verify_path::<Poseidon>(root, tree.leaf(3).unwrap(), &path));
+
+        let mut moved = path.clone();
+        moved.leaf_index = 3;
+        assert!(!verify_path::<Poseidon>(root, leaf, &moved));
+
+        let mut out_of_range = path.clone();
+        out_of_range.leaf_index = 1u64 << COMMITMENT_TREE_HEIGHT;
+        assert!(!verify_path::<Poseidon>(root, leaf, &out_of_range));
+
+        assert_eq!(tree.prove(5), Err(ProofError::LeafNotFound(5)));
+    }
+
+    #[test]
+    fn test_full_tree_rejects_append() {
+        let mut tree = OffchainMerkleTree::<Sha256>::new(2);
+        for i in 0..4u8 {
+            tree.append([i; 32]).unwrap();
+        }
+        assert_eq!(tree.append([4u8; 32]), Err(ProofError::TreeFull));
+    }
+}
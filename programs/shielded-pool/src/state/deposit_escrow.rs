@@ -16,14 +16,44 @@
 //! - Relayer substituting a different proof
 //! - Relayer modifying the recipient/amounts
 //! - Replay attacks (consumed flag + expiry)
+//!
+//! # Asset Kinds
+//!
+//! - [`DepositEscrowAssetKind::Token`]: SPL tokens held in the escrow vault ATA.
+//! - [`DepositEscrowAssetKind::NativeSol`]: lamports held directly on the escrow PDA
+//!   above its rent-exempt minimum. The vault is a WSOL ATA that stays empty until
+//!   `execute_transact` moves the lamports into it and syncs it just before the
+//!   pool deposit, so the depositor never needs a WSOL account of their own.
 
 use crate::state::ShieldedPoolAccount;
 use panchor::prelude::*;
 use pinocchio::pubkey::Pubkey;
 
+/// Asset held by a [`DepositEscrow`].
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DepositEscrowAssetKind {
+    /// SPL tokens in the escrow vault ATA
+    Token = 0,
+    /// Lamports on the escrow PDA, wrapped into the WSOL vault ATA at deposit time
+    NativeSol = 1,
+}
+
+impl DepositEscrowAssetKind {
+    /// Convert from u8, returning None for invalid values.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(DepositEscrowAssetKind::Token),
+            1 => Some(DepositEscrowAssetKind::NativeSol),
+            _ => None,
+        }
+    }
+}
+
 /// Deposit escrow account for relayer-assisted deposits.
 ///
-/// This account holds tokens deposited by a user, bound to a specific proof hash.
+/// This account holds tokens (or, for native SOL escrows, lamports) deposited by a
+/// user, bound to a specific proof hash.
 /// A relayer can consume the escrow when executing the matching transact proof.
 ///
 /// # Account Layout
@@ -39,7 +69,7 @@ pub struct DepositEscrow {
     /// This binds the escrow to the exact proof parameters the user authorized.
     pub proof_hash: [u8; 32],
 
-    /// The SPL token mint for this escrow's vault.
+    /// The SPL token mint for this escrow's vault (WSOL for native SOL escrows).
     /// Used to derive the escrow vault ATA.
     pub mint: Pubkey,
 
@@ -63,14 +93,23 @@ pub struct DepositEscrow {
     /// PDA bump seed for this escrow account.
     pub bump: u8,
 
+    /// Escrowed asset (see [`DepositEscrowAssetKind`]).
+    /// Former padding, so escrows created before native SOL support read as `Token`.
+    pub asset_kind: u8,
+
+    /// Number of times the depositor has changed `authorized_relayer`.
+    /// Former padding, so existing escrows start at 0.
+    pub relayer_revision: u8,
+
     /// Padding for 8-byte alignment.
-    pub _padding: [u8; 6],
+    pub _padding: [u8; 4],
 }
 
 impl DepositEscrow {
     /// Size of the DepositEscrow struct (excluding discriminator).
     /// 32 (proof_hash) + 32 (mint) + 32 (authorized_relayer) + 8 (expiry_slot)
-    /// + 8 (nonce) + 1 (consumed) + 1 (bump) + 6 (padding) = 120 bytes
+    /// + 8 (nonce) + 1 (consumed) + 1 (bump) + 1 (asset_kind) + 1 (relayer_revision)
+    /// + 4 (padding) = 120 bytes
     pub const SIZE: usize = 120;
 
     /// Total account size including 8-byte discriminator.
@@ -86,6 +125,17 @@ impl DepositEscrow {
         self.authorized_relayer == Pubkey::default() || self.authorized_relayer == *relayer
     }
 
+    /// Replace the authorized relayer, returning the previous one.
+    ///
+    /// Bumps `relayer_revision`, so relayers and indexers can tell the binding
+    /// changed even if it is later set back. Returns None once the revision
+    /// counter is exhausted.
+    #[inline]
+    pub fn set_authorized_relayer(&mut self, relayer: Pubkey) -> Option<Pubkey> {
+        self.relayer_revision = self.relayer_revision.checked_add(1)?;
+        Some(core::mem::replace(&mut self.authorized_relayer, relayer))
+    }
+
     /// Check if this escrow has expired.
     ///
     /// Returns true if current_slot > expiry_slot.
@@ -99,6 +149,20 @@ impl DepositEscrow {
     pub fn is_consumed(&self) -> bool {
         self.consumed != 0
     }
+
+    /// Escrowed asset kind, or None for unknown values.
+    #[inline]
+    pub fn asset_kind(&self) -> Option<DepositEscrowAssetKind> {
+        DepositEscrowAssetKind::from_u8(self.asset_kind)
+    }
+
+    /// Lamports escrowed on a native SOL escrow PDA holding `lamports`.
+    ///
+    /// Everything above the rent-exempt minimum for [`Self::ACCOUNT_SIZE`].
+    #[inline]
+    pub fn escrowed_lamports(lamports: u64, rent_exempt_minimum: u64) -> u64 {
+        lamports.saturating_sub(rent_exempt_minimum)
+    }
 }
 
 #[cfg(test)]
@@ -115,7 +179,9 @@ mod tests {
             nonce: 0,
             consumed: 0,
             bump: 0,
-            _padding: [0u8; 6],
+            asset_kind: DepositEscrowAssetKind::Token as u8,
+            relayer_revision: 0,
+            _padding: [0u8; 4],
         };
 
         let relayer1: Pubkey = [1u8; 32];
@@ -131,6 +197,33 @@ mod tests {
         assert!(!escrow.is_relayer_authorized(&relayer2));
     }
 
+    #[test]
+    fn test_set_authorized_relayer() {
+        let relayer1: Pubkey = [1u8; 32];
+        let relayer2: Pubkey = [2u8; 32];
+        let mut escrow = DepositEscrow::zeroed();
+        escrow.authorized_relayer = relayer1;
+
+        // The old relayer loses access as soon as the binding changes
+        assert_eq!(escrow.set_authorized_relayer(relayer2), Some(relayer1));
+        assert!(!escrow.is_relayer_authorized(&relayer1));
+        assert!(escrow.is_relayer_authorized(&relayer2));
+        assert_eq!(escrow.relayer_revision, 1);
+
+        // Clearing opens the escrow to any relayer, including self-relay
+        assert_eq!(
+            escrow.set_authorized_relayer(Pubkey::default()),
+            Some(relayer2)
+        );
+        assert!(escrow.is_relayer_authorized(&relayer1));
+        assert_eq!(escrow.relayer_revision, 2);
+
+        // An exhausted revision counter refuses further changes
+        escrow.relayer_revision = u8::MAX;
+        assert_eq!(escrow.set_authorized_relayer(relayer1), None);
+        assert_eq!(escrow.authorized_relayer, Pubkey::default());
+    }
+
     #[test]
     fn test_is_expired() {
         let escrow = DepositEscrow {
@@ -141,7 +234,9 @@ mod tests {
             nonce: 0,
             consumed: 0,
             bump: 0,
-            _padding: [0u8; 6],
+            asset_kind: DepositEscrowAssetKind::Token as u8,
+            relayer_revision: 0,
+            _padding: [0u8; 4],
         };
 
         // Not expired at or before expiry_slot
@@ -152,6 +247,24 @@ mod tests {
         assert!(escrow.is_expired(1001));
     }
 
+    #[test]
+    fn test_asset_kind() {
+        assert_eq!(
+            DepositEscrowAssetKind::from_u8(0),
+            Some(DepositEscrowAssetKind::Token)
+        );
+        assert_eq!(
+            DepositEscrowAssetKind::from_u8(1),
+            Some(DepositEscrowAssetKind::NativeSol)
+        );
+        assert_eq!(DepositEscrowAssetKind::from_u8(2), None);
+
+        // Lamports above rent are escrowed; never underflows
+        assert_eq!(DepositEscrow::escrowed_lamports(1_500, 1_000), 500);
+        assert_eq!(DepositEscrow::escrowed_lamports(1_000, 1_000), 0);
+        assert_eq!(DepositEscrow::escrowed_lamports(900, 1_000), 0);
+    }
+
     #[test]
     fn test_struct_size() {
         // Verify the struct size matches our constant
@@ -1,15 +1,225 @@
 use panchor::prelude::*;
-use pinocchio::pubkey::Pubkey;
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey,
+};
+use pinocchio_contrib::AccountAssertions;
 use zorb_pool_interface::authority::HasAuthority;
 
-use crate::state::ShieldedPoolAccount;
+use crate::errors::ShieldedPoolError;
+use crate::state::{MIN_SLOTS_PER_NULLIFIER_EPOCH, ROOT_HISTORY_SIZE, ShieldedPoolAccount};
+
+/// Current [`GlobalConfig`] layout version.
+///
+/// | Version | Size | Changes |
+/// |---------|------|---------|
+/// | 1 | 192 | Authority, timelock and roles. Predates the `version` field, which reads 0 |
+/// | 2 | 216 | Adds the tunable parameters (see [`GlobalParam`]) |
+/// | 3 | 256 | Adds the protocol statistics (see [`ProtocolStats`]) |
+///
+/// Older accounts are upgraded in place by `MigrateGlobalConfig`.
+pub const GLOBAL_CONFIG_VERSION: u16 = 3;
+
+/// On-chain size of a version 1 [`GlobalConfig`] account.
+pub const GLOBAL_CONFIG_V1_SIZE: usize = 192;
+
+/// On-chain size of a version 2 [`GlobalConfig`] account.
+pub const GLOBAL_CONFIG_V2_SIZE: usize = 216;
+
+/// Minimum delay between `TransferAuthority` and `AcceptAuthority` (~1 hour at 400ms slots).
+pub const MIN_AUTHORITY_TRANSFER_DELAY_SLOTS: u64 = 9_000;
+
+/// Authority transfer delay set at initialization (~24 hours at 400ms slots).
+pub const DEFAULT_AUTHORITY_TRANSFER_DELAY_SLOTS: u64 = 216_000;
+
+/// Basis-point denominator for [`GlobalParam::MaxRelayerFeeBps`].
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Upper bound for [`GlobalParam::NullifierEpochMinSlots`] (~1 day at 400ms slots).
+pub const MAX_NULLIFIER_EPOCH_MIN_SLOTS: u64 = 216_000;
+
+/// Tunable protocol parameters, set in batches by `SetGlobalParams`.
+///
+/// | ID | Parameter | Default | Min | Max | Enforced by |
+/// |----|-----------|---------|-----|-----|-------------|
+/// | 0 | `MaxRootAge` | `ROOT_HISTORY_SIZE` (256) | 1 | `ROOT_HISTORY_SIZE` | ExecuteTransact commitment root check |
+/// | 1 | `MaxRelayerFeeBps` | 10_000 (no cap) | 0 | 10_000 | ExecuteTransact relayer fee per public line |
+/// | 2 | `NullifierEpochMinSlots` | `MIN_SLOTS_PER_NULLIFIER_EPOCH` (9_000) | `MIN_SLOTS_PER_NULLIFIER_EPOCH` | `MAX_NULLIFIER_EPOCH_MIN_SLOTS` | AdvanceNullifierEpoch interval |
+///
+/// IDs are part of the instruction ABI and must never be reused.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u16)]
+pub enum GlobalParam {
+    /// Number of most recent commitment roots a proof may reference
+    MaxRootAge = 0,
+    /// Relayer fee cap in basis points of |ext_amount|
+    MaxRelayerFeeBps = 1,
+    /// Minimum slots between nullifier epoch advances
+    NullifierEpochMinSlots = 2,
+}
+
+impl GlobalParam {
+    /// All parameters in ID order
+    pub const ALL: [GlobalParam; 3] = [
+        GlobalParam::MaxRootAge,
+        GlobalParam::MaxRelayerFeeBps,
+        GlobalParam::NullifierEpochMinSlots,
+    ];
+
+    /// Convert from u16 to GlobalParam
+    pub fn from_u16(value: u16) -> Option<Self> {
+        match value {
+            0 => Some(GlobalParam::MaxRootAge),
+            1 => Some(GlobalParam::MaxRelayerFeeBps),
+            2 => Some(GlobalParam::NullifierEpochMinSlots),
+            _ => None,
+        }
+    }
+
+    /// Inclusive `(min, max)` bounds for the parameter value
+    pub const fn bounds(self) -> (u64, u64) {
+        match self {
+            GlobalParam::MaxRootAge => (1, ROOT_HISTORY_SIZE as u64),
+            GlobalParam::MaxRelayerFeeBps => (0, BPS_DENOMINATOR),
+            GlobalParam::NullifierEpochMinSlots => {
+                (MIN_SLOTS_PER_NULLIFIER_EPOCH, MAX_NULLIFIER_EPOCH_MIN_SLOTS)
+            }
+        }
+    }
+
+    /// Value set at initialization
+    pub const fn default_value(self) -> u64 {
+        match self {
+            GlobalParam::MaxRootAge => ROOT_HISTORY_SIZE as u64,
+            GlobalParam::MaxRelayerFeeBps => BPS_DENOMINATOR,
+            GlobalParam::NullifierEpochMinSlots => MIN_SLOTS_PER_NULLIFIER_EPOCH,
+        }
+    }
+
+    /// Returns true if `value` is within the parameter's bounds
+    #[inline]
+    pub const fn is_valid(self, value: u64) -> bool {
+        let (min, max) = self.bounds();
+        value >= min && value <= max
+    }
+}
+
+/// Delegated admin roles stored on [`GlobalConfig`].
+///
+/// Each role grants a subset of admin capabilities in addition to the
+/// authority. An unset role (zero pubkey) leaves the capability authority-only.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum AdminRole {
+    /// May pause and unpause the pool
+    Pauser = 0,
+    /// May change fees and protocol limits
+    FeeAdmin = 1,
+    /// May register pools and toggle pool configs
+    Registrar = 2,
+}
+
+impl AdminRole {
+    /// Convert from u8 to AdminRole
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(AdminRole::Pauser),
+            1 => Some(AdminRole::FeeAdmin),
+            2 => Some(AdminRole::Registrar),
+            _ => None,
+        }
+    }
+}
+
+/// How events are written to the transaction logs (`GlobalConfig.event_emission_mode`).
+///
+/// Both modes write the same discriminator-prefixed event bytes; see the
+/// `events` module for how indexers recognize each encoding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum EventEmissionMode {
+    /// Self-CPI to the `Log` instruction, signed by a program PDA
+    SelfCpi = 0,
+    /// Direct `sol_log_data` from the executing instruction (no CPI)
+    LogData = 1,
+}
+
+impl EventEmissionMode {
+    /// Convert from u8 to EventEmissionMode
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(EventEmissionMode::SelfCpi),
+            1 => Some(EventEmissionMode::LogData),
+            _ => None,
+        }
+    }
+}
+
+/// Cumulative protocol counters stored on [`GlobalConfig`].
+///
+/// Maintained on-chain so dashboards can read totals without an indexer.
+/// All counters use saturating arithmetic and are never reset.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Pod, Zeroable, IdlType)]
+pub struct ProtocolStats {
+    /// Successful `ExecuteTransact` calls
+    pub transactions_executed: u64,
+    /// Public deposit lines executed (`ext_amount > 0`)
+    pub deposits: u64,
+    /// Public withdrawal lines executed (`ext_amount < 0`)
+    pub withdrawals: u64,
+    /// Nullifiers inserted into the indexed tree by `NullifierBatchInsert`
+    pub nullifiers_created: u64,
+    /// Lamports returned by nullifier PDA and epoch root garbage collection
+    pub rent_reclaimed: u64,
+}
+
+impl ProtocolStats {
+    /// Count one executed transaction and its public deposit/withdrawal lines
+    pub fn record_transact(&mut self, ext_amounts: &[i64]) {
+        self.transactions_executed = self.transactions_executed.saturating_add(1);
+        for &ext_amount in ext_amounts {
+            if ext_amount > 0 {
+                self.deposits = self.deposits.saturating_add(1);
+            } else if ext_amount < 0 {
+                self.withdrawals = self.withdrawals.saturating_add(1);
+            }
+        }
+    }
+
+    /// Count nullifiers inserted into the indexed tree
+    pub fn record_nullifiers(&mut self, count: u64) {
+        self.nullifiers_created = self.nullifiers_created.saturating_add(count);
+    }
+
+    /// Count lamports reclaimed by garbage collection
+    pub fn record_rent_reclaimed(&mut self, lamports: u64) {
+        self.rent_reclaimed = self.rent_reclaimed.saturating_add(lamports);
+    }
+
+    /// Apply `update` to the stats of a GlobalConfig account of any layout version.
+    ///
+    /// For header-only paths that already validated the account with
+    /// [`GlobalConfigHeader::load`]. Accounts that predate the stats are left
+    /// untouched so those paths keep working before migration.
+    pub fn update(account: &AccountInfo, update: impl FnOnce(&mut ProtocolStats)) -> ProgramResult {
+        let mut data = account.try_borrow_mut_data()?;
+        if data.len() < GlobalConfig::INIT_SPACE {
+            return Ok(());
+        }
+
+        let start = 8 + core::mem::offset_of!(GlobalConfig, stats);
+        let end = start + core::mem::size_of::<Self>();
+        update(bytemuck::from_bytes_mut(&mut data[start..end]));
+        Ok(())
+    }
+}
 
 /// Global configuration singleton for the shielded pool.
 ///
 /// # Account Layout (on-chain)
-/// `[8-byte discriminator][72-byte struct data]`
+/// `[8-byte discriminator][248-byte struct data]`
 ///
-/// Total on-chain size: 80 bytes
+/// Total on-chain size: 256 bytes
 #[account(ShieldedPoolAccount::GlobalConfig)]
 #[repr(C)]
 pub struct GlobalConfig {
@@ -22,8 +232,38 @@ pub struct GlobalConfig {
     pub is_paused: u8,
     /// PDA bump seed
     pub bump: u8,
+    /// Layout version (see [`GLOBAL_CONFIG_VERSION`]); 0 on version 1 accounts
+    pub version: u16,
+    /// Hub-wide withdrawal-only mode (0 = off, 1 = deposits disabled).
+    /// Former padding, so it reads 0 on every layout version.
+    pub withdrawals_only: u8,
+    /// Event encoding (see [`EventEmissionMode`]); 0 = self-CPI.
+    /// Former padding, so it reads 0 on every layout version.
+    pub event_emission_mode: u8,
+    /// Accept a token pool accumulator one finalization stale (0 = off, 1 = on).
+    /// Former padding, so it reads 0 on every layout version.
+    pub allow_one_epoch_stale: u8,
     /// Padding for alignment
-    pub _padding: [u8; 6],
+    pub _padding: [u8; 1],
+    /// Slots that must elapse between `transfer_authority` and `accept_authority`.
+    /// Never below `MIN_AUTHORITY_TRANSFER_DELAY_SLOTS`.
+    pub authority_transfer_delay_slots: u64,
+    /// Slot at which the pending transfer was initiated (0 if none pending)
+    pub transfer_initiated_slot: u64,
+    /// Optional pauser role (zero = authority only)
+    pub pauser: Pubkey,
+    /// Optional fee/limit admin role (zero = authority only)
+    pub fee_admin: Pubkey,
+    /// Optional pool registrar role (zero = authority only)
+    pub registrar: Pubkey,
+    /// See [`GlobalParam::MaxRootAge`]
+    pub max_root_age: u64,
+    /// See [`GlobalParam::MaxRelayerFeeBps`]
+    pub max_relayer_fee_bps: u64,
+    /// See [`GlobalParam::NullifierEpochMinSlots`]
+    pub nullifier_epoch_min_slots: u64,
+    /// Cumulative protocol counters
+    pub stats: ProtocolStats,
 }
 
 impl GlobalConfig {
@@ -32,6 +272,158 @@ impl GlobalConfig {
     pub fn paused(&self) -> bool {
         self.is_paused != 0
     }
+
+    /// Returns true if the hub is in withdrawal-only mode (deposits disabled)
+    #[inline]
+    pub fn deposits_disabled(&self) -> bool {
+        self.withdrawals_only != 0
+    }
+
+    /// Event encoding; unknown values fall back to self-CPI
+    #[inline]
+    pub fn emission_mode(&self) -> EventEmissionMode {
+        EventEmissionMode::from_u8(self.event_emission_mode).unwrap_or(EventEmissionMode::SelfCpi)
+    }
+
+    /// Returns true if token pool accumulators may be one finalization stale
+    #[inline]
+    pub fn allows_one_epoch_stale(&self) -> bool {
+        self.allow_one_epoch_stale != 0
+    }
+
+    /// Returns true if an authority transfer is pending
+    #[inline]
+    pub fn has_pending_authority(&self) -> bool {
+        self.pending_authority != Pubkey::default()
+    }
+
+    /// First slot at which the pending authority may accept
+    #[inline]
+    pub fn authority_transfer_unlock_slot(&self) -> u64 {
+        self.transfer_initiated_slot
+            .saturating_add(self.authority_transfer_delay_slots)
+    }
+
+    /// Returns the holder of `role` (zero pubkey if unset)
+    #[inline]
+    pub fn role(&self, role: AdminRole) -> &Pubkey {
+        match role {
+            AdminRole::Pauser => &self.pauser,
+            AdminRole::FeeAdmin => &self.fee_admin,
+            AdminRole::Registrar => &self.registrar,
+        }
+    }
+
+    /// Mutable access to the holder of `role`
+    #[inline]
+    pub fn role_mut(&mut self, role: AdminRole) -> &mut Pubkey {
+        match role {
+            AdminRole::Pauser => &mut self.pauser,
+            AdminRole::FeeAdmin => &mut self.fee_admin,
+            AdminRole::Registrar => &mut self.registrar,
+        }
+    }
+
+    /// Returns true if `key` is the authority or the (set) holder of `role`
+    #[inline]
+    pub fn has_role(&self, role: AdminRole, key: &Pubkey) -> bool {
+        let holder = self.role(role);
+        *key == self.authority || (*holder != Pubkey::default() && holder == key)
+    }
+
+    /// Returns the current value of `param`
+    #[inline]
+    pub fn param(&self, param: GlobalParam) -> u64 {
+        match param {
+            GlobalParam::MaxRootAge => self.max_root_age,
+            GlobalParam::MaxRelayerFeeBps => self.max_relayer_fee_bps,
+            GlobalParam::NullifierEpochMinSlots => self.nullifier_epoch_min_slots,
+        }
+    }
+
+    /// Mutable access to the value of `param`
+    #[inline]
+    pub fn param_mut(&mut self, param: GlobalParam) -> &mut u64 {
+        match param {
+            GlobalParam::MaxRootAge => &mut self.max_root_age,
+            GlobalParam::MaxRelayerFeeBps => &mut self.max_relayer_fee_bps,
+            GlobalParam::NullifierEpochMinSlots => &mut self.nullifier_epoch_min_slots,
+        }
+    }
+
+    /// Set every tunable parameter to its default
+    pub fn set_default_params(&mut self) {
+        for param in GlobalParam::ALL {
+            *self.param_mut(param) = param.default_value();
+        }
+    }
+}
+
+/// Leading fields of [`GlobalConfig`], identical in every layout version.
+///
+/// Read paths that only need these fields load the header so that they keep
+/// working on accounts that have not been migrated yet.
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct GlobalConfigHeader {
+    /// Authority that controls the pool
+    pub authority: Pubkey,
+    /// Pending authority for two-step transfer
+    pub pending_authority: Pubkey,
+    /// Whether the pool is paused (0 = active, 1 = paused)
+    pub is_paused: u8,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Layout version; 0 on version 1 accounts
+    pub version: u16,
+    /// Hub-wide withdrawal-only mode (0 = off, 1 = deposits disabled)
+    pub withdrawals_only: u8,
+    /// Event encoding (see [`EventEmissionMode`]); 0 = self-CPI
+    pub event_emission_mode: u8,
+    /// Accept a token pool accumulator one finalization stale (0 = off, 1 = on)
+    pub allow_one_epoch_stale: u8,
+    /// Padding for alignment
+    pub _padding: [u8; 1],
+}
+
+impl GlobalConfigHeader {
+    /// Load the header from a GlobalConfig account of any layout version.
+    ///
+    /// Validates owner and discriminator; only the header bytes must be present.
+    pub fn load(account: &AccountInfo) -> Result<Self, ProgramError> {
+        account.assert_owner(&crate::ID)?;
+
+        let data = account.try_borrow_data()?;
+        let end = 8 + core::mem::size_of::<Self>();
+        if data.len() < end {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let discriminator = u64::from_le_bytes(data[..8].try_into().unwrap());
+        if discriminator != GlobalConfig::DISCRIMINATOR {
+            return Err(ShieldedPoolError::InvalidDiscriminator.into());
+        }
+
+        Ok(*bytemuck::from_bytes::<Self>(&data[8..end]))
+    }
+
+    /// Returns true if the hub is in withdrawal-only mode (deposits disabled)
+    #[inline]
+    pub fn deposits_disabled(&self) -> bool {
+        self.withdrawals_only != 0
+    }
+
+    /// Event encoding; unknown values fall back to self-CPI
+    #[inline]
+    pub fn emission_mode(&self) -> EventEmissionMode {
+        EventEmissionMode::from_u8(self.event_emission_mode).unwrap_or(EventEmissionMode::SelfCpi)
+    }
+
+    /// Layout version, treating the unset field of version 1 accounts as 1
+    #[inline]
+    pub fn layout_version(&self) -> u16 {
+        self.version.max(1)
+    }
 }
 
 impl HasAuthority for GlobalConfig {
@@ -48,3 +440,149 @@ impl HasAuthority for GlobalConfig {
         &mut self.pending_authority
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(byte: u8) -> Pubkey {
+        [byte; 32]
+    }
+
+    #[test]
+    fn test_global_config_size() {
+        // 8 (discriminator) + 32 + 32 + 1 + 1 + 2 + 1 + 1 + 1 + 1 (padding) + 8 + 8 + 32 * 3 + 8 * 3 + 8 * 5 = 256
+        assert_eq!(GlobalConfig::INIT_SPACE, 256);
+        // Version 1 ended before the tunable parameters
+        assert_eq!(
+            8 + core::mem::offset_of!(GlobalConfig, max_root_age),
+            GLOBAL_CONFIG_V1_SIZE
+        );
+        // Version 2 ended before the stats
+        assert_eq!(
+            8 + core::mem::offset_of!(GlobalConfig, stats),
+            GLOBAL_CONFIG_V2_SIZE
+        );
+    }
+
+    #[test]
+    fn test_record_transact_counts_public_lines() {
+        let mut stats = ProtocolStats::default();
+        stats.record_transact(&[100, -50]);
+        stats.record_transact(&[0, 0]);
+        stats.record_transact(&[-1, -1]);
+
+        assert_eq!(stats.transactions_executed, 3);
+        assert_eq!(stats.deposits, 1);
+        assert_eq!(stats.withdrawals, 3);
+    }
+
+    #[test]
+    fn test_stats_saturate() {
+        let mut stats = ProtocolStats {
+            transactions_executed: u64::MAX,
+            nullifiers_created: u64::MAX - 1,
+            rent_reclaimed: u64::MAX - 1,
+            ..Default::default()
+        };
+        stats.record_transact(&[1]);
+        stats.record_nullifiers(4);
+        stats.record_rent_reclaimed(1_000);
+
+        assert_eq!(stats.transactions_executed, u64::MAX);
+        assert_eq!(stats.deposits, 1);
+        assert_eq!(stats.nullifiers_created, u64::MAX);
+        assert_eq!(stats.rent_reclaimed, u64::MAX);
+    }
+
+    #[test]
+    fn test_global_config_header_is_prefix() {
+        // The header must cover exactly the fields before the timelock
+        assert_eq!(core::mem::size_of::<GlobalConfigHeader>(), 72);
+        assert_eq!(
+            core::mem::offset_of!(GlobalConfig, authority_transfer_delay_slots),
+            core::mem::size_of::<GlobalConfigHeader>()
+        );
+        assert_eq!(core::mem::offset_of!(GlobalConfig, version), 66);
+        assert_eq!(core::mem::offset_of!(GlobalConfig, withdrawals_only), 68);
+        assert_eq!(core::mem::offset_of!(GlobalConfigHeader, withdrawals_only), 68);
+        assert_eq!(core::mem::offset_of!(GlobalConfigHeader, event_emission_mode), 69);
+        assert_eq!(
+            core::mem::offset_of!(GlobalConfig, allow_one_epoch_stale),
+            70
+        );
+        assert_eq!(
+            core::mem::offset_of!(GlobalConfigHeader, allow_one_epoch_stale),
+            70
+        );
+    }
+
+    #[test]
+    fn test_global_param_ids_round_trip() {
+        for param in GlobalParam::ALL {
+            assert_eq!(GlobalParam::from_u16(param as u16), Some(param));
+        }
+        assert_eq!(GlobalParam::from_u16(GlobalParam::ALL.len() as u16), None);
+    }
+
+    #[test]
+    fn test_global_param_defaults_within_bounds() {
+        let mut config = GlobalConfig::zeroed();
+        config.set_default_params();
+
+        for param in GlobalParam::ALL {
+            assert!(param.is_valid(param.default_value()), "{:?}", param);
+            assert_eq!(config.param(param), param.default_value());
+        }
+        assert!(!GlobalParam::MaxRootAge.is_valid(0));
+        assert!(!GlobalParam::MaxRelayerFeeBps.is_valid(BPS_DENOMINATOR + 1));
+        assert!(!GlobalParam::NullifierEpochMinSlots.is_valid(MIN_SLOTS_PER_NULLIFIER_EPOCH - 1));
+    }
+
+    #[test]
+    fn test_event_emission_mode_from_u8() {
+        assert_eq!(EventEmissionMode::from_u8(0), Some(EventEmissionMode::SelfCpi));
+        assert_eq!(EventEmissionMode::from_u8(1), Some(EventEmissionMode::LogData));
+        assert_eq!(EventEmissionMode::from_u8(2), None);
+
+        let mut config = GlobalConfig::zeroed();
+        assert_eq!(config.emission_mode(), EventEmissionMode::SelfCpi);
+        config.event_emission_mode = 1;
+        assert_eq!(config.emission_mode(), EventEmissionMode::LogData);
+        config.event_emission_mode = 2;
+        assert_eq!(config.emission_mode(), EventEmissionMode::SelfCpi);
+    }
+
+    #[test]
+    fn test_admin_role_from_u8() {
+        assert_eq!(AdminRole::from_u8(0), Some(AdminRole::Pauser));
+        assert_eq!(AdminRole::from_u8(1), Some(AdminRole::FeeAdmin));
+        assert_eq!(AdminRole::from_u8(2), Some(AdminRole::Registrar));
+        assert_eq!(AdminRole::from_u8(3), None);
+    }
+
+    #[test]
+    fn test_has_role_falls_back_to_authority() {
+        let mut config = GlobalConfig::zeroed();
+        config.authority = key(1);
+
+        for role in [AdminRole::Pauser, AdminRole::FeeAdmin, AdminRole::Registrar] {
+            assert!(config.has_role(role, &key(1)));
+            assert!(!config.has_role(role, &key(2)));
+            // An unset role must not match the zero pubkey
+            assert!(!config.has_role(role, &Pubkey::default()));
+        }
+    }
+
+    #[test]
+    fn test_has_role_is_role_specific() {
+        let mut config = GlobalConfig::zeroed();
+        config.authority = key(1);
+        *config.role_mut(AdminRole::Pauser) = key(2);
+
+        assert!(config.has_role(AdminRole::Pauser, &key(2)));
+        assert!(config.has_role(AdminRole::Pauser, &key(1)));
+        assert!(!config.has_role(AdminRole::FeeAdmin, &key(2)));
+        assert!(!config.has_role(AdminRole::Registrar, &key(2)));
+    }
+}
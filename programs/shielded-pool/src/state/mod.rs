@@ -75,20 +75,28 @@ pub use crate::events::{
 };
 
 pub use commitment_tree::CommitmentMerkleTree;
-pub use global_config::GlobalConfig;
+pub use global_config::{
+    AdminRole, BPS_DENOMINATOR, DEFAULT_AUTHORITY_TRANSFER_DELAY_SLOTS, EventEmissionMode,
+    GLOBAL_CONFIG_V1_SIZE, GLOBAL_CONFIG_V2_SIZE, GLOBAL_CONFIG_VERSION, GlobalConfig,
+    GlobalConfigHeader, GlobalParam, MAX_NULLIFIER_EPOCH_MIN_SLOTS,
+    MIN_AUTHORITY_TRANSFER_DELAY_SLOTS, ProtocolStats,
+};
 pub use nullifier_epoch_root::{NullifierEpochRoot, MIN_PROVABLE_NULLIFIER_EPOCHS};
 pub use nullifier::Nullifier;
 pub use nullifier_tree::{
-    CLEANUP_GRACE_EPOCHS, IndexedLeaf, MAX_NULLIFIER_VALUE, MIN_SLOTS_PER_NULLIFIER_EPOCH,
-    NULLIFIER_TREE_HEIGHT, NullifierIndexedTree,
+    CLEANUP_GRACE_EPOCHS, IndexedLeaf, MAX_EARLIEST_EPOCH_ADVANCE, MAX_NULLIFIER_VALUE,
+    MIN_SLOTS_PER_NULLIFIER_EPOCH, NULLIFIER_TREE_HEIGHT, NullifierIndexedTree,
 };
 
 pub use pool_traits::RATE_PRECISION;
 
-pub use pool_config::{PoolConfig, PoolType as HubPoolType};
+pub use pool_config::{
+    POOL_CONFIG_CLOSE_GRACE_SLOTS, POOL_MODE_ALL, POOL_MODE_DEPOSITS_ENABLED,
+    POOL_MODE_WITHDRAWALS_ENABLED, PoolConfig, PoolType as HubPoolType,
+};
 pub use receipt_tree::{RECEIPT_TREE_HEIGHT, ReceiptMerkleTree};
 pub use transact_session::{
-    MAX_SESSION_DATA_LEN, SESSION_EXPIRY_SLOTS, TRANSACT_SESSION_HEADER_SIZE, TransactSession,
+    MAX_SESSION_DATA_LEN, ProofFormat, SESSION_EXPIRY_SLOTS, TRANSACT_SESSION_HEADER_SIZE, TransactSession,
 };
 
 // Re-export types from pool programs
@@ -105,7 +113,7 @@ pub use unified_sol_pool::{
 };
 
 pub use commitment_tree::{COMMITMENT_TREE_HEIGHT, ROOT_HISTORY_SIZE};
-pub use deposit_escrow::DepositEscrow;
+pub use deposit_escrow::{DepositEscrow, DepositEscrowAssetKind};
 
 #[cfg(any(feature = "localnet", feature = "test-mode", test))]
 pub const ADMIN_PUBKEY: Option<Pubkey> = None;
@@ -81,10 +81,11 @@
 //!
 //! Invariant: next_index <= next_pending_index
 //! Invariant: earliest_provable_epoch <= current_epoch
-//! Invariant: Epoch advances require current_slot >= last_epoch_slot + MIN_SLOTS_PER_NULLIFIER_EPOCH
+//! Invariant: Epoch advances require current_slot >= last_epoch_slot + GlobalConfig.nullifier_epoch_min_slots
 //! ```
 
 use super::commitment_tree::COMMITMENT_TREE_HEIGHT;
+use super::nullifier_epoch_root::MIN_PROVABLE_NULLIFIER_EPOCHS;
 use bytemuck::{Pod, Zeroable};
 use panchor::prelude::*;
 use pinocchio::pubkey::Pubkey;
@@ -118,6 +119,14 @@ pub const MAX_NULLIFIER_VALUE: [u8; 32] = [
 /// 2. After grace period, third parties can clean up old state and earn rent
 pub const CLEANUP_GRACE_EPOCHS: u64 = 43200;
 
+/// Maximum epochs one AdvanceEarliestProvableEpoch call moves
+/// `earliest_provable_epoch` forward by.
+///
+/// Each epoch advanced emits its own event (a self-CPI), so this keeps a call
+/// well inside the transaction's CPI limit. Catching up after downtime takes
+/// one call per 16 epochs instead of one per epoch.
+pub const MAX_EARLIEST_EPOCH_ADVANCE: u64 = 16;
+
 /// Minimum number of slots that must pass before an epoch can advance when pending nullifiers exist.
 ///
 /// Epochs can advance in two ways:
@@ -130,7 +139,8 @@ pub const CLEANUP_GRACE_EPOCHS: u64 = 43200;
 /// - Preventing a backlog of pending nullifiers from stalling the epoch lifecycle
 /// - Allowing provers to use recent epoch roots for their proofs
 ///
-/// At ~400ms per slot, 9000 slots ≈ 1 hour minimum epoch duration.
+/// At ~400ms per slot, 9000 slots ≈ 1 hour minimum epoch duration. This is the
+/// default and floor of the `NullifierEpochMinSlots` global parameter.
 pub const MIN_SLOTS_PER_NULLIFIER_EPOCH: u64 = 9000;
 
 /// Indexed leaf in the nullifier tree.
@@ -278,6 +288,27 @@ impl NullifierIndexedTree {
     pub fn remaining_capacity(&self) -> u64 {
         self.capacity().saturating_sub(self.next_index)
     }
+
+    /// Latest epoch `earliest_provable_epoch` may reach: the newest
+    /// `MIN_PROVABLE_NULLIFIER_EPOCHS` epochs always stay provable.
+    pub fn max_earliest_provable_epoch(&self) -> u64 {
+        self.current_epoch
+            .saturating_sub(MIN_PROVABLE_NULLIFIER_EPOCHS)
+    }
+
+    /// Epoch `earliest_provable_epoch` moves to when advanced by up to
+    /// `max_epochs`.
+    ///
+    /// The step is capped at [`MAX_EARLIEST_EPOCH_ADVANCE`] and the result at
+    /// [`Self::max_earliest_provable_epoch`]; it never moves backwards, so a
+    /// tree that is already caught up stays where it is.
+    pub fn earliest_provable_epoch_target(&self, max_epochs: u64) -> u64 {
+        let step = max_epochs.min(MAX_EARLIEST_EPOCH_ADVANCE);
+        self.earliest_provable_epoch
+            .saturating_add(step)
+            .min(self.max_earliest_provable_epoch())
+            .max(self.earliest_provable_epoch)
+    }
 }
 
 #[cfg(test)]
@@ -294,6 +325,50 @@ mod tests {
         assert_eq!(genesis.next_index, 0);
     }
 
+    fn tree_at(current_epoch: u64, earliest_provable_epoch: u64) -> NullifierIndexedTree {
+        let mut tree = NullifierIndexedTree::zeroed();
+        tree.current_epoch = current_epoch;
+        tree.earliest_provable_epoch = earliest_provable_epoch;
+        tree
+    }
+
+    #[test]
+    fn test_earliest_provable_epoch_target_multi_step() {
+        // 100 epochs behind: each call advances by at most MAX_EARLIEST_EPOCH_ADVANCE
+        let tree = tree_at(MIN_PROVABLE_NULLIFIER_EPOCHS + 101, 1);
+        assert_eq!(tree.earliest_provable_epoch_target(1), 2);
+        assert_eq!(tree.earliest_provable_epoch_target(5), 6);
+        assert_eq!(
+            tree.earliest_provable_epoch_target(u64::MAX),
+            1 + MAX_EARLIEST_EPOCH_ADVANCE
+        );
+    }
+
+    #[test]
+    fn test_earliest_provable_epoch_target_clamped_to_provable_window() {
+        // Only 3 epochs can go before the window would shrink below the minimum
+        let tree = tree_at(MIN_PROVABLE_NULLIFIER_EPOCHS + 4, 1);
+        assert_eq!(tree.max_earliest_provable_epoch(), 4);
+        assert_eq!(tree.earliest_provable_epoch_target(10), 4);
+
+        // Too young a tree to advance at all
+        let tree = tree_at(MIN_PROVABLE_NULLIFIER_EPOCHS - 1, 1);
+        assert_eq!(tree.max_earliest_provable_epoch(), 0);
+        assert_eq!(tree.earliest_provable_epoch_target(10), 1);
+    }
+
+    #[test]
+    fn test_earliest_provable_epoch_target_noop_when_caught_up() {
+        let tree = tree_at(MIN_PROVABLE_NULLIFIER_EPOCHS + 50, 50);
+        assert_eq!(
+            tree.earliest_provable_epoch_target(MAX_EARLIEST_EPOCH_ADVANCE),
+            50
+        );
+        // Asking for zero epochs is also a no-op
+        let tree = tree_at(MIN_PROVABLE_NULLIFIER_EPOCHS + 50, 10);
+        assert_eq!(tree.earliest_provable_epoch_target(0), 10);
+    }
+
     #[test]
     fn test_max_nullifier_value() {
         // Ensure MAX_NULLIFIER_VALUE is less than BN254 field modulus (big-endian)
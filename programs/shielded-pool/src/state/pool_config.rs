@@ -1,7 +1,8 @@
 //! Hub's PoolConfig account for routing pool operations.
 //!
 //! This is a lightweight routing configuration that the hub uses to:
-//! 1. Identify the pool type (Token or UnifiedSol)
+//! 1. Identify the pool kind, which selects the hub's routing strategy from
+//!    the [pool registry](crate::pool_registry)
 //! 2. Get the pool program ID to CPI to
 //! 3. Match asset_ids from ZK proofs
 //!
@@ -10,8 +11,27 @@
 
 use panchor::prelude::*;
 use pinocchio::pubkey::Pubkey;
+use zorb_pool_interface::PoolKind;
 
-use crate::state::ShieldedPoolAccount;
+use crate::{errors::ShieldedPoolError, state::ShieldedPoolAccount};
+
+/// Slots between `DeregisterPool` and the earliest `ClosePoolConfig` (~24 hours at 400ms slots).
+///
+/// Gives clients and indexers time to observe the deregistration before the
+/// PDA disappears (and becomes available for re-registration).
+pub const POOL_CONFIG_CLOSE_GRACE_SLOTS: u64 = 216_000;
+
+/// `PoolConfig.mode` bit allowing withdrawals (ext_amount < 0).
+///
+/// Bit 0 is the former `is_active` flag, so configs written before directional
+/// modes keep letting users exit but need deposits re-enabled explicitly.
+pub const POOL_MODE_WITHDRAWALS_ENABLED: u8 = 1 << 0;
+
+/// `PoolConfig.mode` bit allowing deposits (ext_amount > 0).
+pub const POOL_MODE_DEPOSITS_ENABLED: u8 = 1 << 1;
+
+/// `PoolConfig.mode` with both directions enabled (fully active pool).
+pub const POOL_MODE_ALL: u8 = POOL_MODE_DEPOSITS_ENABLED | POOL_MODE_WITHDRAWALS_ENABLED;
 
 /// Pool type discriminator for routing.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -71,9 +91,9 @@ impl PoolType {
 /// `["pool_config", asset_id]`
 ///
 /// # Account Layout (on-chain)
-/// `[8-byte discriminator][72-byte struct data]`
+/// `[8-byte discriminator][80-byte struct data]`
 ///
-/// Total on-chain size: 80 bytes
+/// Total on-chain size: 88 bytes
 #[account(ShieldedPoolAccount::PoolConfig)]
 #[repr(C)]
 pub struct PoolConfig {
@@ -86,25 +106,84 @@ pub struct PoolConfig {
     // === Metadata (8 bytes) ===
     /// Pool type (0 = Token, 1 = UnifiedSol)
     pub pool_type: u8,
-    /// Whether the pool is active (0 = inactive, 1 = active)
-    pub is_active: u8,
+    /// Enabled directions (`POOL_MODE_DEPOSITS_ENABLED | POOL_MODE_WITHDRAWALS_ENABLED`)
+    pub mode: u8,
     /// PDA bump seed
     pub bump: u8,
+    /// Whether the pool was removed from the hub via `DeregisterPool`.
+    /// Deregistered configs are never reactivated; they can only be closed.
+    pub is_deregistered: u8,
+    /// Pool registry key (`PoolKind`); 0 for configs registered before it
+    /// existed, which resolve it from `pool_type`
+    pub pool_kind: u16,
     /// Padding for alignment
-    pub _padding: [u8; 5],
+    pub _padding: [u8; 2],
+
+    // === Deregistration (8 bytes) ===
+    /// First slot at which `ClosePoolConfig` may close this account
+    /// (0 unless deregistered)
+    pub closable_at_slot: u64,
 }
 
 impl PoolConfig {
     /// Account size in bytes (including 8-byte discriminator)
     pub const SIZE: usize = 8 + core::mem::size_of::<Self>();
 
-    /// Returns true if the pool is active.
-    ///
-    /// Note: Named `active()` instead of `is_active()` to avoid shadowing
-    /// the `is_active` field.
+    /// Returns true if the pool accepts transactions in at least one direction.
     #[inline]
     pub fn active(&self) -> bool {
-        self.is_active != 0
+        self.mode & POOL_MODE_ALL != 0
+    }
+
+    /// Returns true if deposits are enabled.
+    #[inline]
+    pub fn deposits_enabled(&self) -> bool {
+        self.mode & POOL_MODE_DEPOSITS_ENABLED != 0
+    }
+
+    /// Returns true if withdrawals are enabled.
+    #[inline]
+    pub fn withdrawals_enabled(&self) -> bool {
+        self.mode & POOL_MODE_WITHDRAWALS_ENABLED != 0
+    }
+
+    /// Returns true if `mode` only contains known direction bits.
+    #[inline]
+    pub fn is_valid_mode(mode: u8) -> bool {
+        mode & !POOL_MODE_ALL == 0
+    }
+
+    /// Reject a public slot whose direction is disabled by `mode`.
+    ///
+    /// Deposits (`ext_amount > 0`) need `POOL_MODE_DEPOSITS_ENABLED`, withdrawals
+    /// (`ext_amount < 0`) need `POOL_MODE_WITHDRAWALS_ENABLED`.
+    #[inline]
+    pub fn require_direction_enabled(&self, ext_amount: i64) -> Result<(), ShieldedPoolError> {
+        if ext_amount > 0 && !self.deposits_enabled() {
+            return Err(ShieldedPoolError::PoolDepositsDisabled);
+        }
+        if ext_amount < 0 && !self.withdrawals_enabled() {
+            return Err(ShieldedPoolError::PoolWithdrawalsDisabled);
+        }
+        Ok(())
+    }
+
+    /// Returns true if the pool has been deregistered from the hub.
+    #[inline]
+    pub fn deregistered(&self) -> bool {
+        self.is_deregistered != 0
+    }
+
+    /// Reject routing to a deregistered pool.
+    ///
+    /// Used by every asset lookup in `ExecuteTransact` so that a deregistered
+    /// asset fails with a dedicated error instead of a generic config error.
+    #[inline]
+    pub fn require_registered(&self) -> Result<(), ShieldedPoolError> {
+        if self.deregistered() {
+            return Err(ShieldedPoolError::PoolDeregistered);
+        }
+        Ok(())
     }
 
     /// Get the pool type
@@ -113,6 +192,17 @@ impl PoolConfig {
         PoolType::from_u8(self.pool_type)
     }
 
+    /// Get the pool kind, falling back to the one implied by `pool_type` for
+    /// configs registered before `pool_kind` was stored.
+    #[inline]
+    pub fn pool_kind(&self) -> PoolKind {
+        if self.pool_kind != PoolKind::UNSET.0 {
+            return PoolKind(self.pool_kind);
+        }
+        zorb_pool_interface::PoolType::from_u8(self.pool_type)
+            .map_or(PoolKind::UNSET, PoolKind::from_pool_type)
+    }
+
     /// Get the pool program ID
     #[inline]
     pub fn pool_program(&self) -> &Pubkey {
@@ -155,8 +245,91 @@ mod tests {
 
     #[test]
     fn test_pool_config_size() {
-        // 8 (discriminator) + 1 + 1 + 1 + 5 (padding) + 32 + 32 = 80
-        assert_eq!(PoolConfig::SIZE, 80);
+        // 8 (discriminator) + 32 + 32 + 1 + 1 + 1 + 1 + 2 + 2 (padding) + 8 = 88
+        assert_eq!(PoolConfig::SIZE, 88);
+    }
+
+    #[test]
+    fn test_pool_kind() {
+        let mut config = PoolConfig::zeroed();
+        config.pool_kind = PoolKind::UNIFIED_SOL.0;
+        config.pool_type = PoolType::UnifiedSol as u8;
+        assert_eq!(config.pool_kind(), PoolKind::UNIFIED_SOL);
+
+        // Configs registered before pool_kind resolve it from pool_type
+        config.pool_kind = 0;
+        assert_eq!(config.pool_kind(), PoolKind::UNIFIED_SOL);
+        config.pool_type = PoolType::Token as u8;
+        assert_eq!(config.pool_kind(), PoolKind::TOKEN);
+        config.pool_type = 2;
+        assert_eq!(config.pool_kind(), PoolKind::UNSET);
+
+        // A stored kind wins, registered or not
+        config.pool_kind = 0x8000;
+        assert_eq!(config.pool_kind(), PoolKind(0x8000));
+    }
+
+    #[test]
+    fn test_require_registered() {
+        let mut config = PoolConfig::zeroed();
+        assert_eq!(config.require_registered(), Ok(()));
+
+        config.is_deregistered = 1;
+        assert_eq!(
+            config.require_registered(),
+            Err(ShieldedPoolError::PoolDeregistered)
+        );
+    }
+
+    #[test]
+    fn test_require_direction_enabled() {
+        let mut config = PoolConfig::zeroed();
+        let deposit = 1_000i64;
+        let withdrawal = -1_000i64;
+
+        // Both directions disabled
+        config.mode = 0;
+        assert!(!config.active());
+        assert_eq!(
+            config.require_direction_enabled(deposit),
+            Err(ShieldedPoolError::PoolDepositsDisabled)
+        );
+        assert_eq!(
+            config.require_direction_enabled(withdrawal),
+            Err(ShieldedPoolError::PoolWithdrawalsDisabled)
+        );
+
+        // Deposits only
+        config.mode = POOL_MODE_DEPOSITS_ENABLED;
+        assert_eq!(config.require_direction_enabled(deposit), Ok(()));
+        assert_eq!(
+            config.require_direction_enabled(withdrawal),
+            Err(ShieldedPoolError::PoolWithdrawalsDisabled)
+        );
+
+        // Withdrawals only (incident mode: users can exit)
+        config.mode = POOL_MODE_WITHDRAWALS_ENABLED;
+        assert_eq!(
+            config.require_direction_enabled(deposit),
+            Err(ShieldedPoolError::PoolDepositsDisabled)
+        );
+        assert_eq!(config.require_direction_enabled(withdrawal), Ok(()));
+
+        // Both directions enabled
+        config.mode = POOL_MODE_ALL;
+        assert!(config.active());
+        assert_eq!(config.require_direction_enabled(deposit), Ok(()));
+        assert_eq!(config.require_direction_enabled(withdrawal), Ok(()));
+    }
+
+    #[test]
+    fn test_is_valid_mode() {
+        assert!(PoolConfig::is_valid_mode(0));
+        assert!(PoolConfig::is_valid_mode(POOL_MODE_DEPOSITS_ENABLED));
+        assert!(PoolConfig::is_valid_mode(POOL_MODE_WITHDRAWALS_ENABLED));
+        assert!(PoolConfig::is_valid_mode(POOL_MODE_ALL));
+        assert!(!PoolConfig::is_valid_mode(1 << 2));
+        assert!(!PoolConfig::is_valid_mode(u8::MAX));
     }
 
     #[test]
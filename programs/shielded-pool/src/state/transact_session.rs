@@ -8,8 +8,10 @@ use pinocchio_contrib::AccountAssertions;
 use crate::state::ShieldedPoolAccount;
 
 /// Header size for TransactSession (on-chain)
-/// discriminator(8) + authority(32) + nonce(8) + created_slot(8) + data_len(4) + bump(1) + padding(3) = 64 bytes
-pub const TRANSACT_SESSION_HEADER_SIZE: usize = 64;
+/// discriminator(8) + authority(32) + nonce(8) + created_slot(8) + data_len(4) + bump(1)
+/// + proof_format(1) + executed(1) + padding(1) + bytes_received(4) + reserved(4)
+/// + body_hash(32) = 104 bytes
+pub const TRANSACT_SESSION_HEADER_SIZE: usize = 104;
 
 /// Duration in slots after which anyone can close a session
 /// ~400ms per slot, so 24 hours ≈ 216,000 slots
@@ -19,6 +21,31 @@ pub const SESSION_EXPIRY_SLOTS: u64 = 216_000;
 /// Limits proof + params + encrypted outputs to 4KB.
 pub const MAX_SESSION_DATA_LEN: u32 = 4096;
 
+/// Encoding of the Groth16 proof points uploaded to a transact session.
+///
+/// Stored in [`TransactSession::proof_format`]. Determines how
+/// `parse_session_data` lays out the session body.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProofFormat {
+    /// Compressed points embedded in `TransactProofData` (32-byte G1, 64-byte G2).
+    Compressed = 0,
+    /// Uncompressed points (64-byte G1, 128-byte G2) prefixed to the body.
+    /// The compressed point fields of `TransactProofData` must be zero.
+    Uncompressed = 1,
+}
+
+impl ProofFormat {
+    /// Convert from u8, returning None for invalid values.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(ProofFormat::Compressed),
+            1 => Some(ProofFormat::Uncompressed),
+            _ => None,
+        }
+    }
+}
+
 /// Transact session account for splitting transact into multiple transactions.
 ///
 /// This account stores raw transaction data uploaded in chunks.
@@ -29,7 +56,7 @@ pub const MAX_SESSION_DATA_LEN: u32 = 4096;
 /// Re-execution is prevented by closing the account (reclaiming rent).
 ///
 /// # Account Layout (on-chain)
-/// `[8-byte discriminator][56-byte struct][variable-length data]`
+/// `[8-byte discriminator][96-byte struct][variable-length data]`
 ///
 /// Seeds: ["transact_session", authority, nonce]
 #[account(ShieldedPoolAccount::TransactSession)]
@@ -45,8 +72,19 @@ pub struct TransactSession {
     pub data_len: u32,
     /// PDA bump seed
     pub bump: u8,
+    /// Proof point encoding of the uploaded body (see [`ProofFormat`])
+    pub proof_format: u8,
+    /// Set to 1 once `execute_transact` has succeeded with this session
+    pub executed: u8,
     /// Padding for alignment
-    pub _padding: [u8; 3],
+    pub _padding: [u8; 1],
+    /// Total chunk bytes uploaded so far (overwrites are counted again)
+    pub bytes_received: u32,
+    /// Reserved for future use
+    pub _reserved: [u8; 4],
+    /// Expected SHA256 of the full body, declared by the client at init.
+    /// `execute_transact` rejects the session if the uploaded bytes differ.
+    pub body_hash: [u8; 32],
     // Data follows immediately after (variable length, borsh-serialized)
 }
 
@@ -74,7 +112,7 @@ impl TransactSession {
 
     /// Load account and return header + raw body bytes.
     ///
-    /// Returns the header (copied, 56 bytes) and a Ref to the body data.
+    /// Returns the header (copied, 96 bytes) and a Ref to the body data.
     /// The body contains: Proof + TransactParams + NullifierNMProof + encrypted_outputs.
     pub fn load_body<'a>(
         account: &'a AccountInfo,
@@ -93,7 +131,7 @@ impl TransactSession {
             return Err(ShieldedPoolError::InvalidDiscriminator.into());
         }
 
-        // Copy header (56 bytes at offset 8)
+        // Copy header (96 bytes at offset 8)
         let header = *bytemuck::from_bytes::<Self>(&data[8..TRANSACT_SESSION_HEADER_SIZE]);
 
         // Return header and mapped Ref to body
@@ -130,6 +168,7 @@ impl TransactSession {
     ///
     /// Writes discriminator and header fields. Body data should be
     /// written separately via upload_transact_chunk.
+    #[allow(clippy::too_many_arguments)]
     pub fn init_account(
         account: &AccountInfo,
         authority: &Pubkey,
@@ -137,6 +176,8 @@ impl TransactSession {
         data_len: u32,
         bump: u8,
         created_slot: u64,
+        proof_format: ProofFormat,
+        body_hash: [u8; 32],
     ) -> ProgramResult {
         let mut data = account.try_borrow_mut_data()?;
 
@@ -150,8 +191,38 @@ impl TransactSession {
         header.created_slot = created_slot;
         header.data_len = data_len;
         header.bump = bump;
-        header._padding = [0u8; 3];
+        header.proof_format = proof_format as u8;
+        header.executed = 0;
+        header._padding = [0u8; 1];
+        header.bytes_received = 0;
+        header._reserved = [0u8; 4];
+        header.body_hash = body_hash;
 
         Ok(())
     }
+
+    /// Slot from which anyone may close the session.
+    #[inline]
+    pub const fn expiry_slot(&self) -> u64 {
+        self.created_slot.saturating_add(SESSION_EXPIRY_SLOTS)
+    }
+
+    /// Whether the session has been consumed by `execute_transact`.
+    #[inline]
+    pub const fn is_executed(&self) -> bool {
+        self.executed != 0
+    }
+
+    /// Mutably borrow the header of an already validated session account.
+    pub fn header_mut<'a>(
+        account: &'a AccountInfo,
+    ) -> Result<pinocchio::account_info::RefMut<'a, Self>, ProgramError> {
+        let data = account.try_borrow_mut_data()?;
+        if data.len() < TRANSACT_SESSION_HEADER_SIZE {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(pinocchio::account_info::RefMut::map(data, |d| {
+            bytemuck::from_bytes_mut::<Self>(&mut d[8..TRANSACT_SESSION_HEADER_SIZE])
+        }))
+    }
 }
@@ -18,8 +18,8 @@ pub const SPL_TOKEN_PROGRAM_ID: Pubkey = [
 
 /// SPL Token-2022 Program ID
 pub const SPL_TOKEN_2022_PROGRAM_ID: Pubkey = [
-    0x06, 0xa7, 0xd5, 0x17, 0x18, 0x7b, 0xd1, 0x65, 0x35, 0x50, 0xc4, 0x9a, 0x3a, 0x8b, 0x9a, 0x28,
-    0xb9, 0x51, 0x9f, 0x60, 0x7d, 0x1f, 0x55, 0xb8, 0x26, 0xb4, 0x53, 0x06, 0x76, 0x8b, 0x9f, 0x71,
+    0x06, 0xdd, 0xf6, 0xe1, 0xee, 0x75, 0x8f, 0xde, 0x18, 0x42, 0x5d, 0xbc, 0xe4, 0x6c, 0xcd, 0xda,
+    0xb6, 0x1a, 0xfc, 0x4d, 0x83, 0xb9, 0x0d, 0x27, 0xfe, 0xbd, 0xf9, 0x28, 0xd8, 0xa1, 0x8b, 0xfc,
 ];
 
 /// Associated Token Account Program ID
@@ -264,21 +264,3 @@ pub fn find_associated_token_address(
         &ASSOCIATED_TOKEN_PROGRAM_ID,
     )
 }
-
-/// Verify that a token account is the canonical ATA for the given wallet and mint.
-///
-/// Returns Ok(()) if the token_account address matches the derived ATA,
-/// or InvalidAccountData if it doesn't.
-pub fn require_associated_token_account(
-    token_account: &AccountInfo,
-    wallet_address: &Pubkey,
-    mint_address: &Pubkey,
-    token_program_id: &Pubkey,
-) -> Result<(), ProgramError> {
-    let (expected_ata, _) =
-        find_associated_token_address(wallet_address, mint_address, token_program_id);
-    if token_account.key() != &expected_ata {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    Ok(())
-}
@@ -1,4 +1,4 @@
-use crate::groth16::{CompressedGroth16Proof, Groth16Verifyingkey, verify_groth16};
+use crate::groth16::{Groth16ProofPoints, Groth16Verifyingkey, verify_groth16_points};
 use crate::{errors::ShieldedPoolError, instructions::TransactProofData};
 use alloc::vec::Vec;
 use ark_bn254::Fr;
@@ -12,12 +12,11 @@ use pinocchio::{
     pubkey::Pubkey,
 };
 use pinocchio_log::log;
-use solana_poseidon::{Endianness, Parameters, hashv};
 use solana_program::hash::hash;
-
-/// Basis points denominator (100% = 10000 basis points).
-/// Used for fee rate calculations where rates are specified in basis points (e.g., 100 = 1%).
-pub const BASIS_POINTS_DENOMINATOR: u128 = 10_000;
+use zorb_pool_interface::{
+    BASIS_POINTS, asset_ids,
+    fee::{Rounding, compute_fee},
+};
 
 /// WSOL mint address on Solana mainnet.
 /// This is the canonical wrapped SOL mint used for the unified SOL asset.
@@ -31,8 +30,9 @@ pub use unified_sol_pool::UNIFIED_SOL_ASSET_ID;
 
 /// Returns the unified SOL asset ID constant.
 /// This is used to detect whether a transaction is operating on the unified SOL pool.
+#[deprecated(note = "use zorb_pool_interface::asset_ids::compute_unified_sol_asset_id")]
 pub fn compute_unified_sol_asset_id() -> [u8; 32] {
-    UNIFIED_SOL_ASSET_ID
+    asset_ids::compute_unified_sol_asset_id()
 }
 
 /// Asset ID required for relayer fee payments.
@@ -228,11 +228,15 @@ pub fn check_public_amount_unified(
 
 /// Internal helper to validate provided fee against calculated expected fee.
 ///
+/// The expected fee is the one the pool charges, from
+/// [`compute_fee`] with the pool's `rounding`.
+///
 /// Returns Ok(()) if provided_fee >= expected_fee, error otherwise.
 #[inline]
 fn validate_fee_amount(
-    fee_base: u128,
-    fee_rate: u128,
+    fee_base: u64,
+    fee_rate: u16,
+    rounding: Rounding,
     provided_fee: u64,
     log_prefix: &str,
 ) -> Result<(), ProgramError> {
@@ -241,18 +245,15 @@ fn validate_fee_amount(
         return Ok(());
     }
 
-    let expected_fee = fee_base
-        .checked_mul(fee_rate)
-        .ok_or(ShieldedPoolError::ArithmeticOverflow)?
-        .checked_div(BASIS_POINTS_DENOMINATOR)
-        .ok_or(ShieldedPoolError::ArithmeticOverflow)? as u64;
+    let expected_fee =
+        compute_fee(fee_base, fee_rate, rounding).ok_or(ShieldedPoolError::ArithmeticOverflow)?;
 
     log!(
         "{}: expected_fee = {} * {} / {} = {}",
         log_prefix,
         fee_base,
         fee_rate,
-        BASIS_POINTS_DENOMINATOR,
+        BASIS_POINTS,
         expected_fee
     );
 
@@ -278,12 +279,16 @@ fn validate_fee_amount(
 /// - Transfer (ext_amount = 0): fee = 0 (no boundary crossing)
 ///
 /// Relayer fee is a derived split of |ext_amount|, not part of the fee base.
+///
+/// The division rounds up when `round_fees_up` is set, matching the token
+/// pool's `TokenPoolConfig.round_fees_up`, and down otherwise.
 pub fn validate_fee(
     ext_amount: i64,
     provided_fee: u64,
     _relayer_fee: u64, // Unused - relayer_fee is derived from ext_amount, not additive
     deposit_fee_rate: u16,
     withdrawal_fee_rate: u16,
+    round_fees_up: bool,
 ) -> Result<(), ProgramError> {
     log!(
         "validate_fee: ext_amount={}, provided_fee={}, deposit_fee_rate={}, withdrawal_fee_rate={}",
@@ -295,22 +300,28 @@ pub fn validate_fee(
 
     let (fee_base, fee_rate) = if ext_amount > 0 {
         log!("validate_fee: DEPOSIT case (ext_amount > 0)");
-        (ext_amount as u128, deposit_fee_rate as u128)
+        (ext_amount as u64, deposit_fee_rate)
     } else if ext_amount < 0 {
         let abs_ext_amount = ext_amount
             .checked_neg()
-            .ok_or(ShieldedPoolError::ArithmeticOverflow)? as u128;
+            .ok_or(ShieldedPoolError::ArithmeticOverflow)? as u64;
         log!(
             "validate_fee: WITHDRAWAL case (ext_amount < 0), fee_base = {}",
             abs_ext_amount
         );
-        (abs_ext_amount, withdrawal_fee_rate as u128)
+        (abs_ext_amount, withdrawal_fee_rate)
     } else {
         log!("validate_fee: TRANSFER case (ext_amount == 0), fee_base = 0");
-        (0u128, 0u128)
+        (0, 0)
     };
 
-    validate_fee_amount(fee_base, fee_rate, provided_fee, "validate_fee")
+    validate_fee_amount(
+        fee_base,
+        fee_rate,
+        Rounding::from_round_up(round_fees_up),
+        provided_fee,
+        "validate_fee",
+    )
 }
 
 /// Validates fee for unified SOL pools.
@@ -352,6 +363,7 @@ pub fn validate_fee_unified(
         let s = (ext_amount as u128)
             .checked_mul(exchange_rate as u128)
             .and_then(|v| v.checked_div(rate_precision as u128))
+            .and_then(|v| u64::try_from(v).ok())
             .ok_or(ShieldedPoolError::ArithmeticOverflow)?;
         log!(
             "validate_fee_unified: DEPOSIT case, s = φ(e) = {} * {} / {} = {}",
@@ -360,22 +372,28 @@ pub fn validate_fee_unified(
             rate_precision,
             s
         );
-        (s, deposit_fee_rate as u128)
+        (s, deposit_fee_rate)
     } else if ext_amount < 0 {
         let provided_public_amount = Fr::from_be_bytes_mod_order(&public_amount_bytes);
         let s_bigint = (-provided_public_amount).into_bigint();
-        let s = s_bigint.0[0] as u128;
+        let s = s_bigint.0[0];
         log!(
             "validate_fee_unified: WITHDRAWAL case, s = |public_amount| = {}",
             s
         );
-        (s, withdrawal_fee_rate as u128)
+        (s, withdrawal_fee_rate)
     } else {
         log!("validate_fee_unified: TRANSFER case, fee_base = 0");
-        (0u128, 0u128)
+        (0, 0)
     };
 
-    validate_fee_amount(fee_base, fee_rate, provided_fee, "validate_fee_unified")
+    validate_fee_amount(
+        fee_base,
+        fee_rate,
+        Rounding::Down,
+        provided_fee,
+        "validate_fee_unified",
+    )
 }
 
 use crate::instructions::types::{N_INS, N_OUTS, N_PUBLIC_LINES, N_REWARD_LINES};
@@ -395,8 +413,15 @@ pub const N_PUBLIC_INPUTS: usize =
 
 /// Verify a ZK proof with the given verifying key.
 /// Takes proof by reference to avoid ~800 byte stack copy.
+///
+/// Public inputs are taken from `proof`; the Groth16 points come from `points`,
+/// which may be either the compressed fields of `proof` or an uncompressed prefix.
 #[inline(never)]
-pub fn verify_proof(proof: &TransactProofData, verifying_key: Groth16Verifyingkey) -> bool {
+pub fn verify_proof(
+    proof: &TransactProofData,
+    points: &Groth16ProofPoints,
+    verifying_key: &Groth16Verifyingkey,
+) -> bool {
     // Build public inputs array matching circuit order
     let mut public_inputs: [[u8; 32]; N_PUBLIC_INPUTS] = [[0u8; 32]; N_PUBLIC_INPUTS];
     let mut idx = 0;
@@ -433,13 +458,59 @@ pub fn verify_proof(proof: &TransactProofData, verifying_key: Groth16Verifyingke
     public_inputs[idx..idx + N_REWARD_LINES].copy_from_slice(&proof.reward_asset_id);
 
     // Use shared verification helper
-    let compressed = CompressedGroth16Proof {
-        proof_a: &proof.proof_a,
-        proof_b: &proof.proof_b,
-        proof_c: &proof.proof_c,
-    };
+    verify_groth16_points(points, &public_inputs, verifying_key).unwrap_or(false)
+}
 
-    verify_groth16(&compressed, &public_inputs, &verifying_key).unwrap_or(false)
+/// BN254 scalar field modulus `r` (big-endian).
+pub const FR_MODULUS_BE: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+/// Check that a 32-byte big-endian value is a canonical BN254 scalar field element.
+///
+/// Values `>= r` alias `value mod r` inside the circuit while on-chain bookkeeping
+/// uses the raw bytes, so they are rejected outright.
+#[inline]
+pub fn validate_fr_canonical(bytes: &[u8; 32]) -> Result<(), ShieldedPoolError> {
+    if *bytes < FR_MODULUS_BE {
+        Ok(())
+    } else {
+        Err(ShieldedPoolError::NonCanonicalFieldElement)
+    }
+}
+
+/// Validate that every transact public input is a canonical field element.
+///
+/// Checks inputs in circuit order (see [`N_PUBLIC_INPUTS`]) and logs the first
+/// offending input as `name[index]`.
+pub fn validate_public_inputs_canonical(proof: &TransactProofData) -> Result<(), ProgramError> {
+    let groups: [(&str, &[[u8; 32]]); 8] = [
+        (
+            "commitment_root",
+            core::slice::from_ref(&proof.commitment_root),
+        ),
+        (
+            "transact_params_hash",
+            core::slice::from_ref(&proof.transact_params_hash),
+        ),
+        ("public_asset_ids", &proof.public_asset_ids),
+        ("public_amounts", &proof.public_amounts),
+        ("nullifiers", &proof.nullifiers),
+        ("commitments", &proof.commitments),
+        ("reward_acc", &proof.reward_acc),
+        ("reward_asset_id", &proof.reward_asset_id),
+    ];
+
+    for (name, values) in groups {
+        for (i, value) in values.iter().enumerate() {
+            validate_fr_canonical(value).inspect_err(|_| {
+                log!("non-canonical public input: {}[{}]", name, i);
+            })?;
+        }
+    }
+
+    Ok(())
 }
 
 use crate::instructions::TransactParams;
@@ -459,6 +530,16 @@ pub fn calculate_transact_params_hash(transact_params: &TransactParams) -> [u8;
     sha256(bytemuck::bytes_of(transact_params))
 }
 
+/// Reduces a transact params hash into the BN254 scalar field.
+///
+/// The digest is read little-endian. The proof carries the same value as a
+/// big-endian public input, so P8 compares this against
+/// `Fr::from_be_bytes_mod_order(&proof.transact_params_hash)`.
+#[inline]
+pub fn transact_params_hash_to_fr(transact_params_hash: &[u8; 32]) -> Fr {
+    Fr::from_le_bytes_mod_order(transact_params_hash)
+}
+
 pub fn change_endianness(bytes: &[u8]) -> Vec<u8> {
     let mut vec = Vec::new();
     for b in bytes.chunks(32) {
@@ -479,36 +560,15 @@ pub fn sha256(data: &[u8]) -> [u8; 32] {
 
 /// Computes an asset ID from raw mint bytes using Poseidon hash.
 ///
-/// The mint is stored as little-endian bytes. We split into two 128-bit limbs:
-/// - Low limb:  bytes[0..16]  (least significant 128 bits)
-/// - High limb: bytes[16..32] (most significant 128 bits)
-///
-/// Each limb is converted from little-endian to big-endian for Poseidon hashing.
+/// Delegates to [`asset_ids::compute_token_asset_id`], the canonical derivation.
 ///
 /// # Errors
 /// Returns `ShieldedPoolError::AssetIdComputationFailed` if Poseidon hashing fails.
+#[deprecated(note = "use zorb_pool_interface::asset_ids::compute_token_asset_id")]
 pub fn compute_asset_id_from_bytes(mint_bytes: &[u8; 32]) -> Result<[u8; 32], ProgramError> {
-    // Split mint into two 128-bit limbs (little-endian source)
-    // Low limb: bytes 0-15, High limb: bytes 16-31
-    let mut low_limb = [0u8; 32];
-    let mut high_limb = [0u8; 32];
-
-    // Convert each 16-byte little-endian chunk to 32-byte big-endian field element
-    // Reverse bytes and place in low 16 bytes of the 32-byte array
-    for i in 0..16 {
-        low_limb[31 - i] = mint_bytes[i]; // Reverse bytes[0..16] -> positions [16..32]
-        high_limb[31 - i] = mint_bytes[16 + i]; // Reverse bytes[16..32] -> positions [16..32]
-    }
-
     // AUDIT FIX (H-01): Return Result instead of panicking on hash failure.
-    // Hash both limbs: Poseidon(low_limb, high_limb)
-    let hash_result = hashv(
-        Parameters::Bn254X5,
-        Endianness::BigEndian,
-        &[&low_limb, &high_limb],
-    )
-    .map_err(|_| ShieldedPoolError::AssetIdComputationFailed)?;
-    Ok(hash_result.to_bytes())
+    asset_ids::compute_token_asset_id(mint_bytes)
+        .ok_or_else(|| ShieldedPoolError::AssetIdComputationFailed.into())
 }
 
 /// Verifies that a nullifier account PDA is correctly derived and has not been used before
@@ -604,278 +664,87 @@ pub fn log_bytes_as_hex(label: &str, bytes: &[u8; 32]) {
 
 #[cfg(test)]
 pub mod test {
-    use crate::utils::compute_asset_id_from_bytes;
-    use std::println;
-
-    #[test]
-    fn test_asset_id_deterministic() {
-        // Use a fixed mint address for reproducibility
-        let mint_bytes: [u8; 32] = [
-            0x06, 0xa7, 0xd5, 0x17, 0x18, 0x7b, 0xd1, 0x63, 0x35, 0xb7, 0xd6, 0xb8, 0x7a, 0x67,
-            0x6c, 0x55, 0x26, 0x3a, 0x6c, 0x86, 0x40, 0xf4, 0xd1, 0x6e, 0x86, 0xf8, 0x24, 0x85,
-            0x05, 0x9d, 0x65, 0x38,
-        ];
-
-        // Compute asset ID multiple times - should always be the same
-        let asset_id_1 = compute_asset_id_from_bytes(&mint_bytes).unwrap();
-        let asset_id_2 = compute_asset_id_from_bytes(&mint_bytes).unwrap();
-        let asset_id_3 = compute_asset_id_from_bytes(&mint_bytes).unwrap();
-
-        assert_eq!(asset_id_1, asset_id_2, "Asset ID should be deterministic");
-        assert_eq!(asset_id_2, asset_id_3, "Asset ID should be deterministic");
-
-        println!("Asset ID: {:02x?}", asset_id_1);
+    use zorb_pool_interface::asset_ids::compute_token_asset_id;
+
+    use super::{FR_MODULUS_BE, validate_fr_canonical, validate_public_inputs_canonical};
+    use crate::errors::ShieldedPoolError;
+    use crate::instructions::TransactProofData;
+    use ark_ff::BigInteger;
+    use bytemuck::Zeroable;
+
+    /// Add a small signed offset to a big-endian 256-bit value.
+    fn offset_be(base: [u8; 32], delta: i8) -> [u8; 32] {
+        let value = num_bigint::BigUint::from_bytes_be(&base);
+        let value = if delta >= 0 {
+            value + delta as u32
+        } else {
+            value - (-delta) as u32
+        };
+        let bytes = value.to_bytes_be();
+        let mut out = [0u8; 32];
+        out[32 - bytes.len()..].copy_from_slice(&bytes);
+        out
     }
 
     #[test]
-    fn test_asset_id_different_mints_produce_different_ids() {
-        let mint1_bytes: [u8; 32] = [1u8; 32];
-        let mint2_bytes: [u8; 32] = [2u8; 32];
-
-        let asset_id_1 = compute_asset_id_from_bytes(&mint1_bytes).unwrap();
-        let asset_id_2 = compute_asset_id_from_bytes(&mint2_bytes).unwrap();
-
-        assert_ne!(
-            asset_id_1, asset_id_2,
-            "Different mints should produce different asset IDs"
-        );
+    fn test_fr_modulus_matches_ark() {
+        let modulus = ark_bn254::Fr::MODULUS.to_bytes_be();
+        assert_eq!(modulus.as_slice(), FR_MODULUS_BE.as_slice());
     }
 
     #[test]
-    fn test_asset_id_zero_mint() {
-        // Edge case: zero mint address
-        let zero_mint_bytes: [u8; 32] = [0u8; 32];
-
-        let asset_id = compute_asset_id_from_bytes(&zero_mint_bytes).unwrap();
-
-        // Poseidon hash of two zero limbs produces a specific non-zero value
-        assert_ne!(
-            asset_id, [0u8; 32],
-            "Asset ID of zero mint should not be zero"
-        );
-
-        // Should be deterministic
-        let asset_id_2 = compute_asset_id_from_bytes(&zero_mint_bytes).unwrap();
+    fn test_validate_fr_canonical_boundaries() {
+        assert!(validate_fr_canonical(&[0u8; 32]).is_ok());
+        assert!(validate_fr_canonical(&offset_be(FR_MODULUS_BE, -1)).is_ok());
         assert_eq!(
-            asset_id, asset_id_2,
-            "Asset ID should be deterministic for zero mint"
+            validate_fr_canonical(&FR_MODULUS_BE),
+            Err(ShieldedPoolError::NonCanonicalFieldElement)
         );
-
-        println!("Zero mint asset ID: {:02x?}", asset_id);
-    }
-
-    #[test]
-    fn test_asset_id_small_values() {
-        // Test with small mint values
-        let small_mint_1: [u8; 32] = {
-            let mut arr = [0u8; 32];
-            arr[31] = 1;
-            arr
-        };
-        let small_mint_2: [u8; 32] = {
-            let mut arr = [0u8; 32];
-            arr[31] = 2;
-            arr
-        };
-
-        let asset_id_1 = compute_asset_id_from_bytes(&small_mint_1).unwrap();
-        let asset_id_2 = compute_asset_id_from_bytes(&small_mint_2).unwrap();
-
-        // Different inputs should produce different outputs
-        assert_ne!(asset_id_1, asset_id_2);
-
-        // Each should be deterministic
-        assert_eq!(asset_id_1, compute_asset_id_from_bytes(&small_mint_1).unwrap());
-        assert_eq!(asset_id_2, compute_asset_id_from_bytes(&small_mint_2).unwrap());
-    }
-
-    #[test]
-    fn test_asset_id_collision_resistance() {
-        // Test that similar inputs produce very different outputs (avalanche effect)
-        let mint1: [u8; 32] = [0x01; 32];
-        let mut mint2 = mint1;
-        mint2[0] = 0x02; // Only change first byte
-
-        let asset_id_1 = compute_asset_id_from_bytes(&mint1).unwrap();
-        let asset_id_2 = compute_asset_id_from_bytes(&mint2).unwrap();
-
-        // Count differing bytes - should be significant (avalanche effect)
-        let differing_bytes: usize = asset_id_1
-            .iter()
-            .zip(asset_id_2.iter())
-            .filter(|(a, b)| a != b)
-            .count();
-
-        // With good hash function, roughly half the bytes should differ
-        assert!(
-            differing_bytes > 10,
-            "Poseidon should exhibit avalanche effect, but only {} bytes differ",
-            differing_bytes
+        assert_eq!(
+            validate_fr_canonical(&offset_be(FR_MODULUS_BE, 1)),
+            Err(ShieldedPoolError::NonCanonicalFieldElement)
         );
+        assert!(validate_fr_canonical(&[0xff; 32]).is_err());
     }
 
     #[test]
-    fn test_asset_id_limb_split() {
-        // Test that the 128-bit limb split works correctly
-        // Mint with distinct high and low halves
-        let mint_bytes: [u8; 32] = [
-            // High 128 bits (bytes 0-15)
-            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
-            0x0f, 0x10, // Low 128 bits (bytes 16-31)
-            0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e,
-            0x1f, 0x20,
+    fn test_validate_public_inputs_canonical_checks_every_group() {
+        let r_minus_one = offset_be(FR_MODULUS_BE, -1);
+        let mut proof = TransactProofData::zeroed();
+        proof.nullifiers = [r_minus_one; 4];
+        assert!(validate_public_inputs_canonical(&proof).is_ok());
+
+        let setters: [fn(&mut TransactProofData); 8] = [
+            |p| p.commitment_root = FR_MODULUS_BE,
+            |p| p.transact_params_hash = FR_MODULUS_BE,
+            |p| p.public_asset_ids[0] = FR_MODULUS_BE,
+            |p| p.public_amounts[1] = FR_MODULUS_BE,
+            |p| p.nullifiers[3] = FR_MODULUS_BE,
+            |p| p.commitments[0] = FR_MODULUS_BE,
+            |p| p.reward_acc[0] = FR_MODULUS_BE,
+            |p| p.reward_asset_id[0] = FR_MODULUS_BE,
         ];
-
-        let asset_id = compute_asset_id_from_bytes(&mint_bytes).unwrap();
-
-        // Swapping high and low should produce different result
-        let mut swapped_bytes = [0u8; 32];
-        swapped_bytes[0..16].copy_from_slice(&mint_bytes[16..32]);
-        swapped_bytes[16..32].copy_from_slice(&mint_bytes[0..16]);
-
-        let swapped_asset_id = compute_asset_id_from_bytes(&swapped_bytes).unwrap();
-
-        assert_ne!(
-            asset_id, swapped_asset_id,
-            "Swapping limbs should produce different asset ID"
-        );
-
-        println!("Original asset ID: {:02x?}", asset_id);
-        println!("Swapped asset ID: {:02x?}", swapped_asset_id);
+        for set in setters {
+            let mut proof = TransactProofData::zeroed();
+            set(&mut proof);
+            assert_eq!(
+                validate_public_inputs_canonical(&proof),
+                Err(ShieldedPoolError::NonCanonicalFieldElement.into())
+            );
+        }
     }
 
     #[test]
-    fn test_asset_id_max_values() {
-        // Test with max value (0xFF..FF) - should work without field reduction
-        // since each 128-bit limb is well within the ~254-bit BN254 field
-        let max_value: [u8; 32] = [0xFF; 32];
-        let asset_id = compute_asset_id_from_bytes(&max_value).unwrap();
-
-        // Should produce a valid non-zero hash
-        assert_ne!(
-            asset_id, [0u8; 32],
-            "Max value should produce non-zero asset ID"
-        );
-
-        // Should be deterministic
-        let asset_id_2 = compute_asset_id_from_bytes(&max_value).unwrap();
+    #[allow(deprecated)]
+    fn test_deprecated_asset_id_shims_match_interface() {
+        let mint_bytes = [7u8; 32];
         assert_eq!(
-            asset_id, asset_id_2,
-            "Asset ID should be deterministic for max value"
-        );
-
-        println!("Max value asset ID: {:02x?}", asset_id);
-    }
-
-    #[test]
-    fn test_asset_id_little_endian_interpretation() {
-        // Test that mint bytes are interpreted as little-endian
-        // A small value (1) in little-endian has the 0x01 byte at position 0
-        let mut mint_le: [u8; 32] = [0u8; 32];
-        mint_le[0] = 0x01; // Value = 1 in little-endian (low byte first)
-
-        // Same value in "big-endian position" would be at byte 31
-        let mut mint_be_style: [u8; 32] = [0u8; 32];
-        mint_be_style[31] = 0x01;
-
-        let asset_id_le = compute_asset_id_from_bytes(&mint_le).unwrap();
-        let asset_id_be = compute_asset_id_from_bytes(&mint_be_style).unwrap();
-
-        // These should produce different results since we interpret as little-endian
-        assert_ne!(
-            asset_id_le, asset_id_be,
-            "LE and BE-style positioning should produce different asset IDs"
-        );
-    }
-
-    #[test]
-    fn test_asset_id_low_limb_only() {
-        // Value that only affects the low limb (bytes 0-15)
-        let mut mint_low: [u8; 32] = [0u8; 32];
-        mint_low[0] = 0x42;
-        mint_low[15] = 0x43;
-
-        // Value that only affects the high limb (bytes 16-31)
-        let mut mint_high: [u8; 32] = [0u8; 32];
-        mint_high[16] = 0x42;
-        mint_high[31] = 0x43;
-
-        let asset_id_low = compute_asset_id_from_bytes(&mint_low).unwrap();
-        let asset_id_high = compute_asset_id_from_bytes(&mint_high).unwrap();
-
-        // Low-only and high-only should produce different results
-        assert_ne!(
-            asset_id_low, asset_id_high,
-            "Low-limb-only and high-limb-only should produce different asset IDs"
+            super::compute_asset_id_from_bytes(&mint_bytes).unwrap(),
+            compute_token_asset_id(&mint_bytes).unwrap()
         );
-
-        println!("Low-limb asset ID: {:02x?}", asset_id_low);
-        println!("High-limb asset ID: {:02x?}", asset_id_high);
-    }
-
-    #[test]
-    fn test_asset_id_known_value() {
-        // Known test vector for cross-implementation verification
-        // This test ensures the implementation remains consistent
-        let mint_bytes: [u8; 32] = [
-            // Low limb (bytes 0-15) - little endian
-            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
-            0x0f, 0x10, // High limb (bytes 16-31) - little endian
-            0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e,
-            0x1f, 0x20,
-        ];
-
-        // Expected asset ID for this mint (Poseidon hash of two LE->BE converted limbs)
-        // Use this value for cross-implementation verification
-        let expected_asset_id: [u8; 32] = [
-            0x2a, 0xd3, 0x69, 0x9b, 0xf2, 0xd1, 0xbe, 0x62, 0x80, 0x90, 0x82, 0x18, 0x0a, 0x33,
-            0xac, 0x9b, 0x9d, 0x4f, 0x05, 0x75, 0xa3, 0xfd, 0x45, 0x44, 0x1f, 0x7b, 0xaf, 0x04,
-            0x2f, 0x97, 0x77, 0x09,
-        ];
-
-        let asset_id = compute_asset_id_from_bytes(&mint_bytes).unwrap();
-
-        // Print for reference when updating client implementations
-        println!("Known value test - mint: {:02x?}", mint_bytes);
-        println!("Known value test - asset_id: {:02x?}", asset_id);
-
-        // Verify exact match with expected value
         assert_eq!(
-            asset_id, expected_asset_id,
-            "Asset ID should match expected value for cross-implementation compatibility"
-        );
-    }
-
-    #[test]
-    fn test_asset_id_byte_reversal() {
-        // Test that bytes are correctly reversed within each limb
-        // In little-endian: byte[0] is LSB, byte[15] is MSB of low limb
-
-        // Create a mint where low limb has ascending bytes
-        let mint_bytes: [u8; 32] = [
-            // Low limb: 0x01 at LSB, 0x10 at MSB (little-endian)
-            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
-            0x0f, 0x10, // High limb: all zeros
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00,
-        ];
-
-        // Create a mint where low limb has descending bytes (reversed)
-        let mint_bytes_reversed: [u8; 32] = [
-            // Low limb: 0x10 at LSB, 0x01 at MSB (reversed)
-            0x10, 0x0f, 0x0e, 0x0d, 0x0c, 0x0b, 0x0a, 0x09, 0x08, 0x07, 0x06, 0x05, 0x04, 0x03,
-            0x02, 0x01, // High limb: all zeros
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00,
-        ];
-
-        let asset_id = compute_asset_id_from_bytes(&mint_bytes).unwrap();
-        let asset_id_reversed = compute_asset_id_from_bytes(&mint_bytes_reversed).unwrap();
-
-        // These represent different values, so should produce different hashes
-        assert_ne!(
-            asset_id, asset_id_reversed,
-            "Original and reversed bytes should produce different asset IDs"
+            super::compute_unified_sol_asset_id(),
+            zorb_pool_interface::asset_ids::compute_unified_sol_asset_id()
         );
     }
 
@@ -884,6 +753,7 @@ pub mod test {
     // ========================================================================
 
     use super::{check_public_amount, check_public_amount_unified, validate_fee, validate_fee_unified};
+    use super::{Rounding, compute_fee};
     use ark_bn254::Fr;
     use ark_ff::PrimeField;
 
@@ -1106,7 +976,15 @@ pub mod test {
         let withdrawal_rate: u16 = 50;
 
         assert!(
-            validate_fee(ext_amount, fee, relayer_fee, deposit_rate, withdrawal_rate).is_ok(),
+            validate_fee(
+                ext_amount,
+                fee,
+                relayer_fee,
+                deposit_rate,
+                withdrawal_rate,
+                false
+            )
+            .is_ok(),
             "Valid deposit fee should pass"
         );
     }
@@ -1121,7 +999,15 @@ pub mod test {
         let withdrawal_rate: u16 = 50;
 
         assert!(
-            validate_fee(ext_amount, fee, relayer_fee, deposit_rate, withdrawal_rate).is_err(),
+            validate_fee(
+                ext_amount,
+                fee,
+                relayer_fee,
+                deposit_rate,
+                withdrawal_rate,
+                false
+            )
+            .is_err(),
             "Insufficient deposit fee should fail"
         );
     }
@@ -1136,7 +1022,15 @@ pub mod test {
         let withdrawal_rate: u16 = 50; // 0.5%
 
         assert!(
-            validate_fee(ext_amount, fee, relayer_fee, deposit_rate, withdrawal_rate).is_ok(),
+            validate_fee(
+                ext_amount,
+                fee,
+                relayer_fee,
+                deposit_rate,
+                withdrawal_rate,
+                false
+            )
+            .is_ok(),
             "Valid withdrawal fee should pass"
         );
     }
@@ -1151,7 +1045,15 @@ pub mod test {
         let withdrawal_rate: u16 = 50;
 
         assert!(
-            validate_fee(ext_amount, fee, relayer_fee, deposit_rate, withdrawal_rate).is_ok(),
+            validate_fee(
+                ext_amount,
+                fee,
+                relayer_fee,
+                deposit_rate,
+                withdrawal_rate,
+                false
+            )
+            .is_ok(),
             "Transfer with zero fee should pass"
         );
     }
@@ -1166,11 +1068,43 @@ pub mod test {
         let withdrawal_rate: u16 = 50;
 
         assert!(
-            validate_fee(ext_amount, fee, relayer_fee, deposit_rate, withdrawal_rate).is_ok(),
+            validate_fee(
+                ext_amount,
+                fee,
+                relayer_fee,
+                deposit_rate,
+                withdrawal_rate,
+                false
+            )
+            .is_ok(),
             "Fee overpayment should be allowed"
         );
     }
 
+    #[test]
+    fn test_validate_fee_rounding_matches_token_pool() {
+        // 1 bps at the 10_000-unit boundary, for deposits and withdrawals in
+        // both rounding directions: the token pool's fee must be exactly the
+        // minimum the hub accepts
+        for round_up in [false, true] {
+            for amount in [9_999u64, 10_000, 10_001] {
+                let rounding = Rounding::from_round_up(round_up);
+                let pool_fee = compute_fee(amount, 1, rounding).unwrap();
+                for ext_amount in [amount as i64, -(amount as i64)] {
+                    assert!(validate_fee(ext_amount, pool_fee, 0, 1, 1, round_up).is_ok());
+                    if pool_fee > 0 {
+                        assert!(validate_fee(ext_amount, pool_fee - 1, 0, 1, 1, round_up).is_err());
+                    }
+                }
+            }
+        }
+
+        // Rounding up charges one unit where rounding down charges nothing
+        assert!(validate_fee(9_999, 0, 0, 1, 1, false).is_ok());
+        assert!(validate_fee(9_999, 0, 0, 1, 1, true).is_err());
+        assert!(validate_fee(-10_001, 1, 0, 1, 1, true).is_err());
+    }
+
     // ------------------------------------------------------------------------
     // validate_fee_unified tests (Unified SOL Pool)
     // ------------------------------------------------------------------------
@@ -1213,6 +1147,72 @@ pub mod test {
         );
     }
 
+    #[test]
+    fn test_validate_fee_unified_lst_fee_overrides() {
+        // The hub resolves rates through LstConfig exactly as the pool does,
+        // so the fee the pool charges always validates, with or without an
+        // override, and a fee priced at the pool-wide rate is rejected when a
+        // higher override applies.
+        use crate::state::LstConfig;
+        use zorb_pool_interface::FEE_RATE_UNSET;
+
+        let exchange_rate: u64 = 1_100_000_000;
+        let rate_precision: u64 = 1_000_000_000;
+        let pool_deposit_rate: u16 = 100;
+        let pool_withdrawal_rate: u16 = 50;
+        let ext_amount: i64 = 10000;
+        let virtual_sol: u128 = 11000;
+
+        // Pool-side fee, as charged by unified-sol-pool deposit/withdraw
+        let pool_fee = |rate: u16| (virtual_sol * rate as u128 / 10_000) as u64;
+
+        let mut lst = LstConfig::zeroed();
+        for (deposit_override, withdraw_override) in
+            [(FEE_RATE_UNSET, FEE_RATE_UNSET), (300, 0), (0, 200)]
+        {
+            lst.deposit_fee_bps_override = deposit_override;
+            lst.withdraw_fee_bps_override = withdraw_override;
+            let deposit_rate = lst.effective_deposit_fee_rate(pool_deposit_rate);
+            let withdrawal_rate = lst.effective_withdrawal_fee_rate(pool_withdrawal_rate);
+
+            let fee = pool_fee(deposit_rate);
+            let public_amount = amount_to_field_bytes(virtual_sol as i64 - fee as i64);
+            assert!(
+                validate_fee_unified(ext_amount, fee, public_amount, deposit_rate, withdrawal_rate, exchange_rate, rate_precision).is_ok(),
+                "Deposit fee charged by the pool should validate (override {})",
+                deposit_override
+            );
+
+            let fee = pool_fee(withdrawal_rate);
+            let public_amount = amount_to_field_bytes(-(virtual_sol as i64));
+            assert!(
+                validate_fee_unified(-ext_amount, fee, public_amount, deposit_rate, withdrawal_rate, exchange_rate, rate_precision).is_ok(),
+                "Withdrawal fee charged by the pool should validate (override {})",
+                withdraw_override
+            );
+        }
+
+        // Deposit override above the pool-wide rate: pool-wide pricing underpays
+        lst.deposit_fee_bps_override = 300;
+        let deposit_rate = lst.effective_deposit_fee_rate(pool_deposit_rate);
+        let fee = pool_fee(pool_deposit_rate);
+        let public_amount = amount_to_field_bytes(virtual_sol as i64 - fee as i64);
+        assert!(
+            validate_fee_unified(ext_amount, fee, public_amount, deposit_rate, pool_withdrawal_rate, exchange_rate, rate_precision).is_err(),
+            "Fee at the pool-wide rate should fail when the LST override is higher"
+        );
+
+        // Withdrawal override above the pool-wide rate
+        lst.withdraw_fee_bps_override = 200;
+        let withdrawal_rate = lst.effective_withdrawal_fee_rate(pool_withdrawal_rate);
+        let fee = pool_fee(pool_withdrawal_rate);
+        let public_amount = amount_to_field_bytes(-(virtual_sol as i64));
+        assert!(
+            validate_fee_unified(-ext_amount, fee, public_amount, deposit_rate, withdrawal_rate, exchange_rate, rate_precision).is_err(),
+            "Withdrawal fee at the pool-wide rate should fail when the LST override is higher"
+        );
+    }
+
     #[test]
     fn test_validate_fee_unified_transfer() {
         // Transfer: ext_amount = 0, fee should be 0
@@ -1291,7 +1291,7 @@ pub mod test {
     fn test_validate_fee_i64_min() {
         // validate_fee should handle i64::MIN gracefully (not panic)
         // checked_neg() returns None for i64::MIN, triggering ArithmeticOverflow
-        let result = validate_fee(i64::MIN, 0, 0, 100, 50);
+        let result = validate_fee(i64::MIN, 0, 0, 100, 50, false);
         assert!(result.is_err(), "i64::MIN should return error, not panic");
     }
 
@@ -1359,14 +1359,14 @@ pub mod test {
         // 100% fee rate (10000 bps) - entire amount is fee
         let ext_amount: i64 = 1000;
         let fee: u64 = 1000; // 100% of 1000
-        let result = validate_fee(ext_amount, fee, 0, 10000, 10000);
+        let result = validate_fee(ext_amount, fee, 0, 10000, 10000, false);
         assert!(result.is_ok(), "100% fee rate should be allowed");
     }
 
     #[test]
     fn test_validate_fee_zero_amount() {
         // Zero ext_amount (transfer) should allow zero fee
-        let result = validate_fee(0, 0, 0, 100, 50);
+        let result = validate_fee(0, 0, 0, 100, 50, false);
         assert!(result.is_ok(), "Zero amount with zero fee should pass");
     }
 
@@ -1423,8 +1423,18 @@ pub mod test {
         let withdrawal_rate: u16 = 50;
 
         // Should pass regardless of relayer_fee value
-        assert!(validate_fee(ext_amount, fee, 0, deposit_rate, withdrawal_rate).is_ok());
-        assert!(validate_fee(ext_amount, fee, 1000, deposit_rate, withdrawal_rate).is_ok());
-        assert!(validate_fee(ext_amount, fee, u64::MAX, deposit_rate, withdrawal_rate).is_ok());
+        assert!(validate_fee(ext_amount, fee, 0, deposit_rate, withdrawal_rate, false).is_ok());
+        assert!(validate_fee(ext_amount, fee, 1000, deposit_rate, withdrawal_rate, false).is_ok());
+        assert!(
+            validate_fee(
+                ext_amount,
+                fee,
+                u64::MAX,
+                deposit_rate,
+                withdrawal_rate,
+                false
+            )
+            .is_ok()
+        );
     }
 }
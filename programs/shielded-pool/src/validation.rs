@@ -7,7 +7,9 @@
 //! `pinocchio_contrib::AccountAssertions` trait instead. This module provides
 //! specialized validators for token-specific logic and initialization checks.
 
-use crate::token::{SPL_TOKEN_2022_PROGRAM_ID, SPL_TOKEN_PROGRAM_ID};
+use crate::token::{
+    SPL_TOKEN_2022_PROGRAM_ID, SPL_TOKEN_PROGRAM_ID, find_associated_token_address,
+};
 use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
 use pinocchio_token::state::TokenAccount;
 
@@ -23,17 +25,103 @@ pub fn require_token_program(account: &AccountInfo) -> Result<(), ProgramError>
     Ok(())
 }
 
-/// Require account to be owned by a token program (SPL Token or Token-2022).
+/// Token program a token account belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenProgram {
+    /// SPL Token
+    Spl,
+    /// SPL Token-2022
+    Token2022,
+}
+
+impl TokenProgram {
+    /// The token program with `program_id`, if it is one.
+    #[inline]
+    pub fn from_id(program_id: &Pubkey) -> Option<Self> {
+        if *program_id == SPL_TOKEN_PROGRAM_ID {
+            Some(Self::Spl)
+        } else if *program_id == SPL_TOKEN_2022_PROGRAM_ID {
+            Some(Self::Token2022)
+        } else {
+            None
+        }
+    }
+
+    /// The program ID.
+    #[inline]
+    pub const fn id(self) -> &'static Pubkey {
+        match self {
+            Self::Spl => &SPL_TOKEN_PROGRAM_ID,
+            Self::Token2022 => &SPL_TOKEN_2022_PROGRAM_ID,
+        }
+    }
+}
+
+/// Require account to be owned by the token program `token_program_id`.
 ///
-/// Use this to validate that a token account is a valid SPL token account.
-/// Returns `IncorrectProgramId` if the account is not owned by a token program.
+/// Use this to validate that a token account belongs to the token program an
+/// instruction works with. Returns `IncorrectProgramId` if `token_program_id`
+/// is not SPL Token or Token-2022, or does not own the account.
 #[inline]
-pub fn require_token_program_owner(account: &AccountInfo) -> Result<(), ProgramError> {
-    let owner = account.owner();
-    if *owner != SPL_TOKEN_PROGRAM_ID && *owner != SPL_TOKEN_2022_PROGRAM_ID {
+pub fn require_token_program_owner(
+    account: &AccountInfo,
+    token_program_id: &Pubkey,
+) -> Result<TokenProgram, ProgramError> {
+    let program =
+        TokenProgram::from_id(token_program_id).ok_or(ProgramError::IncorrectProgramId)?;
+    if account.owner() != token_program_id {
         return Err(ProgramError::IncorrectProgramId);
     }
-    Ok(())
+    Ok(program)
+}
+
+/// Require account to be owned by either token program, returning which.
+///
+/// Use this when the token program is not known up front, e.g. for a
+/// recipient's token account; pass the result's [`TokenProgram::id`] to the
+/// other helpers. Returns `IncorrectProgramId` if the account is not owned by
+/// a token program.
+#[inline]
+pub fn require_any_token_program_owner(
+    account: &AccountInfo,
+) -> Result<TokenProgram, ProgramError> {
+    TokenProgram::from_id(account.owner()).ok_or(ProgramError::IncorrectProgramId)
+}
+
+/// Require token account to be the canonical ATA of `wallet_address` for
+/// `mint_address` under the token program `token_program_id`.
+///
+/// The ATA address is derived with `token_program_id` in its seeds, so the
+/// same wallet and mint have a different ATA under each token program.
+///
+/// Returns `IncorrectProgramId` if the account is not owned by
+/// `token_program_id` (see [`require_token_program_owner`]), or
+/// `InvalidAccountData` if its address is not the derived ATA.
+#[inline]
+pub fn require_associated_token_account(
+    token_account: &AccountInfo,
+    wallet_address: &Pubkey,
+    mint_address: &Pubkey,
+    token_program_id: &Pubkey,
+) -> Result<TokenProgram, ProgramError> {
+    let (expected_ata, _) =
+        find_associated_token_address(wallet_address, mint_address, token_program_id);
+    require_token_account_at(token_account, &expected_ata, token_program_id)
+}
+
+/// [`require_associated_token_account`] after the ATA derivation, which
+/// needs the runtime's PDA syscall.
+#[inline]
+fn require_token_account_at(
+    token_account: &AccountInfo,
+    expected_address: &Pubkey,
+    token_program_id: &Pubkey,
+) -> Result<TokenProgram, ProgramError> {
+    let program = require_token_program_owner(token_account, token_program_id)?;
+    if token_account.key() != expected_address {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(program)
 }
 
 /// Require account to be uninitialized (PDA does not exist).
@@ -167,3 +255,122 @@ pub fn require_valid_token_account(account: &AccountInfo) -> Result<(), ProgramE
     let _ = TokenAccount::from_account_info(account)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token::ASSOCIATED_TOKEN_PROGRAM_ID;
+    use pinocchio_test_utils::AccountInfoBuilder;
+
+    const WALLET: Pubkey = [1u8; 32];
+    const MINT: Pubkey = [2u8; 32];
+    /// An unrelated program that could create lookalike token accounts
+    const MALICIOUS_PROGRAM_ID: Pubkey = [9u8; 32];
+
+    /// `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`
+    const TOKEN_2022_ID_BYTES: Pubkey = [
+        6, 221, 246, 225, 238, 117, 143, 222, 24, 66, 93, 188, 228, 108, 205, 218, 182, 26, 252,
+        77, 131, 185, 13, 39, 254, 189, 249, 40, 216, 161, 139, 252,
+    ];
+
+    /// The wallet's ATA for the mint, derived off-chain as wallets do.
+    fn ata(token_program_id: &Pubkey) -> Pubkey {
+        solana_pubkey::Pubkey::find_program_address(
+            &[&WALLET, token_program_id, &MINT],
+            &solana_pubkey::Pubkey::new_from_array(ASSOCIATED_TOKEN_PROGRAM_ID),
+        )
+        .0
+        .to_bytes()
+    }
+
+    #[test]
+    fn test_token_program_ids() {
+        assert_eq!(SPL_TOKEN_2022_PROGRAM_ID, TOKEN_2022_ID_BYTES);
+        for program in [TokenProgram::Spl, TokenProgram::Token2022] {
+            assert_eq!(TokenProgram::from_id(program.id()), Some(program));
+        }
+        assert_eq!(TokenProgram::from_id(&MALICIOUS_PROGRAM_ID), None);
+    }
+
+    #[test]
+    fn test_require_token_program_owner() {
+        for program in [TokenProgram::Spl, TokenProgram::Token2022] {
+            let account = AccountInfoBuilder::new().owner(program.id()).build();
+            let info = account.info();
+            assert_eq!(
+                require_token_program_owner(&info, program.id()),
+                Ok(program)
+            );
+            assert_eq!(require_any_token_program_owner(&info), Ok(program));
+        }
+
+        // Owned by the other token program
+        let account = AccountInfoBuilder::new()
+            .owner(&SPL_TOKEN_PROGRAM_ID)
+            .build();
+        assert_eq!(
+            require_token_program_owner(&account.info(), &SPL_TOKEN_2022_PROGRAM_ID),
+            Err(ProgramError::IncorrectProgramId)
+        );
+    }
+
+    #[test]
+    fn test_require_token_program_owner_rejects_unrelated_program() {
+        let account = AccountInfoBuilder::new()
+            .owner(&MALICIOUS_PROGRAM_ID)
+            .build();
+        let info = account.info();
+        assert_eq!(
+            require_any_token_program_owner(&info),
+            Err(ProgramError::IncorrectProgramId)
+        );
+        // Naming the account's own owner does not make it a token program
+        assert_eq!(
+            require_token_program_owner(&info, &MALICIOUS_PROGRAM_ID),
+            Err(ProgramError::IncorrectProgramId)
+        );
+    }
+
+    #[test]
+    fn test_require_associated_token_account() {
+        for program in [TokenProgram::Spl, TokenProgram::Token2022] {
+            let address = ata(program.id());
+            let account = AccountInfoBuilder::new()
+                .key(&address)
+                .owner(program.id())
+                .build();
+            assert_eq!(
+                require_token_account_at(&account.info(), &address, program.id()),
+                Ok(program)
+            );
+        }
+
+        // Each token program has its own ATA for the same wallet and mint
+        let spl_ata = ata(&SPL_TOKEN_PROGRAM_ID);
+        assert_ne!(spl_ata, ata(&SPL_TOKEN_2022_PROGRAM_ID));
+        let account = AccountInfoBuilder::new()
+            .key(&spl_ata)
+            .owner(&SPL_TOKEN_2022_PROGRAM_ID)
+            .build();
+        assert_eq!(
+            require_token_account_at(
+                &account.info(),
+                &ata(&SPL_TOKEN_2022_PROGRAM_ID),
+                &SPL_TOKEN_2022_PROGRAM_ID
+            ),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        // An account at the ATA address owned by an unrelated program
+        let account = AccountInfoBuilder::new()
+            .key(&spl_ata)
+            .owner(&MALICIOUS_PROGRAM_ID)
+            .build();
+        for token_program_id in [&SPL_TOKEN_PROGRAM_ID, &MALICIOUS_PROGRAM_ID] {
+            assert_eq!(
+                require_token_account_at(&account.info(), &spl_ata, token_program_id),
+                Err(ProgramError::IncorrectProgramId)
+            );
+        }
+    }
+}
@@ -4,331 +4,432 @@
 //! - Transaction circuit VK (for private transfers, deposits, withdrawals)
 //! - Nullifier non-membership circuit VK (for proving nullifiers haven't been spent)
 //! - Nullifier batch insertion circuit VKs (for batched tree updates)
+//!
+//! Every key is wrapped in [`VkRegistry::validated`], which checks at compile
+//! time that `nr_pubinputs` and the IC length agree with the circuit's public
+//! input count. Call sites look keys up with [`vk_for`] rather than naming the
+//! constants directly.
+//!
+//! The keys are pasted from the snarkjs `*.vkey.json` artifacts, which are not
+//! checked into this repository, so they cannot be loaded with `include_bytes!`.
 
 use crate::groth16::Groth16Verifyingkey;
+use crate::instructions::types::N_INS;
+
+// =============================================================================
+// Registry
+// =============================================================================
+
+/// Circuits with a registered verification key.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CircuitId {
+    /// Transaction circuit (transaction4)
+    Transact = 0,
+    /// Nullifier non-membership circuit (nullifierNonMembership4)
+    NullifierNonMembership = 1,
+    /// Nullifier batch insertion, batch size 4
+    NullifierBatch4 = 2,
+    /// Nullifier batch insertion, batch size 16
+    NullifierBatch16 = 3,
+    /// Nullifier batch insertion, batch size 64
+    NullifierBatch64 = 4,
+}
+
+impl CircuitId {
+    /// All registered circuits.
+    pub const ALL: [CircuitId; 5] = [
+        CircuitId::Transact,
+        CircuitId::NullifierNonMembership,
+        CircuitId::NullifierBatch4,
+        CircuitId::NullifierBatch16,
+        CircuitId::NullifierBatch64,
+    ];
+
+    /// Convert from u8 to CircuitId.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(CircuitId::Transact),
+            1 => Some(CircuitId::NullifierNonMembership),
+            2 => Some(CircuitId::NullifierBatch4),
+            3 => Some(CircuitId::NullifierBatch16),
+            4 => Some(CircuitId::NullifierBatch64),
+            _ => None,
+        }
+    }
+
+    /// Number of public inputs the circuit expects.
+    pub const fn n_public_inputs(self) -> usize {
+        match self {
+            CircuitId::Transact => N_PUBLIC_INPUTS_TRANSACT,
+            CircuitId::NullifierNonMembership => N_PUBLIC_INPUTS_NULLIFIER_NON_MEMBERSHIP,
+            CircuitId::NullifierBatch4 => N_PUBLIC_INPUTS_BATCH_4,
+            CircuitId::NullifierBatch16 => N_PUBLIC_INPUTS_BATCH_16,
+            CircuitId::NullifierBatch64 => N_PUBLIC_INPUTS_BATCH_64,
+        }
+    }
+}
+
+/// Compile-time validation for verification key constants.
+pub struct VkRegistry;
+
+impl VkRegistry {
+    /// Validate a verification key for a circuit with `N` public inputs.
+    ///
+    /// Point byte lengths are enforced by the field types; this checks the
+    /// lengths that are not: `nr_pubinputs == N` and `vk_ic.len() == N + 1`.
+    /// Used in `const` items, a mismatch fails the build.
+    pub const fn validated<const N: usize>(
+        vk: Groth16Verifyingkey<'static>,
+    ) -> Groth16Verifyingkey<'static> {
+        assert!(vk.nr_pubinputs == N, "vk nr_pubinputs does not match N");
+        assert!(vk.vk_ic.len() == N + 1, "vk IC length must be N + 1");
+        vk
+    }
+}
+
+/// Look up the verification key for a circuit.
+pub const fn vk_for(circuit: CircuitId) -> &'static Groth16Verifyingkey<'static> {
+    match circuit {
+        CircuitId::Transact => &TRANSACT_VK,
+        CircuitId::NullifierNonMembership => &NULLIFIER_NON_MEMBERSHIP_VK,
+        CircuitId::NullifierBatch4 => &NULLIFIER_BATCH_VK_4,
+        CircuitId::NullifierBatch16 => &NULLIFIER_BATCH_VK_16,
+        CircuitId::NullifierBatch64 => &NULLIFIER_BATCH_VK_64,
+    }
+}
 
 // =============================================================================
 // Transaction Circuit Verification Key
 // =============================================================================
 
+/// Number of public inputs for the transaction circuit.
+pub const N_PUBLIC_INPUTS_TRANSACT: usize = 30;
+
 /// Verifying key for the transaction circuit (transaction4.vkey.json).
 /// Circuit has 30 public inputs with 4 nullifiers.
-pub const TRANSACT_VK: Groth16Verifyingkey = Groth16Verifyingkey {
-    nr_pubinputs: 30,
-
-    vk_alpha_g1: [
-        39, 169, 40, 239, 166, 214, 128, 133, 23, 58, 18, 22, 185, 103, 82, 233, 131, 216, 244, 50,
-        15, 33, 111, 4, 85, 45, 223, 203, 224, 55, 57, 157, 4, 59, 94, 215, 65, 70, 228, 196, 63,
-        100, 92, 251, 33, 208, 151, 28, 122, 11, 174, 144, 172, 245, 130, 186, 132, 239, 142, 229,
-        133, 79, 147, 116,
-    ],
-
-    vk_beta_g2: [
-        4, 138, 240, 121, 209, 121, 249, 232, 130, 201, 107, 246, 0, 158, 51, 109, 210, 38, 185,
-        151, 71, 28, 3, 3, 38, 18, 19, 132, 3, 180, 191, 112, 14, 2, 153, 59, 155, 70, 230, 131,
-        57, 233, 35, 112, 222, 197, 197, 164, 59, 180, 137, 178, 91, 172, 10, 108, 182, 166, 196,
-        47, 82, 162, 67, 76, 1, 112, 69, 59, 234, 188, 16, 215, 235, 87, 97, 98, 105, 145, 68, 17,
-        113, 68, 60, 226, 203, 205, 8, 106, 169, 15, 97, 10, 60, 89, 227, 164, 37, 154, 52, 205,
-        182, 240, 226, 87, 20, 106, 187, 6, 233, 32, 107, 126, 133, 206, 2, 29, 70, 67, 91, 124,
-        145, 134, 114, 159, 152, 161, 244, 164,
-    ],
-
-    vk_gamme_g2: [
-        25, 142, 147, 147, 146, 13, 72, 58, 114, 96, 191, 183, 49, 251, 93, 37, 241, 170, 73, 51,
-        53, 169, 231, 18, 151, 228, 133, 183, 174, 243, 18, 194, 24, 0, 222, 239, 18, 31, 30, 118,
-        66, 106, 0, 102, 94, 92, 68, 121, 103, 67, 34, 212, 247, 94, 218, 221, 70, 222, 189, 92,
-        217, 146, 246, 237, 9, 6, 137, 208, 88, 95, 240, 117, 236, 158, 153, 173, 105, 12, 51, 149,
-        188, 75, 49, 51, 112, 179, 142, 243, 85, 172, 218, 220, 209, 34, 151, 91, 18, 200, 94, 165,
-        219, 140, 109, 235, 74, 171, 113, 128, 141, 203, 64, 143, 227, 209, 231, 105, 12, 67, 211,
-        123, 76, 230, 204, 1, 102, 250, 125, 170,
-    ],
-
-    vk_delta_g2: [
-        42, 71, 119, 245, 104, 137, 167, 189, 76, 36, 99, 224, 42, 81, 174, 171, 242, 39, 19, 171,
-        160, 165, 4, 61, 82, 210, 31, 41, 176, 70, 242, 44, 43, 77, 32, 83, 23, 62, 107, 172,
-        166, 246, 43, 235, 190, 82, 171, 69, 78, 243, 90, 167, 111, 88, 178, 32, 148, 161, 243, 187,
-        99, 169, 132, 145, 25, 70, 187, 83, 151, 164, 176, 110, 248, 208, 243, 96, 191, 249, 135, 202,
-        83, 181, 114, 39, 14, 125, 78, 177, 8, 55, 183, 169, 143, 98, 224, 92, 7, 14, 45, 128,
-        49, 125, 30, 106, 188, 42, 201, 51, 83, 245, 208, 51, 209, 250, 23, 115, 165, 121, 51, 228,
-        73, 60, 148, 161, 180, 89, 57, 248,
-    ],
-
-    vk_ic: &[
-        [
-            6, 232, 175, 144, 233, 51, 198, 70, 73, 66, 11, 33, 177, 91, 235, 33, 20, 248, 209, 225,
-            75, 192, 10, 114, 100, 44, 6, 234, 58, 171, 157, 230, 13, 70, 186, 166, 20, 24, 246, 133,
-            224, 225, 87, 187, 57, 60, 122, 121, 80, 119, 2, 55, 134, 215, 61, 158, 9, 107, 21, 94,
-            6, 228, 239, 164,
-        ],
-        [
-            29, 27, 248, 23, 41, 253, 4, 178, 185, 178, 56, 188, 208, 189, 98, 168, 93, 67, 86, 92,
-            191, 125, 105, 100, 0, 108, 141, 246, 66, 82, 172, 235, 17, 108, 88, 209, 80, 29, 101, 211,
-            217, 66, 23, 163, 254, 115, 195, 106, 61, 191, 109, 153, 0, 14, 180, 242, 233, 189, 70, 193,
-            77, 95, 28, 137,
-        ],
-        [
-            34, 192, 199, 27, 55, 56, 90, 212, 203, 152, 242, 47, 247, 69, 55, 125, 73, 241, 176, 21,
-            5, 28, 97, 238, 214, 34, 42, 204, 9, 113, 64, 17, 39, 244, 146, 172, 107, 21, 16, 167,
-            137, 94, 219, 24, 91, 44, 99, 205, 253, 247, 177, 244, 100, 58, 26, 4, 136, 25, 3, 142,
-            128, 183, 68, 132,
-        ],
-        [
-            48, 13, 142, 77, 116, 175, 162, 242, 197, 48, 93, 31, 32, 99, 203, 173, 189, 214, 212, 96,
-            176, 11, 51, 51, 240, 167, 94, 46, 205, 12, 64, 205, 11, 203, 61, 244, 64, 126, 185, 175,
-            215, 248, 87, 61, 98, 236, 189, 181, 70, 190, 116, 166, 34, 20, 36, 199, 114, 182, 221, 104,
-            53, 54, 72, 76,
-        ],
-        [
-            23, 97, 141, 115, 87, 191, 250, 180, 35, 69, 198, 118, 113, 138, 183, 41, 186, 46, 40, 22,
-            192, 26, 77, 135, 218, 244, 166, 34, 59, 159, 5, 146, 22, 108, 40, 229, 57, 206, 102, 150,
-            94, 216, 141, 129, 155, 225, 53, 223, 21, 174, 160, 53, 248, 122, 144, 83, 98, 131, 15, 219,
-            29, 7, 212, 243,
-        ],
-        [
-            16, 196, 11, 195, 204, 68, 244, 127, 251, 184, 125, 12, 148, 141, 95, 94, 245, 151, 28, 195,
-            101, 231, 159, 69, 190, 104, 196, 128, 213, 17, 113, 131, 32, 227, 110, 21, 40, 248, 166, 153,
-            201, 57, 177, 169, 173, 235, 152, 87, 135, 154, 61, 96, 103, 162, 108, 114, 125, 179, 252, 131,
-            67, 104, 72, 210,
-        ],
-        [
-            13, 83, 249, 32, 182, 31, 8, 225, 17, 162, 92, 14, 50, 84, 157, 17, 45, 209, 195, 248,
-            115, 127, 51, 2, 44, 196, 75, 168, 44, 53, 1, 191, 24, 239, 211, 41, 112, 235, 152, 221,
-            118, 38, 202, 54, 75, 243, 232, 207, 199, 25, 146, 207, 200, 9, 33, 149, 18, 120, 186, 108,
-            183, 213, 127, 157,
-        ],
-        [
-            1, 235, 202, 211, 38, 77, 51, 185, 75, 163, 189, 117, 151, 250, 126, 210, 211, 219, 127, 119,
-            113, 170, 111, 208, 88, 158, 224, 15, 125, 251, 80, 59, 32, 92, 87, 152, 81, 232, 157, 43,
-            192, 235, 123, 23, 229, 165, 27, 144, 230, 42, 211, 211, 97, 213, 162, 226, 214, 4, 22, 24,
-            150, 165, 22, 44,
-        ],
-        [
-            1, 167, 202, 130, 216, 152, 20, 208, 48, 241, 15, 225, 107, 11, 237, 101, 216, 26, 242, 201,
-            233, 91, 161, 129, 97, 213, 152, 38, 38, 244, 94, 117, 0, 158, 10, 122, 216, 29, 203, 44,
-            7, 242, 175, 92, 40, 36, 209, 208, 38, 97, 156, 64, 241, 12, 88, 118, 132, 20, 51, 79,
-            128, 67, 87, 180,
-        ],
-        [
-            41, 78, 44, 187, 199, 74, 191, 253, 154, 119, 179, 128, 85, 8, 201, 62, 207, 129, 156, 94,
-            213, 210, 93, 77, 191, 114, 48, 214, 118, 11, 138, 232, 2, 234, 252, 72, 41, 252, 209, 159,
-            208, 64, 33, 89, 45, 90, 73, 218, 145, 220, 109, 192, 152, 242, 149, 144, 220, 68, 183, 200,
-            175, 136, 154, 79,
-        ],
-        [
-            0, 74, 51, 249, 132, 134, 182, 247, 50, 92, 182, 212, 30, 76, 178, 116, 61, 78, 36, 230,
-            93, 84, 218, 114, 52, 40, 50, 40, 38, 181, 40, 233, 41, 209, 65, 144, 17, 246, 118, 253,
-            69, 99, 77, 157, 75, 253, 251, 224, 40, 156, 0, 100, 112, 243, 150, 188, 46, 205, 115, 234,
-            32, 213, 19, 42,
-        ],
-        [
-            16, 48, 186, 63, 33, 75, 252, 214, 74, 209, 133, 27, 169, 234, 7, 136, 96, 154, 9, 13,
-            149, 51, 157, 4, 31, 193, 87, 251, 74, 143, 145, 115, 9, 177, 25, 82, 244, 131, 12, 227,
-            42, 195, 6, 13, 69, 52, 127, 147, 251, 29, 145, 243, 0, 149, 101, 152, 75, 201, 116, 237,
-            196, 231, 67, 3,
-        ],
-        [
-            47, 52, 154, 118, 114, 146, 239, 212, 239, 158, 103, 209, 179, 170, 158, 10, 51, 187, 93, 26,
-            96, 114, 28, 73, 144, 25, 167, 217, 210, 118, 39, 117, 20, 67, 4, 166, 178, 70, 207, 160,
-            25, 217, 177, 175, 199, 241, 144, 56, 113, 13, 167, 166, 6, 182, 31, 29, 65, 168, 209, 247,
-            248, 15, 84, 57,
-        ],
-        [
-            25, 3, 8, 246, 74, 119, 123, 212, 18, 128, 123, 48, 35, 161, 78, 6, 230, 136, 59, 199,
-            50, 27, 212, 180, 208, 75, 191, 237, 25, 78, 243, 194, 18, 44, 249, 233, 220, 24, 240, 228,
-            126, 18, 36, 249, 9, 90, 100, 126, 183, 146, 108, 194, 213, 95, 208, 140, 40, 51, 126, 186,
-            107, 94, 116, 209,
-        ],
-        [
-            27, 165, 79, 117, 30, 240, 111, 165, 172, 12, 152, 182, 169, 212, 141, 196, 13, 87, 81, 217,
-            43, 234, 222, 190, 11, 86, 136, 164, 149, 217, 243, 186, 18, 6, 7, 45, 233, 122, 66, 94,
-            94, 180, 239, 53, 158, 45, 116, 48, 211, 238, 54, 179, 8, 39, 87, 158, 30, 31, 145, 71,
-            22, 37, 191, 227,
-        ],
-        [
-            0, 9, 39, 220, 78, 28, 206, 177, 175, 130, 204, 11, 105, 241, 195, 17, 193, 74, 126, 192,
-            149, 162, 236, 127, 80, 106, 74, 63, 231, 116, 24, 6, 3, 120, 158, 226, 218, 100, 216, 140,
-            94, 186, 190, 195, 169, 97, 200, 115, 87, 28, 96, 146, 69, 199, 111, 250, 41, 239, 219, 235,
-            114, 131, 72, 75,
-        ],
-        [
-            15, 108, 73, 17, 39, 130, 41, 71, 202, 112, 158, 71, 192, 200, 114, 171, 10, 140, 49, 187,
-            186, 3, 140, 37, 54, 62, 18, 63, 94, 145, 66, 167, 20, 138, 245, 15, 44, 120, 139, 241,
-            245, 112, 72, 193, 245, 41, 158, 163, 100, 158, 165, 130, 108, 102, 199, 167, 16, 213, 71, 212,
-            77, 45, 58, 30,
-        ],
-        [
-            19, 167, 51, 69, 204, 160, 68, 97, 142, 230, 53, 138, 111, 131, 202, 78, 223, 219, 113, 80,
-            171, 223, 234, 78, 118, 92, 20, 150, 163, 67, 32, 203, 30, 247, 32, 65, 195, 164, 196, 117,
-            41, 227, 226, 33, 201, 4, 97, 96, 185, 74, 85, 226, 59, 59, 44, 125, 147, 6, 1, 37,
-            252, 65, 8, 108,
-        ],
-        [
-            7, 10, 253, 120, 41, 143, 191, 140, 161, 59, 209, 139, 80, 62, 35, 9, 239, 67, 87, 96,
-            24, 190, 15, 189, 179, 157, 30, 192, 213, 7, 29, 62, 1, 98, 140, 109, 0, 114, 193, 73,
-            117, 138, 225, 101, 179, 191, 214, 11, 32, 16, 214, 171, 116, 164, 167, 51, 219, 59, 61, 177,
-            153, 54, 167, 116,
-        ],
-        [
-            6, 135, 91, 7, 14, 143, 207, 149, 221, 96, 158, 168, 160, 127, 223, 104, 104, 71, 203, 151,
-            252, 128, 61, 253, 154, 211, 154, 18, 219, 21, 140, 42, 27, 237, 175, 171, 70, 52, 82, 43,
-            61, 13, 231, 41, 101, 247, 108, 61, 199, 84, 157, 81, 59, 114, 127, 106, 78, 213, 146, 15,
-            40, 151, 165, 42,
-        ],
-        [
-            3, 25, 79, 140, 147, 88, 209, 231, 216, 8, 94, 170, 247, 207, 104, 38, 248, 145, 172, 108,
-            119, 253, 196, 122, 13, 205, 223, 144, 208, 117, 13, 253, 33, 24, 17, 252, 152, 225, 8, 227,
-            207, 93, 52, 246, 142, 47, 71, 141, 15, 51, 104, 152, 229, 206, 199, 91, 157, 209, 43, 105,
-            174, 84, 132, 108,
-        ],
-        [
-            33, 6, 9, 188, 46, 45, 72, 153, 41, 235, 185, 149, 12, 255, 7, 46, 221, 242, 100, 254,
-            165, 239, 129, 185, 174, 91, 130, 18, 64, 163, 11, 237, 37, 88, 72, 238, 22, 254, 29, 45,
-            242, 185, 11, 194, 194, 203, 243, 228, 68, 231, 24, 151, 43, 167, 240, 95, 136, 184, 100, 112,
-            173, 8, 5, 235,
-        ],
-        [
-            19, 71, 53, 76, 67, 174, 196, 154, 155, 224, 247, 233, 20, 16, 110, 242, 3, 231, 111, 106,
-            159, 230, 128, 143, 77, 3, 23, 166, 178, 236, 243, 125, 34, 247, 98, 99, 217, 248, 252, 27,
-            120, 148, 109, 181, 116, 101, 16, 250, 112, 10, 62, 174, 199, 134, 134, 33, 200, 42, 18, 253,
-            143, 129, 106, 249,
-        ],
-        [
-            30, 255, 102, 215, 220, 198, 248, 30, 233, 117, 112, 10, 94, 182, 214, 238, 27, 211, 92, 148,
-            244, 216, 106, 49, 49, 188, 196, 89, 175, 228, 65, 151, 12, 39, 124, 104, 52, 76, 182, 137,
-            19, 172, 42, 152, 53, 116, 63, 194, 238, 169, 40, 90, 194, 203, 16, 165, 120, 1, 80, 18,
-            216, 239, 123, 77,
-        ],
-        [
-            11, 1, 3, 220, 225, 4, 98, 78, 201, 81, 249, 165, 16, 219, 84, 95, 100, 40, 240, 112,
-            55, 121, 201, 29, 162, 167, 102, 65, 68, 205, 76, 18, 18, 30, 39, 172, 21, 122, 200, 141,
-            246, 26, 198, 51, 55, 76, 175, 176, 157, 61, 210, 212, 44, 66, 198, 170, 213, 56, 197, 254,
-            134, 181, 238, 155,
-        ],
-        [
-            9, 181, 243, 59, 55, 113, 51, 239, 83, 78, 101, 18, 210, 191, 137, 152, 226, 63, 161, 203,
-            205, 30, 189, 60, 116, 164, 103, 143, 103, 102, 102, 73, 32, 47, 204, 66, 149, 167, 138, 10,
-            86, 190, 157, 68, 180, 73, 160, 3, 109, 207, 247, 69, 238, 169, 66, 244, 156, 27, 156, 20,
-            12, 251, 111, 146,
-        ],
-        [
-            43, 78, 235, 9, 217, 233, 147, 217, 195, 60, 102, 19, 120, 3, 243, 226, 38, 238, 56, 164,
-            144, 118, 151, 198, 61, 236, 71, 161, 238, 67, 193, 213, 3, 169, 50, 80, 130, 245, 221, 237,
-            107, 145, 236, 186, 61, 100, 133, 232, 155, 20, 114, 252, 90, 12, 176, 210, 237, 208, 176, 11,
-            166, 196, 47, 86,
-        ],
-        [
-            14, 117, 89, 144, 188, 217, 213, 229, 78, 223, 199, 61, 34, 31, 225, 56, 19, 18, 120, 35,
-            52, 190, 130, 84, 35, 45, 219, 166, 185, 143, 221, 207, 29, 180, 153, 176, 230, 61, 71, 1,
-            227, 233, 255, 71, 104, 184, 253, 150, 20, 191, 48, 214, 218, 129, 35, 200, 22, 120, 195, 152,
-            249, 2, 225, 120,
+pub const TRANSACT_VK: Groth16Verifyingkey =
+    VkRegistry::validated::<N_PUBLIC_INPUTS_TRANSACT>(Groth16Verifyingkey {
+        nr_pubinputs: N_PUBLIC_INPUTS_TRANSACT,
+
+        vk_alpha_g1: [
+            39, 169, 40, 239, 166, 214, 128, 133, 23, 58, 18, 22, 185, 103, 82, 233, 131, 216, 244,
+            50, 15, 33, 111, 4, 85, 45, 223, 203, 224, 55, 57, 157, 4, 59, 94, 215, 65, 70, 228,
+            196, 63, 100, 92, 251, 33, 208, 151, 28, 122, 11, 174, 144, 172, 245, 130, 186, 132,
+            239, 142, 229, 133, 79, 147, 116,
         ],
-        [
-            38, 112, 136, 50, 254, 118, 222, 42, 45, 193, 254, 46, 152, 129, 56, 87, 232, 57, 202, 68,
-            141, 181, 147, 216, 60, 78, 5, 235, 10, 149, 23, 173, 19, 157, 241, 158, 154, 72, 200, 255,
-            231, 226, 164, 178, 237, 64, 148, 247, 47, 42, 25, 158, 114, 32, 6, 62, 15, 100, 206, 57,
-            31, 184, 146, 8,
+
+        vk_beta_g2: [
+            4, 138, 240, 121, 209, 121, 249, 232, 130, 201, 107, 246, 0, 158, 51, 109, 210, 38,
+            185, 151, 71, 28, 3, 3, 38, 18, 19, 132, 3, 180, 191, 112, 14, 2, 153, 59, 155, 70,
+            230, 131, 57, 233, 35, 112, 222, 197, 197, 164, 59, 180, 137, 178, 91, 172, 10, 108,
+            182, 166, 196, 47, 82, 162, 67, 76, 1, 112, 69, 59, 234, 188, 16, 215, 235, 87, 97, 98,
+            105, 145, 68, 17, 113, 68, 60, 226, 203, 205, 8, 106, 169, 15, 97, 10, 60, 89, 227,
+            164, 37, 154, 52, 205, 182, 240, 226, 87, 20, 106, 187, 6, 233, 32, 107, 126, 133, 206,
+            2, 29, 70, 67, 91, 124, 145, 134, 114, 159, 152, 161, 244, 164,
         ],
-        [
-            35, 121, 154, 15, 169, 228, 77, 86, 180, 221, 138, 45, 222, 131, 210, 38, 186, 14, 131, 111,
-            145, 119, 57, 219, 97, 63, 1, 200, 120, 213, 149, 145, 15, 222, 68, 139, 122, 236, 207, 9,
-            49, 238, 210, 11, 135, 35, 164, 141, 2, 48, 94, 108, 127, 116, 248, 232, 28, 9, 179, 33,
-            45, 215, 173, 40,
+
+        vk_gamme_g2: [
+            25, 142, 147, 147, 146, 13, 72, 58, 114, 96, 191, 183, 49, 251, 93, 37, 241, 170, 73,
+            51, 53, 169, 231, 18, 151, 228, 133, 183, 174, 243, 18, 194, 24, 0, 222, 239, 18, 31,
+            30, 118, 66, 106, 0, 102, 94, 92, 68, 121, 103, 67, 34, 212, 247, 94, 218, 221, 70,
+            222, 189, 92, 217, 146, 246, 237, 9, 6, 137, 208, 88, 95, 240, 117, 236, 158, 153, 173,
+            105, 12, 51, 149, 188, 75, 49, 51, 112, 179, 142, 243, 85, 172, 218, 220, 209, 34, 151,
+            91, 18, 200, 94, 165, 219, 140, 109, 235, 74, 171, 113, 128, 141, 203, 64, 143, 227,
+            209, 231, 105, 12, 67, 211, 123, 76, 230, 204, 1, 102, 250, 125, 170,
         ],
-        [
-            45, 117, 167, 204, 154, 2, 122, 192, 249, 242, 210, 236, 19, 24, 115, 7, 230, 65, 16, 120,
-            47, 108, 216, 150, 131, 195, 77, 37, 210, 230, 56, 97, 10, 217, 101, 49, 211, 199, 199, 237,
-            76, 62, 126, 109, 62, 76, 194, 174, 207, 101, 130, 123, 150, 109, 231, 90, 29, 3, 154, 171,
-            25, 24, 227, 242,
+
+        vk_delta_g2: [
+            42, 71, 119, 245, 104, 137, 167, 189, 76, 36, 99, 224, 42, 81, 174, 171, 242, 39, 19,
+            171, 160, 165, 4, 61, 82, 210, 31, 41, 176, 70, 242, 44, 43, 77, 32, 83, 23, 62, 107,
+            172, 166, 246, 43, 235, 190, 82, 171, 69, 78, 243, 90, 167, 111, 88, 178, 32, 148, 161,
+            243, 187, 99, 169, 132, 145, 25, 70, 187, 83, 151, 164, 176, 110, 248, 208, 243, 96,
+            191, 249, 135, 202, 83, 181, 114, 39, 14, 125, 78, 177, 8, 55, 183, 169, 143, 98, 224,
+            92, 7, 14, 45, 128, 49, 125, 30, 106, 188, 42, 201, 51, 83, 245, 208, 51, 209, 250, 23,
+            115, 165, 121, 51, 228, 73, 60, 148, 161, 180, 89, 57, 248,
         ],
-    ],
-};
+
+        vk_ic: &[
+            [
+                6, 232, 175, 144, 233, 51, 198, 70, 73, 66, 11, 33, 177, 91, 235, 33, 20, 248, 209,
+                225, 75, 192, 10, 114, 100, 44, 6, 234, 58, 171, 157, 230, 13, 70, 186, 166, 20,
+                24, 246, 133, 224, 225, 87, 187, 57, 60, 122, 121, 80, 119, 2, 55, 134, 215, 61,
+                158, 9, 107, 21, 94, 6, 228, 239, 164,
+            ],
+            [
+                29, 27, 248, 23, 41, 253, 4, 178, 185, 178, 56, 188, 208, 189, 98, 168, 93, 67, 86,
+                92, 191, 125, 105, 100, 0, 108, 141, 246, 66, 82, 172, 235, 17, 108, 88, 209, 80,
+                29, 101, 211, 217, 66, 23, 163, 254, 115, 195, 106, 61, 191, 109, 153, 0, 14, 180,
+                242, 233, 189, 70, 193, 77, 95, 28, 137,
+            ],
+            [
+                34, 192, 199, 27, 55, 56, 90, 212, 203, 152, 242, 47, 247, 69, 55, 125, 73, 241,
+                176, 21, 5, 28, 97, 238, 214, 34, 42, 204, 9, 113, 64, 17, 39, 244, 146, 172, 107,
+                21, 16, 167, 137, 94, 219, 24, 91, 44, 99, 205, 253, 247, 177, 244, 100, 58, 26, 4,
+                136, 25, 3, 142, 128, 183, 68, 132,
+            ],
+            [
+                48, 13, 142, 77, 116, 175, 162, 242, 197, 48, 93, 31, 32, 99, 203, 173, 189, 214,
+                212, 96, 176, 11, 51, 51, 240, 167, 94, 46, 205, 12, 64, 205, 11, 203, 61, 244, 64,
+                126, 185, 175, 215, 248, 87, 61, 98, 236, 189, 181, 70, 190, 116, 166, 34, 20, 36,
+                199, 114, 182, 221, 104, 53, 54, 72, 76,
+            ],
+            [
+                23, 97, 141, 115, 87, 191, 250, 180, 35, 69, 198, 118, 113, 138, 183, 41, 186, 46,
+                40, 22, 192, 26, 77, 135, 218, 244, 166, 34, 59, 159, 5, 146, 22, 108, 40, 229, 57,
+                206, 102, 150, 94, 216, 141, 129, 155, 225, 53, 223, 21, 174, 160, 53, 248, 122,
+                144, 83, 98, 131, 15, 219, 29, 7, 212, 243,
+            ],
+            [
+                16, 196, 11, 195, 204, 68, 244, 127, 251, 184, 125, 12, 148, 141, 95, 94, 245, 151,
+                28, 195, 101, 231, 159, 69, 190, 104, 196, 128, 213, 17, 113, 131, 32, 227, 110,
+                21, 40, 248, 166, 153, 201, 57, 177, 169, 173, 235, 152, 87, 135, 154, 61, 96, 103,
+                162, 108, 114, 125, 179, 252, 131, 67, 104, 72, 210,
+            ],
+            [
+                13, 83, 249, 32, 182, 31, 8, 225, 17, 162, 92, 14, 50, 84, 157, 17, 45, 209, 195,
+                248, 115, 127, 51, 2, 44, 196, 75, 168, 44, 53, 1, 191, 24, 239, 211, 41, 112, 235,
+                152, 221, 118, 38, 202, 54, 75, 243, 232, 207, 199, 25, 146, 207, 200, 9, 33, 149,
+                18, 120, 186, 108, 183, 213, 127, 157,
+            ],
+            [
+                1, 235, 202, 211, 38, 77, 51, 185, 75, 163, 189, 117, 151, 250, 126, 210, 211, 219,
+                127, 119, 113, 170, 111, 208, 88, 158, 224, 15, 125, 251, 80, 59, 32, 92, 87, 152,
+                81, 232, 157, 43, 192, 235, 123, 23, 229, 165, 27, 144, 230, 42, 211, 211, 97, 213,
+                162, 226, 214, 4, 22, 24, 150, 165, 22, 44,
+            ],
+            [
+                1, 167, 202, 130, 216, 152, 20, 208, 48, 241, 15, 225, 107, 11, 237, 101, 216, 26,
+                242, 201, 233, 91, 161, 129, 97, 213, 152, 38, 38, 244, 94, 117, 0, 158, 10, 122,
+                216, 29, 203, 44, 7, 242, 175, 92, 40, 36, 209, 208, 38, 97, 156, 64, 241, 12, 88,
+                118, 132, 20, 51, 79, 128, 67, 87, 180,
+            ],
+            [
+                41, 78, 44, 187, 199, 74, 191, 253, 154, 119, 179, 128, 85, 8, 201, 62, 207, 129,
+                156, 94, 213, 210, 93, 77, 191, 114, 48, 214, 118, 11, 138, 232, 2, 234, 252, 72,
+                41, 252, 209, 159, 208, 64, 33, 89, 45, 90, 73, 218, 145, 220, 109, 192, 152, 242,
+                149, 144, 220, 68, 183, 200, 175, 136, 154, 79,
+            ],
+            [
+                0, 74, 51, 249, 132, 134, 182, 247, 50, 92, 182, 212, 30, 76, 178, 116, 61, 78, 36,
+                230, 93, 84, 218, 114, 52, 40, 50, 40, 38, 181, 40, 233, 41, 209, 65, 144, 17, 246,
+                118, 253, 69, 99, 77, 157, 75, 253, 251, 224, 40, 156, 0, 100, 112, 243, 150, 188,
+                46, 205, 115, 234, 32, 213, 19, 42,
+            ],
+            [
+                16, 48, 186, 63, 33, 75, 252, 214, 74, 209, 133, 27, 169, 234, 7, 136, 96, 154, 9,
+                13, 149, 51, 157, 4, 31, 193, 87, 251, 74, 143, 145, 115, 9, 177, 25, 82, 244, 131,
+                12, 227, 42, 195, 6, 13, 69, 52, 127, 147, 251, 29, 145, 243, 0, 149, 101, 152, 75,
+                201, 116, 237, 196, 231, 67, 3,
+            ],
+            [
+                47, 52, 154, 118, 114, 146, 239, 212, 239, 158, 103, 209, 179, 170, 158, 10, 51,
+                187, 93, 26, 96, 114, 28, 73, 144, 25, 167, 217, 210, 118, 39, 117, 20, 67, 4, 166,
+                178, 70, 207, 160, 25, 217, 177, 175, 199, 241, 144, 56, 113, 13, 167, 166, 6, 182,
+                31, 29, 65, 168, 209, 247, 248, 15, 84, 57,
+            ],
+            [
+                25, 3, 8, 246, 74, 119, 123, 212, 18, 128, 123, 48, 35, 161, 78, 6, 230, 136, 59,
+                199, 50, 27, 212, 180, 208, 75, 191, 237, 25, 78, 243, 194, 18, 44, 249, 233, 220,
+                24, 240, 228, 126, 18, 36, 249, 9, 90, 100, 126, 183, 146, 108, 194, 213, 95, 208,
+                140, 40, 51, 126, 186, 107, 94, 116, 209,
+            ],
+            [
+                27, 165, 79, 117, 30, 240, 111, 165, 172, 12, 152, 182, 169, 212, 141, 196, 13, 87,
+                81, 217, 43, 234, 222, 190, 11, 86, 136, 164, 149, 217, 243, 186, 18, 6, 7, 45,
+                233, 122, 66, 94, 94, 180, 239, 53, 158, 45, 116, 48, 211, 238, 54, 179, 8, 39, 87,
+                158, 30, 31, 145, 71, 22, 37, 191, 227,
+            ],
+            [
+                0, 9, 39, 220, 78, 28, 206, 177, 175, 130, 204, 11, 105, 241, 195, 17, 193, 74,
+                126, 192, 149, 162, 236, 127, 80, 106, 74, 63, 231, 116, 24, 6, 3, 120, 158, 226,
+                218, 100, 216, 140, 94, 186, 190, 195, 169, 97, 200, 115, 87, 28, 96, 146, 69, 199,
+                111, 250, 41, 239, 219, 235, 114, 131, 72, 75,
+            ],
+            [
+                15, 108, 73, 17, 39, 130, 41, 71, 202, 112, 158, 71, 192, 200, 114, 171, 10, 140,
+                49, 187, 186, 3, 140, 37, 54, 62, 18, 63, 94, 145, 66, 167, 20, 138, 245, 15, 44,
+                120, 139, 241, 245, 112, 72, 193, 245, 41, 158, 163, 100, 158, 165, 130, 108, 102,
+                199, 167, 16, 213, 71, 212, 77, 45, 58, 30,
+            ],
+            [
+                19, 167, 51, 69, 204, 160, 68, 97, 142, 230, 53, 138, 111, 131, 202, 78, 223, 219,
+                113, 80, 171, 223, 234, 78, 118, 92, 20, 150, 163, 67, 32, 203, 30, 247, 32, 65,
+                195, 164, 196, 117, 41, 227, 226, 33, 201, 4, 97, 96, 185, 74, 85, 226, 59, 59, 44,
+                125, 147, 6, 1, 37, 252, 65, 8, 108,
+            ],
+            [
+                7, 10, 253, 120, 41, 143, 191, 140, 161, 59, 209, 139, 80, 62, 35, 9, 239, 67, 87,
+                96, 24, 190, 15, 189, 179, 157, 30, 192, 213, 7, 29, 62, 1, 98, 140, 109, 0, 114,
+                193, 73, 117, 138, 225, 101, 179, 191, 214, 11, 32, 16, 214, 171, 116, 164, 167,
+                51, 219, 59, 61, 177, 153, 54, 167, 116,
+            ],
+            [
+                6, 135, 91, 7, 14, 143, 207, 149, 221, 96, 158, 168, 160, 127, 223, 104, 104, 71,
+                203, 151, 252, 128, 61, 253, 154, 211, 154, 18, 219, 21, 140, 42, 27, 237, 175,
+                171, 70, 52, 82, 43, 61, 13, 231, 41, 101, 247, 108, 61, 199, 84, 157, 81, 59, 114,
+                127, 106, 78, 213, 146, 15, 40, 151, 165, 42,
+            ],
+            [
+                3, 25, 79, 140, 147, 88, 209, 231, 216, 8, 94, 170, 247, 207, 104, 38, 248, 145,
+                172, 108, 119, 253, 196, 122, 13, 205, 223, 144, 208, 117, 13, 253, 33, 24, 17,
+                252, 152, 225, 8, 227, 207, 93, 52, 246, 142, 47, 71, 141, 15, 51, 104, 152, 229,
+                206, 199, 91, 157, 209, 43, 105, 174, 84, 132, 108,
+            ],
+            [
+                33, 6, 9, 188, 46, 45, 72, 153, 41, 235, 185, 149, 12, 255, 7, 46, 221, 242, 100,
+                254, 165, 239, 129, 185, 174, 91, 130, 18, 64, 163, 11, 237, 37, 88, 72, 238, 22,
+                254, 29, 45, 242, 185, 11, 194, 194, 203, 243, 228, 68, 231, 24, 151, 43, 167, 240,
+                95, 136, 184, 100, 112, 173, 8, 5, 235,
+            ],
+            [
+                19, 71, 53, 76, 67, 174, 196, 154, 155, 224, 247, 233, 20, 16, 110, 242, 3, 231,
+                111, 106, 159, 230, 128, 143, 77, 3, 23, 166, 178, 236, 243, 125, 34, 247, 98, 99,
+                217, 248, 252, 27, 120, 148, 109, 181, 116, 101, 16, 250, 112, 10, 62, 174, 199,
+                134, 134, 33, 200, 42, 18, 253, 143, 129, 106, 249,
+            ],
+            [
+                30, 255, 102, 215, 220, 198, 248, 30, 233, 117, 112, 10, 94, 182, 214, 238, 27,
+                211, 92, 148, 244, 216, 106, 49, 49, 188, 196, 89, 175, 228, 65, 151, 12, 39, 124,
+                104, 52, 76, 182, 137, 19, 172, 42, 152, 53, 116, 63, 194, 238, 169, 40, 90, 194,
+                203, 16, 165, 120, 1, 80, 18, 216, 239, 123, 77,
+            ],
+            [
+                11, 1, 3, 220, 225, 4, 98, 78, 201, 81, 249, 165, 16, 219, 84, 95, 100, 40, 240,
+                112, 55, 121, 201, 29, 162, 167, 102, 65, 68, 205, 76, 18, 18, 30, 39, 172, 21,
+                122, 200, 141, 246, 26, 198, 51, 55, 76, 175, 176, 157, 61, 210, 212, 44, 66, 198,
+                170, 213, 56, 197, 254, 134, 181, 238, 155,
+            ],
+            [
+                9, 181, 243, 59, 55, 113, 51, 239, 83, 78, 101, 18, 210, 191, 137, 152, 226, 63,
+                161, 203, 205, 30, 189, 60, 116, 164, 103, 143, 103, 102, 102, 73, 32, 47, 204, 66,
+                149, 167, 138, 10, 86, 190, 157, 68, 180, 73, 160, 3, 109, 207, 247, 69, 238, 169,
+                66, 244, 156, 27, 156, 20, 12, 251, 111, 146,
+            ],
+            [
+                43, 78, 235, 9, 217, 233, 147, 217, 195, 60, 102, 19, 120, 3, 243, 226, 38, 238,
+                56, 164, 144, 118, 151, 198, 61, 236, 71, 161, 238, 67, 193, 213, 3, 169, 50, 80,
+                130, 245, 221, 237, 107, 145, 236, 186, 61, 100, 133, 232, 155, 20, 114, 252, 90,
+                12, 176, 210, 237, 208, 176, 11, 166, 196, 47, 86,
+            ],
+            [
+                14, 117, 89, 144, 188, 217, 213, 229, 78, 223, 199, 61, 34, 31, 225, 56, 19, 18,
+                120, 35, 52, 190, 130, 84, 35, 45, 219, 166, 185, 143, 221, 207, 29, 180, 153, 176,
+                230, 61, 71, 1, 227, 233, 255, 71, 104, 184, 253, 150, 20, 191, 48, 214, 218, 129,
+                35, 200, 22, 120, 195, 152, 249, 2, 225, 120,
+            ],
+            [
+                38, 112, 136, 50, 254, 118, 222, 42, 45, 193, 254, 46, 152, 129, 56, 87, 232, 57,
+                202, 68, 141, 181, 147, 216, 60, 78, 5, 235, 10, 149, 23, 173, 19, 157, 241, 158,
+                154, 72, 200, 255, 231, 226, 164, 178, 237, 64, 148, 247, 47, 42, 25, 158, 114, 32,
+                6, 62, 15, 100, 206, 57, 31, 184, 146, 8,
+            ],
+            [
+                35, 121, 154, 15, 169, 228, 77, 86, 180, 221, 138, 45, 222, 131, 210, 38, 186, 14,
+                131, 111, 145, 119, 57, 219, 97, 63, 1, 200, 120, 213, 149, 145, 15, 222, 68, 139,
+                122, 236, 207, 9, 49, 238, 210, 11, 135, 35, 164, 141, 2, 48, 94, 108, 127, 116,
+                248, 232, 28, 9, 179, 33, 45, 215, 173, 40,
+            ],
+            [
+                45, 117, 167, 204, 154, 2, 122, 192, 249, 242, 210, 236, 19, 24, 115, 7, 230, 65,
+                16, 120, 47, 108, 216, 150, 131, 195, 77, 37, 210, 230, 56, 97, 10, 217, 101, 49,
+                211, 199, 199, 237, 76, 62, 126, 109, 62, 76, 194, 174, 207, 101, 130, 123, 150,
+                109, 231, 90, 29, 3, 154, 171, 25, 24, 227, 242,
+            ],
+        ],
+    });
 
 // =============================================================================
 // Nullifier Non-Membership Circuit Verification Key
 // =============================================================================
 
+/// Number of public inputs for the nullifier non-membership circuit.
+/// Layout: epoch_root (1) + nullifiers (N_INS)
+pub const N_PUBLIC_INPUTS_NULLIFIER_NON_MEMBERSHIP: usize = 1 + N_INS;
+
 /// Verifying key for the nullifier non-membership circuit (nullifierNonMembership4.vkey.json).
 /// Circuit has 5 public inputs: [epoch_root, nullifier0, nullifier1, nullifier2, nullifier3].
 /// Proves that the 4 nullifiers are NOT in the indexed merkle tree with the given root.
-pub const NULLIFIER_NON_MEMBERSHIP_VK: Groth16Verifyingkey = Groth16Verifyingkey {
-    nr_pubinputs: 5,
-
-    vk_alpha_g1: [
-        39, 169, 40, 239, 166, 214, 128, 133, 23, 58, 18, 22, 185, 103, 82, 233, 131, 216, 244, 50,
-        15, 33, 111, 4, 85, 45, 223, 203, 224, 55, 57, 157, 4, 59, 94, 215, 65, 70, 228, 196, 63,
-        100, 92, 251, 33, 208, 151, 28, 122, 11, 174, 144, 172, 245, 130, 186, 132, 239, 142, 229,
-        133, 79, 147, 116,
-    ],
-
-    vk_beta_g2: [
-        4, 138, 240, 121, 209, 121, 249, 232, 130, 201, 107, 246, 0, 158, 51, 109, 210, 38, 185,
-        151, 71, 28, 3, 3, 38, 18, 19, 132, 3, 180, 191, 112, 14, 2, 153, 59, 155, 70, 230, 131,
-        57, 233, 35, 112, 222, 197, 197, 164, 59, 180, 137, 178, 91, 172, 10, 108, 182, 166, 196,
-        47, 82, 162, 67, 76, 1, 112, 69, 59, 234, 188, 16, 215, 235, 87, 97, 98, 105, 145, 68, 17,
-        113, 68, 60, 226, 203, 205, 8, 106, 169, 15, 97, 10, 60, 89, 227, 164, 37, 154, 52, 205,
-        182, 240, 226, 87, 20, 106, 187, 6, 233, 32, 107, 126, 133, 206, 2, 29, 70, 67, 91, 124,
-        145, 134, 114, 159, 152, 161, 244, 164,
-    ],
-
-    vk_gamme_g2: [
-        25, 142, 147, 147, 146, 13, 72, 58, 114, 96, 191, 183, 49, 251, 93, 37, 241, 170, 73, 51,
-        53, 169, 231, 18, 151, 228, 133, 183, 174, 243, 18, 194, 24, 0, 222, 239, 18, 31, 30, 118,
-        66, 106, 0, 102, 94, 92, 68, 121, 103, 67, 34, 212, 247, 94, 218, 221, 70, 222, 189, 92,
-        217, 146, 246, 237, 9, 6, 137, 208, 88, 95, 240, 117, 236, 158, 153, 173, 105, 12, 51, 149,
-        188, 75, 49, 51, 112, 179, 142, 243, 85, 172, 218, 220, 209, 34, 151, 91, 18, 200, 94, 165,
-        219, 140, 109, 235, 74, 171, 113, 128, 141, 203, 64, 143, 227, 209, 231, 105, 12, 67, 211,
-        123, 76, 230, 204, 1, 102, 250, 125, 170,
-    ],
-
-    vk_delta_g2: [
-        10, 223, 6, 120, 151, 69, 94, 215, 118, 57, 155, 223, 105, 243, 185, 104, 4, 111, 22, 88,
-        134, 32, 141, 211, 115, 93, 149, 175, 113, 146, 115, 130, 42, 180, 65, 78, 119, 135, 46, 159,
-        191, 139, 15, 218, 183, 119, 188, 247, 166, 28, 84, 52, 35, 40, 171, 54, 14, 93, 34, 35,
-        167, 217, 140, 208, 34, 255, 184, 113, 118, 40, 90, 102, 116, 74, 117, 223, 75, 79, 107, 202,
-        213, 188, 220, 163, 197, 254, 216, 227, 30, 143, 129, 72, 98, 40, 20, 51, 42, 223, 177, 209,
-        30, 106, 34, 140, 141, 34, 1, 21, 11, 86, 236, 81, 17, 137, 78, 80, 250, 189, 226, 99,
-        84, 140, 111, 207, 121, 123, 35, 59,
-    ],
-
-    vk_ic: &[
-        [
-            38, 196, 71, 132, 109, 217, 153, 213, 139, 128, 35, 20, 1, 221, 3, 185, 220, 49, 169, 211,
-            69, 53, 140, 207, 112, 13, 17, 13, 164, 89, 87, 18, 47, 240, 178, 103, 220, 126, 126, 18,
-            126, 113, 37, 24, 209, 190, 82, 188, 119, 189, 219, 229, 24, 52, 178, 81, 74, 42, 217, 183,
-            82, 185, 103, 58,
-        ],
-        [
-            23, 155, 128, 63, 237, 104, 216, 151, 216, 81, 186, 223, 226, 182, 99, 125, 154, 8, 107, 134,
-            44, 116, 28, 89, 44, 213, 248, 195, 50, 179, 30, 22, 20, 129, 98, 42, 114, 30, 61, 10,
-            130, 153, 249, 194, 12, 10, 46, 0, 205, 23, 50, 177, 255, 53, 246, 164, 232, 14, 66, 102,
-            0, 204, 149, 75,
-        ],
-        [
-            18, 191, 125, 159, 55, 22, 50, 229, 98, 31, 172, 205, 162, 170, 154, 124, 141, 80, 232, 4,
-            166, 60, 92, 198, 200, 28, 45, 203, 255, 50, 25, 89, 36, 174, 83, 138, 175, 177, 244, 107,
-            160, 31, 129, 74, 45, 148, 156, 28, 44, 171, 193, 191, 251, 226, 18, 165, 91, 30, 79, 67,
-            65, 83, 132, 122,
+pub const NULLIFIER_NON_MEMBERSHIP_VK: Groth16Verifyingkey =
+    VkRegistry::validated::<N_PUBLIC_INPUTS_NULLIFIER_NON_MEMBERSHIP>(Groth16Verifyingkey {
+        nr_pubinputs: N_PUBLIC_INPUTS_NULLIFIER_NON_MEMBERSHIP,
+
+        vk_alpha_g1: [
+            39, 169, 40, 239, 166, 214, 128, 133, 23, 58, 18, 22, 185, 103, 82, 233, 131, 216, 244,
+            50, 15, 33, 111, 4, 85, 45, 223, 203, 224, 55, 57, 157, 4, 59, 94, 215, 65, 70, 228,
+            196, 63, 100, 92, 251, 33, 208, 151, 28, 122, 11, 174, 144, 172, 245, 130, 186, 132,
+            239, 142, 229, 133, 79, 147, 116,
         ],
-        [
-            36, 224, 127, 97, 25, 182, 224, 174, 85, 146, 210, 240, 199, 83, 17, 219, 197, 188, 202, 52,
-            222, 125, 65, 10, 203, 30, 100, 61, 86, 254, 20, 64, 22, 48, 183, 24, 40, 49, 75, 95,
-            30, 48, 3, 179, 209, 25, 18, 206, 124, 49, 253, 143, 118, 13, 172, 227, 120, 34, 24, 167,
-            14, 54, 100, 249,
+
+        vk_beta_g2: [
+            4, 138, 240, 121, 209, 121, 249, 232, 130, 201, 107, 246, 0, 158, 51, 109, 210, 38,
+            185, 151, 71, 28, 3, 3, 38, 18, 19, 132, 3, 180, 191, 112, 14, 2, 153, 59, 155, 70,
+            230, 131, 57, 233, 35, 112, 222, 197, 197, 164, 59, 180, 137, 178, 91, 172, 10, 108,
+            182, 166, 196, 47, 82, 162, 67, 76, 1, 112, 69, 59, 234, 188, 16, 215, 235, 87, 97, 98,
+            105, 145, 68, 17, 113, 68, 60, 226, 203, 205, 8, 106, 169, 15, 97, 10, 60, 89, 227,
+            164, 37, 154, 52, 205, 182, 240, 226, 87, 20, 106, 187, 6, 233, 32, 107, 126, 133, 206,
+            2, 29, 70, 67, 91, 124, 145, 134, 114, 159, 152, 161, 244, 164,
         ],
-        [
-            35, 28, 155, 239, 106, 215, 209, 148, 219, 47, 28, 82, 32, 191, 156, 59, 55, 145, 224, 225,
-            128, 3, 51, 202, 140, 11, 247, 251, 196, 63, 127, 21, 20, 12, 104, 174, 167, 127, 155, 190,
-            17, 212, 78, 153, 43, 203, 4, 176, 49, 224, 126, 50, 199, 57, 211, 72, 128, 85, 55, 18,
-            206, 1, 99, 139,
+
+        vk_gamme_g2: [
+            25, 142, 147, 147, 146, 13, 72, 58, 114, 96, 191, 183, 49, 251, 93, 37, 241, 170, 73,
+            51, 53, 169, 231, 18, 151, 228, 133, 183, 174, 243, 18, 194, 24, 0, 222, 239, 18, 31,
+            30, 118, 66, 106, 0, 102, 94, 92, 68, 121, 103, 67, 34, 212, 247, 94, 218, 221, 70,
+            222, 189, 92, 217, 146, 246, 237, 9, 6, 137, 208, 88, 95, 240, 117, 236, 158, 153, 173,
+            105, 12, 51, 149, 188, 75, 49, 51, 112, 179, 142, 243, 85, 172, 218, 220, 209, 34, 151,
+            91, 18, 200, 94, 165, 219, 140, 109, 235, 74, 171, 113, 128, 141, 203, 64, 143, 227,
+            209, 231, 105, 12, 67, 211, 123, 76, 230, 204, 1, 102, 250, 125, 170,
         ],
-        [
-            33, 207, 10, 248, 223, 228, 190, 94, 193, 95, 51, 63, 71, 77, 174, 90, 26, 185, 83, 159,
-            179, 234, 226, 105, 113, 28, 24, 170, 218, 237, 44, 222, 33, 203, 240, 166, 195, 45, 229, 55,
-            169, 242, 84, 83, 160, 176, 21, 164, 54, 199, 188, 185, 31, 254, 43, 154, 30, 120, 19, 78,
-            33, 249, 74, 196,
+
+        vk_delta_g2: [
+            10, 223, 6, 120, 151, 69, 94, 215, 118, 57, 155, 223, 105, 243, 185, 104, 4, 111, 22,
+            88, 134, 32, 141, 211, 115, 93, 149, 175, 113, 146, 115, 130, 42, 180, 65, 78, 119,
+            135, 46, 159, 191, 139, 15, 218, 183, 119, 188, 247, 166, 28, 84, 52, 35, 40, 171, 54,
+            14, 93, 34, 35, 167, 217, 140, 208, 34, 255, 184, 113, 118, 40, 90, 102, 116, 74, 117,
+            223, 75, 79, 107, 202, 213, 188, 220, 163, 197, 254, 216, 227, 30, 143, 129, 72, 98,
+            40, 20, 51, 42, 223, 177, 209, 30, 106, 34, 140, 141, 34, 1, 21, 11, 86, 236, 81, 17,
+            137, 78, 80, 250, 189, 226, 99, 84, 140, 111, 207, 121, 123, 35, 59,
         ],
-    ],
-};
+
+        vk_ic: &[
+            [
+                38, 196, 71, 132, 109, 217, 153, 213, 139, 128, 35, 20, 1, 221, 3, 185, 220, 49,
+                169, 211, 69, 53, 140, 207, 112, 13, 17, 13, 164, 89, 87, 18, 47, 240, 178, 103,
+                220, 126, 126, 18, 126, 113, 37, 24, 209, 190, 82, 188, 119, 189, 219, 229, 24, 52,
+                178, 81, 74, 42, 217, 183, 82, 185, 103, 58,
+            ],
+            [
+                23, 155, 128, 63, 237, 104, 216, 151, 216, 81, 186, 223, 226, 182, 99, 125, 154, 8,
+                107, 134, 44, 116, 28, 89, 44, 213, 248, 195, 50, 179, 30, 22, 20, 129, 98, 42,
+                114, 30, 61, 10, 130, 153, 249, 194, 12, 10, 46, 0, 205, 23, 50, 177, 255, 53, 246,
+                164, 232, 14, 66, 102, 0, 204, 149, 75,
+            ],
+            [
+                18, 191, 125, 159, 55, 22, 50, 229, 98, 31, 172, 205, 162, 170, 154, 124, 141, 80,
+                232, 4, 166, 60, 92, 198, 200, 28, 45, 203, 255, 50, 25, 89, 36, 174, 83, 138, 175,
+                177, 244, 107, 160, 31, 129, 74, 45, 148, 156, 28, 44, 171, 193, 191, 251, 226, 18,
+                165, 91, 30, 79, 67, 65, 83, 132, 122,
+            ],
+            [
+                36, 224, 127, 97, 25, 182, 224, 174, 85, 146, 210, 240, 199, 83, 17, 219, 197, 188,
+                202, 52, 222, 125, 65, 10, 203, 30, 100, 61, 86, 254, 20, 64, 22, 48, 183, 24, 40,
+                49, 75, 95, 30, 48, 3, 179, 209, 25, 18, 206, 124, 49, 253, 143, 118, 13, 172, 227,
+                120, 34, 24, 167, 14, 54, 100, 249,
+            ],
+            [
+                35, 28, 155, 239, 106, 215, 209, 148, 219, 47, 28, 82, 32, 191, 156, 59, 55, 145,
+                224, 225, 128, 3, 51, 202, 140, 11, 247, 251, 196, 63, 127, 21, 20, 12, 104, 174,
+                167, 127, 155, 190, 17, 212, 78, 153, 43, 203, 4, 176, 49, 224, 126, 50, 199, 57,
+                211, 72, 128, 85, 55, 18, 206, 1, 99, 139,
+            ],
+            [
+                33, 207, 10, 248, 223, 228, 190, 94, 193, 95, 51, 63, 71, 77, 174, 90, 26, 185, 83,
+                159, 179, 234, 226, 105, 113, 28, 24, 170, 218, 237, 44, 222, 33, 203, 240, 166,
+                195, 45, 229, 55, 169, 242, 84, 83, 160, 176, 21, 164, 54, 199, 188, 185, 31, 254,
+                43, 154, 30, 120, 19, 78, 33, 249, 74, 196,
+            ],
+        ],
+    });
 
 // =============================================================================
 // Nullifier Batch Insertion Circuit Verification Keys
@@ -348,122 +449,157 @@ pub const N_PUBLIC_INPUTS_BATCH_64: usize = 67;
 
 /// Verifying key for batch size 4 circuit (nullifierBatchInsert4.vkey.json).
 /// Circuit has 7 public inputs: [old_root, new_root, starting_index, nullifier0, nullifier1, nullifier2, nullifier3].
-pub const NULLIFIER_BATCH_VK_4: Groth16Verifyingkey = Groth16Verifyingkey {
-    nr_pubinputs: N_PUBLIC_INPUTS_BATCH_4,
-
-    vk_alpha_g1: [
-        45, 77, 154, 167, 227, 2, 217, 223, 65, 116, 157, 85, 7, 148, 157, 5, 219, 234, 51, 251,
-        177, 108, 100, 59, 34, 245, 153, 162, 190, 109, 242, 226, 20, 190, 221, 80, 60, 55, 206,
-        176, 97, 216, 236, 96, 32, 159, 227, 69, 206, 137, 131, 10, 25, 35, 3, 1, 240, 118, 202,
-        255, 0, 77, 25, 38,
-    ],
-
-    vk_beta_g2: [
-        9, 103, 3, 47, 203, 247, 118, 209, 175, 201, 133, 248, 136, 119, 241, 130, 211, 132, 128, 166,
-        83, 242, 222, 202, 169, 121, 76, 188, 59, 243, 6, 12, 14, 24, 120, 71, 173, 76, 121, 131,
-        116, 208, 214, 115, 43, 245, 1, 132, 125, 214, 139, 192, 224, 113, 36, 30, 2, 19, 188, 127,
-        193, 61, 183, 171, 48, 76, 251, 209, 224, 138, 112, 74, 153, 245, 232, 71, 217, 63, 140, 60,
-        170, 253, 222, 196, 107, 122, 13, 55, 157, 166, 154, 77, 17, 35, 70, 167, 23, 57, 193, 177,
-        164, 87, 168, 199, 49, 49, 35, 210, 77, 47, 145, 146, 248, 150, 183, 198, 62, 234, 5, 169,
-        213, 127, 6, 84, 122, 208, 206, 200,
-    ],
-
-    vk_gamme_g2: [
-        25, 142, 147, 147, 146, 13, 72, 58, 114, 96, 191, 183, 49, 251, 93, 37, 241, 170, 73, 51,
-        53, 169, 231, 18, 151, 228, 133, 183, 174, 243, 18, 194, 24, 0, 222, 239, 18, 31, 30, 118,
-        66, 106, 0, 102, 94, 92, 68, 121, 103, 67, 34, 212, 247, 94, 218, 221, 70, 222, 189, 92,
-        217, 146, 246, 237, 9, 6, 137, 208, 88, 95, 240, 117, 236, 158, 153, 173, 105, 12, 51, 149,
-        188, 75, 49, 51, 112, 179, 142, 243, 85, 172, 218, 220, 209, 34, 151, 91, 18, 200, 94, 165,
-        219, 140, 109, 235, 74, 171, 113, 128, 141, 203, 64, 143, 227, 209, 231, 105, 12, 67, 211, 123,
-        76, 230, 204, 1, 102, 250, 125, 170,
-    ],
-
-    vk_delta_g2: [
-        38, 214, 208, 79, 212, 228, 1, 49, 212, 121, 50, 53, 233, 100, 109, 125, 172, 218, 187, 185,
-        97, 244, 110, 42, 201, 62, 28, 253, 11, 216, 101, 246, 39, 117, 50, 231, 101, 204, 16, 169,
-        251, 170, 3, 222, 173, 51, 63, 149, 165, 13, 101, 24, 56, 59, 36, 118, 120, 130, 75, 226,
-        191, 206, 86, 6, 15, 68, 98, 11, 94, 177, 167, 17, 199, 159, 129, 115, 46, 236, 198, 8,
-        160, 217, 98, 132, 188, 196, 27, 72, 187, 232, 112, 253, 136, 31, 139, 176, 37, 209, 64, 244,
-        111, 86, 159, 173, 31, 175, 142, 26, 28, 101, 207, 118, 160, 104, 238, 193, 77, 241, 237, 245,
-        109, 97, 234, 251, 13, 113, 169, 183,
-    ],
-
-    vk_ic: &[
-        [
-            21, 96, 114, 205, 38, 99, 6, 100, 140, 3, 238, 48, 69, 53, 79, 251, 68, 134, 4, 66,
-            223, 242, 237, 156, 235, 146, 21, 114, 213, 89, 248, 1, 40, 184, 184, 68, 225, 107, 27, 5,
-            229, 32, 139, 100, 93, 116, 84, 78, 100, 23, 240, 144, 59, 128, 149, 203, 59, 125, 230, 39,
-            77, 29, 145, 230,
-        ],
-        [
-            42, 23, 231, 86, 213, 29, 101, 202, 59, 90, 248, 232, 131, 141, 189, 99, 8, 42, 102, 2,
-            117, 229, 246, 44, 13, 53, 9, 176, 7, 129, 133, 193, 11, 130, 162, 237, 73, 48, 232, 204,
-            66, 99, 252, 52, 90, 145, 167, 185, 144, 169, 11, 168, 43, 161, 4, 89, 161, 146, 137, 28,
-            67, 170, 122, 214,
-        ],
-        [
-            23, 11, 156, 126, 9, 50, 110, 203, 30, 195, 31, 113, 245, 166, 149, 191, 119, 150, 107, 4,
-            39, 226, 252, 145, 140, 30, 82, 114, 55, 222, 115, 131, 24, 40, 192, 143, 165, 98, 161, 136,
-            81, 213, 144, 238, 36, 64, 67, 131, 139, 248, 174, 182, 40, 212, 128, 211, 170, 191, 190, 97,
-            36, 30, 233, 13,
-        ],
-        [
-            27, 11, 193, 93, 182, 122, 47, 171, 110, 73, 67, 142, 33, 154, 149, 65, 68, 175, 154, 126,
-            148, 42, 241, 247, 145, 163, 233, 203, 209, 15, 51, 114, 4, 43, 81, 99, 93, 187, 1, 17,
-            18, 176, 126, 188, 89, 55, 113, 253, 59, 55, 157, 46, 189, 126, 28, 93, 13, 227, 138, 230,
-            116, 114, 110, 175,
-        ],
-        [
-            29, 255, 25, 97, 254, 219, 165, 134, 92, 28, 65, 140, 233, 220, 137, 102, 62, 187, 226, 62,
-            234, 57, 187, 82, 22, 242, 239, 93, 248, 209, 224, 187, 19, 80, 54, 209, 245, 57, 85, 210,
-            114, 17, 71, 37, 99, 30, 151, 178, 16, 131, 26, 187, 90, 11, 19, 237, 179, 211, 182, 15,
-            137, 155, 21, 156,
+pub const NULLIFIER_BATCH_VK_4: Groth16Verifyingkey =
+    VkRegistry::validated::<N_PUBLIC_INPUTS_BATCH_4>(Groth16Verifyingkey {
+        nr_pubinputs: N_PUBLIC_INPUTS_BATCH_4,
+
+        vk_alpha_g1: [
+            45, 77, 154, 167, 227, 2, 217, 223, 65, 116, 157, 85, 7, 148, 157, 5, 219, 234, 51,
+            251, 177, 108, 100, 59, 34, 245, 153, 162, 190, 109, 242, 226, 20, 190, 221, 80, 60,
+            55, 206, 176, 97, 216, 236, 96, 32, 159, 227, 69, 206, 137, 131, 10, 25, 35, 3, 1, 240,
+            118, 202, 255, 0, 77, 25, 38,
         ],
-        [
-            14, 82, 151, 196, 130, 103, 26, 89, 156, 131, 35, 2, 37, 36, 35, 137, 237, 21, 65, 113,
-            242, 78, 159, 44, 200, 81, 149, 70, 84, 186, 245, 1, 7, 23, 234, 255, 96, 186, 75, 218,
-            167, 253, 60, 190, 233, 212, 122, 134, 101, 208, 54, 180, 224, 137, 174, 169, 236, 171, 208, 7,
-            124, 117, 141, 82,
+
+        vk_beta_g2: [
+            9, 103, 3, 47, 203, 247, 118, 209, 175, 201, 133, 248, 136, 119, 241, 130, 211, 132,
+            128, 166, 83, 242, 222, 202, 169, 121, 76, 188, 59, 243, 6, 12, 14, 24, 120, 71, 173,
+            76, 121, 131, 116, 208, 214, 115, 43, 245, 1, 132, 125, 214, 139, 192, 224, 113, 36,
+            30, 2, 19, 188, 127, 193, 61, 183, 171, 48, 76, 251, 209, 224, 138, 112, 74, 153, 245,
+            232, 71, 217, 63, 140, 60, 170, 253, 222, 196, 107, 122, 13, 55, 157, 166, 154, 77, 17,
+            35, 70, 167, 23, 57, 193, 177, 164, 87, 168, 199, 49, 49, 35, 210, 77, 47, 145, 146,
+            248, 150, 183, 198, 62, 234, 5, 169, 213, 127, 6, 84, 122, 208, 206, 200,
         ],
-        [
-            6, 152, 173, 20, 198, 138, 57, 230, 94, 234, 100, 174, 123, 183, 200, 223, 121, 162, 77, 150,
-            33, 181, 38, 217, 41, 70, 189, 223, 14, 27, 40, 9, 17, 196, 131, 209, 115, 96, 18, 147,
-            26, 97, 216, 36, 167, 30, 27, 230, 136, 33, 211, 116, 52, 56, 36, 146, 3, 188, 115, 143,
-            183, 245, 27, 159,
+
+        vk_gamme_g2: [
+            25, 142, 147, 147, 146, 13, 72, 58, 114, 96, 191, 183, 49, 251, 93, 37, 241, 170, 73,
+            51, 53, 169, 231, 18, 151, 228, 133, 183, 174, 243, 18, 194, 24, 0, 222, 239, 18, 31,
+            30, 118, 66, 106, 0, 102, 94, 92, 68, 121, 103, 67, 34, 212, 247, 94, 218, 221, 70,
+            222, 189, 92, 217, 146, 246, 237, 9, 6, 137, 208, 88, 95, 240, 117, 236, 158, 153, 173,
+            105, 12, 51, 149, 188, 75, 49, 51, 112, 179, 142, 243, 85, 172, 218, 220, 209, 34, 151,
+            91, 18, 200, 94, 165, 219, 140, 109, 235, 74, 171, 113, 128, 141, 203, 64, 143, 227,
+            209, 231, 105, 12, 67, 211, 123, 76, 230, 204, 1, 102, 250, 125, 170,
         ],
-        [
-            2, 91, 182, 48, 146, 215, 165, 55, 210, 156, 53, 71, 242, 151, 42, 31, 120, 243, 1, 46,
-            14, 208, 196, 28, 69, 4, 196, 186, 66, 122, 124, 93, 4, 59, 165, 183, 202, 218, 76, 182,
-            246, 234, 207, 220, 186, 73, 90, 252, 240, 31, 148, 39, 192, 70, 14, 128, 161, 48, 197, 120,
-            92, 146, 66, 113,
+
+        vk_delta_g2: [
+            38, 214, 208, 79, 212, 228, 1, 49, 212, 121, 50, 53, 233, 100, 109, 125, 172, 218, 187,
+            185, 97, 244, 110, 42, 201, 62, 28, 253, 11, 216, 101, 246, 39, 117, 50, 231, 101, 204,
+            16, 169, 251, 170, 3, 222, 173, 51, 63, 149, 165, 13, 101, 24, 56, 59, 36, 118, 120,
+            130, 75, 226, 191, 206, 86, 6, 15, 68, 98, 11, 94, 177, 167, 17, 199, 159, 129, 115,
+            46, 236, 198, 8, 160, 217, 98, 132, 188, 196, 27, 72, 187, 232, 112, 253, 136, 31, 139,
+            176, 37, 209, 64, 244, 111, 86, 159, 173, 31, 175, 142, 26, 28, 101, 207, 118, 160,
+            104, 238, 193, 77, 241, 237, 245, 109, 97, 234, 251, 13, 113, 169, 183,
         ],
-    ],
-};
+
+        vk_ic: &[
+            [
+                21, 96, 114, 205, 38, 99, 6, 100, 140, 3, 238, 48, 69, 53, 79, 251, 68, 134, 4, 66,
+                223, 242, 237, 156, 235, 146, 21, 114, 213, 89, 248, 1, 40, 184, 184, 68, 225, 107,
+                27, 5, 229, 32, 139, 100, 93, 116, 84, 78, 100, 23, 240, 144, 59, 128, 149, 203,
+                59, 125, 230, 39, 77, 29, 145, 230,
+            ],
+            [
+                42, 23, 231, 86, 213, 29, 101, 202, 59, 90, 248, 232, 131, 141, 189, 99, 8, 42,
+                102, 2, 117, 229, 246, 44, 13, 53, 9, 176, 7, 129, 133, 193, 11, 130, 162, 237, 73,
+                48, 232, 204, 66, 99, 252, 52, 90, 145, 167, 185, 144, 169, 11, 168, 43, 161, 4,
+                89, 161, 146, 137, 28, 67, 170, 122, 214,
+            ],
+            [
+                23, 11, 156, 126, 9, 50, 110, 203, 30, 195, 31, 113, 245, 166, 149, 191, 119, 150,
+                107, 4, 39, 226, 252, 145, 140, 30, 82, 114, 55, 222, 115, 131, 24, 40, 192, 143,
+                165, 98, 161, 136, 81, 213, 144, 238, 36, 64, 67, 131, 139, 248, 174, 182, 40, 212,
+                128, 211, 170, 191, 190, 97, 36, 30, 233, 13,
+            ],
+            [
+                27, 11, 193, 93, 182, 122, 47, 171, 110, 73, 67, 142, 33, 154, 149, 65, 68, 175,
+                154, 126, 148, 42, 241, 247, 145, 163, 233, 203, 209, 15, 51, 114, 4, 43, 81, 99,
+                93, 187, 1, 17, 18, 176, 126, 188, 89, 55, 113, 253, 59, 55, 157, 46, 189, 126, 28,
+                93, 13, 227, 138, 230, 116, 114, 110, 175,
+            ],
+            [
+                29, 255, 25, 97, 254, 219, 165, 134, 92, 28, 65, 140, 233, 220, 137, 102, 62, 187,
+                226, 62, 234, 57, 187, 82, 22, 242, 239, 93, 248, 209, 224, 187, 19, 80, 54, 209,
+                245, 57, 85, 210, 114, 17, 71, 37, 99, 30, 151, 178, 16, 131, 26, 187, 90, 11, 19,
+                237, 179, 211, 182, 15, 137, 155, 21, 156,
+            ],
+            [
+                14, 82, 151, 196, 130, 103, 26, 89, 156, 131, 35, 2, 37, 36, 35, 137, 237, 21, 65,
+                113, 242, 78, 159, 44, 200, 81, 149, 70, 84, 186, 245, 1, 7, 23, 234, 255, 96, 186,
+                75, 218, 167, 253, 60, 190, 233, 212, 122, 134, 101, 208, 54, 180, 224, 137, 174,
+                169, 236, 171, 208, 7, 124, 117, 141, 82,
+            ],
+            [
+                6, 152, 173, 20, 198, 138, 57, 230, 94, 234, 100, 174, 123, 183, 200, 223, 121,
+                162, 77, 150, 33, 181, 38, 217, 41, 70, 189, 223, 14, 27, 40, 9, 17, 196, 131, 209,
+                115, 96, 18, 147, 26, 97, 216, 36, 167, 30, 27, 230, 136, 33, 211, 116, 52, 56, 36,
+                146, 3, 188, 115, 143, 183, 245, 27, 159,
+            ],
+            [
+                2, 91, 182, 48, 146, 215, 165, 55, 210, 156, 53, 71, 242, 151, 42, 31, 120, 243, 1,
+                46, 14, 208, 196, 28, 69, 4, 196, 186, 66, 122, 124, 93, 4, 59, 165, 183, 202, 218,
+                76, 182, 246, 234, 207, 220, 186, 73, 90, 252, 240, 31, 148, 39, 192, 70, 14, 128,
+                161, 48, 197, 120, 92, 146, 66, 113,
+            ],
+        ],
+    });
 
 /// Placeholder verification key for batch size 16 circuit.
 /// Requires PTAU 19 (573MB) - 360k constraints.
 /// To compile: `npx snarkjs groth16 setup build/nullifierBatchInsert16/nullifierBatchInsert16.r1cs ptau/powersOfTau28_hez_final_19.ptau ...`
 /// TODO: Replace with actual VK after trusted setup ceremony.
-pub const NULLIFIER_BATCH_VK_16: Groth16Verifyingkey = Groth16Verifyingkey {
-    nr_pubinputs: N_PUBLIC_INPUTS_BATCH_16,
-    // Placeholder values - must be replaced with actual VK from trusted setup
-    vk_alpha_g1: [0u8; 64],
-    vk_beta_g2: [0u8; 128],
-    vk_gamme_g2: [0u8; 128],
-    vk_delta_g2: [0u8; 128],
-    vk_ic: &[[0u8; 64]; 20], // nr_pubinputs + 1
-};
+pub const NULLIFIER_BATCH_VK_16: Groth16Verifyingkey =
+    VkRegistry::validated::<N_PUBLIC_INPUTS_BATCH_16>(Groth16Verifyingkey {
+        nr_pubinputs: N_PUBLIC_INPUTS_BATCH_16,
+        // Placeholder values - must be replaced with actual VK from trusted setup
+        vk_alpha_g1: [0u8; 64],
+        vk_beta_g2: [0u8; 128],
+        vk_gamme_g2: [0u8; 128],
+        vk_delta_g2: [0u8; 128],
+        vk_ic: &[[0u8; 64]; 20], // nr_pubinputs + 1
+    });
 
 /// Placeholder verification key for batch size 64 circuit.
 /// Requires PTAU 21 (2.2GB) - 1.4M constraints.
 /// To compile: `npx snarkjs groth16 setup build/nullifierBatchInsert64/nullifierBatchInsert64.r1cs ptau/powersOfTau28_hez_final_21.ptau ...`
 /// TODO: Replace with actual VK after trusted setup ceremony.
-pub const NULLIFIER_BATCH_VK_64: Groth16Verifyingkey = Groth16Verifyingkey {
-    nr_pubinputs: N_PUBLIC_INPUTS_BATCH_64,
-    // Placeholder values - must be replaced with actual VK from trusted setup
-    vk_alpha_g1: [0u8; 64],
-    vk_beta_g2: [0u8; 128],
-    vk_gamme_g2: [0u8; 128],
-    vk_delta_g2: [0u8; 128],
-    vk_ic: &[[0u8; 64]; 68], // nr_pubinputs + 1
-};
+pub const NULLIFIER_BATCH_VK_64: Groth16Verifyingkey =
+    VkRegistry::validated::<N_PUBLIC_INPUTS_BATCH_64>(Groth16Verifyingkey {
+        nr_pubinputs: N_PUBLIC_INPUTS_BATCH_64,
+        // Placeholder values - must be replaced with actual VK from trusted setup
+        vk_alpha_g1: [0u8; 64],
+        vk_beta_g2: [0u8; 128],
+        vk_gamme_g2: [0u8; 128],
+        vk_delta_g2: [0u8; 128],
+        vk_ic: &[[0u8; 64]; 68], // nr_pubinputs + 1
+    });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::N_PUBLIC_INPUTS;
+
+    #[test]
+    fn test_registered_vks_are_consistent() {
+        for circuit in CircuitId::ALL {
+            let vk = vk_for(circuit);
+            assert_eq!(vk.nr_pubinputs, circuit.n_public_inputs(), "{:?}", circuit);
+            assert_eq!(vk.vk_ic.len(), vk.nr_pubinputs + 1, "{:?}", circuit);
+            assert_eq!(CircuitId::from_u8(circuit as u8), Some(circuit));
+        }
+        assert_eq!(CircuitId::from_u8(CircuitId::ALL.len() as u8), None);
+    }
+
+    #[test]
+    fn test_public_input_counts_match_callers() {
+        // execute_transact builds N_PUBLIC_INPUTS inputs in utils::verify_proof
+        assert_eq!(vk_for(CircuitId::Transact).nr_pubinputs, N_PUBLIC_INPUTS);
+        // Non-membership proof covers the epoch root plus every input nullifier
+        assert_eq!(
+            vk_for(CircuitId::NullifierNonMembership).nr_pubinputs,
+            1 + N_INS
+        );
+        // Batch insert: old_root + new_root + starting_index + nullifiers
+        assert_eq!(vk_for(CircuitId::NullifierBatch4).nr_pubinputs, 3 + 4);
+        assert_eq!(vk_for(CircuitId::NullifierBatch16).nr_pubinputs, 3 + 16);
+        assert_eq!(vk_for(CircuitId::NullifierBatch64).nr_pubinputs, 3 + 64);
+    }
+}
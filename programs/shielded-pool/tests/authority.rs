@@ -1,14 +1,21 @@
 //! Shielded pool authority transfer tests.
 //!
-//! Tests for TransferAuthority and AcceptAuthority instructions.
+//! Tests for TransferAuthority, AcceptAuthority, CancelAuthorityTransfer and
+//! SetAuthorityTransferDelay instructions.
 
 mod common;
 
 use common::*;
 use litesvm::LiteSVM;
+use shielded_pool::state::{
+    DEFAULT_AUTHORITY_TRANSFER_DELAY_SLOTS, MIN_AUTHORITY_TRANSFER_DELAY_SLOTS,
+};
 use solana_keypair::Keypair;
 use solana_signer::Signer;
 
+/// LiteSVM starts at slot 0, so a transfer initiated at genesis unlocks here.
+const UNLOCK_SLOT: u64 = DEFAULT_AUTHORITY_TRANSFER_DELAY_SLOTS;
+
 // ============================================================================
 // Authority Transfer Tests
 // ============================================================================
@@ -41,7 +48,8 @@ fn test_transfer_authority_success() {
         result.err()
     );
 
-    // Accept authority
+    // Accept authority once the timelock has elapsed
+    warp_to_slot(&mut svm, UNLOCK_SLOT);
     let result = accept_authority(&mut svm, &program_id, &global_config, &new_authority);
     assert!(
         result.is_ok(),
@@ -230,7 +238,8 @@ fn test_transfer_authority_overwrite_pending() {
         "first authority should not be able to accept after overwrite"
     );
 
-    // Second authority should be able to accept
+    // Second authority should be able to accept once the timelock has elapsed
+    warp_to_slot(&mut svm, UNLOCK_SLOT);
     let result = accept_authority(
         &mut svm,
         &program_id,
@@ -243,3 +252,240 @@ fn test_transfer_authority_overwrite_pending() {
         result.err()
     );
 }
+
+// ============================================================================
+// Timelock Tests
+// ============================================================================
+
+/// Test that accept_authority is rejected before the delay has elapsed.
+#[test]
+fn test_accept_authority_before_delay_rejected() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let new_authority = Keypair::new();
+    svm.airdrop(&new_authority.pubkey(), 10_000_000_000)
+        .unwrap();
+
+    transfer_authority(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        &new_authority.pubkey(),
+    )
+    .unwrap();
+
+    // Immediately after initiation
+    let result = accept_authority(&mut svm, &program_id, &global_config, &new_authority);
+    assert!(
+        result.is_err(),
+        "accept_authority should fail immediately after transfer"
+    );
+
+    // One slot before unlock (fresh blockhash so the retry is not deduplicated)
+    warp_to_slot(&mut svm, UNLOCK_SLOT - 1);
+    svm.expire_blockhash();
+    let result = accept_authority(&mut svm, &program_id, &global_config, &new_authority);
+    assert!(
+        result.is_err(),
+        "accept_authority should fail one slot before unlock"
+    );
+
+    // Exactly at unlock
+    warp_to_slot(&mut svm, UNLOCK_SLOT);
+    svm.expire_blockhash();
+    let result = accept_authority(&mut svm, &program_id, &global_config, &new_authority);
+    assert!(
+        result.is_ok(),
+        "accept_authority should succeed at unlock slot: {:?}",
+        result.err()
+    );
+}
+
+/// Test that the current authority can cancel a pending transfer.
+#[test]
+fn test_cancel_authority_transfer() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let new_authority = Keypair::new();
+    svm.airdrop(&new_authority.pubkey(), 10_000_000_000)
+        .unwrap();
+
+    transfer_authority(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        &new_authority.pubkey(),
+    )
+    .unwrap();
+
+    // Pending authority cannot cancel
+    let result = cancel_authority_transfer(&mut svm, &program_id, &global_config, &new_authority);
+    assert!(
+        result.is_err(),
+        "pending authority should not be able to cancel"
+    );
+
+    let result = cancel_authority_transfer(&mut svm, &program_id, &global_config, &authority);
+    assert!(
+        result.is_ok(),
+        "cancel_authority_transfer failed: {:?}",
+        result.err()
+    );
+
+    // Accept fails even after the original unlock slot
+    warp_to_slot(&mut svm, UNLOCK_SLOT);
+    let result = accept_authority(&mut svm, &program_id, &global_config, &new_authority);
+    assert!(
+        result.is_err(),
+        "accept_authority should fail after cancellation"
+    );
+
+    // Nothing left to cancel
+    svm.expire_blockhash();
+    let result = cancel_authority_transfer(&mut svm, &program_id, &global_config, &authority);
+    assert!(
+        result.is_err(),
+        "cancel_authority_transfer should fail with no pending transfer"
+    );
+}
+
+/// Test that re-initiating a transfer restarts the timelock.
+#[test]
+fn test_transfer_authority_reinitiation_resets_clock() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let new_authority = Keypair::new();
+    svm.airdrop(&new_authority.pubkey(), 10_000_000_000)
+        .unwrap();
+
+    transfer_authority(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        &new_authority.pubkey(),
+    )
+    .unwrap();
+
+    // Re-initiate halfway through the delay
+    let reinitiated_slot = UNLOCK_SLOT / 2;
+    warp_to_slot(&mut svm, reinitiated_slot);
+    svm.expire_blockhash();
+    transfer_authority(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        &new_authority.pubkey(),
+    )
+    .unwrap();
+
+    // The original unlock slot no longer suffices
+    warp_to_slot(&mut svm, UNLOCK_SLOT);
+    let result = accept_authority(&mut svm, &program_id, &global_config, &new_authority);
+    assert!(
+        result.is_err(),
+        "accept_authority should fail at the original unlock slot after re-initiation"
+    );
+
+    warp_to_slot(
+        &mut svm,
+        reinitiated_slot + DEFAULT_AUTHORITY_TRANSFER_DELAY_SLOTS,
+    );
+    svm.expire_blockhash();
+    let result = accept_authority(&mut svm, &program_id, &global_config, &new_authority);
+    assert!(
+        result.is_ok(),
+        "accept_authority should succeed at the new unlock slot: {:?}",
+        result.err()
+    );
+}
+
+/// Test updating the transfer delay, including the floor and pending-transfer guard.
+#[test]
+fn test_set_authority_transfer_delay() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let new_authority = Keypair::new();
+    svm.airdrop(&new_authority.pubkey(), 10_000_000_000)
+        .unwrap();
+
+    // Below the floor
+    let result = set_authority_transfer_delay(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        MIN_AUTHORITY_TRANSFER_DELAY_SLOTS - 1,
+    );
+    assert!(result.is_err(), "delay below minimum should be rejected");
+
+    // Unauthorized
+    let result = set_authority_transfer_delay(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &new_authority,
+        MIN_AUTHORITY_TRANSFER_DELAY_SLOTS,
+    );
+    assert!(result.is_err(), "non-authority should not set the delay");
+
+    // At the floor
+    let result = set_authority_transfer_delay(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        MIN_AUTHORITY_TRANSFER_DELAY_SLOTS,
+    );
+    assert!(
+        result.is_ok(),
+        "set_authority_transfer_delay failed: {:?}",
+        result.err()
+    );
+
+    transfer_authority(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        &new_authority.pubkey(),
+    )
+    .unwrap();
+
+    // Cannot change the delay while a transfer is pending
+    let result = set_authority_transfer_delay(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        MIN_AUTHORITY_TRANSFER_DELAY_SLOTS + 1,
+    );
+    assert!(
+        result.is_err(),
+        "delay change should be rejected while a transfer is pending"
+    );
+
+    // New delay applies
+    warp_to_slot(&mut svm, MIN_AUTHORITY_TRANSFER_DELAY_SLOTS);
+    let result = accept_authority(&mut svm, &program_id, &global_config, &new_authority);
+    assert!(
+        result.is_ok(),
+        "accept_authority should succeed after the shortened delay: {:?}",
+        result.err()
+    );
+}
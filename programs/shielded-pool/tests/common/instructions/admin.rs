@@ -9,6 +9,8 @@ use solana_pubkey::Pubkey;
 use solana_signer::Signer;
 use solana_transaction::Transaction;
 
+use crate::common::pda::{SYSTEM_PROGRAM_ID, find_pool_config_pda};
+
 /// Build instruction data with discriminator and Borsh-serialized args.
 fn build_instruction_data<T: BorshSerialize>(discriminator: u8, args: &T) -> Vec<u8> {
     let mut data = vec![discriminator];
@@ -63,6 +65,132 @@ pub fn set_pool_paused(
         .map_err(|e| format!("{:?}", e))
 }
 
+/// Set hub-wide withdrawal-only mode
+pub fn set_withdrawals_only(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    global_config: &Pubkey,
+    authority: &Keypair,
+    withdrawals_only: bool,
+) -> Result<(), String> {
+    #[derive(BorshSerialize)]
+    struct SetWithdrawalsOnlyArgs {
+        withdrawals_only: u8,
+        _padding: [u8; 7],
+    }
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*global_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // shielded_pool_program for CPI events
+        ],
+        data: build_instruction_data(
+            ShieldedPoolInstruction::SetWithdrawalsOnly as u8,
+            &SetWithdrawalsOnlyArgs {
+                withdrawals_only: withdrawals_only as u8,
+                _padding: [0; 7],
+            },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Set the event emission mode (0 = self-CPI, 1 = log data)
+pub fn set_event_emission_mode(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    global_config: &Pubkey,
+    authority: &Keypair,
+    mode: u8,
+) -> Result<(), String> {
+    #[derive(BorshSerialize)]
+    struct SetEventEmissionModeArgs {
+        mode: u8,
+        _padding: [u8; 7],
+    }
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*global_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // shielded_pool_program for CPI events
+        ],
+        data: build_instruction_data(
+            ShieldedPoolInstruction::SetEventEmissionMode as u8,
+            &SetEventEmissionModeArgs {
+                mode,
+                _padding: [0; 7],
+            },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Set whether token pool accumulators may be one finalization stale
+pub fn set_allow_one_epoch_stale(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    global_config: &Pubkey,
+    authority: &Keypair,
+    allow_one_epoch_stale: bool,
+) -> Result<(), String> {
+    #[derive(BorshSerialize)]
+    struct SetAllowOneEpochStaleArgs {
+        allow_one_epoch_stale: u8,
+        _padding: [u8; 7],
+    }
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*global_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // shielded_pool_program for CPI events
+        ],
+        data: build_instruction_data(
+            ShieldedPoolInstruction::SetAllowOneEpochStale as u8,
+            &SetAllowOneEpochStaleArgs {
+                allow_one_epoch_stale: allow_one_epoch_stale as u8,
+                _padding: [0; 7],
+            },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
 /// Transfer authority to a new pending authority
 pub fn transfer_authority(
     svm: &mut LiteSVM,
@@ -123,3 +251,379 @@ pub fn accept_authority(
         .map(|_| ())
         .map_err(|e| format!("{:?}", e))
 }
+
+/// Cancel a pending authority transfer (called by current authority)
+pub fn cancel_authority_transfer(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    global_config: &Pubkey,
+    authority: &Keypair,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*global_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // shielded_pool_program for CPI events
+        ],
+        data: build_instruction_data_no_args(
+            ShieldedPoolInstruction::CancelAuthorityTransfer as u8,
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Set the authority transfer timelock in slots
+pub fn set_authority_transfer_delay(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    global_config: &Pubkey,
+    authority: &Keypair,
+    delay_slots: u64,
+) -> Result<(), String> {
+    #[derive(BorshSerialize)]
+    struct SetAuthorityTransferDelayArgs {
+        delay_slots: u64,
+    }
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*global_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // shielded_pool_program for CPI events
+        ],
+        data: build_instruction_data(
+            ShieldedPoolInstruction::SetAuthorityTransferDelay as u8,
+            &SetAuthorityTransferDelayArgs { delay_slots },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Assign or clear a delegated admin role (0 = pauser, 1 = fee_admin, 2 = registrar)
+pub fn set_role(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    global_config: &Pubkey,
+    authority: &Keypair,
+    role: u8,
+    holder: &Pubkey,
+) -> Result<(), String> {
+    #[derive(BorshSerialize)]
+    struct SetRoleArgs {
+        holder: [u8; 32],
+        role: u8,
+        _padding: [u8; 7],
+    }
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*global_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // shielded_pool_program for CPI events
+        ],
+        data: build_instruction_data(
+            ShieldedPoolInstruction::SetRole as u8,
+            &SetRoleArgs {
+                holder: holder.to_bytes(),
+                role,
+                _padding: [0; 7],
+            },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Set tunable global parameters from `(param_id, value)` pairs
+pub fn set_global_params(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    global_config: &Pubkey,
+    authority: &Keypair,
+    params: &[(u16, u64)],
+) -> Result<(), String> {
+    // Raw data: repeated [param_id: u16][len: u8][value: u64]
+    let mut data = vec![ShieldedPoolInstruction::SetGlobalParams as u8];
+    for (param_id, value) in params {
+        data.extend_from_slice(&param_id.to_le_bytes());
+        data.push(8);
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*global_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // shielded_pool_program for CPI events
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Migrate GlobalConfig to the current layout version
+pub fn migrate_global_config(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    global_config: &Pubkey,
+    authority: &Keypair,
+    payer: &Keypair,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*global_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*program_id, false), // shielded_pool_program for CPI events
+        ],
+        data: build_instruction_data_no_args(ShieldedPoolInstruction::MigrateGlobalConfig as u8),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        &[authority, payer],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Register a token pool with the hub.
+/// Returns the hub PoolConfig PDA.
+pub fn register_token_pool(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    global_config: &Pubkey,
+    token_pool_config: &Pubkey,
+    authority: &Keypair,
+) -> Result<Pubkey, String> {
+    let account = svm
+        .get_account(token_pool_config)
+        .ok_or("token pool config not found")?;
+    let config: &shielded_pool::state::TokenPoolConfig = bytemuck::from_bytes(&account.data[8..]);
+    let (pool_config, _) = find_pool_config_pda(program_id, &config.asset_id);
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*global_config, false),
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new_readonly(*token_pool_config, false),
+            AccountMeta::new(authority.pubkey(), true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*program_id, false), // shielded_pool_program for CPI events
+        ],
+        data: build_instruction_data_no_args(ShieldedPoolInstruction::RegisterTokenPool as u8),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| pool_config)
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Set the active state for a hub pool config
+pub fn set_pool_config_active(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    global_config: &Pubkey,
+    pool_config: &Pubkey,
+    authority: &Keypair,
+    is_active: bool,
+) -> Result<(), String> {
+    #[derive(BorshSerialize)]
+    struct SetPoolConfigActiveArgs {
+        is_active: u8,
+        _padding: [u8; 7],
+    }
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*global_config, false),
+            AccountMeta::new(*pool_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // shielded_pool_program for CPI events
+        ],
+        data: build_instruction_data(
+            ShieldedPoolInstruction::SetPoolConfigActive as u8,
+            &SetPoolConfigActiveArgs {
+                is_active: is_active as u8,
+                _padding: [0; 7],
+            },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Set the deposit/withdrawal mode bits for a hub pool config
+pub fn set_pool_config_mode(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    global_config: &Pubkey,
+    pool_config: &Pubkey,
+    authority: &Keypair,
+    mode: u8,
+) -> Result<(), String> {
+    #[derive(BorshSerialize)]
+    struct SetPoolConfigModeArgs {
+        mode: u8,
+        _padding: [u8; 7],
+    }
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*global_config, false),
+            AccountMeta::new(*pool_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // shielded_pool_program for CPI events
+        ],
+        data: build_instruction_data(
+            ShieldedPoolInstruction::SetPoolConfigMode as u8,
+            &SetPoolConfigModeArgs {
+                mode,
+                _padding: [0; 7],
+            },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Deregister a pool from the hub
+pub fn deregister_pool(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    global_config: &Pubkey,
+    pool_config: &Pubkey,
+    pool_program_config: &Pubkey,
+    authority: &Keypair,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*global_config, false),
+            AccountMeta::new(*pool_config, false),
+            AccountMeta::new_readonly(*pool_program_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // shielded_pool_program for CPI events
+        ],
+        data: build_instruction_data_no_args(ShieldedPoolInstruction::DeregisterPool as u8),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Close a deregistered pool config (rent returned to authority)
+pub fn close_pool_config(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    global_config: &Pubkey,
+    pool_config: &Pubkey,
+    authority: &Keypair,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*global_config, false),
+            AccountMeta::new(*pool_config, false),
+            AccountMeta::new(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // shielded_pool_program for CPI events
+        ],
+        data: build_instruction_data_no_args(ShieldedPoolInstruction::ClosePoolConfig as u8),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
@@ -0,0 +1,205 @@
+//! Deposit escrow instruction helpers.
+
+use borsh::BorshSerialize;
+use litesvm::LiteSVM;
+use shielded_pool::{instructions::ShieldedPoolInstruction, state::DepositEscrowAssetKind};
+use solana_instruction::{AccountMeta, Instruction};
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+use crate::common::mock_accounts::{ASSOCIATED_TOKEN_PROGRAM_ID, get_associated_token_address};
+use crate::common::pda::{
+    SPL_TOKEN_PROGRAM_ID, SYSTEM_PROGRAM_ID, find_deposit_escrow_pda,
+    find_escrow_vault_authority_pda,
+};
+
+/// Slots after creation when helper-created escrows expire.
+pub const DEPOSIT_ESCROW_EXPIRY_SLOTS: u64 = 1_000;
+
+/// Build instruction data with discriminator and Borsh-serialized args.
+fn build_instruction_data<T: BorshSerialize>(discriminator: u8, args: &T) -> Vec<u8> {
+    let mut data = vec![discriminator];
+    args.serialize(&mut data).unwrap();
+    data
+}
+
+/// Initialize a deposit escrow open to any relayer.
+/// Returns the escrow PDA.
+#[allow(clippy::too_many_arguments)]
+pub fn init_deposit_escrow(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    global_config: &Pubkey,
+    depositor: &Keypair,
+    depositor_token: &Pubkey,
+    mint: &Pubkey,
+    nonce: u64,
+    amount: u64,
+) -> Result<Pubkey, String> {
+    init_deposit_escrow_with_kind(
+        svm,
+        program_id,
+        global_config,
+        depositor,
+        depositor_token,
+        mint,
+        nonce,
+        amount,
+        DepositEscrowAssetKind::Token,
+    )
+}
+
+/// Initialize a deposit escrow of the given asset kind open to any relayer.
+/// Native SOL escrows ignore `depositor_token`. Returns the escrow PDA.
+#[allow(clippy::too_many_arguments)]
+pub fn init_deposit_escrow_with_kind(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    global_config: &Pubkey,
+    depositor: &Keypair,
+    depositor_token: &Pubkey,
+    mint: &Pubkey,
+    nonce: u64,
+    amount: u64,
+    asset_kind: DepositEscrowAssetKind,
+) -> Result<Pubkey, String> {
+    #[derive(BorshSerialize)]
+    struct InitDepositEscrowArgs {
+        proof_hash: [u8; 32],
+        nonce: u64,
+        amount: u64,
+        authorized_relayer: [u8; 32],
+        expiry_slots: u64,
+        asset_kind: u8,
+        _padding: [u8; 7],
+    }
+
+    let (escrow, _) = find_deposit_escrow_pda(program_id, &depositor.pubkey(), nonce);
+    let (escrow_vault_authority, _) = find_escrow_vault_authority_pda(program_id, &escrow);
+    let escrow_vault = get_associated_token_address(&escrow_vault_authority, mint);
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(depositor.pubkey(), true),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new_readonly(escrow_vault_authority, false),
+            AccountMeta::new(escrow_vault, false),
+            AccountMeta::new(*depositor_token, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(SPL_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*program_id, false), // shielded_pool_program for CPI events
+            AccountMeta::new_readonly(*global_config, false),
+        ],
+        data: build_instruction_data(
+            ShieldedPoolInstruction::InitDepositEscrow as u8,
+            &InitDepositEscrowArgs {
+                proof_hash: [7u8; 32],
+                nonce,
+                amount,
+                authorized_relayer: [0u8; 32],
+                expiry_slots: DEPOSIT_ESCROW_EXPIRY_SLOTS,
+                asset_kind: asset_kind as u8,
+                _padding: [0u8; 7],
+            },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[depositor],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| escrow)
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Close an expired deposit escrow, returning its funds to the depositor.
+pub fn close_deposit_escrow(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    depositor: &Keypair,
+    depositor_token: &Pubkey,
+    mint: &Pubkey,
+    nonce: u64,
+) -> Result<(), String> {
+    let (escrow, _) = find_deposit_escrow_pda(program_id, &depositor.pubkey(), nonce);
+    let (escrow_vault_authority, _) = find_escrow_vault_authority_pda(program_id, &escrow);
+    let escrow_vault = get_associated_token_address(&escrow_vault_authority, mint);
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(depositor.pubkey(), true),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new_readonly(escrow_vault_authority, false),
+            AccountMeta::new(escrow_vault, false),
+            AccountMeta::new(*depositor_token, false),
+            AccountMeta::new_readonly(SPL_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*program_id, false), // shielded_pool_program for CPI events
+        ],
+        data: build_instruction_data(ShieldedPoolInstruction::CloseDepositEscrow as u8, &nonce),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[depositor],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Change the authorized relayer of `escrow`, signed by `signer`.
+/// `Pubkey::default()` clears the binding.
+pub fn update_deposit_escrow_relayer(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    signer: &Keypair,
+    escrow: &Pubkey,
+    nonce: u64,
+    new_relayer: &Pubkey,
+) -> Result<(), String> {
+    #[derive(BorshSerialize)]
+    struct UpdateDepositEscrowRelayerArgs {
+        new_relayer: [u8; 32],
+        nonce: u64,
+    }
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(signer.pubkey(), true),
+            AccountMeta::new(*escrow, false),
+            AccountMeta::new_readonly(*program_id, false), // shielded_pool_program for CPI events
+        ],
+        data: build_instruction_data(
+            ShieldedPoolInstruction::UpdateDepositEscrowRelayer as u8,
+            &UpdateDepositEscrowRelayerArgs {
+                new_relayer: new_relayer.to_bytes(),
+                nonce,
+            },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
@@ -1,11 +1,15 @@
 //! Instruction helper modules organized by domain.
 
 pub mod admin;
+pub mod deposit_escrow;
+pub mod nullifier_tree;
 pub mod token_config;
 pub mod transact;
 pub mod unified_sol;
 
 pub use admin::*;
+pub use deposit_escrow::*;
+pub use nullifier_tree::*;
 pub use token_config::*;
 pub use transact::*;
 pub use unified_sol::*;
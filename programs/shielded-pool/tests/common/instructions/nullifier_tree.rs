@@ -0,0 +1,125 @@
+//! Nullifier tree instruction helpers.
+
+use borsh::BorshSerialize;
+use litesvm::{LiteSVM, types::TransactionMetadata};
+use shielded_pool::instructions::ShieldedPoolInstruction;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+/// Build instruction data with discriminator and Borsh-serialized args.
+fn build_instruction_data<T: BorshSerialize>(discriminator: u8, args: &T) -> Vec<u8> {
+    let mut data = vec![discriminator];
+    args.serialize(&mut data).unwrap();
+    data
+}
+
+/// Advance the nullifier tree's earliest provable epoch by up to `max_epochs`
+pub fn advance_earliest_provable_epoch(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    nullifier_tree: &Pubkey,
+    global_config: &Pubkey,
+    authority: &Keypair,
+    max_epochs: u64,
+) -> Result<TransactionMetadata, String> {
+    #[derive(BorshSerialize)]
+    struct AdvanceEarliestProvableEpochArgs {
+        max_epochs: u64,
+    }
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*nullifier_tree, false),
+            AccountMeta::new_readonly(*global_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // shielded_pool_program for CPI events
+        ],
+        data: build_instruction_data(
+            ShieldedPoolInstruction::AdvanceEarliestProvableEpoch as u8,
+            &AdvanceEarliestProvableEpochArgs { max_epochs },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx).map_err(|e| format!("{:?}", e))
+}
+
+/// Close an inserted nullifier PDA and send its rent to `destination`
+pub fn close_inserted_nullifier(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    nullifier_tree: &Pubkey,
+    nullifier_pda: &Pubkey,
+    destination: &Pubkey,
+    global_config: &Pubkey,
+    authority: &Keypair,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*nullifier_tree, false),
+            AccountMeta::new(*nullifier_pda, false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new(*global_config, false),
+            AccountMeta::new_readonly(*program_id, false), // shielded_pool_program for CPI events
+        ],
+        data: vec![ShieldedPoolInstruction::CloseInsertedNullifier as u8],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Close a nullifier epoch root PDA and send its rent to `destination`
+pub fn close_nullifier_epoch_root(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    nullifier_tree: &Pubkey,
+    epoch_root: &Pubkey,
+    destination: &Pubkey,
+    global_config: &Pubkey,
+    authority: &Keypair,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*nullifier_tree, false),
+            AccountMeta::new(*epoch_root, false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new(*global_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // shielded_pool_program for CPI events
+        ],
+        data: vec![ShieldedPoolInstruction::CloseNullifierEpochRoot as u8],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
@@ -11,9 +11,8 @@ use solana_signer::Signer;
 use solana_transaction::Transaction;
 use token_pool::TokenPoolInstruction;
 
-use crate::common::pda::{
-    SPL_TOKEN_PROGRAM_ID, SYSTEM_PROGRAM_ID, find_token_config_pda, find_vault_pda,
-};
+use crate::common::mock_accounts::{ASSOCIATED_TOKEN_PROGRAM_ID, get_associated_token_address};
+use crate::common::pda::{SPL_TOKEN_PROGRAM_ID, SYSTEM_PROGRAM_ID, find_token_config_pda};
 
 /// Build instruction data with discriminator and Borsh-serialized args.
 fn build_instruction_data<T: BorshSerialize>(discriminator: u8, args: &T) -> Vec<u8> {
@@ -28,7 +27,7 @@ fn build_instruction_data_no_args(discriminator: u8) -> Vec<u8> {
 }
 
 /// Initialize token pool for a new asset
-/// Returns (pool_config, vault) PDAs
+/// Returns (pool_config PDA, vault ATA)
 pub fn init_token_pool(
     svm: &mut LiteSVM,
     token_pool_program_id: &Pubkey,
@@ -46,7 +45,7 @@ pub fn init_token_pool(
     }
 
     let (pool_config, _) = find_token_config_pda(token_pool_program_id, mint);
-    let (vault, _) = find_vault_pda(token_pool_program_id, &pool_config);
+    let vault = get_associated_token_address(&pool_config, mint);
 
     let ix = Instruction {
         program_id: *token_pool_program_id,
@@ -57,6 +56,7 @@ pub fn init_token_pool(
             AccountMeta::new(authority.pubkey(), true),
             AccountMeta::new_readonly(SPL_TOKEN_PROGRAM_ID, false),
             AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
         ],
         data: build_instruction_data(
             TokenPoolInstruction::InitPool as u8,
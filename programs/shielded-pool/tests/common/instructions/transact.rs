@@ -30,7 +30,9 @@ fn build_instruction_data_no_args(discriminator: u8) -> Vec<u8> {
 struct InitTransactSessionArgs {
     nonce: u64,
     data_len: u32,
-    _padding: [u8; 4],
+    proof_format: u8,
+    _padding: [u8; 3],
+    body_hash: [u8; 32],
 }
 
 #[derive(BorshSerialize)]
@@ -328,20 +330,58 @@ pub fn init_transact_session(
     nonce: u64,
     data_len: u32,
 ) -> Result<Pubkey, String> {
-    let (session_pda, _) = find_transact_session_pda(program_id, &authority.pubkey(), nonce);
+    init_transact_session_with_format(svm, program_id, authority, nonce, data_len, 0)
+}
 
-    let ix = Instruction {
+/// Build an InitTransactSession instruction for the session PDA of `authority` and `nonce`
+pub fn init_transact_session_ix(
+    program_id: &Pubkey,
+    authority: &Pubkey,
+    nonce: u64,
+    data_len: u32,
+    proof_format: u8,
+    body_hash: [u8; 32],
+) -> Instruction {
+    let (session_pda, _) = find_transact_session_pda(program_id, authority, nonce);
+
+    Instruction {
         program_id: *program_id,
         accounts: vec![
             AccountMeta::new(session_pda, false),
-            AccountMeta::new(authority.pubkey(), true),
+            AccountMeta::new(*authority, true),
             AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
         ],
         data: build_instruction_data(
             ShieldedPoolInstruction::InitTransactSession as u8,
-            &InitTransactSessionArgs { nonce, data_len, _padding: [0; 4] },
+            &InitTransactSessionArgs {
+                nonce,
+                data_len,
+                proof_format,
+                _padding: [0; 3],
+                body_hash,
+            },
         ),
-    };
+    }
+}
+
+/// Initialize a transact session with an explicit proof format (0 = compressed, 1 = uncompressed)
+pub fn init_transact_session_with_format(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    authority: &Keypair,
+    nonce: u64,
+    data_len: u32,
+    proof_format: u8,
+) -> Result<Pubkey, String> {
+    let (session_pda, _) = find_transact_session_pda(program_id, &authority.pubkey(), nonce);
+    let ix = init_transact_session_ix(
+        program_id,
+        &authority.pubkey(),
+        nonce,
+        data_len,
+        proof_format,
+        [0; 32],
+    );
 
     let tx = Transaction::new_signed_with_payer(
         &[ix],
@@ -355,26 +395,67 @@ pub fn init_transact_session(
         .map_err(|e| format!("{:?}", e))
 }
 
-/// Upload a chunk to a transact session
-pub fn upload_transact_chunk(
+/// Initialize a compressed-format transact session sized and hashed for `body`
+pub fn init_transact_session_for_body(
     svm: &mut LiteSVM,
     program_id: &Pubkey,
-    session: &Pubkey,
     authority: &Keypair,
+    nonce: u64,
+    body: &[u8],
+) -> Result<Pubkey, String> {
+    let (session_pda, _) = find_transact_session_pda(program_id, &authority.pubkey(), nonce);
+    let ix = init_transact_session_ix(
+        program_id,
+        &authority.pubkey(),
+        nonce,
+        body.len() as u32,
+        0,
+        shielded_pool::utils::sha256(body),
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| session_pda)
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Build an UploadTransactChunk instruction
+pub fn upload_transact_chunk_ix(
+    program_id: &Pubkey,
+    session: &Pubkey,
+    authority: &Pubkey,
     offset: u32,
     data: Vec<u8>,
-) -> Result<(), String> {
-    let ix = Instruction {
+) -> Instruction {
+    Instruction {
         program_id: *program_id,
         accounts: vec![
             AccountMeta::new(*session, false),
-            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*authority, true),
         ],
         data: build_instruction_data(
             ShieldedPoolInstruction::UploadTransactChunk as u8,
             &UploadTransactChunkArgs { offset, data },
         ),
-    };
+    }
+}
+
+/// Upload a chunk to a transact session
+pub fn upload_transact_chunk(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    session: &Pubkey,
+    authority: &Keypair,
+    offset: u32,
+    data: Vec<u8>,
+) -> Result<(), String> {
+    let ix = upload_transact_chunk_ix(program_id, session, &authority.pubkey(), offset, data);
 
     let tx = Transaction::new_signed_with_payer(
         &[ix],
@@ -388,21 +469,30 @@ pub fn upload_transact_chunk(
         .map_err(|e| format!("{:?}", e))
 }
 
-/// Close a transact session
-pub fn close_transact_session(
-    svm: &mut LiteSVM,
+/// Build a CloseTransactSession instruction refunding rent to `closer`
+pub fn close_transact_session_ix(
     program_id: &Pubkey,
     session: &Pubkey,
-    authority: &Keypair,
-) -> Result<(), String> {
-    let ix = Instruction {
+    closer: &Pubkey,
+) -> Instruction {
+    Instruction {
         program_id: *program_id,
         accounts: vec![
             AccountMeta::new(*session, false),
-            AccountMeta::new(authority.pubkey(), true),
+            AccountMeta::new(*closer, true),
         ],
         data: build_instruction_data_no_args(ShieldedPoolInstruction::CloseTransactSession as u8),
-    };
+    }
+}
+
+/// Close a transact session
+pub fn close_transact_session(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    session: &Pubkey,
+    authority: &Keypair,
+) -> Result<(), String> {
+    let ix = close_transact_session_ix(program_id, session, &authority.pubkey());
 
     let tx = Transaction::new_signed_with_payer(
         &[ix],
@@ -457,7 +547,7 @@ pub fn execute_transact(
         AccountMeta::new(*session, false), // 0: transact_session
         AccountMeta::new(*commitment_tree, false), // 1: commitment_tree
         AccountMeta::new(*receipt_tree, false), // 2: receipt_tree
-        AccountMeta::new_readonly(*global_config, false), // 3: global_config
+        AccountMeta::new(*global_config, false), // 3: global_config
         AccountMeta::new(*nullifiers[0], false), // 4: nullifier[0]
         AccountMeta::new(*nullifiers[1], false), // 5: nullifier[1]
         AccountMeta::new(*nullifiers[2], false), // 6: nullifier[2]
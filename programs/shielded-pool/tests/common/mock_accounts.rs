@@ -1,6 +1,7 @@
 //! Mock account creation helpers for testing.
 
 use litesvm::LiteSVM;
+use shielded_pool::state::PoolConfig;
 use solana_account::Account;
 use solana_pubkey::Pubkey;
 
@@ -37,6 +38,29 @@ pub fn create_mock_mint(svm: &mut LiteSVM, decimals: u8) -> Pubkey {
     mint
 }
 
+/// Native (WSOL) mint address
+pub const NATIVE_MINT: Pubkey =
+    solana_pubkey::pubkey!("So11111111111111111111111111111111111111112");
+
+/// Create the native (WSOL) mint account at its canonical address
+pub fn create_native_mint(svm: &mut LiteSVM) -> Pubkey {
+    // SPL Token mint layout (82 bytes), no mint authority, 9 decimals
+    let mut data = vec![0u8; 82];
+    data[44] = 9;
+    data[45] = 1;
+
+    let account = Account {
+        lamports: 1_000_000_000,
+        data,
+        owner: SPL_TOKEN_PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    svm.set_account(NATIVE_MINT, account).unwrap();
+
+    NATIVE_MINT
+}
+
 /// Create a mock SPL Token account
 pub fn create_mock_token_account(
     svm: &mut LiteSVM,
@@ -154,6 +178,17 @@ pub fn update_vault_balance(svm: &mut LiteSVM, vault: &Pubkey, new_balance: u64)
     svm.set_account(*vault, updated).unwrap();
 }
 
+/// Overwrite TokenPoolConfig's finalized_balance field
+pub fn set_token_pool_finalized_balance(svm: &mut LiteSVM, token_pool_config: &Pubkey, balance: u128) {
+    let mut account = svm
+        .get_account(token_pool_config)
+        .expect("token_pool_config should exist");
+    let config: &mut shielded_pool::state::TokenPoolConfig =
+        bytemuck::from_bytes_mut(&mut account.data[8..]);
+    config.finalized_balance = balance;
+    svm.set_account(*token_pool_config, account).unwrap();
+}
+
 /// Update stake pool exchange rate (simulate appreciation)
 pub fn update_stake_pool_rate(
     svm: &mut LiteSVM,
@@ -298,3 +333,9 @@ pub fn create_ata(
 
     ata
 }
+
+/// Read the hub PoolConfig state
+pub fn read_pool_config(svm: &LiteSVM, pool_config: &Pubkey) -> PoolConfig {
+    let account = svm.get_account(pool_config).unwrap();
+    *bytemuck::from_bytes::<PoolConfig>(&account.data[8..])
+}
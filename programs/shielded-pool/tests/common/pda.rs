@@ -3,19 +3,13 @@
 use solana_pubkey::Pubkey;
 
 /// Shielded pool program ID (from centralized zorb-program-ids crate)
-pub const SHIELDED_POOL_PROGRAM_ID: Pubkey = Pubkey::new_from_array(
-    five8_const::decode_32_const(zorb_program_ids::SHIELDED_POOL_ID),
-);
+pub const SHIELDED_POOL_PROGRAM_ID: Pubkey = Pubkey::new_from_array(zorb_program_ids::SHIELDED_POOL_PUBKEY);
 
 /// Token pool program ID (from centralized zorb-program-ids crate)
-pub const TOKEN_POOL_PROGRAM_ID: Pubkey = Pubkey::new_from_array(
-    five8_const::decode_32_const(zorb_program_ids::TOKEN_POOL_ID),
-);
+pub const TOKEN_POOL_PROGRAM_ID: Pubkey = Pubkey::new_from_array(zorb_program_ids::TOKEN_POOL_PUBKEY);
 
 /// Unified SOL pool program ID (from centralized zorb-program-ids crate)
-pub const UNIFIED_SOL_POOL_PROGRAM_ID: Pubkey = Pubkey::new_from_array(
-    five8_const::decode_32_const(zorb_program_ids::UNIFIED_SOL_POOL_ID),
-);
+pub const UNIFIED_SOL_POOL_PROGRAM_ID: Pubkey = Pubkey::new_from_array(zorb_program_ids::UNIFIED_SOL_POOL_PUBKEY);
 
 /// PDA seeds (must match src/instructions/initialize.rs)
 pub const COMMITMENT_TREE_SEED: &[u8] = b"commitment_tree";
@@ -69,11 +63,6 @@ pub fn find_token_config_pda(program_id: &Pubkey, mint: &Pubkey) -> (Pubkey, u8)
     Pubkey::find_program_address(&[TOKEN_CONFIG_SEED, mint.as_ref()], program_id)
 }
 
-/// Derive Vault PDA for token config
-pub fn find_vault_pda(program_id: &Pubkey, token_config: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[VAULT_SEED, token_config.as_ref()], program_id)
-}
-
 /// Nullifier PDA seed
 pub const NULLIFIER_SEED: &[u8] = b"nullifier";
 
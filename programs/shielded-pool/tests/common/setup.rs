@@ -8,6 +8,8 @@ use solana_pubkey::Pubkey;
 use solana_signer::Signer;
 use solana_transaction::Transaction;
 
+use super::instructions::{init_token_pool, register_token_pool};
+use super::mock_accounts::create_mock_mint;
 use super::pda::{
     SHIELDED_POOL_PROGRAM_ID, SYSTEM_PROGRAM_ID, TOKEN_POOL_PROGRAM_ID,
     UNIFIED_SOL_POOL_PROGRAM_ID, derive_pdas,
@@ -87,3 +89,47 @@ pub fn initialize_shielded_pool(
 pub fn warp_to_slot(svm: &mut LiteSVM, slot: u64) {
     svm.warp_to_slot(slot);
 }
+
+/// Create a keypair funded with 10 SOL
+pub fn funded_keypair(svm: &mut LiteSVM) -> Keypair {
+    let keypair = Keypair::new();
+    svm.airdrop(&keypair.pubkey(), 10_000_000_000).unwrap();
+    keypair
+}
+
+/// Initialize the hub, create a token pool and register it.
+/// Returns (program_id, global_config, authority, token_pool_config, pool_config).
+pub fn setup_registered_token_pool(svm: &mut LiteSVM) -> (Pubkey, Pubkey, Keypair, Pubkey, Pubkey) {
+    let program_id = deploy_program(svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(svm, &program_id);
+
+    let token_pool_program_id = deploy_token_pool_program(svm);
+    let mint = create_mock_mint(svm, 6);
+    let (token_pool_config, _) = init_token_pool(
+        svm,
+        &token_pool_program_id,
+        &mint,
+        &authority,
+        u64::MAX,
+        0,
+        0,
+    )
+    .unwrap();
+
+    let pool_config = register_token_pool(
+        svm,
+        &program_id,
+        &global_config,
+        &token_pool_config,
+        &authority,
+    )
+    .unwrap();
+
+    (
+        program_id,
+        global_config,
+        authority,
+        token_pool_config,
+        pool_config,
+    )
+}
@@ -0,0 +1,456 @@
+//! Compute-unit budget regression tests.
+//!
+//! Replays recorded transaction fixtures against the built programs and checks
+//! the compute units of every shielded pool instruction listed in
+//! `tests/compute_budgets.json` against its budget. A failure prints the
+//! previously recorded and the new consumption.
+//!
+//! # Fixtures
+//!
+//! Each `tests/fixtures/compute_budget/*.json` file holds one flow (e.g. init
+//! session, upload chunks, ExecuteTransact, close) with real proofs, captured
+//! from a localnet run of the client:
+//!
+//! ```json
+//! {
+//!   "slot": 1000,
+//!   "signers": ["<base58 keypair>"],
+//!   "accounts": [{ "pubkey": "..", "owner": "..", "lamports": 0,
+//!                  "executable": false, "data": "<base64>" }],
+//!   "transactions": [{ "payer": "..", "instructions": [{ "program_id": "..",
+//!     "accounts": [{ "pubkey": "..", "signer": false, "writable": true }],
+//!     "data": "<base64>" }] }]
+//! }
+//! ```
+//!
+//! `accounts` is the state before the first transaction (program accounts are
+//! deployed from `target/deploy`); `slot` is optional.
+//!
+//! # Updating budgets
+//!
+//! 1. `cargo build-sbf` (without `cu-trace`, whose probes add to the count)
+//! 2. `UPDATE_COMPUTE_BUDGETS=1 cargo test -p shielded-pool --test compute_budget -- --include-ignored`
+//!    rewrites each `recorded` value with the new consumption.
+//! 3. `budget` is only ever raised by hand, in the PR that needs it, with the
+//!    reason in the PR description.
+//!
+//! Building with `--features cu-trace` additionally prints a per-phase
+//! breakdown (see `src/cu_trace.rs`).
+
+mod common;
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use common::*;
+use litesvm::LiteSVM;
+use serde::{Deserialize, Serialize};
+use shielded_pool::instructions::ShieldedPoolInstruction;
+use solana_account::Account;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+const BUDGETS_FILE: &str = "tests/compute_budgets.json";
+const FIXTURES_DIR: &str = "tests/fixtures/compute_budget";
+const UPDATE_ENV: &str = "UPDATE_COMPUTE_BUDGETS";
+const TRACE_PREFIX: &str = "Program log: cu-trace: ";
+
+/// Budget for one instruction, keyed by `ShieldedPoolInstruction` name
+#[derive(Serialize, Deserialize)]
+struct Budget {
+    /// Maximum compute units the instruction may consume
+    budget: u64,
+    /// Consumption when the budgets were last updated
+    recorded: Option<u64>,
+}
+
+type Budgets = BTreeMap<String, Budget>;
+
+/// Compute units used by one top-level shielded pool instruction
+#[derive(Debug, PartialEq)]
+struct Measurement {
+    instruction: String,
+    consumed: u64,
+    /// CU spent in each `cu-trace` phase, starting with `entry` (dispatch
+    /// and account validation); empty without the feature
+    phases: Vec<(String, u64)>,
+}
+
+#[derive(Deserialize)]
+struct Fixture {
+    slot: Option<u64>,
+    signers: Vec<String>,
+    accounts: Vec<FixtureAccount>,
+    transactions: Vec<FixtureTransaction>,
+}
+
+#[derive(Deserialize)]
+struct FixtureAccount {
+    pubkey: String,
+    owner: String,
+    lamports: u64,
+    executable: bool,
+    data: String,
+}
+
+#[derive(Deserialize)]
+struct FixtureTransaction {
+    payer: String,
+    instructions: Vec<FixtureInstruction>,
+}
+
+#[derive(Deserialize)]
+struct FixtureInstruction {
+    program_id: String,
+    accounts: Vec<FixtureAccountMeta>,
+    data: String,
+}
+
+#[derive(Deserialize)]
+struct FixtureAccountMeta {
+    pubkey: String,
+    signer: bool,
+    writable: bool,
+}
+
+fn pubkey(s: &str) -> Pubkey {
+    Pubkey::from_str(s).unwrap_or_else(|e| panic!("invalid pubkey {s}: {e}"))
+}
+
+fn base64(s: &str) -> Vec<u8> {
+    STANDARD.decode(s).expect("invalid base64")
+}
+
+fn manifest_path(relative: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(relative)
+}
+
+fn load_budgets() -> Budgets {
+    let json = std::fs::read_to_string(manifest_path(BUDGETS_FILE)).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+/// Split a transaction's logs into per-instruction measurements.
+///
+/// `names` are the shielded pool top-level instructions of the transaction,
+/// in order.
+fn measure(logs: &[String], names: &[String]) -> Vec<Measurement> {
+    let program = SHIELDED_POOL_PROGRAM_ID.to_string();
+    let invoke = format!("Program {program} invoke [1]");
+    let consumed_prefix = format!("Program {program} consumed ");
+
+    let mut measurements = Vec::new();
+    let mut depth = 0usize;
+    let mut label: Option<String> = None;
+    let mut probes: Vec<(String, u64)> = Vec::new();
+
+    for line in logs {
+        let status = program_status(line);
+        if status.is_some_and(|status| status.starts_with("invoke [")) {
+            depth += 1;
+            if *line == invoke {
+                probes.clear();
+                label = None;
+            }
+        } else if status.is_some_and(|status| status == "success" || status.starts_with("failed: "))
+        {
+            depth = depth.saturating_sub(1);
+        } else if depth != 1 {
+            continue;
+        } else if let Some(phase) = line.strip_prefix(TRACE_PREFIX) {
+            label = Some(phase.to_string());
+        } else if let Some(remaining) = line
+            .strip_prefix("Program consumption: ")
+            .and_then(|rest| rest.strip_suffix(" units remaining"))
+            && let Some(phase) = label.take()
+        {
+            probes.push((phase, remaining.parse().unwrap()));
+        } else if let Some(rest) = line.strip_prefix(&consumed_prefix) {
+            // "<consumed> of <available> compute units"
+            let mut words = rest.split(' ');
+            let consumed: u64 = words.next().unwrap().parse().unwrap();
+            let available: u64 = words.nth(1).unwrap().parse().unwrap();
+            let instruction = names[measurements.len()].clone();
+            measurements.push(Measurement {
+                instruction,
+                consumed,
+                phases: phases(&probes, available, consumed),
+            });
+        }
+    }
+    measurements
+}
+
+/// The text after `Program <program id> ` in a runtime log line.
+fn program_status(line: &str) -> Option<&str> {
+    let (id, status) = line.strip_prefix("Program ")?.split_once(' ')?;
+    Pubkey::from_str(id).ok().map(|_| status)
+}
+
+/// Per-phase CU from the remaining-units readings at each probe.
+fn phases(probes: &[(String, u64)], available: u64, consumed: u64) -> Vec<(String, u64)> {
+    let Some((_, first)) = probes.first() else {
+        return Vec::new();
+    };
+    let mut phases = vec![("entry".to_string(), available - first)];
+    let ends = probes
+        .iter()
+        .skip(1)
+        .map(|(_, remaining)| *remaining)
+        .chain([available - consumed]);
+    for ((phase, remaining), end) in probes.iter().zip(ends) {
+        phases.push((phase.clone(), remaining - end));
+    }
+    phases
+}
+
+/// Check a measurement against its budget, describing an overrun.
+fn check_budget(budget: &Budget, measurement: &Measurement) -> Result<(), String> {
+    if measurement.consumed <= budget.budget {
+        return Ok(());
+    }
+    let previous = budget
+        .recorded
+        .map_or_else(|| "not recorded".to_string(), |cu| format!("{cu} CU"));
+    Err(format!(
+        "{} used {} CU, over its budget of {} CU (previously {previous})",
+        measurement.instruction, measurement.consumed, budget.budget,
+    ))
+}
+
+fn print_measurement(budget: &Budget, measurement: &Measurement) {
+    let previous = budget
+        .recorded
+        .map_or_else(|| "-".to_string(), |cu| cu.to_string());
+    println!(
+        "{}: {} CU (budget {}, previously {previous})",
+        measurement.instruction, measurement.consumed, budget.budget
+    );
+    for (phase, cu) in &measurement.phases {
+        println!("  {phase:>8}: {cu}");
+    }
+}
+
+/// Replay a fixture, returning the measurements of its budgeted instructions.
+fn replay(fixture: &Fixture, budgets: &Budgets) -> Vec<Measurement> {
+    let mut svm = LiteSVM::new();
+    deploy_program(&mut svm);
+    deploy_token_pool_program(&mut svm);
+    deploy_unified_sol_pool_program(&mut svm);
+    if let Some(slot) = fixture.slot {
+        warp_to_slot(&mut svm, slot);
+    }
+
+    for account in &fixture.accounts {
+        svm.set_account(
+            pubkey(&account.pubkey),
+            Account {
+                lamports: account.lamports,
+                data: base64(&account.data),
+                owner: pubkey(&account.owner),
+                executable: account.executable,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+    }
+
+    let signers: Vec<Keypair> = fixture
+        .signers
+        .iter()
+        .map(|s| Keypair::from_base58_string(s))
+        .collect();
+
+    let mut measurements = Vec::new();
+    for tx in &fixture.transactions {
+        let payer = pubkey(&tx.payer);
+        let instructions: Vec<Instruction> = tx
+            .instructions
+            .iter()
+            .map(|ix| Instruction {
+                program_id: pubkey(&ix.program_id),
+                accounts: ix
+                    .accounts
+                    .iter()
+                    .map(|meta| AccountMeta {
+                        pubkey: pubkey(&meta.pubkey),
+                        is_signer: meta.signer,
+                        is_writable: meta.writable,
+                    })
+                    .collect(),
+                data: base64(&ix.data),
+            })
+            .collect();
+        let names: Vec<String> = instructions
+            .iter()
+            .filter(|ix| ix.program_id == SHIELDED_POOL_PROGRAM_ID)
+            .map(|ix| {
+                ShieldedPoolInstruction::try_from(ix.data[0]).map_or_else(
+                    |_| format!("Unknown({})", ix.data[0]),
+                    |i| i.as_ref().to_string(),
+                )
+            })
+            .collect();
+
+        let tx_signers: Vec<&Keypair> = signers
+            .iter()
+            .filter(|kp| {
+                kp.pubkey() == payer
+                    || instructions
+                        .iter()
+                        .flat_map(|ix| &ix.accounts)
+                        .any(|meta| meta.is_signer && meta.pubkey == kp.pubkey())
+            })
+            .collect();
+        let transaction = Transaction::new_signed_with_payer(
+            &instructions,
+            Some(&payer),
+            &tx_signers,
+            svm.latest_blockhash(),
+        );
+        let result = svm
+            .send_transaction(transaction)
+            .unwrap_or_else(|e| panic!("fixture transaction failed: {:?}", e.err));
+        svm.expire_blockhash();
+
+        measurements.extend(
+            measure(&result.logs, &names)
+                .into_iter()
+                .filter(|m| budgets.contains_key(&m.instruction)),
+        );
+    }
+    measurements
+}
+
+/// Replay every fixture and hold each budgeted instruction to its budget.
+#[test]
+#[ignore = "Requires cargo build-sbf and recorded fixtures in tests/fixtures/compute_budget"]
+fn test_compute_budgets() {
+    let mut budgets = load_budgets();
+
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(manifest_path(FIXTURES_DIR))
+        .unwrap_or_else(|e| panic!("{FIXTURES_DIR}: {e}"))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+
+    let mut measurements = Vec::new();
+    for path in &paths {
+        println!("{}", path.display());
+        let fixture: Fixture = serde_json::from_str(&std::fs::read_to_string(path).unwrap())
+            .unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+        for measurement in replay(&fixture, &budgets) {
+            print_measurement(&budgets[&measurement.instruction], &measurement);
+            measurements.push(measurement);
+        }
+    }
+
+    let unmeasured: Vec<&String> = budgets
+        .keys()
+        .filter(|name| !measurements.iter().any(|m| &m.instruction == *name))
+        .collect();
+    assert!(unmeasured.is_empty(), "no fixture exercises {unmeasured:?}");
+
+    let failures: Vec<String> = measurements
+        .iter()
+        .filter_map(|m| check_budget(&budgets[&m.instruction], m).err())
+        .collect();
+
+    if std::env::var_os(UPDATE_ENV).is_some() {
+        for (name, budget) in &mut budgets {
+            budget.recorded = measurements
+                .iter()
+                .filter(|m| &m.instruction == name)
+                .map(|m| m.consumed)
+                .max();
+        }
+        let json = serde_json::to_string_pretty(&budgets).unwrap();
+        std::fs::write(manifest_path(BUDGETS_FILE), json + "\n").unwrap();
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+fn trace_logs(probes: &[(&str, u64)], consumed: u64, available: u64) -> Vec<String> {
+    let program = SHIELDED_POOL_PROGRAM_ID.to_string();
+    let mut logs = vec![
+        "Program ComputeBudget111111111111111111111111111111 invoke [1]".to_string(),
+        "Program ComputeBudget111111111111111111111111111111 success".to_string(),
+        format!("Program {program} invoke [1]"),
+    ];
+    for (phase, remaining) in probes {
+        logs.push(format!("{TRACE_PREFIX}{phase}"));
+        logs.push(format!("Program consumption: {remaining} units remaining"));
+    }
+    // A nested self-CPI Log instruction does not affect the breakdown
+    logs.push(format!("Program {program} invoke [2]"));
+    logs.push("Program consumption: 1 units remaining".to_string());
+    logs.push(format!(
+        "Program {program} consumed 900 of 1000 compute units"
+    ));
+    logs.push(format!("Program {program} success"));
+    logs.push(format!(
+        "Program {program} consumed {consumed} of {available} compute units"
+    ));
+    logs.push(format!("Program {program} success"));
+    logs
+}
+
+#[test]
+fn test_measure_phase_breakdown() {
+    let logs = trace_logs(&[("P1", 1_390_000), ("P10", 1_300_000)], 300_000, 1_400_000);
+    let names = vec!["ExecuteTransact".to_string()];
+    assert_eq!(
+        measure(&logs, &names),
+        vec![Measurement {
+            instruction: "ExecuteTransact".to_string(),
+            consumed: 300_000,
+            phases: vec![
+                ("entry".to_string(), 10_000),
+                ("P1".to_string(), 90_000),
+                ("P10".to_string(), 200_000),
+            ],
+        }]
+    );
+
+    // Without cu-trace only the total is known
+    let logs = trace_logs(&[], 300_000, 1_400_000);
+    assert!(measure(&logs, &names)[0].phases.is_empty());
+}
+
+#[test]
+fn test_budget_overrun_reports_previous_and_new() {
+    let measurement = Measurement {
+        instruction: "ExecuteTransact".to_string(),
+        consumed: 1_130_000,
+        phases: Vec::new(),
+    };
+    let budget = Budget {
+        budget: 1_100_000,
+        recorded: Some(1_070_000),
+    };
+    assert_eq!(
+        check_budget(&budget, &measurement),
+        Err(
+            "ExecuteTransact used 1130000 CU, over its budget of 1100000 CU (previously 1070000 CU)"
+                .to_string()
+        )
+    );
+    let within = Budget {
+        budget: 1_130_000,
+        recorded: None,
+    };
+    assert_eq!(check_budget(&within, &measurement), Ok(()));
+}
+
+#[test]
+fn test_budgets_file_parses() {
+    let budgets = load_budgets();
+    assert_eq!(budgets["ExecuteTransact"].budget, 1_100_000);
+    assert_eq!(budgets["NullifierBatchInsert"].budget, 400_000);
+}
@@ -0,0 +1,263 @@
+//! Shielded pool deposit escrow tests.
+//!
+//! Tests for InitDepositEscrow, CloseDepositEscrow and UpdateDepositEscrowRelayer
+//! with token and native SOL escrows. Consumption in ExecuteTransact needs a verified proof and is covered
+//! by the `DepositEscrow` unit tests and the execute-time helpers.
+
+mod common;
+
+use common::*;
+use litesvm::LiteSVM;
+use shielded_pool::state::{DepositEscrow, DepositEscrowAssetKind};
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+
+/// Slot escrows are created at
+const CREATED_SLOT: u64 = 100;
+
+/// Read the escrow state
+fn read_escrow(svm: &LiteSVM, escrow: &Pubkey) -> DepositEscrow {
+    let account = svm.get_account(escrow).unwrap();
+    *bytemuck::from_bytes::<DepositEscrow>(&account.data[8..DepositEscrow::ACCOUNT_SIZE])
+}
+
+/// Whether an account has been closed (missing or drained)
+fn is_closed(svm: &LiteSVM, address: &Pubkey) -> bool {
+    svm.get_account(address)
+        .is_none_or(|account| account.lamports == 0)
+}
+
+/// Test creating and refunding a native SOL escrow.
+#[test]
+fn test_native_escrow_create_and_refund() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, _) = initialize_shielded_pool(&mut svm, &program_id);
+    let mint = create_native_mint(&mut svm);
+    warp_to_slot(&mut svm, CREATED_SLOT);
+
+    let depositor = funded_keypair(&mut svm);
+    // Native escrows take no source token account
+    let unused_token = Pubkey::new_unique();
+    let amount = 2_000_000_000;
+
+    let escrow = init_deposit_escrow_with_kind(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &depositor,
+        &unused_token,
+        &mint,
+        0,
+        amount,
+        DepositEscrowAssetKind::NativeSol,
+    )
+    .unwrap();
+
+    // Lamports sit on the escrow PDA above rent; the WSOL vault stays empty
+    let rent = svm.minimum_balance_for_rent_exemption(DepositEscrow::ACCOUNT_SIZE);
+    assert_eq!(svm.get_account(&escrow).unwrap().lamports, rent + amount);
+    let state = read_escrow(&svm, &escrow);
+    assert_eq!(state.asset_kind(), Some(DepositEscrowAssetKind::NativeSol));
+    assert_eq!(state.mint, mint.to_bytes());
+
+    let (vault_authority, _) = find_escrow_vault_authority_pda(&program_id, &escrow);
+    let escrow_vault = get_associated_token_address(&vault_authority, &mint);
+    assert_eq!(get_token_balance(&svm, &escrow_vault), 0);
+
+    // Not refundable before expiry
+    let result = close_deposit_escrow(&mut svm, &program_id, &depositor, &unused_token, &mint, 0);
+    assert!(result.is_err(), "unexpired escrow should not close");
+
+    warp_to_slot(&mut svm, CREATED_SLOT + DEPOSIT_ESCROW_EXPIRY_SLOTS + 1);
+    let before = svm.get_account(&depositor.pubkey()).unwrap().lamports;
+    let vault_rent = svm.get_account(&escrow_vault).unwrap().lamports;
+
+    close_deposit_escrow(&mut svm, &program_id, &depositor, &unused_token, &mint, 0).unwrap();
+
+    // Escrowed lamports and both rents come back, less the transaction fee
+    let after = svm.get_account(&depositor.pubkey()).unwrap().lamports;
+    let refunded = amount + rent + vault_rent;
+    assert!(after > before + refunded - 10_000, "lamports not refunded");
+    assert!(after <= before + refunded);
+    assert!(is_closed(&svm, &escrow));
+    assert!(is_closed(&svm, &escrow_vault));
+}
+
+/// Test that a native SOL escrow must use the WSOL mint.
+#[test]
+fn test_native_escrow_requires_wsol_mint() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, _) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let depositor = funded_keypair(&mut svm);
+    let mint = create_mock_mint(&mut svm, 9);
+
+    let result = init_deposit_escrow_with_kind(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &depositor,
+        &Pubkey::new_unique(),
+        &mint,
+        0,
+        1_000_000,
+        DepositEscrowAssetKind::NativeSol,
+    );
+    assert!(result.is_err(), "non-WSOL native escrow should be rejected");
+}
+
+/// Test that token escrows still escrow and refund tokens.
+#[test]
+fn test_token_escrow_create_and_refund() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, _) = initialize_shielded_pool(&mut svm, &program_id);
+    warp_to_slot(&mut svm, CREATED_SLOT);
+
+    let depositor = funded_keypair(&mut svm);
+    let mint = create_mock_mint(&mut svm, 6);
+    let depositor_token = create_mock_token_account(&mut svm, &mint, &depositor.pubkey(), 1_000);
+
+    let escrow = init_deposit_escrow(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &depositor,
+        &depositor_token,
+        &mint,
+        0,
+        400,
+    )
+    .unwrap();
+
+    let rent = svm.minimum_balance_for_rent_exemption(DepositEscrow::ACCOUNT_SIZE);
+    assert_eq!(svm.get_account(&escrow).unwrap().lamports, rent);
+    assert_eq!(
+        read_escrow(&svm, &escrow).asset_kind(),
+        Some(DepositEscrowAssetKind::Token)
+    );
+
+    let (vault_authority, _) = find_escrow_vault_authority_pda(&program_id, &escrow);
+    let escrow_vault = get_associated_token_address(&vault_authority, &mint);
+    assert_eq!(get_token_balance(&svm, &escrow_vault), 400);
+    assert_eq!(get_token_balance(&svm, &depositor_token), 600);
+
+    warp_to_slot(&mut svm, CREATED_SLOT + DEPOSIT_ESCROW_EXPIRY_SLOTS + 1);
+    close_deposit_escrow(
+        &mut svm,
+        &program_id,
+        &depositor,
+        &depositor_token,
+        &mint,
+        0,
+    )
+    .unwrap();
+
+    assert_eq!(get_token_balance(&svm, &depositor_token), 1_000);
+    assert!(is_closed(&svm, &escrow));
+    assert!(is_closed(&svm, &escrow_vault));
+}
+
+/// Create a token escrow for `depositor` with nonce 0, returning its PDA.
+fn token_escrow(svm: &mut LiteSVM, program_id: &Pubkey, depositor: &Keypair) -> Pubkey {
+    let (_, global_config, _, _, _) = initialize_shielded_pool(svm, program_id);
+    warp_to_slot(svm, CREATED_SLOT);
+    let mint = create_mock_mint(svm, 6);
+    let depositor_token = create_mock_token_account(svm, &mint, &depositor.pubkey(), 1_000);
+    init_deposit_escrow(
+        svm,
+        program_id,
+        &global_config,
+        depositor,
+        &depositor_token,
+        &mint,
+        0,
+        400,
+    )
+    .unwrap()
+}
+
+/// Test the depositor rebinding and then clearing an escrow's relayer.
+#[test]
+fn test_update_escrow_relayer() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let depositor = funded_keypair(&mut svm);
+    let escrow = token_escrow(&mut svm, &program_id, &depositor);
+    assert_eq!(read_escrow(&svm, &escrow).relayer_revision, 0);
+
+    let relayer = Pubkey::new_unique();
+    update_deposit_escrow_relayer(&mut svm, &program_id, &depositor, &escrow, 0, &relayer)
+        .unwrap();
+    let state = read_escrow(&svm, &escrow);
+    assert_eq!(state.authorized_relayer, relayer.to_bytes());
+    assert_eq!(state.relayer_revision, 1);
+
+    // Clear the binding to relay the deposit yourself
+    svm.expire_blockhash();
+    update_deposit_escrow_relayer(
+        &mut svm,
+        &program_id,
+        &depositor,
+        &escrow,
+        0,
+        &Pubkey::default(),
+    )
+    .unwrap();
+    let state = read_escrow(&svm, &escrow);
+    assert_eq!(state.authorized_relayer, [0u8; 32]);
+    assert_eq!(state.relayer_revision, 2);
+}
+
+/// Test that only the depositor can change an escrow's relayer.
+#[test]
+fn test_update_escrow_relayer_rejects_non_depositor() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let depositor = funded_keypair(&mut svm);
+    let escrow = token_escrow(&mut svm, &program_id, &depositor);
+
+    let attacker = funded_keypair(&mut svm);
+    let err = update_deposit_escrow_relayer(
+        &mut svm,
+        &program_id,
+        &attacker,
+        &escrow,
+        0,
+        &attacker.pubkey(),
+    )
+    .unwrap_err();
+    assert!(err.contains("Custom(90)"), "expected InvalidEscrowAccount, got {err}");
+    assert_eq!(read_escrow(&svm, &escrow).authorized_relayer, [0u8; 32]);
+}
+
+/// Test that a consumed escrow's relayer can no longer be changed.
+#[test]
+fn test_update_escrow_relayer_rejects_consumed() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let depositor = funded_keypair(&mut svm);
+    let escrow = token_escrow(&mut svm, &program_id, &depositor);
+
+    // Consumption needs a verified proof, so mark the escrow consumed directly
+    let mut account = svm.get_account(&escrow).unwrap();
+    let mut state = read_escrow(&svm, &escrow);
+    state.consumed = 1;
+    account.data[8..DepositEscrow::ACCOUNT_SIZE].copy_from_slice(bytemuck::bytes_of(&state));
+    svm.set_account(escrow, account).unwrap();
+
+    let err = update_deposit_escrow_relayer(
+        &mut svm,
+        &program_id,
+        &depositor,
+        &escrow,
+        0,
+        &Pubkey::new_unique(),
+    )
+    .unwrap_err();
+    assert!(err.contains("Custom(86)"), "expected EscrowAlreadyConsumed, got {err}");
+    assert_eq!(read_escrow(&svm, &escrow).relayer_revision, 0);
+}
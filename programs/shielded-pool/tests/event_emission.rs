@@ -0,0 +1,180 @@
+//! Shielded pool event emission mode tests.
+//!
+//! Tests for SetEventEmissionMode and for the payloads and compute cost of the
+//! self-CPI and direct `sol_log_data` encodings.
+
+mod common;
+
+use borsh::BorshSerialize;
+use common::*;
+use litesvm::{LiteSVM, types::TransactionMetadata};
+use shielded_pool::{
+    instructions::ShieldedPoolInstruction,
+    state::{EventEmissionMode, GlobalConfig},
+};
+use solana_instruction::{AccountMeta, Instruction};
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+/// Read the GlobalConfig event emission mode
+fn read_emission_mode(svm: &LiteSVM, global_config: &Pubkey) -> EventEmissionMode {
+    let account = svm.get_account(global_config).unwrap();
+    let config: &GlobalConfig = bytemuck::from_bytes(&account.data[8..]);
+    config.emission_mode()
+}
+
+/// Send SetPoolPaused and return the transaction metadata
+fn send_set_pool_paused(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    global_config: &Pubkey,
+    authority: &Keypair,
+    is_paused: bool,
+) -> TransactionMetadata {
+    #[derive(BorshSerialize)]
+    struct SetPoolPausedArgs {
+        is_paused: u8,
+        _padding: [u8; 7],
+    }
+
+    let mut data = vec![ShieldedPoolInstruction::SetPoolPaused as u8];
+    SetPoolPausedArgs {
+        is_paused: is_paused as u8,
+        _padding: [0; 7],
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*global_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx).unwrap()
+}
+
+/// Collect the `Program data:` entries from a transaction's logs
+fn program_data_logs(meta: &TransactionMetadata) -> Vec<String> {
+    meta.logs
+        .iter()
+        .filter(|log| log.starts_with("Program data: "))
+        .cloned()
+        .collect()
+}
+
+/// Test that initialize defaults to self-CPI and the authority can switch modes.
+#[test]
+fn test_set_event_emission_mode() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+    assert_eq!(
+        read_emission_mode(&svm, &global_config),
+        EventEmissionMode::SelfCpi
+    );
+
+    set_event_emission_mode(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        EventEmissionMode::LogData as u8,
+    )
+    .unwrap();
+    assert_eq!(
+        read_emission_mode(&svm, &global_config),
+        EventEmissionMode::LogData
+    );
+
+    set_event_emission_mode(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        EventEmissionMode::SelfCpi as u8,
+    )
+    .unwrap();
+    assert_eq!(
+        read_emission_mode(&svm, &global_config),
+        EventEmissionMode::SelfCpi
+    );
+}
+
+/// Test that unknown modes and non-authority signers are rejected.
+#[test]
+fn test_set_event_emission_mode_rejects_invalid() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let result = set_event_emission_mode(&mut svm, &program_id, &global_config, &authority, 2);
+    assert!(result.is_err(), "unknown mode should be rejected");
+
+    let attacker = funded_keypair(&mut svm);
+    let result = set_event_emission_mode(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &attacker,
+        EventEmissionMode::LogData as u8,
+    );
+    assert!(result.is_err(), "non-authority should not set the mode");
+
+    assert_eq!(
+        read_emission_mode(&svm, &global_config),
+        EventEmissionMode::SelfCpi
+    );
+}
+
+/// Test that both modes log identical event bytes and direct logging is cheaper.
+#[test]
+fn test_event_emission_modes_payload_and_cost() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let self_cpi = send_set_pool_paused(&mut svm, &program_id, &global_config, &authority, true);
+    send_set_pool_paused(&mut svm, &program_id, &global_config, &authority, false);
+
+    set_event_emission_mode(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        EventEmissionMode::LogData as u8,
+    )
+    .unwrap();
+
+    // Same instruction as the first pause, so the blockhash must change
+    svm.expire_blockhash();
+    let log_data = send_set_pool_paused(&mut svm, &program_id, &global_config, &authority, true);
+
+    let self_cpi_events = program_data_logs(&self_cpi);
+    assert_eq!(self_cpi_events.len(), 1);
+    assert_eq!(self_cpi_events, program_data_logs(&log_data));
+
+    // Only the self-CPI mode invokes the Log instruction
+    assert!(!self_cpi.inner_instructions.concat().is_empty());
+    assert!(log_data.inner_instructions.concat().is_empty());
+
+    assert!(
+        log_data.compute_units_consumed < self_cpi.compute_units_consumed,
+        "log data mode used {} CU, self-CPI used {} CU",
+        log_data.compute_units_consumed,
+        self_cpi.compute_units_consumed
+    );
+}
@@ -0,0 +1,182 @@
+//! Shielded pool SetGlobalParams tests.
+//!
+//! Tests for batched updates of the tunable protocol parameters on GlobalConfig.
+
+mod common;
+
+use common::*;
+use litesvm::LiteSVM;
+use shielded_pool::state::{AdminRole, GlobalConfig, GlobalParam};
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+
+/// Read the current value of every tunable parameter
+fn read_params(svm: &LiteSVM, global_config: &Pubkey) -> [u64; 3] {
+    let account = svm.get_account(global_config).unwrap();
+    let config: &GlobalConfig = bytemuck::from_bytes(&account.data[8..]);
+    GlobalParam::ALL.map(|param| config.param(param))
+}
+
+/// Test that initialize sets every parameter to its default.
+#[test]
+fn test_global_params_defaults() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, _) = initialize_shielded_pool(&mut svm, &program_id);
+
+    assert_eq!(
+        read_params(&svm, &global_config),
+        GlobalParam::ALL.map(|param| param.default_value())
+    );
+}
+
+/// Test that several parameters are applied in one instruction.
+#[test]
+fn test_set_global_params_multi_param_update() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    set_global_params(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        &[
+            (GlobalParam::MaxRootAge as u16, 64),
+            (GlobalParam::MaxRelayerFeeBps as u16, 250),
+            (GlobalParam::NullifierEpochMinSlots as u16, 18_000),
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(read_params(&svm, &global_config), [64, 250, 18_000]);
+}
+
+/// Test that one out-of-bounds value rejects the whole batch.
+#[test]
+fn test_set_global_params_invalid_param_rejects_batch() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+    let before = read_params(&svm, &global_config);
+
+    // MaxRootAge = 0 is below its minimum of 1
+    let result = set_global_params(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        &[
+            (GlobalParam::MaxRelayerFeeBps as u16, 250),
+            (GlobalParam::MaxRootAge as u16, 0),
+        ],
+    );
+    assert!(result.is_err(), "out-of-bounds value should be rejected");
+    assert_eq!(read_params(&svm, &global_config), before);
+
+    // MaxRelayerFeeBps above 100%
+    let result = set_global_params(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        &[(GlobalParam::MaxRelayerFeeBps as u16, 10_001)],
+    );
+    assert!(result.is_err(), "fee cap above 100% should be rejected");
+    assert_eq!(read_params(&svm, &global_config), before);
+}
+
+/// Test that unknown, duplicate and empty batches are rejected.
+#[test]
+fn test_set_global_params_unknown_param_rejected() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+    let before = read_params(&svm, &global_config);
+
+    let result = set_global_params(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        &[(GlobalParam::MaxRootAge as u16, 64), (99, 1)],
+    );
+    assert!(result.is_err(), "unknown param_id should be rejected");
+
+    let result = set_global_params(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        &[
+            (GlobalParam::MaxRootAge as u16, 64),
+            (GlobalParam::MaxRootAge as u16, 32),
+        ],
+    );
+    assert!(result.is_err(), "duplicate param_id should be rejected");
+
+    let result = set_global_params(&mut svm, &program_id, &global_config, &authority, &[]);
+    assert!(result.is_err(), "empty batch should be rejected");
+
+    assert_eq!(read_params(&svm, &global_config), before);
+}
+
+/// Test that the fee_admin role may set parameters and other signers may not.
+#[test]
+fn test_set_global_params_requires_fee_admin() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let fee_admin = funded_keypair(&mut svm);
+    let result = set_global_params(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &fee_admin,
+        &[(GlobalParam::MaxRelayerFeeBps as u16, 100)],
+    );
+    assert!(result.is_err(), "non-admin should not set params");
+
+    set_role(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        AdminRole::FeeAdmin as u8,
+        &fee_admin.pubkey(),
+    )
+    .unwrap();
+
+    svm.expire_blockhash();
+    set_global_params(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &fee_admin,
+        &[(GlobalParam::MaxRelayerFeeBps as u16, 100)],
+    )
+    .unwrap();
+    assert_eq!(read_params(&svm, &global_config)[1], 100);
+
+    // Other roles do not grant parameter access
+    let pauser = funded_keypair(&mut svm);
+    set_role(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        AdminRole::Pauser as u8,
+        &pauser.pubkey(),
+    )
+    .unwrap();
+    let result = set_global_params(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &pauser,
+        &[(GlobalParam::MaxRelayerFeeBps as u16, 200)],
+    );
+    assert!(result.is_err(), "pauser should not set params");
+}
@@ -3,7 +3,10 @@ mod common;
 use litesvm::LiteSVM;
 use shielded_pool::{
     instructions::ShieldedPoolInstruction,
-    state::{CommitmentMerkleTree, GlobalConfig, NullifierIndexedTree, ReceiptMerkleTree},
+    state::{
+        CommitmentMerkleTree, DEFAULT_AUTHORITY_TRANSFER_DELAY_SLOTS, GlobalConfig,
+        NullifierIndexedTree, ReceiptMerkleTree,
+    },
 };
 use solana_instruction::{AccountMeta, Instruction};
 use solana_keypair::Keypair;
@@ -95,6 +98,10 @@ fn test_initialize() {
 
     let config_state: &GlobalConfig = bytemuck::from_bytes(&config_data.data[discriminator_size..]);
     assert_eq!(config_state.authority, authority.pubkey().to_bytes());
+    assert_eq!(
+        config_state.authority_transfer_delay_slots,
+        DEFAULT_AUTHORITY_TRANSFER_DELAY_SLOTS
+    );
 
     let receipt_tree_state: &ReceiptMerkleTree =
         bytemuck::from_bytes(&receipt_tree_data.data[discriminator_size..]);
@@ -0,0 +1,210 @@
+//! Shielded pool GlobalConfig migration tests.
+//!
+//! Tests for MigrateGlobalConfig and for read paths that must keep working on
+//! GlobalConfig accounts that have not been migrated yet.
+
+mod common;
+
+use common::*;
+use litesvm::LiteSVM;
+use shielded_pool::state::{
+    GLOBAL_CONFIG_V1_SIZE, GLOBAL_CONFIG_V2_SIZE, GLOBAL_CONFIG_VERSION, GlobalConfig, GlobalParam,
+    MIN_PROVABLE_NULLIFIER_EPOCHS, NullifierIndexedTree, ProtocolStats,
+};
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+
+/// Current GlobalConfig account size (discriminator + struct)
+const GLOBAL_CONFIG_SIZE: usize = 8 + core::mem::size_of::<GlobalConfig>();
+
+/// Byte offset of `GlobalConfig.version` in account data (after discriminator)
+const VERSION_OFFSET: usize = 8 + 66;
+
+/// Rewrite the GlobalConfig account as a version 1 account (192 bytes, no version).
+fn downgrade_to_v1(svm: &mut LiteSVM, global_config: &Pubkey) {
+    let mut account = svm.get_account(global_config).unwrap();
+    account.data.truncate(GLOBAL_CONFIG_V1_SIZE);
+    account.data[VERSION_OFFSET..VERSION_OFFSET + 2].fill(0);
+    account.lamports = svm.minimum_balance_for_rent_exemption(GLOBAL_CONFIG_V1_SIZE);
+    svm.set_account(*global_config, account).unwrap();
+}
+
+/// Read the full GlobalConfig (requires a migrated account)
+fn read_global_config(svm: &LiteSVM, global_config: &Pubkey) -> GlobalConfig {
+    let account = svm.get_account(global_config).unwrap();
+    *bytemuck::from_bytes::<GlobalConfig>(&account.data[8..GLOBAL_CONFIG_SIZE])
+}
+
+/// Test that a freshly initialized config is already at the current version.
+#[test]
+fn test_initialize_sets_current_version() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    assert_eq!(
+        read_global_config(&svm, &global_config).version,
+        GLOBAL_CONFIG_VERSION
+    );
+
+    let result = migrate_global_config(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        &authority,
+    );
+    assert!(result.is_err(), "current config should not migrate");
+}
+
+/// Test migrating a version 1 account to the current version.
+#[test]
+fn test_migrate_v1_to_current() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+    downgrade_to_v1(&mut svm, &global_config);
+
+    // Instructions that load the full struct reject the short account
+    let result = set_pool_paused(&mut svm, &program_id, &global_config, &authority, true);
+    assert!(result.is_err(), "v1 account should require migration");
+
+    let payer = funded_keypair(&mut svm);
+    migrate_global_config(&mut svm, &program_id, &global_config, &authority, &payer).unwrap();
+
+    let account = svm.get_account(&global_config).unwrap();
+    assert_eq!(account.data.len(), GLOBAL_CONFIG_SIZE);
+    assert!(account.lamports >= svm.minimum_balance_for_rent_exemption(GLOBAL_CONFIG_SIZE));
+
+    let config = read_global_config(&svm, &global_config);
+    assert_eq!(config.version, GLOBAL_CONFIG_VERSION);
+    assert_eq!(config.authority, authority.pubkey().to_bytes());
+    for param in GlobalParam::ALL {
+        assert_eq!(config.param(param), param.default_value(), "{:?}", param);
+    }
+    assert_eq!(config.stats, ProtocolStats::default());
+
+    // Full-struct instructions work again
+    svm.expire_blockhash();
+    set_pool_paused(&mut svm, &program_id, &global_config, &authority, true).unwrap();
+}
+
+/// Test migrating a version 2 account keeps its parameters and zeroes the stats.
+#[test]
+fn test_migrate_v2_to_current() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    // Version 2 ended before the stats; the version field was set
+    let mut account = svm.get_account(&global_config).unwrap();
+    account.data.truncate(GLOBAL_CONFIG_V2_SIZE);
+    account.data[VERSION_OFFSET..VERSION_OFFSET + 2].copy_from_slice(&2u16.to_le_bytes());
+    account.lamports = svm.minimum_balance_for_rent_exemption(GLOBAL_CONFIG_V2_SIZE);
+    svm.set_account(global_config, account).unwrap();
+
+    let payer = funded_keypair(&mut svm);
+    migrate_global_config(&mut svm, &program_id, &global_config, &authority, &payer).unwrap();
+
+    let config = read_global_config(&svm, &global_config);
+    assert_eq!(config.version, GLOBAL_CONFIG_VERSION);
+    for param in GlobalParam::ALL {
+        assert_eq!(config.param(param), param.default_value(), "{:?}", param);
+    }
+    assert_eq!(config.stats, ProtocolStats::default());
+}
+
+/// Test that a migrated account cannot be migrated again.
+#[test]
+fn test_migrate_twice_rejected() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+    downgrade_to_v1(&mut svm, &global_config);
+
+    migrate_global_config(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        &authority,
+    )
+    .unwrap();
+
+    svm.expire_blockhash();
+    let result = migrate_global_config(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        &authority,
+    );
+    assert!(result.is_err(), "second migration should be rejected");
+    assert_eq!(
+        svm.get_account(&global_config).unwrap().data.len(),
+        GLOBAL_CONFIG_SIZE
+    );
+}
+
+/// Test that only the authority can migrate.
+#[test]
+fn test_migrate_requires_authority() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, _) = initialize_shielded_pool(&mut svm, &program_id);
+    downgrade_to_v1(&mut svm, &global_config);
+
+    let attacker = funded_keypair(&mut svm);
+    let result = migrate_global_config(&mut svm, &program_id, &global_config, &attacker, &attacker);
+    assert!(result.is_err(), "non-authority should not migrate");
+    assert_eq!(
+        svm.get_account(&global_config).unwrap().data.len(),
+        GLOBAL_CONFIG_V1_SIZE
+    );
+}
+
+/// Test that header-only read paths keep working before migration.
+#[test]
+fn test_unmigrated_config_serves_header_reads() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, nullifier_tree, authority) =
+        initialize_shielded_pool(&mut svm, &program_id);
+    downgrade_to_v1(&mut svm, &global_config);
+
+    // Give the tree enough epochs that advancing the earliest epoch is allowed
+    let mut tree_account = svm.get_account(&nullifier_tree).unwrap();
+    let size = core::mem::size_of::<NullifierIndexedTree>();
+    let tree: &mut NullifierIndexedTree =
+        bytemuck::from_bytes_mut(&mut tree_account.data[8..8 + size]);
+    tree.current_epoch = MIN_PROVABLE_NULLIFIER_EPOCHS;
+    svm.set_account(nullifier_tree, tree_account).unwrap();
+
+    // Authority check reads the v1 header
+    let other = funded_keypair(&mut svm);
+    let result = advance_earliest_provable_epoch(
+        &mut svm,
+        &program_id,
+        &nullifier_tree,
+        &global_config,
+        &other,
+        0,
+    );
+    assert!(result.is_err(), "non-authority should be rejected");
+
+    advance_earliest_provable_epoch(
+        &mut svm,
+        &program_id,
+        &nullifier_tree,
+        &global_config,
+        &authority,
+        0,
+    )
+    .unwrap();
+
+    // The account was not touched
+    assert_eq!(
+        svm.get_account(&global_config).unwrap().data.len(),
+        GLOBAL_CONFIG_V1_SIZE
+    );
+}
@@ -2,10 +2,13 @@
 
 mod common;
 
-use litesvm::LiteSVM;
+use litesvm::{LiteSVM, types::TransactionMetadata};
 use shielded_pool::{
     instructions::ShieldedPoolInstruction,
-    state::{NULLIFIER_TREE_HEIGHT, NullifierIndexedTree},
+    state::{
+        MAX_EARLIEST_EPOCH_ADVANCE, MIN_PROVABLE_NULLIFIER_EPOCHS, NULLIFIER_TREE_HEIGHT,
+        NullifierIndexedTree,
+    },
 };
 use solana_instruction::{AccountMeta, Instruction};
 use solana_keypair::Keypair;
@@ -14,7 +17,10 @@ use solana_signer::Signer;
 use solana_transaction::Transaction;
 use std::mem::size_of;
 
-use common::{SYSTEM_PROGRAM_ID, derive_pdas};
+use common::{
+    SYSTEM_PROGRAM_ID, advance_earliest_provable_epoch, deploy_program, derive_pdas,
+    initialize_shielded_pool,
+};
 
 // ============================================================================
 // Tests
@@ -70,3 +76,113 @@ fn test_nullifier_tree_account_size() {
     println!("NullifierIndexedTree size: {} bytes", size);
     assert!(size < 10240, "Account size should be under 10KB");
 }
+
+/// Set the nullifier tree's current and earliest provable epochs
+fn set_tree_epochs(svm: &mut LiteSVM, nullifier_tree: &Pubkey, current: u64, earliest: u64) {
+    let mut account = svm.get_account(nullifier_tree).unwrap();
+    let size = size_of::<NullifierIndexedTree>();
+    let tree: &mut NullifierIndexedTree = bytemuck::from_bytes_mut(&mut account.data[8..8 + size]);
+    tree.current_epoch = current;
+    tree.earliest_provable_epoch = earliest;
+    svm.set_account(*nullifier_tree, account).unwrap();
+}
+
+/// Read the nullifier tree's earliest provable epoch
+fn earliest_provable_epoch(svm: &LiteSVM, nullifier_tree: &Pubkey) -> u64 {
+    let account = svm.get_account(nullifier_tree).unwrap();
+    let size = size_of::<NullifierIndexedTree>();
+    bytemuck::from_bytes::<NullifierIndexedTree>(&account.data[8..8 + size]).earliest_provable_epoch
+}
+
+/// Number of self-CPI events emitted by a transaction
+fn self_cpi_events(meta: &TransactionMetadata, program_id: &Pubkey) -> usize {
+    let invoke = format!("Program {program_id} invoke [2]");
+    meta.logs.iter().filter(|line| **line == invoke).count()
+}
+
+/// Test catching up several epochs per call, one event per epoch.
+#[test]
+fn test_advance_earliest_provable_epoch_multi_step() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, nullifier_tree, authority) =
+        initialize_shielded_pool(&mut svm, &program_id);
+    // 40 epochs behind
+    set_tree_epochs(&mut svm, &nullifier_tree, MIN_PROVABLE_NULLIFIER_EPOCHS + 41, 1);
+
+    let meta = advance_earliest_provable_epoch(
+        &mut svm,
+        &program_id,
+        &nullifier_tree,
+        &global_config,
+        &authority,
+        5,
+    )
+    .unwrap();
+    assert_eq!(earliest_provable_epoch(&svm, &nullifier_tree), 6);
+    assert_eq!(self_cpi_events(&meta, &program_id), 5);
+
+    // Larger requests are capped per call
+    svm.expire_blockhash();
+    let meta = advance_earliest_provable_epoch(
+        &mut svm,
+        &program_id,
+        &nullifier_tree,
+        &global_config,
+        &authority,
+        u64::MAX,
+    )
+    .unwrap();
+    assert_eq!(
+        earliest_provable_epoch(&svm, &nullifier_tree),
+        6 + MAX_EARLIEST_EPOCH_ADVANCE
+    );
+    assert_eq!(
+        self_cpi_events(&meta, &program_id),
+        MAX_EARLIEST_EPOCH_ADVANCE as usize
+    );
+}
+
+/// Test that the target is clamped to keep the minimum provable window.
+#[test]
+fn test_advance_earliest_provable_epoch_clamped() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, nullifier_tree, authority) =
+        initialize_shielded_pool(&mut svm, &program_id);
+    set_tree_epochs(&mut svm, &nullifier_tree, MIN_PROVABLE_NULLIFIER_EPOCHS + 4, 1);
+
+    let meta = advance_earliest_provable_epoch(
+        &mut svm,
+        &program_id,
+        &nullifier_tree,
+        &global_config,
+        &authority,
+        10,
+    )
+    .unwrap();
+    assert_eq!(earliest_provable_epoch(&svm, &nullifier_tree), 4);
+    assert_eq!(self_cpi_events(&meta, &program_id), 3);
+}
+
+/// Test that a caught-up tree is left unchanged without events.
+#[test]
+fn test_advance_earliest_provable_epoch_noop_when_caught_up() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, nullifier_tree, authority) =
+        initialize_shielded_pool(&mut svm, &program_id);
+    set_tree_epochs(&mut svm, &nullifier_tree, MIN_PROVABLE_NULLIFIER_EPOCHS + 4, 4);
+
+    let meta = advance_earliest_provable_epoch(
+        &mut svm,
+        &program_id,
+        &nullifier_tree,
+        &global_config,
+        &authority,
+        MAX_EARLIEST_EPOCH_ADVANCE,
+    )
+    .unwrap();
+    assert_eq!(earliest_provable_epoch(&svm, &nullifier_tree), 4);
+    assert_eq!(self_cpi_events(&meta, &program_id), 0);
+}
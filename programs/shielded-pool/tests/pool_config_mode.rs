@@ -0,0 +1,168 @@
+//! Shielded pool directional mode tests.
+//!
+//! Tests for SetPoolConfigMode and the SetPoolConfigActive compatibility wrapper.
+
+mod common;
+
+use common::*;
+use litesvm::LiteSVM;
+use shielded_pool::state::{
+    AdminRole, POOL_MODE_ALL, POOL_MODE_DEPOSITS_ENABLED, POOL_MODE_WITHDRAWALS_ENABLED,
+};
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+
+/// Test that registration enables both directions.
+#[test]
+fn test_register_enables_both_directions() {
+    let mut svm = LiteSVM::new();
+    let (_, _, _, _, pool_config) = setup_registered_token_pool(&mut svm);
+
+    let state = read_pool_config(&svm, &pool_config);
+    assert_eq!(state.mode, POOL_MODE_ALL);
+    assert!(state.deposits_enabled());
+    assert!(state.withdrawals_enabled());
+}
+
+/// Test every mode combination round-trips through SetPoolConfigMode.
+#[test]
+fn test_set_pool_config_mode_combinations() {
+    let mut svm = LiteSVM::new();
+    let (program_id, global_config, authority, _, pool_config) =
+        setup_registered_token_pool(&mut svm);
+
+    for mode in [
+        POOL_MODE_WITHDRAWALS_ENABLED,
+        0,
+        POOL_MODE_DEPOSITS_ENABLED,
+        POOL_MODE_ALL,
+    ] {
+        set_pool_config_mode(
+            &mut svm,
+            &program_id,
+            &global_config,
+            &pool_config,
+            &authority,
+            mode,
+        )
+        .unwrap();
+
+        let state = read_pool_config(&svm, &pool_config);
+        assert_eq!(state.mode, mode);
+        assert_eq!(
+            state.deposits_enabled(),
+            mode & POOL_MODE_DEPOSITS_ENABLED != 0
+        );
+        assert_eq!(
+            state.withdrawals_enabled(),
+            mode & POOL_MODE_WITHDRAWALS_ENABLED != 0
+        );
+    }
+}
+
+/// Test that unknown mode bits are rejected.
+#[test]
+fn test_set_pool_config_mode_rejects_unknown_bits() {
+    let mut svm = LiteSVM::new();
+    let (program_id, global_config, authority, _, pool_config) =
+        setup_registered_token_pool(&mut svm);
+
+    let result = set_pool_config_mode(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &pool_config,
+        &authority,
+        POOL_MODE_ALL | (1 << 2),
+    );
+    assert!(result.is_err(), "unknown mode bits should be rejected");
+    assert_eq!(read_pool_config(&svm, &pool_config).mode, POOL_MODE_ALL);
+}
+
+/// Test that SetPoolConfigActive sets and clears both direction bits.
+#[test]
+fn test_set_pool_config_active_sets_both_bits() {
+    let mut svm = LiteSVM::new();
+    let (program_id, global_config, authority, _, pool_config) =
+        setup_registered_token_pool(&mut svm);
+
+    // Withdrawal-only pool is fully re-enabled by the wrapper
+    set_pool_config_mode(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &pool_config,
+        &authority,
+        POOL_MODE_WITHDRAWALS_ENABLED,
+    )
+    .unwrap();
+    set_pool_config_active(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &pool_config,
+        &authority,
+        true,
+    )
+    .unwrap();
+    assert_eq!(read_pool_config(&svm, &pool_config).mode, POOL_MODE_ALL);
+
+    set_pool_config_active(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &pool_config,
+        &authority,
+        false,
+    )
+    .unwrap();
+    let state = read_pool_config(&svm, &pool_config);
+    assert_eq!(state.mode, 0);
+    assert!(!state.active());
+}
+
+/// Test that SetPoolConfigMode requires the authority or registrar.
+#[test]
+fn test_set_pool_config_mode_requires_registrar() {
+    let mut svm = LiteSVM::new();
+    let (program_id, global_config, authority, _, pool_config) =
+        setup_registered_token_pool(&mut svm);
+
+    let registrar = Keypair::new();
+    svm.airdrop(&registrar.pubkey(), 10_000_000_000).unwrap();
+
+    let result = set_pool_config_mode(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &pool_config,
+        &registrar,
+        POOL_MODE_WITHDRAWALS_ENABLED,
+    );
+    assert!(result.is_err(), "non-admin should not set pool mode");
+
+    set_role(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        AdminRole::Registrar as u8,
+        &registrar.pubkey(),
+    )
+    .unwrap();
+
+    svm.expire_blockhash();
+    set_pool_config_mode(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &pool_config,
+        &registrar,
+        POOL_MODE_WITHDRAWALS_ENABLED,
+    )
+    .unwrap();
+    assert_eq!(
+        read_pool_config(&svm, &pool_config).mode,
+        POOL_MODE_WITHDRAWALS_ENABLED
+    );
+}
@@ -0,0 +1,247 @@
+//! Shielded pool deregistration tests.
+//!
+//! Tests for DeregisterPool and ClosePoolConfig, and pool kind lookup.
+
+mod common;
+
+use common::*;
+use litesvm::LiteSVM;
+use shielded_pool::state::{POOL_CONFIG_CLOSE_GRACE_SLOTS, PoolConfig};
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+use zorb_pool_interface::PoolKind;
+
+/// Test that registration records the pool kind and that a config whose kind
+/// is not in the hub's pool registry is rejected.
+#[test]
+fn test_pool_kind_registry_lookup() {
+    let mut svm = LiteSVM::new();
+    let (program_id, global_config, authority, token_pool_config, pool_config) =
+        setup_registered_token_pool(&mut svm);
+
+    let state = read_pool_config(&svm, &pool_config);
+    assert_eq!(state.pool_kind, PoolKind::TOKEN.0);
+    assert_eq!(state.pool_kind(), PoolKind::TOKEN);
+
+    // Point the config at an unregistered kind
+    let mut account = svm.get_account(&pool_config).unwrap();
+    let offset = 8 + core::mem::offset_of!(PoolConfig, pool_kind);
+    account.data[offset..offset + 2].copy_from_slice(&0x8000u16.to_le_bytes());
+    svm.set_account(pool_config, account).unwrap();
+
+    let result = deregister_pool(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &pool_config,
+        &token_pool_config,
+        &authority,
+    );
+    let err = result.expect_err("unknown pool kind should be rejected");
+    assert!(err.contains("Custom(130)"), "expected UnknownPoolKind, got {err}");
+}
+
+/// Test that a pool with a non-zero balance cannot be deregistered.
+#[test]
+fn test_deregister_pool_rejects_nonzero_balance() {
+    let mut svm = LiteSVM::new();
+    let (program_id, global_config, authority, token_pool_config, pool_config) =
+        setup_registered_token_pool(&mut svm);
+
+    set_token_pool_finalized_balance(&mut svm, &token_pool_config, 1);
+
+    let result = deregister_pool(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &pool_config,
+        &token_pool_config,
+        &authority,
+    );
+    assert!(
+        result.is_err(),
+        "deregister_pool should fail while the pool holds a balance"
+    );
+    assert!(!read_pool_config(&svm, &pool_config).deregistered());
+
+    // Once drained, deregistration succeeds
+    set_token_pool_finalized_balance(&mut svm, &token_pool_config, 0);
+    svm.expire_blockhash();
+    let result = deregister_pool(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &pool_config,
+        &token_pool_config,
+        &authority,
+    );
+    assert!(result.is_ok(), "deregister_pool failed: {:?}", result.err());
+
+    let state = read_pool_config(&svm, &pool_config);
+    assert!(state.deregistered());
+    assert!(!state.active());
+    assert_eq!(state.closable_at_slot, POOL_CONFIG_CLOSE_GRACE_SLOTS);
+}
+
+/// Test that deregistration requires authority or registrar.
+#[test]
+fn test_deregister_pool_unauthorized() {
+    let mut svm = LiteSVM::new();
+    let (program_id, global_config, _authority, token_pool_config, pool_config) =
+        setup_registered_token_pool(&mut svm);
+
+    let unauthorized = Keypair::new();
+    svm.airdrop(&unauthorized.pubkey(), 10_000_000_000).unwrap();
+
+    let result = deregister_pool(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &pool_config,
+        &token_pool_config,
+        &unauthorized,
+    );
+    assert!(result.is_err(), "deregister_pool should fail with wrong authority");
+}
+
+/// Test that a deregistered pool cannot be reactivated or deregistered twice.
+#[test]
+fn test_deregistered_pool_cannot_be_reactivated() {
+    let mut svm = LiteSVM::new();
+    let (program_id, global_config, authority, token_pool_config, pool_config) =
+        setup_registered_token_pool(&mut svm);
+
+    deregister_pool(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &pool_config,
+        &token_pool_config,
+        &authority,
+    )
+    .unwrap();
+
+    let result = set_pool_config_active(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &pool_config,
+        &authority,
+        true,
+    );
+    assert!(
+        result.is_err(),
+        "deregistered pool config should not be reactivated"
+    );
+
+    // Expire the blockhash so the identical transaction is not deduplicated
+    svm.expire_blockhash();
+    let result = deregister_pool(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &pool_config,
+        &token_pool_config,
+        &authority,
+    );
+    assert!(result.is_err(), "pool should not be deregistered twice");
+}
+
+/// Test that ClosePoolConfig enforces deregistration and the grace period.
+#[test]
+fn test_close_pool_config_grace_period() {
+    let mut svm = LiteSVM::new();
+    let (program_id, global_config, authority, token_pool_config, pool_config) =
+        setup_registered_token_pool(&mut svm);
+
+    // Not deregistered yet
+    let result = close_pool_config(&mut svm, &program_id, &global_config, &pool_config, &authority);
+    assert!(
+        result.is_err(),
+        "close_pool_config should fail before deregistration"
+    );
+
+    deregister_pool(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &pool_config,
+        &token_pool_config,
+        &authority,
+    )
+    .unwrap();
+
+    // Grace period still active
+    warp_to_slot(&mut svm, POOL_CONFIG_CLOSE_GRACE_SLOTS - 1);
+    svm.expire_blockhash();
+    let result = close_pool_config(&mut svm, &program_id, &global_config, &pool_config, &authority);
+    assert!(
+        result.is_err(),
+        "close_pool_config should fail during the grace period"
+    );
+
+    // Grace period elapsed
+    warp_to_slot(&mut svm, POOL_CONFIG_CLOSE_GRACE_SLOTS);
+    svm.expire_blockhash();
+    let rent = svm.get_account(&pool_config).unwrap().lamports;
+    let balance_before = svm.get_balance(&authority.pubkey()).unwrap();
+    let result = close_pool_config(&mut svm, &program_id, &global_config, &pool_config, &authority);
+    assert!(result.is_ok(), "close_pool_config failed: {:?}", result.err());
+
+    // Rent returned to authority (minus the transaction fee)
+    let balance_after = svm.get_balance(&authority.pubkey()).unwrap();
+    assert!(balance_after > balance_before);
+    assert!(balance_after <= balance_before + rent);
+}
+
+/// Test lookups after the PoolConfig has been closed.
+#[test]
+fn test_lookups_after_close() {
+    let mut svm = LiteSVM::new();
+    let (program_id, global_config, authority, token_pool_config, pool_config) =
+        setup_registered_token_pool(&mut svm);
+
+    deregister_pool(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &pool_config,
+        &token_pool_config,
+        &authority,
+    )
+    .unwrap();
+    warp_to_slot(&mut svm, POOL_CONFIG_CLOSE_GRACE_SLOTS);
+    close_pool_config(&mut svm, &program_id, &global_config, &pool_config, &authority).unwrap();
+
+    // The PDA is gone
+    let account = svm.get_account(&pool_config);
+    assert!(
+        account.is_none_or(|a| a.data.is_empty() && a.lamports == 0),
+        "pool config should be closed"
+    );
+
+    // Admin lookups against the closed config fail
+    let result = set_pool_config_active(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &pool_config,
+        &authority,
+        true,
+    );
+    assert!(result.is_err(), "closed pool config should not be loadable");
+
+    // The asset can be registered again at the same PDA
+    svm.expire_blockhash();
+    let result = register_token_pool(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &token_pool_config,
+        &authority,
+    );
+    assert_eq!(result, Ok(pool_config));
+    let state = read_pool_config(&svm, &pool_config);
+    assert!(state.active());
+    assert!(!state.deregistered());
+}
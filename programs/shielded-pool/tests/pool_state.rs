@@ -0,0 +1,202 @@
+//! Shielded pool GetPoolState tests.
+//!
+//! Simulates GetPoolState and checks the returned `PoolStateView` against
+//! direct reads of the tree and config accounts.
+
+mod common;
+
+use common::*;
+use litesvm::LiteSVM;
+use shielded_pool::instructions::{PoolStateView, ShieldedPoolInstruction};
+use shielded_pool::state::{
+    CommitmentMerkleTree, GlobalConfigHeader, NullifierIndexedTree, ReceiptMerkleTree,
+};
+use solana_instruction::{AccountMeta, Instruction};
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+/// Pool PDAs in GetPoolState account order
+struct PoolAccounts {
+    commitment_tree: Pubkey,
+    receipt_tree: Pubkey,
+    nullifier_tree: Pubkey,
+    global_config: Pubkey,
+}
+
+/// Initialize the pool and return its PDAs and authority
+fn setup(svm: &mut LiteSVM) -> (Pubkey, PoolAccounts, Keypair) {
+    let program_id = deploy_program(svm);
+    let (commitment_tree, global_config, receipt_tree, nullifier_tree, authority) =
+        initialize_shielded_pool(svm, &program_id);
+    let accounts = PoolAccounts {
+        commitment_tree,
+        receipt_tree,
+        nullifier_tree,
+        global_config,
+    };
+    (program_id, accounts, authority)
+}
+
+/// Build the GetPoolState instruction
+fn get_pool_state_ix(program_id: &Pubkey, accounts: &PoolAccounts) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(accounts.commitment_tree, false),
+            AccountMeta::new_readonly(accounts.receipt_tree, false),
+            AccountMeta::new_readonly(accounts.nullifier_tree, false),
+            AccountMeta::new_readonly(accounts.global_config, false),
+        ],
+        data: vec![ShieldedPoolInstruction::GetPoolState as u8],
+    }
+}
+
+/// Simulate GetPoolState and decode the return data
+fn get_pool_state(
+    svm: &LiteSVM,
+    program_id: &Pubkey,
+    accounts: &PoolAccounts,
+    payer: &Keypair,
+) -> Result<PoolStateView, String> {
+    let tx = Transaction::new_signed_with_payer(
+        &[get_pool_state_ix(program_id, accounts)],
+        Some(&payer.pubkey()),
+        &[payer],
+        svm.latest_blockhash(),
+    );
+
+    let result = svm
+        .simulate_transaction(tx)
+        .map_err(|e| format!("{:?}", e))?;
+    let data = &result.meta.return_data.data;
+    assert_eq!(data.len(), core::mem::size_of::<PoolStateView>());
+    Ok(*bytemuck::from_bytes(data))
+}
+
+/// Read a zero-copy account struct (after the 8-byte discriminator)
+fn read_account<T: bytemuck::Pod>(svm: &LiteSVM, address: &Pubkey) -> T {
+    let account = svm.get_account(address).unwrap();
+    *bytemuck::from_bytes(&account.data[8..8 + core::mem::size_of::<T>()])
+}
+
+/// Assert the view matches direct reads of the pool accounts
+fn assert_matches_accounts(svm: &LiteSVM, accounts: &PoolAccounts, view: &PoolStateView) {
+    let commitment: CommitmentMerkleTree = read_account(svm, &accounts.commitment_tree);
+    let receipt: ReceiptMerkleTree = read_account(svm, &accounts.receipt_tree);
+    let nullifier: NullifierIndexedTree = read_account(svm, &accounts.nullifier_tree);
+    let config: GlobalConfigHeader = read_account(svm, &accounts.global_config);
+
+    assert_eq!(view.commitment_root, commitment.root);
+    assert_eq!(view.commitment_next_index, commitment.next_index);
+    assert_eq!(view.receipt_next_index, receipt.next_index);
+    assert_eq!(view.nullifier_root, nullifier.root);
+    assert_eq!(view.nullifier_epoch, nullifier.current_epoch);
+    assert_eq!(
+        view.earliest_provable_epoch,
+        nullifier.earliest_provable_epoch
+    );
+    assert_eq!(view.is_paused, config.is_paused);
+    assert_eq!(view.withdrawals_only, config.withdrawals_only);
+}
+
+/// Test that a freshly initialized pool reports its account state.
+#[test]
+fn test_get_pool_state_after_initialize() {
+    let mut svm = LiteSVM::new();
+    let (program_id, accounts, authority) = setup(&mut svm);
+
+    let view = get_pool_state(&svm, &program_id, &accounts, &authority).unwrap();
+    assert_matches_accounts(&svm, &accounts, &view);
+    assert_eq!(view.commitment_next_index, 0);
+    assert_eq!(view.is_paused, 0);
+}
+
+/// Test that the view follows changes to every source account.
+#[test]
+fn test_get_pool_state_tracks_account_changes() {
+    let mut svm = LiteSVM::new();
+    let (program_id, accounts, authority) = setup(&mut svm);
+
+    // Move the trees forward as transacts and epoch advances would
+    let mut account = svm.get_account(&accounts.commitment_tree).unwrap();
+    let size = core::mem::size_of::<CommitmentMerkleTree>();
+    let tree: &mut CommitmentMerkleTree = bytemuck::from_bytes_mut(&mut account.data[8..8 + size]);
+    tree.root = [1u8; 32];
+    tree.next_index = 8;
+    svm.set_account(accounts.commitment_tree, account).unwrap();
+
+    let mut account = svm.get_account(&accounts.receipt_tree).unwrap();
+    let size = core::mem::size_of::<ReceiptMerkleTree>();
+    let tree: &mut ReceiptMerkleTree = bytemuck::from_bytes_mut(&mut account.data[8..8 + size]);
+    tree.next_index = 4;
+    svm.set_account(accounts.receipt_tree, account).unwrap();
+
+    let mut account = svm.get_account(&accounts.nullifier_tree).unwrap();
+    let size = core::mem::size_of::<NullifierIndexedTree>();
+    let tree: &mut NullifierIndexedTree = bytemuck::from_bytes_mut(&mut account.data[8..8 + size]);
+    tree.root = [2u8; 32];
+    tree.current_epoch = 5;
+    tree.earliest_provable_epoch = 3;
+    svm.set_account(accounts.nullifier_tree, account).unwrap();
+
+    set_pool_paused(
+        &mut svm,
+        &program_id,
+        &accounts.global_config,
+        &authority,
+        true,
+    )
+    .unwrap();
+
+    let view = get_pool_state(&svm, &program_id, &accounts, &authority).unwrap();
+    assert_matches_accounts(&svm, &accounts, &view);
+    assert_eq!(view.commitment_root, [1u8; 32]);
+    assert_eq!(view.commitment_next_index, 8);
+    assert_eq!(view.receipt_next_index, 4);
+    assert_eq!(view.nullifier_root, [2u8; 32]);
+    assert_eq!(view.nullifier_epoch, 5);
+    assert_eq!(view.earliest_provable_epoch, 3);
+    assert_eq!(view.is_paused, 1);
+}
+
+/// Test that executing GetPoolState leaves every account unchanged.
+#[test]
+fn test_get_pool_state_is_read_only() {
+    let mut svm = LiteSVM::new();
+    let (program_id, accounts, authority) = setup(&mut svm);
+    let addresses = [
+        accounts.commitment_tree,
+        accounts.receipt_tree,
+        accounts.nullifier_tree,
+        accounts.global_config,
+    ];
+    let before: Vec<_> = addresses
+        .iter()
+        .map(|address| svm.get_account(address).unwrap())
+        .collect();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[get_pool_state_ix(&program_id, &accounts)],
+        Some(&authority.pubkey()),
+        &[&authority],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+
+    for (address, account) in addresses.iter().zip(before) {
+        assert_eq!(svm.get_account(address).unwrap(), account);
+    }
+}
+
+/// Test that accounts are checked by type.
+#[test]
+fn test_get_pool_state_rejects_swapped_accounts() {
+    let mut svm = LiteSVM::new();
+    let (program_id, mut accounts, authority) = setup(&mut svm);
+    core::mem::swap(&mut accounts.commitment_tree, &mut accounts.receipt_tree);
+
+    let result = get_pool_state(&svm, &program_id, &accounts, &authority);
+    assert!(result.is_err(), "swapped trees should be rejected");
+}
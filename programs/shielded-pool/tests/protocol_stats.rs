@@ -0,0 +1,266 @@
+//! Shielded pool protocol statistics tests.
+//!
+//! Tests for the GlobalConfig counters and the GetProtocolStats read path.
+//! ExecuteTransact and NullifierBatchInsert need verified proofs; their
+//! counting is covered by the `ProtocolStats` unit tests.
+
+mod common;
+
+use common::*;
+use litesvm::LiteSVM;
+use panchor::Discriminator;
+use shielded_pool::instructions::ShieldedPoolInstruction;
+use shielded_pool::state::{
+    GLOBAL_CONFIG_V2_SIZE, GlobalConfig, Nullifier, NullifierEpochRoot, NullifierIndexedTree,
+    ProtocolStats,
+};
+use solana_account::Account;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+/// Current GlobalConfig account size (discriminator + struct)
+const GLOBAL_CONFIG_SIZE: usize = 8 + core::mem::size_of::<GlobalConfig>();
+
+/// Epoch the test nullifiers and epoch roots were inserted/finalized in
+const INSERTED_EPOCH: u64 = 1;
+
+/// Simulate GetProtocolStats and decode the return data
+fn get_protocol_stats(
+    svm: &LiteSVM,
+    program_id: &Pubkey,
+    global_config: &Pubkey,
+    payer: &Keypair,
+) -> Result<ProtocolStats, String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![AccountMeta::new_readonly(*global_config, false)],
+        data: vec![ShieldedPoolInstruction::GetProtocolStats as u8],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        &[payer],
+        svm.latest_blockhash(),
+    );
+
+    let result = svm
+        .simulate_transaction(tx)
+        .map_err(|e| format!("{:?}", e))?;
+    Ok(*bytemuck::from_bytes(&result.meta.return_data.data))
+}
+
+/// Read the stats straight from the GlobalConfig account
+fn read_stats(svm: &LiteSVM, global_config: &Pubkey) -> ProtocolStats {
+    let account = svm.get_account(global_config).unwrap();
+    bytemuck::from_bytes::<GlobalConfig>(&account.data[8..GLOBAL_CONFIG_SIZE]).stats
+}
+
+/// Make `INSERTED_EPOCH` no longer provable so its accounts can be closed
+fn advance_tree_past_inserted_epoch(svm: &mut LiteSVM, nullifier_tree: &Pubkey) {
+    let mut account = svm.get_account(nullifier_tree).unwrap();
+    let size = core::mem::size_of::<NullifierIndexedTree>();
+    let tree: &mut NullifierIndexedTree = bytemuck::from_bytes_mut(&mut account.data[8..8 + size]);
+    tree.current_epoch = INSERTED_EPOCH + 1;
+    tree.earliest_provable_epoch = INSERTED_EPOCH + 1;
+    svm.set_account(*nullifier_tree, account).unwrap();
+}
+
+/// Create a program-owned account holding `state` behind its discriminator
+fn set_program_account<T: bytemuck::Pod>(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    address: Pubkey,
+    discriminator: u64,
+    state: &T,
+) -> u64 {
+    let mut data = discriminator.to_le_bytes().to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(state));
+    let lamports = svm.minimum_balance_for_rent_exemption(data.len());
+
+    let account = Account {
+        lamports,
+        data,
+        owner: *program_id,
+        executable: false,
+        rent_epoch: 0,
+    };
+    svm.set_account(address, account).unwrap();
+    lamports
+}
+
+/// Create an inserted nullifier PDA owned by `authority`, returning its rent
+fn create_inserted_nullifier(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    authority: &Pubkey,
+    value: u8,
+) -> (Pubkey, u64) {
+    let (address, bump) = find_nullifier_pda(program_id, &[value; 32]);
+    let nullifier = Nullifier {
+        authority: authority.to_bytes(),
+        pending_index: value as u64,
+        inserted_epoch: INSERTED_EPOCH,
+        bump,
+        _padding: [0u8; 7],
+    };
+    let lamports = set_program_account(
+        svm,
+        program_id,
+        address,
+        Nullifier::DISCRIMINATOR,
+        &nullifier,
+    );
+    (address, lamports)
+}
+
+/// Create the epoch root PDA for `INSERTED_EPOCH`, returning its rent
+fn create_epoch_root(svm: &mut LiteSVM, program_id: &Pubkey) -> (Pubkey, u64) {
+    let (address, bump) = find_nullifier_epoch_root_pda(program_id, INSERTED_EPOCH);
+    let epoch_root = NullifierEpochRoot {
+        root: [7u8; 32],
+        nullifier_epoch: INSERTED_EPOCH,
+        finalized_index: 1,
+        bump,
+        _padding: [0u8; 7],
+    };
+    let lamports = set_program_account(
+        svm,
+        program_id,
+        address,
+        NullifierEpochRoot::DISCRIMINATOR,
+        &epoch_root,
+    );
+    (address, lamports)
+}
+
+/// Test that a fresh pool reports zeroed counters.
+#[test]
+fn test_stats_start_at_zero() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let stats = get_protocol_stats(&svm, &program_id, &global_config, &authority).unwrap();
+    assert_eq!(stats, ProtocolStats::default());
+    assert_eq!(read_stats(&svm, &global_config), stats);
+}
+
+/// Test that GC closes accumulate reclaimed rent across a sequence of operations.
+#[test]
+fn test_gc_accumulates_rent_reclaimed() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, nullifier_tree, authority) =
+        initialize_shielded_pool(&mut svm, &program_id);
+    advance_tree_past_inserted_epoch(&mut svm, &nullifier_tree);
+
+    let owner = funded_keypair(&mut svm);
+    let destination = Pubkey::new_unique();
+    let (nullifier_a, rent_a) =
+        create_inserted_nullifier(&mut svm, &program_id, &owner.pubkey(), 1);
+    let (nullifier_b, rent_b) =
+        create_inserted_nullifier(&mut svm, &program_id, &owner.pubkey(), 2);
+    let (epoch_root, rent_root) = create_epoch_root(&mut svm, &program_id);
+
+    close_inserted_nullifier(
+        &mut svm,
+        &program_id,
+        &nullifier_tree,
+        &nullifier_a,
+        &destination,
+        &global_config,
+        &owner,
+    )
+    .unwrap();
+    assert_eq!(read_stats(&svm, &global_config).rent_reclaimed, rent_a);
+
+    close_inserted_nullifier(
+        &mut svm,
+        &program_id,
+        &nullifier_tree,
+        &nullifier_b,
+        &destination,
+        &global_config,
+        &owner,
+    )
+    .unwrap();
+    assert_eq!(
+        read_stats(&svm, &global_config).rent_reclaimed,
+        rent_a + rent_b
+    );
+
+    close_nullifier_epoch_root(
+        &mut svm,
+        &program_id,
+        &nullifier_tree,
+        &epoch_root,
+        &destination,
+        &global_config,
+        &authority,
+    )
+    .unwrap();
+
+    // A failed close leaves the counters unchanged
+    svm.expire_blockhash();
+    let result = close_inserted_nullifier(
+        &mut svm,
+        &program_id,
+        &nullifier_tree,
+        &nullifier_a,
+        &destination,
+        &global_config,
+        &owner,
+    );
+    assert!(result.is_err(), "closed nullifier should not close again");
+
+    let stats = get_protocol_stats(&svm, &program_id, &global_config, &authority).unwrap();
+    assert_eq!(stats.rent_reclaimed, rent_a + rent_b + rent_root);
+    assert_eq!(
+        svm.get_account(&destination).unwrap().lamports,
+        stats.rent_reclaimed
+    );
+    assert_eq!(stats.transactions_executed, 0);
+    assert_eq!(stats.nullifiers_created, 0);
+    assert_eq!(read_stats(&svm, &global_config), stats);
+}
+
+/// Test that GC keeps working on an unmigrated (version 2) config.
+#[test]
+fn test_gc_on_unmigrated_config_skips_stats() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, nullifier_tree, authority) =
+        initialize_shielded_pool(&mut svm, &program_id);
+    advance_tree_past_inserted_epoch(&mut svm, &nullifier_tree);
+
+    // Version 2 ended before the stats
+    let mut account = svm.get_account(&global_config).unwrap();
+    account.data.truncate(GLOBAL_CONFIG_V2_SIZE);
+    svm.set_account(global_config, account).unwrap();
+
+    let owner = funded_keypair(&mut svm);
+    let (nullifier, _) = create_inserted_nullifier(&mut svm, &program_id, &owner.pubkey(), 1);
+    close_inserted_nullifier(
+        &mut svm,
+        &program_id,
+        &nullifier_tree,
+        &nullifier,
+        &Pubkey::new_unique(),
+        &global_config,
+        &owner,
+    )
+    .unwrap();
+
+    assert_eq!(
+        svm.get_account(&global_config).unwrap().data.len(),
+        GLOBAL_CONFIG_V2_SIZE
+    );
+
+    // The read path needs the current layout
+    let result = get_protocol_stats(&svm, &program_id, &global_config, &authority);
+    assert!(result.is_err(), "unmigrated config has no stats");
+}
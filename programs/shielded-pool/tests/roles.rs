@@ -0,0 +1,292 @@
+//! Shielded pool delegated admin role tests.
+//!
+//! Tests for SetRole and the role-aware permission checks on SetPoolPaused,
+//! RegisterTokenPool and SetPoolConfigActive.
+
+mod common;
+
+use common::*;
+use litesvm::LiteSVM;
+use shielded_pool::state::AdminRole;
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+
+/// Deploy the token pool program and initialize a token pool for a fresh mint.
+/// Returns the TokenPoolConfig address.
+fn setup_token_pool(svm: &mut LiteSVM, payer: &Keypair) -> Pubkey {
+    let token_pool_program_id = deploy_token_pool_program(svm);
+    let mint = create_mock_mint(svm, 6);
+    let (token_pool_config, _) =
+        init_token_pool(svm, &token_pool_program_id, &mint, payer, u64::MAX, 0, 0).unwrap();
+    token_pool_config
+}
+
+// ============================================================================
+// SetRole Tests
+// ============================================================================
+
+/// Test that only the authority can assign roles.
+#[test]
+fn test_set_role_requires_authority() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let pauser = funded_keypair(&mut svm);
+    set_role(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        AdminRole::Pauser as u8,
+        &pauser.pubkey(),
+    )
+    .unwrap();
+
+    // A role holder cannot assign roles (including its own)
+    let other = funded_keypair(&mut svm);
+    let result = set_role(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &pauser,
+        AdminRole::Pauser as u8,
+        &other.pubkey(),
+    );
+    assert!(
+        result.is_err(),
+        "role holder should not be able to set roles"
+    );
+}
+
+/// Test that an unknown role id is rejected.
+#[test]
+fn test_set_role_invalid_role() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let result = set_role(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        3,
+        &Pubkey::new_unique(),
+    );
+    assert!(result.is_err(), "unknown role id should be rejected");
+}
+
+// ============================================================================
+// Pauser Tests
+// ============================================================================
+
+/// Test that the pauser and the authority can both pause and unpause.
+#[test]
+fn test_pauser_can_pause() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let pauser = funded_keypair(&mut svm);
+    set_role(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        AdminRole::Pauser as u8,
+        &pauser.pubkey(),
+    )
+    .unwrap();
+
+    let result = set_pool_paused(&mut svm, &program_id, &global_config, &pauser, true);
+    assert!(
+        result.is_ok(),
+        "pauser should be able to pause: {:?}",
+        result.err()
+    );
+
+    // Authority keeps the capability
+    let result = set_pool_paused(&mut svm, &program_id, &global_config, &authority, false);
+    assert!(
+        result.is_ok(),
+        "authority should still be able to unpause: {:?}",
+        result.err()
+    );
+}
+
+/// Test that unset roles fall back to authority-only, including after clearing.
+#[test]
+fn test_unset_role_falls_back_to_authority() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let pauser = funded_keypair(&mut svm);
+
+    // No pauser set
+    let result = set_pool_paused(&mut svm, &program_id, &global_config, &pauser, true);
+    assert!(
+        result.is_err(),
+        "non-authority should not pause without a role"
+    );
+
+    // Assign and then clear the role
+    set_role(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        AdminRole::Pauser as u8,
+        &pauser.pubkey(),
+    )
+    .unwrap();
+    set_role(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        AdminRole::Pauser as u8,
+        &Pubkey::default(),
+    )
+    .unwrap();
+
+    let result = set_pool_paused(&mut svm, &program_id, &global_config, &pauser, false);
+    assert!(
+        result.is_err(),
+        "cleared pauser should not be able to unpause"
+    );
+
+    let result = set_pool_paused(&mut svm, &program_id, &global_config, &authority, true);
+    assert!(
+        result.is_ok(),
+        "authority should be able to pause: {:?}",
+        result.err()
+    );
+}
+
+// ============================================================================
+// Registrar Tests
+// ============================================================================
+
+/// Test that the registrar can register pools and toggle pool configs.
+#[test]
+fn test_registrar_can_register_and_toggle() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let registrar = funded_keypair(&mut svm);
+    set_role(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        AdminRole::Registrar as u8,
+        &registrar.pubkey(),
+    )
+    .unwrap();
+
+    let token_pool_config = setup_token_pool(&mut svm, &authority);
+
+    let result = register_token_pool(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &token_pool_config,
+        &registrar,
+    );
+    assert!(
+        result.is_ok(),
+        "registrar should be able to register: {:?}",
+        result.err()
+    );
+    let pool_config = result.unwrap();
+
+    let result = set_pool_config_active(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &pool_config,
+        &registrar,
+        false,
+    );
+    assert!(
+        result.is_ok(),
+        "registrar should be able to toggle pool config: {:?}",
+        result.err()
+    );
+}
+
+// ============================================================================
+// Cross-Role Tests
+// ============================================================================
+
+/// Test that each role only grants its own capability.
+#[test]
+fn test_cross_role_rejection() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let pauser = funded_keypair(&mut svm);
+    let fee_admin = funded_keypair(&mut svm);
+    let registrar = funded_keypair(&mut svm);
+    for (role, holder) in [
+        (AdminRole::Pauser, &pauser),
+        (AdminRole::FeeAdmin, &fee_admin),
+        (AdminRole::Registrar, &registrar),
+    ] {
+        set_role(
+            &mut svm,
+            &program_id,
+            &global_config,
+            &authority,
+            role as u8,
+            &holder.pubkey(),
+        )
+        .unwrap();
+    }
+
+    // Registrar and fee admin cannot pause
+    for holder in [&registrar, &fee_admin] {
+        let result = set_pool_paused(&mut svm, &program_id, &global_config, holder, true);
+        assert!(result.is_err(), "only authority or pauser may pause");
+    }
+
+    // Pauser and fee admin cannot register
+    let token_pool_config = setup_token_pool(&mut svm, &authority);
+    for holder in [&pauser, &fee_admin] {
+        let result = register_token_pool(
+            &mut svm,
+            &program_id,
+            &global_config,
+            &token_pool_config,
+            holder,
+        );
+        assert!(result.is_err(), "only authority or registrar may register");
+    }
+
+    // Authority can still register; pauser cannot toggle the result
+    let pool_config = register_token_pool(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &token_pool_config,
+        &authority,
+    )
+    .unwrap();
+    let result = set_pool_config_active(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &pool_config,
+        &pauser,
+        false,
+    );
+    assert!(
+        result.is_err(),
+        "only authority or registrar may toggle pool configs"
+    );
+}
@@ -1,13 +1,26 @@
 //! Transact session lifecycle tests.
 //!
-//! Tests for InitTransactSession, UploadTransactChunk, and CloseTransactSession.
+//! Tests for InitTransactSession, UploadTransactChunk, and CloseTransactSession,
+//! and the session lifecycle events they log.
 
 mod common;
 
+use base64::{Engine, prelude::BASE64_STANDARD};
 use common::*;
-use litesvm::LiteSVM;
+use litesvm::{LiteSVM, types::TransactionMetadata};
+use shielded_pool::{
+    events::{
+        EventSchema, EventType, TransactChunkUploadedEvent, TransactSessionCloseReason,
+        TransactSessionClosedEvent, TransactSessionCreatedEvent, split_event_frame,
+    },
+    instructions::ShieldedPoolInstruction,
+    state::{SESSION_EXPIRY_SLOTS, TRANSACT_SESSION_HEADER_SIZE},
+};
+use solana_instruction::{AccountMeta, Instruction};
 use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
 use solana_signer::Signer;
+use solana_transaction::Transaction;
 
 // ============================================================================
 // Session Lifecycle Tests
@@ -204,3 +217,358 @@ fn test_close_session_wrong_authority() {
     close_transact_session(&mut svm, &program_id, &session, &authority)
         .expect("close should succeed with correct authority");
 }
+
+// ============================================================================
+// Proof Format Tests
+// ============================================================================
+
+/// Offset of `proof_format` in the session account (discriminator + header fields).
+const PROOF_FORMAT_OFFSET: usize = 8 + 32 + 8 + 8 + 4 + 1;
+
+/// Test that the proof format is recorded in the session header.
+#[test]
+fn test_init_session_records_proof_format() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+
+    let (_, _, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let compressed =
+        init_transact_session_with_format(&mut svm, &program_id, &authority, 6, 1024, 0)
+            .expect("compressed session should be created");
+    let uncompressed =
+        init_transact_session_with_format(&mut svm, &program_id, &authority, 7, 1280, 1)
+            .expect("uncompressed session should be created");
+
+    let compressed_account = svm.get_account(&compressed).unwrap();
+    let uncompressed_account = svm.get_account(&uncompressed).unwrap();
+    assert_eq!(compressed_account.data[PROOF_FORMAT_OFFSET], 0);
+    assert_eq!(uncompressed_account.data[PROOF_FORMAT_OFFSET], 1);
+}
+
+/// Test that an unknown proof format is rejected at session creation.
+#[test]
+fn test_init_session_invalid_proof_format() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+
+    let (_, _, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let result = init_transact_session_with_format(&mut svm, &program_id, &authority, 8, 1024, 2);
+    assert!(result.is_err(), "unknown proof format should be rejected");
+}
+
+// ============================================================================
+// Lifecycle Event Tests
+// ============================================================================
+
+/// Offset of `executed` in the session account (discriminator + header fields).
+const EXECUTED_OFFSET: usize = PROOF_FORMAT_OFFSET + 1;
+
+/// Send a single-instruction transaction and return its metadata
+fn send(svm: &mut LiteSVM, ix: Instruction, signer: &Keypair) -> TransactionMetadata {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap()
+}
+
+/// Decode the single `Program data:` event of a transaction as `T`
+fn logged_event<T: bytemuck::Pod + EventSchema>(
+    meta: &TransactionMetadata,
+    event_type: EventType,
+) -> T {
+    let events: Vec<Vec<u8>> = meta
+        .logs
+        .iter()
+        .filter_map(|log| log.strip_prefix("Program data: "))
+        .map(|data| BASE64_STANDARD.decode(data).unwrap())
+        .collect();
+    assert_eq!(events.len(), 1, "expected one event, got {:?}", meta.logs);
+
+    // Logged directly, so no Log inner instruction
+    assert!(meta.inner_instructions.concat().is_empty());
+
+    let (version, payload) =
+        split_event_frame(&events[0], event_type as u64).expect("unexpected event type");
+    assert_eq!(version, T::CURRENT_VERSION);
+    bytemuck::pod_read_unaligned(payload)
+}
+
+/// Test the created, chunk and closed events across a full upload.
+#[test]
+fn test_session_lifecycle_events() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, _, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let nonce = 8u64;
+    let data_len = 1000u32;
+    let (session, _) = find_transact_session_pda(&program_id, &authority.pubkey(), nonce);
+    let created_slot = 100;
+    warp_to_slot(&mut svm, created_slot);
+
+    let meta = send(
+        &mut svm,
+        init_transact_session_ix(
+            &program_id,
+            &authority.pubkey(),
+            nonce,
+            data_len,
+            1,
+            [0; 32],
+        ),
+        &authority,
+    );
+    let created: TransactSessionCreatedEvent =
+        logged_event(&meta, EventType::TransactSessionCreated);
+    assert_eq!(created.session, session.to_bytes());
+    assert_eq!(created.payer, authority.pubkey().to_bytes());
+    assert_eq!(created.nonce, nonce);
+    assert_eq!(created.data_len, data_len);
+    assert_eq!(created.proof_format, 1);
+    assert_eq!(created.slot, created_slot);
+    assert_eq!(created.expiry_slot, created_slot + SESSION_EXPIRY_SLOTS);
+
+    // Totals include the overwrite of the first chunk
+    let uploads = [(0u32, 600u32, 600u32), (600, 400, 1000), (0, 100, 1100)];
+    for (offset, len, total) in uploads {
+        let meta = send(
+            &mut svm,
+            upload_transact_chunk_ix(
+                &program_id,
+                &session,
+                &authority.pubkey(),
+                offset,
+                vec![7u8; len as usize],
+            ),
+            &authority,
+        );
+        let chunk: TransactChunkUploadedEvent =
+            logged_event(&meta, EventType::TransactChunkUploaded);
+        assert_eq!(chunk.session, session.to_bytes());
+        assert_eq!(chunk.offset, offset);
+        assert_eq!(chunk.len, len);
+        assert_eq!(chunk.bytes_received_total, total);
+        assert_eq!(chunk.data_len, data_len);
+    }
+
+    let rent = svm.get_account(&session).unwrap().lamports;
+    let meta = send(
+        &mut svm,
+        close_transact_session_ix(&program_id, &session, &authority.pubkey()),
+        &authority,
+    );
+    let closed: TransactSessionClosedEvent = logged_event(&meta, EventType::TransactSessionClosed);
+    assert_eq!(closed.session, session.to_bytes());
+    assert_eq!(closed.rent_refunded_to, authority.pubkey().to_bytes());
+    assert_eq!(closed.lamports_refunded, rent);
+    assert_eq!(
+        closed.close_reason(),
+        Some(TransactSessionCloseReason::Cancelled)
+    );
+}
+
+/// Test that a third party closing an expired session logs `Expired`.
+#[test]
+fn test_session_expired_close_event() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, _, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let created_slot = 100;
+    warp_to_slot(&mut svm, created_slot);
+    let session = init_transact_session(&mut svm, &program_id, &authority, 9, 1024)
+        .expect("init_transact_session should succeed");
+    let rent = svm.get_account(&session).unwrap().lamports;
+
+    let cleaner = Keypair::new();
+    svm.airdrop(&cleaner.pubkey(), 10_000_000_000).unwrap();
+    warp_to_slot(&mut svm, created_slot + SESSION_EXPIRY_SLOTS);
+
+    let meta = send(
+        &mut svm,
+        close_transact_session_ix(&program_id, &session, &cleaner.pubkey()),
+        &cleaner,
+    );
+    let closed: TransactSessionClosedEvent = logged_event(&meta, EventType::TransactSessionClosed);
+    assert_eq!(closed.session, session.to_bytes());
+    assert_eq!(closed.rent_refunded_to, cleaner.pubkey().to_bytes());
+    assert_eq!(closed.lamports_refunded, rent);
+    assert_eq!(closed.slot, created_slot + SESSION_EXPIRY_SLOTS);
+    assert_eq!(
+        closed.close_reason(),
+        Some(TransactSessionCloseReason::Expired)
+    );
+}
+
+/// Test that closing a session consumed by ExecuteTransact logs `Executed`.
+#[test]
+fn test_session_executed_close_event() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, _, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let session = init_transact_session(&mut svm, &program_id, &authority, 10, 1024)
+        .expect("init_transact_session should succeed");
+
+    // Mark the session executed as ExecuteTransact does on success
+    let mut account = svm.get_account(&session).unwrap();
+    assert!(EXECUTED_OFFSET < TRANSACT_SESSION_HEADER_SIZE);
+    assert_eq!(account.data[EXECUTED_OFFSET], 0);
+    account.data[EXECUTED_OFFSET] = 1;
+    svm.set_account(session, account).unwrap();
+
+    let meta = send(
+        &mut svm,
+        close_transact_session_ix(&program_id, &session, &authority.pubkey()),
+        &authority,
+    );
+    let closed: TransactSessionClosedEvent = logged_event(&meta, EventType::TransactSessionClosed);
+    assert_eq!(
+        closed.close_reason(),
+        Some(TransactSessionCloseReason::Executed)
+    );
+}
+
+// ============================================================================
+// Upload Integrity Tests
+// ============================================================================
+
+/// Chunk size used by the integrity tests (fits in one transaction)
+const INTEGRITY_CHUNK_SIZE: usize = 900;
+
+/// Deterministic non-zero session body
+fn test_body(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i * 31 % 251) as u8).collect()
+}
+
+/// Upload `body` in fixed-size chunks, returning the compute units of each upload
+fn upload_body(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    session: &Pubkey,
+    authority: &Keypair,
+    body: &[u8],
+) -> Vec<u64> {
+    body.chunks(INTEGRITY_CHUNK_SIZE)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let offset = (i * INTEGRITY_CHUNK_SIZE) as u32;
+            let ix = upload_transact_chunk_ix(
+                program_id,
+                session,
+                &authority.pubkey(),
+                offset,
+                chunk.to_vec(),
+            );
+            send(svm, ix, authority).compute_units_consumed
+        })
+        .collect()
+}
+
+/// Send ExecuteTransact with only the fixed accounts and no public slots.
+///
+/// Enough to reach session parsing (P2), which runs before remaining accounts are loaded.
+fn execute_without_slots(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    session: &Pubkey,
+    authority: &Keypair,
+) -> Result<(), String> {
+    let (commitment_tree, global_config, receipt_tree, nullifier_tree) = derive_pdas(program_id);
+
+    let mut accounts = vec![
+        AccountMeta::new(*session, false),
+        AccountMeta::new(commitment_tree, false),
+        AccountMeta::new(receipt_tree, false),
+        AccountMeta::new(nullifier_tree, false),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false), // epoch_root_pda placeholder
+        AccountMeta::new(global_config, false),
+    ];
+    for _ in 0..N_INS {
+        accounts.push(AccountMeta::new(Pubkey::new_unique(), false));
+    }
+    accounts.extend([
+        AccountMeta::new_readonly(authority.pubkey(), true), // relayer
+        AccountMeta::new_readonly(SPL_TOKEN_PROGRAM_ID, false),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        AccountMeta::new(authority.pubkey(), true), // payer
+        AccountMeta::new_readonly(*program_id, false),
+    ]);
+
+    // ExecuteTransactData: no reward configs, no public slots
+    let mut data = vec![ShieldedPoolInstruction::ExecuteTransact as u8];
+    data.extend_from_slice(&[0u8; 8]);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: *program_id,
+            accounts,
+            data,
+        }],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Test that a chunk corrupted in transit is caught at execute before parsing.
+#[test]
+fn test_execute_rejects_corrupted_chunk() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, _, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let body = test_body(2_000);
+    let session = init_transact_session_for_body(&mut svm, &program_id, &authority, 11, &body)
+        .expect("init_transact_session should succeed");
+
+    // One flipped bit in the second chunk
+    let mut corrupted = body.clone();
+    corrupted[INTEGRITY_CHUNK_SIZE + 17] ^= 0x04;
+    upload_body(&mut svm, &program_id, &session, &authority, &corrupted);
+
+    let err = execute_without_slots(&mut svm, &program_id, &session, &authority).unwrap_err();
+    assert!(
+        err.contains("Custom(96)"),
+        "expected SessionDataCorrupted, got {err}"
+    );
+
+    // Re-uploading the chunk repairs the session; execute now gets past the hash check
+    svm.expire_blockhash();
+    upload_body(&mut svm, &program_id, &session, &authority, &body);
+    let err = execute_without_slots(&mut svm, &program_id, &session, &authority).unwrap_err();
+    assert!(
+        !err.contains("Custom(96)"),
+        "intact body should pass the integrity check, got {err}"
+    );
+}
+
+/// Test that integrity checking adds no per-chunk cost to uploads.
+///
+/// The body is hashed once at execute, so uploads cost the same whether or not
+/// the session declared a real body hash.
+#[test]
+fn test_upload_integrity_overhead() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, _, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let body = test_body(2_000);
+    let hashed = init_transact_session_for_body(&mut svm, &program_id, &authority, 12, &body)
+        .expect("init_transact_session should succeed");
+    let unhashed = init_transact_session(&mut svm, &program_id, &authority, 13, body.len() as u32)
+        .expect("init_transact_session should succeed");
+
+    let hashed_cu = upload_body(&mut svm, &program_id, &hashed, &authority, &body);
+    let unhashed_cu = upload_body(&mut svm, &program_id, &unhashed, &authority, &body);
+    println!("Upload CU per chunk: {:?}", hashed_cu);
+    assert_eq!(hashed_cu, unhashed_cu);
+}
@@ -0,0 +1,26 @@
+//! SBF stack frame regression test.
+//!
+//! Runs `scripts/check-stack-frames.sh`, which rebuilds the program with
+//! `cargo build-sbf` and fails when the linker reports a stack offset overflow
+//! for `process_execute_transact`. Run it after touching the transact handler:
+//!
+//! ```bash
+//! cargo test -p shielded-pool --test stack_frames -- --include-ignored
+//! ```
+
+use std::path::Path;
+use std::process::Command;
+
+/// Build the program and check process_execute_transact fits its stack frame.
+#[test]
+#[ignore = "Requires cargo build-sbf; rebuilds target/deploy/shielded_pool.so"]
+fn test_execute_transact_stack_frame() {
+    let script = Path::new(env!("CARGO_MANIFEST_DIR")).join("scripts/check-stack-frames.sh");
+
+    let status = Command::new("bash")
+        .arg(&script)
+        .status()
+        .unwrap_or_else(|e| panic!("{}: {e}", script.display()));
+
+    assert!(status.success(), "{} failed", script.display());
+}
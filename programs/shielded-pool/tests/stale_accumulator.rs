@@ -0,0 +1,57 @@
+//! Shielded pool stale accumulator policy tests.
+//!
+//! Tests for SetAllowOneEpochStale. Acceptance of the previous accumulator in
+//! ExecuteTransact is covered by `token_accumulator_accepted` unit tests.
+
+mod common;
+
+use common::*;
+use litesvm::LiteSVM;
+use shielded_pool::state::{AdminRole, GlobalConfig};
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+
+/// Read the GlobalConfig stale accumulator flag
+fn read_allow_one_epoch_stale(svm: &LiteSVM, global_config: &Pubkey) -> bool {
+    let account = svm.get_account(global_config).unwrap();
+    let config: &GlobalConfig = bytemuck::from_bytes(&account.data[8..]);
+    config.allows_one_epoch_stale()
+}
+
+/// Test toggling the policy by the authority.
+#[test]
+fn test_set_allow_one_epoch_stale() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+    assert!(!read_allow_one_epoch_stale(&svm, &global_config));
+
+    set_allow_one_epoch_stale(&mut svm, &program_id, &global_config, &authority, true).unwrap();
+    assert!(read_allow_one_epoch_stale(&svm, &global_config));
+
+    set_allow_one_epoch_stale(&mut svm, &program_id, &global_config, &authority, false).unwrap();
+    assert!(!read_allow_one_epoch_stale(&svm, &global_config));
+}
+
+/// Test that delegated roles cannot change the policy.
+#[test]
+fn test_set_allow_one_epoch_stale_unauthorized() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let pauser = funded_keypair(&mut svm);
+    set_role(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        AdminRole::Pauser as u8,
+        &pauser.pubkey(),
+    )
+    .unwrap();
+
+    let result = set_allow_one_epoch_stale(&mut svm, &program_id, &global_config, &pauser, true);
+    assert!(result.is_err(), "pauser should not change the policy");
+    assert!(!read_allow_one_epoch_stale(&svm, &global_config));
+}
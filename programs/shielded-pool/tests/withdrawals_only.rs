@@ -0,0 +1,123 @@
+//! Shielded pool withdrawal-only mode tests.
+//!
+//! Tests for SetWithdrawalsOnly and the InitDepositEscrow block. Slot-level
+//! enforcement in ExecuteTransact is covered by `validate_withdrawals_only`
+//! unit tests.
+
+mod common;
+
+use common::*;
+use litesvm::LiteSVM;
+use shielded_pool::state::{AdminRole, GlobalConfig};
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+
+/// Read the GlobalConfig withdrawal-only flag
+fn read_withdrawals_only(svm: &LiteSVM, global_config: &Pubkey) -> bool {
+    let account = svm.get_account(global_config).unwrap();
+    let config: &GlobalConfig = bytemuck::from_bytes(&account.data[8..]);
+    config.deposits_disabled()
+}
+
+/// Test toggling withdrawal-only mode by the authority and the pauser.
+#[test]
+fn test_set_withdrawals_only() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+    assert!(!read_withdrawals_only(&svm, &global_config));
+
+    set_withdrawals_only(&mut svm, &program_id, &global_config, &authority, true).unwrap();
+    assert!(read_withdrawals_only(&svm, &global_config));
+
+    // Withdrawal-only mode is independent of the global pause
+    let account = svm.get_account(&global_config).unwrap();
+    let config: &GlobalConfig = bytemuck::from_bytes(&account.data[8..]);
+    assert!(!config.paused());
+
+    let pauser = funded_keypair(&mut svm);
+    set_role(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        AdminRole::Pauser as u8,
+        &pauser.pubkey(),
+    )
+    .unwrap();
+    set_withdrawals_only(&mut svm, &program_id, &global_config, &pauser, false).unwrap();
+    assert!(!read_withdrawals_only(&svm, &global_config));
+}
+
+/// Test that signers without the pauser role cannot change the mode.
+#[test]
+fn test_set_withdrawals_only_unauthorized() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let registrar = funded_keypair(&mut svm);
+    set_role(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &authority,
+        AdminRole::Registrar as u8,
+        &registrar.pubkey(),
+    )
+    .unwrap();
+
+    let result = set_withdrawals_only(&mut svm, &program_id, &global_config, &registrar, true);
+    assert!(
+        result.is_err(),
+        "registrar should not set withdrawal-only mode"
+    );
+    assert!(!read_withdrawals_only(&svm, &global_config));
+}
+
+/// Test that deposit escrows cannot be created in withdrawal-only mode.
+#[test]
+fn test_init_deposit_escrow_blocked_in_withdrawals_only() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_program(&mut svm);
+    let (_, global_config, _, _, authority) = initialize_shielded_pool(&mut svm, &program_id);
+
+    let depositor = funded_keypair(&mut svm);
+    let mint = create_mock_mint(&mut svm, 6);
+    let depositor_token = create_mock_token_account(&mut svm, &mint, &depositor.pubkey(), 1_000);
+
+    set_withdrawals_only(&mut svm, &program_id, &global_config, &authority, true).unwrap();
+
+    let result = init_deposit_escrow(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &depositor,
+        &depositor_token,
+        &mint,
+        0,
+        500,
+    );
+    assert!(
+        result.is_err(),
+        "escrow should be rejected in withdrawal-only mode"
+    );
+    assert_eq!(get_token_balance(&svm, &depositor_token), 1_000);
+
+    // Escrows work again once deposits are re-enabled
+    set_withdrawals_only(&mut svm, &program_id, &global_config, &authority, false).unwrap();
+    svm.expire_blockhash();
+    let escrow = init_deposit_escrow(
+        &mut svm,
+        &program_id,
+        &global_config,
+        &depositor,
+        &depositor_token,
+        &mint,
+        0,
+        500,
+    )
+    .unwrap();
+    assert!(svm.get_account(&escrow).is_some());
+    assert_eq!(get_token_balance(&svm, &depositor_token), 500);
+}
@@ -40,7 +40,7 @@ pub enum TokenPoolError {
     InvalidMint = 15,
     /// Invalid system program
     InvalidSystemProgram = 16,
-    /// Pool already initialized
+    /// Pool already initialized with different parameters
     AlreadyInitialized = 17,
     /// Expected output doesn't match computed value
     ExpectedOutputMismatch = 18,
@@ -50,6 +50,63 @@ pub enum TokenPoolError {
     InvalidVaultPda = 20,
     /// Invalid pool config PDA address
     InvalidPoolConfigPda = 21,
+    /// Amount exceeds accrued protocol fees
+    InsufficientProtocolFees = 22,
+    /// Treasury is unset or the token account is not its canonical ATA
+    InvalidTreasuryAccount = 23,
+    /// Pool still holds pending balances, fees, or vault tokens
+    PoolNotEmpty = 24,
+    /// Hub PoolConfig is still active or is not the canonical PDA
+    HubPoolStillRegistered = 25,
+    /// Mint has a Token-2022 extension the pool cannot hold
+    UnsupportedMintExtension = 26,
+    /// Token-2022 pools need the mint as a trailing account
+    MissingMintAccount = 27,
+    /// Token-2022 transfer fee on a deposit exceeds the pool's deposit fee
+    TransferFeeExceedsPoolFee = 28,
+    /// Deposit is below the pool's minimum deposit
+    DepositBelowMinimum = 29,
+    /// Deposit exceeds the pool's single-deposit cap
+    SingleDepositCapExceeded = 30,
+    /// Deposit would push the accounted pool balance over its cap
+    PoolCapExceeded = 31,
+    /// Finalize interval is outside the allowed bounds
+    InvalidFinalizeInterval = 32,
+    /// Amount exceeds the untracked excess held in the vault
+    InsufficientExcess = 33,
+    /// Fee-exempt list has no free slot
+    FeeExemptListFull = 34,
+    /// Hub's `expect_fee_exempt` disagrees with the pool's fee-exempt list
+    FeeExemptionMismatch = 35,
+    /// Authority is not on the fee-exempt list
+    FeeExemptNotListed = 36,
+    /// `ReconcileVault` found the vault below its expected balance
+    VaultDeficitDetected = 37,
+    /// An emergency withdrawal is already pending
+    EmergencyWithdrawPending = 38,
+    /// No emergency withdrawal is pending
+    NoEmergencyWithdrawPending = 39,
+    /// Emergency withdrawal delay has not passed
+    EmergencyWithdrawLocked = 40,
+    /// Emergency withdrawal delay is below the hard-coded floor
+    InvalidEmergencyWithdrawDelay = 41,
+    /// Destination does not match the pending emergency withdrawal
+    InvalidEmergencyWithdrawDestination = 42,
+    /// A vault migration is already pending
+    VaultMigrationPending = 43,
+    /// No vault migration is pending
+    NoVaultMigrationPending = 44,
+    /// Vault migration delay has not passed
+    VaultMigrationLocked = 45,
+    /// New vault received less than the old vault held
+    VaultMigrationShortfall = 46,
+    /// Withdrawal is below the pool's minimum withdrawal
+    WithdrawalBelowMinimum = 47,
+    /// Finalize batch is empty, exceeds `MAX_FINALIZE_BATCH_SIZE`, or is not
+    /// made of (pool_config, vault) pairs
+    InvalidFinalizeBatch = 48,
+    /// Asset ID computation failed (Poseidon hash error)
+    AssetIdComputationFailed = 49,
 }
 
 impl From<TokenPoolError> for ProgramError {
@@ -57,3 +114,32 @@ impl From<TokenPoolError> for ProgramError {
         ProgramError::Custom(e as u32)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use zorb_pool_interface::{
+        PoolErrorKind, TOKEN_POOL_ERROR_NAMES, TOKEN_POOL_PROGRAM_ID, decode_pool_error,
+    };
+
+    /// Fails when a variant is added here without updating the interface's
+    /// `TOKEN_POOL_ERROR_NAMES`, so the hub keeps decoding every pool error.
+    #[test]
+    fn test_errors_mapped_in_interface() {
+        let variants: Vec<(&str, u32)> = include_str!("errors.rs")
+            .lines()
+            .filter_map(|line| {
+                let (name, code) = line.trim().strip_suffix(',')?.split_once(" = ")?;
+                Some((name, code.parse().ok()?))
+            })
+            .collect();
+
+        assert_eq!(variants.len(), TOKEN_POOL_ERROR_NAMES.len());
+        for (name, code) in variants {
+            assert_eq!(
+                decode_pool_error(&TOKEN_POOL_PROGRAM_ID, code),
+                Some(PoolErrorKind::Token(name)),
+                "TokenPoolError::{name} = {code} is not mapped"
+            );
+        }
+    }
+}
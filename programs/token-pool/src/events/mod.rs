@@ -8,6 +8,15 @@
 //! - [`TokenDepositEvent`] - Emitted when tokens are deposited
 //! - [`TokenWithdrawalEvent`] - Emitted when tokens are withdrawn
 //! - [`TokenRewardsFinalizedEvent`] - Emitted when rewards are finalized
+//! - [`VaultReconciledEvent`] - Emitted when the vault balance is reconciled
+//! - [`ProtocolFeesWithdrawnEvent`] - Emitted when protocol fees go to the treasury
+//! - [`PoolPauseChangedEvent`] - Emitted when the pool's own pause state is set
+//! - [`PoolClosedEvent`] - Emitted when a retired pool is closed
+//! - [`EmergencyWithdrawInitiatedEvent`] - Emitted when an emergency withdrawal is queued
+//! - [`EmergencyWithdrawCancelledEvent`] - Emitted when a queued emergency withdrawal is dropped
+//! - [`EmergencyWithdrawExecutedEvent`] - Emitted when an emergency withdrawal leaves the vault
+//! - [`VaultMigrationInitiatedEvent`] - Emitted when a vault migration is queued
+//! - [`VaultMigratedEvent`] - Emitted when the vault balance moves to the new vault
 //!
 //! # Event Pattern
 //!
@@ -35,7 +44,7 @@ use crate::instructions::TokenPoolInstruction;
 ///
 /// # Ranges (per discriminator-standard.md)
 /// - **1-15**: Core events (deposit, withdrawal, rewards)
-/// - **16-31**: Admin events (reserved for future use)
+/// - **16-31**: Admin events
 #[repr(u64)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, strum::IntoStaticStr)]
 pub enum EventType {
@@ -50,11 +59,32 @@ pub enum EventType {
     TokenRewardsFinalized = 3,
     /// Excess tokens swept into rewards
     SweepExcess = 4,
-    // Reserved: 5-15
+    /// Vault balance checked against the accounted balance
+    VaultReconciled = 5,
+    // Reserved: 6-15
 
     // =========================================================================
-    // Admin Events (16-31) - Reserved for future use
+    // Admin Events (16-31) - Authority-gated configuration and withdrawals
     // =========================================================================
+    /// Protocol fees withdrawn to the treasury
+    ProtocolFeesWithdrawn = 16,
+    /// Pool pause state changed
+    PoolPauseChanged = 17,
+    /// Pool config and vault closed
+    PoolClosed = 18,
+    /// Untracked excess returned out of the vault
+    ExcessReturned = 19,
+    /// Emergency withdrawal queued behind the delay
+    EmergencyWithdrawInitiated = 20,
+    /// Queued emergency withdrawal cancelled
+    EmergencyWithdrawCancelled = 21,
+    /// Emergency withdrawal transferred out of the vault
+    EmergencyWithdrawExecuted = 22,
+    /// Vault migration queued behind the delay
+    VaultMigrationInitiated = 23,
+    /// Vault balance moved to the replacement vault
+    VaultMigrated = 24,
+    // Reserved: 25-31
 }
 
 /// Event emitted when tokens are deposited into the token pool.
@@ -114,10 +144,27 @@ pub struct TokenRewardsFinalizedEvent {
     pub deposit_fees: u64,
     /// Withdrawal fees distributed (in token base units)
     pub withdrawal_fees: u64,
-    /// Funded rewards distributed (external funding, in token base units)
+    /// Funded rewards distributed, including vested rewards released this
+    /// finalization (external funding, in token base units)
     pub funded_rewards: u64,
     /// Solana slot when finalization occurred
     pub slot: u64,
+    /// Minimum slots between finalizations in effect for this pool
+    pub interval_slots: u64,
+    /// Slots elapsed since the previous finalization
+    pub slots_since_last: u64,
+    /// Gross deposit volume over the epoch just closed
+    pub epoch_deposit_volume: u64,
+    /// Gross withdrawal volume over the epoch just closed
+    pub epoch_withdrawal_volume: u64,
+    /// Deposit fees charged over the epoch just closed, protocol share included
+    pub epoch_deposit_fees: u64,
+    /// Withdrawal fees charged over the epoch just closed, protocol share included
+    pub epoch_withdrawal_fees: u64,
+    /// Accumulator epoch that `new_accumulator` belongs to (1 after the first finalization)
+    pub accumulator_epoch: u64,
+    /// Padding for 16-byte alignment
+    pub _padding: u64,
 }
 
 /// Event emitted when excess tokens are swept into pending rewards.
@@ -133,6 +180,178 @@ pub struct SweepExcessEvent {
     pub amount: u64,
     /// Solana slot when the sweep occurred
     pub slot: u64,
+    /// Cumulative excess swept over the pool's lifetime, including this sweep
+    pub total_swept: u128,
+}
+
+/// Event emitted when `ReconcileVault` compares the vault with its expected balance.
+#[event(EventType::VaultReconciled)]
+#[repr(C)]
+pub struct VaultReconciledEvent {
+    /// Token mint address
+    pub mint: [u8; 32],
+    /// Balance implied by the pool's cumulative accounting
+    pub expected: u128,
+    /// `actual - expected` (positive = surplus, negative = deficit)
+    pub delta: i128,
+    /// Vault token balance
+    pub actual: u64,
+    /// Solana slot when the reconciliation occurred
+    pub slot: u64,
+}
+
+/// Event emitted when accrued protocol fees are withdrawn to the treasury.
+#[event(EventType::ProtocolFeesWithdrawn)]
+#[repr(C)]
+pub struct ProtocolFeesWithdrawnEvent {
+    /// Token mint address
+    pub mint: [u8; 32],
+    /// Treasury wallet whose ATA received the fees
+    pub treasury: [u8; 32],
+    /// Amount withdrawn (in token base units)
+    pub amount: u64,
+    /// Protocol fees still accrued after the withdrawal
+    pub remaining: u64,
+    /// Solana slot when the withdrawal occurred
+    pub slot: u64,
+}
+
+/// Event emitted when `SetPoolPaused` updates the pool's pause state.
+#[event(EventType::PoolPauseChanged)]
+#[repr(C)]
+pub struct PoolPauseChangedEvent {
+    /// Token mint address
+    pub mint: [u8; 32],
+    /// Solana slot when the change occurred
+    pub slot: u64,
+    /// New pause state (1 = paused)
+    pub paused: u8,
+    /// Whether withdrawals stay open while paused (1 = allowed)
+    pub allow_withdrawals_when_paused: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 6],
+}
+
+/// Event emitted when `ClosePool` closes a retired pool's config and vault.
+#[event(EventType::PoolClosed)]
+#[repr(C)]
+pub struct PoolClosedEvent {
+    /// Token mint address
+    pub mint: [u8; 32],
+    /// Pool config PDA that was closed
+    pub pool_config: [u8; 32],
+    /// Account that received the reclaimed rent
+    pub authority: [u8; 32],
+    /// Lamports reclaimed from the vault and pool config
+    pub reclaimed_lamports: u64,
+    /// Solana slot when the pool was closed
+    pub slot: u64,
+}
+
+/// Event emitted when `ReturnExcess` sends untracked excess out of the vault.
+#[event(EventType::ExcessReturned)]
+#[repr(C)]
+pub struct ExcessReturnedEvent {
+    /// Token mint address
+    pub mint: [u8; 32],
+    /// Token account that received the excess
+    pub destination: [u8; 32],
+    /// Amount returned (in token base units)
+    pub amount: u64,
+    /// Excess still held in the vault after the return
+    pub remaining_excess: u64,
+    /// Solana slot when the return occurred
+    pub slot: u64,
+}
+
+/// Event emitted when `InitiateEmergencyWithdraw` queues an emergency withdrawal.
+#[event(EventType::EmergencyWithdrawInitiated)]
+#[repr(C)]
+pub struct EmergencyWithdrawInitiatedEvent {
+    /// Token mint address
+    pub mint: [u8; 32],
+    /// Token account that will receive the withdrawal
+    pub destination: [u8; 32],
+    /// Amount queued (in token base units)
+    pub amount: u64,
+    /// Slot from which the withdrawal can be executed
+    pub unlock_slot: u64,
+    /// Solana slot when the withdrawal was initiated
+    pub slot: u64,
+}
+
+/// Event emitted when `CancelEmergencyWithdraw` drops a queued emergency withdrawal.
+#[event(EventType::EmergencyWithdrawCancelled)]
+#[repr(C)]
+pub struct EmergencyWithdrawCancelledEvent {
+    /// Token mint address
+    pub mint: [u8; 32],
+    /// Token account the withdrawal was queued for
+    pub destination: [u8; 32],
+    /// Amount that was queued (in token base units)
+    pub amount: u64,
+    /// Solana slot when the withdrawal was cancelled
+    pub slot: u64,
+}
+
+/// Event emitted when `ExecuteEmergencyWithdraw` transfers out of the vault.
+#[event(EventType::EmergencyWithdrawExecuted)]
+#[repr(C)]
+pub struct EmergencyWithdrawExecutedEvent {
+    /// Token mint address
+    pub mint: [u8; 32],
+    /// Token account that received the withdrawal
+    pub destination: [u8; 32],
+    /// Cumulative emergency withdrawals over the pool's lifetime, including this one
+    pub total_emergency_withdrawn: u128,
+    /// Amount withdrawn (in token base units)
+    pub amount: u64,
+    /// Solana slot when the withdrawal occurred
+    pub slot: u64,
+}
+
+/// Event emitted when `InitiateVaultMigration` queues a vault migration.
+#[event(EventType::VaultMigrationInitiated)]
+#[repr(C)]
+pub struct VaultMigrationInitiatedEvent {
+    /// Token mint address
+    pub mint: [u8; 32],
+    /// Current vault
+    pub old_vault: [u8; 32],
+    /// Replacement vault
+    pub new_vault: [u8; 32],
+    /// Slot from which the migration can be executed
+    pub unlock_slot: u64,
+    /// Solana slot when the migration was initiated
+    pub slot: u64,
+}
+
+/// Event emitted when `ExecuteVaultMigration` moves the vault balance.
+#[event(EventType::VaultMigrated)]
+#[repr(C)]
+pub struct VaultMigratedEvent {
+    /// Token mint address
+    pub mint: [u8; 32],
+    /// Replaced vault (left empty)
+    pub old_vault: [u8; 32],
+    /// Vault now recorded in `TokenPoolConfig.vault`
+    pub new_vault: [u8; 32],
+    /// Tokens moved (in token base units)
+    pub amount: u64,
+    /// Solana slot when the migration occurred
+    pub slot: u64,
+}
+
+/// Build the `Log` instruction data carrying `event_data`.
+///
+/// Format: `[Log discriminator (69)][data length: u32 LE][data...]`, the
+/// `LengthPrefixed` framing the Log handler decodes.
+pub fn build_log_instruction_data(event_data: &[u8]) -> Vec<u8> {
+    let mut instruction_data = Vec::with_capacity(1 + 4 + event_data.len());
+    instruction_data.push(TokenPoolInstruction::Log as u8);
+    instruction_data.extend_from_slice(&(event_data.len() as u32).to_le_bytes());
+    instruction_data.extend_from_slice(event_data);
+    instruction_data
 }
 
 /// Emit a panchor event via self-invocation of the Log instruction.
@@ -156,13 +375,7 @@ pub fn emit_event<T: EventBytes>(
     // Serialize the event using Pod serialization with discriminator
     let event_data = event.to_event_bytes();
 
-    // Build instruction data: [Log discriminator, length (4 bytes LE), data...]
-    let log_discriminator = TokenPoolInstruction::Log as u8;
-    let len = event_data.len() as u32;
-    let mut instruction_data = Vec::with_capacity(1 + 4 + event_data.len());
-    instruction_data.push(log_discriminator);
-    instruction_data.extend_from_slice(&len.to_le_bytes());
-    instruction_data.extend_from_slice(&event_data);
+    let instruction_data = build_log_instruction_data(&event_data);
 
     // Build instruction for self-CPI to Log
     let instruction = Instruction {
@@ -177,3 +390,31 @@ pub fn emit_event<T: EventBytes>(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use panchor::events::LogFraming;
+
+    #[test]
+    fn test_log_instruction_data_decodes_to_event() {
+        let event = TokenDepositEvent {
+            mint: [3u8; 32],
+            new_balance: 10_000,
+            amount: 1_000,
+            fee: 5,
+            net_amount: 995,
+            slot: 42,
+        };
+        let instruction_data = build_log_instruction_data(&event.to_event_bytes());
+        assert_eq!(instruction_data[0], TokenPoolInstruction::Log as u8);
+
+        // What the Log handler passes to sol_log_data
+        let payload = LogFraming::LengthPrefixed
+            .payload(&instruction_data[1..])
+            .unwrap();
+        assert_eq!(payload[..8], TokenDepositEvent::discriminator_bytes());
+        let decoded: TokenDepositEvent = bytemuck::pod_read_unaligned(&payload[8..]);
+        assert_eq!(bytemuck::bytes_of(&decoded), bytemuck::bytes_of(&event));
+    }
+}
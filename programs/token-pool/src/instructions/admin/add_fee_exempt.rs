@@ -0,0 +1,49 @@
+//! Add an authority to the pool's fee-exempt list.
+
+use crate::TokenPoolConfig;
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::ProgramResult;
+use pinocchio_log::log;
+
+/// Instruction data for AddFeeExempt.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct AddFeeExemptData {
+    /// Depositor or recipient authority to exempt from pool fees
+    pub authority: [u8; 32],
+}
+
+/// Accounts for the AddFeeExempt instruction.
+#[derive(Accounts)]
+pub struct AddFeeExemptAccounts<'info> {
+    /// Pool config PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub pool_config: AccountLoader<'info, TokenPoolConfig>,
+
+    /// Must match pool_config.authority
+    pub authority: Signer<'info>,
+}
+
+/// Exempt an authority from deposit and withdrawal fees.
+///
+/// Intended for hub-internal rebalancing flows. Adding a listed authority is a
+/// no-op; fails with `FeeExemptListFull` once all `MAX_FEE_EXEMPT` slots are
+/// taken.
+pub fn process_add_fee_exempt(
+    ctx: Context<AddFeeExemptAccounts>,
+    data: AddFeeExemptData,
+) -> ProgramResult {
+    let AddFeeExemptAccounts {
+        pool_config,
+        authority,
+    } = ctx.accounts;
+
+    pool_config.try_inspect_mut(|config| {
+        config.require_authority(authority.key())?;
+        config.add_fee_exempt(&data.authority)?;
+
+        log!("add_fee_exempt: success");
+        Ok(())
+    })
+}
@@ -0,0 +1,65 @@
+//! Cancel a pending emergency withdrawal.
+
+use crate::{
+    TokenPoolConfig, emit_event, events::EmergencyWithdrawCancelledEvent,
+    gen_token_pool_config_seeds,
+};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, instruction::Signer as PinocchioSigner,
+    sysvars::Sysvar,
+};
+use pinocchio_log::log;
+
+/// Accounts for the CancelEmergencyWithdraw instruction.
+#[derive(Accounts)]
+pub struct CancelEmergencyWithdrawAccounts<'info> {
+    /// Pool config PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub pool_config: AccountLoader<'info, TokenPoolConfig>,
+
+    /// Must match pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Token pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub token_pool_program: &'info AccountInfo,
+}
+
+/// Drop the pending emergency withdrawal.
+///
+/// Fails with `NoEmergencyWithdrawPending` if none was initiated.
+pub fn process_cancel_emergency_withdraw(
+    ctx: Context<CancelEmergencyWithdrawAccounts>,
+) -> ProgramResult {
+    let CancelEmergencyWithdrawAccounts {
+        pool_config,
+        authority,
+        token_pool_program,
+    } = ctx.accounts;
+
+    let (amount, destination, mint, bump) = pool_config.try_map_mut(|config| {
+        config.require_authority(authority.key())?;
+        let destination = config.emergency_withdraw_destination;
+        let amount = config.cancel_emergency_withdraw()?;
+        Ok((amount, destination, config.mint, config.bump))
+    })?;
+
+    let bump_bytes = [bump];
+    let seeds = gen_token_pool_config_seeds(&mint, &bump_bytes);
+
+    emit_event(
+        pool_config.account_info(),
+        token_pool_program,
+        PinocchioSigner::from(&seeds),
+        &EmergencyWithdrawCancelledEvent {
+            mint,
+            destination,
+            amount,
+            slot: pinocchio::sysvars::clock::Clock::get()?.slot,
+        },
+    )?;
+
+    log!("cancel_emergency_withdraw: success");
+    Ok(())
+}
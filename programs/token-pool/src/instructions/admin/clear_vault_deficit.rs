@@ -0,0 +1,36 @@
+//! Clear a vault deficit flagged by `ReconcileVault`.
+
+use crate::TokenPoolConfig;
+use panchor::prelude::*;
+use pinocchio::ProgramResult;
+use pinocchio_log::log;
+
+/// Accounts for the ClearVaultDeficit instruction.
+#[derive(Accounts)]
+pub struct ClearVaultDeficitAccounts<'info> {
+    /// Pool config PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub pool_config: AccountLoader<'info, TokenPoolConfig>,
+
+    /// Must match pool_config.authority
+    pub authority: Signer<'info>,
+}
+
+/// Clear `deficit_detected` and `vault_deficit`, reopening deposits.
+///
+/// Does not re-check the vault; if the shortfall persists, the next
+/// `ReconcileVault` flags it again.
+pub fn process_clear_vault_deficit(ctx: Context<ClearVaultDeficitAccounts>) -> ProgramResult {
+    let ClearVaultDeficitAccounts {
+        pool_config,
+        authority,
+    } = ctx.accounts;
+
+    pool_config.try_inspect_mut(|config| {
+        config.require_authority(authority.key())?;
+        config.clear_vault_deficit();
+
+        log!("clear_vault_deficit: success");
+        Ok(())
+    })
+}
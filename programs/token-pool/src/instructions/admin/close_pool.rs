@@ -0,0 +1,180 @@
+//! Close a retired token pool.
+//!
+//! Closes the vault token account and the TokenPoolConfig PDA once the hub
+//! has stopped routing to the pool and every tracked balance has drained,
+//! returning all rent to the authority.
+
+use crate::{
+    TokenPoolConfig, TokenPoolError, emit_event,
+    events::PoolClosedEvent,
+    gen_token_pool_config_seeds,
+    token::{close_token_account, read_token_balance},
+};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, instruction::Signer as PinocchioSigner,
+    pubkey::find_program_address, sysvars::Sysvar,
+};
+use pinocchio_log::log;
+use zorb_pool_interface::HUB_PROGRAM_ID;
+
+/// Hub PoolConfig PDA seed: `["pool_config", asset_id]` under the hub program.
+const HUB_POOL_CONFIG_SEED: &[u8] = b"pool_config";
+
+/// Hub `ShieldedPoolAccount::PoolConfig` discriminator.
+const HUB_POOL_CONFIG_DISCRIMINATOR: u64 = 15;
+
+/// Offset of `PoolConfig.mode` (8-byte discriminator + pool_program + asset_id + pool_type).
+const HUB_POOL_CONFIG_MODE_OFFSET: usize = 8 + 32 + 32 + 1;
+
+/// `PoolConfig.mode` bits enabling withdrawals and deposits.
+const HUB_POOL_MODE_ALL: u8 = 0b11;
+
+/// Accounts for the ClosePool instruction.
+#[derive(Accounts)]
+pub struct ClosePoolAccounts<'info> {
+    /// Pool config PDA to close
+    #[account(mut, owner = crate::ID)]
+    pub pool_config: AccountLoader<'info, TokenPoolConfig>,
+
+    /// Vault token account to close
+    /// Must match pool_config.vault
+    #[account(mut)]
+    pub vault: &'info AccountInfo,
+
+    /// Hub PoolConfig PDA for this asset (must be closed or inactive)
+    pub hub_pool_config: &'info AccountInfo,
+
+    /// Must match pool_config.authority (receives reclaimed rent)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Token program the pool was created with (required for CloseAccount CPI)
+    pub token_program: &'info AccountInfo,
+
+    /// Token pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub token_pool_program: &'info AccountInfo,
+}
+
+/// Close a retired pool's vault and config, returning rent to the authority.
+///
+/// Requires the hub PoolConfig for the pool's asset to be closed or inactive,
+/// and fails with `PoolNotEmpty` while any pending balance, pending reward,
+/// accrued protocol fee, or vault token remains.
+pub fn process_close_pool(ctx: Context<ClosePoolAccounts>) -> ProgramResult {
+    let ClosePoolAccounts {
+        pool_config,
+        vault: vault_acc,
+        hub_pool_config,
+        authority,
+        token_program,
+        token_pool_program,
+    } = ctx.accounts;
+
+    let (mint, asset_id, bump, token_program_id) = pool_config.try_map(|config| {
+        config.require_authority(authority.key())?;
+        config.require_token_program(token_program.key())?;
+        config.require_vault(vault_acc.key())?;
+        config.require_empty()?;
+        Ok((
+            config.mint,
+            config.asset_id,
+            config.bump,
+            config.token_program,
+        ))
+    })?;
+
+    require_hub_pool_retired(hub_pool_config, &asset_id)?;
+
+    // Tokens may have arrived outside tracked flows; they must be swept and
+    // distributed (or never exist) before the vault can be closed
+    if read_token_balance(vault_acc, &token_program_id)? != 0 {
+        log!("close_pool: vault not empty");
+        return Err(TokenPoolError::PoolNotEmpty.into());
+    }
+
+    let bump_bytes = [bump];
+    let seeds = gen_token_pool_config_seeds(&mint, &bump_bytes);
+
+    // Close vault -> authority, signed by the pool config PDA
+    let vault_lamports = vault_acc.lamports();
+    close_token_account(
+        &token_program_id,
+        vault_acc,
+        authority,
+        pool_config.account_info(),
+        &[PinocchioSigner::from(&seeds)],
+    )?;
+
+    let config_lamports = pool_config.lamports();
+    let reclaimed_lamports = vault_lamports
+        .checked_add(config_lamports)
+        .ok_or(TokenPoolError::ArithmeticOverflow)?;
+
+    // Emit while the pool config can still sign the self-CPI
+    emit_event(
+        pool_config.account_info(),
+        token_pool_program,
+        PinocchioSigner::from(&seeds),
+        &PoolClosedEvent {
+            mint,
+            pool_config: *pool_config.key(),
+            authority: *authority.key(),
+            reclaimed_lamports,
+            slot: pinocchio::sysvars::clock::Clock::get()?.slot,
+        },
+    )?;
+
+    // Tombstone: zero the data so the discriminator no longer matches, then
+    // truncate so the account cannot be loaded again in this transaction
+    let mut data = pool_config.try_borrow_mut_data()?;
+    data.fill(0);
+    drop(data);
+    pool_config.resize(0)?;
+
+    unsafe {
+        *pool_config.borrow_mut_lamports_unchecked() = 0;
+        *authority.borrow_mut_lamports_unchecked() = authority
+            .lamports()
+            .checked_add(config_lamports)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+    }
+
+    log!("close_pool: success");
+    Ok(())
+}
+
+/// Check that the hub no longer routes to this pool.
+///
+/// `hub_pool_config` must be the canonical hub PoolConfig PDA for `asset_id`.
+/// It passes if the account is absent (never registered or already closed
+/// via `ClosePoolConfig`) or if its `mode` has no direction enabled.
+fn require_hub_pool_retired(hub_pool_config: &AccountInfo, asset_id: &[u8; 32]) -> ProgramResult {
+    let (expected, _) = find_program_address(&[HUB_POOL_CONFIG_SEED, asset_id], &HUB_PROGRAM_ID);
+    if *hub_pool_config.key() != expected {
+        log!("close_pool: hub_pool_config is not the hub PDA for this asset");
+        return Err(TokenPoolError::HubPoolStillRegistered.into());
+    }
+
+    // Absent: closed accounts are reassigned to the system program with no data
+    if !hub_pool_config.is_owned_by(&HUB_PROGRAM_ID) || hub_pool_config.data_is_empty() {
+        return Ok(());
+    }
+
+    let data = hub_pool_config.try_borrow_data()?;
+    let discriminator = data
+        .get(..8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_le_bytes);
+    let mode = data.get(HUB_POOL_CONFIG_MODE_OFFSET).copied();
+    match (discriminator, mode) {
+        (Some(HUB_POOL_CONFIG_DISCRIMINATOR), Some(mode)) if mode & HUB_POOL_MODE_ALL == 0 => {
+            Ok(())
+        }
+        _ => {
+            log!("close_pool: hub pool config still active");
+            Err(TokenPoolError::HubPoolStillRegistered.into())
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! Execute an emergency withdrawal once its delay has passed.
+
+use crate::{
+    TokenPoolConfig, TokenPoolError, emit_event,
+    events::EmergencyWithdrawExecutedEvent,
+    gen_token_pool_config_seeds,
+    token::{optional_mint, transfer_tokens},
+};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, instruction::Signer as PinocchioSigner,
+    sysvars::Sysvar,
+};
+use pinocchio_log::log;
+
+/// Accounts for the ExecuteEmergencyWithdraw instruction.
+#[derive(Accounts)]
+pub struct ExecuteEmergencyWithdrawAccounts<'info> {
+    /// Pool config PDA (vault owner, signs the transfer)
+    #[account(mut, owner = crate::ID)]
+    pub pool_config: AccountLoader<'info, TokenPoolConfig>,
+
+    /// Vault token account (source of the withdrawal)
+    /// Must match pool_config.vault
+    #[account(mut)]
+    pub vault: &'info AccountInfo,
+
+    /// Token account recorded by `InitiateEmergencyWithdraw`
+    #[account(mut)]
+    pub destination: &'info AccountInfo,
+
+    /// Must match pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Token program the pool was created with (required for Transfer CPI)
+    pub token_program: &'info AccountInfo,
+
+    /// Token pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub token_pool_program: &'info AccountInfo,
+    // Optional trailing account: mint (required for Token-2022 pools)
+}
+
+/// Transfer the pending emergency withdrawal from the vault to its destination.
+///
+/// Fails with `EmergencyWithdrawLocked` before the unlock slot and with
+/// `InvalidEmergencyWithdrawDestination` if `destination` is not the recorded
+/// token account. The amount is debited from the accounted pool balance.
+pub fn process_execute_emergency_withdraw(
+    ctx: Context<ExecuteEmergencyWithdrawAccounts>,
+) -> ProgramResult {
+    let ExecuteEmergencyWithdrawAccounts {
+        pool_config,
+        vault: vault_acc,
+        destination,
+        authority,
+        token_program,
+        token_pool_program,
+    } = ctx.accounts;
+
+    let current_slot = pinocchio::sysvars::clock::Clock::get()?.slot;
+
+    let (amount, total_emergency_withdrawn, mint, bump, token_program_id, decimals) =
+        pool_config.try_map_mut(|config| {
+            config.require_authority(authority.key())?;
+            config.require_token_program(token_program.key())?;
+            config.require_vault(vault_acc.key())?;
+            if config.emergency_withdraw_destination != *destination.key() {
+                log!("execute_emergency_withdraw: destination mismatch");
+                return Err(TokenPoolError::InvalidEmergencyWithdrawDestination.into());
+            }
+
+            let (amount, _) = config.execute_emergency_withdraw(current_slot)?;
+            Ok((
+                amount,
+                config.total_emergency_withdrawn,
+                config.mint,
+                config.bump,
+                config.token_program,
+                config.decimals,
+            ))
+        })?;
+
+    // Transfer vault -> destination, signed by the pool config PDA
+    let bump_bytes = [bump];
+    let seeds = gen_token_pool_config_seeds(&mint, &bump_bytes);
+
+    transfer_tokens(
+        &token_program_id,
+        vault_acc,
+        destination,
+        pool_config.account_info(),
+        optional_mint(ctx.remaining_accounts, &mint)?,
+        amount,
+        decimals,
+        &[PinocchioSigner::from(&seeds)],
+    )?;
+
+    emit_event(
+        pool_config.account_info(),
+        token_pool_program,
+        PinocchioSigner::from(&seeds),
+        &EmergencyWithdrawExecutedEvent {
+            mint,
+            destination: *destination.key(),
+            total_emergency_withdrawn,
+            amount,
+            slot: current_slot,
+        },
+    )?;
+
+    log!("execute_emergency_withdraw: success");
+    Ok(())
+}
@@ -1,30 +1,15 @@
 //! Initialize token pool configuration.
 
 use crate::{
-    TokenPoolConfig, TokenPoolError, VAULT_SEED, find_token_pool_config_pda, find_vault_pda,
+    TokenPoolConfig, TokenPoolError, find_token_pool_config_pda,
+    state::MAX_FEE_EXEMPT,
+    token::{find_associated_token_address, is_token_program, read_mint_decimals},
 };
 use bytemuck::{Pod, Zeroable};
 use panchor::prelude::*;
-use pinocchio::{ProgramResult, account_info::AccountInfo, instruction::Seed, pubkey::Pubkey};
+use pinocchio::{ProgramResult, account_info::AccountInfo};
 use pinocchio_log::log;
-use pinocchio_token::{instructions::InitializeAccount3, state::Mint};
-use solana_poseidon::{Endianness, Parameters, hashv};
-use zorb_pool_interface::BASIS_POINTS;
-
-/// SPL Token account size
-const TOKEN_ACCOUNT_SIZE: usize = 165;
-
-/// SPL Token Program ID
-const SPL_TOKEN_PROGRAM_ID: Pubkey = [
-    0x06, 0xdd, 0xf6, 0xe1, 0xd7, 0x65, 0xa1, 0x93, 0xd9, 0xcb, 0xe1, 0x46, 0xce, 0xeb, 0x79, 0xac,
-    0x1c, 0xb4, 0x85, 0xed, 0x5f, 0x5b, 0x37, 0x91, 0x3a, 0x8c, 0xf5, 0x85, 0x7e, 0xff, 0x00, 0xa9,
-];
-
-/// SPL Token-2022 Program ID
-const SPL_TOKEN_2022_PROGRAM_ID: Pubkey = [
-    0x06, 0xa7, 0xd5, 0x17, 0x18, 0x7b, 0xd1, 0x65, 0x35, 0x50, 0xc4, 0x9a, 0x3a, 0x8b, 0x9a, 0x28,
-    0xb9, 0x51, 0x9f, 0x60, 0x7d, 0x1f, 0x55, 0xb8, 0x26, 0xb4, 0x53, 0x06, 0x76, 0x8b, 0x9f, 0x71,
-];
+use zorb_pool_interface::{BASIS_POINTS, asset_ids::compute_token_asset_id};
 
 /// Instruction data for InitPool.
 #[repr(C)]
@@ -46,12 +31,12 @@ pub struct InitPoolAccounts<'info> {
     /// SPL Token mint to register
     pub mint_account: &'info AccountInfo,
 
-    /// Pool config PDA ["token_pool", mint] to create
-    #[account(init, payer = authority, pda = TokenPoolConfig, pda::mint = mint_account.key())]
+    /// Pool config PDA ["token_pool", mint] to create (left as is if it exists)
+    #[account(init_idempotent, payer = authority, pda = TokenPoolConfig, pda::mint = mint_account.key())]
     pub pool_config: AccountLoader<'info, TokenPoolConfig>,
 
-    /// Vault PDA ["vault", pool_config] to create
-    /// Note: Manually created as token account (owned by token program, not pool program)
+    /// Vault: associated token account of pool_config for the mint
+    /// Note: Created through the ATA program (owned by token program, not pool program)
     #[account(mut)]
     pub vault: &'info AccountInfo,
 
@@ -59,18 +44,33 @@ pub struct InitPoolAccounts<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
-    /// SPL Token program (required for CPI, validated to match mint.owner())
+    /// SPL Token or Token-2022 program (validated to match mint.owner(), stored in config)
     pub token_program: &'info AccountInfo,
 
     /// System program for account creation
     pub system_program: Program<'info, System>,
+
+    /// Associated token account program (creates the vault)
+    #[account(address = pinocchio_associated_token_account::ID)]
+    pub associated_token_program: &'info AccountInfo,
 }
 
 /// Initialize a new token pool.
 ///
-/// Creates a TokenPoolConfig PDA and the vault token account.
-/// Vault is created at PDA address derived from pool_config:
-/// - vault = PDA ["vault", pool_config] - owned by pool_config PDA
+/// Creates a TokenPoolConfig PDA and the vault token account. The vault is
+/// the canonical ATA of the pool config for the mint, created with the ATA
+/// program's `CreateIdempotent` (so a vault someone else created first is
+/// accepted), and its address is stored in `TokenPoolConfig.vault`.
+///
+/// Idempotent: if the pool already exists with the same authority, token
+/// program, deposit limit, and fee rates, and `vault` is its stored vault,
+/// this logs and returns without changes. Any other existing pool fails with
+/// `AlreadyInitialized`. Pools created before ATA vaults keep their
+/// `["vault", pool_config]` PDA, so the stored address is authoritative.
+///
+/// Token-2022 mints are accepted unless they carry an extension in
+/// `UNSUPPORTED_MINT_EXTENSIONS` (NonTransferable, PermanentDelegate,
+/// TransferHook, Pausable) or default new accounts to frozen.
 pub fn process_init_pool(ctx: Context<InitPoolAccounts>, data: InitPoolData) -> ProgramResult {
     let InitPoolAccounts {
         mint_account,
@@ -79,6 +79,7 @@ pub fn process_init_pool(ctx: Context<InitPoolAccounts>, data: InitPoolData) ->
         authority,
         token_program,
         system_program,
+        associated_token_program: _,
     } = ctx.accounts;
 
     // Validate system program
@@ -89,9 +90,7 @@ pub fn process_init_pool(ctx: Context<InitPoolAccounts>, data: InitPoolData) ->
 
     // Validate token program (SPL Token or Token-2022)
     let token_program_id = token_program.key();
-    let is_token_program =
-        *token_program_id == SPL_TOKEN_PROGRAM_ID || *token_program_id == SPL_TOKEN_2022_PROGRAM_ID;
-    if !is_token_program {
+    if !is_token_program(token_program_id) {
         log!("init_pool: invalid token program");
         return Err(TokenPoolError::InvalidTokenProgram.into());
     }
@@ -102,10 +101,10 @@ pub fn process_init_pool(ctx: Context<InitPoolAccounts>, data: InitPoolData) ->
         return Err(TokenPoolError::InvalidMint.into());
     }
 
-    // Read decimals from mint account using pinocchio_token typed access
-    let decimals = Mint::from_account_info(mint_account)
-        .map_err(|_| TokenPoolError::InvalidMint)?
-        .decimals();
+    // Read decimals, rejecting unsupported Token-2022 extensions
+    let decimals = read_mint_decimals(mint_account, token_program_id).inspect_err(|_| {
+        log!("init_pool: invalid or unsupported mint");
+    })?;
 
     // Validate fee rates (max 100%)
     if data.deposit_fee_rate > BASIS_POINTS as u16 || data.withdrawal_fee_rate > BASIS_POINTS as u16
@@ -114,43 +113,59 @@ pub fn process_init_pool(ctx: Context<InitPoolAccounts>, data: InitPoolData) ->
         return Err(TokenPoolError::InvalidFeeRate.into());
     }
 
-    // Get PDA bumps for account creation
-    // Note: pool_config PDA is created by panchor via init constraint
+    // init_idempotent only checks the address when it creates the account
     let (expected_config_pda, config_bump) = find_token_pool_config_pda(mint_account.key());
-    let (expected_vault_pda, vault_bump) = find_vault_pda(&expected_config_pda);
+    if *pool_config.key() != expected_config_pda {
+        log!("init_pool: invalid pool config PDA");
+        return Err(TokenPoolError::InvalidPoolConfigPda.into());
+    }
 
-    // Validate vault PDA address
-    if *vault.key() != expected_vault_pda {
-        log!("init_pool: invalid vault PDA");
-        return Err(TokenPoolError::InvalidVaultPda.into());
+    // Re-run against an existing pool: no-op if nothing differs
+    let existing = pool_config.try_map(|config| {
+        if config.mint == [0u8; 32] {
+            return Ok(false);
+        }
+        let same_params = config.authority == *authority.key()
+            && config.token_program == *token_program_id
+            && config.max_deposit_amount == data.max_deposit_amount
+            && config.deposit_fee_rate == data.deposit_fee_rate
+            && config.withdrawal_fee_rate == data.withdrawal_fee_rate;
+        if !same_params {
+            log!("init_pool: pool exists with different parameters");
+            return Err(TokenPoolError::AlreadyInitialized.into());
+        }
+        config.require_vault(vault.key())?;
+        Ok(true)
+    })?;
+    if existing {
+        log!("init_pool: pool already initialized, nothing to do");
+        return Ok(());
     }
 
-    // Create vault token account PDA (owned by token program, not pool program)
-    let vault_bump_bytes = [vault_bump];
-    let vault_seeds = [
-        Seed::from(VAULT_SEED),
-        Seed::from(expected_config_pda.as_ref()),
-        Seed::from(&vault_bump_bytes),
-    ];
+    // Validate vault is the pool config's ATA for this mint and token program
+    let expected_vault =
+        find_associated_token_address(&expected_config_pda, mint_account.key(), token_program_id);
+    if *vault.key() != expected_vault {
+        log!("init_pool: vault is not the pool config ATA");
+        return Err(TokenPoolError::InvalidVaultPda.into());
+    }
 
-    vault.create_pda_account_with_space(
+    // Create the vault ATA; the ATA program sizes Token-2022 accounts for
+    // the mint's extensions and accepts an ATA that already exists
+    panchor::programs::associated_token::create_idempotent(
         authority,
-        &vault_seeds,
+        vault,
+        pool_config.account_info(),
+        mint_account,
+        token_program,
         system_program.account_info(),
-        TOKEN_ACCOUNT_SIZE,
-        token_program_id,
     )?;
 
-    // Initialize vault as token account with pool_config as owner
-    InitializeAccount3 {
-        account: vault,
-        mint: mint_account,
-        owner: &expected_config_pda,
-    }
-    .invoke()?;
-
-    // Compute asset_id from mint using Poseidon hash
-    let asset_id = compute_asset_id(mint_account.key());
+    // Compute asset_id from mint using the canonical Poseidon derivation
+    let asset_id = compute_token_asset_id(mint_account.key()).ok_or_else(|| {
+        log!("init_pool: asset id computation failed");
+        TokenPoolError::AssetIdComputationFailed
+    })?;
 
     // Initialize pool config data
     // Note: Account and discriminator already created by panchor's init constraint
@@ -158,7 +173,7 @@ pub fn process_init_pool(ctx: Context<InitPoolAccounts>, data: InitPoolData) ->
         config.authority = *authority.key();
         config.pending_authority = [0u8; 32];
         config.mint = *mint_account.key();
-        config.vault = expected_vault_pda;
+        config.vault = expected_vault;
         config.asset_id = asset_id;
         config.finalized_balance = 0;
         config.pending_deposits = 0;
@@ -166,14 +181,14 @@ pub fn process_init_pool(ctx: Context<InitPoolAccounts>, data: InitPoolData) ->
         config.pending_deposit_fees = 0;
         config.pending_withdrawal_fees = 0;
         config.pending_funded_rewards = 0;
-        config._pad_fees = 0;
+        config.accrued_protocol_fees = 0;
         config.total_deposited = 0;
         config.total_withdrawn = 0;
         config.total_rewards_distributed = 0;
         config.total_deposit_fees = 0;
         config.total_withdrawal_fees = 0;
         config.total_funded_rewards = 0;
-        config._reserved_stats = 0;
+        config.total_protocol_fees_withdrawn = 0;
         config.max_deposit_amount = data.max_deposit_amount;
         config.deposit_count = 0;
         config.withdrawal_count = 0;
@@ -181,48 +196,56 @@ pub fn process_init_pool(ctx: Context<InitPoolAccounts>, data: InitPoolData) ->
         config.last_finalized_slot = 0;
         config.deposit_fee_rate = data.deposit_fee_rate;
         config.withdrawal_fee_rate = data.withdrawal_fee_rate;
+        config.protocol_fee_share_bps = 0;
         config.decimals = decimals;
         config.is_active = 1;
         config.bump = config_bump;
-        config._padding = [0u8; 9];
+        config.paused = 0;
+        config.allow_withdrawals_when_paused = 0;
+        config.deficit_detected = 0;
+        config._padding = [0u8; 4];
+        config.treasury = [0u8; 32];
+        config.token_program = *token_program_id;
+        config.total_transfer_fee_shortfall = 0;
+        config.max_total_pool = 0;
+        config.max_single_deposit = 0;
+        config.min_deposit = 0;
+        config.finalize_interval_slots = 0;
+        config.vesting_rewards = 0;
+        config.vesting_start_slot = 0;
+        config.vesting_end_slot = 0;
+        config.total_swept = 0;
+        config.total_excess_returned = 0;
+        config.max_sweep_per_call = 0;
+        config.sweep_delay_slots = 0;
+        config.detected_excess = 0;
+        config.excess_detected_slot = 0;
+        config.total_fee_exempt_volume = 0;
+        config.fee_exempt = [[0u8; 32]; MAX_FEE_EXEMPT];
+        config.vault_deficit = 0;
+        config.last_reconciled_slot = 0;
+        config.total_emergency_withdrawn = 0;
+        config.emergency_withdraw_destination = [0u8; 32];
+        config.emergency_withdraw_amount = 0;
+        config.emergency_withdraw_unlock_slot = 0;
+        config.emergency_withdraw_delay_slots = TokenPoolConfig::MIN_EMERGENCY_WITHDRAW_DELAY_SLOTS;
+        config.emergency_withdraw_initiated_slot = 0;
+        config.vault_generation = 0;
+        config.pending_vault = [0u8; 32];
+        config.vault_migration_unlock_slot = 0;
+        config.epoch_deposit_volume = 0;
+        config.epoch_withdrawal_volume = 0;
+        config.epoch_deposit_fees = 0;
+        config.epoch_withdrawal_fees = 0;
+        config.min_withdrawal = 0;
+        config.round_fees_up = 0;
+        config._withdrawal_policy_padding = [0u8; 7];
+        config.previous_reward_accumulator = 0;
+        config.accumulator_epoch = 0;
+        config._accumulator_epoch_padding = [0u8; 8];
     })?;
 
     log!("init_pool: pool initialized successfully");
 
     Ok(())
 }
-
-/// Compute asset_id from mint address using Poseidon hash.
-///
-/// The mint address (32 bytes, little-endian) is split into two 128-bit limbs
-/// and hashed together. This matches the circuit's representation where a
-/// 256-bit value is split into two field elements.
-///
-/// Layout (little-endian source -> big-endian Poseidon input):
-/// - Low limb:  mint[0..16]  (LE) -> reversed to BE, zero-padded to 32 bytes
-/// - High limb: mint[16..32] (LE) -> reversed to BE, zero-padded to 32 bytes
-///
-/// Uses BN254 curve with X5 parameters and big-endian encoding.
-fn compute_asset_id(mint: &Pubkey) -> [u8; 32] {
-    // Split mint into two 128-bit limbs (little-endian source)
-    // Low limb: bytes 0-15, High limb: bytes 16-31
-    let mint_bytes = mint;
-    let mut low_limb = [0u8; 32];
-    let mut high_limb = [0u8; 32];
-
-    // Convert each 16-byte little-endian chunk to 32-byte big-endian field element
-    // Reverse bytes and place in low 16 bytes of the 32-byte array
-    for i in 0..16 {
-        low_limb[31 - i] = mint_bytes[i]; // Reverse bytes[0..16] -> positions [16..32]
-        high_limb[31 - i] = mint_bytes[16 + i]; // Reverse bytes[16..32] -> positions [16..32]
-    }
-
-    // Hash both limbs: Poseidon(low_limb, high_limb)
-    let hash_result = hashv(
-        Parameters::Bn254X5,
-        Endianness::BigEndian,
-        &[&low_limb, &high_limb],
-    )
-    .expect("Poseidon hash should succeed");
-    hash_result.to_bytes()
-}
@@ -0,0 +1,82 @@
+//! Queue an emergency withdrawal out of the vault.
+
+use crate::{
+    TokenPoolConfig, emit_event, events::EmergencyWithdrawInitiatedEvent,
+    gen_token_pool_config_seeds,
+};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, instruction::Signer as PinocchioSigner,
+    sysvars::Sysvar,
+};
+use pinocchio_log::log;
+
+/// Instruction data for InitiateEmergencyWithdraw.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct InitiateEmergencyWithdrawData {
+    /// Amount to withdraw (at most the accounted pool balance)
+    pub amount: u64,
+    /// Token account that will receive the withdrawal
+    pub destination: [u8; 32],
+}
+
+/// Accounts for the InitiateEmergencyWithdraw instruction.
+#[derive(Accounts)]
+pub struct InitiateEmergencyWithdrawAccounts<'info> {
+    /// Pool config PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub pool_config: AccountLoader<'info, TokenPoolConfig>,
+
+    /// Must match pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Token pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub token_pool_program: &'info AccountInfo,
+}
+
+/// Record an emergency withdrawal that unlocks after the pool's delay.
+///
+/// Only one emergency withdrawal can be pending; it is executed with
+/// `ExecuteEmergencyWithdraw` once `emergency_withdraw_delay()` slots have
+/// passed, or dropped with `CancelEmergencyWithdraw`.
+pub fn process_initiate_emergency_withdraw(
+    ctx: Context<InitiateEmergencyWithdrawAccounts>,
+    data: InitiateEmergencyWithdrawData,
+) -> ProgramResult {
+    let InitiateEmergencyWithdrawAccounts {
+        pool_config,
+        authority,
+        token_pool_program,
+    } = ctx.accounts;
+
+    let current_slot = pinocchio::sysvars::clock::Clock::get()?.slot;
+
+    let (unlock_slot, mint, bump) = pool_config.try_map_mut(|config| {
+        config.require_authority(authority.key())?;
+        let unlock_slot =
+            config.initiate_emergency_withdraw(data.amount, &data.destination, current_slot)?;
+        Ok((unlock_slot, config.mint, config.bump))
+    })?;
+
+    let bump_bytes = [bump];
+    let seeds = gen_token_pool_config_seeds(&mint, &bump_bytes);
+
+    emit_event(
+        pool_config.account_info(),
+        token_pool_program,
+        PinocchioSigner::from(&seeds),
+        &EmergencyWithdrawInitiatedEvent {
+            mint,
+            destination: data.destination,
+            amount: data.amount,
+            unlock_slot,
+            slot: current_slot,
+        },
+    )?;
+
+    log!("initiate_emergency_withdraw: success");
+    Ok(())
+}
@@ -0,0 +1,86 @@
+//! Queue a move of the pool's funds to a replacement vault.
+
+use crate::{
+    TokenPoolConfig, TokenPoolError, emit_event, events::VaultMigrationInitiatedEvent,
+    find_migrated_vault_pda, gen_token_pool_config_seeds,
+};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, instruction::Signer as PinocchioSigner,
+    sysvars::Sysvar,
+};
+use pinocchio_log::log;
+
+/// Accounts for the InitiateVaultMigration instruction.
+#[derive(Accounts)]
+pub struct InitiateVaultMigrationAccounts<'info> {
+    /// Pool config PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub pool_config: AccountLoader<'info, TokenPoolConfig>,
+
+    /// Replacement vault PDA (not yet created)
+    /// PDA derived from: ["migrated_vault", pool_config, vault_generation + 1]
+    pub new_vault: &'info AccountInfo,
+
+    /// Must match pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Token pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub token_pool_program: &'info AccountInfo,
+}
+
+/// Record the next vault generation's PDA as the pool's pending vault.
+///
+/// `MigrateVault` moves the funds once the emergency withdrawal delay has
+/// passed. Fails with `InvalidVaultPda` if `new_vault` is not the next
+/// generation's PDA and with `VaultMigrationPending` if one is already queued.
+pub fn process_initiate_vault_migration(
+    ctx: Context<InitiateVaultMigrationAccounts>,
+) -> ProgramResult {
+    let InitiateVaultMigrationAccounts {
+        pool_config,
+        new_vault,
+        authority,
+        token_pool_program,
+    } = ctx.accounts;
+
+    let current_slot = pinocchio::sysvars::clock::Clock::get()?.slot;
+    let pool_config_key = *pool_config.key();
+
+    let (old_vault, unlock_slot, mint, bump) = pool_config.try_map_mut(|config| {
+        config.require_authority(authority.key())?;
+
+        let generation = config
+            .vault_generation
+            .checked_add(1)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+        let (expected_vault, _) = find_migrated_vault_pda(&pool_config_key, generation);
+        if *new_vault.key() != expected_vault {
+            log!("initiate_vault_migration: invalid new vault PDA");
+            return Err(TokenPoolError::InvalidVaultPda.into());
+        }
+
+        let unlock_slot = config.initiate_vault_migration(new_vault.key(), current_slot)?;
+        Ok((config.vault, unlock_slot, config.mint, config.bump))
+    })?;
+
+    let bump_bytes = [bump];
+    let seeds = gen_token_pool_config_seeds(&mint, &bump_bytes);
+
+    emit_event(
+        pool_config.account_info(),
+        token_pool_program,
+        PinocchioSigner::from(&seeds),
+        &VaultMigrationInitiatedEvent {
+            mint,
+            old_vault,
+            new_vault: *new_vault.key(),
+            unlock_slot,
+            slot: current_slot,
+        },
+    )?;
+
+    log!("initiate_vault_migration: success");
+    Ok(())
+}
@@ -0,0 +1,148 @@
+//! Move the pool's funds to the replacement vault queued by `InitiateVaultMigration`.
+
+use crate::{
+    TokenPoolConfig, TokenPoolError, emit_event,
+    events::VaultMigratedEvent,
+    find_migrated_vault_pda, gen_migrated_vault_seeds, gen_token_pool_config_seeds,
+    token::{initialize_account3, read_token_balance, token_account_size, transfer_tokens},
+};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, instruction::Signer as PinocchioSigner,
+    sysvars::Sysvar,
+};
+use pinocchio_log::log;
+
+/// Accounts for the MigrateVault instruction.
+#[derive(Accounts)]
+pub struct MigrateVaultAccounts<'info> {
+    /// Pool config PDA (owner of both vaults, signs the transfer)
+    #[account(mut, owner = crate::ID)]
+    pub pool_config: AccountLoader<'info, TokenPoolConfig>,
+
+    /// Current vault - must match pool_config.vault (left empty)
+    #[account(mut)]
+    pub old_vault: &'info AccountInfo,
+
+    /// Replacement vault to create - must match pool_config.pending_vault
+    #[account(mut)]
+    pub new_vault: &'info AccountInfo,
+
+    /// Pool's token mint
+    pub mint_account: &'info AccountInfo,
+
+    /// Must match pool_config.authority (pays for the new vault)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Token program the pool was created with
+    pub token_program: &'info AccountInfo,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Token pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub token_pool_program: &'info AccountInfo,
+}
+
+/// Create the pending vault, move the full balance into it, and switch
+/// `TokenPoolConfig.vault` over.
+///
+/// Fails with `VaultMigrationLocked` before the unlock slot. Afterwards every
+/// instruction rejects the old vault with `InvalidVault`. Fails with
+/// `VaultMigrationShortfall` if the new vault receives less than was sent
+/// (e.g. a Token-2022 transfer fee), since tracked funds would be lost.
+pub fn process_migrate_vault(ctx: Context<MigrateVaultAccounts>) -> ProgramResult {
+    let MigrateVaultAccounts {
+        pool_config,
+        old_vault,
+        new_vault,
+        mint_account,
+        authority,
+        token_program,
+        system_program,
+        token_pool_program,
+    } = ctx.accounts;
+
+    let current_slot = pinocchio::sysvars::clock::Clock::get()?.slot;
+    let pool_config_key = *pool_config.key();
+
+    let (generation, mint, bump, token_program_id, decimals) =
+        pool_config.try_map_mut(|config| {
+            config.require_authority(authority.key())?;
+            config.require_token_program(token_program.key())?;
+            config.require_vault(old_vault.key())?;
+            if config.pending_vault != *new_vault.key() {
+                log!("migrate_vault: new vault is not the pending vault");
+                return Err(TokenPoolError::InvalidVaultPda.into());
+            }
+            if config.mint != *mint_account.key() {
+                return Err(TokenPoolError::InvalidMint.into());
+            }
+
+            config.execute_vault_migration(current_slot)?;
+            Ok((
+                config.vault_generation,
+                config.mint,
+                config.bump,
+                config.token_program,
+                config.decimals,
+            ))
+        })?;
+
+    // Create the new vault token account, owned by the pool config like the old one
+    let (_, vault_bump) = find_migrated_vault_pda(&pool_config_key, generation);
+    let generation_bytes = generation.to_le_bytes();
+    let vault_bump_bytes = [vault_bump];
+    let vault_seeds =
+        gen_migrated_vault_seeds(&pool_config_key, &generation_bytes, &vault_bump_bytes);
+
+    let vault_size = token_account_size(&token_program_id, mint_account)?;
+    new_vault.create_pda_account_with_space(
+        authority,
+        &vault_seeds,
+        system_program.account_info(),
+        vault_size,
+        &token_program_id,
+    )?;
+    initialize_account3(&token_program_id, new_vault, mint_account, &pool_config_key)?;
+
+    // Move the full balance, signed by the pool config PDA
+    let bump_bytes = [bump];
+    let seeds = gen_token_pool_config_seeds(&mint, &bump_bytes);
+
+    let amount = read_token_balance(old_vault, &token_program_id)?;
+    if amount != 0 {
+        transfer_tokens(
+            &token_program_id,
+            old_vault,
+            new_vault,
+            pool_config.account_info(),
+            Some(mint_account),
+            amount,
+            decimals,
+            &[PinocchioSigner::from(&seeds)],
+        )?;
+    }
+    if read_token_balance(new_vault, &token_program_id)? != amount {
+        log!("migrate_vault: new vault received less than the old vault held");
+        return Err(TokenPoolError::VaultMigrationShortfall.into());
+    }
+
+    emit_event(
+        pool_config.account_info(),
+        token_pool_program,
+        PinocchioSigner::from(&seeds),
+        &VaultMigratedEvent {
+            mint,
+            old_vault: *old_vault.key(),
+            new_vault: *new_vault.key(),
+            amount,
+            slot: current_slot,
+        },
+    )?;
+
+    log!("migrate_vault: success");
+    Ok(())
+}
@@ -3,13 +3,119 @@
 //! These instructions are admin-gated and manage pool configuration.
 
 mod accept_authority;
+mod add_fee_exempt;
+mod cancel_emergency_withdraw;
+mod clear_vault_deficit;
+mod close_pool;
+mod execute_emergency_withdraw;
 mod init_pool;
+mod initiate_emergency_withdraw;
+mod initiate_vault_migration;
+mod migrate_vault;
+mod remove_fee_exempt;
+mod return_excess;
+mod set_emergency_withdraw_delay;
 mod set_fee_rates;
+mod set_finalize_interval;
 mod set_pool_active;
+mod set_pool_limits;
+mod set_pool_paused;
+mod set_protocol_fee_config;
+mod set_sweep_config;
+mod set_withdrawal_policy;
 mod transfer_authority;
+mod withdraw_protocol_fees;
 
 pub use accept_authority::{AcceptAuthorityAccounts, process_accept_authority};
+pub use add_fee_exempt::{AddFeeExemptAccounts, AddFeeExemptData, process_add_fee_exempt};
+pub use cancel_emergency_withdraw::{
+    CancelEmergencyWithdrawAccounts, process_cancel_emergency_withdraw,
+};
+pub use clear_vault_deficit::{ClearVaultDeficitAccounts, process_clear_vault_deficit};
+pub use close_pool::{ClosePoolAccounts, process_close_pool};
+pub use execute_emergency_withdraw::{
+    ExecuteEmergencyWithdrawAccounts, process_execute_emergency_withdraw,
+};
 pub use init_pool::{InitPoolAccounts, InitPoolData, process_init_pool};
+pub use initiate_emergency_withdraw::{
+    InitiateEmergencyWithdrawAccounts, InitiateEmergencyWithdrawData,
+    process_initiate_emergency_withdraw,
+};
+pub use initiate_vault_migration::{
+    InitiateVaultMigrationAccounts, process_initiate_vault_migration,
+};
+pub use migrate_vault::{MigrateVaultAccounts, process_migrate_vault};
+pub use remove_fee_exempt::{
+    RemoveFeeExemptAccounts, RemoveFeeExemptData, process_remove_fee_exempt,
+};
+pub use return_excess::{ReturnExcessAccounts, ReturnExcessData, process_return_excess};
+pub use set_emergency_withdraw_delay::{
+    SetEmergencyWithdrawDelayAccounts, SetEmergencyWithdrawDelayData,
+    process_set_emergency_withdraw_delay,
+};
 pub use set_fee_rates::{SetFeeRatesAccounts, SetFeeRatesData, process_set_fee_rates};
+pub use set_finalize_interval::{
+    SetFinalizeIntervalAccounts, SetFinalizeIntervalData, process_set_finalize_interval,
+};
 pub use set_pool_active::{SetPoolActiveAccounts, SetPoolActiveData, process_set_pool_active};
+pub use set_pool_limits::{SetPoolLimitsAccounts, SetPoolLimitsData, process_set_pool_limits};
+pub use set_pool_paused::{SetPoolPausedAccounts, SetPoolPausedData, process_set_pool_paused};
+pub use set_protocol_fee_config::{
+    SetProtocolFeeConfigAccounts, SetProtocolFeeConfigData, process_set_protocol_fee_config,
+};
+pub use set_sweep_config::{SetSweepConfigAccounts, SetSweepConfigData, process_set_sweep_config};
+pub use set_withdrawal_policy::{
+    SetWithdrawalPolicyAccounts, SetWithdrawalPolicyData, process_set_withdrawal_policy,
+};
 pub use transfer_authority::{TransferAuthorityAccounts, process_transfer_authority};
+pub use withdraw_protocol_fees::{
+    WithdrawProtocolFeesAccounts, WithdrawProtocolFeesData, process_withdraw_protocol_fees,
+};
+
+// Re-export client Input structs (generated by #[derive(Accounts)])
+#[cfg(feature = "solana-sdk")]
+pub use accept_authority::AcceptAuthorityInput;
+#[cfg(feature = "solana-sdk")]
+pub use add_fee_exempt::AddFeeExemptInput;
+#[cfg(feature = "solana-sdk")]
+pub use cancel_emergency_withdraw::CancelEmergencyWithdrawInput;
+#[cfg(feature = "solana-sdk")]
+pub use clear_vault_deficit::ClearVaultDeficitInput;
+#[cfg(feature = "solana-sdk")]
+pub use close_pool::ClosePoolInput;
+#[cfg(feature = "solana-sdk")]
+pub use execute_emergency_withdraw::ExecuteEmergencyWithdrawInput;
+#[cfg(feature = "solana-sdk")]
+pub use init_pool::InitPoolInput;
+#[cfg(feature = "solana-sdk")]
+pub use initiate_emergency_withdraw::InitiateEmergencyWithdrawInput;
+#[cfg(feature = "solana-sdk")]
+pub use initiate_vault_migration::InitiateVaultMigrationInput;
+#[cfg(feature = "solana-sdk")]
+pub use migrate_vault::MigrateVaultInput;
+#[cfg(feature = "solana-sdk")]
+pub use remove_fee_exempt::RemoveFeeExemptInput;
+#[cfg(feature = "solana-sdk")]
+pub use return_excess::ReturnExcessInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_emergency_withdraw_delay::SetEmergencyWithdrawDelayInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_fee_rates::SetFeeRatesInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_finalize_interval::SetFinalizeIntervalInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_pool_active::SetPoolActiveInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_pool_limits::SetPoolLimitsInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_pool_paused::SetPoolPausedInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_protocol_fee_config::SetProtocolFeeConfigInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_sweep_config::SetSweepConfigInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_withdrawal_policy::SetWithdrawalPolicyInput;
+#[cfg(feature = "solana-sdk")]
+pub use transfer_authority::TransferAuthorityInput;
+#[cfg(feature = "solana-sdk")]
+pub use withdraw_protocol_fees::WithdrawProtocolFeesInput;
@@ -0,0 +1,47 @@
+//! Remove an authority from the pool's fee-exempt list.
+
+use crate::TokenPoolConfig;
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::ProgramResult;
+use pinocchio_log::log;
+
+/// Instruction data for RemoveFeeExempt.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct RemoveFeeExemptData {
+    /// Authority to remove from the fee-exempt list
+    pub authority: [u8; 32],
+}
+
+/// Accounts for the RemoveFeeExempt instruction.
+#[derive(Accounts)]
+pub struct RemoveFeeExemptAccounts<'info> {
+    /// Pool config PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub pool_config: AccountLoader<'info, TokenPoolConfig>,
+
+    /// Must match pool_config.authority
+    pub authority: Signer<'info>,
+}
+
+/// Make an authority pay deposit and withdrawal fees again.
+///
+/// Fails with `FeeExemptNotListed` if the authority is not on the list.
+pub fn process_remove_fee_exempt(
+    ctx: Context<RemoveFeeExemptAccounts>,
+    data: RemoveFeeExemptData,
+) -> ProgramResult {
+    let RemoveFeeExemptAccounts {
+        pool_config,
+        authority,
+    } = ctx.accounts;
+
+    pool_config.try_inspect_mut(|config| {
+        config.require_authority(authority.key())?;
+        config.remove_fee_exempt(&data.authority)?;
+
+        log!("remove_fee_exempt: success");
+        Ok(())
+    })
+}
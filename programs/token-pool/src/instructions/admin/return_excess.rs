@@ -0,0 +1,130 @@
+//! Return untracked excess tokens out of the vault.
+
+use crate::{
+    TokenPoolConfig, TokenPoolError, emit_event,
+    events::ExcessReturnedEvent,
+    gen_token_pool_config_seeds,
+    token::{optional_mint, read_token_balance, transfer_tokens},
+};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, instruction::Signer as PinocchioSigner,
+    sysvars::Sysvar,
+};
+use pinocchio_log::log;
+
+/// Instruction data for ReturnExcess.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct ReturnExcessData {
+    /// Amount to return (at most the vault's current excess)
+    pub amount: u64,
+}
+
+/// Accounts for the ReturnExcess instruction.
+#[derive(Accounts)]
+pub struct ReturnExcessAccounts<'info> {
+    /// Pool config PDA (vault owner, signs the transfer)
+    #[account(mut, owner = crate::ID)]
+    pub pool_config: AccountLoader<'info, TokenPoolConfig>,
+
+    /// Vault token account (source of the excess)
+    /// Must match pool_config.vault
+    #[account(mut)]
+    pub vault: &'info AccountInfo,
+
+    /// Token account receiving the excess (e.g. the original sender's)
+    #[account(mut)]
+    pub destination: &'info AccountInfo,
+
+    /// Must match pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Token program the pool was created with (required for Transfer CPI)
+    pub token_program: &'info AccountInfo,
+
+    /// Token pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub token_pool_program: &'info AccountInfo,
+    // Optional trailing account: mint (required for Token-2022 pools)
+}
+
+/// Send excess tokens that arrived outside program control back out of the vault.
+///
+/// Lets the authority refund an accidental transfer before `SweepExcess`
+/// socializes it. Fails with `InsufficientExcess` if `amount` exceeds the
+/// vault balance above the expected balance, so tracked funds never leave.
+pub fn process_return_excess(
+    ctx: Context<ReturnExcessAccounts>,
+    data: ReturnExcessData,
+) -> ProgramResult {
+    let ReturnExcessAccounts {
+        pool_config,
+        vault: vault_acc,
+        destination,
+        authority,
+        token_program,
+        token_pool_program,
+    } = ctx.accounts;
+
+    if data.amount == 0 {
+        return Err(TokenPoolError::InvalidAmount.into());
+    }
+
+    let (mint, bump, token_program_id, decimals) = pool_config.try_map(|config| {
+        config.require_authority(authority.key())?;
+        config.require_token_program(token_program.key())?;
+        config.require_vault(vault_acc.key())?;
+        Ok((
+            config.mint,
+            config.bump,
+            config.token_program,
+            config.decimals,
+        ))
+    })?;
+
+    // Check and record the return against the current excess before transferring
+    let vault_balance = read_token_balance(vault_acc, &token_program_id)?;
+    let remaining_excess = pool_config.try_map_mut(|config| {
+        let excess = (vault_balance as u128).saturating_sub(config.expected_vault_balance()?);
+        let excess = u64::try_from(excess).map_err(|_| TokenPoolError::ArithmeticOverflow)?;
+        if data.amount > excess {
+            log!("return_excess: amount exceeds vault excess");
+            return Err(TokenPoolError::InsufficientExcess.into());
+        }
+        config.return_excess(data.amount, excess)?;
+        Ok(excess - data.amount)
+    })?;
+
+    // Transfer vault -> destination, signed by the pool config PDA
+    let bump_bytes = [bump];
+    let seeds = gen_token_pool_config_seeds(&mint, &bump_bytes);
+
+    transfer_tokens(
+        &token_program_id,
+        vault_acc,
+        destination,
+        pool_config.account_info(),
+        optional_mint(ctx.remaining_accounts, &mint)?,
+        data.amount,
+        decimals,
+        &[PinocchioSigner::from(&seeds)],
+    )?;
+
+    emit_event(
+        pool_config.account_info(),
+        token_pool_program,
+        PinocchioSigner::from(&seeds),
+        &ExcessReturnedEvent {
+            mint,
+            destination: *destination.key(),
+            amount: data.amount,
+            remaining_excess,
+            slot: pinocchio::sysvars::clock::Clock::get()?.slot,
+        },
+    )?;
+
+    log!("return_excess: success");
+    Ok(())
+}
@@ -0,0 +1,54 @@
+//! Set the delay between initiating and executing an emergency withdrawal.
+
+use crate::{TokenPoolConfig, TokenPoolError};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::ProgramResult;
+use pinocchio_log::log;
+
+/// Instruction data for SetEmergencyWithdrawDelay.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetEmergencyWithdrawDelayData {
+    /// Slots between initiation and execution
+    /// (at least `MIN_EMERGENCY_WITHDRAW_DELAY_SLOTS`)
+    pub delay_slots: u64,
+}
+
+/// Accounts for the SetEmergencyWithdrawDelay instruction.
+#[derive(Accounts)]
+pub struct SetEmergencyWithdrawDelayAccounts<'info> {
+    /// Pool config PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub pool_config: AccountLoader<'info, TokenPoolConfig>,
+
+    /// Must match pool_config.authority
+    pub authority: Signer<'info>,
+}
+
+/// Update `emergency_withdraw_delay_slots`.
+///
+/// Fails with `InvalidEmergencyWithdrawDelay` below the hard-coded floor. An
+/// already pending emergency withdrawal keeps the unlock slot it was given.
+pub fn process_set_emergency_withdraw_delay(
+    ctx: Context<SetEmergencyWithdrawDelayAccounts>,
+    data: SetEmergencyWithdrawDelayData,
+) -> ProgramResult {
+    let SetEmergencyWithdrawDelayAccounts {
+        pool_config,
+        authority,
+    } = ctx.accounts;
+
+    if data.delay_slots < TokenPoolConfig::MIN_EMERGENCY_WITHDRAW_DELAY_SLOTS {
+        log!("set_emergency_withdraw_delay: delay below floor");
+        return Err(TokenPoolError::InvalidEmergencyWithdrawDelay.into());
+    }
+
+    pool_config.try_inspect_mut(|config| {
+        config.require_authority(authority.key())?;
+        config.emergency_withdraw_delay_slots = data.delay_slots;
+
+        log!("set_emergency_withdraw_delay: success");
+        Ok(())
+    })
+}
@@ -0,0 +1,60 @@
+//! Set the pool's rewards finalization interval.
+
+use crate::{TokenPoolConfig, TokenPoolError};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::ProgramResult;
+use pinocchio_log::log;
+
+/// Instruction data for SetFinalizeInterval.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetFinalizeIntervalData {
+    /// Minimum slots between finalizations (0 = `UPDATE_SLOT_INTERVAL`)
+    pub finalize_interval_slots: u64,
+}
+
+/// Accounts for the SetFinalizeInterval instruction.
+#[derive(Accounts)]
+pub struct SetFinalizeIntervalAccounts<'info> {
+    /// Pool config PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub pool_config: AccountLoader<'info, TokenPoolConfig>,
+
+    /// Must match pool_config.authority
+    pub authority: Signer<'info>,
+}
+
+/// Update how often `FinalizeRewards` may run for this pool.
+///
+/// A non-zero interval must lie within `MIN_FINALIZE_INTERVAL_SLOTS` and
+/// `MAX_FINALIZE_INTERVAL_SLOTS`; 0 reverts to the global default. Takes
+/// effect from the next finalization, measured from `last_finalized_slot`.
+pub fn process_set_finalize_interval(
+    ctx: Context<SetFinalizeIntervalAccounts>,
+    data: SetFinalizeIntervalData,
+) -> ProgramResult {
+    let SetFinalizeIntervalAccounts {
+        pool_config,
+        authority,
+    } = ctx.accounts;
+
+    pool_config.try_inspect_mut(|config| {
+        config.require_authority(authority.key())?;
+
+        let interval = data.finalize_interval_slots;
+        if interval != 0
+            && !(TokenPoolConfig::MIN_FINALIZE_INTERVAL_SLOTS
+                ..=TokenPoolConfig::MAX_FINALIZE_INTERVAL_SLOTS)
+                .contains(&interval)
+        {
+            log!("set_finalize_interval: interval out of bounds");
+            return Err(TokenPoolError::InvalidFinalizeInterval.into());
+        }
+
+        config.finalize_interval_slots = interval;
+
+        log!("set_finalize_interval: success");
+        Ok(())
+    })
+}
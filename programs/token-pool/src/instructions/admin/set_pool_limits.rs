@@ -0,0 +1,61 @@
+//! Set per-pool deposit limits.
+
+use crate::{TokenPoolConfig, TokenPoolError};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::ProgramResult;
+use pinocchio_log::log;
+
+/// Instruction data for SetPoolLimits.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetPoolLimitsData {
+    /// Cap on the accounted pool balance after a deposit (0 = unlimited)
+    pub max_total_pool: u128,
+    /// Cap on a single deposit's gross amount (0 = unlimited)
+    pub max_single_deposit: u64,
+    /// Minimum gross amount per deposit (0 = unlimited)
+    pub min_deposit: u64,
+}
+
+/// Accounts for the SetPoolLimits instruction.
+#[derive(Accounts)]
+pub struct SetPoolLimitsAccounts<'info> {
+    /// Pool config PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub pool_config: AccountLoader<'info, TokenPoolConfig>,
+
+    /// Must match pool_config.authority
+    pub authority: Signer<'info>,
+}
+
+/// Update the deposit limits enforced by `Deposit`.
+///
+/// Each limit is independent and 0 disables it. Fails with `InvalidAmount`
+/// if both `min_deposit` and `max_single_deposit` are set and the minimum
+/// exceeds the cap. Withdrawals are never limited.
+pub fn process_set_pool_limits(
+    ctx: Context<SetPoolLimitsAccounts>,
+    data: SetPoolLimitsData,
+) -> ProgramResult {
+    let SetPoolLimitsAccounts {
+        pool_config,
+        authority,
+    } = ctx.accounts;
+
+    pool_config.try_inspect_mut(|config| {
+        config.require_authority(authority.key())?;
+
+        if data.max_single_deposit != 0 && data.min_deposit > data.max_single_deposit {
+            log!("set_pool_limits: min_deposit exceeds max_single_deposit");
+            return Err(TokenPoolError::InvalidAmount.into());
+        }
+
+        config.max_total_pool = data.max_total_pool;
+        config.max_single_deposit = data.max_single_deposit;
+        config.min_deposit = data.min_deposit;
+
+        log!("set_pool_limits: success");
+        Ok(())
+    })
+}
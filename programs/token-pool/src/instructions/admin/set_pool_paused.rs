@@ -0,0 +1,88 @@
+//! Set the pool program's own pause state.
+
+use crate::{
+    TokenPoolConfig, emit_event, events::PoolPauseChangedEvent, gen_token_pool_config_seeds,
+};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, instruction::Signer as PinocchioSigner,
+    sysvars::Sysvar,
+};
+use pinocchio_log::log;
+
+/// Instruction data for SetPoolPaused.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetPoolPausedData {
+    /// New pause state (1 = paused, 0 = unpaused)
+    pub paused: u8,
+    /// While paused, still allow withdrawals (1 = allow, 0 = block)
+    pub allow_withdrawals_when_paused: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 6],
+}
+
+/// Accounts for the SetPoolPaused instruction.
+#[derive(Accounts)]
+pub struct SetPoolPausedAccounts<'info> {
+    /// Pool config PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub pool_config: AccountLoader<'info, TokenPoolConfig>,
+
+    /// Must match pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Token pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub token_pool_program: &'info AccountInfo,
+}
+
+/// Pause or unpause deposits and withdrawals in the pool program itself.
+///
+/// Unlike `SetPoolActive`, which mirrors the hub's routing flag, this is
+/// checked first in `Deposit` and `Withdraw` as defense in depth against a
+/// hub that routes to a pool it should not.
+pub fn process_set_pool_paused(
+    ctx: Context<SetPoolPausedAccounts>,
+    data: SetPoolPausedData,
+) -> ProgramResult {
+    let SetPoolPausedAccounts {
+        pool_config,
+        authority,
+        token_pool_program,
+    } = ctx.accounts;
+
+    // Normalize flags to 0/1
+    let paused = (data.paused != 0) as u8;
+    let allow_withdrawals_when_paused = (data.allow_withdrawals_when_paused != 0) as u8;
+
+    let (mint, bump) = pool_config.try_map_mut(|config| {
+        config.require_authority(authority.key())?;
+
+        config.paused = paused;
+        config.allow_withdrawals_when_paused = allow_withdrawals_when_paused;
+
+        Ok((config.mint, config.bump))
+    })?;
+
+    let bump_bytes = [bump];
+    let seeds = gen_token_pool_config_seeds(&mint, &bump_bytes);
+    let signer = PinocchioSigner::from(&seeds);
+
+    emit_event(
+        pool_config.account_info(),
+        token_pool_program,
+        signer,
+        &PoolPauseChangedEvent {
+            mint,
+            slot: pinocchio::sysvars::clock::Clock::get()?.slot,
+            paused,
+            allow_withdrawals_when_paused,
+            _padding: [0u8; 6],
+        },
+    )?;
+
+    log!("set_pool_paused: success");
+    Ok(())
+}
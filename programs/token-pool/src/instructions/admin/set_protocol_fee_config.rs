@@ -0,0 +1,61 @@
+//! Set the protocol fee share and treasury.
+
+use crate::{TokenPoolConfig, TokenPoolError};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::{ProgramResult, pubkey::Pubkey};
+use pinocchio_log::log;
+use zorb_pool_interface::BASIS_POINTS;
+
+/// Instruction data for SetProtocolFeeConfig.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetProtocolFeeConfigData {
+    /// Wallet whose canonical ATA receives withdrawn protocol fees
+    pub treasury: Pubkey,
+    /// Share of each fee kept by the protocol, in basis points (max 10000)
+    pub protocol_fee_share_bps: u16,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 6],
+}
+
+/// Accounts for the SetProtocolFeeConfig instruction.
+#[derive(Accounts)]
+pub struct SetProtocolFeeConfigAccounts<'info> {
+    /// Pool config PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub pool_config: AccountLoader<'info, TokenPoolConfig>,
+
+    /// Must match pool_config.authority
+    pub authority: Signer<'info>,
+}
+
+/// Set the protocol fee share and treasury for a token pool.
+///
+/// The share applies to fees collected after this call; fees already in
+/// `pending_deposit_fees`/`pending_withdrawal_fees` stay with depositors.
+pub fn process_set_protocol_fee_config(
+    ctx: Context<SetProtocolFeeConfigAccounts>,
+    data: SetProtocolFeeConfigData,
+) -> ProgramResult {
+    let SetProtocolFeeConfigAccounts {
+        pool_config,
+        authority,
+    } = ctx.accounts;
+
+    pool_config.try_inspect_mut(|config| {
+        config.require_authority(authority.key())?;
+
+        // Validate share is within bounds (max 100% = 10000 basis points)
+        if data.protocol_fee_share_bps > BASIS_POINTS as u16 {
+            log!("set_protocol_fee_config: share exceeds 100%");
+            return Err(TokenPoolError::InvalidFeeRate.into());
+        }
+
+        config.treasury = data.treasury;
+        config.protocol_fee_share_bps = data.protocol_fee_share_bps;
+
+        log!("set_protocol_fee_config: success");
+        Ok(())
+    })
+}
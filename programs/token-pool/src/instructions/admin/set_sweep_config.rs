@@ -0,0 +1,51 @@
+//! Set the limits on sweeping excess vault tokens.
+
+use crate::TokenPoolConfig;
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::ProgramResult;
+use pinocchio_log::log;
+
+/// Instruction data for SetSweepConfig.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetSweepConfigData {
+    /// Cap on the excess swept per `SweepExcess` call (0 = unlimited)
+    pub max_sweep_per_call: u64,
+    /// Slots detected excess must wait before it can be swept (0 = immediately)
+    pub sweep_delay_slots: u64,
+}
+
+/// Accounts for the SetSweepConfig instruction.
+#[derive(Accounts)]
+pub struct SetSweepConfigAccounts<'info> {
+    /// Pool config PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub pool_config: AccountLoader<'info, TokenPoolConfig>,
+
+    /// Must match pool_config.authority
+    pub authority: Signer<'info>,
+}
+
+/// Update the per-call cap and delay applied by `SweepExcess`.
+///
+/// A new delay also applies to excess that is already detected and waiting.
+pub fn process_set_sweep_config(
+    ctx: Context<SetSweepConfigAccounts>,
+    data: SetSweepConfigData,
+) -> ProgramResult {
+    let SetSweepConfigAccounts {
+        pool_config,
+        authority,
+    } = ctx.accounts;
+
+    pool_config.try_inspect_mut(|config| {
+        config.require_authority(authority.key())?;
+
+        config.max_sweep_per_call = data.max_sweep_per_call;
+        config.sweep_delay_slots = data.sweep_delay_slots;
+
+        log!("set_sweep_config: success");
+        Ok(())
+    })
+}
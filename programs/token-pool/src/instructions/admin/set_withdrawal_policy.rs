@@ -0,0 +1,57 @@
+//! Set the per-pool withdrawal minimum and fee rounding direction.
+
+use crate::TokenPoolConfig;
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::ProgramResult;
+use pinocchio_log::log;
+
+/// Instruction data for SetWithdrawalPolicy.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetWithdrawalPolicyData {
+    /// Minimum gross amount per withdrawal (0 = unlimited)
+    pub min_withdrawal: u64,
+    /// Fee rounding direction (1 = up, 0 = down)
+    pub round_fees_up: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 7],
+}
+
+/// Accounts for the SetWithdrawalPolicy instruction.
+#[derive(Accounts)]
+pub struct SetWithdrawalPolicyAccounts<'info> {
+    /// Pool config PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub pool_config: AccountLoader<'info, TokenPoolConfig>,
+
+    /// Must match pool_config.authority
+    pub authority: Signer<'info>,
+}
+
+/// Update the withdrawal minimum and the fee rounding direction.
+///
+/// `min_withdrawal` keeps withdrawals from paying out less than the ATA rent
+/// a recipient spends to receive them. `round_fees_up` applies to deposit and
+/// withdrawal fees alike; the hub reads it from the config, so clients must
+/// compute fees with the same direction.
+pub fn process_set_withdrawal_policy(
+    ctx: Context<SetWithdrawalPolicyAccounts>,
+    data: SetWithdrawalPolicyData,
+) -> ProgramResult {
+    let SetWithdrawalPolicyAccounts {
+        pool_config,
+        authority,
+    } = ctx.accounts;
+
+    pool_config.try_inspect_mut(|config| {
+        config.require_authority(authority.key())?;
+
+        config.min_withdrawal = data.min_withdrawal;
+        // Normalize flag to 0/1
+        config.round_fees_up = (data.round_fees_up != 0) as u8;
+
+        log!("set_withdrawal_policy: success");
+        Ok(())
+    })
+}
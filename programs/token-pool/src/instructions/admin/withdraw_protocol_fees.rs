@@ -0,0 +1,143 @@
+//! Withdraw accrued protocol fees to the treasury.
+
+use crate::{
+    TokenPoolConfig, TokenPoolError, emit_event,
+    events::ProtocolFeesWithdrawnEvent,
+    gen_token_pool_config_seeds,
+    token::{find_associated_token_address, optional_mint, transfer_tokens},
+};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, instruction::Signer as PinocchioSigner,
+    sysvars::Sysvar,
+};
+use pinocchio_log::log;
+
+/// Instruction data for WithdrawProtocolFees.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct WithdrawProtocolFeesData {
+    /// Amount to withdraw (at most `accrued_protocol_fees`)
+    pub amount: u64,
+}
+
+/// Accounts for the WithdrawProtocolFees instruction.
+#[derive(Accounts)]
+pub struct WithdrawProtocolFeesAccounts<'info> {
+    /// Pool config PDA (vault owner, signs the transfer)
+    #[account(mut, owner = crate::ID)]
+    pub pool_config: AccountLoader<'info, TokenPoolConfig>,
+
+    /// Vault token account (source of protocol fees)
+    /// Must match pool_config.vault
+    #[account(mut)]
+    pub vault: &'info AccountInfo,
+
+    /// Treasury token account - canonical ATA of `pool_config.treasury` for the mint
+    #[account(mut)]
+    pub treasury_token: &'info AccountInfo,
+
+    /// Must match pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Token program the pool was created with (required for Transfer CPI)
+    pub token_program: &'info AccountInfo,
+
+    /// Token pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub token_pool_program: &'info AccountInfo,
+    // Optional trailing account: mint (required for Token-2022 pools)
+}
+
+/// Transfer accrued protocol fees from the vault to the treasury ATA.
+///
+/// Fails with `InsufficientProtocolFees` if `amount` exceeds
+/// `accrued_protocol_fees`, and with `InvalidTreasuryAccount` if no treasury
+/// is set or `treasury_token` is not its canonical ATA.
+pub fn process_withdraw_protocol_fees(
+    ctx: Context<WithdrawProtocolFeesAccounts>,
+    data: WithdrawProtocolFeesData,
+) -> ProgramResult {
+    let WithdrawProtocolFeesAccounts {
+        pool_config,
+        vault: vault_acc,
+        treasury_token,
+        authority,
+        token_program,
+        token_pool_program,
+    } = ctx.accounts;
+
+    if data.amount == 0 {
+        return Err(TokenPoolError::InvalidAmount.into());
+    }
+
+    // Validate authority, treasury, and available fees (borrow released after closure)
+    let (mint, treasury, bump, token_program_id, decimals) = pool_config.try_map(|config| {
+        config.require_authority(authority.key())?;
+        config.require_token_program(token_program.key())?;
+        config.require_vault(vault_acc.key())?;
+
+        if config.treasury == [0u8; 32] {
+            log!("withdraw_protocol_fees: treasury not set");
+            return Err(TokenPoolError::InvalidTreasuryAccount.into());
+        }
+
+        if data.amount > config.accrued_protocol_fees {
+            log!("withdraw_protocol_fees: amount exceeds accrued fees");
+            return Err(TokenPoolError::InsufficientProtocolFees.into());
+        }
+
+        Ok((
+            config.mint,
+            config.treasury,
+            config.bump,
+            config.token_program,
+            config.decimals,
+        ))
+    })?;
+
+    let expected_treasury_token =
+        find_associated_token_address(&treasury, &mint, &token_program_id);
+    if *treasury_token.key() != expected_treasury_token {
+        log!("withdraw_protocol_fees: treasury_token is not the treasury ATA");
+        return Err(TokenPoolError::InvalidTreasuryAccount.into());
+    }
+
+    // Transfer vault -> treasury, signed by the pool config PDA
+    let bump_bytes = [bump];
+    let seeds = gen_token_pool_config_seeds(&mint, &bump_bytes);
+
+    transfer_tokens(
+        &token_program_id,
+        vault_acc,
+        treasury_token,
+        pool_config.account_info(),
+        optional_mint(ctx.remaining_accounts, &mint)?,
+        data.amount,
+        decimals,
+        &[PinocchioSigner::from(&seeds)],
+    )?;
+
+    // Update pool state
+    let remaining = pool_config.try_map_mut(|config| {
+        config.withdraw_protocol_fees(data.amount)?;
+        Ok(config.accrued_protocol_fees)
+    })?;
+
+    emit_event(
+        pool_config.account_info(),
+        token_pool_program,
+        PinocchioSigner::from(&seeds),
+        &ProtocolFeesWithdrawnEvent {
+            mint,
+            treasury,
+            amount: data.amount,
+            remaining,
+            slot: pinocchio::sysvars::clock::Clock::get()?.slot,
+        },
+    )?;
+
+    log!("withdraw_protocol_fees: success");
+    Ok(())
+}
@@ -1,19 +1,21 @@
 //! Deposit instruction handler.
 //!
 //! Pool executes the token transfer itself, validates expected_output,
-//! updates accounting, and returns the protocol fee via return data.
+//! updates accounting, and returns a `PoolOpResult` via return data.
 
 use crate::{
-    TokenPoolConfig, TokenPoolError, emit_event, events::TokenDepositEvent,
+    TokenPoolConfig, TokenPoolError, emit_event,
+    events::TokenDepositEvent,
     gen_token_pool_config_seeds,
+    token::{optional_mint, read_token_balance, transfer_tokens},
 };
 use panchor::prelude::*;
 use pinocchio::{
     ProgramResult, account_info::AccountInfo, instruction::Signer as PinocchioSigner,
-    program::set_return_data, sysvars::Sysvar,
+    program::set_return_data, program_error::ProgramError, sysvars::Sysvar,
 };
-use pinocchio_token::instructions::Transfer;
-use zorb_pool_interface::{DepositParams, PoolReturnData, calculate_deposit_output};
+use pinocchio_log::log;
+use zorb_pool_interface::{DepositParams, PoolOpResult};
 
 /// Accounts for the Deposit instruction.
 ///
@@ -26,6 +28,7 @@ use zorb_pool_interface::{DepositParams, PoolReturnData, calculate_deposit_outpu
 /// 3. depositor (signer)
 /// 4. token_program
 /// 5. self_program
+/// 6. mint (optional, required for Token-2022 pools)
 #[derive(Accounts)]
 pub struct DepositAccounts<'info> {
     /// Pool configuration account (writable for state updates)
@@ -33,8 +36,8 @@ pub struct DepositAccounts<'info> {
     pub pool_config: AccountLoader<'info, TokenPoolConfig>,
 
     /// Vault token account (writable for receiving tokens)
-    /// PDA derived from: ["vault", pool_config]
-    #[account(mut, pda = Vault, pda::pool_config = pool_config.key())]
+    /// Must match pool_config.vault
+    #[account(mut)]
     pub vault: &'info AccountInfo,
 
     /// Depositor's token account (writable for transfer)
@@ -44,7 +47,7 @@ pub struct DepositAccounts<'info> {
     /// Depositor authority (signer for transfer)
     pub depositor: Signer<'info>,
 
-    /// SPL Token program (required for Transfer CPI)
+    /// Token program the pool was created with (required for Transfer CPI)
     pub token_program: &'info AccountInfo,
 
     /// Token pool program account (required for self-CPI event emission)
@@ -55,42 +58,70 @@ pub struct DepositAccounts<'info> {
 /// Process a deposit instruction.
 ///
 /// 1. Validates caller is hub
-/// 2. Parses params { amount, expected_output }
-/// 3. Calculates fee = amount * deposit_fee_rate
-/// 4. Validates: amount - fee == expected_output
+/// 2. Parses params { amount, expected_output, depositor, expect_fee_exempt }
+/// 3. Calculates fee = amount * deposit_fee_rate, rounded per `round_fees_up`
+///    (0 for fee-exempt depositors)
+/// 4. Validates: amount - fee == expected_output, and the pool limits
 /// 5. Executes transfer: depositor_token -> vault (amount)
-/// 6. Updates pool accounting
-/// 7. Returns { fee } via set_return_data
+/// 6. Updates pool accounting with what the vault actually received
+/// 7. Returns `PoolOpResult { gross, fee, net, .. }` via set_return_data
+///
+/// A Token-2022 transfer fee reduces what the vault receives. The shortfall
+/// comes out of the pool's deposit fee so the principal stays fully backed;
+/// fails with `TransferFeeExceedsPoolFee` if the deposit fee cannot cover it.
+///
+/// Fails with `FeeExemptionMismatch` if `expect_fee_exempt` disagrees with
+/// the fee-exempt list, or if an exempt `depositor` is not the signer.
+///
+/// Fails with `PoolPaused` before anything else while the pool is paused
+/// via `SetPoolPaused`.
 pub fn process_deposit(ctx: Context<DepositAccounts>, instruction_data: &[u8]) -> ProgramResult {
     let DepositAccounts {
         pool_config,
         vault: vault_acc,
         depositor_token: depositor_token_acc,
         depositor: depositor_acc,
-        token_program: _,
+        token_program,
         token_pool_program,
     } = ctx.accounts;
 
+    // Enforce the pool's own pause even if the hub routed this deposit
+    pool_config.try_inspect(|config| Ok(config.require_deposits_allowed()?))?;
+
     // Validate pool_config is the canonical PDA derived from its mint
     let pool_config_key = pool_config.key();
     let mint = pool_config.map(|config| config.mint)?;
     TokenPoolConfig::validate_pda(pool_config_key, &mint)?;
 
-    // Parse instruction data (panchor strips discriminator, so we get raw params)
-    let params = DepositParams::from_bytes(instruction_data)
-        .ok_or(TokenPoolError::InvalidInstructionData)?;
+    let params = parse_deposit_params(instruction_data)?;
+
+    let mint_acc = optional_mint(ctx.remaining_accounts, &mint)?;
 
     // Read config to calculate fee and validate (borrow released after closure)
-    let (fee, principal) = pool_config.try_map(|config| {
+    let (fee, principal, exempt, token_program_id, decimals) = pool_config.try_map(|config| {
         config.require_active()?;
+        config.require_token_program(token_program.key())?;
+        config.require_vault(vault_acc.key())?;
 
         // Check deposit limit
         if params.amount > config.max_deposit_amount {
             return Err(TokenPoolError::DepositLimitExceeded.into());
         }
 
-        // Calculate fee using shared helper (None = no exchange rate for token pool)
-        let (principal, fee) = calculate_deposit_output(params.amount, config.deposit_fee_rate, None)
+        // Exempt depositors pay no fee; the hub must have computed the same
+        let exempt =
+            config.require_fee_exemption(&params.depositor, params.expects_fee_exempt())?;
+        if exempt && params.depositor != *depositor_acc.key() {
+            log!("deposit: fee-exempt depositor is not the signer");
+            return Err(TokenPoolError::FeeExemptionMismatch.into());
+        }
+        let fee_rate = if exempt { 0 } else { config.deposit_fee_rate };
+
+        // Calculate fee in the pool's rounding direction (the hub uses the same)
+        let fee = config.calculate_fee(params.amount, fee_rate)?;
+        let principal = params
+            .amount
+            .checked_sub(fee)
             .ok_or(TokenPoolError::ArithmeticOverflow)?;
 
         // Validate expected_output matches
@@ -98,17 +129,43 @@ pub fn process_deposit(ctx: Context<DepositAccounts>, instruction_data: &[u8]) -
             return Err(TokenPoolError::ExpectedOutputMismatch.into());
         }
 
-        Ok((fee, principal))
+        // Check per-pool minimum, single-deposit cap, and total pool cap
+        config.require_deposit_within_limits(params.amount, principal)?;
+
+        Ok((
+            fee,
+            principal,
+            exempt,
+            config.token_program,
+            config.decimals,
+        ))
     })?;
 
     // Execute transfer: depositor_token -> vault (borrow released)
-    Transfer {
-        from: depositor_token_acc,
-        to: vault_acc,
-        authority: depositor_acc,
-        amount: params.amount,
-    }
-    .invoke()?;
+    let vault_before = read_token_balance(vault_acc, &token_program_id)?;
+    transfer_tokens(
+        &token_program_id,
+        depositor_token_acc,
+        vault_acc,
+        depositor_acc,
+        mint_acc,
+        params.amount,
+        decimals,
+        &[],
+    )?;
+
+    // Credit what arrived: any transfer fee is absorbed by the deposit fee
+    let received = read_token_balance(vault_acc, &token_program_id)?
+        .checked_sub(vault_before)
+        .ok_or(TokenPoolError::ArithmeticOverflow)?;
+    let shortfall = params
+        .amount
+        .checked_sub(received)
+        .ok_or(TokenPoolError::ArithmeticOverflow)?;
+    let fee = fee.checked_sub(shortfall).ok_or_else(|| {
+        log!("deposit: transfer fee exceeds deposit fee");
+        TokenPoolError::TransferFeeExceedsPoolFee
+    })?;
 
     // Update pool state
     pool_config.try_inspect_mut(|config| {
@@ -122,18 +179,19 @@ pub fn process_deposit(ctx: Context<DepositAccounts>, instruction_data: &[u8]) -
             .checked_add(principal as u128)
             .ok_or(TokenPoolError::ArithmeticOverflow)?;
 
-        // Track protocol fees
+        // Track fees, accruing the protocol share
         if fee > 0 {
-            config.total_deposit_fees = config
-                .total_deposit_fees
-                .checked_add(fee as u128)
-                .ok_or(TokenPoolError::ArithmeticOverflow)?;
-
-            config.pending_deposit_fees = config
-                .pending_deposit_fees
-                .checked_add(fee)
-                .ok_or(TokenPoolError::ArithmeticOverflow)?;
+            config.record_deposit_fee(fee)?;
         }
+        if exempt {
+            config.record_fee_exempt_volume(params.amount)?;
+        }
+        config.record_epoch_deposit(params.amount, fee);
+
+        config.total_transfer_fee_shortfall = config
+            .total_transfer_fee_shortfall
+            .checked_add(shortfall as u128)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
 
         // Increment deposit counter
         config.deposit_count = config
@@ -145,8 +203,12 @@ pub fn process_deposit(ctx: Context<DepositAccounts>, instruction_data: &[u8]) -
     })?;
 
     // Emit deposit event FIRST (before set_return_data, since self-CPI clears return data)
-    let (new_balance, bump) = pool_config.try_map(|config| {
-        Ok((config.current_balance()?, config.bump))
+    let (new_balance, accumulator_epoch, bump) = pool_config.try_map(|config| {
+        Ok((
+            config.current_balance()?,
+            config.accumulator_epoch,
+            config.bump,
+        ))
     })?;
 
     let bump_bytes = [bump];
@@ -167,9 +229,23 @@ pub fn process_deposit(ctx: Context<DepositAccounts>, instruction_data: &[u8]) -
         },
     )?;
 
-    // Return fee via set_return_data (AFTER emit_event to avoid CPI overwriting it)
-    let return_data = PoolReturnData { fee };
+    // Return the result via set_return_data (AFTER emit_event to avoid CPI overwriting it).
+    // The fee reported is what the hub priced in, including any transfer fee it absorbed.
+    let return_data = PoolOpResult {
+        gross: params.amount,
+        fee: params.amount - principal,
+        net: principal,
+        accumulator_epoch,
+        new_accounted_total: new_balance,
+    };
     set_return_data(bytemuck::bytes_of(&return_data));
 
     Ok(())
 }
+
+/// Parse the hub's deposit params from instruction data.
+///
+/// Panchor strips the discriminator; accepts the legacy or versioned layout.
+pub(crate) fn parse_deposit_params(instruction_data: &[u8]) -> Result<DepositParams, ProgramError> {
+    DepositParams::parse_any(instruction_data).ok_or(TokenPoolError::InvalidInstructionData.into())
+}
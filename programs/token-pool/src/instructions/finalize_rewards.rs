@@ -1,7 +1,8 @@
 //! Finalize rewards by updating the reward accumulator.
 
 use crate::{
-    TokenPoolConfig, emit_event, events::TokenRewardsFinalizedEvent, gen_token_pool_config_seeds,
+    TokenPoolConfig, TokenPoolError, emit_event, events::TokenRewardsFinalizedEvent,
+    gen_token_pool_config_seeds,
 };
 use panchor::prelude::*;
 use pinocchio::{
@@ -28,23 +29,36 @@ pub struct FinalizeRewardsAccounts<'info> {
 /// - Calculates reward delta from pending_rewards / (finalized_balance + pending_deposits - pending_withdrawals)
 /// - Updates reward_accumulator by adding the delta
 /// - Updates finalized_balance = finalized_balance + pending_deposits - pending_withdrawals
-/// - Resets pending values to 0
+/// - Resets pending values and the per-epoch volume and fee statistics to 0
 /// - Updates last_finalized_slot
+/// - Keeps the outgoing accumulator as previous_reward_accumulator and increments accumulator_epoch
 ///
 /// After finalization, clients can generate ZK proofs against the frozen accumulator value.
 ///
 /// # Notes
 ///
 /// - Anyone can call this instruction (permissionless)
-/// - Will fail if UPDATE_SLOT_INTERVAL slots have not passed since last update
+/// - Will fail if the pool's finalize interval (`finalize_interval_slots`, or
+///   UPDATE_SLOT_INTERVAL when unset) has not passed since last update
 /// - Does nothing if there are no deposits or rewards to distribute
 pub fn process_finalize_rewards(ctx: Context<FinalizeRewardsAccounts>) -> ProgramResult {
     let FinalizeRewardsAccounts { pool_config, token_pool_program } = ctx.accounts;
 
     // Get current slot
     let clock = Clock::get()?;
-    let current_slot = clock.slot;
 
+    finalize_pool(pool_config, token_pool_program, clock.slot)
+}
+
+/// Finalize one pool and emit its `TokenRewardsFinalizedEvent`.
+///
+/// Shared by `FinalizeRewards` and `FinalizeRewardsBatch`. Fails with
+/// `RewardsNotReady` if the pool's finalize interval has not passed.
+pub(crate) fn finalize_pool(
+    pool_config: &AccountLoader<'_, TokenPoolConfig>,
+    token_pool_program: &AccountInfo,
+    current_slot: u64,
+) -> ProgramResult {
     // Capture pre-finalization values for event
     let (mint, deposit_fees, withdrawal_fees, funded_rewards, bump) = pool_config.map(|config| {
         (
@@ -55,17 +69,36 @@ pub fn process_finalize_rewards(ctx: Context<FinalizeRewardsAccounts>) -> Progra
             config.bump,
         )
     })?;
+    let (interval_slots, slots_since_last) = pool_config.map(|config| {
+        (
+            config.finalize_interval(),
+            current_slot.saturating_sub(config.last_finalized_slot),
+        )
+    })?;
+    let (epoch_deposit_volume, epoch_withdrawal_volume, epoch_deposit_fees, epoch_withdrawal_fees) =
+        pool_config.map(|config| {
+            (
+                config.epoch_deposit_volume,
+                config.epoch_withdrawal_volume,
+                config.epoch_deposit_fees,
+                config.epoch_withdrawal_fees,
+            )
+        })?;
 
     // Finalize the reward accumulator
-    pool_config.try_inspect_mut(|config| {
-        config.finalize_rewards(current_slot)?;
+    let vested_rewards = pool_config.try_map_mut(|config| {
+        let released = config.finalize_rewards(current_slot)?;
         log!("finalize_rewards: reward accumulator finalized");
-        Ok(())
+        Ok(released)
     })?;
 
     // Capture post-finalization values and emit event
-    let (total_pool, new_accumulator) = pool_config.map(|config| {
-        (config.finalized_balance, config.reward_accumulator)
+    let (total_pool, new_accumulator, accumulator_epoch) = pool_config.map(|config| {
+        (
+            config.finalized_balance,
+            config.reward_accumulator,
+            config.accumulator_epoch,
+        )
     })?;
 
     let bump_bytes = [bump];
@@ -82,8 +115,18 @@ pub fn process_finalize_rewards(ctx: Context<FinalizeRewardsAccounts>) -> Progra
             new_accumulator,
             deposit_fees,
             withdrawal_fees,
-            funded_rewards,
+            funded_rewards: funded_rewards
+                .checked_add(vested_rewards)
+                .ok_or(TokenPoolError::ArithmeticOverflow)?,
             slot: current_slot,
+            interval_slots,
+            slots_since_last,
+            epoch_deposit_volume,
+            epoch_withdrawal_volume,
+            epoch_deposit_fees,
+            epoch_withdrawal_fees,
+            accumulator_epoch,
+            _padding: 0,
         },
     )?;
 
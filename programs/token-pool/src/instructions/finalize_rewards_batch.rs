@@ -0,0 +1,85 @@
+//! Finalize rewards for several pools in one instruction.
+
+use super::finalize_rewards::finalize_pool;
+use crate::{TokenPoolConfig, TokenPoolError};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    program_error::ProgramError,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+
+/// Maximum pools per `FinalizeRewardsBatch`.
+///
+/// Each finalization costs an accumulator update plus a self-CPI event, so
+/// more than ~8 pools risks exceeding the default compute budget.
+pub const MAX_FINALIZE_BATCH_SIZE: usize = 8;
+
+/// Accounts for the FinalizeRewardsBatch instruction.
+///
+/// Pools are passed as (pool_config, vault) pairs in remaining accounts;
+/// each pool_config must be writable and each vault must match its
+/// `pool_config.vault`.
+#[derive(Accounts)]
+pub struct FinalizeRewardsBatchAccounts<'info> {
+    /// Token pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub token_pool_program: &'info AccountInfo,
+}
+
+/// Finalize rewards for up to `MAX_FINALIZE_BATCH_SIZE` pools.
+///
+/// Runs the `FinalizeRewards` logic for each pair and emits a
+/// `TokenRewardsFinalizedEvent` per finalized pool. Pools whose finalize
+/// interval has not passed are skipped with a log instead of failing the
+/// batch; any other per-pool error (wrong owner, vault mismatch, overflow)
+/// fails the whole batch.
+///
+/// Fails with `InvalidFinalizeBatch` if the remaining accounts are empty,
+/// odd in number, or hold more than `MAX_FINALIZE_BATCH_SIZE` pairs.
+pub fn process_finalize_rewards_batch(ctx: Context<FinalizeRewardsBatchAccounts>) -> ProgramResult {
+    let FinalizeRewardsBatchAccounts { token_pool_program } = ctx.accounts;
+    let pairs = ctx.remaining_accounts;
+
+    if pairs.is_empty()
+        || !pairs.len().is_multiple_of(2)
+        || pairs.len() / 2 > MAX_FINALIZE_BATCH_SIZE
+    {
+        log!(
+            "finalize_rewards_batch: invalid batch of {} accounts",
+            pairs.len()
+        );
+        return Err(TokenPoolError::InvalidFinalizeBatch.into());
+    }
+
+    let current_slot = Clock::get()?.slot;
+    let mut finalized = 0u64;
+
+    for (i, [pool_config_info, vault]) in pairs.as_chunks::<2>().0.iter().enumerate() {
+        if !pool_config_info.is_writable() {
+            log!("finalize_rewards_batch: pool {} config not writable", i);
+            return Err(ProgramError::Immutable);
+        }
+
+        let pool_config = AccountLoader::<TokenPoolConfig>::new(pool_config_info)?;
+        let ready = pool_config.try_map(|config| {
+            config.require_vault(vault.key())?;
+            Ok(config.rewards_ready(current_slot))
+        })?;
+        if !ready {
+            log!("finalize_rewards_batch: pool {} not ready, skipping", i);
+            continue;
+        }
+
+        finalize_pool(&pool_config, token_pool_program, current_slot)?;
+        finalized += 1;
+    }
+
+    log!(
+        "finalize_rewards_batch: finalized {} of {} pools",
+        finalized,
+        pairs.len() / 2
+    );
+    Ok(())
+}
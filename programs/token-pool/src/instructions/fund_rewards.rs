@@ -1,13 +1,20 @@
 //! Fund rewards instruction handler.
 //!
 //! Allows external callers to fund the reward pool with tokens.
-//! Tokens are transferred to vault and tracked in pending_rewards.
+//! Tokens are transferred to vault and tracked in pending_rewards, either
+//! immediately or through the vesting schedule.
 
-use crate::{TokenPoolConfig, TokenPoolError};
+use crate::{
+    TokenPoolConfig, TokenPoolError,
+    token::{optional_mint, read_token_balance, transfer_tokens},
+};
 use bytemuck::{Pod, Zeroable};
 use panchor::prelude::*;
-use pinocchio::{ProgramResult, account_info::AccountInfo};
-use pinocchio_token::{instructions::Transfer, state::TokenAccount};
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    sysvars::{Sysvar, clock::Clock},
+};
 
 /// Instruction data for FundRewards.
 #[repr(C)]
@@ -15,6 +22,8 @@ use pinocchio_token::{instructions::Transfer, state::TokenAccount};
 pub struct FundRewardsData {
     /// Amount of tokens to fund as rewards
     pub amount: u64,
+    /// Slots over which the funding vests linearly (0 = distribute at next finalization)
+    pub vest_duration_slots: u64,
 }
 
 /// Accounts for the FundRewards instruction.
@@ -25,25 +34,34 @@ pub struct FundRewardsAccounts<'info> {
     pub pool_config: AccountLoader<'info, TokenPoolConfig>,
 
     /// Vault token account (receives reward tokens)
-    #[account(mut, pda = Vault, pda::pool_config = pool_config.key())]
+    /// Must match pool_config.vault
+    #[account(mut)]
     pub vault: &'info AccountInfo,
 
-    /// Funder's token account (source of reward tokens)
+    /// Funder's token account (source of reward tokens, validated by the token program)
     #[account(mut)]
-    pub mine_sol: LazyAccount<'info, TokenAccount>,
+    pub mine_sol: &'info AccountInfo,
 
     /// Funder authority (signer for transfer)
     pub funder: Signer<'info>,
 
-    /// SPL Token program (required for Transfer CPI)
-    #[account(address = pinocchio_token::ID)]
+    /// Token program the pool was created with (required for Transfer CPI)
     pub token_program: &'info AccountInfo,
+    // Optional trailing account: mint (required for Token-2022 pools)
 }
 
 /// Fund the reward pool with external tokens.
 ///
 /// Permissionless - anyone with tokens can fund rewards.
 /// Tokens are transferred to vault and added to pending_rewards.
+///
+/// With `vest_duration_slots` set, the amount joins the pool's vesting
+/// schedule instead (see `TokenPoolConfig::add_vesting_rewards`), and each
+/// finalization only distributes the part vested so far. This spreads a large
+/// funding across finalizations rather than creating a one-off APY spike.
+///
+/// Only what the vault receives is credited; a Token-2022 transfer fee is
+/// recorded in `total_transfer_fee_shortfall`.
 pub fn process_fund_rewards(
     ctx: Context<FundRewardsAccounts>,
     data: FundRewardsData,
@@ -53,39 +71,53 @@ pub fn process_fund_rewards(
         vault: vault_acc,
         mine_sol,
         funder: funder_acc,
-        token_program: _,
+        token_program,
     } = ctx.accounts;
 
     // Validate config is active and amount > 0
-    pool_config.try_inspect(|config| {
+    let (mint, token_program_id, decimals) = pool_config.try_map(|config| {
         config.require_active()?;
+        config.require_token_program(token_program.key())?;
+        config.require_vault(vault_acc.key())?;
         if data.amount == 0 {
             return Err(TokenPoolError::InvalidAmount.into());
         }
-        Ok(())
+        Ok((config.mint, config.token_program, config.decimals))
     })?;
+    let mint_acc = optional_mint(ctx.remaining_accounts, &mint)?;
 
     // Transfer tokens: mine_sol -> vault (borrow released)
-    Transfer {
-        from: mine_sol.info(),
-        to: vault_acc,
-        authority: funder_acc,
-        amount: data.amount,
-    }
-    .invoke()?;
+    let vault_before = read_token_balance(vault_acc, &token_program_id)?;
+    transfer_tokens(
+        &token_program_id,
+        mine_sol,
+        vault_acc,
+        funder_acc,
+        mint_acc,
+        data.amount,
+        decimals,
+        &[],
+    )?;
+    let received = read_token_balance(vault_acc, &token_program_id)?
+        .checked_sub(vault_before)
+        .ok_or(TokenPoolError::ArithmeticOverflow)?;
 
     // Update state
+    let current_slot = Clock::get()?.slot;
     pool_config.try_inspect_mut(|config| {
-        // Update pending_funded_rewards (tracks external funding separately from fees)
-        config.pending_funded_rewards = config
-            .pending_funded_rewards
-            .checked_add(data.amount)
-            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+        // Credit pending_funded_rewards directly or via the vesting schedule
+        // (tracks external funding separately from fees)
+        config.add_vesting_rewards(received, data.vest_duration_slots, current_slot)?;
 
         // Update total_funded_rewards (cumulative tracking)
         config.total_funded_rewards = config
             .total_funded_rewards
-            .checked_add(data.amount as u128)
+            .checked_add(received as u128)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+
+        config.total_transfer_fee_shortfall = config
+            .total_transfer_fee_shortfall
+            .checked_add((data.amount - received) as u128)
             .ok_or(TokenPoolError::ArithmeticOverflow)?;
 
         Ok(())
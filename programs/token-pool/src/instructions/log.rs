@@ -11,8 +11,9 @@
 //!
 //! Together these checks ensure only this program's code paths can emit events.
 
+use panchor::events::LogFraming;
 use panchor::prelude::*;
-use pinocchio::{ProgramResult, account_info::AccountInfo, program_error::ProgramError};
+use pinocchio::{ProgramResult, account_info::AccountInfo};
 
 /// Accounts for Log instruction.
 #[derive(Accounts)]
@@ -26,44 +27,17 @@ pub struct LogAccounts<'info> {
 ///
 /// This instruction simply logs the provided data. It's called via self-CPI
 /// with a program-owned PDA as signer to ensure only valid program invocations
-/// can emit events.
-///
-/// # Security Checks
-///
-/// - Authority must be a signer (PDA signed via `invoke_signed`)
-/// - Authority must be owned by this program (proves it's our PDA)
+/// can emit events. Checks and framing are handled by
+/// [`panchor::events::process_log`].
 ///
 /// The event data format is: [length (4 bytes), discriminator (8 bytes), event fields...]
 pub fn process_log(ctx: Context<LogAccounts>, data: &[u8]) -> ProgramResult {
-    let LogAccounts { authority } = ctx.accounts;
-
-    // Authority must be a signer (PDA signed via invoke_signed)
-    if !authority.is_signer() {
-        log!("log: authority must be a signer");
-        return Err(ProgramError::MissingRequiredSignature);
-    }
-
-    // Authority must be owned by this program (proves it's our PDA)
-    if authority.owner() != &crate::ID {
-        log!("log: authority must be owned by this program");
-        return Err(ProgramError::IllegalOwner);
-    }
-
-    // Parse the length prefix (4 bytes, little-endian)
-    if data.len() < 4 {
-        return Err(ProgramError::InvalidInstructionData);
-    }
-
-    let len = u32::from_le_bytes([data[0], data[1], data[2], data[3]]) as usize;
-    let event_data = &data[4..];
-
-    if event_data.len() < len {
-        return Err(ProgramError::InvalidInstructionData);
-    }
-
-    // Log the raw event bytes using base64 encoding
-    // Format: "Program data: <base64_encoded_data>"
-    pinocchio::log::sol_log_data(&[&event_data[..len]]);
+    panchor::events::process_log(
+        ctx.accounts.authority,
+        &crate::ID,
+        data,
+        LogFraming::LengthPrefixed,
+    )?;
 
     log!("Token pool event emitted");
 
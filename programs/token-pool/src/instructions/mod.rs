@@ -13,8 +13,10 @@ mod withdraw;
 
 // Permissionless operations
 mod finalize_rewards;
+mod finalize_rewards_batch;
 mod fund_rewards;
 mod log;
+mod reconcile_vault;
 mod sweep_excess;
 
 // Re-export admin accounts, data, and handlers
@@ -26,10 +28,32 @@ pub use withdraw::{WithdrawAccounts, process_withdraw};
 
 // Re-export permissionless operation accounts and handlers
 pub use finalize_rewards::{FinalizeRewardsAccounts, process_finalize_rewards};
+pub use finalize_rewards_batch::{
+    FinalizeRewardsBatchAccounts, MAX_FINALIZE_BATCH_SIZE, process_finalize_rewards_batch,
+};
 pub use fund_rewards::{FundRewardsAccounts, FundRewardsData, process_fund_rewards};
 pub use log::{LogAccounts, process_log};
+pub use reconcile_vault::{ReconcileVaultAccounts, process_reconcile_vault};
 pub use sweep_excess::{SweepExcessAccounts, process_sweep_excess};
 
+// Re-export client Input structs for the generated `client` module
+#[cfg(feature = "solana-sdk")]
+pub use deposit::DepositInput;
+#[cfg(feature = "solana-sdk")]
+pub use finalize_rewards::FinalizeRewardsInput;
+#[cfg(feature = "solana-sdk")]
+pub use finalize_rewards_batch::FinalizeRewardsBatchInput;
+#[cfg(feature = "solana-sdk")]
+pub use fund_rewards::FundRewardsInput;
+#[cfg(feature = "solana-sdk")]
+pub use log::LogInput;
+#[cfg(feature = "solana-sdk")]
+pub use reconcile_vault::ReconcileVaultInput;
+#[cfg(feature = "solana-sdk")]
+pub use sweep_excess::SweepExcessInput;
+#[cfg(feature = "solana-sdk")]
+pub use withdraw::WithdrawInput;
+
 /// Token pool instruction set.
 ///
 /// # Discriminator Ranges (per discriminator-standard.md)
@@ -45,16 +69,20 @@ pub enum TokenPoolInstruction {
     // =========================================================================
     /// Process a deposit: transfer tokens from depositor to vault.
     ///
+    /// Returns a `PoolOpResult` as return data.
+    ///
     /// # Accounts
     /// See `DepositAccounts` for the required accounts.
-    #[handler(raw_data, accounts = DepositAccounts)]
+    #[handler(raw_data, accounts = DepositAccounts, returns = zorb_pool_interface::PoolOpResult)]
     Deposit = 0,
 
     /// Process a withdrawal: transfer tokens from vault to recipient.
     ///
+    /// Returns a `PoolOpResult` as return data.
+    ///
     /// # Accounts
     /// See `WithdrawAccounts` for the required accounts.
-    #[handler(raw_data, accounts = WithdrawAccounts)]
+    #[handler(raw_data, accounts = WithdrawAccounts, returns = zorb_pool_interface::PoolOpResult)]
     Withdraw = 1,
     // Reserved: 2-31
 
@@ -78,7 +106,7 @@ pub enum TokenPoolInstruction {
 
     /// Finalize pending rewards by updating the reward accumulator.
     ///
-    /// Permissionless - anyone can call after UPDATE_SLOT_INTERVAL has passed.
+    /// Permissionless - anyone can call after the pool's finalize interval has passed.
     FinalizeRewards = 67,
 
     /// Fund the reward pool with external tokens.
@@ -99,7 +127,19 @@ pub enum TokenPoolInstruction {
     /// Permissionless - anyone can call. Recovers tokens that arrived in the
     /// vault outside of normal deposit/fund_rewards flows (e.g., direct transfers).
     SweepExcess = 70,
-    // Reserved: 71-127
+
+    /// Check the vault balance against the pool's accounted balance.
+    ///
+    /// Permissionless - anyone can call. A surplus is recorded for
+    /// `SweepExcess`; a deficit blocks deposits until `ClearVaultDeficit`.
+    ReconcileVault = 71,
+
+    /// Finalize rewards for several pools, skipping pools not yet due.
+    ///
+    /// Permissionless - pools are passed as (pool_config, vault) pairs in
+    /// remaining accounts, at most `MAX_FINALIZE_BATCH_SIZE`.
+    FinalizeRewardsBatch = 72,
+    // Reserved: 73-127
 
     // =========================================================================
     // Admin Operations (192-255) - For future admin instructions
@@ -113,4 +153,506 @@ pub enum TokenPoolInstruction {
     ///
     /// Must be called by the `pending_authority` address.
     AcceptAuthority = 193,
+
+    /// Set the protocol fee share and treasury wallet.
+    #[handler(data)]
+    SetProtocolFeeConfig = 194,
+
+    /// Transfer accrued protocol fees from the vault to the treasury ATA.
+    #[handler(data)]
+    WithdrawProtocolFees = 195,
+
+    /// Pause or unpause the pool program's own deposit/withdraw handling.
+    ///
+    /// Enforced independently of the hub's routing flag (`SetPoolActive`).
+    #[handler(data)]
+    SetPoolPaused = 196,
+
+    /// Close a retired pool's vault and config, reclaiming rent.
+    ///
+    /// Requires the hub PoolConfig to be closed or inactive and the pool to
+    /// hold no pending balances, fees, or vault tokens.
+    ClosePool = 197,
+
+    /// Set per-pool deposit minimum, single-deposit cap, and total pool cap.
+    #[handler(data)]
+    SetPoolLimits = 198,
+
+    /// Set the pool's minimum slots between reward finalizations.
+    #[handler(data)]
+    SetFinalizeInterval = 199,
+
+    /// Set the per-call cap and delay applied by `SweepExcess`.
+    #[handler(data)]
+    SetSweepConfig = 200,
+
+    /// Send untracked vault excess to a token account chosen by the authority.
+    ///
+    /// Refunds accidental transfers before `SweepExcess` socializes them.
+    #[handler(data)]
+    ReturnExcess = 201,
+
+    /// Exempt a depositor/recipient authority from pool fees.
+    ///
+    /// For hub-internal rebalancing flows; the hub signals exemption via
+    /// `expect_fee_exempt` in the CPI params.
+    #[handler(data)]
+    AddFeeExempt = 202,
+
+    /// Remove an authority from the fee-exempt list.
+    #[handler(data)]
+    RemoveFeeExempt = 203,
+
+    /// Clear a vault deficit flagged by `ReconcileVault`, reopening deposits.
+    ClearVaultDeficit = 204,
+
+    /// Queue an emergency withdrawal from the vault to a token account.
+    ///
+    /// Executable only after the pool's emergency withdrawal delay.
+    #[handler(data)]
+    InitiateEmergencyWithdraw = 205,
+
+    /// Drop the pending emergency withdrawal.
+    CancelEmergencyWithdraw = 206,
+
+    /// Transfer the pending emergency withdrawal once its delay has passed.
+    ///
+    /// Debits the amount from the accounted pool balance.
+    ExecuteEmergencyWithdraw = 207,
+
+    /// Set the emergency withdrawal delay (floored at ~1 day of slots).
+    #[handler(data)]
+    SetEmergencyWithdrawDelay = 208,
+
+    /// Queue a move of the pool's funds to the next vault generation's PDA.
+    ///
+    /// Executable with `MigrateVault` after the emergency withdrawal delay.
+    InitiateVaultMigration = 209,
+
+    /// Create the queued vault, move the full balance into it, and record it
+    /// in `TokenPoolConfig.vault`.
+    MigrateVault = 210,
+
+    /// Set the minimum withdrawal and the fee rounding direction.
+    #[handler(data)]
+    SetWithdrawalPolicy = 211,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zorb_pool_interface::{PoolIx, token_pool_ix};
+
+    const POOL_CONFIG: Pubkey = [1; 32];
+    const AUTHORITY: Pubkey = [2; 32];
+    const VAULT: Pubkey = [3; 32];
+    const MINT: Pubkey = [4; 32];
+    const TOKEN_PROGRAM: Pubkey = [5; 32];
+    const OTHER: Pubkey = [6; 32];
+    const OTHER_2: Pubkey = [7; 32];
+
+    /// Compare the builder's account flags with the accounts struct, which
+    /// `trailing` optional accounts follow (`idl-build` only).
+    macro_rules! assert_accounts {
+        ($ix:expr, $accounts:ty) => {
+            assert_accounts!($ix, $accounts, 0)
+        };
+        ($ix:expr, $accounts:ty, $trailing:expr) => {
+            #[cfg(feature = "idl-build")]
+            {
+                let expected = <$accounts>::__idl_instruction_accounts();
+                assert_eq!($ix.accounts.len(), expected.len() + $trailing);
+                for (account, meta) in $ix.accounts.iter().zip(&expected) {
+                    assert_eq!(
+                        (account.is_writable, account.is_signer),
+                        (meta.writable, meta.signer),
+                        "{}",
+                        meta.name
+                    );
+                }
+            }
+        };
+    }
+
+    /// Compare the listed fields, naming the first that differs.
+    macro_rules! assert_fields_eq {
+        ($actual:expr, $expected:expr, [$($field:ident),+ $(,)?]) => {
+            $(
+                assert_eq!(
+                    $actual.$field,
+                    $expected.$field,
+                    concat!("field `", stringify!($field), "`")
+                );
+            )+
+        };
+    }
+
+    /// Check the program and discriminator, returning the data after it.
+    fn data_of(ix: &PoolIx, instruction: TokenPoolInstruction) -> &[u8] {
+        assert_eq!(ix.program_id, crate::ID);
+        assert_eq!(ix.data[0], instruction as u8);
+        &ix.data[1..]
+    }
+
+    #[test]
+    fn test_init_pool_ix() {
+        let ix = token_pool_ix::init_pool(
+            &MINT,
+            &POOL_CONFIG,
+            &VAULT,
+            &AUTHORITY,
+            &TOKEN_PROGRAM,
+            1_000,
+            10,
+            20,
+        );
+        let data = InitPoolData::try_from(data_of(&ix, TokenPoolInstruction::InitPool)).unwrap();
+        assert_eq!(data.max_deposit_amount, 1_000);
+        assert_eq!(data.deposit_fee_rate, 10);
+        assert_eq!(data.withdrawal_fee_rate, 20);
+        assert_eq!(ix.accounts[0].pubkey, MINT);
+        assert_accounts!(ix, InitPoolAccounts);
+    }
+
+    #[test]
+    fn test_config_ixs() {
+        let ix = token_pool_ix::set_pool_active(&POOL_CONFIG, &AUTHORITY, true);
+        let data =
+            SetPoolActiveData::try_from(data_of(&ix, TokenPoolInstruction::SetPoolActive)).unwrap();
+        assert_eq!(data.is_active, 1);
+        assert_accounts!(ix, SetPoolActiveAccounts);
+
+        let ix = token_pool_ix::set_fee_rates(&POOL_CONFIG, &AUTHORITY, 30, 40);
+        let data =
+            SetFeeRatesData::try_from(data_of(&ix, TokenPoolInstruction::SetFeeRates)).unwrap();
+        assert_eq!((data.deposit_fee_rate, data.withdrawal_fee_rate), (30, 40));
+        assert_accounts!(ix, SetFeeRatesAccounts);
+
+        let ix = token_pool_ix::set_protocol_fee_config(&POOL_CONFIG, &AUTHORITY, &OTHER, 2_500);
+        let data = SetProtocolFeeConfigData::try_from(data_of(
+            &ix,
+            TokenPoolInstruction::SetProtocolFeeConfig,
+        ))
+        .unwrap();
+        assert_eq!(data.treasury, OTHER);
+        assert_eq!(data.protocol_fee_share_bps, 2_500);
+        assert_accounts!(ix, SetProtocolFeeConfigAccounts);
+
+        let ix = token_pool_ix::set_pool_paused(&POOL_CONFIG, &AUTHORITY, true, false);
+        let data =
+            SetPoolPausedData::try_from(data_of(&ix, TokenPoolInstruction::SetPoolPaused)).unwrap();
+        assert_eq!((data.paused, data.allow_withdrawals_when_paused), (1, 0));
+        assert_accounts!(ix, SetPoolPausedAccounts);
+
+        let ix = token_pool_ix::set_pool_limits(&POOL_CONFIG, &AUTHORITY, u128::MAX, 500, 5);
+        let data =
+            SetPoolLimitsData::try_from(data_of(&ix, TokenPoolInstruction::SetPoolLimits)).unwrap();
+        assert_eq!(data.max_total_pool, u128::MAX);
+        assert_eq!((data.max_single_deposit, data.min_deposit), (500, 5));
+        assert_accounts!(ix, SetPoolLimitsAccounts);
+
+        let ix = token_pool_ix::set_finalize_interval(&POOL_CONFIG, &AUTHORITY, 150);
+        let data = SetFinalizeIntervalData::try_from(data_of(
+            &ix,
+            TokenPoolInstruction::SetFinalizeInterval,
+        ))
+        .unwrap();
+        assert_eq!(data.finalize_interval_slots, 150);
+        assert_accounts!(ix, SetFinalizeIntervalAccounts);
+
+        let ix = token_pool_ix::set_sweep_config(&POOL_CONFIG, &AUTHORITY, 700, 80);
+        let data = SetSweepConfigData::try_from(data_of(&ix, TokenPoolInstruction::SetSweepConfig))
+            .unwrap();
+        assert_eq!((data.max_sweep_per_call, data.sweep_delay_slots), (700, 80));
+        assert_accounts!(ix, SetSweepConfigAccounts);
+
+        let ix = token_pool_ix::add_fee_exempt(&POOL_CONFIG, &AUTHORITY, &OTHER);
+        let data =
+            AddFeeExemptData::try_from(data_of(&ix, TokenPoolInstruction::AddFeeExempt)).unwrap();
+        assert_eq!(data.authority, OTHER);
+        assert_accounts!(ix, AddFeeExemptAccounts);
+
+        let ix = token_pool_ix::remove_fee_exempt(&POOL_CONFIG, &AUTHORITY, &OTHER);
+        let data =
+            RemoveFeeExemptData::try_from(data_of(&ix, TokenPoolInstruction::RemoveFeeExempt))
+                .unwrap();
+        assert_eq!(data.authority, OTHER);
+        assert_accounts!(ix, RemoveFeeExemptAccounts);
+
+        let ix = token_pool_ix::set_withdrawal_policy(&POOL_CONFIG, &AUTHORITY, 25, true);
+        let data = SetWithdrawalPolicyData::try_from(data_of(
+            &ix,
+            TokenPoolInstruction::SetWithdrawalPolicy,
+        ))
+        .unwrap();
+        assert_eq!((data.min_withdrawal, data.round_fees_up), (25, 1));
+        assert_accounts!(ix, SetWithdrawalPolicyAccounts);
+    }
+
+    #[test]
+    fn test_authority_transfer_ixs() {
+        let ix = token_pool_ix::transfer_authority(&POOL_CONFIG, &AUTHORITY, &OTHER);
+        assert!(data_of(&ix, TokenPoolInstruction::TransferAuthority).is_empty());
+        assert_eq!(ix.accounts[2].pubkey, OTHER);
+        assert_accounts!(ix, TransferAuthorityAccounts);
+
+        let ix = token_pool_ix::accept_authority(&POOL_CONFIG, &OTHER);
+        assert!(data_of(&ix, TokenPoolInstruction::AcceptAuthority).is_empty());
+        assert_accounts!(ix, AcceptAuthorityAccounts);
+    }
+
+    #[test]
+    fn test_vault_transfer_ixs() {
+        let ix = token_pool_ix::withdraw_protocol_fees(
+            &POOL_CONFIG,
+            &VAULT,
+            &OTHER,
+            &AUTHORITY,
+            &TOKEN_PROGRAM,
+            Some(&MINT),
+            90,
+        );
+        let data = WithdrawProtocolFeesData::try_from(data_of(
+            &ix,
+            TokenPoolInstruction::WithdrawProtocolFees,
+        ))
+        .unwrap();
+        assert_eq!(data.amount, 90);
+        assert_eq!(ix.accounts.last().unwrap().pubkey, MINT);
+        assert_accounts!(ix, WithdrawProtocolFeesAccounts, 1);
+
+        let ix = token_pool_ix::return_excess(
+            &POOL_CONFIG,
+            &VAULT,
+            &OTHER,
+            &AUTHORITY,
+            &TOKEN_PROGRAM,
+            None,
+            60,
+        );
+        let data =
+            ReturnExcessData::try_from(data_of(&ix, TokenPoolInstruction::ReturnExcess)).unwrap();
+        assert_eq!(data.amount, 60);
+        assert_accounts!(ix, ReturnExcessAccounts);
+
+        let ix = token_pool_ix::fund_rewards(
+            &POOL_CONFIG,
+            &VAULT,
+            &OTHER,
+            &AUTHORITY,
+            &TOKEN_PROGRAM,
+            Some(&MINT),
+            400,
+            12,
+        );
+        let data =
+            FundRewardsData::try_from(data_of(&ix, TokenPoolInstruction::FundRewards)).unwrap();
+        assert_eq!((data.amount, data.vest_duration_slots), (400, 12));
+        assert_accounts!(ix, FundRewardsAccounts, 1);
+
+        let ix =
+            token_pool_ix::close_pool(&POOL_CONFIG, &VAULT, &OTHER, &AUTHORITY, &TOKEN_PROGRAM);
+        assert!(data_of(&ix, TokenPoolInstruction::ClosePool).is_empty());
+        assert_accounts!(ix, ClosePoolAccounts);
+    }
+
+    #[test]
+    fn test_emergency_and_migration_ixs() {
+        let ix = token_pool_ix::initiate_emergency_withdraw(&POOL_CONFIG, &AUTHORITY, 77, &OTHER);
+        let data = InitiateEmergencyWithdrawData::try_from(data_of(
+            &ix,
+            TokenPoolInstruction::InitiateEmergencyWithdraw,
+        ))
+        .unwrap();
+        assert_eq!((data.amount, data.destination), (77, OTHER));
+        assert_accounts!(ix, InitiateEmergencyWithdrawAccounts);
+
+        let ix = token_pool_ix::cancel_emergency_withdraw(&POOL_CONFIG, &AUTHORITY);
+        assert!(data_of(&ix, TokenPoolInstruction::CancelEmergencyWithdraw).is_empty());
+        assert_accounts!(ix, CancelEmergencyWithdrawAccounts);
+
+        let ix = token_pool_ix::execute_emergency_withdraw(
+            &POOL_CONFIG,
+            &VAULT,
+            &OTHER,
+            &AUTHORITY,
+            &TOKEN_PROGRAM,
+            None,
+        );
+        assert!(data_of(&ix, TokenPoolInstruction::ExecuteEmergencyWithdraw).is_empty());
+        assert_accounts!(ix, ExecuteEmergencyWithdrawAccounts);
+
+        let ix = token_pool_ix::set_emergency_withdraw_delay(&POOL_CONFIG, &AUTHORITY, 9_000);
+        let data = SetEmergencyWithdrawDelayData::try_from(data_of(
+            &ix,
+            TokenPoolInstruction::SetEmergencyWithdrawDelay,
+        ))
+        .unwrap();
+        assert_eq!(data.delay_slots, 9_000);
+        assert_accounts!(ix, SetEmergencyWithdrawDelayAccounts);
+
+        let ix = token_pool_ix::clear_vault_deficit(&POOL_CONFIG, &AUTHORITY);
+        assert!(data_of(&ix, TokenPoolInstruction::ClearVaultDeficit).is_empty());
+        assert_accounts!(ix, ClearVaultDeficitAccounts);
+
+        let ix = token_pool_ix::initiate_vault_migration(&POOL_CONFIG, &OTHER, &AUTHORITY);
+        assert!(data_of(&ix, TokenPoolInstruction::InitiateVaultMigration).is_empty());
+        assert_accounts!(ix, InitiateVaultMigrationAccounts);
+
+        let ix = token_pool_ix::migrate_vault(
+            &POOL_CONFIG,
+            &VAULT,
+            &OTHER,
+            &MINT,
+            &AUTHORITY,
+            &TOKEN_PROGRAM,
+        );
+        assert!(data_of(&ix, TokenPoolInstruction::MigrateVault).is_empty());
+        assert_accounts!(ix, MigrateVaultAccounts);
+    }
+
+    #[test]
+    fn test_permissionless_ixs() {
+        let ix = token_pool_ix::finalize_rewards(&POOL_CONFIG);
+        assert!(data_of(&ix, TokenPoolInstruction::FinalizeRewards).is_empty());
+        assert_accounts!(ix, FinalizeRewardsAccounts);
+
+        let ix = token_pool_ix::finalize_rewards_batch(&[(POOL_CONFIG, VAULT), (OTHER, OTHER_2)]);
+        assert!(data_of(&ix, TokenPoolInstruction::FinalizeRewardsBatch).is_empty());
+        assert_eq!(ix.accounts[3].pubkey, OTHER);
+        assert!(ix.accounts[3].is_writable && !ix.accounts[4].is_writable);
+        assert_accounts!(ix, FinalizeRewardsBatchAccounts, 4);
+
+        let ix = token_pool_ix::sweep_excess(&POOL_CONFIG, &VAULT);
+        assert!(data_of(&ix, TokenPoolInstruction::SweepExcess).is_empty());
+        assert_accounts!(ix, SweepExcessAccounts);
+
+        let ix = token_pool_ix::reconcile_vault(&POOL_CONFIG, &VAULT);
+        assert!(data_of(&ix, TokenPoolInstruction::ReconcileVault).is_empty());
+        assert_accounts!(ix, ReconcileVaultAccounts);
+    }
+
+    /// The hub's encoded deposit and withdraw params, in the versioned and
+    /// legacy layouts, come out of this pool's parsers field for field.
+    #[test]
+    fn test_hub_params_parse() {
+        use zorb_pool_interface::{
+            DepositParams, WithdrawParams, build_deposit_instruction_data,
+            build_withdraw_instruction_data,
+        };
+
+        let params = DepositParams {
+            amount: 1_000_000,
+            expected_output: 990_000,
+            depositor: AUTHORITY,
+            expect_fee_exempt: 1,
+            ..Default::default()
+        };
+        let data = build_deposit_instruction_data(&params);
+        assert_eq!(data[0], TokenPoolInstruction::Deposit as u8);
+        for bytes in [&data[1..], &params.to_bytes()[..]] {
+            let parsed = deposit::parse_deposit_params(bytes).unwrap();
+            assert_fields_eq!(
+                parsed,
+                params,
+                [amount, expected_output, depositor, expect_fee_exempt]
+            );
+        }
+
+        let params = WithdrawParams {
+            amount: 1_000_000,
+            expected_output: 990_000,
+            recipient: OTHER,
+            expect_fee_exempt: 1,
+            unwrap: 1,
+            route_automatically: 1,
+            fallback_count: 2,
+            unwrap_amount: 980_000,
+            ..Default::default()
+        };
+        let data = build_withdraw_instruction_data(&params);
+        assert_eq!(data[0], TokenPoolInstruction::Withdraw as u8);
+        for bytes in [&data[1..], &params.to_bytes()[..]] {
+            let parsed = withdraw::parse_withdraw_params(bytes).unwrap();
+            assert_fields_eq!(
+                parsed,
+                params,
+                [
+                    amount,
+                    expected_output,
+                    recipient,
+                    expect_fee_exempt,
+                    unwrap,
+                    route_automatically,
+                    fallback_count,
+                    unwrap_amount,
+                ]
+            );
+        }
+    }
+
+    /// The hub's deposit and withdraw CPIs send the metas the accounts
+    /// structs' `Input` builders produce.
+    #[cfg(feature = "solana-sdk")]
+    #[test]
+    fn test_hub_cpi_metas_match_input() {
+        use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey as SdkPubkey};
+        use zorb_pool_interface::{
+            CpiAccountList, DepositCpiAccounts, PoolCpi, TokenPoolCpi, WithdrawCpiAccounts,
+        };
+
+        fn sdk_metas(list: &CpiAccountList<'_, Pubkey>) -> Vec<AccountMeta> {
+            list.as_slice()
+                .iter()
+                .map(|(key, access)| AccountMeta {
+                    pubkey: SdkPubkey::new_from_array(**key),
+                    is_signer: access.is_signer(),
+                    is_writable: access.is_writable(),
+                })
+                .collect()
+        }
+        let sdk = SdkPubkey::new_from_array;
+
+        let deposit = TokenPoolCpi
+            .deposit_accounts(&DepositCpiAccounts {
+                pool_state: None,
+                pool_config: &POOL_CONFIG,
+                vault: &VAULT,
+                depositor_token: &OTHER,
+                depositor: &AUTHORITY,
+                token_program: &TOKEN_PROGRAM,
+                pool_program: &crate::ID,
+            })
+            .unwrap();
+        let input = DepositInput {
+            pool_config: sdk(POOL_CONFIG),
+            vault: sdk(VAULT),
+            depositor_token: sdk(OTHER),
+            depositor: sdk(AUTHORITY),
+            token_program: sdk(TOKEN_PROGRAM),
+            token_pool_program: sdk(crate::ID),
+        };
+        assert_eq!(sdk_metas(&deposit), input.to_account_metas());
+
+        let withdraw = TokenPoolCpi
+            .withdraw_accounts(&WithdrawCpiAccounts {
+                pool_state: None,
+                pool_config: &POOL_CONFIG,
+                vault: &VAULT,
+                hub_authority: &OTHER,
+                token_program: &TOKEN_PROGRAM,
+                pool_program: &crate::ID,
+                unwrap: None,
+                route: &[],
+            })
+            .unwrap();
+        let input = WithdrawInput {
+            pool_config: sdk(POOL_CONFIG),
+            vault: sdk(VAULT),
+            hub_authority: sdk(OTHER),
+            token_pool_program: sdk(crate::ID),
+            token_program: sdk(TOKEN_PROGRAM),
+        };
+        assert_eq!(sdk_metas(&withdraw), input.to_account_metas());
+    }
 }
@@ -0,0 +1,85 @@
+//! Reconcile vault instruction handler.
+//!
+//! Permissionless crank that checks the vault token balance against the
+//! balance implied by the pool's cumulative accounting (see the vault balance
+//! invariant in `sweep_excess`) and surfaces any discrepancy before it
+//! compounds:
+//! - **Surplus:** recorded as detected excess, starting the `SweepExcess`
+//!   delay just as a `SweepExcess` call would
+//! - **Deficit:** sets `deficit_detected`, which blocks deposits until the
+//!   authority clears it with `ClearVaultDeficit`
+
+use crate::{
+    TokenPoolConfig, emit_event, events::VaultReconciledEvent, gen_token_pool_config_seeds,
+    token::read_token_balance,
+};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, instruction::Signer as PinocchioSigner,
+    sysvars::Sysvar,
+};
+use pinocchio_log::log;
+
+/// Accounts for the ReconcileVault instruction.
+#[derive(Accounts)]
+pub struct ReconcileVaultAccounts<'info> {
+    /// Pool configuration account (writable for state updates)
+    #[account(mut, owner = crate::ID)]
+    pub pool_config: AccountLoader<'info, TokenPoolConfig>,
+
+    /// Vault token account (read-only to check balance)
+    /// Must match pool_config.vault
+    pub vault: &'info AccountInfo,
+
+    /// Token pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub token_pool_program: &'info AccountInfo,
+}
+
+/// Compare the vault balance with the expected balance and record the result.
+///
+/// Permissionless - anyone can call this. Always emits `VaultReconciledEvent`,
+/// including when the vault is balanced.
+pub fn process_reconcile_vault(ctx: Context<ReconcileVaultAccounts>) -> ProgramResult {
+    let ReconcileVaultAccounts {
+        pool_config,
+        vault,
+        token_pool_program,
+    } = ctx.accounts;
+
+    // Get actual vault balance (owned by the pool's token program)
+    let token_program = pool_config.try_map(|config| {
+        config.require_vault(vault.key())?;
+        Ok(config.token_program)
+    })?;
+    let actual = read_token_balance(vault, &token_program)?;
+    let current_slot = pinocchio::sysvars::clock::Clock::get()?.slot;
+
+    let (expected, delta, mint, bump) = pool_config.try_map_mut(|config| {
+        let (expected, delta) = config.reconcile_vault(actual, current_slot)?;
+        Ok((expected, delta, config.mint, config.bump))
+    })?;
+
+    if delta < 0 {
+        log!("reconcile_vault: deficit detected, deposits blocked");
+    }
+
+    let bump_bytes = [bump];
+    let seeds = gen_token_pool_config_seeds(&mint, &bump_bytes);
+    let signer = PinocchioSigner::from(&seeds);
+
+    emit_event(
+        pool_config.account_info(),
+        token_pool_program,
+        signer,
+        &VaultReconciledEvent {
+            mint,
+            expected,
+            delta,
+            actual,
+            slot: current_slot,
+        },
+    )?;
+
+    Ok(())
+}
@@ -11,37 +11,65 @@
 //! vault.amount = total_deposited - total_withdrawn
 //!              + total_deposit_fees + total_withdrawal_fees
 //!              + total_funded_rewards
+//!              - total_protocol_fees_withdrawn
+//!              - total_emergency_withdrawn
 //! ```
 //!
 //! ## Proof Sketch
 //!
-//! **Completeness:** Only 3 of 11 instructions affect vault balance:
+//! **Completeness:** Only 5 of 31 instructions affect the tracked vault balance:
 //! - `Deposit`: transfers tokens IN, updates `total_deposited` + `total_deposit_fees`
 //! - `Withdraw`: approves tokens OUT, updates `total_withdrawn` + `total_withdrawal_fees`
 //! - `FundRewards`: transfers tokens IN, updates `total_funded_rewards`
+//! - `WithdrawProtocolFees`: transfers tokens OUT, updates `total_protocol_fees_withdrawn`
+//! - `ExecuteEmergencyWithdraw`: transfers tokens OUT, updates `total_emergency_withdrawn`
 //!
-//! The other 8 instructions (InitPool, SetPoolActive, SetFeeRates, FinalizeRewards,
-//! Log, SweepExcess, TransferAuthority, AcceptAuthority) do not transfer tokens
-//! to/from the vault or modify the tracked balance fields.
+//! `ReturnExcess` also transfers tokens OUT, but only up to the current excess,
+//! so the vault never drops below the expected balance.
+//!
+//! The other 26 instructions (InitPool, SetPoolActive, SetFeeRates, FinalizeRewards,
+//! FinalizeRewardsBatch, Log, SweepExcess, ReconcileVault, TransferAuthority, AcceptAuthority,
+//! SetProtocolFeeConfig, SetPoolPaused, ClosePool, SetPoolLimits,
+//! SetFinalizeInterval, SetSweepConfig, AddFeeExempt, RemoveFeeExempt,
+//! ClearVaultDeficit, InitiateEmergencyWithdraw, CancelEmergencyWithdraw,
+//! SetEmergencyWithdrawDelay, InitiateVaultMigration, MigrateVault,
+//! SetWithdrawalPolicy) do not
+//! transfer tokens to/from the vault or modify the tracked balance fields.
+//! ClosePool requires an empty vault before closing it. MigrateVault moves the
+//! whole balance to the new `vault` and fails unless all of it arrives.
 //!
 //! **Correctness:** For each vault-modifying operation, Δvault = Δexpected:
 //! - Deposit: `Δvault = +gross`, `Δexpected = +(net + fee) = +gross` ✓
 //! - Withdraw: `Δvault = -output`, `Δexpected = -gross + fee = -(gross - fee) = -output` ✓
 //! - FundRewards: `Δvault = +amount`, `Δexpected = +amount` ✓
+//! - WithdrawProtocolFees: `Δvault = -amount`, `Δexpected = -amount` ✓
+//! - ExecuteEmergencyWithdraw: `Δvault = -amount`, `Δexpected = -amount` ✓
 //!
 //! **Corollary:** Any `excess = vault.amount - expected > 0` represents tokens
 //! that arrived outside program control (direct SPL transfers). SweepExcess
 //! captures these by adding to `total_funded_rewards`, restoring the invariant.
+//! `ReconcileVault` checks the same invariant and flags `excess < 0`.
+//!
+//! # Sweep Limits
+//!
+//! An accidental transfer is socialized irreversibly once swept, so sweeps are
+//! gated by two authority-set limits (`SetSweepConfig`):
+//! - `sweep_delay_slots`: newly detected excess waits this long before it can
+//!   be swept, giving the sender time to ask for `ReturnExcess`
+//! - `max_sweep_per_call`: caps each sweep
 //!
 //! See [`docs/vault-invariant-proof.md`](../../../docs/vault-invariant-proof.md) for the complete formal proof.
 
 use crate::{
     TokenPoolConfig, TokenPoolError, emit_event, events::SweepExcessEvent,
-    gen_token_pool_config_seeds,
+    gen_token_pool_config_seeds, token::read_token_balance,
 };
 use panchor::prelude::*;
-use pinocchio::{ProgramResult, instruction::Signer as PinocchioSigner, sysvars::Sysvar};
-use pinocchio_token::state::TokenAccount;
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, instruction::Signer as PinocchioSigner,
+    sysvars::Sysvar,
+};
+use pinocchio_log::log;
 
 /// Accounts for the SweepExcess instruction.
 #[derive(Accounts)]
@@ -51,8 +79,8 @@ pub struct SweepExcessAccounts<'info> {
     pub pool_config: AccountLoader<'info, TokenPoolConfig>,
 
     /// Vault token account (read-only to check balance)
-    #[account(pda = Vault, pda::pool_config = pool_config.key())]
-    pub vault: LazyAccount<'info, TokenAccount>,
+    /// Must match pool_config.vault
+    pub vault: &'info AccountInfo,
 
     /// Token pool program account (required for self-CPI event emission)
     #[account(address = crate::ID)]
@@ -66,66 +94,38 @@ pub struct SweepExcessAccounts<'info> {
 ///
 /// Excess = vault_balance - expected_balance
 /// where expected_balance is derived from cumulative accounting stats.
+///
+/// The first call that sees new excess only records it; the sweep happens on
+/// a call after `sweep_delay_slots`, and each call moves at most
+/// `max_sweep_per_call`.
 pub fn process_sweep_excess(ctx: Context<SweepExcessAccounts>) -> ProgramResult {
     let SweepExcessAccounts { pool_config, vault, token_pool_program } = ctx.accounts;
 
-    // Get actual vault balance by loading the token account
-    let vault_data = vault.load()?;
-    let vault_balance = vault_data.amount();
-
-    // Calculate expected vault balance and update state
-    let (excess, mint, bump) = pool_config.try_map(|config| {
-        // Expected vault balance formula:
-        // = tokens_deposited - tokens_withdrawn_out + funded_rewards
-        // = (total_deposited + total_deposit_fees)
-        //   - (total_withdrawn - total_withdrawal_fees)
-        //   + total_funded_rewards
-        //
-        // Simplified:
-        // = total_deposited - total_withdrawn
-        //   + total_deposit_fees + total_withdrawal_fees
-        //   + total_funded_rewards
-        let expected = config
-            .total_deposited
-            .checked_sub(config.total_withdrawn)
-            .ok_or(TokenPoolError::ArithmeticOverflow)?
-            .checked_add(config.total_deposit_fees)
-            .ok_or(TokenPoolError::ArithmeticOverflow)?
-            .checked_add(config.total_withdrawal_fees)
-            .ok_or(TokenPoolError::ArithmeticOverflow)?
-            .checked_add(config.total_funded_rewards)
-            .ok_or(TokenPoolError::ArithmeticOverflow)?;
-
-        // Calculate excess (saturating to 0 if vault has less than expected)
-        let vault_balance_u128 = vault_balance as u128;
-        let excess = vault_balance_u128.saturating_sub(expected);
-
-        Ok((excess, config.mint, config.bump))
+    // Get actual vault balance (owned by the pool's token program)
+    let token_program = pool_config.try_map(|config| {
+        config.require_vault(vault.key())?;
+        Ok(config.token_program)
+    })?;
+    let vault_balance = read_token_balance(vault, &token_program)?;
+    let current_slot = pinocchio::sysvars::clock::Clock::get()?.slot;
+
+    // Record or sweep the excess over the expected vault balance
+    let (swept, total_swept, mint, bump) = pool_config.try_map_mut(|config| {
+        // Saturates to 0 if the vault has less than expected
+        let excess = (vault_balance as u128).saturating_sub(config.expected_vault_balance()?);
+        // Excess should fit in u64 (it's bounded by vault balance which is u64)
+        let excess = u64::try_from(excess).map_err(|_| TokenPoolError::ArithmeticOverflow)?;
+
+        let swept = config.sweep_excess(excess, current_slot)?;
+        Ok((swept, config.total_swept, config.mint, config.bump))
     })?;
 
-    // Nothing to sweep
-    if excess == 0 {
+    // Nothing to sweep (yet)
+    if swept == 0 {
+        log!("sweep_excess: nothing sweepable");
         return Ok(());
     }
 
-    // Excess should fit in u64 (it's bounded by vault balance which is u64)
-    let excess_u64 = u64::try_from(excess).map_err(|_| TokenPoolError::ArithmeticOverflow)?;
-
-    // Update pending and total funded rewards
-    pool_config.try_inspect_mut(|config| {
-        config.pending_funded_rewards = config
-            .pending_funded_rewards
-            .checked_add(excess_u64)
-            .ok_or(TokenPoolError::ArithmeticOverflow)?;
-
-        config.total_funded_rewards = config
-            .total_funded_rewards
-            .checked_add(excess)
-            .ok_or(TokenPoolError::ArithmeticOverflow)?;
-
-        Ok(())
-    })?;
-
     // Emit event
     let bump_bytes = [bump];
     let seeds = gen_token_pool_config_seeds(&mint, &bump_bytes);
@@ -137,8 +137,9 @@ pub fn process_sweep_excess(ctx: Context<SweepExcessAccounts>) -> ProgramResult
         signer,
         &SweepExcessEvent {
             mint,
-            amount: excess_u64,
-            slot: pinocchio::sysvars::clock::Clock::get()?.slot,
+            amount: swept,
+            slot: current_slot,
+            total_swept,
         },
     )?;
 
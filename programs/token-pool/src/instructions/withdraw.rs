@@ -1,20 +1,21 @@
 //! Withdraw instruction handler.
 //!
 //! Pool validates amounts, approves hub_authority for the output tokens,
-//! updates accounting, and returns the protocol fee. Hub handles distribution.
+//! updates accounting, and returns a `PoolOpResult`. Hub handles distribution.
 
 use crate::{
-    TokenPoolConfig, TokenPoolError, emit_event, events::TokenWithdrawalEvent,
+    TokenPoolConfig, TokenPoolError, emit_event,
+    events::TokenWithdrawalEvent,
     gen_token_pool_config_seeds,
+    token::{approve_tokens, optional_mint},
 };
 use panchor::prelude::*;
 use pinocchio::{
     ProgramResult, account_info::AccountInfo, instruction::Signer as PinocchioSigner,
-    program::set_return_data, pubkey::Pubkey, sysvars::Sysvar,
+    program::set_return_data, program_error::ProgramError, sysvars::Sysvar,
 };
 use pinocchio_log::log;
-use pinocchio_token::instructions::Approve;
-use zorb_pool_interface::{PoolReturnData, WithdrawParams, calculate_withdrawal_output, validate_hub_authority};
+use zorb_pool_interface::{PoolOpResult, WithdrawParams, validate_hub_authority};
 
 /// Accounts for the Withdraw instruction.
 ///
@@ -26,6 +27,8 @@ use zorb_pool_interface::{PoolReturnData, WithdrawParams, calculate_withdrawal_o
 /// 1. vault (mut)
 /// 2. hub_authority (delegate for transfers)
 /// 3. token_pool_program
+/// 4. token_program
+/// 5. mint (optional, required for Token-2022 pools)
 #[derive(Accounts)]
 pub struct WithdrawAccounts<'info> {
     /// Pool configuration account (PDA signer for vault operations)
@@ -33,8 +36,8 @@ pub struct WithdrawAccounts<'info> {
     pub pool_config: AccountLoader<'info, TokenPoolConfig>,
 
     /// Vault token account (source for transfers)
-    /// PDA derived from: ["vault", pool_config]
-    #[account(mut, pda = Vault, pda::pool_config = pool_config.key())]
+    /// Must match pool_config.vault
+    #[account(mut)]
     pub vault: &'info AccountInfo,
 
     /// Hub authority PDA (delegate for vault transfers)
@@ -44,34 +47,49 @@ pub struct WithdrawAccounts<'info> {
     #[account(address = crate::ID)]
     pub token_pool_program: &'info AccountInfo,
 
-    /// SPL Token program (required for Approve CPI)
-    #[account(address = pinocchio_token::ID)]
+    /// Token program the pool was created with (required for Approve CPI)
     pub token_program: &'info AccountInfo,
 }
 
 /// Process a withdrawal instruction.
 ///
 /// 1. Validates caller is hub
-/// 2. Parses params { amount, expected_output }
-/// 3. Calculates fee = amount * withdrawal_fee_rate
+/// 2. Parses params { amount, expected_output, recipient, expect_fee_exempt }
+/// 3. Calculates fee = amount * withdrawal_fee_rate, rounded per `round_fees_up`
+///    (0 for fee-exempt recipients)
 /// 4. Validates: amount - fee == expected_output
 /// 5. Approves hub_authority for expected_output (total tokens to distribute)
 /// 6. Updates pool accounting
-/// 7. Returns { fee } via set_return_data
+/// 7. Returns `PoolOpResult { gross, fee, net, .. }` via set_return_data
 ///
 /// Note: Hub uses the approval to transfer tokens from vault:
 /// - (expected_output - relayer_fee) to recipient
 /// - relayer_fee to relayer
 /// Protocol fee stays in vault as revenue.
+///
+/// With the mint supplied the approval uses `ApproveChecked`, and the hub
+/// must move the tokens with `TransferChecked`; Token-2022 transfer fees are
+/// then withheld from what the recipient receives, not from the vault.
+///
+/// Fails with `FeeExemptionMismatch` if `expect_fee_exempt` disagrees with
+/// the fee-exempt list for `recipient`.
+///
+/// Fails with `WithdrawalBelowMinimum` if `amount` is below `min_withdrawal`.
+///
+/// Fails with `PoolPaused` before anything else while the pool is paused
+/// via `SetPoolPaused`, unless `allow_withdrawals_when_paused` is set.
 pub fn process_withdraw(ctx: Context<WithdrawAccounts>, instruction_data: &[u8]) -> ProgramResult {
     let WithdrawAccounts {
         pool_config,
         vault: vault_acc,
         hub_authority: hub_authority_acc,
         token_pool_program,
-        token_program: _,
+        token_program,
     } = ctx.accounts;
 
+    // Enforce the pool's own pause even if the hub routed this withdrawal
+    pool_config.try_inspect(|config| Ok(config.require_withdrawals_allowed()?))?;
+
     // Validate hub_authority is the canonical PDA derived from hub program
     if !validate_hub_authority(hub_authority_acc.key()) {
         log!("withdraw: invalid hub_authority PDA");
@@ -83,25 +101,59 @@ pub fn process_withdraw(ctx: Context<WithdrawAccounts>, instruction_data: &[u8])
     let mint_for_pda = pool_config.map(|config| config.mint)?;
     TokenPoolConfig::validate_pda(pool_config_key, &mint_for_pda)?;
 
-    // Parse instruction data (panchor strips discriminator, so we get raw params)
-    let params = WithdrawParams::from_bytes(instruction_data)
-        .ok_or(TokenPoolError::InvalidInstructionData)?;
+    let params = parse_withdraw_params(instruction_data)?;
 
-    // Read config to validate and get values for PDA signer (borrow released after closure)
-    let (fee, output, bump, mint): (u64, u64, u8, Pubkey) = pool_config.try_map(|config| {
-        config.require_active()?;
+    // Native SOL unwrap and vault routing are unified SOL pool features
+    if params.unwraps() {
+        log!("withdraw: unwrap not supported by token pools");
+        return Err(TokenPoolError::InvalidInstructionData.into());
+    }
+    if params.routes_automatically() {
+        log!("withdraw: routing not supported by token pools");
+        return Err(TokenPoolError::InvalidInstructionData.into());
+    }
 
-        // Calculate fee using shared helper (None = no exchange rate for token pool)
-        let (output, fee) = calculate_withdrawal_output(params.amount, config.withdrawal_fee_rate, None)
-            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+    let mint_acc = optional_mint(ctx.remaining_accounts, &mint_for_pda)?;
 
-        // Validate expected_output matches
-        if output != params.expected_output {
-            return Err(TokenPoolError::ExpectedOutputMismatch.into());
-        }
+    // Read config to validate and get values for PDA signer (borrow released after closure)
+    let (fee, output, exempt, bump, mint, token_program_id, decimals) =
+        pool_config.try_map(|config| {
+            config.require_active()?;
+            config.require_token_program(token_program.key())?;
+            config.require_vault(vault_acc.key())?;
+            config.require_withdrawal_above_minimum(params.amount)?;
+
+            // Exempt recipients pay no fee; the hub must have computed the same
+            let exempt =
+                config.require_fee_exemption(&params.recipient, params.expects_fee_exempt())?;
+            let fee_rate = if exempt {
+                0
+            } else {
+                config.withdrawal_fee_rate
+            };
+
+            // Calculate fee in the pool's rounding direction (the hub uses the same)
+            let fee = config.calculate_fee(params.amount, fee_rate)?;
+            let output = params
+                .amount
+                .checked_sub(fee)
+                .ok_or(TokenPoolError::ArithmeticOverflow)?;
 
-        Ok((fee, output, config.bump, config.mint))
-    })?;
+            // Validate expected_output matches
+            if output != params.expected_output {
+                return Err(TokenPoolError::ExpectedOutputMismatch.into());
+            }
+
+            Ok((
+                fee,
+                output,
+                exempt,
+                config.bump,
+                config.mint,
+                config.token_program,
+                config.decimals,
+            ))
+        })?;
 
     // Build PDA signer seeds for pool_config using generated helper
     let bump_bytes = [bump];
@@ -113,13 +165,16 @@ pub fn process_withdraw(ctx: Context<WithdrawAccounts>, instruction_data: &[u8])
 
     // Approve hub_authority for output tokens (hub handles distribution)
     // Hub will transfer: (output - relayer_fee) to recipient, relayer_fee to relayer
-    Approve {
-        source: vault_acc,
-        delegate: hub_authority_acc,
-        authority: pool_config_info,
-        amount: output,
-    }
-    .invoke_signed(&signer)?;
+    approve_tokens(
+        &token_program_id,
+        vault_acc,
+        hub_authority_acc,
+        pool_config_info,
+        mint_acc,
+        output,
+        decimals,
+        &signer,
+    )?;
 
     // Update pool state
     pool_config.try_inspect_mut(|config| {
@@ -133,18 +188,14 @@ pub fn process_withdraw(ctx: Context<WithdrawAccounts>, instruction_data: &[u8])
             .checked_add(params.amount as u128)
             .ok_or(TokenPoolError::ArithmeticOverflow)?;
 
-        // Track protocol fees (fee stays in vault)
+        // Track fees, accruing the protocol share (fee stays in vault)
         if fee > 0 {
-            config.total_withdrawal_fees = config
-                .total_withdrawal_fees
-                .checked_add(fee as u128)
-                .ok_or(TokenPoolError::ArithmeticOverflow)?;
-
-            config.pending_withdrawal_fees = config
-                .pending_withdrawal_fees
-                .checked_add(fee)
-                .ok_or(TokenPoolError::ArithmeticOverflow)?;
+            config.record_withdrawal_fee(fee)?;
+        }
+        if exempt {
+            config.record_fee_exempt_volume(params.amount)?;
         }
+        config.record_epoch_withdrawal(params.amount, fee);
 
         // Increment withdrawal counter
         config.withdrawal_count = config
@@ -157,7 +208,8 @@ pub fn process_withdraw(ctx: Context<WithdrawAccounts>, instruction_data: &[u8])
 
     // Emit withdrawal event FIRST (before set_return_data, since self-CPI clears return data)
     // Note: The actual recipient is determined by the hub - we use hub_authority as the delegate
-    let new_balance = pool_config.try_map(|config| Ok(config.current_balance()?))?;
+    let (new_balance, accumulator_epoch) = pool_config
+        .try_map(|config| Ok((config.current_balance()?, config.accumulator_epoch)))?;
 
     let signer = PinocchioSigner::from(&seeds);
 
@@ -175,9 +227,24 @@ pub fn process_withdraw(ctx: Context<WithdrawAccounts>, instruction_data: &[u8])
         },
     )?;
 
-    // Return fee via set_return_data (AFTER emit_event to avoid CPI overwriting it)
-    let return_data = PoolReturnData { fee };
+    // Return the result via set_return_data (AFTER emit_event to avoid CPI overwriting it)
+    let return_data = PoolOpResult {
+        gross: params.amount,
+        fee,
+        net: output,
+        accumulator_epoch,
+        new_accounted_total: new_balance,
+    };
     set_return_data(bytemuck::bytes_of(&return_data));
 
     Ok(())
 }
+
+/// Parse the hub's withdraw params from instruction data.
+///
+/// Panchor strips the discriminator; accepts the legacy or versioned layout.
+pub(crate) fn parse_withdraw_params(
+    instruction_data: &[u8],
+) -> Result<WithdrawParams, ProgramError> {
+    WithdrawParams::parse_any(instruction_data).ok_or(TokenPoolError::InvalidInstructionData.into())
+}
@@ -14,6 +14,8 @@
 //!
 //! - `Deposit`: Transfer tokens from depositor to vault
 //! - `Withdraw`: Transfer tokens from vault to recipient
+//!
+//! Pools can hold SPL Token or Token-2022 mints; see [`token`].
 
 #![cfg_attr(not(any(test, feature = "idl-build")), no_std)]
 
@@ -24,12 +26,16 @@ pub mod events;
 pub mod instructions;
 pub mod pda;
 pub mod state;
+pub mod token;
 
 // Error and event types
 pub use errors::TokenPoolError;
 pub use events::{
-    EventType, SweepExcessEvent, TokenDepositEvent, TokenRewardsFinalizedEvent,
-    TokenWithdrawalEvent, emit_event,
+    EmergencyWithdrawCancelledEvent, EmergencyWithdrawExecutedEvent,
+    EmergencyWithdrawInitiatedEvent, EventType, ExcessReturnedEvent, PoolClosedEvent,
+    PoolPauseChangedEvent, ProtocolFeesWithdrawnEvent, SweepExcessEvent, TokenDepositEvent,
+    TokenRewardsFinalizedEvent, TokenWithdrawalEvent, VaultMigratedEvent,
+    VaultMigrationInitiatedEvent, VaultReconciledEvent, emit_event,
 };
 
 // Instruction enum for panchor dispatch
@@ -23,11 +23,24 @@ pub enum TokenPoolPdas {
         mint: Pubkey,
     },
 
-    /// Vault token account PDA - per pool config
+    /// Legacy vault token account PDA - per pool config
     /// Seeds: ["vault", pool_config]
+    ///
+    /// Only pools created before `InitPool` switched to ATA vaults use this
+    /// address; read `TokenPoolConfig.vault` first.
     #[seeds("vault")]
     Vault {
         /// The pool config PDA
         pool_config: Pubkey,
     },
+
+    /// Replacement vault token account PDA - per pool config and migration
+    /// Seeds: ["migrated_vault", pool_config, generation]
+    #[seeds("migrated_vault")]
+    MigratedVault {
+        /// The pool config PDA
+        pool_config: Pubkey,
+        /// Vault generation the account replaces into (1 for the first migration)
+        generation: u64,
+    },
 }
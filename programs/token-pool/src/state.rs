@@ -2,7 +2,11 @@
 
 use panchor::prelude::*;
 use pinocchio::pubkey::Pubkey;
-use zorb_pool_interface::authority::HasAuthority;
+use zorb_pool_interface::{
+    BASIS_POINTS,
+    authority::HasAuthority,
+    fee::{Rounding, compute_fee},
+};
 
 use crate::TokenPoolError;
 
@@ -38,6 +42,11 @@ pub enum TokenPoolAccount {
     // =========================================================================
 }
 
+/// Capacity of `TokenPoolConfig.fee_exempt`.
+///
+/// Meant for a handful of hub-internal rebalancing authorities, not users.
+pub const MAX_FEE_EXEMPT: usize = 4;
+
 /// Token pool configuration account.
 ///
 /// Stores all state for a single token pool, including:
@@ -58,6 +67,7 @@ pub enum TokenPoolAccount {
 /// - `pending_withdrawals`: Withdrawals since last finalization (additive only).
 /// - `pending_deposit_fees`: Deposit fees collected since last finalization.
 /// - `pending_withdrawal_fees`: Withdrawal fees collected since last finalization.
+/// - `accrued_protocol_fees`: Protocol share of fees, held for `withdraw_protocol_fees`.
 /// - `reward_accumulator`: Cumulative rewards per unit, scaled by 1e18.
 ///
 /// ## Key Properties
@@ -76,10 +86,11 @@ pub enum TokenPoolAccount {
 ///
 /// On `finalize_rewards(current_slot)`:
 /// 1. Check enough slots elapsed since `last_finalized_slot`
-/// 2. Calculate `total_pool = finalized_balance + pending_deposits - pending_withdrawals`
-/// 3. If rewards exist: `accumulator += (pending_rewards * 1e18) / total_pool`
-/// 4. Update: `finalized_balance = total_pool`
-/// 5. Reset: `pending_deposits = 0`, `pending_withdrawals = 0`, `pending_rewards = 0`
+/// 2. Release vested funded rewards into `pending_funded_rewards`
+/// 3. Calculate `total_pool = finalized_balance + pending_deposits - pending_withdrawals`
+/// 4. If rewards exist: `accumulator += (pending_rewards * 1e18) / total_pool`
+/// 5. Update: `finalized_balance = total_pool`
+/// 6. Reset: `pending_deposits = 0`, `pending_withdrawals = 0`, `pending_rewards = 0`
 ///
 /// ## Example
 ///
@@ -109,7 +120,11 @@ pub struct TokenPoolConfig {
     pub pending_authority: Pubkey,
     /// Token mint address
     pub mint: Pubkey,
-    /// Vault token account PDA
+    /// Vault token account: the pool config's ATA for `mint`, the
+    /// `["vault", pool_config]` PDA for pools created before ATA vaults, or a
+    /// replacement set by `MigrateVault`
+    ///
+    /// Always read the vault from here rather than deriving it.
     pub vault: Pubkey,
     /// Asset ID (Poseidon hash of mint)
     pub asset_id: [u8; 32],
@@ -193,7 +208,8 @@ pub struct TokenPoolConfig {
     /// **Units:** Token base units (matches token decimals)
     ///
     /// **Updates on:**
-    /// - `fund_rewards()`: += funded_amount
+    /// - `fund_rewards()`: += funded_amount (without a vest duration)
+    /// - `release_vested_rewards()`: += vested portion of `vesting_rewards`
     /// - `finalize_rewards()`: reset to 0 after distribution
     ///
     /// **Audit Note:** This separation enables the finalization event to report
@@ -201,8 +217,16 @@ pub struct TokenPoolConfig {
     /// on reward sources for indexers and users.
     pub pending_funded_rewards: u64,
 
-    /// Padding for u128 alignment (3 × u64 = 24 bytes, need 32 for u128 alignment)
-    pub _pad_fees: u64,
+    /// Protocol share of deposit and withdrawal fees, not yet withdrawn.
+    ///
+    /// **Units:** Token base units (matches token decimals)
+    ///
+    /// **Updates on:**
+    /// - Deposit/withdraw: += fee * protocol_fee_share_bps / 10000
+    /// - `withdraw_protocol_fees()`: -= amount
+    ///
+    /// Never enters the reward accumulator, so depositors do not earn on it.
+    pub accrued_protocol_fees: u64,
 
     /// Cumulative total deposits (in token base units)
     pub total_deposited: u128,
@@ -221,8 +245,8 @@ pub struct TokenPoolConfig {
     pub total_withdrawal_fees: u128,
     /// Total rewards funded via fund_rewards instruction (in token base units)
     pub total_funded_rewards: u128,
-    /// Reserved for future use (maintains struct alignment)
-    pub _reserved_stats: u128,
+    /// Total protocol fees transferred to the treasury (in token base units)
+    pub total_protocol_fees_withdrawn: u128,
     /// Maximum deposit amount per transaction
     pub max_deposit_amount: u64,
     /// Number of deposit transactions
@@ -239,14 +263,161 @@ pub struct TokenPoolConfig {
     pub deposit_fee_rate: u16,
     /// Withdrawal fee rate in basis points
     pub withdrawal_fee_rate: u16,
+    /// Share of each fee kept by the protocol, in basis points (max 10000)
+    pub protocol_fee_share_bps: u16,
     /// Token decimals
     pub decimals: u8,
     /// Whether this config is active
     pub is_active: u8,
     /// PDA bump seed
     pub bump: u8,
-    /// Padding for struct alignment (9 bytes to reach 16-byte alignment)
-    pub _padding: [u8; 9],
+    /// Pool-level pause, enforced by the pool itself regardless of hub routing
+    pub paused: u8,
+    /// While paused, still allow withdrawals (withdrawal-only mode)
+    pub allow_withdrawals_when_paused: u8,
+    /// Set by `ReconcileVault` when the vault holds less than expected;
+    /// blocks deposits until `ClearVaultDeficit`
+    pub deficit_detected: u8,
+    /// Padding for struct alignment (4 bytes to reach 16-byte alignment)
+    pub _padding: [u8; 4],
+    /// Wallet whose canonical ATA receives withdrawn protocol fees ([0; 32] = unset)
+    pub treasury: Pubkey,
+    /// Token program that owns the mint and vault (SPL Token or Token-2022)
+    pub token_program: Pubkey,
+    /// Token-2022 transfer fees withheld from transfers into the vault (in token base units)
+    ///
+    /// **Updates on:**
+    /// - Deposit: += gross - received (absorbed by the deposit fee)
+    /// - `fund_rewards()`: += amount - received
+    ///
+    /// Tracking fields only count tokens that actually reached the vault, so
+    /// this records the gap between instructed and received amounts.
+    pub total_transfer_fee_shortfall: u128,
+    /// Cap on the accounted pool balance after a deposit (0 = unlimited)
+    pub max_total_pool: u128,
+    /// Cap on a single deposit's gross amount (0 = unlimited)
+    pub max_single_deposit: u64,
+    /// Minimum gross amount per deposit (0 = unlimited)
+    pub min_deposit: u64,
+    /// Minimum slots between finalizations (0 = `UPDATE_SLOT_INTERVAL`)
+    ///
+    /// **Updates on:** `SetFinalizeInterval` only
+    pub finalize_interval_slots: u64,
+
+    /// Funded rewards still vesting into `pending_funded_rewards`.
+    ///
+    /// **Units:** Token base units (matches token decimals)
+    ///
+    /// **Updates on:**
+    /// - `fund_rewards()` with a vest duration: += funded_amount
+    /// - `release_vested_rewards()`: -= linearly vested portion
+    ///
+    /// Already counted in `total_funded_rewards`; these tokens sit in the vault.
+    pub vesting_rewards: u64,
+    /// Slot from which `vesting_rewards` vests (last release or funding)
+    pub vesting_start_slot: u64,
+    /// Slot at which `vesting_rewards` is fully vested
+    pub vesting_end_slot: u64,
+
+    /// Cumulative excess swept into rewards by `sweep_excess()` (in token base units)
+    pub total_swept: u128,
+    /// Cumulative excess sent back out of the vault by `ReturnExcess` (in token base units)
+    pub total_excess_returned: u128,
+    /// Cap on the excess swept per `SweepExcess` call (0 = unlimited)
+    pub max_sweep_per_call: u64,
+    /// Slots detected excess must wait before it can be swept (0 = immediately)
+    pub sweep_delay_slots: u64,
+    /// Excess observed by `SweepExcess` and waiting out `sweep_delay_slots`.
+    ///
+    /// **Units:** Token base units (matches token decimals)
+    ///
+    /// **Updates on:**
+    /// - `sweep_excess()`: set when excess is first seen, -= amount swept
+    /// - `reconcile_vault()`: set when a surplus is first seen
+    /// - `return_excess()`: capped to the excess left in the vault
+    pub detected_excess: u64,
+    /// Slot at which `detected_excess` was first seen
+    pub excess_detected_slot: u64,
+
+    /// Cumulative gross volume deposited or withdrawn fee-free by exempt
+    /// authorities (in token base units)
+    pub total_fee_exempt_volume: u128,
+    /// Authorities that pay no deposit or withdrawal fee ([0; 32] = empty slot)
+    ///
+    /// **Updates on:** `AddFeeExempt` / `RemoveFeeExempt` only
+    pub fee_exempt: [Pubkey; MAX_FEE_EXEMPT],
+
+    /// Shortfall found by the last `ReconcileVault` that set `deficit_detected`
+    /// (in token base units, saturating)
+    pub vault_deficit: u64,
+    /// Slot of the last `ReconcileVault` (0 = never reconciled)
+    pub last_reconciled_slot: u64,
+
+    /// Cumulative amount taken out of the vault by `ExecuteEmergencyWithdraw`
+    /// (in token base units)
+    pub total_emergency_withdrawn: u128,
+    /// Token account recorded by `InitiateEmergencyWithdraw` ([0; 32] = none pending)
+    pub emergency_withdraw_destination: Pubkey,
+    /// Amount of the pending emergency withdrawal (0 = none pending)
+    pub emergency_withdraw_amount: u64,
+    /// Slot from which the pending emergency withdrawal can be executed
+    pub emergency_withdraw_unlock_slot: u64,
+    /// Slots an emergency withdrawal waits between initiation and execution
+    /// (never less than `MIN_EMERGENCY_WITHDRAW_DELAY_SLOTS`)
+    pub emergency_withdraw_delay_slots: u64,
+    /// Slot at which the pending emergency withdrawal was initiated
+    pub emergency_withdraw_initiated_slot: u64,
+
+    /// Completed vault migrations (0 = `vault` is the one created by `InitPool`)
+    pub vault_generation: u64,
+    /// Replacement vault recorded by `InitiateVaultMigration` ([0; 32] = none pending)
+    pub pending_vault: Pubkey,
+    /// Slot from which the pending vault migration can be executed
+    pub vault_migration_unlock_slot: u64,
+
+    /// Gross deposit volume since the last finalization (in token base units, saturating)
+    ///
+    /// **Updates on:**
+    /// - Deposit: += amount
+    /// - `finalize_rewards()`: reset to 0
+    pub epoch_deposit_volume: u64,
+    /// Gross withdrawal volume since the last finalization (in token base units, saturating)
+    ///
+    /// **Updates on:**
+    /// - Withdraw: += amount
+    /// - `finalize_rewards()`: reset to 0
+    pub epoch_withdrawal_volume: u64,
+    /// Deposit fees charged since the last finalization, protocol share included
+    /// (in token base units, saturating)
+    pub epoch_deposit_fees: u64,
+    /// Withdrawal fees charged since the last finalization, protocol share included
+    /// (in token base units, saturating)
+    pub epoch_withdrawal_fees: u64,
+
+    /// Minimum gross amount per withdrawal (0 = unlimited)
+    ///
+    /// **Updates on:** `SetWithdrawalPolicy` only
+    pub min_withdrawal: u64,
+    /// Fee rounding direction for deposits and withdrawals (0 = down, 1 = up)
+    ///
+    /// The hub reads this flag to compute the same expected fee.
+    pub round_fees_up: u8,
+    /// Padding for struct alignment
+    pub _withdrawal_policy_padding: [u8; 7],
+
+    /// `reward_accumulator` before the most recent finalization
+    ///
+    /// Lets the hub accept a proof built against the accumulator that a
+    /// finalization replaced moments before the transaction executed.
+    ///
+    /// **Updates on:** `finalize_rewards()`: set to the outgoing accumulator
+    pub previous_reward_accumulator: u128,
+    /// Number of finalizations so far (saturating)
+    ///
+    /// **Updates on:** `finalize_rewards()`: += 1
+    pub accumulator_epoch: u64,
+    /// Padding for struct alignment
+    pub _accumulator_epoch_padding: [u8; 8],
 }
 
 impl TokenPoolConfig {
@@ -266,6 +437,20 @@ impl TokenPoolConfig {
     /// At 400ms/slot: 2700 slots ≈ 18 minutes ≈ ~3 finalizations per hour (max)
     pub const UPDATE_SLOT_INTERVAL: u64 = 2700;
 
+    /// Lower bound for `finalize_interval_slots` (~10 minutes at 400ms slots).
+    ///
+    /// Keeps the proof validity window long enough to generate and submit a proof.
+    pub const MIN_FINALIZE_INTERVAL_SLOTS: u64 = 1_500;
+
+    /// Upper bound for `finalize_interval_slots` (~1 day at 400ms slots).
+    pub const MAX_FINALIZE_INTERVAL_SLOTS: u64 = 216_000;
+
+    /// Floor for `emergency_withdraw_delay_slots` (~1 day at 400ms slots).
+    ///
+    /// Gives users and the hub time to react to an initiated emergency
+    /// withdrawal, even if the authority key is compromised.
+    pub const MIN_EMERGENCY_WITHDRAW_DELAY_SLOTS: u64 = 216_000;
+
     /// Precision multiplier for accumulator calculations (1e18)
     ///
     /// This scaling factor preserves precision when computing rewards per unit.
@@ -306,6 +491,36 @@ impl TokenPoolConfig {
         Ok(())
     }
 
+    /// Check if the pool program has paused itself
+    pub fn is_paused(&self) -> bool {
+        self.paused != 0
+    }
+
+    /// Check deposits are not paused, returning error if they are.
+    ///
+    /// An uncleared vault deficit also blocks deposits.
+    #[inline]
+    pub fn require_deposits_allowed(&self) -> Result<(), TokenPoolError> {
+        if self.is_paused() {
+            return Err(TokenPoolError::PoolPaused);
+        }
+        if self.deficit_detected != 0 {
+            return Err(TokenPoolError::VaultDeficitDetected);
+        }
+        Ok(())
+    }
+
+    /// Check withdrawals are not paused, returning error if they are.
+    ///
+    /// Withdrawals stay open while paused if `allow_withdrawals_when_paused` is set.
+    #[inline]
+    pub fn require_withdrawals_allowed(&self) -> Result<(), TokenPoolError> {
+        if self.is_paused() && self.allow_withdrawals_when_paused == 0 {
+            return Err(TokenPoolError::PoolPaused);
+        }
+        Ok(())
+    }
+
     /// Check if signer matches authority, returning error if unauthorized.
     #[inline]
     pub fn require_authority(&self, signer: &Pubkey) -> Result<(), TokenPoolError> {
@@ -325,6 +540,639 @@ impl TokenPoolConfig {
         Ok(())
     }
 
+    /// Split a fee into `(protocol_share, depositor_share)` by `protocol_fee_share_bps`.
+    ///
+    /// The protocol share rounds down, so depositors keep any remainder.
+    pub fn split_fee(&self, fee: u64) -> Result<(u64, u64), TokenPoolError> {
+        let protocol_share = (fee as u128)
+            .checked_mul(self.protocol_fee_share_bps as u128)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?
+            .checked_div(BASIS_POINTS as u128)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+        let protocol_share =
+            u64::try_from(protocol_share).map_err(|_| TokenPoolError::ArithmeticOverflow)?;
+        let depositor_share = fee
+            .checked_sub(protocol_share)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+        Ok((protocol_share, depositor_share))
+    }
+
+    /// Record a deposit fee, accruing the protocol share and leaving the rest
+    /// in `pending_deposit_fees`. Returns the protocol share.
+    pub fn record_deposit_fee(&mut self, fee: u64) -> Result<u64, TokenPoolError> {
+        let (protocol_share, depositor_share) = self.split_fee(fee)?;
+
+        self.total_deposit_fees = self
+            .total_deposit_fees
+            .checked_add(fee as u128)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+        self.pending_deposit_fees = self
+            .pending_deposit_fees
+            .checked_add(depositor_share)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+        self.accrued_protocol_fees = self
+            .accrued_protocol_fees
+            .checked_add(protocol_share)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+
+        Ok(protocol_share)
+    }
+
+    /// Record a withdrawal fee, accruing the protocol share and leaving the rest
+    /// in `pending_withdrawal_fees`. Returns the protocol share.
+    pub fn record_withdrawal_fee(&mut self, fee: u64) -> Result<u64, TokenPoolError> {
+        let (protocol_share, depositor_share) = self.split_fee(fee)?;
+
+        self.total_withdrawal_fees = self
+            .total_withdrawal_fees
+            .checked_add(fee as u128)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+        self.pending_withdrawal_fees = self
+            .pending_withdrawal_fees
+            .checked_add(depositor_share)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+        self.accrued_protocol_fees = self
+            .accrued_protocol_fees
+            .checked_add(protocol_share)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+
+        Ok(protocol_share)
+    }
+
+    /// Add a deposit to the current epoch's statistics.
+    ///
+    /// Saturates rather than failing, so statistics can never block a deposit.
+    pub fn record_epoch_deposit(&mut self, amount: u64, fee: u64) {
+        self.epoch_deposit_volume = self.epoch_deposit_volume.saturating_add(amount);
+        self.epoch_deposit_fees = self.epoch_deposit_fees.saturating_add(fee);
+    }
+
+    /// Add a withdrawal to the current epoch's statistics.
+    ///
+    /// Saturates rather than failing, so statistics can never block a withdrawal.
+    pub fn record_epoch_withdrawal(&mut self, amount: u64, fee: u64) {
+        self.epoch_withdrawal_volume = self.epoch_withdrawal_volume.saturating_add(amount);
+        self.epoch_withdrawal_fees = self.epoch_withdrawal_fees.saturating_add(fee);
+    }
+
+    /// Deduct `amount` from `accrued_protocol_fees` for a treasury withdrawal.
+    ///
+    /// Returns `Err(InsufficientProtocolFees)` if more than the accrued amount
+    /// is requested.
+    pub fn withdraw_protocol_fees(&mut self, amount: u64) -> Result<(), TokenPoolError> {
+        self.accrued_protocol_fees = self
+            .accrued_protocol_fees
+            .checked_sub(amount)
+            .ok_or(TokenPoolError::InsufficientProtocolFees)?;
+        self.total_protocol_fees_withdrawn = self
+            .total_protocol_fees_withdrawn
+            .checked_add(amount as u128)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Check that `token_program` is the program this pool was created with.
+    ///
+    /// Returns `Err(InvalidTokenProgram)` otherwise.
+    pub fn require_token_program(&self, token_program: &Pubkey) -> Result<(), TokenPoolError> {
+        if *token_program != self.token_program {
+            return Err(TokenPoolError::InvalidTokenProgram);
+        }
+        Ok(())
+    }
+
+    /// Check a deposit against the limits set via `SetPoolLimits`.
+    ///
+    /// `amount` is the gross deposit and `principal` the amount it adds to the
+    /// accounted balance. Zero limits are unlimited. Returns
+    /// `Err(DepositBelowMinimum)`, `Err(SingleDepositCapExceeded)`, or
+    /// `Err(PoolCapExceeded)` for the first limit violated.
+    pub fn require_deposit_within_limits(
+        &self,
+        amount: u64,
+        principal: u64,
+    ) -> Result<(), TokenPoolError> {
+        if self.min_deposit != 0 && amount < self.min_deposit {
+            return Err(TokenPoolError::DepositBelowMinimum);
+        }
+        if self.max_single_deposit != 0 && amount > self.max_single_deposit {
+            return Err(TokenPoolError::SingleDepositCapExceeded);
+        }
+        if self.max_total_pool != 0 {
+            let new_total = self
+                .current_balance()?
+                .checked_add(principal as u128)
+                .ok_or(TokenPoolError::ArithmeticOverflow)?;
+            if new_total > self.max_total_pool {
+                return Err(TokenPoolError::PoolCapExceeded);
+            }
+        }
+        Ok(())
+    }
+
+    /// Check a withdrawal's gross amount against `min_withdrawal`.
+    ///
+    /// Returns `Err(WithdrawalBelowMinimum)` if a minimum is set and `amount`
+    /// is below it.
+    pub fn require_withdrawal_above_minimum(&self, amount: u64) -> Result<(), TokenPoolError> {
+        if self.min_withdrawal != 0 && amount < self.min_withdrawal {
+            return Err(TokenPoolError::WithdrawalBelowMinimum);
+        }
+        Ok(())
+    }
+
+    /// Rounding of this pool's fees, per `round_fees_up`.
+    #[inline]
+    pub fn fee_rounding(&self) -> Rounding {
+        Rounding::from_round_up(self.round_fees_up != 0)
+    }
+
+    /// Fee of `fee_rate` basis points on `amount`, rounded per `round_fees_up`.
+    pub fn calculate_fee(&self, amount: u64, fee_rate: u16) -> Result<u64, TokenPoolError> {
+        compute_fee(amount, fee_rate, self.fee_rounding()).ok_or(TokenPoolError::ArithmeticOverflow)
+    }
+
+    /// Check that no pending balances, pending or vesting rewards, or accrued
+    /// protocol fees remain, as required before `ClosePool`.
+    ///
+    /// Returns `Err(PoolNotEmpty)` if any tracked amount is non-zero. The vault
+    /// token balance is checked separately by the caller.
+    pub fn require_empty(&self) -> Result<(), TokenPoolError> {
+        if self.pending_deposits != 0
+            || self.pending_withdrawals != 0
+            || self.pending_deposit_fees != 0
+            || self.pending_withdrawal_fees != 0
+            || self.pending_funded_rewards != 0
+            || self.accrued_protocol_fees != 0
+            || self.vesting_rewards != 0
+            || self.has_pending_emergency_withdraw()
+        {
+            return Err(TokenPoolError::PoolNotEmpty);
+        }
+        Ok(())
+    }
+
+    /// Slots required between finalizations for this pool.
+    ///
+    /// `finalize_interval_slots`, or `UPDATE_SLOT_INTERVAL` when it is 0.
+    pub fn finalize_interval(&self) -> u64 {
+        match self.finalize_interval_slots {
+            0 => Self::UPDATE_SLOT_INTERVAL,
+            interval => interval,
+        }
+    }
+
+    /// Whether `finalize_interval()` slots have passed since `last_finalized_slot`.
+    pub fn rewards_ready(&self, current_slot: u64) -> bool {
+        current_slot.saturating_sub(self.last_finalized_slot) >= self.finalize_interval()
+    }
+
+    /// Add funded rewards that vest linearly over `duration_slots`.
+    ///
+    /// A zero duration credits `pending_funded_rewards` directly. Otherwise
+    /// concurrent fundings aggregate into a single weighted schedule: the
+    /// portion vested so far is released, then the remaining and new amounts
+    /// vest together from `current_slot` over the amount-weighted average of
+    /// their remaining durations (rounded up, so nothing vests early).
+    pub fn add_vesting_rewards(
+        &mut self,
+        amount: u64,
+        duration_slots: u64,
+        current_slot: u64,
+    ) -> Result<(), TokenPoolError> {
+        if amount == 0 {
+            return Ok(());
+        }
+        if duration_slots == 0 {
+            self.pending_funded_rewards = self
+                .pending_funded_rewards
+                .checked_add(amount)
+                .ok_or(TokenPoolError::ArithmeticOverflow)?;
+            return Ok(());
+        }
+
+        self.release_vested_rewards(current_slot)?;
+
+        let existing = self.vesting_rewards as u128;
+        let existing_duration = self.vesting_end_slot.saturating_sub(current_slot) as u128;
+        let total = existing
+            .checked_add(amount as u128)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+        let weighted = existing
+            .checked_mul(existing_duration)
+            .and_then(|w| w.checked_add((amount as u128).checked_mul(duration_slots as u128)?))
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+        let duration = weighted.div_ceil(total);
+
+        self.vesting_rewards =
+            u64::try_from(total).map_err(|_| TokenPoolError::ArithmeticOverflow)?;
+        self.vesting_start_slot = current_slot;
+        self.vesting_end_slot = u64::try_from(duration)
+            .ok()
+            .and_then(|d| current_slot.checked_add(d))
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Move the linearly vested part of `vesting_rewards` into
+    /// `pending_funded_rewards`.
+    ///
+    /// Releases `vesting_rewards * (current_slot - start) / (end - start)`,
+    /// or everything once `vesting_end_slot` is reached, then moves
+    /// `vesting_start_slot` to `current_slot`. Rounding remainders stay in
+    /// `vesting_rewards`, so the release never exceeds the funded amount and
+    /// completes exactly at the end slot.
+    ///
+    /// Returns the amount released.
+    pub fn release_vested_rewards(&mut self, current_slot: u64) -> Result<u64, TokenPoolError> {
+        if self.vesting_rewards == 0 {
+            return Ok(0);
+        }
+
+        let released = if current_slot >= self.vesting_end_slot {
+            self.vesting_rewards
+        } else if current_slot <= self.vesting_start_slot {
+            return Ok(0);
+        } else {
+            let elapsed = (current_slot - self.vesting_start_slot) as u128;
+            let duration = (self.vesting_end_slot - self.vesting_start_slot) as u128;
+            // elapsed < duration, so the result is below vesting_rewards
+            ((self.vesting_rewards as u128 * elapsed) / duration) as u64
+        };
+
+        self.vesting_rewards -= released;
+        self.vesting_start_slot = current_slot;
+        self.pending_funded_rewards = self
+            .pending_funded_rewards
+            .checked_add(released)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+        Ok(released)
+    }
+
+    /// Check if `authority` is on the fee-exempt list.
+    pub fn is_fee_exempt(&self, authority: &Pubkey) -> bool {
+        *authority != [0u8; 32] && self.fee_exempt.contains(authority)
+    }
+
+    /// Check the hub's `expect_fee_exempt` signal against the fee-exempt list.
+    ///
+    /// Returns whether `authority` is exempt, or `Err(FeeExemptionMismatch)` if
+    /// the hub computed its expected output under the other assumption.
+    pub fn require_fee_exemption(
+        &self,
+        authority: &Pubkey,
+        expect_fee_exempt: bool,
+    ) -> Result<bool, TokenPoolError> {
+        let exempt = self.is_fee_exempt(authority);
+        if exempt != expect_fee_exempt {
+            return Err(TokenPoolError::FeeExemptionMismatch);
+        }
+        Ok(exempt)
+    }
+
+    /// Add `authority` to the fee-exempt list (no-op if already listed).
+    ///
+    /// Returns `Err(InvalidInstructionData)` for the zero key and
+    /// `Err(FeeExemptListFull)` when every slot is taken.
+    pub fn add_fee_exempt(&mut self, authority: &Pubkey) -> Result<(), TokenPoolError> {
+        if *authority == [0u8; 32] {
+            return Err(TokenPoolError::InvalidInstructionData);
+        }
+        if self.is_fee_exempt(authority) {
+            return Ok(());
+        }
+        let slot = self
+            .fee_exempt
+            .iter_mut()
+            .find(|slot| **slot == [0u8; 32])
+            .ok_or(TokenPoolError::FeeExemptListFull)?;
+        *slot = *authority;
+        Ok(())
+    }
+
+    /// Remove `authority` from the fee-exempt list.
+    ///
+    /// Returns `Err(FeeExemptNotListed)` if it is not on the list.
+    pub fn remove_fee_exempt(&mut self, authority: &Pubkey) -> Result<(), TokenPoolError> {
+        if *authority == [0u8; 32] {
+            return Err(TokenPoolError::FeeExemptNotListed);
+        }
+        let slot = self
+            .fee_exempt
+            .iter_mut()
+            .find(|slot| **slot == *authority)
+            .ok_or(TokenPoolError::FeeExemptNotListed)?;
+        *slot = [0u8; 32];
+        Ok(())
+    }
+
+    /// Record `amount` moved fee-free by an exempt authority.
+    pub fn record_fee_exempt_volume(&mut self, amount: u64) -> Result<(), TokenPoolError> {
+        self.total_fee_exempt_volume = self
+            .total_fee_exempt_volume
+            .checked_add(amount as u128)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Vault balance implied by the cumulative accounting stats.
+    ///
+    /// ```text
+    /// expected = total_deposited - total_withdrawn
+    ///          + total_deposit_fees + total_withdrawal_fees
+    ///          + total_funded_rewards
+    ///          - total_protocol_fees_withdrawn
+    ///          - total_emergency_withdrawn
+    /// ```
+    ///
+    /// Anything the vault holds above this is excess.
+    pub fn expected_vault_balance(&self) -> Result<u128, TokenPoolError> {
+        self.total_deposited
+            .checked_sub(self.total_withdrawn)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?
+            .checked_add(self.total_deposit_fees)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?
+            .checked_add(self.total_withdrawal_fees)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?
+            .checked_add(self.total_funded_rewards)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?
+            .checked_sub(self.total_protocol_fees_withdrawn)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?
+            .checked_sub(self.total_emergency_withdrawn)
+            .ok_or(TokenPoolError::ArithmeticOverflow)
+    }
+
+    /// Sweep the vault's `excess` into `pending_funded_rewards`, subject to
+    /// `sweep_delay_slots` and `max_sweep_per_call`.
+    ///
+    /// Excess is first recorded in `detected_excess` with the current slot and
+    /// only becomes sweepable once `sweep_delay_slots` have passed. Excess that
+    /// arrives while an earlier detection is waiting starts its own delay once
+    /// that detection is fully swept. At most `max_sweep_per_call` is swept.
+    ///
+    /// Returns the amount swept (0 while the delay runs or with no excess).
+    pub fn sweep_excess(&mut self, excess: u64, current_slot: u64) -> Result<u64, TokenPoolError> {
+        self.detect_excess(excess, current_slot);
+        if self.detected_excess == 0 {
+            return Ok(0);
+        }
+
+        let sweepable_slot = self
+            .excess_detected_slot
+            .saturating_add(self.sweep_delay_slots);
+        if current_slot < sweepable_slot {
+            return Ok(0);
+        }
+
+        let amount = match self.max_sweep_per_call {
+            0 => self.detected_excess,
+            cap => self.detected_excess.min(cap),
+        };
+        self.detected_excess -= amount;
+
+        self.pending_funded_rewards = self
+            .pending_funded_rewards
+            .checked_add(amount)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+        self.total_funded_rewards = self
+            .total_funded_rewards
+            .checked_add(amount as u128)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+        self.total_swept = self
+            .total_swept
+            .checked_add(amount as u128)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+        Ok(amount)
+    }
+
+    /// Record the vault's current `excess` in `detected_excess`.
+    ///
+    /// A detection already waiting keeps its slot (capped to `excess`); with
+    /// nothing waiting, new excess starts its delay at `current_slot`.
+    pub fn detect_excess(&mut self, excess: u64, current_slot: u64) {
+        // Detections no longer in the vault were returned or are gone
+        self.detected_excess = self.detected_excess.min(excess);
+        if self.detected_excess == 0 {
+            if excess == 0 {
+                self.excess_detected_slot = 0;
+                return;
+            }
+            self.detected_excess = excess;
+            self.excess_detected_slot = current_slot;
+        }
+    }
+
+    /// Compare the vault balance with `expected_vault_balance()`.
+    ///
+    /// A surplus is recorded as detected excess for `SweepExcess`; a deficit
+    /// sets `deficit_detected` (blocking deposits) and `vault_deficit`.
+    /// Returns `(expected, delta)` where `delta = vault_balance - expected`.
+    pub fn reconcile_vault(
+        &mut self,
+        vault_balance: u64,
+        current_slot: u64,
+    ) -> Result<(u128, i128), TokenPoolError> {
+        let expected = self.expected_vault_balance()?;
+        let delta = i128::try_from(expected)
+            .ok()
+            .and_then(|expected| (vault_balance as i128).checked_sub(expected))
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+
+        if delta < 0 {
+            self.deficit_detected = 1;
+            self.vault_deficit = u64::try_from(delta.unsigned_abs()).unwrap_or(u64::MAX);
+        } else {
+            // Bounded by the vault balance, so it fits in u64
+            self.detect_excess(delta as u64, current_slot);
+        }
+        self.last_reconciled_slot = current_slot;
+        Ok((expected, delta))
+    }
+
+    /// Clear a deficit flagged by `ReconcileVault`, reopening deposits.
+    pub fn clear_vault_deficit(&mut self) {
+        self.deficit_detected = 0;
+        self.vault_deficit = 0;
+    }
+
+    /// Check that `vault` is the pool's current vault.
+    ///
+    /// Returns `Err(InvalidVault)` for any other account, including vaults
+    /// replaced by `ExecuteVaultMigration`.
+    pub fn require_vault(&self, vault: &Pubkey) -> Result<(), TokenPoolError> {
+        if self.vault != *vault {
+            return Err(TokenPoolError::InvalidVault);
+        }
+        Ok(())
+    }
+
+    /// Whether a vault migration is initiated and not yet executed.
+    pub fn has_pending_vault_migration(&self) -> bool {
+        self.pending_vault != [0u8; 32]
+    }
+
+    /// Record `new_vault` as the pool's next vault.
+    ///
+    /// Uses the same delay as emergency withdrawals. Returns the unlock slot,
+    /// or `Err(VaultMigrationPending)` if a migration is already pending.
+    pub fn initiate_vault_migration(
+        &mut self,
+        new_vault: &Pubkey,
+        current_slot: u64,
+    ) -> Result<u64, TokenPoolError> {
+        if self.has_pending_vault_migration() {
+            return Err(TokenPoolError::VaultMigrationPending);
+        }
+        let unlock_slot = current_slot
+            .checked_add(self.emergency_withdraw_delay())
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+        self.pending_vault = *new_vault;
+        self.vault_migration_unlock_slot = unlock_slot;
+        Ok(unlock_slot)
+    }
+
+    /// Switch `vault` to the pending vault and bump `vault_generation`.
+    ///
+    /// Returns the replaced vault, or `Err(NoVaultMigrationPending)` /
+    /// `Err(VaultMigrationLocked)` before `vault_migration_unlock_slot`.
+    pub fn execute_vault_migration(&mut self, current_slot: u64) -> Result<Pubkey, TokenPoolError> {
+        if !self.has_pending_vault_migration() {
+            return Err(TokenPoolError::NoVaultMigrationPending);
+        }
+        if current_slot < self.vault_migration_unlock_slot {
+            return Err(TokenPoolError::VaultMigrationLocked);
+        }
+
+        let old_vault = self.vault;
+        self.vault = self.pending_vault;
+        self.vault_generation = self
+            .vault_generation
+            .checked_add(1)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+        self.pending_vault = [0u8; 32];
+        self.vault_migration_unlock_slot = 0;
+        Ok(old_vault)
+    }
+
+    /// Whether an emergency withdrawal is initiated and not yet executed or cancelled.
+    pub fn has_pending_emergency_withdraw(&self) -> bool {
+        self.emergency_withdraw_amount != 0
+    }
+
+    /// Slots an emergency withdrawal must wait, floored at
+    /// `MIN_EMERGENCY_WITHDRAW_DELAY_SLOTS`.
+    pub fn emergency_withdraw_delay(&self) -> u64 {
+        self.emergency_withdraw_delay_slots
+            .max(Self::MIN_EMERGENCY_WITHDRAW_DELAY_SLOTS)
+    }
+
+    /// Record an emergency withdrawal of `amount` to `destination`.
+    ///
+    /// Returns the unlock slot, or `Err(EmergencyWithdrawPending)` if one is
+    /// already pending and `Err(InsufficientBalance)` if `amount` exceeds the
+    /// accounted pool balance (`current_balance()`).
+    pub fn initiate_emergency_withdraw(
+        &mut self,
+        amount: u64,
+        destination: &Pubkey,
+        current_slot: u64,
+    ) -> Result<u64, TokenPoolError> {
+        if self.has_pending_emergency_withdraw() {
+            return Err(TokenPoolError::EmergencyWithdrawPending);
+        }
+        if amount == 0 {
+            return Err(TokenPoolError::InvalidAmount);
+        }
+        if *destination == [0u8; 32] {
+            return Err(TokenPoolError::InvalidEmergencyWithdrawDestination);
+        }
+        if amount as u128 > self.current_balance()? {
+            return Err(TokenPoolError::InsufficientBalance);
+        }
+
+        let unlock_slot = current_slot
+            .checked_add(self.emergency_withdraw_delay())
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+        self.emergency_withdraw_amount = amount;
+        self.emergency_withdraw_destination = *destination;
+        self.emergency_withdraw_unlock_slot = unlock_slot;
+        self.emergency_withdraw_initiated_slot = current_slot;
+        Ok(unlock_slot)
+    }
+
+    /// Drop the pending emergency withdrawal.
+    ///
+    /// Returns the cancelled amount, or `Err(NoEmergencyWithdrawPending)`.
+    pub fn cancel_emergency_withdraw(&mut self) -> Result<u64, TokenPoolError> {
+        if !self.has_pending_emergency_withdraw() {
+            return Err(TokenPoolError::NoEmergencyWithdrawPending);
+        }
+        let amount = self.emergency_withdraw_amount;
+        self.clear_emergency_withdraw();
+        Ok(amount)
+    }
+
+    /// Debit the pending emergency withdrawal from the accounted totals.
+    ///
+    /// The amount leaves the pool like a withdrawal (`pending_withdrawals`)
+    /// and is tracked in `total_emergency_withdrawn` so the vault invariant
+    /// still holds after the transfer. Returns `(amount, destination)`, or
+    /// `Err(EmergencyWithdrawLocked)` before `emergency_withdraw_unlock_slot`
+    /// and `Err(InsufficientBalance)` if the accounted balance has since
+    /// shrunk below the amount.
+    pub fn execute_emergency_withdraw(
+        &mut self,
+        current_slot: u64,
+    ) -> Result<(u64, Pubkey), TokenPoolError> {
+        if !self.has_pending_emergency_withdraw() {
+            return Err(TokenPoolError::NoEmergencyWithdrawPending);
+        }
+        if current_slot < self.emergency_withdraw_unlock_slot {
+            return Err(TokenPoolError::EmergencyWithdrawLocked);
+        }
+
+        let amount = self.emergency_withdraw_amount;
+        if amount as u128 > self.current_balance()? {
+            return Err(TokenPoolError::InsufficientBalance);
+        }
+        self.pending_withdrawals = self
+            .pending_withdrawals
+            .checked_add(amount as u128)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+        self.total_emergency_withdrawn = self
+            .total_emergency_withdrawn
+            .checked_add(amount as u128)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+
+        let destination = self.emergency_withdraw_destination;
+        self.clear_emergency_withdraw();
+        Ok((amount, destination))
+    }
+
+    fn clear_emergency_withdraw(&mut self) {
+        self.emergency_withdraw_amount = 0;
+        self.emergency_withdraw_destination = [0u8; 32];
+        self.emergency_withdraw_unlock_slot = 0;
+        self.emergency_withdraw_initiated_slot = 0;
+    }
+
+    /// Record `amount` of the vault's `excess` being sent back by `ReturnExcess`.
+    ///
+    /// Returns `Err(InsufficientExcess)` if `amount` exceeds `excess`, so
+    /// tracked balances can never be returned. Caps `detected_excess` to what
+    /// is left.
+    pub fn return_excess(&mut self, amount: u64, excess: u64) -> Result<(), TokenPoolError> {
+        let remaining = excess
+            .checked_sub(amount)
+            .ok_or(TokenPoolError::InsufficientExcess)?;
+        self.detected_excess = self.detected_excess.min(remaining);
+        self.total_excess_returned = self
+            .total_excess_returned
+            .checked_add(amount as u128)
+            .ok_or(TokenPoolError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
     /// Get current balance (finalized_balance + pending_deposits - pending_withdrawals)
     pub fn current_balance(&self) -> Result<u128, TokenPoolError> {
         self.finalized_balance
@@ -340,6 +1188,11 @@ impl TokenPoolConfig {
     /// - Updates `finalized_balance = finalized_balance + pending_deposits - pending_withdrawals`
     /// - Resets `pending_deposits` and `pending_withdrawals` to 0
     /// - Updates `last_finalized_slot` to `current_slot`
+    /// - Saves the outgoing `reward_accumulator` in `previous_reward_accumulator`
+    ///   and increments `accumulator_epoch`
+    ///
+    /// The protocol share of fees was already moved to `accrued_protocol_fees`
+    /// when collected and is never distributed here.
     ///
     /// Accumulator update (conditional on `total_pool > 0 && total_pending > 0`):
     /// - Calculates `reward_delta = pending_rewards * 1e18 / total_pool`
@@ -348,18 +1201,26 @@ impl TokenPoolConfig {
     ///
     /// When `total_pool = 0`, pending reward fields are preserved until depositors arrive.
     ///
-    /// Returns `Err(RewardsNotReady)` if `UPDATE_SLOT_INTERVAL` slots
-    /// have not passed since `last_finalized_slot`.
+    /// The per-epoch volume and fee statistics are always reset, since each
+    /// finalization closes an epoch.
+    ///
+    /// Vested funded rewards are released into `pending_funded_rewards` first,
+    /// so they are distributed in the same finalization.
+    ///
+    /// Returns the vested amount released, or `Err(RewardsNotReady)` if
+    /// `finalize_interval()` slots have not passed since `last_finalized_slot`.
     pub fn finalize_rewards(
         &mut self,
         current_slot: u64,
-    ) -> Result<(), pinocchio::program_error::ProgramError> {
+    ) -> Result<u64, pinocchio::program_error::ProgramError> {
         // Check if enough slots have passed
-        let slots_elapsed = current_slot.saturating_sub(self.last_finalized_slot);
-        if slots_elapsed < Self::UPDATE_SLOT_INTERVAL {
+        if !self.rewards_ready(current_slot) {
             return Err(TokenPoolError::RewardsNotReady.into());
         }
 
+        // Release the linearly vested part of funded rewards
+        let released = self.release_vested_rewards(current_slot)?;
+
         // Calculate total pool for reward distribution
         let total_pool = self
             .finalized_balance
@@ -375,6 +1236,8 @@ impl TokenPoolConfig {
             .checked_add(self.pending_funded_rewards as u128)
             .ok_or(TokenPoolError::ArithmeticOverflow)?;
 
+        let outgoing_accumulator = self.reward_accumulator;
+
         // Update accumulator only if there are deposits AND pending rewards
         // When total_pool = 0, rewards are preserved until depositors arrive
         if total_pool > 0 && total_pending > 0 {
@@ -410,8 +1273,14 @@ impl TokenPoolConfig {
         self.pending_deposits = 0;
         self.pending_withdrawals = 0;
         self.last_finalized_slot = current_slot;
+        self.previous_reward_accumulator = outgoing_accumulator;
+        self.accumulator_epoch = self.accumulator_epoch.saturating_add(1);
+        self.epoch_deposit_volume = 0;
+        self.epoch_withdrawal_volume = 0;
+        self.epoch_deposit_fees = 0;
+        self.epoch_withdrawal_fees = 0;
 
-        Ok(())
+        Ok(released)
     }
 }
 
@@ -439,4 +1308,19 @@ mod tests {
         // Verify size is reasonable for on-chain account
         assert!(TokenPoolConfig::SIZE < 1024);
     }
+
+    #[test]
+    fn test_fee_rates_offset() {
+        // The hub reads fee rates from raw account data at this offset
+        use core::mem::offset_of;
+        use zorb_pool_interface::pool_layout::TOKEN_POOL_FEE_RATES_OFFSET;
+        assert_eq!(
+            8 + offset_of!(TokenPoolConfig, deposit_fee_rate),
+            TOKEN_POOL_FEE_RATES_OFFSET
+        );
+        assert_eq!(
+            8 + offset_of!(TokenPoolConfig, withdrawal_fee_rate),
+            TOKEN_POOL_FEE_RATES_OFFSET + 2
+        );
+    }
 }
@@ -0,0 +1,372 @@
+//! SPL Token and Token-2022 helpers.
+//!
+//! `pinocchio_token` CPI builders always target the SPL Token program, so
+//! pools created for a Token-2022 mint go through the builders here, which
+//! take the token program stored in `TokenPoolConfig.token_program`.
+//!
+//! Account reads only rely on the base layout shared by both programs
+//! (extensions are appended after byte 165), so they work for either.
+
+use crate::TokenPoolError;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    cpi::{get_return_data, invoke_signed},
+    instruction::{AccountMeta, Instruction, Signer},
+    program_error::ProgramError,
+    pubkey::{Pubkey, find_program_address},
+};
+
+/// SPL Token Program ID
+pub const SPL_TOKEN_PROGRAM_ID: Pubkey = [
+    0x06, 0xdd, 0xf6, 0xe1, 0xd7, 0x65, 0xa1, 0x93, 0xd9, 0xcb, 0xe1, 0x46, 0xce, 0xeb, 0x79, 0xac,
+    0x1c, 0xb4, 0x85, 0xed, 0x5f, 0x5b, 0x37, 0x91, 0x3a, 0x8c, 0xf5, 0x85, 0x7e, 0xff, 0x00, 0xa9,
+];
+
+/// SPL Token-2022 Program ID
+pub const SPL_TOKEN_2022_PROGRAM_ID: Pubkey = [
+    0x06, 0xa7, 0xd5, 0x17, 0x18, 0x7b, 0xd1, 0x65, 0x35, 0x50, 0xc4, 0x9a, 0x3a, 0x8b, 0x9a, 0x28,
+    0xb9, 0x51, 0x9f, 0x60, 0x7d, 0x1f, 0x55, 0xb8, 0x26, 0xb4, 0x53, 0x06, 0x76, 0x8b, 0x9f, 0x71,
+];
+
+/// SPL Token account size (base layout, no extensions)
+pub const TOKEN_ACCOUNT_SIZE: usize = 165;
+
+/// SPL Token mint size (base layout, no extensions)
+pub const MINT_SIZE: usize = 82;
+
+/// Token-2022 `ExtensionType::DefaultAccountState`
+pub const EXTENSION_DEFAULT_ACCOUNT_STATE: u16 = 6;
+
+/// Token-2022 `ExtensionType::NonTransferable`
+pub const EXTENSION_NON_TRANSFERABLE: u16 = 9;
+
+/// Token-2022 `ExtensionType::PermanentDelegate`
+pub const EXTENSION_PERMANENT_DELEGATE: u16 = 12;
+
+/// Token-2022 `ExtensionType::TransferHook`
+pub const EXTENSION_TRANSFER_HOOK: u16 = 14;
+
+/// Token-2022 `ExtensionType::Pausable`
+pub const EXTENSION_PAUSABLE: u16 = 26;
+
+/// `AccountState::Frozen`, as stored in the DefaultAccountState extension
+pub const ACCOUNT_STATE_FROZEN: u8 = 2;
+
+/// Mint extensions a pool cannot hold.
+///
+/// - NonTransferable: tokens could never move into or out of the vault
+/// - PermanentDelegate: the delegate could transfer or burn vault funds
+/// - TransferHook: every vault transfer would need the hook's extra accounts
+/// - Pausable: the mint authority could freeze withdrawals out of the vault
+///
+/// DefaultAccountState is also rejected when its state is
+/// [`ACCOUNT_STATE_FROZEN`], since user token accounts would start frozen.
+pub const UNSUPPORTED_MINT_EXTENSIONS: [u16; 4] = [
+    EXTENSION_NON_TRANSFERABLE,
+    EXTENSION_PERMANENT_DELEGATE,
+    EXTENSION_TRANSFER_HOOK,
+    EXTENSION_PAUSABLE,
+];
+
+/// Token-2022 `AccountType::Mint` marker byte (at offset 165)
+const ACCOUNT_TYPE_MINT: u8 = 1;
+
+/// Offset of `Mint.decimals`
+const MINT_DECIMALS_OFFSET: usize = 44;
+
+/// Offset of `Mint.is_initialized`
+const MINT_IS_INITIALIZED_OFFSET: usize = 45;
+
+/// Offset of `Account.amount`
+const TOKEN_ACCOUNT_AMOUNT_OFFSET: usize = 64;
+
+/// Check if a program ID is a valid token program (SPL Token or Token-2022)
+pub fn is_token_program(program_id: &Pubkey) -> bool {
+    *program_id == SPL_TOKEN_PROGRAM_ID || *program_id == SPL_TOKEN_2022_PROGRAM_ID
+}
+
+/// Read the `amount` of a token account owned by `token_program`.
+pub fn read_token_balance(
+    token_account: &AccountInfo,
+    token_program: &Pubkey,
+) -> Result<u64, ProgramError> {
+    if !token_account.is_owned_by(token_program) {
+        return Err(TokenPoolError::InvalidAccountOwner.into());
+    }
+    let data = token_account.try_borrow_data()?;
+    let amount = data
+        .get(TOKEN_ACCOUNT_AMOUNT_OFFSET..TOKEN_ACCOUNT_AMOUNT_OFFSET + 8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    Ok(amount)
+}
+
+/// Read the decimals of an initialized mint owned by `token_program`.
+///
+/// For Token-2022 mints, also rejects any extension in
+/// [`UNSUPPORTED_MINT_EXTENSIONS`] and a frozen default account state.
+pub fn read_mint_decimals(mint: &AccountInfo, token_program: &Pubkey) -> Result<u8, ProgramError> {
+    if !mint.is_owned_by(token_program) {
+        return Err(TokenPoolError::InvalidMint.into());
+    }
+    let data = mint.try_borrow_data()?;
+
+    let has_extensions = data.len() > TOKEN_ACCOUNT_SIZE;
+    let valid_layout = data.len() == MINT_SIZE
+        || (has_extensions && data[TOKEN_ACCOUNT_SIZE] == ACCOUNT_TYPE_MINT);
+    if !valid_layout || data[MINT_IS_INITIALIZED_OFFSET] != 1 {
+        return Err(TokenPoolError::InvalidMint.into());
+    }
+
+    if has_extensions {
+        require_supported_mint_extensions(&data[TOKEN_ACCOUNT_SIZE + 1..])?;
+    }
+
+    Ok(data[MINT_DECIMALS_OFFSET])
+}
+
+/// Walk a Token-2022 extension TLV region and reject unsupported types.
+///
+/// Each entry is `[type: u16][length: u16][value: length bytes]`; a zero type
+/// marks the end of initialized extensions.
+pub fn require_supported_mint_extensions(mut tlv: &[u8]) -> Result<(), TokenPoolError> {
+    while tlv.len() >= 4 {
+        let extension = u16::from_le_bytes([tlv[0], tlv[1]]);
+        let length = u16::from_le_bytes([tlv[2], tlv[3]]) as usize;
+        if extension == 0 {
+            break;
+        }
+        if UNSUPPORTED_MINT_EXTENSIONS.contains(&extension) {
+            return Err(TokenPoolError::UnsupportedMintExtension);
+        }
+        let value = tlv.get(4..4 + length).ok_or(TokenPoolError::InvalidMint)?;
+        if extension == EXTENSION_DEFAULT_ACCOUNT_STATE
+            && value.first() == Some(&ACCOUNT_STATE_FROZEN)
+        {
+            return Err(TokenPoolError::UnsupportedMintExtension);
+        }
+        tlv = &tlv[4 + length..];
+    }
+    Ok(())
+}
+
+/// Canonical associated token account of `wallet` for `mint`.
+///
+/// ATA seeds are `[wallet, token_program, mint]` under the ATA program.
+pub fn find_associated_token_address(
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Pubkey {
+    find_program_address(
+        &[wallet, token_program, mint],
+        &pinocchio_associated_token_account::ID,
+    )
+    .0
+}
+
+/// Take the optional trailing mint account and check it is the pool's mint.
+pub fn optional_mint<'a>(
+    remaining_accounts: &'a [AccountInfo],
+    mint: &Pubkey,
+) -> Result<Option<&'a AccountInfo>, ProgramError> {
+    match remaining_accounts.first() {
+        Some(account) if account.key() != mint => Err(TokenPoolError::InvalidMint.into()),
+        account => Ok(account),
+    }
+}
+
+/// Transfer tokens, using `TransferChecked` when the mint is supplied.
+///
+/// Token-2022 pools require the mint: `Transfer` is rejected for mints with
+/// a transfer fee.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_tokens(
+    token_program: &Pubkey,
+    from: &AccountInfo,
+    to: &AccountInfo,
+    authority: &AccountInfo,
+    mint: Option<&AccountInfo>,
+    amount: u64,
+    decimals: u8,
+    signers: &[Signer],
+) -> ProgramResult {
+    let Some(mint) = mint else {
+        require_spl_token(token_program)?;
+        return invoke_token(
+            token_program,
+            &[
+                AccountMeta::writable(from.key()),
+                AccountMeta::writable(to.key()),
+                AccountMeta::readonly_signer(authority.key()),
+            ],
+            &amount_data::<9>(3, amount, None),
+            &[from, to, authority],
+            signers,
+        );
+    };
+    invoke_token(
+        token_program,
+        &[
+            AccountMeta::writable(from.key()),
+            AccountMeta::readonly(mint.key()),
+            AccountMeta::writable(to.key()),
+            AccountMeta::readonly_signer(authority.key()),
+        ],
+        &amount_data::<10>(12, amount, Some(decimals)),
+        &[from, mint, to, authority],
+        signers,
+    )
+}
+
+/// Approve a delegate, using `ApproveChecked` when the mint is supplied.
+#[allow(clippy::too_many_arguments)]
+pub fn approve_tokens(
+    token_program: &Pubkey,
+    source: &AccountInfo,
+    delegate: &AccountInfo,
+    owner: &AccountInfo,
+    mint: Option<&AccountInfo>,
+    amount: u64,
+    decimals: u8,
+    signers: &[Signer],
+) -> ProgramResult {
+    let Some(mint) = mint else {
+        require_spl_token(token_program)?;
+        return invoke_token(
+            token_program,
+            &[
+                AccountMeta::writable(source.key()),
+                AccountMeta::readonly(delegate.key()),
+                AccountMeta::readonly_signer(owner.key()),
+            ],
+            &amount_data::<9>(4, amount, None),
+            &[source, delegate, owner],
+            signers,
+        );
+    };
+    invoke_token(
+        token_program,
+        &[
+            AccountMeta::writable(source.key()),
+            AccountMeta::readonly(mint.key()),
+            AccountMeta::readonly(delegate.key()),
+            AccountMeta::readonly_signer(owner.key()),
+        ],
+        &amount_data::<10>(13, amount, Some(decimals)),
+        &[source, mint, delegate, owner],
+        signers,
+    )
+}
+
+/// Initialize a token account with a fixed owner (`InitializeAccount3`).
+pub fn initialize_account3(
+    token_program: &Pubkey,
+    account: &AccountInfo,
+    mint: &AccountInfo,
+    owner: &Pubkey,
+) -> ProgramResult {
+    let mut data = [0u8; 33];
+    data[0] = 18;
+    data[1..].copy_from_slice(owner);
+    invoke_token(
+        token_program,
+        &[
+            AccountMeta::writable(account.key()),
+            AccountMeta::readonly(mint.key()),
+        ],
+        &data,
+        &[account, mint],
+        &[],
+    )
+}
+
+/// Close a zero-balance token account (`CloseAccount`).
+pub fn close_token_account(
+    token_program: &Pubkey,
+    account: &AccountInfo,
+    destination: &AccountInfo,
+    authority: &AccountInfo,
+    signers: &[Signer],
+) -> ProgramResult {
+    invoke_token(
+        token_program,
+        &[
+            AccountMeta::writable(account.key()),
+            AccountMeta::writable(destination.key()),
+            AccountMeta::readonly_signer(authority.key()),
+        ],
+        &[9],
+        &[account, destination, authority],
+        signers,
+    )
+}
+
+/// Size of a token account for `mint`, including required extensions.
+///
+/// SPL Token accounts are always [`TOKEN_ACCOUNT_SIZE`]; Token-2022 is asked
+/// via `GetAccountDataSize` (e.g. transfer-fee mints need `TransferFeeAmount`).
+pub fn token_account_size(
+    token_program: &Pubkey,
+    mint: &AccountInfo,
+) -> Result<usize, ProgramError> {
+    if *token_program == SPL_TOKEN_PROGRAM_ID {
+        return Ok(TOKEN_ACCOUNT_SIZE);
+    }
+
+    invoke_token(
+        token_program,
+        &[AccountMeta::readonly(mint.key())],
+        &[21],
+        &[mint],
+        &[],
+    )?;
+
+    let return_data = get_return_data().ok_or(ProgramError::InvalidAccountData)?;
+    if return_data.program_id() != token_program {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let size = return_data
+        .as_slice()
+        .try_into()
+        .map(u64::from_le_bytes)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    usize::try_from(size).map_err(|_| ProgramError::InvalidAccountData)
+}
+
+/// Reject unchecked instructions for Token-2022 pools.
+fn require_spl_token(token_program: &Pubkey) -> ProgramResult {
+    if *token_program != SPL_TOKEN_PROGRAM_ID {
+        return Err(TokenPoolError::MissingMintAccount.into());
+    }
+    Ok(())
+}
+
+/// Build `[discriminator, amount (u64 LE), decimals?]` instruction data.
+fn amount_data<const N: usize>(discriminator: u8, amount: u64, decimals: Option<u8>) -> [u8; N] {
+    let mut data = [0u8; N];
+    data[0] = discriminator;
+    data[1..9].copy_from_slice(&amount.to_le_bytes());
+    if let Some(decimals) = decimals {
+        data[9] = decimals;
+    }
+    data
+}
+
+/// Invoke `token_program` with the given metas, data, and accounts.
+fn invoke_token<const N: usize>(
+    token_program: &Pubkey,
+    accounts: &[AccountMeta; N],
+    data: &[u8],
+    account_infos: &[&AccountInfo; N],
+    signers: &[Signer],
+) -> ProgramResult {
+    let instruction = Instruction {
+        program_id: token_program,
+        accounts,
+        data,
+    };
+    invoke_signed(&instruction, account_infos, signers)
+}
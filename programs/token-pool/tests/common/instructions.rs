@@ -8,7 +8,10 @@ use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use solana_sdk::transaction::Transaction;
 
-use super::pda::{SPL_TOKEN_PROGRAM_ID, SYSTEM_PROGRAM_ID, find_token_pool_config_pda, find_vault_pda};
+use super::pda::{
+    ASSOCIATED_TOKEN_PROGRAM_ID, SPL_TOKEN_PROGRAM_ID, SYSTEM_PROGRAM_ID,
+    find_token_pool_config_pda, find_vault_ata,
+};
 
 /// Token pool instruction discriminators
 pub mod discriminators {
@@ -61,7 +64,7 @@ pub fn init_token_pool(
     withdrawal_fee_rate: u16,
 ) -> Result<Pubkey, String> {
     let (pool_config, _) = find_token_pool_config_pda(program_id, mint);
-    let (vault, _) = find_vault_pda(program_id, &pool_config);
+    let vault = find_vault_ata(&pool_config, mint);
 
     let ix = Instruction {
         program_id: *program_id,
@@ -72,6 +75,7 @@ pub fn init_token_pool(
             AccountMeta::new(authority.pubkey(), true),
             AccountMeta::new_readonly(SPL_TOKEN_PROGRAM_ID, false),
             AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
         ],
         data: build_instruction_data(
             discriminators::INIT_POOL,
@@ -233,6 +237,7 @@ pub fn advance_token_epoch(
 #[derive(BorshSerialize)]
 struct FundRewardsArgs {
     amount: u64,
+    vest_duration_slots: u64,
 }
 
 /// Fund the reward pool with external tokens.
@@ -256,7 +261,13 @@ pub fn fund_token_rewards(
             AccountMeta::new_readonly(funder.pubkey(), true),
             AccountMeta::new_readonly(SPL_TOKEN_PROGRAM_ID, false),
         ],
-        data: build_instruction_data(discriminators::FUND_REWARDS, &FundRewardsArgs { amount }),
+        data: build_instruction_data(
+            discriminators::FUND_REWARDS,
+            &FundRewardsArgs {
+                amount,
+                vest_duration_slots: 0,
+            },
+        ),
     };
 
     let tx = Transaction::new_signed_with_payer(
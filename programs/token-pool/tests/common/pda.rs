@@ -8,16 +8,23 @@ use solana_sdk::pubkey::Pubkey;
 
 /// Token pool PDA seeds
 pub const TOKEN_POOL_CONFIG_SEED: &[u8] = b"token_pool";
-pub const VAULT_SEED: &[u8] = b"vault";
 
 /// Derive TokenPoolConfig PDA
 pub fn find_token_pool_config_pda(program_id: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[TOKEN_POOL_CONFIG_SEED, mint.as_ref()], program_id)
 }
 
-/// Derive Vault PDA for token config
-pub fn find_vault_pda(program_id: &Pubkey, token_config: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[VAULT_SEED, token_config.as_ref()], program_id)
+/// Derive the vault created by InitPool: the token config's ATA for `mint`
+pub fn find_vault_ata(token_config: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            token_config.as_ref(),
+            SPL_TOKEN_PROGRAM_ID.as_ref(),
+            mint.as_ref(),
+        ],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
 }
 
 // ============================================================================
@@ -30,3 +37,7 @@ pub const SYSTEM_PROGRAM_ID: Pubkey = solana_sdk::system_program::ID;
 /// SPL Token program ID
 pub const SPL_TOKEN_PROGRAM_ID: Pubkey =
     solana_sdk::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// Associated Token Account program ID
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
@@ -4,9 +4,7 @@ use litesvm::LiteSVM;
 use solana_sdk::pubkey::Pubkey;
 
 /// Token Pool program ID (from centralized zorb-program-ids crate)
-pub const TOKEN_POOL_PROGRAM_ID: Pubkey = Pubkey::new_from_array(
-    five8_const::decode_32_const(zorb_program_ids::TOKEN_POOL_ID),
-);
+pub const TOKEN_POOL_PROGRAM_ID: Pubkey = Pubkey::new_from_array(zorb_program_ids::TOKEN_POOL_PUBKEY);
 
 /// Deploy the token pool program
 pub fn deploy_token_pool_program(svm: &mut LiteSVM) -> Pubkey {
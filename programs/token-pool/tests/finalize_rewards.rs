@@ -39,7 +39,7 @@ fn test_finalize_rewards_updates_accumulator() {
     let pool_config = init_token_pool(&mut svm, &program_id, &mint, &authority, u64::MAX, 0, 0)
         .expect("init_pool should succeed");
 
-    let (vault, _) = find_vault_pda(&program_id, &pool_config);
+    let vault = find_vault_ata(&pool_config, &mint);
 
     // First, we need some balance in the pool via pending_deposits
     // Since we can't call deposit directly without the full transact flow,
@@ -124,7 +124,7 @@ fn test_finalize_rewards_resets_pending_state() {
     let pool_config = init_token_pool(&mut svm, &program_id, &mint, &authority, u64::MAX, 0, 0)
         .expect("init_pool should succeed");
 
-    let (vault, _) = find_vault_pda(&program_id, &pool_config);
+    let vault = find_vault_ata(&pool_config, &mint);
 
     // Create funder token account and fund rewards
     let funder_token =
@@ -271,7 +271,7 @@ fn test_finalize_rewards_multiple_epochs() {
     let pool_config = init_token_pool(&mut svm, &program_id, &mint, &authority, u64::MAX, 0, 0)
         .expect("init_pool should succeed");
 
-    let (vault, _) = find_vault_pda(&program_id, &pool_config);
+    let vault = find_vault_ata(&pool_config, &mint);
     let funder_token =
         create_mock_token_account(&mut svm, &mint, &funder.pubkey(), 10_000_000_000_000);
 
@@ -439,7 +439,7 @@ fn test_finalize_rewards_updates_finalized_balance() {
     let pool_config = init_token_pool(&mut svm, &program_id, &mint, &authority, u64::MAX, 0, 0)
         .expect("init_pool should succeed");
 
-    let (vault, _) = find_vault_pda(&program_id, &pool_config);
+    let vault = find_vault_ata(&pool_config, &mint);
 
     // Fund rewards (which goes into the vault, counted as deposits effectively)
     let funder_token = create_mock_token_account(&mut svm, &mint, &funder.pubkey(), 1_000_000_000);
@@ -493,7 +493,7 @@ fn test_finalize_rewards_accumulator_precision() {
     let pool_config = init_token_pool(&mut svm, &program_id, &mint, &authority, u64::MAX, 0, 0)
         .expect("init_pool should succeed");
 
-    let (vault, _) = find_vault_pda(&program_id, &pool_config);
+    let vault = find_vault_ata(&pool_config, &mint);
     let funder_token =
         create_mock_token_account(&mut svm, &mint, &funder.pubkey(), 10_000_000_000_000);
 
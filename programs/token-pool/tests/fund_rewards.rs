@@ -25,7 +25,7 @@ fn test_fund_rewards_success() {
         .expect("init_pool should succeed");
 
     // Get vault PDA
-    let (vault, _) = find_vault_pda(&program_id, &pool_config);
+    let vault = find_vault_ata(&pool_config, &mint);
 
     // Create funder token account with tokens
     let funder_token = create_mock_token_account(&mut svm, &mint, &funder.pubkey(), 1_000_000_000);
@@ -60,7 +60,7 @@ fn test_fund_rewards_updates_pending() {
     let pool_config = init_token_pool(&mut svm, &program_id, &mint, &authority, u64::MAX, 0, 0)
         .expect("init_pool should succeed");
 
-    let (vault, _) = find_vault_pda(&program_id, &pool_config);
+    let vault = find_vault_ata(&pool_config, &mint);
 
     // Create funder token account with tokens
     let funder_token = create_mock_token_account(&mut svm, &mint, &funder.pubkey(), 1_000_000_000);
@@ -125,7 +125,7 @@ fn test_fund_rewards_zero_amount_fails() {
     let pool_config = init_token_pool(&mut svm, &program_id, &mint, &authority, u64::MAX, 0, 0)
         .expect("init_pool should succeed");
 
-    let (vault, _) = find_vault_pda(&program_id, &pool_config);
+    let vault = find_vault_ata(&pool_config, &mint);
 
     // Create funder token account
     let funder_token = create_mock_token_account(&mut svm, &mint, &funder.pubkey(), 1_000_000_000);
@@ -160,7 +160,7 @@ fn test_fund_rewards_pool_paused_fails() {
     let pool_config = init_token_pool(&mut svm, &program_id, &mint, &authority, u64::MAX, 0, 0)
         .expect("init_pool should succeed");
 
-    let (vault, _) = find_vault_pda(&program_id, &pool_config);
+    let vault = find_vault_ata(&pool_config, &mint);
 
     // Disable the pool
     set_token_pool_active(&mut svm, &program_id, &pool_config, &authority, false)
@@ -203,7 +203,7 @@ fn test_fund_rewards_permissionless() {
     let pool_config = init_token_pool(&mut svm, &program_id, &mint, &authority, u64::MAX, 0, 0)
         .expect("init_pool should succeed");
 
-    let (vault, _) = find_vault_pda(&program_id, &pool_config);
+    let vault = find_vault_ata(&pool_config, &mint);
 
     // Random user (not authority) creates token account and funds rewards
     let funder_token =
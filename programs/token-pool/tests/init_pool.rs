@@ -43,7 +43,7 @@ fn test_init_pool_success() {
     );
 
     // Verify vault was created
-    let (vault, _) = find_vault_pda(&program_id, &pool_config);
+    let vault = find_vault_ata(&pool_config, &mint);
     let vault_account = svm.get_account(&vault).expect("vault should exist");
     assert_eq!(
         vault_account.owner, SPL_TOKEN_PROGRAM_ID,
@@ -2,7 +2,15 @@
 //!
 //! Tests the token-pool program state logic and edge cases.
 
-use token_pool::{TokenPoolConfig, TokenPoolError};
+use token_pool::{
+    TokenPoolConfig, TokenPoolError,
+    state::MAX_FEE_EXEMPT,
+    token::{
+        ACCOUNT_STATE_FROZEN, EXTENSION_DEFAULT_ACCOUNT_STATE, EXTENSION_NON_TRANSFERABLE,
+        EXTENSION_PAUSABLE, EXTENSION_PERMANENT_DELEGATE, EXTENSION_TRANSFER_HOOK,
+        SPL_TOKEN_2022_PROGRAM_ID, SPL_TOKEN_PROGRAM_ID, require_supported_mint_extensions,
+    },
+};
 use zorb_pool_interface::{DepositParams, PoolInstruction, WithdrawParams};
 
 /// Slot interval alias for readable test assertions.
@@ -28,24 +36,67 @@ fn default_config() -> TokenPoolConfig {
         pending_deposit_fees: 0,
         pending_withdrawal_fees: 0,
         pending_funded_rewards: 0,
-        _pad_fees: 0,
+        accrued_protocol_fees: 0,
         total_deposited: 0,
         total_withdrawn: 0,
         total_rewards_distributed: 0,
         total_deposit_fees: 0,
         total_withdrawal_fees: 0,
         total_funded_rewards: 0,
-        _reserved_stats: 0,
+        total_protocol_fees_withdrawn: 0,
         max_deposit_amount: u64::MAX,
         deposit_count: 0,
         withdrawal_count: 0,
         last_finalized_slot: 0,
         deposit_fee_rate: 100, // 1%
         withdrawal_fee_rate: 100,
+        protocol_fee_share_bps: 0,
         decimals: 9,
         is_active: 1,
         bump: 255,
-        _padding: [0u8; 9],
+        paused: 0,
+        allow_withdrawals_when_paused: 0,
+        deficit_detected: 0,
+        _padding: [0u8; 4],
+        treasury: [0u8; 32],
+        token_program: SPL_TOKEN_PROGRAM_ID,
+        total_transfer_fee_shortfall: 0,
+        max_total_pool: 0,
+        max_single_deposit: 0,
+        min_deposit: 0,
+        finalize_interval_slots: 0,
+        vesting_rewards: 0,
+        vesting_start_slot: 0,
+        vesting_end_slot: 0,
+        total_swept: 0,
+        total_excess_returned: 0,
+        max_sweep_per_call: 0,
+        sweep_delay_slots: 0,
+        detected_excess: 0,
+        excess_detected_slot: 0,
+        total_fee_exempt_volume: 0,
+        fee_exempt: [[0u8; 32]; MAX_FEE_EXEMPT],
+        vault_deficit: 0,
+        last_reconciled_slot: 0,
+        total_emergency_withdrawn: 0,
+        emergency_withdraw_destination: [0u8; 32],
+        emergency_withdraw_amount: 0,
+        emergency_withdraw_unlock_slot: 0,
+        emergency_withdraw_delay_slots: TokenPoolConfig::MIN_EMERGENCY_WITHDRAW_DELAY_SLOTS,
+        emergency_withdraw_initiated_slot: 0,
+        vault_generation: 0,
+        pending_vault: [0u8; 32],
+        vault_migration_unlock_slot: 0,
+        epoch_deposit_volume: 0,
+        epoch_withdrawal_volume: 0,
+        epoch_deposit_fees: 0,
+        epoch_withdrawal_fees: 0,
+        min_withdrawal: 0,
+        round_fees_up: 0,
+        _withdrawal_policy_padding: [0u8; 7],
+        previous_reward_accumulator: 0,
+        accumulator_epoch: 0,
+        _accumulator_epoch_padding: [0u8; 8],
     }
 }
 
@@ -76,6 +127,27 @@ fn test_token_pool_config_is_active() {
     assert!(config.is_active());
 }
 
+#[test]
+fn test_pool_pause_checks() {
+    let mut config = default_config();
+    assert!(config.require_deposits_allowed().is_ok());
+    assert!(config.require_withdrawals_allowed().is_ok());
+
+    config.paused = 1;
+    assert_eq!(config.require_deposits_allowed(), Err(TokenPoolError::PoolPaused));
+    assert_eq!(config.require_withdrawals_allowed(), Err(TokenPoolError::PoolPaused));
+
+    // Withdrawal-only mode
+    config.allow_withdrawals_when_paused = 1;
+    assert_eq!(config.require_deposits_allowed(), Err(TokenPoolError::PoolPaused));
+    assert!(config.require_withdrawals_allowed().is_ok());
+
+    // The bit alone does nothing while unpaused
+    config.paused = 0;
+    assert!(config.require_deposits_allowed().is_ok());
+    assert!(config.require_withdrawals_allowed().is_ok());
+}
+
 // =============================================================================
 // current_balance() Tests
 // =============================================================================
@@ -228,6 +300,71 @@ fn test_finalize_rewards_saturating_sub_on_clock_drift() {
     assert!(result.is_err()); // 0 < INTERVAL, so not ready
 }
 
+#[test]
+fn test_rewards_ready_matches_finalize_rewards() {
+    let mut config = default_config();
+    config.last_finalized_slot = 1000;
+    config.finalized_balance = 1_000_000_000;
+
+    // Clock drift saturates to 0 elapsed
+    assert!(!config.rewards_ready(500));
+    assert!(!config.rewards_ready(1000 + INTERVAL - 1));
+    assert!(config.rewards_ready(1000 + INTERVAL));
+
+    // Once finalized, the pool is not ready again until the next interval
+    config.finalize_rewards(1000 + INTERVAL).unwrap();
+    assert!(!config.rewards_ready(1000 + INTERVAL));
+    assert!(config.rewards_ready(1000 + 2 * INTERVAL));
+}
+
+#[test]
+fn test_finalize_interval_zero_uses_default() {
+    let config = default_config();
+    assert_eq!(config.finalize_interval_slots, 0);
+    assert_eq!(config.finalize_interval(), INTERVAL);
+}
+
+#[test]
+fn test_finalize_rewards_custom_interval_honored() {
+    // Hourly compounding: longer than the default
+    let mut config = default_config();
+    config.finalize_interval_slots = 9_000;
+    config.last_finalized_slot = 1000;
+    config.finalized_balance = 1_000_000_000;
+
+    // The default interval is no longer enough
+    assert_eq!(
+        config.finalize_rewards(1000 + INTERVAL),
+        Err(pinocchio::program_error::ProgramError::Custom(
+            TokenPoolError::RewardsNotReady as u32
+        ))
+    );
+    assert_eq!(
+        config.finalize_rewards(1000 + 9_000 - 1),
+        Err(pinocchio::program_error::ProgramError::Custom(
+            TokenPoolError::RewardsNotReady as u32
+        ))
+    );
+
+    assert!(config.finalize_rewards(1000 + 9_000).is_ok());
+    assert_eq!(config.last_finalized_slot, 10_000);
+}
+
+#[test]
+fn test_finalize_rewards_custom_interval_shorter_than_default() {
+    let mut config = default_config();
+    config.finalize_interval_slots = TokenPoolConfig::MIN_FINALIZE_INTERVAL_SLOTS;
+    config.last_finalized_slot = 1000;
+    config.finalized_balance = 1_000_000_000;
+
+    assert!(TokenPoolConfig::MIN_FINALIZE_INTERVAL_SLOTS < INTERVAL);
+    assert!(
+        config
+            .finalize_rewards(1000 + TokenPoolConfig::MIN_FINALIZE_INTERVAL_SLOTS)
+            .is_ok()
+    );
+}
+
 // =============================================================================
 // finalize_rewards() Accumulator Math Tests
 // =============================================================================
@@ -307,6 +444,31 @@ fn test_finalize_rewards_accumulator_adds_to_existing() {
     assert_eq!(config.reward_accumulator, 1_000_000_000_000_000_000 + delta);
 }
 
+#[test]
+fn test_finalize_rewards_advances_accumulator_epoch() {
+    let mut config = default_config();
+    config.last_finalized_slot = 0;
+    config.finalized_balance = 1_000_000_000_000;
+    config.reward_accumulator = 1_000_000_000_000_000_000;
+    config.pending_deposit_fees = 1_000_000_000;
+
+    config.finalize_rewards(INTERVAL).unwrap();
+    assert_eq!(config.accumulator_epoch, 1);
+    assert_eq!(config.previous_reward_accumulator, 1_000_000_000_000_000_000);
+
+    // An epoch with no rewards still advances and shifts the accumulator
+    let after_first = config.reward_accumulator;
+    config.finalize_rewards(INTERVAL * 2).unwrap();
+    assert_eq!(config.accumulator_epoch, 2);
+    assert_eq!(config.previous_reward_accumulator, after_first);
+    assert_eq!(config.reward_accumulator, after_first);
+
+    // A rejected finalization leaves both untouched
+    assert!(config.finalize_rewards(INTERVAL * 2).is_err());
+    assert_eq!(config.accumulator_epoch, 2);
+    assert_eq!(config.previous_reward_accumulator, after_first);
+}
+
 // =============================================================================
 // finalize_rewards() Edge Cases - Zero Values
 // =============================================================================
@@ -492,6 +654,8 @@ fn test_deposit_params_serialization() {
     let params = DepositParams {
         amount: 1_000_000_000,
         expected_output: 990_000_000, // 1% fee
+
+        ..Default::default()
     };
 
     let data = zorb_pool_interface::build_deposit_instruction_data(&params);
@@ -507,6 +671,8 @@ fn test_deposit_params_edge_max_values() {
     let params = DepositParams {
         amount: u64::MAX,
         expected_output: u64::MAX - 1,
+
+        ..Default::default()
     };
 
     let data = zorb_pool_interface::build_deposit_instruction_data(&params);
@@ -520,6 +686,8 @@ fn test_deposit_params_edge_zero() {
     let params = DepositParams {
         amount: 0,
         expected_output: 0,
+
+        ..Default::default()
     };
 
     let data = zorb_pool_interface::build_deposit_instruction_data(&params);
@@ -533,6 +701,8 @@ fn test_withdraw_params_serialization() {
     let params = WithdrawParams {
         amount: 1_000_000_000,
         expected_output: 990_000_000,
+
+        ..Default::default()
     };
 
     let data = zorb_pool_interface::build_withdraw_instruction_data(&params);
@@ -617,6 +787,867 @@ fn test_finalization_with_withdrawals_between() {
     assert_eq!(config.finalized_balance, 800_000_000_000);
 }
 
+// =============================================================================
+// Protocol Fee Tests
+// =============================================================================
+
+#[test]
+fn test_protocol_fee_accrues_across_deposits_and_withdrawals() {
+    let mut config = default_config();
+    config.protocol_fee_share_bps = 2_000; // 20%
+
+    assert_eq!(config.record_deposit_fee(1_000).unwrap(), 200);
+    assert_eq!(config.record_withdrawal_fee(500).unwrap(), 100);
+    assert_eq!(config.record_deposit_fee(250).unwrap(), 50);
+
+    assert_eq!(config.accrued_protocol_fees, 350);
+    assert_eq!(config.pending_deposit_fees, 800 + 200);
+    assert_eq!(config.pending_withdrawal_fees, 400);
+
+    // Lifetime totals still count the whole fee (it all entered the vault)
+    assert_eq!(config.total_deposit_fees, 1_250);
+    assert_eq!(config.total_withdrawal_fees, 500);
+}
+
+#[test]
+fn test_protocol_fee_share_rounds_down() {
+    let mut config = default_config();
+    config.protocol_fee_share_bps = 3_333;
+
+    // 7 * 3333 / 10000 = 2.33 -> 2, depositors keep the remainder
+    assert_eq!(config.split_fee(7).unwrap(), (2, 5));
+
+    config.protocol_fee_share_bps = 0;
+    assert_eq!(config.split_fee(u64::MAX).unwrap(), (0, u64::MAX));
+
+    config.protocol_fee_share_bps = 10_000;
+    assert_eq!(config.split_fee(u64::MAX).unwrap(), (u64::MAX, 0));
+}
+
+#[test]
+fn test_protocol_fee_accrual_overflow() {
+    let mut config = default_config();
+    config.protocol_fee_share_bps = 10_000;
+    config.accrued_protocol_fees = u64::MAX;
+
+    let result = config.record_deposit_fee(1);
+    assert_eq!(result, Err(TokenPoolError::ArithmeticOverflow));
+}
+
+#[test]
+fn test_withdraw_protocol_fees_partial_then_over() {
+    let mut config = default_config();
+    config.accrued_protocol_fees = 1_000;
+
+    config.withdraw_protocol_fees(400).unwrap();
+    assert_eq!(config.accrued_protocol_fees, 600);
+    assert_eq!(config.total_protocol_fees_withdrawn, 400);
+
+    // Over-withdrawal leaves state unchanged
+    let result = config.withdraw_protocol_fees(601);
+    assert_eq!(result, Err(TokenPoolError::InsufficientProtocolFees));
+    assert_eq!(config.accrued_protocol_fees, 600);
+    assert_eq!(config.total_protocol_fees_withdrawn, 400);
+
+    config.withdraw_protocol_fees(600).unwrap();
+    assert_eq!(config.accrued_protocol_fees, 0);
+    assert_eq!(config.total_protocol_fees_withdrawn, 1_000);
+}
+
+#[test]
+fn test_finalize_rewards_excludes_protocol_share() {
+    let mut config = default_config();
+    config.finalized_balance = 1_000_000_000_000; // 1000 tokens
+    config.protocol_fee_share_bps = 2_500; // 25%
+
+    config.record_deposit_fee(4_000_000_000).unwrap(); // 4 tokens
+    config.record_withdrawal_fee(4_000_000_000).unwrap(); // 4 tokens
+    assert_eq!(config.accrued_protocol_fees, 2_000_000_000);
+
+    config.finalize_rewards(INTERVAL).unwrap();
+
+    // Only the 6 depositor tokens reach the accumulator
+    let distributed = 6_000_000_000u128;
+    let expected_delta = distributed * TokenPoolConfig::ACCUMULATOR_PRECISION / 1_000_000_000_000;
+    assert_eq!(config.reward_accumulator, expected_delta);
+    assert_eq!(config.total_rewards_distributed, distributed);
+    assert_eq!(config.accrued_protocol_fees, 2_000_000_000);
+}
+
+// =============================================================================
+// Reward Vesting Tests
+// =============================================================================
+
+/// Finalize at each slot and return the cumulative vested amount released.
+fn finalize_at_slots(config: &mut TokenPoolConfig, slots: &[u64], funded: u64) -> u64 {
+    let mut released = 0u64;
+    for &slot in slots {
+        released += config.finalize_rewards(slot).unwrap();
+        assert!(released <= funded, "released {released} > funded {funded}");
+        assert_eq!(config.vesting_rewards, funded - released);
+    }
+    released
+}
+
+#[test]
+fn test_vesting_zero_duration_is_immediate() {
+    let mut config = default_config();
+    config.add_vesting_rewards(1_000, 0, 500).unwrap();
+    assert_eq!(config.pending_funded_rewards, 1_000);
+    assert_eq!(config.vesting_rewards, 0);
+}
+
+#[test]
+fn test_vesting_releases_linearly_across_finalizations() {
+    let mut config = default_config();
+    config.finalized_balance = 1_000_000_000;
+    config.add_vesting_rewards(1_000_000, 10 * INTERVAL, 0).unwrap();
+    assert_eq!(config.pending_funded_rewards, 0);
+    assert_eq!(config.vesting_end_slot, 10 * INTERVAL);
+
+    // Each interval releases a tenth, distributed in the same finalization
+    let released = config.finalize_rewards(INTERVAL).unwrap();
+    assert_eq!(released, 100_000);
+    assert_eq!(config.total_rewards_distributed, 100_000);
+    assert_eq!(config.pending_funded_rewards, 0);
+
+    let slots: Vec<u64> = (2..=10).map(|i| i * INTERVAL).collect();
+    let rest = finalize_at_slots(&mut config, &slots, 900_000);
+    assert_eq!(rest, 900_000);
+    assert_eq!(config.total_rewards_distributed, 1_000_000);
+}
+
+#[test]
+fn test_vesting_irregular_finalizations_fully_release() {
+    // Amount not divisible by the duration; finalizations at uneven slots
+    let mut config = default_config();
+    config.finalized_balance = 1_000_000_000;
+    config.add_vesting_rewards(1_000_003, 7 * INTERVAL, 100).unwrap();
+
+    let slots = [
+        100 + INTERVAL,
+        100 + 2 * INTERVAL + 17,
+        100 + 4 * INTERVAL + 3,
+        100 + 5 * INTERVAL + 999,
+        100 + 6 * INTERVAL + 1_001,
+    ];
+    let released = finalize_at_slots(&mut config, &slots, 1_000_003);
+    assert!(released < 1_000_003);
+
+    // The remainder carried through truncation is released at the end
+    let released = released + config.finalize_rewards(100 + 8 * INTERVAL).unwrap();
+    assert_eq!(released, 1_000_003);
+    assert_eq!(config.vesting_rewards, 0);
+    assert_eq!(config.total_rewards_distributed, 1_000_003);
+}
+
+#[test]
+fn test_vesting_remainder_carried() {
+    let mut config = default_config();
+    config.add_vesting_rewards(10, 3, 0).unwrap();
+
+    assert_eq!(config.release_vested_rewards(1), Ok(3));
+    assert_eq!(config.release_vested_rewards(2), Ok(3));
+    assert_eq!(config.release_vested_rewards(3), Ok(4));
+    assert_eq!(config.release_vested_rewards(4), Ok(0));
+    assert_eq!(config.pending_funded_rewards, 10);
+}
+
+#[test]
+fn test_vesting_concurrent_fundings_aggregate_weighted() {
+    let mut config = default_config();
+    config.add_vesting_rewards(1_000, 1_000, 0).unwrap();
+
+    // Halfway through, fund 1_000 more over 2_000 slots
+    config.add_vesting_rewards(1_000, 2_000, 500).unwrap();
+    assert_eq!(config.pending_funded_rewards, 500);
+    assert_eq!(config.vesting_rewards, 1_500);
+    // (500 * 500 + 1_000 * 2_000) / 1_500 = 1_500 slots from slot 500
+    assert_eq!(config.vesting_start_slot, 500);
+    assert_eq!(config.vesting_end_slot, 2_000);
+
+    assert_eq!(config.release_vested_rewards(1_250), Ok(750));
+    assert_eq!(config.release_vested_rewards(2_000), Ok(750));
+    assert_eq!(config.pending_funded_rewards, 2_000);
+}
+
+#[test]
+fn test_vesting_weighted_end_rounds_up() {
+    let mut config = default_config();
+    config.add_vesting_rewards(2, 1, 0).unwrap();
+    config.add_vesting_rewards(1, 2, 0).unwrap();
+    // (2 * 1 + 1 * 2) / 3 = 1.33 -> 2 slots
+    assert_eq!(config.vesting_end_slot, 2);
+}
+
+#[test]
+fn test_vesting_not_released_before_interval() {
+    let mut config = default_config();
+    config.last_finalized_slot = 1_000;
+    config.add_vesting_rewards(1_000, INTERVAL, 1_000).unwrap();
+
+    assert!(config.finalize_rewards(1_000 + INTERVAL - 1).is_err());
+    assert_eq!(config.vesting_rewards, 1_000);
+    assert_eq!(config.pending_funded_rewards, 0);
+}
+
+// =============================================================================
+// Sweep Limit Tests
+// =============================================================================
+
+#[test]
+fn test_expected_vault_balance() {
+    let mut config = default_config();
+    config.total_deposited = 1_000;
+    config.total_withdrawn = 400;
+    config.total_deposit_fees = 10;
+    config.total_withdrawal_fees = 4;
+    config.total_funded_rewards = 50;
+    config.total_protocol_fees_withdrawn = 7;
+    assert_eq!(config.expected_vault_balance().unwrap(), 657);
+}
+
+#[test]
+fn test_sweep_excess_without_limits_is_immediate() {
+    let mut config = default_config();
+    assert_eq!(config.sweep_excess(1_000, 10).unwrap(), 1_000);
+    assert_eq!(config.pending_funded_rewards, 1_000);
+    assert_eq!(config.total_funded_rewards, 1_000);
+    assert_eq!(config.total_swept, 1_000);
+    assert_eq!(config.detected_excess, 0);
+
+    // Nothing left to sweep once accounted for
+    assert_eq!(config.sweep_excess(0, 11).unwrap(), 0);
+    assert_eq!(config.total_swept, 1_000);
+}
+
+#[test]
+fn test_sweep_excess_per_call_cap() {
+    let mut config = default_config();
+    config.max_sweep_per_call = 300;
+
+    // Each call sweeps at most the cap; the vault excess shrinks as it is swept
+    let mut excess = 1_000;
+    for expected in [300, 300, 300, 100] {
+        let swept = config.sweep_excess(excess, 10).unwrap();
+        assert_eq!(swept, expected);
+        excess -= swept;
+    }
+    assert_eq!(config.sweep_excess(excess, 10).unwrap(), 0);
+    assert_eq!(config.total_swept, 1_000);
+    assert_eq!(config.pending_funded_rewards, 1_000);
+}
+
+#[test]
+fn test_sweep_excess_waits_for_delay() {
+    let mut config = default_config();
+    config.sweep_delay_slots = 100;
+
+    // First sighting only records the excess
+    assert_eq!(config.sweep_excess(1_000, 50).unwrap(), 0);
+    assert_eq!(config.detected_excess, 1_000);
+    assert_eq!(config.excess_detected_slot, 50);
+
+    assert_eq!(config.sweep_excess(1_000, 149).unwrap(), 0);
+    assert_eq!(config.pending_funded_rewards, 0);
+
+    assert_eq!(config.sweep_excess(1_000, 150).unwrap(), 1_000);
+    assert_eq!(config.pending_funded_rewards, 1_000);
+    assert_eq!(config.detected_excess, 0);
+}
+
+#[test]
+fn test_sweep_excess_late_arrival_waits_its_own_delay() {
+    let mut config = default_config();
+    config.sweep_delay_slots = 100;
+    config.sweep_excess(1_000, 0).unwrap();
+
+    // 500 more arrives while the first 1_000 waits; only the first is swept
+    assert_eq!(config.sweep_excess(1_500, 100).unwrap(), 1_000);
+
+    // The late 500 is detected now and waits a full delay
+    assert_eq!(config.sweep_excess(500, 101).unwrap(), 0);
+    assert_eq!(config.excess_detected_slot, 101);
+    assert_eq!(config.sweep_excess(500, 201).unwrap(), 500);
+    assert_eq!(config.total_swept, 1_500);
+}
+
+#[test]
+fn test_return_excess_reduces_sweepable_excess() {
+    let mut config = default_config();
+    config.sweep_delay_slots = 100;
+    config.sweep_excess(1_000, 0).unwrap();
+
+    config.return_excess(600, 1_000).unwrap();
+    assert_eq!(config.detected_excess, 400);
+    assert_eq!(config.total_excess_returned, 600);
+
+    // Only what is left in the vault gets swept after the delay
+    assert_eq!(config.sweep_excess(400, 100).unwrap(), 400);
+    assert_eq!(config.pending_funded_rewards, 400);
+
+    // Returning everything clears the detection
+    config.sweep_excess(200, 200).unwrap();
+    config.return_excess(200, 200).unwrap();
+    assert_eq!(config.detected_excess, 0);
+    assert_eq!(config.sweep_excess(0, 400).unwrap(), 0);
+    assert_eq!(config.total_swept, 400);
+}
+
+#[test]
+fn test_return_excess_cannot_exceed_excess() {
+    let mut config = default_config();
+    assert_eq!(
+        config.return_excess(101, 100),
+        Err(TokenPoolError::InsufficientExcess)
+    );
+    assert_eq!(config.total_excess_returned, 0);
+}
+
+// =============================================================================
+// Pool Closure Tests
+// =============================================================================
+
+#[test]
+fn test_require_empty_checks_each_balance() {
+    assert_eq!(default_config().require_empty(), Ok(()));
+
+    let cases: [fn(&mut TokenPoolConfig); 7] = [
+        |c| c.pending_deposits = 1,
+        |c| c.pending_withdrawals = 1,
+        |c| c.pending_deposit_fees = 1,
+        |c| c.pending_withdrawal_fees = 1,
+        |c| c.pending_funded_rewards = 1,
+        |c| c.accrued_protocol_fees = 1,
+        |c| c.vesting_rewards = 1,
+    ];
+    for set_nonzero in cases {
+        let mut config = default_config();
+        set_nonzero(&mut config);
+        assert_eq!(config.require_empty(), Err(TokenPoolError::PoolNotEmpty));
+    }
+
+    // Lifetime totals and the finalized balance do not block closure
+    let mut config = default_config();
+    config.finalized_balance = 1_000;
+    config.total_deposited = 1_000;
+    config.total_withdrawn = 1_000;
+    assert_eq!(config.require_empty(), Ok(()));
+}
+
+// =============================================================================
+// Pool Limit Tests
+// =============================================================================
+
+#[test]
+fn test_deposit_limits_zero_is_unlimited() {
+    let mut config = default_config();
+    config.finalized_balance = u64::MAX as u128;
+    assert_eq!(config.require_deposit_within_limits(1, 1), Ok(()));
+    assert_eq!(
+        config.require_deposit_within_limits(u64::MAX, u64::MAX),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_deposit_limits_min_deposit_boundary() {
+    let mut config = default_config();
+    config.min_deposit = 1_000;
+    assert_eq!(config.require_deposit_within_limits(1_000, 990), Ok(()));
+    assert_eq!(
+        config.require_deposit_within_limits(999, 989),
+        Err(TokenPoolError::DepositBelowMinimum)
+    );
+}
+
+#[test]
+fn test_deposit_limits_single_cap_boundary() {
+    let mut config = default_config();
+    config.max_single_deposit = 1_000_000;
+    assert_eq!(
+        config.require_deposit_within_limits(1_000_000, 990_000),
+        Ok(())
+    );
+    assert_eq!(
+        config.require_deposit_within_limits(1_000_001, 990_001),
+        Err(TokenPoolError::SingleDepositCapExceeded)
+    );
+}
+
+#[test]
+fn test_deposit_limits_total_pool_cap_boundary() {
+    // Accounted balance: 600 finalized + 300 pending in - 100 pending out = 800
+    let mut config = default_config();
+    config.finalized_balance = 600;
+    config.pending_deposits = 300;
+    config.pending_withdrawals = 100;
+    config.max_total_pool = 1_000;
+
+    // The cap applies to the principal, not the gross amount
+    assert_eq!(config.require_deposit_within_limits(250, 200), Ok(()));
+    assert_eq!(
+        config.require_deposit_within_limits(250, 201),
+        Err(TokenPoolError::PoolCapExceeded)
+    );
+}
+
+// =============================================================================
+// Token-2022 Tests
+// =============================================================================
+
+/// Build one `[type][length][value]` Token-2022 extension entry.
+fn tlv_entry(extension: u16, length: u16) -> Vec<u8> {
+    let mut entry = Vec::new();
+    entry.extend_from_slice(&extension.to_le_bytes());
+    entry.extend_from_slice(&length.to_le_bytes());
+    entry.extend(std::iter::repeat_n(0u8, length as usize));
+    entry
+}
+
+#[test]
+fn test_require_token_program() {
+    let config = default_config();
+    assert_eq!(config.require_token_program(&SPL_TOKEN_PROGRAM_ID), Ok(()));
+    assert_eq!(
+        config.require_token_program(&SPL_TOKEN_2022_PROGRAM_ID),
+        Err(TokenPoolError::InvalidTokenProgram)
+    );
+}
+
+#[test]
+fn test_supported_mint_extensions() {
+    // No extensions, zero padding, and TransferFeeConfig (1) + MetadataPointer (18)
+    assert_eq!(require_supported_mint_extensions(&[]), Ok(()));
+    assert_eq!(require_supported_mint_extensions(&[0u8; 8]), Ok(()));
+
+    let mut tlv = tlv_entry(1, 108);
+    tlv.extend(tlv_entry(18, 64));
+    assert_eq!(require_supported_mint_extensions(&tlv), Ok(()));
+}
+
+#[test]
+fn test_unsupported_mint_extensions_rejected() {
+    for extension in [EXTENSION_TRANSFER_HOOK, EXTENSION_PAUSABLE] {
+        // Rejected whether first or after a supported entry
+        assert_eq!(
+            require_supported_mint_extensions(&tlv_entry(extension, 64)),
+            Err(TokenPoolError::UnsupportedMintExtension)
+        );
+
+        let mut tlv = tlv_entry(1, 108);
+        tlv.extend(tlv_entry(extension, 64));
+        assert_eq!(
+            require_supported_mint_extensions(&tlv),
+            Err(TokenPoolError::UnsupportedMintExtension)
+        );
+    }
+}
+
+#[test]
+fn test_permanent_delegate_mint_rejected() {
+    let mut tlv = tlv_entry(1, 108);
+    tlv.extend(tlv_entry(EXTENSION_PERMANENT_DELEGATE, 32));
+    assert_eq!(
+        require_supported_mint_extensions(&tlv),
+        Err(TokenPoolError::UnsupportedMintExtension)
+    );
+}
+
+#[test]
+fn test_non_transferable_mint_rejected() {
+    let mut tlv = tlv_entry(1, 108);
+    tlv.extend(tlv_entry(EXTENSION_NON_TRANSFERABLE, 0));
+    assert_eq!(
+        require_supported_mint_extensions(&tlv),
+        Err(TokenPoolError::UnsupportedMintExtension)
+    );
+}
+
+#[test]
+fn test_frozen_default_account_state_mint_rejected() {
+    // Initialized (1) is fine, Frozen (2) is rejected
+    let mut tlv = tlv_entry(EXTENSION_DEFAULT_ACCOUNT_STATE, 1);
+    tlv[4] = 1;
+    assert_eq!(require_supported_mint_extensions(&tlv), Ok(()));
+
+    tlv[4] = ACCOUNT_STATE_FROZEN;
+    assert_eq!(
+        require_supported_mint_extensions(&tlv),
+        Err(TokenPoolError::UnsupportedMintExtension)
+    );
+}
+
+#[test]
+fn test_truncated_mint_extension_rejected() {
+    let mut tlv = tlv_entry(1, 108);
+    tlv.truncate(50);
+    assert_eq!(
+        require_supported_mint_extensions(&tlv),
+        Err(TokenPoolError::InvalidMint)
+    );
+}
+
+// =============================================================================
+// Fee Exemption Tests
+// =============================================================================
+
+#[test]
+fn test_add_and_remove_fee_exempt() {
+    let mut config = default_config();
+    let rebalancer = [7u8; 32];
+    assert!(!config.is_fee_exempt(&rebalancer));
+
+    config.add_fee_exempt(&rebalancer).unwrap();
+    assert!(config.is_fee_exempt(&rebalancer));
+
+    // Adding twice is a no-op and does not use another slot
+    config.add_fee_exempt(&rebalancer).unwrap();
+    assert_eq!(
+        config
+            .fee_exempt
+            .iter()
+            .filter(|key| **key == rebalancer)
+            .count(),
+        1
+    );
+
+    config.remove_fee_exempt(&rebalancer).unwrap();
+    assert!(!config.is_fee_exempt(&rebalancer));
+    assert_eq!(
+        config.remove_fee_exempt(&rebalancer),
+        Err(TokenPoolError::FeeExemptNotListed)
+    );
+}
+
+#[test]
+fn test_fee_exempt_list_full() {
+    let mut config = default_config();
+    for i in 0..MAX_FEE_EXEMPT as u8 {
+        config.add_fee_exempt(&[i + 1; 32]).unwrap();
+    }
+    assert_eq!(
+        config.add_fee_exempt(&[0xff; 32]),
+        Err(TokenPoolError::FeeExemptListFull)
+    );
+
+    // Removing one frees a slot
+    config.remove_fee_exempt(&[1; 32]).unwrap();
+    config.add_fee_exempt(&[0xff; 32]).unwrap();
+    assert!(config.is_fee_exempt(&[0xff; 32]));
+}
+
+#[test]
+fn test_zero_key_never_fee_exempt() {
+    let mut config = default_config();
+    // Empty slots hold the zero key, which must not count as listed
+    assert!(!config.is_fee_exempt(&[0u8; 32]));
+    assert_eq!(
+        config.add_fee_exempt(&[0u8; 32]),
+        Err(TokenPoolError::InvalidInstructionData)
+    );
+}
+
+#[test]
+fn test_require_fee_exemption() {
+    let mut config = default_config();
+    let rebalancer = [7u8; 32];
+    let user = [8u8; 32];
+    config.add_fee_exempt(&rebalancer).unwrap();
+
+    // Hub and pool agree
+    assert_eq!(config.require_fee_exemption(&rebalancer, true), Ok(true));
+    assert_eq!(config.require_fee_exemption(&user, false), Ok(false));
+
+    // Mismatch in either direction is rejected
+    assert_eq!(
+        config.require_fee_exemption(&rebalancer, false),
+        Err(TokenPoolError::FeeExemptionMismatch)
+    );
+    assert_eq!(
+        config.require_fee_exemption(&user, true),
+        Err(TokenPoolError::FeeExemptionMismatch)
+    );
+}
+
+#[test]
+fn test_record_fee_exempt_volume() {
+    let mut config = default_config();
+    config.record_fee_exempt_volume(1_000).unwrap();
+    config.record_fee_exempt_volume(u64::MAX).unwrap();
+    assert_eq!(config.total_fee_exempt_volume, 1_000 + u64::MAX as u128);
+}
+
+#[test]
+fn test_fee_exempt_params_roundtrip() {
+    let params = DepositParams {
+        amount: 1_000_000,
+        expected_output: 1_000_000,
+        depositor: [7u8; 32],
+        expect_fee_exempt: 1,
+        ..Default::default()
+    };
+
+    let data = zorb_pool_interface::build_deposit_instruction_data(&params);
+    let parsed = zorb_pool_interface::parse_deposit_params(&data).unwrap();
+    assert_eq!(parsed.depositor, [7u8; 32]);
+    assert!(parsed.expects_fee_exempt());
+}
+// =============================================================================
+// Vault Reconciliation Tests
+// =============================================================================
+
+/// Config whose cumulative stats imply a vault balance of 1_000.
+fn reconcilable_config() -> TokenPoolConfig {
+    let mut config = default_config();
+    config.total_deposited = 900;
+    config.total_deposit_fees = 100;
+    config
+}
+
+#[test]
+fn test_reconcile_vault_balanced() {
+    let mut config = reconcilable_config();
+    assert_eq!(config.reconcile_vault(1_000, 50), Ok((1_000, 0)));
+    assert_eq!(config.deficit_detected, 0);
+    assert_eq!(config.detected_excess, 0);
+    assert_eq!(config.last_reconciled_slot, 50);
+    assert_eq!(config.require_deposits_allowed(), Ok(()));
+}
+
+#[test]
+fn test_reconcile_vault_surplus_detected_for_sweep() {
+    let mut config = reconcilable_config();
+    config.sweep_delay_slots = 100;
+    assert_eq!(config.reconcile_vault(1_250, 50), Ok((1_000, 250)));
+    assert_eq!(config.detected_excess, 250);
+    assert_eq!(config.excess_detected_slot, 50);
+    assert_eq!(config.deficit_detected, 0);
+
+    // The sweep delay runs from the reconciliation, not a later sweep
+    assert_eq!(config.sweep_excess(250, 149), Ok(0));
+    assert_eq!(config.sweep_excess(250, 150), Ok(250));
+}
+
+#[test]
+fn test_reconcile_vault_deficit_blocks_deposits() {
+    let mut config = reconcilable_config();
+    assert_eq!(config.reconcile_vault(990, 50), Ok((1_000, -10)));
+    assert_eq!(config.deficit_detected, 1);
+    assert_eq!(config.vault_deficit, 10);
+    assert_eq!(
+        config.require_deposits_allowed(),
+        Err(TokenPoolError::VaultDeficitDetected)
+    );
+    assert_eq!(config.require_withdrawals_allowed(), Ok(()));
+
+    // A later balanced reconciliation does not clear the flag by itself
+    assert_eq!(config.reconcile_vault(1_000, 60), Ok((1_000, 0)));
+    assert_eq!(config.deficit_detected, 1);
+
+    config.clear_vault_deficit();
+    assert_eq!(config.vault_deficit, 0);
+    assert_eq!(config.require_deposits_allowed(), Ok(()));
+}
+// =============================================================================
+// Emergency Withdrawal Tests
+// =============================================================================
+
+const DELAY: u64 = TokenPoolConfig::MIN_EMERGENCY_WITHDRAW_DELAY_SLOTS;
+
+/// Config with 1_000 accounted (and 1_100 expected in the vault, with fees).
+fn emergency_config() -> TokenPoolConfig {
+    let mut config = reconcilable_config();
+    config.total_deposited = 1_000;
+    config.pending_deposits = 1_000;
+    config
+}
+
+#[test]
+fn test_emergency_withdraw_lifecycle() {
+    let mut config = emergency_config();
+    let destination = [7u8; 32];
+
+    assert_eq!(
+        config.initiate_emergency_withdraw(400, &destination, 100),
+        Ok(100 + DELAY)
+    );
+    assert!(config.has_pending_emergency_withdraw());
+    assert_eq!(config.emergency_withdraw_destination, destination);
+    assert_eq!(config.emergency_withdraw_initiated_slot, 100);
+
+    // Only one request at a time
+    assert_eq!(
+        config.initiate_emergency_withdraw(1, &destination, 101),
+        Err(TokenPoolError::EmergencyWithdrawPending)
+    );
+
+    // Early execution is rejected and leaves the request in place
+    assert_eq!(
+        config.execute_emergency_withdraw(100 + DELAY - 1),
+        Err(TokenPoolError::EmergencyWithdrawLocked)
+    );
+    assert!(config.has_pending_emergency_withdraw());
+
+    let expected_before = config.expected_vault_balance().unwrap();
+    assert_eq!(
+        config.execute_emergency_withdraw(100 + DELAY),
+        Ok((400, destination))
+    );
+    assert!(!config.has_pending_emergency_withdraw());
+    assert_eq!(config.emergency_withdraw_destination, [0u8; 32]);
+    assert_eq!(config.emergency_withdraw_unlock_slot, 0);
+
+    // Debited from the accounted balance and the expected vault balance
+    assert_eq!(config.total_emergency_withdrawn, 400);
+    assert_eq!(config.pending_withdrawals, 400);
+    assert_eq!(config.current_balance(), Ok(600));
+    assert_eq!(config.expected_vault_balance(), Ok(expected_before - 400));
+
+    // Nothing left to execute
+    assert_eq!(
+        config.execute_emergency_withdraw(100 + DELAY),
+        Err(TokenPoolError::NoEmergencyWithdrawPending)
+    );
+}
+
+#[test]
+fn test_emergency_withdraw_cancel() {
+    let mut config = emergency_config();
+    assert_eq!(
+        config.cancel_emergency_withdraw(),
+        Err(TokenPoolError::NoEmergencyWithdrawPending)
+    );
+
+    config
+        .initiate_emergency_withdraw(400, &[7u8; 32], 100)
+        .unwrap();
+    assert_eq!(config.cancel_emergency_withdraw(), Ok(400));
+    assert!(!config.has_pending_emergency_withdraw());
+    assert_eq!(
+        config.execute_emergency_withdraw(100 + DELAY),
+        Err(TokenPoolError::NoEmergencyWithdrawPending)
+    );
+
+    // Accounting untouched
+    assert_eq!(config.total_emergency_withdrawn, 0);
+    assert_eq!(config.current_balance(), Ok(1_000));
+
+    // A new request can follow
+    assert!(
+        config
+            .initiate_emergency_withdraw(400, &[7u8; 32], 200)
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_emergency_withdraw_delay_floor() {
+    let mut config = emergency_config();
+
+    // An unset delay still waits out the floor
+    config.emergency_withdraw_delay_slots = 0;
+    assert_eq!(config.emergency_withdraw_delay(), DELAY);
+
+    config.emergency_withdraw_delay_slots = DELAY * 2;
+    assert_eq!(
+        config.initiate_emergency_withdraw(400, &[7u8; 32], 100),
+        Ok(100 + DELAY * 2)
+    );
+
+    // Shortening the delay does not move a pending unlock slot
+    config.emergency_withdraw_delay_slots = DELAY;
+    assert_eq!(
+        config.execute_emergency_withdraw(100 + DELAY),
+        Err(TokenPoolError::EmergencyWithdrawLocked)
+    );
+    assert!(config.execute_emergency_withdraw(100 + DELAY * 2).is_ok());
+}
+
+#[test]
+fn test_emergency_withdraw_bounded_by_balance() {
+    let mut config = emergency_config();
+    assert_eq!(
+        config.initiate_emergency_withdraw(1_001, &[7u8; 32], 100),
+        Err(TokenPoolError::InsufficientBalance)
+    );
+    assert_eq!(
+        config.initiate_emergency_withdraw(0, &[7u8; 32], 100),
+        Err(TokenPoolError::InvalidAmount)
+    );
+    assert_eq!(
+        config.initiate_emergency_withdraw(400, &[0u8; 32], 100),
+        Err(TokenPoolError::InvalidEmergencyWithdrawDestination)
+    );
+
+    // Withdrawals during the delay shrink the balance below the request
+    config
+        .initiate_emergency_withdraw(800, &[7u8; 32], 100)
+        .unwrap();
+    config.pending_withdrawals = 300;
+    assert_eq!(
+        config.execute_emergency_withdraw(100 + DELAY),
+        Err(TokenPoolError::InsufficientBalance)
+    );
+}
+
+#[test]
+fn test_emergency_withdraw_pending_blocks_close() {
+    let mut config = default_config();
+    config.pending_deposits = 1_000;
+    config
+        .initiate_emergency_withdraw(1_000, &[7u8; 32], 100)
+        .unwrap();
+    config.pending_deposits = 0;
+    assert_eq!(config.require_empty(), Err(TokenPoolError::PoolNotEmpty));
+
+    config.cancel_emergency_withdraw().unwrap();
+    assert_eq!(config.require_empty(), Ok(()));
+}
+// =============================================================================
+// Vault Migration Tests
+// =============================================================================
+
+#[test]
+fn test_vault_migration_state() {
+    let mut config = default_config();
+    let old_vault = [1u8; 32];
+    let new_vault = [2u8; 32];
+    config.vault = old_vault;
+
+    assert_eq!(config.require_vault(&old_vault), Ok(()));
+    assert_eq!(
+        config.execute_vault_migration(DELAY),
+        Err(TokenPoolError::NoVaultMigrationPending)
+    );
+
+    assert_eq!(
+        config.initiate_vault_migration(&new_vault, 100),
+        Ok(100 + DELAY)
+    );
+    assert_eq!(
+        config.initiate_vault_migration(&[3u8; 32], 101),
+        Err(TokenPoolError::VaultMigrationPending)
+    );
+    assert_eq!(
+        config.execute_vault_migration(100 + DELAY - 1),
+        Err(TokenPoolError::VaultMigrationLocked)
+    );
+    assert_eq!(config.vault, old_vault);
+
+    assert_eq!(config.execute_vault_migration(100 + DELAY), Ok(old_vault));
+    assert_eq!(config.vault, new_vault);
+    assert_eq!(config.vault_generation, 1);
+    assert!(!config.has_pending_vault_migration());
+
+    // The replaced vault is no longer accepted
+    assert_eq!(
+        config.require_vault(&old_vault),
+        Err(TokenPoolError::InvalidVault)
+    );
+    assert_eq!(config.require_vault(&new_vault), Ok(()));
+}
+
 // =============================================================================
 // Documentation Example Verification
 // =============================================================================
@@ -647,3 +1678,85 @@ fn test_docstring_example() {
     assert!(config.reward_accumulator > 38_000_000_000_000_000);
     assert!(config.reward_accumulator < 39_000_000_000_000_000);
 }
+// =============================================================================
+// Epoch Statistics Tests
+// =============================================================================
+
+#[test]
+fn test_epoch_stats_reset_on_finalize() {
+    let mut config = default_config();
+
+    // Epoch 1
+    config.record_epoch_deposit(1_000, 10);
+    config.record_epoch_deposit(500, 5);
+    config.record_epoch_withdrawal(300, 3);
+    assert_eq!(config.epoch_deposit_volume, 1_500);
+    assert_eq!(config.epoch_deposit_fees, 15);
+    assert_eq!(config.epoch_withdrawal_volume, 300);
+    assert_eq!(config.epoch_withdrawal_fees, 3);
+
+    config.finalize_rewards(INTERVAL).unwrap();
+    assert_eq!(config.epoch_deposit_volume, 0);
+    assert_eq!(config.epoch_withdrawal_volume, 0);
+    assert_eq!(config.epoch_deposit_fees, 0);
+    assert_eq!(config.epoch_withdrawal_fees, 0);
+
+    // Epoch 2 starts from zero
+    config.record_epoch_withdrawal(200, 2);
+    assert_eq!(config.epoch_deposit_volume, 0);
+    assert_eq!(config.epoch_withdrawal_volume, 200);
+    assert_eq!(config.epoch_withdrawal_fees, 2);
+
+    config.finalize_rewards(2 * INTERVAL).unwrap();
+    assert_eq!(config.epoch_withdrawal_volume, 0);
+    assert_eq!(config.epoch_withdrawal_fees, 0);
+}
+
+#[test]
+fn test_epoch_stats_saturate() {
+    let mut config = default_config();
+    config.epoch_deposit_volume = u64::MAX - 1;
+    config.epoch_withdrawal_fees = u64::MAX;
+
+    config.record_epoch_deposit(10, 0);
+    config.record_epoch_withdrawal(0, 1);
+    assert_eq!(config.epoch_deposit_volume, u64::MAX);
+    assert_eq!(config.epoch_withdrawal_fees, u64::MAX);
+}
+// =============================================================================
+// Withdrawal Policy Tests
+// =============================================================================
+
+#[test]
+fn test_min_withdrawal_boundary() {
+    let mut config = default_config();
+
+    // Unset: any amount passes
+    assert_eq!(config.require_withdrawal_above_minimum(1), Ok(()));
+
+    config.min_withdrawal = 1_000;
+    assert_eq!(
+        config.require_withdrawal_above_minimum(999),
+        Err(TokenPoolError::WithdrawalBelowMinimum)
+    );
+    assert_eq!(config.require_withdrawal_above_minimum(1_000), Ok(()));
+}
+
+#[test]
+fn test_fee_rounding_boundary() {
+    let mut config = default_config();
+
+    // 1 bps rounds down by default: one unit short of 10_000 pays nothing
+    assert_eq!(config.calculate_fee(9_999, 1), Ok(0));
+    assert_eq!(config.calculate_fee(10_000, 1), Ok(1));
+    assert_eq!(config.calculate_fee(10_001, 1), Ok(1));
+
+    // Rounding up charges for any remainder, and agrees on exact multiples
+    config.round_fees_up = 1;
+    assert_eq!(config.calculate_fee(9_999, 1), Ok(1));
+    assert_eq!(config.calculate_fee(10_000, 1), Ok(1));
+    assert_eq!(config.calculate_fee(10_001, 1), Ok(2));
+
+    // Never a fee without a rate
+    assert_eq!(config.calculate_fee(1, 0), Ok(0));
+}
@@ -8,13 +8,14 @@
 
 use borsh::BorshSerialize;
 use litesvm::LiteSVM;
-use litesvm_token::{CreateAccount, CreateMint, MintTo};
+use litesvm_token::{CreateAccount, CreateAssociatedTokenAccount, CreateMint, MintTo};
 use solana_instruction::{AccountMeta, Instruction};
 use solana_keypair::Keypair;
 use solana_pubkey::Pubkey;
 use solana_signer::Signer;
 use solana_transaction::Transaction;
-use token_pool::{TokenPoolConfig, TokenPoolInstruction};
+use token_pool::{TokenPoolConfig, TokenPoolError, TokenPoolInstruction};
+use zorb_pool_interface::PoolOpResult;
 
 // --- Constants ---
 
@@ -26,6 +27,9 @@ const SPL_TOKEN_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
     237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169,
 ]);
 
+const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    solana_pubkey::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
 const TOKEN_POOL_CONFIG_SEED: &[u8] = b"token_pool";
 const VAULT_SEED: &[u8] = b"vault";
 
@@ -44,10 +48,20 @@ fn find_token_config_pda(program_id: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[TOKEN_POOL_CONFIG_SEED, mint.as_ref()], program_id)
 }
 
+/// Legacy `["vault", pool_config]` vault address used before ATA vaults
 fn find_vault_pda(program_id: &Pubkey, token_config: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[VAULT_SEED, token_config.as_ref()], program_id)
 }
 
+/// Vault created by InitPool: the pool config's ATA for the mint
+fn find_vault_ata(token_config: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[token_config.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
 /// Create a proper SPL Token mint using litesvm-token
 fn create_real_mint(svm: &mut LiteSVM, authority: &Keypair, decimals: u8) -> Pubkey {
     CreateMint::new(svm, authority)
@@ -103,17 +117,18 @@ fn build_init_pool_ix(
     withdrawal_fee_rate: u16,
 ) -> Instruction {
     let (pool_config_pda, _) = find_token_config_pda(&program_id, &mint);
-    let (vault_pda, _) = find_vault_pda(&program_id, &pool_config_pda);
+    let vault = find_vault_ata(&pool_config_pda, &mint, &SPL_TOKEN_PROGRAM_ID);
 
     Instruction {
         program_id,
         accounts: vec![
             AccountMeta::new_readonly(mint, false),
             AccountMeta::new(pool_config_pda, false),
-            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(vault, false),
             AccountMeta::new(authority.pubkey(), true),
             AccountMeta::new_readonly(SPL_TOKEN_PROGRAM_ID, false),
             AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
         ],
         data: build_instruction_data(
             TokenPoolInstruction::InitPool as u8,
@@ -127,7 +142,7 @@ fn build_init_pool_ix(
     }
 }
 
-/// Initialize a pool with a real SPL Token mint and return (mint, pool_config_pda, vault_pda)
+/// Initialize a pool with a real SPL Token mint and return (mint, pool_config_pda, vault)
 fn init_pool(
     svm: &mut LiteSVM,
     program_id: Pubkey,
@@ -140,7 +155,7 @@ fn init_pool(
     // Create a real SPL Token mint using litesvm-token
     let mint = create_real_mint(svm, authority, decimals);
     let (pool_config_pda, _) = find_token_config_pda(&program_id, &mint);
-    let (vault_pda, _) = find_vault_pda(&program_id, &pool_config_pda);
+    let vault = find_vault_ata(&pool_config_pda, &mint, &SPL_TOKEN_PROGRAM_ID);
 
     let ix = build_init_pool_ix(
         program_id,
@@ -161,7 +176,7 @@ fn init_pool(
     svm.send_transaction(tx)
         .expect("InitPool should succeed");
 
-    (mint, pool_config_pda, vault_pda)
+    (mint, pool_config_pda, vault)
 }
 
 /// Read TokenPoolConfig from account data
@@ -177,10 +192,18 @@ fn read_token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
 }
 
 /// Build deposit instruction data (raw bytes as expected by process_deposit)
-fn build_deposit_data(amount: u64, expected_output: u64) -> Vec<u8> {
+fn build_deposit_data(
+    amount: u64,
+    expected_output: u64,
+    depositor: &Pubkey,
+    expect_fee_exempt: bool,
+) -> Vec<u8> {
     let mut data = vec![TokenPoolInstruction::Deposit as u8];
     data.extend_from_slice(&amount.to_le_bytes());
     data.extend_from_slice(&expected_output.to_le_bytes());
+    data.extend_from_slice(depositor.as_ref());
+    data.push(expect_fee_exempt as u8);
+    data.extend_from_slice(&[0u8; 7]);
     data
 }
 
@@ -205,7 +228,7 @@ fn build_deposit_ix(
             // Include token-pool program for self-CPI (emit_event calls Log instruction)
             AccountMeta::new_readonly(TOKEN_POOL_PROGRAM_ID, false),
         ],
-        data: build_deposit_data(amount, expected_output),
+        data: build_deposit_data(amount, expected_output, &depositor.pubkey(), false),
     }
 }
 
@@ -272,6 +295,21 @@ fn test_deposit_basic() {
     assert_eq!(config.pending_deposit_fees, fee);
     assert_eq!(config.total_deposit_fees, fee as u128);
 
+    // Verify return data
+    let return_data = result.unwrap().return_data;
+    assert_eq!(return_data.program_id, program_id);
+    let op_result = PoolOpResult::from_bytes(&return_data.data).expect("PoolOpResult");
+    assert_eq!(
+        op_result,
+        PoolOpResult {
+            gross: deposit_amount,
+            fee,
+            net: expected_output,
+            accumulator_epoch: config.accumulator_epoch,
+            new_accounted_total: expected_output as u128,
+        }
+    );
+
     // Verify token balances
     assert_eq!(read_token_balance(&svm, &depositor_token), 0);
     assert_eq!(read_token_balance(&svm, &vault), deposit_amount);
@@ -718,6 +756,8 @@ fn build_fund_rewards_ix(
 ) -> Instruction {
     let mut data = vec![TokenPoolInstruction::FundRewards as u8];
     data.extend_from_slice(&amount.to_le_bytes());
+    // vest_duration_slots = 0: distribute at the next finalization
+    data.extend_from_slice(&0u64.to_le_bytes());
 
     Instruction {
         program_id,
@@ -1136,6 +1176,72 @@ fn test_finalize_rewards_basic() {
     assert!(config.last_finalized_slot > 0);
 }
 
+#[test]
+fn test_deposit_after_finalize_returns_accumulator_epoch() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (mint, pool_config, vault) = init_pool(
+        &mut svm,
+        program_id,
+        &authority,
+        9,
+        u64::MAX,
+        0,
+        0,
+    );
+
+    // Advance past UPDATE_SLOT_INTERVAL and finalize once
+    svm.warp_to_slot(UPDATE_SLOT_INTERVAL + 100);
+    svm.expire_blockhash();
+
+    let caller = Keypair::new();
+    svm.airdrop(&caller.pubkey(), 100_000_000).unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[build_finalize_rewards_ix(program_id, pool_config)],
+        Some(&caller.pubkey()),
+        &[&caller],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("FinalizeRewards should succeed");
+
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.accumulator_epoch, 1);
+
+    // Deposit in the new epoch
+    let depositor = Keypair::new();
+    svm.airdrop(&depositor.pubkey(), 1_000_000_000).unwrap();
+    let deposit_amount: u64 = 1_000_000;
+    let depositor_token =
+        create_real_token_account(&mut svm, &authority, &mint, &depositor.pubkey(), deposit_amount);
+
+    let deposit_ix = build_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        deposit_amount,
+        deposit_amount,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[deposit_ix],
+        Some(&depositor.pubkey()),
+        &[&depositor],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx).expect("Deposit should succeed");
+
+    // The result carries the epoch counter, not the finalization slot
+    let op_result = PoolOpResult::from_bytes(&result.return_data.data).expect("PoolOpResult");
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(op_result.accumulator_epoch, config.accumulator_epoch);
+    assert_ne!(op_result.accumulator_epoch, config.last_finalized_slot);
+}
+
 #[test]
 fn test_finalize_rewards_too_early() {
     let mut svm = LiteSVM::new();
@@ -1288,6 +1394,8 @@ fn test_withdraw_invalid_hub_authority() {
     let expected_output = amount - fee;
     data.extend_from_slice(&amount.to_le_bytes());
     data.extend_from_slice(&expected_output.to_le_bytes());
+    // recipient, expect_fee_exempt, unwrap, padding, unwrap_amount
+    data.extend_from_slice(&[0u8; 48]);
 
     let ix = Instruction {
         program_id,
@@ -1356,6 +1464,8 @@ fn test_withdraw_pool_paused() {
     let mut data = vec![TokenPoolInstruction::Withdraw as u8];
     data.extend_from_slice(&1_000_000_000u64.to_le_bytes());
     data.extend_from_slice(&1_000_000_000u64.to_le_bytes());
+    // recipient, expect_fee_exempt, unwrap, padding, unwrap_amount
+    data.extend_from_slice(&[0u8; 48]);
 
     let ix = Instruction {
         program_id,
@@ -1380,3 +1490,3057 @@ fn test_withdraw_pool_paused() {
     // Should fail (either due to hub_authority validation or pool paused check)
     assert!(result.is_err(), "Withdraw should fail on paused pool");
 }
+
+// =============================================================================
+// Protocol Fee Tests
+// =============================================================================
+
+#[derive(BorshSerialize)]
+struct SetProtocolFeeConfigArgs {
+    treasury: [u8; 32],
+    protocol_fee_share_bps: u16,
+    _padding: [u8; 6],
+}
+
+fn build_set_protocol_fee_config_ix(
+    program_id: Pubkey,
+    pool_config: Pubkey,
+    authority: &Keypair,
+    treasury: Pubkey,
+    protocol_fee_share_bps: u16,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+        ],
+        data: build_instruction_data(
+            TokenPoolInstruction::SetProtocolFeeConfig as u8,
+            &SetProtocolFeeConfigArgs {
+                treasury: treasury.to_bytes(),
+                protocol_fee_share_bps,
+                _padding: [0; 6],
+            },
+        ),
+    }
+}
+
+fn build_withdraw_protocol_fees_ix(
+    program_id: Pubkey,
+    pool_config: Pubkey,
+    vault: Pubkey,
+    treasury_token: Pubkey,
+    authority: &Keypair,
+    amount: u64,
+) -> Instruction {
+    let mut data = vec![TokenPoolInstruction::WithdrawProtocolFees as u8];
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new(treasury_token, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(SPL_TOKEN_PROGRAM_ID, false),
+            // Include token-pool program for self-CPI (emit_event calls Log instruction)
+            AccountMeta::new_readonly(TOKEN_POOL_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+fn send_ix(svm: &mut LiteSVM, ix: Instruction, signer: &Keypair) -> Result<(), String> {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        svm.latest_blockhash(),
+    );
+    let result = svm
+        .send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e));
+    svm.expire_blockhash();
+    result
+}
+
+/// Init a pool with 1% fees and a 50% protocol share, then deposit 1 token.
+///
+/// Returns (mint, pool_config, vault, treasury wallet, treasury ATA).
+fn setup_protocol_fee_pool(
+    svm: &mut LiteSVM,
+    program_id: Pubkey,
+    authority: &Keypair,
+) -> (Pubkey, Pubkey, Pubkey, Pubkey, Pubkey) {
+    let (mint, pool_config, vault) = init_pool(svm, program_id, authority, 9, u64::MAX, 100, 100);
+
+    let treasury = Pubkey::new_unique();
+    let treasury_token = CreateAssociatedTokenAccount::new(svm, authority, &mint)
+        .owner(&treasury)
+        .send()
+        .expect("create treasury ATA");
+
+    let ix = build_set_protocol_fee_config_ix(program_id, pool_config, authority, treasury, 5_000);
+    send_ix(svm, ix, authority).expect("SetProtocolFeeConfig should succeed");
+
+    let depositor = Keypair::new();
+    svm.airdrop(&depositor.pubkey(), 1_000_000_000).unwrap();
+    let depositor_token =
+        create_real_token_account(svm, authority, &mint, &depositor.pubkey(), 1_000_000_000);
+    let ix = build_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        1_000_000_000,
+        990_000_000,
+    );
+    send_ix(svm, ix, &depositor).expect("Deposit should succeed");
+
+    (mint, pool_config, vault, treasury, treasury_token)
+}
+
+#[test]
+fn test_protocol_fees_accrue_withdraw_and_skip_finalize() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (_, pool_config, vault, treasury, treasury_token) =
+        setup_protocol_fee_pool(&mut svm, program_id, &authority);
+
+    // 1% fee = 10_000_000, half to the protocol
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.treasury, treasury.to_bytes());
+    assert_eq!(config.accrued_protocol_fees, 5_000_000);
+    assert_eq!(config.pending_deposit_fees, 5_000_000);
+    assert_eq!(config.total_deposit_fees, 10_000_000);
+
+    // Partial withdrawal
+    let ix = build_withdraw_protocol_fees_ix(
+        program_id,
+        pool_config,
+        vault,
+        treasury_token,
+        &authority,
+        2_000_000,
+    );
+    send_ix(&mut svm, ix, &authority).expect("WithdrawProtocolFees should succeed");
+
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.accrued_protocol_fees, 3_000_000);
+    assert_eq!(config.total_protocol_fees_withdrawn, 2_000_000);
+    assert_eq!(read_token_balance(&svm, &treasury_token), 2_000_000);
+    assert_eq!(read_token_balance(&svm, &vault), 998_000_000);
+
+    // Over-withdrawal is rejected
+    let ix = build_withdraw_protocol_fees_ix(
+        program_id,
+        pool_config,
+        vault,
+        treasury_token,
+        &authority,
+        3_000_001,
+    );
+    let result = send_ix(&mut svm, ix, &authority);
+    assert!(result.is_err(), "Withdrawing more than accrued should fail");
+
+    // Finalization only distributes the depositor share
+    svm.warp_to_slot(UPDATE_SLOT_INTERVAL + 100);
+    let caller = Keypair::new();
+    svm.airdrop(&caller.pubkey(), 100_000_000).unwrap();
+    send_ix(
+        &mut svm,
+        build_finalize_rewards_ix(program_id, pool_config),
+        &caller,
+    )
+    .expect("FinalizeRewards should succeed");
+
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.total_rewards_distributed, 5_000_000);
+    assert_eq!(config.pending_deposit_fees, 0);
+    assert_eq!(config.accrued_protocol_fees, 3_000_000);
+
+    // The rest can still be withdrawn after finalization
+    let ix = build_withdraw_protocol_fees_ix(
+        program_id,
+        pool_config,
+        vault,
+        treasury_token,
+        &authority,
+        3_000_000,
+    );
+    send_ix(&mut svm, ix, &authority).expect("WithdrawProtocolFees should succeed");
+
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.accrued_protocol_fees, 0);
+    assert_eq!(read_token_balance(&svm, &treasury_token), 5_000_000);
+}
+
+#[test]
+fn test_withdraw_protocol_fees_requires_treasury_ata() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (mint, pool_config, vault, treasury, _) =
+        setup_protocol_fee_pool(&mut svm, program_id, &authority);
+
+    // A token account owned by the treasury that is not its ATA
+    let other_token = create_real_token_account(&mut svm, &authority, &mint, &treasury, 0);
+    let ix = build_withdraw_protocol_fees_ix(
+        program_id,
+        pool_config,
+        vault,
+        other_token,
+        &authority,
+        1_000_000,
+    );
+    let result = send_ix(&mut svm, ix, &authority);
+    assert!(
+        result.is_err(),
+        "Non-ATA treasury account should be rejected"
+    );
+
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.accrued_protocol_fees, 5_000_000);
+}
+
+#[test]
+fn test_protocol_fee_admin_unauthorized() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (_, pool_config, vault, _, treasury_token) =
+        setup_protocol_fee_pool(&mut svm, program_id, &authority);
+
+    let wrong_authority = Keypair::new();
+    svm.airdrop(&wrong_authority.pubkey(), 1_000_000_000)
+        .unwrap();
+
+    let ix = build_set_protocol_fee_config_ix(
+        program_id,
+        pool_config,
+        &wrong_authority,
+        wrong_authority.pubkey(),
+        10_000,
+    );
+    let result = send_ix(&mut svm, ix, &wrong_authority);
+    assert!(
+        result.is_err(),
+        "SetProtocolFeeConfig with wrong authority should fail"
+    );
+
+    let ix = build_withdraw_protocol_fees_ix(
+        program_id,
+        pool_config,
+        vault,
+        treasury_token,
+        &wrong_authority,
+        1_000_000,
+    );
+    let result = send_ix(&mut svm, ix, &wrong_authority);
+    assert!(
+        result.is_err(),
+        "WithdrawProtocolFees with wrong authority should fail"
+    );
+
+    // Share above 100% is rejected for the real authority too
+    let ix = build_set_protocol_fee_config_ix(
+        program_id,
+        pool_config,
+        &authority,
+        Pubkey::new_unique(),
+        10_001,
+    );
+    let result = send_ix(&mut svm, ix, &authority);
+    assert!(result.is_err(), "Protocol fee share > 100% should fail");
+}
+
+// =============================================================================
+// Pool Pause Tests
+// =============================================================================
+
+#[derive(BorshSerialize)]
+struct SetPoolPausedArgs {
+    paused: u8,
+    allow_withdrawals_when_paused: u8,
+    _padding: [u8; 6],
+}
+
+fn build_set_pool_paused_ix(
+    program_id: Pubkey,
+    pool_config: Pubkey,
+    authority: &Keypair,
+    paused: bool,
+    allow_withdrawals_when_paused: bool,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            // Include token-pool program for self-CPI (emit_event calls Log instruction)
+            AccountMeta::new_readonly(TOKEN_POOL_PROGRAM_ID, false),
+        ],
+        data: build_instruction_data(
+            TokenPoolInstruction::SetPoolPaused as u8,
+            &SetPoolPausedArgs {
+                paused: paused as u8,
+                allow_withdrawals_when_paused: allow_withdrawals_when_paused as u8,
+                _padding: [0; 6],
+            },
+        ),
+    }
+}
+
+/// Withdraw with a non-canonical hub_authority, in WithdrawAccounts order.
+///
+/// Gets past account validation, so the error shows which handler check failed.
+fn build_withdraw_ix_with_fake_hub(
+    program_id: Pubkey,
+    pool_config: Pubkey,
+    vault: Pubkey,
+    amount: u64,
+) -> Instruction {
+    let mut data = vec![TokenPoolInstruction::Withdraw as u8];
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.extend_from_slice(&amount.to_le_bytes());
+    // recipient, expect_fee_exempt, unwrap, padding, unwrap_amount
+    data.extend_from_slice(&[0u8; 48]);
+
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(TOKEN_POOL_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SPL_TOKEN_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+fn is_custom_error(err: &str, error: TokenPoolError) -> bool {
+    err.contains(&format!("Custom({})", error as u32))
+}
+
+#[test]
+fn test_deposit_rejected_while_pool_paused() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (mint, pool_config, vault) = init_pool(&mut svm, program_id, &authority, 9, u64::MAX, 0, 0);
+
+    let ix = build_set_pool_paused_ix(program_id, pool_config, &authority, true, true);
+    send_ix(&mut svm, ix, &authority).expect("SetPoolPaused should succeed");
+
+    // Still active on the hub-mirrored flag, but paused locally
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.is_active, 1);
+    assert_eq!(config.paused, 1);
+    assert_eq!(config.allow_withdrawals_when_paused, 1);
+
+    let depositor = Keypair::new();
+    svm.airdrop(&depositor.pubkey(), 1_000_000_000).unwrap();
+    let depositor_token = create_real_token_account(
+        &mut svm,
+        &authority,
+        &mint,
+        &depositor.pubkey(),
+        100_000_000,
+    );
+    let deposit_ix = || {
+        build_deposit_ix(
+            program_id,
+            pool_config,
+            vault,
+            depositor_token,
+            &depositor,
+            100_000_000,
+            100_000_000,
+        )
+    };
+
+    // Withdrawal-only mode still blocks deposits
+    let err = send_ix(&mut svm, deposit_ix(), &depositor).unwrap_err();
+    assert!(is_custom_error(&err, TokenPoolError::PoolPaused), "{err}");
+    assert_eq!(read_token_balance(&svm, &vault), 0);
+
+    let ix = build_set_pool_paused_ix(program_id, pool_config, &authority, false, false);
+    send_ix(&mut svm, ix, &authority).expect("Unpause should succeed");
+
+    send_ix(&mut svm, deposit_ix(), &depositor).expect("Deposit should succeed after unpause");
+    assert_eq!(read_token_balance(&svm, &vault), 100_000_000);
+}
+
+#[test]
+fn test_withdraw_under_pool_pause() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (_, pool_config, vault) = init_pool(&mut svm, program_id, &authority, 9, u64::MAX, 0, 0);
+
+    // Paused without the withdrawal bit: the pause check rejects first
+    let ix = build_set_pool_paused_ix(program_id, pool_config, &authority, true, false);
+    send_ix(&mut svm, ix, &authority).expect("SetPoolPaused should succeed");
+
+    let ix = build_withdraw_ix_with_fake_hub(program_id, pool_config, vault, 1_000);
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(is_custom_error(&err, TokenPoolError::PoolPaused), "{err}");
+
+    // Paused with the withdrawal bit: the pause check passes and the
+    // hub_authority check is the one that rejects
+    let ix = build_set_pool_paused_ix(program_id, pool_config, &authority, true, true);
+    send_ix(&mut svm, ix, &authority).expect("SetPoolPaused should succeed");
+
+    let ix = build_withdraw_ix_with_fake_hub(program_id, pool_config, vault, 1_000);
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::InvalidHubAuthority),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_set_pool_paused_unauthorized() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (_, pool_config, _) = init_pool(&mut svm, program_id, &authority, 9, u64::MAX, 0, 0);
+
+    let wrong_authority = Keypair::new();
+    svm.airdrop(&wrong_authority.pubkey(), 1_000_000_000)
+        .unwrap();
+
+    let ix = build_set_pool_paused_ix(program_id, pool_config, &wrong_authority, true, false);
+    let err = send_ix(&mut svm, ix, &wrong_authority).unwrap_err();
+    assert!(is_custom_error(&err, TokenPoolError::Unauthorized), "{err}");
+
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.paused, 0);
+}
+
+// =============================================================================
+// Close Pool Tests
+// =============================================================================
+
+const HUB_PROGRAM_ID: Pubkey =
+    solana_pubkey::pubkey!("zrbus1K97oD9wzzygehPBZMh5EVXPturZNgbfoTig5Z");
+
+fn find_hub_pool_config_pda(asset_id: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(&[b"pool_config", asset_id], &HUB_PROGRAM_ID).0
+}
+
+fn build_close_pool_ix(
+    program_id: Pubkey,
+    pool_config: Pubkey,
+    vault: Pubkey,
+    hub_pool_config: Pubkey,
+    authority: &Keypair,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(hub_pool_config, false),
+            AccountMeta::new(authority.pubkey(), true),
+            AccountMeta::new_readonly(SPL_TOKEN_PROGRAM_ID, false),
+            // Include token-pool program for self-CPI (emit_event calls Log instruction)
+            AccountMeta::new_readonly(TOKEN_POOL_PROGRAM_ID, false),
+        ],
+        data: vec![TokenPoolInstruction::ClosePool as u8],
+    }
+}
+
+/// Overwrite TokenPoolConfig fields in place
+fn modify_pool_config(
+    svm: &mut LiteSVM,
+    pool_config: &Pubkey,
+    f: impl FnOnce(&mut TokenPoolConfig),
+) {
+    let mut account = svm.get_account(pool_config).unwrap();
+    let range = DISC_SIZE..DISC_SIZE + TokenPoolConfig::SIZE;
+    let mut config: TokenPoolConfig = bytemuck::pod_read_unaligned(&account.data[range.clone()]);
+    f(&mut config);
+    account.data[range].copy_from_slice(bytemuck::bytes_of(&config));
+    svm.set_account(*pool_config, account).unwrap();
+}
+
+/// Write a hub PoolConfig account (discriminator 15) with the given mode
+fn set_hub_pool_config(svm: &mut LiteSVM, address: Pubkey, mode: u8) {
+    let mut data = vec![0u8; 88];
+    data[..8].copy_from_slice(&15u64.to_le_bytes());
+    data[8 + 32 + 32 + 1] = mode;
+    svm.set_account(
+        address,
+        solana_account::Account {
+            lamports: 1_000_000_000,
+            data,
+            owner: HUB_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_close_pool_rejects_nonzero_balances() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (mint, pool_config, vault) = init_pool(&mut svm, program_id, &authority, 9, u64::MAX, 0, 0);
+    let hub_pool_config = find_hub_pool_config_pda(&read_pool_config(&svm, &pool_config).asset_id);
+
+    let cases: [(&str, fn(&mut TokenPoolConfig)); 6] = [
+        ("pending_deposits", |c| c.pending_deposits = 1),
+        ("pending_withdrawals", |c| c.pending_withdrawals = 1),
+        ("pending_deposit_fees", |c| c.pending_deposit_fees = 1),
+        ("pending_withdrawal_fees", |c| c.pending_withdrawal_fees = 1),
+        ("pending_funded_rewards", |c| c.pending_funded_rewards = 1),
+        ("accrued_protocol_fees", |c| c.accrued_protocol_fees = 1),
+    ];
+    for (field, set_nonzero) in cases {
+        let original = read_pool_config(&svm, &pool_config);
+        modify_pool_config(&mut svm, &pool_config, set_nonzero);
+
+        let ix = build_close_pool_ix(program_id, pool_config, vault, hub_pool_config, &authority);
+        let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+        assert!(
+            is_custom_error(&err, TokenPoolError::PoolNotEmpty),
+            "{field}: {err}"
+        );
+
+        modify_pool_config(&mut svm, &pool_config, |c| *c = original);
+    }
+
+    // Untracked tokens in the vault also block closure
+    MintTo::new(&mut svm, &authority, &mint, &vault, 1)
+        .owner(&authority)
+        .send()
+        .expect("mint to vault");
+
+    let ix = build_close_pool_ix(program_id, pool_config, vault, hub_pool_config, &authority);
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(is_custom_error(&err, TokenPoolError::PoolNotEmpty), "{err}");
+    assert!(svm.get_account(&pool_config).is_some());
+}
+
+#[test]
+fn test_close_pool_requires_inactive_hub_pool() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (_, pool_config, vault) = init_pool(&mut svm, program_id, &authority, 9, u64::MAX, 0, 0);
+    let hub_pool_config = find_hub_pool_config_pda(&read_pool_config(&svm, &pool_config).asset_id);
+
+    // Hub still routes withdrawals to the pool
+    set_hub_pool_config(&mut svm, hub_pool_config, 0b01);
+    let ix = build_close_pool_ix(program_id, pool_config, vault, hub_pool_config, &authority);
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::HubPoolStillRegistered),
+        "{err}"
+    );
+
+    // Some other account cannot stand in for the hub PDA
+    let ix = build_close_pool_ix(
+        program_id,
+        pool_config,
+        vault,
+        Pubkey::new_unique(),
+        &authority,
+    );
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::HubPoolStillRegistered),
+        "{err}"
+    );
+
+    // Wrong authority
+    let wrong_authority = Keypair::new();
+    svm.airdrop(&wrong_authority.pubkey(), 1_000_000_000)
+        .unwrap();
+    let ix = build_close_pool_ix(
+        program_id,
+        pool_config,
+        vault,
+        hub_pool_config,
+        &wrong_authority,
+    );
+    let err = send_ix(&mut svm, ix, &wrong_authority).unwrap_err();
+    assert!(is_custom_error(&err, TokenPoolError::Unauthorized), "{err}");
+
+    // Deregistered (mode cleared) on the hub: closure proceeds
+    set_hub_pool_config(&mut svm, hub_pool_config, 0);
+    let ix = build_close_pool_ix(program_id, pool_config, vault, hub_pool_config, &authority);
+    send_ix(&mut svm, ix, &authority).expect("ClosePool should succeed");
+}
+
+#[test]
+fn test_close_pool_reclaims_rent() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (mint, pool_config, vault) = init_pool(&mut svm, program_id, &authority, 9, u64::MAX, 0, 0);
+    let hub_pool_config = find_hub_pool_config_pda(&read_pool_config(&svm, &pool_config).asset_id);
+
+    // A drained pool with history: lifetime totals do not block closure
+    modify_pool_config(&mut svm, &pool_config, |c| {
+        c.total_deposited = 1_000;
+        c.total_withdrawn = 1_000;
+    });
+
+    let rent =
+        svm.get_account(&pool_config).unwrap().lamports + svm.get_account(&vault).unwrap().lamports;
+    let balance_before = svm.get_balance(&authority.pubkey()).unwrap();
+
+    // Hub PoolConfig already closed via ClosePoolConfig (absent)
+    let ix = build_close_pool_ix(program_id, pool_config, vault, hub_pool_config, &authority);
+    send_ix(&mut svm, ix, &authority).expect("ClosePool should succeed");
+
+    // Rent returned, less the 5000-lamport signature fee
+    let balance_after = svm.get_balance(&authority.pubkey()).unwrap();
+    assert_eq!(balance_after, balance_before + rent - 5_000);
+
+    for closed in [pool_config, vault] {
+        let account = svm.get_account(&closed);
+        assert!(
+            account.is_none_or(|a| a.lamports == 0 && a.data.is_empty()),
+            "{closed} should be closed"
+        );
+    }
+
+    // The mint can be pooled again from scratch
+    let ix = build_init_pool_ix(program_id, mint, &authority, u64::MAX, 0, 0);
+    send_ix(&mut svm, ix, &authority).expect("InitPool should succeed after close");
+}
+// =============================================================================
+// Token-2022 Tests
+// =============================================================================
+
+const SPL_TOKEN_2022_PROGRAM_ID: Pubkey =
+    solana_pubkey::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// Token-2022 extension types used by the fixtures below
+const EXTENSION_TRANSFER_FEE_CONFIG: u16 = 1;
+const EXTENSION_TRANSFER_FEE_AMOUNT: u16 = 2;
+const EXTENSION_DEFAULT_ACCOUNT_STATE: u16 = 6;
+const EXTENSION_NON_TRANSFERABLE: u16 = 9;
+const EXTENSION_PERMANENT_DELEGATE: u16 = 12;
+const EXTENSION_TRANSFER_HOOK: u16 = 14;
+const EXTENSION_PAUSABLE: u16 = 26;
+
+/// Store a Token-2022 account: base layout padded to 165 bytes, the account
+/// type byte, then `[type][length][value]` extension entries.
+fn set_token_2022_account(
+    svm: &mut LiteSVM,
+    address: Pubkey,
+    mut base: Vec<u8>,
+    account_type: u8,
+    extensions: &[(u16, Vec<u8>)],
+) {
+    base.resize(165, 0);
+    base.push(account_type);
+    for (extension, value) in extensions {
+        base.extend_from_slice(&extension.to_le_bytes());
+        base.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        base.extend_from_slice(value);
+    }
+    svm.set_account(
+        address,
+        solana_account::Account {
+            lamports: svm.minimum_balance_for_rent_exemption(base.len()),
+            data: base,
+            owner: SPL_TOKEN_2022_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
+/// TransferFeeConfig with the same fee for the older and newer epochs
+fn transfer_fee_config(basis_points: u16, maximum_fee: u64) -> Vec<u8> {
+    let mut value = vec![0u8; 32 + 32 + 8];
+    for _ in 0..2 {
+        value.extend_from_slice(&0u64.to_le_bytes());
+        value.extend_from_slice(&maximum_fee.to_le_bytes());
+        value.extend_from_slice(&basis_points.to_le_bytes());
+    }
+    value
+}
+
+/// Create a Token-2022 mint with the given extensions
+fn create_token_2022_mint(
+    svm: &mut LiteSVM,
+    authority: &Pubkey,
+    decimals: u8,
+    extensions: &[(u16, Vec<u8>)],
+) -> Pubkey {
+    let mint = Pubkey::new_unique();
+    let mut base = vec![0u8; 82];
+    base[..4].copy_from_slice(&1u32.to_le_bytes());
+    base[4..36].copy_from_slice(authority.as_ref());
+    base[44] = decimals;
+    base[45] = 1;
+    set_token_2022_account(svm, mint, base, 1, extensions);
+    mint
+}
+
+/// Create an initialized Token-2022 token account for a transfer-fee mint
+fn create_token_2022_account(
+    svm: &mut LiteSVM,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    balance: u64,
+) -> Pubkey {
+    let token_account = Pubkey::new_unique();
+    let mut base = vec![0u8; 165];
+    base[..32].copy_from_slice(mint.as_ref());
+    base[32..64].copy_from_slice(owner.as_ref());
+    base[64..72].copy_from_slice(&balance.to_le_bytes());
+    base[108] = 1;
+    set_token_2022_account(
+        svm,
+        token_account,
+        base,
+        2,
+        &[(EXTENSION_TRANSFER_FEE_AMOUNT, vec![0u8; 8])],
+    );
+    token_account
+}
+
+/// InitPool against Token-2022 for an existing mint
+fn build_init_token_2022_pool_ix(
+    program_id: Pubkey,
+    mint: Pubkey,
+    authority: &Keypair,
+    deposit_fee_rate: u16,
+) -> Instruction {
+    let (pool_config, _) = find_token_config_pda(&program_id, &mint);
+    let vault = find_vault_ata(&pool_config, &mint, &SPL_TOKEN_2022_PROGRAM_ID);
+
+    let mut ix = build_init_pool_ix(program_id, mint, authority, u64::MAX, deposit_fee_rate, 0);
+    ix.accounts[2] = AccountMeta::new(vault, false);
+    ix.accounts[4] = AccountMeta::new_readonly(SPL_TOKEN_2022_PROGRAM_ID, false);
+    ix
+}
+
+/// Deposit into a Token-2022 pool, optionally passing the trailing mint
+#[allow(clippy::too_many_arguments)]
+fn build_token_2022_deposit_ix(
+    program_id: Pubkey,
+    pool_config: Pubkey,
+    vault: Pubkey,
+    depositor_token: Pubkey,
+    depositor: &Keypair,
+    amount: u64,
+    expected_output: u64,
+    mint: Option<Pubkey>,
+) -> Instruction {
+    let mut ix = build_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        depositor,
+        amount,
+        expected_output,
+    );
+    ix.accounts[4] = AccountMeta::new_readonly(SPL_TOKEN_2022_PROGRAM_ID, false);
+    ix.accounts
+        .extend(mint.map(|mint| AccountMeta::new_readonly(mint, false)));
+    ix
+}
+
+/// Init a Token-2022 pool whose mint charges a 0.5% transfer fee.
+///
+/// Returns (mint, pool_config, vault, depositor, depositor token account).
+fn setup_transfer_fee_pool(
+    svm: &mut LiteSVM,
+    program_id: Pubkey,
+    authority: &Keypair,
+    deposit_fee_rate: u16,
+) -> (Pubkey, Pubkey, Pubkey, Keypair, Pubkey) {
+    let mint = create_token_2022_mint(
+        svm,
+        &authority.pubkey(),
+        6,
+        &[(
+            EXTENSION_TRANSFER_FEE_CONFIG,
+            transfer_fee_config(50, u64::MAX),
+        )],
+    );
+    let (pool_config, _) = find_token_config_pda(&program_id, &mint);
+    let vault = find_vault_ata(&pool_config, &mint, &SPL_TOKEN_2022_PROGRAM_ID);
+
+    let ix = build_init_token_2022_pool_ix(program_id, mint, authority, deposit_fee_rate);
+    send_ix(svm, ix, authority).expect("InitPool should succeed");
+
+    let depositor = Keypair::new();
+    svm.airdrop(&depositor.pubkey(), 1_000_000_000).unwrap();
+    let depositor_token = create_token_2022_account(svm, &mint, &depositor.pubkey(), 10_000_000);
+
+    (mint, pool_config, vault, depositor, depositor_token)
+}
+
+#[test]
+fn test_token_2022_deposit_absorbs_transfer_fee() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    // 1% deposit fee covers the 0.5% transfer fee
+    let (mint, pool_config, vault, depositor, depositor_token) =
+        setup_transfer_fee_pool(&mut svm, program_id, &authority, 100);
+
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.token_program, SPL_TOKEN_2022_PROGRAM_ID.to_bytes());
+    assert_eq!(config.decimals, 6);
+
+    // Vault was sized for the TransferFeeAmount extension
+    let vault_account = svm.get_account(&vault).unwrap();
+    assert_eq!(vault_account.owner, SPL_TOKEN_2022_PROGRAM_ID);
+    assert!(vault_account.data.len() > 165);
+
+    let ix = build_token_2022_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        1_000_000,
+        990_000,
+        Some(mint),
+    );
+    send_ix(&mut svm, ix, &depositor).expect("Deposit should succeed");
+
+    // 5_000 withheld by the mint; the pool books the remaining 5_000 as its fee
+    assert_eq!(read_token_balance(&svm, &vault), 995_000);
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.pending_deposits, 990_000);
+    assert_eq!(config.pending_deposit_fees, 5_000);
+    assert_eq!(config.total_deposit_fees, 5_000);
+    assert_eq!(config.total_transfer_fee_shortfall, 5_000);
+}
+
+#[test]
+fn test_token_2022_deposit_transfer_fee_exceeds_pool_fee() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    // No deposit fee: the principal would not be fully backed
+    let (mint, pool_config, vault, depositor, depositor_token) =
+        setup_transfer_fee_pool(&mut svm, program_id, &authority, 0);
+
+    let ix = build_token_2022_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        1_000_000,
+        1_000_000,
+        Some(mint),
+    );
+    let err = send_ix(&mut svm, ix, &depositor).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::TransferFeeExceedsPoolFee),
+        "{err}"
+    );
+    assert_eq!(read_token_balance(&svm, &vault), 0);
+}
+
+#[test]
+fn test_token_2022_deposit_requires_mint_and_token_program() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (mint, pool_config, vault, depositor, depositor_token) =
+        setup_transfer_fee_pool(&mut svm, program_id, &authority, 100);
+
+    // Unchecked Transfer is not available for Token-2022 pools
+    let ix = build_token_2022_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        1_000_000,
+        990_000,
+        None,
+    );
+    let err = send_ix(&mut svm, ix, &depositor).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::MissingMintAccount),
+        "{err}"
+    );
+
+    // The token program must be the one the pool was created with
+    let mut ix = build_token_2022_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        1_000_000,
+        990_000,
+        Some(mint),
+    );
+    ix.accounts[4] = AccountMeta::new_readonly(SPL_TOKEN_PROGRAM_ID, false);
+    let err = send_ix(&mut svm, ix, &depositor).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::InvalidTokenProgram),
+        "{err}"
+    );
+
+    // A trailing account that is not the pool's mint is rejected
+    let ix = build_token_2022_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        1_000_000,
+        990_000,
+        Some(Pubkey::new_unique()),
+    );
+    let err = send_ix(&mut svm, ix, &depositor).unwrap_err();
+    assert!(is_custom_error(&err, TokenPoolError::InvalidMint), "{err}");
+}
+
+#[test]
+fn test_init_pool_rejects_unsupported_token_2022_extensions() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let unsupported = [
+        (EXTENSION_TRANSFER_HOOK, vec![0u8; 64]),
+        (EXTENSION_PAUSABLE, vec![0u8; 33]),
+        (EXTENSION_PERMANENT_DELEGATE, vec![1u8; 32]),
+        (EXTENSION_NON_TRANSFERABLE, vec![]),
+        // AccountState::Frozen
+        (EXTENSION_DEFAULT_ACCOUNT_STATE, vec![2u8]),
+    ];
+    for extension in unsupported {
+        let mint = create_token_2022_mint(
+            &mut svm,
+            &authority.pubkey(),
+            6,
+            &[
+                (
+                    EXTENSION_TRANSFER_FEE_CONFIG,
+                    transfer_fee_config(50, u64::MAX),
+                ),
+                extension,
+            ],
+        );
+
+        let ix = build_init_token_2022_pool_ix(program_id, mint, &authority, 100);
+        let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+        assert!(
+            is_custom_error(&err, TokenPoolError::UnsupportedMintExtension),
+            "{err}"
+        );
+    }
+}
+// =============================================================================
+// Pool Limit Tests
+// =============================================================================
+
+#[derive(BorshSerialize)]
+struct SetPoolLimitsArgs {
+    max_total_pool: u128,
+    max_single_deposit: u64,
+    min_deposit: u64,
+}
+
+fn build_set_pool_limits_ix(
+    program_id: Pubkey,
+    pool_config: Pubkey,
+    authority: &Keypair,
+    max_total_pool: u128,
+    max_single_deposit: u64,
+    min_deposit: u64,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+        ],
+        data: build_instruction_data(
+            TokenPoolInstruction::SetPoolLimits as u8,
+            &SetPoolLimitsArgs {
+                max_total_pool,
+                max_single_deposit,
+                min_deposit,
+            },
+        ),
+    }
+}
+
+/// Init a fee-free pool with the given limits and a funded depositor.
+///
+/// Returns (pool_config, vault, depositor, depositor token account).
+fn setup_limited_pool(
+    svm: &mut LiteSVM,
+    program_id: Pubkey,
+    authority: &Keypair,
+    max_total_pool: u128,
+    max_single_deposit: u64,
+    min_deposit: u64,
+) -> (Pubkey, Pubkey, Keypair, Pubkey) {
+    let (mint, pool_config, vault) = init_pool(svm, program_id, authority, 9, u64::MAX, 0, 0);
+
+    let ix = build_set_pool_limits_ix(
+        program_id,
+        pool_config,
+        authority,
+        max_total_pool,
+        max_single_deposit,
+        min_deposit,
+    );
+    send_ix(svm, ix, authority).expect("SetPoolLimits should succeed");
+
+    let depositor = Keypair::new();
+    svm.airdrop(&depositor.pubkey(), 1_000_000_000).unwrap();
+    let depositor_token =
+        create_real_token_account(svm, authority, &mint, &depositor.pubkey(), 10_000_000);
+
+    (pool_config, vault, depositor, depositor_token)
+}
+
+#[test]
+fn test_deposit_min_and_single_cap_boundaries() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (pool_config, vault, depositor, depositor_token) =
+        setup_limited_pool(&mut svm, program_id, &authority, 0, 1_000_000, 1_000);
+
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.max_total_pool, 0);
+    assert_eq!(config.max_single_deposit, 1_000_000);
+    assert_eq!(config.min_deposit, 1_000);
+
+    let deposit = |svm: &mut LiteSVM, amount: u64| {
+        let ix = build_deposit_ix(
+            program_id,
+            pool_config,
+            vault,
+            depositor_token,
+            &depositor,
+            amount,
+            amount,
+        );
+        send_ix(svm, ix, &depositor)
+    };
+
+    let err = deposit(&mut svm, 999).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::DepositBelowMinimum),
+        "{err}"
+    );
+    let err = deposit(&mut svm, 1_000_001).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::SingleDepositCapExceeded),
+        "{err}"
+    );
+    assert_eq!(read_token_balance(&svm, &vault), 0);
+
+    deposit(&mut svm, 1_000).expect("Deposit at the minimum should succeed");
+    deposit(&mut svm, 1_000_000).expect("Deposit at the single cap should succeed");
+    assert_eq!(read_token_balance(&svm, &vault), 1_001_000);
+}
+
+#[test]
+fn test_deposit_total_pool_cap_boundary() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (pool_config, vault, depositor, depositor_token) =
+        setup_limited_pool(&mut svm, program_id, &authority, 2_000_000, 0, 0);
+
+    let deposit = |svm: &mut LiteSVM, amount: u64| {
+        let ix = build_deposit_ix(
+            program_id,
+            pool_config,
+            vault,
+            depositor_token,
+            &depositor,
+            amount,
+            amount,
+        );
+        send_ix(svm, ix, &depositor)
+    };
+
+    deposit(&mut svm, 1_500_000).expect("Deposit under the cap should succeed");
+
+    // One above the remaining room fails, exactly filling the cap succeeds
+    let err = deposit(&mut svm, 500_001).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::PoolCapExceeded),
+        "{err}"
+    );
+    deposit(&mut svm, 500_000).expect("Deposit up to the cap should succeed");
+
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.pending_deposits, 2_000_000);
+    assert_eq!(read_token_balance(&svm, &vault), 2_000_000);
+}
+
+#[test]
+fn test_pool_limits_do_not_apply_to_withdrawals() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    // Pool already over a tiny cap, withdrawing below the minimum
+    let (pool_config, vault, _, _) = setup_limited_pool(&mut svm, program_id, &authority, 1, 10, 5);
+    modify_pool_config(&mut svm, &pool_config, |config| {
+        config.pending_deposits = 1_000;
+    });
+
+    // The hub_authority check is what rejects, not any limit
+    let ix = build_withdraw_ix_with_fake_hub(program_id, pool_config, vault, 1);
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::InvalidHubAuthority),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_set_pool_limits_validation() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (_, pool_config, _) = init_pool(&mut svm, program_id, &authority, 9, u64::MAX, 0, 0);
+
+    let ix = build_set_pool_limits_ix(program_id, pool_config, &authority, 0, 100, 101);
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::InvalidAmount),
+        "{err}"
+    );
+
+    let wrong_authority = Keypair::new();
+    svm.airdrop(&wrong_authority.pubkey(), 1_000_000_000)
+        .unwrap();
+    let ix = build_set_pool_limits_ix(program_id, pool_config, &wrong_authority, 1, 1, 1);
+    let err = send_ix(&mut svm, ix, &wrong_authority).unwrap_err();
+    assert!(is_custom_error(&err, TokenPoolError::Unauthorized), "{err}");
+
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.max_total_pool, 0);
+    assert_eq!(config.max_single_deposit, 0);
+    assert_eq!(config.min_deposit, 0);
+}
+// =============================================================================
+// Finalize Interval Tests
+// =============================================================================
+
+fn build_set_finalize_interval_ix(
+    program_id: Pubkey,
+    pool_config: Pubkey,
+    authority: &Keypair,
+    finalize_interval_slots: u64,
+) -> Instruction {
+    let mut data = vec![TokenPoolInstruction::SetFinalizeInterval as u8];
+    data.extend_from_slice(&finalize_interval_slots.to_le_bytes());
+
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+        ],
+        data,
+    }
+}
+
+#[test]
+fn test_finalize_rewards_custom_interval() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (_, pool_config, _) = init_pool(&mut svm, program_id, &authority, 9, u64::MAX, 0, 0);
+    let start = read_pool_config(&svm, &pool_config).last_finalized_slot;
+
+    // Hourly compounding
+    let ix = build_set_finalize_interval_ix(program_id, pool_config, &authority, 9_000);
+    send_ix(&mut svm, ix, &authority).expect("SetFinalizeInterval should succeed");
+    assert_eq!(
+        read_pool_config(&svm, &pool_config).finalize_interval_slots,
+        9_000
+    );
+
+    // The global default no longer suffices
+    svm.warp_to_slot(start + TokenPoolConfig::UPDATE_SLOT_INTERVAL);
+    let ix = build_finalize_rewards_ix(program_id, pool_config);
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::RewardsNotReady),
+        "{err}"
+    );
+
+    svm.warp_to_slot(start + 9_000);
+    let ix = build_finalize_rewards_ix(program_id, pool_config);
+    send_ix(&mut svm, ix, &authority).expect("FinalizeRewards should succeed");
+    assert_eq!(
+        read_pool_config(&svm, &pool_config).last_finalized_slot,
+        start + 9_000
+    );
+}
+
+#[test]
+fn test_finalize_interval_zero_restores_default() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (_, pool_config, _) = init_pool(&mut svm, program_id, &authority, 9, u64::MAX, 0, 0);
+    let start = read_pool_config(&svm, &pool_config).last_finalized_slot;
+
+    let ix = build_set_finalize_interval_ix(program_id, pool_config, &authority, 216_000);
+    send_ix(&mut svm, ix, &authority).expect("SetFinalizeInterval should succeed");
+    let ix = build_set_finalize_interval_ix(program_id, pool_config, &authority, 0);
+    send_ix(&mut svm, ix, &authority).expect("Resetting to the default should succeed");
+
+    svm.warp_to_slot(start + TokenPoolConfig::UPDATE_SLOT_INTERVAL);
+    let ix = build_finalize_rewards_ix(program_id, pool_config);
+    send_ix(&mut svm, ix, &authority).expect("FinalizeRewards should use the default");
+}
+
+#[test]
+fn test_set_finalize_interval_bounds_and_authority() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (_, pool_config, _) = init_pool(&mut svm, program_id, &authority, 9, u64::MAX, 0, 0);
+
+    for interval in [
+        TokenPoolConfig::MIN_FINALIZE_INTERVAL_SLOTS - 1,
+        TokenPoolConfig::MAX_FINALIZE_INTERVAL_SLOTS + 1,
+    ] {
+        let ix = build_set_finalize_interval_ix(program_id, pool_config, &authority, interval);
+        let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+        assert!(
+            is_custom_error(&err, TokenPoolError::InvalidFinalizeInterval),
+            "{err}"
+        );
+    }
+
+    for interval in [
+        TokenPoolConfig::MIN_FINALIZE_INTERVAL_SLOTS,
+        TokenPoolConfig::MAX_FINALIZE_INTERVAL_SLOTS,
+    ] {
+        let ix = build_set_finalize_interval_ix(program_id, pool_config, &authority, interval);
+        send_ix(&mut svm, ix, &authority).expect("Interval at a bound should succeed");
+    }
+
+    let wrong_authority = Keypair::new();
+    svm.airdrop(&wrong_authority.pubkey(), 1_000_000_000)
+        .unwrap();
+    let ix = build_set_finalize_interval_ix(program_id, pool_config, &wrong_authority, 9_000);
+    let err = send_ix(&mut svm, ix, &wrong_authority).unwrap_err();
+    assert!(is_custom_error(&err, TokenPoolError::Unauthorized), "{err}");
+}
+// =============================================================================
+// Reward Vesting Tests
+// =============================================================================
+
+/// FundRewards with a linear vesting duration
+fn build_vesting_fund_rewards_ix(
+    program_id: Pubkey,
+    pool_config: Pubkey,
+    vault: Pubkey,
+    funder_token: Pubkey,
+    funder: &Keypair,
+    amount: u64,
+    vest_duration_slots: u64,
+) -> Instruction {
+    let mut ix =
+        build_fund_rewards_ix(program_id, pool_config, vault, funder_token, funder, amount);
+    ix.data[9..17].copy_from_slice(&vest_duration_slots.to_le_bytes());
+    ix
+}
+
+#[test]
+fn test_fund_rewards_vests_across_finalizations() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (mint, pool_config, vault) = init_pool(&mut svm, program_id, &authority, 9, u64::MAX, 0, 0);
+    let interval = TokenPoolConfig::UPDATE_SLOT_INTERVAL;
+
+    let funder = Keypair::new();
+    svm.airdrop(&funder.pubkey(), 1_000_000_000).unwrap();
+    let funder_token =
+        create_real_token_account(&mut svm, &authority, &mint, &funder.pubkey(), 1_000_000);
+
+    let ix = build_vesting_fund_rewards_ix(
+        program_id,
+        pool_config,
+        vault,
+        funder_token,
+        &funder,
+        1_000_000,
+        4 * interval,
+    );
+    send_ix(&mut svm, ix, &funder).expect("FundRewards should succeed");
+
+    // Tokens are in the vault and counted, but nothing is pending yet
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(read_token_balance(&svm, &vault), 1_000_000);
+    assert_eq!(config.total_funded_rewards, 1_000_000);
+    assert_eq!(config.pending_funded_rewards, 0);
+    assert_eq!(config.vesting_rewards, 1_000_000);
+    let start = config.vesting_start_slot;
+    assert_eq!(config.vesting_end_slot, start + 4 * interval);
+
+    // A quarter vests per interval; released never exceeds funded
+    let mut previous_vesting = 1_000_000;
+    for i in 1..=4 {
+        svm.warp_to_slot(start + i * interval);
+        let ix = build_finalize_rewards_ix(program_id, pool_config);
+        send_ix(&mut svm, ix, &authority).expect("FinalizeRewards should succeed");
+
+        let config = read_pool_config(&svm, &pool_config);
+        assert!(config.vesting_rewards < previous_vesting);
+        assert_eq!(
+            config.pending_funded_rewards + config.vesting_rewards,
+            1_000_000
+        );
+        previous_vesting = config.vesting_rewards;
+    }
+
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.vesting_rewards, 0);
+    assert_eq!(config.pending_funded_rewards, 1_000_000);
+}
+
+#[test]
+fn test_close_pool_blocked_by_vesting_rewards() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (_, pool_config, vault) = init_pool(&mut svm, program_id, &authority, 9, u64::MAX, 0, 0);
+    modify_pool_config(&mut svm, &pool_config, |config| {
+        config.vesting_rewards = 1;
+    });
+
+    let asset_id = read_pool_config(&svm, &pool_config).asset_id;
+    let ix = build_close_pool_ix(
+        program_id,
+        pool_config,
+        vault,
+        find_hub_pool_config_pda(&asset_id),
+        &authority,
+    );
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(is_custom_error(&err, TokenPoolError::PoolNotEmpty), "{err}");
+}
+
+// =============================================================================
+// Sweep Limit Tests
+// =============================================================================
+
+fn build_sweep_excess_ix(program_id: Pubkey, pool_config: Pubkey, vault: Pubkey) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new_readonly(vault, false),
+            // Include token-pool program for self-CPI (emit_event calls Log instruction)
+            AccountMeta::new_readonly(TOKEN_POOL_PROGRAM_ID, false),
+        ],
+        data: vec![TokenPoolInstruction::SweepExcess as u8],
+    }
+}
+
+#[derive(BorshSerialize)]
+struct SetSweepConfigArgs {
+    max_sweep_per_call: u64,
+    sweep_delay_slots: u64,
+}
+
+fn build_set_sweep_config_ix(
+    program_id: Pubkey,
+    pool_config: Pubkey,
+    authority: &Keypair,
+    max_sweep_per_call: u64,
+    sweep_delay_slots: u64,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+        ],
+        data: build_instruction_data(
+            TokenPoolInstruction::SetSweepConfig as u8,
+            &SetSweepConfigArgs {
+                max_sweep_per_call,
+                sweep_delay_slots,
+            },
+        ),
+    }
+}
+
+fn build_return_excess_ix(
+    program_id: Pubkey,
+    pool_config: Pubkey,
+    vault: Pubkey,
+    destination: Pubkey,
+    authority: &Keypair,
+    amount: u64,
+) -> Instruction {
+    let mut data = vec![TokenPoolInstruction::ReturnExcess as u8];
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new(destination, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(SPL_TOKEN_PROGRAM_ID, false),
+            // Include token-pool program for self-CPI (emit_event calls Log instruction)
+            AccountMeta::new_readonly(TOKEN_POOL_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+/// Init a fee-free pool with the given sweep limits and mint `excess`
+/// straight into its vault, bypassing the program.
+///
+/// Returns (mint, pool_config, vault).
+fn setup_excess_pool(
+    svm: &mut LiteSVM,
+    program_id: Pubkey,
+    authority: &Keypair,
+    max_sweep_per_call: u64,
+    sweep_delay_slots: u64,
+    excess: u64,
+) -> (Pubkey, Pubkey, Pubkey) {
+    let (mint, pool_config, vault) = init_pool(svm, program_id, authority, 9, u64::MAX, 0, 0);
+
+    let ix = build_set_sweep_config_ix(
+        program_id,
+        pool_config,
+        authority,
+        max_sweep_per_call,
+        sweep_delay_slots,
+    );
+    send_ix(svm, ix, authority).expect("SetSweepConfig should succeed");
+
+    MintTo::new(svm, authority, &mint, &vault, excess)
+        .owner(authority)
+        .send()
+        .expect("mint to vault");
+
+    (mint, pool_config, vault)
+}
+
+#[test]
+fn test_sweep_excess_capped_per_call() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (_, pool_config, vault) =
+        setup_excess_pool(&mut svm, program_id, &authority, 400, 0, 1_000);
+
+    for expected_total in [400, 800, 1_000] {
+        let ix = build_sweep_excess_ix(program_id, pool_config, vault);
+        send_ix(&mut svm, ix, &authority).expect("SweepExcess should succeed");
+
+        let config = read_pool_config(&svm, &pool_config);
+        assert_eq!(config.total_swept, expected_total);
+        assert_eq!(config.pending_funded_rewards as u128, expected_total);
+    }
+    assert_eq!(
+        read_pool_config(&svm, &pool_config).total_funded_rewards,
+        1_000
+    );
+}
+
+#[test]
+fn test_sweep_excess_waits_for_delay() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (_, pool_config, vault) =
+        setup_excess_pool(&mut svm, program_id, &authority, 0, 100, 1_000);
+
+    // First call only records the excess
+    let ix = build_sweep_excess_ix(program_id, pool_config, vault);
+    send_ix(&mut svm, ix, &authority).expect("SweepExcess should succeed");
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.detected_excess, 1_000);
+    assert_eq!(config.pending_funded_rewards, 0);
+    let detected_slot = config.excess_detected_slot;
+
+    svm.warp_to_slot(detected_slot + 99);
+    let ix = build_sweep_excess_ix(program_id, pool_config, vault);
+    send_ix(&mut svm, ix, &authority).expect("SweepExcess should succeed");
+    assert_eq!(read_pool_config(&svm, &pool_config).total_swept, 0);
+
+    svm.warp_to_slot(detected_slot + 100);
+    let ix = build_sweep_excess_ix(program_id, pool_config, vault);
+    send_ix(&mut svm, ix, &authority).expect("SweepExcess should succeed");
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.total_swept, 1_000);
+    assert_eq!(config.pending_funded_rewards, 1_000);
+}
+
+#[test]
+fn test_return_excess_reduces_sweepable_excess() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (mint, pool_config, vault) =
+        setup_excess_pool(&mut svm, program_id, &authority, 0, 100, 1_000);
+
+    let ix = build_sweep_excess_ix(program_id, pool_config, vault);
+    send_ix(&mut svm, ix, &authority).expect("SweepExcess should succeed");
+    let detected_slot = read_pool_config(&svm, &pool_config).excess_detected_slot;
+
+    // Send most of it back to the sender
+    let sender = Pubkey::new_unique();
+    let sender_token = create_real_token_account(&mut svm, &authority, &mint, &sender, 0);
+    let ix = build_return_excess_ix(
+        program_id,
+        pool_config,
+        vault,
+        sender_token,
+        &authority,
+        700,
+    );
+    send_ix(&mut svm, ix, &authority).expect("ReturnExcess should succeed");
+
+    assert_eq!(read_token_balance(&svm, &sender_token), 700);
+    assert_eq!(read_token_balance(&svm, &vault), 300);
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.detected_excess, 300);
+    assert_eq!(config.total_excess_returned, 700);
+
+    // Only the remainder is swept after the delay
+    svm.warp_to_slot(detected_slot + 100);
+    let ix = build_sweep_excess_ix(program_id, pool_config, vault);
+    send_ix(&mut svm, ix, &authority).expect("SweepExcess should succeed");
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.total_swept, 300);
+    assert_eq!(config.pending_funded_rewards, 300);
+}
+
+#[test]
+fn test_return_excess_cannot_touch_tracked_funds() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (mint, pool_config, vault) = setup_excess_pool(&mut svm, program_id, &authority, 0, 0, 100);
+
+    // Tracked rewards sit in the vault alongside the excess
+    let funder = Keypair::new();
+    svm.airdrop(&funder.pubkey(), 1_000_000_000).unwrap();
+    let funder_token =
+        create_real_token_account(&mut svm, &authority, &mint, &funder.pubkey(), 5_000);
+    let ix = build_fund_rewards_ix(program_id, pool_config, vault, funder_token, &funder, 5_000);
+    send_ix(&mut svm, ix, &funder).expect("FundRewards should succeed");
+
+    let ix = build_return_excess_ix(
+        program_id,
+        pool_config,
+        vault,
+        funder_token,
+        &authority,
+        101,
+    );
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::InsufficientExcess),
+        "{err}"
+    );
+
+    // Only the authority can return excess
+    let ix = build_return_excess_ix(program_id, pool_config, vault, funder_token, &funder, 100);
+    let err = send_ix(&mut svm, ix, &funder).unwrap_err();
+    assert!(is_custom_error(&err, TokenPoolError::Unauthorized), "{err}");
+
+    let ix = build_set_sweep_config_ix(program_id, pool_config, &funder, 1, 1);
+    let err = send_ix(&mut svm, ix, &funder).unwrap_err();
+    assert!(is_custom_error(&err, TokenPoolError::Unauthorized), "{err}");
+}
+
+// =============================================================================
+// Fee Exemption Tests
+// =============================================================================
+
+#[derive(BorshSerialize)]
+struct FeeExemptArgs {
+    authority: [u8; 32],
+}
+
+fn build_fee_exempt_ix(
+    program_id: Pubkey,
+    pool_config: Pubkey,
+    authority: &Keypair,
+    exempt: Pubkey,
+    add: bool,
+) -> Instruction {
+    let discriminator = if add {
+        TokenPoolInstruction::AddFeeExempt
+    } else {
+        TokenPoolInstruction::RemoveFeeExempt
+    };
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+        ],
+        data: build_instruction_data(
+            discriminator as u8,
+            &FeeExemptArgs {
+                authority: exempt.to_bytes(),
+            },
+        ),
+    }
+}
+
+/// Deposit with an explicit `depositor` / `expect_fee_exempt` in the params.
+#[allow(clippy::too_many_arguments)]
+fn build_exempt_deposit_ix(
+    program_id: Pubkey,
+    pool_config: Pubkey,
+    vault: Pubkey,
+    depositor_token: Pubkey,
+    depositor: &Keypair,
+    amount: u64,
+    expected_output: u64,
+    params_depositor: Pubkey,
+    expect_fee_exempt: bool,
+) -> Instruction {
+    let mut ix = build_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        depositor,
+        amount,
+        expected_output,
+    );
+    ix.data = build_deposit_data(
+        amount,
+        expected_output,
+        &params_depositor,
+        expect_fee_exempt,
+    );
+    ix
+}
+
+/// Init a pool with a 1% deposit fee and a funded depositor.
+///
+/// Returns (pool_config, vault, depositor, depositor token account).
+fn setup_fee_exempt_pool(
+    svm: &mut LiteSVM,
+    program_id: Pubkey,
+    authority: &Keypair,
+) -> (Pubkey, Pubkey, Keypair, Pubkey) {
+    let (mint, pool_config, vault) = init_pool(svm, program_id, authority, 9, u64::MAX, 100, 100);
+
+    let depositor = Keypair::new();
+    svm.airdrop(&depositor.pubkey(), 1_000_000_000).unwrap();
+    let depositor_token =
+        create_real_token_account(svm, authority, &mint, &depositor.pubkey(), 10_000);
+
+    (pool_config, vault, depositor, depositor_token)
+}
+
+#[test]
+fn test_fee_exempt_deposit_skips_fee() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (pool_config, vault, depositor, depositor_token) =
+        setup_fee_exempt_pool(&mut svm, program_id, &authority);
+
+    let ix = build_fee_exempt_ix(
+        program_id,
+        pool_config,
+        &authority,
+        depositor.pubkey(),
+        true,
+    );
+    send_ix(&mut svm, ix, &authority).expect("AddFeeExempt should succeed");
+
+    let ix = build_exempt_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        1_000,
+        1_000,
+        depositor.pubkey(),
+        true,
+    );
+    send_ix(&mut svm, ix, &depositor).expect("exempt deposit should succeed");
+
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.pending_deposits, 1_000);
+    assert_eq!(config.pending_deposit_fees, 0);
+    assert_eq!(config.total_fee_exempt_volume, 1_000);
+
+    // Once removed, the same depositor pays the normal fee
+    let ix = build_fee_exempt_ix(
+        program_id,
+        pool_config,
+        &authority,
+        depositor.pubkey(),
+        false,
+    );
+    send_ix(&mut svm, ix, &authority).expect("RemoveFeeExempt should succeed");
+
+    let ix = build_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        1_000,
+        990,
+    );
+    send_ix(&mut svm, ix, &depositor).expect("non-exempt deposit should succeed");
+
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.pending_deposits, 1_990);
+    assert_eq!(config.pending_deposit_fees, 10);
+    assert_eq!(config.total_fee_exempt_volume, 1_000);
+}
+
+#[test]
+fn test_fee_exemption_mismatch_rejected() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (pool_config, vault, depositor, depositor_token) =
+        setup_fee_exempt_pool(&mut svm, program_id, &authority);
+
+    // Expecting an exemption the pool does not grant
+    let ix = build_exempt_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        1_000,
+        1_000,
+        depositor.pubkey(),
+        true,
+    );
+    let err = send_ix(&mut svm, ix, &depositor).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::FeeExemptionMismatch),
+        "{err}"
+    );
+
+    let rebalancer = Keypair::new();
+    let ix = build_fee_exempt_ix(
+        program_id,
+        pool_config,
+        &authority,
+        rebalancer.pubkey(),
+        true,
+    );
+    send_ix(&mut svm, ix, &authority).expect("AddFeeExempt should succeed");
+
+    // Pricing in a fee for a listed authority is also a mismatch
+    let ix = build_exempt_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        1_000,
+        990,
+        rebalancer.pubkey(),
+        false,
+    );
+    let err = send_ix(&mut svm, ix, &depositor).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::FeeExemptionMismatch),
+        "{err}"
+    );
+
+    // A listed authority cannot be claimed by a different signer
+    let ix = build_exempt_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        1_000,
+        1_000,
+        rebalancer.pubkey(),
+        true,
+    );
+    let err = send_ix(&mut svm, ix, &depositor).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::FeeExemptionMismatch),
+        "{err}"
+    );
+    assert_eq!(
+        read_pool_config(&svm, &pool_config).total_fee_exempt_volume,
+        0
+    );
+}
+
+#[test]
+fn test_fee_exempt_admin_unauthorized() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (pool_config, _, depositor, _) = setup_fee_exempt_pool(&mut svm, program_id, &authority);
+
+    let ix = build_fee_exempt_ix(
+        program_id,
+        pool_config,
+        &depositor,
+        depositor.pubkey(),
+        true,
+    );
+    let err = send_ix(&mut svm, ix, &depositor).unwrap_err();
+    assert!(is_custom_error(&err, TokenPoolError::Unauthorized), "{err}");
+
+    let ix = build_fee_exempt_ix(
+        program_id,
+        pool_config,
+        &authority,
+        depositor.pubkey(),
+        false,
+    );
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::FeeExemptNotListed),
+        "{err}"
+    );
+}
+
+// =============================================================================
+// Vault Reconciliation Tests
+// =============================================================================
+
+fn build_reconcile_vault_ix(program_id: Pubkey, pool_config: Pubkey, vault: Pubkey) -> Instruction {
+    let mut ix = build_sweep_excess_ix(program_id, pool_config, vault);
+    ix.data = vec![TokenPoolInstruction::ReconcileVault as u8];
+    ix
+}
+
+fn build_clear_vault_deficit_ix(
+    program_id: Pubkey,
+    pool_config: Pubkey,
+    authority: &Keypair,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+        ],
+        data: vec![TokenPoolInstruction::ClearVaultDeficit as u8],
+    }
+}
+
+#[test]
+fn test_reconcile_vault_balanced_and_surplus() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (pool_config, vault, depositor, depositor_token) =
+        setup_limited_pool(&mut svm, program_id, &authority, 0, 0, 0);
+    let ix = build_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        1_000,
+        1_000,
+    );
+    send_ix(&mut svm, ix, &depositor).expect("deposit should succeed");
+
+    // Balanced: nothing recorded beyond the reconciliation slot
+    svm.warp_to_slot(10);
+    let ix = build_reconcile_vault_ix(program_id, pool_config, vault);
+    send_ix(&mut svm, ix, &authority).expect("ReconcileVault should succeed");
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.last_reconciled_slot, 10);
+    assert_eq!(config.detected_excess, 0);
+    assert_eq!(config.deficit_detected, 0);
+
+    // Surplus: recorded as excess for SweepExcess
+    let mint = Pubkey::new_from_array(config.mint);
+    MintTo::new(&mut svm, &authority, &mint, &vault, 250)
+        .owner(&authority)
+        .send()
+        .expect("mint to vault");
+    svm.expire_blockhash();
+    let ix = build_reconcile_vault_ix(program_id, pool_config, vault);
+    send_ix(&mut svm, ix, &authority).expect("ReconcileVault should succeed");
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.detected_excess, 250);
+    assert_eq!(config.excess_detected_slot, 10);
+    assert_eq!(config.deficit_detected, 0);
+}
+
+#[test]
+fn test_reconcile_vault_deficit_pauses_deposits() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (pool_config, vault, depositor, depositor_token) =
+        setup_limited_pool(&mut svm, program_id, &authority, 0, 0, 0);
+
+    // Accounting claims 100 tokens the vault does not hold
+    modify_pool_config(&mut svm, &pool_config, |config| {
+        config.total_deposited = 100
+    });
+
+    let ix = build_reconcile_vault_ix(program_id, pool_config, vault);
+    send_ix(&mut svm, ix, &authority).expect("ReconcileVault should succeed");
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.deficit_detected, 1);
+    assert_eq!(config.vault_deficit, 100);
+
+    let ix = build_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        1_000,
+        1_000,
+    );
+    let err = send_ix(&mut svm, ix, &depositor).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::VaultDeficitDetected),
+        "{err}"
+    );
+
+    // Only the authority can clear the deficit
+    let ix = build_clear_vault_deficit_ix(program_id, pool_config, &depositor);
+    let err = send_ix(&mut svm, ix, &depositor).unwrap_err();
+    assert!(is_custom_error(&err, TokenPoolError::Unauthorized), "{err}");
+
+    let ix = build_clear_vault_deficit_ix(program_id, pool_config, &authority);
+    send_ix(&mut svm, ix, &authority).expect("ClearVaultDeficit should succeed");
+    assert_eq!(read_pool_config(&svm, &pool_config).deficit_detected, 0);
+
+    svm.expire_blockhash();
+    let ix = build_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        1_000,
+        1_000,
+    );
+    send_ix(&mut svm, ix, &depositor).expect("deposit should succeed after clearing");
+}
+// =============================================================================
+// Emergency Withdrawal Tests
+// =============================================================================
+
+#[derive(BorshSerialize)]
+struct InitiateEmergencyWithdrawArgs {
+    amount: u64,
+    destination: [u8; 32],
+}
+
+fn build_initiate_emergency_withdraw_ix(
+    program_id: Pubkey,
+    pool_config: Pubkey,
+    authority: &Keypair,
+    amount: u64,
+    destination: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            // Include token-pool program for self-CPI (emit_event calls Log instruction)
+            AccountMeta::new_readonly(TOKEN_POOL_PROGRAM_ID, false),
+        ],
+        data: build_instruction_data(
+            TokenPoolInstruction::InitiateEmergencyWithdraw as u8,
+            &InitiateEmergencyWithdrawArgs {
+                amount,
+                destination: destination.to_bytes(),
+            },
+        ),
+    }
+}
+
+fn build_cancel_emergency_withdraw_ix(
+    program_id: Pubkey,
+    pool_config: Pubkey,
+    authority: &Keypair,
+) -> Instruction {
+    let mut ix = build_initiate_emergency_withdraw_ix(
+        program_id,
+        pool_config,
+        authority,
+        0,
+        Pubkey::default(),
+    );
+    ix.data = vec![TokenPoolInstruction::CancelEmergencyWithdraw as u8];
+    ix
+}
+
+fn build_execute_emergency_withdraw_ix(
+    program_id: Pubkey,
+    pool_config: Pubkey,
+    vault: Pubkey,
+    destination: Pubkey,
+    authority: &Keypair,
+) -> Instruction {
+    let mut ix = build_return_excess_ix(program_id, pool_config, vault, destination, authority, 0);
+    ix.data = vec![TokenPoolInstruction::ExecuteEmergencyWithdraw as u8];
+    ix
+}
+
+fn build_set_emergency_withdraw_delay_ix(
+    program_id: Pubkey,
+    pool_config: Pubkey,
+    authority: &Keypair,
+    delay_slots: u64,
+) -> Instruction {
+    let mut data = vec![TokenPoolInstruction::SetEmergencyWithdrawDelay as u8];
+    data.extend_from_slice(&delay_slots.to_le_bytes());
+
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+        ],
+        data,
+    }
+}
+
+/// Init a fee-free pool holding a 1_000 deposit and an empty destination account.
+///
+/// Returns (pool_config, vault, destination token account).
+fn setup_emergency_pool(
+    svm: &mut LiteSVM,
+    program_id: Pubkey,
+    authority: &Keypair,
+) -> (Pubkey, Pubkey, Pubkey) {
+    let (pool_config, vault, depositor, depositor_token) =
+        setup_limited_pool(svm, program_id, authority, 0, 0, 0);
+    let ix = build_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        1_000,
+        1_000,
+    );
+    send_ix(svm, ix, &depositor).expect("deposit should succeed");
+
+    let mint = Pubkey::new_from_array(read_pool_config(svm, &pool_config).mint);
+    let destination = create_real_token_account(svm, authority, &mint, &Pubkey::new_unique(), 0);
+
+    (pool_config, vault, destination)
+}
+
+#[test]
+fn test_emergency_withdraw_lifecycle() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (pool_config, vault, destination) = setup_emergency_pool(&mut svm, program_id, &authority);
+
+    svm.warp_to_slot(10);
+    let ix =
+        build_initiate_emergency_withdraw_ix(program_id, pool_config, &authority, 400, destination);
+    send_ix(&mut svm, ix, &authority).expect("InitiateEmergencyWithdraw should succeed");
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.emergency_withdraw_amount, 400);
+    assert_eq!(
+        config.emergency_withdraw_destination,
+        destination.to_bytes()
+    );
+    let unlock_slot = config.emergency_withdraw_unlock_slot;
+    assert_eq!(
+        unlock_slot,
+        10 + TokenPoolConfig::MIN_EMERGENCY_WITHDRAW_DELAY_SLOTS
+    );
+
+    // Locked until the unlock slot
+    svm.warp_to_slot(unlock_slot - 1);
+    let ix = build_execute_emergency_withdraw_ix(
+        program_id,
+        pool_config,
+        vault,
+        destination,
+        &authority,
+    );
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::EmergencyWithdrawLocked),
+        "{err}"
+    );
+
+    // Only to the recorded destination
+    svm.warp_to_slot(unlock_slot);
+    let mint = Pubkey::new_from_array(config.mint);
+    let other = create_real_token_account(&mut svm, &authority, &mint, &Pubkey::new_unique(), 0);
+    let ix = build_execute_emergency_withdraw_ix(program_id, pool_config, vault, other, &authority);
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::InvalidEmergencyWithdrawDestination),
+        "{err}"
+    );
+
+    let ix = build_execute_emergency_withdraw_ix(
+        program_id,
+        pool_config,
+        vault,
+        destination,
+        &authority,
+    );
+    send_ix(&mut svm, ix, &authority).expect("ExecuteEmergencyWithdraw should succeed");
+
+    assert_eq!(read_token_balance(&svm, &destination), 400);
+    assert_eq!(read_token_balance(&svm, &vault), 600);
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.emergency_withdraw_amount, 0);
+    assert_eq!(config.total_emergency_withdrawn, 400);
+    assert_eq!(config.pending_withdrawals, 400);
+    assert_eq!(config.current_balance(), Ok(600));
+
+    // The vault still matches the accounting
+    let ix = build_reconcile_vault_ix(program_id, pool_config, vault);
+    send_ix(&mut svm, ix, &authority).expect("ReconcileVault should succeed");
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.deficit_detected, 0);
+    assert_eq!(config.detected_excess, 0);
+}
+
+#[test]
+fn test_emergency_withdraw_cancel() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (pool_config, vault, destination) = setup_emergency_pool(&mut svm, program_id, &authority);
+
+    let ix =
+        build_initiate_emergency_withdraw_ix(program_id, pool_config, &authority, 400, destination);
+    send_ix(&mut svm, ix, &authority).expect("InitiateEmergencyWithdraw should succeed");
+    let unlock_slot = read_pool_config(&svm, &pool_config).emergency_withdraw_unlock_slot;
+
+    let ix = build_cancel_emergency_withdraw_ix(program_id, pool_config, &authority);
+    send_ix(&mut svm, ix, &authority).expect("CancelEmergencyWithdraw should succeed");
+
+    svm.warp_to_slot(unlock_slot);
+    let ix = build_execute_emergency_withdraw_ix(
+        program_id,
+        pool_config,
+        vault,
+        destination,
+        &authority,
+    );
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::NoEmergencyWithdrawPending),
+        "{err}"
+    );
+
+    assert_eq!(read_token_balance(&svm, &vault), 1_000);
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.total_emergency_withdrawn, 0);
+    assert_eq!(config.current_balance(), Ok(1_000));
+}
+
+#[test]
+fn test_emergency_withdraw_admin_checks() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+    let attacker = Keypair::new();
+    svm.airdrop(&attacker.pubkey(), 1_000_000_000).unwrap();
+
+    let (pool_config, _, destination) = setup_emergency_pool(&mut svm, program_id, &authority);
+
+    let ix =
+        build_initiate_emergency_withdraw_ix(program_id, pool_config, &attacker, 400, destination);
+    let err = send_ix(&mut svm, ix, &attacker).unwrap_err();
+    assert!(is_custom_error(&err, TokenPoolError::Unauthorized), "{err}");
+
+    // More than the accounted pool balance
+    let ix = build_initiate_emergency_withdraw_ix(
+        program_id,
+        pool_config,
+        &authority,
+        1_001,
+        destination,
+    );
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::InsufficientBalance),
+        "{err}"
+    );
+
+    // The delay cannot go below the floor
+    let floor = TokenPoolConfig::MIN_EMERGENCY_WITHDRAW_DELAY_SLOTS;
+    let ix = build_set_emergency_withdraw_delay_ix(program_id, pool_config, &authority, floor - 1);
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::InvalidEmergencyWithdrawDelay),
+        "{err}"
+    );
+
+    let ix = build_set_emergency_withdraw_delay_ix(program_id, pool_config, &attacker, floor * 2);
+    let err = send_ix(&mut svm, ix, &attacker).unwrap_err();
+    assert!(is_custom_error(&err, TokenPoolError::Unauthorized), "{err}");
+
+    let ix = build_set_emergency_withdraw_delay_ix(program_id, pool_config, &authority, floor * 2);
+    send_ix(&mut svm, ix, &authority).expect("SetEmergencyWithdrawDelay should succeed");
+    assert_eq!(
+        read_pool_config(&svm, &pool_config).emergency_withdraw_delay_slots,
+        floor * 2
+    );
+}
+// =============================================================================
+// Vault Migration Tests
+// =============================================================================
+
+fn find_migrated_vault_pda(program_id: &Pubkey, pool_config: &Pubkey, generation: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"migrated_vault",
+            pool_config.as_ref(),
+            &generation.to_le_bytes(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+fn build_initiate_vault_migration_ix(
+    program_id: Pubkey,
+    pool_config: Pubkey,
+    new_vault: Pubkey,
+    authority: &Keypair,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new_readonly(new_vault, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            // Include token-pool program for self-CPI (emit_event calls Log instruction)
+            AccountMeta::new_readonly(TOKEN_POOL_PROGRAM_ID, false),
+        ],
+        data: vec![TokenPoolInstruction::InitiateVaultMigration as u8],
+    }
+}
+
+fn build_migrate_vault_ix(
+    program_id: Pubkey,
+    pool_config: Pubkey,
+    old_vault: Pubkey,
+    new_vault: Pubkey,
+    mint: Pubkey,
+    authority: &Keypair,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new(old_vault, false),
+            AccountMeta::new(new_vault, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(authority.pubkey(), true),
+            AccountMeta::new_readonly(SPL_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+            // Include token-pool program for self-CPI (emit_event calls Log instruction)
+            AccountMeta::new_readonly(TOKEN_POOL_PROGRAM_ID, false),
+        ],
+        data: vec![TokenPoolInstruction::MigrateVault as u8],
+    }
+}
+
+/// Queue and execute a migration to the first replacement vault.
+///
+/// Returns the new vault.
+fn migrate_to_first_replacement(
+    svm: &mut LiteSVM,
+    program_id: Pubkey,
+    pool_config: Pubkey,
+    vault: Pubkey,
+    authority: &Keypair,
+) -> Pubkey {
+    let new_vault = find_migrated_vault_pda(&program_id, &pool_config, 1);
+    let mint = Pubkey::new_from_array(read_pool_config(svm, &pool_config).mint);
+
+    let ix = build_initiate_vault_migration_ix(program_id, pool_config, new_vault, authority);
+    send_ix(svm, ix, authority).expect("InitiateVaultMigration should succeed");
+
+    let unlock_slot = read_pool_config(svm, &pool_config).vault_migration_unlock_slot;
+    svm.warp_to_slot(unlock_slot);
+    let ix = build_migrate_vault_ix(program_id, pool_config, vault, new_vault, mint, authority);
+    send_ix(svm, ix, authority).expect("MigrateVault should succeed");
+
+    new_vault
+}
+
+#[test]
+fn test_migrate_vault_moves_full_balance() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (pool_config, vault, _) = setup_emergency_pool(&mut svm, program_id, &authority);
+    let mint = Pubkey::new_from_array(read_pool_config(&svm, &pool_config).mint);
+    let new_vault = find_migrated_vault_pda(&program_id, &pool_config, 1);
+
+    // Only the next generation's PDA can be queued
+    let wrong = find_migrated_vault_pda(&program_id, &pool_config, 2);
+    let ix = build_initiate_vault_migration_ix(program_id, pool_config, wrong, &authority);
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::InvalidVaultPda),
+        "{err}"
+    );
+
+    let ix = build_initiate_vault_migration_ix(program_id, pool_config, new_vault, &authority);
+    send_ix(&mut svm, ix, &authority).expect("InitiateVaultMigration should succeed");
+    let unlock_slot = read_pool_config(&svm, &pool_config).vault_migration_unlock_slot;
+
+    // Locked until the unlock slot
+    let ix = build_migrate_vault_ix(program_id, pool_config, vault, new_vault, mint, &authority);
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::VaultMigrationLocked),
+        "{err}"
+    );
+
+    svm.warp_to_slot(unlock_slot);
+    let ix = build_migrate_vault_ix(program_id, pool_config, vault, new_vault, mint, &authority);
+    send_ix(&mut svm, ix, &authority).expect("MigrateVault should succeed");
+
+    assert_eq!(read_token_balance(&svm, &vault), 0);
+    assert_eq!(read_token_balance(&svm, &new_vault), 1_000);
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.vault, new_vault.to_bytes());
+    assert_eq!(config.vault_generation, 1);
+    assert_eq!(config.pending_vault, [0u8; 32]);
+
+    // Accounting is unchanged and matches the new vault
+    assert_eq!(config.current_balance(), Ok(1_000));
+    let ix = build_reconcile_vault_ix(program_id, pool_config, new_vault);
+    send_ix(&mut svm, ix, &authority).expect("ReconcileVault should succeed");
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.deficit_detected, 0);
+    assert_eq!(config.detected_excess, 0);
+}
+
+#[test]
+fn test_deposits_land_in_migrated_vault() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (pool_config, vault, depositor, depositor_token) =
+        setup_limited_pool(&mut svm, program_id, &authority, 0, 0, 0);
+    let new_vault =
+        migrate_to_first_replacement(&mut svm, program_id, pool_config, vault, &authority);
+
+    let ix = build_deposit_ix(
+        program_id,
+        pool_config,
+        new_vault,
+        depositor_token,
+        &depositor,
+        1_000,
+        1_000,
+    );
+    send_ix(&mut svm, ix, &depositor).expect("deposit should succeed");
+
+    assert_eq!(read_token_balance(&svm, &new_vault), 1_000);
+    assert_eq!(read_token_balance(&svm, &vault), 0);
+    assert_eq!(read_pool_config(&svm, &pool_config).pending_deposits, 1_000);
+}
+
+#[test]
+fn test_old_vault_rejected_after_migration() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (pool_config, vault, depositor, depositor_token) =
+        setup_limited_pool(&mut svm, program_id, &authority, 0, 0, 0);
+    migrate_to_first_replacement(&mut svm, program_id, pool_config, vault, &authority);
+
+    let ix = build_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        1_000,
+        1_000,
+    );
+    let err = send_ix(&mut svm, ix, &depositor).unwrap_err();
+    assert!(is_custom_error(&err, TokenPoolError::InvalidVault), "{err}");
+
+    let ix = build_sweep_excess_ix(program_id, pool_config, vault);
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(is_custom_error(&err, TokenPoolError::InvalidVault), "{err}");
+
+    let ix = build_reconcile_vault_ix(program_id, pool_config, vault);
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(is_custom_error(&err, TokenPoolError::InvalidVault), "{err}");
+}
+// =============================================================================
+// Epoch Statistics Tests
+// =============================================================================
+
+#[test]
+fn test_epoch_stats_across_finalizations() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    // 1% deposit fee
+    let (mint, pool_config, vault) =
+        init_pool(&mut svm, program_id, &authority, 9, u64::MAX, 100, 100);
+
+    let depositor = Keypair::new();
+    svm.airdrop(&depositor.pubkey(), 1_000_000_000).unwrap();
+    let depositor_token =
+        create_real_token_account(&mut svm, &authority, &mint, &depositor.pubkey(), 10_000);
+
+    // Epoch 1: two deposits
+    for amount in [1_000u64, 2_000] {
+        let fee = amount / 100;
+        let ix = build_deposit_ix(
+            program_id,
+            pool_config,
+            vault,
+            depositor_token,
+            &depositor,
+            amount,
+            amount - fee,
+        );
+        send_ix(&mut svm, ix, &depositor).expect("deposit should succeed");
+    }
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.epoch_deposit_volume, 3_000);
+    assert_eq!(config.epoch_deposit_fees, 30);
+    assert_eq!(config.epoch_withdrawal_volume, 0);
+    assert_eq!(config.epoch_withdrawal_fees, 0);
+
+    svm.warp_to_slot(UPDATE_SLOT_INTERVAL + 100);
+    let ix = build_finalize_rewards_ix(program_id, pool_config);
+    send_ix(&mut svm, ix, &authority).expect("FinalizeRewards should succeed");
+
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.epoch_deposit_volume, 0);
+    assert_eq!(config.epoch_deposit_fees, 0);
+
+    // Epoch 2: one deposit, counted from zero
+    let ix = build_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        5_000,
+        4_950,
+    );
+    send_ix(&mut svm, ix, &depositor).expect("deposit should succeed");
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.epoch_deposit_volume, 5_000);
+    assert_eq!(config.epoch_deposit_fees, 50);
+
+    svm.warp_to_slot(2 * UPDATE_SLOT_INTERVAL + 200);
+    let ix = build_finalize_rewards_ix(program_id, pool_config);
+    send_ix(&mut svm, ix, &authority).expect("FinalizeRewards should succeed");
+
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.epoch_deposit_volume, 0);
+    assert_eq!(config.epoch_deposit_fees, 0);
+}
+// =============================================================================
+// Withdrawal Policy Tests
+// =============================================================================
+
+fn build_set_withdrawal_policy_ix(
+    program_id: Pubkey,
+    pool_config: Pubkey,
+    authority: &Keypair,
+    min_withdrawal: u64,
+    round_fees_up: bool,
+) -> Instruction {
+    let mut data = vec![TokenPoolInstruction::SetWithdrawalPolicy as u8];
+    data.extend_from_slice(&min_withdrawal.to_le_bytes());
+    data.push(round_fees_up as u8);
+    data.extend_from_slice(&[0u8; 7]);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+        ],
+        data,
+    }
+}
+
+#[test]
+fn test_set_withdrawal_policy() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+    let (_, pool_config, _) = init_pool(&mut svm, program_id, &authority, 9, u64::MAX, 0, 0);
+
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.min_withdrawal, 0);
+    assert_eq!(config.round_fees_up, 0);
+
+    let ix = build_set_withdrawal_policy_ix(program_id, pool_config, &authority, 5_000, true);
+    send_ix(&mut svm, ix, &authority).expect("SetWithdrawalPolicy should succeed");
+
+    let config = read_pool_config(&svm, &pool_config);
+    assert_eq!(config.min_withdrawal, 5_000);
+    assert_eq!(config.round_fees_up, 1);
+
+    // Only the authority may change the policy
+    let attacker = Keypair::new();
+    svm.airdrop(&attacker.pubkey(), 1_000_000_000).unwrap();
+    let ix = build_set_withdrawal_policy_ix(program_id, pool_config, &attacker, 0, false);
+    assert!(send_ix(&mut svm, ix, &attacker).is_err());
+}
+
+#[test]
+fn test_deposit_fee_rounds_up_at_boundary() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    // 1 bps deposit fee, rounded up
+    let (mint, pool_config, vault) = init_pool(&mut svm, program_id, &authority, 9, u64::MAX, 1, 1);
+    let ix = build_set_withdrawal_policy_ix(program_id, pool_config, &authority, 0, true);
+    send_ix(&mut svm, ix, &authority).expect("SetWithdrawalPolicy should succeed");
+
+    let depositor = Keypair::new();
+    svm.airdrop(&depositor.pubkey(), 1_000_000_000).unwrap();
+    let depositor_token =
+        create_real_token_account(&mut svm, &authority, &mint, &depositor.pubkey(), 100_000);
+
+    // 10_001 at 1 bps is 1.0001: rounding down (fee 1) is rejected
+    let ix = build_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        10_001,
+        10_000,
+    );
+    let err = send_ix(&mut svm, ix, &depositor).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::ExpectedOutputMismatch),
+        "{err}"
+    );
+
+    // Rounding up (fee 2) is accepted
+    let ix = build_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        10_001,
+        9_999,
+    );
+    send_ix(&mut svm, ix, &depositor).expect("deposit should succeed");
+    assert_eq!(read_pool_config(&svm, &pool_config).pending_deposit_fees, 2);
+
+    // An exact multiple pays the same fee in either direction
+    let ix = build_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        10_000,
+        9_999,
+    );
+    send_ix(&mut svm, ix, &depositor).expect("deposit should succeed");
+}
+// =============================================================================
+// InitPool ATA Vault / Idempotency Tests
+// =============================================================================
+
+#[test]
+fn test_init_pool_creates_ata_vault() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (mint, pool_config, vault) = init_pool(&mut svm, program_id, &authority, 9, 1_000, 10, 20);
+
+    assert_eq!(
+        vault,
+        find_vault_ata(&pool_config, &mint, &SPL_TOKEN_PROGRAM_ID)
+    );
+    assert_eq!(read_pool_config(&svm, &pool_config).vault, vault.to_bytes());
+
+    let vault_account = svm.get_account(&vault).expect("vault should exist");
+    assert_eq!(vault_account.owner, SPL_TOKEN_PROGRAM_ID);
+    // SPL token account layout: mint at 0, owner at 32
+    assert_eq!(&vault_account.data[0..32], mint.as_ref());
+    assert_eq!(&vault_account.data[32..64], pool_config.as_ref());
+}
+
+#[test]
+fn test_init_pool_rerun_is_noop() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (mint, pool_config, vault) = init_pool(&mut svm, program_id, &authority, 9, 1_000, 10, 20);
+
+    // Move some state so a re-initialization would be visible
+    let depositor = Keypair::new();
+    svm.airdrop(&depositor.pubkey(), 1_000_000_000).unwrap();
+    let depositor_token =
+        create_real_token_account(&mut svm, &authority, &mint, &depositor.pubkey(), 1_000);
+    let ix = build_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        1_000,
+        999,
+    );
+    send_ix(&mut svm, ix, &depositor).expect("deposit should succeed");
+
+    let config_before = svm.get_account(&pool_config).unwrap().data;
+    let vault_before = svm.get_account(&vault).unwrap().data;
+
+    svm.expire_blockhash();
+    let ix = build_init_pool_ix(program_id, mint, &authority, 1_000, 10, 20);
+    send_ix(&mut svm, ix, &authority).expect("re-running InitPool should succeed");
+
+    assert_eq!(svm.get_account(&pool_config).unwrap().data, config_before);
+    assert_eq!(svm.get_account(&vault).unwrap().data, vault_before);
+}
+
+#[test]
+fn test_init_pool_rerun_rejects_mismatched_params() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (mint, pool_config, _vault) = init_pool(&mut svm, program_id, &authority, 9, 1_000, 10, 20);
+    let config_before = svm.get_account(&pool_config).unwrap().data;
+
+    let mismatches = [(2_000, 10, 20), (1_000, 11, 20), (1_000, 10, 21)];
+    for (max_deposit_amount, deposit_fee_rate, withdrawal_fee_rate) in mismatches {
+        svm.expire_blockhash();
+        let ix = build_init_pool_ix(
+            program_id,
+            mint,
+            &authority,
+            max_deposit_amount,
+            deposit_fee_rate,
+            withdrawal_fee_rate,
+        );
+        let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+        assert!(
+            is_custom_error(&err, TokenPoolError::AlreadyInitialized),
+            "{err}"
+        );
+    }
+
+    // A different authority is also a mismatch
+    let other = Keypair::new();
+    svm.airdrop(&other.pubkey(), 10_000_000_000).unwrap();
+    let ix = build_init_pool_ix(program_id, mint, &other, 1_000, 10, 20);
+    let err = send_ix(&mut svm, ix, &other).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::AlreadyInitialized),
+        "{err}"
+    );
+
+    assert_eq!(svm.get_account(&pool_config).unwrap().data, config_before);
+}
+
+#[test]
+fn test_deposit_uses_stored_vault() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (mint, pool_config, vault) = init_pool(&mut svm, program_id, &authority, 9, u64::MAX, 0, 0);
+
+    let depositor = Keypair::new();
+    svm.airdrop(&depositor.pubkey(), 1_000_000_000).unwrap();
+    let depositor_token =
+        create_real_token_account(&mut svm, &authority, &mint, &depositor.pubkey(), 10_000);
+
+    // The legacy PDA is not this pool's vault
+    let (legacy_vault, _) = find_vault_pda(&program_id, &pool_config);
+    let ix = build_deposit_ix(
+        program_id,
+        pool_config,
+        legacy_vault,
+        depositor_token,
+        &depositor,
+        1_000,
+        1_000,
+    );
+    let err = send_ix(&mut svm, ix, &depositor).unwrap_err();
+    assert!(is_custom_error(&err, TokenPoolError::InvalidVault), "{err}");
+
+    let ix = build_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        1_000,
+        1_000,
+    );
+    send_ix(&mut svm, ix, &depositor).expect("deposit should succeed");
+    assert_eq!(read_token_balance(&svm, &vault), 1_000);
+
+    // Simulate a pool created before ATA vaults: its vault is the legacy PDA
+    let legacy_account = create_real_token_account(&mut svm, &authority, &mint, &pool_config, 0);
+    let account = svm.get_account(&legacy_account).unwrap();
+    svm.set_account(legacy_vault, account).unwrap();
+    modify_pool_config(&mut svm, &pool_config, |config| {
+        config.vault = legacy_vault.to_bytes();
+    });
+
+    svm.expire_blockhash();
+    let ix = build_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        1_000,
+        1_000,
+    );
+    let err = send_ix(&mut svm, ix, &depositor).unwrap_err();
+    assert!(is_custom_error(&err, TokenPoolError::InvalidVault), "{err}");
+
+    let ix = build_deposit_ix(
+        program_id,
+        pool_config,
+        legacy_vault,
+        depositor_token,
+        &depositor,
+        1_000,
+        1_000,
+    );
+    send_ix(&mut svm, ix, &depositor).expect("deposit to the legacy vault should succeed");
+    assert_eq!(read_token_balance(&svm, &legacy_vault), 1_000);
+}
+// =============================================================================
+// FinalizeRewardsBatch Tests
+// =============================================================================
+
+fn build_finalize_rewards_batch_ix(program_id: Pubkey, pools: &[(Pubkey, Pubkey)]) -> Instruction {
+    let mut accounts = vec![
+        // Include token-pool program for self-CPI (emit_event calls Log instruction)
+        AccountMeta::new_readonly(TOKEN_POOL_PROGRAM_ID, false),
+    ];
+    for (pool_config, vault) in pools {
+        accounts.push(AccountMeta::new(*pool_config, false));
+        accounts.push(AccountMeta::new_readonly(*vault, false));
+    }
+    Instruction {
+        program_id,
+        accounts,
+        data: vec![TokenPoolInstruction::FinalizeRewardsBatch as u8],
+    }
+}
+
+/// Initialize a pool and deposit `amount` into it with a 1% deposit fee
+fn setup_pool_with_deposit(
+    svm: &mut LiteSVM,
+    program_id: Pubkey,
+    authority: &Keypair,
+    amount: u64,
+) -> (Pubkey, Pubkey) {
+    let (mint, pool_config, vault) = init_pool(svm, program_id, authority, 9, u64::MAX, 100, 0);
+
+    let depositor = Keypair::new();
+    svm.airdrop(&depositor.pubkey(), 1_000_000_000).unwrap();
+    let depositor_token =
+        create_real_token_account(svm, authority, &mint, &depositor.pubkey(), amount);
+    let fee = amount / 100;
+    let ix = build_deposit_ix(
+        program_id,
+        pool_config,
+        vault,
+        depositor_token,
+        &depositor,
+        amount,
+        amount - fee,
+    );
+    send_ix(svm, ix, &depositor).expect("deposit should succeed");
+
+    (pool_config, vault)
+}
+
+#[test]
+fn test_finalize_rewards_batch_skips_pools_not_due() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let pools: Vec<(Pubkey, Pubkey)> = (0..3)
+        .map(|_| setup_pool_with_deposit(&mut svm, program_id, &authority, 1_000_000))
+        .collect();
+
+    svm.warp_to_slot(UPDATE_SLOT_INTERVAL + 100);
+    svm.expire_blockhash();
+
+    // Finalize the middle pool on its own so it is not due for the batch
+    let ix = build_finalize_rewards_ix(program_id, pools[1].0);
+    send_ix(&mut svm, ix, &authority).expect("FinalizeRewards should succeed");
+    let skipped_before = read_pool_config(&svm, &pools[1].0);
+
+    let ix = build_finalize_rewards_batch_ix(program_id, &pools);
+    send_ix(&mut svm, ix, &authority).expect("FinalizeRewardsBatch should succeed");
+
+    for (i, (pool_config, _)) in pools.iter().enumerate() {
+        let config = read_pool_config(&svm, pool_config);
+        assert_eq!(
+            config.last_finalized_slot,
+            UPDATE_SLOT_INTERVAL + 100,
+            "pool {i}"
+        );
+        assert_eq!(config.pending_deposits, 0, "pool {i}");
+        assert_eq!(config.finalized_balance, 990_000, "pool {i}");
+    }
+    let skipped_after = read_pool_config(&svm, &pools[1].0);
+    assert_eq!(
+        bytemuck::bytes_of(&skipped_after),
+        bytemuck::bytes_of(&skipped_before)
+    );
+
+    // Nothing is due any more: the whole batch is skipped, not failed
+    svm.expire_blockhash();
+    let ix = build_finalize_rewards_batch_ix(program_id, &pools);
+    send_ix(&mut svm, ix, &authority).expect("FinalizeRewardsBatch should succeed");
+
+    // The single-pool instruction still fails when not due
+    let ix = build_finalize_rewards_ix(program_id, pools[0].0);
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::RewardsNotReady),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_finalize_rewards_batch_rejects_invalid_batches() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_token_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (pool_config, vault) = setup_pool_with_deposit(&mut svm, program_id, &authority, 1_000);
+    svm.warp_to_slot(UPDATE_SLOT_INTERVAL + 100);
+
+    // Empty batch
+    let ix = build_finalize_rewards_batch_ix(program_id, &[]);
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::InvalidFinalizeBatch),
+        "{err}"
+    );
+
+    // Unpaired account
+    let mut ix = build_finalize_rewards_batch_ix(program_id, &[(pool_config, vault)]);
+    ix.accounts.pop();
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::InvalidFinalizeBatch),
+        "{err}"
+    );
+
+    // Too many pools
+    let pools = vec![(pool_config, vault); token_pool::instructions::MAX_FINALIZE_BATCH_SIZE + 1];
+    let ix = build_finalize_rewards_batch_ix(program_id, &pools);
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(
+        is_custom_error(&err, TokenPoolError::InvalidFinalizeBatch),
+        "{err}"
+    );
+
+    // Vault that is not the pool's
+    let (other_pool, other_vault) =
+        setup_pool_with_deposit(&mut svm, program_id, &authority, 1_000);
+    let ix =
+        build_finalize_rewards_batch_ix(program_id, &[(pool_config, vault), (other_pool, vault)]);
+    let err = send_ix(&mut svm, ix, &authority).unwrap_err();
+    assert!(is_custom_error(&err, TokenPoolError::InvalidVault), "{err}");
+
+    // The failed batch did not finalize the first pool
+    assert_eq!(read_pool_config(&svm, &pool_config).last_finalized_slot, 0);
+
+    let ix = build_finalize_rewards_batch_ix(
+        program_id,
+        &[(pool_config, vault), (other_pool, other_vault)],
+    );
+    send_ix(&mut svm, ix, &authority).expect("FinalizeRewardsBatch should succeed");
+    assert_eq!(
+        read_pool_config(&svm, &other_pool).last_finalized_slot,
+        UPDATE_SLOT_INTERVAL + 100
+    );
+}
@@ -44,7 +44,8 @@ pub enum UnifiedSolPoolError {
     InvalidHubAuthority = 17,
     /// Unauthorized operation
     Unauthorized = 18,
-    /// Rewards not ready - not enough slots elapsed since last finalization
+    /// Rewards not ready - not enough slots elapsed (or, in SolanaEpoch mode,
+    /// the Solana epoch has not advanced) since last finalization
     RewardsNotReady = 19,
     /// Invalid system program
     InvalidSystemProgram = 20,
@@ -82,6 +83,72 @@ pub enum UnifiedSolPoolError {
     InsufficientBuffer = 36,
     /// Counter vault_token_balance doesn't match actual vault balance
     VaultBalanceMismatch = 37,
+    /// Derived exchange rate deviates from the caller's expected rate
+    ExchangeRateMismatch = 38,
+    /// Every stake pool program allowlist slot is taken
+    StakePoolProgramAllowlistFull = 39,
+    /// Stake pool program is not on the allowlist
+    StakePoolProgramNotListed = 40,
+    /// LST is retired; deposits are closed
+    LstRetired = 41,
+    /// LST must be retired before it can be closed
+    LstNotRetired = 42,
+    /// LST vault or virtual SOL attribution is not yet zero
+    LstConfigNotEmpty = 43,
+    /// Frozen exchange rate would move outside the LST's per-epoch band
+    ExchangeRateOutOfBounds = 44,
+    /// Frozen exchange rate is older than the LST's staleness limit
+    ExchangeRateStale = 45,
+    /// Rate band or staleness limit is outside the program-enforced ceilings
+    InvalidRateBounds = 46,
+    /// Native SOL unwrap requested for an LST other than WSOL
+    UnwrapNotSupported = 47,
+    /// Unwrap recipient, temporary account or mint is invalid
+    InvalidUnwrapAccounts = 48,
+    /// Deposit would push the LST's virtual SOL past its cap
+    LstVirtualSolCapExceeded = 49,
+    /// Deposit would push the pool's total virtual SOL past its cap
+    PoolVirtualSolCapExceeded = 50,
+    /// Withdrawal priority list is malformed (duplicate LST mint)
+    InvalidWithdrawPriority = 51,
+    /// Routed withdrawal vaults are invalid or out of priority order
+    InvalidRouteAccounts = 52,
+    /// Named and routed vaults together cannot cover the withdrawal
+    InsufficientRouteLiquidity = 53,
+    /// Commission withdrawal exceeds the accrued commission
+    InsufficientCommission = 54,
+    /// Commission treasury is unset or the destination is not owned by it
+    InvalidCommissionDestination = 55,
+    /// LstConfig is already at the current layout version
+    LstConfigUpToDate = 56,
+    /// Epoch mode is not a known `EpochMode`
+    InvalidEpochMode = 57,
+    /// Deposits of this LST are disabled by its mode
+    LstDepositsDisabled = 58,
+    /// Withdrawals of this LST are disabled by its mode
+    LstWithdrawalsDisabled = 59,
+    /// LST mode contains unknown bits
+    InvalidLstMode = 60,
+    /// No manual exchange rate is pending for this LST
+    ManualRateNotProposed = 61,
+    /// Manual exchange rate proposal has not reached its unlock slot
+    ManualRateTimelocked = 62,
+    /// Manual exchange rate rejected: the LST's rate source is still readable
+    RateSourceHealthy = 63,
+    /// Manual rate delay is outside the program-enforced bounds
+    InvalidManualRateDelay = 64,
+    /// LstConfig predates the extension this instruction needs (run MigrateLstConfig)
+    LstConfigNeedsMigration = 65,
+    /// Caller is not on the keeper allowlist while it is enforced
+    KeeperNotAllowed = 66,
+    /// Invalid keeper (the zero key)
+    InvalidKeeper = 67,
+    /// Every keeper allowlist slot is taken
+    KeeperAllowlistFull = 68,
+    /// Keeper is not on the allowlist
+    KeeperNotListed = 69,
+    /// The keeper allowlist cannot be enforced with no keepers listed
+    KeeperAllowlistEmpty = 70,
 }
 
 impl From<UnifiedSolPoolError> for ProgramError {
@@ -89,3 +156,32 @@ impl From<UnifiedSolPoolError> for ProgramError {
         ProgramError::Custom(e as u32)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use zorb_pool_interface::{
+        PoolErrorKind, UNIFIED_SOL_POOL_ERROR_NAMES, UNIFIED_SOL_POOL_PROGRAM_ID, decode_pool_error,
+    };
+
+    /// Fails when a variant is added here without updating the interface's
+    /// `UNIFIED_SOL_POOL_ERROR_NAMES`, so the hub keeps decoding every pool error.
+    #[test]
+    fn test_errors_mapped_in_interface() {
+        let variants: Vec<(&str, u32)> = include_str!("errors.rs")
+            .lines()
+            .filter_map(|line| {
+                let (name, code) = line.trim().strip_suffix(',')?.split_once(" = ")?;
+                Some((name, code.parse().ok()?))
+            })
+            .collect();
+
+        assert_eq!(variants.len(), UNIFIED_SOL_POOL_ERROR_NAMES.len());
+        for (name, code) in variants {
+            assert_eq!(
+                decode_pool_error(&UNIFIED_SOL_POOL_PROGRAM_ID, code),
+                Some(PoolErrorKind::UnifiedSol(name)),
+                "UnifiedSolPoolError::{name} = {code} is not mapped"
+            );
+        }
+    }
+}
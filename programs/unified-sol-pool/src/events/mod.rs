@@ -10,6 +10,28 @@
 //! - [`AppreciationHarvestedEvent`] - Emitted when LST appreciation is harvested
 //! - [`ExchangeRateUpdatedEvent`] - Emitted when exchange rate is updated
 //! - [`UnifiedSolRewardsFinalizedEvent`] - Emitted when rewards are finalized
+//! - [`LstFinalizedEvent`] - Emitted per LST when rewards are finalized
+//! - [`StakePoolProgramAddedEvent`] - Emitted when a stake pool program is allowlisted
+//! - [`StakePoolProgramRemovedEvent`] - Emitted when a stake pool program is delisted
+//! - [`LstConfigRetiredEvent`] - Emitted when an LST is retired or un-retired
+//! - [`LstConfigClosedEvent`] - Emitted when a drained LST config is closed
+//! - [`LstRateBoundsUpdatedEvent`] - Emitted when an LST's rate band or staleness limit changes
+//! - [`LstRateJumpApprovedEvent`] - Emitted when the authority approves an out-of-band rate
+//! - [`LstVirtualSolCapUpdatedEvent`] - Emitted when an LST's virtual SOL cap changes
+//! - [`UnifiedSolVirtualSolCapUpdatedEvent`] - Emitted when the pool-wide virtual SOL cap changes
+//! - [`WithdrawPriorityUpdatedEvent`] - Emitted when the withdrawal routing order changes
+//! - [`AppreciationCommissionUpdatedEvent`] - Emitted when the appreciation commission changes
+//! - [`CommissionWithdrawnEvent`] - Emitted when accrued commission is paid out
+//! - [`LstConfigMigratedEvent`] - Emitted when an LST config is migrated to a new layout
+//! - [`EpochModeUpdatedEvent`] - Emitted when the reward epoch mode changes
+//! - [`MinHarvestLamportsUpdatedEvent`] - Emitted when the harvest dust threshold changes
+//! - [`ManualRateProposedEvent`] - Emitted when the authority proposes a manual exchange rate
+//! - [`ManualRateAppliedEvent`] - Emitted when a proposed manual exchange rate takes effect
+//! - [`ManualRateCancelledEvent`] - Emitted when a pending manual exchange rate is cancelled
+//! - [`ManualRateDelayUpdatedEvent`] - Emitted when the manual rate timelock changes
+//! - [`KeeperAddedEvent`] - Emitted when a keeper is allowlisted
+//! - [`KeeperRemovedEvent`] - Emitted when a keeper is delisted
+//! - [`KeeperAllowlistEnabledUpdatedEvent`] - Emitted when the keeper allowlist is enforced or lifted
 //!
 //! # Event Pattern
 //!
@@ -38,7 +60,8 @@ use crate::instructions::UnifiedSolPoolInstruction;
 /// # Ranges (per discriminator-standard.md)
 /// - **1-15**: Core events (deposit, withdrawal, rewards)
 /// - **16-31**: LST events (appreciation, rate updates)
-/// - **32-47**: Admin events (reserved for future use)
+/// - **32-47**: Admin events
+/// - **48-63**: Admin events, continued
 #[repr(u64)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, strum::IntoStaticStr)]
 pub enum EventType {
@@ -60,11 +83,60 @@ pub enum EventType {
     AppreciationHarvested = 16,
     /// Exchange rate updated
     ExchangeRateUpdated = 17,
-    // Reserved: 18-31
+    /// Manual exchange rate proposed
+    ManualRateProposed = 18,
+    /// Manual exchange rate applied
+    ManualRateApplied = 19,
+    /// Manual exchange rate proposal cancelled
+    ManualRateCancelled = 20,
+    /// Per-LST breakdown of a finalization
+    LstFinalized = 21,
+    // Reserved: 22-31
 
     // =========================================================================
-    // Admin Events (32-47) - Reserved for future use
+    // Admin Events (32-47) - Configuration changes
     // =========================================================================
+    /// Stake pool program added to the allowlist
+    StakePoolProgramAdded = 32,
+    /// Stake pool program removed from the allowlist
+    StakePoolProgramRemoved = 33,
+    /// LST retirement flag changed
+    LstConfigRetired = 34,
+    /// Retired LST config and vault closed
+    LstConfigClosed = 35,
+    /// LST rate band or staleness limit changed
+    LstRateBoundsUpdated = 36,
+    /// Out-of-band exchange rate approved for the next finalization
+    LstRateJumpApproved = 37,
+    /// LST virtual SOL cap changed
+    LstVirtualSolCapUpdated = 38,
+    /// Pool-wide virtual SOL cap changed
+    UnifiedSolVirtualSolCapUpdated = 39,
+    /// Withdrawal routing priority list changed
+    WithdrawPriorityUpdated = 40,
+    /// Appreciation commission rate or treasury changed
+    AppreciationCommissionUpdated = 41,
+    /// Accrued commission withdrawn to the treasury
+    CommissionWithdrawn = 42,
+    /// LST config migrated to a new layout version
+    LstConfigMigrated = 43,
+    /// Reward epoch mode changed
+    EpochModeUpdated = 44,
+    /// Harvest dust threshold changed
+    MinHarvestLamportsUpdated = 45,
+    /// Manual rate timelock changed
+    ManualRateDelayUpdated = 46,
+    /// Keeper added to the allowlist
+    KeeperAdded = 47,
+
+    // =========================================================================
+    // Admin Events, continued (48-63)
+    // =========================================================================
+    /// Keeper removed from the allowlist
+    KeeperRemoved = 48,
+    /// Keeper allowlist enforced or lifted
+    KeeperAllowlistEnabledUpdated = 49,
+    // Reserved: 50-63
 }
 
 /// Event emitted when SOL/LST is deposited into the unified SOL pool.
@@ -103,8 +175,9 @@ pub struct UnifiedSolWithdrawalEvent {
     pub exchange_rate: u64,
     /// Solana slot when the withdrawal occurred
     pub slot: u64,
-    /// Padding for alignment
-    pub _padding: u64,
+    /// Pool-wide withdrawal count after this withdrawal, shared by the events
+    /// of every vault one withdrawal draws from
+    pub correlation_id: u64,
 }
 
 /// Event emitted when LST appreciation is harvested.
@@ -123,6 +196,10 @@ pub struct AppreciationHarvestedEvent {
     pub epoch: u64,
     /// Solana slot when harvested
     pub slot: u64,
+    /// Protocol commission taken from `appreciation_amount` (in virtual SOL / lamports)
+    pub commission_amount: u64,
+    /// Signer that ran the harvest
+    pub caller: [u8; 32],
 }
 
 /// Event emitted when exchange rate is updated.
@@ -165,13 +242,371 @@ pub struct UnifiedSolRewardsFinalizedEvent {
     pub epoch: u64,
     /// Solana slot when finalization occurred
     pub slot: u64,
+    /// Solana epoch when finalization occurred
+    pub solana_epoch: u64,
     /// Number of LST configs that were validated and finalized.
     /// Matches UnifiedSolPoolConfig.lst_count - confirms all LSTs were included.
     pub lst_count: u8,
-    /// Padding for 16-byte alignment (struct total: 88 bytes)
+    /// Padding for 16-byte alignment
+    pub _padding: [u8; 15],
+    /// Signer that ran the finalization (struct total: 128 bytes)
+    pub caller: [u8; 32],
+}
+
+/// Event emitted for each LST when rewards are finalized.
+///
+/// Breaks [`UnifiedSolRewardsFinalizedEvent`] down by LST: across one
+/// finalization, `deposit_fees`, `withdraw_fees` and `appreciation_contributed`
+/// sum to the aggregate `deposit_fees`, `withdrawal_fees` and
+/// `appreciation_rewards`, and `attributed_virtual_sol` to `total_virtual_sol`.
+/// LST configs older than version 4 report zero rewards.
+#[event(EventType::LstFinalized)]
+#[repr(C)]
+pub struct LstFinalizedEvent {
+    /// LST mint address
+    pub lst_mint: [u8; 32],
+    /// Exchange rate frozen for the new reward epoch (1 LST = rate/1e9 SOL)
+    pub frozen_rate: u64,
+    /// Appreciation booked by this LST's harvests, net of commission (in lamports)
+    pub appreciation_contributed: u64,
+    /// Deposit fees charged on this LST (in lamports)
+    pub deposit_fees: u64,
+    /// Withdrawal fees charged on this LST (in lamports)
+    pub withdraw_fees: u64,
+    /// Virtual SOL held in this LST's vault at the frozen rate
+    pub attributed_virtual_sol: u128,
+    /// Reward epoch number (after finalization, as in the aggregate event)
+    pub epoch: u64,
+    /// Solana slot when finalization occurred
+    pub slot: u64,
+}
+
+/// Event emitted when a program is added to the stake pool program allowlist.
+#[event(EventType::StakePoolProgramAdded)]
+#[repr(C)]
+pub struct StakePoolProgramAddedEvent {
+    /// Program now trusted to own SPL-layout rate source accounts
+    pub program: [u8; 32],
+    /// Authority that made the change
+    pub authority: [u8; 32],
+    /// Solana slot when the change occurred
+    pub slot: u64,
+    /// Padding for alignment
+    pub _padding: u64,
+}
+
+/// Event emitted when a program is removed from the stake pool program allowlist.
+///
+/// LSTs registered against `program` cannot be harvested (and so block
+/// finalization) until it is added back.
+#[event(EventType::StakePoolProgramRemoved)]
+#[repr(C)]
+pub struct StakePoolProgramRemovedEvent {
+    /// Program no longer trusted as a rate source owner
+    pub program: [u8; 32],
+    /// Authority that made the change
+    pub authority: [u8; 32],
+    /// Solana slot when the change occurred
+    pub slot: u64,
+    /// Padding for alignment
+    pub _padding: u64,
+}
+
+/// Event emitted when an LST's retirement flag changes.
+#[event(EventType::LstConfigRetired)]
+#[repr(C)]
+pub struct LstConfigRetiredEvent {
+    /// LST mint address
+    pub lst_mint: [u8; 32],
+    /// Solana slot when the change occurred
+    pub slot: u64,
+    /// New retirement flag (1 = retired, withdraw-only)
+    pub is_retired: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 7],
+}
+
+/// Event emitted when a retired, drained LST config is closed.
+#[event(EventType::LstConfigClosed)]
+#[repr(C)]
+pub struct LstConfigClosedEvent {
+    /// LST mint address
+    pub lst_mint: [u8; 32],
+    /// Closed LstConfig PDA
+    pub lst_config: [u8; 32],
+    /// Authority that closed it (receives reclaimed rent)
+    pub authority: [u8; 32],
+    /// Rent returned from the vault and the LstConfig (lamports)
+    pub reclaimed_lamports: u64,
+    /// Solana slot when closed
+    pub slot: u64,
+}
+
+/// Event emitted when an LST's rate band or staleness limit changes.
+#[event(EventType::LstRateBoundsUpdated)]
+#[repr(C)]
+pub struct LstRateBoundsUpdatedEvent {
+    /// LST mint address
+    pub lst_mint: [u8; 32],
+    /// New maximum staleness of the frozen rate (slots)
+    pub max_rate_staleness_slots: u64,
+    /// Solana slot when the change occurred
+    pub slot: u64,
+    /// New maximum rate increase per finalization (basis points)
+    pub max_rate_increase_bps_per_epoch: u16,
+    /// New maximum rate decrease per finalization (basis points)
+    pub max_rate_decrease_bps_per_epoch: u16,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 4],
+}
+
+/// Event emitted when the authority approves an out-of-band exchange rate.
+#[event(EventType::LstRateJumpApproved)]
+#[repr(C)]
+pub struct LstRateJumpApprovedEvent {
+    /// LST mint address
+    pub lst_mint: [u8; 32],
+    /// Frozen rate at the time of approval
+    pub harvested_exchange_rate: u64,
+    /// Rate the next finalization may freeze regardless of the band (0 = revoked)
+    pub approved_exchange_rate: u64,
+    /// Solana slot when approved
+    pub slot: u64,
+}
+
+/// Event emitted when an LST's virtual SOL cap changes.
+#[event(EventType::LstVirtualSolCapUpdated)]
+#[repr(C)]
+pub struct LstVirtualSolCapUpdatedEvent {
+    /// LST mint address
+    pub lst_mint: [u8; 32],
+    /// New cap on the LST's virtual SOL (0 = uncapped)
+    pub max_virtual_sol: u64,
+    /// Virtual SOL above the new cap, parked until withdrawals catch up
+    pub virtual_sol_over_cap: u64,
+    /// Solana slot when the change occurred
+    pub slot: u64,
+}
+
+/// Event emitted when the pool-wide virtual SOL cap changes.
+#[event(EventType::UnifiedSolVirtualSolCapUpdated)]
+#[repr(C)]
+pub struct UnifiedSolVirtualSolCapUpdatedEvent {
+    /// New cap on the pool's total virtual SOL (0 = uncapped)
+    pub max_total_virtual_sol: u64,
+    /// Virtual SOL above the new cap, parked until withdrawals catch up
+    pub total_virtual_sol_over_cap: u64,
+    /// Solana slot when the change occurred
+    pub slot: u64,
+}
+
+/// Event emitted when the withdrawal routing priority list changes.
+#[event(EventType::WithdrawPriorityUpdated)]
+#[repr(C)]
+pub struct WithdrawPriorityUpdatedEvent {
+    /// First LST mint routed to (zero = empty slot)
+    pub priority_0: [u8; 32],
+    /// Second LST mint routed to (zero = empty slot)
+    pub priority_1: [u8; 32],
+    /// Third LST mint routed to (zero = empty slot)
+    pub priority_2: [u8; 32],
+    /// Fourth LST mint routed to (zero = empty slot)
+    pub priority_3: [u8; 32],
+    /// Solana slot when the change occurred
+    pub slot: u64,
+}
+
+/// Event emitted when the appreciation commission rate or treasury changes.
+#[event(EventType::AppreciationCommissionUpdated)]
+#[repr(C)]
+pub struct AppreciationCommissionUpdatedEvent {
+    /// New commission treasury (zero = withdrawals disabled)
+    pub commission_treasury: [u8; 32],
+    /// Solana slot when the change occurred
+    pub slot: u64,
+    /// New share of harvested appreciation kept by the protocol (basis points)
+    pub appreciation_commission_bps: u16,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 6],
+}
+
+/// Event emitted when accrued commission is withdrawn as LST tokens.
+#[event(EventType::CommissionWithdrawn)]
+#[repr(C)]
+pub struct CommissionWithdrawnEvent {
+    /// LST mint paid out
+    pub lst_mint: [u8; 32],
+    /// Destination token account
+    pub destination: [u8; 32],
+    /// Commission withdrawn (in virtual SOL / lamports)
+    pub amount: u64,
+    /// LST tokens transferred (in token base units)
+    pub lst_amount: u64,
+    /// Exchange rate used (1 LST = rate/1e9 SOL)
+    pub exchange_rate: u64,
+    /// Solana slot when withdrawn
+    pub slot: u64,
+}
+
+/// Event emitted when an LST config is migrated to a new layout version.
+#[event(EventType::LstConfigMigrated)]
+#[repr(C)]
+pub struct LstConfigMigratedEvent {
+    /// LST mint address
+    pub lst_mint: [u8; 32],
+    /// Layout version before the migration
+    pub old_version: u16,
+    /// Layout version after the migration
+    pub new_version: u16,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 4],
+    /// Account size after the migration (bytes)
+    pub new_size: u64,
+    /// Solana slot when migrated
+    pub slot: u64,
+}
+
+/// Event emitted when the reward epoch mode changes.
+#[event(EventType::EpochModeUpdated)]
+#[repr(C)]
+pub struct EpochModeUpdatedEvent {
+    /// Solana epoch of the last finalization (the next one must be later in
+    /// SolanaEpoch mode)
+    pub last_finalized_solana_epoch: u64,
+    /// Solana slot when updated
+    pub slot: u64,
+    /// New mode (0 = SlotInterval, 1 = SolanaEpoch)
+    pub epoch_mode: u8,
+    /// Padding for 8-byte alignment
     pub _padding: [u8; 7],
 }
 
+/// Event emitted when the harvest dust threshold changes.
+#[event(EventType::MinHarvestLamportsUpdated)]
+#[repr(C)]
+pub struct MinHarvestLamportsUpdatedEvent {
+    /// New minimum appreciation a harvest books (0 = no minimum)
+    pub min_harvest_lamports: u64,
+    /// Appreciation carried from earlier dust harvests
+    pub carried_appreciation: u64,
+    /// Solana slot when updated
+    pub slot: u64,
+}
+
+/// Event emitted when the authority proposes a manual exchange rate.
+#[event(EventType::ManualRateProposed)]
+#[repr(C)]
+pub struct ManualRateProposedEvent {
+    /// LST mint address
+    pub lst_mint: [u8; 32],
+    /// Exchange rate at the time of the proposal
+    pub old_rate: u64,
+    /// Proposed exchange rate
+    pub new_rate: u64,
+    /// First slot at which the proposal may be applied
+    pub unlock_slot: u64,
+    /// Solana slot when proposed
+    pub slot: u64,
+}
+
+/// Event emitted when a proposed manual exchange rate takes effect.
+#[event(EventType::ManualRateApplied)]
+#[repr(C)]
+pub struct ManualRateAppliedEvent {
+    /// LST mint address
+    pub lst_mint: [u8; 32],
+    /// Exchange rate before the override
+    pub old_rate: u64,
+    /// Exchange rate after the override
+    pub new_rate: u64,
+    /// Appreciation from the rate change (in virtual SOL / lamports)
+    pub appreciation_amount: u64,
+    /// Epoch the LST counts as harvested for
+    pub epoch: u64,
+    /// Solana slot when applied
+    pub slot: u64,
+    /// Signer that applied the rate
+    pub caller: [u8; 32],
+}
+
+/// Event emitted when a pending manual exchange rate is cancelled.
+#[event(EventType::ManualRateCancelled)]
+#[repr(C)]
+pub struct ManualRateCancelledEvent {
+    /// LST mint address
+    pub lst_mint: [u8; 32],
+    /// Exchange rate in force (unchanged)
+    pub old_rate: u64,
+    /// Proposed exchange rate that was dropped
+    pub new_rate: u64,
+    /// Solana slot when cancelled
+    pub slot: u64,
+}
+
+/// Event emitted when the manual rate timelock changes.
+#[event(EventType::ManualRateDelayUpdated)]
+#[repr(C)]
+pub struct ManualRateDelayUpdatedEvent {
+    /// New slots between a manual rate proposal and its earliest application
+    pub manual_rate_delay_slots: u64,
+    /// Solana slot when updated
+    pub slot: u64,
+}
+
+/// Event emitted when a keeper is added to the allowlist.
+#[event(EventType::KeeperAdded)]
+#[repr(C)]
+pub struct KeeperAddedEvent {
+    /// Keeper now allowed to harvest and finalize while the allowlist is enforced
+    pub keeper: [u8; 32],
+    /// Authority that made the change
+    pub authority: [u8; 32],
+    /// Solana slot when the change occurred
+    pub slot: u64,
+    /// Padding for alignment
+    pub _padding: u64,
+}
+
+/// Event emitted when a keeper is removed from the allowlist.
+#[event(EventType::KeeperRemoved)]
+#[repr(C)]
+pub struct KeeperRemovedEvent {
+    /// Keeper no longer on the allowlist
+    pub keeper: [u8; 32],
+    /// Authority that made the change
+    pub authority: [u8; 32],
+    /// Solana slot when the change occurred
+    pub slot: u64,
+    /// Padding for alignment
+    pub _padding: u64,
+}
+
+/// Event emitted when the keeper allowlist is enforced or lifted.
+#[event(EventType::KeeperAllowlistEnabledUpdated)]
+#[repr(C)]
+pub struct KeeperAllowlistEnabledUpdatedEvent {
+    /// Solana slot when updated
+    pub slot: u64,
+    /// 1 = keeper operations restricted to the allowlist, 0 = permissionless
+    pub enabled: u8,
+    /// Number of keepers listed
+    pub keeper_count: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 6],
+}
+
+/// Build the `Log` instruction data carrying `event_data`.
+///
+/// Format: `[Log discriminator (71)][data length: u32 LE][data...]`, the
+/// `LengthPrefixed` framing the Log handler decodes.
+pub fn build_log_instruction_data(event_data: &[u8]) -> Vec<u8> {
+    let mut instruction_data = Vec::with_capacity(1 + 4 + event_data.len());
+    instruction_data.push(UnifiedSolPoolInstruction::Log as u8);
+    instruction_data.extend_from_slice(&(event_data.len() as u32).to_le_bytes());
+    instruction_data.extend_from_slice(event_data);
+    instruction_data
+}
+
 /// Emit a panchor event via self-invocation of the Log instruction.
 ///
 /// This function:
@@ -193,13 +628,7 @@ pub fn emit_event<T: EventBytes>(
     // Serialize the event using Pod serialization with discriminator
     let event_data = event.to_event_bytes();
 
-    // Build instruction data: [Log discriminator, length (4 bytes LE), data...]
-    let log_discriminator = UnifiedSolPoolInstruction::Log as u8;
-    let len = event_data.len() as u32;
-    let mut instruction_data = Vec::with_capacity(1 + 4 + event_data.len());
-    instruction_data.push(log_discriminator);
-    instruction_data.extend_from_slice(&len.to_le_bytes());
-    instruction_data.extend_from_slice(&event_data);
+    let instruction_data = build_log_instruction_data(&event_data);
 
     // Build instruction for self-CPI to Log
     let instruction = Instruction {
@@ -214,3 +643,32 @@ pub fn emit_event<T: EventBytes>(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use panchor::events::LogFraming;
+
+    #[test]
+    fn test_log_instruction_data_decodes_to_event() {
+        let event = UnifiedSolDepositEvent {
+            lst_mint: [3u8; 32],
+            lst_amount: 1_000,
+            sol_value: 1_050,
+            fee: 5,
+            exchange_rate: 1_050_000_000,
+            slot: 42,
+            _padding: 0,
+        };
+        let instruction_data = build_log_instruction_data(&event.to_event_bytes());
+        assert_eq!(instruction_data[0], UnifiedSolPoolInstruction::Log as u8);
+
+        // What the Log handler passes to sol_log_data
+        let payload = LogFraming::LengthPrefixed
+            .payload(&instruction_data[1..])
+            .unwrap();
+        assert_eq!(payload[..8], UnifiedSolDepositEvent::discriminator_bytes());
+        let decoded: UnifiedSolDepositEvent = bytemuck::pod_read_unaligned(&payload[8..]);
+        assert_eq!(bytemuck::bytes_of(&decoded), bytemuck::bytes_of(&event));
+    }
+}
@@ -0,0 +1,77 @@
+//! Add a keeper to the keeper allowlist.
+
+use crate::{
+    UnifiedSolPoolConfig, UnifiedSolPoolError, emit_event, events::KeeperAddedEvent,
+    gen_unified_sol_pool_config_seeds,
+};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+
+/// Instruction data for AddKeeper.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct AddKeeperData {
+    /// Signer to allow to harvest and finalize while the allowlist is enforced
+    pub keeper: [u8; 32],
+}
+
+/// Accounts for the AddKeeper instruction.
+#[derive(Accounts)]
+pub struct AddKeeperAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// Must match unified_sol_pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Unified SOL pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub unified_sol_program: &'info AccountInfo,
+}
+
+/// Allow `keeper` to run keeper operations while the allowlist is enforced.
+///
+/// Adding a listed keeper is a no-op; fails with `KeeperAllowlistFull` once
+/// every slot is taken. Has no effect on callers until the allowlist is
+/// enabled with `set_keeper_allowlist_enabled`.
+pub fn process_add_keeper(ctx: Context<AddKeeperAccounts>, data: AddKeeperData) -> ProgramResult {
+    let AddKeeperAccounts {
+        unified_sol_pool_config,
+        authority,
+        unified_sol_program,
+    } = ctx.accounts;
+
+    let bump = unified_sol_pool_config.try_map_mut(|config| {
+        if config.authority != *authority.key() {
+            log!("add_keeper: unauthorized");
+            return Err(UnifiedSolPoolError::Unauthorized.into());
+        }
+        config.add_keeper(&data.keeper)?;
+        Ok(config.bump)
+    })?;
+
+    let bump_bytes = [bump];
+    let seeds = gen_unified_sol_pool_config_seeds(&bump_bytes);
+    emit_event(
+        unified_sol_pool_config.account_info(),
+        unified_sol_program,
+        PinocchioSigner::from(&seeds),
+        &KeeperAddedEvent {
+            keeper: data.keeper,
+            authority: *authority.key(),
+            slot: Clock::get()?.slot,
+            _padding: 0,
+        },
+    )?;
+
+    log!("add_keeper: success");
+    Ok(())
+}
@@ -0,0 +1,80 @@
+//! Add a program to the stake pool program allowlist.
+
+use crate::{
+    UnifiedSolPoolConfig, UnifiedSolPoolError, emit_event, events::StakePoolProgramAddedEvent,
+    gen_unified_sol_pool_config_seeds,
+};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+
+/// Instruction data for AddStakePoolProgram.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct AddStakePoolProgramData {
+    /// SPL-layout stake pool program to trust as a rate source owner
+    pub program: [u8; 32],
+}
+
+/// Accounts for the AddStakePoolProgram instruction.
+#[derive(Accounts)]
+pub struct AddStakePoolProgramAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// Must match unified_sol_pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Unified SOL pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub unified_sol_program: &'info AccountInfo,
+}
+
+/// Allow `PoolType::SplStakePool` LSTs whose stake pool is owned by `program`.
+///
+/// Intended for other deployments of the SPL stake pool program, such as
+/// Sanctum single-validator pools. Adding a listed program is a no-op; fails
+/// with `StakePoolProgramAllowlistFull` once every slot is taken.
+pub fn process_add_stake_pool_program(
+    ctx: Context<AddStakePoolProgramAccounts>,
+    data: AddStakePoolProgramData,
+) -> ProgramResult {
+    let AddStakePoolProgramAccounts {
+        unified_sol_pool_config,
+        authority,
+        unified_sol_program,
+    } = ctx.accounts;
+
+    let bump = unified_sol_pool_config.try_map_mut(|config| {
+        if config.authority != *authority.key() {
+            log!("add_stake_pool_program: unauthorized");
+            return Err(UnifiedSolPoolError::Unauthorized.into());
+        }
+        config.add_stake_pool_program(&data.program)?;
+        Ok(config.bump)
+    })?;
+
+    let bump_bytes = [bump];
+    let seeds = gen_unified_sol_pool_config_seeds(&bump_bytes);
+    emit_event(
+        unified_sol_pool_config.account_info(),
+        unified_sol_program,
+        PinocchioSigner::from(&seeds),
+        &StakePoolProgramAddedEvent {
+            program: data.program,
+            authority: *authority.key(),
+            slot: Clock::get()?.slot,
+            _padding: 0,
+        },
+    )?;
+
+    log!("add_stake_pool_program: success");
+    Ok(())
+}
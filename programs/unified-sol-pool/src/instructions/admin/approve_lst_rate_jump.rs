@@ -0,0 +1,98 @@
+//! Approve an exchange rate outside an LST's per-epoch band.
+
+use crate::{
+    LstConfig, UnifiedSolPoolConfig, UnifiedSolPoolError, emit_event,
+    events::LstRateJumpApprovedEvent, gen_unified_sol_pool_config_seeds,
+};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+
+/// Instruction data for ApproveLstRateJump.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct ApproveLstRateJumpData {
+    /// Exact rate the next finalization may freeze (0 revokes a pending approval)
+    pub approved_rate: u64,
+}
+
+/// Accounts for the ApproveLstRateJump instruction.
+#[derive(Accounts)]
+pub struct ApproveLstRateJumpAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA (for authority check and event signing)
+    #[account(owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// LstConfig PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub lst_config: AccountLoader<'info, LstConfig>,
+
+    /// Must match unified_sol_pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Unified SOL pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub unified_sol_program: &'info AccountInfo,
+}
+
+/// Let the next `finalize_unified_rewards` freeze `approved_rate` for this
+/// LST even though it is outside the per-epoch band.
+///
+/// For legitimate large moves, such as onboarding an LST whose rate is far
+/// from 1:1 or catching up after a long deactivation. The approval matches
+/// the harvested `exchange_rate` exactly, so a later harvest that moves the
+/// rate again needs a new approval; finalization consumes it.
+pub fn process_approve_lst_rate_jump(
+    ctx: Context<ApproveLstRateJumpAccounts>,
+    data: ApproveLstRateJumpData,
+) -> ProgramResult {
+    let ApproveLstRateJumpAccounts {
+        unified_sol_pool_config,
+        lst_config,
+        authority,
+        unified_sol_program,
+    } = ctx.accounts;
+
+    // Read authority from unified config (releases borrow after closure)
+    let (unified_authority, unified_bump) =
+        unified_sol_pool_config.map(|config| (config.authority, config.bump))?;
+
+    if unified_authority != *authority.key() {
+        log!("approve_lst_rate_jump: unauthorized");
+        return Err(UnifiedSolPoolError::Unauthorized.into());
+    }
+
+    // Frozen rates must stay >= RATE_PRECISION (see harvest_lst_appreciation.rs)
+    if data.approved_rate != 0 && data.approved_rate < LstConfig::RATE_PRECISION {
+        log!("approve_lst_rate_jump: exchange rate below 1:1");
+        return Err(UnifiedSolPoolError::InvalidExchangeRate.into());
+    }
+
+    let (lst_mint, harvested_exchange_rate) = lst_config.try_map_mut(|config| {
+        config.approved_exchange_rate = data.approved_rate;
+        Ok((config.lst_mint, config.harvested_exchange_rate))
+    })?;
+
+    let bump_bytes = [unified_bump];
+    let seeds = gen_unified_sol_pool_config_seeds(&bump_bytes);
+    emit_event(
+        unified_sol_pool_config.account_info(),
+        unified_sol_program,
+        PinocchioSigner::from(&seeds),
+        &LstRateJumpApprovedEvent {
+            lst_mint,
+            harvested_exchange_rate,
+            approved_exchange_rate: data.approved_rate,
+            slot: Clock::get()?.slot,
+        },
+    )?;
+
+    log!("approve_lst_rate_jump: approved");
+    Ok(())
+}
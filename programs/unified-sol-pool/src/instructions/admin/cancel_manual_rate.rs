@@ -0,0 +1,85 @@
+//! Cancel a pending manual exchange rate.
+
+use crate::{
+    LstConfig, LstManualRate, UnifiedSolPoolConfig, UnifiedSolPoolError, emit_event,
+    events::ManualRateCancelledEvent, gen_unified_sol_pool_config_seeds,
+};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+
+/// Accounts for the CancelManualRate instruction.
+#[derive(Accounts)]
+pub struct CancelManualRateAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA (for authority check and event signing)
+    #[account(owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// LstConfig PDA holding the proposal
+    #[account(mut, owner = crate::ID)]
+    pub lst_config: AccountLoader<'info, LstConfig>,
+
+    /// Must match unified_sol_pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Unified SOL pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub unified_sol_program: &'info AccountInfo,
+}
+
+/// Drop the LST's pending manual exchange rate.
+///
+/// Fails with `ManualRateNotProposed` if nothing is pending.
+pub fn process_cancel_manual_rate(ctx: Context<CancelManualRateAccounts>) -> ProgramResult {
+    let CancelManualRateAccounts {
+        unified_sol_pool_config,
+        lst_config,
+        authority,
+        unified_sol_program,
+    } = ctx.accounts;
+
+    let (unified_authority, unified_bump) =
+        unified_sol_pool_config.map(|config| (config.authority, config.bump))?;
+
+    if unified_authority != *authority.key() {
+        log!("cancel_manual_rate: unauthorized");
+        return Err(UnifiedSolPoolError::Unauthorized.into());
+    }
+
+    let (lst_mint, old_rate) = lst_config.map(|config| (config.lst_mint, config.exchange_rate))?;
+
+    let new_rate = {
+        let mut account_data = lst_config.account_info().try_borrow_mut_data()?;
+        let proposal = LstManualRate::from_account_data_mut(&mut account_data)
+            .filter(|proposal| proposal.is_pending())
+            .ok_or_else(|| {
+                log!("cancel_manual_rate: no pending proposal");
+                UnifiedSolPoolError::ManualRateNotProposed
+            })?;
+        let rate = proposal.proposed_rate;
+        proposal.clear();
+        rate
+    };
+
+    let bump_bytes = [unified_bump];
+    let seeds = gen_unified_sol_pool_config_seeds(&bump_bytes);
+    emit_event(
+        unified_sol_pool_config.account_info(),
+        unified_sol_program,
+        PinocchioSigner::from(&seeds),
+        &ManualRateCancelledEvent {
+            lst_mint,
+            old_rate,
+            new_rate,
+            slot: Clock::get()?.slot,
+        },
+    )?;
+
+    log!("cancel_manual_rate: cancelled");
+    Ok(())
+}
@@ -0,0 +1,157 @@
+//! Close a retired LST configuration.
+//!
+//! Closes the LST vault token account and the LstConfig PDA once the LST has
+//! been retired and fully drained, returning all rent to the authority and
+//! removing the LST from the set finalization iterates over.
+
+use crate::{
+    LstConfig, UnifiedSolPoolConfig, UnifiedSolPoolError, emit_event, events::LstConfigClosedEvent,
+    gen_lst_config_seeds, gen_unified_sol_pool_config_seeds, read_token_account_balance,
+};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+use pinocchio_token::instructions::CloseAccount;
+
+/// Accounts for the CloseLstConfig instruction.
+#[derive(Accounts)]
+pub struct CloseLstConfigAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA (for authority check and lst_count update)
+    #[account(mut, owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// LstConfig PDA to close
+    #[account(mut, owner = crate::ID)]
+    pub lst_config: AccountLoader<'info, LstConfig>,
+
+    /// LST vault token account to close
+    /// Must match lst_config.lst_vault
+    #[account(mut)]
+    pub lst_vault: &'info AccountInfo,
+
+    /// Must match unified_sol_pool_config.authority (receives reclaimed rent)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Token program that owns the vault (required for CloseAccount CPI)
+    pub token_program: &'info AccountInfo,
+
+    /// Unified SOL pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub unified_sol_program: &'info AccountInfo,
+}
+
+/// Close a retired LST's vault and config, returning rent to the authority.
+///
+/// Requires the LST to be retired, and fails with `LstConfigNotEmpty` while
+/// the vault holds tokens or the config still attributes virtual SOL to it
+/// (run `finalize_unified_rewards` after the last withdrawal to clear it).
+/// Decrements `lst_count`, so finalization stops expecting the config.
+pub fn process_close_lst_config(ctx: Context<CloseLstConfigAccounts>) -> ProgramResult {
+    let CloseLstConfigAccounts {
+        unified_sol_pool_config,
+        lst_config,
+        lst_vault,
+        authority,
+        token_program,
+        unified_sol_program,
+    } = ctx.accounts;
+
+    let (unified_authority, unified_bump) =
+        unified_sol_pool_config.map(|config| (config.authority, config.bump))?;
+
+    if unified_authority != *authority.key() {
+        log!("close_lst_config: unauthorized");
+        return Err(UnifiedSolPoolError::Unauthorized.into());
+    }
+
+    let (lst_mint, bump) = lst_config.try_map(|config| {
+        if !config.is_retired() {
+            log!("close_lst_config: LST is not retired");
+            return Err(UnifiedSolPoolError::LstNotRetired.into());
+        }
+        if *lst_vault.key() != config.lst_vault {
+            log!("close_lst_config: invalid lst_vault");
+            return Err(UnifiedSolPoolError::InvalidVault.into());
+        }
+        if config.vault_token_balance != 0 || config.total_virtual_sol != 0 {
+            log!("close_lst_config: LST still holds value");
+            return Err(UnifiedSolPoolError::LstConfigNotEmpty.into());
+        }
+        Ok((config.lst_mint, config.bump))
+    })?;
+
+    if !lst_vault.is_owned_by(token_program.key()) {
+        log!("close_lst_config: token program does not own the vault");
+        return Err(UnifiedSolPoolError::InvalidTokenProgram.into());
+    }
+
+    // Tokens may have arrived outside tracked flows; CloseAccount would
+    // refuse them anyway, but fail with a pool error first
+    if read_token_account_balance(lst_vault)? != 0 {
+        log!("close_lst_config: vault not empty");
+        return Err(UnifiedSolPoolError::LstConfigNotEmpty.into());
+    }
+
+    // Close vault -> authority, signed by the lst_config PDA
+    let vault_lamports = lst_vault.lamports();
+    let bump_bytes = [bump];
+    let seeds = gen_lst_config_seeds(&lst_mint, &bump_bytes);
+    CloseAccount {
+        account: lst_vault,
+        destination: authority,
+        authority: lst_config.account_info(),
+    }
+    .invoke_signed(&[PinocchioSigner::from(&seeds)])?;
+
+    unified_sol_pool_config.try_inspect_mut(|unified| {
+        unified.lst_count = unified
+            .lst_count
+            .checked_sub(1)
+            .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?;
+        Ok(())
+    })?;
+
+    let config_lamports = lst_config.lamports();
+    let reclaimed_lamports = vault_lamports
+        .checked_add(config_lamports)
+        .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?;
+
+    let unified_bump_bytes = [unified_bump];
+    let unified_seeds = gen_unified_sol_pool_config_seeds(&unified_bump_bytes);
+    emit_event(
+        unified_sol_pool_config.account_info(),
+        unified_sol_program,
+        PinocchioSigner::from(&unified_seeds),
+        &LstConfigClosedEvent {
+            lst_mint,
+            lst_config: *lst_config.key(),
+            authority: *authority.key(),
+            reclaimed_lamports,
+            slot: Clock::get()?.slot,
+        },
+    )?;
+
+    // Tombstone: zero the data so the discriminator no longer matches, then
+    // truncate so the account cannot be loaded again in this transaction
+    let mut data = lst_config.try_borrow_mut_data()?;
+    data.fill(0);
+    drop(data);
+    lst_config.resize(0)?;
+
+    unsafe {
+        *lst_config.borrow_mut_lamports_unchecked() = 0;
+        *authority.borrow_mut_lamports_unchecked() = authority
+            .lamports()
+            .checked_add(config_lamports)
+            .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?;
+    }
+
+    log!("close_lst_config: LST config closed");
+    Ok(())
+}
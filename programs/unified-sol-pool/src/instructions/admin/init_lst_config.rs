@@ -1,8 +1,13 @@
 //! Initialize LST (Liquid Staking Token) configuration.
 
 use crate::{
-    LST_VAULT_SEED, LstConfig, PoolType, UnifiedSolPoolConfig, UnifiedSolPoolError,
-    find_lst_config_pda, find_lst_vault_pda,
+    LST_CONFIG_ACCOUNT_SIZE, LST_CONFIG_VERSION, LST_MODE_ALL, LST_VAULT_SEED, LstConfig,
+    LstRateHistory, PoolType, UnifiedSolPoolConfig, UnifiedSolPoolError, find_lst_config_pda,
+    find_lst_vault_pda,
+    rate_source::{
+        RateSource, load_for_mint, marinade::MarinadeState, spl_stake_pool::StakePoolState,
+    },
+    utils::grow_account,
 };
 use bytemuck::{Pod, Zeroable};
 use panchor::prelude::*;
@@ -10,12 +15,14 @@ use pinocchio::{
     ProgramResult,
     account_info::AccountInfo,
     instruction::{Seed, Signer as PinocchioSigner},
+    program_error::ProgramError,
     pubkey::Pubkey,
-    sysvars::{Sysvar, rent::Rent},
+    sysvars::{Sysvar, clock::Clock, rent::Rent},
 };
 use pinocchio_log::log;
 use pinocchio_system::instructions::CreateAccount;
 use pinocchio_token::instructions::InitializeAccount3;
+use zorb_pool_interface::FEE_RATE_UNSET;
 
 /// SPL Token account size
 const TOKEN_ACCOUNT_SIZE: usize = 165;
@@ -32,11 +39,6 @@ const SPL_TOKEN_PROGRAM_ID: Pubkey =
 const SPL_TOKEN_2022_PROGRAM_ID: Pubkey =
     pinocchio_pubkey::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
 
-/// SPL Stake Pool Program ID
-/// This is the canonical SPL Stake Pool program that manages stake pools for most LSTs
-const SPL_STAKE_POOL_PROGRAM_ID: Pubkey =
-    pinocchio_pubkey::pubkey!("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
-
 /// Instruction data for InitLstConfig.
 #[repr(C)]
 #[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
@@ -123,7 +125,14 @@ pub fn process_init_lst_config(
     }
 
     // Read and verify authority from unified config (releases borrow)
-    let unified_authority = unified_sol_pool_config.map(|config| config.authority)?;
+    let (unified_authority, stake_pool_program_allowed, reward_epoch) = unified_sol_pool_config
+        .map(|config| {
+            (
+                config.authority,
+                config.allows_stake_pool_program(stake_pool_program.key()),
+                config.reward_epoch,
+            )
+        })?;
 
     if unified_authority != *authority.key() {
         log!("init_lst_config: unauthorized");
@@ -162,48 +171,26 @@ pub fn process_init_lst_config(
             // WSOL doesn't need stake pool validation (rate is always 1:1)
         }
         PoolType::SplStakePool => {
-            // Validate stake_pool_program is the canonical SPL Stake Pool program
-            if *stake_pool_program.key() != SPL_STAKE_POOL_PROGRAM_ID {
-                log!("init_lst_config: invalid stake pool program");
-                return Err(UnifiedSolPoolError::InvalidStakePoolProgram.into());
-            }
-
-            // Validate stake_pool is owned by stake_pool_program
-            if *stake_pool.owner() != SPL_STAKE_POOL_PROGRAM_ID {
-                log!("init_lst_config: stake pool not owned by stake pool program");
-                return Err(UnifiedSolPoolError::InvalidStakePool.into());
-            }
-
-            // Validate stake pool's pool_mint matches provided lst_mint
-            // SPL Stake Pool layout:
-            //   [0]:      account_type (1 byte)
-            //   [1-33]:   manager (32 bytes)
-            //   [33-65]:  staker (32 bytes)
-            //   [65-66]:  stake_deposit_authority_bump (1 byte)
-            //   [66-67]:  validator_list_bump (1 byte)
-            //   [67-73]:  preferred_... (various)
-            //   [73-105]: pool_mint (32 bytes)
-            const POOL_MINT_OFFSET: usize = 73;
-            const PUBKEY_SIZE: usize = 32;
-
-            let stake_pool_data = stake_pool.try_borrow_data()?;
-            if stake_pool_data.len() < POOL_MINT_OFFSET + PUBKEY_SIZE {
-                log!("init_lst_config: stake pool data too short");
-                return Err(UnifiedSolPoolError::InvalidStakePool.into());
-            }
-
-            let pool_mint_bytes =
-                &stake_pool_data[POOL_MINT_OFFSET..POOL_MINT_OFFSET + PUBKEY_SIZE];
-
-            if pool_mint_bytes != lst_mint.key().as_ref() {
-                log!(
-                    "init_lst_config: stake pool mint mismatch - pool has different mint"
-                );
-                return Err(UnifiedSolPoolError::StakePoolMintMismatch.into());
-            }
+            // Any allowlisted SPL-layout program (SPL stake pool, Sanctum
+            // single-validator deployments, ...)
+            validate_rate_source::<StakePoolState>(
+                stake_pool,
+                stake_pool_program,
+                stake_pool_program_allowed,
+                lst_mint,
+            )?;
+        }
+        PoolType::Marinade => {
+            let is_marinade = *stake_pool_program.key() == MarinadeState::PROGRAM_ID;
+            validate_rate_source::<MarinadeState>(
+                stake_pool,
+                stake_pool_program,
+                is_marinade,
+                lst_mint,
+            )?;
         }
-        PoolType::Marinade | PoolType::Lido => {
-            // These pool types are not yet supported
+        PoolType::Lido => {
+            // This pool type is not yet supported
             log!("init_lst_config: pool type not yet supported");
             return Err(UnifiedSolPoolError::InvalidPoolType.into());
         }
@@ -264,12 +251,15 @@ pub fn process_init_lst_config(
     //
     // See: README.md "Audit TODOs" section for full analysis.
     // =============================================================================
+    let current_slot = Clock::get()?.slot;
     lst_config.inspect_mut(|config| {
         // === Header ===
         config.pool_type = data.pool_type;
-        config.is_active = 1;
+        config.mode = LST_MODE_ALL;
         config.bump = lst_config_bump;
-        config._header_pad = [0u8; 5];
+        config.is_retired = 0;
+        config.max_rate_increase_bps_per_epoch = LstConfig::DEFAULT_MAX_RATE_INCREASE_BPS_PER_EPOCH;
+        config.max_rate_decrease_bps_per_epoch = LstConfig::DEFAULT_MAX_RATE_DECREASE_BPS_PER_EPOCH;
 
         // === Common References ===
         config.lst_mint = *lst_mint.key();
@@ -295,12 +285,13 @@ pub fn process_init_lst_config(
         // - harvest_lst_appreciation.rs: sets last_harvest_epoch = current_epoch
         // =====================================================================
         config.last_harvest_epoch = 0;
-        config._pad_for_u128 = 0;
+        // The initial 1:1 rate counts as frozen now for staleness purposes
+        config.rate_updated_slot = current_slot;
 
         // === Value Tracking ===
         config.total_virtual_sol = 0;
         config.vault_token_balance = 0;
-        config._value_pad = 0;
+        config.max_rate_staleness_slots = LstConfig::DEFAULT_MAX_RATE_STALENESS_SLOTS;
 
         // === Statistics ===
         config.total_deposited = 0;
@@ -308,17 +299,40 @@ pub fn process_init_lst_config(
         config.total_appreciation_harvested = 0;
         config.deposit_count = 0;
         config.withdrawal_count = 0;
-        config._stat_pad = 0;
+
+        // === Fee Overrides ===
+        config.deposit_fee_bps_override = FEE_RATE_UNSET;
+        config.withdraw_fee_bps_override = FEE_RATE_UNSET;
+        config.version = LST_CONFIG_VERSION;
+        config._fee_pad = [0u8; 2];
 
         // === Stake Pool Specific ===
         config.stake_pool = *stake_pool.key();
         config.stake_pool_program = *stake_pool_program.key();
         config.previous_exchange_rate = LstConfig::RATE_PRECISION;
 
-        // === Reserved ===
-        config._reserved = 0;
+        // === Rate Bounds Override ===
+        config.approved_exchange_rate = 0;
+
+        // === Virtual SOL Cap ===
+        config.max_virtual_sol = 0;
+        config.virtual_sol_over_cap = 0;
     })?;
 
+    // Extensions: panchor sized the account for LstConfig alone
+    grow_account(
+        lst_config.account_info(),
+        authority,
+        LST_CONFIG_ACCOUNT_SIZE,
+    )?;
+    {
+        let mut data = lst_config.account_info().try_borrow_mut_data()?;
+        let history = LstRateHistory::from_account_data_mut(&mut data)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        // The initial 1:1 rate prices the current epoch
+        history.push(LstConfig::RATE_PRECISION, reward_epoch, current_slot);
+    }
+
     // Increment LST count in unified config (check limit first)
     unified_sol_pool_config.try_inspect_mut(|unified| {
         if unified.lst_count >= MAX_LST_CONFIGS {
@@ -336,3 +350,22 @@ pub fn process_init_lst_config(
 
     Ok(())
 }
+
+/// Validate a stake pool LST's rate source account.
+///
+/// `stake_pool_program` must be trusted for the source type (`allowed`), and
+/// `stake_pool` an account of that program pricing `lst_mint`.
+fn validate_rate_source<S: RateSource>(
+    stake_pool: &AccountInfo,
+    stake_pool_program: &AccountInfo,
+    allowed: bool,
+    lst_mint: &AccountInfo,
+) -> ProgramResult {
+    if !allowed {
+        log!("init_lst_config: invalid stake pool program");
+        return Err(UnifiedSolPoolError::InvalidStakePoolProgram.into());
+    }
+
+    load_for_mint::<S>(stake_pool, stake_pool_program.key(), lst_mint.key())?;
+    Ok(())
+}
@@ -1,14 +1,14 @@
 //! Initialize unified SOL pool configuration.
 
 use crate::{
-    UNIFIED_SOL_ASSET_ID, UnifiedSolPoolConfig, UnifiedSolPoolError,
-    find_unified_sol_pool_config_pda,
+    EpochMode, MAX_KEEPERS, UNIFIED_SOL_ASSET_ID, UnifiedSolPoolConfig, UnifiedSolPoolError,
+    find_unified_sol_pool_config_pda, rate_source::spl_stake_pool::SPL_STAKE_POOL_PROGRAM_ID,
 };
 use bytemuck::{Pod, Zeroable};
 use panchor::prelude::*;
 use pinocchio::ProgramResult;
 use pinocchio_log::log;
-use zorb_pool_interface::BASIS_POINTS;
+use zorb_pool_interface::{BASIS_POINTS, MAX_WITHDRAW_ROUTE_VAULTS};
 
 /// Instruction data for InitUnifiedSolPoolConfig.
 #[repr(C)]
@@ -91,7 +91,11 @@ pub fn process_init_unified_sol_pool_config(
         // - pool_config.rs (shielded-pool): last_harvest_epoch == accumulator_epoch - 1
         // =====================================================================
         config.reward_epoch = 1;
-        config._reserved1 = [0u64; 7];
+        config.last_finalized_solana_epoch = 0;
+        config.min_harvest_lamports = 0;
+        config.carried_appreciation = 0;
+        config.manual_rate_delay_slots = UnifiedSolPoolConfig::DEFAULT_MANUAL_RATE_DELAY_SLOTS;
+        config._reserved1 = [0u64; 3];
         config.total_virtual_sol = 0;
         config.reward_accumulator = 0;
         config.last_finalized_slot = 0;
@@ -120,7 +124,25 @@ pub fn process_init_unified_sol_pool_config(
         config.deposit_count = 0;
         config.withdrawal_count = 0;
         config.lst_count = 0;
-        config._reserved = [0u8; 23];
+        config.epoch_mode = EpochMode::SlotInterval as u8;
+        config._reserved = [0u8; 22];
+        // Only the canonical SPL stake pool program until the authority
+        // allowlists other SPL-layout deployments
+        config.stake_pool_programs = [[0u8; 32]; 4];
+        config.stake_pool_programs[0] = SPL_STAKE_POOL_PROGRAM_ID;
+        // Uncapped until the authority sets a limit
+        config.max_total_virtual_sol = 0;
+        config.total_virtual_sol_over_cap = 0;
+        config.withdraw_priority = [[0u8; 32]; MAX_WITHDRAW_ROUTE_VAULTS];
+        // No commission until the authority sets a rate and treasury
+        config.commission_treasury = [0u8; 32];
+        config.accrued_commission = 0;
+        config.appreciation_commission_bps = 0;
+        config._commission_padding = [0u8; 6];
+        // Permissionless harvests and finalization until the authority opts in
+        config.keepers = [[0u8; 32]; MAX_KEEPERS];
+        config.keeper_allowlist_enabled = 0;
+        config._keeper_padding = [0u8; 15];
     })?;
 
     log!("init_unified_sol_pool_config: initialized successfully");
@@ -0,0 +1,140 @@
+//! Migrate an LstConfig to the current layout version.
+
+use crate::{
+    LST_CONFIG_ACCOUNT_SIZE, LST_CONFIG_VERSION, LstConfig, LstRateHistory, UnifiedSolPoolConfig,
+    UnifiedSolPoolError, emit_event, events::LstConfigMigratedEvent,
+    gen_unified_sol_pool_config_seeds, utils::grow_account,
+};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    program_error::ProgramError,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+
+/// Accounts for the MigrateLstConfig instruction.
+#[derive(Accounts)]
+pub struct MigrateLstConfigAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA (for authority check; signs the event)
+    #[account(owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// LstConfig PDA to migrate (any layout version)
+    #[account(mut, owner = crate::ID)]
+    pub lst_config: AccountLoader<'info, LstConfig>,
+
+    /// Must match unified_sol_pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Tops up rent for the grown account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for the rent transfer
+    pub system_program: Program<'info, System>,
+
+    /// Unified SOL pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub unified_sol_program: &'info AccountInfo,
+}
+
+/// Upgrade the account contents from `version` to `version + 1`.
+///
+/// Runs after the account has been grown and zero-filled, so new fields
+/// start at zero and only need non-zero defaults written here.
+fn upgrade_from(
+    lst_config: &AccountLoader<LstConfig>,
+    version: u16,
+    reward_epoch: u64,
+) -> ProgramResult {
+    match version {
+        // v2 appended the rate history: seed it with the rate in force now
+        1 => {
+            let (rate, slot) = lst_config
+                .map(|config| (config.harvested_exchange_rate, config.rate_updated_slot))?;
+            let mut data = lst_config.account_info().try_borrow_mut_data()?;
+            let history = LstRateHistory::from_account_data_mut(&mut data)
+                .ok_or(ProgramError::InvalidAccountData)?;
+            history.push(rate, reward_epoch, slot);
+        }
+        // v3 appended the manual rate proposal: zero-filled means none pending
+        2 => {}
+        // v4 appended per-LST pending rewards: zero-filled means none attributed
+        // yet, so this LST reports zeros until the next finalization
+        3 => {}
+        _ => return Err(ProgramError::InvalidAccountData),
+    }
+    Ok(())
+}
+
+/// Migrate an LstConfig in place to [`LST_CONFIG_VERSION`].
+///
+/// Reallocs the account to [`LST_CONFIG_ACCOUNT_SIZE`] (the payer covers the
+/// additional rent), runs the upgrade step for every intermediate version,
+/// and records the new version. Rejected once the account is current.
+///
+/// `LstConfig` itself is the same in every version, so deposits, withdrawals
+/// and harvests work before migration; only the rate history, manual rate
+/// overrides and per-LST finalization figures wait for it.
+pub fn process_migrate_lst_config(ctx: Context<MigrateLstConfigAccounts>) -> ProgramResult {
+    let MigrateLstConfigAccounts {
+        unified_sol_pool_config,
+        lst_config,
+        authority,
+        payer,
+        system_program,
+        unified_sol_program,
+    } = ctx.accounts;
+
+    if *system_program.key() != pinocchio_contrib::constants::SYSTEM_PROGRAM_ID {
+        log!("migrate_lst_config: invalid system program");
+        return Err(UnifiedSolPoolError::InvalidSystemProgram.into());
+    }
+
+    let (unified_authority, unified_bump, reward_epoch) = unified_sol_pool_config
+        .map(|config| (config.authority, config.bump, config.reward_epoch))?;
+    if unified_authority != *authority.key() {
+        log!("migrate_lst_config: unauthorized");
+        return Err(UnifiedSolPoolError::Unauthorized.into());
+    }
+
+    let (old_version, lst_mint) =
+        lst_config.map(|config| (config.layout_version(), config.lst_mint))?;
+    if old_version >= LST_CONFIG_VERSION {
+        log!("migrate_lst_config: already current");
+        return Err(UnifiedSolPoolError::LstConfigUpToDate.into());
+    }
+
+    // Grow the account (zero-filled) and top up rent for the new size
+    let account_info = lst_config.account_info();
+    let new_size = LST_CONFIG_ACCOUNT_SIZE.max(account_info.data_len());
+    grow_account(account_info, payer, new_size)?;
+
+    // Run each upgrade step and stamp the new version
+    for version in old_version..LST_CONFIG_VERSION {
+        upgrade_from(lst_config, version, reward_epoch)?;
+    }
+    lst_config.inspect_mut(|config| config.version = LST_CONFIG_VERSION)?;
+
+    let bump_bytes = [unified_bump];
+    let seeds = gen_unified_sol_pool_config_seeds(&bump_bytes);
+    emit_event(
+        unified_sol_pool_config.account_info(),
+        unified_sol_program,
+        PinocchioSigner::from(&seeds),
+        &LstConfigMigratedEvent {
+            lst_mint,
+            old_version,
+            new_version: LST_CONFIG_VERSION,
+            _padding: [0u8; 4],
+            new_size: new_size as u64,
+            slot: Clock::get()?.slot,
+        },
+    )?;
+
+    log!("migrate_lst_config: migrated successfully");
+    Ok(())
+}
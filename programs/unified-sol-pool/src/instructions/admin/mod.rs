@@ -3,22 +3,93 @@
 //! These instructions are admin-gated and manage pool configuration.
 
 mod accept_authority;
+mod add_keeper;
+mod add_stake_pool_program;
+mod approve_lst_rate_jump;
+mod cancel_manual_rate;
+mod close_lst_config;
 mod init_lst_config;
 mod init_unified_sol_pool_config;
+mod migrate_lst_config;
+mod propose_manual_rate;
+mod remove_keeper;
+mod remove_stake_pool_program;
+mod set_appreciation_commission;
+mod set_epoch_mode;
+mod set_keeper_allowlist_enabled;
 mod set_lst_config_active;
+mod set_lst_config_fee_overrides;
+mod set_lst_config_mode;
+mod set_lst_config_rate_bounds;
+mod set_lst_config_retired;
+mod set_lst_config_virtual_sol_cap;
+mod set_manual_rate_delay;
+mod set_min_harvest_lamports;
 mod set_unified_sol_pool_config_active;
 mod set_unified_sol_pool_config_fee_rates;
+mod set_unified_sol_pool_config_virtual_sol_cap;
+mod set_withdraw_priority;
 mod transfer_authority;
+mod withdraw_commission;
 
 pub use accept_authority::{AcceptAuthorityAccounts, process_accept_authority};
+pub use add_keeper::{AddKeeperAccounts, AddKeeperData, process_add_keeper};
+pub use add_stake_pool_program::{
+    AddStakePoolProgramAccounts, AddStakePoolProgramData, process_add_stake_pool_program,
+};
+pub use approve_lst_rate_jump::{
+    ApproveLstRateJumpAccounts, ApproveLstRateJumpData, process_approve_lst_rate_jump,
+};
+pub use cancel_manual_rate::{CancelManualRateAccounts, process_cancel_manual_rate};
+pub use close_lst_config::{CloseLstConfigAccounts, process_close_lst_config};
 pub use init_lst_config::{InitLstConfigAccounts, InitLstConfigData, process_init_lst_config};
 pub use init_unified_sol_pool_config::{
     InitUnifiedSolPoolConfigAccounts, InitUnifiedSolPoolConfigData,
     process_init_unified_sol_pool_config,
 };
+pub use migrate_lst_config::{MigrateLstConfigAccounts, process_migrate_lst_config};
+pub use propose_manual_rate::{
+    ProposeManualRateAccounts, ProposeManualRateData, process_propose_manual_rate,
+};
+pub use remove_keeper::{RemoveKeeperAccounts, RemoveKeeperData, process_remove_keeper};
+pub use remove_stake_pool_program::{
+    RemoveStakePoolProgramAccounts, RemoveStakePoolProgramData, process_remove_stake_pool_program,
+};
+pub use set_appreciation_commission::{
+    SetAppreciationCommissionAccounts, SetAppreciationCommissionData,
+    process_set_appreciation_commission,
+};
+pub use set_epoch_mode::{SetEpochModeAccounts, SetEpochModeData, process_set_epoch_mode};
+pub use set_keeper_allowlist_enabled::{
+    SetKeeperAllowlistEnabledAccounts, SetKeeperAllowlistEnabledData,
+    process_set_keeper_allowlist_enabled,
+};
 pub use set_lst_config_active::{
     SetLstConfigActiveAccounts, SetLstConfigActiveData, process_set_lst_config_active,
 };
+pub use set_lst_config_fee_overrides::{
+    SetLstConfigFeeOverridesAccounts, SetLstConfigFeeOverridesData,
+    process_set_lst_config_fee_overrides,
+};
+pub use set_lst_config_mode::{
+    SetLstConfigModeAccounts, SetLstConfigModeData, process_set_lst_config_mode,
+};
+pub use set_lst_config_rate_bounds::{
+    SetLstConfigRateBoundsAccounts, SetLstConfigRateBoundsData, process_set_lst_config_rate_bounds,
+};
+pub use set_lst_config_retired::{
+    SetLstConfigRetiredAccounts, SetLstConfigRetiredData, process_set_lst_config_retired,
+};
+pub use set_lst_config_virtual_sol_cap::{
+    SetLstConfigVirtualSolCapAccounts, SetLstConfigVirtualSolCapData,
+    process_set_lst_config_virtual_sol_cap,
+};
+pub use set_manual_rate_delay::{
+    SetManualRateDelayAccounts, SetManualRateDelayData, process_set_manual_rate_delay,
+};
+pub use set_min_harvest_lamports::{
+    SetMinHarvestLamportsAccounts, SetMinHarvestLamportsData, process_set_min_harvest_lamports,
+};
 pub use set_unified_sol_pool_config_active::{
     SetUnifiedSolPoolConfigActiveAccounts, SetUnifiedSolPoolConfigActiveData,
     process_set_unified_sol_pool_config_active,
@@ -27,4 +98,74 @@ pub use set_unified_sol_pool_config_fee_rates::{
     SetUnifiedSolPoolConfigFeeRatesAccounts, SetUnifiedSolPoolConfigFeeRatesData,
     process_set_unified_sol_pool_config_fee_rates,
 };
+pub use set_unified_sol_pool_config_virtual_sol_cap::{
+    SetUnifiedSolPoolConfigVirtualSolCapAccounts, SetUnifiedSolPoolConfigVirtualSolCapData,
+    process_set_unified_sol_pool_config_virtual_sol_cap,
+};
+pub use set_withdraw_priority::{
+    SetWithdrawPriorityAccounts, SetWithdrawPriorityData, process_set_withdraw_priority,
+};
 pub use transfer_authority::{TransferAuthorityAccounts, process_transfer_authority};
+pub use withdraw_commission::{
+    WithdrawCommissionAccounts, WithdrawCommissionData, process_withdraw_commission,
+};
+
+// Re-export client Input structs (generated by #[derive(Accounts)])
+#[cfg(feature = "solana-sdk")]
+pub use accept_authority::AcceptAuthorityInput;
+#[cfg(feature = "solana-sdk")]
+pub use add_keeper::AddKeeperInput;
+#[cfg(feature = "solana-sdk")]
+pub use add_stake_pool_program::AddStakePoolProgramInput;
+#[cfg(feature = "solana-sdk")]
+pub use approve_lst_rate_jump::ApproveLstRateJumpInput;
+#[cfg(feature = "solana-sdk")]
+pub use cancel_manual_rate::CancelManualRateInput;
+#[cfg(feature = "solana-sdk")]
+pub use close_lst_config::CloseLstConfigInput;
+#[cfg(feature = "solana-sdk")]
+pub use init_lst_config::InitLstConfigInput;
+#[cfg(feature = "solana-sdk")]
+pub use init_unified_sol_pool_config::InitUnifiedSolPoolConfigInput;
+#[cfg(feature = "solana-sdk")]
+pub use migrate_lst_config::MigrateLstConfigInput;
+#[cfg(feature = "solana-sdk")]
+pub use propose_manual_rate::ProposeManualRateInput;
+#[cfg(feature = "solana-sdk")]
+pub use remove_keeper::RemoveKeeperInput;
+#[cfg(feature = "solana-sdk")]
+pub use remove_stake_pool_program::RemoveStakePoolProgramInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_appreciation_commission::SetAppreciationCommissionInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_epoch_mode::SetEpochModeInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_keeper_allowlist_enabled::SetKeeperAllowlistEnabledInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_lst_config_active::SetLstConfigActiveInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_lst_config_fee_overrides::SetLstConfigFeeOverridesInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_lst_config_mode::SetLstConfigModeInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_lst_config_rate_bounds::SetLstConfigRateBoundsInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_lst_config_retired::SetLstConfigRetiredInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_lst_config_virtual_sol_cap::SetLstConfigVirtualSolCapInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_manual_rate_delay::SetManualRateDelayInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_min_harvest_lamports::SetMinHarvestLamportsInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_unified_sol_pool_config_active::SetUnifiedSolPoolConfigActiveInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_unified_sol_pool_config_fee_rates::SetUnifiedSolPoolConfigFeeRatesInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_unified_sol_pool_config_virtual_sol_cap::SetUnifiedSolPoolConfigVirtualSolCapInput;
+#[cfg(feature = "solana-sdk")]
+pub use set_withdraw_priority::SetWithdrawPriorityInput;
+#[cfg(feature = "solana-sdk")]
+pub use transfer_authority::TransferAuthorityInput;
+#[cfg(feature = "solana-sdk")]
+pub use withdraw_commission::WithdrawCommissionInput;
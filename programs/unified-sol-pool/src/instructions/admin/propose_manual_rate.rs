@@ -0,0 +1,116 @@
+//! Propose an emergency exchange rate for an LST.
+
+use crate::{
+    LstConfig, LstManualRate, PoolType, UnifiedSolPoolConfig, UnifiedSolPoolError, emit_event,
+    events::ManualRateProposedEvent, gen_unified_sol_pool_config_seeds,
+};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+
+/// Instruction data for ProposeManualRate.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct ProposeManualRateData {
+    /// Proposed exchange rate (1 LST = rate/1e9 SOL)
+    pub rate: u64,
+}
+
+/// Accounts for the ProposeManualRate instruction.
+#[derive(Accounts)]
+pub struct ProposeManualRateAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA (for authority check, delay and event signing)
+    #[account(owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// LstConfig PDA of the LST to override (version 3 or later)
+    #[account(mut, owner = crate::ID)]
+    pub lst_config: AccountLoader<'info, LstConfig>,
+
+    /// Must match unified_sol_pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Unified SOL pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub unified_sol_program: &'info AccountInfo,
+}
+
+/// Propose `rate` as a manual exchange rate for a stake pool LST.
+///
+/// For when the LST's rate source can no longer be read and harvests (and so
+/// finalization) are stuck. The proposal unlocks after the pool's
+/// `manual_rate_delay()` and replaces any pending one, restarting the delay.
+/// `apply_manual_rate` puts it into effect; `cancel_manual_rate` drops it.
+pub fn process_propose_manual_rate(
+    ctx: Context<ProposeManualRateAccounts>,
+    data: ProposeManualRateData,
+) -> ProgramResult {
+    let ProposeManualRateAccounts {
+        unified_sol_pool_config,
+        lst_config,
+        authority,
+        unified_sol_program,
+    } = ctx.accounts;
+
+    let (unified_authority, unified_bump, delay) = unified_sol_pool_config
+        .map(|config| (config.authority, config.bump, config.manual_rate_delay()))?;
+
+    if unified_authority != *authority.key() {
+        log!("propose_manual_rate: unauthorized");
+        return Err(UnifiedSolPoolError::Unauthorized.into());
+    }
+
+    let (lst_mint, pool_type, old_rate) =
+        lst_config.map(|config| (config.lst_mint, config.pool_type, config.exchange_rate))?;
+
+    // WSOL is fixed at 1:1 and has no rate source to fail
+    if pool_type == PoolType::Wsol as u8 {
+        log!("propose_manual_rate: WSOL rate cannot be overridden");
+        return Err(UnifiedSolPoolError::InvalidPoolType.into());
+    }
+
+    // Exchange rates must stay >= RATE_PRECISION (see harvest_lst_appreciation.rs)
+    if data.rate < LstConfig::RATE_PRECISION {
+        log!("propose_manual_rate: exchange rate below 1:1");
+        return Err(UnifiedSolPoolError::InvalidExchangeRate.into());
+    }
+
+    let current_slot = Clock::get()?.slot;
+    let unlock_slot = current_slot
+        .checked_add(delay)
+        .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?;
+
+    {
+        let mut account_data = lst_config.account_info().try_borrow_mut_data()?;
+        let proposal =
+            LstManualRate::from_account_data_mut(&mut account_data).ok_or_else(|| {
+                log!("propose_manual_rate: LstConfig needs migration");
+                UnifiedSolPoolError::LstConfigNeedsMigration
+            })?;
+        proposal.propose(data.rate, unlock_slot);
+    }
+
+    let bump_bytes = [unified_bump];
+    let seeds = gen_unified_sol_pool_config_seeds(&bump_bytes);
+    emit_event(
+        unified_sol_pool_config.account_info(),
+        unified_sol_program,
+        PinocchioSigner::from(&seeds),
+        &ManualRateProposedEvent {
+            lst_mint,
+            old_rate,
+            new_rate: data.rate,
+            unlock_slot,
+            slot: current_slot,
+        },
+    )?;
+
+    log!("propose_manual_rate: proposed");
+    Ok(())
+}
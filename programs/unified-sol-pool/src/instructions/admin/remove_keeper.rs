@@ -0,0 +1,80 @@
+//! Remove a keeper from the keeper allowlist.
+
+use crate::{
+    UnifiedSolPoolConfig, UnifiedSolPoolError, emit_event, events::KeeperRemovedEvent,
+    gen_unified_sol_pool_config_seeds,
+};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+
+/// Instruction data for RemoveKeeper.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct RemoveKeeperData {
+    /// Keeper to remove from the allowlist
+    pub keeper: [u8; 32],
+}
+
+/// Accounts for the RemoveKeeper instruction.
+#[derive(Accounts)]
+pub struct RemoveKeeperAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// Must match unified_sol_pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Unified SOL pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub unified_sol_program: &'info AccountInfo,
+}
+
+/// Stop allowing `keeper` to run keeper operations.
+///
+/// Fails with `KeeperNotListed` if `keeper` is not on the allowlist, and with
+/// `KeeperAllowlistEmpty` if it is the last keeper while the allowlist is
+/// enforced: lift the allowlist first.
+pub fn process_remove_keeper(
+    ctx: Context<RemoveKeeperAccounts>,
+    data: RemoveKeeperData,
+) -> ProgramResult {
+    let RemoveKeeperAccounts {
+        unified_sol_pool_config,
+        authority,
+        unified_sol_program,
+    } = ctx.accounts;
+
+    let bump = unified_sol_pool_config.try_map_mut(|config| {
+        if config.authority != *authority.key() {
+            log!("remove_keeper: unauthorized");
+            return Err(UnifiedSolPoolError::Unauthorized.into());
+        }
+        config.remove_keeper(&data.keeper)?;
+        Ok(config.bump)
+    })?;
+
+    let bump_bytes = [bump];
+    let seeds = gen_unified_sol_pool_config_seeds(&bump_bytes);
+    emit_event(
+        unified_sol_pool_config.account_info(),
+        unified_sol_program,
+        PinocchioSigner::from(&seeds),
+        &KeeperRemovedEvent {
+            keeper: data.keeper,
+            authority: *authority.key(),
+            slot: Clock::get()?.slot,
+            _padding: 0,
+        },
+    )?;
+
+    log!("remove_keeper: success");
+    Ok(())
+}
@@ -0,0 +1,81 @@
+//! Remove a program from the stake pool program allowlist.
+
+use crate::{
+    UnifiedSolPoolConfig, UnifiedSolPoolError, emit_event, events::StakePoolProgramRemovedEvent,
+    gen_unified_sol_pool_config_seeds,
+};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+
+/// Instruction data for RemoveStakePoolProgram.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct RemoveStakePoolProgramData {
+    /// Stake pool program to stop trusting
+    pub program: [u8; 32],
+}
+
+/// Accounts for the RemoveStakePoolProgram instruction.
+#[derive(Accounts)]
+pub struct RemoveStakePoolProgramAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// Must match unified_sol_pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Unified SOL pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub unified_sol_program: &'info AccountInfo,
+}
+
+/// Stop trusting `program` as the owner of SPL-layout rate source accounts.
+///
+/// New LSTs can no longer be registered against it, and LSTs already
+/// registered against it fail to harvest (and so block finalization) until
+/// they are deactivated or the program is added back. Fails with
+/// `StakePoolProgramNotListed` if `program` is not on the allowlist.
+pub fn process_remove_stake_pool_program(
+    ctx: Context<RemoveStakePoolProgramAccounts>,
+    data: RemoveStakePoolProgramData,
+) -> ProgramResult {
+    let RemoveStakePoolProgramAccounts {
+        unified_sol_pool_config,
+        authority,
+        unified_sol_program,
+    } = ctx.accounts;
+
+    let bump = unified_sol_pool_config.try_map_mut(|config| {
+        if config.authority != *authority.key() {
+            log!("remove_stake_pool_program: unauthorized");
+            return Err(UnifiedSolPoolError::Unauthorized.into());
+        }
+        config.remove_stake_pool_program(&data.program)?;
+        Ok(config.bump)
+    })?;
+
+    let bump_bytes = [bump];
+    let seeds = gen_unified_sol_pool_config_seeds(&bump_bytes);
+    emit_event(
+        unified_sol_pool_config.account_info(),
+        unified_sol_program,
+        PinocchioSigner::from(&seeds),
+        &StakePoolProgramRemovedEvent {
+            program: data.program,
+            authority: *authority.key(),
+            slot: Clock::get()?.slot,
+            _padding: 0,
+        },
+    )?;
+
+    log!("remove_stake_pool_program: success");
+    Ok(())
+}
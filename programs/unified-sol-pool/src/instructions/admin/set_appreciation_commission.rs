@@ -0,0 +1,91 @@
+//! Set the protocol commission on harvested LST appreciation.
+
+use crate::{
+    UnifiedSolPoolConfig, UnifiedSolPoolError, emit_event,
+    events::AppreciationCommissionUpdatedEvent, gen_unified_sol_pool_config_seeds,
+};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+
+/// Instruction data for SetAppreciationCommission.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetAppreciationCommissionData {
+    /// Owner of the token accounts commission is paid to (zero = withdrawals disabled)
+    pub commission_treasury: [u8; 32],
+    /// Share of harvested appreciation kept by the protocol (basis points, max 10000)
+    pub appreciation_commission_bps: u16,
+    /// Padding for alignment
+    pub _padding: [u8; 6],
+}
+
+/// Accounts for the SetAppreciationCommission instruction.
+#[derive(Accounts)]
+pub struct SetAppreciationCommissionAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA to update (also signs the event)
+    #[account(mut, owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// Must match unified_sol_pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Unified SOL pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub unified_sol_program: &'info AccountInfo,
+}
+
+/// Set the appreciation commission rate and the treasury it is paid to.
+///
+/// Applies to appreciation harvested from now on; commission already accrued
+/// is unaffected. Fails with `InvalidFeeRate` above 10000 basis points.
+pub fn process_set_appreciation_commission(
+    ctx: Context<SetAppreciationCommissionAccounts>,
+    data: SetAppreciationCommissionData,
+) -> ProgramResult {
+    let SetAppreciationCommissionAccounts {
+        unified_sol_pool_config,
+        authority,
+        unified_sol_program,
+    } = ctx.accounts;
+
+    let unified_bump = unified_sol_pool_config.try_map_mut(|config| {
+        // Verify authority
+        if config.authority != *authority.key() {
+            log!("set_appreciation_commission: unauthorized");
+            return Err(UnifiedSolPoolError::Unauthorized.into());
+        }
+
+        if let Err(e) = config.set_appreciation_commission(
+            data.appreciation_commission_bps,
+            &data.commission_treasury,
+        ) {
+            log!("set_appreciation_commission: commission exceeds 10000 bps");
+            return Err(e.into());
+        }
+        Ok(config.bump)
+    })?;
+
+    let bump_bytes = [unified_bump];
+    let seeds = gen_unified_sol_pool_config_seeds(&bump_bytes);
+    emit_event(
+        unified_sol_pool_config.account_info(),
+        unified_sol_program,
+        PinocchioSigner::from(&seeds),
+        &AppreciationCommissionUpdatedEvent {
+            commission_treasury: data.commission_treasury,
+            slot: Clock::get()?.slot,
+            appreciation_commission_bps: data.appreciation_commission_bps,
+            _padding: [0u8; 6],
+        },
+    )?;
+
+    log!("set_appreciation_commission: updated successfully");
+    Ok(())
+}
@@ -0,0 +1,90 @@
+//! Set what advances the unified SOL pool's reward epoch.
+
+use crate::{
+    EpochMode, UnifiedSolPoolConfig, UnifiedSolPoolError, emit_event,
+    events::EpochModeUpdatedEvent, gen_unified_sol_pool_config_seeds,
+};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+
+/// Instruction data for SetEpochMode.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetEpochModeData {
+    /// 0 = SlotInterval, 1 = SolanaEpoch
+    pub epoch_mode: u8,
+    /// Padding for alignment
+    pub _padding: [u8; 7],
+}
+
+/// Accounts for the SetEpochMode instruction.
+#[derive(Accounts)]
+pub struct SetEpochModeAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA to update (also signs the event)
+    #[account(mut, owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// Must match unified_sol_pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Unified SOL pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub unified_sol_program: &'info AccountInfo,
+}
+
+/// Switch finalization between the slot interval and Solana epochs.
+///
+/// Takes effect from the next finalization, measured from the last one:
+/// `last_finalized_slot` and `last_finalized_solana_epoch` are recorded in
+/// every mode. Fails with `InvalidEpochMode` for an unknown mode.
+pub fn process_set_epoch_mode(
+    ctx: Context<SetEpochModeAccounts>,
+    data: SetEpochModeData,
+) -> ProgramResult {
+    let SetEpochModeAccounts {
+        unified_sol_pool_config,
+        authority,
+        unified_sol_program,
+    } = ctx.accounts;
+
+    if EpochMode::from_u8(data.epoch_mode).is_none() {
+        log!("set_epoch_mode: invalid epoch mode");
+        return Err(UnifiedSolPoolError::InvalidEpochMode.into());
+    }
+
+    let (unified_bump, last_finalized_solana_epoch) =
+        unified_sol_pool_config.try_map_mut(|config| {
+            // Verify authority
+            if config.authority != *authority.key() {
+                log!("set_epoch_mode: unauthorized");
+                return Err(UnifiedSolPoolError::Unauthorized.into());
+            }
+
+            config.epoch_mode = data.epoch_mode;
+            Ok((config.bump, config.last_finalized_solana_epoch))
+        })?;
+
+    let bump_bytes = [unified_bump];
+    let seeds = gen_unified_sol_pool_config_seeds(&bump_bytes);
+    emit_event(
+        unified_sol_pool_config.account_info(),
+        unified_sol_program,
+        PinocchioSigner::from(&seeds),
+        &EpochModeUpdatedEvent {
+            last_finalized_solana_epoch,
+            slot: Clock::get()?.slot,
+            epoch_mode: data.epoch_mode,
+            _padding: [0u8; 7],
+        },
+    )?;
+
+    log!("set_epoch_mode: updated successfully");
+    Ok(())
+}
@@ -0,0 +1,85 @@
+//! Enforce or lift the keeper allowlist.
+
+use crate::{
+    UnifiedSolPoolConfig, UnifiedSolPoolError, emit_event,
+    events::KeeperAllowlistEnabledUpdatedEvent, gen_unified_sol_pool_config_seeds,
+};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+
+/// Instruction data for SetKeeperAllowlistEnabled.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetKeeperAllowlistEnabledData {
+    /// 1 = restrict keeper operations to the allowlist, 0 = permissionless
+    pub enabled: u8,
+    /// Padding for alignment
+    pub _padding: [u8; 7],
+}
+
+/// Accounts for the SetKeeperAllowlistEnabled instruction.
+#[derive(Accounts)]
+pub struct SetKeeperAllowlistEnabledAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA to update (also signs the event)
+    #[account(mut, owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// Must match unified_sol_pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Unified SOL pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub unified_sol_program: &'info AccountInfo,
+}
+
+/// Restrict harvests, manual rate application and finalization to the
+/// keeper allowlist, or open them to anyone again.
+///
+/// Fails with `KeeperAllowlistEmpty` when enabling with no keepers listed,
+/// which would leave nobody able to finalize.
+pub fn process_set_keeper_allowlist_enabled(
+    ctx: Context<SetKeeperAllowlistEnabledAccounts>,
+    data: SetKeeperAllowlistEnabledData,
+) -> ProgramResult {
+    let SetKeeperAllowlistEnabledAccounts {
+        unified_sol_pool_config,
+        authority,
+        unified_sol_program,
+    } = ctx.accounts;
+
+    let (bump, keeper_count) = unified_sol_pool_config.try_map_mut(|config| {
+        if config.authority != *authority.key() {
+            log!("set_keeper_allowlist_enabled: unauthorized");
+            return Err(UnifiedSolPoolError::Unauthorized.into());
+        }
+        if let Err(e) = config.set_keeper_allowlist_enabled(data.enabled != 0) {
+            log!("set_keeper_allowlist_enabled: no keepers listed");
+            return Err(e.into());
+        }
+        Ok((config.bump, config.keeper_count() as u8))
+    })?;
+
+    let bump_bytes = [bump];
+    let seeds = gen_unified_sol_pool_config_seeds(&bump_bytes);
+    emit_event(
+        unified_sol_pool_config.account_info(),
+        unified_sol_program,
+        PinocchioSigner::from(&seeds),
+        &KeeperAllowlistEnabledUpdatedEvent {
+            slot: Clock::get()?.slot,
+            enabled: (data.enabled != 0) as u8,
+            keeper_count,
+            _padding: [0u8; 6],
+        },
+    )?;
+
+    log!("set_keeper_allowlist_enabled: updated successfully");
+    Ok(())
+}
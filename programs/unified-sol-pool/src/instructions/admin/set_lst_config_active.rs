@@ -1,6 +1,6 @@
 //! Set active state for LST config.
 
-use crate::{LstConfig, UnifiedSolPoolConfig, UnifiedSolPoolError};
+use crate::{LST_MODE_ALL, LstConfig, UnifiedSolPoolConfig, UnifiedSolPoolError};
 use bytemuck::{Pod, Zeroable};
 use panchor::prelude::*;
 use pinocchio::ProgramResult;
@@ -33,7 +33,8 @@ pub struct SetLstConfigActiveAccounts<'info> {
 
 /// Set the active state for an LST config.
 ///
-/// When inactive, deposits and withdrawals for this LST are blocked.
+/// Compatibility wrapper over `SetLstConfigMode` that sets or clears both
+/// directions. When inactive, deposits and withdrawals for this LST are blocked.
 pub fn process_set_lst_config_active(
     ctx: Context<SetLstConfigActiveAccounts>,
     data: SetLstConfigActiveData,
@@ -54,8 +55,8 @@ pub fn process_set_lst_config_active(
 
     // Update LST config active state
     lst_config.try_inspect_mut(|config| {
-        // Update active state directly (no inversion)
-        config.is_active = data.is_active;
+        // Active enables both directions, inactive disables both
+        config.mode = if data.is_active != 0 { LST_MODE_ALL } else { 0 };
 
         log!("set_lst_config_active: updated successfully");
         Ok(())
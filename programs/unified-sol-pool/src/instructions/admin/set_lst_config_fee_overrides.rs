@@ -0,0 +1,73 @@
+//! Set per-LST fee rate overrides.
+
+use crate::{LstConfig, UnifiedSolPoolConfig, UnifiedSolPoolError};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::ProgramResult;
+use pinocchio_log::log;
+use zorb_pool_interface::{BASIS_POINTS, FEE_RATE_UNSET};
+
+/// Instruction data for SetLstConfigFeeOverrides.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetLstConfigFeeOverridesData {
+    /// Deposit fee override in basis points (max 10000, 0xFFFF = use pool-wide rate)
+    pub deposit_fee_bps_override: u16,
+    /// Withdrawal fee override in basis points (max 10000, 0xFFFF = use pool-wide rate)
+    pub withdraw_fee_bps_override: u16,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 4],
+}
+
+/// Accounts for the SetLstConfigFeeOverrides instruction.
+#[derive(Accounts)]
+pub struct SetLstConfigFeeOverridesAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA (for authority check)
+    #[account(owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// LstConfig PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub lst_config: AccountLoader<'info, LstConfig>,
+
+    /// Must match unified_sol_pool_config.authority
+    pub authority: Signer<'info>,
+}
+
+/// Set the fee rates for one LST, overriding the pool-wide rates.
+///
+/// Each override applies on its own; pass `FEE_RATE_UNSET` (0xFFFF) to fall
+/// back to the pool-wide rate. The hub resolves the same effective rates
+/// when it validates fees, so no hub change is needed per LST.
+pub fn process_set_lst_config_fee_overrides(
+    ctx: Context<SetLstConfigFeeOverridesAccounts>,
+    data: SetLstConfigFeeOverridesData,
+) -> ProgramResult {
+    let SetLstConfigFeeOverridesAccounts {
+        unified_sol_pool_config,
+        lst_config,
+        authority,
+    } = ctx.accounts;
+
+    // Validate fee rates (max 100%, or unset)
+    let valid = |rate: u16| rate == FEE_RATE_UNSET || rate <= BASIS_POINTS as u16;
+    if !valid(data.deposit_fee_bps_override) || !valid(data.withdraw_fee_bps_override) {
+        log!("set_lst_config_fee_overrides: fee rate too high");
+        return Err(UnifiedSolPoolError::InvalidFeeRate.into());
+    }
+
+    // Verify authority
+    let unified_authority = unified_sol_pool_config.map(|config| config.authority)?;
+    if unified_authority != *authority.key() {
+        log!("set_lst_config_fee_overrides: unauthorized");
+        return Err(UnifiedSolPoolError::Unauthorized.into());
+    }
+
+    lst_config.inspect_mut(|config| {
+        config.deposit_fee_bps_override = data.deposit_fee_bps_override;
+        config.withdraw_fee_bps_override = data.withdraw_fee_bps_override;
+    })?;
+
+    log!("set_lst_config_fee_overrides: updated successfully");
+    Ok(())
+}
@@ -0,0 +1,67 @@
+//! Set the enabled directions for an LST config.
+
+use crate::{LST_MODE_ALL, LstConfig, UnifiedSolPoolConfig, UnifiedSolPoolError};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::ProgramResult;
+use pinocchio_log::log;
+
+/// Instruction data for SetLstConfigMode.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetLstConfigModeData {
+    /// Enabled directions (`LST_MODE_DEPOSITS_ENABLED | LST_MODE_WITHDRAWALS_ENABLED`)
+    pub mode: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 7],
+}
+
+/// Accounts for the SetLstConfigMode instruction.
+#[derive(Accounts)]
+pub struct SetLstConfigModeAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA (for authority check)
+    #[account(owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// LstConfig PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub lst_config: AccountLoader<'info, LstConfig>,
+
+    /// Must match unified_sol_pool_config.authority
+    pub authority: Signer<'info>,
+}
+
+/// Set which directions are enabled for an LST config.
+///
+/// Lets the authority pause deposits of an LST while users can still exit
+/// (or the reverse). Fails with `InvalidLstMode` if unknown bits are set.
+pub fn process_set_lst_config_mode(
+    ctx: Context<SetLstConfigModeAccounts>,
+    data: SetLstConfigModeData,
+) -> ProgramResult {
+    let SetLstConfigModeAccounts {
+        unified_sol_pool_config,
+        lst_config,
+        authority,
+    } = ctx.accounts;
+
+    if !LstConfig::is_valid_mode(data.mode) {
+        log!("set_lst_config_mode: invalid mode {}", data.mode);
+        return Err(UnifiedSolPoolError::InvalidLstMode.into());
+    }
+
+    // Read authority from unified config (releases borrow after closure)
+    let unified_authority = unified_sol_pool_config.map(|config| config.authority)?;
+
+    if unified_authority != *authority.key() {
+        log!("set_lst_config_mode: unauthorized");
+        return Err(UnifiedSolPoolError::Unauthorized.into());
+    }
+
+    lst_config.try_inspect_mut(|config| {
+        config.mode = data.mode & LST_MODE_ALL;
+
+        log!("set_lst_config_mode: mode set to {}", config.mode);
+        Ok(())
+    })
+}
@@ -0,0 +1,106 @@
+//! Set the exchange-rate band and staleness limit for an LST config.
+
+use crate::{
+    LstConfig, UnifiedSolPoolConfig, UnifiedSolPoolError, emit_event,
+    events::LstRateBoundsUpdatedEvent, gen_unified_sol_pool_config_seeds,
+};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+
+/// Instruction data for SetLstConfigRateBounds.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetLstConfigRateBoundsData {
+    /// Maximum rate increase per finalization (basis points, at most 1000)
+    pub max_rate_increase_bps_per_epoch: u16,
+    /// Maximum rate decrease per finalization (basis points, at most 1000)
+    pub max_rate_decrease_bps_per_epoch: u16,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 4],
+    /// Maximum age of the frozen rate for deposits/withdrawals (slots)
+    pub max_rate_staleness_slots: u64,
+}
+
+/// Accounts for the SetLstConfigRateBounds instruction.
+#[derive(Accounts)]
+pub struct SetLstConfigRateBoundsAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA (for authority check and event signing)
+    #[account(owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// LstConfig PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub lst_config: AccountLoader<'info, LstConfig>,
+
+    /// Must match unified_sol_pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Unified SOL pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub unified_sol_program: &'info AccountInfo,
+}
+
+/// Set how far an LST's frozen rate may move per finalization, and how old
+/// it may get before deposits and withdrawals are refused.
+///
+/// Fails with `InvalidRateBounds` if either band exceeds
+/// `MAX_RATE_BAND_BPS_CEILING`, or the staleness limit is outside
+/// `UPDATE_SLOT_INTERVAL..=MAX_RATE_STALENESS_SLOTS_CEILING`.
+pub fn process_set_lst_config_rate_bounds(
+    ctx: Context<SetLstConfigRateBoundsAccounts>,
+    data: SetLstConfigRateBoundsData,
+) -> ProgramResult {
+    let SetLstConfigRateBoundsAccounts {
+        unified_sol_pool_config,
+        lst_config,
+        authority,
+        unified_sol_program,
+    } = ctx.accounts;
+
+    // Read authority from unified config (releases borrow after closure)
+    let (unified_authority, unified_bump) =
+        unified_sol_pool_config.map(|config| (config.authority, config.bump))?;
+
+    if unified_authority != *authority.key() {
+        log!("set_lst_config_rate_bounds: unauthorized");
+        return Err(UnifiedSolPoolError::Unauthorized.into());
+    }
+
+    let lst_mint = lst_config.try_map_mut(|config| {
+        if let Err(e) = config.set_rate_bounds(
+            data.max_rate_increase_bps_per_epoch,
+            data.max_rate_decrease_bps_per_epoch,
+            data.max_rate_staleness_slots,
+        ) {
+            log!("set_lst_config_rate_bounds: bounds exceed program ceilings");
+            return Err(e.into());
+        }
+        Ok(config.lst_mint)
+    })?;
+
+    let bump_bytes = [unified_bump];
+    let seeds = gen_unified_sol_pool_config_seeds(&bump_bytes);
+    emit_event(
+        unified_sol_pool_config.account_info(),
+        unified_sol_program,
+        PinocchioSigner::from(&seeds),
+        &LstRateBoundsUpdatedEvent {
+            lst_mint,
+            max_rate_staleness_slots: data.max_rate_staleness_slots,
+            slot: Clock::get()?.slot,
+            max_rate_increase_bps_per_epoch: data.max_rate_increase_bps_per_epoch,
+            max_rate_decrease_bps_per_epoch: data.max_rate_decrease_bps_per_epoch,
+            _padding: [0u8; 4],
+        },
+    )?;
+
+    log!("set_lst_config_rate_bounds: updated successfully");
+    Ok(())
+}
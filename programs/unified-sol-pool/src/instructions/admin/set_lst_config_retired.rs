@@ -0,0 +1,93 @@
+//! Set retirement state for LST config.
+
+use crate::{
+    LstConfig, UnifiedSolPoolConfig, UnifiedSolPoolError, emit_event,
+    events::LstConfigRetiredEvent, gen_unified_sol_pool_config_seeds,
+};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+
+/// Instruction data for SetLstConfigRetired.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetLstConfigRetiredData {
+    /// New retirement state (1 = retired/withdraw-only, 0 = normal)
+    pub is_retired: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 7],
+}
+
+/// Accounts for the SetLstConfigRetired instruction.
+#[derive(Accounts)]
+pub struct SetLstConfigRetiredAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA (for authority check and event signing)
+    #[account(owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// LstConfig PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub lst_config: AccountLoader<'info, LstConfig>,
+
+    /// Must match unified_sol_pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Unified SOL pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub unified_sol_program: &'info AccountInfo,
+}
+
+/// Retire (or un-retire) an LST, e.g. a depegged or deprecated token.
+///
+/// While retired, deposits into the LST are rejected but withdrawals still
+/// work, and finalization no longer requires it to be harvested. Once its
+/// vault has drained it can be removed with `close_lst_config`.
+pub fn process_set_lst_config_retired(
+    ctx: Context<SetLstConfigRetiredAccounts>,
+    data: SetLstConfigRetiredData,
+) -> ProgramResult {
+    let SetLstConfigRetiredAccounts {
+        unified_sol_pool_config,
+        lst_config,
+        authority,
+        unified_sol_program,
+    } = ctx.accounts;
+
+    // Read authority from unified config (releases borrow after closure)
+    let (unified_authority, unified_bump) =
+        unified_sol_pool_config.map(|config| (config.authority, config.bump))?;
+
+    if unified_authority != *authority.key() {
+        log!("set_lst_config_retired: unauthorized");
+        return Err(UnifiedSolPoolError::Unauthorized.into());
+    }
+
+    let is_retired = (data.is_retired != 0) as u8;
+    let lst_mint = lst_config.try_map_mut(|config| {
+        config.is_retired = is_retired;
+        Ok(config.lst_mint)
+    })?;
+
+    let bump_bytes = [unified_bump];
+    let seeds = gen_unified_sol_pool_config_seeds(&bump_bytes);
+    emit_event(
+        unified_sol_pool_config.account_info(),
+        unified_sol_program,
+        PinocchioSigner::from(&seeds),
+        &LstConfigRetiredEvent {
+            lst_mint,
+            slot: Clock::get()?.slot,
+            is_retired,
+            _padding: [0u8; 7],
+        },
+    )?;
+
+    log!("set_lst_config_retired: updated successfully");
+    Ok(())
+}
@@ -0,0 +1,90 @@
+//! Set the virtual SOL cap for an LST config.
+
+use crate::{
+    LstConfig, UnifiedSolPoolConfig, UnifiedSolPoolError, emit_event,
+    events::LstVirtualSolCapUpdatedEvent, gen_unified_sol_pool_config_seeds,
+};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+
+/// Instruction data for SetLstConfigVirtualSolCap.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetLstConfigVirtualSolCapData {
+    /// Maximum virtual SOL attributed to this LST (0 = uncapped)
+    pub max_virtual_sol: u64,
+}
+
+/// Accounts for the SetLstConfigVirtualSolCap instruction.
+#[derive(Accounts)]
+pub struct SetLstConfigVirtualSolCapAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA (for authority check and event signing)
+    #[account(owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// LstConfig PDA to update
+    #[account(mut, owner = crate::ID)]
+    pub lst_config: AccountLoader<'info, LstConfig>,
+
+    /// Must match unified_sol_pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Unified SOL pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub unified_sol_program: &'info AccountInfo,
+}
+
+/// Cap the virtual SOL an LST may hold.
+///
+/// Deposits that would push the LST past the cap fail. A cap below the
+/// current total is allowed; the excess is parked in `virtual_sol_over_cap`.
+pub fn process_set_lst_config_virtual_sol_cap(
+    ctx: Context<SetLstConfigVirtualSolCapAccounts>,
+    data: SetLstConfigVirtualSolCapData,
+) -> ProgramResult {
+    let SetLstConfigVirtualSolCapAccounts {
+        unified_sol_pool_config,
+        lst_config,
+        authority,
+        unified_sol_program,
+    } = ctx.accounts;
+
+    // Read authority from unified config (releases borrow after closure)
+    let (unified_authority, unified_bump) =
+        unified_sol_pool_config.map(|config| (config.authority, config.bump))?;
+
+    if unified_authority != *authority.key() {
+        log!("set_lst_config_virtual_sol_cap: unauthorized");
+        return Err(UnifiedSolPoolError::Unauthorized.into());
+    }
+
+    let (lst_mint, virtual_sol_over_cap) = lst_config.map_mut(|config| {
+        config.max_virtual_sol = data.max_virtual_sol;
+        config.park_virtual_sol_overflow();
+        (config.lst_mint, config.virtual_sol_over_cap)
+    })?;
+
+    let bump_bytes = [unified_bump];
+    let seeds = gen_unified_sol_pool_config_seeds(&bump_bytes);
+    emit_event(
+        unified_sol_pool_config.account_info(),
+        unified_sol_program,
+        PinocchioSigner::from(&seeds),
+        &LstVirtualSolCapUpdatedEvent {
+            lst_mint,
+            max_virtual_sol: data.max_virtual_sol,
+            virtual_sol_over_cap,
+            slot: Clock::get()?.slot,
+        },
+    )?;
+
+    log!("set_lst_config_virtual_sol_cap: updated successfully");
+    Ok(())
+}
@@ -0,0 +1,83 @@
+//! Set the timelock on manual exchange rate overrides.
+
+use crate::{
+    UnifiedSolPoolConfig, UnifiedSolPoolError, emit_event, events::ManualRateDelayUpdatedEvent,
+    gen_unified_sol_pool_config_seeds,
+};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+
+/// Instruction data for SetManualRateDelay.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetManualRateDelayData {
+    /// Slots between a manual rate proposal and its earliest application
+    pub delay_slots: u64,
+}
+
+/// Accounts for the SetManualRateDelay instruction.
+#[derive(Accounts)]
+pub struct SetManualRateDelayAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA to update (also signs the event)
+    #[account(mut, owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// Must match unified_sol_pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Unified SOL pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub unified_sol_program: &'info AccountInfo,
+}
+
+/// Set how long manual rate proposals wait before they may be applied.
+///
+/// Applies to proposals made from now on; pending proposals keep their unlock
+/// slot. Fails with `InvalidManualRateDelay` outside
+/// `MIN_MANUAL_RATE_DELAY_SLOTS..=MAX_MANUAL_RATE_DELAY_SLOTS`.
+pub fn process_set_manual_rate_delay(
+    ctx: Context<SetManualRateDelayAccounts>,
+    data: SetManualRateDelayData,
+) -> ProgramResult {
+    let SetManualRateDelayAccounts {
+        unified_sol_pool_config,
+        authority,
+        unified_sol_program,
+    } = ctx.accounts;
+
+    let unified_bump = unified_sol_pool_config.try_map_mut(|config| {
+        // Verify authority
+        if config.authority != *authority.key() {
+            log!("set_manual_rate_delay: unauthorized");
+            return Err(UnifiedSolPoolError::Unauthorized.into());
+        }
+
+        if let Err(e) = config.set_manual_rate_delay(data.delay_slots) {
+            log!("set_manual_rate_delay: delay out of bounds");
+            return Err(e.into());
+        }
+        Ok(config.bump)
+    })?;
+
+    let bump_bytes = [unified_bump];
+    let seeds = gen_unified_sol_pool_config_seeds(&bump_bytes);
+    emit_event(
+        unified_sol_pool_config.account_info(),
+        unified_sol_program,
+        PinocchioSigner::from(&seeds),
+        &ManualRateDelayUpdatedEvent {
+            manual_rate_delay_slots: data.delay_slots,
+            slot: Clock::get()?.slot,
+        },
+    )?;
+
+    log!("set_manual_rate_delay: updated successfully");
+    Ok(())
+}
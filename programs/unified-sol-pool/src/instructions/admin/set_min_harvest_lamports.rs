@@ -0,0 +1,80 @@
+//! Set the minimum appreciation a harvest must book.
+
+use crate::{
+    UnifiedSolPoolConfig, UnifiedSolPoolError, emit_event, events::MinHarvestLamportsUpdatedEvent,
+    gen_unified_sol_pool_config_seeds,
+};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+
+/// Instruction data for SetMinHarvestLamports.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetMinHarvestLamportsData {
+    /// Minimum appreciation (virtual SOL lamports) a harvest books (0 = no minimum)
+    pub min_harvest_lamports: u64,
+}
+
+/// Accounts for the SetMinHarvestLamports instruction.
+#[derive(Accounts)]
+pub struct SetMinHarvestLamportsAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA to update (also signs the event)
+    #[account(mut, owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// Must match unified_sol_pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Unified SOL pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub unified_sol_program: &'info AccountInfo,
+}
+
+/// Set the dust threshold for appreciation harvests.
+///
+/// Applies from the next harvest; appreciation already carried stays carried
+/// until a harvest brings the total to the new threshold.
+pub fn process_set_min_harvest_lamports(
+    ctx: Context<SetMinHarvestLamportsAccounts>,
+    data: SetMinHarvestLamportsData,
+) -> ProgramResult {
+    let SetMinHarvestLamportsAccounts {
+        unified_sol_pool_config,
+        authority,
+        unified_sol_program,
+    } = ctx.accounts;
+
+    let (unified_bump, carried_appreciation) = unified_sol_pool_config.try_map_mut(|config| {
+        // Verify authority
+        if config.authority != *authority.key() {
+            log!("set_min_harvest_lamports: unauthorized");
+            return Err(UnifiedSolPoolError::Unauthorized.into());
+        }
+
+        config.min_harvest_lamports = data.min_harvest_lamports;
+        Ok((config.bump, config.carried_appreciation))
+    })?;
+
+    let bump_bytes = [unified_bump];
+    let seeds = gen_unified_sol_pool_config_seeds(&bump_bytes);
+    emit_event(
+        unified_sol_pool_config.account_info(),
+        unified_sol_program,
+        PinocchioSigner::from(&seeds),
+        &MinHarvestLamportsUpdatedEvent {
+            min_harvest_lamports: data.min_harvest_lamports,
+            carried_appreciation,
+            slot: Clock::get()?.slot,
+        },
+    )?;
+
+    log!("set_min_harvest_lamports: updated successfully");
+    Ok(())
+}
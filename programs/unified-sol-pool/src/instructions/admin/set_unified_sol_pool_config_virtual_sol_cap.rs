@@ -0,0 +1,83 @@
+//! Set the pool-wide virtual SOL cap for unified SOL pool config.
+
+use crate::{
+    UnifiedSolPoolConfig, UnifiedSolPoolError, emit_event,
+    events::UnifiedSolVirtualSolCapUpdatedEvent, gen_unified_sol_pool_config_seeds,
+};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+
+/// Instruction data for SetUnifiedSolPoolConfigVirtualSolCap.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetUnifiedSolPoolConfigVirtualSolCapData {
+    /// Maximum virtual SOL across all LST vaults (0 = uncapped)
+    pub max_total_virtual_sol: u64,
+}
+
+/// Accounts for the SetUnifiedSolPoolConfigVirtualSolCap instruction.
+#[derive(Accounts)]
+pub struct SetUnifiedSolPoolConfigVirtualSolCapAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA to update (also signs the event)
+    #[account(mut, owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// Must match unified_sol_pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Unified SOL pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub unified_sol_program: &'info AccountInfo,
+}
+
+/// Cap the total virtual SOL the pool may hold across all LSTs.
+///
+/// Deposits that would push the pool past the cap fail. A cap below the
+/// current total is allowed; the excess is parked in
+/// `total_virtual_sol_over_cap`.
+pub fn process_set_unified_sol_pool_config_virtual_sol_cap(
+    ctx: Context<SetUnifiedSolPoolConfigVirtualSolCapAccounts>,
+    data: SetUnifiedSolPoolConfigVirtualSolCapData,
+) -> ProgramResult {
+    let SetUnifiedSolPoolConfigVirtualSolCapAccounts {
+        unified_sol_pool_config,
+        authority,
+        unified_sol_program,
+    } = ctx.accounts;
+
+    let (unified_bump, total_virtual_sol_over_cap) =
+        unified_sol_pool_config.try_map_mut(|config| {
+            // Verify authority
+            if config.authority != *authority.key() {
+                log!("set_unified_sol_pool_config_virtual_sol_cap: unauthorized");
+                return Err(UnifiedSolPoolError::Unauthorized.into());
+            }
+
+            config.max_total_virtual_sol = data.max_total_virtual_sol;
+            config.park_virtual_sol_overflow();
+            Ok((config.bump, config.total_virtual_sol_over_cap))
+        })?;
+
+    let bump_bytes = [unified_bump];
+    let seeds = gen_unified_sol_pool_config_seeds(&bump_bytes);
+    emit_event(
+        unified_sol_pool_config.account_info(),
+        unified_sol_program,
+        PinocchioSigner::from(&seeds),
+        &UnifiedSolVirtualSolCapUpdatedEvent {
+            max_total_virtual_sol: data.max_total_virtual_sol,
+            total_virtual_sol_over_cap,
+            slot: Clock::get()?.slot,
+        },
+    )?;
+
+    log!("set_unified_sol_pool_config_virtual_sol_cap: updated successfully");
+    Ok(())
+}
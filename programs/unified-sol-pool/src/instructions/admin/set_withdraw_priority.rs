@@ -0,0 +1,88 @@
+//! Set the LST priority list for automatically routed withdrawals.
+
+use crate::{
+    UnifiedSolPoolConfig, UnifiedSolPoolError, emit_event, events::WithdrawPriorityUpdatedEvent,
+    gen_unified_sol_pool_config_seeds,
+};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+use zorb_pool_interface::MAX_WITHDRAW_ROUTE_VAULTS;
+
+/// Instruction data for SetWithdrawPriority.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct SetWithdrawPriorityData {
+    /// LST mints in routing order (zero = empty slot)
+    pub withdraw_priority: [[u8; 32]; MAX_WITHDRAW_ROUTE_VAULTS],
+}
+
+/// Accounts for the SetWithdrawPriority instruction.
+#[derive(Accounts)]
+pub struct SetWithdrawPriorityAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA to update (also signs the event)
+    #[account(mut, owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// Must match unified_sol_pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Unified SOL pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub unified_sol_program: &'info AccountInfo,
+}
+
+/// Replace the order in which routed withdrawals fall back to other vaults.
+///
+/// Mints are not checked against registered LSTs here; each routed vault is
+/// validated when a withdrawal draws from it. Fails with
+/// `InvalidWithdrawPriority` if a mint is listed twice.
+pub fn process_set_withdraw_priority(
+    ctx: Context<SetWithdrawPriorityAccounts>,
+    data: SetWithdrawPriorityData,
+) -> ProgramResult {
+    let SetWithdrawPriorityAccounts {
+        unified_sol_pool_config,
+        authority,
+        unified_sol_program,
+    } = ctx.accounts;
+
+    let unified_bump = unified_sol_pool_config.try_map_mut(|config| {
+        // Verify authority
+        if config.authority != *authority.key() {
+            log!("set_withdraw_priority: unauthorized");
+            return Err(UnifiedSolPoolError::Unauthorized.into());
+        }
+
+        if let Err(e) = config.set_withdraw_priority(&data.withdraw_priority) {
+            log!("set_withdraw_priority: duplicate LST mint");
+            return Err(e.into());
+        }
+        Ok(config.bump)
+    })?;
+
+    let [priority_0, priority_1, priority_2, priority_3] = data.withdraw_priority;
+    let bump_bytes = [unified_bump];
+    let seeds = gen_unified_sol_pool_config_seeds(&bump_bytes);
+    emit_event(
+        unified_sol_pool_config.account_info(),
+        unified_sol_program,
+        PinocchioSigner::from(&seeds),
+        &WithdrawPriorityUpdatedEvent {
+            priority_0,
+            priority_1,
+            priority_2,
+            priority_3,
+            slot: Clock::get()?.slot,
+        },
+    )?;
+
+    log!("set_withdraw_priority: updated successfully");
+    Ok(())
+}
@@ -0,0 +1,226 @@
+//! Withdraw accrued protocol commission as LST tokens.
+//!
+//! Commission taken at harvest stays in the LST vaults as virtual SOL owed to
+//! the protocol. This pays it out of one vault, converted at the LST's frozen
+//! `harvested_exchange_rate`, to a token account owned by the commission
+//! treasury.
+
+use crate::{
+    LstConfig, PoolType, UnifiedSolPoolConfig, UnifiedSolPoolError, emit_event,
+    events::CommissionWithdrawnEvent, gen_lst_config_seeds, gen_unified_sol_pool_config_seeds,
+    read_token_account_balance,
+};
+use bytemuck::{Pod, Zeroable};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+use pinocchio_token::{instructions::Transfer, state::TokenAccount};
+
+/// Instruction data for WithdrawCommission.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, Zeroable, InstructionArgs, IdlType)]
+pub struct WithdrawCommissionData {
+    /// Commission to withdraw (in virtual SOL / lamports)
+    pub amount: u64,
+}
+
+/// Accounts for the WithdrawCommission instruction.
+#[derive(Accounts)]
+pub struct WithdrawCommissionAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA (authority check, commission and totals; signs the event)
+    #[account(mut, owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// LstConfig PDA of the LST to pay out in
+    #[account(mut, owner = crate::ID)]
+    pub lst_config: AccountLoader<'info, LstConfig>,
+
+    /// LST vault token account to pay from
+    /// Must match lst_config.lst_vault
+    #[account(mut)]
+    pub lst_vault: &'info AccountInfo,
+
+    /// Destination token account, owned by the commission treasury
+    #[account(mut)]
+    pub destination: &'info AccountInfo,
+
+    /// Must match unified_sol_pool_config.authority
+    pub authority: Signer<'info>,
+
+    /// Token program that owns the vault (required for Transfer CPI)
+    pub token_program: &'info AccountInfo,
+
+    /// Unified SOL pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub unified_sol_program: &'info AccountInfo,
+}
+
+/// Pay `amount` of accrued commission out of one LST vault.
+///
+/// The LST must be active with a fresh frozen rate, and WSOL payouts may not
+/// dip into the required buffer. Fails with `InvalidCommissionDestination`
+/// while no treasury is set or if the destination is not owned by it, and
+/// with `InsufficientCommission` if `amount` exceeds what has accrued.
+///
+/// The paid-out value leaves `total_virtual_sol` but not the depositor
+/// balance, which never included it.
+pub fn process_withdraw_commission(
+    ctx: Context<WithdrawCommissionAccounts>,
+    data: WithdrawCommissionData,
+) -> ProgramResult {
+    let WithdrawCommissionAccounts {
+        unified_sol_pool_config,
+        lst_config,
+        lst_vault,
+        destination,
+        authority,
+        token_program,
+        unified_sol_program,
+    } = ctx.accounts;
+
+    let (unified_authority, unified_bump, treasury, accrued) =
+        unified_sol_pool_config.map(|config| {
+            (
+                config.authority,
+                config.bump,
+                config.commission_treasury,
+                config.accrued_commission,
+            )
+        })?;
+
+    if unified_authority != *authority.key() {
+        log!("withdraw_commission: unauthorized");
+        return Err(UnifiedSolPoolError::Unauthorized.into());
+    }
+
+    if treasury == [0u8; 32] {
+        log!("withdraw_commission: no commission treasury set");
+        return Err(UnifiedSolPoolError::InvalidCommissionDestination.into());
+    }
+
+    if data.amount == 0 {
+        log!("withdraw_commission: nothing to withdraw");
+        return Err(UnifiedSolPoolError::InvalidInstructionData.into());
+    }
+    if data.amount > accrued {
+        log!("withdraw_commission: amount exceeds accrued commission");
+        return Err(UnifiedSolPoolError::InsufficientCommission.into());
+    }
+
+    let current_slot = Clock::get()?.slot;
+    let (exchange_rate, lst_amount, vault_token_balance, bump, lst_mint, pool_type) = lst_config
+        .try_map(|config| {
+            if !config.is_active() {
+                log!("withdraw_commission: LST not active");
+                return Err(UnifiedSolPoolError::LstNotActive.into());
+            }
+            if *lst_vault.key() != config.lst_vault {
+                log!("withdraw_commission: invalid lst_vault");
+                return Err(UnifiedSolPoolError::InvalidVault.into());
+            }
+            config.check_rate_fresh(current_slot)?;
+            Ok((
+                config.harvested_exchange_rate,
+                config.calculate_lst_tokens(data.amount),
+                config.vault_token_balance,
+                config.bump,
+                config.lst_mint,
+                config.pool_type,
+            ))
+        })?;
+
+    if !lst_vault.is_owned_by(token_program.key()) {
+        log!("withdraw_commission: token program does not own the vault");
+        return Err(UnifiedSolPoolError::InvalidTokenProgram.into());
+    }
+
+    let destination_owner = *TokenAccount::from_account_info(destination)
+        .map_err(|_| UnifiedSolPoolError::InvalidCommissionDestination)?
+        .owner();
+    if destination_owner != treasury {
+        log!("withdraw_commission: destination not owned by the treasury");
+        return Err(UnifiedSolPoolError::InvalidCommissionDestination.into());
+    }
+
+    if lst_amount == 0 {
+        log!("withdraw_commission: amount rounds to zero tokens");
+        return Err(UnifiedSolPoolError::InvalidInstructionData.into());
+    }
+    if lst_amount > vault_token_balance {
+        log!("withdraw_commission: insufficient vault balance");
+        return Err(UnifiedSolPoolError::InsufficientBalance.into());
+    }
+
+    // WSOL buffer gating, as for user withdrawals
+    if pool_type == PoolType::Wsol as u8 {
+        let wsol_vault_balance = read_token_account_balance(lst_vault)?;
+        let required_buffer = unified_sol_pool_config.map(|c| c.calculate_required_buffer())??;
+
+        let remaining = wsol_vault_balance
+            .checked_sub(lst_amount)
+            .ok_or(UnifiedSolPoolError::InsufficientBalance)?;
+
+        if remaining < required_buffer {
+            log!("withdraw_commission: WSOL buffer violation");
+            return Err(UnifiedSolPoolError::InsufficientBuffer.into());
+        }
+    }
+
+    // Transfer vault -> destination, signed by the lst_config PDA
+    let bump_bytes = [bump];
+    let seeds = gen_lst_config_seeds(&lst_mint, &bump_bytes);
+    Transfer {
+        from: lst_vault,
+        to: destination,
+        authority: lst_config.account_info(),
+        amount: lst_amount,
+    }
+    .invoke_signed(&[PinocchioSigner::from(&seeds)])?;
+
+    lst_config.try_inspect_mut(|config| {
+        config.vault_token_balance = config
+            .vault_token_balance
+            .checked_sub(lst_amount)
+            .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?;
+        config.total_virtual_sol = config
+            .total_virtual_sol
+            .checked_sub(data.amount as u128)
+            .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?;
+        config.park_virtual_sol_overflow();
+        Ok(())
+    })?;
+
+    unified_sol_pool_config.try_inspect_mut(|config| {
+        config.take_commission(data.amount)?;
+        config.total_virtual_sol = config
+            .total_virtual_sol
+            .checked_sub(data.amount as u128)
+            .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?;
+        config.park_virtual_sol_overflow();
+        Ok(())
+    })?;
+
+    let unified_bump_bytes = [unified_bump];
+    let unified_seeds = gen_unified_sol_pool_config_seeds(&unified_bump_bytes);
+    emit_event(
+        unified_sol_pool_config.account_info(),
+        unified_sol_program,
+        PinocchioSigner::from(&unified_seeds),
+        &CommissionWithdrawnEvent {
+            lst_mint,
+            destination: *destination.key(),
+            amount: data.amount,
+            lst_amount,
+            exchange_rate,
+            slot: current_slot,
+        },
+    )?;
+
+    log!("withdraw_commission: withdrawn successfully");
+    Ok(())
+}
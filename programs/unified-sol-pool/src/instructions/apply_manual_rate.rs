@@ -0,0 +1,204 @@
+//! Apply an unlocked manual exchange rate in place of a failed rate source.
+
+use crate::{
+    LstConfig, LstManualRate, PoolType, UnifiedSolPoolConfig, UnifiedSolPoolError, emit_event,
+    events::ManualRateAppliedEvent, gen_unified_sol_pool_config_seeds,
+    instructions::harvest_lst_appreciation::read_stake_pool_rate, read_token_account_balance,
+    utils::record_pending_rewards,
+};
+use panchor::prelude::*;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Signer as PinocchioSigner,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_log::log;
+
+/// Accounts for the ApplyManualRate instruction.
+#[derive(Accounts)]
+pub struct ApplyManualRateAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA
+    #[account(mut, owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// LstConfig PDA holding the proposal
+    #[account(mut, owner = crate::ID)]
+    pub lst_config: AccountLoader<'info, LstConfig>,
+
+    /// The LST's configured rate source (stake pool or Marinade state)
+    pub rate_data_account: &'info AccountInfo,
+
+    /// LST vault (for the balance invariant and appreciation)
+    pub lst_vault: &'info AccountInfo,
+
+    /// Keeper running the override (must be allowlisted while the keeper
+    /// allowlist is enforced)
+    pub caller: Signer<'info>,
+
+    /// Unified SOL pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub unified_sol_program: &'info AccountInfo,
+}
+
+/// Put a pending manual exchange rate into effect.
+///
+/// Permissionless (keepers only while the keeper allowlist is enforced), but only once the proposal's unlock slot has passed and
+/// only while the LST's rate source cannot be read (a harvest would fail):
+/// a healthy source must be harvested instead (`RateSourceHealthy`).
+///
+/// Acts as the LST's harvest for the current reward epoch: the rate must stay
+/// within the per-epoch band (or match an approved jump), appreciation is
+/// booked as for a harvest, and the proposal is consumed.
+pub fn process_apply_manual_rate(ctx: Context<ApplyManualRateAccounts>) -> ProgramResult {
+    let ApplyManualRateAccounts {
+        unified_sol_pool_config,
+        lst_config,
+        rate_data_account,
+        lst_vault,
+        caller,
+        unified_sol_program,
+    } = ctx.accounts;
+
+    unified_sol_pool_config.try_map(|config| {
+        config.check_keeper(caller.key()).map_err(|e| {
+            log!("apply_manual_rate: caller is not an allowlisted keeper");
+            e.into()
+        })
+    })?;
+
+    let (current_epoch, unified_bump) =
+        unified_sol_pool_config.map(|config| (config.reward_epoch, config.bump))?;
+
+    let (lst_mint, vault, stake_pool, pool_type, active) = lst_config.map(|config| {
+        (
+            config.lst_mint,
+            config.lst_vault,
+            config.stake_pool,
+            config.pool_type,
+            config.is_active(),
+        )
+    })?;
+
+    if !active {
+        log!("apply_manual_rate: LST is not active");
+        return Err(UnifiedSolPoolError::LstNotActive.into());
+    }
+
+    let clock = Clock::get()?;
+    let current_slot = clock.slot;
+    let solana_epoch = clock.epoch;
+
+    let can_harvest = unified_sol_pool_config.map(|config| config.can_harvest(solana_epoch))?;
+    if !can_harvest {
+        log!("apply_manual_rate: Solana epoch not advanced since last finalization");
+        return Err(UnifiedSolPoolError::RewardsNotReady.into());
+    }
+
+    // Only stake pool LSTs have a rate source that can fail
+    let pool_type = match PoolType::from_u8(pool_type) {
+        Some(pool_type @ (PoolType::SplStakePool | PoolType::Marinade)) => pool_type,
+        _ => {
+            log!("apply_manual_rate: pool type has no manual rate");
+            return Err(UnifiedSolPoolError::InvalidPoolType.into());
+        }
+    };
+
+    if *rate_data_account.key() != stake_pool {
+        log!("apply_manual_rate: expected the configured stake_pool");
+        return Err(UnifiedSolPoolError::InvalidStakePool.into());
+    }
+
+    let new_rate = {
+        let account_data = lst_config.account_info().try_borrow_data()?;
+        let proposal = LstManualRate::from_account_data(&account_data).ok_or_else(|| {
+            log!("apply_manual_rate: LstConfig needs migration");
+            UnifiedSolPoolError::LstConfigNeedsMigration
+        })?;
+        proposal.unlocked_rate(current_slot)?
+    };
+
+    // The override is only for sources a harvest cannot use
+    let source_rate = read_stake_pool_rate(
+        unified_sol_pool_config,
+        lst_config,
+        rate_data_account,
+        pool_type,
+        &lst_mint,
+        solana_epoch,
+    );
+    if matches!(source_rate, Ok(rate) if rate >= LstConfig::RATE_PRECISION) {
+        log!("apply_manual_rate: rate source is healthy");
+        return Err(UnifiedSolPoolError::RateSourceHealthy.into());
+    }
+
+    if *lst_vault.key() != vault {
+        log!("apply_manual_rate: invalid lst_vault account");
+        return Err(UnifiedSolPoolError::InvalidVault.into());
+    }
+
+    // INVARIANT: Counter must match actual vault balance (as for a harvest)
+    let vault_balance = read_token_account_balance(lst_vault)?;
+    let counter_balance = lst_config.map(|c| c.vault_token_balance)?;
+    if counter_balance != vault_balance {
+        log!(
+            "apply_manual_rate: vault balance mismatch - counter: {}, actual: {}",
+            counter_balance,
+            vault_balance
+        );
+        return Err(UnifiedSolPoolError::VaultBalanceMismatch.into());
+    }
+
+    let (old_rate, appreciation_value) = lst_config.try_map_mut(|lst| {
+        // The finalization that freezes this rate enforces the same band
+        if let Err(e) = lst.validate_rate_bounds(new_rate) {
+            log!("apply_manual_rate: exchange rate outside per-epoch band");
+            return Err(e.into());
+        }
+        let old_rate = lst.exchange_rate;
+        let appreciation = lst.update_exchange_rate(vault_balance, new_rate, current_slot)?;
+        lst.last_harvest_epoch = current_epoch;
+        Ok((old_rate, appreciation))
+    })?;
+
+    // Booked (or carried while dust) exactly as a harvest would
+    let booked = unified_sol_pool_config
+        .try_map_mut(|unified| Ok(unified.book_appreciation(appreciation_value)?))?;
+    match booked {
+        Some((booked_value, commission_value)) => {
+            record_pending_rewards(lst_config.account_info(), |pending| {
+                pending.add_appreciation(booked_value - commission_value)
+            })?;
+        }
+        None => {
+            log!("apply_manual_rate: dust appreciation carried forward");
+        }
+    }
+
+    {
+        let mut account_data = lst_config.account_info().try_borrow_mut_data()?;
+        if let Some(proposal) = LstManualRate::from_account_data_mut(&mut account_data) {
+            proposal.clear();
+        }
+    }
+
+    let bump_bytes = [unified_bump];
+    let seeds = gen_unified_sol_pool_config_seeds(&bump_bytes);
+    emit_event(
+        unified_sol_pool_config.account_info(),
+        unified_sol_program,
+        PinocchioSigner::from(&seeds),
+        &ManualRateAppliedEvent {
+            lst_mint,
+            old_rate,
+            new_rate,
+            appreciation_amount: appreciation_value,
+            epoch: current_epoch,
+            slot: current_slot,
+            caller: *caller.key(),
+        },
+    )?;
+
+    log!("apply_manual_rate: applied");
+    Ok(())
+}
@@ -6,16 +6,16 @@
 use crate::{
     LstConfig, UNIFIED_SOL_POOL_CONFIG_ADDRESS, UnifiedSolPoolConfig, UnifiedSolPoolError,
     emit_event, events::UnifiedSolDepositEvent, find_lst_config_pda,
-    gen_unified_sol_pool_config_seeds,
+    gen_unified_sol_pool_config_seeds, state::protocol_fee, utils::record_pending_rewards,
 };
 use panchor::prelude::*;
 use pinocchio::{
     ProgramResult, account_info::AccountInfo, instruction::Signer as PinocchioSigner,
-    program::set_return_data, sysvars::Sysvar,
+    program::set_return_data, program_error::ProgramError, sysvars::Sysvar,
 };
 use pinocchio_log::log;
 use pinocchio_token::instructions::Transfer;
-use zorb_pool_interface::{BASIS_POINTS, DepositParams, PoolReturnData, tokens_to_virtual_sol};
+use zorb_pool_interface::{DepositParams, UnifiedSolOpResult, tokens_to_virtual_sol};
 
 /// Accounts for the Deposit instruction.
 ///
@@ -68,7 +68,7 @@ pub struct DepositAccounts<'info> {
 /// 5. Validates: virtual_sol - fee == expected_output
 /// 6. Executes transfer: depositor_token -> vault (amount)
 /// 7. Updates pool accounting
-/// 8. Returns { fee } via set_return_data
+/// 8. Returns `UnifiedSolOpResult` via set_return_data
 pub fn process_deposit(ctx: Context<DepositAccounts>, instruction_data: &[u8]) -> ProgramResult {
     let DepositAccounts {
         unified_config,
@@ -95,9 +95,13 @@ pub fn process_deposit(ctx: Context<DepositAccounts>, instruction_data: &[u8]) -
         return Err(UnifiedSolPoolError::InvalidLstConfig.into());
     }
 
-    // Parse instruction data (panchor strips discriminator, so we get raw params)
-    let params = DepositParams::from_bytes(instruction_data)
-        .ok_or(UnifiedSolPoolError::InvalidInstructionData)?;
+    let params = parse_deposit_params(instruction_data)?;
+
+    // This pool keeps no fee-exempt list, so the hub must always price in fees
+    if params.expects_fee_exempt() {
+        log!("deposit: fee exemption not supported");
+        return Err(UnifiedSolPoolError::InvalidInstructionData.into());
+    }
 
     // Read values from unified config (releases borrow after closure)
     let (deposit_fee_rate, reward_epoch, unified_bump) = unified_config.try_map(|config| {
@@ -133,28 +137,34 @@ pub fn process_deposit(ctx: Context<DepositAccounts>, instruction_data: &[u8]) -
     // See: state.rs `finalize_rewards()` for the conservation proof.
     // See: state.rs `MAX_RATE_CHANGE_BPS` for the arbitrage bound (0.5% max).
     // =========================================================================
-    let exchange_rate = lst_config.try_map(|config| {
-        // Check LST is active
-        if !config.is_active() {
-            return Err(UnifiedSolPoolError::LstNotActive.into());
+    let current_slot = pinocchio::sysvars::clock::Clock::get()?.slot;
+    let (exchange_rate, deposit_fee_rate) = lst_config.try_map(|config| {
+        // Check LST accepts deposits
+        if !config.deposits_enabled() {
+            return Err(UnifiedSolPoolError::LstDepositsDisabled.into());
+        }
+        // Retired LSTs are withdraw-only
+        if config.is_retired() {
+            return Err(UnifiedSolPoolError::LstRetired.into());
         }
         // Validate harvest epoch (LST must be harvested before deposits)
         if reward_epoch > 0 && config.last_harvest_epoch < reward_epoch.checked_sub(1).unwrap() {
             return Err(UnifiedSolPoolError::LstNotHarvested.into());
         }
-        Ok(config.harvested_exchange_rate)
+        // Refuse to price against a rate frozen too long ago
+        config.check_rate_fresh(current_slot)?;
+        Ok((
+            config.harvested_exchange_rate,
+            config.effective_deposit_fee_rate(deposit_fee_rate),
+        ))
     })?;
 
     // Convert token amount to virtual SOL: φ(e) = e × λ / ρ
     let virtual_sol = tokens_to_virtual_sol(params.amount, exchange_rate)
         .ok_or(UnifiedSolPoolError::ArithmeticOverflow)? as u64;
 
-    // Calculate protocol fee from deposit_fee_rate (basis points)
-    let fee = (virtual_sol as u128)
-        .checked_mul(deposit_fee_rate as u128)
-        .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?
-        .checked_div(BASIS_POINTS as u128)
-        .ok_or(UnifiedSolPoolError::ArithmeticOverflow)? as u64;
+    // Calculate protocol fee from the LST's effective deposit rate (basis points)
+    let fee = protocol_fee(virtual_sol, deposit_fee_rate)?;
 
     // Validate expected_output matches
     let principal = virtual_sol
@@ -165,6 +175,20 @@ pub fn process_deposit(ctx: Context<DepositAccounts>, instruction_data: &[u8]) -
         return Err(UnifiedSolPoolError::ExpectedOutputMismatch.into());
     }
 
+    // Enforce virtual SOL caps on the full credited value (fee included)
+    lst_config.try_map(|config| {
+        config.check_virtual_sol_cap(virtual_sol).map_err(|e| {
+            log!("deposit: LST virtual SOL cap exceeded");
+            e.into()
+        })
+    })?;
+    unified_config.try_map(|config| {
+        config.check_virtual_sol_cap(virtual_sol).map_err(|e| {
+            log!("deposit: pool virtual SOL cap exceeded");
+            e.into()
+        })
+    })?;
+
     // Execute transfer: depositor_token -> vault
     Transfer {
         from: depositor_token_acc,
@@ -195,6 +219,13 @@ pub fn process_deposit(ctx: Context<DepositAccounts>, instruction_data: &[u8]) -
         Ok(())
     })?;
 
+    // Attribute the fee to this LST for its LstFinalizedEvent
+    if fee > 0 {
+        record_pending_rewards(lst_config.account_info(), |pending| {
+            pending.add_deposit_fee(fee)
+        })?;
+    }
+
     // Update unified config state (using principal, not virtual_sol)
     unified_config.try_inspect_mut(|config| {
         config.pending_deposits = config
@@ -250,14 +281,29 @@ pub fn process_deposit(ctx: Context<DepositAccounts>, instruction_data: &[u8]) -
             sol_value: virtual_sol,
             fee,
             exchange_rate,
-            slot: pinocchio::sysvars::clock::Clock::get()?.slot,
+            slot: current_slot,
             _padding: 0,
         },
     )?;
 
-    // Return fee via set_return_data (AFTER emit_event to avoid CPI overwriting it)
-    let return_data = PoolReturnData { fee };
+    // Return the priced amounts via set_return_data (AFTER emit_event to avoid
+    // CPI overwriting it) so the hub can check them against its own
+    let return_data = UnifiedSolOpResult {
+        lst_amount: params.amount,
+        sol_value: virtual_sol,
+        fee,
+        exchange_rate,
+        accumulator_epoch: reward_epoch,
+    };
     set_return_data(bytemuck::bytes_of(&return_data));
 
     Ok(())
 }
+
+/// Parse the hub's deposit params from instruction data.
+///
+/// Panchor strips the discriminator; accepts the legacy or versioned layout.
+pub(crate) fn parse_deposit_params(instruction_data: &[u8]) -> Result<DepositParams, ProgramError> {
+    DepositParams::parse_any(instruction_data)
+        .ok_or(UnifiedSolPoolError::InvalidInstructionData.into())
+}
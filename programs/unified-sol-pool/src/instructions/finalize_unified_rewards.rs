@@ -1,9 +1,10 @@
 //! Finalize unified SOL rewards by updating the reward accumulator.
 
 use crate::{
-    LstConfig, UnifiedSolPoolConfig, UnifiedSolPoolError, emit_event,
-    events::UnifiedSolRewardsFinalizedEvent, find_lst_config_pda,
-    gen_unified_sol_pool_config_seeds,
+    LstConfig, LstPendingRewards, LstRateHistory, UnifiedSolPoolConfig, UnifiedSolPoolError,
+    emit_event,
+    events::{LstFinalizedEvent, UnifiedSolRewardsFinalizedEvent},
+    find_lst_config_pda, gen_unified_sol_pool_config_seeds,
 };
 use panchor::prelude::*;
 use pinocchio::{
@@ -23,6 +24,10 @@ pub struct FinalizeUnifiedRewardsAccounts<'info> {
     #[account(mut, owner = crate::ID)]
     pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
 
+    /// Keeper running the finalization (must be allowlisted while the keeper
+    /// allowlist is enforced)
+    pub caller: Signer<'info>,
+
     /// Unified SOL pool program account (required for self-CPI event emission)
     #[account(address = crate::ID)]
     pub unified_sol_program: &'info AccountInfo,
@@ -32,7 +37,8 @@ pub struct FinalizeUnifiedRewardsAccounts<'info> {
 /// Finalize unified SOL rewards by updating the reward accumulator.
 ///
 /// This permissionless instruction distributes pending rewards by updating
-/// the accumulator. Anyone can call it once the finalization interval has passed.
+/// the accumulator. Anyone can call it once the finalization interval has passed
+/// (only an allowlisted keeper while the keeper allowlist is enforced).
 ///
 /// Before finalizing, validates that ALL registered LST configs have been
 /// harvested in the current reward epoch.
@@ -44,9 +50,17 @@ pub fn process_finalize_unified_rewards(
 ) -> ProgramResult {
     let FinalizeUnifiedRewardsAccounts {
         unified_sol_pool_config,
+        caller,
         unified_sol_program,
     } = ctx.accounts;
 
+    unified_sol_pool_config.try_map(|config| {
+        config.check_keeper(caller.key()).map_err(|e| {
+            log!("finalize_unified_rewards: caller is not an allowlisted keeper");
+            e.into()
+        })
+    })?;
+
     let program_id = &crate::ID;
 
     // Read values from unified config (releases borrow after closure)
@@ -90,8 +104,8 @@ pub fn process_finalize_unified_rewards(
 
         // Use AccountLoader for validation
         let loader = AccountLoader::<LstConfig>::new(lst_config_account)?;
-        let (lst_mint, last_harvest_epoch, is_lst_active) = loader
-            .try_map(|lst_config| Ok((lst_config.lst_mint, lst_config.last_harvest_epoch, lst_config.is_active)))?;
+        let (lst_mint, last_harvest_epoch, tracks_rate) = loader
+            .try_map(|lst_config| Ok((lst_config.lst_mint, lst_config.last_harvest_epoch, lst_config.tracks_rate())))?;
 
         // Verify lst_config is the canonical PDA derived from its mint
         let (expected_pda, _) = find_lst_config_pda(&lst_mint);
@@ -134,27 +148,28 @@ pub fn process_finalize_unified_rewards(
         // - init_lst_config.rs: last_harvest_epoch starts at 0
         // - harvest_lst_appreciation.rs: sets last_harvest_epoch = current_epoch
         // =====================================================================
-        // AUDIT: INACTIVE LST HANDLING
-        // Inactive LSTs skip harvest check but must still be passed.
+        // AUDIT: INACTIVE / RETIRED LST HANDLING
+        // Inactive and retired LSTs skip harvest check but must still be passed
+        // (until a retired LST is closed, which decrements lst_count).
         // Their frozen rate (harvested_exchange_rate) remains unchanged.
-        // When reactivated, must harvest before next finalization.
-        if is_lst_active != 0 && last_harvest_epoch != current_epoch {
+        // When reactivated or un-retired, must harvest before next finalization.
+        if tracks_rate && last_harvest_epoch != current_epoch {
             log!("finalize_unified_rewards: active LST not harvested this epoch");
             return Err(UnifiedSolPoolError::LstNotHarvested.into());
         }
     }
 
-    // Get current slot
+    // Get current slot and Solana epoch
     let clock = Clock::get()?;
     let current_slot = clock.slot;
+    let solana_epoch = clock.epoch;
 
-    // Check if finalization interval has passed
-    let can_finalize = unified_sol_pool_config.map(|config| {
-        current_slot >= config.last_finalized_slot + UnifiedSolPoolConfig::UPDATE_SLOT_INTERVAL
-    })?;
+    // Check if finalization interval (or Solana epoch, per epoch_mode) has passed
+    let can_finalize =
+        unified_sol_pool_config.map(|config| config.can_finalize(current_slot, solana_epoch))?;
 
     if !can_finalize {
-        log!("finalize_unified_rewards: not enough slots elapsed");
+        log!("finalize_unified_rewards: epoch boundary not reached");
         return Err(UnifiedSolPoolError::RewardsNotReady.into());
     }
 
@@ -183,24 +198,39 @@ pub fn process_finalize_unified_rewards(
     // Finalize the rewards
     unified_sol_pool_config.try_inspect_mut(|config| {
         config
-            .finalize_rewards(current_slot)
+            .finalize_rewards(current_slot, solana_epoch)
             .map_err(|_| UnifiedSolPoolError::ArithmeticOverflow)?;
         Ok(())
     })?;
 
+    // Rates frozen now price the epoch finalization just opened. Pending rewards
+    // are left in place when there was no pool to distribute them to; per-LST
+    // counters are only reset alongside them so they keep summing to the totals.
+    let (reward_epoch, distributed) = unified_sol_pool_config.map(|config| {
+        (
+            config.reward_epoch,
+            config.pending_deposit_fees == 0
+                && config.pending_withdrawal_fees == 0
+                && config.pending_appreciation == 0,
+        )
+    })?;
+
+    let bump_bytes = [bump];
+    let seeds = gen_unified_sol_pool_config_seeds(&bump_bytes);
+
     // Calculate total_virtual_sol atomically from vault_token_balance × exchange_rate (INV-8)
     // This ensures value is computed at the moment rates are frozen, not from stale harvest-time data
     let mut total_pool_virtual_sol: u128 = 0;
     for lst_config_account in ctx.remaining_accounts {
         let loader = AccountLoader::<LstConfig>::new(lst_config_account)?;
 
-        // Read vault_token_balance, exchange_rate, harvested_exchange_rate, and is_active
-        let (vault_token_balance, exchange_rate, harvested_exchange_rate, is_lst_active) = loader
-            .map(|c| (c.vault_token_balance, c.exchange_rate, c.harvested_exchange_rate, c.is_active))?;
+        // Read vault_token_balance, exchange_rate, harvested_exchange_rate, and tracks_rate
+        let (vault_token_balance, exchange_rate, harvested_exchange_rate, tracks_rate) = loader
+            .map(|c| (c.vault_token_balance, c.exchange_rate, c.harvested_exchange_rate, c.tracks_rate()))?;
 
         // Active LSTs: use current exchange_rate (will be frozen)
-        // Inactive LSTs: use existing harvested_exchange_rate (already frozen)
-        let rate_for_value = if is_lst_active != 0 { exchange_rate } else { harvested_exchange_rate };
+        // Inactive/retired LSTs: use existing harvested_exchange_rate (already frozen)
+        let rate_for_value = if tracks_rate { exchange_rate } else { harvested_exchange_rate };
 
         // Calculate: lst_total = vault_token_balance × rate_for_value / RATE_PRECISION
         let lst_total = (vault_token_balance as u128)
@@ -213,18 +243,65 @@ pub fn process_finalize_unified_rewards(
             .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?;
 
         // Update LstConfig: set total_virtual_sol and freeze exchange rate (active LSTs only)
-        loader.inspect_mut(|lst_config| {
+        loader.try_inspect_mut(|lst_config| {
             lst_config.total_virtual_sol = lst_total;
-            // Only freeze rate for active LSTs; inactive LSTs keep their frozen rate
-            if lst_config.is_active != 0 {
-                lst_config.harvested_exchange_rate = lst_config.exchange_rate;
+            // Appreciation past the cap is parked rather than failing the finalize
+            lst_config.park_virtual_sol_overflow();
+            // Only freeze rate for active LSTs; inactive/retired LSTs keep their frozen rate.
+            // The move must stay within the LST's per-epoch band unless approved.
+            if tracks_rate && let Err(e) = lst_config.freeze_exchange_rate(current_slot) {
+                log!("finalize_unified_rewards: exchange rate outside per-epoch band");
+                return Err(e.into());
             }
+            Ok(())
         })?;
+
+        // Record the frozen rate; version 1 accounts have no history until migrated
+        if tracks_rate {
+            let mut data = lst_config_account.try_borrow_mut_data()?;
+            if let Some(history) = LstRateHistory::from_account_data_mut(&mut data) {
+                history.push(exchange_rate, reward_epoch, current_slot);
+            }
+        }
+
+        // Take this LST's share of the pending rewards (none before version 4)
+        let pending = {
+            let mut data = lst_config_account.try_borrow_mut_data()?;
+            match LstPendingRewards::from_account_data_mut(&mut data) {
+                Some(pending) => {
+                    let share = *pending;
+                    if distributed {
+                        *pending = LstPendingRewards::default();
+                    }
+                    share
+                }
+                None => LstPendingRewards::default(),
+            }
+        };
+
+        let (lst_mint, frozen_rate) = loader.map(|c| (c.lst_mint, c.harvested_exchange_rate))?;
+
+        emit_event(
+            unified_sol_pool_config.account_info(),
+            unified_sol_program,
+            PinocchioSigner::from(&seeds),
+            &LstFinalizedEvent {
+                lst_mint,
+                frozen_rate,
+                appreciation_contributed: pending.appreciation,
+                deposit_fees: pending.deposit_fees,
+                withdraw_fees: pending.withdrawal_fees,
+                attributed_virtual_sol: lst_total,
+                epoch: reward_epoch,
+                slot: current_slot,
+            },
+        )?;
     }
 
     // Update unified config's total_virtual_sol with the sum of all LST values
     unified_sol_pool_config.inspect_mut(|config| {
         config.total_virtual_sol = total_pool_virtual_sol;
+        config.park_virtual_sol_overflow();
     })?;
 
     log!("finalize_unified_rewards: rewards finalized, rates frozen");
@@ -238,8 +315,6 @@ pub fn process_finalize_unified_rewards(
         )
     })?;
 
-    let bump_bytes = [bump];
-    let seeds = gen_unified_sol_pool_config_seeds(&bump_bytes);
     let signer = PinocchioSigner::from(&seeds);
 
     emit_event(
@@ -254,8 +329,10 @@ pub fn process_finalize_unified_rewards(
             appreciation_rewards: pending_appreciation,
             epoch: new_epoch, // The new epoch after finalization
             slot: current_slot,
+            solana_epoch,
             lst_count,
-            _padding: [0u8; 7],
+            _padding: [0u8; 15],
+            caller: *caller.key(),
         },
     )?;
 
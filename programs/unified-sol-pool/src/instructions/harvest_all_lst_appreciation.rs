@@ -0,0 +1,135 @@
+//! Harvest appreciation for every registered LST in one transaction.
+
+use crate::{
+    LstConfig, UnifiedSolPoolConfig, UnifiedSolPoolError, find_lst_config_pda,
+    instructions::harvest_lst_appreciation::harvest_lst,
+};
+use panchor::prelude::*;
+use pinocchio::{ProgramResult, account_info::AccountInfo};
+use pinocchio_log::log;
+
+/// Maximum number of LSTs a single HarvestAllLstAppreciation can cover.
+///
+/// Each LST takes three accounts, so 8 LSTs plus the fixed accounts (26 keys)
+/// fit a legacy transaction without lookup tables, and a stake pool harvest
+/// with its two event CPIs stays well under 1/8 of the 1.4M CU ceiling. Pools
+/// with more LSTs must harvest them individually.
+pub const MAX_HARVEST_ALL_LSTS: usize = 8;
+
+/// Accounts per LST in remaining accounts: lst_config, rate_data_account, lst_vault.
+const ACCOUNTS_PER_LST: usize = 3;
+
+/// Accounts for the HarvestAllLstAppreciation instruction.
+#[derive(Accounts)]
+pub struct HarvestAllLstAppreciationAccounts<'info> {
+    /// UnifiedSolPoolConfig PDA
+    #[account(mut, owner = crate::ID)]
+    pub unified_sol_pool_config: AccountLoader<'info, UnifiedSolPoolConfig>,
+
+    /// Keeper running the harvest (must be allowlisted while the keeper
+    /// allowlist is enforced)
+    pub caller: Signer<'info>,
+
+    /// Unified SOL pool program account (required for self-CPI event emission)
+    #[account(address = crate::ID)]
+    pub unified_sol_program: &'info AccountInfo,
+    // Remaining accounts: for each registered LST, in any order,
+    // [lst_config (mut), rate_data_account, lst_vault]
+    // (for WSOL, rate_data_account is the lst_vault as well)
+}
+
+/// Harvest appreciation for all registered LSTs.
+///
+/// Permissionless unless the keeper allowlist is enforced. Every registered LstConfig must be passed (the count must
+/// equal `lst_count`, at most `MAX_HARVEST_ALL_LSTS`), each exactly once, so
+/// a crank cannot silently miss one before `finalize_unified_rewards`.
+///
+/// LSTs already harvested this epoch, and inactive or retired LSTs (which
+/// finalization does not require), are skipped. The rest go through the same
+/// per-LST logic and events as `harvest_lst_appreciation`.
+pub fn process_harvest_all_lst_appreciation(
+    ctx: Context<HarvestAllLstAppreciationAccounts>,
+) -> ProgramResult {
+    let HarvestAllLstAppreciationAccounts {
+        unified_sol_pool_config,
+        caller,
+        unified_sol_program,
+    } = ctx.accounts;
+
+    unified_sol_pool_config.try_map(|config| {
+        config.check_keeper(caller.key()).map_err(|e| {
+            log!("harvest_all_lst_appreciation: caller is not an allowlisted keeper");
+            e.into()
+        })
+    })?;
+
+    let (current_epoch, lst_count) =
+        unified_sol_pool_config.map(|config| (config.reward_epoch, config.lst_count as usize))?;
+
+    if lst_count > MAX_HARVEST_ALL_LSTS {
+        log!("harvest_all_lst_appreciation: too many LSTs, harvest individually");
+        return Err(UnifiedSolPoolError::InvalidInstructionData.into());
+    }
+
+    let remaining = ctx.remaining_accounts;
+    if remaining.len() != lst_count * ACCOUNTS_PER_LST {
+        log!("harvest_all_lst_appreciation: wrong number of LST accounts");
+        return Err(UnifiedSolPoolError::MissingLstConfigs.into());
+    }
+
+    let mut seen_mints = [[0u8; 32]; MAX_HARVEST_ALL_LSTS];
+    let mut harvested = 0usize;
+
+    let (lst_accounts, _) = remaining.as_chunks::<ACCOUNTS_PER_LST>();
+    for (i, [lst_config_account, rate_data_account, lst_vault_account]) in
+        lst_accounts.iter().enumerate()
+    {
+        if lst_config_account.owner() != &crate::ID {
+            log!("harvest_all_lst_appreciation: invalid lst config owner");
+            return Err(UnifiedSolPoolError::InvalidLstConfig.into());
+        }
+
+        let lst_config = AccountLoader::<LstConfig>::new(lst_config_account)?;
+        let (lst_mint, needs_harvest) = lst_config.map(|config| {
+            (
+                config.lst_mint,
+                config.tracks_rate() && config.last_harvest_epoch != current_epoch,
+            )
+        })?;
+
+        // Verify lst_config is the canonical PDA derived from its mint
+        let (expected_pda, _) = find_lst_config_pda(&lst_mint);
+        if *lst_config_account.key() != expected_pda {
+            log!("harvest_all_lst_appreciation: invalid lst_config PDA");
+            return Err(UnifiedSolPoolError::InvalidLstConfig.into());
+        }
+
+        if seen_mints[..i].contains(&lst_mint) {
+            log!("harvest_all_lst_appreciation: duplicate LST config");
+            return Err(UnifiedSolPoolError::DuplicateLstConfig.into());
+        }
+        seen_mints[i] = lst_mint;
+
+        if !needs_harvest {
+            continue;
+        }
+
+        harvest_lst(
+            unified_sol_pool_config,
+            &lst_config,
+            rate_data_account,
+            Some(lst_vault_account),
+            caller.key(),
+            unified_sol_program,
+            None,
+        )?;
+        harvested += 1;
+    }
+
+    log!(
+        "harvest_all_lst_appreciation: harvested {} of {} LSTs",
+        harvested,
+        lst_count
+    );
+    Ok(())
+}
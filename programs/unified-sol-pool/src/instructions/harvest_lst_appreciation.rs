@@ -3,17 +3,26 @@
 use crate::{
     LstConfig, PoolType, UnifiedSolPoolConfig, UnifiedSolPoolError, emit_event,
     events::{AppreciationHarvestedEvent, ExchangeRateUpdatedEvent},
-    gen_unified_sol_pool_config_seeds, read_token_account_balance,
+    gen_unified_sol_pool_config_seeds,
+    rate_source::{RateSource, marinade::MarinadeState, read_rate, spl_stake_pool::StakePoolState},
+    read_token_account_balance,
+    utils::record_pending_rewards,
 };
 use panchor::prelude::*;
 use pinocchio::{
     ProgramResult,
     account_info::AccountInfo,
     instruction::Signer as PinocchioSigner,
+    program_error::ProgramError,
     pubkey::Pubkey,
     sysvars::{Sysvar, clock::Clock},
 };
 use pinocchio_log::log;
+use zorb_pool_interface::BASIS_POINTS;
+
+/// Maximum deviation, in basis points, between the on-chain rate and a
+/// caller-supplied `expected_rate` (0.1%).
+pub const RATE_CROSS_CHECK_TOLERANCE_BPS: u64 = 10;
 
 /// Accounts for the HarvestLstAppreciation instruction.
 #[derive(Accounts)]
@@ -26,51 +35,96 @@ pub struct HarvestLstAppreciationAccounts<'info> {
     #[account(mut, owner = crate::ID)]
     pub lst_config: AccountLoader<'info, LstConfig>,
 
-    /// Rate data account: stake pool (for SPL pools) or lst_vault (for WSOL)
+    /// Rate data account: stake pool (SPL pools), Marinade state (Marinade), or lst_vault (WSOL)
     pub rate_data_account: &'info AccountInfo,
 
+    /// Keeper running the harvest (must be allowlisted while the keeper
+    /// allowlist is enforced)
+    pub caller: Signer<'info>,
+
     /// Unified SOL pool program account (required for self-CPI event emission)
     #[account(address = crate::ID)]
     pub unified_sol_program: &'info AccountInfo,
-    // Remaining accounts: lst_vault (for SplStakePool type)
+    // Remaining accounts: lst_vault (for SplStakePool and Marinade types)
 }
 
 /// Harvest LST appreciation for a specific LST.
 ///
-/// This permissionless instruction reads the current exchange rate and calculates
-/// appreciation since the last update. The appreciation is added to pending rewards.
+/// Permissionless unless the keeper allowlist is enforced, in which case
+/// `caller` must be an allowlisted keeper. Reads the current exchange rate and calculates
+/// appreciation since the last update. The appreciation is added to pending rewards,
+/// or carried forward (without events) while below `min_harvest_lamports`.
+///
+/// The rate is always derived on-chain (1:1 for WSOL, the issuing program's own
+/// accounting otherwise; see `rate_source`). Instruction data may optionally carry an
+/// 8-byte little-endian `expected_rate`; if present, the harvest fails with
+/// `ExchangeRateMismatch` unless the derived rate is within
+/// `RATE_CROSS_CHECK_TOLERANCE_BPS` of it. Keepers use this to refuse to
+/// harvest a rate they did not anticipate.
 pub fn process_harvest_lst_appreciation(
     ctx: Context<HarvestLstAppreciationAccounts>,
+    instruction_data: &[u8],
 ) -> ProgramResult {
     let HarvestLstAppreciationAccounts {
         unified_sol_pool_config,
         lst_config,
         rate_data_account,
+        caller,
         unified_sol_program,
     } = ctx.accounts;
 
+    unified_sol_pool_config.try_map(|config| {
+        config.check_keeper(caller.key()).map_err(|e| {
+            log!("harvest_lst_appreciation: caller is not an allowlisted keeper");
+            e.into()
+        })
+    })?;
+
+    let expected_rate = parse_expected_rate(instruction_data)?;
+
+    harvest_lst(
+        unified_sol_pool_config,
+        lst_config,
+        rate_data_account,
+        ctx.remaining_accounts.first(),
+        caller.key(),
+        unified_sol_program,
+        expected_rate,
+    )
+}
+
+/// Harvest one LST: derive its rate, validate it, and book any appreciation.
+///
+/// Shared by `harvest_lst_appreciation` and `harvest_all_lst_appreciation`;
+/// `caller` is the (already checked) keeper recorded in the harvest event.
+/// `lst_vault_account` is required for stake pool types (for WSOL the vault
+/// is `rate_data_account` itself).
+pub(crate) fn harvest_lst(
+    unified_sol_pool_config: &AccountLoader<'_, UnifiedSolPoolConfig>,
+    lst_config: &AccountLoader<'_, LstConfig>,
+    rate_data_account: &AccountInfo,
+    lst_vault_account: Option<&AccountInfo>,
+    caller: &Pubkey,
+    unified_sol_program: &AccountInfo,
+    expected_rate: Option<u64>,
+) -> ProgramResult {
     // Read values from both configs (releases borrows after closure)
     let (current_epoch, unified_bump) =
         unified_sol_pool_config.map(|config| (config.reward_epoch, config.bump))?;
 
-    let (lst_vault, stake_pool, stake_pool_program, pool_type, is_active): (
-        Pubkey,
-        Pubkey,
-        Pubkey,
-        u8,
-        u8,
-    ) = lst_config.map(|config| {
-        (
-            config.lst_vault,
-            config.stake_pool,
-            config.stake_pool_program,
-            config.pool_type,
-            config.is_active,
-        )
-    })?;
-
-    // Check if LST is active
-    if is_active == 0 {
+    let (lst_mint, lst_vault, stake_pool, pool_type, active): (Pubkey, Pubkey, Pubkey, u8, bool) =
+        lst_config.map(|config| {
+            (
+                config.lst_mint,
+                config.lst_vault,
+                config.stake_pool,
+                config.pool_type,
+                config.is_active(),
+            )
+        })?;
+
+    // Check if LST is active (either direction enabled)
+    if !active {
         log!("harvest_lst_appreciation: LST is not active");
         return Err(UnifiedSolPoolError::LstNotActive.into());
     }
@@ -80,6 +134,14 @@ pub fn process_harvest_lst_appreciation(
     let current_slot = clock.slot;
     let solana_epoch = clock.epoch;
 
+    // In SolanaEpoch mode, harvests for the next reward epoch wait for the
+    // Solana epoch that finalization is waiting on
+    let can_harvest = unified_sol_pool_config.map(|config| config.can_harvest(solana_epoch))?;
+    if !can_harvest {
+        log!("harvest_lst_appreciation: Solana epoch not advanced since last finalization");
+        return Err(UnifiedSolPoolError::RewardsNotReady.into());
+    }
+
     // Read pool type
     let pool_type = PoolType::from_u8(pool_type).ok_or_else(|| {
         log!("harvest_lst_appreciation: invalid pool_type");
@@ -94,6 +156,8 @@ pub fn process_harvest_lst_appreciation(
                 return Err(UnifiedSolPoolError::InvalidVault.into());
             }
 
+            check_expected_rate(LstConfig::RATE_PRECISION, expected_rate)?;
+
             // Read vault balance for invariant check
             let vault_balance = read_token_account_balance(rate_data_account)?;
 
@@ -122,24 +186,23 @@ pub fn process_harvest_lst_appreciation(
             log!("harvest_lst_appreciation: WSOL harvested");
         }
 
-        PoolType::SplStakePool => {
-            // For SPL Stake Pool, the rate_data_account is the stake pool
+        PoolType::SplStakePool | PoolType::Marinade => {
+            // For stake pools, the rate_data_account is the rate source account
+            // recorded at init (SPL stake pool or Marinade state)
             if *rate_data_account.key() != stake_pool {
-                log!("harvest_lst_appreciation: expected stake_pool for SplStakePool");
-                return Err(UnifiedSolPoolError::InvalidStakePool.into());
-            }
-
-            // Validate stake pool program ownership
-            if *rate_data_account.owner() != stake_pool_program {
-                log!("harvest_lst_appreciation: stake pool has wrong owner");
+                log!("harvest_lst_appreciation: expected the configured stake_pool");
                 return Err(UnifiedSolPoolError::InvalidStakePool.into());
             }
 
-            // Read exchange rate from stake pool data
-            // M-02 AUDIT FIX: Validates stake pool was updated in current Solana epoch
-            // (see read_spl_stake_pool_rate for details)
-            let new_exchange_rate =
-                read_spl_stake_pool_rate(rate_data_account, solana_epoch)?;
+            let new_exchange_rate = read_stake_pool_rate(
+                unified_sol_pool_config,
+                lst_config,
+                rate_data_account,
+                pool_type,
+                &lst_mint,
+                solana_epoch,
+            )?;
+            check_expected_rate(new_exchange_rate, expected_rate)?;
 
             // AUDIT TODO: Exchange Rate Invariant - THIS IS THE ONLY RUNTIME CHECK
             // =============================================================================
@@ -159,9 +222,9 @@ pub fn process_harvest_lst_appreciation(
                 return Err(UnifiedSolPoolError::InvalidExchangeRate.into());
             }
 
-            // Get lst_vault account from remaining accounts
-            let lst_vault_account = ctx.remaining_accounts.first().ok_or_else(|| {
-                log!("harvest_lst_appreciation: missing lst_vault account for SplStakePool");
+            // Get lst_vault account (remaining accounts for a single harvest)
+            let lst_vault_account = lst_vault_account.ok_or_else(|| {
+                log!("harvest_lst_appreciation: missing lst_vault account");
                 UnifiedSolPoolError::InvalidVault
             })?;
 
@@ -199,9 +262,6 @@ pub fn process_harvest_lst_appreciation(
                 Ok(())
             })?;
 
-            // Get lst_mint for events
-            let lst_mint = lst_config.map(|lst| lst.lst_mint)?;
-
             // Update LST config and calculate appreciation using a shared variable
             // Note: total_virtual_sol is calculated atomically at finalize time
             let mut appreciation_value = 0u64;
@@ -214,12 +274,20 @@ pub fn process_harvest_lst_appreciation(
                 Ok(())
             })?;
 
+            // Add appreciation (with any carried dust) to unified config pending
+            // rewards, less the protocol commission. Below min_harvest_lamports
+            // it is carried to a later harvest and nothing is emitted; the LST
+            // still counts as harvested for finalization.
+            let booked = unified_sol_pool_config
+                .try_map_mut(|unified| Ok(unified.book_appreciation(appreciation_value)?))?;
+            let Some((appreciation_value, commission_value)) = booked else {
+                log!("harvest_lst_appreciation: dust appreciation carried forward");
+                return Ok(());
+            };
+            record_pending_rewards(lst_config.account_info(), |pending| {
+                pending.add_appreciation(appreciation_value - commission_value)
+            })?;
             if appreciation_value > 0 {
-                // Add appreciation to unified config pending rewards
-                unified_sol_pool_config.try_inspect_mut(|unified| {
-                    unified.add_appreciation(appreciation_value)?;
-                    Ok(())
-                })?;
                 log!("harvest_lst_appreciation: appreciation harvested");
             } else {
                 log!("harvest_lst_appreciation: no appreciation to harvest");
@@ -257,12 +325,14 @@ pub fn process_harvest_lst_appreciation(
                         appreciation_amount: appreciation_value,
                         epoch: current_epoch,
                         slot: current_slot,
+                        commission_amount: commission_value,
+                        caller: *caller,
                     },
                 )?;
             }
         }
 
-        PoolType::Marinade | PoolType::Lido => {
+        PoolType::Lido => {
             // Not yet implemented
             log!("harvest_lst_appreciation: pool type not yet implemented");
             return Err(UnifiedSolPoolError::InvalidPoolType.into());
@@ -272,81 +342,82 @@ pub fn process_harvest_lst_appreciation(
     Ok(())
 }
 
-/// Read the exchange rate from an SPL Stake Pool account.
-///
-/// # Layout Dependency
-/// This function reads data at offsets 259-283 which corresponds to:
-/// - [259..267]: total_lamports (u64) - total SOL value in the pool
-/// - [267..275]: pool_token_supply (u64) - total pool tokens minted
-/// - [275..283]: last_update_epoch (u64) - epoch when pool was last updated
-///
-/// This is based on SPL Stake Pool program version 0.9.x (program ID: SPoo1...).
-/// If the stake pool program layout changes, this function must be updated.
-///
-/// The exchange rate is calculated as: (total_lamports * 1e9) / pool_token_supply
+/// Read the current rate of a stake pool LST from its configured source.
 ///
-/// # Epoch Validation (M-02 Audit Fix)
-/// This function validates that the stake pool was updated in the current epoch.
-/// At epoch boundaries, stake rewards are distributed to validators but the stake
-/// pool's `total_lamports` field isn't updated until someone calls `UpdateStakePoolBalance`.
-/// Reading stale data would under-count appreciation. Requiring epoch freshness ensures
-/// callers get accurate exchange rates.
-fn read_spl_stake_pool_rate(
-    stake_pool: &AccountInfo,
-    current_epoch: u64,
-) -> Result<u64, UnifiedSolPoolError> {
-    let data = stake_pool
-        .try_borrow_data()
-        .map_err(|_| UnifiedSolPoolError::InvalidInstructionData)?;
-
-    // Need 283 bytes minimum: up to last_update_epoch field end
-    if data.len() < 283 {
-        log!("harvest_lst_appreciation: stake pool data too short");
-        return Err(UnifiedSolPoolError::InvalidInstructionData);
-    }
+/// The source is loaded from its owning program, for this LST's mint, and
+/// only if it has caught up with `solana_epoch`. The caller has checked that
+/// `rate_data_account` is the LST's `stake_pool`. Shared with
+/// `apply_manual_rate`, which treats any failure as an unhealthy source.
+pub(crate) fn read_stake_pool_rate(
+    unified_sol_pool_config: &AccountLoader<'_, UnifiedSolPoolConfig>,
+    lst_config: &AccountLoader<'_, LstConfig>,
+    rate_data_account: &AccountInfo,
+    pool_type: PoolType,
+    lst_mint: &Pubkey,
+    solana_epoch: u64,
+) -> Result<u64, ProgramError> {
+    let rate = match pool_type {
+        PoolType::Marinade => read_rate::<MarinadeState>(
+            rate_data_account,
+            &MarinadeState::PROGRAM_ID,
+            lst_mint,
+            solana_epoch,
+        )?,
+        _ => {
+            // The program recorded at init must still be allowlisted;
+            // removing it from the allowlist revokes harvesting
+            let stake_pool_program = lst_config.map(|c| c.stake_pool_program)?;
+            let allowed = unified_sol_pool_config
+                .map(|c| c.allows_stake_pool_program(&stake_pool_program))?;
+            if !allowed {
+                log!("harvest_lst_appreciation: stake pool program not allowlisted");
+                return Err(UnifiedSolPoolError::InvalidStakePoolProgram.into());
+            }
 
-    // SPL stake pool offsets (struct field order: total_lamports, pool_token_supply, last_update_epoch)
-    let total_lamports = u64::from_le_bytes(
-        data[259..267]
-            .try_into()
-            .map_err(|_| UnifiedSolPoolError::InvalidInstructionData)?,
-    );
-    let pool_token_supply = u64::from_le_bytes(
-        data[267..275]
-            .try_into()
-            .map_err(|_| UnifiedSolPoolError::InvalidInstructionData)?,
-    );
-    let last_update_epoch = u64::from_le_bytes(
-        data[275..283]
-            .try_into()
-            .map_err(|_| UnifiedSolPoolError::InvalidInstructionData)?,
-    );
-
-    // M-02 AUDIT FIX: Validate stake pool was updated in current epoch
-    // At epoch boundaries, stake rewards haven't been distributed yet, so
-    // the stake pool's total_lamports may be stale. Requiring epoch freshness
-    // ensures we read accurate exchange rates.
-    if last_update_epoch != current_epoch {
-        log!(
-            "harvest_lst_appreciation: stake pool stale (updated epoch {}, current {})",
-            last_update_epoch,
-            current_epoch
-        );
-        return Err(UnifiedSolPoolError::StaleStakePoolRate.into());
-    }
+            read_rate::<StakePoolState>(
+                rate_data_account,
+                &stake_pool_program,
+                lst_mint,
+                solana_epoch,
+            )?
+        }
+    };
+    Ok(rate)
+}
 
-    if pool_token_supply == 0 {
-        log!("harvest_lst_appreciation: pool_token_supply is zero");
-        return Err(UnifiedSolPoolError::InvalidExchangeRate);
+/// Parse the optional `expected_rate` from instruction data.
+pub(super) fn parse_expected_rate(
+    instruction_data: &[u8],
+) -> Result<Option<u64>, UnifiedSolPoolError> {
+    match instruction_data.len() {
+        0 => Ok(None),
+        8 => {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(instruction_data);
+            Ok(Some(u64::from_le_bytes(bytes)))
+        }
+        _ => {
+            log!("harvest_lst_appreciation: invalid instruction data length");
+            Err(UnifiedSolPoolError::InvalidInstructionData)
+        }
     }
+}
 
-    let rate = (total_lamports as u128)
-        .checked_mul(LstConfig::RATE_PRECISION as u128)
-        .and_then(|v| v.checked_div(pool_token_supply as u128))
-        .ok_or_else(|| {
-            log!("harvest_lst_appreciation: rate calculation overflow");
-            UnifiedSolPoolError::ArithmeticOverflow
-        })? as u64;
+/// Check the derived rate against the caller's `expected_rate`, if any.
+fn check_expected_rate(rate: u64, expected_rate: Option<u64>) -> Result<(), UnifiedSolPoolError> {
+    let Some(expected_rate) = expected_rate else {
+        return Ok(());
+    };
 
-    Ok(rate)
+    let tolerance =
+        expected_rate as u128 * RATE_CROSS_CHECK_TOLERANCE_BPS as u128 / BASIS_POINTS as u128;
+    if rate.abs_diff(expected_rate) as u128 > tolerance {
+        log!(
+            "harvest_lst_appreciation: rate {} deviates from expected {}",
+            rate,
+            expected_rate
+        );
+        return Err(UnifiedSolPoolError::ExchangeRateMismatch);
+    }
+    Ok(())
 }
@@ -12,7 +12,9 @@ mod deposit;
 mod withdraw;
 
 // Permissionless operations
+mod apply_manual_rate;
 mod finalize_unified_rewards;
+mod harvest_all_lst_appreciation;
 mod harvest_lst_appreciation;
 mod log;
 
@@ -24,14 +26,34 @@ pub use deposit::{DepositAccounts, process_deposit};
 pub use withdraw::{WithdrawAccounts, process_withdraw};
 
 // Re-export permissionless operation accounts and handlers
+pub use apply_manual_rate::{ApplyManualRateAccounts, process_apply_manual_rate};
 pub use finalize_unified_rewards::{
     FinalizeUnifiedRewardsAccounts, process_finalize_unified_rewards,
 };
+pub use harvest_all_lst_appreciation::{
+    HarvestAllLstAppreciationAccounts, MAX_HARVEST_ALL_LSTS, process_harvest_all_lst_appreciation,
+};
 pub use harvest_lst_appreciation::{
     HarvestLstAppreciationAccounts, process_harvest_lst_appreciation,
 };
 pub use log::{LogAccounts, process_log};
 
+// Re-export client Input structs for the generated `client` module
+#[cfg(feature = "solana-sdk")]
+pub use apply_manual_rate::ApplyManualRateInput;
+#[cfg(feature = "solana-sdk")]
+pub use deposit::DepositInput;
+#[cfg(feature = "solana-sdk")]
+pub use finalize_unified_rewards::FinalizeUnifiedRewardsInput;
+#[cfg(feature = "solana-sdk")]
+pub use harvest_all_lst_appreciation::HarvestAllLstAppreciationInput;
+#[cfg(feature = "solana-sdk")]
+pub use harvest_lst_appreciation::HarvestLstAppreciationInput;
+#[cfg(feature = "solana-sdk")]
+pub use log::LogInput;
+#[cfg(feature = "solana-sdk")]
+pub use withdraw::WithdrawInput;
+
 /// Unified SOL pool instruction set.
 ///
 /// # Discriminator Ranges (per discriminator-standard.md)
@@ -47,16 +69,21 @@ pub enum UnifiedSolPoolInstruction {
     // =========================================================================
     /// Process a deposit: transfer LST tokens from depositor to vault.
     ///
+    /// Returns a `UnifiedSolOpResult` as return data.
+    ///
     /// # Accounts
     /// See `DepositAccounts` for the required accounts.
-    #[handler(raw_data, accounts = DepositAccounts)]
+    #[handler(raw_data, accounts = DepositAccounts, returns = zorb_pool_interface::UnifiedSolOpResult)]
     Deposit = 0,
 
     /// Process a withdrawal: transfer LST tokens from vault to recipient.
     ///
+    /// Returns a `UnifiedSolOpResult` as return data (`RoutedWithdrawReturnData`
+    /// when routed).
+    ///
     /// # Accounts
     /// See `WithdrawAccounts` for the required accounts.
-    #[handler(raw_data, accounts = WithdrawAccounts)]
+    #[handler(raw_data, accounts = WithdrawAccounts, returns = zorb_pool_interface::UnifiedSolOpResult)]
     Withdraw = 1,
     // Reserved: 2-31
 
@@ -90,13 +117,17 @@ pub enum UnifiedSolPoolInstruction {
 
     /// Finalize unified SOL rewards by updating the reward accumulator.
     ///
-    /// Permissionless - anyone can call after UPDATE_SLOT_INTERVAL has passed.
+    /// Permissionless - anyone can call after UPDATE_SLOT_INTERVAL has passed
+    /// (only keepers while the keeper allowlist is enforced).
     /// Freezes exchange rates atomically with accumulator update.
     FinalizeUnifiedRewards = 69,
 
     /// Harvest LST appreciation for a specific LST.
     ///
-    /// Permissionless. Updates exchange rate and adds appreciation to pending rewards.
+    /// Permissionless unless the keeper allowlist is enforced. Updates exchange
+    /// rate and adds appreciation to pending rewards.
+    /// Optional data: `expected_rate: u64` cross-check.
+    #[handler(raw_data)]
     HarvestLstAppreciation = 70,
 
     /// Log an event via CPI (internal use only).
@@ -105,7 +136,13 @@ pub enum UnifiedSolPoolInstruction {
     /// It validates the caller is the program itself via PDA signer.
     #[handler(raw_data, accounts = LogAccounts)]
     Log = 71,
-    // Reserved: 72-127
+
+    /// Harvest LST appreciation for every registered LST.
+    ///
+    /// Permissionless unless the keeper allowlist is enforced. Skips LSTs
+    /// already harvested this epoch; covers at most `MAX_HARVEST_ALL_LSTS` LSTs.
+    HarvestAllLstAppreciation = 72,
+    // Reserved: 73-127
 
     // =========================================================================
     // Admin Operations (192-255) - For future admin instructions
@@ -119,4 +156,636 @@ pub enum UnifiedSolPoolInstruction {
     ///
     /// Must be called by the `pending_authority` address.
     AcceptAuthority = 193,
+
+    /// Allowlist an SPL-layout stake pool program (e.g. a Sanctum deployment).
+    #[handler(data)]
+    AddStakePoolProgram = 194,
+
+    /// Remove a program from the stake pool program allowlist.
+    #[handler(data)]
+    RemoveStakePoolProgram = 195,
+
+    /// Retire an LST: deposits blocked, withdrawals allowed, harvest not required.
+    #[handler(data)]
+    SetLstConfigRetired = 196,
+
+    /// Close a retired, drained LST config and its vault.
+    ///
+    /// Decrements `lst_count`; rent is returned to the authority.
+    CloseLstConfig = 197,
+
+    /// Set an LST's per-epoch rate band and rate staleness limit.
+    #[handler(data)]
+    SetLstConfigRateBounds = 198,
+
+    /// Approve an exact out-of-band rate for the next finalization.
+    #[handler(data)]
+    ApproveLstRateJump = 199,
+
+    /// Override the pool-wide deposit/withdrawal fee rates for one LST.
+    #[handler(data)]
+    SetLstConfigFeeOverrides = 200,
+
+    /// Cap the virtual SOL a single LST may hold (0 = uncapped).
+    #[handler(data)]
+    SetLstConfigVirtualSolCap = 201,
+
+    /// Cap the pool's total virtual SOL across all LSTs (0 = uncapped).
+    #[handler(data)]
+    SetUnifiedSolPoolConfigVirtualSolCap = 202,
+
+    /// Set the LST order automatically routed withdrawals fall back to.
+    #[handler(data)]
+    SetWithdrawPriority = 203,
+
+    /// Set the protocol commission on harvested appreciation and its treasury.
+    #[handler(data)]
+    SetAppreciationCommission = 204,
+
+    /// Pay accrued commission to the treasury in a chosen LST.
+    #[handler(data)]
+    WithdrawCommission = 205,
+
+    /// Realloc an LstConfig to the current layout and run version upgrades.
+    MigrateLstConfig = 206,
+
+    /// Switch reward epochs between the slot interval and Solana epochs.
+    #[handler(data)]
+    SetEpochMode = 207,
+
+    /// Set which directions (deposit, withdraw) are enabled for an LST.
+    #[handler(data)]
+    SetLstConfigMode = 208,
+
+    /// Set the minimum appreciation a harvest books (smaller harvests carry forward).
+    #[handler(data)]
+    SetMinHarvestLamports = 209,
+
+    /// Propose a timelocked emergency exchange rate for an LST.
+    #[handler(data)]
+    ProposeManualRate = 210,
+
+    /// Apply an unlocked manual exchange rate while the LST's rate source is unhealthy.
+    ///
+    /// Permissionless unless the keeper allowlist is enforced. Counts as the
+    /// LST's harvest for the current epoch.
+    ApplyManualRate = 211,
+
+    /// Cancel an LST's pending manual exchange rate.
+    CancelManualRate = 212,
+
+    /// Set the delay between a manual rate proposal and its earliest application.
+    #[handler(data)]
+    SetManualRateDelay = 213,
+
+    /// Add a keeper to the allowlist for harvests and finalization.
+    #[handler(data)]
+    AddKeeper = 214,
+
+    /// Remove a keeper from the allowlist.
+    #[handler(data)]
+    RemoveKeeper = 215,
+
+    /// Restrict harvests and finalization to the keeper allowlist, or lift it.
+    #[handler(data)]
+    SetKeeperAllowlistEnabled = 216,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zorb_pool_interface::{MAX_WITHDRAW_ROUTE_VAULTS, PoolIx, unified_sol_pool_ix};
+
+    const CONFIG: Pubkey = [1; 32];
+    const AUTHORITY: Pubkey = [2; 32];
+    const LST_CONFIG: Pubkey = [3; 32];
+    const LST_VAULT: Pubkey = [4; 32];
+    const RATE_DATA: Pubkey = [5; 32];
+    const TOKEN_PROGRAM: Pubkey = [6; 32];
+    const OTHER: Pubkey = [7; 32];
+
+    /// Compare the builder's account flags with the accounts struct, which
+    /// `trailing` remaining accounts follow (`idl-build` only).
+    macro_rules! assert_accounts {
+        ($ix:expr, $accounts:ty) => {
+            assert_accounts!($ix, $accounts, 0)
+        };
+        ($ix:expr, $accounts:ty, $trailing:expr) => {
+            #[cfg(feature = "idl-build")]
+            {
+                let expected = <$accounts>::__idl_instruction_accounts();
+                assert_eq!($ix.accounts.len(), expected.len() + $trailing);
+                for (account, meta) in $ix.accounts.iter().zip(&expected) {
+                    assert_eq!(
+                        (account.is_writable, account.is_signer),
+                        (meta.writable, meta.signer),
+                        "{}",
+                        meta.name
+                    );
+                }
+            }
+        };
+    }
+
+    /// Compare the listed fields, naming the first that differs.
+    macro_rules! assert_fields_eq {
+        ($actual:expr, $expected:expr, [$($field:ident),+ $(,)?]) => {
+            $(
+                assert_eq!(
+                    $actual.$field,
+                    $expected.$field,
+                    concat!("field `", stringify!($field), "`")
+                );
+            )+
+        };
+    }
+
+    /// Check the program and discriminator, returning the data after it.
+    fn data_of(ix: &PoolIx, instruction: UnifiedSolPoolInstruction) -> &[u8] {
+        assert_eq!(ix.program_id, crate::ID);
+        assert_eq!(ix.data[0], instruction as u8);
+        &ix.data[1..]
+    }
+
+    #[test]
+    fn test_init_ixs() {
+        let ix = unified_sol_pool_ix::init_unified_sol_pool_config(
+            &CONFIG, &AUTHORITY, 1_000, 10, 20, 500, 3_000,
+        );
+        let data = InitUnifiedSolPoolConfigData::try_from(data_of(
+            &ix,
+            UnifiedSolPoolInstruction::InitUnifiedSolPoolConfig,
+        ))
+        .unwrap();
+        assert_eq!(data.max_deposit_amount, 1_000);
+        assert_eq!((data.deposit_fee_rate, data.withdrawal_fee_rate), (10, 20));
+        assert_eq!((data.min_buffer_bps, data.min_buffer_amount), (500, 3_000));
+        assert_accounts!(ix, InitUnifiedSolPoolConfigAccounts);
+
+        let ix = unified_sol_pool_ix::init_lst_config(
+            &CONFIG,
+            &LST_CONFIG,
+            &OTHER,
+            &LST_VAULT,
+            &RATE_DATA,
+            &OTHER,
+            &AUTHORITY,
+            &TOKEN_PROGRAM,
+            2,
+        );
+        let data =
+            InitLstConfigData::try_from(data_of(&ix, UnifiedSolPoolInstruction::InitLstConfig))
+                .unwrap();
+        assert_eq!(data.pool_type, 2);
+        assert_accounts!(ix, InitLstConfigAccounts);
+    }
+
+    #[test]
+    fn test_pool_config_ixs() {
+        let ix = unified_sol_pool_ix::set_unified_sol_pool_config_active(&CONFIG, &AUTHORITY, true);
+        let data = SetUnifiedSolPoolConfigActiveData::try_from(data_of(
+            &ix,
+            UnifiedSolPoolInstruction::SetUnifiedSolPoolConfigActive,
+        ))
+        .unwrap();
+        assert_eq!(data.is_active, 1);
+        assert_accounts!(ix, SetUnifiedSolPoolConfigActiveAccounts);
+
+        let ix =
+            unified_sol_pool_ix::set_unified_sol_pool_config_fee_rates(&CONFIG, &AUTHORITY, 30, 40);
+        let data = SetUnifiedSolPoolConfigFeeRatesData::try_from(data_of(
+            &ix,
+            UnifiedSolPoolInstruction::SetUnifiedSolPoolConfigFeeRates,
+        ))
+        .unwrap();
+        assert_eq!((data.deposit_fee_rate, data.withdrawal_fee_rate), (30, 40));
+        assert_accounts!(ix, SetUnifiedSolPoolConfigFeeRatesAccounts);
+
+        let ix = unified_sol_pool_ix::add_stake_pool_program(&CONFIG, &AUTHORITY, &OTHER);
+        let data = AddStakePoolProgramData::try_from(data_of(
+            &ix,
+            UnifiedSolPoolInstruction::AddStakePoolProgram,
+        ))
+        .unwrap();
+        assert_eq!(data.program, OTHER);
+        assert_accounts!(ix, AddStakePoolProgramAccounts);
+
+        let ix = unified_sol_pool_ix::remove_stake_pool_program(&CONFIG, &AUTHORITY, &OTHER);
+        let data = RemoveStakePoolProgramData::try_from(data_of(
+            &ix,
+            UnifiedSolPoolInstruction::RemoveStakePoolProgram,
+        ))
+        .unwrap();
+        assert_eq!(data.program, OTHER);
+        assert_accounts!(ix, RemoveStakePoolProgramAccounts);
+
+        let ix = unified_sol_pool_ix::set_unified_sol_pool_config_virtual_sol_cap(
+            &CONFIG, &AUTHORITY, 9_000,
+        );
+        let data = SetUnifiedSolPoolConfigVirtualSolCapData::try_from(data_of(
+            &ix,
+            UnifiedSolPoolInstruction::SetUnifiedSolPoolConfigVirtualSolCap,
+        ))
+        .unwrap();
+        assert_eq!(data.max_total_virtual_sol, 9_000);
+        assert_accounts!(ix, SetUnifiedSolPoolConfigVirtualSolCapAccounts);
+
+        let mut priority = [[0; 32]; MAX_WITHDRAW_ROUTE_VAULTS];
+        priority[0] = OTHER;
+        priority[1] = LST_CONFIG;
+        let ix = unified_sol_pool_ix::set_withdraw_priority(&CONFIG, &AUTHORITY, &priority);
+        let data = SetWithdrawPriorityData::try_from(data_of(
+            &ix,
+            UnifiedSolPoolInstruction::SetWithdrawPriority,
+        ))
+        .unwrap();
+        assert_eq!(data.withdraw_priority, priority);
+        assert_accounts!(ix, SetWithdrawPriorityAccounts);
+
+        let ix = unified_sol_pool_ix::set_appreciation_commission(&CONFIG, &AUTHORITY, &OTHER, 250);
+        let data = SetAppreciationCommissionData::try_from(data_of(
+            &ix,
+            UnifiedSolPoolInstruction::SetAppreciationCommission,
+        ))
+        .unwrap();
+        assert_eq!(data.commission_treasury, OTHER);
+        assert_eq!(data.appreciation_commission_bps, 250);
+        assert_accounts!(ix, SetAppreciationCommissionAccounts);
+
+        let ix = unified_sol_pool_ix::set_epoch_mode(&CONFIG, &AUTHORITY, 1);
+        let data =
+            SetEpochModeData::try_from(data_of(&ix, UnifiedSolPoolInstruction::SetEpochMode))
+                .unwrap();
+        assert_eq!(data.epoch_mode, 1);
+        assert_accounts!(ix, SetEpochModeAccounts);
+
+        let ix = unified_sol_pool_ix::set_min_harvest_lamports(&CONFIG, &AUTHORITY, 5_000);
+        let data = SetMinHarvestLamportsData::try_from(data_of(
+            &ix,
+            UnifiedSolPoolInstruction::SetMinHarvestLamports,
+        ))
+        .unwrap();
+        assert_eq!(data.min_harvest_lamports, 5_000);
+        assert_accounts!(ix, SetMinHarvestLamportsAccounts);
+
+        let ix = unified_sol_pool_ix::set_manual_rate_delay(&CONFIG, &AUTHORITY, 750);
+        let data = SetManualRateDelayData::try_from(data_of(
+            &ix,
+            UnifiedSolPoolInstruction::SetManualRateDelay,
+        ))
+        .unwrap();
+        assert_eq!(data.delay_slots, 750);
+        assert_accounts!(ix, SetManualRateDelayAccounts);
+    }
+
+    #[test]
+    fn test_authority_and_keeper_ixs() {
+        let ix = unified_sol_pool_ix::transfer_authority(&CONFIG, &AUTHORITY, &OTHER);
+        assert!(data_of(&ix, UnifiedSolPoolInstruction::TransferAuthority).is_empty());
+        assert_eq!(ix.accounts[2].pubkey, OTHER);
+        assert_accounts!(ix, TransferAuthorityAccounts);
+
+        let ix = unified_sol_pool_ix::accept_authority(&CONFIG, &OTHER);
+        assert!(data_of(&ix, UnifiedSolPoolInstruction::AcceptAuthority).is_empty());
+        assert_accounts!(ix, AcceptAuthorityAccounts);
+
+        let ix = unified_sol_pool_ix::add_keeper(&CONFIG, &AUTHORITY, &OTHER);
+        let data =
+            AddKeeperData::try_from(data_of(&ix, UnifiedSolPoolInstruction::AddKeeper)).unwrap();
+        assert_eq!(data.keeper, OTHER);
+        assert_accounts!(ix, AddKeeperAccounts);
+
+        let ix = unified_sol_pool_ix::remove_keeper(&CONFIG, &AUTHORITY, &OTHER);
+        let data =
+            RemoveKeeperData::try_from(data_of(&ix, UnifiedSolPoolInstruction::RemoveKeeper))
+                .unwrap();
+        assert_eq!(data.keeper, OTHER);
+        assert_accounts!(ix, RemoveKeeperAccounts);
+
+        let ix = unified_sol_pool_ix::set_keeper_allowlist_enabled(&CONFIG, &AUTHORITY, true);
+        let data = SetKeeperAllowlistEnabledData::try_from(data_of(
+            &ix,
+            UnifiedSolPoolInstruction::SetKeeperAllowlistEnabled,
+        ))
+        .unwrap();
+        assert_eq!(data.enabled, 1);
+        assert_accounts!(ix, SetKeeperAllowlistEnabledAccounts);
+    }
+
+    #[test]
+    fn test_lst_config_ixs() {
+        let ix =
+            unified_sol_pool_ix::set_lst_config_active(&CONFIG, &LST_CONFIG, &AUTHORITY, false);
+        let data = SetLstConfigActiveData::try_from(data_of(
+            &ix,
+            UnifiedSolPoolInstruction::SetLstConfigActive,
+        ))
+        .unwrap();
+        assert_eq!(data.is_active, 0);
+        assert_accounts!(ix, SetLstConfigActiveAccounts);
+
+        let ix =
+            unified_sol_pool_ix::set_lst_config_retired(&CONFIG, &LST_CONFIG, &AUTHORITY, true);
+        let data = SetLstConfigRetiredData::try_from(data_of(
+            &ix,
+            UnifiedSolPoolInstruction::SetLstConfigRetired,
+        ))
+        .unwrap();
+        assert_eq!(data.is_retired, 1);
+        assert_accounts!(ix, SetLstConfigRetiredAccounts);
+
+        let ix = unified_sol_pool_ix::set_lst_config_rate_bounds(
+            &CONFIG,
+            &LST_CONFIG,
+            &AUTHORITY,
+            15,
+            25,
+            900,
+        );
+        let data = SetLstConfigRateBoundsData::try_from(data_of(
+            &ix,
+            UnifiedSolPoolInstruction::SetLstConfigRateBounds,
+        ))
+        .unwrap();
+        assert_eq!(data.max_rate_increase_bps_per_epoch, 15);
+        assert_eq!(data.max_rate_decrease_bps_per_epoch, 25);
+        assert_eq!(data.max_rate_staleness_slots, 900);
+        assert_accounts!(ix, SetLstConfigRateBoundsAccounts);
+
+        let ix = unified_sol_pool_ix::approve_lst_rate_jump(&CONFIG, &LST_CONFIG, &AUTHORITY, 42);
+        let data = ApproveLstRateJumpData::try_from(data_of(
+            &ix,
+            UnifiedSolPoolInstruction::ApproveLstRateJump,
+        ))
+        .unwrap();
+        assert_eq!(data.approved_rate, 42);
+        assert_accounts!(ix, ApproveLstRateJumpAccounts);
+
+        let ix = unified_sol_pool_ix::set_lst_config_fee_overrides(
+            &CONFIG,
+            &LST_CONFIG,
+            &AUTHORITY,
+            7,
+            8,
+        );
+        let data = SetLstConfigFeeOverridesData::try_from(data_of(
+            &ix,
+            UnifiedSolPoolInstruction::SetLstConfigFeeOverrides,
+        ))
+        .unwrap();
+        assert_eq!(
+            (
+                data.deposit_fee_bps_override,
+                data.withdraw_fee_bps_override
+            ),
+            (7, 8)
+        );
+        assert_accounts!(ix, SetLstConfigFeeOverridesAccounts);
+
+        let ix = unified_sol_pool_ix::set_lst_config_virtual_sol_cap(
+            &CONFIG,
+            &LST_CONFIG,
+            &AUTHORITY,
+            66,
+        );
+        let data = SetLstConfigVirtualSolCapData::try_from(data_of(
+            &ix,
+            UnifiedSolPoolInstruction::SetLstConfigVirtualSolCap,
+        ))
+        .unwrap();
+        assert_eq!(data.max_virtual_sol, 66);
+        assert_accounts!(ix, SetLstConfigVirtualSolCapAccounts);
+
+        let ix = unified_sol_pool_ix::set_lst_config_mode(&CONFIG, &LST_CONFIG, &AUTHORITY, 3);
+        let data = SetLstConfigModeData::try_from(data_of(
+            &ix,
+            UnifiedSolPoolInstruction::SetLstConfigMode,
+        ))
+        .unwrap();
+        assert_eq!(data.mode, 3);
+        assert_accounts!(ix, SetLstConfigModeAccounts);
+
+        let ix = unified_sol_pool_ix::propose_manual_rate(&CONFIG, &LST_CONFIG, &AUTHORITY, 1_050);
+        let data = ProposeManualRateData::try_from(data_of(
+            &ix,
+            UnifiedSolPoolInstruction::ProposeManualRate,
+        ))
+        .unwrap();
+        assert_eq!(data.rate, 1_050);
+        assert_accounts!(ix, ProposeManualRateAccounts);
+
+        let ix = unified_sol_pool_ix::cancel_manual_rate(&CONFIG, &LST_CONFIG, &AUTHORITY);
+        assert!(data_of(&ix, UnifiedSolPoolInstruction::CancelManualRate).is_empty());
+        assert_accounts!(ix, CancelManualRateAccounts);
+
+        let ix = unified_sol_pool_ix::close_lst_config(
+            &CONFIG,
+            &LST_CONFIG,
+            &LST_VAULT,
+            &AUTHORITY,
+            &TOKEN_PROGRAM,
+        );
+        assert!(data_of(&ix, UnifiedSolPoolInstruction::CloseLstConfig).is_empty());
+        assert_accounts!(ix, CloseLstConfigAccounts);
+
+        let ix = unified_sol_pool_ix::withdraw_commission(
+            &CONFIG,
+            &LST_CONFIG,
+            &LST_VAULT,
+            &OTHER,
+            &AUTHORITY,
+            &TOKEN_PROGRAM,
+            120,
+        );
+        let data = WithdrawCommissionData::try_from(data_of(
+            &ix,
+            UnifiedSolPoolInstruction::WithdrawCommission,
+        ))
+        .unwrap();
+        assert_eq!(data.amount, 120);
+        assert_accounts!(ix, WithdrawCommissionAccounts);
+
+        let ix = unified_sol_pool_ix::migrate_lst_config(&CONFIG, &LST_CONFIG, &AUTHORITY, &OTHER);
+        assert!(data_of(&ix, UnifiedSolPoolInstruction::MigrateLstConfig).is_empty());
+        assert_accounts!(ix, MigrateLstConfigAccounts);
+    }
+
+    #[test]
+    fn test_permissionless_ixs() {
+        let ix = unified_sol_pool_ix::finalize_unified_rewards(
+            &CONFIG,
+            &AUTHORITY,
+            &[LST_CONFIG, OTHER],
+        );
+        assert!(data_of(&ix, UnifiedSolPoolInstruction::FinalizeUnifiedRewards).is_empty());
+        assert!(ix.accounts[3..].iter().all(|account| account.is_writable));
+        assert_accounts!(ix, FinalizeUnifiedRewardsAccounts, 2);
+
+        for expected_rate in [None, Some(1_020)] {
+            let ix = unified_sol_pool_ix::harvest_lst_appreciation(
+                &CONFIG,
+                &LST_CONFIG,
+                &RATE_DATA,
+                &AUTHORITY,
+                expected_rate,
+            );
+            let data = data_of(&ix, UnifiedSolPoolInstruction::HarvestLstAppreciation);
+            assert_eq!(
+                harvest_lst_appreciation::parse_expected_rate(data),
+                Ok(expected_rate)
+            );
+            assert_accounts!(ix, HarvestLstAppreciationAccounts);
+        }
+
+        let ix = unified_sol_pool_ix::harvest_all_lst_appreciation(
+            &CONFIG,
+            &AUTHORITY,
+            &[(LST_CONFIG, RATE_DATA, LST_VAULT)],
+        );
+        assert!(data_of(&ix, UnifiedSolPoolInstruction::HarvestAllLstAppreciation).is_empty());
+        assert_eq!(ix.accounts[3].pubkey, LST_CONFIG);
+        assert!(ix.accounts[3].is_writable && !ix.accounts[4].is_writable);
+        assert_accounts!(ix, HarvestAllLstAppreciationAccounts, 3);
+
+        let ix = unified_sol_pool_ix::apply_manual_rate(
+            &CONFIG,
+            &LST_CONFIG,
+            &RATE_DATA,
+            &LST_VAULT,
+            &AUTHORITY,
+        );
+        assert!(data_of(&ix, UnifiedSolPoolInstruction::ApplyManualRate).is_empty());
+        assert_accounts!(ix, ApplyManualRateAccounts);
+    }
+
+    /// The hub's encoded deposit and withdraw params, in the versioned and
+    /// legacy layouts, come out of this pool's parsers field for field.
+    #[test]
+    fn test_hub_params_parse() {
+        use zorb_pool_interface::{
+            DepositParams, WithdrawParams, build_deposit_instruction_data,
+            build_withdraw_instruction_data,
+        };
+
+        let params = DepositParams {
+            amount: 1_000_000,
+            expected_output: 990_000,
+            depositor: AUTHORITY,
+            expect_fee_exempt: 1,
+            ..Default::default()
+        };
+        let data = build_deposit_instruction_data(&params);
+        assert_eq!(data[0], UnifiedSolPoolInstruction::Deposit as u8);
+        for bytes in [&data[1..], &params.to_bytes()[..]] {
+            let parsed = deposit::parse_deposit_params(bytes).unwrap();
+            assert_fields_eq!(
+                parsed,
+                params,
+                [amount, expected_output, depositor, expect_fee_exempt]
+            );
+        }
+
+        let params = WithdrawParams {
+            amount: 1_000_000,
+            expected_output: 990_000,
+            recipient: OTHER,
+            expect_fee_exempt: 1,
+            unwrap: 1,
+            route_automatically: 1,
+            fallback_count: 2,
+            unwrap_amount: 980_000,
+            ..Default::default()
+        };
+        let data = build_withdraw_instruction_data(&params);
+        assert_eq!(data[0], UnifiedSolPoolInstruction::Withdraw as u8);
+        for bytes in [&data[1..], &params.to_bytes()[..]] {
+            let parsed = withdraw::parse_withdraw_params(bytes).unwrap();
+            assert_fields_eq!(
+                parsed,
+                params,
+                [
+                    amount,
+                    expected_output,
+                    recipient,
+                    expect_fee_exempt,
+                    unwrap,
+                    route_automatically,
+                    fallback_count,
+                    unwrap_amount,
+                ]
+            );
+        }
+    }
+
+    /// The hub's deposit and withdraw CPIs send the metas the accounts
+    /// structs' `Input` builders produce (withdraw then appends the token
+    /// program).
+    #[cfg(feature = "solana-sdk")]
+    #[test]
+    fn test_hub_cpi_metas_match_input() {
+        use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey as SdkPubkey};
+        use zorb_pool_interface::{
+            CpiAccountList, DepositCpiAccounts, PoolCpi, UnifiedSolPoolCpi, WithdrawCpiAccounts,
+        };
+
+        fn sdk_metas(list: &CpiAccountList<'_, Pubkey>) -> Vec<AccountMeta> {
+            list.as_slice()
+                .iter()
+                .map(|(key, access)| AccountMeta {
+                    pubkey: SdkPubkey::new_from_array(**key),
+                    is_signer: access.is_signer(),
+                    is_writable: access.is_writable(),
+                })
+                .collect()
+        }
+        let sdk = SdkPubkey::new_from_array;
+
+        let deposit = UnifiedSolPoolCpi
+            .deposit_accounts(&DepositCpiAccounts {
+                pool_state: Some(&CONFIG),
+                pool_config: &LST_CONFIG,
+                vault: &LST_VAULT,
+                depositor_token: &OTHER,
+                depositor: &AUTHORITY,
+                token_program: &TOKEN_PROGRAM,
+                pool_program: &crate::ID,
+            })
+            .unwrap();
+        let input = DepositInput {
+            unified_config: sdk(CONFIG),
+            lst_config: sdk(LST_CONFIG),
+            vault: sdk(LST_VAULT),
+            depositor_token: sdk(OTHER),
+            depositor: sdk(AUTHORITY),
+            unified_sol_program: sdk(crate::ID),
+            token_program: sdk(TOKEN_PROGRAM),
+        };
+        assert_eq!(sdk_metas(&deposit), input.to_account_metas());
+
+        let withdraw = UnifiedSolPoolCpi
+            .withdraw_accounts(&WithdrawCpiAccounts {
+                pool_state: Some(&CONFIG),
+                pool_config: &LST_CONFIG,
+                vault: &LST_VAULT,
+                hub_authority: &OTHER,
+                token_program: &TOKEN_PROGRAM,
+                pool_program: &crate::ID,
+                unwrap: None,
+                route: &[],
+            })
+            .unwrap();
+        let input = WithdrawInput {
+            unified_config: sdk(CONFIG),
+            lst_config: sdk(LST_CONFIG),
+            vault: sdk(LST_VAULT),
+            hub_authority: sdk(OTHER),
+            unified_sol_program: sdk(crate::ID),
+        };
+        let metas = sdk_metas(&withdraw);
+        assert_eq!(metas[..WithdrawAccounts::LEN], input.to_account_metas());
+        assert_eq!(
+            metas[WithdrawAccounts::LEN],
+            AccountMeta::new_readonly(sdk(TOKEN_PROGRAM), false)
+        );
+    }
 }
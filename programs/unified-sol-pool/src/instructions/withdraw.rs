@@ -2,23 +2,40 @@
 //!
 //! Pool validates amounts, approves hub_authority for the output tokens,
 //! updates accounting, and returns the protocol fee. Hub handles distribution.
+//!
+//! For WSOL with `WithdrawParams::unwrap` set, the pool pays the recipient's
+//! share out as native SOL itself and approves hub_authority only for the rest.
+//!
+//! With `WithdrawParams::route_automatically` set, a shortfall in the named
+//! vault is filled from the priority-list vaults passed as `route_accounts`,
+//! each approved for its own share. With `WithdrawParams::fallback_count` set,
+//! the shortfall is filled the same way from the caller's fallback vaults.
 
 use crate::{
     LstConfig, PoolType, UNIFIED_SOL_POOL_CONFIG_ADDRESS, UnifiedSolPoolConfig,
-    UnifiedSolPoolError, emit_event, events::UnifiedSolWithdrawalEvent, find_lst_config_pda,
-    gen_lst_config_seeds, gen_unified_sol_pool_config_seeds, read_token_account_balance,
+    UnifiedSolPoolError, emit_event,
+    events::UnifiedSolWithdrawalEvent,
+    find_lst_config_pda, find_lst_vault_pda, find_unwrap_account_pda, gen_lst_config_seeds,
+    gen_unified_sol_pool_config_seeds, gen_unwrap_account_seeds, read_token_account_balance,
+    state::{protocol_fee, route_fill},
+    utils::record_pending_rewards,
 };
 use panchor::prelude::*;
 use pinocchio::{
     ProgramResult, account_info::AccountInfo, instruction::Signer as PinocchioSigner,
-    program::set_return_data, pubkey::Pubkey, sysvars::Sysvar,
+    program::set_return_data, program_error::ProgramError, pubkey::Pubkey, sysvars::Sysvar,
 };
 use pinocchio_log::log;
-use pinocchio_token::instructions::Approve;
+use pinocchio_token::instructions::{Approve, CloseAccount, InitializeAccount3, Transfer};
 use zorb_pool_interface::{
-    BASIS_POINTS, PoolReturnData, WithdrawParams, validate_hub_authority, virtual_sol_to_tokens,
+    MAX_WITHDRAW_FALLBACK_VAULTS, MAX_WITHDRAW_ROUTE_VAULTS, RoutedWithdrawReturnData,
+    UnifiedSolOpResult, WithdrawParams, route_accounts, unwrap_accounts, validate_hub_authority,
+    virtual_sol_to_tokens,
 };
 
+/// Size of an SPL Token account
+const TOKEN_ACCOUNT_SIZE: usize = 165;
+
 /// Accounts for the Withdraw instruction.
 ///
 /// Pool approves hub_authority to transfer output tokens from vault.
@@ -30,6 +47,13 @@ use zorb_pool_interface::{
 /// 2. vault (mut) - LST vault token account (PDA derived from lst_config)
 /// 3. hub_authority - Hub authority PDA (delegate for transfers)
 /// 4. unified_sol_program - Program account for self-CPI
+///
+/// Remaining accounts: token_program, then for unwrapping withdrawals the
+/// `zorb_pool_interface::unwrap_accounts` (recipient, unwrap_account,
+/// native_mint, payer, system_program), or for routed withdrawals up to
+/// `MAX_WITHDRAW_ROUTE_VAULTS` `zorb_pool_interface::route_accounts` pairs
+/// (lst_config, vault) in priority order, or for fallback withdrawals
+/// `params.fallback_count` such pairs in the caller's order.
 #[derive(Accounts)]
 pub struct WithdrawAccounts<'info> {
     /// Unified SOL pool config account
@@ -61,12 +85,30 @@ pub struct WithdrawAccounts<'info> {
 /// 4. Validates: output_tokens = φ⁻¹(amount - fee) == expected_output
 /// 5. Approves hub_authority for output_tokens (total tokens to distribute)
 /// 6. Updates pool accounting
-/// 7. Returns { fee } via set_return_data
+/// 7. Returns `UnifiedSolOpResult` via set_return_data
 ///
 /// Note: Hub uses the approval to transfer tokens from vault:
 /// - (output_tokens - relayer_fee_tokens) to recipient
 /// - relayer_fee_tokens to relayer
 /// Protocol fee stays in vault as revenue.
+///
+/// When `params.unwrap` is set (WSOL only, else `UnwrapNotSupported`; the
+/// hub must sign as hub_authority), the pool pays `params.unwrap_amount` to the recipient system account as native
+/// SOL and approves hub_authority for only `output_tokens - unwrap_amount`.
+///
+/// When `params.route_automatically` is set, the named vault gives what it can
+/// spare (net of the WSOL buffer) and the routed vaults cover the rest of
+/// `amount - fee` at their own frozen rates, failing with
+/// `InsufficientRouteLiquidity` if they cannot. One withdrawal event is
+/// emitted per vault drawn from, and the per-vault approvals are returned as
+/// `RoutedWithdrawReturnData`.
+///
+/// When `params.fallback_count` is set instead, the same fill runs over the
+/// caller's fallback vaults in the order given. They need not be on the
+/// priority list but must be distinct from each other and the named LST.
+///
+/// Every event of one withdrawal carries the same `correlation_id` (the
+/// pool's withdrawal count after it).
 pub fn process_withdraw(ctx: Context<WithdrawAccounts>, instruction_data: &[u8]) -> ProgramResult {
     let WithdrawAccounts {
         unified_config,
@@ -97,17 +139,21 @@ pub fn process_withdraw(ctx: Context<WithdrawAccounts>, instruction_data: &[u8])
         return Err(UnifiedSolPoolError::InvalidLstConfig.into());
     }
 
-    // Parse instruction data (panchor strips discriminator, so we get raw params)
-    let params = WithdrawParams::from_bytes(instruction_data)
-        .ok_or(UnifiedSolPoolError::InvalidInstructionData)?;
+    let params = parse_withdraw_params(instruction_data)?;
+
+    // This pool keeps no fee-exempt list, so the hub must always price in fees
+    if params.expects_fee_exempt() {
+        log!("withdraw: fee exemption not supported");
+        return Err(UnifiedSolPoolError::InvalidInstructionData.into());
+    }
 
     // Read values from unified config (releases borrow after closure)
-    let (withdrawal_fee_rate, unified_bump) = unified_config.try_map(|config| {
+    let (withdrawal_fee_rate, reward_epoch, unified_bump) = unified_config.try_map(|config| {
         // Check pool is active
         if !config.is_active() {
             return Err(UnifiedSolPoolError::PoolPaused.into());
         }
-        Ok((config.withdrawal_fee_rate, config.bump))
+        Ok((config.withdrawal_fee_rate, config.reward_epoch, config.bump))
     })?;
 
     // Read values from LST config (releases borrow after closure)
@@ -116,14 +162,19 @@ pub fn process_withdraw(ctx: Context<WithdrawAccounts>, instruction_data: &[u8])
     // market value. This is bounded by MAX_RATE_CHANGE_BPS (0.5%) and the vault
     // always has sufficient tokens since deposits entered at the same stale rate.
     // See: deposit.rs and state.rs finalize_rewards() for full timing safety analysis.
-    let (exchange_rate, bump, lst_mint, pool_type): (u64, u8, Pubkey, u8) =
+    let current_slot = pinocchio::sysvars::clock::Clock::get()?.slot;
+    let (exchange_rate, withdrawal_fee_rate, bump, lst_mint, pool_type) =
         lst_config.try_map(|config| {
-            // Check LST is active
-            if !config.is_active() {
-                return Err(UnifiedSolPoolError::LstNotActive.into());
+            // Check LST allows withdrawals
+            if !config.withdrawals_enabled() {
+                return Err(UnifiedSolPoolError::LstWithdrawalsDisabled.into());
             }
+            // Refuse to price against a rate frozen too long ago
+            // (retired LSTs are exempt so they can always drain)
+            config.check_rate_fresh(current_slot)?;
             Ok((
                 config.harvested_exchange_rate,
+                config.effective_withdrawal_fee_rate(withdrawal_fee_rate),
                 config.bump,
                 config.lst_mint,
                 config.pool_type,
@@ -133,12 +184,8 @@ pub fn process_withdraw(ctx: Context<WithdrawAccounts>, instruction_data: &[u8])
     // params.amount is virtual SOL being withdrawn
     let virtual_sol = params.amount;
 
-    // Calculate protocol fee from withdrawal_fee_rate (basis points)
-    let fee = (virtual_sol as u128)
-        .checked_mul(withdrawal_fee_rate as u128)
-        .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?
-        .checked_div(BASIS_POINTS as u128)
-        .ok_or(UnifiedSolPoolError::ArithmeticOverflow)? as u64;
+    // Calculate protocol fee from the LST's effective withdrawal rate (basis points)
+    let fee = protocol_fee(virtual_sol, withdrawal_fee_rate)?;
 
     // Calculate output: (virtual_sol - fee) converted to tokens
     let net_virtual_sol = virtual_sol
@@ -154,6 +201,57 @@ pub fn process_withdraw(ctx: Context<WithdrawAccounts>, instruction_data: &[u8])
         return Err(UnifiedSolPoolError::ExpectedOutputMismatch.into());
     }
 
+    // Automatic routing or fallback LSTs: the named vault gives what it can
+    // spare and the routed vaults cover the rest
+    let route_order = match (params.routes_automatically(), params.has_fallbacks()) {
+        (false, false) => None,
+        (true, false) => Some(RouteOrder::Priority),
+        (false, true) if params.fallback_count as usize <= MAX_WITHDRAW_FALLBACK_VAULTS => {
+            Some(RouteOrder::Fallback)
+        }
+        _ => {
+            log!("withdraw: invalid routing options");
+            return Err(UnifiedSolPoolError::InvalidInstructionData.into());
+        }
+    };
+    let routed = route_order.is_some();
+    let (named_tokens, named_virtual_sol) = if routed {
+        if params.unwraps() {
+            log!("withdraw: routed withdrawals cannot unwrap");
+            return Err(UnifiedSolPoolError::InvalidInstructionData.into());
+        }
+        let vault_token_balance = lst_config.map(|config| config.vault_token_balance)?;
+        let available =
+            available_tokens(vault_acc, vault_token_balance, pool_type, unified_config)?;
+        route_fill(net_virtual_sol, available, exchange_rate)
+            .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?
+    } else {
+        (output_tokens, net_virtual_sol)
+    };
+
+    // Native SOL unwrap: the pool pays the recipient's share itself, leaving
+    // the hub approved for the remainder (the relayer fee)
+    let approve_amount = if params.unwraps() {
+        if pool_type != PoolType::Wsol as u8 {
+            log!("withdraw: unwrap requires WSOL");
+            return Err(UnifiedSolPoolError::UnwrapNotSupported.into());
+        }
+        // The pool moves the funds itself here, so only the hub may ask
+        if !hub_authority_acc.is_signer() {
+            log!("withdraw: unwrap requires hub_authority signature");
+            return Err(UnifiedSolPoolError::InvalidHubAuthority.into());
+        }
+        if params.unwrap_amount == 0 {
+            log!("withdraw: nothing to unwrap");
+            return Err(UnifiedSolPoolError::InvalidInstructionData.into());
+        }
+        output_tokens
+            .checked_sub(params.unwrap_amount)
+            .ok_or(UnifiedSolPoolError::InvalidInstructionData)?
+    } else {
+        named_tokens
+    };
+
     // WSOL buffer gating: ensure minimum WSOL liquidity is maintained
     // Only WSOL withdrawals are gated - other LST withdrawals don't affect WSOL liquidity
     if pool_type == PoolType::Wsol as u8 {
@@ -161,7 +259,7 @@ pub fn process_withdraw(ctx: Context<WithdrawAccounts>, instruction_data: &[u8])
         let required_buffer = unified_config.map(|c| c.calculate_required_buffer())??;
 
         let remaining = wsol_vault_balance
-            .checked_sub(output_tokens)
+            .checked_sub(named_tokens)
             .ok_or(UnifiedSolPoolError::InsufficientBalance)?;
 
         if remaining < required_buffer {
@@ -180,37 +278,71 @@ pub fn process_withdraw(ctx: Context<WithdrawAccounts>, instruction_data: &[u8])
 
     // Approve hub_authority for output tokens (hub handles distribution)
     // Hub will transfer: (output_tokens - relayer_fee) to recipient, relayer_fee to relayer
+    // (when unwrapping, only relayer_fee remains for the hub)
     Approve {
         source: vault_acc,
         delegate: hub_authority_acc,
         authority: lst_config_info,
-        amount: output_tokens,
+        amount: approve_amount,
     }
     .invoke_signed(&signer)?;
 
-    // Update LstConfig state: track vault token balance and virtual SOL value
-    // Subtract output_tokens from vault balance counter, net_virtual_sol from value
-    lst_config.try_inspect_mut(|config| {
-        // Decrement vault token balance counter
-        config.vault_token_balance = config
-            .vault_token_balance
-            .checked_sub(output_tokens)
-            .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?;
-        // Track virtual SOL value (will be recalculated atomically at finalize)
-        config.total_virtual_sol = config
-            .total_virtual_sol
-            .checked_sub(net_virtual_sol as u128)
-            .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?;
-        // Increment LST-specific withdrawal counter
-        config.withdrawal_count = config
-            .withdrawal_count
-            .checked_add(1)
+    if params.unwraps() {
+        let unwrap = ctx
+            .remaining_accounts
+            .get(1..1 + unwrap_accounts::COUNT)
+            .ok_or(UnifiedSolPoolError::InvalidUnwrapAccounts)?;
+        unwrap_to_recipient(
+            unwrap,
+            vault_acc,
+            lst_config_info,
+            &signer,
+            &lst_mint,
+            &params.recipient,
+            params.unwrap_amount,
+        )?;
+    }
+
+    // Routed vaults cover whatever the named vault could not
+    let route_draws = if let Some(order) = route_order {
+        let route = ctx
+            .remaining_accounts
+            .get(1..)
+            .ok_or(UnifiedSolPoolError::InvalidRouteAccounts)?;
+        if order == RouteOrder::Fallback
+            && route.len() != params.fallback_count as usize * route_accounts::COUNT
+        {
+            log!("withdraw: fallback account count mismatch");
+            return Err(UnifiedSolPoolError::InvalidRouteAccounts.into());
+        }
+        let shortfall = net_virtual_sol
+            .checked_sub(named_virtual_sol)
             .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?;
-        Ok(())
-    })?;
+        draw_route_vaults(
+            route,
+            unified_config,
+            hub_authority_acc,
+            &lst_mint,
+            order,
+            shortfall,
+            current_slot,
+        )?
+    } else {
+        [None; MAX_WITHDRAW_ROUTE_VAULTS]
+    };
+
+    // Update LstConfig state: track vault token balance and virtual SOL value
+    record_lst_withdrawal(lst_config, named_tokens, named_virtual_sol)?;
+
+    // The fee is attributed to the named LST, as in its withdrawal event
+    if fee > 0 {
+        record_pending_rewards(lst_config.account_info(), |pending| {
+            pending.add_withdrawal_fee(fee)
+        })?;
+    }
 
     // Update unified config state (using virtual SOL)
-    unified_config.try_inspect_mut(|config| {
+    let correlation_id = unified_config.try_map_mut(|config| {
         config.pending_withdrawals = config
             .pending_withdrawals
             .checked_add(virtual_sol as u128)
@@ -227,6 +359,7 @@ pub fn process_withdraw(ctx: Context<WithdrawAccounts>, instruction_data: &[u8])
             .total_virtual_sol
             .checked_sub(net_virtual_sol as u128)
             .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?;
+        config.park_virtual_sol_overflow();
 
         // Track protocol fees (fees are in virtual SOL terms)
         if fee > 0 {
@@ -247,7 +380,7 @@ pub fn process_withdraw(ctx: Context<WithdrawAccounts>, instruction_data: &[u8])
             .checked_add(1)
             .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?;
 
-        Ok(())
+        Ok(config.withdrawal_count)
     })?;
 
     // Emit withdrawal event FIRST (before set_return_data, since self-CPI clears return data)
@@ -256,24 +389,337 @@ pub fn process_withdraw(ctx: Context<WithdrawAccounts>, instruction_data: &[u8])
     let unified_seeds = gen_unified_sol_pool_config_seeds(&unified_bump_bytes);
     let unified_signer = PinocchioSigner::from(&unified_seeds);
 
+    // The named LST's event carries the fee; routed vaults report only their share
     emit_event(
         unified_config.account_info(),
         unified_sol_program,
-        unified_signer,
+        unified_signer.clone(),
         &UnifiedSolWithdrawalEvent {
             lst_mint,
+            lst_amount: named_tokens,
+            sol_value: named_virtual_sol
+                .checked_add(fee)
+                .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?,
+            fee,
+            exchange_rate,
+            slot: current_slot,
+            correlation_id,
+        },
+    )?;
+    for draw in route_draws.iter().flatten() {
+        emit_event(
+            unified_config.account_info(),
+            unified_sol_program,
+            unified_signer.clone(),
+            &UnifiedSolWithdrawalEvent {
+                lst_mint: draw.lst_mint,
+                lst_amount: draw.tokens,
+                sol_value: draw.virtual_sol,
+                fee: 0,
+                exchange_rate: draw.exchange_rate,
+                slot: current_slot,
+                correlation_id,
+            },
+        )?;
+    }
+
+    // Return the priced amounts via set_return_data (AFTER emit_event to avoid
+    // CPI overwriting it) so the hub can check them against its own
+    if routed {
+        let mut return_data = RoutedWithdrawReturnData {
+            fee,
+            ..Default::default()
+        };
+        return_data.outputs[0] = named_tokens;
+        for (output, draw) in return_data.outputs[1..].iter_mut().zip(&route_draws) {
+            *output = draw.map_or(0, |draw| draw.tokens);
+        }
+        set_return_data(bytemuck::bytes_of(&return_data));
+    } else {
+        let return_data = UnifiedSolOpResult {
             lst_amount: output_tokens,
             sol_value: virtual_sol,
             fee,
             exchange_rate,
-            slot: pinocchio::sysvars::clock::Clock::get()?.slot,
-            _padding: 0,
-        },
+            accumulator_epoch: reward_epoch,
+        };
+        set_return_data(bytemuck::bytes_of(&return_data));
+    }
+
+    Ok(())
+}
+
+/// Parse the hub's withdraw params from instruction data.
+///
+/// Panchor strips the discriminator; accepts the legacy or versioned layout.
+pub(crate) fn parse_withdraw_params(
+    instruction_data: &[u8],
+) -> Result<WithdrawParams, ProgramError> {
+    WithdrawParams::parse_any(instruction_data)
+        .ok_or(UnifiedSolPoolError::InvalidInstructionData.into())
+}
+
+/// Debit a withdrawal from an LST's vault balance counter and virtual SOL.
+fn record_lst_withdrawal(
+    lst_config: &AccountLoader<LstConfig>,
+    tokens: u64,
+    virtual_sol: u64,
+) -> ProgramResult {
+    lst_config.try_inspect_mut(|config| {
+        // Decrement vault token balance counter
+        config.vault_token_balance = config
+            .vault_token_balance
+            .checked_sub(tokens)
+            .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?;
+        // Track virtual SOL value (will be recalculated atomically at finalize)
+        config.total_virtual_sol = config
+            .total_virtual_sol
+            .checked_sub(virtual_sol as u128)
+            .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?;
+        // Withdrawals may bring the LST back under its cap
+        config.park_virtual_sol_overflow();
+        // Increment LST-specific withdrawal counter
+        config.withdrawal_count = config
+            .withdrawal_count
+            .checked_add(1)
+            .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?;
+        Ok(())
+    })
+}
+
+/// Tokens a vault can give up: its tracked balance, less the WSOL buffer.
+fn available_tokens(
+    vault: &AccountInfo,
+    vault_token_balance: u64,
+    pool_type: u8,
+    unified_config: &AccountLoader<UnifiedSolPoolConfig>,
+) -> Result<u64, ProgramError> {
+    if pool_type != PoolType::Wsol as u8 {
+        return Ok(vault_token_balance);
+    }
+    let wsol_vault_balance = read_token_account_balance(vault)?;
+    let required_buffer = unified_config.map(|c| c.calculate_required_buffer())??;
+    Ok(vault_token_balance.min(wsol_vault_balance.saturating_sub(required_buffer)))
+}
+
+/// How the vaults after the named one were chosen.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum RouteOrder {
+    /// Automatic routing: the pool's priority list, skipping the named LST
+    Priority,
+    /// Caller-named fallback LSTs, in the order given
+    Fallback,
+}
+
+/// One routed vault's share of a withdrawal.
+#[derive(Clone, Copy)]
+struct RouteDraw {
+    lst_mint: Pubkey,
+    tokens: u64,
+    virtual_sol: u64,
+    exchange_rate: u64,
+}
+
+/// Fill `shortfall` virtual SOL from the routed vaults, in order.
+///
+/// With `RouteOrder::Priority` each (lst_config, vault) pair must be the next
+/// LST on the priority list (skipping `named_mint`); with
+/// `RouteOrder::Fallback` any LST other than `named_mint` and the earlier
+/// pairs. Paused LSTs and LSTs with a stale rate are passed over; the rest
+/// are drawn down at their frozen rate, approved for hub_authority and
+/// debited. Fails with `InsufficientRouteLiquidity` if a shortfall remains.
+fn draw_route_vaults(
+    route: &[AccountInfo],
+    unified_config: &AccountLoader<UnifiedSolPoolConfig>,
+    hub_authority: &AccountInfo,
+    named_mint: &Pubkey,
+    order: RouteOrder,
+    mut shortfall: u64,
+    current_slot: u64,
+) -> Result<[Option<RouteDraw>; MAX_WITHDRAW_ROUTE_VAULTS], ProgramError> {
+    if !route.len().is_multiple_of(route_accounts::COUNT)
+        || route.len() / route_accounts::COUNT > MAX_WITHDRAW_ROUTE_VAULTS
+    {
+        log!("withdraw: invalid route account count");
+        return Err(UnifiedSolPoolError::InvalidRouteAccounts.into());
+    }
+
+    let mut draws = [None; MAX_WITHDRAW_ROUTE_VAULTS];
+    let mut cursor = 0;
+    let (legs, _) = route.as_chunks::<{ route_accounts::COUNT }>();
+    for (i, (draw, leg)) in draws.iter_mut().zip(legs).enumerate() {
+        let lst_config_acc = &leg[route_accounts::LST_CONFIG];
+        let vault = &leg[route_accounts::VAULT];
+
+        // Routed vaults must follow the priority list, without gaps
+        let expected_mint = match order {
+            RouteOrder::Priority => Some(
+                unified_config
+                    .map(|config| config.next_route_mint(&mut cursor, named_mint))?
+                    .ok_or(UnifiedSolPoolError::InvalidRouteAccounts)?,
+            ),
+            RouteOrder::Fallback => None,
+        };
+        if !lst_config_acc.is_owned_by(&crate::ID) {
+            log!("withdraw: route lst_config not owned by program");
+            return Err(UnifiedSolPoolError::InvalidRouteAccounts.into());
+        }
+        let lst_config = AccountLoader::<LstConfig>::new(lst_config_acc)?;
+        let (lst_mint, bump, exchange_rate, pool_type, vault_token_balance, usable) = lst_config
+            .map(|config| {
+                (
+                    config.lst_mint,
+                    config.bump,
+                    config.harvested_exchange_rate,
+                    config.pool_type,
+                    config.vault_token_balance,
+                    config.withdrawals_enabled() && config.check_rate_fresh(current_slot).is_ok(),
+                )
+            })?;
+        if expected_mint.is_some_and(|mint| mint != lst_mint)
+            || *lst_config_acc.key() != find_lst_config_pda(&lst_mint).0
+            || *vault.key() != find_lst_vault_pda(lst_config_acc.key()).0
+        {
+            log!("withdraw: route vault out of priority order");
+            return Err(UnifiedSolPoolError::InvalidRouteAccounts.into());
+        }
+        // Fallback vaults are the caller's choice; each may be drawn only once
+        if order == RouteOrder::Fallback
+            && (lst_mint == *named_mint
+                || legs[..i]
+                    .iter()
+                    .any(|prev| prev[route_accounts::LST_CONFIG].key() == lst_config_acc.key()))
+        {
+            log!("withdraw: duplicate fallback vault");
+            return Err(UnifiedSolPoolError::InvalidRouteAccounts.into());
+        }
+
+        if shortfall == 0 || !usable {
+            continue;
+        }
+        let available = available_tokens(vault, vault_token_balance, pool_type, unified_config)?;
+        let (tokens, virtual_sol) = route_fill(shortfall, available, exchange_rate)
+            .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?;
+        if virtual_sol == 0 {
+            continue;
+        }
+
+        let bump_bytes = [bump];
+        let seeds = gen_lst_config_seeds(&lst_mint, &bump_bytes);
+        Approve {
+            source: vault,
+            delegate: hub_authority,
+            authority: lst_config_acc,
+            amount: tokens,
+        }
+        .invoke_signed(&[PinocchioSigner::from(&seeds)])?;
+
+        record_lst_withdrawal(&lst_config, tokens, virtual_sol)?;
+        shortfall -= virtual_sol;
+        *draw = Some(RouteDraw {
+            lst_mint,
+            tokens,
+            virtual_sol,
+            exchange_rate,
+        });
+    }
+
+    if shortfall > 0 {
+        log!("withdraw: routed vaults cannot cover the withdrawal");
+        return Err(UnifiedSolPoolError::InsufficientRouteLiquidity.into());
+    }
+    Ok(draws)
+}
+
+/// Pay `amount` WSOL from the vault to `recipient` as native SOL.
+///
+/// Creates the temporary WSOL account (rent from the payer), moves the tokens
+/// into it and closes it into lst_config, then credits the recipient and
+/// refunds the payer's rent from those lamports. lst_config ends the
+/// instruction with the lamports it started with.
+fn unwrap_to_recipient(
+    accounts: &[AccountInfo],
+    vault: &AccountInfo,
+    lst_config: &AccountInfo,
+    lst_config_signer: &[PinocchioSigner],
+    lst_mint: &Pubkey,
+    recipient_key: &Pubkey,
+    amount: u64,
+) -> ProgramResult {
+    let recipient = &accounts[unwrap_accounts::RECIPIENT];
+    let unwrap_account = &accounts[unwrap_accounts::UNWRAP_ACCOUNT];
+    let native_mint = &accounts[unwrap_accounts::NATIVE_MINT];
+    let payer = &accounts[unwrap_accounts::PAYER];
+    let system_program = &accounts[unwrap_accounts::SYSTEM_PROGRAM];
+
+    // Lamports go to a plain wallet, never to a token or program account
+    if recipient.key() != recipient_key || !recipient.is_owned_by(&pinocchio_system::ID) {
+        log!("withdraw: unwrap recipient must be a system account");
+        return Err(UnifiedSolPoolError::InvalidUnwrapAccounts.into());
+    }
+    if native_mint.key() != lst_mint {
+        log!("withdraw: invalid native mint");
+        return Err(UnifiedSolPoolError::InvalidUnwrapAccounts.into());
+    }
+    let (expected_unwrap_account, unwrap_bump) = find_unwrap_account_pda(lst_config.key());
+    if *unwrap_account.key() != expected_unwrap_account {
+        log!("withdraw: invalid unwrap account PDA");
+        return Err(UnifiedSolPoolError::InvalidUnwrapAccounts.into());
+    }
+
+    let unwrap_bump_bytes = [unwrap_bump];
+    let unwrap_seeds = gen_unwrap_account_seeds(lst_config.key(), &unwrap_bump_bytes);
+    unwrap_account.create_pda_account_with_space(
+        payer,
+        &unwrap_seeds,
+        system_program,
+        TOKEN_ACCOUNT_SIZE,
+        &pinocchio_token::ID,
     )?;
+    let rent_lamports = unwrap_account.lamports();
+
+    InitializeAccount3 {
+        account: unwrap_account,
+        mint: native_mint,
+        owner: lst_config.key(),
+    }
+    .invoke()?;
+
+    Transfer {
+        from: vault,
+        to: unwrap_account,
+        authority: lst_config,
+        amount,
+    }
+    .invoke_signed(lst_config_signer)?;
 
-    // Return fee via set_return_data (AFTER emit_event to avoid CPI overwriting it)
-    let return_data = PoolReturnData { fee };
-    set_return_data(bytemuck::bytes_of(&return_data));
+    CloseAccount {
+        account: unwrap_account,
+        destination: lst_config,
+        authority: lst_config,
+    }
+    .invoke_signed(lst_config_signer)?;
+
+    // lst_config now holds rent + amount extra lamports; pass them on
+    let recipient_lamports = recipient
+        .lamports()
+        .checked_add(amount)
+        .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?;
+    let payer_lamports = payer
+        .lamports()
+        .checked_add(rent_lamports)
+        .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?;
+    let lst_config_lamports = lst_config
+        .lamports()
+        .checked_sub(amount)
+        .and_then(|lamports| lamports.checked_sub(rent_lamports))
+        .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?;
+
+    unsafe {
+        *lst_config.borrow_mut_lamports_unchecked() = lst_config_lamports;
+        *recipient.borrow_mut_lamports_unchecked() = recipient_lamports;
+        *payer.borrow_mut_lamports_unchecked() = payer_lamports;
+    }
 
     Ok(())
 }
@@ -31,14 +31,22 @@ pub mod errors;
 pub mod events;
 pub mod instructions;
 pub mod pda;
+pub mod rate_source;
 pub mod state;
 pub mod utils;
 
 // Error and event types
 pub use errors::UnifiedSolPoolError;
 pub use events::{
-    AppreciationHarvestedEvent, EventType, ExchangeRateUpdatedEvent, UnifiedSolDepositEvent,
-    UnifiedSolRewardsFinalizedEvent, UnifiedSolWithdrawalEvent, emit_event,
+    AppreciationCommissionUpdatedEvent, AppreciationHarvestedEvent, CommissionWithdrawnEvent,
+    EpochModeUpdatedEvent, EventType, ExchangeRateUpdatedEvent, KeeperAddedEvent,
+    KeeperAllowlistEnabledUpdatedEvent, KeeperRemovedEvent, LstConfigClosedEvent,
+    LstConfigMigratedEvent, LstConfigRetiredEvent, LstFinalizedEvent, LstRateBoundsUpdatedEvent,
+    LstRateJumpApprovedEvent, LstVirtualSolCapUpdatedEvent, ManualRateAppliedEvent,
+    ManualRateCancelledEvent, ManualRateDelayUpdatedEvent, ManualRateProposedEvent,
+    MinHarvestLamportsUpdatedEvent, StakePoolProgramAddedEvent, StakePoolProgramRemovedEvent,
+    UnifiedSolDepositEvent, UnifiedSolRewardsFinalizedEvent, UnifiedSolVirtualSolCapUpdatedEvent,
+    UnifiedSolWithdrawalEvent, WithdrawPriorityUpdatedEvent, emit_event,
 };
 
 // Instruction enum for panchor dispatch
@@ -49,7 +57,12 @@ pub use pda::*;
 
 // State types and constants
 // Note: PDA seeds (LST_CONFIG_SEED, etc.) come from pda::* above
-pub use state::{LstConfig, PoolType, UNIFIED_SOL_ASSET_ID, UnifiedSolPoolConfig};
+pub use state::{
+    EpochMode, LST_CONFIG_ACCOUNT_SIZE, LST_CONFIG_V1_SIZE, LST_CONFIG_VERSION, LST_MODE_ALL,
+    LST_MODE_DEPOSITS_ENABLED, LST_MODE_WITHDRAWALS_ENABLED, LstConfig, LstManualRate,
+    LstPendingRewards, LstRateHistory, MAX_KEEPERS, PoolType, RATE_HISTORY_LEN, RateHistoryEntry,
+    UNIFIED_SOL_ASSET_ID, UnifiedSolPoolConfig,
+};
 
 // Utility functions
 pub use utils::read_token_account_balance;
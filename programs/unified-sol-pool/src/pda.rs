@@ -39,4 +39,13 @@ pub enum UnifiedSolPoolPdas {
         /// The LST config PDA
         lst_config: Pubkey,
     },
+
+    /// Temporary WSOL account for native SOL unwrap - per LST config
+    /// Seeds: ["unwrap", lst_config]
+    /// Created and closed within a single unwrapping withdrawal
+    #[seeds("unwrap")]
+    UnwrapAccount {
+        /// The LST config PDA
+        lst_config: Pubkey,
+    },
 }
@@ -0,0 +1,289 @@
+//! Marinade rate source.
+//!
+//! mSOL is not an SPL stake pool token; its backing is tracked in Marinade's
+//! singleton `State` account. The rate is Marinade's own
+//! `total_virtual_staked_lamports / msol_supply`:
+//!
+//! ```text
+//! total_virtual_staked_lamports = total_active_balance
+//!                               + delayed_unstake_cooling_down
+//!                               + emergency_cooling_down
+//!                               + available_reserve_balance
+//!                               - circulating_ticket_balance
+//! rate = total_virtual_staked_lamports * 1e9 / msol_supply
+//! ```
+//!
+//! # Layout
+//!
+//! `State` is an Anchor account (8-byte discriminator) with a fixed-size
+//! prefix; later Marinade versions only append fields. Offsets of the fields
+//! read here:
+//!
+//! | Offset | Size | Field                                      |
+//! |--------|------|--------------------------------------------|
+//! | 0      | 8    | Anchor discriminator (`account:State`)     |
+//! | 8      | 32   | msol_mint                                  |
+//! | 226    | 8    | stake_system.delayed_unstake_cooling_down  |
+//! | 244    | 8    | stake_system.last_stake_delta_epoch        |
+//! | 376    | 8    | validator_system.total_active_balance      |
+//! | 496    | 8    | available_reserve_balance                  |
+//! | 504    | 8    | msol_supply                                |
+//! | 528    | 8    | circulating_ticket_balance                 |
+//! | 568    | 8    | emergency_cooling_down                     |
+
+use super::{RateSource, read_pubkey, read_u64, scaled_rate};
+use crate::UnifiedSolPoolError;
+use pinocchio::pubkey::Pubkey;
+use pinocchio_log::log;
+
+/// Marinade liquid staking program ID
+pub const MARINADE_PROGRAM_ID: Pubkey =
+    pinocchio_pubkey::pubkey!("MarBmsSgKXdrN1egZf5sqe1TMai9K1rChYNDJgjq7aD");
+
+/// Anchor discriminator of `State` (`sha256("account:State")[..8]`)
+pub const STATE_DISCRIMINATOR: [u8; 8] = [216, 146, 107, 94, 104, 75, 182, 177];
+
+/// Offset of `msol_mint`
+pub const MSOL_MINT_OFFSET: usize = 8;
+
+/// Offset of `stake_system.delayed_unstake_cooling_down`
+pub const DELAYED_UNSTAKE_COOLING_DOWN_OFFSET: usize = 226;
+
+/// Offset of `stake_system.last_stake_delta_epoch`
+pub const LAST_STAKE_DELTA_EPOCH_OFFSET: usize = 244;
+
+/// Offset of `validator_system.total_active_balance`
+pub const TOTAL_ACTIVE_BALANCE_OFFSET: usize = 376;
+
+/// Offset of `available_reserve_balance`
+pub const AVAILABLE_RESERVE_BALANCE_OFFSET: usize = 496;
+
+/// Offset of `msol_supply`
+pub const MSOL_SUPPLY_OFFSET: usize = 504;
+
+/// Offset of `circulating_ticket_balance`
+pub const CIRCULATING_TICKET_BALANCE_OFFSET: usize = 528;
+
+/// Offset of `emergency_cooling_down`
+pub const EMERGENCY_COOLING_DOWN_OFFSET: usize = 568;
+
+/// Minimum account length covering every field read here
+pub const STATE_MIN_LEN: usize = EMERGENCY_COOLING_DOWN_OFFSET + 8;
+
+/// The fields of Marinade's `State` needed to price mSOL.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MarinadeState {
+    /// mSOL mint
+    pub msol_mint: Pubkey,
+    /// Lamports in unstake tickets still cooling down
+    pub delayed_unstake_cooling_down: u64,
+    /// Epoch of the last stake-delta crank
+    pub last_stake_delta_epoch: u64,
+    /// Lamports actively staked with validators
+    pub total_active_balance: u64,
+    /// Lamports in the reserve
+    pub available_reserve_balance: u64,
+    /// mSOL in circulation
+    pub msol_supply: u64,
+    /// Lamports owed to unclaimed delayed-unstake tickets
+    pub circulating_ticket_balance: u64,
+    /// Lamports in emergency unstakes still cooling down
+    pub emergency_cooling_down: u64,
+}
+
+impl MarinadeState {
+    /// Marinade's `total_virtual_staked_lamports`: everything under the
+    /// program's control minus what is already owed to ticket holders.
+    pub fn total_virtual_staked_lamports(&self) -> Result<u64, UnifiedSolPoolError> {
+        let under_control = self
+            .total_active_balance
+            .checked_add(self.delayed_unstake_cooling_down)
+            .and_then(|v| v.checked_add(self.emergency_cooling_down))
+            .and_then(|v| v.checked_add(self.available_reserve_balance))
+            .ok_or(UnifiedSolPoolError::ArithmeticOverflow)?;
+        Ok(under_control.saturating_sub(self.circulating_ticket_balance))
+    }
+}
+
+impl RateSource for MarinadeState {
+    const PROGRAM_ID: Pubkey = MARINADE_PROGRAM_ID;
+
+    fn from_bytes(data: &[u8]) -> Result<Self, UnifiedSolPoolError> {
+        if data.len() < STATE_MIN_LEN {
+            log!("marinade: data too short");
+            return Err(UnifiedSolPoolError::InvalidStakePool);
+        }
+
+        // Marinade also owns ticket, stake list, and validator list accounts
+        if data[..8] != STATE_DISCRIMINATOR {
+            log!("marinade: account is not the Marinade state");
+            return Err(UnifiedSolPoolError::InvalidStakePool);
+        }
+
+        Ok(Self {
+            msol_mint: read_pubkey(data, MSOL_MINT_OFFSET),
+            delayed_unstake_cooling_down: read_u64(data, DELAYED_UNSTAKE_COOLING_DOWN_OFFSET),
+            last_stake_delta_epoch: read_u64(data, LAST_STAKE_DELTA_EPOCH_OFFSET),
+            total_active_balance: read_u64(data, TOTAL_ACTIVE_BALANCE_OFFSET),
+            available_reserve_balance: read_u64(data, AVAILABLE_RESERVE_BALANCE_OFFSET),
+            msol_supply: read_u64(data, MSOL_SUPPLY_OFFSET),
+            circulating_ticket_balance: read_u64(data, CIRCULATING_TICKET_BALANCE_OFFSET),
+            emergency_cooling_down: read_u64(data, EMERGENCY_COOLING_DOWN_OFFSET),
+        })
+    }
+
+    fn lst_mint(&self) -> &Pubkey {
+        &self.msol_mint
+    }
+
+    /// `total_virtual_staked_lamports * 1e9 / msol_supply`
+    fn exchange_rate(&self) -> Result<u64, UnifiedSolPoolError> {
+        scaled_rate(self.total_virtual_staked_lamports()?, self.msol_supply)
+    }
+
+    /// The stake-delta crank ran this epoch or the previous one.
+    ///
+    /// Marinade has no per-epoch balance update like SPL's
+    /// `UpdateStakePoolBalance`; the stake delta runs once near the end of
+    /// each epoch, so for most of an epoch the freshest state carries the
+    /// previous epoch.
+    fn is_fresh(&self, current_epoch: u64) -> bool {
+        self.last_stake_delta_epoch.saturating_add(1) >= current_epoch
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// mSOL mint
+    const MSOL_MINT: Pubkey =
+        pinocchio_pubkey::pubkey!("mSoLzYCxHdYgdzU16g5QSh3i5K3z3KZK7ytfqcJm7So");
+
+    /// Encode a Marinade `State` field by field in declaration order, followed
+    /// by the appended fields of later versions as zeros.
+    fn marinade_state_fixture(state: &MarinadeState) -> Vec<u8> {
+        let list = |data: &mut Vec<u8>, account: u8, count: u32| {
+            data.extend_from_slice(&[account; 32]); // account
+            data.extend_from_slice(&8u32.to_le_bytes()); // item_size
+            data.extend_from_slice(&count.to_le_bytes()); // count
+            data.extend_from_slice(&[0u8; 32]); // _reserved1
+            data.extend_from_slice(&0u32.to_le_bytes()); // _reserved2
+        };
+
+        let mut data = STATE_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&state.msol_mint);
+        data.extend_from_slice(&[1u8; 32]); // admin_authority
+        data.extend_from_slice(&[2u8; 32]); // operational_sol_account
+        data.extend_from_slice(&[3u8; 32]); // treasury_msol_account
+        data.push(254); // reserve_bump_seed
+        data.push(253); // msol_mint_authority_bump_seed
+        data.extend_from_slice(&2_039_280u64.to_le_bytes()); // rent_exempt_for_token_acc
+        data.extend_from_slice(&600u32.to_le_bytes()); // reward_fee
+
+        // stake_system
+        list(&mut data, 4, 1_200);
+        data.extend_from_slice(&state.delayed_unstake_cooling_down.to_le_bytes());
+        data.push(255); // stake_deposit_bump_seed
+        data.push(255); // stake_withdraw_bump_seed
+        data.extend_from_slice(&18_000u64.to_le_bytes()); // slots_for_stake_delta
+        data.extend_from_slice(&state.last_stake_delta_epoch.to_le_bytes());
+        data.extend_from_slice(&1_000_000_000u64.to_le_bytes()); // min_stake
+        data.extend_from_slice(&0u32.to_le_bytes()); // extra_stake_delta_runs
+
+        // validator_system
+        list(&mut data, 5, 450);
+        data.extend_from_slice(&[6u8; 32]); // manager_authority
+        data.extend_from_slice(&1_000_000u32.to_le_bytes()); // total_validator_score
+        data.extend_from_slice(&state.total_active_balance.to_le_bytes());
+        data.push(0); // auto_add_validator_enabled
+
+        // liq_pool
+        data.extend_from_slice(&[7u8; 32]); // lp_mint
+        data.extend_from_slice(&[255, 255, 255]); // bump seeds
+        data.extend_from_slice(&[8u8; 32]); // msol_leg
+        data.extend_from_slice(&[0u8; 8 + 4 + 4 + 4 + 8 + 8 + 8]); // targets, fees, supply
+
+        data.extend_from_slice(&state.available_reserve_balance.to_le_bytes());
+        data.extend_from_slice(&state.msol_supply.to_le_bytes());
+        data.extend_from_slice(&0u64.to_le_bytes()); // msol_price (not read)
+        data.extend_from_slice(&2_500u64.to_le_bytes()); // circulating_ticket_count
+        data.extend_from_slice(&state.circulating_ticket_balance.to_le_bytes());
+        data.extend_from_slice(&[0u8; 8 * 4]); // lent_from_reserve .. staking_sol_cap
+        data.extend_from_slice(&state.emergency_cooling_down.to_le_bytes());
+        assert_eq!(data.len(), STATE_MIN_LEN);
+        data.resize(1_000, 0);
+        data
+    }
+
+    /// Mainnet-scale mSOL state (~1.31 SOL per mSOL)
+    fn msol_like_state() -> MarinadeState {
+        MarinadeState {
+            msol_mint: MSOL_MINT,
+            delayed_unstake_cooling_down: 41_000_000_000_000,
+            last_stake_delta_epoch: 811,
+            total_active_balance: 6_420_000_000_000_000,
+            available_reserve_balance: 96_000_000_000_000,
+            msol_supply: 4_990_000_000_000_000,
+            circulating_ticket_balance: 12_000_000_000_000,
+            emergency_cooling_down: 0,
+        }
+    }
+
+    #[test]
+    fn test_parse_msol_state() {
+        let expected = msol_like_state();
+        let state = MarinadeState::from_bytes(&marinade_state_fixture(&expected)).unwrap();
+        assert_eq!(state, expected);
+
+        // 6_420_000 + 41_000 + 96_000 - 12_000 = 6_545_000 SOL behind 4_990_000 mSOL
+        assert_eq!(
+            state.total_virtual_staked_lamports().unwrap(),
+            6_545_000_000_000_000
+        );
+        assert_eq!(state.exchange_rate().unwrap(), 1_311_623_246);
+        assert_eq!(state.lst_mint(), &MSOL_MINT);
+    }
+
+    #[test]
+    fn test_freshness_allows_previous_epoch() {
+        let state = msol_like_state();
+        assert!(state.is_fresh(811));
+        assert!(state.is_fresh(812));
+        assert!(!state.is_fresh(813));
+    }
+
+    #[test]
+    fn test_rejects_truncated_state() {
+        let data = marinade_state_fixture(&msol_like_state());
+        assert!(MarinadeState::from_bytes(&data[..STATE_MIN_LEN]).is_ok());
+        assert_eq!(
+            MarinadeState::from_bytes(&data[..STATE_MIN_LEN - 1]),
+            Err(UnifiedSolPoolError::InvalidStakePool)
+        );
+        assert_eq!(
+            MarinadeState::from_bytes(&data[..8]),
+            Err(UnifiedSolPoolError::InvalidStakePool)
+        );
+    }
+
+    #[test]
+    fn test_rejects_other_marinade_accounts() {
+        let mut data = marinade_state_fixture(&msol_like_state());
+        data[..8].copy_from_slice(b"staker__");
+        assert_eq!(
+            MarinadeState::from_bytes(&data),
+            Err(UnifiedSolPoolError::InvalidStakePool)
+        );
+    }
+
+    #[test]
+    fn test_empty_supply_rejected() {
+        let mut state = msol_like_state();
+        state.msol_supply = 0;
+        assert_eq!(
+            state.exchange_rate(),
+            Err(UnifiedSolPoolError::InvalidExchangeRate)
+        );
+    }
+}
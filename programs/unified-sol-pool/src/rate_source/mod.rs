@@ -0,0 +1,139 @@
+//! On-chain exchange-rate sources for LSTs.
+//!
+//! Each non-WSOL `PoolType` prices its LST from an account owned by the
+//! issuing program:
+//!
+//! - [`spl_stake_pool`]: SPL `StakePool` (jitoSOL, vSOL, ...)
+//! - [`marinade`]: Marinade `State` (mSOL)
+//!
+//! SPL-layout pools are not tied to one program: Sanctum single-validator
+//! LSTs run their own deployments of the stake pool program. Which owners are
+//! trusted for `PoolType::SplStakePool` is the admin-managed allowlist on
+//! `UnifiedSolPoolConfig`; each `LstConfig` records the program it was
+//! validated against.
+//!
+//! Adding a source means implementing [`RateSource`] for its account type and
+//! routing its `PoolType` to it in `init_lst_config` and
+//! `harvest_lst_appreciation`; owner, mint, and freshness checks come from
+//! [`load_for_mint`] and [`read_rate`].
+
+pub mod marinade;
+pub mod spl_stake_pool;
+
+use crate::UnifiedSolPoolError;
+use pinocchio::{account_info::AccountInfo, pubkey::Pubkey};
+use pinocchio_log::log;
+
+/// An account an LST's exchange rate can be derived from.
+pub trait RateSource: Sized {
+    /// Canonical program that owns the source account
+    const PROGRAM_ID: Pubkey;
+
+    /// Parse the source account's data.
+    ///
+    /// Fails with `InvalidStakePool` if the data is not this source's account.
+    fn from_bytes(data: &[u8]) -> Result<Self, UnifiedSolPoolError>;
+
+    /// LST mint the account prices
+    fn lst_mint(&self) -> &Pubkey;
+
+    /// Exchange rate scaled by `LstConfig::RATE_PRECISION` (lamports per 1e9
+    /// LST base units).
+    fn exchange_rate(&self) -> Result<u64, UnifiedSolPoolError>;
+
+    /// Returns true if the account reflects rewards up to `current_epoch`.
+    fn is_fresh(&self, current_epoch: u64) -> bool;
+
+    /// Load a source account owned by `program`.
+    ///
+    /// Fails with `InvalidStakePool` if the owner differs (a look-alike account
+    /// from any other program could report an arbitrary rate). Callers pass
+    /// `PROGRAM_ID` or a program the admin has allowlisted.
+    fn load(account: &AccountInfo, program: &Pubkey) -> Result<Self, UnifiedSolPoolError> {
+        if !account.is_owned_by(program) {
+            log!("rate_source: account not owned by the rate source program");
+            return Err(UnifiedSolPoolError::InvalidStakePool);
+        }
+
+        let data = account
+            .try_borrow_data()
+            .map_err(|_| UnifiedSolPoolError::InvalidStakePool)?;
+        Self::from_bytes(&data)
+    }
+}
+
+/// Load a rate source and check it prices `lst_mint`.
+///
+/// Fails with `StakePoolMintMismatch` if the source is for another mint.
+pub fn load_for_mint<S: RateSource>(
+    account: &AccountInfo,
+    program: &Pubkey,
+    lst_mint: &Pubkey,
+) -> Result<S, UnifiedSolPoolError> {
+    let source = S::load(account, program)?;
+    if source.lst_mint() != lst_mint {
+        log!("rate_source: mint mismatch");
+        return Err(UnifiedSolPoolError::StakePoolMintMismatch);
+    }
+    Ok(source)
+}
+
+/// Read the current exchange rate for `lst_mint` from a rate source.
+///
+/// On top of [`load_for_mint`], fails with `StaleStakePoolRate` if the source
+/// has not caught up with `current_epoch`.
+pub fn read_rate<S: RateSource>(
+    account: &AccountInfo,
+    program: &Pubkey,
+    lst_mint: &Pubkey,
+    current_epoch: u64,
+) -> Result<u64, UnifiedSolPoolError> {
+    let source = load_for_mint::<S>(account, program, lst_mint)?;
+
+    // M-02 AUDIT FIX: At epoch boundaries, stake rewards haven't been
+    // distributed yet, so the source's lamport totals may be stale until its
+    // crank runs. Requiring freshness ensures we read accurate exchange rates.
+    if !source.is_fresh(current_epoch) {
+        log!(
+            "rate_source: stale rate data (current epoch {})",
+            current_epoch
+        );
+        return Err(UnifiedSolPoolError::StaleStakePoolRate);
+    }
+
+    source.exchange_rate()
+}
+
+/// Compute `lamports * RATE_PRECISION / supply` with checked math.
+///
+/// Fails with `InvalidExchangeRate` for an empty pool and with
+/// `ArithmeticOverflow` if the rate does not fit in a u64.
+pub(crate) fn scaled_rate(lamports: u64, supply: u64) -> Result<u64, UnifiedSolPoolError> {
+    if supply == 0 {
+        log!("rate_source: token supply is zero");
+        return Err(UnifiedSolPoolError::InvalidExchangeRate);
+    }
+
+    (lamports as u128)
+        .checked_mul(crate::LstConfig::RATE_PRECISION as u128)
+        .and_then(|v| v.checked_div(supply as u128))
+        .and_then(|v| u64::try_from(v).ok())
+        .ok_or_else(|| {
+            log!("rate_source: rate calculation overflow");
+            UnifiedSolPoolError::ArithmeticOverflow
+        })
+}
+
+/// Read a pubkey at `offset`; the caller has checked the length.
+pub(crate) fn read_pubkey(data: &[u8], offset: usize) -> Pubkey {
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&data[offset..offset + 32]);
+    key
+}
+
+/// Read a little-endian u64 at `offset`; the caller has checked the length.
+pub(crate) fn read_u64(data: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&data[offset..offset + 8]);
+    u64::from_le_bytes(bytes)
+}
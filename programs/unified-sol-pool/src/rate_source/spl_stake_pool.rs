@@ -0,0 +1,201 @@
+//! SPL Stake Pool rate source.
+//!
+//! LSTs backed by an SPL stake pool (jitoSOL, vSOL, ...) derive their exchange
+//! rate from the pool's own accounting rather than from caller input:
+//!
+//! ```text
+//! rate = total_lamports * 1e9 / pool_token_supply
+//! ```
+//!
+//! # Layout
+//!
+//! `StakePool` is Borsh-encoded by the SPL Stake Pool program. The fields read
+//! here all precede the first variable-length field (`lockup`), so their
+//! offsets are fixed:
+//!
+//! | Offset | Size | Field                     |
+//! |--------|------|---------------------------|
+//! | 0      | 1    | account_type (1 = StakePool) |
+//! | 1      | 32   | manager                   |
+//! | 33     | 32   | staker                    |
+//! | 65     | 32   | stake_deposit_authority   |
+//! | 97     | 1    | stake_withdraw_bump_seed  |
+//! | 98     | 32   | validator_list            |
+//! | 130    | 32   | reserve_stake             |
+//! | 162    | 32   | pool_mint                 |
+//! | 194    | 32   | manager_fee_account       |
+//! | 226    | 32   | token_program_id          |
+//! | 258    | 8    | total_lamports            |
+//! | 266    | 8    | pool_token_supply         |
+//! | 274    | 8    | last_update_epoch         |
+
+use super::{RateSource, read_pubkey, read_u64, scaled_rate};
+use crate::UnifiedSolPoolError;
+use pinocchio::pubkey::Pubkey;
+use pinocchio_log::log;
+
+/// SPL Stake Pool Program ID
+/// This is the canonical SPL Stake Pool program that manages stake pools for most LSTs
+pub const SPL_STAKE_POOL_PROGRAM_ID: Pubkey =
+    pinocchio_pubkey::pubkey!("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
+
+/// `AccountType::StakePool` tag (0 = uninitialized, 2 = validator list)
+pub const STAKE_POOL_ACCOUNT_TYPE: u8 = 1;
+
+/// Offset of `pool_mint`
+pub const POOL_MINT_OFFSET: usize = 162;
+
+/// Offset of `total_lamports`
+pub const TOTAL_LAMPORTS_OFFSET: usize = 258;
+
+/// Offset of `pool_token_supply`
+pub const POOL_TOKEN_SUPPLY_OFFSET: usize = 266;
+
+/// Offset of `last_update_epoch`
+pub const LAST_UPDATE_EPOCH_OFFSET: usize = 274;
+
+/// Minimum account length covering every field read here
+pub const STAKE_POOL_MIN_LEN: usize = LAST_UPDATE_EPOCH_OFFSET + 8;
+
+/// The fields of an SPL `StakePool` needed to price its pool token.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StakePoolState {
+    /// LST mint issued by the pool
+    pub pool_mint: Pubkey,
+    /// Total SOL under management, in lamports
+    pub total_lamports: u64,
+    /// Pool tokens in circulation
+    pub pool_token_supply: u64,
+    /// Epoch of the pool's last `UpdateStakePoolBalance`
+    pub last_update_epoch: u64,
+}
+
+impl RateSource for StakePoolState {
+    const PROGRAM_ID: Pubkey = SPL_STAKE_POOL_PROGRAM_ID;
+
+    fn from_bytes(data: &[u8]) -> Result<Self, UnifiedSolPoolError> {
+        if data.len() < STAKE_POOL_MIN_LEN {
+            log!("spl_stake_pool: data too short");
+            return Err(UnifiedSolPoolError::InvalidStakePool);
+        }
+
+        if data[0] != STAKE_POOL_ACCOUNT_TYPE {
+            log!("spl_stake_pool: account is not a stake pool");
+            return Err(UnifiedSolPoolError::InvalidStakePool);
+        }
+
+        Ok(Self {
+            pool_mint: read_pubkey(data, POOL_MINT_OFFSET),
+            total_lamports: read_u64(data, TOTAL_LAMPORTS_OFFSET),
+            pool_token_supply: read_u64(data, POOL_TOKEN_SUPPLY_OFFSET),
+            last_update_epoch: read_u64(data, LAST_UPDATE_EPOCH_OFFSET),
+        })
+    }
+
+    fn lst_mint(&self) -> &Pubkey {
+        &self.pool_mint
+    }
+
+    /// `total_lamports * 1e9 / pool_token_supply`
+    fn exchange_rate(&self) -> Result<u64, UnifiedSolPoolError> {
+        scaled_rate(self.total_lamports, self.pool_token_supply)
+    }
+
+    /// `UpdateStakePoolBalance` has run this epoch.
+    fn is_fresh(&self, current_epoch: u64) -> bool {
+        self.last_update_epoch == current_epoch
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// jitoSOL mint
+    const JITOSOL_MINT: Pubkey =
+        pinocchio_pubkey::pubkey!("J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn");
+
+    /// Borsh-encode a `StakePool` prefix field by field, followed by the
+    /// variable-length tail (lockup, fees, ...) as zeros.
+    fn stake_pool_fixture(
+        pool_mint: &Pubkey,
+        total_lamports: u64,
+        pool_token_supply: u64,
+        last_update_epoch: u64,
+    ) -> Vec<u8> {
+        let mut data = vec![STAKE_POOL_ACCOUNT_TYPE];
+        data.extend_from_slice(&[1u8; 32]); // manager
+        data.extend_from_slice(&[2u8; 32]); // staker
+        data.extend_from_slice(&[3u8; 32]); // stake_deposit_authority
+        data.push(255); // stake_withdraw_bump_seed
+        data.extend_from_slice(&[4u8; 32]); // validator_list
+        data.extend_from_slice(&[5u8; 32]); // reserve_stake
+        data.extend_from_slice(pool_mint);
+        data.extend_from_slice(&[6u8; 32]); // manager_fee_account
+        data.extend_from_slice(&[7u8; 32]); // token_program_id
+        data.extend_from_slice(&total_lamports.to_le_bytes());
+        data.extend_from_slice(&pool_token_supply.to_le_bytes());
+        data.extend_from_slice(&last_update_epoch.to_le_bytes());
+        data.resize(611, 0);
+        data
+    }
+
+    #[test]
+    fn test_parse_jitosol_like_pool() {
+        // ~14.2M SOL backing ~11.9M jitoSOL
+        let data = stake_pool_fixture(
+            &JITOSOL_MINT,
+            14_200_000_000_000_000,
+            11_900_000_000_000_000,
+            812,
+        );
+
+        let state = StakePoolState::from_bytes(&data).unwrap();
+        assert_eq!(state.pool_mint, JITOSOL_MINT);
+        assert_eq!(state.total_lamports, 14_200_000_000_000_000);
+        assert_eq!(state.pool_token_supply, 11_900_000_000_000_000);
+        assert_eq!(state.last_update_epoch, 812);
+        assert_eq!(state.exchange_rate().unwrap(), 1_193_277_310);
+    }
+
+    #[test]
+    fn test_rejects_non_stake_pool_data() {
+        let mut data = stake_pool_fixture(&JITOSOL_MINT, 1, 1, 0);
+
+        // Validator list and uninitialized accounts share the owner
+        data[0] = 2;
+        assert_eq!(
+            StakePoolState::from_bytes(&data),
+            Err(UnifiedSolPoolError::InvalidStakePool)
+        );
+        data[0] = 0;
+        assert_eq!(
+            StakePoolState::from_bytes(&data),
+            Err(UnifiedSolPoolError::InvalidStakePool)
+        );
+
+        data[0] = STAKE_POOL_ACCOUNT_TYPE;
+        assert_eq!(
+            StakePoolState::from_bytes(&data[..STAKE_POOL_MIN_LEN - 1]),
+            Err(UnifiedSolPoolError::InvalidStakePool)
+        );
+    }
+
+    #[test]
+    fn test_exchange_rate_edge_cases() {
+        let data = stake_pool_fixture(&JITOSOL_MINT, 1_000, 0, 0);
+        let state = StakePoolState::from_bytes(&data).unwrap();
+        assert_eq!(
+            state.exchange_rate(),
+            Err(UnifiedSolPoolError::InvalidExchangeRate)
+        );
+
+        // u64::MAX lamports behind one base unit overflows the scaled rate
+        let data = stake_pool_fixture(&JITOSOL_MINT, u64::MAX, 1, 0);
+        let state = StakePoolState::from_bytes(&data).unwrap();
+        assert_eq!(
+            state.exchange_rate(),
+            Err(UnifiedSolPoolError::ArithmeticOverflow)
+        );
+    }
+}
@@ -6,8 +6,13 @@
 
 use panchor::prelude::*;
 use pinocchio::pubkey::Pubkey;
+use zorb_pool_interface::asset_ids;
 use zorb_pool_interface::authority::HasAuthority;
-use zorb_pool_interface::{BASIS_POINTS, tokens_to_virtual_sol, virtual_sol_to_tokens};
+use zorb_pool_interface::{
+    BASIS_POINTS, MAX_WITHDRAW_ROUTE_VAULTS, effective_fee_rate,
+    fee::{Rounding, compute_fee},
+    tokens_to_virtual_sol, virtual_sol_to_tokens,
+};
 
 // ============================================================================
 // Constants
@@ -21,11 +26,48 @@ use zorb_pool_interface::{BASIS_POINTS, tokens_to_virtual_sol, virtual_sol_to_to
 /// 2. No collision with any existing or future token's asset ID
 /// 3. Obvious identification in logs/debugging (value 1 vs random-looking hash)
 ///
-/// The value 1 (as a 256-bit big-endian integer) is used.
-pub const UNIFIED_SOL_ASSET_ID: [u8; 32] = [
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
-];
+/// The value 1 (as a 256-bit big-endian integer) is used; see
+/// [`asset_ids::compute_unified_sol_asset_id`].
+pub const UNIFIED_SOL_ASSET_ID: [u8; 32] = asset_ids::compute_unified_sol_asset_id();
+
+/// Current [`LstConfig`] layout version.
+///
+/// | Version | Size | Changes |
+/// |---------|------|---------|
+/// | 1 | 312 | `LstConfig` only; predates the `version` field, which reads 0 |
+/// | 2 | 1096 | Appends [`LstRateHistory`] after the `LstConfig` |
+/// | 3 | 1112 | Appends [`LstManualRate`] after the rate history |
+/// | 4 | 1136 | Appends [`LstPendingRewards`] after the manual rate |
+///
+/// `LstConfig` itself never grows: later versions append extensions after it,
+/// so code that only needs `LstConfig` accepts accounts of every version.
+/// Extensions are read only when the account is large enough to hold them.
+/// Older accounts are upgraded in place by `MigrateLstConfig`.
+pub const LST_CONFIG_VERSION: u16 = 4;
+
+/// On-chain size of a version 1 [`LstConfig`] account.
+pub const LST_CONFIG_V1_SIZE: usize = 312;
+
+/// On-chain size of a current [`LstConfig`] account, extensions included.
+pub const LST_CONFIG_ACCOUNT_SIZE: usize = LstPendingRewards::OFFSET + LstPendingRewards::SIZE;
+
+/// Number of frozen exchange rates kept in [`LstRateHistory`].
+pub const RATE_HISTORY_LEN: usize = 32;
+
+/// `LstConfig.mode` bit allowing withdrawals of the LST.
+///
+/// Bit 0 is the former `is_active` flag, so LSTs activated before directional
+/// modes keep letting users exit but need deposits re-enabled explicitly.
+pub const LST_MODE_WITHDRAWALS_ENABLED: u8 = 1 << 0;
+
+/// `LstConfig.mode` bit allowing deposits of the LST.
+pub const LST_MODE_DEPOSITS_ENABLED: u8 = 1 << 1;
+
+/// `LstConfig.mode` with both directions enabled (fully active LST).
+pub const LST_MODE_ALL: u8 = LST_MODE_DEPOSITS_ENABLED | LST_MODE_WITHDRAWALS_ENABLED;
+
+/// Number of slots in the keeper allowlist.
+pub const MAX_KEEPERS: usize = 4;
 
 // ============================================================================
 // Account Type Enum
@@ -77,7 +119,7 @@ pub enum PoolType {
     Wsol = 0,
     /// SPL Stake Pool (Jito, Sanctum, etc.)
     SplStakePool = 1,
-    /// Marinade Stake Pool
+    /// Marinade (mSOL), priced from Marinade's State account
     Marinade = 2,
     /// Lido Stake Pool
     Lido = 3,
@@ -96,6 +138,31 @@ impl PoolType {
     }
 }
 
+// ============================================================================
+// Epoch Mode
+// ============================================================================
+
+/// What advances the reward epoch.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EpochMode {
+    /// Finalize every `UPDATE_SLOT_INTERVAL` slots (the default)
+    SlotInterval = 0,
+    /// Finalize once per Solana epoch, in step with LST appreciation
+    SolanaEpoch = 1,
+}
+
+impl EpochMode {
+    /// Convert from u8 to EpochMode
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::SlotInterval),
+            1 => Some(Self::SolanaEpoch),
+            _ => None,
+        }
+    }
+}
+
 // ============================================================================
 // UnifiedSolPoolConfig
 // ============================================================================
@@ -119,7 +186,8 @@ impl PoolType {
 /// # Reward Accumulator Design
 ///
 /// The accumulator tracks rewards per unit of virtual SOL. Rewards are finalized
-/// every `UPDATE_SLOT_INTERVAL` slots (~5 minutes).
+/// every `UPDATE_SLOT_INTERVAL` slots (~5 minutes), or once per Solana epoch
+/// when `epoch_mode` is `EpochMode::SolanaEpoch`.
 ///
 /// ## State Fields
 ///
@@ -145,8 +213,10 @@ impl PoolType {
 ///
 /// ## Finalization Flow
 ///
-/// On `finalize_rewards(current_slot)`:
-/// 1. Check enough slots elapsed since `last_finalized_slot`
+/// On `finalize_rewards(current_slot, solana_epoch)`:
+/// 1. Check enough slots elapsed since `last_finalized_slot` (or, in
+///    `SolanaEpoch` mode, that the Solana epoch advanced past
+///    `last_finalized_solana_epoch`)
 /// 2. Calculate `total_pool = finalized_balance + pending_deposits - pending_withdrawals`
 /// 3. If rewards exist: `accumulator += (pending_rewards * 1e18) / total_pool`
 /// 4. Update: `finalized_balance = total_pool`
@@ -205,8 +275,30 @@ pub struct UnifiedSolPoolConfig {
     /// See: `init_unified_sol_pool_config.rs` for initialization
     pub reward_epoch: u64,
 
-    /// Reserved for future use (using u64 array since bytemuck doesn't support [u8; 56])
-    pub _reserved1: [u64; 7],
+    /// Solana epoch of the last finalization (recorded in every mode).
+    ///
+    /// **Updates on:** `finalize_rewards()` only
+    pub last_finalized_solana_epoch: u64,
+
+    /// Minimum appreciation a harvest must book (0 = book every harvest).
+    ///
+    /// Harvests below it are carried in `carried_appreciation`; see
+    /// `book_appreciation()`.
+    pub min_harvest_lamports: u64,
+
+    /// Appreciation from harvests below `min_harvest_lamports`, not yet booked.
+    ///
+    /// **Units:** Virtual SOL (lamports, 1e9 per SOL)
+    ///
+    /// **Updates on:** `book_appreciation()` only
+    pub carried_appreciation: u64,
+
+    /// Slots between `propose_manual_rate` and the earliest `apply_manual_rate`
+    /// (0 = `DEFAULT_MANUAL_RATE_DELAY_SLOTS`, for accounts created before it).
+    pub manual_rate_delay_slots: u64,
+
+    /// Reserved for future use (using u64 array since bytemuck doesn't support [u8; 32])
+    pub _reserved1: [u64; 3],
 
     // === Virtual SOL Tracking ===
     /// Total virtual SOL value across all LST vaults.
@@ -289,7 +381,7 @@ pub struct UnifiedSolPoolConfig {
     /// is added to `pending_appreciation`.
     ///
     /// **Updates on:**
-    /// - `add_appreciation()`: += amount (from harvest_lst_appreciation)
+    /// - `add_appreciation()`: += amount less protocol commission (from harvest_lst_appreciation)
     /// - `finalize_rewards()`: reset to 0 after distribution
     ///
     /// **Audit Note:** This separation enables the finalization event to report
@@ -406,9 +498,67 @@ pub struct UnifiedSolPoolConfig {
     /// Number of registered LST configs
     pub lst_count: u8,
 
-    /// Reserved for future use (23 bytes for 16-byte struct alignment)
-    /// Note: Increased from 15 to 23 bytes after removing transfer_count (u64 = 8 bytes)
-    pub _reserved: [u8; 23],
+    /// What advances the reward epoch (see [`EpochMode`]).
+    ///
+    /// Carved from reserved bytes, so configs created before it existed read
+    /// 0 = `SlotInterval` and keep today's behavior.
+    pub epoch_mode: u8,
+
+    /// Reserved for future use (22 bytes for 16-byte struct alignment)
+    /// Note: Increased from 15 to 23 bytes after removing transfer_count (u64 = 8 bytes),
+    /// then reduced to 22 for `epoch_mode`
+    pub _reserved: [u8; 22],
+
+    // === Rate Sources ===
+    /// Programs trusted to own `PoolType::SplStakePool` rate source accounts.
+    ///
+    /// Seeded with the SPL stake pool program at init; the authority adds
+    /// further SPL-layout deployments (e.g. Sanctum single-validator pools)
+    /// via `add_stake_pool_program`. Zero entries are empty slots.
+    ///
+    /// Removing a program blocks new LSTs on it and halts harvesting (and so
+    /// finalization) for LSTs already registered against it.
+    pub stake_pool_programs: [Pubkey; 4],
+
+    // === Virtual SOL Cap ===
+    /// Maximum `total_virtual_sol` deposits may push the pool to (0 = uncapped).
+    pub max_total_virtual_sol: u64,
+
+    /// Virtual SOL above `max_total_virtual_sol` from appreciation (pending
+    /// bucket), parked at finalization and drained by withdrawals.
+    pub total_virtual_sol_over_cap: u64,
+
+    // === Withdrawal Routing ===
+    /// LST mints that automatically routed withdrawals draw from, in order,
+    /// once the named LST's vault is exhausted. Zero entries are empty slots.
+    pub withdraw_priority: [Pubkey; MAX_WITHDRAW_ROUTE_VAULTS],
+
+    // === Protocol Commission ===
+    /// Owner of the token accounts `withdraw_commission` pays out to
+    /// (zero = commission accrues but cannot be withdrawn).
+    pub commission_treasury: Pubkey,
+
+    /// Commission taken from harvested appreciation and not yet withdrawn
+    /// (in virtual SOL). Still backed by LST tokens held in the vaults.
+    pub accrued_commission: u64,
+
+    /// Share of harvested appreciation kept by the protocol (basis points).
+    pub appreciation_commission_bps: u16,
+
+    /// Padding for 16-byte struct alignment
+    pub _commission_padding: [u8; 6],
+
+    // === Keeper Allowlist ===
+    /// Keepers allowed to harvest, apply manual rates and finalize while
+    /// `keeper_allowlist_enabled` is set. Zero entries are empty slots.
+    pub keepers: [Pubkey; MAX_KEEPERS],
+
+    /// Whether keeper operations are restricted to `keepers`
+    /// (1 = restricted, 0 = permissionless).
+    pub keeper_allowlist_enabled: u8,
+
+    /// Padding for 16-byte struct alignment
+    pub _keeper_padding: [u8; 15],
 }
 
 impl UnifiedSolPoolConfig {
@@ -449,6 +599,15 @@ impl UnifiedSolPoolConfig {
     /// The circuit MUST use the same precision constant.
     pub const ACCUMULATOR_PRECISION: u128 = 1_000_000_000_000_000_000;
 
+    /// Default `manual_rate_delay_slots` (~2 days at 400ms slots)
+    pub const DEFAULT_MANUAL_RATE_DELAY_SLOTS: u64 = 432_000;
+
+    /// Shortest allowed `manual_rate_delay_slots` (one slot interval)
+    pub const MIN_MANUAL_RATE_DELAY_SLOTS: u64 = Self::UPDATE_SLOT_INTERVAL;
+
+    /// Longest allowed `manual_rate_delay_slots` (~20 days)
+    pub const MAX_MANUAL_RATE_DELAY_SLOTS: u64 = 10 * Self::DEFAULT_MANUAL_RATE_DELAY_SLOTS;
+
     /// Calculate the PDA address for unified SOL pool config (singleton)
     /// Seeds: ["unified_sol_pool"]
     ///
@@ -462,6 +621,266 @@ impl UnifiedSolPoolConfig {
         self.is_active != 0
     }
 
+    /// Returns the epoch mode (unknown values fall back to `SlotInterval`).
+    pub fn epoch_mode(&self) -> EpochMode {
+        EpochMode::from_u8(self.epoch_mode).unwrap_or(EpochMode::SlotInterval)
+    }
+
+    /// Returns true if the reward epoch may advance at `current_slot` / `solana_epoch`.
+    ///
+    /// - `SlotInterval`: `UPDATE_SLOT_INTERVAL` slots since `last_finalized_slot`
+    /// - `SolanaEpoch`: the Solana epoch is past `last_finalized_solana_epoch`
+    pub fn can_finalize(&self, current_slot: u64, solana_epoch: u64) -> bool {
+        match self.epoch_mode() {
+            EpochMode::SlotInterval => {
+                current_slot
+                    >= self
+                        .last_finalized_slot
+                        .saturating_add(Self::UPDATE_SLOT_INTERVAL)
+            }
+            EpochMode::SolanaEpoch => solana_epoch > self.last_finalized_solana_epoch,
+        }
+    }
+
+    /// Returns true if LSTs may be harvested for the current reward epoch.
+    ///
+    /// In `SlotInterval` mode harvests are always allowed. In `SolanaEpoch`
+    /// mode they wait for the Solana epoch to advance past the last
+    /// finalization, so each harvest captures a full epoch of stake rewards.
+    pub fn can_harvest(&self, solana_epoch: u64) -> bool {
+        match self.epoch_mode() {
+            EpochMode::SlotInterval => true,
+            EpochMode::SolanaEpoch => solana_epoch > self.last_finalized_solana_epoch,
+        }
+    }
+
+    /// Returns true if `program` is on the stake pool program allowlist.
+    pub fn allows_stake_pool_program(&self, program: &Pubkey) -> bool {
+        *program != [0u8; 32] && self.stake_pool_programs.contains(program)
+    }
+
+    /// Add `program` to the stake pool program allowlist (no-op if already listed).
+    ///
+    /// Returns `Err(InvalidStakePoolProgram)` for the zero key and
+    /// `Err(StakePoolProgramAllowlistFull)` when every slot is taken.
+    pub fn add_stake_pool_program(
+        &mut self,
+        program: &Pubkey,
+    ) -> Result<(), crate::UnifiedSolPoolError> {
+        if *program == [0u8; 32] {
+            return Err(crate::UnifiedSolPoolError::InvalidStakePoolProgram);
+        }
+        if self.allows_stake_pool_program(program) {
+            return Ok(());
+        }
+        let slot = self
+            .stake_pool_programs
+            .iter_mut()
+            .find(|slot| **slot == [0u8; 32])
+            .ok_or(crate::UnifiedSolPoolError::StakePoolProgramAllowlistFull)?;
+        *slot = *program;
+        Ok(())
+    }
+
+    /// Remove `program` from the stake pool program allowlist.
+    ///
+    /// Returns `Err(StakePoolProgramNotListed)` if it is not on the list.
+    pub fn remove_stake_pool_program(
+        &mut self,
+        program: &Pubkey,
+    ) -> Result<(), crate::UnifiedSolPoolError> {
+        if *program == [0u8; 32] {
+            return Err(crate::UnifiedSolPoolError::StakePoolProgramNotListed);
+        }
+        let slot = self
+            .stake_pool_programs
+            .iter_mut()
+            .find(|slot| **slot == *program)
+            .ok_or(crate::UnifiedSolPoolError::StakePoolProgramNotListed)?;
+        *slot = [0u8; 32];
+        Ok(())
+    }
+
+    /// Returns true if `keeper` is on the keeper allowlist.
+    pub fn is_keeper(&self, keeper: &Pubkey) -> bool {
+        *keeper != [0u8; 32] && self.keepers.contains(keeper)
+    }
+
+    /// Add `keeper` to the keeper allowlist (no-op if already listed).
+    ///
+    /// Returns `Err(InvalidKeeper)` for the zero key and
+    /// `Err(KeeperAllowlistFull)` when every slot is taken.
+    pub fn add_keeper(&mut self, keeper: &Pubkey) -> Result<(), crate::UnifiedSolPoolError> {
+        if *keeper == [0u8; 32] {
+            return Err(crate::UnifiedSolPoolError::InvalidKeeper);
+        }
+        if self.is_keeper(keeper) {
+            return Ok(());
+        }
+        let slot = self
+            .keepers
+            .iter_mut()
+            .find(|slot| **slot == [0u8; 32])
+            .ok_or(crate::UnifiedSolPoolError::KeeperAllowlistFull)?;
+        *slot = *keeper;
+        Ok(())
+    }
+
+    /// Remove `keeper` from the keeper allowlist.
+    ///
+    /// Returns `Err(KeeperNotListed)` if it is not on the list, and
+    /// `Err(KeeperAllowlistEmpty)` if it is the last keeper while the
+    /// allowlist is enforced (nobody could harvest or finalize).
+    pub fn remove_keeper(&mut self, keeper: &Pubkey) -> Result<(), crate::UnifiedSolPoolError> {
+        if *keeper == [0u8; 32] {
+            return Err(crate::UnifiedSolPoolError::KeeperNotListed);
+        }
+        let index = self
+            .keepers
+            .iter()
+            .position(|slot| *slot == *keeper)
+            .ok_or(crate::UnifiedSolPoolError::KeeperNotListed)?;
+        if self.keeper_allowlist_enabled() && self.keeper_count() == 1 {
+            return Err(crate::UnifiedSolPoolError::KeeperAllowlistEmpty);
+        }
+        self.keepers[index] = [0u8; 32];
+        Ok(())
+    }
+
+    /// Number of keepers on the allowlist.
+    pub fn keeper_count(&self) -> usize {
+        self.keepers.iter().filter(|k| **k != [0u8; 32]).count()
+    }
+
+    /// Returns true if keeper operations are restricted to the allowlist.
+    pub fn keeper_allowlist_enabled(&self) -> bool {
+        self.keeper_allowlist_enabled != 0
+    }
+
+    /// Enforce or lift the keeper allowlist.
+    ///
+    /// Returns `Err(KeeperAllowlistEmpty)` when enabling with no keepers listed.
+    pub fn set_keeper_allowlist_enabled(
+        &mut self,
+        enabled: bool,
+    ) -> Result<(), crate::UnifiedSolPoolError> {
+        if enabled && self.keeper_count() == 0 {
+            return Err(crate::UnifiedSolPoolError::KeeperAllowlistEmpty);
+        }
+        self.keeper_allowlist_enabled = enabled as u8;
+        Ok(())
+    }
+
+    /// Check that `caller` may run a keeper operation (harvests, manual rate
+    /// application, finalization).
+    ///
+    /// Anyone may while the allowlist is lifted; otherwise returns
+    /// `Err(KeeperNotAllowed)` unless `caller` is listed.
+    pub fn check_keeper(&self, caller: &Pubkey) -> Result<(), crate::UnifiedSolPoolError> {
+        if self.keeper_allowlist_enabled() && !self.is_keeper(caller) {
+            return Err(crate::UnifiedSolPoolError::KeeperNotAllowed);
+        }
+        Ok(())
+    }
+
+    /// Check that crediting `virtual_sol` keeps `total_virtual_sol` within
+    /// `max_total_virtual_sol`.
+    pub fn check_virtual_sol_cap(&self, virtual_sol: u64) -> Result<(), crate::UnifiedSolPoolError> {
+        if exceeds_virtual_sol_cap(self.total_virtual_sol, virtual_sol, self.max_total_virtual_sol) {
+            return Err(crate::UnifiedSolPoolError::PoolVirtualSolCapExceeded);
+        }
+        Ok(())
+    }
+
+    /// Recompute `total_virtual_sol_over_cap` after `total_virtual_sol` changed.
+    pub fn park_virtual_sol_overflow(&mut self) {
+        self.total_virtual_sol_over_cap =
+            virtual_sol_over_cap(self.total_virtual_sol, self.max_total_virtual_sol);
+    }
+
+    /// Replace the withdrawal priority list.
+    ///
+    /// Returns `Err(InvalidWithdrawPriority)` if a non-zero mint is listed twice.
+    pub fn set_withdraw_priority(
+        &mut self,
+        priority: &[Pubkey; MAX_WITHDRAW_ROUTE_VAULTS],
+    ) -> Result<(), crate::UnifiedSolPoolError> {
+        for (i, mint) in priority.iter().enumerate() {
+            if *mint != [0u8; 32] && priority[i + 1..].contains(mint) {
+                return Err(crate::UnifiedSolPoolError::InvalidWithdrawPriority);
+            }
+        }
+        self.withdraw_priority = *priority;
+        Ok(())
+    }
+
+    /// Next LST mint a routed withdrawal of `named_mint` draws from.
+    ///
+    /// Advances `cursor` past empty slots and the named LST itself; returns
+    /// `None` once the priority list is exhausted.
+    pub fn next_route_mint(&self, cursor: &mut usize, named_mint: &Pubkey) -> Option<Pubkey> {
+        while let Some(mint) = self.withdraw_priority.get(*cursor) {
+            *cursor += 1;
+            if *mint != [0u8; 32] && mint != named_mint {
+                return Some(*mint);
+            }
+        }
+        None
+    }
+
+    /// Slots a manual rate proposal waits before it may be applied.
+    pub fn manual_rate_delay(&self) -> u64 {
+        match self.manual_rate_delay_slots {
+            0 => Self::DEFAULT_MANUAL_RATE_DELAY_SLOTS,
+            slots => slots,
+        }
+    }
+
+    /// Set the manual rate delay.
+    ///
+    /// Returns `Err(InvalidManualRateDelay)` outside
+    /// `MIN_MANUAL_RATE_DELAY_SLOTS..=MAX_MANUAL_RATE_DELAY_SLOTS`.
+    pub fn set_manual_rate_delay(&mut self, slots: u64) -> Result<(), crate::UnifiedSolPoolError> {
+        if !(Self::MIN_MANUAL_RATE_DELAY_SLOTS..=Self::MAX_MANUAL_RATE_DELAY_SLOTS).contains(&slots)
+        {
+            return Err(crate::UnifiedSolPoolError::InvalidManualRateDelay);
+        }
+        self.manual_rate_delay_slots = slots;
+        Ok(())
+    }
+
+    /// Set the appreciation commission rate and the treasury it is paid to.
+    ///
+    /// Returns `Err(InvalidFeeRate)` if `bps` exceeds `BASIS_POINTS`.
+    pub fn set_appreciation_commission(
+        &mut self,
+        bps: u16,
+        treasury: &Pubkey,
+    ) -> Result<(), crate::UnifiedSolPoolError> {
+        if bps as u64 > BASIS_POINTS {
+            return Err(crate::UnifiedSolPoolError::InvalidFeeRate);
+        }
+        self.appreciation_commission_bps = bps;
+        self.commission_treasury = *treasury;
+        Ok(())
+    }
+
+    /// Protocol commission on `amount` of harvested appreciation (rounded down).
+    pub fn appreciation_commission(&self, amount: u64) -> u64 {
+        (amount as u128 * self.appreciation_commission_bps as u128 / BASIS_POINTS as u128) as u64
+    }
+
+    /// Remove `amount` of withdrawn commission from `accrued_commission`.
+    ///
+    /// Returns `Err(InsufficientCommission)` if more than has accrued is requested.
+    pub fn take_commission(&mut self, amount: u64) -> Result<(), crate::UnifiedSolPoolError> {
+        self.accrued_commission = self
+            .accrued_commission
+            .checked_sub(amount)
+            .ok_or(crate::UnifiedSolPoolError::InsufficientCommission)?;
+        Ok(())
+    }
+
     /// Get current balance (finalized_balance + pending_deposits - pending_withdrawals)
     pub fn current_balance(&self) -> Result<u128, crate::UnifiedSolPoolError> {
         self.finalized_balance
@@ -574,9 +993,10 @@ impl UnifiedSolPoolConfig {
     pub fn finalize_rewards(
         &mut self,
         current_slot: u64,
+        solana_epoch: u64,
     ) -> Result<bool, crate::UnifiedSolPoolError> {
-        // Check if enough slots have passed
-        if current_slot < self.last_finalized_slot + Self::UPDATE_SLOT_INTERVAL {
+        // Check if enough slots (or Solana epochs) have passed
+        if !self.can_finalize(current_slot, solana_epoch) {
             return Ok(false);
         }
 
@@ -624,6 +1044,7 @@ impl UnifiedSolPoolConfig {
         self.pending_deposits = 0;
         self.pending_withdrawals = 0;
         self.last_finalized_slot = current_slot;
+        self.last_finalized_solana_epoch = solana_epoch;
         self.reward_epoch = self
             .reward_epoch
             .checked_add(1)
@@ -632,21 +1053,54 @@ impl UnifiedSolPoolConfig {
         Ok(true)
     }
 
+    /// Book harvested appreciation, carrying dust forward.
+    ///
+    /// `amount` is added to `carried_appreciation`. While the total is below
+    /// `min_harvest_lamports` it stays carried and `None` is returned, so the
+    /// harvest leaves pending rewards untouched. Otherwise the total is passed
+    /// to `add_appreciation()` and `Some((total, commission))` is returned.
+    pub fn book_appreciation(
+        &mut self,
+        amount: u64,
+    ) -> Result<Option<(u64, u64)>, crate::UnifiedSolPoolError> {
+        let total = self
+            .carried_appreciation
+            .checked_add(amount)
+            .ok_or(crate::UnifiedSolPoolError::ArithmeticOverflow)?;
+        if total < self.min_harvest_lamports {
+            self.carried_appreciation = total;
+            return Ok(None);
+        }
+        self.carried_appreciation = 0;
+        let commission = self.add_appreciation(total)?;
+        Ok(Some((total, commission)))
+    }
+
     /// Add appreciation to pending appreciation rewards and track total appreciation.
     ///
     /// This is called by `harvest_lst_appreciation` when LST exchange rates increase.
     /// The appreciation is tracked separately from fees to enable transparent
     /// reporting in finalization events.
-    pub fn add_appreciation(&mut self, amount: u64) -> Result<(), crate::UnifiedSolPoolError> {
+    ///
+    /// The protocol's `appreciation_commission_bps` share accrues to
+    /// `accrued_commission`; only the remainder is distributed to depositors at
+    /// finalization. `total_appreciation` records the full amount. Returns the
+    /// commission taken.
+    pub fn add_appreciation(&mut self, amount: u64) -> Result<u64, crate::UnifiedSolPoolError> {
+        let commission = self.appreciation_commission(amount);
         self.pending_appreciation = self
             .pending_appreciation
-            .checked_add(amount)
+            .checked_add(amount - commission)
+            .ok_or(crate::UnifiedSolPoolError::ArithmeticOverflow)?;
+        self.accrued_commission = self
+            .accrued_commission
+            .checked_add(commission)
             .ok_or(crate::UnifiedSolPoolError::ArithmeticOverflow)?;
         self.total_appreciation = self
             .total_appreciation
             .checked_add(amount as u128)
             .ok_or(crate::UnifiedSolPoolError::ArithmeticOverflow)?;
-        Ok(())
+        Ok(commission)
     }
 }
 
@@ -717,14 +1171,27 @@ pub struct LstConfig {
     /// FIRST field for instant pool type discrimination in variable decoders.
     pub pool_type: u8,
 
-    /// Whether this LST is enabled for operations (1 = active)
-    pub is_active: u8,
+    /// Enabled directions (`LST_MODE_DEPOSITS_ENABLED | LST_MODE_WITHDRAWALS_ENABLED`)
+    pub mode: u8,
 
     /// PDA bump seed
     pub bump: u8,
 
-    /// Padding for 8-byte alignment
-    pub _header_pad: [u8; 5],
+    /// Whether this LST is being wound down (1 = retired).
+    ///
+    /// Retired LSTs reject deposits but still allow withdrawals, are exempt
+    /// from the per-epoch harvest requirement, and keep their frozen rate.
+    /// Once drained, `close_lst_config` removes them.
+    pub is_retired: u8,
+
+    /// Maximum rise of `harvested_exchange_rate` per finalization, in basis points.
+    ///
+    /// A larger move fails finalization with `ExchangeRateOutOfBounds` unless
+    /// the authority approved the exact rate via `approve_lst_rate_jump`.
+    pub max_rate_increase_bps_per_epoch: u16,
+
+    /// Maximum fall of `harvested_exchange_rate` per finalization, in basis points.
+    pub max_rate_decrease_bps_per_epoch: u16,
 
     // === Common References (64 bytes) ===
     /// LST token mint (e.g., WSOL, jitoSOL)
@@ -775,8 +1242,12 @@ pub struct LstConfig {
     /// (0 != 1) until it's actually harvested.
     pub last_harvest_epoch: u64,
 
-    /// Padding to align `total_virtual_sol` (u128) to 16-byte boundary.
-    pub _pad_for_u128: u64,
+    /// Slot when `harvested_exchange_rate` was last frozen (init or finalize).
+    ///
+    /// Deposits and withdrawals fail with `ExchangeRateStale` once this is more
+    /// than `max_rate_staleness_slots` old. Also aligns `total_virtual_sol`
+    /// (u128) to a 16-byte boundary.
+    pub rate_updated_slot: u64,
 
     // === Value Tracking (40 bytes) ===
     /// Total virtual SOL value of this LST's vault.
@@ -797,10 +1268,11 @@ pub struct LstConfig {
     /// Note: External transfers directly to vault are not tracked (free equity to pool).
     pub vault_token_balance: u64,
 
-    /// Padding for u128 alignment
-    pub _value_pad: u64,
+    /// Maximum age of `harvested_exchange_rate`, in slots, for deposits and
+    /// withdrawals (ignored for WSOL, whose rate is fixed, and for retired LSTs).
+    pub max_rate_staleness_slots: u64,
 
-    // === Statistics (80 bytes) ===
+    // === Statistics (72 bytes) ===
     /// Total LST tokens deposited (in token base units)
     pub total_deposited: u128,
 
@@ -816,8 +1288,20 @@ pub struct LstConfig {
     /// Number of withdrawals from this LST
     pub withdrawal_count: u64,
 
+    // === Fee Overrides (8 bytes) ===
+    /// Deposit fee for this LST in basis points, replacing the pool-wide
+    /// `deposit_fee_rate` (`FEE_RATE_UNSET` = 0xFFFF uses the pool-wide rate)
+    pub deposit_fee_bps_override: u16,
+
+    /// Withdrawal fee for this LST in basis points, replacing the pool-wide
+    /// `withdrawal_fee_rate` (`FEE_RATE_UNSET` = 0xFFFF uses the pool-wide rate)
+    pub withdraw_fee_bps_override: u16,
+
+    /// Layout version (see [`LST_CONFIG_VERSION`]); 0 on version 1 accounts
+    pub version: u16,
+
     /// Padding for alignment
-    pub _stat_pad: u64,
+    pub _fee_pad: [u8; 2],
 
     // =========================================================================
     // STAKE POOL SPECIFIC FIELDS - 72 bytes
@@ -825,7 +1309,7 @@ pub struct LstConfig {
     // These fields are only used by stake pool configs (SplStakePool, Marinade, Lido).
     // For WSOL, these are zeroed and can be skipped by variable decoders.
 
-    /// Stake pool address for this LST (for rate queries)
+    /// Rate source account for this LST (SPL stake pool, or Marinade state)
     /// Zeroed for WSOL.
     pub stake_pool: Pubkey,
 
@@ -838,10 +1322,249 @@ pub struct LstConfig {
     pub previous_exchange_rate: u64,
 
     // =========================================================================
-    // RESERVED - 8 bytes
+    // RATE BOUNDS OVERRIDE - 8 bytes
+    // =========================================================================
+    /// Rate the authority approved for the next freeze outside the band (0 = none).
+    ///
+    /// Finalization accepts `exchange_rate == approved_exchange_rate` regardless
+    /// of the per-epoch band, then clears it.
+    pub approved_exchange_rate: u64,
+
+    // =========================================================================
+    // VIRTUAL SOL CAP - 16 bytes
     // =========================================================================
-    /// Reserved for future use
-    pub _reserved: u64,
+    /// Maximum `total_virtual_sol` deposits may push this LST to (0 = uncapped).
+    ///
+    /// Bounds how much of the pool's value one LST can carry; withdrawals are
+    /// never capped.
+    pub max_virtual_sol: u64,
+
+    /// Virtual SOL above `max_virtual_sol` from appreciation (pending bucket).
+    ///
+    /// Finalization may grow `total_virtual_sol` past the cap; the excess is
+    /// parked here rather than failing, and drains as withdrawals bring the
+    /// LST back under its cap. Deposits stay closed while it is non-zero.
+    pub virtual_sol_over_cap: u64,
+}
+
+// ============================================================================
+// LstConfig Extensions
+// ============================================================================
+
+/// Exchange rate history of one LST (version 2 extension).
+///
+/// Stored in the LstConfig account directly after the [`LstConfig`] struct,
+/// at [`LstRateHistory::OFFSET`]. Version 1 accounts end before it.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Pod, Zeroable, IdlType)]
+pub struct LstRateHistory {
+    /// Number of rates ever pushed; the next entry is written at
+    /// `count % RATE_HISTORY_LEN`.
+    pub count: u64,
+
+    /// Padding for 16-byte struct alignment
+    pub _pad: [u8; 8],
+
+    /// Ring buffer of the last `RATE_HISTORY_LEN` frozen exchange rates,
+    /// pushed whenever `harvested_exchange_rate` is frozen (init and finalize).
+    pub entries: [RateHistoryEntry; RATE_HISTORY_LEN],
+}
+
+/// One frozen exchange rate in [`LstRateHistory`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Pod, Zeroable, IdlType)]
+pub struct RateHistoryEntry {
+    /// Frozen `harvested_exchange_rate` (1 LST = rate/1e9 SOL)
+    pub rate: u64,
+    /// Reward epoch the rate prices deposits and withdrawals for
+    pub epoch: u64,
+    /// Slot when the rate was frozen
+    pub slot: u64,
+}
+
+impl LstRateHistory {
+    /// Extension size
+    pub const SIZE: usize = core::mem::size_of::<Self>();
+
+    /// Offset of the extension in the LstConfig account (after the
+    /// discriminator and [`LstConfig`])
+    pub const OFFSET: usize = LST_CONFIG_V1_SIZE;
+
+    /// The rate history in LstConfig account `data`, or `None` if the
+    /// account predates it (version 1).
+    pub fn from_account_data(data: &[u8]) -> Option<&Self> {
+        let bytes = data.get(Self::OFFSET..Self::OFFSET + Self::SIZE)?;
+        bytemuck::try_from_bytes(bytes).ok()
+    }
+
+    /// Mutable variant of [`Self::from_account_data`].
+    pub fn from_account_data_mut(data: &mut [u8]) -> Option<&mut Self> {
+        let bytes = data.get_mut(Self::OFFSET..Self::OFFSET + Self::SIZE)?;
+        bytemuck::try_from_bytes_mut(bytes).ok()
+    }
+
+    /// Record a frozen `rate`, overwriting the oldest entry once the buffer
+    /// is full.
+    pub fn push(&mut self, rate: u64, epoch: u64, slot: u64) {
+        let index = (self.count % RATE_HISTORY_LEN as u64) as usize;
+        self.entries[index] = RateHistoryEntry { rate, epoch, slot };
+        self.count = self.count.saturating_add(1);
+    }
+
+    /// Recorded rates, oldest first.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &RateHistoryEntry> {
+        let len = self.count.min(RATE_HISTORY_LEN as u64) as usize;
+        let start = (self.count % RATE_HISTORY_LEN as u64) as usize;
+        let (newer, older) = self.entries.split_at(start);
+        // Until the buffer wraps, `older` holds only unwritten entries
+        older[..len.saturating_sub(newer.len())].iter().chain(newer)
+    }
+
+    /// Rate in force during reward `epoch`: the latest recorded rate frozen
+    /// at or before it.
+    ///
+    /// Returns `None` if `epoch` predates the oldest recorded rate.
+    pub fn rate_at_epoch(&self, epoch: u64) -> Option<u64> {
+        self.iter()
+            .rev()
+            .find(|entry| entry.epoch <= epoch)
+            .map(|entry| entry.rate)
+    }
+}
+
+/// Pending emergency exchange rate override of one LST (version 3 extension).
+///
+/// Stored directly after [`LstRateHistory`], at [`LstManualRate::OFFSET`].
+/// Written by `propose_manual_rate`, consumed by `apply_manual_rate` once
+/// `unlock_slot` is reached, and cleared by `cancel_manual_rate`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Pod, Zeroable, IdlType)]
+pub struct LstManualRate {
+    /// Proposed `exchange_rate` (1 LST = rate/1e9 SOL; 0 = no proposal)
+    pub proposed_rate: u64,
+
+    /// First slot at which the proposal may be applied
+    pub unlock_slot: u64,
+}
+
+impl LstManualRate {
+    /// Extension size
+    pub const SIZE: usize = core::mem::size_of::<Self>();
+
+    /// Offset of the extension in the LstConfig account (after the rate history)
+    pub const OFFSET: usize = LstRateHistory::OFFSET + LstRateHistory::SIZE;
+
+    /// The manual rate proposal in LstConfig account `data`, or `None` if the
+    /// account predates it (version 2 or older).
+    pub fn from_account_data(data: &[u8]) -> Option<&Self> {
+        let bytes = data.get(Self::OFFSET..Self::OFFSET + Self::SIZE)?;
+        bytemuck::try_from_bytes(bytes).ok()
+    }
+
+    /// Mutable variant of [`Self::from_account_data`].
+    pub fn from_account_data_mut(data: &mut [u8]) -> Option<&mut Self> {
+        let bytes = data.get_mut(Self::OFFSET..Self::OFFSET + Self::SIZE)?;
+        bytemuck::try_from_bytes_mut(bytes).ok()
+    }
+
+    /// Returns true if a rate has been proposed and not yet applied or cancelled.
+    pub fn is_pending(&self) -> bool {
+        self.proposed_rate != 0
+    }
+
+    /// Record a proposal of `rate`, replacing any pending one.
+    pub fn propose(&mut self, rate: u64, unlock_slot: u64) {
+        self.proposed_rate = rate;
+        self.unlock_slot = unlock_slot;
+    }
+
+    /// Drop the pending proposal.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// The proposed rate, if it may be applied at `current_slot`.
+    ///
+    /// Returns `Err(ManualRateNotProposed)` without a pending proposal and
+    /// `Err(ManualRateTimelocked)` before `unlock_slot`.
+    pub fn unlocked_rate(&self, current_slot: u64) -> Result<u64, crate::UnifiedSolPoolError> {
+        if !self.is_pending() {
+            return Err(crate::UnifiedSolPoolError::ManualRateNotProposed);
+        }
+        if current_slot < self.unlock_slot {
+            return Err(crate::UnifiedSolPoolError::ManualRateTimelocked);
+        }
+        Ok(self.proposed_rate)
+    }
+}
+
+/// Rewards one LST added to the pool's pending totals since the last
+/// finalization (version 4 extension).
+///
+/// Stored directly after [`LstManualRate`], at [`LstPendingRewards::OFFSET`].
+/// Mirrors `pending_deposit_fees`, `pending_withdrawal_fees` and
+/// `pending_appreciation` on [`UnifiedSolPoolConfig`], so the per-LST figures
+/// in `LstFinalizedEvent` sum to the aggregate, and is reset whenever those are.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Pod, Zeroable, IdlType)]
+pub struct LstPendingRewards {
+    /// Deposit fees charged on deposits of this LST (virtual SOL)
+    pub deposit_fees: u64,
+
+    /// Withdrawal fees charged on withdrawals naming this LST (virtual SOL)
+    pub withdrawal_fees: u64,
+
+    /// Appreciation booked by this LST's harvests, net of commission
+    /// (virtual SOL). Carried dust is attributed to the harvest that books it.
+    pub appreciation: u64,
+}
+
+impl LstPendingRewards {
+    /// Extension size
+    pub const SIZE: usize = core::mem::size_of::<Self>();
+
+    /// Offset of the extension in the LstConfig account (after the manual rate)
+    pub const OFFSET: usize = LstManualRate::OFFSET + LstManualRate::SIZE;
+
+    /// The pending rewards in LstConfig account `data`, or `None` if the
+    /// account predates them (version 3 or older).
+    pub fn from_account_data(data: &[u8]) -> Option<&Self> {
+        let bytes = data.get(Self::OFFSET..Self::OFFSET + Self::SIZE)?;
+        bytemuck::try_from_bytes(bytes).ok()
+    }
+
+    /// Mutable variant of [`Self::from_account_data`].
+    pub fn from_account_data_mut(data: &mut [u8]) -> Option<&mut Self> {
+        let bytes = data.get_mut(Self::OFFSET..Self::OFFSET + Self::SIZE)?;
+        bytemuck::try_from_bytes_mut(bytes).ok()
+    }
+
+    /// Record a deposit fee.
+    pub fn add_deposit_fee(&mut self, fee: u64) -> Result<(), crate::UnifiedSolPoolError> {
+        self.deposit_fees = self
+            .deposit_fees
+            .checked_add(fee)
+            .ok_or(crate::UnifiedSolPoolError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Record a withdrawal fee.
+    pub fn add_withdrawal_fee(&mut self, fee: u64) -> Result<(), crate::UnifiedSolPoolError> {
+        self.withdrawal_fees = self
+            .withdrawal_fees
+            .checked_add(fee)
+            .ok_or(crate::UnifiedSolPoolError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Record appreciation booked to pending rewards.
+    pub fn add_appreciation(&mut self, amount: u64) -> Result<(), crate::UnifiedSolPoolError> {
+        self.appreciation = self
+            .appreciation
+            .checked_add(amount)
+            .ok_or(crate::UnifiedSolPoolError::ArithmeticOverflow)?;
+        Ok(())
+    }
 }
 
 impl LstConfig {
@@ -859,9 +1582,69 @@ impl LstConfig {
         crate::find_lst_config_pda(lst_mint)
     }
 
-    /// Check if the LST is active
+    /// Check if the LST is active (either direction enabled)
     pub fn is_active(&self) -> bool {
-        self.is_active != 0
+        self.mode & LST_MODE_ALL != 0
+    }
+
+    /// Check if deposits of this LST are enabled
+    pub fn deposits_enabled(&self) -> bool {
+        self.mode & LST_MODE_DEPOSITS_ENABLED != 0
+    }
+
+    /// Check if withdrawals of this LST are enabled
+    pub fn withdrawals_enabled(&self) -> bool {
+        self.mode & LST_MODE_WITHDRAWALS_ENABLED != 0
+    }
+
+    /// Returns true if `mode` only contains known direction bits
+    pub fn is_valid_mode(mode: u8) -> bool {
+        mode & !LST_MODE_ALL == 0
+    }
+
+    /// Check if the LST is retired (withdraw-only, awaiting close)
+    pub fn is_retired(&self) -> bool {
+        self.is_retired != 0
+    }
+
+    /// Layout version, treating the unset field of version 1 accounts as 1
+    pub fn layout_version(&self) -> u16 {
+        self.version.max(1)
+    }
+
+    /// Whether finalization requires a fresh harvest and freezes this LST's rate.
+    ///
+    /// Inactive and retired LSTs are passed to finalization but keep their
+    /// frozen `harvested_exchange_rate`.
+    pub fn tracks_rate(&self) -> bool {
+        self.is_active() && !self.is_retired()
+    }
+
+    /// Deposit fee rate for this LST given the pool-wide `deposit_fee_rate`.
+    ///
+    /// The hub prices expected fees with the same function (via the shared
+    /// `effective_fee_rate`), so the two always agree.
+    pub fn effective_deposit_fee_rate(&self, pool_rate: u16) -> u16 {
+        effective_fee_rate(pool_rate, self.deposit_fee_bps_override)
+    }
+
+    /// Withdrawal fee rate for this LST given the pool-wide `withdrawal_fee_rate`.
+    pub fn effective_withdrawal_fee_rate(&self, pool_rate: u16) -> u16 {
+        effective_fee_rate(pool_rate, self.withdraw_fee_bps_override)
+    }
+
+    /// Check that crediting `virtual_sol` keeps `total_virtual_sol` within
+    /// `max_virtual_sol`.
+    pub fn check_virtual_sol_cap(&self, virtual_sol: u64) -> Result<(), crate::UnifiedSolPoolError> {
+        if exceeds_virtual_sol_cap(self.total_virtual_sol, virtual_sol, self.max_virtual_sol) {
+            return Err(crate::UnifiedSolPoolError::LstVirtualSolCapExceeded);
+        }
+        Ok(())
+    }
+
+    /// Recompute `virtual_sol_over_cap` after `total_virtual_sol` changed.
+    pub fn park_virtual_sol_overflow(&mut self) {
+        self.virtual_sol_over_cap = virtual_sol_over_cap(self.total_virtual_sol, self.max_virtual_sol);
     }
 
     /// Calculate the current SOL value of a given LST balance.
@@ -895,6 +1678,108 @@ impl LstConfig {
     /// the stale-rate cost, remains economically irrelevant.
     pub const MAX_RATE_CHANGE_BPS: u64 = 50;
 
+    /// Default `max_rate_increase_bps_per_epoch` (matches `MAX_RATE_CHANGE_BPS`).
+    pub const DEFAULT_MAX_RATE_INCREASE_BPS_PER_EPOCH: u16 = 50;
+
+    /// Default `max_rate_decrease_bps_per_epoch`.
+    pub const DEFAULT_MAX_RATE_DECREASE_BPS_PER_EPOCH: u16 = 50;
+
+    /// Ceiling the authority can raise either per-epoch band to (10%).
+    pub const MAX_RATE_BAND_BPS_CEILING: u16 = 1_000;
+
+    /// Default `max_rate_staleness_slots` (ten finalization intervals).
+    pub const DEFAULT_MAX_RATE_STALENESS_SLOTS: u64 =
+        10 * UnifiedSolPoolConfig::UPDATE_SLOT_INTERVAL;
+
+    /// Ceiling for `max_rate_staleness_slots` (one Solana epoch).
+    ///
+    /// The floor is `UPDATE_SLOT_INTERVAL`: anything shorter would reject
+    /// deposits between two finalizations that arrive on schedule.
+    pub const MAX_RATE_STALENESS_SLOTS_CEILING: u64 = 432_000;
+
+    /// Set the per-epoch rate band and staleness limit, enforcing the ceilings.
+    pub fn set_rate_bounds(
+        &mut self,
+        max_increase_bps: u16,
+        max_decrease_bps: u16,
+        max_staleness_slots: u64,
+    ) -> Result<(), crate::UnifiedSolPoolError> {
+        if max_increase_bps > Self::MAX_RATE_BAND_BPS_CEILING
+            || max_decrease_bps > Self::MAX_RATE_BAND_BPS_CEILING
+            || !(UnifiedSolPoolConfig::UPDATE_SLOT_INTERVAL
+                ..=Self::MAX_RATE_STALENESS_SLOTS_CEILING)
+                .contains(&max_staleness_slots)
+        {
+            return Err(crate::UnifiedSolPoolError::InvalidRateBounds);
+        }
+
+        self.max_rate_increase_bps_per_epoch = max_increase_bps;
+        self.max_rate_decrease_bps_per_epoch = max_decrease_bps;
+        self.max_rate_staleness_slots = max_staleness_slots;
+        Ok(())
+    }
+
+    /// Validate that freezing `new_rate` stays within the per-epoch band.
+    ///
+    /// The band is measured against the current `harvested_exchange_rate`.
+    /// A rate equal to a non-zero `approved_exchange_rate` always passes.
+    pub fn validate_rate_bounds(&self, new_rate: u64) -> Result<(), crate::UnifiedSolPoolError> {
+        if self.approved_exchange_rate != 0 && new_rate == self.approved_exchange_rate {
+            return Ok(());
+        }
+
+        let old_rate = self.harvested_exchange_rate as u128;
+        let (diff, max_bps) = if new_rate >= self.harvested_exchange_rate {
+            (
+                new_rate - self.harvested_exchange_rate,
+                self.max_rate_increase_bps_per_epoch,
+            )
+        } else {
+            (
+                self.harvested_exchange_rate - new_rate,
+                self.max_rate_decrease_bps_per_epoch,
+            )
+        };
+
+        // diff / old_rate > max_bps / BASIS_POINTS, without division
+        if diff as u128 * BASIS_POINTS as u128 > old_rate * max_bps as u128 {
+            return Err(crate::UnifiedSolPoolError::ExchangeRateOutOfBounds);
+        }
+
+        Ok(())
+    }
+
+    /// Freeze `exchange_rate` into `harvested_exchange_rate` at finalization.
+    ///
+    /// Fails with `ExchangeRateOutOfBounds` if the move is outside the band and
+    /// not approved. Consumes any approval.
+    pub fn freeze_exchange_rate(
+        &mut self,
+        current_slot: u64,
+    ) -> Result<(), crate::UnifiedSolPoolError> {
+        self.validate_rate_bounds(self.exchange_rate)?;
+        self.harvested_exchange_rate = self.exchange_rate;
+        self.rate_updated_slot = current_slot;
+        self.approved_exchange_rate = 0;
+        Ok(())
+    }
+
+    /// Check `harvested_exchange_rate` is recent enough to price a deposit or withdrawal.
+    ///
+    /// WSOL is exempt (its rate is fixed at 1:1), as are retired LSTs, which
+    /// keep a frozen rate by design while they drain.
+    pub fn check_rate_fresh(&self, current_slot: u64) -> Result<(), crate::UnifiedSolPoolError> {
+        if self.pool_type == PoolType::Wsol as u8 || self.is_retired() {
+            return Ok(());
+        }
+
+        if current_slot.saturating_sub(self.rate_updated_slot) > self.max_rate_staleness_slots {
+            return Err(crate::UnifiedSolPoolError::ExchangeRateStale);
+        }
+
+        Ok(())
+    }
+
     /// Validate that a new exchange rate is within acceptable bounds.
     pub fn validate_rate_change(&self, new_rate: u64) -> Result<(), crate::UnifiedSolPoolError> {
         if self.exchange_rate == 0 {
@@ -947,6 +1832,54 @@ impl LstConfig {
     }
 }
 
+// ============================================================================
+// Virtual SOL Caps
+// ============================================================================
+
+/// Whether crediting `added` to `total` would exceed `cap` (0 = uncapped).
+fn exceeds_virtual_sol_cap(total: u128, added: u64, cap: u64) -> bool {
+    cap != 0 && total.saturating_add(added as u128) > cap as u128
+}
+
+/// Portion of `total` above `cap` (0 when uncapped or under the cap).
+fn virtual_sol_over_cap(total: u128, cap: u64) -> u64 {
+    if cap == 0 {
+        return 0;
+    }
+    total.saturating_sub(cap as u128).min(u64::MAX as u128) as u64
+}
+
+// ============================================================================
+// Protocol Fees
+// ============================================================================
+
+/// Protocol fee of `fee_rate` basis points on `virtual_sol`.
+///
+/// Unified SOL fees are charged in virtual SOL and always round down; the hub
+/// prices the same fee with the same [`compute_fee`] call.
+pub fn protocol_fee(virtual_sol: u64, fee_rate: u16) -> Result<u64, crate::UnifiedSolPoolError> {
+    compute_fee(virtual_sol, fee_rate, Rounding::Down)
+        .ok_or(crate::UnifiedSolPoolError::ArithmeticOverflow)
+}
+
+// ============================================================================
+// Withdrawal Routing
+// ============================================================================
+
+/// Draw one vault's share of a routed withdrawal.
+///
+/// Takes the tokens worth `remaining` virtual SOL at `exchange_rate`, or all
+/// of `available_tokens` if the vault holds less. Returns `(tokens,
+/// virtual_sol)` drawn; `virtual_sol` never exceeds `remaining`.
+pub fn route_fill(remaining: u64, available_tokens: u64, exchange_rate: u64) -> Option<(u64, u64)> {
+    let wanted = virtual_sol_to_tokens(remaining, exchange_rate)?;
+    if wanted <= available_tokens {
+        return Some((wanted, remaining));
+    }
+    let drawn = tokens_to_virtual_sol(available_tokens, exchange_rate)?;
+    Some((available_tokens, drawn.min(remaining as u128) as u64))
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -954,6 +1887,7 @@ impl LstConfig {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use zorb_pool_interface::FEE_RATE_UNSET;
 
     #[test]
     fn test_unified_sol_pool_config_size() {
@@ -961,10 +1895,57 @@ mod tests {
         assert!(UnifiedSolPoolConfig::SIZE < 1024);
     }
 
+    #[test]
+    fn test_fee_rates_offset() {
+        // The hub reads fee rates from raw account data at this offset
+        use core::mem::offset_of;
+        use zorb_pool_interface::pool_layout::UNIFIED_SOL_POOL_FEE_RATES_OFFSET;
+        assert_eq!(
+            8 + offset_of!(UnifiedSolPoolConfig, deposit_fee_rate),
+            UNIFIED_SOL_POOL_FEE_RATES_OFFSET
+        );
+        assert_eq!(
+            8 + offset_of!(UnifiedSolPoolConfig, withdrawal_fee_rate),
+            UNIFIED_SOL_POOL_FEE_RATES_OFFSET + 2
+        );
+    }
+
     #[test]
     fn test_lst_config_size() {
         // Verify size is reasonable for on-chain account
         assert!(LstConfig::SIZE < 512);
+        // LstConfig never grows; versions append extensions after it
+        assert_eq!(8 + LstConfig::SIZE, LST_CONFIG_V1_SIZE);
+        assert_eq!(LstManualRate::OFFSET, 1096);
+        assert_eq!(LstPendingRewards::OFFSET, 1112);
+        assert_eq!(LST_CONFIG_ACCOUNT_SIZE, 1136);
+    }
+
+    #[test]
+    fn test_lst_config_mode() {
+        let mut config: LstConfig = bytemuck::Zeroable::zeroed();
+
+        // (mode, deposits, withdrawals)
+        for (mode, deposits, withdrawals) in [
+            (0, false, false),
+            (LST_MODE_DEPOSITS_ENABLED, true, false),
+            (LST_MODE_WITHDRAWALS_ENABLED, false, true),
+            (LST_MODE_ALL, true, true),
+        ] {
+            config.mode = mode;
+            assert_eq!(config.deposits_enabled(), deposits);
+            assert_eq!(config.withdrawals_enabled(), withdrawals);
+            assert_eq!(config.is_active(), deposits || withdrawals);
+            assert!(LstConfig::is_valid_mode(mode));
+        }
+
+        // The former is_active = 1 leaves withdrawals open
+        config.mode = 1;
+        assert!(config.withdrawals_enabled());
+        assert!(!config.deposits_enabled());
+
+        assert!(!LstConfig::is_valid_mode(1 << 2));
+        assert!(!LstConfig::is_valid_mode(u8::MAX));
     }
 
     #[test]
@@ -972,9 +1953,11 @@ mod tests {
         let config = LstConfig {
             // Header
             pool_type: 1, // SplStakePool
-            is_active: 1,
+            mode: LST_MODE_ALL,
             bump: 255,
-            _header_pad: [0u8; 5],
+            is_retired: 0,
+            max_rate_increase_bps_per_epoch: 0,
+            max_rate_decrease_bps_per_epoch: 0,
             // Common References
             lst_mint: [0u8; 32],
             lst_vault: [0u8; 32],
@@ -983,24 +1966,31 @@ mod tests {
             harvested_exchange_rate: 1_050_000_000,
             last_rate_update_slot: 0,
             last_harvest_epoch: 0,
-            _pad_for_u128: 0,
+            rate_updated_slot: 0,
             // Value Tracking
             total_virtual_sol: 0,
             vault_token_balance: 0,
-            _value_pad: 0,
+            max_rate_staleness_slots: 0,
             // Statistics
             total_deposited: 0,
             total_withdrawn: 0,
             total_appreciation_harvested: 0,
             deposit_count: 0,
             withdrawal_count: 0,
-            _stat_pad: 0,
+            // Fee Overrides
+            deposit_fee_bps_override: FEE_RATE_UNSET,
+            withdraw_fee_bps_override: FEE_RATE_UNSET,
+            version: LST_CONFIG_VERSION,
+            _fee_pad: [0u8; 2],
             // Stake Pool Specific
             stake_pool: [0u8; 32],
             stake_pool_program: [0u8; 32],
             previous_exchange_rate: 1_000_000_000,
-            // Reserved
-            _reserved: 0,
+            // Rate Bounds Override
+            approved_exchange_rate: 0,
+            // Virtual SOL Cap
+            max_virtual_sol: 0,
+            virtual_sol_over_cap: 0,
         };
 
         // 100 LST at 1.05x = 105 SOL
@@ -1013,9 +2003,11 @@ mod tests {
         let config = LstConfig {
             // Header
             pool_type: 1, // SplStakePool
-            is_active: 1,
+            mode: LST_MODE_ALL,
             bump: 255,
-            _header_pad: [0u8; 5],
+            is_retired: 0,
+            max_rate_increase_bps_per_epoch: 0,
+            max_rate_decrease_bps_per_epoch: 0,
             // Common References
             lst_mint: [0u8; 32],
             lst_vault: [0u8; 32],
@@ -1024,28 +2016,537 @@ mod tests {
             harvested_exchange_rate: 1_050_000_000,
             last_rate_update_slot: 0,
             last_harvest_epoch: 0,
-            _pad_for_u128: 0,
+            rate_updated_slot: 0,
             // Value Tracking
             total_virtual_sol: 0,
             vault_token_balance: 0,
-            _value_pad: 0,
+            max_rate_staleness_slots: 0,
             // Statistics
             total_deposited: 0,
             total_withdrawn: 0,
             total_appreciation_harvested: 0,
             deposit_count: 0,
             withdrawal_count: 0,
-            _stat_pad: 0,
+            // Fee Overrides
+            deposit_fee_bps_override: FEE_RATE_UNSET,
+            withdraw_fee_bps_override: FEE_RATE_UNSET,
+            version: LST_CONFIG_VERSION,
+            _fee_pad: [0u8; 2],
             // Stake Pool Specific
             stake_pool: [0u8; 32],
             stake_pool_program: [0u8; 32],
             previous_exchange_rate: 1_000_000_000,
-            // Reserved
-            _reserved: 0,
+            // Rate Bounds Override
+            approved_exchange_rate: 0,
+            // Virtual SOL Cap
+            max_virtual_sol: 0,
+            virtual_sol_over_cap: 0,
         };
 
         // 105 virtual SOL at 1.05x = 100 LST
         let lst_tokens = config.calculate_lst_tokens(105_000_000_000);
         assert_eq!(lst_tokens, 100_000_000_000);
     }
+
+    #[test]
+    fn test_effective_fee_rates() {
+        let mut config: LstConfig = bytemuck::Zeroable::zeroed();
+        config.deposit_fee_bps_override = FEE_RATE_UNSET;
+        config.withdraw_fee_bps_override = FEE_RATE_UNSET;
+        assert_eq!(config.effective_deposit_fee_rate(30), 30);
+        assert_eq!(config.effective_withdrawal_fee_rate(50), 50);
+
+        // Overrides apply independently; zero is a real (fee-free) rate
+        config.deposit_fee_bps_override = 0;
+        assert_eq!(config.effective_deposit_fee_rate(30), 0);
+        assert_eq!(config.effective_withdrawal_fee_rate(50), 50);
+        config.withdraw_fee_bps_override = 120;
+        assert_eq!(config.effective_withdrawal_fee_rate(50), 120);
+    }
+
+    #[test]
+    fn test_stake_pool_program_allowlist() {
+        let mut config: UnifiedSolPoolConfig = bytemuck::Zeroable::zeroed();
+        let programs: [Pubkey; 5] = core::array::from_fn(|i| [i as u8 + 1; 32]);
+
+        assert!(!config.allows_stake_pool_program(&programs[0]));
+        assert!(!config.allows_stake_pool_program(&[0u8; 32]));
+        assert_eq!(
+            config.add_stake_pool_program(&[0u8; 32]),
+            Err(crate::UnifiedSolPoolError::InvalidStakePoolProgram)
+        );
+
+        for program in &programs[..4] {
+            config.add_stake_pool_program(program).unwrap();
+        }
+        // Re-adding a listed program is a no-op even when full
+        config.add_stake_pool_program(&programs[0]).unwrap();
+        assert_eq!(
+            config.add_stake_pool_program(&programs[4]),
+            Err(crate::UnifiedSolPoolError::StakePoolProgramAllowlistFull)
+        );
+
+        config.remove_stake_pool_program(&programs[1]).unwrap();
+        assert!(!config.allows_stake_pool_program(&programs[1]));
+        assert_eq!(
+            config.remove_stake_pool_program(&programs[1]),
+            Err(crate::UnifiedSolPoolError::StakePoolProgramNotListed)
+        );
+
+        // The freed slot is reused
+        config.add_stake_pool_program(&programs[4]).unwrap();
+        assert!(config.allows_stake_pool_program(&programs[4]));
+    }
+
+    #[test]
+    fn test_keeper_allowlist() {
+        let mut config: UnifiedSolPoolConfig = bytemuck::Zeroable::zeroed();
+        let keepers: [Pubkey; 5] = core::array::from_fn(|i| [i as u8 + 1; 32]);
+
+        // Permissionless while the allowlist is not enforced
+        assert_eq!(config.check_keeper(&keepers[0]), Ok(()));
+        assert_eq!(
+            config.set_keeper_allowlist_enabled(true),
+            Err(crate::UnifiedSolPoolError::KeeperAllowlistEmpty)
+        );
+        assert_eq!(
+            config.add_keeper(&[0u8; 32]),
+            Err(crate::UnifiedSolPoolError::InvalidKeeper)
+        );
+
+        for keeper in &keepers[..MAX_KEEPERS] {
+            config.add_keeper(keeper).unwrap();
+        }
+        config.add_keeper(&keepers[0]).unwrap();
+        assert_eq!(config.keeper_count(), MAX_KEEPERS);
+        assert_eq!(
+            config.add_keeper(&keepers[4]),
+            Err(crate::UnifiedSolPoolError::KeeperAllowlistFull)
+        );
+
+        config.set_keeper_allowlist_enabled(true).unwrap();
+        assert_eq!(config.check_keeper(&keepers[1]), Ok(()));
+        assert_eq!(
+            config.check_keeper(&keepers[4]),
+            Err(crate::UnifiedSolPoolError::KeeperNotAllowed)
+        );
+        assert_eq!(
+            config.check_keeper(&[0u8; 32]),
+            Err(crate::UnifiedSolPoolError::KeeperNotAllowed)
+        );
+
+        config.remove_keeper(&keepers[1]).unwrap();
+        assert_eq!(
+            config.check_keeper(&keepers[1]),
+            Err(crate::UnifiedSolPoolError::KeeperNotAllowed)
+        );
+        assert_eq!(
+            config.remove_keeper(&keepers[1]),
+            Err(crate::UnifiedSolPoolError::KeeperNotListed)
+        );
+
+        // The last keeper stays while the allowlist is enforced
+        for keeper in [keepers[0], keepers[2]] {
+            config.remove_keeper(&keeper).unwrap();
+        }
+        assert_eq!(
+            config.remove_keeper(&keepers[3]),
+            Err(crate::UnifiedSolPoolError::KeeperAllowlistEmpty)
+        );
+        config.set_keeper_allowlist_enabled(false).unwrap();
+        config.remove_keeper(&keepers[3]).unwrap();
+        assert_eq!(config.keeper_count(), 0);
+        assert_eq!(config.check_keeper(&keepers[4]), Ok(()));
+    }
+
+    fn lst_config_with_bounds(harvested_exchange_rate: u64) -> LstConfig {
+        let mut config: LstConfig = bytemuck::Zeroable::zeroed();
+        config.pool_type = PoolType::SplStakePool as u8;
+        config.mode = LST_MODE_ALL;
+        config.harvested_exchange_rate = harvested_exchange_rate;
+        config.set_rate_bounds(50, 20, 10_000).unwrap();
+        config
+    }
+
+    #[test]
+    fn test_rate_bounds_band_edges() {
+        let config = lst_config_with_bounds(1_000_000_000);
+
+        // +50 bps is the edge; one lamport-per-SOL more is out
+        config.validate_rate_bounds(1_005_000_000).unwrap();
+        assert_eq!(
+            config.validate_rate_bounds(1_005_000_001),
+            Err(crate::UnifiedSolPoolError::ExchangeRateOutOfBounds)
+        );
+
+        // -20 bps is the edge on the way down
+        config.validate_rate_bounds(998_000_000).unwrap();
+        assert_eq!(
+            config.validate_rate_bounds(997_999_999),
+            Err(crate::UnifiedSolPoolError::ExchangeRateOutOfBounds)
+        );
+
+        // The band scales with the current frozen rate
+        let config = lst_config_with_bounds(1_200_000_000);
+        config.validate_rate_bounds(1_206_000_000).unwrap();
+        assert_eq!(
+            config.validate_rate_bounds(1_206_000_001),
+            Err(crate::UnifiedSolPoolError::ExchangeRateOutOfBounds)
+        );
+    }
+
+    #[test]
+    fn test_rate_jump_approval() {
+        let mut config = lst_config_with_bounds(1_000_000_000);
+        config.exchange_rate = 1_250_000_000;
+
+        assert_eq!(
+            config.freeze_exchange_rate(100),
+            Err(crate::UnifiedSolPoolError::ExchangeRateOutOfBounds)
+        );
+
+        // An approval only covers the exact rate
+        config.approved_exchange_rate = 1_249_000_000;
+        assert_eq!(
+            config.freeze_exchange_rate(100),
+            Err(crate::UnifiedSolPoolError::ExchangeRateOutOfBounds)
+        );
+
+        config.approved_exchange_rate = 1_250_000_000;
+        config.freeze_exchange_rate(100).unwrap();
+        assert_eq!(config.harvested_exchange_rate, 1_250_000_000);
+        assert_eq!(config.rate_updated_slot, 100);
+        assert_eq!(config.approved_exchange_rate, 0);
+    }
+
+    #[test]
+    fn test_rate_staleness() {
+        let mut config = lst_config_with_bounds(1_000_000_000);
+        config.rate_updated_slot = 5_000;
+
+        config.check_rate_fresh(15_000).unwrap();
+        assert_eq!(
+            config.check_rate_fresh(15_001),
+            Err(crate::UnifiedSolPoolError::ExchangeRateStale)
+        );
+
+        // Retired LSTs drain at their frozen rate
+        config.is_retired = 1;
+        config.check_rate_fresh(15_001).unwrap();
+
+        // WSOL is always 1:1
+        config.is_retired = 0;
+        config.pool_type = PoolType::Wsol as u8;
+        config.check_rate_fresh(u64::MAX).unwrap();
+    }
+
+    #[test]
+    fn test_set_rate_bounds_ceilings() {
+        let mut config: LstConfig = bytemuck::Zeroable::zeroed();
+        let ceiling = LstConfig::MAX_RATE_BAND_BPS_CEILING;
+        let interval = UnifiedSolPoolConfig::UPDATE_SLOT_INTERVAL;
+
+        config
+            .set_rate_bounds(
+                ceiling,
+                ceiling,
+                LstConfig::MAX_RATE_STALENESS_SLOTS_CEILING,
+            )
+            .unwrap();
+        config.set_rate_bounds(0, 0, interval).unwrap();
+
+        for (increase, decrease, staleness) in [
+            (ceiling + 1, 0, interval),
+            (0, ceiling + 1, interval),
+            (0, 0, interval - 1),
+            (0, 0, LstConfig::MAX_RATE_STALENESS_SLOTS_CEILING + 1),
+        ] {
+            assert_eq!(
+                config.set_rate_bounds(increase, decrease, staleness),
+                Err(crate::UnifiedSolPoolError::InvalidRateBounds)
+            );
+        }
+        // A rejected update leaves the previous bounds in place
+        assert_eq!(config.max_rate_staleness_slots, interval);
+    }
+
+    #[test]
+    fn test_virtual_sol_cap_boundaries() {
+        let mut lst: LstConfig = bytemuck::Zeroable::zeroed();
+        let mut pool: UnifiedSolPoolConfig = bytemuck::Zeroable::zeroed();
+
+        // Uncapped by default
+        lst.total_virtual_sol = u64::MAX as u128;
+        lst.check_virtual_sol_cap(u64::MAX).unwrap();
+        pool.check_virtual_sol_cap(u64::MAX).unwrap();
+
+        // Reaching the cap exactly is allowed; one lamport past it is not
+        lst.max_virtual_sol = 1_000;
+        lst.total_virtual_sol = 400;
+        lst.check_virtual_sol_cap(600).unwrap();
+        assert_eq!(
+            lst.check_virtual_sol_cap(601),
+            Err(crate::UnifiedSolPoolError::LstVirtualSolCapExceeded)
+        );
+
+        pool.max_total_virtual_sol = 5_000;
+        pool.total_virtual_sol = 4_999;
+        pool.check_virtual_sol_cap(1).unwrap();
+        assert_eq!(
+            pool.check_virtual_sol_cap(2),
+            Err(crate::UnifiedSolPoolError::PoolVirtualSolCapExceeded)
+        );
+    }
+
+    #[test]
+    fn test_virtual_sol_overflow_parking() {
+        let mut lst: LstConfig = bytemuck::Zeroable::zeroed();
+        lst.max_virtual_sol = 1_000;
+
+        // Appreciation at finalization pushes the LST past its cap: parked
+        lst.total_virtual_sol = 1_050;
+        lst.park_virtual_sol_overflow();
+        assert_eq!(lst.virtual_sol_over_cap, 50);
+        // Deposits stay closed while over the cap
+        assert_eq!(
+            lst.check_virtual_sol_cap(1),
+            Err(crate::UnifiedSolPoolError::LstVirtualSolCapExceeded)
+        );
+
+        // Withdrawals drain the bucket
+        lst.total_virtual_sol = 1_020;
+        lst.park_virtual_sol_overflow();
+        assert_eq!(lst.virtual_sol_over_cap, 20);
+        lst.total_virtual_sol = 900;
+        lst.park_virtual_sol_overflow();
+        assert_eq!(lst.virtual_sol_over_cap, 0);
+        lst.check_virtual_sol_cap(100).unwrap();
+
+        // Lifting the cap empties the bucket
+        let mut pool: UnifiedSolPoolConfig = bytemuck::Zeroable::zeroed();
+        pool.max_total_virtual_sol = 1_000;
+        pool.total_virtual_sol = 3_000;
+        pool.park_virtual_sol_overflow();
+        assert_eq!(pool.total_virtual_sol_over_cap, 2_000);
+        pool.max_total_virtual_sol = 0;
+        pool.park_virtual_sol_overflow();
+        assert_eq!(pool.total_virtual_sol_over_cap, 0);
+    }
+
+    #[test]
+    fn test_withdraw_priority_order() {
+        let mut config: UnifiedSolPoolConfig = bytemuck::Zeroable::zeroed();
+        let [a, b, c]: [Pubkey; 3] = core::array::from_fn(|i| [i as u8 + 1; 32]);
+
+        assert_eq!(
+            config.set_withdraw_priority(&[a, b, a, [0u8; 32]]),
+            Err(crate::UnifiedSolPoolError::InvalidWithdrawPriority)
+        );
+        // Empty slots may repeat
+        config
+            .set_withdraw_priority(&[a, [0u8; 32], b, [0u8; 32]])
+            .unwrap();
+
+        // Named LST and empty slots are skipped
+        let mut cursor = 0;
+        assert_eq!(config.next_route_mint(&mut cursor, &a), Some(b));
+        assert_eq!(config.next_route_mint(&mut cursor, &a), None);
+
+        let mut cursor = 0;
+        assert_eq!(config.next_route_mint(&mut cursor, &c), Some(a));
+        assert_eq!(config.next_route_mint(&mut cursor, &c), Some(b));
+        assert_eq!(config.next_route_mint(&mut cursor, &c), None);
+    }
+
+    #[test]
+    fn test_route_fill() {
+        let rate = LstConfig::RATE_PRECISION * 2; // 1 token = 2 SOL
+
+        // Single vault: enough liquidity fills the whole amount
+        assert_eq!(route_fill(1_000, 10_000, rate), Some((500, 1_000)));
+
+        // Two vaults: the first is drained, the second covers the rest
+        let (tokens, drawn) = route_fill(1_000, 300, rate).unwrap();
+        assert_eq!((tokens, drawn), (300, 600));
+        assert_eq!(
+            route_fill(1_000 - drawn, 1_000, LstConfig::RATE_PRECISION),
+            Some((400, 400))
+        );
+
+        // Insufficient total: both vaults drained, a shortfall remains
+        let (_, first) = route_fill(1_000, 100, rate).unwrap();
+        let (_, second) = route_fill(1_000 - first, 100, LstConfig::RATE_PRECISION).unwrap();
+        assert_eq!(1_000 - first - second, 700);
+
+        // Empty vault draws nothing
+        assert_eq!(route_fill(1_000, 0, rate), Some((0, 0)));
+    }
+
+    /// Record `rate` for `epoch` the way finalization does (slot = epoch * 10).
+    fn freeze_at(history: &mut LstRateHistory, rate: u64, epoch: u64) {
+        history.push(rate, epoch, epoch * 10);
+    }
+
+    #[test]
+    fn test_rate_history_wraparound() {
+        let mut history = LstRateHistory::default();
+        assert_eq!(history.iter().count(), 0);
+
+        // Partially filled: oldest first
+        for epoch in 1..=3 {
+            freeze_at(&mut history, 1_000_000_000 + epoch, epoch);
+        }
+        let epochs: Vec<u64> = history.iter().map(|e| e.epoch).collect();
+        assert_eq!(epochs, [1, 2, 3]);
+
+        // Past capacity the oldest entries are overwritten, order is kept
+        let pushes = RATE_HISTORY_LEN as u64 + 5;
+        for epoch in 4..=pushes {
+            freeze_at(&mut history, 1_000_000_000 + epoch, epoch);
+        }
+        let recorded: Vec<RateHistoryEntry> = history.iter().copied().collect();
+        assert_eq!(recorded.len(), RATE_HISTORY_LEN);
+        assert_eq!(history.count, pushes);
+        for (entry, epoch) in recorded.iter().zip(6..=pushes) {
+            assert_eq!(
+                *entry,
+                RateHistoryEntry {
+                    rate: 1_000_000_000 + epoch,
+                    epoch,
+                    slot: epoch * 10,
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_rate_at_epoch() {
+        let mut history = LstRateHistory::default();
+        assert_eq!(history.rate_at_epoch(1), None);
+
+        // Inactive LSTs skip freezes, leaving gaps in the epochs
+        freeze_at(&mut history, 1_001_000_000, 2);
+        freeze_at(&mut history, 1_002_000_000, 3);
+        freeze_at(&mut history, 1_003_000_000, 6);
+
+        // Hits: exact epochs and the rate still in force across a gap
+        assert_eq!(history.rate_at_epoch(2), Some(1_001_000_000));
+        assert_eq!(history.rate_at_epoch(3), Some(1_002_000_000));
+        assert_eq!(history.rate_at_epoch(5), Some(1_002_000_000));
+        assert_eq!(history.rate_at_epoch(100), Some(1_003_000_000));
+        // Miss: before the first recorded rate
+        assert_eq!(history.rate_at_epoch(1), None);
+
+        // Once wrapped, epochs older than the buffer miss
+        for epoch in 7..7 + RATE_HISTORY_LEN as u64 {
+            freeze_at(&mut history, 1_004_000_000, epoch);
+        }
+        assert_eq!(history.rate_at_epoch(6), None);
+        assert_eq!(history.rate_at_epoch(7), Some(1_004_000_000));
+    }
+
+    #[test]
+    fn test_rate_history_account_data() {
+        // 8-byte aligned backing, as for on-chain account data
+        let mut words = vec![0u64; LST_CONFIG_ACCOUNT_SIZE / 8];
+        let data: &mut [u8] = bytemuck::cast_slice_mut(&mut words);
+
+        // Version 1 accounts end before the extension
+        assert!(LstRateHistory::from_account_data(&data[..LST_CONFIG_V1_SIZE]).is_none());
+        assert!(
+            LstRateHistory::from_account_data_mut(&mut data[..LstManualRate::OFFSET - 1]).is_none()
+        );
+
+        // Writes land after the LstConfig, leaving it untouched
+        LstRateHistory::from_account_data_mut(data)
+            .unwrap()
+            .push(1_001_000_000, 2, 20);
+        assert!(data[..LST_CONFIG_V1_SIZE].iter().all(|b| *b == 0));
+        let history = LstRateHistory::from_account_data(data).unwrap();
+        assert_eq!(history.count, 1);
+        assert_eq!(history.rate_at_epoch(2), Some(1_001_000_000));
+    }
+
+    #[test]
+    fn test_manual_rate_lifecycle() {
+        let mut words = vec![0u64; LST_CONFIG_ACCOUNT_SIZE / 8];
+        let data: &mut [u8] = bytemuck::cast_slice_mut(&mut words);
+
+        // Version 2 accounts end before the extension
+        assert!(LstManualRate::from_account_data(&data[..LstManualRate::OFFSET]).is_none());
+
+        let proposal = LstManualRate::from_account_data_mut(data).unwrap();
+        assert_eq!(
+            proposal.unlocked_rate(0),
+            Err(crate::UnifiedSolPoolError::ManualRateNotProposed)
+        );
+
+        proposal.propose(1_010_000_000, 500);
+        assert!(proposal.is_pending());
+        assert_eq!(
+            proposal.unlocked_rate(499),
+            Err(crate::UnifiedSolPoolError::ManualRateTimelocked)
+        );
+        assert_eq!(proposal.unlocked_rate(500), Ok(1_010_000_000));
+
+        proposal.clear();
+        assert!(!proposal.is_pending());
+        // The rate history is untouched
+        assert!(data[..LstManualRate::OFFSET].iter().all(|b| *b == 0));
+    }
+
+    #[test]
+    fn test_pending_rewards_accumulate() {
+        let mut words = vec![0u64; LST_CONFIG_ACCOUNT_SIZE / 8];
+        let data: &mut [u8] = bytemuck::cast_slice_mut(&mut words);
+
+        // Version 3 accounts end before the extension
+        assert!(LstPendingRewards::from_account_data(&data[..LstPendingRewards::OFFSET]).is_none());
+
+        let pending = LstPendingRewards::from_account_data_mut(data).unwrap();
+        pending.add_deposit_fee(10).unwrap();
+        pending.add_deposit_fee(5).unwrap();
+        pending.add_withdrawal_fee(7).unwrap();
+        pending.add_appreciation(100).unwrap();
+        assert_eq!(
+            *pending,
+            LstPendingRewards {
+                deposit_fees: 15,
+                withdrawal_fees: 7,
+                appreciation: 100,
+            }
+        );
+        assert_eq!(
+            pending.add_appreciation(u64::MAX),
+            Err(crate::UnifiedSolPoolError::ArithmeticOverflow)
+        );
+    }
+
+    #[test]
+    fn test_manual_rate_delay_bounds() {
+        let mut config: UnifiedSolPoolConfig = bytemuck::Zeroable::zeroed();
+        // Accounts created before the field use the default
+        assert_eq!(
+            config.manual_rate_delay(),
+            UnifiedSolPoolConfig::DEFAULT_MANUAL_RATE_DELAY_SLOTS
+        );
+
+        for slots in [
+            UnifiedSolPoolConfig::MIN_MANUAL_RATE_DELAY_SLOTS - 1,
+            UnifiedSolPoolConfig::MAX_MANUAL_RATE_DELAY_SLOTS + 1,
+        ] {
+            assert_eq!(
+                config.set_manual_rate_delay(slots),
+                Err(crate::UnifiedSolPoolError::InvalidManualRateDelay)
+            );
+        }
+
+        config
+            .set_manual_rate_delay(UnifiedSolPoolConfig::MIN_MANUAL_RATE_DELAY_SLOTS)
+            .unwrap();
+        assert_eq!(
+            config.manual_rate_delay(),
+            UnifiedSolPoolConfig::MIN_MANUAL_RATE_DELAY_SLOTS
+        );
+    }
 }
@@ -1,7 +1,12 @@
 //! Utility functions for unified SOL pool operations.
 
-use crate::UnifiedSolPoolError;
-use pinocchio::account_info::AccountInfo;
+use crate::{LstPendingRewards, UnifiedSolPoolError};
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    sysvars::{Sysvar, rent::Rent},
+};
+use pinocchio_system::instructions::Transfer;
 use pinocchio_token::state::TokenAccount;
 
 /// Read the balance from a token account using pinocchio_token typed access.
@@ -10,3 +15,42 @@ pub fn read_token_account_balance(account: &AccountInfo) -> Result<u64, UnifiedS
         .map_err(|_| UnifiedSolPoolError::InvalidInstructionData)?;
     Ok(token_account.amount())
 }
+
+/// Grow a program-owned account to `new_size`, with `payer` covering the
+/// additional rent. The new region is zero-filled.
+pub fn grow_account(account: &AccountInfo, payer: &AccountInfo, new_size: usize) -> ProgramResult {
+    let old_size = account.data_len();
+    if new_size <= old_size {
+        return Ok(());
+    }
+
+    let required_lamports = Rent::get()?.minimum_balance(new_size);
+    let shortfall = required_lamports.saturating_sub(account.lamports());
+    if shortfall > 0 {
+        Transfer {
+            from: payer,
+            to: account,
+            lamports: shortfall,
+        }
+        .invoke()?;
+    }
+
+    account.resize(new_size)?;
+    account.try_borrow_mut_data()?[old_size..].fill(0);
+    Ok(())
+}
+
+/// Update the [`LstPendingRewards`] extension of an LstConfig account.
+///
+/// Accounts that predate the extension (version 3 or older) are skipped:
+/// their share of the pool's pending rewards goes unattributed until migrated.
+pub fn record_pending_rewards(
+    lst_config: &AccountInfo,
+    record: impl FnOnce(&mut LstPendingRewards) -> Result<(), UnifiedSolPoolError>,
+) -> ProgramResult {
+    let mut data = lst_config.try_borrow_mut_data()?;
+    if let Some(pending) = LstPendingRewards::from_account_data_mut(&mut data) {
+        record(pending)?;
+    }
+    Ok(())
+}
@@ -4,6 +4,7 @@ mod common;
 
 use common::*;
 use litesvm::LiteSVM;
+use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 
@@ -363,3 +364,231 @@ fn test_set_lst_config_active_unauthorized() {
         "disable lst_config should fail with wrong authority"
     );
 }
+// ============================================================================
+// SetLstConfigFeeOverrides Tests
+// ============================================================================
+
+/// Test setting, validating and clearing per-LST fee overrides.
+#[test]
+fn test_set_lst_config_fee_overrides() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    let other_user = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+    svm.airdrop(&other_user.pubkey(), 10_000_000_000).unwrap();
+
+    let unified_config = init_unified_sol_pool_config(
+        &mut svm,
+        &program_id,
+        &authority,
+        0,
+        100,
+        50,
+        2000,
+        1_000_000_000,
+    )
+    .expect("init_unified_sol_pool_config should succeed");
+
+    let wsol_mint = create_mock_mint(&mut svm, 9);
+    let lst_config = init_lst_config(
+        &mut svm,
+        &program_id,
+        &unified_config,
+        &wsol_mint,
+        &wsol_mint,
+        &SPL_TOKEN_PROGRAM_ID,
+        &authority,
+        pool_types::WSOL,
+    )
+    .expect("init_lst_config should succeed");
+
+    // New LSTs use the pool-wide rates
+    assert_eq!(
+        get_lst_config_fee_overrides(&svm, &lst_config),
+        (0xFFFF, 0xFFFF)
+    );
+
+    // Override both; 0 is a valid override (fee-free)
+    set_lst_config_fee_overrides(
+        &mut svm,
+        &program_id,
+        &unified_config,
+        &lst_config,
+        &authority,
+        25,
+        0,
+    )
+    .expect("set_lst_config_fee_overrides should succeed");
+    assert_eq!(get_lst_config_fee_overrides(&svm, &lst_config), (25, 0));
+
+    // Rates above 100% (other than the unset sentinel) are rejected
+    let result = set_lst_config_fee_overrides(
+        &mut svm,
+        &program_id,
+        &unified_config,
+        &lst_config,
+        &authority,
+        10_001,
+        0,
+    );
+    assert!(result.is_err(), "deposit override above 10000 should fail");
+    let result = set_lst_config_fee_overrides(
+        &mut svm,
+        &program_id,
+        &unified_config,
+        &lst_config,
+        &authority,
+        0xFFFF,
+        10_001,
+    );
+    assert!(result.is_err(), "withdraw override above 10000 should fail");
+
+    // Only the authority can set overrides
+    let result = set_lst_config_fee_overrides(
+        &mut svm,
+        &program_id,
+        &unified_config,
+        &lst_config,
+        &other_user,
+        0xFFFF,
+        0xFFFF,
+    );
+    assert!(result.is_err(), "non-authority should not set overrides");
+    assert_eq!(get_lst_config_fee_overrides(&svm, &lst_config), (25, 0));
+
+    // Clear both overrides
+    set_lst_config_fee_overrides(
+        &mut svm,
+        &program_id,
+        &unified_config,
+        &lst_config,
+        &authority,
+        0xFFFF,
+        0xFFFF,
+    )
+    .expect("clearing overrides should succeed");
+    assert_eq!(
+        get_lst_config_fee_overrides(&svm, &lst_config),
+        (0xFFFF, 0xFFFF)
+    );
+}
+
+// ============================================================================
+// Stake Pool Program Allowlist Tests
+// ============================================================================
+
+/// Test the authority can extend and trim the stake pool program allowlist.
+#[test]
+fn test_stake_pool_program_allowlist() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let unified_config =
+        init_unified_sol_pool_config(&mut svm, &program_id, &authority, 0, 0, 0, 0, 0)
+            .expect("init_unified_sol_pool_config should succeed");
+
+    // Seeded with the SPL stake pool program only
+    assert_eq!(
+        get_unified_config_stake_pool_programs(&svm, &unified_config),
+        vec![SPL_STAKE_POOL_PROGRAM_ID]
+    );
+
+    let sanctum_program = Pubkey::new_unique();
+    add_stake_pool_program(
+        &mut svm,
+        &program_id,
+        &unified_config,
+        &authority,
+        &sanctum_program,
+    )
+    .expect("add_stake_pool_program should succeed");
+    assert_eq!(
+        get_unified_config_stake_pool_programs(&svm, &unified_config),
+        vec![SPL_STAKE_POOL_PROGRAM_ID, sanctum_program]
+    );
+
+    // Fill the remaining slots; a fifth program does not fit
+    for _ in 0..2 {
+        add_stake_pool_program(
+            &mut svm,
+            &program_id,
+            &unified_config,
+            &authority,
+            &Pubkey::new_unique(),
+        )
+        .expect("add_stake_pool_program should succeed");
+    }
+    let result = add_stake_pool_program(
+        &mut svm,
+        &program_id,
+        &unified_config,
+        &authority,
+        &Pubkey::new_unique(),
+    );
+    assert!(result.is_err(), "allowlist should be full");
+
+    remove_stake_pool_program(
+        &mut svm,
+        &program_id,
+        &unified_config,
+        &authority,
+        &sanctum_program,
+    )
+    .expect("remove_stake_pool_program should succeed");
+    assert!(
+        !get_unified_config_stake_pool_programs(&svm, &unified_config).contains(&sanctum_program)
+    );
+
+    svm.expire_blockhash();
+    let result = remove_stake_pool_program(
+        &mut svm,
+        &program_id,
+        &unified_config,
+        &authority,
+        &sanctum_program,
+    );
+    assert!(result.is_err(), "removing an unlisted program should fail");
+}
+
+/// Test only the authority can change the stake pool program allowlist.
+#[test]
+fn test_stake_pool_program_allowlist_unauthorized() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    let other_user = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+    svm.airdrop(&other_user.pubkey(), 10_000_000_000).unwrap();
+
+    let unified_config =
+        init_unified_sol_pool_config(&mut svm, &program_id, &authority, 0, 0, 0, 0, 0)
+            .expect("init_unified_sol_pool_config should succeed");
+
+    let result = add_stake_pool_program(
+        &mut svm,
+        &program_id,
+        &unified_config,
+        &other_user,
+        &Pubkey::new_unique(),
+    );
+    assert!(result.is_err(), "add should fail with wrong authority");
+
+    let result = remove_stake_pool_program(
+        &mut svm,
+        &program_id,
+        &unified_config,
+        &other_user,
+        &SPL_STAKE_POOL_PROGRAM_ID,
+    );
+    assert!(result.is_err(), "remove should fail with wrong authority");
+    assert_eq!(
+        get_unified_config_stake_pool_programs(&svm, &unified_config),
+        vec![SPL_STAKE_POOL_PROGRAM_ID]
+    );
+}
@@ -0,0 +1,243 @@
+//! Unified SOL pool protocol commission tests.
+//!
+//! Tests for SetAppreciationCommission and WithdrawCommission. The split of
+//! harvested appreciation itself is covered by the `add_appreciation` tests
+//! in integration_test.rs; accrued commission is seeded directly here.
+
+mod common;
+
+use common::*;
+use litesvm::LiteSVM;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+/// Vault balance (and virtual SOL, at 1:1) funded into the LST
+const FUNDED: u64 = 100_000_000_000;
+
+/// Commission seeded as accrued before withdrawing
+const ACCRUED: u64 = 1_000_000_000;
+
+/// UnifiedSolPoolError::Unauthorized
+const UNAUTHORIZED: u32 = 18;
+/// UnifiedSolPoolError::InvalidFeeRate
+const INVALID_FEE_RATE: u32 = 25;
+/// UnifiedSolPoolError::InsufficientCommission
+const INSUFFICIENT_COMMISSION: u32 = 54;
+/// UnifiedSolPoolError::InvalidCommissionDestination
+const INVALID_COMMISSION_DESTINATION: u32 = 55;
+
+/// Test the commission setter stores the rate and treasury and rejects
+/// non-authorities and rates above 100%.
+#[test]
+fn test_set_appreciation_commission() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, _, _, _) = setup_funded_lst(
+        &mut svm,
+        &program_id,
+        &authority,
+        pool_types::SPL_STAKE_POOL,
+        FUNDED,
+    );
+
+    // No commission by default
+    assert_eq!(
+        get_unified_config_commission(&svm, &unified_sol_config),
+        (Pubkey::default(), 0, 0)
+    );
+
+    let treasury = Pubkey::new_unique();
+    set_appreciation_commission(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        &treasury,
+        1_000,
+    )
+    .expect("authority should set the commission");
+    assert_eq!(
+        get_unified_config_commission(&svm, &unified_sol_config),
+        (treasury, 0, 1_000)
+    );
+
+    let attacker = Keypair::new();
+    svm.airdrop(&attacker.pubkey(), 1_000_000_000).unwrap();
+    let err = set_appreciation_commission(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &attacker,
+        &attacker.pubkey(),
+        10_000,
+    )
+    .expect_err("non-authority should be rejected");
+    assert!(err.contains(&custom_error(UNAUTHORIZED)), "{}", err);
+
+    let err = set_appreciation_commission(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        &treasury,
+        10_001,
+    )
+    .expect_err("commission above 100% should be rejected");
+    assert!(err.contains(&custom_error(INVALID_FEE_RATE)), "{}", err);
+    assert_eq!(
+        get_unified_config_commission(&svm, &unified_sol_config),
+        (treasury, 0, 1_000)
+    );
+}
+
+/// Test accrued commission pays out to a treasury-owned token account.
+#[test]
+fn test_withdraw_commission() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, lst_mint, lst_vault) = setup_funded_lst(
+        &mut svm,
+        &program_id,
+        &authority,
+        pool_types::SPL_STAKE_POOL,
+        FUNDED,
+    );
+    let treasury = Pubkey::new_unique();
+    let destination = create_mock_token_account(&mut svm, &lst_mint, &treasury);
+    update_unified_config_accrued_commission(&mut svm, &unified_sol_config, ACCRUED);
+
+    // Nothing can be withdrawn until a treasury is set
+    let err = withdraw_commission(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &destination,
+        &authority,
+        ACCRUED,
+    )
+    .expect_err("withdrawal without a treasury should be rejected");
+    assert!(
+        err.contains(&custom_error(INVALID_COMMISSION_DESTINATION)),
+        "{}",
+        err
+    );
+
+    set_appreciation_commission(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        &treasury,
+        1_000,
+    )
+    .expect("authority should set the commission");
+
+    withdraw_commission(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &destination,
+        &authority,
+        400_000_000,
+    )
+    .expect("authority should withdraw accrued commission");
+
+    // 1:1 rate, so the virtual SOL amount pays out token for token
+    assert_eq!(get_token_balance(&svm, &destination), 400_000_000);
+    assert_eq!(get_token_balance(&svm, &lst_vault), FUNDED - 400_000_000);
+    assert_eq!(
+        get_unified_config_commission(&svm, &unified_sol_config),
+        (treasury, ACCRUED - 400_000_000, 1_000)
+    );
+
+    let err = withdraw_commission(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &destination,
+        &authority,
+        ACCRUED,
+    )
+    .expect_err("withdrawing more than accrued should be rejected");
+    assert!(
+        err.contains(&custom_error(INSUFFICIENT_COMMISSION)),
+        "{}",
+        err
+    );
+}
+
+/// Test commission cannot be sent elsewhere or withdrawn by a non-authority.
+#[test]
+fn test_withdraw_commission_rejects_invalid_callers() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, lst_mint, _) = setup_funded_lst(
+        &mut svm,
+        &program_id,
+        &authority,
+        pool_types::SPL_STAKE_POOL,
+        FUNDED,
+    );
+    let treasury = Pubkey::new_unique();
+    set_appreciation_commission(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        &treasury,
+        1_000,
+    )
+    .expect("authority should set the commission");
+    update_unified_config_accrued_commission(&mut svm, &unified_sol_config, ACCRUED);
+
+    let attacker = Keypair::new();
+    svm.airdrop(&attacker.pubkey(), 1_000_000_000).unwrap();
+    let attacker_account = create_mock_token_account(&mut svm, &lst_mint, &attacker.pubkey());
+
+    let err = withdraw_commission(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &attacker_account,
+        &authority,
+        ACCRUED,
+    )
+    .expect_err("destination not owned by the treasury should be rejected");
+    assert!(
+        err.contains(&custom_error(INVALID_COMMISSION_DESTINATION)),
+        "{}",
+        err
+    );
+
+    let destination = create_mock_token_account(&mut svm, &lst_mint, &treasury);
+    let err = withdraw_commission(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &destination,
+        &attacker,
+        ACCRUED,
+    )
+    .expect_err("non-authority should be rejected");
+    assert!(err.contains(&custom_error(UNAUTHORIZED)), "{}", err);
+
+    assert_eq!(
+        get_unified_config_commission(&svm, &unified_sol_config),
+        (treasury, ACCRUED, 1_000)
+    );
+}
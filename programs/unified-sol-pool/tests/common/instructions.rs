@@ -22,14 +22,53 @@ pub mod discriminators {
     pub const SET_UNIFIED_SOL_POOL_CONFIG_FEE_RATES: u8 = 68;
     pub const FINALIZE_UNIFIED_REWARDS: u8 = 69;
     pub const HARVEST_LST_APPRECIATION: u8 = 70;
+    pub const LOG: u8 = 71;
+    pub const HARVEST_ALL_LST_APPRECIATION: u8 = 72;
     pub const TRANSFER_AUTHORITY: u8 = 192;
     pub const ACCEPT_AUTHORITY: u8 = 193;
+    pub const ADD_STAKE_POOL_PROGRAM: u8 = 194;
+    pub const REMOVE_STAKE_POOL_PROGRAM: u8 = 195;
+    pub const SET_LST_CONFIG_RETIRED: u8 = 196;
+    pub const CLOSE_LST_CONFIG: u8 = 197;
+    pub const SET_LST_CONFIG_RATE_BOUNDS: u8 = 198;
+    pub const APPROVE_LST_RATE_JUMP: u8 = 199;
+    pub const SET_LST_CONFIG_FEE_OVERRIDES: u8 = 200;
+    pub const SET_LST_CONFIG_VIRTUAL_SOL_CAP: u8 = 201;
+    pub const SET_UNIFIED_SOL_POOL_CONFIG_VIRTUAL_SOL_CAP: u8 = 202;
+    pub const SET_WITHDRAW_PRIORITY: u8 = 203;
+    pub const SET_APPRECIATION_COMMISSION: u8 = 204;
+    pub const WITHDRAW_COMMISSION: u8 = 205;
+    pub const MIGRATE_LST_CONFIG: u8 = 206;
+    pub const SET_EPOCH_MODE: u8 = 207;
+    pub const SET_LST_CONFIG_MODE: u8 = 208;
+    pub const SET_MIN_HARVEST_LAMPORTS: u8 = 209;
+    pub const PROPOSE_MANUAL_RATE: u8 = 210;
+    pub const APPLY_MANUAL_RATE: u8 = 211;
+    pub const CANCEL_MANUAL_RATE: u8 = 212;
+    pub const SET_MANUAL_RATE_DELAY: u8 = 213;
+    pub const ADD_KEEPER: u8 = 214;
+    pub const REMOVE_KEEPER: u8 = 215;
+    pub const SET_KEEPER_ALLOWLIST_ENABLED: u8 = 216;
+}
+
+/// LstConfig mode bits
+pub mod lst_modes {
+    pub const WITHDRAWALS_ENABLED: u8 = 1 << 0;
+    pub const DEPOSITS_ENABLED: u8 = 1 << 1;
+    pub const ALL: u8 = DEPOSITS_ENABLED | WITHDRAWALS_ENABLED;
+}
+
+/// Epoch mode enum values
+pub mod epoch_modes {
+    pub const SLOT_INTERVAL: u8 = 0;
+    pub const SOLANA_EPOCH: u8 = 1;
 }
 
 /// Pool type enum values
 pub mod pool_types {
     pub const WSOL: u8 = 0;
     pub const SPL_STAKE_POOL: u8 = 1;
+    pub const MARINADE: u8 = 2;
 }
 
 /// Build instruction data with discriminator and Borsh-serialized args.
@@ -261,14 +300,220 @@ pub fn set_lst_config_active(
         .map_err(|e| format!("{:?}", e))
 }
 
+/// Args for SetLstConfigMode instruction
+#[derive(BorshSerialize)]
+struct SetLstConfigModeArgs {
+    mode: u8,
+    _padding: [u8; 7],
+}
+
+/// Set which directions are enabled for an LST config.
+pub fn set_lst_config_mode(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    authority: &Keypair,
+    mode: u8,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*unified_sol_pool_config, false),
+            AccountMeta::new(*lst_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+        ],
+        data: build_instruction_data(
+            discriminators::SET_LST_CONFIG_MODE,
+            &SetLstConfigModeArgs {
+                mode,
+                _padding: [0; 7],
+            },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+// ============================================================================
+// SetLstConfigRetired / CloseLstConfig
+// ============================================================================
+
+/// Args for SetLstConfigRetired instruction
+#[derive(BorshSerialize)]
+struct SetLstConfigRetiredArgs {
+    is_retired: u8,
+    _padding: [u8; 7],
+}
+
+/// Set the retirement state for an LST config.
+pub fn set_lst_config_retired(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    authority: &Keypair,
+    is_retired: bool,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*unified_sol_pool_config, false),
+            AccountMeta::new(*lst_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // unified_sol_program for self-CPI events
+        ],
+        data: build_instruction_data(
+            discriminators::SET_LST_CONFIG_RETIRED,
+            &SetLstConfigRetiredArgs {
+                is_retired: is_retired as u8,
+                _padding: [0; 7],
+            },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Close a retired, drained LST config and its vault.
+pub fn close_lst_config(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    authority: &Keypair,
+) -> Result<(), String> {
+    let (lst_vault, _) = find_lst_vault_pda(program_id, lst_config);
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*unified_sol_pool_config, false),
+            AccountMeta::new(*lst_config, false),
+            AccountMeta::new(lst_vault, false),
+            AccountMeta::new(authority.pubkey(), true),
+            AccountMeta::new_readonly(SPL_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*program_id, false), // unified_sol_program for self-CPI events
+        ],
+        data: build_instruction_data_no_args(discriminators::CLOSE_LST_CONFIG),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Realloc an LstConfig to the current layout (payer covers the extra rent).
+pub fn migrate_lst_config(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    authority: &Keypair,
+    payer: &Keypair,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*unified_sol_pool_config, false),
+            AccountMeta::new(*lst_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*program_id, false), // unified_sol_program for self-CPI events
+        ],
+        data: build_instruction_data_no_args(discriminators::MIGRATE_LST_CONFIG),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        &[authority, payer],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Args for SetEpochMode instruction
+#[derive(BorshSerialize)]
+struct SetEpochModeArgs {
+    epoch_mode: u8,
+    _padding: [u8; 7],
+}
+
+/// Switch reward epochs between the slot interval and Solana epochs.
+pub fn set_epoch_mode(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    authority: &Keypair,
+    epoch_mode: u8,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*unified_sol_pool_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // unified_sol_program for self-CPI events
+        ],
+        data: build_instruction_data(
+            discriminators::SET_EPOCH_MODE,
+            &SetEpochModeArgs {
+                epoch_mode,
+                _padding: [0u8; 7],
+            },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
 // ============================================================================
 // FinalizeUnifiedRewards (AdvanceUnifiedEpoch)
 // ============================================================================
 
 /// Advance the unified SOL reward epoch.
 ///
-/// This is permissionless. Requires all registered LST configs to be passed
-/// and all must have been harvested in the current epoch.
+/// Permissionless unless the keeper allowlist is enforced; `payer` signs as
+/// the caller. Requires all registered LST configs to be passed and all must
+/// have been harvested in the current epoch.
 pub fn advance_unified_epoch(
     svm: &mut LiteSVM,
     program_id: &Pubkey,
@@ -276,13 +521,30 @@ pub fn advance_unified_epoch(
     lst_configs: &[Pubkey],
     payer: &Keypair,
 ) -> Result<(), String> {
+    advance_unified_epoch_with_events(svm, program_id, unified_sol_pool_config, lst_configs, payer)
+        .map(|_| ())
+}
+
+/// Advance the unified SOL reward epoch and return the events it emitted.
+///
+/// Each event is its 8-byte discriminator followed by the Pod payload, in
+/// emission order (the per-LST `LstFinalized` events, then the aggregate).
+pub fn advance_unified_epoch_with_events(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    lst_configs: &[Pubkey],
+    payer: &Keypair,
+) -> Result<Vec<Vec<u8>>, String> {
     // FinalizeUnifiedRewardsAccounts has:
     // 1. unified_sol_pool_config (mutable)
-    // 2. unified_sol_program (for self-CPI event emission)
+    // 2. caller (signer, checked against the keeper allowlist)
+    // 3. unified_sol_program (for self-CPI event emission)
     // Then LST configs are passed via remaining_accounts
     let mut accounts = vec![
         AccountMeta::new(*unified_sol_pool_config, false),
-        AccountMeta::new_readonly(*program_id, false), // unified_sol_program
+        AccountMeta::new_readonly(payer.pubkey(), true), // caller
+        AccountMeta::new_readonly(*program_id, false),   // unified_sol_program
     ];
 
     // Add all LST configs as remaining accounts
@@ -303,8 +565,17 @@ pub fn advance_unified_epoch(
         svm.latest_blockhash(),
     );
 
+    // Log instruction data: [LOG][len: u32 LE][event bytes]
     svm.send_transaction(tx)
-        .map(|_| ())
+        .map(|meta| {
+            meta.inner_instructions
+                .concat()
+                .into_iter()
+                .map(|inner| inner.instruction.data)
+                .filter(|data| data.first() == Some(&discriminators::LOG))
+                .map(|data| data[5..].to_vec())
+                .collect()
+        })
         .map_err(|e| format!("{:?}", e))
 }
 
@@ -314,7 +585,8 @@ pub fn advance_unified_epoch(
 
 /// Harvest LST appreciation for a specific LST.
 ///
-/// This is permissionless. For WSOL, rate_data_account is the lst_vault.
+/// Permissionless unless the keeper allowlist is enforced; `payer` signs as
+/// the caller. For WSOL, rate_data_account is the lst_vault.
 /// For SplStakePool, rate_data_account is the stake_pool and lst_vault must
 /// be passed as a remaining account.
 pub fn harvest_lst_appreciation(
@@ -325,12 +597,37 @@ pub fn harvest_lst_appreciation(
     rate_data_account: &Pubkey,
     lst_vault: Option<&Pubkey>,
     payer: &Keypair,
+) -> Result<(), String> {
+    harvest_lst_appreciation_with_expected_rate(
+        svm,
+        program_id,
+        unified_sol_pool_config,
+        lst_config,
+        rate_data_account,
+        lst_vault,
+        None,
+        payer,
+    )
+}
+
+/// Harvest LST appreciation, optionally cross-checking against `expected_rate`
+#[allow(clippy::too_many_arguments)]
+pub fn harvest_lst_appreciation_with_expected_rate(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    rate_data_account: &Pubkey,
+    lst_vault: Option<&Pubkey>,
+    expected_rate: Option<u64>,
+    payer: &Keypair,
 ) -> Result<(), String> {
     let mut accounts = vec![
         AccountMeta::new(*unified_sol_pool_config, false),
         AccountMeta::new(*lst_config, false),
         AccountMeta::new_readonly(*rate_data_account, false),
-        AccountMeta::new_readonly(*program_id, false), // unified_sol_program for self-CPI events
+        AccountMeta::new_readonly(payer.pubkey(), true), // caller
+        AccountMeta::new_readonly(*program_id, false),   // unified_sol_program for self-CPI events
     ];
 
     // Add lst_vault as remaining account for SplStakePool type
@@ -341,7 +638,54 @@ pub fn harvest_lst_appreciation(
     let ix = Instruction {
         program_id: *program_id,
         accounts,
-        data: build_instruction_data_no_args(discriminators::HARVEST_LST_APPRECIATION),
+        data: {
+            let mut data = build_instruction_data_no_args(discriminators::HARVEST_LST_APPRECIATION);
+            if let Some(rate) = expected_rate {
+                data.extend_from_slice(&rate.to_le_bytes());
+            }
+            data
+        },
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        &[payer],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Harvest every registered LST in one instruction.
+///
+/// Each entry is (lst_config, rate_data_account, lst_vault); for WSOL the
+/// rate_data_account is the lst_vault.
+pub fn harvest_all_lst_appreciation(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    lsts: &[(Pubkey, Pubkey, Pubkey)],
+    payer: &Keypair,
+) -> Result<(), String> {
+    let mut accounts = vec![
+        AccountMeta::new(*unified_sol_pool_config, false),
+        AccountMeta::new_readonly(payer.pubkey(), true), // caller
+        AccountMeta::new_readonly(*program_id, false),   // unified_sol_program for self-CPI events
+    ];
+
+    for (lst_config, rate_data_account, lst_vault) in lsts {
+        accounts.push(AccountMeta::new(*lst_config, false));
+        accounts.push(AccountMeta::new_readonly(*rate_data_account, false));
+        accounts.push(AccountMeta::new_readonly(*lst_vault, false));
+    }
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts,
+        data: build_instruction_data_no_args(discriminators::HARVEST_ALL_LST_APPRECIATION),
     };
 
     let tx = Transaction::new_signed_with_payer(
@@ -466,3 +810,830 @@ pub fn accept_unified_sol_pool_authority(
         .map(|_| ())
         .map_err(|e| format!("{:?}", e))
 }
+
+// ============================================================================
+// Stake Pool Program Allowlist
+// ============================================================================
+
+/// Args for AddStakePoolProgram / RemoveStakePoolProgram instructions
+#[derive(BorshSerialize)]
+struct StakePoolProgramArgs {
+    program: [u8; 32],
+}
+
+/// Allowlist an SPL-layout stake pool program.
+pub fn add_stake_pool_program(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    authority: &Keypair,
+    stake_pool_program: &Pubkey,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*unified_sol_pool_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // unified_sol_program for self-CPI events
+        ],
+        data: build_instruction_data(
+            discriminators::ADD_STAKE_POOL_PROGRAM,
+            &StakePoolProgramArgs {
+                program: stake_pool_program.to_bytes(),
+            },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Remove a stake pool program from the allowlist.
+pub fn remove_stake_pool_program(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    authority: &Keypair,
+    stake_pool_program: &Pubkey,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*unified_sol_pool_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // unified_sol_program for self-CPI events
+        ],
+        data: build_instruction_data(
+            discriminators::REMOVE_STAKE_POOL_PROGRAM,
+            &StakePoolProgramArgs {
+                program: stake_pool_program.to_bytes(),
+            },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+// ============================================================================
+// AddKeeper / RemoveKeeper / SetKeeperAllowlistEnabled
+// ============================================================================
+
+/// Args for AddKeeper / RemoveKeeper instructions
+#[derive(BorshSerialize)]
+struct KeeperArgs {
+    keeper: [u8; 32],
+}
+
+/// Send a keeper allowlist instruction signed by `authority`.
+fn send_keeper_instruction(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    authority: &Keypair,
+    data: Vec<u8>,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*unified_sol_pool_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // unified_sol_program for self-CPI events
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Add a keeper to the allowlist.
+pub fn add_keeper(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    authority: &Keypair,
+    keeper: &Pubkey,
+) -> Result<(), String> {
+    let data = build_instruction_data(
+        discriminators::ADD_KEEPER,
+        &KeeperArgs {
+            keeper: keeper.to_bytes(),
+        },
+    );
+    send_keeper_instruction(svm, program_id, unified_sol_pool_config, authority, data)
+}
+
+/// Remove a keeper from the allowlist.
+pub fn remove_keeper(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    authority: &Keypair,
+    keeper: &Pubkey,
+) -> Result<(), String> {
+    let data = build_instruction_data(
+        discriminators::REMOVE_KEEPER,
+        &KeeperArgs {
+            keeper: keeper.to_bytes(),
+        },
+    );
+    send_keeper_instruction(svm, program_id, unified_sol_pool_config, authority, data)
+}
+
+/// Args for SetKeeperAllowlistEnabled instruction
+#[derive(BorshSerialize)]
+struct SetKeeperAllowlistEnabledArgs {
+    enabled: u8,
+    _padding: [u8; 7],
+}
+
+/// Enforce (`true`) or lift (`false`) the keeper allowlist.
+pub fn set_keeper_allowlist_enabled(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    authority: &Keypair,
+    enabled: bool,
+) -> Result<(), String> {
+    let data = build_instruction_data(
+        discriminators::SET_KEEPER_ALLOWLIST_ENABLED,
+        &SetKeeperAllowlistEnabledArgs {
+            enabled: enabled as u8,
+            _padding: [0u8; 7],
+        },
+    );
+    send_keeper_instruction(svm, program_id, unified_sol_pool_config, authority, data)
+}
+
+// ============================================================================
+// SetLstConfigRateBounds / ApproveLstRateJump
+// ============================================================================
+
+/// Args for SetLstConfigRateBounds instruction
+#[derive(BorshSerialize)]
+struct SetLstConfigRateBoundsArgs {
+    max_rate_increase_bps_per_epoch: u16,
+    max_rate_decrease_bps_per_epoch: u16,
+    _padding: [u8; 4],
+    max_rate_staleness_slots: u64,
+}
+
+/// Set an LST's per-epoch rate band and rate staleness limit.
+pub fn set_lst_config_rate_bounds(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    authority: &Keypair,
+    max_rate_increase_bps_per_epoch: u16,
+    max_rate_decrease_bps_per_epoch: u16,
+    max_rate_staleness_slots: u64,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*unified_sol_pool_config, false),
+            AccountMeta::new(*lst_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // unified_sol_program for self-CPI events
+        ],
+        data: build_instruction_data(
+            discriminators::SET_LST_CONFIG_RATE_BOUNDS,
+            &SetLstConfigRateBoundsArgs {
+                max_rate_increase_bps_per_epoch,
+                max_rate_decrease_bps_per_epoch,
+                _padding: [0; 4],
+                max_rate_staleness_slots,
+            },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Approve an exact out-of-band rate for the LST's next finalization.
+pub fn approve_lst_rate_jump(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    authority: &Keypair,
+    approved_rate: u64,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*unified_sol_pool_config, false),
+            AccountMeta::new(*lst_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // unified_sol_program for self-CPI events
+        ],
+        data: build_instruction_data(discriminators::APPROVE_LST_RATE_JUMP, &approved_rate),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+// ============================================================================
+// SetLstConfigFeeOverrides
+// ============================================================================
+
+/// Args for SetLstConfigFeeOverrides instruction
+#[derive(BorshSerialize)]
+struct SetLstConfigFeeOverridesArgs {
+    deposit_fee_bps_override: u16,
+    withdraw_fee_bps_override: u16,
+    _padding: [u8; 4],
+}
+
+/// Set an LST's deposit/withdrawal fee overrides (0xFFFF = use pool-wide rate).
+pub fn set_lst_config_fee_overrides(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    authority: &Keypair,
+    deposit_fee_bps_override: u16,
+    withdraw_fee_bps_override: u16,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*unified_sol_pool_config, false),
+            AccountMeta::new(*lst_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+        ],
+        data: build_instruction_data(
+            discriminators::SET_LST_CONFIG_FEE_OVERRIDES,
+            &SetLstConfigFeeOverridesArgs {
+                deposit_fee_bps_override,
+                withdraw_fee_bps_override,
+                _padding: [0; 4],
+            },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+// ============================================================================
+// SetLstConfigVirtualSolCap / SetUnifiedSolPoolConfigVirtualSolCap
+// ============================================================================
+
+/// Args for SetLstConfigVirtualSolCap instruction
+#[derive(BorshSerialize)]
+struct SetLstConfigVirtualSolCapArgs {
+    max_virtual_sol: u64,
+}
+
+/// Cap the virtual SOL an LST may hold (0 = uncapped).
+pub fn set_lst_config_virtual_sol_cap(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    authority: &Keypair,
+    max_virtual_sol: u64,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*unified_sol_pool_config, false),
+            AccountMeta::new(*lst_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // unified_sol_program for self-CPI events
+        ],
+        data: build_instruction_data(
+            discriminators::SET_LST_CONFIG_VIRTUAL_SOL_CAP,
+            &SetLstConfigVirtualSolCapArgs { max_virtual_sol },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Args for SetUnifiedSolPoolConfigVirtualSolCap instruction
+#[derive(BorshSerialize)]
+struct SetUnifiedSolPoolConfigVirtualSolCapArgs {
+    max_total_virtual_sol: u64,
+}
+
+/// Cap the pool's total virtual SOL across all LSTs (0 = uncapped).
+pub fn set_unified_sol_pool_config_virtual_sol_cap(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    authority: &Keypair,
+    max_total_virtual_sol: u64,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*unified_sol_pool_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // unified_sol_program for self-CPI events
+        ],
+        data: build_instruction_data(
+            discriminators::SET_UNIFIED_SOL_POOL_CONFIG_VIRTUAL_SOL_CAP,
+            &SetUnifiedSolPoolConfigVirtualSolCapArgs {
+                max_total_virtual_sol,
+            },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Args for SetWithdrawPriority instruction
+#[derive(BorshSerialize)]
+struct SetWithdrawPriorityArgs {
+    withdraw_priority: [[u8; 32]; 4],
+}
+
+/// Set the LST mints routed withdrawals draw from, in order (default = empty slot).
+pub fn set_withdraw_priority(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    authority: &Keypair,
+    withdraw_priority: [Pubkey; 4],
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*unified_sol_pool_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // unified_sol_program for self-CPI events
+        ],
+        data: build_instruction_data(
+            discriminators::SET_WITHDRAW_PRIORITY,
+            &SetWithdrawPriorityArgs {
+                withdraw_priority: withdraw_priority.map(|mint| mint.to_bytes()),
+            },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Args for SetAppreciationCommission instruction
+#[derive(BorshSerialize)]
+struct SetAppreciationCommissionArgs {
+    commission_treasury: [u8; 32],
+    appreciation_commission_bps: u16,
+    _padding: [u8; 6],
+}
+
+/// Set the appreciation commission rate and treasury.
+pub fn set_appreciation_commission(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    authority: &Keypair,
+    commission_treasury: &Pubkey,
+    appreciation_commission_bps: u16,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*unified_sol_pool_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // unified_sol_program for self-CPI events
+        ],
+        data: build_instruction_data(
+            discriminators::SET_APPRECIATION_COMMISSION,
+            &SetAppreciationCommissionArgs {
+                commission_treasury: commission_treasury.to_bytes(),
+                appreciation_commission_bps,
+                _padding: [0u8; 6],
+            },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Args for SetMinHarvestLamports instruction
+#[derive(BorshSerialize)]
+struct SetMinHarvestLamportsArgs {
+    min_harvest_lamports: u64,
+}
+
+/// Set the minimum appreciation a harvest books.
+pub fn set_min_harvest_lamports(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    authority: &Keypair,
+    min_harvest_lamports: u64,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*unified_sol_pool_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // unified_sol_program for self-CPI events
+        ],
+        data: build_instruction_data(
+            discriminators::SET_MIN_HARVEST_LAMPORTS,
+            &SetMinHarvestLamportsArgs {
+                min_harvest_lamports,
+            },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Args for ProposeManualRate instruction
+#[derive(BorshSerialize)]
+struct ProposeManualRateArgs {
+    rate: u64,
+}
+
+/// Propose a timelocked manual exchange rate for an LST.
+pub fn propose_manual_rate(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    authority: &Keypair,
+    rate: u64,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*unified_sol_pool_config, false),
+            AccountMeta::new(*lst_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // unified_sol_program for self-CPI events
+        ],
+        data: build_instruction_data(
+            discriminators::PROPOSE_MANUAL_RATE,
+            &ProposeManualRateArgs { rate },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Apply an unlocked manual exchange rate (permissionless unless the keeper
+/// allowlist is enforced; `payer` signs as the caller).
+pub fn apply_manual_rate(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    rate_data_account: &Pubkey,
+    lst_vault: &Pubkey,
+    payer: &Keypair,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*unified_sol_pool_config, false),
+            AccountMeta::new(*lst_config, false),
+            AccountMeta::new_readonly(*rate_data_account, false),
+            AccountMeta::new_readonly(*lst_vault, false),
+            AccountMeta::new_readonly(payer.pubkey(), true), // caller
+            AccountMeta::new_readonly(*program_id, false), // unified_sol_program for self-CPI events
+        ],
+        data: build_instruction_data_no_args(discriminators::APPLY_MANUAL_RATE),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        &[payer],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Cancel an LST's pending manual exchange rate.
+pub fn cancel_manual_rate(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    authority: &Keypair,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*unified_sol_pool_config, false),
+            AccountMeta::new(*lst_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // unified_sol_program for self-CPI events
+        ],
+        data: build_instruction_data_no_args(discriminators::CANCEL_MANUAL_RATE),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Args for SetManualRateDelay instruction
+#[derive(BorshSerialize)]
+struct SetManualRateDelayArgs {
+    delay_slots: u64,
+}
+
+/// Set the slots a manual rate proposal waits before it may be applied.
+pub fn set_manual_rate_delay(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    authority: &Keypair,
+    delay_slots: u64,
+) -> Result<(), String> {
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*unified_sol_pool_config, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false), // unified_sol_program for self-CPI events
+        ],
+        data: build_instruction_data(
+            discriminators::SET_MANUAL_RATE_DELAY,
+            &SetManualRateDelayArgs { delay_slots },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Args for WithdrawCommission instruction
+#[derive(BorshSerialize)]
+struct WithdrawCommissionArgs {
+    amount: u64,
+}
+
+/// Pay `amount` of accrued commission (virtual SOL) out of an LST vault.
+pub fn withdraw_commission(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    destination: &Pubkey,
+    authority: &Keypair,
+    amount: u64,
+) -> Result<(), String> {
+    let (lst_vault, _) = find_lst_vault_pda(program_id, lst_config);
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*unified_sol_pool_config, false),
+            AccountMeta::new(*lst_config, false),
+            AccountMeta::new(lst_vault, false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(SPL_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*program_id, false), // unified_sol_program for self-CPI events
+        ],
+        data: build_instruction_data(
+            discriminators::WITHDRAW_COMMISSION,
+            &WithdrawCommissionArgs { amount },
+        ),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+// ============================================================================
+// Deposit
+// ============================================================================
+
+/// Call Deposit directly, as the hub would via CPI.
+///
+/// `depositor` signs for the transfer out of `depositor_token` and pays fees.
+/// Returns the pool's return data on success.
+pub fn deposit(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    depositor_token: &Pubkey,
+    depositor: &Keypair,
+    amount: u64,
+    expected_output: u64,
+) -> Result<Vec<u8>, String> {
+    let (lst_vault, _) = find_lst_vault_pda(program_id, lst_config);
+
+    let params = zorb_pool_interface::DepositParams {
+        amount,
+        expected_output,
+        depositor: depositor.pubkey().to_bytes(),
+        ..Default::default()
+    };
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*unified_sol_pool_config, false),
+            AccountMeta::new(*lst_config, false),
+            AccountMeta::new(lst_vault, false),
+            AccountMeta::new(*depositor_token, false),
+            AccountMeta::new_readonly(depositor.pubkey(), true),
+            AccountMeta::new_readonly(*program_id, false),
+            AccountMeta::new_readonly(SPL_TOKEN_PROGRAM_ID, false),
+        ],
+        data: zorb_pool_interface::build_deposit_instruction_data(&params).to_vec(),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&depositor.pubkey()),
+        &[depositor],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|meta| meta.return_data.data)
+        .map_err(|e| format!("{:?}", e))
+}
+
+// ============================================================================
+// Withdraw
+// ============================================================================
+
+/// Call Withdraw directly, as the hub would via CPI (hub_authority does not sign).
+///
+/// `extra_accounts` are appended after the token program: the unwrap accounts
+/// for unwrapping withdrawals, or the (lst_config, vault) pairs for routed ones.
+/// Returns the pool's return data on success.
+pub fn withdraw(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_pool_config: &Pubkey,
+    lst_config: &Pubkey,
+    params: &zorb_pool_interface::WithdrawParams,
+    extra_accounts: &[AccountMeta],
+    payer: &Keypair,
+) -> Result<Vec<u8>, String> {
+    let (lst_vault, _) = find_lst_vault_pda(program_id, lst_config);
+    let (hub_authority, _) = zorb_pool_interface::find_hub_authority_pda();
+
+    let mut accounts = vec![
+        AccountMeta::new(*unified_sol_pool_config, false),
+        AccountMeta::new(*lst_config, false),
+        AccountMeta::new(lst_vault, false),
+        AccountMeta::new_readonly(Pubkey::new_from_array(hub_authority), false),
+        AccountMeta::new_readonly(*program_id, false),
+        AccountMeta::new_readonly(SPL_TOKEN_PROGRAM_ID, false),
+    ];
+    accounts.extend_from_slice(extra_accounts);
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts,
+        data: zorb_pool_interface::build_withdraw_instruction_data(params).to_vec(),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        &[payer],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx)
+        .map(|meta| meta.return_data.data)
+        .map_err(|e| format!("{:?}", e))
+}
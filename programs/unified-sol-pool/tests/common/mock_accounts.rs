@@ -55,6 +55,32 @@ pub fn create_invalid_mint(svm: &mut LiteSVM, decimals: u8) -> Pubkey {
     mint
 }
 
+/// Borsh-encode an SPL `StakePool` account the way the stake pool program
+/// lays it out: fixed-size prefix up to `last_update_epoch`, then the
+/// variable-length tail (lockup, fees, ...) as zeros.
+pub fn stake_pool_data(
+    pool_mint: &Pubkey,
+    total_lamports: u64,
+    pool_token_supply: u64,
+    last_update_epoch: u64,
+) -> Vec<u8> {
+    let mut data = vec![1u8]; // account_type = StakePool
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // manager
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // staker
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // stake_deposit_authority
+    data.push(255); // stake_withdraw_bump_seed
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // validator_list
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // reserve_stake
+    data.extend_from_slice(pool_mint.as_ref()); // pool_mint (offset 162)
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // manager_fee_account
+    data.extend_from_slice(SPL_TOKEN_PROGRAM_ID.as_ref()); // token_program_id
+    data.extend_from_slice(&total_lamports.to_le_bytes()); // offset 258
+    data.extend_from_slice(&pool_token_supply.to_le_bytes()); // offset 266
+    data.extend_from_slice(&last_update_epoch.to_le_bytes()); // offset 274
+    data.resize(611, 0);
+    data
+}
+
 /// Create a mock SPL Stake Pool account with specified exchange rate
 pub fn create_mock_stake_pool(
     svm: &mut LiteSVM,
@@ -65,15 +91,7 @@ pub fn create_mock_stake_pool(
 ) -> Pubkey {
     let stake_pool = Pubkey::new_unique();
 
-    // SPL Stake Pool layout (at least 283 bytes for harvest_lst_appreciation)
-    // Offset 73-105: pool_mint (32 bytes)
-    // Offset 259-267: total_lamports (u64)
-    // Offset 267-275: pool_token_supply (u64)
-    // Offset 275-283: last_update_epoch (u64) - required by harvest
-    let mut data = vec![0u8; 283];
-    data[73..105].copy_from_slice(pool_mint.as_ref());
-    data[259..267].copy_from_slice(&total_lamports.to_le_bytes());
-    data[267..275].copy_from_slice(&pool_token_supply.to_le_bytes());
+    let data = stake_pool_data(pool_mint, total_lamports, pool_token_supply, 0);
 
     let account = Account {
         lamports: 1_000_000_000,
@@ -87,6 +105,66 @@ pub fn create_mock_stake_pool(
     stake_pool
 }
 
+/// Create a mock Marinade State account pricing `msol_mint`.
+///
+/// Only the fields the rate is derived from are set; the whole balance sits
+/// in `total_active_balance` and the stake delta last ran in epoch 0.
+pub fn create_mock_marinade_state(
+    svm: &mut LiteSVM,
+    msol_mint: &Pubkey,
+    total_active_balance: u64,
+    msol_supply: u64,
+    owner: Pubkey,
+) -> Pubkey {
+    let state = Pubkey::new_unique();
+
+    // Marinade State layout (Anchor account, 576-byte fixed prefix)
+    // Offset 0-8: discriminator sha256("account:State")[..8]
+    // Offset 8-40: msol_mint
+    // Offset 376-384: validator_system.total_active_balance
+    // Offset 504-512: msol_supply
+    let mut data = vec![0u8; 1_000];
+    data[0..8].copy_from_slice(&[216, 146, 107, 94, 104, 75, 182, 177]);
+    data[8..40].copy_from_slice(msol_mint.as_ref());
+    data[376..384].copy_from_slice(&total_active_balance.to_le_bytes());
+    data[504..512].copy_from_slice(&msol_supply.to_le_bytes());
+
+    let account = Account {
+        lamports: 1_000_000_000,
+        data,
+        owner,
+        executable: false,
+        rent_epoch: 0,
+    };
+    svm.set_account(state, account).unwrap();
+
+    state
+}
+
+/// Create an initialized SPL Token account for `mint` owned by `owner`
+pub fn create_mock_token_account(svm: &mut LiteSVM, mint: &Pubkey, owner: &Pubkey) -> Pubkey {
+    let token_account = Pubkey::new_unique();
+
+    // SPL Token account layout (165 bytes)
+    let mut data = vec![0u8; 165];
+    data[0..32].copy_from_slice(mint.as_ref());
+    data[32..64].copy_from_slice(owner.as_ref());
+    // amount: 8 bytes at offset 64 (zero)
+    // state: 1 byte at offset 108
+    data[108] = 1; // Initialized
+
+    let account = Account {
+        lamports: 1_000_000_000,
+        data,
+        owner: SPL_TOKEN_PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    svm.set_account(token_account, account).unwrap();
+
+    token_account
+}
+
 /// Update a token account's balance
 pub fn update_vault_balance(svm: &mut LiteSVM, vault: &Pubkey, new_balance: u64) {
     let account = svm.get_account(vault).expect("vault should exist");
@@ -121,6 +199,38 @@ pub fn update_lst_config_vault_balance(svm: &mut LiteSVM, lst_config: &Pubkey, n
     svm.set_account(*lst_config, updated).unwrap();
 }
 
+/// Set total_virtual_sol on both the LstConfig and the UnifiedSolPoolConfig,
+/// as if `value` had been deposited into the LST.
+pub fn update_total_virtual_sol(
+    svm: &mut LiteSVM,
+    unified_config: &Pubkey,
+    lst_config: &Pubkey,
+    value: u128,
+) {
+    for (key, offset) in [
+        (unified_config, unified_config_offsets::TOTAL_VIRTUAL_SOL),
+        (lst_config, lst_config_offsets::TOTAL_VIRTUAL_SOL),
+    ] {
+        let mut account = svm.get_account(key).expect("config should exist");
+        account.data[offset..offset + 16].copy_from_slice(&value.to_le_bytes());
+        svm.set_account(*key, account).unwrap();
+    }
+}
+
+/// Read a token account's delegate and delegated amount
+pub fn get_token_delegation(svm: &LiteSVM, token_account: &Pubkey) -> Option<(Pubkey, u64)> {
+    let account = svm
+        .get_account(token_account)
+        .expect("token_account should exist");
+    // delegate: COption<Pubkey> at 72-107, delegated_amount at 121-128
+    if account.data[72..76] == [0u8; 4] {
+        return None;
+    }
+    let delegate = Pubkey::new_from_array(account.data[76..108].try_into().unwrap());
+    let amount = u64::from_le_bytes(account.data[121..129].try_into().unwrap());
+    Some((delegate, amount))
+}
+
 /// Update stake pool exchange rate (simulate appreciation)
 pub fn update_stake_pool_rate(
     svm: &mut LiteSVM,
@@ -132,10 +242,10 @@ pub fn update_stake_pool_rate(
         .get_account(stake_pool)
         .expect("stake_pool should exist");
     let mut data = account.data.clone();
-    // total_lamports at offset 259-267
-    data[259..267].copy_from_slice(&new_total_lamports.to_le_bytes());
-    // pool_token_supply at offset 267-275
-    data[267..275].copy_from_slice(&pool_token_supply.to_le_bytes());
+    // total_lamports at offset 258-266
+    data[258..266].copy_from_slice(&new_total_lamports.to_le_bytes());
+    // pool_token_supply at offset 266-274
+    data[266..274].copy_from_slice(&pool_token_supply.to_le_bytes());
     let updated = Account {
         lamports: account.lamports,
         data,
@@ -160,6 +270,10 @@ pub fn get_token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
 
 /// UnifiedSolPoolConfig field offsets (from unified-sol-pool/src/state.rs)
 pub mod unified_config_offsets {
+    pub const LAST_FINALIZED_SOLANA_EPOCH: usize = 112; // u64
+    pub const MIN_HARVEST_LAMPORTS: usize = 120; // u64
+    pub const CARRIED_APPRECIATION: usize = 128; // u64
+    pub const TOTAL_VIRTUAL_SOL: usize = 168; // u128
     pub const REWARD_ACCUMULATOR: usize = 184; // u128
     pub const PENDING_APPRECIATION: usize = 224; // u64
     pub const FINALIZED_BALANCE: usize = 232; // u128
@@ -167,40 +281,78 @@ pub mod unified_config_offsets {
     pub const PENDING_WITHDRAWALS: usize = 264; // u128
     pub const TOTAL_REWARDS_DISTRIBUTED: usize = 344; // u128
     pub const TOTAL_APPRECIATION: usize = 408; // u128
+    pub const EPOCH_MODE: usize = 449; // u8
+    pub const STAKE_POOL_PROGRAMS: usize = 472; // [Pubkey; 4]
+    pub const MAX_TOTAL_VIRTUAL_SOL: usize = 600; // u64
+    pub const TOTAL_VIRTUAL_SOL_OVER_CAP: usize = 608; // u64
+    pub const WITHDRAW_PRIORITY: usize = 616; // [Pubkey; 4]
+    pub const COMMISSION_TREASURY: usize = 744; // Pubkey
+    pub const ACCRUED_COMMISSION: usize = 776; // u64
+    pub const APPRECIATION_COMMISSION_BPS: usize = 784; // u16
+    pub const KEEPERS: usize = 792; // [Pubkey; 4]
+    pub const KEEPER_ALLOWLIST_ENABLED: usize = 920; // u8
 }
 
 /// LstConfig field offsets (with 8-byte panchor discriminator)
 ///
 /// Calculated from struct layout:
 /// - discriminator: 0-7 (8 bytes)
-/// - pool_type: 8 (1 byte), is_active: 9 (1 byte), bump: 10 (1 byte), _header_pad: 11-15 (5 bytes)
+/// - pool_type: 8 (1 byte), mode: 9 (1 byte), bump: 10 (1 byte), is_retired: 11 (1 byte),
+///   max_rate_increase_bps_per_epoch: 12-13, max_rate_decrease_bps_per_epoch: 14-15
 /// - lst_mint: 16-47 (32 bytes)
 /// - lst_vault: 48-79 (32 bytes)
 /// - exchange_rate: 80-87 (8 bytes)
 /// - harvested_exchange_rate: 88-95 (8 bytes)
 /// - last_rate_update_slot: 96-103 (8 bytes)
 /// - last_harvest_epoch: 104-111 (8 bytes)
-/// - _pad_for_u128: 112-119 (8 bytes)
+/// - rate_updated_slot: 112-119 (8 bytes)
 /// - total_virtual_sol: 120-135 (16 bytes, u128)
 /// - vault_token_balance: 136-143 (8 bytes)
-/// - _value_pad: 144-151 (8 bytes)
+/// - max_rate_staleness_slots: 144-151 (8 bytes)
 /// - total_deposited: 152-167 (16 bytes, u128)
 /// - total_withdrawn: 168-183 (16 bytes, u128)
 /// - total_appreciation_harvested: 184-191 (8 bytes)
 /// - deposit_count: 192-199 (8 bytes)
 /// - withdrawal_count: 200-207 (8 bytes)
-/// - _stat_pad: 208-215 (8 bytes)
+/// - deposit_fee_bps_override: 208-209, withdraw_fee_bps_override: 210-211, version: 212-213,
+///   _fee_pad: 214-215
 /// - stake_pool: 216-247 (32 bytes)
 /// - stake_pool_program: 248-279 (32 bytes)
 /// - previous_exchange_rate: 280-287 (8 bytes)
-/// - _reserved: 288-295 (8 bytes)
+/// - approved_exchange_rate: 288-295 (8 bytes)
+/// - max_virtual_sol: 296-303 (8 bytes)
+/// - virtual_sol_over_cap: 304-311 (8 bytes)
+///
+/// Version 2 accounts append the LstRateHistory extension:
+/// - count: 312-319 (8 bytes), _pad: 320-327
+/// - entries: 328-1095 (32 × {rate, epoch, slot})
+///
+/// Version 3 accounts append the LstManualRate extension:
+/// - proposed_rate: 1096-1103 (8 bytes), unlock_slot: 1104-1111 (8 bytes)
+///
+/// Version 4 accounts append the LstPendingRewards extension:
+/// - deposit_fees: 1112-1119, withdrawal_fees: 1120-1127, appreciation: 1128-1135
 pub mod lst_config_offsets {
+    pub const MODE: usize = 9; // u8
     pub const EXCHANGE_RATE: usize = 80; // u64
     pub const HARVESTED_EXCHANGE_RATE: usize = 88; // u64
     pub const LAST_HARVEST_EPOCH: usize = 104; // u64
+    pub const RATE_UPDATED_SLOT: usize = 112; // u64
+    pub const TOTAL_VIRTUAL_SOL: usize = 120; // u128
     pub const VAULT_TOKEN_BALANCE: usize = 136; // u64
     pub const TOTAL_APPRECIATION_HARVESTED: usize = 184; // u64
+    pub const DEPOSIT_FEE_BPS_OVERRIDE: usize = 208; // u16
+    pub const WITHDRAW_FEE_BPS_OVERRIDE: usize = 210; // u16
     pub const PREVIOUS_EXCHANGE_RATE: usize = 280; // u64
+    pub const APPROVED_EXCHANGE_RATE: usize = 288; // u64
+    pub const MAX_VIRTUAL_SOL: usize = 296; // u64
+    pub const VIRTUAL_SOL_OVER_CAP: usize = 304; // u64
+    pub const VERSION: usize = 212; // u16
+    pub const MANUAL_RATE_PROPOSED: usize = 1096; // u64
+    pub const MANUAL_RATE_UNLOCK_SLOT: usize = 1104; // u64
+    pub const PENDING_DEPOSIT_FEES: usize = 1112; // u64
+    pub const PENDING_WITHDRAWAL_FEES: usize = 1120; // u64
+    pub const PENDING_APPRECIATION: usize = 1128; // u64
 }
 
 /// Rate precision constant (1e9) for exchange rate calculations
@@ -240,6 +392,19 @@ pub fn get_unified_config_pending_appreciation(svm: &LiteSVM, unified_config: &P
     u64::from_le_bytes(account.data[offset..offset + 8].try_into().unwrap())
 }
 
+/// Read UnifiedSolConfig's (min_harvest_lamports, carried_appreciation) fields
+pub fn get_unified_config_harvest_threshold(svm: &LiteSVM, unified_config: &Pubkey) -> (u64, u64) {
+    let account = svm
+        .get_account(unified_config)
+        .expect("unified_config should exist");
+    let min = unified_config_offsets::MIN_HARVEST_LAMPORTS;
+    let carried = unified_config_offsets::CARRIED_APPRECIATION;
+    (
+        u64::from_le_bytes(account.data[min..min + 8].try_into().unwrap()),
+        u64::from_le_bytes(account.data[carried..carried + 8].try_into().unwrap()),
+    )
+}
+
 /// Read UnifiedSolConfig's finalized_balance field
 pub fn get_unified_config_finalized_balance(svm: &LiteSVM, unified_config: &Pubkey) -> u128 {
     let account = svm
@@ -285,6 +450,107 @@ pub fn get_unified_config_total_rewards_distributed(svm: &LiteSVM, unified_confi
     u128::from_le_bytes(account.data[offset..offset + 16].try_into().unwrap())
 }
 
+/// Read UnifiedSolConfig's stake_pool_programs allowlist (non-empty slots only)
+pub fn get_unified_config_stake_pool_programs(svm: &LiteSVM, unified_config: &Pubkey) -> Vec<Pubkey> {
+    let account = svm
+        .get_account(unified_config)
+        .expect("unified_config should exist");
+    let offset = unified_config_offsets::STAKE_POOL_PROGRAMS;
+    account.data[offset..offset + 128]
+        .as_chunks::<32>()
+        .0
+        .iter()
+        .map(|slot| Pubkey::new_from_array(*slot))
+        .filter(|program| *program != Pubkey::default())
+        .collect()
+}
+
+/// Read UnifiedSolConfig's (max_total_virtual_sol, total_virtual_sol_over_cap) fields
+pub fn get_unified_config_virtual_sol_cap(svm: &LiteSVM, unified_config: &Pubkey) -> (u64, u64) {
+    let account = svm
+        .get_account(unified_config)
+        .expect("unified_config should exist");
+    let cap = unified_config_offsets::MAX_TOTAL_VIRTUAL_SOL;
+    let over = unified_config_offsets::TOTAL_VIRTUAL_SOL_OVER_CAP;
+    (
+        u64::from_le_bytes(account.data[cap..cap + 8].try_into().unwrap()),
+        u64::from_le_bytes(account.data[over..over + 8].try_into().unwrap()),
+    )
+}
+
+/// Read UnifiedSolConfig's withdraw_priority list (all 4 slots, in order)
+pub fn get_unified_config_withdraw_priority(svm: &LiteSVM, unified_config: &Pubkey) -> [Pubkey; 4] {
+    let account = svm
+        .get_account(unified_config)
+        .expect("unified_config should exist");
+    let offset = unified_config_offsets::WITHDRAW_PRIORITY;
+    core::array::from_fn(|i| {
+        let start = offset + i * 32;
+        Pubkey::new_from_array(account.data[start..start + 32].try_into().unwrap())
+    })
+}
+
+/// Read UnifiedSolConfig's (commission_treasury, accrued_commission, appreciation_commission_bps) fields
+pub fn get_unified_config_commission(svm: &LiteSVM, unified_config: &Pubkey) -> (Pubkey, u64, u16) {
+    let account = svm
+        .get_account(unified_config)
+        .expect("unified_config should exist");
+    let treasury = unified_config_offsets::COMMISSION_TREASURY;
+    let accrued = unified_config_offsets::ACCRUED_COMMISSION;
+    let bps = unified_config_offsets::APPRECIATION_COMMISSION_BPS;
+    (
+        Pubkey::new_from_array(account.data[treasury..treasury + 32].try_into().unwrap()),
+        u64::from_le_bytes(account.data[accrued..accrued + 8].try_into().unwrap()),
+        u16::from_le_bytes(account.data[bps..bps + 2].try_into().unwrap()),
+    )
+}
+
+/// Read UnifiedSolConfig's (epoch_mode, last_finalized_solana_epoch) fields
+pub fn get_unified_config_epoch_mode(svm: &LiteSVM, unified_config: &Pubkey) -> (u8, u64) {
+    let account = svm
+        .get_account(unified_config)
+        .expect("unified_config should exist");
+    let mode = unified_config_offsets::EPOCH_MODE;
+    let epoch = unified_config_offsets::LAST_FINALIZED_SOLANA_EPOCH;
+    (
+        account.data[mode],
+        u64::from_le_bytes(account.data[epoch..epoch + 8].try_into().unwrap()),
+    )
+}
+
+/// Read UnifiedSolConfig's (keepers, keeper_allowlist_enabled) fields, listed keepers only
+pub fn get_unified_config_keepers(svm: &LiteSVM, unified_config: &Pubkey) -> (Vec<Pubkey>, u8) {
+    let account = svm
+        .get_account(unified_config)
+        .expect("unified_config should exist");
+    let offset = unified_config_offsets::KEEPERS;
+    let keepers = account.data[offset..offset + 128]
+        .as_chunks::<32>()
+        .0
+        .iter()
+        .map(|slot| Pubkey::new_from_array(*slot))
+        .filter(|keeper| *keeper != Pubkey::default())
+        .collect();
+    (
+        keepers,
+        account.data[unified_config_offsets::KEEPER_ALLOWLIST_ENABLED],
+    )
+}
+
+/// Set UnifiedSolConfig's accrued_commission, as if harvested with a commission
+pub fn update_unified_config_accrued_commission(
+    svm: &mut LiteSVM,
+    unified_config: &Pubkey,
+    value: u64,
+) {
+    let mut account = svm
+        .get_account(unified_config)
+        .expect("unified_config should exist");
+    let offset = unified_config_offsets::ACCRUED_COMMISSION;
+    account.data[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+    svm.set_account(*unified_config, account).unwrap();
+}
+
 // ============================================================================
 // LstConfig Reading Helpers
 // ============================================================================
@@ -325,6 +591,37 @@ pub fn get_lst_config_last_harvest_epoch(svm: &LiteSVM, lst_config: &Pubkey) ->
     u64::from_le_bytes(account.data[offset..offset + 8].try_into().unwrap())
 }
 
+/// Read LstConfig's rate_updated_slot field
+pub fn get_lst_config_rate_updated_slot(svm: &LiteSVM, lst_config: &Pubkey) -> u64 {
+    let account = svm
+        .get_account(lst_config)
+        .expect("lst_config should exist");
+    let offset = lst_config_offsets::RATE_UPDATED_SLOT;
+    u64::from_le_bytes(account.data[offset..offset + 8].try_into().unwrap())
+}
+
+/// Read LstConfig's approved_exchange_rate field
+pub fn get_lst_config_approved_exchange_rate(svm: &LiteSVM, lst_config: &Pubkey) -> u64 {
+    let account = svm
+        .get_account(lst_config)
+        .expect("lst_config should exist");
+    let offset = lst_config_offsets::APPROVED_EXCHANGE_RATE;
+    u64::from_le_bytes(account.data[offset..offset + 8].try_into().unwrap())
+}
+
+/// Read LstConfig's (deposit_fee_bps_override, withdraw_fee_bps_override) fields
+pub fn get_lst_config_fee_overrides(svm: &LiteSVM, lst_config: &Pubkey) -> (u16, u16) {
+    let account = svm
+        .get_account(lst_config)
+        .expect("lst_config should exist");
+    let deposit = lst_config_offsets::DEPOSIT_FEE_BPS_OVERRIDE;
+    let withdraw = lst_config_offsets::WITHDRAW_FEE_BPS_OVERRIDE;
+    (
+        u16::from_le_bytes(account.data[deposit..deposit + 2].try_into().unwrap()),
+        u16::from_le_bytes(account.data[withdraw..withdraw + 2].try_into().unwrap()),
+    )
+}
+
 /// Read LstConfig's total_appreciation_harvested field
 pub fn get_lst_config_total_appreciation_harvested(svm: &LiteSVM, lst_config: &Pubkey) -> u64 {
     let account = svm
@@ -333,3 +630,44 @@ pub fn get_lst_config_total_appreciation_harvested(svm: &LiteSVM, lst_config: &P
     let offset = lst_config_offsets::TOTAL_APPRECIATION_HARVESTED;
     u64::from_le_bytes(account.data[offset..offset + 8].try_into().unwrap())
 }
+
+/// Read LstConfig's (max_virtual_sol, virtual_sol_over_cap) fields
+pub fn get_lst_config_virtual_sol_cap(svm: &LiteSVM, lst_config: &Pubkey) -> (u64, u64) {
+    let account = svm
+        .get_account(lst_config)
+        .expect("lst_config should exist");
+    let cap = lst_config_offsets::MAX_VIRTUAL_SOL;
+    let over = lst_config_offsets::VIRTUAL_SOL_OVER_CAP;
+    (
+        u64::from_le_bytes(account.data[cap..cap + 8].try_into().unwrap()),
+        u64::from_le_bytes(account.data[over..over + 8].try_into().unwrap()),
+    )
+}
+
+/// Read LstConfig's pending rewards (deposit_fees, withdrawal_fees, appreciation)
+pub fn get_lst_config_pending_rewards(svm: &LiteSVM, lst_config: &Pubkey) -> (u64, u64, u64) {
+    let account = svm
+        .get_account(lst_config)
+        .expect("lst_config should exist");
+    let deposit = lst_config_offsets::PENDING_DEPOSIT_FEES;
+    let withdrawal = lst_config_offsets::PENDING_WITHDRAWAL_FEES;
+    let appreciation = lst_config_offsets::PENDING_APPRECIATION;
+    (
+        u64::from_le_bytes(account.data[deposit..deposit + 8].try_into().unwrap()),
+        u64::from_le_bytes(account.data[withdrawal..withdrawal + 8].try_into().unwrap()),
+        u64::from_le_bytes(account.data[appreciation..appreciation + 8].try_into().unwrap()),
+    )
+}
+
+/// Read LstConfig's pending manual rate (proposed_rate, unlock_slot); 0 rate = none
+pub fn get_lst_config_manual_rate(svm: &LiteSVM, lst_config: &Pubkey) -> (u64, u64) {
+    let account = svm
+        .get_account(lst_config)
+        .expect("lst_config should exist");
+    let rate = lst_config_offsets::MANUAL_RATE_PROPOSED;
+    let unlock = lst_config_offsets::MANUAL_RATE_UNLOCK_SLOT;
+    (
+        u64::from_le_bytes(account.data[rate..rate + 8].try_into().unwrap()),
+        u64::from_le_bytes(account.data[unlock..unlock + 8].try_into().unwrap()),
+    )
+}
@@ -12,6 +12,7 @@ use super::setup::UNIFIED_SOL_POOL_PROGRAM_ID;
 pub const UNIFIED_SOL_POOL_CONFIG_SEED: &[u8] = b"unified_sol_pool";
 pub const LST_CONFIG_SEED: &[u8] = b"lst_config";
 pub const LST_VAULT_SEED: &[u8] = b"lst_vault";
+pub const UNWRAP_SEED: &[u8] = b"unwrap";
 
 /// Derive UnifiedSolPoolConfig PDA
 pub fn find_unified_sol_pool_config_pda(program_id: &Pubkey) -> (Pubkey, u8) {
@@ -28,6 +29,11 @@ pub fn find_lst_vault_pda(program_id: &Pubkey, lst_config: &Pubkey) -> (Pubkey,
     Pubkey::find_program_address(&[LST_VAULT_SEED, lst_config.as_ref()], program_id)
 }
 
+/// Derive the temporary WSOL account PDA used for native SOL unwrap
+pub fn find_unwrap_account_pda(program_id: &Pubkey, lst_config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[UNWRAP_SEED, lst_config.as_ref()], program_id)
+}
+
 /// Convenience function to derive LST vault PDA using default program ID
 pub fn find_lst_vault_pda_default(lst_config: &Pubkey) -> (Pubkey, u8) {
     find_lst_vault_pda(&UNIFIED_SOL_POOL_PROGRAM_ID, lst_config)
@@ -47,3 +53,7 @@ pub const SPL_TOKEN_PROGRAM_ID: Pubkey =
 /// SPL Stake Pool Program ID
 pub const SPL_STAKE_POOL_PROGRAM_ID: Pubkey =
     solana_sdk::pubkey!("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
+
+/// Marinade liquid staking program ID
+pub const MARINADE_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("MarBmsSgKXdrN1egZf5sqe1TMai9K1rChYNDJgjq7aD");
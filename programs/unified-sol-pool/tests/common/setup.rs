@@ -1,12 +1,19 @@
-//! Program deployment helpers for unified-sol-pool tests.
+//! Program deployment and pool setup helpers for unified-sol-pool tests.
 
 use litesvm::LiteSVM;
+use solana_sdk::clock::Clock;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+
+use super::instructions::{init_lst_config, init_unified_sol_pool_config, pool_types};
+use super::mock_accounts::{
+    create_mock_mint, create_mock_stake_pool, update_lst_config_vault_balance,
+    update_total_virtual_sol, update_vault_balance,
+};
+use super::pda::{SPL_STAKE_POOL_PROGRAM_ID, find_lst_vault_pda};
 
 /// Unified SOL Pool program ID (from centralized zorb-program-ids crate)
-pub const UNIFIED_SOL_POOL_PROGRAM_ID: Pubkey = Pubkey::new_from_array(
-    five8_const::decode_32_const(zorb_program_ids::UNIFIED_SOL_POOL_ID),
-);
+pub const UNIFIED_SOL_POOL_PROGRAM_ID: Pubkey = Pubkey::new_from_array(zorb_program_ids::UNIFIED_SOL_POOL_PUBKEY);
 
 /// Deploy the unified SOL pool program
 pub fn deploy_unified_sol_pool_program(svm: &mut LiteSVM) -> Pubkey {
@@ -20,3 +27,174 @@ pub fn deploy_unified_sol_pool_program(svm: &mut LiteSVM) -> Pubkey {
 pub fn warp_to_slot(svm: &mut LiteSVM, slot: u64) {
     svm.warp_to_slot(slot);
 }
+
+/// Set the clock's slot and Solana epoch independently
+pub fn set_clock(svm: &mut LiteSVM, slot: u64, epoch: u64) {
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.slot = slot;
+    clock.epoch = epoch;
+    svm.set_sysvar(&clock);
+    svm.expire_blockhash();
+}
+
+/// Register an SPL stake pool LST (at 1:1) whose vault holds 100 tokens,
+/// not counted in `total_virtual_sol`.
+///
+/// Returns (lst_config, lst_mint, stake_pool, lst_vault).
+pub fn add_spl_stake_pool_lst(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_config: &Pubkey,
+    authority: &Keypair,
+) -> (Pubkey, Pubkey, Pubkey, Pubkey) {
+    let lst_mint = create_mock_mint(svm, 9);
+    let stake_pool = create_mock_stake_pool(
+        svm,
+        &lst_mint,
+        1_000_000_000_000,
+        1_000_000_000_000,
+        SPL_STAKE_POOL_PROGRAM_ID,
+    );
+
+    let lst_config = init_lst_config(
+        svm,
+        program_id,
+        unified_sol_config,
+        &lst_mint,
+        &stake_pool,
+        &SPL_STAKE_POOL_PROGRAM_ID,
+        authority,
+        pool_types::SPL_STAKE_POOL,
+    )
+    .expect("init_lst_config should succeed");
+
+    let (lst_vault, _) = find_lst_vault_pda(program_id, &lst_config);
+    update_vault_balance(svm, &lst_vault, 100_000_000_000);
+    update_lst_config_vault_balance(svm, &lst_config, 100_000_000_000);
+
+    (lst_config, lst_mint, stake_pool, lst_vault)
+}
+
+/// Initialize a unified config with one LST from [`add_spl_stake_pool_lst`].
+///
+/// Returns (unified_sol_config, lst_config, stake_pool, lst_vault).
+pub fn setup_stake_pool_lst(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    authority: &Keypair,
+) -> (Pubkey, Pubkey, Pubkey, Pubkey) {
+    let unified_sol_config =
+        init_unified_sol_pool_config(svm, program_id, authority, 0, 0, 0, 0, 0)
+            .expect("init_unified_sol_pool_config should succeed");
+
+    let (lst_config, _, stake_pool, lst_vault) =
+        add_spl_stake_pool_lst(svm, program_id, &unified_sol_config, authority);
+
+    (unified_sol_config, lst_config, stake_pool, lst_vault)
+}
+
+/// Initialize a unified config with one unfunded WSOL LST.
+///
+/// Returns (unified_sol_config, lst_config, lst_vault).
+pub fn setup_wsol_pool(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    authority: &Keypair,
+) -> (Pubkey, Pubkey, Pubkey) {
+    let unified_sol_config =
+        init_unified_sol_pool_config(svm, program_id, authority, 0, 0, 0, 0, 0)
+            .expect("init_unified_sol_pool_config should succeed");
+
+    let wsol_mint = create_mock_mint(svm, 9);
+    let lst_config = init_lst_config(
+        svm,
+        program_id,
+        &unified_sol_config,
+        &wsol_mint,
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        authority,
+        pool_types::WSOL,
+    )
+    .expect("init_lst_config should succeed");
+    let (lst_vault, _) = find_lst_vault_pda(program_id, &lst_config);
+
+    (unified_sol_config, lst_config, lst_vault)
+}
+
+/// Register an LST of `pool_type` (at 1:1) holding `funded` tokens, counted
+/// in `total_virtual_sol`.
+///
+/// Returns (lst_config, lst_mint, lst_vault).
+pub fn add_funded_lst(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_config: &Pubkey,
+    authority: &Keypair,
+    pool_type: u8,
+    funded: u64,
+) -> (Pubkey, Pubkey, Pubkey) {
+    let lst_mint = create_mock_mint(svm, 9);
+    let (stake_pool, stake_pool_program) = if pool_type == pool_types::WSOL {
+        (Pubkey::new_unique(), Pubkey::new_unique())
+    } else {
+        let stake_pool = create_mock_stake_pool(
+            svm,
+            &lst_mint,
+            1_000_000_000_000,
+            1_000_000_000_000,
+            SPL_STAKE_POOL_PROGRAM_ID,
+        );
+        (stake_pool, SPL_STAKE_POOL_PROGRAM_ID)
+    };
+    let lst_config = init_lst_config(
+        svm,
+        program_id,
+        unified_sol_config,
+        &lst_mint,
+        &stake_pool,
+        &stake_pool_program,
+        authority,
+        pool_type,
+    )
+    .expect("init_lst_config should succeed");
+
+    let (lst_vault, _) = find_lst_vault_pda(program_id, &lst_config);
+    update_vault_balance(svm, &lst_vault, funded);
+    update_lst_config_vault_balance(svm, &lst_config, funded);
+    update_total_virtual_sol(svm, unified_sol_config, &lst_config, funded as u128);
+
+    (lst_config, lst_mint, lst_vault)
+}
+
+/// Initialize a unified config with one LST of `pool_type` holding `funded`
+/// tokens (see [`add_funded_lst`]).
+///
+/// Returns (unified_sol_config, lst_config, lst_mint, lst_vault).
+pub fn setup_funded_lst(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    authority: &Keypair,
+    pool_type: u8,
+    funded: u64,
+) -> (Pubkey, Pubkey, Pubkey, Pubkey) {
+    let unified_sol_config =
+        init_unified_sol_pool_config(svm, program_id, authority, 0, 0, 0, 0, 0)
+            .expect("init_unified_sol_pool_config should succeed");
+
+    let (lst_config, lst_mint, lst_vault) = add_funded_lst(
+        svm,
+        program_id,
+        &unified_sol_config,
+        authority,
+        pool_type,
+        funded,
+    );
+
+    (unified_sol_config, lst_config, lst_mint, lst_vault)
+}
+
+/// Format a custom program error code as it appears in a transaction error
+pub fn custom_error(code: u32) -> String {
+    format!("Custom({})", code)
+}
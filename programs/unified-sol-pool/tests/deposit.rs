@@ -0,0 +1,76 @@
+//! Unified SOL pool deposit tests.
+//!
+//! Deposit is called directly here, standing in for the hub's CPI.
+
+mod common;
+
+use common::*;
+use litesvm::LiteSVM;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use zorb_pool_interface::UnifiedSolOpResult;
+
+/// Pool-wide deposit fee (1%)
+const DEPOSIT_FEE_RATE: u16 = 100;
+
+/// Test a deposit returns the amounts and rate it was priced at.
+#[test]
+fn test_deposit_returns_op_result() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let unified_sol_config = init_unified_sol_pool_config(
+        &mut svm,
+        &program_id,
+        &authority,
+        0,
+        DEPOSIT_FEE_RATE,
+        0,
+        0,
+        0,
+    )
+    .expect("init_unified_sol_pool_config should succeed");
+    let lst_mint = create_mock_mint(&mut svm, 9);
+    let lst_config = init_lst_config(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_mint,
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &authority,
+        pool_types::WSOL,
+    )
+    .expect("init_lst_config should succeed");
+
+    let depositor = Keypair::new();
+    svm.airdrop(&depositor.pubkey(), 10_000_000_000).unwrap();
+    let depositor_token = create_mock_token_account(&mut svm, &lst_mint, &depositor.pubkey());
+    update_vault_balance(&mut svm, &depositor_token, 1_000_000_000);
+
+    let return_data = deposit(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &depositor_token,
+        &depositor,
+        1_000_000_000,
+        990_000_000,
+    )
+    .expect("deposit should succeed");
+
+    assert_eq!(
+        UnifiedSolOpResult::from_bytes(&return_data),
+        Some(UnifiedSolOpResult {
+            lst_amount: 1_000_000_000,
+            sol_value: 1_000_000_000,
+            fee: 10_000_000,
+            exchange_rate: RATE_PRECISION,
+            accumulator_epoch: get_unified_config_reward_epoch(&svm, &unified_sol_config),
+        })
+    );
+}
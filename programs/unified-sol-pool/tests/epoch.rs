@@ -469,3 +469,200 @@ fn test_advance_epoch_too_early_fails() {
         "advance_unified_epoch should fail when called too early"
     );
 }
+
+/// Test that slot interval mode ignores Solana epoch boundaries.
+#[test]
+fn test_slot_interval_mode_ignores_solana_epoch() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, lst_vault) =
+        setup_wsol_pool(&mut svm, &program_id, &authority);
+    assert_eq!(
+        get_unified_config_epoch_mode(&svm, &unified_sol_config),
+        (epoch_modes::SLOT_INTERVAL, 0),
+        "new configs default to slot interval mode"
+    );
+
+    // A new Solana epoch alone does not open the reward epoch
+    set_clock(&mut svm, 10, 5);
+    harvest_lst_appreciation(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &lst_vault,
+        None,
+        &authority,
+    )
+    .expect("harvest should succeed");
+    let result = advance_unified_epoch(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &[lst_config],
+        &authority,
+    );
+    assert!(
+        result.is_err(),
+        "finalize should wait for the slot interval"
+    );
+
+    // The slot interval alone does, within the same Solana epoch
+    set_clock(&mut svm, UPDATE_SLOT_INTERVAL + 10, 5);
+    advance_unified_epoch(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &[lst_config],
+        &authority,
+    )
+    .expect("finalize should succeed after the slot interval");
+
+    assert_eq!(
+        get_unified_config_reward_epoch(&svm, &unified_sol_config),
+        2
+    );
+    assert_eq!(
+        get_unified_config_epoch_mode(&svm, &unified_sol_config),
+        (epoch_modes::SLOT_INTERVAL, 5),
+        "the Solana epoch is recorded in slot interval mode too"
+    );
+}
+
+/// Test that Solana epoch mode finalizes once per Solana epoch, regardless of slots.
+#[test]
+fn test_solana_epoch_mode_ignores_slot_interval() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, lst_vault) =
+        setup_wsol_pool(&mut svm, &program_id, &authority);
+    set_epoch_mode(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        epoch_modes::SOLANA_EPOCH,
+    )
+    .expect("set_epoch_mode should succeed");
+
+    // Solana epoch 1, well inside the slot interval: harvest and finalize
+    set_clock(&mut svm, 10, 1);
+    harvest_lst_appreciation(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &lst_vault,
+        None,
+        &authority,
+    )
+    .expect("harvest should succeed in a new Solana epoch");
+    advance_unified_epoch(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &[lst_config],
+        &authority,
+    )
+    .expect("finalize should not wait for the slot interval");
+    assert_eq!(
+        get_unified_config_epoch_mode(&svm, &unified_sol_config),
+        (epoch_modes::SOLANA_EPOCH, 1)
+    );
+
+    // Past the slot interval but still Solana epoch 1: harvest and finalize wait
+    set_clock(&mut svm, 2 * UPDATE_SLOT_INTERVAL + 20, 1);
+    let result = harvest_lst_appreciation(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &lst_vault,
+        None,
+        &authority,
+    );
+    assert!(
+        result.is_err(),
+        "harvest should wait for the next Solana epoch"
+    );
+    let result = advance_unified_epoch(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &[lst_config],
+        &authority,
+    );
+    assert!(
+        result.is_err(),
+        "finalize should wait for the next Solana epoch"
+    );
+
+    // Solana epoch 2, a few slots later: both go through
+    set_clock(&mut svm, 2 * UPDATE_SLOT_INTERVAL + 30, 2);
+    harvest_lst_appreciation(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &lst_vault,
+        None,
+        &authority,
+    )
+    .expect("harvest should succeed in the next Solana epoch");
+    advance_unified_epoch(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &[lst_config],
+        &authority,
+    )
+    .expect("finalize should succeed in the next Solana epoch");
+
+    assert_eq!(
+        get_unified_config_reward_epoch(&svm, &unified_sol_config),
+        3
+    );
+    assert_eq!(
+        get_unified_config_epoch_mode(&svm, &unified_sol_config),
+        (epoch_modes::SOLANA_EPOCH, 2)
+    );
+}
+
+/// Test SetEpochMode rejects unknown modes and non-authority signers.
+#[test]
+fn test_set_epoch_mode_validation() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, _, _) = setup_wsol_pool(&mut svm, &program_id, &authority);
+
+    let result = set_epoch_mode(&mut svm, &program_id, &unified_sol_config, &authority, 2);
+    assert!(result.is_err(), "unknown epoch mode should be rejected");
+
+    let attacker = Keypair::new();
+    svm.airdrop(&attacker.pubkey(), 1_000_000_000).unwrap();
+    let result = set_epoch_mode(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &attacker,
+        epoch_modes::SOLANA_EPOCH,
+    );
+    assert!(result.is_err(), "non-authority should be rejected");
+
+    assert_eq!(
+        get_unified_config_epoch_mode(&svm, &unified_sol_config).0,
+        epoch_modes::SLOT_INTERVAL
+    );
+}
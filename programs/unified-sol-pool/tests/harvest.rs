@@ -309,3 +309,596 @@ fn test_harvest_twice_same_epoch_idempotent() {
         result2.err()
     );
 }
+/// Set up an SPL stake pool LST with a jitoSOL-like pool at the given rate
+/// and 100 tokens in the vault. Returns (unified_config, lst_config,
+/// stake_pool, lst_vault).
+fn setup_spl_stake_pool_lst(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    authority: &Keypair,
+    total_lamports: u64,
+    pool_token_supply: u64,
+) -> (Pubkey, Pubkey, Pubkey, Pubkey) {
+    let unified_sol_config =
+        init_unified_sol_pool_config(svm, program_id, authority, 0, 0, 0, 0, 0)
+            .expect("init_unified_sol_pool_config should succeed");
+
+    let lst_mint = create_mock_mint(svm, 9);
+    let stake_pool = create_mock_stake_pool(
+        svm,
+        &lst_mint,
+        total_lamports,
+        pool_token_supply,
+        SPL_STAKE_POOL_PROGRAM_ID,
+    );
+
+    let lst_config = init_lst_config(
+        svm,
+        program_id,
+        &unified_sol_config,
+        &lst_mint,
+        &stake_pool,
+        &SPL_STAKE_POOL_PROGRAM_ID,
+        authority,
+        pool_types::SPL_STAKE_POOL,
+    )
+    .expect("init_lst_config should succeed");
+
+    let (lst_vault, _) = find_lst_vault_pda(program_id, &lst_config);
+    update_vault_balance(svm, &lst_vault, 100_000_000_000);
+    update_lst_config_vault_balance(svm, &lst_config, 100_000_000_000);
+
+    (unified_sol_config, lst_config, stake_pool, lst_vault)
+}
+
+/// Test the rate is derived from the stake pool's own accounting.
+#[test]
+fn test_harvest_derives_rate_from_stake_pool() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    // 1.004 SOL per token, within the 0.5% per-harvest cap from 1:1
+    let (unified_sol_config, lst_config, stake_pool, lst_vault) = setup_spl_stake_pool_lst(
+        &mut svm,
+        &program_id,
+        &authority,
+        12_048_000_000_000_000,
+        12_000_000_000_000_000,
+    );
+
+    harvest_lst_appreciation(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &stake_pool,
+        Some(&lst_vault),
+        &authority,
+    )
+    .expect("harvest should succeed");
+
+    assert_eq!(
+        get_lst_config_exchange_rate(&svm, &lst_config),
+        1_004_000_000
+    );
+    // 100 tokens * 0.004 SOL of appreciation
+    assert_eq!(
+        get_unified_config_pending_appreciation(&svm, &unified_sol_config),
+        400_000_000
+    );
+}
+
+/// Test a look-alike stake pool owned by another program is rejected.
+#[test]
+fn test_harvest_rejects_stake_pool_owned_by_wrong_program() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, stake_pool, lst_vault) = setup_spl_stake_pool_lst(
+        &mut svm,
+        &program_id,
+        &authority,
+        1_000_000_000_000,
+        1_000_000_000_000,
+    );
+
+    // Same address and layout, reporting appreciation, but owned by a fake program
+    let mut account = svm.get_account(&stake_pool).unwrap();
+    account.owner = Pubkey::new_unique();
+    svm.set_account(stake_pool, account).unwrap();
+    update_stake_pool_rate(&mut svm, &stake_pool, 1_004_000_000_000, 1_000_000_000_000);
+
+    let result = harvest_lst_appreciation(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &stake_pool,
+        Some(&lst_vault),
+        &authority,
+    );
+
+    assert!(
+        result.is_err(),
+        "harvest should reject a stake pool owned by the wrong program"
+    );
+    assert_eq!(
+        get_lst_config_exchange_rate(&svm, &lst_config),
+        1_000_000_000
+    );
+}
+
+/// Test the optional expected rate is a cross-check, not the source of truth.
+#[test]
+fn test_harvest_expected_rate_cross_check() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    let other_payer = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+    svm.airdrop(&other_payer.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, stake_pool, lst_vault) = setup_spl_stake_pool_lst(
+        &mut svm,
+        &program_id,
+        &authority,
+        1_004_000_000_000,
+        1_000_000_000_000,
+    );
+
+    // More than 10 bps away from the derived 1.004 rate
+    let result = harvest_lst_appreciation_with_expected_rate(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &stake_pool,
+        Some(&lst_vault),
+        Some(1_002_000_000),
+        &authority,
+    );
+    assert!(result.is_err(), "harvest should reject a mismatched rate");
+
+    // Within tolerance: the derived rate is stored, not the expected one
+    harvest_lst_appreciation_with_expected_rate(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &stake_pool,
+        Some(&lst_vault),
+        Some(1_003_500_000),
+        &other_payer,
+    )
+    .expect("harvest within tolerance should succeed");
+    assert_eq!(
+        get_lst_config_exchange_rate(&svm, &lst_config),
+        1_004_000_000
+    );
+}
+/// Test an mSOL config is priced from the Marinade state account.
+#[test]
+fn test_harvest_marinade_rate_source() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let unified_sol_config =
+        init_unified_sol_pool_config(&mut svm, &program_id, &authority, 0, 0, 0, 0, 0)
+            .expect("init_unified_sol_pool_config should succeed");
+
+    let msol_mint = create_mock_mint(&mut svm, 9);
+    // 1.003 SOL per mSOL
+    let marinade_state = create_mock_marinade_state(
+        &mut svm,
+        &msol_mint,
+        5_015_000_000_000_000,
+        5_000_000_000_000_000,
+        MARINADE_PROGRAM_ID,
+    );
+
+    // The SPL stake pool program is not a valid owner for a Marinade config
+    let result = init_lst_config(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &msol_mint,
+        &marinade_state,
+        &SPL_STAKE_POOL_PROGRAM_ID,
+        &authority,
+        pool_types::MARINADE,
+    );
+    assert!(result.is_err(), "init should require the Marinade program");
+
+    let lst_config = init_lst_config(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &msol_mint,
+        &marinade_state,
+        &MARINADE_PROGRAM_ID,
+        &authority,
+        pool_types::MARINADE,
+    )
+    .expect("init_lst_config should succeed");
+
+    let (lst_vault, _) = find_lst_vault_pda(&program_id, &lst_config);
+    update_vault_balance(&mut svm, &lst_vault, 100_000_000_000);
+    update_lst_config_vault_balance(&mut svm, &lst_config, 100_000_000_000);
+
+    harvest_lst_appreciation(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &marinade_state,
+        Some(&lst_vault),
+        &authority,
+    )
+    .expect("harvest should succeed");
+
+    assert_eq!(
+        get_lst_config_exchange_rate(&svm, &lst_config),
+        1_003_000_000
+    );
+    assert_eq!(
+        get_unified_config_pending_appreciation(&svm, &unified_sol_config),
+        300_000_000
+    );
+}
+/// Test a Sanctum single-validator LST (SPL layout, own program deployment)
+/// can be registered and harvested only while its program is allowlisted.
+#[test]
+fn test_sanctum_stake_pool_requires_allowlisted_program() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let unified_sol_config =
+        init_unified_sol_pool_config(&mut svm, &program_id, &authority, 0, 0, 0, 0, 0)
+            .expect("init_unified_sol_pool_config should succeed");
+
+    let sanctum_program = Pubkey::new_unique();
+    let lst_mint = create_mock_mint(&mut svm, 9);
+    let stake_pool = create_mock_stake_pool(
+        &mut svm,
+        &lst_mint,
+        1_000_000_000_000,
+        1_000_000_000_000,
+        sanctum_program,
+    );
+
+    // Rejected before the program is allowlisted
+    let result = init_lst_config(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_mint,
+        &stake_pool,
+        &sanctum_program,
+        &authority,
+        pool_types::SPL_STAKE_POOL,
+    );
+    assert!(
+        result.is_err(),
+        "init_lst_config should reject a program that is not allowlisted"
+    );
+
+    add_stake_pool_program(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        &sanctum_program,
+    )
+    .expect("add_stake_pool_program should succeed");
+
+    let lst_config = init_lst_config(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_mint,
+        &stake_pool,
+        &sanctum_program,
+        &authority,
+        pool_types::SPL_STAKE_POOL,
+    )
+    .expect("init_lst_config should accept an allowlisted program");
+
+    let (lst_vault, _) = find_lst_vault_pda(&program_id, &lst_config);
+    update_vault_balance(&mut svm, &lst_vault, 100_000_000_000);
+    update_lst_config_vault_balance(&mut svm, &lst_config, 100_000_000_000);
+    update_stake_pool_rate(&mut svm, &stake_pool, 1_004_000_000_000, 1_000_000_000_000);
+
+    harvest_lst_appreciation(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &stake_pool,
+        Some(&lst_vault),
+        &authority,
+    )
+    .expect("harvest should read the Sanctum pool");
+    assert_eq!(
+        get_lst_config_exchange_rate(&svm, &lst_config),
+        1_004_000_000
+    );
+
+    // Delisting the program revokes harvesting
+    remove_stake_pool_program(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        &sanctum_program,
+    )
+    .expect("remove_stake_pool_program should succeed");
+    svm.expire_blockhash();
+
+    let result = harvest_lst_appreciation(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &stake_pool,
+        Some(&lst_vault),
+        &authority,
+    );
+    assert!(
+        result.is_err(),
+        "harvest should fail once the program is delisted"
+    );
+}
+
+/// Test HarvestAll covers every LST, skipping one already harvested this epoch.
+#[test]
+fn test_harvest_all_lst_appreciation() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let unified_sol_config =
+        init_unified_sol_pool_config(&mut svm, &program_id, &authority, 0, 0, 0, 0, 0)
+            .expect("init_unified_sol_pool_config should succeed");
+
+    // WSOL: rate is always 1:1, so zero appreciation
+    let wsol_mint = create_mock_mint(&mut svm, 9);
+    let wsol_config = init_lst_config(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &wsol_mint,
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &authority,
+        pool_types::WSOL,
+    )
+    .expect("init_lst_config should succeed");
+    let (wsol_vault, _) = find_lst_vault_pda(&program_id, &wsol_config);
+
+    let (appreciating_config, _, appreciating_pool, appreciating_vault) =
+        add_spl_stake_pool_lst(&mut svm, &program_id, &unified_sol_config, &authority);
+    let (harvested_config, _, harvested_pool, harvested_vault) =
+        add_spl_stake_pool_lst(&mut svm, &program_id, &unified_sol_config, &authority);
+
+    // Harvest one LST individually at 1.002
+    update_stake_pool_rate(
+        &mut svm,
+        &harvested_pool,
+        1_002_000_000_000,
+        1_000_000_000_000,
+    );
+    harvest_lst_appreciation(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &harvested_config,
+        &harvested_pool,
+        Some(&harvested_vault),
+        &authority,
+    )
+    .expect("harvest should succeed");
+    let pending_after_single = get_unified_config_pending_appreciation(&svm, &unified_sol_config);
+
+    // Both stake pools move again; only the unharvested one should be read
+    update_stake_pool_rate(
+        &mut svm,
+        &appreciating_pool,
+        1_004_000_000_000,
+        1_000_000_000_000,
+    );
+    update_stake_pool_rate(
+        &mut svm,
+        &harvested_pool,
+        1_004_000_000_000,
+        1_000_000_000_000,
+    );
+
+    let lsts = [
+        (wsol_config, wsol_vault, wsol_vault),
+        (appreciating_config, appreciating_pool, appreciating_vault),
+        (harvested_config, harvested_pool, harvested_vault),
+    ];
+
+    // Leaving one out is rejected
+    let result = harvest_all_lst_appreciation(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lsts[..2],
+        &authority,
+    );
+    assert!(result.is_err(), "harvest_all should require every LST");
+
+    harvest_all_lst_appreciation(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lsts,
+        &authority,
+    )
+    .expect("harvest_all should succeed");
+
+    let epoch = get_unified_config_reward_epoch(&svm, &unified_sol_config);
+    for (lst_config, _, _) in &lsts {
+        assert_eq!(get_lst_config_last_harvest_epoch(&svm, lst_config), epoch);
+    }
+    assert_eq!(
+        get_lst_config_exchange_rate(&svm, &wsol_config),
+        1_000_000_000
+    );
+    assert_eq!(
+        get_lst_config_exchange_rate(&svm, &appreciating_config),
+        1_004_000_000
+    );
+    // Skipped: still at the rate from its individual harvest
+    assert_eq!(
+        get_lst_config_exchange_rate(&svm, &harvested_config),
+        1_002_000_000
+    );
+    assert!(
+        get_unified_config_pending_appreciation(&svm, &unified_sol_config) > pending_after_single
+    );
+
+    // Every LST is harvested, so finalization can proceed
+    warp_to_slot(&mut svm, 2710);
+    advance_unified_epoch(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &[wsol_config, appreciating_config, harvested_config],
+        &authority,
+    )
+    .expect("finalize should succeed after harvest_all");
+}
+
+/// Test sub-threshold appreciation is carried across epochs until it crosses
+/// `min_harvest_lamports`, without blocking finalization.
+#[test]
+fn test_dust_harvest_carried_until_threshold() {
+    const POOL_SUPPLY: u64 = 1_000_000_000_000;
+    const VAULT_TOKENS: u64 = 100_000_000_000;
+    // +4000 stake pool lamports = +4 rate units = 400 lamports of appreciation
+    const LAMPORTS_STEP: u64 = 4_000;
+    const MIN_HARVEST_LAMPORTS: u64 = 1_000;
+
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let unified_sol_config =
+        init_unified_sol_pool_config(&mut svm, &program_id, &authority, 0, 0, 0, 0, 0)
+            .expect("init_unified_sol_pool_config should succeed");
+
+    let lst_mint = create_mock_mint(&mut svm, 9);
+    let stake_pool = create_mock_stake_pool(
+        &mut svm,
+        &lst_mint,
+        POOL_SUPPLY,
+        POOL_SUPPLY,
+        SPL_STAKE_POOL_PROGRAM_ID,
+    );
+    let lst_config = init_lst_config(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_mint,
+        &stake_pool,
+        &SPL_STAKE_POOL_PROGRAM_ID,
+        &authority,
+        pool_types::SPL_STAKE_POOL,
+    )
+    .expect("init_lst_config should succeed");
+
+    let (lst_vault, _) = find_lst_vault_pda(&program_id, &lst_config);
+    update_vault_balance(&mut svm, &lst_vault, VAULT_TOKENS);
+    update_lst_config_vault_balance(&mut svm, &lst_config, VAULT_TOKENS);
+
+    let attacker = Keypair::new();
+    svm.airdrop(&attacker.pubkey(), 10_000_000_000).unwrap();
+    let result = set_min_harvest_lamports(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &attacker,
+        MIN_HARVEST_LAMPORTS,
+    );
+    assert!(
+        result.is_err(),
+        "non-authority should not set the threshold"
+    );
+
+    set_min_harvest_lamports(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        MIN_HARVEST_LAMPORTS,
+    )
+    .expect("set_min_harvest_lamports should succeed");
+
+    // (carried after the harvest, pending_appreciation after the harvest)
+    let expected = [(400, 0), (800, 0), (0, 1_200)];
+    for (i, (carried, pending)) in expected.into_iter().enumerate() {
+        let step = i as u64 + 1;
+        svm.expire_blockhash();
+        update_stake_pool_rate(
+            &mut svm,
+            &stake_pool,
+            POOL_SUPPLY + step * LAMPORTS_STEP,
+            POOL_SUPPLY,
+        );
+        harvest_lst_appreciation(
+            &mut svm,
+            &program_id,
+            &unified_sol_config,
+            &lst_config,
+            &stake_pool,
+            Some(&lst_vault),
+            &authority,
+        )
+        .expect("harvest should succeed");
+
+        // The rate always advances and the LST counts as harvested
+        let epoch = get_unified_config_reward_epoch(&svm, &unified_sol_config);
+        assert_eq!(get_lst_config_last_harvest_epoch(&svm, &lst_config), epoch);
+        assert_eq!(
+            get_lst_config_exchange_rate(&svm, &lst_config),
+            1_000_000_000 + step * 4
+        );
+        assert_eq!(
+            get_unified_config_harvest_threshold(&svm, &unified_sol_config),
+            (MIN_HARVEST_LAMPORTS, carried)
+        );
+        assert_eq!(
+            get_unified_config_pending_appreciation(&svm, &unified_sol_config),
+            pending
+        );
+
+        // Finalization proceeds even when the harvest was dust-skipped
+        warp_to_slot(&mut svm, step * 2710);
+        advance_unified_epoch(
+            &mut svm,
+            &program_id,
+            &unified_sol_config,
+            &[lst_config],
+            &authority,
+        )
+        .expect("finalize should succeed");
+    }
+
+    assert_eq!(
+        get_unified_config_total_appreciation(&svm, &unified_sol_config),
+        1_200
+    );
+}
@@ -2,7 +2,11 @@
 //!
 //! Tests the unified-sol-pool program state logic and edge cases.
 
-use unified_sol_pool::{LstConfig, UnifiedSolPoolConfig, UnifiedSolPoolError, UNIFIED_SOL_ASSET_ID};
+use unified_sol_pool::{
+    EpochMode, LST_CONFIG_VERSION, LST_MODE_ALL, LST_MODE_DEPOSITS_ENABLED,
+    LST_MODE_WITHDRAWALS_ENABLED, LstConfig, UNIFIED_SOL_ASSET_ID, UnifiedSolPoolConfig,
+    UnifiedSolPoolError,
+};
 use zorb_pool_interface::{DepositParams, WithdrawParams};
 
 /// Slot interval alias for readable test assertions.
@@ -20,7 +24,11 @@ fn default_pool_config() -> UnifiedSolPoolConfig {
         authority: [0u8; 32],
         pending_authority: [0u8; 32],
         reward_epoch: 1, // Starts at 1, not 0
-        _reserved1: [0u64; 7],
+        last_finalized_solana_epoch: 0,
+        min_harvest_lamports: 0,
+        carried_appreciation: 0,
+        manual_rate_delay_slots: UnifiedSolPoolConfig::DEFAULT_MANUAL_RATE_DELAY_SLOTS,
+        _reserved1: [0u64; 3],
         total_virtual_sol: 0,
         reward_accumulator: 0,
         last_finalized_slot: 0,
@@ -49,7 +57,19 @@ fn default_pool_config() -> UnifiedSolPoolConfig {
         deposit_count: 0,
         withdrawal_count: 0,
         lst_count: 0,
-        _reserved: [0u8; 23],
+        epoch_mode: 0,
+        _reserved: [0u8; 22],
+        stake_pool_programs: [[0u8; 32]; 4],
+        max_total_virtual_sol: 0,
+        total_virtual_sol_over_cap: 0,
+        withdraw_priority: [[0u8; 32]; 4],
+        commission_treasury: [0u8; 32],
+        accrued_commission: 0,
+        appreciation_commission_bps: 0,
+        _commission_padding: [0u8; 6],
+        keepers: [[0u8; 32]; 4],
+        keeper_allowlist_enabled: 0,
+        _keeper_padding: [0u8; 15],
     }
 }
 
@@ -58,9 +78,11 @@ fn default_lst_config() -> LstConfig {
     LstConfig {
         // Header
         pool_type: 0, // WSOL
-        is_active: 1,
+        mode: LST_MODE_ALL,
         bump: 255,
-        _header_pad: [0u8; 5],
+        is_retired: 0,
+        max_rate_increase_bps_per_epoch: LstConfig::DEFAULT_MAX_RATE_INCREASE_BPS_PER_EPOCH,
+        max_rate_decrease_bps_per_epoch: LstConfig::DEFAULT_MAX_RATE_DECREASE_BPS_PER_EPOCH,
         // Common References
         lst_mint: [0u8; 32],
         lst_vault: [0u8; 32],
@@ -69,24 +91,29 @@ fn default_lst_config() -> LstConfig {
         harvested_exchange_rate: 1_000_000_000,
         last_rate_update_slot: 0,
         last_harvest_epoch: 0,
-        _pad_for_u128: 0,
+        rate_updated_slot: 0,
         // Value Tracking
         total_virtual_sol: 0,
         vault_token_balance: 0,
-        _value_pad: 0,
+        max_rate_staleness_slots: LstConfig::DEFAULT_MAX_RATE_STALENESS_SLOTS,
         // Statistics
         total_deposited: 0,
         total_withdrawn: 0,
         total_appreciation_harvested: 0,
         deposit_count: 0,
         withdrawal_count: 0,
-        _stat_pad: 0,
+        deposit_fee_bps_override: zorb_pool_interface::FEE_RATE_UNSET,
+        withdraw_fee_bps_override: zorb_pool_interface::FEE_RATE_UNSET,
+        version: LST_CONFIG_VERSION,
+        _fee_pad: [0u8; 2],
         // Stake Pool Specific (zeroed for WSOL)
         stake_pool: [0u8; 32],
         stake_pool_program: [0u8; 32],
         previous_exchange_rate: 1_000_000_000,
         // Reserved
-        _reserved: 0,
+        approved_exchange_rate: 0,
+        max_virtual_sol: 0,
+        virtual_sol_over_cap: 0,
     }
 }
 
@@ -135,8 +162,14 @@ fn test_lst_config_is_active() {
     let mut config = default_lst_config();
     assert!(config.is_active());
 
-    config.is_active = 0;
+    config.mode = 0;
     assert!(!config.is_active());
+
+    // Either direction keeps the LST active
+    config.mode = LST_MODE_DEPOSITS_ENABLED;
+    assert!(config.is_active());
+    config.mode = LST_MODE_WITHDRAWALS_ENABLED;
+    assert!(config.is_active());
 }
 
 // =============================================================================
@@ -395,6 +428,185 @@ fn test_add_appreciation_overflow_total() {
     assert!(result.is_err());
 }
 
+// =============================================================================
+// book_appreciation() Tests
+// =============================================================================
+
+#[test]
+fn test_book_appreciation_without_threshold() {
+    let mut config = default_pool_config();
+
+    assert_eq!(config.book_appreciation(3).unwrap(), Some((3, 0)));
+    assert_eq!(config.pending_appreciation, 3);
+    assert_eq!(config.carried_appreciation, 0);
+}
+
+#[test]
+fn test_book_appreciation_carries_dust_until_threshold() {
+    let mut config = default_pool_config();
+    config.min_harvest_lamports = 1_000;
+
+    // Below the threshold: carried, pending rewards untouched
+    assert_eq!(config.book_appreciation(400).unwrap(), None);
+    assert_eq!(config.book_appreciation(400).unwrap(), None);
+    assert_eq!(config.carried_appreciation, 800);
+    assert_eq!(config.pending_appreciation, 0);
+    assert_eq!(config.total_appreciation, 0);
+
+    // Crossing the threshold books the carried dust with this harvest
+    assert_eq!(config.book_appreciation(400).unwrap(), Some((1_200, 0)));
+    assert_eq!(config.carried_appreciation, 0);
+    assert_eq!(config.pending_appreciation, 1_200);
+    assert_eq!(config.total_appreciation, 1_200);
+}
+
+#[test]
+fn test_book_appreciation_carry_survives_finalization() {
+    let mut config = default_pool_config();
+    config.finalized_balance = 1_000_000_000_000;
+    config.min_harvest_lamports = 1_000;
+
+    assert_eq!(config.book_appreciation(600).unwrap(), None);
+    config.finalize_rewards(INTERVAL, 0).unwrap();
+    assert_eq!(config.carried_appreciation, 600);
+    assert_eq!(config.total_rewards_distributed, 0);
+
+    assert_eq!(config.book_appreciation(600).unwrap(), Some((1_200, 0)));
+    assert_eq!(config.pending_appreciation, 1_200);
+}
+
+#[test]
+fn test_book_appreciation_takes_commission_on_total() {
+    let mut config = default_pool_config();
+    config.min_harvest_lamports = 1_000;
+    config
+        .set_appreciation_commission(1_000, &[7u8; 32])
+        .unwrap();
+
+    assert_eq!(config.book_appreciation(500).unwrap(), None);
+    assert_eq!(config.accrued_commission, 0);
+
+    assert_eq!(config.book_appreciation(500).unwrap(), Some((1_000, 100)));
+    assert_eq!(config.accrued_commission, 100);
+    assert_eq!(config.pending_appreciation, 900);
+}
+
+// =============================================================================
+// Appreciation Commission Tests
+// =============================================================================
+
+#[test]
+fn test_add_appreciation_zero_commission() {
+    let mut config = default_pool_config();
+
+    let commission = config.add_appreciation(1_000_000_000).unwrap();
+
+    assert_eq!(commission, 0);
+    assert_eq!(config.accrued_commission, 0);
+    assert_eq!(config.pending_appreciation, 1_000_000_000);
+}
+
+#[test]
+fn test_add_appreciation_ten_percent_commission() {
+    let mut config = default_pool_config();
+    config
+        .set_appreciation_commission(1_000, &[7u8; 32])
+        .unwrap();
+
+    let commission = config.add_appreciation(1_000_000_000).unwrap();
+
+    assert_eq!(commission, 100_000_000);
+    assert_eq!(config.accrued_commission, 100_000_000);
+    assert_eq!(config.pending_appreciation, 900_000_000);
+    // Total appreciation records the full harvested amount
+    assert_eq!(config.total_appreciation, 1_000_000_000);
+}
+
+#[test]
+fn test_add_appreciation_full_commission() {
+    let mut config = default_pool_config();
+    config
+        .set_appreciation_commission(10_000, &[7u8; 32])
+        .unwrap();
+
+    let commission = config.add_appreciation(1_000_000_000).unwrap();
+
+    assert_eq!(commission, 1_000_000_000);
+    assert_eq!(config.accrued_commission, 1_000_000_000);
+    assert_eq!(config.pending_appreciation, 0);
+}
+
+#[test]
+fn test_appreciation_commission_rounds_down() {
+    let mut config = default_pool_config();
+    config
+        .set_appreciation_commission(1_000, &[7u8; 32])
+        .unwrap();
+
+    // 10% of 19 lamports = 1.9 -> 1; depositors keep the remainder
+    assert_eq!(config.add_appreciation(19).unwrap(), 1);
+    assert_eq!(config.pending_appreciation, 18);
+}
+
+#[test]
+fn test_set_appreciation_commission_rejects_over_100_percent() {
+    let mut config = default_pool_config();
+
+    assert_eq!(
+        config.set_appreciation_commission(10_001, &[7u8; 32]),
+        Err(UnifiedSolPoolError::InvalidFeeRate)
+    );
+    assert_eq!(config.appreciation_commission_bps, 0);
+    assert_eq!(config.commission_treasury, [0u8; 32]);
+}
+
+#[test]
+fn test_finalize_distributes_only_depositor_share() {
+    let mut config = default_pool_config();
+    config.finalized_balance = 1_000_000_000_000;
+    config
+        .set_appreciation_commission(1_000, &[7u8; 32])
+        .unwrap();
+    config.add_appreciation(10_000_000_000).unwrap();
+
+    config.finalize_rewards(INTERVAL, 0).unwrap();
+
+    // 9 SOL of the 10 SOL harvested reaches depositors
+    assert_eq!(config.total_rewards_distributed, 9_000_000_000);
+    assert_eq!(
+        config.reward_accumulator,
+        9_000_000_000 * UnifiedSolPoolConfig::ACCUMULATOR_PRECISION / 1_000_000_000_000
+    );
+    // The commission survives finalization
+    assert_eq!(config.accrued_commission, 1_000_000_000);
+}
+
+#[test]
+fn test_take_commission() {
+    let mut config = default_pool_config();
+    config.accrued_commission = 1_000_000_000;
+
+    config.take_commission(400_000_000).unwrap();
+    assert_eq!(config.accrued_commission, 600_000_000);
+
+    assert_eq!(
+        config.take_commission(600_000_001),
+        Err(UnifiedSolPoolError::InsufficientCommission)
+    );
+    assert_eq!(config.accrued_commission, 600_000_000);
+}
+
+#[test]
+fn test_commission_withdrawal_conversion() {
+    let mut lst = default_lst_config();
+    lst.harvested_exchange_rate = 1_050_000_000;
+
+    // 1.05 SOL of commission pays out 1 LST at the frozen 1.05x rate
+    assert_eq!(lst.calculate_lst_tokens(1_050_000_000), 1_000_000_000);
+    // Conversion rounds down in the pool's favour
+    assert_eq!(lst.calculate_lst_tokens(1_050_000_001), 1_000_000_000);
+}
+
 // =============================================================================
 // finalize_rewards() Slot Timing Tests
 // =============================================================================
@@ -406,7 +618,7 @@ fn test_finalize_rewards_not_ready_returns_false() {
     config.finalized_balance = 100;
 
     // Not enough slots elapsed (one slot short of interval)
-    let result = config.finalize_rewards(1000 + INTERVAL - 1);
+    let result = config.finalize_rewards(1000 + INTERVAL - 1, 0);
     assert!(result.is_ok());
     assert!(!result.unwrap()); // Returns false when not ready
 }
@@ -419,7 +631,7 @@ fn test_finalize_rewards_ready_returns_true() {
     config.pending_deposit_fees = 1_000_000;
 
     // Exactly UPDATE_SLOT_INTERVAL slots
-    let result = config.finalize_rewards(1000 + INTERVAL);
+    let result = config.finalize_rewards(1000 + INTERVAL, 0);
     assert!(result.is_ok());
     assert!(result.unwrap()); // Returns true when ready and finalized
 }
@@ -430,7 +642,7 @@ fn test_finalize_rewards_past_interval() {
     config.last_finalized_slot = 1000;
     config.finalized_balance = 1_000_000_000;
 
-    let result = config.finalize_rewards(1000 + INTERVAL * 3);
+    let result = config.finalize_rewards(1000 + INTERVAL * 3, 0);
     assert!(result.is_ok());
     assert!(result.unwrap());
     assert_eq!(config.last_finalized_slot, 1000 + INTERVAL * 3);
@@ -446,7 +658,7 @@ fn test_finalize_rewards_increments_epoch() {
     config.reward_epoch = 1;
     config.finalized_balance = 1_000_000_000;
 
-    config.finalize_rewards(INTERVAL).unwrap();
+    config.finalize_rewards(INTERVAL, 0).unwrap();
 
     assert_eq!(config.reward_epoch, 2);
 }
@@ -457,13 +669,13 @@ fn test_finalize_rewards_epoch_increments_multiple_times() {
     config.reward_epoch = 1;
     config.finalized_balance = 1_000_000_000;
 
-    config.finalize_rewards(INTERVAL).unwrap();
+    config.finalize_rewards(INTERVAL, 0).unwrap();
     assert_eq!(config.reward_epoch, 2);
 
-    config.finalize_rewards(INTERVAL * 2).unwrap();
+    config.finalize_rewards(INTERVAL * 2, 0).unwrap();
     assert_eq!(config.reward_epoch, 3);
 
-    config.finalize_rewards(INTERVAL * 3).unwrap();
+    config.finalize_rewards(INTERVAL * 3, 0).unwrap();
     assert_eq!(config.reward_epoch, 4);
 }
 
@@ -473,10 +685,67 @@ fn test_finalize_rewards_epoch_overflow() {
     config.reward_epoch = u64::MAX;
     config.finalized_balance = 1_000_000_000;
 
-    let result = config.finalize_rewards(INTERVAL);
+    let result = config.finalize_rewards(INTERVAL, 0);
     assert!(result.is_err());
 }
 
+// =============================================================================
+// finalize_rewards() Epoch Mode Tests
+// =============================================================================
+
+#[test]
+fn test_slot_interval_mode_ignores_solana_epoch() {
+    let mut config = default_pool_config();
+    config.last_finalized_slot = 1000;
+    config.finalized_balance = 1_000_000_000;
+
+    // A new Solana epoch alone is not enough
+    assert!(!config.can_finalize(1000 + INTERVAL - 1, 7));
+    assert!(!config.finalize_rewards(1000 + INTERVAL - 1, 7).unwrap());
+    assert!(config.can_harvest(0), "harvests are ungated in slot interval mode");
+
+    // The slot interval alone is, and the Solana epoch is still recorded
+    assert!(config.finalize_rewards(1000 + INTERVAL, 0).unwrap());
+    assert_eq!(config.last_finalized_solana_epoch, 0);
+    assert!(config.finalize_rewards(1000 + INTERVAL * 2, 9).unwrap());
+    assert_eq!(config.last_finalized_solana_epoch, 9);
+}
+
+#[test]
+fn test_solana_epoch_mode_ignores_slot_interval() {
+    let mut config = default_pool_config();
+    config.epoch_mode = EpochMode::SolanaEpoch as u8;
+    config.last_finalized_slot = 1000;
+    config.last_finalized_solana_epoch = 5;
+    config.finalized_balance = 1_000_000_000;
+
+    // Slots alone are not enough
+    assert!(!config.can_harvest(5));
+    assert!(!config.finalize_rewards(1000 + INTERVAL * 10, 5).unwrap());
+    assert_eq!(config.reward_epoch, 1);
+
+    // The next Solana epoch is, one slot after the last finalization
+    assert!(config.can_harvest(6));
+    assert!(config.finalize_rewards(1001, 6).unwrap());
+    assert_eq!(config.reward_epoch, 2);
+    assert_eq!(config.last_finalized_slot, 1001);
+    assert_eq!(config.last_finalized_solana_epoch, 6);
+
+    // Once per Solana epoch
+    assert!(!config.can_harvest(6));
+    assert!(!config.finalize_rewards(1001 + INTERVAL, 6).unwrap());
+}
+
+#[test]
+fn test_unknown_epoch_mode_falls_back_to_slot_interval() {
+    let mut config = default_pool_config();
+    config.epoch_mode = 0xFF;
+
+    assert_eq!(config.epoch_mode(), EpochMode::SlotInterval);
+    assert!(!config.can_finalize(INTERVAL - 1, 1));
+    assert!(config.can_finalize(INTERVAL, 0));
+}
+
 // =============================================================================
 // finalize_rewards() Accumulator Math Tests
 // =============================================================================
@@ -487,7 +756,7 @@ fn test_finalize_rewards_basic_distribution() {
     config.finalized_balance = 1_000_000_000_000; // 1000 virtual SOL
     config.pending_deposit_fees = 10_000_000_000; // 10 virtual SOL in fees
 
-    config.finalize_rewards(INTERVAL).unwrap();
+    config.finalize_rewards(INTERVAL, 0).unwrap();
 
     let expected = 10_000_000_000u128 * UnifiedSolPoolConfig::ACCUMULATOR_PRECISION / 1_000_000_000_000;
     assert_eq!(config.reward_accumulator, expected);
@@ -502,7 +771,7 @@ fn test_finalize_rewards_combines_all_sources() {
     config.pending_withdrawal_fees = 2_000_000_000;
     config.pending_appreciation = 3_000_000_000;
 
-    config.finalize_rewards(INTERVAL).unwrap();
+    config.finalize_rewards(INTERVAL, 0).unwrap();
 
     // Total = 1 + 2 + 3 = 6 virtual SOL
     let expected = 6_000_000_000u128 * UnifiedSolPoolConfig::ACCUMULATOR_PRECISION / 1_000_000_000_000;
@@ -520,7 +789,7 @@ fn test_finalize_rewards_resets_pending_fields() {
     config.pending_deposits = 400;
     config.pending_withdrawals = 100;
 
-    config.finalize_rewards(INTERVAL).unwrap();
+    config.finalize_rewards(INTERVAL, 0).unwrap();
 
     assert_eq!(config.pending_deposit_fees, 0);
     assert_eq!(config.pending_withdrawal_fees, 0);
@@ -536,7 +805,7 @@ fn test_finalize_rewards_updates_finalized_balance() {
     config.pending_deposits = 300;
     config.pending_withdrawals = 100;
 
-    config.finalize_rewards(INTERVAL).unwrap();
+    config.finalize_rewards(INTERVAL, 0).unwrap();
 
     assert_eq!(config.finalized_balance, 1200); // 1000 + 300 - 100
 }
@@ -552,7 +821,7 @@ fn test_finalize_rewards_zero_rewards_skips_accumulator_update() {
     // All pending reward fields = 0
 
     let original_accumulator = config.reward_accumulator;
-    config.finalize_rewards(INTERVAL).unwrap();
+    config.finalize_rewards(INTERVAL, 0).unwrap();
 
     // Accumulator should not change if no rewards
     assert_eq!(config.reward_accumulator, original_accumulator);
@@ -569,7 +838,7 @@ fn test_finalize_rewards_zero_pool_with_rewards_skips_update() {
     config.pending_deposit_fees = 1_000_000; // Has rewards
 
     // If total_pool = 0, reward accumulator update is skipped
-    let result = config.finalize_rewards(INTERVAL);
+    let result = config.finalize_rewards(INTERVAL, 0);
     assert!(result.is_ok());
     assert!(result.unwrap()); // Still returns true (finalization happened)
 
@@ -583,7 +852,7 @@ fn test_finalize_rewards_zero_both_pool_and_rewards() {
     config.finalized_balance = 0;
     // All pending = 0
 
-    let result = config.finalize_rewards(INTERVAL);
+    let result = config.finalize_rewards(INTERVAL, 0);
     assert!(result.is_ok());
     assert!(result.unwrap());
 
@@ -601,7 +870,7 @@ fn test_finalize_rewards_precision_small_rewards_large_pool() {
     config.finalized_balance = 1_000_000_000_000_000_000_000; // 1 trillion SOL
     config.pending_deposit_fees = 1;
 
-    config.finalize_rewards(INTERVAL).unwrap();
+    config.finalize_rewards(INTERVAL, 0).unwrap();
 
     // Very small rewards truncate to 0
     assert_eq!(config.reward_accumulator, 0);
@@ -615,7 +884,7 @@ fn test_finalize_rewards_precision_preserved() {
     config.finalized_balance = 100_000_000_000; // 100 SOL
     config.pending_deposit_fees = 1_000_000_000; // 1 SOL (1%)
 
-    config.finalize_rewards(INTERVAL).unwrap();
+    config.finalize_rewards(INTERVAL, 0).unwrap();
 
     let expected = 1_000_000_000u128 * UnifiedSolPoolConfig::ACCUMULATOR_PRECISION / 100_000_000_000;
     assert_eq!(config.reward_accumulator, expected);
@@ -902,6 +1171,7 @@ fn test_deposit_params_for_unified_sol() {
     let params = DepositParams {
         amount: 100_000_000_000,          // 100 LST tokens
         expected_output: 103_950_000_000, // ~104 virtual SOL (accounting for rate and fee)
+        ..Default::default()
     };
 
     let data = zorb_pool_interface::build_deposit_instruction_data(&params);
@@ -916,6 +1186,7 @@ fn test_withdraw_params_for_unified_sol() {
     let params = WithdrawParams {
         amount: 106_050_000_000,          // virtual SOL spent
         expected_output: 100_000_000_000, // LST tokens to receive
+        ..Default::default()
     };
 
     let data = zorb_pool_interface::build_withdraw_instruction_data(&params);
@@ -937,14 +1208,14 @@ fn test_multiple_finalization_with_appreciation() {
     // Cycle 1: Deposit fees + appreciation
     config.pending_deposit_fees = 1_000_000_000;
     config.pending_appreciation = 5_000_000_000;
-    config.finalize_rewards(INTERVAL).unwrap();
+    config.finalize_rewards(INTERVAL, 0).unwrap();
 
     let acc1 = config.reward_accumulator;
     assert_eq!(config.reward_epoch, 2);
 
     // Cycle 2: More appreciation
     config.pending_appreciation = 7_000_000_000;
-    config.finalize_rewards(INTERVAL * 2).unwrap();
+    config.finalize_rewards(INTERVAL * 2, 0).unwrap();
 
     let acc2 = config.reward_accumulator;
     assert_eq!(config.reward_epoch, 3);
@@ -966,7 +1237,7 @@ fn test_finalization_with_deposits_and_withdrawals() {
     config.pending_withdrawals = 200_000_000_000;
     config.pending_appreciation = 10_000_000_000;
 
-    config.finalize_rewards(INTERVAL).unwrap();
+    config.finalize_rewards(INTERVAL, 0).unwrap();
 
     // finalized_balance = 1000 + 500 - 200 = 1300
     assert_eq!(config.finalized_balance, 1_300_000_000_000);
@@ -989,7 +1260,7 @@ fn test_docstring_example() {
     // LST appreciation + fees: 50e9
     config.pending_appreciation = 50_000_000_000;
 
-    config.finalize_rewards(INTERVAL).unwrap();
+    config.finalize_rewards(INTERVAL, 0).unwrap();
 
     // total_pool = 1000e9 + 500e9 - 200e9 = 1300e9
     assert_eq!(config.finalized_balance, 1_300_000_000_000);
@@ -0,0 +1,384 @@
+//! Keeper allowlist tests.
+//!
+//! Tests for AddKeeper, RemoveKeeper and SetKeeperAllowlistEnabled, and the
+//! caller check they add to harvests and finalization.
+
+mod common;
+
+use common::*;
+use litesvm::LiteSVM;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use unified_sol_pool::{EventType, UnifiedSolRewardsFinalizedEvent};
+
+/// Slot interval required between epoch advances
+const UPDATE_SLOT_INTERVAL: u64 = 2700;
+
+/// UnifiedSolPoolError::Unauthorized
+const UNAUTHORIZED: u32 = 18;
+/// UnifiedSolPoolError::KeeperNotAllowed
+const KEEPER_NOT_ALLOWED: u32 = 66;
+/// UnifiedSolPoolError::InvalidKeeper
+const INVALID_KEEPER: u32 = 67;
+/// UnifiedSolPoolError::KeeperAllowlistFull
+const KEEPER_ALLOWLIST_FULL: u32 = 68;
+/// UnifiedSolPoolError::KeeperNotListed
+const KEEPER_NOT_LISTED: u32 = 69;
+/// UnifiedSolPoolError::KeeperAllowlistEmpty
+const KEEPER_ALLOWLIST_EMPTY: u32 = 70;
+
+/// A funded signer to act as a keeper or an outsider.
+fn funded_signer(svm: &mut LiteSVM) -> Keypair {
+    let signer = Keypair::new();
+    svm.airdrop(&signer.pubkey(), 10_000_000_000).unwrap();
+    signer
+}
+
+/// The caller recorded in the finalization event among `events`.
+fn finalize_caller(events: &[Vec<u8>]) -> Pubkey {
+    let event = events
+        .iter()
+        .find(|event| event[..8] == (EventType::UnifiedSolRewardsFinalized as u64).to_le_bytes())
+        .expect("finalize should emit UnifiedSolRewardsFinalized");
+    let event: UnifiedSolRewardsFinalizedEvent = bytemuck::pod_read_unaligned(&event[8..]);
+    Pubkey::new_from_array(event.caller)
+}
+
+/// Test harvests and finalization stay open to any signer by default.
+#[test]
+fn test_keeper_allowlist_disabled_is_permissionless() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = funded_signer(&mut svm);
+    let (unified_sol_config, lst_config, lst_vault) =
+        setup_wsol_pool(&mut svm, &program_id, &authority);
+
+    assert_eq!(
+        get_unified_config_keepers(&svm, &unified_sol_config),
+        (vec![], 0)
+    );
+
+    let anyone = funded_signer(&mut svm);
+    harvest_lst_appreciation(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &lst_vault,
+        None,
+        &anyone,
+    )
+    .expect("harvest should be permissionless");
+
+    warp_to_slot(&mut svm, UPDATE_SLOT_INTERVAL + 10);
+    let events = advance_unified_epoch_with_events(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &[lst_config],
+        &anyone,
+    )
+    .expect("finalize should be permissionless");
+    assert_eq!(finalize_caller(&events), anyone.pubkey());
+
+    // Listing a keeper alone does not restrict anyone
+    let keeper = funded_signer(&mut svm);
+    add_keeper(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        &keeper.pubkey(),
+    )
+    .expect("add_keeper should succeed");
+    harvest_all_lst_appreciation(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &[(lst_config, lst_vault, lst_vault)],
+        &anyone,
+    )
+    .expect("harvest_all should be permissionless");
+}
+
+/// Test an enforced allowlist admits listed keepers only.
+#[test]
+fn test_keeper_allowlist_enforced() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = funded_signer(&mut svm);
+    let (unified_sol_config, lst_config, lst_vault) =
+        setup_wsol_pool(&mut svm, &program_id, &authority);
+
+    let keeper = funded_signer(&mut svm);
+    let outsider = funded_signer(&mut svm);
+    add_keeper(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        &keeper.pubkey(),
+    )
+    .expect("add_keeper should succeed");
+    set_keeper_allowlist_enabled(&mut svm, &program_id, &unified_sol_config, &authority, true)
+        .expect("enabling the allowlist should succeed");
+    assert_eq!(
+        get_unified_config_keepers(&svm, &unified_sol_config),
+        (vec![keeper.pubkey()], 1)
+    );
+
+    let err = harvest_lst_appreciation(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &lst_vault,
+        None,
+        &outsider,
+    )
+    .unwrap_err();
+    assert!(err.contains(&custom_error(KEEPER_NOT_ALLOWED)), "{}", err);
+
+    let err = harvest_all_lst_appreciation(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &[(lst_config, lst_vault, lst_vault)],
+        &outsider,
+    )
+    .unwrap_err();
+    assert!(err.contains(&custom_error(KEEPER_NOT_ALLOWED)), "{}", err);
+
+    harvest_lst_appreciation(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &lst_vault,
+        None,
+        &keeper,
+    )
+    .expect("keeper harvest should succeed");
+
+    warp_to_slot(&mut svm, UPDATE_SLOT_INTERVAL + 10);
+    let err = advance_unified_epoch(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &[lst_config],
+        &outsider,
+    )
+    .unwrap_err();
+    assert!(err.contains(&custom_error(KEEPER_NOT_ALLOWED)), "{}", err);
+
+    let events = advance_unified_epoch_with_events(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &[lst_config],
+        &keeper,
+    )
+    .expect("keeper finalize should succeed");
+    assert_eq!(finalize_caller(&events), keeper.pubkey());
+
+    // A delisted keeper is turned away; lifting the allowlist reopens harvests
+    let backup = funded_signer(&mut svm);
+    add_keeper(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        &backup.pubkey(),
+    )
+    .expect("add_keeper should succeed");
+    remove_keeper(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        &keeper.pubkey(),
+    )
+    .expect("remove_keeper should succeed");
+    svm.expire_blockhash();
+    let err = harvest_lst_appreciation(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &lst_vault,
+        None,
+        &keeper,
+    )
+    .unwrap_err();
+    assert!(err.contains(&custom_error(KEEPER_NOT_ALLOWED)), "{}", err);
+
+    set_keeper_allowlist_enabled(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        false,
+    )
+    .expect("lifting the allowlist should succeed");
+    harvest_lst_appreciation(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &lst_vault,
+        None,
+        &outsider,
+    )
+    .expect("harvest should be permissionless again");
+}
+
+/// Test the allowlist's bounds and that it cannot be enforced while empty.
+#[test]
+fn test_keeper_allowlist_bounds() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = funded_signer(&mut svm);
+    let (unified_sol_config, _, _) = setup_wsol_pool(&mut svm, &program_id, &authority);
+
+    let err =
+        set_keeper_allowlist_enabled(&mut svm, &program_id, &unified_sol_config, &authority, true)
+            .unwrap_err();
+    assert!(
+        err.contains(&custom_error(KEEPER_ALLOWLIST_EMPTY)),
+        "{}",
+        err
+    );
+
+    let err = add_keeper(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        &Pubkey::default(),
+    )
+    .unwrap_err();
+    assert!(err.contains(&custom_error(INVALID_KEEPER)), "{}", err);
+
+    let keepers: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+    for keeper in &keepers {
+        add_keeper(
+            &mut svm,
+            &program_id,
+            &unified_sol_config,
+            &authority,
+            keeper,
+        )
+        .expect("add_keeper should succeed");
+    }
+    let err = add_keeper(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        &Pubkey::new_unique(),
+    )
+    .unwrap_err();
+    assert!(
+        err.contains(&custom_error(KEEPER_ALLOWLIST_FULL)),
+        "{}",
+        err
+    );
+
+    let err = remove_keeper(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        &Pubkey::new_unique(),
+    )
+    .unwrap_err();
+    assert!(err.contains(&custom_error(KEEPER_NOT_LISTED)), "{}", err);
+
+    set_keeper_allowlist_enabled(&mut svm, &program_id, &unified_sol_config, &authority, true)
+        .expect("enabling the allowlist should succeed");
+    for keeper in &keepers[..3] {
+        remove_keeper(
+            &mut svm,
+            &program_id,
+            &unified_sol_config,
+            &authority,
+            keeper,
+        )
+        .expect("remove_keeper should succeed");
+    }
+
+    // The last keeper stays while the allowlist is enforced
+    let err = remove_keeper(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        &keepers[3],
+    )
+    .unwrap_err();
+    assert!(
+        err.contains(&custom_error(KEEPER_ALLOWLIST_EMPTY)),
+        "{}",
+        err
+    );
+    assert_eq!(
+        get_unified_config_keepers(&svm, &unified_sol_config),
+        (vec![keepers[3]], 1)
+    );
+}
+
+/// Test only the authority can change the keeper allowlist.
+#[test]
+fn test_keeper_allowlist_unauthorized() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = funded_signer(&mut svm);
+    let (unified_sol_config, _, _) = setup_wsol_pool(&mut svm, &program_id, &authority);
+
+    let keeper = Pubkey::new_unique();
+    add_keeper(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        &keeper,
+    )
+    .expect("add_keeper should succeed");
+
+    let other_user = funded_signer(&mut svm);
+    let err = add_keeper(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &other_user,
+        &other_user.pubkey(),
+    )
+    .unwrap_err();
+    assert!(err.contains(&custom_error(UNAUTHORIZED)), "{}", err);
+
+    let err = remove_keeper(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &other_user,
+        &keeper,
+    )
+    .unwrap_err();
+    assert!(err.contains(&custom_error(UNAUTHORIZED)), "{}", err);
+
+    let err = set_keeper_allowlist_enabled(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &other_user,
+        true,
+    )
+    .unwrap_err();
+    assert!(err.contains(&custom_error(UNAUTHORIZED)), "{}", err);
+
+    assert_eq!(
+        get_unified_config_keepers(&svm, &unified_sol_config),
+        (vec![keeper], 0)
+    );
+}
@@ -0,0 +1,212 @@
+//! Per-LST finalization breakdown tests.
+
+mod common;
+
+use bytemuck::Pod;
+use common::*;
+use litesvm::LiteSVM;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use unified_sol_pool::{EventType, LstFinalizedEvent, UnifiedSolRewardsFinalizedEvent};
+use zorb_pool_interface::WithdrawParams;
+
+/// Pool-wide deposit fee (1%)
+const DEPOSIT_FEE_RATE: u16 = 100;
+/// Pool-wide withdrawal fee (0.5%)
+const WITHDRAWAL_FEE_RATE: u16 = 50;
+
+/// Deposit `amount` tokens of `lst_mint` from a fresh depositor.
+fn deposit_lst(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_config: &Pubkey,
+    lst_config: &Pubkey,
+    lst_mint: &Pubkey,
+    amount: u64,
+) {
+    let depositor = Keypair::new();
+    svm.airdrop(&depositor.pubkey(), 10_000_000_000).unwrap();
+    let depositor_token = create_mock_token_account(svm, lst_mint, &depositor.pubkey());
+    update_vault_balance(svm, &depositor_token, amount);
+
+    let fee = amount * DEPOSIT_FEE_RATE as u64 / 10_000;
+    deposit(
+        svm,
+        program_id,
+        unified_sol_config,
+        lst_config,
+        &depositor_token,
+        &depositor,
+        amount,
+        amount - fee,
+    )
+    .expect("deposit should succeed");
+}
+
+/// Events of type `T` among `events`.
+fn events_of<T: Pod>(events: &[Vec<u8>], event_type: EventType) -> Vec<T> {
+    events
+        .iter()
+        .filter(|event| event[..8] == (event_type as u64).to_le_bytes())
+        .map(|event| bytemuck::pod_read_unaligned(&event[8..]))
+        .collect()
+}
+
+/// Test the per-LST events emitted at finalization sum to the aggregate event,
+/// and the per-LST counters reset once the rewards are distributed.
+#[test]
+fn test_lst_finalized_events_sum_to_aggregate() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let unified_sol_config = init_unified_sol_pool_config(
+        &mut svm,
+        &program_id,
+        &authority,
+        0,
+        DEPOSIT_FEE_RATE,
+        WITHDRAWAL_FEE_RATE,
+        0,
+        0,
+    )
+    .expect("init_unified_sol_pool_config should succeed");
+
+    let (first_config, first_mint, first_pool, first_vault) =
+        add_spl_stake_pool_lst(&mut svm, &program_id, &unified_sol_config, &authority);
+    let (second_config, second_mint, second_pool, second_vault) =
+        add_spl_stake_pool_lst(&mut svm, &program_id, &unified_sol_config, &authority);
+
+    // Deposit fees on both LSTs, a withdrawal fee on the second only
+    deposit_lst(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &first_config,
+        &first_mint,
+        2_000_000_000,
+    );
+    deposit_lst(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &second_config,
+        &second_mint,
+        1_000_000_000,
+    );
+
+    let params = WithdrawParams {
+        amount: 1_000_000_000,
+        expected_output: 995_000_000,
+        recipient: [7u8; 32],
+        ..Default::default()
+    };
+    withdraw(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &second_config,
+        &params,
+        &[],
+        &authority,
+    )
+    .expect("withdraw should succeed");
+    // The hub moves the approved output out of the vault
+    let second_balance = get_token_balance(&svm, &second_vault);
+    update_vault_balance(&mut svm, &second_vault, second_balance - 995_000_000);
+
+    assert_eq!(
+        get_lst_config_pending_rewards(&svm, &first_config),
+        (20_000_000, 0, 0)
+    );
+    assert_eq!(
+        get_lst_config_pending_rewards(&svm, &second_config),
+        (10_000_000, 5_000_000, 0)
+    );
+
+    // Both LSTs appreciate, by different amounts
+    update_stake_pool_rate(&mut svm, &first_pool, 1_002_000_000_000, 1_000_000_000_000);
+    update_stake_pool_rate(&mut svm, &second_pool, 1_004_000_000_000, 1_000_000_000_000);
+    for (lst_config, stake_pool, lst_vault) in [
+        (first_config, first_pool, first_vault),
+        (second_config, second_pool, second_vault),
+    ] {
+        harvest_lst_appreciation(
+            &mut svm,
+            &program_id,
+            &unified_sol_config,
+            &lst_config,
+            &stake_pool,
+            Some(&lst_vault),
+            &authority,
+        )
+        .expect("harvest should succeed");
+    }
+
+    warp_to_slot(&mut svm, 2710);
+    let events = advance_unified_epoch_with_events(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &[first_config, second_config],
+        &authority,
+    )
+    .expect("finalize should succeed");
+
+    let aggregate: Vec<UnifiedSolRewardsFinalizedEvent> =
+        events_of(&events, EventType::UnifiedSolRewardsFinalized);
+    let per_lst: Vec<LstFinalizedEvent> = events_of(&events, EventType::LstFinalized);
+    assert_eq!(aggregate.len(), 1);
+    let aggregate = aggregate[0];
+
+    // One event per LST, in account order
+    assert_eq!(per_lst.len(), 2);
+    assert_eq!(per_lst[0].lst_mint, first_mint.to_bytes());
+    assert_eq!(per_lst[1].lst_mint, second_mint.to_bytes());
+    assert_eq!(per_lst[0].frozen_rate, 1_002_000_000);
+    assert_eq!(per_lst[1].frozen_rate, 1_004_000_000);
+    assert_eq!(per_lst[0].deposit_fees, 20_000_000);
+    assert_eq!(per_lst[1].withdraw_fees, 5_000_000);
+    assert!(per_lst[1].appreciation_contributed > per_lst[0].appreciation_contributed);
+    for event in &per_lst {
+        assert_eq!(event.epoch, aggregate.epoch);
+        assert_eq!(event.slot, aggregate.slot);
+    }
+
+    // The breakdown accounts for every aggregate figure
+    assert_eq!(
+        per_lst.iter().map(|e| e.deposit_fees).sum::<u64>(),
+        aggregate.deposit_fees
+    );
+    assert_eq!(
+        per_lst.iter().map(|e| e.withdraw_fees).sum::<u64>(),
+        aggregate.withdrawal_fees
+    );
+    assert_eq!(
+        per_lst
+            .iter()
+            .map(|e| e.appreciation_contributed)
+            .sum::<u64>(),
+        aggregate.appreciation_rewards
+    );
+    assert_eq!(
+        per_lst
+            .iter()
+            .map(|e| e.attributed_virtual_sol)
+            .sum::<u128>(),
+        aggregate.total_virtual_sol
+    );
+    assert!(aggregate.appreciation_rewards > 0);
+
+    // Distributed, so attribution starts over for the next epoch
+    assert_eq!(
+        get_lst_config_pending_rewards(&svm, &first_config),
+        (0, 0, 0)
+    );
+    assert_eq!(
+        get_lst_config_pending_rewards(&svm, &second_config),
+        (0, 0, 0)
+    );
+}
@@ -0,0 +1,223 @@
+//! Unified SOL pool LST mode tests.
+//!
+//! Tests for SetLstConfigMode and the per-direction checks in deposit and
+//! withdraw. Both are called directly here, standing in for the hub's CPI.
+
+mod common;
+
+use common::*;
+use litesvm::LiteSVM;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use zorb_pool_interface::WithdrawParams;
+
+/// Vault balance (and virtual SOL, at 1:1) funded into the LST
+const FUNDED: u64 = 5_000_000_000;
+/// Tokens moved per deposit or withdrawal (no fees, 1:1 rate)
+const AMOUNT: u64 = 1_000_000_000;
+
+/// UnifiedSolPoolError::Unauthorized
+const UNAUTHORIZED: u32 = 18;
+/// UnifiedSolPoolError::LstDepositsDisabled
+const LST_DEPOSITS_DISABLED: u32 = 58;
+/// UnifiedSolPoolError::LstWithdrawalsDisabled
+const LST_WITHDRAWALS_DISABLED: u32 = 59;
+/// UnifiedSolPoolError::InvalidLstMode
+const INVALID_LST_MODE: u32 = 60;
+
+/// An LST with a funded vault and a depositor holding its tokens.
+struct FundedLst {
+    unified_sol_config: Pubkey,
+    lst_config: Pubkey,
+    depositor: Keypair,
+    depositor_token: Pubkey,
+}
+
+/// Initialize a unified config with one funded WSOL LST and a depositor.
+fn setup_wsol_lst_with_depositor(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    authority: &Keypair,
+) -> FundedLst {
+    let (unified_sol_config, lst_config, lst_mint, _) =
+        setup_funded_lst(svm, program_id, authority, pool_types::WSOL, FUNDED);
+
+    let depositor = Keypair::new();
+    svm.airdrop(&depositor.pubkey(), 10_000_000_000).unwrap();
+    let depositor_token = create_mock_token_account(svm, &lst_mint, &depositor.pubkey());
+    update_vault_balance(svm, &depositor_token, AMOUNT);
+
+    FundedLst {
+        unified_sol_config,
+        lst_config,
+        depositor,
+        depositor_token,
+    }
+}
+
+/// Read LstConfig.mode from the account.
+fn get_lst_mode(svm: &LiteSVM, lst_config: &Pubkey) -> u8 {
+    let account = svm.get_account(lst_config).unwrap();
+    account.data[lst_config_offsets::MODE]
+}
+
+/// Test deposits and withdrawals under each of the four modes.
+#[test]
+fn test_mode_gates_each_direction() {
+    // (mode, deposit allowed, withdrawal allowed)
+    let cases = [
+        (0, false, false),
+        (lst_modes::DEPOSITS_ENABLED, true, false),
+        (lst_modes::WITHDRAWALS_ENABLED, false, true),
+        (lst_modes::ALL, true, true),
+    ];
+
+    for (mode, deposit_allowed, withdrawal_allowed) in cases {
+        let mut svm = LiteSVM::new();
+        let program_id = deploy_unified_sol_pool_program(&mut svm);
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let lst = setup_wsol_lst_with_depositor(&mut svm, &program_id, &authority);
+        set_lst_config_mode(
+            &mut svm,
+            &program_id,
+            &lst.unified_sol_config,
+            &lst.lst_config,
+            &authority,
+            mode,
+        )
+        .expect("set_lst_config_mode should succeed");
+        assert_eq!(get_lst_mode(&svm, &lst.lst_config), mode);
+
+        let result = deposit(
+            &mut svm,
+            &program_id,
+            &lst.unified_sol_config,
+            &lst.lst_config,
+            &lst.depositor_token,
+            &lst.depositor,
+            AMOUNT,
+            AMOUNT,
+        );
+        if deposit_allowed {
+            result.expect("deposit should succeed");
+        } else {
+            let err = result.expect_err("deposit should be disabled");
+            assert!(
+                err.contains(&custom_error(LST_DEPOSITS_DISABLED)),
+                "mode {}: {}",
+                mode,
+                err
+            );
+        }
+
+        let params = WithdrawParams {
+            amount: AMOUNT,
+            expected_output: AMOUNT,
+            recipient: [7u8; 32],
+            ..Default::default()
+        };
+        let result = withdraw(
+            &mut svm,
+            &program_id,
+            &lst.unified_sol_config,
+            &lst.lst_config,
+            &params,
+            &[],
+            &authority,
+        );
+        if withdrawal_allowed {
+            result.expect("withdraw should succeed");
+        } else {
+            let err = result.expect_err("withdraw should be disabled");
+            assert!(
+                err.contains(&custom_error(LST_WITHDRAWALS_DISABLED)),
+                "mode {}: {}",
+                mode,
+                err
+            );
+        }
+    }
+}
+
+/// Test SetLstConfigMode rejects unknown bits and non-authority signers.
+#[test]
+fn test_set_mode_validation() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let lst = setup_wsol_lst_with_depositor(&mut svm, &program_id, &authority);
+    assert_eq!(get_lst_mode(&svm, &lst.lst_config), lst_modes::ALL);
+
+    let err = set_lst_config_mode(
+        &mut svm,
+        &program_id,
+        &lst.unified_sol_config,
+        &lst.lst_config,
+        &authority,
+        lst_modes::ALL | 0x04,
+    )
+    .expect_err("unknown mode bits should be rejected");
+    assert!(err.contains(&custom_error(INVALID_LST_MODE)), "{}", err);
+
+    let attacker = Keypair::new();
+    svm.airdrop(&attacker.pubkey(), 10_000_000_000).unwrap();
+    let err = set_lst_config_mode(
+        &mut svm,
+        &program_id,
+        &lst.unified_sol_config,
+        &lst.lst_config,
+        &attacker,
+        0,
+    )
+    .expect_err("non-authority should not set the mode");
+    assert!(err.contains(&custom_error(UNAUTHORIZED)), "{}", err);
+
+    assert_eq!(get_lst_mode(&svm, &lst.lst_config), lst_modes::ALL);
+}
+
+/// Test SetLstConfigActive sets or clears both directions.
+#[test]
+fn test_set_active_sets_both_directions() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let lst = setup_wsol_lst_with_depositor(&mut svm, &program_id, &authority);
+    set_lst_config_mode(
+        &mut svm,
+        &program_id,
+        &lst.unified_sol_config,
+        &lst.lst_config,
+        &authority,
+        lst_modes::WITHDRAWALS_ENABLED,
+    )
+    .unwrap();
+
+    set_lst_config_active(
+        &mut svm,
+        &program_id,
+        &lst.unified_sol_config,
+        &lst.lst_config,
+        &authority,
+        true,
+    )
+    .unwrap();
+    assert_eq!(get_lst_mode(&svm, &lst.lst_config), lst_modes::ALL);
+
+    set_lst_config_active(
+        &mut svm,
+        &program_id,
+        &lst.unified_sol_config,
+        &lst.lst_config,
+        &authority,
+        false,
+    )
+    .unwrap();
+    assert_eq!(get_lst_mode(&svm, &lst.lst_config), 0);
+}
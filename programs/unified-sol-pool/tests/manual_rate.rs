@@ -0,0 +1,387 @@
+//! Unified SOL pool manual rate override tests.
+//!
+//! Tests for ProposeManualRate, ApplyManualRate, CancelManualRate and
+//! SetManualRateDelay: the timelock, the unhealthy-source requirement and the
+//! per-epoch band the applied rate must stay within.
+
+mod common;
+
+use common::*;
+use litesvm::LiteSVM;
+use solana_sdk::account::Account;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+/// Manual rate delay used by the tests (the minimum, one slot interval)
+const DELAY_SLOTS: u64 = 2700;
+/// Proposed rate: +40 bps, inside the default 50 bps band
+const MANUAL_RATE: u64 = 1_004_000_000;
+
+/// UnifiedSolPoolError::Unauthorized
+const UNAUTHORIZED: u32 = 18;
+/// UnifiedSolPoolError::ExchangeRateOutOfBounds
+const EXCHANGE_RATE_OUT_OF_BOUNDS: u32 = 44;
+/// UnifiedSolPoolError::ManualRateNotProposed
+const MANUAL_RATE_NOT_PROPOSED: u32 = 61;
+/// UnifiedSolPoolError::ManualRateTimelocked
+const MANUAL_RATE_TIMELOCKED: u32 = 62;
+/// UnifiedSolPoolError::RateSourceHealthy
+const RATE_SOURCE_HEALTHY: u32 = 63;
+/// UnifiedSolPoolError::InvalidManualRateDelay
+const INVALID_MANUAL_RATE_DELAY: u32 = 64;
+
+/// A unified config with one funded SPL stake pool LST at 1:1.
+struct StakePoolLst {
+    unified_sol_config: Pubkey,
+    lst_config: Pubkey,
+    stake_pool: Pubkey,
+    lst_vault: Pubkey,
+}
+
+/// Initialize the pool and LST and shorten the manual rate delay.
+fn setup_manual_rate_lst(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    authority: &Keypair,
+) -> StakePoolLst {
+    let (unified_sol_config, lst_config, stake_pool, lst_vault) =
+        setup_stake_pool_lst(svm, program_id, authority);
+
+    set_manual_rate_delay(svm, program_id, &unified_sol_config, authority, DELAY_SLOTS)
+        .expect("set_manual_rate_delay should succeed");
+
+    StakePoolLst {
+        unified_sol_config,
+        lst_config,
+        stake_pool,
+        lst_vault,
+    }
+}
+
+/// Make the stake pool unreadable, as after its program changed hands.
+fn break_stake_pool(svm: &mut LiteSVM, stake_pool: &Pubkey) {
+    let account = svm
+        .get_account(stake_pool)
+        .expect("stake_pool should exist");
+    let broken = Account {
+        owner: Pubkey::new_unique(),
+        ..account
+    };
+    svm.set_account(*stake_pool, broken).unwrap();
+}
+
+/// Propose, wait out the delay and apply while the stake pool is broken.
+#[test]
+fn test_manual_rate_lifecycle() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+    let lst = setup_manual_rate_lst(&mut svm, &program_id, &authority);
+
+    warp_to_slot(&mut svm, 100);
+    propose_manual_rate(
+        &mut svm,
+        &program_id,
+        &lst.unified_sol_config,
+        &lst.lst_config,
+        &authority,
+        MANUAL_RATE,
+    )
+    .expect("propose_manual_rate should succeed");
+    assert_eq!(
+        get_lst_config_manual_rate(&svm, &lst.lst_config),
+        (MANUAL_RATE, 100 + DELAY_SLOTS)
+    );
+    // Proposing leaves the rate in force untouched
+    assert_eq!(
+        get_lst_config_exchange_rate(&svm, &lst.lst_config),
+        RATE_PRECISION
+    );
+
+    break_stake_pool(&mut svm, &lst.stake_pool);
+    warp_to_slot(&mut svm, 100 + DELAY_SLOTS);
+    apply_manual_rate(
+        &mut svm,
+        &program_id,
+        &lst.unified_sol_config,
+        &lst.lst_config,
+        &lst.stake_pool,
+        &lst.lst_vault,
+        &authority,
+    )
+    .expect("apply_manual_rate should succeed once unlocked");
+
+    assert_eq!(
+        get_lst_config_exchange_rate(&svm, &lst.lst_config),
+        MANUAL_RATE
+    );
+    assert_eq!(
+        get_lst_config_previous_exchange_rate(&svm, &lst.lst_config),
+        RATE_PRECISION
+    );
+    // Counts as this epoch's harvest, booking the appreciation
+    assert_eq!(
+        get_lst_config_last_harvest_epoch(&svm, &lst.lst_config),
+        get_unified_config_reward_epoch(&svm, &lst.unified_sol_config)
+    );
+    assert_eq!(
+        get_unified_config_pending_appreciation(&svm, &lst.unified_sol_config),
+        400_000_000
+    );
+    // The proposal is consumed
+    assert_eq!(get_lst_config_manual_rate(&svm, &lst.lst_config), (0, 0));
+
+    svm.expire_blockhash();
+    let result = apply_manual_rate(
+        &mut svm,
+        &program_id,
+        &lst.unified_sol_config,
+        &lst.lst_config,
+        &lst.stake_pool,
+        &lst.lst_vault,
+        &authority,
+    );
+    assert!(
+        result
+            .unwrap_err()
+            .contains(&custom_error(MANUAL_RATE_NOT_PROPOSED)),
+        "an applied proposal should not apply twice"
+    );
+}
+
+/// Applying before the unlock slot fails, even with a broken source.
+#[test]
+fn test_apply_manual_rate_before_unlock() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+    let lst = setup_manual_rate_lst(&mut svm, &program_id, &authority);
+
+    warp_to_slot(&mut svm, 100);
+    propose_manual_rate(
+        &mut svm,
+        &program_id,
+        &lst.unified_sol_config,
+        &lst.lst_config,
+        &authority,
+        MANUAL_RATE,
+    )
+    .expect("propose_manual_rate should succeed");
+    break_stake_pool(&mut svm, &lst.stake_pool);
+
+    warp_to_slot(&mut svm, 100 + DELAY_SLOTS - 1);
+    let result = apply_manual_rate(
+        &mut svm,
+        &program_id,
+        &lst.unified_sol_config,
+        &lst.lst_config,
+        &lst.stake_pool,
+        &lst.lst_vault,
+        &authority,
+    );
+    assert!(
+        result
+            .unwrap_err()
+            .contains(&custom_error(MANUAL_RATE_TIMELOCKED)),
+        "apply one slot early should be timelocked"
+    );
+    assert_eq!(
+        get_lst_config_exchange_rate(&svm, &lst.lst_config),
+        RATE_PRECISION
+    );
+}
+
+/// A readable stake pool blocks the override; it must be harvested instead.
+#[test]
+fn test_apply_manual_rate_healthy_source() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+    let lst = setup_manual_rate_lst(&mut svm, &program_id, &authority);
+
+    propose_manual_rate(
+        &mut svm,
+        &program_id,
+        &lst.unified_sol_config,
+        &lst.lst_config,
+        &authority,
+        MANUAL_RATE,
+    )
+    .expect("propose_manual_rate should succeed");
+
+    warp_to_slot(&mut svm, DELAY_SLOTS + 1);
+    let result = apply_manual_rate(
+        &mut svm,
+        &program_id,
+        &lst.unified_sol_config,
+        &lst.lst_config,
+        &lst.stake_pool,
+        &lst.lst_vault,
+        &authority,
+    );
+    assert!(
+        result
+            .unwrap_err()
+            .contains(&custom_error(RATE_SOURCE_HEALTHY)),
+        "a healthy rate source should block the manual rate"
+    );
+    assert_eq!(
+        get_lst_config_manual_rate(&svm, &lst.lst_config).0,
+        MANUAL_RATE,
+        "the proposal stays pending"
+    );
+}
+
+/// The applied rate must stay within the LST's per-epoch band.
+#[test]
+fn test_apply_manual_rate_out_of_band() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+    let lst = setup_manual_rate_lst(&mut svm, &program_id, &authority);
+
+    // +60 bps, past the default 50 bps band
+    propose_manual_rate(
+        &mut svm,
+        &program_id,
+        &lst.unified_sol_config,
+        &lst.lst_config,
+        &authority,
+        1_006_000_000,
+    )
+    .expect("propose_manual_rate should succeed");
+    break_stake_pool(&mut svm, &lst.stake_pool);
+
+    warp_to_slot(&mut svm, DELAY_SLOTS + 1);
+    let result = apply_manual_rate(
+        &mut svm,
+        &program_id,
+        &lst.unified_sol_config,
+        &lst.lst_config,
+        &lst.stake_pool,
+        &lst.lst_vault,
+        &authority,
+    );
+    assert!(
+        result
+            .unwrap_err()
+            .contains(&custom_error(EXCHANGE_RATE_OUT_OF_BOUNDS)),
+        "an out-of-band manual rate should be rejected"
+    );
+}
+
+/// Cancel drops the proposal; only the authority may propose or cancel.
+#[test]
+fn test_cancel_manual_rate() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+    let lst = setup_manual_rate_lst(&mut svm, &program_id, &authority);
+
+    let attacker = Keypair::new();
+    svm.airdrop(&attacker.pubkey(), 10_000_000_000).unwrap();
+    let result = propose_manual_rate(
+        &mut svm,
+        &program_id,
+        &lst.unified_sol_config,
+        &lst.lst_config,
+        &attacker,
+        MANUAL_RATE,
+    );
+    assert!(result.unwrap_err().contains(&custom_error(UNAUTHORIZED)));
+
+    let result = cancel_manual_rate(
+        &mut svm,
+        &program_id,
+        &lst.unified_sol_config,
+        &lst.lst_config,
+        &authority,
+    );
+    assert!(
+        result
+            .unwrap_err()
+            .contains(&custom_error(MANUAL_RATE_NOT_PROPOSED)),
+        "nothing to cancel yet"
+    );
+
+    propose_manual_rate(
+        &mut svm,
+        &program_id,
+        &lst.unified_sol_config,
+        &lst.lst_config,
+        &authority,
+        MANUAL_RATE,
+    )
+    .expect("propose_manual_rate should succeed");
+
+    let result = cancel_manual_rate(
+        &mut svm,
+        &program_id,
+        &lst.unified_sol_config,
+        &lst.lst_config,
+        &attacker,
+    );
+    assert!(result.unwrap_err().contains(&custom_error(UNAUTHORIZED)));
+
+    svm.expire_blockhash();
+    cancel_manual_rate(
+        &mut svm,
+        &program_id,
+        &lst.unified_sol_config,
+        &lst.lst_config,
+        &authority,
+    )
+    .expect("cancel_manual_rate should succeed");
+    assert_eq!(get_lst_config_manual_rate(&svm, &lst.lst_config), (0, 0));
+
+    break_stake_pool(&mut svm, &lst.stake_pool);
+    warp_to_slot(&mut svm, DELAY_SLOTS + 1);
+    let result = apply_manual_rate(
+        &mut svm,
+        &program_id,
+        &lst.unified_sol_config,
+        &lst.lst_config,
+        &lst.stake_pool,
+        &lst.lst_vault,
+        &authority,
+    );
+    assert!(
+        result
+            .unwrap_err()
+            .contains(&custom_error(MANUAL_RATE_NOT_PROPOSED)),
+        "a cancelled proposal cannot be applied"
+    );
+}
+
+/// The delay is bounded to one slot interval up to ten default delays.
+#[test]
+fn test_set_manual_rate_delay_bounds() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+    let lst = setup_manual_rate_lst(&mut svm, &program_id, &authority);
+
+    for delay_slots in [DELAY_SLOTS - 1, 4_320_001] {
+        let result = set_manual_rate_delay(
+            &mut svm,
+            &program_id,
+            &lst.unified_sol_config,
+            &authority,
+            delay_slots,
+        );
+        assert!(
+            result
+                .unwrap_err()
+                .contains(&custom_error(INVALID_MANUAL_RATE_DELAY)),
+            "delay {} should be rejected",
+            delay_slots
+        );
+    }
+}
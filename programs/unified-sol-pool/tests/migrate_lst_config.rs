@@ -0,0 +1,217 @@
+//! Unified SOL pool LstConfig migration tests.
+//!
+//! Tests for MigrateLstConfig, which grows version 1 LstConfig accounts to
+//! the current layout (appending the exchange rate history extension).
+
+mod common;
+
+use common::*;
+use litesvm::LiteSVM;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use unified_sol_pool::{
+    LST_CONFIG_ACCOUNT_SIZE, LST_CONFIG_V1_SIZE, LST_CONFIG_VERSION, LstConfig, LstRateHistory,
+};
+
+/// Tokens minted to the depositor's token account
+const DEPOSITOR_BALANCE: u64 = 10_000_000_000;
+/// Tokens moved per deposit (no fees, 1:1 rate)
+const DEPOSIT_AMOUNT: u64 = 1_000_000_000;
+
+/// Initialize a unified config with one WSOL LST.
+///
+/// Returns (unified_sol_config, lst_config, lst_mint).
+fn setup_lst(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    authority: &Keypair,
+) -> (Pubkey, Pubkey, Pubkey) {
+    let unified_sol_config =
+        init_unified_sol_pool_config(svm, program_id, authority, 0, 0, 0, 0, 0)
+            .expect("init_unified_sol_pool_config should succeed");
+
+    let lst_mint = create_mock_mint(svm, 9);
+    let lst_config = init_lst_config(
+        svm,
+        program_id,
+        &unified_sol_config,
+        &lst_mint,
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        authority,
+        pool_types::WSOL,
+    )
+    .expect("init_lst_config should succeed");
+
+    (unified_sol_config, lst_config, lst_mint)
+}
+
+/// Rewrite the LstConfig account as a version 1 account (312 bytes, no version).
+fn downgrade_to_v1(svm: &mut LiteSVM, lst_config: &Pubkey) {
+    let mut account = svm.get_account(lst_config).unwrap();
+    account.data.truncate(LST_CONFIG_V1_SIZE);
+    let offset = lst_config_offsets::VERSION;
+    account.data[offset..offset + 2].fill(0);
+    account.lamports = svm.minimum_balance_for_rent_exemption(LST_CONFIG_V1_SIZE);
+    svm.set_account(*lst_config, account).unwrap();
+}
+
+/// Read the LstConfig (valid for every version)
+fn read_lst_config(svm: &LiteSVM, lst_config: &Pubkey) -> LstConfig {
+    let account = svm.get_account(lst_config).unwrap();
+    bytemuck::pod_read_unaligned(&account.data[8..LST_CONFIG_V1_SIZE])
+}
+
+/// Read the rate history extension (requires a version 2 account)
+fn read_rate_history(svm: &LiteSVM, lst_config: &Pubkey) -> LstRateHistory {
+    let account = svm.get_account(lst_config).unwrap();
+    let end = LstRateHistory::OFFSET + LstRateHistory::SIZE;
+    bytemuck::pod_read_unaligned(&account.data[LstRateHistory::OFFSET..end])
+}
+
+/// Create a depositor holding `DEPOSITOR_BALANCE` tokens of `lst_mint`.
+///
+/// Returns (depositor, depositor_token).
+fn create_depositor(svm: &mut LiteSVM, lst_mint: &Pubkey) -> (Keypair, Pubkey) {
+    let depositor = Keypair::new();
+    svm.airdrop(&depositor.pubkey(), 10_000_000_000).unwrap();
+    let depositor_token = create_mock_token_account(svm, lst_mint, &depositor.pubkey());
+    update_vault_balance(svm, &depositor_token, DEPOSITOR_BALANCE);
+    (depositor, depositor_token)
+}
+
+/// Test that a new LstConfig is current and records its initial rate.
+#[test]
+fn test_init_sets_current_version() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, _) = setup_lst(&mut svm, &program_id, &authority);
+
+    let account = svm.get_account(&lst_config).unwrap();
+    assert_eq!(account.data.len(), LST_CONFIG_ACCOUNT_SIZE);
+
+    let config = read_lst_config(&svm, &lst_config);
+    assert_eq!(config.version, LST_CONFIG_VERSION);
+
+    let history = read_rate_history(&svm, &lst_config);
+    assert_eq!(history.count, 1);
+    assert_eq!(
+        history.rate_at_epoch(1),
+        Some(LstConfig::RATE_PRECISION),
+        "initial 1:1 rate prices the first epoch"
+    );
+
+    let result = migrate_lst_config(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &authority,
+        &authority,
+    );
+    assert!(result.is_err(), "current config should not migrate");
+}
+
+/// Test migrating a version 1 account, with deposits before and after.
+#[test]
+fn test_migrate_v1_to_current() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, lst_mint) = setup_lst(&mut svm, &program_id, &authority);
+    downgrade_to_v1(&mut svm, &lst_config);
+    let (depositor, depositor_token) = create_depositor(&mut svm, &lst_mint);
+
+    // Version 1 accounts keep working without migration
+    deposit(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &depositor_token,
+        &depositor,
+        DEPOSIT_AMOUNT,
+        DEPOSIT_AMOUNT,
+    )
+    .expect("deposit into v1 config should succeed");
+    let config = read_lst_config(&svm, &lst_config);
+    assert_eq!(config.version, 0);
+    assert_eq!(config.vault_token_balance, DEPOSIT_AMOUNT);
+    assert_eq!(config.deposit_count, 1);
+
+    let attacker = Keypair::new();
+    svm.airdrop(&attacker.pubkey(), 10_000_000_000).unwrap();
+    let result = migrate_lst_config(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &attacker,
+        &attacker,
+    );
+    assert!(result.is_err(), "non-authority should not migrate");
+
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 10_000_000_000).unwrap();
+    migrate_lst_config(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &authority,
+        &payer,
+    )
+    .unwrap();
+
+    let account = svm.get_account(&lst_config).unwrap();
+    assert_eq!(account.data.len(), LST_CONFIG_ACCOUNT_SIZE);
+    assert!(account.lamports >= svm.minimum_balance_for_rent_exemption(LST_CONFIG_ACCOUNT_SIZE));
+
+    // Existing fields survive and the history is seeded with the current rate
+    let config = read_lst_config(&svm, &lst_config);
+    assert_eq!(config.version, LST_CONFIG_VERSION);
+    assert_eq!(config.harvested_exchange_rate, LstConfig::RATE_PRECISION);
+    assert_eq!(config.vault_token_balance, DEPOSIT_AMOUNT);
+    assert_eq!(config.deposit_count, 1);
+
+    let history = read_rate_history(&svm, &lst_config);
+    assert_eq!(history.count, 1);
+    assert_eq!(history.rate_at_epoch(1), Some(LstConfig::RATE_PRECISION));
+    // No manual rate is pending after the v3 step
+    assert_eq!(get_lst_config_manual_rate(&svm, &lst_config), (0, 0));
+    // Nor are rewards attributed to it after the v4 step
+    assert_eq!(get_lst_config_pending_rewards(&svm, &lst_config), (0, 0, 0));
+
+    // Deposits keep working, and a second migration is rejected
+    svm.expire_blockhash();
+    deposit(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &depositor_token,
+        &depositor,
+        DEPOSIT_AMOUNT,
+        DEPOSIT_AMOUNT,
+    )
+    .expect("deposit into migrated config should succeed");
+    let config = read_lst_config(&svm, &lst_config);
+    assert_eq!(config.vault_token_balance, 2 * DEPOSIT_AMOUNT);
+    assert_eq!(config.deposit_count, 2);
+
+    let result = migrate_lst_config(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &authority,
+        &payer,
+    );
+    assert!(result.is_err(), "migrated config should not migrate again");
+}
@@ -0,0 +1,361 @@
+//! Unified SOL pool exchange-rate bounds tests.
+//!
+//! Tests for the per-epoch rate band enforced at finalization,
+//! SetLstConfigRateBounds and ApproveLstRateJump. Staleness rejection is
+//! covered by the `check_rate_fresh` unit tests in state.rs, since deposits
+//! and withdrawals are driven by the hub via CPI.
+
+mod common;
+
+use common::*;
+use litesvm::LiteSVM;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+/// Slot interval required between epoch advances
+const UPDATE_SLOT_INTERVAL: u64 = 2700;
+
+/// Stake pool supply used for all rates (rate = lamports * 1e9 / supply)
+const POOL_SUPPLY: u64 = 1_000_000_000_000;
+
+/// Move the stake pool to `lamports / POOL_SUPPLY` and harvest it.
+///
+/// Expires the blockhash first so repeated harvests are not deduplicated.
+fn harvest_at(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    unified_sol_config: &Pubkey,
+    lst_config: &Pubkey,
+    stake_pool: &Pubkey,
+    lst_vault: &Pubkey,
+    payer: &Keypair,
+    lamports: u64,
+) {
+    svm.expire_blockhash();
+    update_stake_pool_rate(svm, stake_pool, lamports, POOL_SUPPLY);
+    harvest_lst_appreciation(
+        svm,
+        program_id,
+        unified_sol_config,
+        lst_config,
+        stake_pool,
+        Some(lst_vault),
+        payer,
+    )
+    .expect("harvest should succeed");
+}
+
+/// Test finalization at and just past the edges of the per-epoch band.
+#[test]
+fn test_finalize_rate_band_edges() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, stake_pool, lst_vault) =
+        setup_stake_pool_lst(&mut svm, &program_id, &authority);
+
+    set_lst_config_rate_bounds(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &authority,
+        40,
+        10,
+        UPDATE_SLOT_INTERVAL * 10,
+    )
+    .expect("set_lst_config_rate_bounds should succeed");
+
+    // Epoch 1: exactly +40 bps from 1.0
+    harvest_at(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &stake_pool,
+        &lst_vault,
+        &authority,
+        1_004_000_000_000,
+    );
+    warp_to_slot(&mut svm, UPDATE_SLOT_INTERVAL + 10);
+    advance_unified_epoch(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &[lst_config],
+        &authority,
+    )
+    .expect("finalize at +40 bps should succeed");
+    assert_eq!(
+        get_lst_config_harvested_exchange_rate(&svm, &lst_config),
+        1_004_000_000
+    );
+    assert_eq!(
+        get_lst_config_rate_updated_slot(&svm, &lst_config),
+        UPDATE_SLOT_INTERVAL + 10
+    );
+
+    // Epoch 2: one unit past +40 bps from 1.004 is rejected
+    harvest_at(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &stake_pool,
+        &lst_vault,
+        &authority,
+        1_008_016_001_000,
+    );
+    warp_to_slot(&mut svm, 2 * UPDATE_SLOT_INTERVAL + 20);
+    let result = advance_unified_epoch(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &[lst_config],
+        &authority,
+    );
+    assert!(result.is_err(), "finalize past +40 bps should fail");
+    assert_eq!(
+        get_lst_config_harvested_exchange_rate(&svm, &lst_config),
+        1_004_000_000
+    );
+
+    // Re-harvesting at the exact edge lets finalization through
+    harvest_at(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &stake_pool,
+        &lst_vault,
+        &authority,
+        1_008_016_000_000,
+    );
+    advance_unified_epoch(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &[lst_config],
+        &authority,
+    )
+    .expect("finalize at +40 bps should succeed");
+
+    // Epoch 3: -10 bps is the edge on the way down, one unit more is rejected
+    harvest_at(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &stake_pool,
+        &lst_vault,
+        &authority,
+        1_007_007_983_999,
+    );
+    warp_to_slot(&mut svm, 3 * UPDATE_SLOT_INTERVAL + 30);
+    let result = advance_unified_epoch(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &[lst_config],
+        &authority,
+    );
+    assert!(result.is_err(), "finalize past -10 bps should fail");
+
+    harvest_at(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &stake_pool,
+        &lst_vault,
+        &authority,
+        1_007_007_984_000,
+    );
+    advance_unified_epoch(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &[lst_config],
+        &authority,
+    )
+    .expect("finalize at -10 bps should succeed");
+    assert_eq!(
+        get_lst_config_harvested_exchange_rate(&svm, &lst_config),
+        1_007_007_984
+    );
+}
+
+/// Test a legitimate large jump goes through once the authority approves it.
+#[test]
+fn test_approve_lst_rate_jump() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, stake_pool, lst_vault) =
+        setup_stake_pool_lst(&mut svm, &program_id, &authority);
+
+    // Two harvests in one epoch move the rate 80 bps, past the default 50
+    for lamports in [1_004_000_000_000, 1_008_000_000_000] {
+        harvest_at(
+            &mut svm,
+            &program_id,
+            &unified_sol_config,
+            &lst_config,
+            &stake_pool,
+            &lst_vault,
+            &authority,
+            lamports,
+        );
+    }
+    warp_to_slot(&mut svm, UPDATE_SLOT_INTERVAL + 10);
+
+    let result = advance_unified_epoch(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &[lst_config],
+        &authority,
+    );
+    assert!(result.is_err(), "finalize outside the band should fail");
+
+    // Only the authority can approve, and never below 1:1
+    let attacker = Keypair::new();
+    svm.airdrop(&attacker.pubkey(), 1_000_000_000).unwrap();
+    let result = approve_lst_rate_jump(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &attacker,
+        1_008_000_000,
+    );
+    assert!(result.is_err(), "non-authority approval should fail");
+
+    let result = approve_lst_rate_jump(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &authority,
+        999_999_999,
+    );
+    assert!(result.is_err(), "approving a rate below 1:1 should fail");
+
+    // An approval for a different rate does not help
+    approve_lst_rate_jump(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &authority,
+        1_007_000_000,
+    )
+    .expect("approve_lst_rate_jump should succeed");
+    svm.expire_blockhash();
+    let result = advance_unified_epoch(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &[lst_config],
+        &authority,
+    );
+    assert!(result.is_err(), "approval must match the harvested rate");
+
+    approve_lst_rate_jump(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &authority,
+        1_008_000_000,
+    )
+    .expect("approve_lst_rate_jump should succeed");
+    assert_eq!(
+        get_lst_config_approved_exchange_rate(&svm, &lst_config),
+        1_008_000_000
+    );
+
+    svm.expire_blockhash();
+    advance_unified_epoch(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &[lst_config],
+        &authority,
+    )
+    .expect("approved jump should finalize");
+    assert_eq!(
+        get_lst_config_harvested_exchange_rate(&svm, &lst_config),
+        1_008_000_000
+    );
+    // The approval is single-use
+    assert_eq!(get_lst_config_approved_exchange_rate(&svm, &lst_config), 0);
+}
+
+/// Test SetLstConfigRateBounds enforces the program ceilings and authority.
+#[test]
+fn test_set_lst_config_rate_bounds_validation() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, _, _) =
+        setup_stake_pool_lst(&mut svm, &program_id, &authority);
+
+    for (increase, decrease, staleness) in [
+        (1_001, 0, UPDATE_SLOT_INTERVAL),
+        (0, 1_001, UPDATE_SLOT_INTERVAL),
+        (0, 0, UPDATE_SLOT_INTERVAL - 1),
+        (0, 0, 432_001),
+    ] {
+        let result = set_lst_config_rate_bounds(
+            &mut svm,
+            &program_id,
+            &unified_sol_config,
+            &lst_config,
+            &authority,
+            increase,
+            decrease,
+            staleness,
+        );
+        assert!(
+            result.is_err(),
+            "bounds ({}, {}, {}) should be rejected",
+            increase,
+            decrease,
+            staleness
+        );
+    }
+
+    let attacker = Keypair::new();
+    svm.airdrop(&attacker.pubkey(), 1_000_000_000).unwrap();
+    let result = set_lst_config_rate_bounds(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &attacker,
+        100,
+        100,
+        UPDATE_SLOT_INTERVAL,
+    );
+    assert!(result.is_err(), "non-authority should fail");
+
+    set_lst_config_rate_bounds(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &authority,
+        1_000,
+        1_000,
+        432_000,
+    )
+    .expect("bounds at the ceilings should be accepted");
+}
@@ -0,0 +1,182 @@
+//! Unified SOL pool LST retirement tests.
+//!
+//! Tests for SetLstConfigRetired and CloseLstConfig. Withdrawals are driven
+//! by the hub via CPI, so draining is simulated by zeroing the vault and its
+//! counter.
+
+mod common;
+
+use common::*;
+use litesvm::LiteSVM;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+/// Slot interval required between epoch advances
+const UPDATE_SLOT_INTERVAL: u64 = 2700;
+
+/// [`setup_wsol_pool`] with 5 tokens in the vault.
+///
+/// Returns (unified_sol_config, lst_config, lst_vault).
+fn setup_funded_wsol_lst(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    authority: &Keypair,
+) -> (Pubkey, Pubkey, Pubkey) {
+    let (unified_sol_config, lst_config, lst_vault) = setup_wsol_pool(svm, program_id, authority);
+    update_vault_balance(svm, &lst_vault, 5_000_000_000);
+    update_lst_config_vault_balance(svm, &lst_config, 5_000_000_000);
+
+    (unified_sol_config, lst_config, lst_vault)
+}
+
+/// Test retire -> drain -> finalize -> close.
+#[test]
+fn test_retire_drain_and_close_lst_config() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, lst_vault) =
+        setup_funded_wsol_lst(&mut svm, &program_id, &authority);
+
+    set_lst_config_retired(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &authority,
+        true,
+    )
+    .expect("set_lst_config_retired should succeed");
+
+    // Withdrawals drain the vault
+    update_vault_balance(&mut svm, &lst_vault, 0);
+    update_lst_config_vault_balance(&mut svm, &lst_config, 0);
+
+    // Retired LSTs do not need a harvest to finalize
+    warp_to_slot(&mut svm, UPDATE_SLOT_INTERVAL + 10);
+    advance_unified_epoch(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &[lst_config],
+        &authority,
+    )
+    .expect("finalize should skip the harvest check for a retired LST");
+
+    let balance_before = svm.get_balance(&authority.pubkey()).unwrap();
+    close_lst_config(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &authority,
+    )
+    .expect("close_lst_config should succeed");
+
+    assert!(
+        svm.get_account(&lst_config)
+            .is_none_or(|account| account.lamports == 0),
+        "lst_config should be closed"
+    );
+    assert!(
+        svm.get_account(&lst_vault)
+            .is_none_or(|account| account.lamports == 0),
+        "lst_vault should be closed"
+    );
+    assert!(
+        svm.get_balance(&authority.pubkey()).unwrap() > balance_before,
+        "rent should be returned to the authority"
+    );
+
+    // lst_count dropped to zero: finalization no longer expects the config
+    warp_to_slot(&mut svm, 2 * UPDATE_SLOT_INTERVAL + 20);
+    advance_unified_epoch(&mut svm, &program_id, &unified_sol_config, &[], &authority)
+        .expect("finalize should succeed with no LST configs");
+}
+
+/// Test close is rejected while the LST is not retired or still holds tokens.
+#[test]
+fn test_close_lst_config_rejects_residual_balance() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, lst_vault) =
+        setup_funded_wsol_lst(&mut svm, &program_id, &authority);
+
+    let result = close_lst_config(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &authority,
+    );
+    assert!(result.is_err(), "close should require retirement");
+
+    set_lst_config_retired(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &authority,
+        true,
+    )
+    .expect("set_lst_config_retired should succeed");
+
+    // Counter drained but tokens left in the vault
+    update_lst_config_vault_balance(&mut svm, &lst_config, 0);
+    svm.expire_blockhash();
+    let result = close_lst_config(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &authority,
+    );
+    assert!(result.is_err(), "close should reject a vault with tokens");
+
+    // Vault drained but the counter still attributes a balance
+    update_vault_balance(&mut svm, &lst_vault, 0);
+    update_lst_config_vault_balance(&mut svm, &lst_config, 1);
+    svm.expire_blockhash();
+    let result = close_lst_config(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &authority,
+    );
+    assert!(
+        result.is_err(),
+        "close should reject a residual vault counter"
+    );
+    assert!(svm.get_account(&lst_config).is_some());
+}
+
+/// Test only the authority can retire an LST.
+#[test]
+fn test_set_lst_config_retired_unauthorized() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    let other_user = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+    svm.airdrop(&other_user.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, _) =
+        setup_funded_wsol_lst(&mut svm, &program_id, &authority);
+
+    let result = set_lst_config_retired(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &other_user,
+        true,
+    );
+    assert!(result.is_err(), "retire should fail with wrong authority");
+}
@@ -0,0 +1,240 @@
+//! Unified SOL pool virtual SOL cap tests.
+//!
+//! Tests for SetLstConfigVirtualSolCap, SetUnifiedSolPoolConfigVirtualSolCap
+//! and overflow parking at finalization. Deposit-side cap rejection is
+//! covered by the `check_virtual_sol_cap` unit tests in state.rs, since
+//! deposits are driven by the hub via CPI.
+
+mod common;
+
+use common::*;
+use litesvm::LiteSVM;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+/// Slot interval required between epoch advances
+const UPDATE_SLOT_INTERVAL: u64 = 2700;
+
+/// Stake pool supply used for all rates (rate = lamports * 1e9 / supply)
+const POOL_SUPPLY: u64 = 1_000_000_000_000;
+
+/// Vault balance (and virtual SOL, at 1:1) funded into the LST
+const FUNDED: u64 = 100_000_000_000;
+
+/// [`setup_stake_pool_lst`] with the vault's `FUNDED` tokens counted in
+/// `total_virtual_sol`.
+///
+/// Returns (unified_sol_config, lst_config, stake_pool, lst_vault).
+fn setup_counted_stake_pool_lst(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    authority: &Keypair,
+) -> (Pubkey, Pubkey, Pubkey, Pubkey) {
+    let accounts = setup_stake_pool_lst(svm, program_id, authority);
+    let (unified_sol_config, lst_config, _, _) = accounts;
+    update_total_virtual_sol(svm, &unified_sol_config, &lst_config, FUNDED as u128);
+    accounts
+}
+
+/// Test both cap setters store the cap and reject non-authorities.
+#[test]
+fn test_set_virtual_sol_caps() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, _, _) =
+        setup_counted_stake_pool_lst(&mut svm, &program_id, &authority);
+
+    // Uncapped by default
+    assert_eq!(get_lst_config_virtual_sol_cap(&svm, &lst_config), (0, 0));
+    assert_eq!(
+        get_unified_config_virtual_sol_cap(&svm, &unified_sol_config),
+        (0, 0)
+    );
+
+    set_lst_config_virtual_sol_cap(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &authority,
+        2 * FUNDED,
+    )
+    .expect("set_lst_config_virtual_sol_cap should succeed");
+    assert_eq!(
+        get_lst_config_virtual_sol_cap(&svm, &lst_config),
+        (2 * FUNDED, 0)
+    );
+
+    set_unified_sol_pool_config_virtual_sol_cap(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        3 * FUNDED,
+    )
+    .expect("set_unified_sol_pool_config_virtual_sol_cap should succeed");
+    assert_eq!(
+        get_unified_config_virtual_sol_cap(&svm, &unified_sol_config),
+        (3 * FUNDED, 0)
+    );
+
+    let attacker = Keypair::new();
+    svm.airdrop(&attacker.pubkey(), 1_000_000_000).unwrap();
+    let result = set_lst_config_virtual_sol_cap(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &attacker,
+        1,
+    );
+    assert!(result.is_err(), "non-authority should not set the LST cap");
+    let result = set_unified_sol_pool_config_virtual_sol_cap(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &attacker,
+        1,
+    );
+    assert!(result.is_err(), "non-authority should not set the pool cap");
+    assert_eq!(
+        get_lst_config_virtual_sol_cap(&svm, &lst_config),
+        (2 * FUNDED, 0)
+    );
+    assert_eq!(
+        get_unified_config_virtual_sol_cap(&svm, &unified_sol_config),
+        (3 * FUNDED, 0)
+    );
+}
+
+/// Test a cap set below the current total parks the excess.
+#[test]
+fn test_cap_below_total_parks_overflow() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, _, _) =
+        setup_counted_stake_pool_lst(&mut svm, &program_id, &authority);
+
+    set_lst_config_virtual_sol_cap(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &authority,
+        FUNDED - 40,
+    )
+    .expect("set_lst_config_virtual_sol_cap should succeed");
+    assert_eq!(
+        get_lst_config_virtual_sol_cap(&svm, &lst_config),
+        (FUNDED - 40, 40)
+    );
+
+    set_unified_sol_pool_config_virtual_sol_cap(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        FUNDED - 20,
+    )
+    .expect("set_unified_sol_pool_config_virtual_sol_cap should succeed");
+    assert_eq!(
+        get_unified_config_virtual_sol_cap(&svm, &unified_sol_config),
+        (FUNDED - 20, 20)
+    );
+
+    // Lifting the caps releases the parked amounts
+    svm.expire_blockhash();
+    set_lst_config_virtual_sol_cap(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &authority,
+        0,
+    )
+    .expect("set_lst_config_virtual_sol_cap should succeed");
+    set_unified_sol_pool_config_virtual_sol_cap(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        0,
+    )
+    .expect("set_unified_sol_pool_config_virtual_sol_cap should succeed");
+    assert_eq!(get_lst_config_virtual_sol_cap(&svm, &lst_config), (0, 0));
+    assert_eq!(
+        get_unified_config_virtual_sol_cap(&svm, &unified_sol_config),
+        (0, 0)
+    );
+}
+
+/// Test harvested appreciation past the caps is parked, not a failed finalize.
+#[test]
+fn test_appreciation_past_caps_is_parked() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, stake_pool, lst_vault) =
+        setup_counted_stake_pool_lst(&mut svm, &program_id, &authority);
+
+    // Both caps exactly at the current total
+    set_lst_config_virtual_sol_cap(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &authority,
+        FUNDED,
+    )
+    .expect("set_lst_config_virtual_sol_cap should succeed");
+    set_unified_sol_pool_config_virtual_sol_cap(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        FUNDED,
+    )
+    .expect("set_unified_sol_pool_config_virtual_sol_cap should succeed");
+
+    // +0.4% appreciation, inside the default rate band
+    update_stake_pool_rate(&mut svm, &stake_pool, 1_004_000_000_000, POOL_SUPPLY);
+    harvest_lst_appreciation(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &stake_pool,
+        Some(&lst_vault),
+        &authority,
+    )
+    .expect("harvest should succeed");
+    warp_to_slot(&mut svm, UPDATE_SLOT_INTERVAL + 10);
+    advance_unified_epoch(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &[lst_config],
+        &authority,
+    )
+    .expect("finalize past the caps should succeed");
+
+    // 100 tokens at 1.004 = 100.4 SOL; 0.4 SOL over both caps
+    let over = FUNDED / 250;
+    assert_eq!(
+        get_lst_config_virtual_sol_cap(&svm, &lst_config),
+        (FUNDED, over)
+    );
+    assert_eq!(
+        get_unified_config_virtual_sol_cap(&svm, &unified_sol_config),
+        (FUNDED, over)
+    );
+}
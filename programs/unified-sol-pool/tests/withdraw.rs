@@ -0,0 +1,642 @@
+//! Unified SOL pool withdrawal tests.
+//!
+//! Withdraw is called directly here, standing in for the hub's CPI; the hub
+//! never signs as hub_authority in these tests, so the native SOL unwrap path
+//! is exercised up to its hub signature check. Routed withdrawals only
+//! approve hub_authority, so they run end to end.
+
+mod common;
+
+use common::*;
+use litesvm::LiteSVM;
+use solana_sdk::instruction::AccountMeta;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use zorb_pool_interface::{
+    RoutedWithdrawReturnData, UnifiedSolOpResult, WithdrawParams, find_hub_authority_pda,
+};
+
+/// Vault balance (and virtual SOL, at 1:1) funded into each LST
+const FUNDED: u64 = 5_000_000_000;
+
+/// UnifiedSolPoolError::InvalidHubAuthority
+const INVALID_HUB_AUTHORITY: u32 = 17;
+/// UnifiedSolPoolError::Unauthorized
+const UNAUTHORIZED: u32 = 18;
+/// UnifiedSolPoolError::UnwrapNotSupported
+const UNWRAP_NOT_SUPPORTED: u32 = 47;
+/// UnifiedSolPoolError::InvalidWithdrawPriority
+const INVALID_WITHDRAW_PRIORITY: u32 = 51;
+/// UnifiedSolPoolError::InvalidRouteAccounts
+const INVALID_ROUTE_ACCOUNTS: u32 = 52;
+/// UnifiedSolPoolError::InsufficientRouteLiquidity
+const INSUFFICIENT_ROUTE_LIQUIDITY: u32 = 53;
+
+/// Unwrap accounts for `recipient`, as the hub would pass them.
+fn unwrap_account_metas(
+    program_id: &Pubkey,
+    lst_config: &Pubkey,
+    lst_mint: &Pubkey,
+    recipient: &Pubkey,
+    payer: &Pubkey,
+) -> Vec<AccountMeta> {
+    let (unwrap_account, _) = find_unwrap_account_pda(program_id, lst_config);
+    vec![
+        AccountMeta::new(*recipient, false),
+        AccountMeta::new(unwrap_account, false),
+        AccountMeta::new_readonly(*lst_mint, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+    ]
+}
+
+/// Route accounts for `lst_configs`, as the hub would pass them.
+fn route_account_metas(program_id: &Pubkey, lst_configs: &[Pubkey]) -> Vec<AccountMeta> {
+    lst_configs
+        .iter()
+        .flat_map(|lst_config| {
+            let (lst_vault, _) = find_lst_vault_pda(program_id, lst_config);
+            [
+                AccountMeta::new(*lst_config, false),
+                AccountMeta::new(lst_vault, false),
+            ]
+        })
+        .collect()
+}
+
+/// Routed withdrawal params for `amount` virtual SOL (no fee, 1:1 rate).
+fn routed_params(amount: u64) -> WithdrawParams {
+    WithdrawParams {
+        amount,
+        expected_output: amount,
+        recipient: [7u8; 32],
+        route_automatically: 1,
+        ..Default::default()
+    }
+}
+
+/// Withdrawal params for `amount` virtual SOL with `fallback_count` fallback
+/// LSTs (no fee, 1:1 rate).
+fn fallback_params(amount: u64, fallback_count: u8) -> WithdrawParams {
+    WithdrawParams {
+        amount,
+        expected_output: amount,
+        recipient: [7u8; 32],
+        fallback_count,
+        ..Default::default()
+    }
+}
+
+/// Test a plain withdrawal approves hub_authority for the whole output.
+#[test]
+fn test_withdraw_plain_approves_full_output() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, _, lst_vault) =
+        setup_funded_lst(&mut svm, &program_id, &authority, pool_types::WSOL, FUNDED);
+
+    let params = WithdrawParams {
+        amount: 1_000_000_000,
+        expected_output: 1_000_000_000,
+        recipient: [7u8; 32],
+        ..Default::default()
+    };
+    let return_data = withdraw(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &params,
+        &[],
+        &authority,
+    )
+    .expect("plain withdraw should succeed");
+
+    let (hub_authority, _) = find_hub_authority_pda();
+    assert_eq!(
+        get_token_delegation(&svm, &lst_vault),
+        Some((Pubkey::new_from_array(hub_authority), 1_000_000_000)),
+        "hub_authority should be approved for the full output"
+    );
+    assert_eq!(get_token_balance(&svm, &lst_vault), FUNDED);
+
+    // The hub cross-checks these against its own pricing
+    assert_eq!(
+        UnifiedSolOpResult::from_bytes(&return_data),
+        Some(UnifiedSolOpResult {
+            lst_amount: 1_000_000_000,
+            sol_value: 1_000_000_000,
+            fee: 0,
+            exchange_rate: RATE_PRECISION,
+            accumulator_epoch: get_unified_config_reward_epoch(&svm, &unified_sol_config),
+        })
+    );
+}
+
+/// Test unwrap is rejected for an LST other than WSOL.
+#[test]
+fn test_withdraw_unwrap_rejects_non_wsol() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, lst_mint, _) = setup_funded_lst(
+        &mut svm,
+        &program_id,
+        &authority,
+        pool_types::SPL_STAKE_POOL,
+        FUNDED,
+    );
+
+    let recipient = Pubkey::new_unique();
+    let params = WithdrawParams {
+        amount: 1_000_000_000,
+        expected_output: 1_000_000_000,
+        recipient: recipient.to_bytes(),
+        unwrap: 1,
+        unwrap_amount: 990_000_000,
+        ..Default::default()
+    };
+    let unwrap_accounts = unwrap_account_metas(
+        &program_id,
+        &lst_config,
+        &lst_mint,
+        &recipient,
+        &authority.pubkey(),
+    );
+    let result = withdraw(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &params,
+        &unwrap_accounts,
+        &authority,
+    );
+
+    let err = result.expect_err("unwrap of a stake pool LST should fail");
+    assert!(err.contains(&custom_error(UNWRAP_NOT_SUPPORTED)), "{}", err);
+}
+
+/// Test unwrap pays out only when hub_authority signs.
+///
+/// Without the check, anyone could call Withdraw directly and have the pool
+/// pay native SOL to an arbitrary recipient.
+#[test]
+fn test_withdraw_unwrap_requires_hub_signature() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, lst_mint, lst_vault) =
+        setup_funded_lst(&mut svm, &program_id, &authority, pool_types::WSOL, FUNDED);
+
+    let recipient = Pubkey::new_unique();
+    let params = WithdrawParams {
+        amount: 1_000_000_000,
+        expected_output: 1_000_000_000,
+        recipient: recipient.to_bytes(),
+        unwrap: 1,
+        unwrap_amount: 990_000_000,
+        ..Default::default()
+    };
+    let unwrap_accounts = unwrap_account_metas(
+        &program_id,
+        &lst_config,
+        &lst_mint,
+        &recipient,
+        &authority.pubkey(),
+    );
+    let result = withdraw(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &params,
+        &unwrap_accounts,
+        &authority,
+    );
+
+    let err = result.expect_err("unwrap without the hub's signature should fail");
+    assert!(
+        err.contains(&custom_error(INVALID_HUB_AUTHORITY)),
+        "{}",
+        err
+    );
+    assert_eq!(get_token_balance(&svm, &lst_vault), FUNDED);
+    assert_eq!(svm.get_balance(&recipient).unwrap_or(0), 0);
+}
+
+/// Test SetWithdrawPriority stores the list and rejects non-authorities and
+/// duplicate mints.
+#[test]
+fn test_set_withdraw_priority() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, _, _, _) =
+        setup_funded_lst(&mut svm, &program_id, &authority, pool_types::WSOL, FUNDED);
+    assert_eq!(
+        get_unified_config_withdraw_priority(&svm, &unified_sol_config),
+        [Pubkey::default(); 4]
+    );
+
+    let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let priority = [mint_a, Pubkey::default(), mint_b, Pubkey::default()];
+    set_withdraw_priority(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        priority,
+    )
+    .expect("authority should set the priority list");
+    assert_eq!(
+        get_unified_config_withdraw_priority(&svm, &unified_sol_config),
+        priority
+    );
+
+    let attacker = Keypair::new();
+    svm.airdrop(&attacker.pubkey(), 1_000_000_000).unwrap();
+    let err = set_withdraw_priority(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &attacker,
+        [mint_b; 4],
+    )
+    .expect_err("non-authority should be rejected");
+    assert!(err.contains(&custom_error(UNAUTHORIZED)), "{}", err);
+
+    let err = set_withdraw_priority(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        [mint_a, mint_b, mint_a, Pubkey::default()],
+    )
+    .expect_err("duplicate mints should be rejected");
+    assert!(
+        err.contains(&custom_error(INVALID_WITHDRAW_PRIORITY)),
+        "{}",
+        err
+    );
+    assert_eq!(
+        get_unified_config_withdraw_priority(&svm, &unified_sol_config),
+        priority
+    );
+}
+
+/// Test a routed withdrawal the named vault covers draws nothing from the
+/// routed vaults.
+#[test]
+fn test_withdraw_routed_single_vault_fill() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, _, lst_vault) =
+        setup_funded_lst(&mut svm, &program_id, &authority, pool_types::WSOL, FUNDED);
+    let (routed_config, routed_mint, routed_vault) = add_funded_lst(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        pool_types::SPL_STAKE_POOL,
+        FUNDED,
+    );
+    set_withdraw_priority(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        [
+            routed_mint,
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+        ],
+    )
+    .unwrap();
+
+    withdraw(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &routed_params(1_000_000_000),
+        &route_account_metas(&program_id, &[routed_config]),
+        &authority,
+    )
+    .expect("routed withdraw should succeed");
+
+    let (hub_authority, _) = find_hub_authority_pda();
+    assert_eq!(
+        get_token_delegation(&svm, &lst_vault),
+        Some((Pubkey::new_from_array(hub_authority), 1_000_000_000)),
+        "the named vault should cover the whole withdrawal"
+    );
+    assert_eq!(get_token_delegation(&svm, &routed_vault), None);
+}
+
+/// Test a routed withdrawal larger than the named vault is split across the
+/// named vault and the next vault on the priority list.
+#[test]
+fn test_withdraw_routed_two_vault_split() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, lst_mint, lst_vault) =
+        setup_funded_lst(&mut svm, &program_id, &authority, pool_types::WSOL, FUNDED);
+    let (routed_config, routed_mint, routed_vault) = add_funded_lst(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        pool_types::SPL_STAKE_POOL,
+        FUNDED,
+    );
+    // The named LST on the list is skipped, not drawn from twice
+    set_withdraw_priority(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        [lst_mint, routed_mint, Pubkey::default(), Pubkey::default()],
+    )
+    .unwrap();
+
+    // Routed vaults must be passed in priority order
+    let err = withdraw(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &routed_params(FUNDED + 2_000_000_000),
+        &route_account_metas(&program_id, &[lst_config]),
+        &authority,
+    )
+    .expect_err("a vault off the priority list should be rejected");
+    assert!(
+        err.contains(&custom_error(INVALID_ROUTE_ACCOUNTS)),
+        "{}",
+        err
+    );
+
+    withdraw(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &routed_params(FUNDED + 2_000_000_000),
+        &route_account_metas(&program_id, &[routed_config]),
+        &authority,
+    )
+    .expect("routed withdraw should succeed");
+
+    let hub_authority = Pubkey::new_from_array(find_hub_authority_pda().0);
+    assert_eq!(
+        get_token_delegation(&svm, &lst_vault),
+        Some((hub_authority, FUNDED)),
+        "the named vault should give everything it holds"
+    );
+    assert_eq!(
+        get_token_delegation(&svm, &routed_vault),
+        Some((hub_authority, 2_000_000_000)),
+        "the routed vault should cover the rest"
+    );
+}
+
+/// Test a routed withdrawal fails when all vaults together cannot cover it.
+#[test]
+fn test_withdraw_routed_insufficient_total() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, _, lst_vault) =
+        setup_funded_lst(&mut svm, &program_id, &authority, pool_types::WSOL, FUNDED);
+    let (routed_config, routed_mint, routed_vault) = add_funded_lst(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        pool_types::SPL_STAKE_POOL,
+        1_000_000_000,
+    );
+    set_withdraw_priority(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        [
+            routed_mint,
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+        ],
+    )
+    .unwrap();
+
+    let err = withdraw(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &routed_params(FUNDED + 2_000_000_000),
+        &route_account_metas(&program_id, &[routed_config]),
+        &authority,
+    )
+    .expect_err("vaults short of the withdrawal should be rejected");
+    assert!(
+        err.contains(&custom_error(INSUFFICIENT_ROUTE_LIQUIDITY)),
+        "{}",
+        err
+    );
+    assert_eq!(get_token_delegation(&svm, &lst_vault), None);
+    assert_eq!(get_token_delegation(&svm, &routed_vault), None);
+}
+
+/// Test a withdrawal the requested vault covers leaves its fallbacks untouched.
+#[test]
+fn test_withdraw_fallback_primary_fill() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, _, lst_vault) =
+        setup_funded_lst(&mut svm, &program_id, &authority, pool_types::WSOL, FUNDED);
+    let (fallback_config, _, fallback_vault) = add_funded_lst(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        pool_types::SPL_STAKE_POOL,
+        FUNDED,
+    );
+
+    let return_data = withdraw(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &fallback_params(1_000_000_000, 1),
+        &route_account_metas(&program_id, &[fallback_config]),
+        &authority,
+    )
+    .expect("withdraw with a fallback should succeed");
+
+    assert_eq!(
+        RoutedWithdrawReturnData::from_bytes(&return_data),
+        Some(RoutedWithdrawReturnData {
+            fee: 0,
+            outputs: [1_000_000_000, 0, 0, 0, 0],
+        })
+    );
+    let hub_authority = Pubkey::new_from_array(find_hub_authority_pda().0);
+    assert_eq!(
+        get_token_delegation(&svm, &lst_vault),
+        Some((hub_authority, 1_000_000_000)),
+        "the requested vault should cover the whole withdrawal"
+    );
+    assert_eq!(get_token_delegation(&svm, &fallback_vault), None);
+}
+
+/// Test a withdrawal larger than the requested vault is split across it and
+/// the fallbacks in the order given, without any priority list.
+#[test]
+fn test_withdraw_fallback_split() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, _, lst_vault) =
+        setup_funded_lst(&mut svm, &program_id, &authority, pool_types::WSOL, FUNDED);
+    let (first_config, _, first_vault) = add_funded_lst(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        pool_types::SPL_STAKE_POOL,
+        1_000_000_000,
+    );
+    let (second_config, _, second_vault) = add_funded_lst(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &authority,
+        pool_types::SPL_STAKE_POOL,
+        FUNDED,
+    );
+
+    // Each vault may be named only once
+    let err = withdraw(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &fallback_params(FUNDED + 2_000_000_000, 2),
+        &route_account_metas(&program_id, &[first_config, first_config]),
+        &authority,
+    )
+    .expect_err("a repeated fallback should be rejected");
+    assert!(
+        err.contains(&custom_error(INVALID_ROUTE_ACCOUNTS)),
+        "{}",
+        err
+    );
+
+    let return_data = withdraw(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &fallback_params(FUNDED + 2_000_000_000, 2),
+        &route_account_metas(&program_id, &[first_config, second_config]),
+        &authority,
+    )
+    .expect("withdraw with fallbacks should succeed");
+
+    assert_eq!(
+        RoutedWithdrawReturnData::from_bytes(&return_data),
+        Some(RoutedWithdrawReturnData {
+            fee: 0,
+            outputs: [FUNDED, 1_000_000_000, 1_000_000_000, 0, 0],
+        })
+    );
+    let hub_authority = Pubkey::new_from_array(find_hub_authority_pda().0);
+    assert_eq!(
+        get_token_delegation(&svm, &lst_vault),
+        Some((hub_authority, FUNDED)),
+        "the requested vault should give everything it holds"
+    );
+    assert_eq!(
+        get_token_delegation(&svm, &first_vault),
+        Some((hub_authority, 1_000_000_000)),
+        "the first fallback should give everything it holds"
+    );
+    assert_eq!(
+        get_token_delegation(&svm, &second_vault),
+        Some((hub_authority, 1_000_000_000)),
+        "the second fallback should cover the rest"
+    );
+}
+
+/// Test a withdrawal fails when the requested vault and its fallbacks
+/// together cannot cover it.
+#[test]
+fn test_withdraw_fallback_insufficient_total() {
+    let mut svm = LiteSVM::new();
+    let program_id = deploy_unified_sol_pool_program(&mut svm);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+    let (unified_sol_config, lst_config, _, lst_vault) =
+        setup_funded_lst(&mut svm, &program_id, &authority, pool_types::WSOL, FUNDED);
+    let mut fallback_configs = [Pubkey::default(); 2];
+    let mut fallback_vaults = [Pubkey::default(); 2];
+    for (config, vault) in fallback_configs.iter_mut().zip(&mut fallback_vaults) {
+        (*config, _, *vault) = add_funded_lst(
+            &mut svm,
+            &program_id,
+            &unified_sol_config,
+            &authority,
+            pool_types::SPL_STAKE_POOL,
+            1_000_000_000,
+        );
+    }
+
+    let err = withdraw(
+        &mut svm,
+        &program_id,
+        &unified_sol_config,
+        &lst_config,
+        &fallback_params(FUNDED + 3_000_000_000, 2),
+        &route_account_metas(&program_id, &fallback_configs),
+        &authority,
+    )
+    .expect_err("vaults short of the withdrawal should be rejected");
+    assert!(
+        err.contains(&custom_error(INSUFFICIENT_ROUTE_LIQUIDITY)),
+        "{}",
+        err
+    );
+    assert_eq!(get_token_delegation(&svm, &lst_vault), None);
+    for vault in &fallback_vaults {
+        assert_eq!(get_token_delegation(&svm, vault), None);
+    }
+}